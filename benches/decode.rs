@@ -0,0 +1,99 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use serde_json::json;
+
+use rtdlib::json::*;
+use rtdlib::types::*;
+
+/// A `messages` reply of `count` text messages, as tdlib sends them for `getChatHistory`
+fn messages_json(count: i64) -> String {
+  let messages: Vec<serde_json::Value> = (1..=count).map(message_json).collect();
+  json!({ "@type": "messages", "total_count": count, "messages": messages, "@extra": "5f3c4b8e" }).to_string()
+}
+
+fn message_json(id: i64) -> serde_json::Value {
+  let content = json!({
+    "@type": "messageText",
+    "text": {
+      "@type": "formattedText",
+      "text": format!("message number {}, see https://example.com/{}", id, id),
+      "entities": [
+        { "@type": "textEntity", "offset": 0, "length": 7, "type": { "@type": "textEntityTypeBold" } },
+        { "@type": "textEntity", "offset": 24, "length": 24, "type": { "@type": "textEntityTypeUrl" } }
+      ]
+    }
+  });
+  json!({
+    "@type": "message",
+    "id": id << 20,
+    "sender": { "@type": "messageSenderUser", "user_id": 1000 + id % 7 },
+    "chat_id": -1001234567890_i64,
+    "is_outgoing": false,
+    "is_pinned": false,
+    "can_be_edited": false,
+    "can_be_forwarded": true,
+    "can_be_deleted_only_for_self": false,
+    "can_be_deleted_for_all_users": true,
+    "can_get_statistics": false,
+    "can_get_message_thread": false,
+    "can_get_viewers": false,
+    "can_get_media_timestamp_links": true,
+    "has_timestamped_media": true,
+    "is_channel_post": false,
+    "contains_unread_mention": false,
+    "date": 1638316800 + id,
+    "edit_date": 0,
+    "interaction_info": { "@type": "messageInteractionInfo", "view_count": 12, "forward_count": 0 },
+    "reply_in_chat_id": 0,
+    "reply_to_message_id": 0,
+    "message_thread_id": 0,
+    "ttl": 0,
+    "ttl_expires_in": 0.0,
+    "via_bot_user_id": 0,
+    "author_signature": "",
+    "media_album_id": "0",
+    "restriction_reason": "",
+    "content": content
+  })
+}
+
+fn decode(c: &mut Criterion) {
+  let mut group = c.benchmark_group("decode messages");
+  for count in [10, 100, 1000].iter() {
+    let json = messages_json(*count);
+    group.throughput(Throughput::Bytes(json.len() as u64));
+    group.bench_with_input(BenchmarkId::new("TdType", count), &json, |b, json| {
+      b.iter(|| from_json::<TdType>(json).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("Messages", count), &json, |b, json| {
+      b.iter(|| Messages::from_json(json).unwrap())
+    });
+    // a lower bound, only parsing the json
+    group.bench_with_input(BenchmarkId::new("serde_json::Value", count), &json, |b, json| {
+      b.iter(|| serde_json::from_str::<serde_json::Value>(json).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("detect_td_type_and_extra", count), &json, |b, json| {
+      b.iter(|| detect_td_type_and_extra(json))
+    });
+  }
+  group.finish();
+}
+
+/// The json backends against each other, with the `simd-json` feature
+fn backends(c: &mut Criterion) {
+  let mut group = c.benchmark_group("json backends");
+  for count in [10, 100, 1000].iter() {
+    let json = messages_json(*count);
+    group.throughput(Throughput::Bytes(json.len() as u64));
+    group.bench_with_input(BenchmarkId::new("SerdeJson", count), &json, |b, json| {
+      b.iter(|| SerdeJson::from_str::<TdType>(json).unwrap())
+    });
+    #[cfg(feature = "simd-json")]
+    group.bench_with_input(BenchmarkId::new("SimdJson", count), &json, |b, json| {
+      b.iter(|| SimdJson::from_str::<TdType>(json).unwrap())
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, decode, backends);
+criterion_main!(benches);
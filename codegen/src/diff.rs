@@ -0,0 +1,268 @@
+//! What changes between two schemas, and so between the types generated from them
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::rust;
+use crate::tl::{Definition, Field, Schema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+  Constructor,
+  Function,
+}
+
+/// A difference between an old and a new schema. Field types are as written in the schema, Rust
+/// types as the generated structs have them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum Change {
+  Added { kind: Kind, name: String },
+  Removed { kind: Kind, name: String },
+  /// Removed and added with the same fields and class or result
+  Renamed { kind: Kind, old: String, new: String },
+  /// The class of a constructor or the result of a function
+  ResultChanged { kind: Kind, name: String, old: String, new: String },
+  /// `required` if the new `new(..)` takes it, breaking its callers
+  FieldAdded {
+    kind: Kind, name: String, field: String,
+    #[serde(rename = "type")]
+    type_: String,
+    #[serde(skip)]
+    required: bool,
+  },
+  FieldRemoved {
+    kind: Kind, name: String, field: String,
+    #[serde(rename = "type")]
+    type_: String,
+  },
+  FieldTypeChanged { kind: Kind, name: String, field: String, old: String, new: String },
+  /// The field became an `Option`, e.g. because the schema now says it may be null
+  FieldOptional { kind: Kind, name: String, field: String, old: String, new: String },
+  /// The field is no longer an `Option`
+  FieldRequired { kind: Kind, name: String, field: String, old: String, new: String },
+  /// The field is in another place among the fields of both schemas, `old` and `new` are its
+  /// positions in the definitions. Swapped fields of the same type still compile, as swapped
+  /// arguments of `new(..)`.
+  FieldMoved { kind: Kind, name: String, field: String, old: usize, new: usize },
+}
+
+impl Change {
+  /// Whether code written against the old types may no longer compile or decode the new ones
+  pub fn is_breaking(&self) -> bool {
+    match self {
+      Change::Added { .. } => false,
+      Change::FieldAdded { required, .. } => *required,
+      _ => true,
+    }
+  }
+
+  /// The change as a single line json object, e.g.
+  /// `{"change":"field_optional","kind":"constructor","name":"message","field":"has_timestamped_media","old":"bool","new":"Option<bool>","breaking":true}`
+  pub fn to_json(&self) -> String {
+    #[derive(Serialize)]
+    struct Line<'a> {
+      #[serde(flatten)]
+      change: &'a Change,
+      breaking: bool,
+    }
+    serde_json::to_string(&Line { change: self, breaking: self.is_breaking() }).expect("changes serialize to json")
+  }
+}
+
+/// Changes from `old` to `new`, those of constructors first
+pub fn diff(old: &Schema, new: &Schema) -> Result<Vec<Change>, String> {
+  let old_types = rust::field_types(old)?;
+  let new_types = rust::field_types(new)?;
+  let required = rust::required_fields(new)?;
+  let mut changes = diff_definitions(Kind::Constructor, &old.types, &new.types, &old_types, &new_types, &required);
+  changes.extend(diff_definitions(Kind::Function, &old.functions, &new.functions, &old_types, &new_types, &required));
+  Ok(changes)
+}
+
+type FieldTypes = BTreeMap<(String, String), String>;
+
+fn diff_definitions(kind: Kind, old: &[Definition], new: &[Definition], old_types: &FieldTypes, new_types: &FieldTypes, required: &BTreeSet<(String, String)>) -> Vec<Change> {
+  let old: BTreeMap<&str, &Definition> = old.iter().map(|definition| (definition.name.as_str(), definition)).collect();
+  let new: BTreeMap<&str, &Definition> = new.iter().map(|definition| (definition.name.as_str(), definition)).collect();
+  let removed: Vec<&Definition> = old.values().filter(|definition| !new.contains_key(definition.name.as_str())).copied().collect();
+  let added: Vec<&Definition> = new.values().filter(|definition| !old.contains_key(definition.name.as_str())).copied().collect();
+
+  // a rename is only certain if nothing else removed or added looks the same
+  let same = |a: &Definition, b: &Definition| a.result == b.result && a.fields.iter().map(|field| (&field.name, &field.type_)).eq(b.fields.iter().map(|field| (&field.name, &field.type_)));
+  let mut renamed = BTreeMap::new();
+  for old_definition in &removed {
+    let mut candidates = added.iter().filter(|new_definition| same(old_definition, new_definition));
+    if let (Some(new_definition), None) = (candidates.next(), candidates.next()) {
+      if removed.iter().filter(|other| same(other, new_definition)).count() == 1 {
+        renamed.insert(old_definition.name.as_str(), new_definition.name.as_str());
+      }
+    }
+  }
+
+  let mut changes = vec![];
+  for definition in &removed {
+    match renamed.get(definition.name.as_str()) {
+      Some(new_name) => changes.push(Change::Renamed { kind, old: definition.name.clone(), new: new_name.to_string() }),
+      None => changes.push(Change::Removed { kind, name: definition.name.clone() }),
+    }
+  }
+  for definition in added.iter().filter(|definition| !renamed.values().any(|new_name| *new_name == definition.name)) {
+    changes.push(Change::Added { kind, name: definition.name.clone() });
+  }
+
+  for (name, old_definition) in &old {
+    let new_definition = match new.get(name) {
+      Some(definition) => definition,
+      None => continue,
+    };
+    let name = name.to_string();
+    if old_definition.result != new_definition.result {
+      changes.push(Change::ResultChanged { kind, name: name.clone(), old: old_definition.result.clone(), new: new_definition.result.clone() });
+    }
+    for old_field in &old_definition.fields {
+      let key = (name.clone(), old_field.name.clone());
+      let new_field = match new_definition.fields.iter().find(|field| field.name == old_field.name) {
+        Some(field) => field,
+        None => {
+          changes.push(Change::FieldRemoved { kind, name: name.clone(), field: old_field.name.clone(), type_: old_field.type_.to_string() });
+          continue;
+        }
+      };
+      if old_field.type_ != new_field.type_ {
+        changes.push(Change::FieldTypeChanged {
+          kind, name: name.clone(), field: old_field.name.clone(), old: old_field.type_.to_string(), new: new_field.type_.to_string(),
+        });
+        continue;
+      }
+      let (old_rust, new_rust) = match (old_types.get(&key), new_types.get(&key)) {
+        (Some(old_rust), Some(new_rust)) if old_rust != new_rust => (old_rust.clone(), new_rust.clone()),
+        _ => continue,
+      };
+      let field = old_field.name.clone();
+      match (is_optional(&old_rust), is_optional(&new_rust)) {
+        (false, true) => changes.push(Change::FieldOptional { kind, name: name.clone(), field, old: old_rust, new: new_rust }),
+        (true, false) => changes.push(Change::FieldRequired { kind, name: name.clone(), field, old: old_rust, new: new_rust }),
+        _ => changes.push(Change::FieldTypeChanged { kind, name: name.clone(), field, old: old_rust, new: new_rust }),
+      }
+    }
+    // the fields of both in their old and new order, a field moved if it has other neighbours
+    let kept = |fields: &[Field], others: &[Field]| -> Vec<String> {
+      fields.iter().filter(|field| others.iter().any(|other| other.name == field.name)).map(|field| field.name.clone()).collect()
+    };
+    let old_order = kept(&old_definition.fields, &new_definition.fields);
+    let new_order = kept(&new_definition.fields, &old_definition.fields);
+    for (index, field) in old_order.iter().enumerate() {
+      if new_order[index] != *field {
+        let position = |fields: &[Field]| fields.iter().position(|other| other.name == *field).unwrap();
+        changes.push(Change::FieldMoved {
+          kind, name: name.clone(), field: field.clone(), old: position(&old_definition.fields), new: position(&new_definition.fields),
+        });
+      }
+    }
+    for new_field in new_definition.fields.iter().filter(|field| !old_definition.fields.iter().any(|old_field| old_field.name == field.name)) {
+      let required = required.contains(&(name.clone(), new_field.name.clone()));
+      changes.push(Change::FieldAdded { kind, name: name.clone(), field: new_field.name.clone(), type_: new_field.type_.to_string(), required });
+    }
+  }
+  changes
+}
+
+/// `Option<T>` or `Vec<Option<T>>`
+fn is_optional(rust_type: &str) -> bool {
+  rust_type.starts_with("Option<") || rust_type.starts_with("Vec<Option<")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const OLD: &str = r#"
+//@class UserStatus @description Describes the last time the user was online
+
+//@description The user status was never changed
+userStatusEmpty = UserStatus;
+
+//@description The user is offline @was_online Point in time when the user was last online
+userStatusOffline was_online:int32 = UserStatus;
+
+//@description A message @id Message identifier @sender_user_id Sender @has_timestamped_media True, if media timestamps are present
+message id:int53 sender_user_id:int53 has_timestamped_media:Bool = Message;
+
+//@description Represents a user @id User identifier @photo Profile photo @status Status
+user id:int32 photo:string status:UserStatus = User;
+
+---functions---
+
+//@description Returns a user @user_id User identifier
+getUser user_id:int53 = User;
+
+//@description Does nothing
+testNothing = Ok;
+
+//@description Returns the squared number @x Number @y Other number
+testSquareInt x:int32 y:int32 = TestInt;
+"#;
+
+  const NEW: &str = r#"
+//@class UserStatus @description Describes the last time the user was online
+
+//@description The user status was never changed
+userStatusEmpty = UserStatus;
+
+//@description The user is offline @was_online Point in time when the user was last online
+userStatusOffline was_online:int32 = UserStatus;
+
+//@description The user is online @expires Point in time when the status expires
+userStatusOnline expires:int32 = UserStatus;
+
+//@description A message @id Message identifier @sender_id Sender @has_timestamped_media True, if media timestamps are present
+message id:int53 sender_id:int53 has_timestamped_media:Bool = Message;
+
+//@description Represents a user @id User identifier @photo Profile photo; may be null @status Status @bio Bio; may be null
+user id:int53 photo:string status:UserStatus bio:string = User;
+
+---functions---
+
+//@description Returns a user @user_id User identifier
+getUser user_id:int53 = User;
+
+//@description Does nothing
+testDoNothing = Ok;
+
+//@description Returns the squared number @x Number @y Other number
+testSquareInt y:int32 x:int32 = TestInt;
+"#;
+
+  #[test]
+  fn test_diff() {
+    let changes = diff(&Schema::parse(OLD).unwrap(), &Schema::parse(NEW).unwrap()).unwrap();
+    let changes: Vec<String> = changes.iter().map(Change::to_json).collect();
+    assert_eq!(changes, vec![
+      r#"{"change":"added","kind":"constructor","name":"userStatusOnline","breaking":false}"#,
+      r#"{"change":"field_removed","kind":"constructor","name":"message","field":"sender_user_id","type":"int53","breaking":true}"#,
+      // has_timestamped_media is `Option<bool>` by a quirk in both
+      // sender_id is required, `Message::new` takes it now
+      r#"{"change":"field_added","kind":"constructor","name":"message","field":"sender_id","type":"int53","breaking":true}"#,
+      r#"{"change":"field_type_changed","kind":"constructor","name":"user","field":"id","old":"int32","new":"int53","breaking":true}"#,
+      r#"{"change":"field_optional","kind":"constructor","name":"user","field":"photo","old":"String","new":"Option<String>","breaking":true}"#,
+      r#"{"change":"field_added","kind":"constructor","name":"user","field":"bio","type":"string","breaking":false}"#,
+      r#"{"change":"renamed","kind":"function","old":"testNothing","new":"testDoNothing","breaking":true}"#,
+      // `TestSquareInt::new(x, y)` takes `(y, x)` now
+      r#"{"change":"field_moved","kind":"function","name":"testSquareInt","field":"x","old":0,"new":1,"breaking":true}"#,
+      r#"{"change":"field_moved","kind":"function","name":"testSquareInt","field":"y","old":1,"new":0,"breaking":true}"#,
+    ]);
+  }
+
+  #[test]
+  fn test_json() {
+    let moved = Change::FieldMoved { kind: Kind::Function, name: "testSquareInt".to_string(), field: "x".to_string(), old: 0, new: 1 };
+    let json: serde_json::Value = serde_json::from_str(&moved.to_json()).unwrap();
+    assert_eq!((json["old"].as_u64(), json["new"].as_u64()), (Some(0), Some(1)));
+    let removed = Change::Removed { kind: Kind::Constructor, name: "a \"b\"\\\n\u{1}".to_string() };
+    let json: serde_json::Value = serde_json::from_str(&removed.to_json()).unwrap();
+    assert_eq!(json["name"], "a \"b\"\\\n\u{1}");
+  }
+}
@@ -0,0 +1,237 @@
+//! Subsystems most bots never use, like passport or payments, generated behind rtdlib cargo
+//! features of their own so they don't cost build time. An object of a disabled subsystem decodes
+//! as the `Unknown` variant of its class or of `TdType`, keeping its json.
+//!
+//! `FEATURES` lists the classes, constructors and functions of each feature by name, the
+//! constructors of a class in a feature are in it too. A type that isn't listed is always
+//! generated, so a type new in a schema is in no feature until it is added here. A type that a
+//! type of another feature or of none needs as a field, like `sticker` in `webPage`, is always
+//! generated too. Functions need the features of the types they take and return as well.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::tl::{self, Definition, Schema};
+
+/// `(feature, names)`, the classes, constructors and functions in each feature. Every name must be
+/// in one of the schemas, checked by codegen/tests, and in one feature only.
+pub const FEATURES: &[(&str, &[&str])] = &[
+  ("calls", &[
+    "CallDiscardReason", "CallProblem", "CallServerType", "CallState", "GroupCallVideoQuality", "call",
+    "callId", "callProtocol", "callServer", "groupCall", "groupCallId", "groupCallParticipant",
+    "groupCallParticipantVideoInfo", "groupCallRecentSpeaker", "groupCallVideoSourceGroup", "messageCall",
+    "notificationGroupTypeCalls", "notificationTypeNewCall", "searchMessagesFilterCall",
+    "searchMessagesFilterMissedCall", "topChatCategoryCalls", "updateCall", "updateGroupCall",
+    "updateGroupCallParticipant", "updateNewCallSignalingData", "acceptCall", "createCall",
+    "deleteAllCallMessages", "discardCall", "discardGroupCall", "endGroupCallRecording",
+    "endGroupCallScreenSharing", "getGroupCall", "getGroupCallInviteLink", "getGroupCallStreamSegment",
+    "inviteGroupCallParticipants", "joinGroupCall", "leaveGroupCall", "loadGroupCallParticipants",
+    "revokeGroupCallInviteLink", "searchCallMessages", "sendCallDebugInformation", "sendCallRating",
+    "sendCallSignalingData", "setGroupCallParticipantIsSpeaking", "setGroupCallParticipantVolumeLevel",
+    "setGroupCallTitle", "startGroupCallRecording", "startGroupCallScreenSharing", "startScheduledGroupCall",
+    "toggleGroupCallEnabledStartNotification", "toggleGroupCallIsMyVideoEnabled",
+    "toggleGroupCallIsMyVideoPaused", "toggleGroupCallMuteNewParticipants",
+    "toggleGroupCallParticipantIsHandRaised", "toggleGroupCallParticipantIsMuted",
+    "toggleGroupCallScreenSharingIsPaused",
+  ]),
+  ("instant-view", &[
+    "PageBlock", "PageBlockHorizontalAlignment", "PageBlockVerticalAlignment", "RichText", "pageBlockCaption",
+    "pageBlockListItem", "pageBlockRelatedArticle", "pageBlockTableCell", "webPageInstantView",
+    "getWebPageInstantView",
+  ]),
+  ("passport", &[
+    "InputPassportElement", "InputPassportElementErrorSource", "PassportElement",
+    "PassportElementErrorSource", "PassportElementType", "datedFile", "encryptedCredentials",
+    "encryptedPassportElement", "identityDocument", "inputIdentityDocument", "inputPassportElementError",
+    "inputPersonalDocument", "internalLinkTypePassportDataRequest", "messagePassportDataReceived",
+    "messagePassportDataSent", "passportAuthorizationForm", "passportElementError", "passportElements",
+    "passportElementsWithErrors", "passportRequiredElement", "passportSuitableElement", "personalDetails",
+    "personalDocument", "checkEmailAddressVerificationCode", "checkPhoneNumberVerificationCode",
+    "deletePassportElement", "getAllPassportElements", "getPassportAuthorizationForm",
+    "getPassportAuthorizationFormAvailableElements", "getPassportElement",
+    "resendEmailAddressVerificationCode", "resendPhoneNumberVerificationCode",
+    "sendEmailAddressVerificationCode", "sendPassportAuthorizationForm", "sendPhoneNumberVerificationCode",
+    "setPassportElement", "setPassportElementErrors",
+  ]),
+  ("payments", &[
+    "InputCredentials", "bankCardActionOpenUrl", "bankCardInfo", "inputMessageInvoice", "invoice",
+    "labeledPricePart", "messageInvoice", "messagePaymentSuccessful", "messagePaymentSuccessfulBot",
+    "orderInfo", "paymentForm", "paymentFormTheme", "paymentReceipt", "paymentResult",
+    "paymentsProviderStripe", "pushMessageContentInvoice", "savedCredentials", "shippingOption",
+    "temporaryPasswordState", "updateNewShippingQuery", "validatedOrderInfo", "answerShippingQuery",
+    "createTemporaryPassword", "deleteSavedCredentials", "deleteSavedOrderInfo", "getBankCardInfo",
+    "getPaymentForm", "getPaymentReceipt", "getSavedOrderInfo", "getTemporaryPasswordState",
+    "sendPaymentForm", "validateOrderInfo",
+  ]),
+  ("statistics", &[
+    "ChatStatistics", "NetworkStatisticsEntry", "StatisticalGraph", "chatStatisticsAdministratorActionsInfo",
+    "chatStatisticsInviterInfo", "chatStatisticsMessageInteractionInfo", "chatStatisticsMessageSenderInfo",
+    "databaseStatistics", "messageStatistics", "networkStatistics", "statisticalValue",
+    "addNetworkStatistics", "getChatStatistics", "getDatabaseStatistics", "getMessageStatistics",
+    "getNetworkStatistics", "getStatisticalGraph", "resetNetworkStatistics",
+  ]),
+  ("stickers", &[
+    "CheckStickerSetNameResult", "DiceStickers", "InputSticker", "MaskPoint", "chatEventStickerSetChanged",
+    "inlineQueryResultSticker", "inputInlineQueryResultSticker", "inputMessageSticker",
+    "internalLinkTypeStickerSet", "maskPosition", "messageSticker", "pushMessageContentSticker", "sticker",
+    "stickerSet", "stickerSetInfo", "stickerSets", "stickers", "tMeUrlTypeStickerSet",
+    "updateFavoriteStickers", "updateInstalledStickerSets", "updateRecentStickers", "updateStickerSet",
+    "updateTrendingStickerSets", "addFavoriteSticker", "addRecentSticker", "addStickerToSet",
+    "changeStickerSet", "checkStickerSetName", "clearRecentStickers", "createNewStickerSet",
+    "getArchivedStickerSets", "getAttachedStickerSets", "getFavoriteStickers", "getInstalledStickerSets",
+    "getRecentStickers", "getStickerEmojis", "getStickerSet", "getStickers", "getSuggestedStickerSetName",
+    "getTrendingStickerSets", "removeFavoriteSticker", "removeRecentSticker", "removeStickerFromSet",
+    "reorderInstalledStickerSets", "searchInstalledStickerSets", "searchStickerSet", "searchStickerSets",
+    "searchStickers", "setStickerPositionInSet", "setStickerSetThumbnail", "setSupergroupStickerSet",
+    "uploadStickerFile", "viewTrendingStickerSets",
+  ]),
+];
+
+/// The feature a class, constructor or function is listed in
+pub fn feature_of_name(name: &str) -> Option<&'static str> {
+  FEATURES.iter()
+    .find(|(_, names)| names.contains(&name))
+    .map(|(feature, _)| *feature)
+}
+
+/// The features of every class, constructor and function of a schema, by name. Those always
+/// generated have none.
+pub fn features(schema: &Schema) -> BTreeMap<String, BTreeSet<&'static str>> {
+  let classes: BTreeSet<&str> = schema.types.iter()
+    .map(|definition| definition.result.as_str())
+    .filter(|class| schema.is_abstract(class))
+    .collect();
+  // a class in a feature is with all of its constructors, the constructors of other classes are on their own
+  let unit = |units: &BTreeMap<String, &'static str>, name: &str| -> String {
+    if classes.contains(name) {
+      return name.to_string();
+    }
+    match schema.find_type(&tl::lower_first(name)) {
+      Some(definition) if units.contains_key(&definition.result) => definition.result.clone(),
+      _ => tl::lower_first(name),
+    }
+  };
+  let mut units: BTreeMap<String, &'static str> = BTreeMap::new();
+  for class in &classes {
+    units.extend(feature_of_name(class).map(|feature| (class.to_string(), feature)));
+  }
+  for definition in &schema.types {
+    if !units.contains_key(&definition.result) {
+      units.extend(feature_of_name(&definition.name).map(|feature| (definition.name.clone(), feature)));
+    }
+  }
+  let unit_feature = |units: &BTreeMap<String, &'static str>, definition: &Definition| -> Option<&'static str> {
+    units.get(&definition.result).or_else(|| units.get(&definition.name)).copied()
+  };
+
+  // types needed as fields outside of their feature are generated whatever the features
+  loop {
+    let needed: BTreeSet<String> = schema.types.iter()
+      .flat_map(|definition| {
+        let feature = unit_feature(&units, definition);
+        definition.fields.iter()
+          .map(|field| unit(&units, field.type_.name()))
+          .filter(|field_unit| matches!(units.get(field_unit), Some(field_feature) if Some(*field_feature) != feature))
+          .collect::<Vec<String>>()
+      })
+      .collect();
+    if needed.is_empty() {
+      break;
+    }
+    needed.iter().for_each(|needed| { units.remove(needed); });
+  }
+
+  let mut features = BTreeMap::new();
+  for definition in &schema.types {
+    features.insert(definition.name.clone(), unit_feature(&units, definition).into_iter().collect());
+  }
+  for class in &classes {
+    features.insert(class.to_string(), units.get(*class).copied().into_iter().collect());
+  }
+  for function in &schema.functions {
+    let used = function.fields.iter().map(|field| unit(&units, field.type_.name())).chain(std::iter::once(unit(&units, &function.result)));
+    let function_features = feature_of_name(&function.name).into_iter()
+      .chain(used.filter_map(|used| units.get(&used).copied()))
+      .collect();
+    features.insert(function.name.clone(), function_features);
+  }
+  features
+}
+
+/// `#[cfg(...)]` enabling an item needing `features`, `None` if it needs none
+pub fn cfg(features: &BTreeSet<&str>) -> Option<String> {
+  let features: Vec<String> = features.iter().map(|feature| format!("feature = \"{}\"", feature)).collect();
+  match features.as_slice() {
+    [] => None,
+    [feature] => Some(format!("#[cfg({})]", feature)),
+    _ => Some(format!("#[cfg(all({}))]", features.join(", "))),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SCHEMA: &str = r#"
+//@class MessageContent @description Contains the content of a message
+
+//@description A text message @text Text @web_page A preview of the web page
+messageText text:string web_page:webPage = MessageContent;
+
+//@description A sticker message @sticker The sticker
+messageSticker sticker:sticker = MessageContent;
+
+//@description A web page preview @sticker Preview of the content as a sticker
+webPage sticker:sticker = WebPage;
+
+//@description A sticker @mask_position Position where the mask is placed
+sticker mask_position:maskPosition = Sticker;
+
+//@description Position of a mask @point Part of the face
+maskPosition point:int32 = MaskPosition;
+
+//@description A sticker set @stickers List of stickers
+stickerSet stickers:vector<sticker> = StickerSet;
+
+//@description A call @id Call identifier
+call id:int32 = Call;
+
+---functions---
+
+//@description Returns a sticker set @set_id Identifier of the sticker set
+getStickerSet set_id:int64 = StickerSet;
+
+//@description Answers a callback query @text Text of the answer
+answerCallbackQuery text:string = Ok;
+
+//@description Sends a sticker message @content The sticker
+sendSticker content:messageSticker = Call;
+"#;
+
+  #[test]
+  fn test_features() {
+    assert_eq!(feature_of_name("updateCall"), Some("calls"));
+    assert_eq!(feature_of_name("answerCallbackQuery"), None);
+    assert_eq!(feature_of_name("authenticationCodeTypeFlashCall"), None);
+    assert_eq!(feature_of_name("PageBlock"), Some("instant-view"));
+    // with its class
+    assert_eq!(feature_of_name("pageBlockTitle"), None);
+
+    let features = features(&Schema::parse(SCHEMA).unwrap());
+    let of = |name: &str| features[name].iter().copied().collect::<Vec<&str>>();
+    assert_eq!(of("MessageContent"), Vec::<&str>::new());
+    assert_eq!(of("messageText"), Vec::<&str>::new());
+    assert_eq!(of("messageSticker"), vec!["stickers"]);
+    // needed by `webPage`, so is what it needs
+    assert_eq!(of("sticker"), Vec::<&str>::new());
+    assert_eq!(of("maskPosition"), Vec::<&str>::new());
+    assert_eq!(of("stickerSet"), vec!["stickers"]);
+    assert_eq!(of("getStickerSet"), vec!["stickers"]);
+    assert_eq!(of("answerCallbackQuery"), Vec::<&str>::new());
+    assert_eq!(of("sendSticker"), vec!["calls", "stickers"]);
+
+    assert_eq!(cfg(&BTreeSet::new()), None);
+    assert_eq!(cfg(&vec!["stickers"].into_iter().collect()), Some("#[cfg(feature = \"stickers\")]".to_string()));
+    assert_eq!(cfg(&vec!["calls", "stickers"].into_iter().collect()), Some("#[cfg(all(feature = \"calls\", feature = \"stickers\"))]".to_string()));
+  }
+}
+
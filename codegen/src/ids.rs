@@ -0,0 +1,77 @@
+//! Fields holding identifiers of chats, users, messages and the like, typed as `ChatId`, `UserId`,
+//! ... instead of `i64`. These are aliases of `i64` unless rtdlib's `typed-ids` feature makes them
+//! newtypes.
+
+use crate::tl::{Field, TlType};
+
+/// `(field name suffix, rust type)`, a field is an id if its name is the suffix or ends with `_`
+/// and the suffix, like `chat_id` and `reply_in_chat_id`. Vectors of ids end with `ids`.
+/// Longer suffixes come first, `secret_chat_id` isn't a `ChatId`.
+pub const IDS: &[(&str, &str)] = &[
+  ("basic_group_id", "BasicGroupId"),
+  ("secret_chat_id", "SecretChatId"),
+  ("supergroup_id", "SupergroupId"),
+  ("chat_id", "ChatId"),
+  ("file_id", "FileId"),
+  ("message_id", "MessageId"),
+  ("user_id", "UserId"),
+];
+
+/// `(constructor, rust type)` of the objects whose `id` field is their own id
+pub const OWN_IDS: &[(&str, &str)] = &[
+  ("basicGroup", "BasicGroupId"),
+  ("chat", "ChatId"),
+  ("file", "FileId"),
+  ("inputFileId", "FileId"),
+  ("message", "MessageId"),
+  ("secretChat", "SecretChatId"),
+  ("supergroup", "SupergroupId"),
+  ("user", "UserId"),
+];
+
+/// `(constructor or function, field)` named like ids of another kind
+pub const NOT_IDS: &[(&str, &str)] = &[
+  ("viewSponsoredMessage", "sponsored_message_id"),
+];
+
+/// Rust type of the ids in a field, `None` if it doesn't hold ids
+pub fn id_type(owner: &str, field: &Field) -> Option<&'static str> {
+  let (element, name) = match &field.type_ {
+    TlType::Vector(element) => (&**element, field.name.strip_suffix('s')?),
+    element => (element, field.name.as_str()),
+  };
+  if !matches!(element, TlType::Named(number) if number == "int32" || number == "int53") {
+    return None;
+  }
+  if NOT_IDS.contains(&(owner, field.name.as_str())) {
+    return None;
+  }
+  if name == "id" {
+    return OWN_IDS.iter().find(|(constructor, _)| *constructor == owner).map(|(_, type_)| *type_);
+  }
+  IDS.iter()
+    .find(|(suffix, _)| name == *suffix || name.ends_with(&format!("_{}", suffix)))
+    .map(|(_, type_)| *type_)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn field(name: &str, type_: &str) -> Field {
+    Field { name: name.to_string(), type_: TlType::parse(type_).unwrap(), description: String::new() }
+  }
+
+  #[test]
+  fn test_id_type() {
+    assert_eq!(id_type("sendMessage", &field("chat_id", "int53")), Some("ChatId"));
+    assert_eq!(id_type("message", &field("reply_in_chat_id", "int53")), Some("ChatId"));
+    assert_eq!(id_type("chatTypeSecret", &field("secret_chat_id", "int32")), Some("SecretChatId"));
+    assert_eq!(id_type("forwardMessages", &field("message_ids", "vector<int53>")), Some("MessageId"));
+    assert_eq!(id_type("message", &field("id", "int53")), Some("MessageId"));
+    assert_eq!(id_type("chatFilterInfo", &field("id", "int32")), None);
+    assert_eq!(id_type("message", &field("message_thread_id", "int53")), None);
+    assert_eq!(id_type("inputFileRemote", &field("file_id", "string")), None);
+    assert_eq!(id_type("viewSponsoredMessage", &field("sponsored_message_id", "int53")), None);
+  }
+}
@@ -0,0 +1,93 @@
+//! Generator of `rtdlib::types` and the tables of `rtdlib::schema` from tdlib's `td_api.tl`.
+//!
+//! Each tdlib version the crate supports has its schema in `schema/<version>/td_api.tl`, e.g.
+//! `schema/td-1-7/td_api.tl`, the version being the name of the cargo feature selecting it. The
+//! types of a version are generated into `src/types/<module>` and its tables into `src/schema/<module>.rs`,
+//! `<module>` being the version with `_` for `-`, e.g. `td_1_7`.
+//!
+//! Types of subsystems most bots don't use are generated behind cargo features, see `features`.
+//!
+//! `diff` compares two schemas, telling what breaks when moving the types from one to the other.
+
+pub mod diff;
+pub mod features;
+pub mod ids;
+pub mod limits;
+pub mod quirks;
+pub mod rust;
+pub mod tl;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub use self::rust::{generate, Generated};
+pub use self::tl::Schema;
+
+/// Versions having a `td_api.tl` in the schema directory, sorted
+pub fn versions<P: AsRef<Path>>(schema_dir: P) -> io::Result<Vec<String>> {
+  let mut versions = vec![];
+  for entry in fs::read_dir(schema_dir)? {
+    let path = entry?.path();
+    if path.join("td_api.tl").is_file() {
+      versions.extend(path.file_name().and_then(|name| name.to_str()).map(str::to_string));
+    }
+  }
+  versions.sort();
+  Ok(versions)
+}
+
+/// Rust module of a version, `td_1_7` for `td-1-7`
+pub fn module(version: &str) -> String { version.replace('-', "_") }
+
+/// Write `generated` for `version` to the crate sources `src`, removing generated files of types the schema no longer has.
+/// Returns the files that changed, with `dry_run` they are only reported.
+pub fn write<P: AsRef<Path>>(generated: &Generated, src: P, version: &str, dry_run: bool) -> io::Result<Vec<PathBuf>> {
+  let module = module(version);
+  let dir = src.as_ref().join("types").join(&module);
+  let mut outputs = vec![(src.as_ref().join("schema").join(format!("{}.rs", module)), generated.schema.clone())];
+  for (name, content) in &generated.files {
+    outputs.push((dir.join(name), content.clone()));
+  }
+  if !dry_run {
+    fs::create_dir_all(&dir)?;
+  }
+
+  let mut changed = vec![];
+  for (path, content) in outputs {
+    if fs::read_to_string(&path).ok().as_ref() == Some(&content) {
+      continue;
+    }
+    if !dry_run {
+      fs::write(&path, content)?;
+    }
+    changed.push(path);
+  }
+  if dir.is_dir() {
+    for entry in fs::read_dir(&dir)? {
+      let path = entry?.path();
+      let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string();
+      if name.ends_with(".rs") && !generated.files.contains_key(&name) {
+        if !dry_run {
+          fs::remove_file(&path)?;
+        }
+        changed.push(path);
+      }
+    }
+  }
+  changed.sort();
+  Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_versions() {
+    let schema_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../schema");
+    let versions = versions(schema_dir).unwrap();
+    assert!(versions.contains(&"td-1-7".to_string()));
+    assert_eq!(module("td-1-7"), "td_1_7");
+  }
+}
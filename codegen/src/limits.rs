@@ -0,0 +1,87 @@
+//! Limits tdlib puts on values, checked by the generated `Validate` impls. Most are the
+//! `1-128 characters` of the field docs, the others are listed here.
+
+use crate::tl::{Field, TlType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+  /// Characters of a string or of the text of a `formattedText`
+  Length(usize, usize),
+  /// Elements of a vector
+  Count(usize, usize),
+  /// Characters of each string of a vector
+  EachLength(usize, usize),
+  /// 1-32 lowercase English letters, digits and underscores
+  BotCommand,
+}
+
+/// Defaults of the options docs give limits by, like `1-GetOption("message_text_length_max") characters`
+const OPTIONS: &[(&str, usize)] = &[
+  ("message_caption_length_max", 1024),
+  ("message_text_length_max", 4096),
+];
+
+/// `(constructor or function, field, limit)` of limits the docs don't state, or state stricter
+/// than tdlib is, they replace a limit of the same kind from the doc
+pub const LIMITS: &[(&str, &str, Limit)] = &[
+  ("answerCallbackQuery", "text", Limit::Length(0, 200)),
+  ("answerInlineQuery", "results", Limit::Count(0, 50)),
+  ("botCommand", "command", Limit::BotCommand),
+  ("botCommand", "description", Limit::Length(1, 256)),
+  ("forwardMessages", "message_ids", Limit::Count(1, 100)),
+  // 255 for users, bots may send 300
+  ("inputMessagePoll", "question", Limit::Length(1, 300)),
+  ("inputMessagePoll", "options", Limit::Count(2, 10)),
+  ("inviteGroupCallParticipants", "user_ids", Limit::Count(0, 10)),
+  ("sendMessageAlbum", "input_message_contents", Limit::Count(2, 10)),
+  ("setCommands", "commands", Limit::Count(0, 100)),
+];
+
+/// Limits of a field of a constructor or function
+pub fn limits(owner: &str, field: &Field) -> Vec<Limit> {
+  let listed: Vec<Limit> = LIMITS.iter()
+    .filter(|(limit_owner, limit_field, _)| *limit_owner == owner && *limit_field == field.name)
+    .map(|(_, _, limit)| *limit)
+    .collect();
+  let documented = length(&field.description).and_then(|(min, max)| match &field.type_ {
+    TlType::Named(name) if name == "string" || name == "formattedText" => Some(Limit::Length(min, max)),
+    TlType::Vector(element) if **element == TlType::Named("string".to_string()) => Some(Limit::EachLength(min, max)),
+    _ => None,
+  });
+  let replaced = |documented: &Limit| listed.iter().any(|limit| std::mem::discriminant(limit) == std::mem::discriminant(documented));
+  documented.into_iter().filter(|documented| !replaced(documented)).chain(listed.iter().copied()).collect()
+}
+
+/// `(1, 128)` of a doc saying `1-128 characters`
+fn length(description: &str) -> Option<(usize, usize)> {
+  let (index, _) = description.match_indices(" characters").next()?;
+  let range = description[..index].rsplit(' ').next()?;
+  let (min, max) = range.split_once('-')?;
+  let max = match max.strip_prefix("GetOption(\"").and_then(|option| option.strip_suffix("\")")) {
+    Some(option) => OPTIONS.iter().find(|(name, _)| *name == option)?.1,
+    None => max.parse().ok()?,
+  };
+  Some((min.parse().ok()?, max))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn field(type_: &str, description: &str) -> Field {
+    Field { name: "text".to_string(), type_: TlType::parse(type_).unwrap(), description: description.to_string() }
+  }
+
+  #[test]
+  fn test_limits() {
+    assert_eq!(limits("setChatTitle", &field("string", "New title of the chat; 1-128 characters")), vec![Limit::Length(1, 128)]);
+    assert_eq!(
+      limits("inputMessageText", &field("formattedText", "Formatted text to be sent; 1-GetOption(\"message_text_length_max\") characters. Only Bold")),
+      vec![Limit::Length(1, 4096)],
+    );
+    assert_eq!(limits("poll", &field("vector<string>", "List of answer options, 2-10 strings 1-100 characters each")), vec![Limit::EachLength(1, 100)]);
+    assert_eq!(limits("answerCallbackQuery", &field("string", "Text of the answer")), vec![Limit::Length(0, 200)]);
+    assert_eq!(limits("setChatTitle", &field("int32", "Volume; 1-20000 characters")), vec![]);
+    assert_eq!(limits("setChatTitle", &field("string", "Average number of characters in sent messages")), vec![]);
+  }
+}
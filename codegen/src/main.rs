@@ -0,0 +1,76 @@
+use std::path::Path;
+use std::{env, fs, process};
+
+const USAGE: &str = "usage: rtdlib-codegen [--check] [<version>...]
+       rtdlib-codegen diff <old> <new>
+
+Regenerate the rtdlib types and schema tables of the given versions, by default of every schema/<version>/td_api.tl,
+into src/types/<module> and src/schema/<module>.rs. Run from the rtdlib directory.
+With --check nothing is written, the files that would change are listed and the exit code is 1 if there are any.
+
+diff prints the changes from the old to the new schema as json lines, the exit code is 1 if any of them breaks code
+written against the old types. A schema is a version in schema/ or the path of a td_api.tl.";
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  if args.first().map(String::as_str) == Some("diff") {
+    match &args[1..] {
+      [old, new] => diff(old, new),
+      _ => fail(USAGE),
+    }
+  }
+
+  let mut check = false;
+  let mut versions = vec![];
+  for arg in args {
+    match arg.as_str() {
+      "--check" => check = true,
+      "-h" | "--help" => {
+        println!("{}", USAGE);
+        return;
+      }
+      _ if arg.starts_with('-') => fail(USAGE),
+      _ => versions.push(arg),
+    }
+  }
+  if versions.is_empty() {
+    versions = rtdlib_codegen::versions("schema").unwrap_or_else(|err| fail(&format!("schema: {}", err)));
+  }
+
+  let mut changed = vec![];
+  for version in &versions {
+    let schema_path = format!("schema/{}/td_api.tl", version);
+    let source = fs::read_to_string(&schema_path).unwrap_or_else(|err| fail(&format!("can't read {}: {}", schema_path, err)));
+    let schema = rtdlib_codegen::Schema::parse(&source).unwrap_or_else(|err| fail(&format!("{}: {}", schema_path, err)));
+    let generated = rtdlib_codegen::generate(&schema).unwrap_or_else(|err| fail(&format!("{}: {}", schema_path, err)));
+    changed.extend(rtdlib_codegen::write(&generated, "src", version, check).unwrap_or_else(|err| fail(&format!("{}: {}", version, err))));
+  }
+
+  for path in &changed {
+    println!("{}", path.display());
+  }
+  if check && !changed.is_empty() {
+    process::exit(1);
+  }
+}
+
+fn diff(old: &str, new: &str) -> ! {
+  let changes = rtdlib_codegen::diff::diff(&read_schema(old), &read_schema(new)).unwrap_or_else(|err| fail(&err));
+  for change in &changes {
+    println!("{}", change.to_json());
+  }
+  process::exit(if changes.iter().any(|change| change.is_breaking()) { 1 } else { 0 });
+}
+
+/// A version in `schema`, or a path
+fn read_schema(schema: &str) -> rtdlib_codegen::Schema {
+  let version_path = format!("schema/{}/td_api.tl", schema);
+  let path = if Path::new(&version_path).is_file() { version_path.as_str() } else { schema };
+  let source = fs::read_to_string(path).unwrap_or_else(|err| fail(&format!("can't read {}: {}", path, err)));
+  rtdlib_codegen::Schema::parse(&source).unwrap_or_else(|err| fail(&format!("{}: {}", path, err)))
+}
+
+fn fail(message: &str) -> ! {
+  eprintln!("{}", message);
+  process::exit(2);
+}
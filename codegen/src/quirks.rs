@@ -0,0 +1,52 @@
+//! Places where the generated types knowingly differ from the schema, kept so regenerating doesn't
+//! break code written against earlier releases of rtdlib.
+
+/// How a field differs from what the schema says
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quirk {
+  /// `Option`, although the schema doesn't say the field may be null
+  Optional,
+  /// `Option`, the getter returns a copy and the setter takes the `Option`
+  OptionalCopy,
+  /// `Option`, the setter takes `AsRef<Option<_>>`
+  OptionalRef,
+}
+
+/// `(constructor or function, field, quirk)`
+pub const QUIRKS: &[(&str, &str, Quirk)] = &[
+  ("chat", "positions", Quirk::Optional),
+  ("chatMember", "member_id", Quirk::OptionalRef),
+  ("chatMemberStatusAdministrator", "can_manage_chat", Quirk::OptionalCopy),
+  ("chatPhotoInfo", "has_animation", Quirk::Optional),
+  ("message", "has_timestamped_media", Quirk::OptionalCopy),
+  ("profilePhoto", "has_animation", Quirk::Optional),
+  ("updateChatLastMessage", "positions", Quirk::Optional),
+];
+
+/// A field the schema dropped but rtdlib still has
+pub struct RemovedField {
+  pub owner: &'static str,
+  /// The field is inserted after this one
+  pub after: &'static str,
+  pub name: &'static str,
+  pub type_: &'static str,
+  pub description: &'static str,
+  pub quirk: Quirk,
+}
+
+pub const REMOVED_FIELDS: &[RemovedField] = &[
+  RemovedField {
+    owner: "chatMember",
+    after: "member_id",
+    name: "user_id",
+    type_: "int53",
+    description: "User identifier of the chat member.",
+    quirk: Quirk::OptionalCopy,
+  },
+];
+
+pub fn quirk(owner: &str, field: &str) -> Option<Quirk> {
+  QUIRKS.iter()
+    .find(|(quirk_owner, quirk_field, _)| *quirk_owner == owner && *quirk_field == field)
+    .map(|(_, _, quirk)| *quirk)
+}
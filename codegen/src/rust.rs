@@ -0,0 +1,755 @@
+//! Rust sources of `src/types`, laid out the way the generated files always were
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::features;
+use crate::ids;
+use crate::limits::{self, Limit};
+use crate::quirks::{self, Quirk};
+use crate::tl::{self, Definition, Field, Schema, TlType};
+
+/// Sources generated from a schema
+#[derive(Debug, Clone, Default)]
+pub struct Generated {
+  /// File name to content, one file per class, `functions.rs` and `mod.rs`
+  pub files: BTreeMap<String, String>,
+  /// Tables of `rtdlib::schema`
+  pub schema: String,
+}
+
+pub fn generate(schema: &Schema) -> Result<Generated, String> {
+  Generator::new(schema).generate()
+}
+
+/// Rust type of every field of the constructors and functions, keyed by their names and the field
+/// name in the schema, as the generated structs have them
+pub fn field_types(schema: &Schema) -> Result<BTreeMap<(String, String), String>, String> {
+  let generator = Generator::new(schema);
+  let definitions = schema.types.iter().map(|definition| (definition, false))
+    .chain(schema.functions.iter().map(|definition| (definition, true)));
+  let mut types = BTreeMap::new();
+  for (definition, is_function) in definitions {
+    for field in &definition.fields {
+      let rust = generator.field(definition, is_function, field, quirks::quirk(&definition.name, &field.name))?;
+      types.insert((definition.name.clone(), field.name.clone()), rust.type_);
+    }
+  }
+  Ok(types)
+}
+
+/// `(definition, field)` of the fields `new(..)` takes, see `is_required`
+pub fn required_fields(schema: &Schema) -> Result<BTreeSet<(String, String)>, String> {
+  let generator = Generator::new(schema);
+  let mut required = BTreeSet::new();
+  let definitions = schema.types.iter().map(|definition| (definition, false))
+    .chain(schema.functions.iter().map(|definition| (definition, true)));
+  for (definition, is_function) in definitions {
+    for field in &definition.fields {
+      if is_required(&generator.field(definition, is_function, field, quirks::quirk(&definition.name, &field.name))?) {
+        required.insert((definition.name.clone(), field.name.clone()));
+      }
+    }
+  }
+  Ok(required)
+}
+
+/// How a field is passed to its builder setter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+  /// Numbers and `bool`, also returned by value from getters
+  Copy,
+  /// `String`, set from any `AsRef<str>`
+  Str,
+  /// `Vec`, set by value
+  Vec,
+  /// Objects and `Bytes`, set from any `AsRef`
+  Clone,
+}
+
+struct RustField {
+  name: String,
+  description: String,
+  /// Type of the struct field
+  type_: String,
+  /// The type in the `Option` for optional fields, else `type_`
+  inner: String,
+  kind: Kind,
+  optional: Option<Quirk>,
+  attribute: Option<String>,
+}
+
+struct Generator<'a> {
+  schema: &'a Schema,
+  /// Abstract classes, these are enums
+  classes: BTreeSet<&'a str>,
+  /// Constructors with limits on their fields, or on fields of their fields, and their classes
+  validated: BTreeSet<&'a str>,
+  /// Features of the classes, constructors and functions in one, see `features`
+  features: BTreeMap<String, BTreeSet<&'static str>>,
+}
+
+impl<'a> Generator<'a> {
+  fn new(schema: &'a Schema) -> Self {
+    let classes = schema.types.iter()
+      .map(|definition| definition.result.as_str())
+      .filter(|class| schema.is_abstract(class))
+      .collect();
+    // what requests can contain: the types of function parameters, their constructors and fields
+    let mut sent: BTreeSet<&str> = schema.functions.iter().flat_map(|function| function.fields.iter().map(|field| field.type_.name())).collect();
+    loop {
+      let more: BTreeSet<&str> = schema.types.iter()
+        .filter(|definition| sent.contains(definition.name.as_str()) || sent.contains(definition.result.as_str()))
+        .flat_map(|definition| std::iter::once(definition.name.as_str()).chain(definition.fields.iter().map(|field| field.type_.name())))
+        .chain(sent.iter().copied())
+        .collect();
+      if more.len() == sent.len() {
+        break;
+      }
+      sent = more;
+    }
+
+    let mut generator = Self { schema, classes, validated: BTreeSet::new(), features: features::features(schema) };
+    loop {
+      let validated: BTreeSet<&str> = schema.types.iter()
+        .filter(|definition| sent.contains(definition.name.as_str()) && !generator.checks(definition).is_empty())
+        .flat_map(|definition| vec![definition.name.as_str(), definition.result.as_str()])
+        .collect();
+      if validated.len() == generator.validated.len() {
+        return generator;
+      }
+      generator.validated = validated;
+    }
+  }
+
+  fn generate(&self) -> Result<Generated, String> {
+    let mut files = BTreeMap::new();
+    // modules of a feature, by file name
+    let mut gated = BTreeMap::new();
+    let mut concrete: Vec<&Definition> = self.schema.types.iter().filter(|definition| !self.classes.contains(definition.result.as_str())).collect();
+    concrete.sort_by_key(|definition| struct_name(definition));
+    for definition in concrete {
+      let content = format!("{}{}", header(false), self.definition(definition, false)?);
+      let file = format!("{}.rs", snake(&struct_name(definition)));
+      gated.extend(self.cfg(&definition.name).map(|cfg| (file.clone(), cfg)));
+      files.insert(file, finish(content));
+    }
+    for class in &self.classes {
+      let mut content = format!("{}{}", header(true), self.class(class));
+      for constructor in self.constructors(class) {
+        content.push_str(BLOCK_SEPARATOR);
+        content.push_str(&gate(self.variant_cfg(class, constructor), self.definition(constructor, false)?));
+      }
+      let file = format!("{}.rs", snake(class));
+      gated.extend(self.cfg(class).map(|cfg| (file.clone(), cfg)));
+      files.insert(file, finish(content));
+    }
+    let mut functions: Vec<&Definition> = self.schema.functions.iter().collect();
+    functions.sort_by_key(|definition| struct_name(definition));
+    let functions = functions.into_iter()
+      .map(|definition| Ok(gate(self.cfg(&definition.name), self.definition(definition, true)?)))
+      .collect::<Result<Vec<String>, String>>()?;
+    files.insert("functions.rs".to_string(), finish(format!("{}{}", header(false), functions.join(BLOCK_SEPARATOR))));
+
+    let modules = files.keys().map(|file| (file.trim_end_matches(".rs"), gated.get(file).map(|cfg| format!("{}\n", cfg)).unwrap_or_default())).collect::<Vec<(&str, String)>>();
+    // the variants hold tdlib's objects as they are, boxing the large ones would change every match on them
+    let mut module = "#![allow(clippy::large_enum_variant)]\n\nuse serde::de::{Deserialize, Deserializer};\n\nuse crate::errors::*;\nuse crate::types::*;\n\n".to_string();
+    modules.iter().for_each(|(name, cfg)| module.push_str(&format!("{}pub use self::{}::*;\n", cfg, name)));
+    module.push('\n');
+    modules.iter().for_each(|(name, cfg)| module.push_str(&format!("{}mod {};\n", cfg, name)));
+    module.push_str(&self.common());
+    files.insert("mod.rs".to_string(), module);
+    Ok(Generated { files, schema: self.schema() })
+  }
+
+  /// Constructors of an abstract class, sorted by name
+  fn constructors(&self, class: &str) -> Vec<&'a Definition> {
+    let mut constructors = self.schema.constructors(class);
+    constructors.sort_by_key(|definition| struct_name(definition));
+    constructors
+  }
+
+  /// `#[cfg(...)]` of a class, constructor or function in a feature
+  fn cfg(&self, name: &str) -> Option<String> {
+    self.features.get(name).and_then(features::cfg)
+  }
+
+  /// `#[cfg(...)]` of a variant of `class` in a feature the class isn't in
+  fn variant_cfg(&self, class: &str, member: &Definition) -> Option<String> {
+    let cfg = self.cfg(&member.name);
+    if cfg == self.cfg(class) { None } else { cfg }
+  }
+
+  fn class_description(&self, class: &str) -> String {
+    self.schema.classes.iter()
+      .find(|declared| declared.name == class)
+      .map(|declared| declared.description.clone())
+      .unwrap_or_default()
+  }
+
+  /// Rust type of a named schema type, `chatPosition` and `ChatPosition` are both `ChatPosition`
+  fn named_type(&self, name: &str) -> Result<String, String> {
+    let type_ = match name {
+      "int32" | "int53" => "i64",
+      "int64" => "i64",
+      "double" => "f32",
+      "string" => "String",
+      "Bool" => "bool",
+      "bytes" => "Bytes",
+      _ if self.classes.contains(name) => name,
+      _ if self.schema.find_type(&tl::lower_first(name)).is_some() => return Ok(tl::upper_first(name)),
+      _ => return Err(format!("unknown type `{}`", name)),
+    };
+    Ok(type_.to_string())
+  }
+
+  fn type_(&self, type_: &TlType) -> Result<String, String> {
+    match type_ {
+      TlType::Named(name) => self.named_type(name),
+      TlType::Vector(inner) => Ok(format!("Vec<{}>", self.type_(inner)?)),
+    }
+  }
+
+  fn field(&self, owner: &Definition, is_function: bool, field: &Field, quirk: Option<Quirk>) -> Result<RustField, String> {
+    let may_be_null = field.description.to_lowercase().contains("may be null");
+    let (inner, kind) = match (&field.type_, ids::id_type(&owner.name, field)) {
+      // aliases of `i64` unless the `typed-ids` feature is on
+      (TlType::Vector(_), Some(id)) => (format!("Vec<{}>", id), Kind::Vec),
+      (_, Some(id)) => (id.to_string(), Kind::Copy),
+      // tdlib sends empty vectors instead of null, it is their elements that may be null
+      (TlType::Vector(element), None) if may_be_null => (format!("Vec<Option<{}>>", self.type_(element)?), Kind::Vec),
+      (TlType::Vector(_), None) => (self.type_(&field.type_)?, Kind::Vec),
+      // a constructor containing its own class
+      (TlType::Named(name), None) if !is_function && (*name == owner.result || *name == tl::lower_first(&owner.result)) => {
+        (format!("Box<{}>", self.named_type(name)?), Kind::Clone)
+      }
+      (TlType::Named(name), None) => {
+        let type_ = self.named_type(name)?;
+        let kind = match type_.as_str() {
+          "i64" | "f32" | "bool" => Kind::Copy,
+          "String" => Kind::Str,
+          _ => Kind::Clone,
+        };
+        (type_, kind)
+      }
+    };
+    let optional = quirk.or(if may_be_null && kind != Kind::Vec { Some(Quirk::Optional) } else { None });
+    let type_ = if optional.is_some() { format!("Option<{}>", inner) } else { inner.clone() };
+    let name = ident(&field.name);
+    let mut attributes = vec![];
+    if name != field.name {
+      attributes.push(format!("rename(serialize = \"{0}\", deserialize = \"{0}\")", field.name));
+    }
+    // `i64` like `int32` and `int53`, but sent as a json string
+    let is_int64 = |type_: &TlType| *type_ == TlType::Named("int64".to_string());
+    match (&field.type_, type_.as_str()) {
+      (tl, "i64") if is_int64(tl) => attributes.push("with = \"crate::types::_common::int64\"".to_string()),
+      (TlType::Vector(element), "Vec<i64>") if is_int64(element) => attributes.push("with = \"crate::types::_common::vec_int64\"".to_string()),
+      (tl, _) if is_int64(tl) || matches!(tl, TlType::Vector(element) if is_int64(element)) => {
+        return Err(format!("int64 field {}.{} is neither plain nor a vector", owner.name, field.name))
+      }
+      _ => {}
+    }
+    Ok(RustField {
+      name,
+      description: field.description.clone(),
+      type_,
+      inner,
+      kind,
+      optional,
+      attribute: if attributes.is_empty() { None } else { Some(format!("#[serde({})] ", attributes.join(", "))) },
+    })
+  }
+
+  fn fields(&self, definition: &Definition, is_function: bool) -> Result<Vec<RustField>, String> {
+    let mut fields = vec![];
+    for field in &definition.fields {
+      fields.push(self.field(definition, is_function, field, quirks::quirk(&definition.name, &field.name))?);
+      for removed in quirks::REMOVED_FIELDS.iter().filter(|removed| removed.owner == definition.name && removed.after == field.name) {
+        let type_ = TlType::parse(removed.type_).ok_or_else(|| format!("invalid type of removed field {}", removed.name))?;
+        let field = Field { name: removed.name.to_string(), type_, description: removed.description.to_string() };
+        fields.push(self.field(definition, is_function, &field, Some(removed.quirk))?);
+      }
+    }
+    Ok(fields)
+  }
+
+  /// The struct of a constructor or function, its builder and trait impls
+  fn definition(&self, definition: &Definition, is_function: bool) -> Result<String, String> {
+    let name = struct_name(definition);
+    let td_name = &definition.name;
+    let fields = self.fields(definition, is_function)?;
+    let marker = if self.classes.contains(definition.result.as_str()) {
+      Some(format!("impl TD{} for {} {{}}\n", definition.result, name))
+    } else {
+      None
+    };
+    let function = if is_function {
+      Some(format!(
+        "impl RFunction for {} {{\n  type Response = {};\n  #[doc(hidden)] fn to_json_with_extra(mut self, extra: &Extra) -> RTDResult<String> {{ self.set_extra(extra.clone()).to_json() }}\n}}\n",
+        name, self.named_type(&definition.result)?
+      ))
+    } else {
+      None
+    };
+
+    let mut out = String::new();
+    out.push_str(&format!("/// {}\n", definition.description));
+    out.push_str("#[derive(Debug, Clone, Default, Serialize, Deserialize)]\n");
+    out.push_str(&format!("pub struct {} {{\n", name));
+    out.push_str("  #[doc(hidden)]\n  #[serde(rename(serialize = \"@type\", deserialize = \"@type\"))]\n  td_name: String,\n");
+    out.push_str("  #[doc(hidden)]\n  #[serde(rename(serialize = \"@extra\", deserialize = \"@extra\"))]\n  extra: Option<Extra>,\n");
+    out.push_str("  #[doc(hidden)]\n  #[serde(rename(serialize = \"@client_id\", deserialize = \"@client_id\"), skip_serializing_if = \"Option::is_none\")]\n  client_id: Option<i32>,\n");
+    for field in &fields {
+      if !field.description.is_empty() {
+        out.push_str(&format!("  /// {}\n", field.description));
+      }
+      out.push_str(&format!("  {}{}: {},\n", field.attribute.as_deref().unwrap_or(""), field.name, field.type_));
+    }
+    out.push_str("  \n}\n\n");
+
+    out.push_str(&format!("impl RObject for {} {{\n", name));
+    out.push_str(&format!("  #[doc(hidden)] fn td_name(&self) -> &str {{ \"{}\" }}\n", td_name));
+    out.push_str("  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }\n");
+    out.push_str("  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }\n");
+    out.push_str("  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }\n}\n");
+    match (&marker, &function) {
+      (None, None) => out.push_str("\n\n\n"),
+      (Some(marker), None) => out.push_str(&format!("\n\n{}\n\n\n", marker)),
+      (None, Some(function)) => out.push_str(&format!("\n\n\n\n{}\n", function)),
+      (Some(marker), Some(function)) => out.push_str(&format!("\n\n{}\n{}\n", marker, function)),
+    }
+
+    out.push_str(&format!("impl {} {{\n", name));
+    out.push_str("  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }\n");
+    out.push_str(&format!("  pub fn builder() -> RTD{}Builder {{\n", name));
+    out.push_str(&format!("    let inner = {} {{ td_name: \"{}\".to_string(), ..Default::default() }};\n", name, td_name));
+    out.push_str(&format!("    RTD{}Builder {{ inner }}\n  }}\n", name));
+    out.push_str(&new(&name, &fields));
+    if is_function {
+      out.push_str("\n  /// The `@extra` tdlib echoes back in the reply, the clients give a function without one a new one\n");
+      out.push_str("  pub fn set_extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {\n    self.extra = Some(extra.into());\n    self\n  }\n");
+    }
+    for field in &fields {
+      out.push_str(&format!("\n{}\n", getter(field)));
+    }
+    out.push_str("\n}\n\n");
+
+    out.push_str(&format!("#[doc(hidden)]\npub struct RTD{0}Builder {{\n  inner: {0}\n}}\n\n", name));
+    out.push_str(&format!("impl RTD{0}Builder {{\n  pub fn build(&self) -> {0} {{ self.inner.clone() }}\n", name));
+    out.push_str("\n  /// Instead of the one the client sending it gets from its `ExtraGenerator`\n");
+    out.push_str("  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {\n    self.inner.extra = Some(extra.into());\n    self\n  }\n");
+    for field in &fields {
+      out.push_str(&format!("\n   \n{}", setter(field)));
+    }
+    out.push_str("\n}\n\n");
+
+    out.push_str(&format!("impl AsRef<{0}> for {0} {{\n  fn as_ref(&self) -> &{0} {{ self }}\n}}\n\n", name));
+    out.push_str(&format!("impl AsRef<{0}> for RTD{0}Builder {{\n  fn as_ref(&self) -> &{0} {{ &self.inner }}\n}}\n", name));
+    out.push_str(&partial_eq(&name, &fields));
+    if is_function || self.validated.contains(td_name.as_str()) {
+      out.push_str(&validate(&name, self.checks(definition)));
+    }
+    Ok(out)
+  }
+
+  /// The trait and enum of an abstract class
+  fn class(&self, class: &str) -> String {
+    let description = self.class_description(class);
+    // functions returning the class are variants too, like `Update::TestUseUpdate`
+    let mut members = self.constructors(class);
+    members.extend(self.schema.functions.iter().filter(|function| function.result == class));
+    members.sort_by_key(|definition| struct_name(definition));
+    let variants: Vec<(String, &Definition)> = members.into_iter()
+      .map(|constructor| (variant_name(class, &struct_name(constructor)), constructor))
+      .collect();
+    // `#[cfg(...)] ` of the variants in a feature
+    let cfgs: BTreeMap<&str, String> = variants.iter()
+      .filter_map(|(variant, constructor)| self.variant_cfg(class, constructor).map(|cfg| (variant.as_str(), format!("{} ", cfg))))
+      .collect();
+    let cfg = |variant: &str| cfgs.get(variant).map(String::as_str).unwrap_or("");
+    // `UserType::Unknown` is tdlib's `userTypeUnknown`
+    let unknown = if variants.iter().any(|(variant, _)| variant == "Unknown") { "UnknownType" } else { "Unknown" };
+
+    let mut out = String::new();
+    out.push_str(&format!("/// TRAIT | {}\npub trait TD{}: Debug + RObject {{}}\n\n", description, class));
+    out.push_str(&format!("/// {}\n#[derive(Debug, Clone, PartialEq, Serialize)]\n#[serde(untagged)]\npub enum {} {{\n", description, class));
+    out.push_str("  #[doc(hidden)] _Default(()),\n");
+    for (variant, constructor) in &variants {
+      let cfg = cfgs.get(variant.as_str()).map(|cfg| format!("  {}\n", cfg.trim_end())).unwrap_or_default();
+      out.push_str(&format!("  /// {}\n{}  {}({}),\n", constructor.description, cfg, variant, struct_name(constructor)));
+    }
+    out.push_str(&format!("  /// {}\n  {}(UnknownObject),\n\n}}\n\n", UNKNOWN_DOC, unknown));
+
+    out.push_str(&format!("impl Default for {0} {{\n  fn default() -> Self {{ {0}::_Default(()) }}\n}}\n\n", class));
+
+    out.push_str(&format!("impl<'de> Deserialize<'de> for {0} {{\n  fn deserialize<D>(deserializer: D) -> Result<{0}, D::Error> where D: Deserializer<'de> {{\n", class));
+    out.push_str(&format!("    use serde::de::Error;\n    rtd_enum_deserialize!(\n      {}, {};\n", class, unknown));
+    for (variant, constructor) in &variants {
+      out.push_str(&format!("      {}({}, {});\n", cfg(variant), constructor.name, variant));
+    }
+    out.push_str("\n    )(deserializer)\n  }\n}\n\n");
+
+    let all: Vec<(String, String)> = variants.iter()
+      .map(|(variant, constructor)| (variant.clone(), struct_name(constructor)))
+      .chain(std::iter::once((unknown.to_string(), "UnknownObject".to_string())))
+      .collect();
+    out.push_str(&format!("impl RObject for {} {{\n  #[doc(hidden)] fn td_name(&self) -> &str {{\n    match self {{\n", class));
+    for (variant, _) in &all {
+      out.push_str(&format!("      {}{}::{}(t) => t.td_name(),\n", cfg(variant), class, variant));
+    }
+    out.push_str("\n      _ => \"-1\",\n    }\n  }\n  #[doc(hidden)] fn extra(&self) -> Option<Extra> {\n    match self {\n");
+    for (variant, _) in &all {
+      out.push_str(&format!("      {}{}::{}(t) => t.extra(),\n", cfg(variant), class, variant));
+    }
+    out.push_str("\n      _ => None,\n    }\n  }\n  #[doc(hidden)] fn client_id(&self) -> Option<i32> {\n    match self {\n");
+    for (variant, _) in &all {
+      out.push_str(&format!("      {}{}::{}(t) => t.client_id(),\n", cfg(variant), class, variant));
+    }
+    out.push_str("\n      _ => None,\n    }\n  }\n  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }\n}\n\n");
+
+    out.push_str(&format!("impl {} {{\n", class));
+    out.push_str("  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }\n");
+    out.push_str(&format!("  #[doc(hidden)] pub fn _is_default(&self) -> bool {{ matches!(self, {}::_Default(_)) }}\n\n", class));
+    for (variant, _) in &all {
+      out.push_str(&format!("  {}pub fn is_{}(&self) -> bool {{ matches!(self, {}::{}(_)) }}\n", cfg(variant), snake(variant), class, variant));
+    }
+    out.push('\n');
+    for (variant, type_) in &all {
+      out.push_str(&format!("  {}pub fn on_{}<F: FnOnce(&{})>(&self, fnc: F) -> &Self {{ if let {}::{}(t) = self {{ fnc(t) }}; self }}\n", cfg(variant), snake(variant), type_, class, variant));
+    }
+    out.push('\n');
+    for (variant, type_) in &all {
+      out.push_str(&format!("  {}pub fn as_{}(&self) -> Option<&{}> {{ if let {}::{}(t) = self {{ return Some(t) }} None }}\n", cfg(variant), snake(variant), type_, class, variant));
+    }
+    out.push_str("\n\n");
+    for (variant, constructor) in &variants {
+      out.push_str(&format!(
+        "\n  {}pub fn {}<T: AsRef<{}>>(t: T) -> Self {{ {}::{}(t.as_ref().clone()) }}\n",
+        cfg(variant), ident(&snake(variant)), struct_name(constructor), class, variant,
+      ));
+    }
+    out.push_str("\n}\n\n");
+    out.push_str(&format!("impl AsRef<{0}> for {0} {{\n  fn as_ref(&self) -> &{0} {{ self }}\n}}\n", class));
+    if self.validated.contains(class) {
+      let checked: Vec<&String> = variants.iter()
+        .filter(|(_, constructor)| self.validated.contains(constructor.name.as_str()))
+        .map(|(variant, _)| variant)
+        .collect();
+      let checks = match checked.as_slice() {
+        [variant] if cfg(variant).is_empty() => vec![
+          format!("if let {}::{}(value) = self {{", class, variant),
+          "  value.check(checker);".to_string(),
+          "}".to_string(),
+        ],
+        _ => {
+          let mut checks = vec!["match self {".to_string()];
+          checks.extend(checked.iter().map(|variant| format!("  {}{}::{}(value) => value.check(checker),", cfg(variant), class, variant)));
+          checks.push("  _ => {}".to_string());
+          checks.push("}".to_string());
+          checks
+        }
+      };
+      out.push_str(&validate(class, checks));
+    }
+    out
+  }
+
+  /// Statements of `Validate::check` checking the fields of a constructor or function
+  fn checks(&self, definition: &Definition) -> Vec<String> {
+    let mut checks = vec![];
+    for field in &definition.fields {
+      let name = ident(&field.name);
+      // as in `field`, vectors that may be null have optional elements instead
+      let may_be_null = field.description.to_lowercase().contains("may be null") && !matches!(field.type_, TlType::Vector(_));
+      let is_optional = quirks::quirk(&definition.name, &field.name).is_some() || may_be_null;
+      let value = if is_optional { "value".to_string() } else { format!("&self.{}", name) };
+      let text = if field.type_.name() == "formattedText" { format!("{}.text()", value.trim_start_matches('&')) } else { value.clone() };
+      let mut field_checks = vec![];
+      for limit in limits::limits(&definition.name, field) {
+        field_checks.push(match limit {
+          Limit::Length(min, max) => format!("checker.length(\"{}\", {}, {}, {});", field.name, text, min, max),
+          Limit::Count(min, max) => format!("checker.count(\"{}\", {}.len(), {}, {});", field.name, value.trim_start_matches('&'), min, max),
+          Limit::EachLength(min, max) => format!("checker.each_length(\"{}\", {}, {}, {});", field.name, value, min, max),
+          Limit::BotCommand => format!("checker.bot_command(\"{}\", {});", field.name, value),
+        });
+      }
+      if self.validated.contains(field.type_.name()) {
+        field_checks.push(format!("checker.nested(\"{}\", &self.{});", field.name, name));
+      }
+      match (is_optional, field_checks.is_empty()) {
+        (_, true) => {}
+        (false, false) => checks.extend(field_checks),
+        // nested checks take the option, the others its value
+        (true, false) => {
+          let (nested, value_checks): (Vec<String>, Vec<String>) = field_checks.into_iter().partition(|check| check.starts_with("checker.nested"));
+          if !value_checks.is_empty() {
+            checks.push(format!("if let Some(value) = &self.{} {{", name));
+            checks.extend(value_checks.into_iter().map(|check| format!("  {}", check)));
+            checks.push("}".to_string());
+          }
+          checks.extend(nested);
+        }
+      }
+    }
+    checks
+  }
+
+  /// Trait impls for references and `TdType`, the enum of updates and function results
+  fn common(&self) -> String {
+    let mut out = String::new();
+    for class in &self.classes {
+      let generic = class.to_uppercase();
+      let cfg = self.cfg(class).map(|cfg| format!("{}\n", cfg)).unwrap_or_default();
+      out.push_str(&format!("\n{2}impl<{0}: TD{1}> TD{1} for &{0} {{}}\n", generic, class, cfg));
+      out.push_str(&format!("{2}impl<{0}: TD{1}> TD{1} for &mut {0} {{}}\n", generic, class, cfg));
+    }
+
+    // updates, then what functions return, abstract classes first
+    let mut updates: Vec<&Definition> = self.schema.types.iter().chain(self.schema.functions.iter())
+      .filter(|definition| definition.result == "Update")
+      .collect();
+    updates.sort_by_key(|definition| struct_name(definition));
+    let updates: Vec<(String, String, String)> = updates.into_iter()
+      .map(|definition| (definition.name.clone(), struct_name(definition), self.prefix(&definition.name)))
+      .collect();
+    let results: BTreeSet<&str> = self.schema.functions.iter().map(|function| function.result.as_str()).collect();
+    let (abstract_results, concrete_results): (Vec<&str>, Vec<&str>) = results.into_iter().partition(|result| self.classes.contains(result));
+    let results: Vec<(String, String, String)> = abstract_results.into_iter()
+      .map(|class| (class.to_string(), class.to_string(), self.prefix(class)))
+      .chain(concrete_results.into_iter().map(|class| (tl::lower_first(class), tl::upper_first(class), self.prefix(&tl::lower_first(class)))))
+      .collect();
+
+    out.push_str("\n\n#[derive(Debug, Clone, PartialEq)]\npub enum TdType {\n");
+    out.push_str(&format!("  /// {}\n  Unknown(UnknownObject),\n", UNKNOWN_DOC));
+    updates.iter().for_each(|(_, variant, cfg)| out.push_str(&format!("  {1}{0}({0}),\n", variant, cfg)));
+    out.push('\n');
+    results.iter().for_each(|(_, variant, cfg)| out.push_str(&format!("  {1}{0}({0}),\n", variant, cfg)));
+    out.push_str("\n}\n");
+
+    out.push_str("impl<'de> Deserialize<'de> for TdType {\nfn deserialize<D>(deserializer: D) -> Result<TdType, D::Error> where D: Deserializer<'de> {\n");
+    out.push_str("    use serde::de::Error;\n    rtd_enum_deserialize!(\n      TdType, Unknown, no_default;\n");
+    updates.iter().for_each(|(td_name, variant, cfg)| out.push_str(&format!("  {}({}, {});\n", cfg, td_name, variant)));
+    out.push('\n');
+    results.iter().for_each(|(td_name, variant, cfg)| out.push_str(&format!("  {}({}, {});\n", cfg, td_name, variant)));
+    out.push_str("\n )(deserializer)\n\n }\n}\n\n");
+
+    out.push_str("impl RObject for TdType {\n");
+    let methods = [
+      ("  #[doc(hidden)]\n  fn td_name(&self) -> &str {", "td_name", "      "),
+      ("  #[doc(hidden)]\n  fn extra(&self) -> Option<Extra> {", "extra", "        "),
+      ("  /// The `@client_id` of the client that received it, see `RObject::client_id`\n  fn client_id(&self) -> Option<i32> {", "client_id", "        "),
+      ("  /// Return td type to json string\n  fn to_json(&self) -> RTDResult<String> {", "to_json", "        "),
+    ];
+    for (signature, method, indent) in methods.iter() {
+      out.push_str(&format!("{}\n    match self {{\n", signature));
+      out.push_str(&format!("{}Self::Unknown(value) => value.{}(),\n", indent, method));
+      updates.iter().for_each(|(_, variant, cfg)| out.push_str(&format!("{}{}Self::{}(value) => value.{}(),\n", indent, cfg, variant, method)));
+      out.push_str(&format!("{}\n", &indent[2..]));
+      results.iter().for_each(|(_, variant, cfg)| out.push_str(&format!("{}{}Self::{}(value) => value.{}(),\n", indent, cfg, variant, method)));
+      out.push_str(&format!("{}\n    }}\n  }}\n", &indent[2..]));
+    }
+    out.push_str("}\n");
+    out
+  }
+
+  /// `#[cfg(...)] ` of a class, constructor or function in a feature, to prefix a line with
+  fn prefix(&self, name: &str) -> String {
+    self.cfg(name).map(|cfg| format!("{} ", cfg)).unwrap_or_default()
+  }
+
+  /// Classes, constructors and functions of the schema as static tables sorted by name, to be
+  /// looked up by `td_name`
+  fn schema(&self) -> String {
+    let mut out = "use super::{Class, Constructor, Function, Parameter};\n".to_string();
+
+    out.push_str("\npub(super) static CLASSES: &[Class] = &[\n");
+    for class in &self.classes {
+      let constructors = self.constructors(class).iter().map(|definition| format!("{:?}", definition.name)).collect::<Vec<String>>();
+      out.push_str(&format!(
+        "  Class {{ name: {:?}, description: {:?}, constructors: &[{}] }},\n",
+        class, self.class_description(class), constructors.join(", ")
+      ));
+    }
+    out.push_str("];\n");
+
+    let mut types: Vec<&Definition> = self.schema.types.iter().collect();
+    types.sort_by(|a, b| a.name.cmp(&b.name));
+    out.push_str("\npub(super) static CONSTRUCTORS: &[Constructor] = &[\n");
+    for definition in types {
+      out.push_str(&format!("  Constructor {{ name: {:?}, description: {:?}, class: {:?}, fields: &[{}] }},\n",
+        definition.name, definition.description, definition.result, parameters(&definition.fields)));
+    }
+    out.push_str("];\n");
+
+    let mut functions: Vec<&Definition> = self.schema.functions.iter().collect();
+    functions.sort_by(|a, b| a.name.cmp(&b.name));
+    out.push_str("\npub(super) static FUNCTIONS: &[Function] = &[\n");
+    for definition in functions {
+      out.push_str(&format!("  Function {{ name: {:?}, description: {:?}, result: {:?}, parameters: &[{}] }},\n",
+        definition.name, definition.description, definition.result, parameters(&definition.fields)));
+    }
+    out.push_str("];\n");
+    out
+  }
+}
+
+/// `Parameter` literals of the fields, one per line
+fn parameters(fields: &[Field]) -> String {
+  if fields.is_empty() {
+    return String::new();
+  }
+  let parameters = fields.iter()
+    .map(|field| format!("    Parameter {{ name: {:?}, type_: {:?}, description: {:?} }},\n", field.name, field.type_.to_string(), field.description))
+    .collect::<String>();
+  format!("\n{}  ", parameters)
+}
+
+const UNKNOWN_DOC: &str = "An object of a type this crate doesn't know, kept as received";
+
+const BLOCK_SEPARATOR: &str = "\n\n\n\n\n\n\n";
+
+fn header(is_abstract: bool) -> String {
+  let mut header = "\nuse crate::types::*;\nuse crate::errors::*;\n\n\n\n\n".to_string();
+  if is_abstract {
+    header.push_str("use std::fmt::Debug;\nuse serde::de::{Deserialize, Deserializer};\n\n\n\n");
+  }
+  header
+}
+
+fn finish(content: String) -> String { content + "\n\n\n" }
+
+/// The items of `block` behind `cfg`, see `rtd_cfg!`
+fn gate(cfg: Option<String>, block: String) -> String {
+  match cfg {
+    Some(cfg) => format!("rtd_cfg! {{ {}\n\n{}\n}}\n", cfg, block),
+    None => block,
+  }
+}
+
+fn getter(field: &RustField) -> String {
+  let name = &field.name;
+  match (field.optional, field.kind) {
+    (Some(Quirk::OptionalCopy), _) | (None, Kind::Copy) => format!("  pub fn {0}(&self) -> {1} {{ self.{0} }}", name, field.type_),
+    // `&Box<_>` as the getters always returned
+    _ if field.type_.starts_with("Box<") => format!("  #[allow(clippy::borrowed_box)]\n  pub fn {0}(&self) -> &{1} {{ &self.{0} }}", name, field.type_),
+    _ => format!("  pub fn {0}(&self) -> &{1} {{ &self.{0} }}", name, field.type_),
+  }
+}
+
+/// `impl PartialEq` comparing the fields, not the `@type` and `@extra` bookkeeping
+fn partial_eq(name: &str, fields: &[RustField]) -> String {
+  if fields.is_empty() {
+    return format!("\nimpl PartialEq for {} {{\n  fn eq(&self, _other: &Self) -> bool {{ true }}\n}}\n", name);
+  }
+  let comparisons = fields.iter().map(|field| format!("self.{0} == other.{0}", field.name)).collect::<Vec<String>>();
+  format!("\nimpl PartialEq for {} {{\n  fn eq(&self, other: &Self) -> bool {{\n    {}\n  }}\n}}\n", name, comparisons.join("\n      && "))
+}
+
+/// `impl Validate`, the default one if there's nothing to check
+fn validate(name: &str, checks: Vec<String>) -> String {
+  if checks.is_empty() {
+    return format!("\nimpl Validate for {} {{}}\n", name);
+  }
+  let checks = checks.iter().map(|check| format!("    {}\n", check)).collect::<String>();
+  format!("\nimpl Validate for {} {{\n  fn check(&self, checker: &mut Checker) {{\n{}  }}\n}}\n", name, checks)
+}
+
+/// Whether the schema requires a value, fields tdlib takes null for are left to the builder
+fn is_required(field: &RustField) -> bool {
+  let description = field.description.to_lowercase();
+  field.optional.is_none() && !description.contains("may be null") && !description.contains("pass null")
+}
+
+/// `new` taking the required fields like their setters do
+fn new(name: &str, fields: &[RustField]) -> String {
+  let required: Vec<&RustField> = fields.iter().filter(|field| is_required(field)).collect();
+  let arguments = required.iter()
+    .map(|field| {
+      let type_ = match field.kind {
+        Kind::Copy | Kind::Vec => field.type_.clone(),
+        Kind::Str => "impl AsRef<str>".to_string(),
+        Kind::Clone => format!("impl AsRef<{}>", field.type_),
+      };
+      format!("{}: {}", field.name, type_)
+    })
+    .collect::<Vec<String>>();
+  let allow = if required.len() > 7 { "  #[allow(clippy::too_many_arguments)]\n" } else { "" };
+  let mut out = format!("\n  /// `{0}` with the fields tdlib requires, the others are set by `{0}::builder()`\n{1}", name, allow);
+  if required.len() <= 3 {
+    let setters = required.iter().map(|field| format!(".{0}({0})", field.name)).collect::<String>();
+    out.push_str(&format!("  pub fn new({}) -> Self {{ Self::builder(){}.build() }}\n", arguments.join(", "), setters));
+  } else {
+    let arguments = arguments.iter().map(|argument| format!("    {},\n", argument)).collect::<String>();
+    let setters = required.iter().map(|field| format!("      .{0}({0})\n", field.name)).collect::<String>();
+    out.push_str(&format!("  pub fn new(\n{}  ) -> Self {{\n    Self::builder()\n{}      .build()\n  }}\n", arguments, setters));
+  }
+  out
+}
+
+fn setter(field: &RustField) -> String {
+  let name = &field.name;
+  let as_ref = |type_: &str| format!("<T: AsRef<{}>>", type_);
+  let (generics, argument, value) = match (field.optional, field.kind) {
+    (None, Kind::Copy) | (None, Kind::Vec) => (String::new(), field.type_.clone(), name.to_string()),
+    (None, Kind::Str) => (as_ref("str"), "T".to_string(), format!("{}.as_ref().to_string()", name)),
+    (None, Kind::Clone) => (as_ref(&field.type_), "T".to_string(), format!("{}.as_ref().clone()", name)),
+    (Some(Quirk::Optional), Kind::Copy) | (Some(Quirk::Optional), Kind::Vec) => (String::new(), field.inner.clone(), format!("Some({})", name)),
+    (Some(Quirk::Optional), Kind::Str) => (as_ref("str"), "T".to_string(), format!("Some({}.as_ref().to_string())", name)),
+    (Some(Quirk::Optional), Kind::Clone) => (as_ref(&field.inner), "T".to_string(), format!("Some({}.as_ref().clone())", name)),
+    (Some(Quirk::OptionalCopy), _) => (String::new(), field.type_.clone(), name.to_string()),
+    (Some(Quirk::OptionalRef), _) => (as_ref(&field.type_), "T".to_string(), format!("{}.as_ref().clone()", name)),
+  };
+  format!(
+    "  pub fn {0}{1}(&mut self, {0}: {2}) -> &mut Self {{\n    self.inner.{0} = {3};\n    self\n  }}\n",
+    name, generics, argument, value,
+  )
+}
+
+pub fn struct_name(definition: &Definition) -> String { tl::upper_first(&definition.name) }
+
+/// `Offline` for `UserStatusOffline` in `UserStatus`, constructors not prefixed by their class keep their name
+pub fn variant_name(class: &str, struct_name: &str) -> String {
+  match struct_name.strip_prefix(class) {
+    Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => rest.to_string(),
+    _ => struct_name.to_string(),
+  }
+}
+
+const KEYWORDS: &[&str] = &[
+  "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for",
+  "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static", "struct",
+  "super", "trait", "true", "try", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final",
+  "macro", "override", "priv", "typeof", "unsized", "virtual", "yield",
+];
+
+/// `type_` for `type`, keywords get a trailing underscore
+pub fn ident(name: &str) -> String {
+  if KEYWORDS.contains(&name) { format!("{}_", name) } else { name.to_string() }
+}
+
+/// `t_me_url` for `TMeUrl`, every capital starts a word
+pub fn snake(name: &str) -> String {
+  let mut snake = String::new();
+  for (index, c) in name.chars().enumerate() {
+    if c.is_ascii_uppercase() && index > 0 {
+      snake.push('_');
+    }
+    snake.push(c.to_ascii_lowercase());
+  }
+  snake
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_names() {
+    assert_eq!(snake("TMeUrl"), "t_me_url");
+    assert_eq!(snake("ApplePushVoIP"), "apple_push_vo_i_p");
+    assert_eq!(variant_name("UserStatus", "UserStatusOffline"), "Offline");
+    assert_eq!(variant_name("MessageContent", "MessageText"), "MessageText");
+    assert_eq!(variant_name("RichText", "RichTexts"), "RichTexts");
+    assert_eq!(ident("static"), "static_");
+  }
+}
@@ -0,0 +1,263 @@
+//! Parser of tdlib's `td_api.tl`
+
+use std::collections::HashMap;
+use std::{error, fmt};
+
+/// Declarations of the builtin types, they aren't generated
+const BUILTIN_RESULTS: &[&str] = &["Double", "String", "Int32", "Int53", "Int64", "Bytes", "Bool", "Vector t"];
+
+/// Type of a field as written in the schema, e.g. `int53`, `chatPosition` or `vector<MessageSender>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TlType {
+  Named(String),
+  Vector(Box<TlType>),
+}
+
+impl TlType {
+  pub fn parse(text: &str) -> Option<TlType> {
+    let text = text.trim();
+    match text.strip_prefix("vector<") {
+      Some(inner) => Some(TlType::Vector(Box::new(TlType::parse(inner.strip_suffix('>')?)?))),
+      None if !text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => Some(TlType::Named(text.to_string())),
+      None => None,
+    }
+  }
+
+  /// Name of the innermost type, `chatPosition` for `vector<vector<chatPosition>>`
+  pub fn name(&self) -> &str {
+    match self {
+      TlType::Named(name) => name,
+      TlType::Vector(inner) => inner.name(),
+    }
+  }
+}
+
+impl fmt::Display for TlType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      TlType::Named(name) => write!(f, "{}", name),
+      TlType::Vector(inner) => write!(f, "vector<{}>", inner),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+  pub name: String,
+  pub type_: TlType,
+  pub description: String,
+}
+
+/// A constructor, like `chatPosition list:ChatList order:int64 ... = ChatPosition;`, or a function
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+  pub name: String,
+  pub description: String,
+  pub fields: Vec<Field>,
+  /// The class a constructor belongs to, or the type a function returns
+  pub result: String,
+}
+
+/// A class declared by `//@class`, the constructors of it are the ones with the class as result
+#[derive(Debug, Clone, PartialEq)]
+pub struct Class {
+  pub name: String,
+  pub description: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Schema {
+  pub classes: Vec<Class>,
+  pub types: Vec<Definition>,
+  pub functions: Vec<Definition>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParseError {
+  /// 1-based
+  pub line: usize,
+  pub message: String,
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "line {}: {}", self.line, self.message)
+  }
+}
+
+impl error::Error for ParseError {}
+
+impl Schema {
+  pub fn parse(source: &str) -> Result<Schema, ParseError> {
+    let mut schema = Schema::default();
+    let mut is_function = false;
+    // `//` lines above the next declaration, `//-` continues the previous line
+    let mut comment = String::new();
+    for (index, line) in source.lines().enumerate() {
+      let line = line.trim();
+      let fail = |message: String| ParseError { line: index + 1, message };
+      if line.is_empty() {
+        // a class comment stands alone, separated from the constructors by a blank line
+        if comment.contains("@class") {
+          let tags = parse_tags(&comment);
+          comment.clear();
+          let name = tags.get("class").ok_or_else(|| fail("@class without a name".to_string()))?;
+          schema.classes.push(Class {
+            name: name.to_string(),
+            description: tags.get("description").cloned().unwrap_or_default(),
+          });
+        }
+        continue;
+      }
+      match line {
+        "---functions---" => { is_function = true; continue; }
+        "---types---" => { is_function = false; continue; }
+        _ => {}
+      }
+      if let Some(text) = line.strip_prefix("//") {
+        comment.push(' ');
+        comment.push_str(text.strip_prefix('-').unwrap_or(text).trim());
+        continue;
+      }
+      let tags = parse_tags(&comment);
+      comment.clear();
+      let definition = parse_definition(line, &tags).map_err(fail)?;
+      match definition {
+        Some(definition) if is_function => schema.functions.push(definition),
+        Some(definition) => schema.types.push(definition),
+        None => {}
+      }
+    }
+    Ok(schema)
+  }
+
+  /// Whether `class` has several constructors, or one not named after it. These are enums in rust.
+  pub fn is_abstract(&self, class: &str) -> bool {
+    let mut constructors = self.types.iter().filter(|definition| definition.result == class);
+    match (constructors.next(), constructors.next()) {
+      (Some(constructor), None) => constructor.name != lower_first(class),
+      (Some(_), Some(_)) => true,
+      (None, _) => self.classes.iter().any(|declared| declared.name == class),
+    }
+  }
+
+  /// Constructors of `class`, in schema order
+  pub fn constructors(&self, class: &str) -> Vec<&Definition> {
+    self.types.iter().filter(|definition| definition.result == class).collect()
+  }
+
+  pub fn find_type(&self, name: &str) -> Option<&Definition> {
+    self.types.iter().find(|definition| definition.name == name)
+  }
+
+  pub fn find_function(&self, name: &str) -> Option<&Definition> {
+    self.functions.iter().find(|definition| definition.name == name)
+  }
+}
+
+/// `name:type` pairs and the result of a declaration, `None` for builtin types
+fn parse_definition(line: &str, tags: &HashMap<String, String>) -> Result<Option<Definition>, String> {
+  let declaration = line.strip_suffix(';').ok_or_else(|| format!("missing `;` in `{}`", line))?;
+  let (left, result) = match declaration.split_once('=') {
+    Some((left, result)) => (left.trim(), result.trim()),
+    None => return Err(format!("missing `=` in `{}`", line)),
+  };
+  if BUILTIN_RESULTS.contains(&result) {
+    return Ok(None);
+  }
+  let mut tokens = left.split_whitespace();
+  let name = tokens.next().ok_or_else(|| format!("missing name in `{}`", line))?;
+  let fields = tokens
+    .map(|token| {
+      let (field, type_) = token.split_once(':').ok_or_else(|| format!("expected `name:type`, found `{}`", token))?;
+      let type_ = TlType::parse(type_).ok_or_else(|| format!("unsupported type `{}` of {}.{}", type_, name, field))?;
+      // fields named like a tag, i.e. `description`, are documented as `@param_<name>`
+      let description = tags.get(&format!("param_{}", field)).or_else(|| tags.get(field)).cloned().unwrap_or_default();
+      Ok(Field { name: field.to_string(), type_, description })
+    })
+    .collect::<Result<Vec<Field>, String>>()?;
+  Ok(Some(Definition {
+    name: name.to_string(),
+    description: tags.get("description").cloned().unwrap_or_default(),
+    fields,
+    result: result.to_string(),
+  }))
+}
+
+/// `@name text` pairs of a comment
+fn parse_tags(comment: &str) -> HashMap<String, String> {
+  comment.split('@')
+    .skip(1)
+    .filter_map(|tag| {
+      let tag = tag.trim();
+      let (name, text) = tag.split_once(' ').unwrap_or((tag, ""));
+      if name.is_empty() { None } else { Some((name.to_string(), text.trim().to_string())) }
+    })
+    .collect()
+}
+
+pub fn lower_first(name: &str) -> String {
+  let mut chars = name.chars();
+  chars.next().map(|first| first.to_lowercase().chain(chars).collect()).unwrap_or_default()
+}
+
+pub fn upper_first(name: &str) -> String {
+  let mut chars = name.chars();
+  chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SCHEMA: &str = r#"
+double ? = Double;
+int32 = Int32;
+vector {t:Type} # [ t ] = Vector t;
+
+//@class UserStatus @description Describes the last time the user was online
+
+//@description The user status was never changed
+userStatusEmpty = UserStatus;
+
+//@description The user is offline @was_online Point in time (Unix timestamp) when the user was last online
+userStatusOffline was_online:int32 = UserStatus;
+
+//@description Represents a list of users @total_count Approximate total count of users found
+//-@user_ids A list of user identifiers
+users total_count:int32 user_ids:vector<int53> = Users;
+
+---functions---
+
+//@description Returns users @param_description Some text
+getUsers description:string = Users;
+"#;
+
+  #[test]
+  fn test_parse() {
+    let schema = Schema::parse(SCHEMA).unwrap();
+    assert_eq!(schema.classes, vec![Class { name: "UserStatus".to_string(), description: "Describes the last time the user was online".to_string() }]);
+    assert_eq!(schema.types.len(), 3);
+    assert!(schema.is_abstract("UserStatus"));
+    assert!(!schema.is_abstract("Users"));
+
+    let users = schema.find_type("users").unwrap();
+    assert_eq!(users.description, "Represents a list of users");
+    assert_eq!(users.fields[1], Field {
+      name: "user_ids".to_string(),
+      type_: TlType::Vector(Box::new(TlType::Named("int53".to_string()))),
+      description: "A list of user identifiers".to_string(),
+    });
+
+    let get_users = schema.find_function("getUsers").unwrap();
+    assert_eq!(get_users.result, "Users");
+    assert_eq!(get_users.fields[0].description, "Some text");
+  }
+
+  #[test]
+  fn test_parse_error() {
+    let error = Schema::parse("\nuser id:int32 = User").unwrap_err();
+    assert_eq!(error.line, 2);
+    assert!(Schema::parse("user id:vector<int32 = User;").is_err());
+  }
+}
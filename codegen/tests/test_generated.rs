@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::Path;
+
+use rtdlib_codegen::{generate, versions, write, Schema};
+
+/// `src/types` and `src/schema` must be what the generator makes of the schemas in `schema`
+#[test]
+fn test_types_are_generated() {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+  for version in versions(root.join("schema")).unwrap() {
+    let source = fs::read_to_string(root.join("schema").join(&version).join("td_api.tl")).unwrap();
+    let generated = generate(&Schema::parse(&source).unwrap()).unwrap();
+    let changed = write(&generated, root.join("src"), &version, true).unwrap();
+    assert!(changed.is_empty(), "{} is out of date, run `cargo run -p rtdlib-codegen`: {:?}", version, changed);
+  }
+}
+
+#[test]
+fn test_features_are_declared() {
+  let manifest = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("../Cargo.toml")).unwrap();
+  for (feature, _) in rtdlib_codegen::features::FEATURES {
+    assert!(manifest.contains(&format!("\n{} = []", feature)), "feature `{}` is missing in Cargo.toml", feature);
+  }
+}
+
+/// Every name of `FEATURES` is a class, constructor or function of a schema, in one feature only
+#[test]
+fn test_features_are_in_the_schemas() {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+  let schemas: Vec<Schema> = versions(root.join("schema")).unwrap().iter()
+    .map(|version| Schema::parse(&fs::read_to_string(root.join("schema").join(version).join("td_api.tl")).unwrap()).unwrap())
+    .collect();
+  let mut listed = std::collections::BTreeSet::new();
+  for (feature, names) in rtdlib_codegen::features::FEATURES {
+    for name in *names {
+      assert!(listed.insert(*name), "{} is in more than one feature", name);
+      let known = schemas.iter().any(|schema| {
+        schema.types.iter().chain(&schema.functions).any(|definition| definition.name == *name || definition.result == *name)
+      });
+      assert!(known, "{} of feature `{}` is in no schema", name, feature);
+    }
+  }
+}
+
+/// Every version is a feature selecting its types and tables, and no two versions can be selected together
+#[test]
+fn test_versions_are_wired() {
+  let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+  let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+  let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+  let types = fs::read_to_string(root.join("src/types/mod.rs")).unwrap();
+  let schema = fs::read_to_string(root.join("src/schema/mod.rs")).unwrap();
+  let ci = fs::read_to_string(root.join(".github/workflows/develop.yml")).unwrap();
+  let matrix = ci.lines().find_map(|line| line.trim().strip_prefix("version: [")).expect("no version matrix in develop.yml");
+  let versions = versions(root.join("schema")).unwrap();
+  for version in &versions {
+    let cfg = format!("#[cfg(feature = \"{}\")]", version);
+    let module = rtdlib_codegen::module(version);
+    assert!(manifest.contains(&format!("\n{} = []", version)), "feature `{}` is missing in Cargo.toml", version);
+    assert!(lib.contains(&format!("{}\npub const TDLIB_VERSION", cfg)), "TDLIB_VERSION of {} is missing in src/lib.rs", version);
+    assert!(types.contains(&format!("{}\nmod {};", cfg, module)), "{} is not a module of src/types/mod.rs", module);
+    assert!(schema.contains(&format!("{}\nmod {};", cfg, module)), "{} is not a module of src/schema/mod.rs", module);
+    assert!(lib.contains(&format!("`{}`", version)), "{} is missing in the compile_error of src/lib.rs", version);
+    assert!(matrix.trim_end_matches(']').split(',').any(|job| job.trim() == version), "{} has no job in develop.yml", version);
+  }
+  for (index, version) in versions.iter().enumerate() {
+    for other in &versions[index + 1..] {
+      let both = format!("#[cfg(all(feature = \"{}\", feature = \"{}\"))]\ncompile_error!", version, other);
+      assert!(lib.contains(&both), "src/lib.rs doesn't reject {} and {} together", version, other);
+    }
+  }
+}
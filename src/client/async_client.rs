@@ -0,0 +1,227 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use futures_core::Stream;
+use crate::errors::*;
+use crate::transport::{TdTransport, RECEIVE_TIMEOUT};
+use crate::types::*;
+
+use super::request_json;
+
+#[derive(Default)]
+struct Slot {
+  reply: Option<String>,
+  waker: Option<Waker>,
+}
+
+#[derive(Default)]
+struct Shared {
+  pending: HashMap<Extra, Slot>,
+  updates: VecDeque<String>,
+  /// The waker of each `UpdateStream` waiting for an update, by stream
+  updates_wakers: HashMap<usize, Waker>,
+  next_stream: usize,
+}
+
+impl Shared {
+  /// Wake every waiting stream, the first to poll takes the update and the others wait again
+  fn wake_streams(&mut self) {
+    self.updates_wakers.drain().for_each(|(_, waker)| waker.wake());
+  }
+}
+
+/// Executor agnostic tdlib client. A background thread receives from the transport, replies are
+/// delivered to the future returned by `send` with the same `@extra`, everything else
+/// goes to the `updates` stream.
+///
+/// ```no_run
+/// # async fn run() -> rtdlib::errors::RTDResult<()> {
+/// use rtdlib::client::AsyncClient;
+/// use rtdlib::transport::ChannelTransport;
+/// use rtdlib::types::GetMe;
+///
+/// // any `TdTransport`, e.g. `rtdlib::Tdlib::new()` with the `sys` feature
+/// let (transport, _peer) = ChannelTransport::pair();
+/// let client = AsyncClient::new(transport);
+/// let me = client.send(GetMe::builder().build()).await?;
+/// println!("{}", me.first_name());
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncClient<T: TdTransport> {
+  transport: Arc<T>,
+  shared: Arc<Mutex<Shared>>,
+  running: Arc<AtomicBool>,
+  extra_generator: Option<Arc<dyn ExtraGenerator>>,
+}
+
+impl<T: TdTransport + Send + Sync + 'static> AsyncClient<T> {
+  pub fn new(transport: T) -> Self {
+    let client = Self {
+      transport: Arc::new(transport),
+      shared: Arc::new(Mutex::new(Shared::default())),
+      running: Arc::new(AtomicBool::new(true)),
+      extra_generator: None,
+    };
+    let transport = client.transport.clone();
+    let shared = client.shared.clone();
+    let running = client.running.clone();
+    thread::spawn(move || receive_loop(transport, shared, running));
+    client
+  }
+
+  /// Take the `@extra` of each function `send` gets without one from `generator`, the receive loop
+  /// resolves its future by it. Unset, they come from the global generator.
+  pub fn extra_generator<G: ExtraGenerator + 'static>(mut self, generator: G) -> Self {
+    self.extra_generator = Some(Arc::new(generator));
+    self
+  }
+
+  /// Send a function to tdlib, the returned future resolves to its typed reply, or
+  /// `RTDError::Tdlib` if tdlib answered with an `error`
+  pub fn send<Fnc: RFunction>(&self, fnc: Fnc) -> ResponseFuture<Fnc> {
+    let extra = request_json(fnc, self.extra_generator.as_ref()).and_then(|(json, extra)| {
+      self.shared.lock().unwrap().pending.insert(extra.clone(), Slot::default());
+      match self.transport.send(&json[..]) {
+        Ok(()) => Ok(extra),
+        Err(err) => {
+          self.shared.lock().unwrap().pending.remove(&extra);
+          Err(err)
+        }
+      }
+    });
+    ResponseFuture {
+      extra: extra.map_err(Some),
+      shared: self.shared.clone(),
+      running: self.running.clone(),
+      _function: PhantomData,
+    }
+  }
+
+  /// Stream of everything tdlib sends that is not a reply to a pending `send`.
+  /// All streams of a client share one queue, each update is yielded once.
+  pub fn updates(&self) -> UpdateStream {
+    let id = {
+      let mut shared = self.shared.lock().unwrap();
+      shared.next_stream += 1;
+      shared.next_stream
+    };
+    UpdateStream {
+      id,
+      shared: self.shared.clone(),
+      running: self.running.clone(),
+    }
+  }
+}
+
+impl<T: TdTransport> Drop for AsyncClient<T> {
+  fn drop(&mut self) {
+    self.running.store(false, Ordering::SeqCst);
+  }
+}
+
+fn receive_loop<T: TdTransport>(transport: Arc<T>, shared: Arc<Mutex<Shared>>, running: Arc<AtomicBool>) {
+  while running.load(Ordering::SeqCst) {
+    let json = match transport.receive(RECEIVE_TIMEOUT) {
+      Some(json) => json,
+      None => continue,
+    };
+    let (_, extra) = detect_td_type_and_extra(&json);
+    let mut shared = shared.lock().unwrap();
+    match extra.and_then(|extra| shared.pending.get_mut(&extra)) {
+      Some(slot) => {
+        slot.reply = Some(json);
+        if let Some(waker) = slot.waker.take() { waker.wake() }
+      }
+      None => {
+        shared.updates.push_back(json);
+        shared.wake_streams();
+      }
+    }
+  }
+  // wake everyone still waiting so they can observe the client is closed
+  let mut shared = shared.lock().unwrap();
+  shared.pending.values_mut().for_each(|slot| if let Some(waker) = slot.waker.take() { waker.wake() });
+  shared.wake_streams();
+}
+
+fn closed_error() -> RTDError {
+  RTDError::custom("tdlib client is closed".to_string())
+}
+
+/// Future of a tdlib function reply, returned by `AsyncClient::send`
+pub struct ResponseFuture<Fnc: RFunction> {
+  extra: Result<Extra, Option<RTDError>>,
+  shared: Arc<Mutex<Shared>>,
+  running: Arc<AtomicBool>,
+  _function: PhantomData<fn() -> Fnc>,
+}
+
+impl<Fnc: RFunction> Future for ResponseFuture<Fnc> {
+  type Output = RTDResult<Fnc::Response>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let extra = match &mut self.extra {
+      Ok(extra) => extra.clone(),
+      Err(err) => return Poll::Ready(Err(err.take().unwrap_or_else(closed_error))),
+    };
+    let mut shared = self.shared.lock().unwrap();
+    let reply = match shared.pending.get_mut(&extra) {
+      Some(slot) => match slot.reply.take() {
+        Some(reply) => reply,
+        None if !self.running.load(Ordering::SeqCst) => return Poll::Ready(Err(closed_error())),
+        None => {
+          slot.waker = Some(cx.waker().clone());
+          return Poll::Pending;
+        }
+      },
+      None => return Poll::Ready(Err(closed_error())),
+    };
+    shared.pending.remove(&extra);
+    Poll::Ready(parse_response::<Fnc>(&reply))
+  }
+}
+
+impl<Fnc: RFunction> Drop for ResponseFuture<Fnc> {
+  fn drop(&mut self) {
+    if let Ok(extra) = &self.extra {
+      self.shared.lock().unwrap().pending.remove(extra);
+    }
+  }
+}
+
+/// Stream of tdlib updates, returned by `AsyncClient::updates`. Ends once the client is dropped
+/// and all received updates were yielded.
+pub struct UpdateStream {
+  id: usize,
+  shared: Arc<Mutex<Shared>>,
+  running: Arc<AtomicBool>,
+}
+
+impl Stream for UpdateStream {
+  type Item = RTDResult<TdType>;
+
+  fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let mut shared = self.shared.lock().unwrap();
+    match shared.updates.pop_front() {
+      Some(json) => Poll::Ready(Some(from_json::<TdType>(&json))),
+      None if !self.running.load(Ordering::SeqCst) => Poll::Ready(None),
+      None => {
+        shared.updates_wakers.insert(self.id, cx.waker().clone());
+        Poll::Pending
+      }
+    }
+  }
+}
+
+impl Drop for UpdateStream {
+  fn drop(&mut self) {
+    self.shared.lock().unwrap().updates_wakers.remove(&self.id);
+  }
+}
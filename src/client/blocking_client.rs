@@ -0,0 +1,90 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::errors::*;
+use crate::transport::TdTransport;
+use crate::types::*;
+
+use super::request_json;
+
+/// Synchronous tdlib client. `call` blocks until the reply with the same `@extra` arrives,
+/// anything received meanwhile is buffered for `poll_updates`.
+///
+/// ```no_run
+/// # fn run() -> rtdlib::errors::RTDResult<()> {
+/// use std::time::Duration;
+/// use rtdlib::client::BlockingClient;
+/// use rtdlib::transport::ChannelTransport;
+/// use rtdlib::types::GetMe;
+///
+/// // any `TdTransport`, e.g. `rtdlib::Tdlib::new()` with the `sys` feature
+/// let (transport, _peer) = ChannelTransport::pair();
+/// let mut client = BlockingClient::new(transport);
+/// let me = client.call(GetMe::builder().build(), Duration::from_secs(10))?;
+/// println!("{}", me.first_name());
+/// for update in client.poll_updates(Duration::from_secs(1)) {
+///   println!("{:?}", update?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct BlockingClient<T: TdTransport> {
+  transport: T,
+  updates: VecDeque<String>,
+  extra_generator: Option<Arc<dyn ExtraGenerator>>,
+}
+
+impl<T: TdTransport> BlockingClient<T> {
+  pub fn new(transport: T) -> Self {
+    Self { transport, updates: VecDeque::new(), extra_generator: None }
+  }
+
+  /// Take the `@extra` of each function `call` gets without one from `generator`, e.g. a
+  /// `PrefixedExtra` telling the calls of this client apart in a recording. Unset, they come from
+  /// the global generator.
+  pub fn extra_generator<G: ExtraGenerator + 'static>(mut self, generator: G) -> Self {
+    self.extra_generator = Some(Arc::new(generator));
+    self
+  }
+
+  /// Send a function to tdlib and wait at most `timeout` for its typed reply. Returns
+  /// `RTDError::Tdlib` if tdlib answered with an `error` and `RTDError::Timeout` if no reply came in time.
+  pub fn call<Fnc: RFunction>(&mut self, fnc: Fnc, timeout: Duration) -> RTDResult<Fnc::Response> {
+    let (json, extra) = request_json(fnc, self.extra_generator.as_ref())?;
+    self.transport.send(&json[..])?;
+    let deadline = Instant::now() + timeout;
+    loop {
+      let json = self.receive(deadline).ok_or(RTDError::Timeout(timeout))?;
+      match detect_td_type_and_extra(&json) {
+        (_, Some(ref reply_extra)) if reply_extra == &extra => return parse_response::<Fnc>(&json),
+        _ => self.updates.push_back(json),
+      }
+    }
+  }
+
+  /// Return buffered updates and those already waiting in the transport, if there are none wait
+  /// at most `timeout` for the next one. Replies of calls that already timed out are returned here as well.
+  pub fn poll_updates(&mut self, timeout: Duration) -> Vec<RTDResult<TdType>> {
+    if self.updates.is_empty() {
+      if let Some(json) = self.receive(Instant::now() + timeout) {
+        self.updates.push_back(json);
+      }
+    }
+    while let Some(json) = self.transport.receive(Duration::from_secs(0)) {
+      self.updates.push_back(json);
+    }
+    self.updates.drain(..).map(|json| from_json::<TdType>(&json)).collect()
+  }
+
+  /// Receive one message from the transport, `None` once `deadline` passed
+  fn receive(&self, deadline: Instant) -> Option<String> {
+    loop {
+      let remaining = deadline.checked_duration_since(Instant::now())?;
+      if let Some(json) = self.transport.receive(remaining) {
+        return Some(json);
+      }
+      if remaining.is_zero() { return None }
+    }
+  }
+}
@@ -0,0 +1,268 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::errors::*;
+use crate::transport::{recv_timeout, TdMultiTransport, TdTransport, RECEIVE_TIMEOUT};
+use crate::types::*;
+
+use super::request_json;
+
+/// What the handle of an account and the receive loop share
+struct AccountState {
+  parameters: TdlibParameters,
+  encryption_key: Bytes,
+  authorization: Mutex<AuthorizationState>,
+  authorization_changed: Condvar,
+  /// `@extra` of the requests the manager sent for the account, their replies are not delivered
+  own: Mutex<HashSet<Extra>>,
+  /// Of the manager when the account was added
+  extra_generator: Option<Arc<dyn ExtraGenerator>>,
+  closed: AtomicBool,
+}
+
+struct Route {
+  sender: Sender<String>,
+  state: Arc<AccountState>,
+}
+
+type Routes = Arc<Mutex<HashMap<i32, Route>>>;
+
+/// Many accounts in one process, a tdlib client each. A background thread receives from the
+/// transport and delivers everything to the `Account` of its `@client_id`.
+///
+/// The manager answers `authorizationStateWaitTdlibParameters` with the `TdlibParameters` of the
+/// account and `authorizationStateWaitEncryptionKey` with its key, the rest of the flow, like
+/// `checkAuthenticationBotToken`, is up to the account. An `Account` is a `TdTransport`, so a
+/// `BlockingClient` or `AsyncClient` of its own matches its replies and buffers its updates.
+///
+/// ```no_run
+/// # fn run() -> rtdlib::errors::RTDResult<()> {
+/// use std::process::Command;
+/// use std::time::Duration;
+/// use rtdlib::client::{BlockingClient, ClientManager};
+/// use rtdlib::transport::{Multiplexer, ProcessTransport};
+/// use rtdlib::types::*;
+///
+/// // any `TdMultiTransport`, e.g. `rtdlib::transport::Tdjson::new()` with the `tdjson` feature
+/// let manager = ClientManager::new(Multiplexer::new(|_client_id| ProcessTransport::spawn(Command::new("tdjson-bridge"))));
+/// let mut clients = vec![];
+/// for (name, token) in vec![("alice", "token-a"), ("bob", "token-b")] {
+///   let parameters = TdlibParameters::builder()
+///     .database_directory(format!("accounts/{}/db", name))
+///     .files_directory(format!("accounts/{}/files", name))
+///     .build();
+///   let account = manager.add_account(parameters)?;
+///   account.wait_authorization(Duration::from_secs(10))?; // authorizationStateWaitPhoneNumber
+///   let mut client = BlockingClient::new(account);
+///   client.call(CheckAuthenticationBotToken::builder().token(token).build(), Duration::from_secs(10))?;
+///   clients.push(client);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientManager<T: TdMultiTransport> {
+  transport: Arc<T>,
+  routes: Routes,
+  running: Arc<AtomicBool>,
+  extra_generator: Option<Arc<dyn ExtraGenerator>>,
+}
+
+impl<T: TdMultiTransport + Send + Sync + 'static> ClientManager<T> {
+  pub fn new(transport: T) -> Self {
+    let manager = Self {
+      transport: Arc::new(transport),
+      routes: Arc::new(Mutex::new(HashMap::new())),
+      running: Arc::new(AtomicBool::new(true)),
+      extra_generator: None,
+    };
+    let transport = manager.transport.clone();
+    let routes = manager.routes.clone();
+    let running = manager.running.clone();
+    thread::spawn(move || receive_loop(transport, routes, running));
+    manager
+  }
+
+  /// Give the requests the manager sends for the accounts added from now on an `@extra` of
+  /// `generator` instead of the global generator. The clients of the accounts have their own.
+  pub fn extra_generator<G: ExtraGenerator + 'static>(mut self, generator: G) -> Self {
+    self.extra_generator = Some(Arc::new(generator));
+    self
+  }
+
+  /// Create the tdlib client of an account with an unencrypted database
+  pub fn add_account(&self, parameters: TdlibParameters) -> RTDResult<Account<T>> {
+    self.add_encrypted_account(parameters, Bytes::default())
+  }
+
+  /// Create the tdlib client of an account whose database is encrypted with `encryption_key`
+  pub fn add_encrypted_account(&self, parameters: TdlibParameters, encryption_key: Bytes) -> RTDResult<Account<T>> {
+    let client_id = self.transport.create_client_id()?;
+    let (sender, receiver) = mpsc::channel();
+    let state = Arc::new(AccountState {
+      parameters,
+      encryption_key,
+      authorization: Mutex::new(AuthorizationState::_Default(())),
+      authorization_changed: Condvar::new(),
+      own: Mutex::new(HashSet::new()),
+      extra_generator: self.extra_generator.clone(),
+      closed: AtomicBool::new(false),
+    });
+    self.routes.lock().unwrap().insert(client_id, Route { sender, state: state.clone() });
+    // tdlib starts the client with its first request
+    if let Err(err) = send_own(&*self.transport, client_id, &state, GetAuthorizationState::builder().build()) {
+      self.routes.lock().unwrap().remove(&client_id);
+      return Err(err);
+    }
+    Ok(Account { client_id, transport: self.transport.clone(), state, receiver: Mutex::new(receiver) })
+  }
+
+  /// The `@client_id` of every account not closed yet
+  pub fn client_ids(&self) -> Vec<i32> {
+    let mut client_ids: Vec<i32> = self.routes.lock().unwrap().keys().copied().collect();
+    client_ids.sort_unstable();
+    client_ids
+  }
+}
+
+impl<T: TdMultiTransport> Drop for ClientManager<T> {
+  fn drop(&mut self) {
+    self.running.store(false, Ordering::SeqCst);
+  }
+}
+
+fn receive_loop<T: TdMultiTransport>(transport: Arc<T>, routes: Routes, running: Arc<AtomicBool>) {
+  while running.load(Ordering::SeqCst) {
+    let json = match transport.receive(RECEIVE_TIMEOUT) {
+      Some(json) => json,
+      None => continue,
+    };
+    let header = detect_td_header(&json);
+    // replies to `execute` and objects of clients created elsewhere have no account
+    let (client_id, sender, state) = match header.client_id.and_then(|client_id| {
+      routes.lock().unwrap().get(&client_id).map(|route| (client_id, route.sender.clone(), route.state.clone()))
+    }) {
+      Some(route) => route,
+      None => continue,
+    };
+    let is_own = match &header.extra {
+      Some(extra) => state.own.lock().unwrap().remove(extra),
+      None => false,
+    };
+    // errors of the manager's own requests are delivered as updates, e.g. rejected parameters
+    if is_own && header.td_type.as_deref() != Some("error") {
+      continue;
+    }
+    if header.td_type.as_deref() == Some("updateAuthorizationState") {
+      if let Ok(update) = from_json::<UpdateAuthorizationState>(&json) {
+        authorize(&*transport, client_id, &state, update.authorization_state());
+      }
+    }
+    let _ = sender.send(json);
+    if state.closed.load(Ordering::SeqCst) {
+      routes.lock().unwrap().remove(&client_id);
+    }
+  }
+}
+
+/// Keep the authorization state of an account, answering the steps its settings are for
+fn authorize<T: TdMultiTransport>(transport: &T, client_id: i32, state: &AccountState, authorization: &AuthorizationState) {
+  *state.authorization.lock().unwrap() = authorization.clone();
+  state.authorization_changed.notify_all();
+  let _ = match authorization {
+    AuthorizationState::WaitTdlibParameters(_) => {
+      send_own(transport, client_id, state, SetTdlibParameters::builder().parameters(&state.parameters).build())
+    }
+    AuthorizationState::WaitEncryptionKey(_) => {
+      send_own(transport, client_id, state, CheckDatabaseEncryptionKey::builder().encryption_key(&state.encryption_key).build())
+    }
+    AuthorizationState::Closed(_) => {
+      state.closed.store(true, Ordering::SeqCst);
+      Ok(())
+    }
+    _ => Ok(()),
+  };
+}
+
+/// Send a request of the manager for an account, its reply is not delivered
+fn send_own<T: TdMultiTransport, Fnc: RFunction>(transport: &T, client_id: i32, state: &AccountState, fnc: Fnc) -> RTDResult<()> {
+  let (json, extra) = request_json(fnc, state.extra_generator.as_ref())?;
+  state.own.lock().unwrap().insert(extra.clone());
+  let sent = transport.send(client_id, &json);
+  if sent.is_err() {
+    state.own.lock().unwrap().remove(&extra);
+  }
+  sent
+}
+
+/// An account of a `ClientManager`, the `TdTransport` of its tdlib client. Dropping it closes the
+/// client, dropping the manager stops delivering to it.
+pub struct Account<T: TdMultiTransport> {
+  client_id: i32,
+  transport: Arc<T>,
+  state: Arc<AccountState>,
+  receiver: Mutex<Receiver<String>>,
+}
+
+impl<T: TdMultiTransport> Account<T> {
+  /// The `@client_id` of its tdlib client
+  pub fn client_id(&self) -> i32 { self.client_id }
+
+  pub fn parameters(&self) -> &TdlibParameters { &self.state.parameters }
+
+  /// The state of the last `updateAuthorizationState`, the default variant until the first one
+  pub fn authorization_state(&self) -> AuthorizationState {
+    self.state.authorization.lock().unwrap().clone()
+  }
+
+  /// Wait at most `timeout` for the manager to pass the steps it answers, returning the state the
+  /// account goes on from, like `authorizationStateWaitPhoneNumber` or `authorizationStateReady`.
+  /// Returns `RTDError::Timeout` if the client is still waiting for its parameters or encryption key.
+  pub fn wait_authorization(&self, timeout: Duration) -> RTDResult<AuthorizationState> {
+    let deadline = Instant::now() + timeout;
+    let mut authorization = self.state.authorization.lock().unwrap();
+    loop {
+      let answered = !(authorization._is_default() || authorization.is_wait_tdlib_parameters() || authorization.is_wait_encryption_key());
+      if answered {
+        return Ok(authorization.clone());
+      }
+      let remaining = deadline.checked_duration_since(Instant::now()).ok_or(RTDError::Timeout(timeout))?;
+      authorization = self.state.authorization_changed.wait_timeout(authorization, remaining).unwrap().0;
+    }
+  }
+
+  /// Whether the client reached `authorizationStateClosed`, nothing is sent or delivered after it
+  pub fn is_closed(&self) -> bool { self.state.closed.load(Ordering::SeqCst) }
+
+  /// Close the tdlib client, `updateAuthorizationState` with `authorizationStateClosed` is the last
+  /// update received
+  pub fn close(&self) -> RTDResult<()> {
+    send_own(&*self.transport, self.client_id, &self.state, Close::builder().build())
+  }
+}
+
+impl<T: TdMultiTransport> TdTransport for Account<T> {
+  fn send(&self, request: &str) -> RTDResult<()> {
+    if self.is_closed() {
+      return Err(RTDError::custom(format!("tdlib client {} is closed", self.client_id)));
+    }
+    self.transport.send(self.client_id, request)
+  }
+
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    recv_timeout(&self.receiver, timeout)
+  }
+
+  fn execute(&self, request: &str) -> Option<String> { self.transport.execute(request) }
+}
+
+impl<T: TdMultiTransport> Drop for Account<T> {
+  fn drop(&mut self) {
+    if !self.is_closed() {
+      let _ = self.close();
+    }
+  }
+}
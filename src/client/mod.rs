@@ -0,0 +1,28 @@
+//! Clients on top of a `TdTransport`, matching function replies to requests by `@extra`
+
+pub use self::async_client::*;
+pub use self::blocking_client::*;
+pub use self::manager::*;
+
+mod async_client;
+mod blocking_client;
+mod manager;
+
+use std::sync::Arc;
+
+use crate::errors::*;
+use crate::types::*;
+
+/// Serialize a function to json, returning it with the `@extra` tdlib will echo back in the reply.
+/// A function without `@extra`, unless set with `extra(...)` of its builder or `set_extra`, is given a
+/// new one by `generator`, or by the global generator of `next_extra` if the client has none.
+pub(crate) fn request_json<Fnc: RFunction>(fnc: Fnc, generator: Option<&Arc<dyn ExtraGenerator>>) -> RTDResult<(String, Extra)> {
+  if let Some(extra) = fnc.extra() {
+    return Ok((fnc.to_json()?, extra));
+  }
+  let extra = Extra::from(match generator {
+    Some(generator) => generator.extra(fnc.td_name()),
+    None => next_extra(fnc.td_name()),
+  });
+  Ok((fnc.to_json_with_extra(&extra)?, extra))
+}
@@ -1,11 +1,14 @@
 
 use std::{io, fmt, error};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub enum RTDError {
   Io(io::Error),
   SerdeJson(serde_json::Error),
   Custom(String),
+  Tdlib(crate::types::Error),
+  Timeout(Duration),
 }
 
 pub type RTDResult<T> = Result<T, RTDError>;
@@ -20,6 +23,8 @@ impl fmt::Display for RTDError {
       RTDError::Io(ref err) => write!(f, "IO error: {}", err),
       RTDError::SerdeJson(ref err) => write!(f, "Serde json error: {}", err),
       RTDError::Custom(msg) => write!(f, "{}", msg),
+      RTDError::Tdlib(err) => write!(f, "Tdlib error {}: {}", err.code(), err.message()),
+      RTDError::Timeout(timeout) => write!(f, "No tdlib reply within {:?}", timeout),
     }
   }
 }
@@ -29,7 +34,9 @@ impl error::Error for RTDError {
     match *self {
       RTDError::Io(ref err) => Some(err),
       RTDError::SerdeJson(ref err) => Some(err),
-      RTDError::Custom(_) => None
+      RTDError::Custom(_) => None,
+      RTDError::Tdlib(_) => None,
+      RTDError::Timeout(_) => None,
     }
   }
 }
@@ -46,4 +53,3 @@ impl From<serde_json::Error> for RTDError {
   }
 }
 
-
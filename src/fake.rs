@@ -0,0 +1,314 @@
+//! In-memory fake tdlib for offline tests. `FakeTdlib` is a `TdTransport` answering from a seeded
+//! model of the current user, chats and messages, so clients run end-to-end without tdjson or network.
+//!
+//! Supported: the authorization flow from `authorizationStateWaitTdlibParameters` to
+//! `authorizationStateReady` (by code or bot token) and on to `authorizationStateClosed` by `close`,
+//! `getAuthorizationState`, `getMe`, `getChat`, `getChatHistory` and text `sendMessage`. Other
+//! functions are answered with an `error`.
+//!
+//! Behind the `fake` feature, meant for dev-dependencies.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::errors::*;
+use crate::transport::TdTransport;
+use crate::types::*;
+
+/// Message identifiers of sent messages are multiples of this, like tdlib server message identifiers
+const MESSAGE_ID_STEP: i64 = 1 << 20;
+
+struct State {
+  authorization: AuthorizationState,
+  parameters: Option<TdlibParameters>,
+  me: User,
+  chats: BTreeMap<ChatId, Chat>,
+  /// chat id -> message id -> message
+  messages: BTreeMap<ChatId, BTreeMap<MessageId, Message>>,
+}
+
+/// Fake tdlib, see the module documentation for what it answers
+///
+/// ```
+/// use std::time::Duration;
+/// use rtdlib::client::BlockingClient;
+/// use rtdlib::fake::FakeTdlib;
+/// use rtdlib::types::*;
+///
+/// let me = User::builder().id(UserId::from(1)).first_name("Alice")
+///   .status(UserStatus::Empty(UserStatusEmpty::builder().build()))
+///   .type_(UserType::Regular(UserTypeRegular::builder().build()))
+///   .build();
+/// let mut client = BlockingClient::new(FakeTdlib::new(me));
+/// let timeout = Duration::from_secs(1);
+/// client.call(SetTdlibParameters::builder().build(), timeout).unwrap();
+/// client.call(CheckDatabaseEncryptionKey::builder().build(), timeout).unwrap();
+/// client.call(CheckAuthenticationBotToken::builder().token("token").build(), timeout).unwrap();
+/// assert_eq!(client.call(GetMe::builder().build(), timeout).unwrap().first_name(), "Alice");
+/// ```
+pub struct FakeTdlib {
+  state: Mutex<State>,
+  outbox: Mutex<VecDeque<String>>,
+  outbox_ready: Condvar,
+}
+
+impl FakeTdlib {
+  /// Create a fake logged in as `me` once authorized, starting in `authorizationStateWaitTdlibParameters`
+  pub fn new(me: User) -> Self {
+    let fake = Self {
+      state: Mutex::new(State {
+        authorization: AuthorizationState::_Default(()),
+        parameters: None,
+        me,
+        chats: BTreeMap::new(),
+        messages: BTreeMap::new(),
+      }),
+      outbox: Mutex::new(VecDeque::new()),
+      outbox_ready: Condvar::new(),
+    };
+    let mut out = vec![];
+    let wait = AuthorizationStateWaitTdlibParameters::builder().build();
+    fake.set_authorization_state(&mut fake.state.lock().unwrap(), AuthorizationState::WaitTdlibParameters(wait), &mut out);
+    fake.deliver(out);
+    fake
+  }
+
+  /// Seed a chat, answered by `getChat`
+  pub fn add_chat<C: AsRef<Chat>>(&self, chat: C) -> &Self {
+    let chat = chat.as_ref().clone();
+    self.state.lock().unwrap().chats.insert(chat.id(), chat);
+    self
+  }
+
+  /// Seed a message of a known chat history, answered by `getChatHistory`
+  pub fn add_message<M: AsRef<Message>>(&self, message: M) -> &Self {
+    let message = message.as_ref().clone();
+    self.state.lock().unwrap().messages.entry(message.chat_id()).or_default().insert(message.id(), message);
+    self
+  }
+
+  /// Deliver an incoming message, it's stored in the chat history and sent as `updateNewMessage`
+  pub fn receive_message<M: AsRef<Message>>(&self, message: M) {
+    let message = message.as_ref().clone();
+    self.add_message(&message);
+    let update = UpdateNewMessage::builder().message(message).build();
+    let out = to_json(&update, None).into_iter().collect();
+    self.deliver(out);
+  }
+
+  /// The current authorization state
+  pub fn authorization_state(&self) -> AuthorizationState {
+    self.state.lock().unwrap().authorization.clone()
+  }
+
+  /// The parameters of `setTdlibParameters`, once it was called
+  pub fn parameters(&self) -> Option<TdlibParameters> {
+    self.state.lock().unwrap().parameters.clone()
+  }
+
+  fn deliver(&self, out: Vec<String>) {
+    self.outbox.lock().unwrap().extend(out);
+    self.outbox_ready.notify_all();
+  }
+
+  fn set_authorization_state(&self, state: &mut State, authorization: AuthorizationState, out: &mut Vec<String>) {
+    state.authorization = authorization.clone();
+    let update = UpdateAuthorizationState::builder().authorization_state(authorization).build();
+    out.extend(to_json(&update, None));
+  }
+
+  /// Handle a request, pushing the reply and any updates it causes to `out`
+  fn handle(&self, state: &mut State, td_type: &str, request: &Value, extra: Option<&Value>, out: &mut Vec<String>) -> RTDResult<()> {
+    let authorization = &state.authorization;
+    let expected = match td_type {
+      "getAuthorizationState" => true,
+      "close" => !authorization.is_closed(),
+      "setTdlibParameters" => authorization.is_wait_tdlib_parameters(),
+      "checkDatabaseEncryptionKey" => authorization.is_wait_encryption_key(),
+      "setAuthenticationPhoneNumber" | "checkAuthenticationBotToken" => authorization.is_wait_phone_number(),
+      "checkAuthenticationCode" => authorization.is_wait_code(),
+      _ if authorization.is_ready() => true,
+      _ => return reply_error(401, "Unauthorized", extra, out),
+    };
+    if !expected {
+      return reply_error(400, &format!("Call to {} unexpected", td_type), extra, out);
+    }
+
+    match td_type {
+      "getAuthorizationState" => out.extend(to_json(&state.authorization, extra)),
+      "setTdlibParameters" => {
+        state.parameters = Some(TdlibParameters::deserialize(&request["parameters"])?);
+        let wait = AuthorizationStateWaitEncryptionKey::builder().is_encrypted(false).build();
+        self.set_authorization_state(state, AuthorizationState::WaitEncryptionKey(wait), out);
+        reply_ok(extra, out);
+      }
+      "checkDatabaseEncryptionKey" => {
+        let wait = AuthorizationStateWaitPhoneNumber::builder().build();
+        self.set_authorization_state(state, AuthorizationState::WaitPhoneNumber(wait), out);
+        reply_ok(extra, out);
+      }
+      "setAuthenticationPhoneNumber" => {
+        let code_info = AuthenticationCodeInfo::builder()
+          .phone_number(request["phone_number"].as_str().unwrap_or_default())
+          .type_(AuthenticationCodeType::Sms(AuthenticationCodeTypeSms::builder().length(5).build()))
+          .build();
+        let wait = AuthorizationStateWaitCode::builder().code_info(code_info).build();
+        self.set_authorization_state(state, AuthorizationState::WaitCode(wait), out);
+        reply_ok(extra, out);
+      }
+      "checkAuthenticationCode" | "checkAuthenticationBotToken" => {
+        let ready = AuthorizationStateReady::builder().build();
+        self.set_authorization_state(state, AuthorizationState::Ready(ready), out);
+        reply_ok(extra, out);
+      }
+      "close" => {
+        let closing = AuthorizationStateClosing::builder().build();
+        self.set_authorization_state(state, AuthorizationState::Closing(closing), out);
+        reply_ok(extra, out);
+        let closed = AuthorizationStateClosed::builder().build();
+        self.set_authorization_state(state, AuthorizationState::Closed(closed), out);
+      }
+      "getMe" => out.extend(to_json(&state.me, extra)),
+      "getChat" => {
+        match state.chats.get(&typed(int(&request["chat_id"]))) {
+          Some(chat) => out.extend(to_json(chat, extra)),
+          None => return reply_error(400, "Chat not found", extra, out),
+        }
+      }
+      "getChatHistory" => {
+        let chat_id: ChatId = typed(int(&request["chat_id"]));
+        if !state.chats.contains_key(&chat_id) {
+          return reply_error(400, "Chat not found", extra, out);
+        }
+        let history: Vec<&Message> = state.messages.get(&chat_id)
+          .map(|messages| messages.values().rev().collect())
+          .unwrap_or_default();
+        let from = match int(&request["from_message_id"]) {
+          0 => 0,
+          from_message_id => history.iter().position(|message| untyped(message.id()) <= from_message_id).unwrap_or(history.len()),
+        };
+        let start = (from as i64 + int(&request["offset"])).max(0) as usize;
+        let total_count = history.len() as i64;
+        let messages: Vec<Option<Message>> = history.into_iter()
+          .skip(start)
+          .take(int(&request["limit"]).max(0) as usize)
+          .map(|message| Some(message.clone()))
+          .collect();
+        let messages = Messages::builder().total_count(total_count).messages(messages).build();
+        out.extend(to_json(&messages, extra));
+      }
+      "sendMessage" => {
+        let chat_id: ChatId = typed(int(&request["chat_id"]));
+        if !state.chats.contains_key(&chat_id) {
+          return reply_error(400, "Chat not found", extra, out);
+        }
+        let text = match InputMessageContent::deserialize(&request["input_message_content"]) {
+          Ok(InputMessageContent::InputMessageText(text)) => text.text().clone(),
+          _ => return reply_error(400, "Fake tdlib only sends text messages", extra, out),
+        };
+        self.send_message(state, chat_id, text, extra, out)?;
+      }
+      _ => return reply_error(400, &format!("Fake tdlib doesn't support {}", td_type), extra, out),
+    }
+    Ok(())
+  }
+
+  /// Answer `sendMessage` like tdlib: `updateNewMessage` and the reply with a pending message
+  /// under a temporary identifier, then `updateMessageSendSucceeded` with the sent message
+  fn send_message(&self, state: &mut State, chat_id: ChatId, text: FormattedText, extra: Option<&Value>, out: &mut Vec<String>) -> RTDResult<()> {
+    let history = state.messages.entry(chat_id).or_default();
+    let last_id = history.keys().next_back().copied().map(untyped).unwrap_or(0);
+    let message_id = (last_id / MESSAGE_ID_STEP + 1) * MESSAGE_ID_STEP;
+    let temporary_id = typed(last_id + 1);
+
+    let mut builder = Message::builder();
+    builder
+      .id(temporary_id)
+      .sender(MessageSender::User(MessageSenderUser::builder().user_id(state.me.id()).build()))
+      .chat_id(chat_id)
+      .sending_state(MessageSendingState::Pending(MessageSendingStatePending::builder().build()))
+      .is_outgoing(true)
+      .can_be_edited(true)
+      .can_be_forwarded(true)
+      .can_be_deleted_only_for_self(true)
+      .can_be_deleted_for_all_users(true)
+      .date(now())
+      .content(MessageContent::MessageText(MessageText::builder().text(text).build()));
+    let pending = builder.build();
+    out.extend(to_json(&UpdateNewMessage::builder().message(&pending).build(), None));
+    out.extend(to_json(&pending, extra));
+
+    let mut sent: Value = serde_json::from_str(&pending.to_json()?)?;
+    sent["id"] = Value::from(message_id);
+    sent["sending_state"] = Value::Null;
+    let sent = Message::from_json(sent.to_string())?;
+    history.insert(typed(message_id), sent.clone());
+    let update = UpdateMessageSendSucceeded::builder().message(sent).old_message_id(temporary_id).build();
+    out.extend(to_json(&update, None));
+    Ok(())
+  }
+}
+
+impl TdTransport for FakeTdlib {
+  fn send(&self, request: &str) -> RTDResult<()> {
+    let value: Value = serde_json::from_str(request)?;
+    let extra = value.get("@extra");
+    let td_type = value.get("@type").and_then(|t| t.as_str())
+      .ok_or_else(|| RTDError::custom("request without @type".to_string()))?;
+    let mut out = vec![];
+    let handled = self.handle(&mut self.state.lock().unwrap(), td_type, &value, extra, &mut out);
+    if let Err(err) = handled {
+      out.clear();
+      reply_error(400, &err.to_string(), extra, &mut out)?;
+    }
+    self.deliver(out);
+    Ok(())
+  }
+
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    let deadline = Instant::now() + timeout;
+    let mut outbox = self.outbox.lock().unwrap();
+    loop {
+      if let Some(json) = outbox.pop_front() {
+        return Some(json);
+      }
+      let remaining = deadline.checked_duration_since(Instant::now())?;
+      outbox = self.outbox_ready.wait_timeout(outbox, remaining).unwrap().0;
+    }
+  }
+
+  fn execute(&self, _request: &str) -> Option<String> { None }
+}
+
+/// Serialize a td type as tdlib sends it: replies carry the request `@extra`, updates carry none
+fn to_json<T: RObject>(object: &T, extra: Option<&Value>) -> Option<String> {
+  let mut value: Value = serde_json::from_str(&object.to_json().ok()?).ok()?;
+  let map = value.as_object_mut()?;
+  match extra {
+    Some(extra) => map.insert("@extra".to_string(), extra.clone()),
+    None => map.remove("@extra"),
+  };
+  Some(value.to_string())
+}
+
+fn reply_ok(extra: Option<&Value>, out: &mut Vec<String>) {
+  out.extend(to_json(&Ok::builder().build(), extra));
+}
+
+fn reply_error(code: i64, message: &str, extra: Option<&Value>, out: &mut Vec<String>) -> RTDResult<()> {
+  out.extend(to_json(&Error::builder().code(code).message(message).build(), extra));
+  Ok(())
+}
+
+/// Read an integer request field, tdlib accepts int64 both as number and string
+fn int(value: &Value) -> i64 {
+  value.as_i64().or_else(|| value.as_str().and_then(|s| s.parse().ok())).unwrap_or(0)
+}
+
+fn now() -> i64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs() as i64).unwrap_or(0)
+}
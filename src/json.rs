@@ -0,0 +1,44 @@
+//! The json parsers decoding goes through. `from_json`, `from_json_lenient`, `parse_response`,
+//! `detect_td_type_and_extra`, the `from_json` of every type and the clients parse with `DefaultJson`:
+//! `SerdeJson`, or `SimdJson` with the `simd-json` feature. Both decode the same json to the same values.
+
+use serde::de::DeserializeOwned;
+
+use crate::errors::*;
+
+/// A json parser, decoding strictly to any td type
+pub trait JsonBackend {
+  fn from_str<T: DeserializeOwned>(json: &str) -> RTDResult<T>;
+}
+
+/// The parser the crate decodes with, `SimdJson` with the `simd-json` feature
+#[cfg(not(feature = "simd-json"))]
+pub type DefaultJson = SerdeJson;
+/// The parser the crate decodes with, `SimdJson` with the `simd-json` feature
+#[cfg(feature = "simd-json")]
+pub type DefaultJson = SimdJson;
+
+/// `serde_json`
+#[derive(Debug, Clone, Copy)]
+pub struct SerdeJson;
+
+impl JsonBackend for SerdeJson {
+  fn from_str<T: DeserializeOwned>(json: &str) -> RTDResult<T> {
+    Ok(serde_json::from_str(json)?)
+  }
+}
+
+/// `simd-json`, parsing with the SIMD instructions the cpu has
+#[cfg(feature = "simd-json")]
+#[derive(Debug, Clone, Copy)]
+pub struct SimdJson;
+
+#[cfg(feature = "simd-json")]
+impl JsonBackend for SimdJson {
+  fn from_str<T: DeserializeOwned>(json: &str) -> RTDResult<T> {
+    // simd-json parses in place, the json is borrowed so it parses a copy
+    let mut json = json.as_bytes().to_vec();
+    // a `Custom` error, so that the feature doesn't add a variant to `RTDError`
+    simd_json::serde::from_slice(&mut json).map_err(|err| RTDError::custom(format!("Simd json error: {}", err)))
+  }
+}
@@ -4,5 +4,19 @@ extern crate serde_derive;
 #[cfg(feature = "sys")]
 pub use rtdlib_sys::Tdlib;
 
+// exactly one version, a `compile_error!` per pair of them once there are several, checked by codegen/tests
+#[cfg(not(any(feature = "td-1-7")))]
+compile_error!("select the tdlib version of the types with one of the features `td-1-7`");
+
+/// The tdlib version the types and `schema` are generated for, selected by the `td-*` feature
+#[cfg(feature = "td-1-7")]
+pub const TDLIB_VERSION: &str = "1.7";
+
 pub mod types;
+pub mod schema;
 pub mod errors;
+pub mod json;
+pub mod transport;
+pub mod client;
+#[cfg(feature = "fake")]
+pub mod fake;
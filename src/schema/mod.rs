@@ -0,0 +1,101 @@
+//! The tdlib schema the types were generated from, `schema/<version>/td_api.tl` of the version
+//! selected by the `td-*` feature.
+//!
+//! Constructors and functions are looked up by the name tdlib uses in `@type`, the same
+//! `td_name()` an `RObject` returns, so tools can work with any object without knowing its type.
+//!
+//! ```
+//! use rtdlib::schema;
+//! use rtdlib::types::{GetMe, RObject};
+//!
+//! let get_me = schema::function(GetMe::builder().build().td_name()).unwrap();
+//! assert_eq!(get_me.result, "User");
+//!
+//! let send_message = schema::function("sendMessage").unwrap();
+//! assert_eq!(send_message.parameter("chat_id").unwrap().type_, "int53");
+//!
+//! let content = schema::class("MessageContent").unwrap();
+//! assert!(content.constructors.contains(&"messageText"));
+//! assert_eq!(schema::constructor("messageText").unwrap().class, "MessageContent");
+//! ```
+
+#[cfg(feature = "td-1-7")]
+mod td_1_7;
+#[cfg(feature = "td-1-7")]
+use self::td_1_7 as td_api;
+
+/// A field of a constructor or a parameter of a function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+  pub name: &'static str,
+  /// The type as written in the schema, e.g. `int53`, `chatPosition` or `vector<MessageSender>`
+  pub type_: &'static str,
+  pub description: &'static str,
+}
+
+impl Parameter {
+  /// Whether the schema says the value may be null
+  pub fn may_be_null(&self) -> bool { self.description.contains("may be null") }
+}
+
+/// A type tdlib sends or takes, like `messageText`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constructor {
+  pub name: &'static str,
+  pub description: &'static str,
+  /// The class the constructor belongs to, e.g. `MessageContent`
+  pub class: &'static str,
+  pub fields: &'static [Parameter],
+}
+
+impl Constructor {
+  pub fn field(&self, name: &str) -> Option<&'static Parameter> {
+    self.fields.iter().find(|field| field.name == name)
+  }
+}
+
+/// A request to tdlib, like `sendMessage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Function {
+  pub name: &'static str,
+  pub description: &'static str,
+  /// The class or type of the response, e.g. `Message`
+  pub result: &'static str,
+  pub parameters: &'static [Parameter],
+}
+
+impl Function {
+  pub fn parameter(&self, name: &str) -> Option<&'static Parameter> {
+    self.parameters.iter().find(|parameter| parameter.name == name)
+  }
+}
+
+/// A class with several constructors, an enum in `rtdlib::types`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Class {
+  pub name: &'static str,
+  pub description: &'static str,
+  /// Names of the constructors of the class
+  pub constructors: &'static [&'static str],
+}
+
+/// All classes, sorted by name
+pub fn classes() -> &'static [Class] { td_api::CLASSES }
+
+/// All constructors, sorted by name
+pub fn constructors() -> &'static [Constructor] { td_api::CONSTRUCTORS }
+
+/// All functions, sorted by name
+pub fn functions() -> &'static [Function] { td_api::FUNCTIONS }
+
+pub fn class(name: &str) -> Option<&'static Class> {
+  td_api::CLASSES.binary_search_by(|class| class.name.cmp(name)).ok().map(|index| &td_api::CLASSES[index])
+}
+
+pub fn constructor(td_name: &str) -> Option<&'static Constructor> {
+  td_api::CONSTRUCTORS.binary_search_by(|constructor| constructor.name.cmp(td_name)).ok().map(|index| &td_api::CONSTRUCTORS[index])
+}
+
+pub fn function(td_name: &str) -> Option<&'static Function> {
+  td_api::FUNCTIONS.binary_search_by(|function| function.name.cmp(td_name)).ok().map(|index| &td_api::FUNCTIONS[index])
+}
@@ -0,0 +1,5518 @@
+use super::{Class, Constructor, Function, Parameter};
+
+pub(super) static CLASSES: &[Class] = &[
+  Class { name: "AuthenticationCodeType", description: "Provides information about the method by which an authentication code is delivered to the user", constructors: &["authenticationCodeTypeCall", "authenticationCodeTypeFlashCall", "authenticationCodeTypeSms", "authenticationCodeTypeTelegramMessage"] },
+  Class { name: "AuthorizationState", description: "Represents the current authorization state of the TDLib client", constructors: &["authorizationStateClosed", "authorizationStateClosing", "authorizationStateLoggingOut", "authorizationStateReady", "authorizationStateWaitCode", "authorizationStateWaitEncryptionKey", "authorizationStateWaitOtherDeviceConfirmation", "authorizationStateWaitPassword", "authorizationStateWaitPhoneNumber", "authorizationStateWaitRegistration", "authorizationStateWaitTdlibParameters"] },
+  Class { name: "BackgroundFill", description: "Describes a fill of a background", constructors: &["backgroundFillFreeformGradient", "backgroundFillGradient", "backgroundFillSolid"] },
+  Class { name: "BackgroundType", description: "Describes the type of a background", constructors: &["backgroundTypeFill", "backgroundTypePattern", "backgroundTypeWallpaper"] },
+  Class { name: "BotCommandScope", description: "Represents the scope to which bot commands are relevant", constructors: &["botCommandScopeAllChatAdministrators", "botCommandScopeAllGroupChats", "botCommandScopeAllPrivateChats", "botCommandScopeChat", "botCommandScopeChatAdministrators", "botCommandScopeChatMember", "botCommandScopeDefault"] },
+  Class { name: "CallDiscardReason", description: "Describes the reason why a call was discarded", constructors: &["callDiscardReasonDeclined", "callDiscardReasonDisconnected", "callDiscardReasonEmpty", "callDiscardReasonHungUp", "callDiscardReasonMissed"] },
+  Class { name: "CallProblem", description: "Describes the exact type of a problem with a call", constructors: &["callProblemDistortedSpeech", "callProblemDistortedVideo", "callProblemDropped", "callProblemEcho", "callProblemInterruptions", "callProblemNoise", "callProblemPixelatedVideo", "callProblemSilentLocal", "callProblemSilentRemote"] },
+  Class { name: "CallServerType", description: "Describes the type of a call server", constructors: &["callServerTypeTelegramReflector", "callServerTypeWebrtc"] },
+  Class { name: "CallState", description: "Describes the current call state", constructors: &["callStateDiscarded", "callStateError", "callStateExchangingKeys", "callStateHangingUp", "callStatePending", "callStateReady"] },
+  Class { name: "CallbackQueryPayload", description: "Represents a payload of a callback query", constructors: &["callbackQueryPayloadData", "callbackQueryPayloadDataWithPassword", "callbackQueryPayloadGame"] },
+  Class { name: "CanTransferOwnershipResult", description: "Represents result of checking whether the current session can be used to transfer a chat ownership to another user", constructors: &["canTransferOwnershipResultOk", "canTransferOwnershipResultPasswordNeeded", "canTransferOwnershipResultPasswordTooFresh", "canTransferOwnershipResultSessionTooFresh"] },
+  Class { name: "ChatAction", description: "Describes the different types of activity in a chat", constructors: &["chatActionCancel", "chatActionChoosingContact", "chatActionChoosingLocation", "chatActionChoosingSticker", "chatActionRecordingVideo", "chatActionRecordingVideoNote", "chatActionRecordingVoiceNote", "chatActionStartPlayingGame", "chatActionTyping", "chatActionUploadingDocument", "chatActionUploadingPhoto", "chatActionUploadingVideo", "chatActionUploadingVideoNote", "chatActionUploadingVoiceNote", "chatActionWatchingAnimations"] },
+  Class { name: "ChatActionBar", description: "Describes actions which must be possible to do through a chat action bar", constructors: &["chatActionBarAddContact", "chatActionBarInviteMembers", "chatActionBarReportAddBlock", "chatActionBarReportSpam", "chatActionBarReportUnrelatedLocation", "chatActionBarSharePhoneNumber"] },
+  Class { name: "ChatEventAction", description: "Represents a chat event", constructors: &["chatEventDescriptionChanged", "chatEventInviteLinkDeleted", "chatEventInviteLinkEdited", "chatEventInviteLinkRevoked", "chatEventInvitesToggled", "chatEventIsAllHistoryAvailableToggled", "chatEventLinkedChatChanged", "chatEventLocationChanged", "chatEventMemberInvited", "chatEventMemberJoined", "chatEventMemberJoinedByInviteLink", "chatEventMemberJoinedByRequest", "chatEventMemberLeft", "chatEventMemberPromoted", "chatEventMemberRestricted", "chatEventMessageDeleted", "chatEventMessageEdited", "chatEventMessagePinned", "chatEventMessageTtlSettingChanged", "chatEventMessageUnpinned", "chatEventPermissionsChanged", "chatEventPhotoChanged", "chatEventPollStopped", "chatEventSignMessagesToggled", "chatEventSlowModeDelayChanged", "chatEventStickerSetChanged", "chatEventTitleChanged", "chatEventUsernameChanged", "chatEventVideoChatCreated", "chatEventVideoChatDiscarded", "chatEventVideoChatMuteNewParticipantsToggled", "chatEventVideoChatParticipantIsMutedToggled", "chatEventVideoChatParticipantVolumeLevelChanged"] },
+  Class { name: "ChatList", description: "Describes a list of chats", constructors: &["chatListArchive", "chatListFilter", "chatListMain"] },
+  Class { name: "ChatMemberStatus", description: "Provides information about the status of a member in a chat", constructors: &["chatMemberStatusAdministrator", "chatMemberStatusBanned", "chatMemberStatusCreator", "chatMemberStatusLeft", "chatMemberStatusMember", "chatMemberStatusRestricted"] },
+  Class { name: "ChatMembersFilter", description: "Specifies the kind of chat members to return in searchChatMembers", constructors: &["chatMembersFilterAdministrators", "chatMembersFilterBanned", "chatMembersFilterBots", "chatMembersFilterContacts", "chatMembersFilterMembers", "chatMembersFilterMention", "chatMembersFilterRestricted"] },
+  Class { name: "ChatReportReason", description: "Describes the reason why a chat is reported", constructors: &["chatReportReasonChildAbuse", "chatReportReasonCopyright", "chatReportReasonCustom", "chatReportReasonFake", "chatReportReasonPornography", "chatReportReasonSpam", "chatReportReasonUnrelatedLocation", "chatReportReasonViolence"] },
+  Class { name: "ChatSource", description: "Describes a reason why an external chat is shown in a chat list", constructors: &["chatSourceMtprotoProxy", "chatSourcePublicServiceAnnouncement"] },
+  Class { name: "ChatStatistics", description: "Contains a detailed statistics about a chat", constructors: &["chatStatisticsChannel", "chatStatisticsSupergroup"] },
+  Class { name: "ChatType", description: "Describes the type of a chat", constructors: &["chatTypeBasicGroup", "chatTypePrivate", "chatTypeSecret", "chatTypeSupergroup"] },
+  Class { name: "CheckChatUsernameResult", description: "Represents result of checking whether a username can be set for a chat", constructors: &["checkChatUsernameResultOk", "checkChatUsernameResultPublicChatsTooMuch", "checkChatUsernameResultPublicGroupsUnavailable", "checkChatUsernameResultUsernameInvalid", "checkChatUsernameResultUsernameOccupied"] },
+  Class { name: "CheckStickerSetNameResult", description: "Represents result of checking whether a name can be used for a new sticker set", constructors: &["checkStickerSetNameResultNameInvalid", "checkStickerSetNameResultNameOccupied", "checkStickerSetNameResultOk"] },
+  Class { name: "ConnectionState", description: "Describes the current state of the connection to Telegram servers", constructors: &["connectionStateConnecting", "connectionStateConnectingToProxy", "connectionStateReady", "connectionStateUpdating", "connectionStateWaitingForNetwork"] },
+  Class { name: "DeviceToken", description: "Represents a data needed to subscribe for push notifications through registerDevice method. To use specific push notification service, the correct application platform must be specified and a valid server authentication data must be uploaded at https://my.telegram.org", constructors: &["deviceTokenApplePush", "deviceTokenApplePushVoIP", "deviceTokenBlackBerryPush", "deviceTokenFirebaseCloudMessaging", "deviceTokenMicrosoftPush", "deviceTokenMicrosoftPushVoIP", "deviceTokenSimplePush", "deviceTokenTizenPush", "deviceTokenUbuntuPush", "deviceTokenWebPush", "deviceTokenWindowsPush"] },
+  Class { name: "DiceStickers", description: "Contains animated stickers which must be used for dice animation rendering", constructors: &["diceStickersRegular", "diceStickersSlotMachine"] },
+  Class { name: "FileType", description: "Represents the type of a file", constructors: &["fileTypeAnimation", "fileTypeAudio", "fileTypeDocument", "fileTypeNone", "fileTypePhoto", "fileTypeProfilePhoto", "fileTypeSecret", "fileTypeSecretThumbnail", "fileTypeSecure", "fileTypeSticker", "fileTypeThumbnail", "fileTypeUnknown", "fileTypeVideo", "fileTypeVideoNote", "fileTypeVoiceNote", "fileTypeWallpaper"] },
+  Class { name: "GroupCallVideoQuality", description: "Describes the quality of a group call video", constructors: &["groupCallVideoQualityFull", "groupCallVideoQualityMedium", "groupCallVideoQualityThumbnail"] },
+  Class { name: "InlineKeyboardButtonType", description: "Describes the type of an inline keyboard button", constructors: &["inlineKeyboardButtonTypeBuy", "inlineKeyboardButtonTypeCallback", "inlineKeyboardButtonTypeCallbackGame", "inlineKeyboardButtonTypeCallbackWithPassword", "inlineKeyboardButtonTypeLoginUrl", "inlineKeyboardButtonTypeSwitchInline", "inlineKeyboardButtonTypeUrl"] },
+  Class { name: "InlineQueryResult", description: "Represents a single result of an inline query", constructors: &["inlineQueryResultAnimation", "inlineQueryResultArticle", "inlineQueryResultAudio", "inlineQueryResultContact", "inlineQueryResultDocument", "inlineQueryResultGame", "inlineQueryResultLocation", "inlineQueryResultPhoto", "inlineQueryResultSticker", "inlineQueryResultVenue", "inlineQueryResultVideo", "inlineQueryResultVoiceNote"] },
+  Class { name: "InputBackground", description: "Contains information about background to set", constructors: &["inputBackgroundLocal", "inputBackgroundRemote"] },
+  Class { name: "InputChatPhoto", description: "Describes a photo to be set as a user profile or chat photo", constructors: &["inputChatPhotoAnimation", "inputChatPhotoPrevious", "inputChatPhotoStatic"] },
+  Class { name: "InputCredentials", description: "Contains information about the payment method chosen by the user", constructors: &["inputCredentialsApplePay", "inputCredentialsGooglePay", "inputCredentialsNew", "inputCredentialsSaved"] },
+  Class { name: "InputFile", description: "Points to a file", constructors: &["inputFileGenerated", "inputFileId", "inputFileLocal", "inputFileRemote"] },
+  Class { name: "InputInlineQueryResult", description: "Represents a single result of an inline query; for bots only", constructors: &["inputInlineQueryResultAnimation", "inputInlineQueryResultArticle", "inputInlineQueryResultAudio", "inputInlineQueryResultContact", "inputInlineQueryResultDocument", "inputInlineQueryResultGame", "inputInlineQueryResultLocation", "inputInlineQueryResultPhoto", "inputInlineQueryResultSticker", "inputInlineQueryResultVenue", "inputInlineQueryResultVideo", "inputInlineQueryResultVoiceNote"] },
+  Class { name: "InputMessageContent", description: "The content of a message to send", constructors: &["inputMessageAnimation", "inputMessageAudio", "inputMessageContact", "inputMessageDice", "inputMessageDocument", "inputMessageForwarded", "inputMessageGame", "inputMessageInvoice", "inputMessageLocation", "inputMessagePhoto", "inputMessagePoll", "inputMessageSticker", "inputMessageText", "inputMessageVenue", "inputMessageVideo", "inputMessageVideoNote", "inputMessageVoiceNote"] },
+  Class { name: "InputPassportElement", description: "Contains information about a Telegram Passport element to be saved", constructors: &["inputPassportElementAddress", "inputPassportElementBankStatement", "inputPassportElementDriverLicense", "inputPassportElementEmailAddress", "inputPassportElementIdentityCard", "inputPassportElementInternalPassport", "inputPassportElementPassport", "inputPassportElementPassportRegistration", "inputPassportElementPersonalDetails", "inputPassportElementPhoneNumber", "inputPassportElementRentalAgreement", "inputPassportElementTemporaryRegistration", "inputPassportElementUtilityBill"] },
+  Class { name: "InputPassportElementErrorSource", description: "Contains the description of an error in a Telegram Passport element; for bots only", constructors: &["inputPassportElementErrorSourceDataField", "inputPassportElementErrorSourceFile", "inputPassportElementErrorSourceFiles", "inputPassportElementErrorSourceFrontSide", "inputPassportElementErrorSourceReverseSide", "inputPassportElementErrorSourceSelfie", "inputPassportElementErrorSourceTranslationFile", "inputPassportElementErrorSourceTranslationFiles", "inputPassportElementErrorSourceUnspecified"] },
+  Class { name: "InputSticker", description: "Describes a sticker that needs to be added to a sticker set", constructors: &["inputStickerAnimated", "inputStickerStatic"] },
+  Class { name: "InternalLinkType", description: "Describes an internal https://t.me or tg: link, which must be processed by the app in a special way", constructors: &["internalLinkTypeActiveSessions", "internalLinkTypeAuthenticationCode", "internalLinkTypeBackground", "internalLinkTypeBotStart", "internalLinkTypeBotStartInGroup", "internalLinkTypeChangePhoneNumber", "internalLinkTypeChatInvite", "internalLinkTypeFilterSettings", "internalLinkTypeGame", "internalLinkTypeLanguagePack", "internalLinkTypeMessage", "internalLinkTypeMessageDraft", "internalLinkTypePassportDataRequest", "internalLinkTypePhoneNumberConfirmation", "internalLinkTypeProxy", "internalLinkTypePublicChat", "internalLinkTypeQrCodeAuthentication", "internalLinkTypeSettings", "internalLinkTypeStickerSet", "internalLinkTypeTheme", "internalLinkTypeThemeSettings", "internalLinkTypeUnknownDeepLink", "internalLinkTypeUnsupportedProxy", "internalLinkTypeVideoChat"] },
+  Class { name: "JsonValue", description: "Represents a JSON value", constructors: &["jsonValueArray", "jsonValueBoolean", "jsonValueNull", "jsonValueNumber", "jsonValueObject", "jsonValueString"] },
+  Class { name: "KeyboardButtonType", description: "Describes a keyboard button type", constructors: &["keyboardButtonTypeRequestLocation", "keyboardButtonTypeRequestPhoneNumber", "keyboardButtonTypeRequestPoll", "keyboardButtonTypeText"] },
+  Class { name: "LanguagePackStringValue", description: "Represents the value of a string in a language pack", constructors: &["languagePackStringValueDeleted", "languagePackStringValueOrdinary", "languagePackStringValuePluralized"] },
+  Class { name: "LogStream", description: "Describes a stream to which TDLib internal log is written", constructors: &["logStreamDefault", "logStreamEmpty", "logStreamFile"] },
+  Class { name: "LoginUrlInfo", description: "Contains information about an inline button of type inlineKeyboardButtonTypeLoginUrl", constructors: &["loginUrlInfoOpen", "loginUrlInfoRequestConfirmation"] },
+  Class { name: "MaskPoint", description: "Part of the face, relative to which a mask is placed", constructors: &["maskPointChin", "maskPointEyes", "maskPointForehead", "maskPointMouth"] },
+  Class { name: "MessageContent", description: "Contains the content of a message", constructors: &["messageAnimatedEmoji", "messageAnimation", "messageAudio", "messageBasicGroupChatCreate", "messageCall", "messageChatAddMembers", "messageChatChangePhoto", "messageChatChangeTitle", "messageChatDeleteMember", "messageChatDeletePhoto", "messageChatJoinByLink", "messageChatJoinByRequest", "messageChatSetTheme", "messageChatSetTtl", "messageChatUpgradeFrom", "messageChatUpgradeTo", "messageContact", "messageContactRegistered", "messageCustomServiceAction", "messageDice", "messageDocument", "messageExpiredPhoto", "messageExpiredVideo", "messageGame", "messageGameScore", "messageInviteVideoChatParticipants", "messageInvoice", "messageLocation", "messagePassportDataReceived", "messagePassportDataSent", "messagePaymentSuccessful", "messagePaymentSuccessfulBot", "messagePhoto", "messagePinMessage", "messagePoll", "messageProximityAlertTriggered", "messageScreenshotTaken", "messageSticker", "messageSupergroupChatCreate", "messageText", "messageUnsupported", "messageVenue", "messageVideo", "messageVideoChatEnded", "messageVideoChatScheduled", "messageVideoChatStarted", "messageVideoNote", "messageVoiceNote", "messageWebsiteConnected"] },
+  Class { name: "MessageFileType", description: "Contains information about a file with messages exported from another app", constructors: &["messageFileTypeGroup", "messageFileTypePrivate", "messageFileTypeUnknown"] },
+  Class { name: "MessageForwardOrigin", description: "Contains information about the origin of a forwarded message", constructors: &["messageForwardOriginChannel", "messageForwardOriginChat", "messageForwardOriginHiddenUser", "messageForwardOriginMessageImport", "messageForwardOriginUser"] },
+  Class { name: "MessageSchedulingState", description: "Contains information about the time when a scheduled message will be sent", constructors: &["messageSchedulingStateSendAtDate", "messageSchedulingStateSendWhenOnline"] },
+  Class { name: "MessageSender", description: "Contains information about the sender of a message", constructors: &["messageSenderChat", "messageSenderUser"] },
+  Class { name: "MessageSendingState", description: "Contains information about the sending state of the message", constructors: &["messageSendingStateFailed", "messageSendingStatePending"] },
+  Class { name: "NetworkStatisticsEntry", description: "Contains statistics about network usage", constructors: &["networkStatisticsEntryCall", "networkStatisticsEntryFile"] },
+  Class { name: "NetworkType", description: "Represents the type of a network", constructors: &["networkTypeMobile", "networkTypeMobileRoaming", "networkTypeNone", "networkTypeOther", "networkTypeWiFi"] },
+  Class { name: "NotificationGroupType", description: "Describes the type of notifications in a notification group", constructors: &["notificationGroupTypeCalls", "notificationGroupTypeMentions", "notificationGroupTypeMessages", "notificationGroupTypeSecretChat"] },
+  Class { name: "NotificationSettingsScope", description: "Describes the types of chats to which notification settings are relevant", constructors: &["notificationSettingsScopeChannelChats", "notificationSettingsScopeGroupChats", "notificationSettingsScopePrivateChats"] },
+  Class { name: "NotificationType", description: "Contains detailed information about a notification", constructors: &["notificationTypeNewCall", "notificationTypeNewMessage", "notificationTypeNewPushMessage", "notificationTypeNewSecretChat"] },
+  Class { name: "OptionValue", description: "Represents the value of an option", constructors: &["optionValueBoolean", "optionValueEmpty", "optionValueInteger", "optionValueString"] },
+  Class { name: "PageBlock", description: "Describes a block of an instant view web page", constructors: &["pageBlockAnchor", "pageBlockAnimation", "pageBlockAudio", "pageBlockAuthorDate", "pageBlockBlockQuote", "pageBlockChatLink", "pageBlockCollage", "pageBlockCover", "pageBlockDetails", "pageBlockDivider", "pageBlockEmbedded", "pageBlockEmbeddedPost", "pageBlockFooter", "pageBlockHeader", "pageBlockKicker", "pageBlockList", "pageBlockMap", "pageBlockParagraph", "pageBlockPhoto", "pageBlockPreformatted", "pageBlockPullQuote", "pageBlockRelatedArticles", "pageBlockSlideshow", "pageBlockSubheader", "pageBlockSubtitle", "pageBlockTable", "pageBlockTitle", "pageBlockVideo", "pageBlockVoiceNote"] },
+  Class { name: "PageBlockHorizontalAlignment", description: "Describes a horizontal alignment of a table cell content", constructors: &["pageBlockHorizontalAlignmentCenter", "pageBlockHorizontalAlignmentLeft", "pageBlockHorizontalAlignmentRight"] },
+  Class { name: "PageBlockVerticalAlignment", description: "Describes a Vertical alignment of a table cell content", constructors: &["pageBlockVerticalAlignmentBottom", "pageBlockVerticalAlignmentMiddle", "pageBlockVerticalAlignmentTop"] },
+  Class { name: "PassportElement", description: "Contains information about a Telegram Passport element", constructors: &["passportElementAddress", "passportElementBankStatement", "passportElementDriverLicense", "passportElementEmailAddress", "passportElementIdentityCard", "passportElementInternalPassport", "passportElementPassport", "passportElementPassportRegistration", "passportElementPersonalDetails", "passportElementPhoneNumber", "passportElementRentalAgreement", "passportElementTemporaryRegistration", "passportElementUtilityBill"] },
+  Class { name: "PassportElementErrorSource", description: "Contains the description of an error in a Telegram Passport element", constructors: &["passportElementErrorSourceDataField", "passportElementErrorSourceFile", "passportElementErrorSourceFiles", "passportElementErrorSourceFrontSide", "passportElementErrorSourceReverseSide", "passportElementErrorSourceSelfie", "passportElementErrorSourceTranslationFile", "passportElementErrorSourceTranslationFiles", "passportElementErrorSourceUnspecified"] },
+  Class { name: "PassportElementType", description: "Contains the type of a Telegram Passport element", constructors: &["passportElementTypeAddress", "passportElementTypeBankStatement", "passportElementTypeDriverLicense", "passportElementTypeEmailAddress", "passportElementTypeIdentityCard", "passportElementTypeInternalPassport", "passportElementTypePassport", "passportElementTypePassportRegistration", "passportElementTypePersonalDetails", "passportElementTypePhoneNumber", "passportElementTypeRentalAgreement", "passportElementTypeTemporaryRegistration", "passportElementTypeUtilityBill"] },
+  Class { name: "PollType", description: "Describes the type of a poll", constructors: &["pollTypeQuiz", "pollTypeRegular"] },
+  Class { name: "ProxyType", description: "Describes the type of a proxy server", constructors: &["proxyTypeHttp", "proxyTypeMtproto", "proxyTypeSocks5"] },
+  Class { name: "PublicChatType", description: "Describes a type of public chats", constructors: &["publicChatTypeHasUsername", "publicChatTypeIsLocationBased"] },
+  Class { name: "PushMessageContent", description: "Contains content of a push message notification", constructors: &["pushMessageContentAnimation", "pushMessageContentAudio", "pushMessageContentBasicGroupChatCreate", "pushMessageContentChatAddMembers", "pushMessageContentChatChangePhoto", "pushMessageContentChatChangeTitle", "pushMessageContentChatDeleteMember", "pushMessageContentChatJoinByLink", "pushMessageContentChatJoinByRequest", "pushMessageContentChatSetTheme", "pushMessageContentContact", "pushMessageContentContactRegistered", "pushMessageContentDocument", "pushMessageContentGame", "pushMessageContentGameScore", "pushMessageContentHidden", "pushMessageContentInvoice", "pushMessageContentLocation", "pushMessageContentMediaAlbum", "pushMessageContentMessageForwards", "pushMessageContentPhoto", "pushMessageContentPoll", "pushMessageContentScreenshotTaken", "pushMessageContentSticker", "pushMessageContentText", "pushMessageContentVideo", "pushMessageContentVideoNote", "pushMessageContentVoiceNote"] },
+  Class { name: "ReplyMarkup", description: "Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots", constructors: &["replyMarkupForceReply", "replyMarkupInlineKeyboard", "replyMarkupRemoveKeyboard", "replyMarkupShowKeyboard"] },
+  Class { name: "ResetPasswordResult", description: "Represents result of 2-step verification password reset", constructors: &["resetPasswordResultDeclined", "resetPasswordResultOk", "resetPasswordResultPending"] },
+  Class { name: "RichText", description: "Describes a text object inside an instant-view web page", constructors: &["richTextAnchor", "richTextAnchorLink", "richTextBold", "richTextEmailAddress", "richTextFixed", "richTextIcon", "richTextItalic", "richTextMarked", "richTextPhoneNumber", "richTextPlain", "richTextReference", "richTextStrikethrough", "richTextSubscript", "richTextSuperscript", "richTextUnderline", "richTextUrl", "richTexts"] },
+  Class { name: "SearchMessagesFilter", description: "Represents a filter for message search results", constructors: &["searchMessagesFilterAnimation", "searchMessagesFilterAudio", "searchMessagesFilterCall", "searchMessagesFilterChatPhoto", "searchMessagesFilterDocument", "searchMessagesFilterEmpty", "searchMessagesFilterFailedToSend", "searchMessagesFilterMention", "searchMessagesFilterMissedCall", "searchMessagesFilterPhoto", "searchMessagesFilterPhotoAndVideo", "searchMessagesFilterPinned", "searchMessagesFilterUnreadMention", "searchMessagesFilterUrl", "searchMessagesFilterVideo", "searchMessagesFilterVideoNote", "searchMessagesFilterVoiceAndVideoNote", "searchMessagesFilterVoiceNote"] },
+  Class { name: "SecretChatState", description: "Describes the current secret chat state", constructors: &["secretChatStateClosed", "secretChatStatePending", "secretChatStateReady"] },
+  Class { name: "StatisticalGraph", description: "Describes a statistical graph", constructors: &["statisticalGraphAsync", "statisticalGraphData", "statisticalGraphError"] },
+  Class { name: "SuggestedAction", description: "Describes an action suggested to the current user", constructors: &["suggestedActionCheckPassword", "suggestedActionCheckPhoneNumber", "suggestedActionConvertToBroadcastGroup", "suggestedActionEnableArchiveAndMuteNewChats", "suggestedActionSeeTicksHint"] },
+  Class { name: "SupergroupMembersFilter", description: "Specifies the kind of chat members to return in getSupergroupMembers", constructors: &["supergroupMembersFilterAdministrators", "supergroupMembersFilterBanned", "supergroupMembersFilterBots", "supergroupMembersFilterContacts", "supergroupMembersFilterMention", "supergroupMembersFilterRecent", "supergroupMembersFilterRestricted", "supergroupMembersFilterSearch"] },
+  Class { name: "TMeUrlType", description: "Describes the type of a URL linking to an internal Telegram entity", constructors: &["tMeUrlTypeChatInvite", "tMeUrlTypeStickerSet", "tMeUrlTypeSupergroup", "tMeUrlTypeUser"] },
+  Class { name: "TextEntityType", description: "Represents a part of the text which must be formatted differently", constructors: &["textEntityTypeBankCardNumber", "textEntityTypeBold", "textEntityTypeBotCommand", "textEntityTypeCashtag", "textEntityTypeCode", "textEntityTypeEmailAddress", "textEntityTypeHashtag", "textEntityTypeItalic", "textEntityTypeMediaTimestamp", "textEntityTypeMention", "textEntityTypeMentionName", "textEntityTypePhoneNumber", "textEntityTypePre", "textEntityTypePreCode", "textEntityTypeStrikethrough", "textEntityTypeTextUrl", "textEntityTypeUnderline", "textEntityTypeUrl"] },
+  Class { name: "TextParseMode", description: "Describes the way the text needs to be parsed for TextEntities", constructors: &["textParseModeHTML", "textParseModeMarkdown"] },
+  Class { name: "ThumbnailFormat", description: "Describes format of the thumbnail", constructors: &["thumbnailFormatGif", "thumbnailFormatJpeg", "thumbnailFormatMpeg4", "thumbnailFormatPng", "thumbnailFormatTgs", "thumbnailFormatWebp"] },
+  Class { name: "TopChatCategory", description: "Represents the categories of chats for which a list of frequently used chats can be retrieved", constructors: &["topChatCategoryBots", "topChatCategoryCalls", "topChatCategoryChannels", "topChatCategoryForwardChats", "topChatCategoryGroups", "topChatCategoryInlineBots", "topChatCategoryUsers"] },
+  Class { name: "Update", description: "Contains notifications about data changes", constructors: &["updateActiveNotifications", "updateAnimatedEmojiMessageClicked", "updateAnimationSearchParameters", "updateAuthorizationState", "updateBasicGroup", "updateBasicGroupFullInfo", "updateCall", "updateChatActionBar", "updateChatDefaultDisableNotification", "updateChatDraftMessage", "updateChatFilters", "updateChatHasScheduledMessages", "updateChatIsBlocked", "updateChatIsMarkedAsUnread", "updateChatLastMessage", "updateChatMember", "updateChatMessageTtlSetting", "updateChatNotificationSettings", "updateChatOnlineMemberCount", "updateChatPendingJoinRequests", "updateChatPermissions", "updateChatPhoto", "updateChatPosition", "updateChatReadInbox", "updateChatReadOutbox", "updateChatReplyMarkup", "updateChatTheme", "updateChatThemes", "updateChatTitle", "updateChatUnreadMentionCount", "updateChatVideoChat", "updateConnectionState", "updateDeleteMessages", "updateDiceEmojis", "updateFavoriteStickers", "updateFile", "updateFileGenerationStart", "updateFileGenerationStop", "updateGroupCall", "updateGroupCallParticipant", "updateHavePendingNotifications", "updateInstalledStickerSets", "updateLanguagePackStrings", "updateMessageContent", "updateMessageContentOpened", "updateMessageEdited", "updateMessageInteractionInfo", "updateMessageIsPinned", "updateMessageLiveLocationViewed", "updateMessageMentionRead", "updateMessageSendAcknowledged", "updateMessageSendFailed", "updateMessageSendSucceeded", "updateNewCallSignalingData", "updateNewCallbackQuery", "updateNewChat", "updateNewChatJoinRequest", "updateNewChosenInlineResult", "updateNewCustomEvent", "updateNewCustomQuery", "updateNewInlineCallbackQuery", "updateNewInlineQuery", "updateNewMessage", "updateNewPreCheckoutQuery", "updateNewShippingQuery", "updateNotification", "updateNotificationGroup", "updateOption", "updatePoll", "updatePollAnswer", "updateRecentStickers", "updateSavedAnimations", "updateScopeNotificationSettings", "updateSecretChat", "updateSelectedBackground", "updateServiceNotification", "updateStickerSet", "updateSuggestedActions", "updateSupergroup", "updateSupergroupFullInfo", "updateTermsOfService", "updateTrendingStickerSets", "updateUnreadChatCount", "updateUnreadMessageCount", "updateUser", "updateUserChatAction", "updateUserFullInfo", "updateUserPrivacySettingRules", "updateUserStatus", "updateUsersNearby"] },
+  Class { name: "UserPrivacySetting", description: "Describes available user privacy settings", constructors: &["userPrivacySettingAllowCalls", "userPrivacySettingAllowChatInvites", "userPrivacySettingAllowFindingByPhoneNumber", "userPrivacySettingAllowPeerToPeerCalls", "userPrivacySettingShowLinkInForwardedMessages", "userPrivacySettingShowPhoneNumber", "userPrivacySettingShowProfilePhoto", "userPrivacySettingShowStatus"] },
+  Class { name: "UserPrivacySettingRule", description: "Represents a single rule for managing privacy settings", constructors: &["userPrivacySettingRuleAllowAll", "userPrivacySettingRuleAllowChatMembers", "userPrivacySettingRuleAllowContacts", "userPrivacySettingRuleAllowUsers", "userPrivacySettingRuleRestrictAll", "userPrivacySettingRuleRestrictChatMembers", "userPrivacySettingRuleRestrictContacts", "userPrivacySettingRuleRestrictUsers"] },
+  Class { name: "UserStatus", description: "Describes the last time the user was online", constructors: &["userStatusEmpty", "userStatusLastMonth", "userStatusLastWeek", "userStatusOffline", "userStatusOnline", "userStatusRecently"] },
+  Class { name: "UserType", description: "Represents the type of a user. The following types are possible: regular users, deleted users and bots", constructors: &["userTypeBot", "userTypeDeleted", "userTypeRegular", "userTypeUnknown"] },
+  Class { name: "VectorPathCommand", description: "Represents a vector path command", constructors: &["vectorPathCommandCubicBezierCurve", "vectorPathCommandLine"] },
+];
+
+pub(super) static CONSTRUCTORS: &[Constructor] = &[
+  Constructor { name: "accountTtl", description: "Contains information about the period of inactivity after which the current user's account will automatically be deleted", class: "AccountTtl", fields: &[
+    Parameter { name: "days", type_: "int32", description: "Number of days of inactivity before the account will be flagged for deletion; 30-366 days" },
+  ] },
+  Constructor { name: "address", description: "Describes an address", class: "Address", fields: &[
+    Parameter { name: "country_code", type_: "string", description: "A two-letter ISO 3166-1 alpha-2 country code" },
+    Parameter { name: "state", type_: "string", description: "State, if applicable" },
+    Parameter { name: "city", type_: "string", description: "City" },
+    Parameter { name: "street_line1", type_: "string", description: "First line of the address" },
+    Parameter { name: "street_line2", type_: "string", description: "Second line of the address" },
+    Parameter { name: "postal_code", type_: "string", description: "Address postal code" },
+  ] },
+  Constructor { name: "animatedChatPhoto", description: "Animated variant of a chat photo in MPEG4 format", class: "AnimatedChatPhoto", fields: &[
+    Parameter { name: "length", type_: "int32", description: "Animation width and height" },
+    Parameter { name: "file", type_: "file", description: "Information about the animation file" },
+    Parameter { name: "main_frame_timestamp", type_: "double", description: "Timestamp of the frame, used as a static chat photo" },
+  ] },
+  Constructor { name: "animatedEmoji", description: "Describes an animated representation of an emoji", class: "AnimatedEmoji", fields: &[
+    Parameter { name: "sticker", type_: "sticker", description: "Animated sticker for the emoji" },
+    Parameter { name: "color_replacements", type_: "vector<colorReplacement>", description: "List of colors to be replaced while the sticker is rendered" },
+    Parameter { name: "sound", type_: "file", description: "File containing the sound to be played when the animated emoji is clicked if any; may be null. The sound is encoded with the Opus codec, and stored inside an OGG container" },
+  ] },
+  Constructor { name: "animation", description: "Describes an animation file. The animation must be encoded in GIF or MPEG4 format", class: "Animation", fields: &[
+    Parameter { name: "duration", type_: "int32", description: "Duration of the animation, in seconds; as defined by the sender" },
+    Parameter { name: "width", type_: "int32", description: "Width of the animation" },
+    Parameter { name: "height", type_: "int32", description: "Height of the animation" },
+    Parameter { name: "file_name", type_: "string", description: "Original name of the file; as defined by the sender" },
+    Parameter { name: "mime_type", type_: "string", description: "MIME type of the file, usually \"image/gif\" or \"video/mp4\"" },
+    Parameter { name: "has_stickers", type_: "Bool", description: "True, if stickers were added to the animation. The list of corresponding sticker set can be received using getAttachedStickerSets" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "Animation minithumbnail; may be null" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Animation thumbnail in JPEG or MPEG4 format; may be null" },
+    Parameter { name: "animation", type_: "file", description: "File containing the animation" },
+  ] },
+  Constructor { name: "animations", description: "Represents a list of animations", class: "Animations", fields: &[
+    Parameter { name: "animations", type_: "vector<animation>", description: "List of animations" },
+  ] },
+  Constructor { name: "audio", description: "Describes an audio file. Audio is usually in MP3 or M4A format", class: "Audio", fields: &[
+    Parameter { name: "duration", type_: "int32", description: "Duration of the audio, in seconds; as defined by the sender" },
+    Parameter { name: "title", type_: "string", description: "Title of the audio; as defined by the sender" },
+    Parameter { name: "performer", type_: "string", description: "Performer of the audio; as defined by the sender" },
+    Parameter { name: "file_name", type_: "string", description: "Original name of the file; as defined by the sender" },
+    Parameter { name: "mime_type", type_: "string", description: "The MIME type of the file; as defined by the sender" },
+    Parameter { name: "album_cover_minithumbnail", type_: "minithumbnail", description: "The minithumbnail of the album cover; may be null" },
+    Parameter { name: "album_cover_thumbnail", type_: "thumbnail", description: "The thumbnail of the album cover in JPEG format; as defined by the sender. The full size thumbnail is supposed to be extracted from the downloaded file; may be null" },
+    Parameter { name: "audio", type_: "file", description: "File containing the audio" },
+  ] },
+  Constructor { name: "authenticationCodeInfo", description: "Information about the authentication code that was sent", class: "AuthenticationCodeInfo", fields: &[
+    Parameter { name: "phone_number", type_: "string", description: "A phone number that is being authenticated" },
+    Parameter { name: "type", type_: "AuthenticationCodeType", description: "The way the code was sent to the user" },
+    Parameter { name: "next_type", type_: "AuthenticationCodeType", description: "The way the next code will be sent to the user; may be null" },
+    Parameter { name: "timeout", type_: "int32", description: "Timeout before the code can be re-sent, in seconds" },
+  ] },
+  Constructor { name: "authenticationCodeTypeCall", description: "An authentication code is delivered via a phone call to the specified phone number", class: "AuthenticationCodeType", fields: &[
+    Parameter { name: "length", type_: "int32", description: "Length of the code" },
+  ] },
+  Constructor { name: "authenticationCodeTypeFlashCall", description: "An authentication code is delivered by an immediately canceled call to the specified phone number. The number from which the call was made is the code", class: "AuthenticationCodeType", fields: &[
+    Parameter { name: "pattern", type_: "string", description: "Pattern of the phone number from which the call will be made" },
+  ] },
+  Constructor { name: "authenticationCodeTypeSms", description: "An authentication code is delivered via an SMS message to the specified phone number", class: "AuthenticationCodeType", fields: &[
+    Parameter { name: "length", type_: "int32", description: "Length of the code" },
+  ] },
+  Constructor { name: "authenticationCodeTypeTelegramMessage", description: "An authentication code is delivered via a private Telegram message, which can be viewed from another active session", class: "AuthenticationCodeType", fields: &[
+    Parameter { name: "length", type_: "int32", description: "Length of the code" },
+  ] },
+  Constructor { name: "authorizationStateClosed", description: "TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one must create a new instance of the TDLib client", class: "AuthorizationState", fields: &[] },
+  Constructor { name: "authorizationStateClosing", description: "TDLib is closing, all subsequent queries will be answered with the error 500. Note that closing TDLib can take a while. All resources will be freed only after authorizationStateClosed has been received", class: "AuthorizationState", fields: &[] },
+  Constructor { name: "authorizationStateLoggingOut", description: "The user is currently logging out", class: "AuthorizationState", fields: &[] },
+  Constructor { name: "authorizationStateReady", description: "The user has been successfully authorized. TDLib is now ready to answer queries", class: "AuthorizationState", fields: &[] },
+  Constructor { name: "authorizationStateWaitCode", description: "TDLib needs the user's authentication code to authorize", class: "AuthorizationState", fields: &[
+    Parameter { name: "code_info", type_: "authenticationCodeInfo", description: "Information about the authorization code that was sent" },
+  ] },
+  Constructor { name: "authorizationStateWaitEncryptionKey", description: "TDLib needs an encryption key to decrypt the local database", class: "AuthorizationState", fields: &[
+    Parameter { name: "is_encrypted", type_: "Bool", description: "True, if the database is currently encrypted" },
+  ] },
+  Constructor { name: "authorizationStateWaitOtherDeviceConfirmation", description: "The user needs to confirm authorization on another logged in device by scanning a QR code with the provided link", class: "AuthorizationState", fields: &[
+    Parameter { name: "link", type_: "string", description: "A tg:// URL for the QR code. The link will be updated frequently" },
+  ] },
+  Constructor { name: "authorizationStateWaitPassword", description: "The user has been authorized, but needs to enter a password to start using the application", class: "AuthorizationState", fields: &[
+    Parameter { name: "password_hint", type_: "string", description: "Hint for the password; may be empty" },
+    Parameter { name: "has_recovery_email_address", type_: "Bool", description: "True, if a recovery email address has been set up" },
+    Parameter { name: "recovery_email_address_pattern", type_: "string", description: "Pattern of the email address to which the recovery email was sent; empty until a recovery email has been sent" },
+  ] },
+  Constructor { name: "authorizationStateWaitPhoneNumber", description: "TDLib needs the user's phone number to authorize. Call `setAuthenticationPhoneNumber` to provide the phone number, or use `requestQrCodeAuthentication`, or `checkAuthenticationBotToken` for other authentication options", class: "AuthorizationState", fields: &[] },
+  Constructor { name: "authorizationStateWaitRegistration", description: "The user is unregistered and need to accept terms of service and enter their first name and last name to finish registration", class: "AuthorizationState", fields: &[
+    Parameter { name: "terms_of_service", type_: "termsOfService", description: "Telegram terms of service" },
+  ] },
+  Constructor { name: "authorizationStateWaitTdlibParameters", description: "TDLib needs TdlibParameters for initialization", class: "AuthorizationState", fields: &[] },
+  Constructor { name: "autoDownloadSettings", description: "Contains auto-download settings", class: "AutoDownloadSettings", fields: &[
+    Parameter { name: "is_auto_download_enabled", type_: "Bool", description: "True, if the auto-download is enabled" },
+    Parameter { name: "max_photo_file_size", type_: "int32", description: "The maximum size of a photo file to be auto-downloaded, in bytes" },
+    Parameter { name: "max_video_file_size", type_: "int32", description: "The maximum size of a video file to be auto-downloaded, in bytes" },
+    Parameter { name: "max_other_file_size", type_: "int32", description: "The maximum size of other file types to be auto-downloaded, in bytes" },
+    Parameter { name: "video_upload_bitrate", type_: "int32", description: "The maximum suggested bitrate for uploaded videos, in kbit/s" },
+    Parameter { name: "preload_large_videos", type_: "Bool", description: "True, if the beginning of video files needs to be preloaded for instant playback" },
+    Parameter { name: "preload_next_audio", type_: "Bool", description: "True, if the next audio track needs to be preloaded while the user is listening to an audio file" },
+    Parameter { name: "use_less_data_for_calls", type_: "Bool", description: "True, if \"use less data for calls\" option needs to be enabled" },
+  ] },
+  Constructor { name: "autoDownloadSettingsPresets", description: "Contains auto-download settings presets for the current user", class: "AutoDownloadSettingsPresets", fields: &[
+    Parameter { name: "low", type_: "autoDownloadSettings", description: "Preset with lowest settings; supposed to be used by default when roaming" },
+    Parameter { name: "medium", type_: "autoDownloadSettings", description: "Preset with medium settings; supposed to be used by default when using mobile data" },
+    Parameter { name: "high", type_: "autoDownloadSettings", description: "Preset with highest settings; supposed to be used by default when connected on Wi-Fi" },
+  ] },
+  Constructor { name: "background", description: "Describes a chat background", class: "Background", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique background identifier" },
+    Parameter { name: "is_default", type_: "Bool", description: "True, if this is one of default backgrounds" },
+    Parameter { name: "is_dark", type_: "Bool", description: "True, if the background is dark and is recommended to be used with dark theme" },
+    Parameter { name: "name", type_: "string", description: "Unique background name" },
+    Parameter { name: "document", type_: "document", description: "Document with the background; may be null. Null only for filled backgrounds" },
+    Parameter { name: "type", type_: "BackgroundType", description: "Type of the background" },
+  ] },
+  Constructor { name: "backgroundFillFreeformGradient", description: "Describes a freeform gradient fill of a background", class: "BackgroundFill", fields: &[
+    Parameter { name: "colors", type_: "vector<int32>", description: "A list of 3 or 4 colors of the freeform gradients in the RGB24 format" },
+  ] },
+  Constructor { name: "backgroundFillGradient", description: "Describes a gradient fill of a background", class: "BackgroundFill", fields: &[
+    Parameter { name: "top_color", type_: "int32", description: "A top color of the background in the RGB24 format" },
+    Parameter { name: "bottom_color", type_: "int32", description: "A bottom color of the background in the RGB24 format" },
+    Parameter { name: "rotation_angle", type_: "int32", description: "Clockwise rotation angle of the gradient, in degrees; 0-359. Must be always divisible by 45" },
+  ] },
+  Constructor { name: "backgroundFillSolid", description: "Describes a solid fill of a background", class: "BackgroundFill", fields: &[
+    Parameter { name: "color", type_: "int32", description: "A color of the background in the RGB24 format" },
+  ] },
+  Constructor { name: "backgroundTypeFill", description: "A filled background", class: "BackgroundType", fields: &[
+    Parameter { name: "fill", type_: "BackgroundFill", description: "The background fill" },
+  ] },
+  Constructor { name: "backgroundTypePattern", description: "A PNG or TGV (gzipped subset of SVG with MIME type \"application/x-tgwallpattern\") pattern to be combined with the background fill chosen by the user", class: "BackgroundType", fields: &[
+    Parameter { name: "fill", type_: "BackgroundFill", description: "Fill of the background" },
+    Parameter { name: "intensity", type_: "int32", description: "Intensity of the pattern when it is shown above the filled background; 0-100." },
+    Parameter { name: "is_inverted", type_: "Bool", description: "True, if the background fill must be applied only to the pattern itself. All other pixels are black in this case. For dark themes only" },
+    Parameter { name: "is_moving", type_: "Bool", description: "True, if the background needs to be slightly moved when device is tilted" },
+  ] },
+  Constructor { name: "backgroundTypeWallpaper", description: "A wallpaper in JPEG format", class: "BackgroundType", fields: &[
+    Parameter { name: "is_blurred", type_: "Bool", description: "True, if the wallpaper must be downscaled to fit in 450x450 square and then box-blurred with radius 12" },
+    Parameter { name: "is_moving", type_: "Bool", description: "True, if the background needs to be slightly moved when device is tilted" },
+  ] },
+  Constructor { name: "backgrounds", description: "Contains a list of backgrounds", class: "Backgrounds", fields: &[
+    Parameter { name: "backgrounds", type_: "vector<background>", description: "A list of backgrounds" },
+  ] },
+  Constructor { name: "bankCardActionOpenUrl", description: "Describes an action associated with a bank card number", class: "BankCardActionOpenUrl", fields: &[
+    Parameter { name: "text", type_: "string", description: "Action text" },
+    Parameter { name: "url", type_: "string", description: "The URL to be opened" },
+  ] },
+  Constructor { name: "bankCardInfo", description: "Information about a bank card", class: "BankCardInfo", fields: &[
+    Parameter { name: "title", type_: "string", description: "Title of the bank card description" },
+    Parameter { name: "actions", type_: "vector<bankCardActionOpenUrl>", description: "Actions that can be done with the bank card number" },
+  ] },
+  Constructor { name: "basicGroup", description: "Represents a basic group of 0-200 users (must be upgraded to a supergroup to accommodate more than 200 users)", class: "BasicGroup", fields: &[
+    Parameter { name: "id", type_: "int53", description: "Group identifier" },
+    Parameter { name: "member_count", type_: "int32", description: "Number of members in the group" },
+    Parameter { name: "status", type_: "ChatMemberStatus", description: "Status of the current user in the group" },
+    Parameter { name: "is_active", type_: "Bool", description: "True, if the group is active" },
+    Parameter { name: "upgraded_to_supergroup_id", type_: "int53", description: "Identifier of the supergroup to which this group was upgraded; 0 if none" },
+  ] },
+  Constructor { name: "basicGroupFullInfo", description: "Contains full information about a basic group", class: "BasicGroupFullInfo", fields: &[
+    Parameter { name: "photo", type_: "chatPhoto", description: "Chat photo; may be null" },
+    Parameter { name: "description", type_: "string", description: "Group description. Updated only after the basic group is opened" },
+    Parameter { name: "creator_user_id", type_: "int53", description: "User identifier of the creator of the group; 0 if unknown" },
+    Parameter { name: "members", type_: "vector<chatMember>", description: "Group members" },
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "Primary invite link for this group; may be null. For chat administrators with can_invite_users right only. Updated only after the basic group is opened" },
+    Parameter { name: "bot_commands", type_: "vector<botCommands>", description: "List of commands of bots in the group" },
+  ] },
+  Constructor { name: "botCommand", description: "Represents a command supported by a bot", class: "BotCommand", fields: &[
+    Parameter { name: "command", type_: "string", description: "Text of the bot command" },
+    Parameter { name: "description", type_: "string", description: "Description of the bot command" },
+  ] },
+  Constructor { name: "botCommandScopeAllChatAdministrators", description: "A scope covering all group and supergroup chat administrators", class: "BotCommandScope", fields: &[] },
+  Constructor { name: "botCommandScopeAllGroupChats", description: "A scope covering all group and supergroup chats", class: "BotCommandScope", fields: &[] },
+  Constructor { name: "botCommandScopeAllPrivateChats", description: "A scope covering all private chats", class: "BotCommandScope", fields: &[] },
+  Constructor { name: "botCommandScopeChat", description: "A scope covering all members of a chat", class: "BotCommandScope", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Constructor { name: "botCommandScopeChatAdministrators", description: "A scope covering all administrators of a chat", class: "BotCommandScope", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Constructor { name: "botCommandScopeChatMember", description: "A scope covering a member of a chat", class: "BotCommandScope", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+  ] },
+  Constructor { name: "botCommandScopeDefault", description: "A scope covering all users", class: "BotCommandScope", fields: &[] },
+  Constructor { name: "botCommands", description: "Contains a list of bot commands", class: "BotCommands", fields: &[
+    Parameter { name: "bot_user_id", type_: "int53", description: "Bot's user identifier" },
+    Parameter { name: "commands", type_: "vector<botCommand>", description: "List of bot commands" },
+  ] },
+  Constructor { name: "call", description: "Describes a call", class: "Call", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Call identifier, not persistent" },
+    Parameter { name: "user_id", type_: "int53", description: "Peer user identifier" },
+    Parameter { name: "is_outgoing", type_: "Bool", description: "True, if the call is outgoing" },
+    Parameter { name: "is_video", type_: "Bool", description: "True, if the call is a video call" },
+    Parameter { name: "state", type_: "CallState", description: "Call state" },
+  ] },
+  Constructor { name: "callDiscardReasonDeclined", description: "The call was ended before the conversation started. It was declined by the other party", class: "CallDiscardReason", fields: &[] },
+  Constructor { name: "callDiscardReasonDisconnected", description: "The call was ended during the conversation because the users were disconnected", class: "CallDiscardReason", fields: &[] },
+  Constructor { name: "callDiscardReasonEmpty", description: "The call wasn't discarded, or the reason is unknown", class: "CallDiscardReason", fields: &[] },
+  Constructor { name: "callDiscardReasonHungUp", description: "The call was ended because one of the parties hung up", class: "CallDiscardReason", fields: &[] },
+  Constructor { name: "callDiscardReasonMissed", description: "The call was ended before the conversation started. It was canceled by the caller or missed by the other party", class: "CallDiscardReason", fields: &[] },
+  Constructor { name: "callId", description: "Contains the call identifier", class: "CallId", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Call identifier" },
+  ] },
+  Constructor { name: "callProblemDistortedSpeech", description: "The speech was distorted", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemDistortedVideo", description: "The video was distorted", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemDropped", description: "The call ended unexpectedly", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemEcho", description: "The user heard their own voice", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemInterruptions", description: "The other side kept disappearing", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemNoise", description: "The user heard background noise", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemPixelatedVideo", description: "The video was pixelated", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemSilentLocal", description: "The user couldn't hear the other side", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProblemSilentRemote", description: "The other side couldn't hear the user", class: "CallProblem", fields: &[] },
+  Constructor { name: "callProtocol", description: "Specifies the supported call protocols", class: "CallProtocol", fields: &[
+    Parameter { name: "udp_p2p", type_: "Bool", description: "True, if UDP peer-to-peer connections are supported" },
+    Parameter { name: "udp_reflector", type_: "Bool", description: "True, if connection through UDP reflectors is supported" },
+    Parameter { name: "min_layer", type_: "int32", description: "The minimum supported API layer; use 65" },
+    Parameter { name: "max_layer", type_: "int32", description: "The maximum supported API layer; use 65" },
+    Parameter { name: "library_versions", type_: "vector<string>", description: "List of supported tgcalls versions" },
+  ] },
+  Constructor { name: "callServer", description: "Describes a server for relaying call data", class: "CallServer", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Server identifier" },
+    Parameter { name: "ip_address", type_: "string", description: "Server IPv4 address" },
+    Parameter { name: "ipv6_address", type_: "string", description: "Server IPv6 address" },
+    Parameter { name: "port", type_: "int32", description: "Server port number" },
+    Parameter { name: "type", type_: "CallServerType", description: "Server type" },
+  ] },
+  Constructor { name: "callServerTypeTelegramReflector", description: "A Telegram call reflector", class: "CallServerType", fields: &[
+    Parameter { name: "peer_tag", type_: "bytes", description: "A peer tag to be used with the reflector" },
+  ] },
+  Constructor { name: "callServerTypeWebrtc", description: "A WebRTC server", class: "CallServerType", fields: &[
+    Parameter { name: "username", type_: "string", description: "Username to be used for authentication" },
+    Parameter { name: "password", type_: "string", description: "Authentication password" },
+    Parameter { name: "supports_turn", type_: "Bool", description: "True, if the server supports TURN" },
+    Parameter { name: "supports_stun", type_: "Bool", description: "True, if the server supports STUN" },
+  ] },
+  Constructor { name: "callStateDiscarded", description: "The call has ended successfully", class: "CallState", fields: &[
+    Parameter { name: "reason", type_: "CallDiscardReason", description: "The reason, why the call has ended" },
+    Parameter { name: "need_rating", type_: "Bool", description: "True, if the call rating must be sent to the server" },
+    Parameter { name: "need_debug_information", type_: "Bool", description: "True, if the call debug information must be sent to the server" },
+  ] },
+  Constructor { name: "callStateError", description: "The call has ended with an error", class: "CallState", fields: &[
+    Parameter { name: "error", type_: "error", description: "Error. An error with the code 4005000 will be returned if an outgoing call is missed because of an expired timeout" },
+  ] },
+  Constructor { name: "callStateExchangingKeys", description: "The call has been answered and encryption keys are being exchanged", class: "CallState", fields: &[] },
+  Constructor { name: "callStateHangingUp", description: "The call is hanging up after discardCall has been called", class: "CallState", fields: &[] },
+  Constructor { name: "callStatePending", description: "The call is pending, waiting to be accepted by a user", class: "CallState", fields: &[
+    Parameter { name: "is_created", type_: "Bool", description: "True, if the call has already been created by the server" },
+    Parameter { name: "is_received", type_: "Bool", description: "True, if the call has already been received by the other party" },
+  ] },
+  Constructor { name: "callStateReady", description: "The call is ready to use", class: "CallState", fields: &[
+    Parameter { name: "protocol", type_: "callProtocol", description: "Call protocols supported by the peer" },
+    Parameter { name: "servers", type_: "vector<callServer>", description: "List of available call servers" },
+    Parameter { name: "config", type_: "string", description: "A JSON-encoded call config" },
+    Parameter { name: "encryption_key", type_: "bytes", description: "Call encryption key" },
+    Parameter { name: "emojis", type_: "vector<string>", description: "Encryption key emojis fingerprint" },
+    Parameter { name: "allow_p2p", type_: "Bool", description: "True, if peer-to-peer connection is allowed by users privacy settings" },
+  ] },
+  Constructor { name: "callbackQueryAnswer", description: "Contains a bot's answer to a callback query", class: "CallbackQueryAnswer", fields: &[
+    Parameter { name: "text", type_: "string", description: "Text of the answer" },
+    Parameter { name: "show_alert", type_: "Bool", description: "True, if an alert must be shown to the user instead of a toast notification" },
+    Parameter { name: "url", type_: "string", description: "URL to be opened" },
+  ] },
+  Constructor { name: "callbackQueryPayloadData", description: "The payload for a general callback button", class: "CallbackQueryPayload", fields: &[
+    Parameter { name: "data", type_: "bytes", description: "Data that was attached to the callback button" },
+  ] },
+  Constructor { name: "callbackQueryPayloadDataWithPassword", description: "The payload for a callback button requiring password", class: "CallbackQueryPayload", fields: &[
+    Parameter { name: "password", type_: "string", description: "The password for the current user" },
+    Parameter { name: "data", type_: "bytes", description: "Data that was attached to the callback button" },
+  ] },
+  Constructor { name: "callbackQueryPayloadGame", description: "The payload for a game callback button", class: "CallbackQueryPayload", fields: &[
+    Parameter { name: "game_short_name", type_: "string", description: "A short name of the game that was attached to the callback button" },
+  ] },
+  Constructor { name: "canTransferOwnershipResultOk", description: "The session can be used", class: "CanTransferOwnershipResult", fields: &[] },
+  Constructor { name: "canTransferOwnershipResultPasswordNeeded", description: "The 2-step verification needs to be enabled first", class: "CanTransferOwnershipResult", fields: &[] },
+  Constructor { name: "canTransferOwnershipResultPasswordTooFresh", description: "The 2-step verification was enabled recently, user needs to wait", class: "CanTransferOwnershipResult", fields: &[
+    Parameter { name: "retry_after", type_: "int32", description: "Time left before the session can be used to transfer ownership of a chat, in seconds" },
+  ] },
+  Constructor { name: "canTransferOwnershipResultSessionTooFresh", description: "The session was created recently, user needs to wait", class: "CanTransferOwnershipResult", fields: &[
+    Parameter { name: "retry_after", type_: "int32", description: "Time left before the session can be used to transfer ownership of a chat, in seconds" },
+  ] },
+  Constructor { name: "chat", description: "A chat. (Can be a private chat, basic group, supergroup, or secret chat)", class: "Chat", fields: &[
+    Parameter { name: "id", type_: "int53", description: "Chat unique identifier" },
+    Parameter { name: "type", type_: "ChatType", description: "Type of the chat" },
+    Parameter { name: "title", type_: "string", description: "Chat title" },
+    Parameter { name: "photo", type_: "chatPhotoInfo", description: "Chat photo; may be null" },
+    Parameter { name: "permissions", type_: "chatPermissions", description: "Actions that non-administrator chat members are allowed to take in the chat" },
+    Parameter { name: "last_message", type_: "message", description: "Last message in the chat; may be null" },
+    Parameter { name: "positions", type_: "vector<chatPosition>", description: "Positions of the chat in chat lists" },
+    Parameter { name: "is_marked_as_unread", type_: "Bool", description: "True, if the chat is marked as unread" },
+    Parameter { name: "is_blocked", type_: "Bool", description: "True, if the chat is blocked by the current user and private messages from the chat can't be received" },
+    Parameter { name: "has_scheduled_messages", type_: "Bool", description: "True, if the chat has scheduled messages" },
+    Parameter { name: "can_be_deleted_only_for_self", type_: "Bool", description: "True, if the chat messages can be deleted only for the current user while other users will continue to see the messages" },
+    Parameter { name: "can_be_deleted_for_all_users", type_: "Bool", description: "True, if the chat messages can be deleted for all users" },
+    Parameter { name: "can_be_reported", type_: "Bool", description: "True, if the chat can be reported to Telegram moderators through reportChat or reportChatPhoto" },
+    Parameter { name: "default_disable_notification", type_: "Bool", description: "Default value of the disable_notification parameter, used when a message is sent to the chat" },
+    Parameter { name: "unread_count", type_: "int32", description: "Number of unread messages in the chat" },
+    Parameter { name: "last_read_inbox_message_id", type_: "int53", description: "Identifier of the last read incoming message" },
+    Parameter { name: "last_read_outbox_message_id", type_: "int53", description: "Identifier of the last read outgoing message" },
+    Parameter { name: "unread_mention_count", type_: "int32", description: "Number of unread messages with a mention/reply in the chat" },
+    Parameter { name: "notification_settings", type_: "chatNotificationSettings", description: "Notification settings for this chat" },
+    Parameter { name: "message_ttl_setting", type_: "int32", description: "Current message Time To Live setting (self-destruct timer) for the chat; 0 if not defined. TTL is counted from the time message or its content is viewed in secret chats and from the send date in other chats" },
+    Parameter { name: "theme_name", type_: "string", description: "If non-empty, name of a theme, set for the chat" },
+    Parameter { name: "action_bar", type_: "ChatActionBar", description: "Information about actions which must be possible to do through the chat action bar; may be null" },
+    Parameter { name: "video_chat", type_: "videoChat", description: "Information about video chat of the chat" },
+    Parameter { name: "pending_join_requests", type_: "chatJoinRequestsInfo", description: "Information about pending join requests; may be null" },
+    Parameter { name: "reply_markup_message_id", type_: "int53", description: "Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat" },
+    Parameter { name: "draft_message", type_: "draftMessage", description: "A draft of a message in the chat; may be null" },
+    Parameter { name: "client_data", type_: "string", description: "Application-specific data associated with the chat. (For example, the chat scroll position or local chat notification settings can be stored here.) Persistent if the message database is used" },
+  ] },
+  Constructor { name: "chatActionBarAddContact", description: "The chat is a private or secret chat and the other user can be added to the contact list using the method addContact", class: "ChatActionBar", fields: &[] },
+  Constructor { name: "chatActionBarInviteMembers", description: "The chat is a recently created group chat, to which new members can be invited", class: "ChatActionBar", fields: &[] },
+  Constructor { name: "chatActionBarReportAddBlock", description: "The chat is a private or secret chat, which can be reported using the method reportChat, or the other user can be blocked using the method toggleMessageSenderIsBlocked, or the other user can be added to the contact list using the method addContact", class: "ChatActionBar", fields: &[
+    Parameter { name: "can_unarchive", type_: "Bool", description: "If true, the chat was automatically archived and can be moved back to the main chat list using addChatToList simultaneously with setting chat notification settings to default using setChatNotificationSettings" },
+    Parameter { name: "distance", type_: "int32", description: "If non-negative, the current user was found by the peer through searchChatsNearby and this is the distance between the users" },
+  ] },
+  Constructor { name: "chatActionBarReportSpam", description: "The chat can be reported as spam using the method reportChat with the reason chatReportReasonSpam", class: "ChatActionBar", fields: &[
+    Parameter { name: "can_unarchive", type_: "Bool", description: "If true, the chat was automatically archived and can be moved back to the main chat list using addChatToList simultaneously with setting chat notification settings to default using setChatNotificationSettings" },
+  ] },
+  Constructor { name: "chatActionBarReportUnrelatedLocation", description: "The chat is a location-based supergroup, which can be reported as having unrelated location using the method reportChat with the reason chatReportReasonUnrelatedLocation", class: "ChatActionBar", fields: &[] },
+  Constructor { name: "chatActionBarSharePhoneNumber", description: "The chat is a private or secret chat with a mutual contact and the user's phone number can be shared with the other user using the method sharePhoneNumber", class: "ChatActionBar", fields: &[] },
+  Constructor { name: "chatActionCancel", description: "The user has canceled the previous action", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionChoosingContact", description: "The user is picking a contact to send", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionChoosingLocation", description: "The user is picking a location or venue to send", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionChoosingSticker", description: "The user is picking a sticker to send", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionRecordingVideo", description: "The user is recording a video", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionRecordingVideoNote", description: "The user is recording a video note", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionRecordingVoiceNote", description: "The user is recording a voice note", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionStartPlayingGame", description: "The user has started to play a game", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionTyping", description: "The user is typing a message", class: "ChatAction", fields: &[] },
+  Constructor { name: "chatActionUploadingDocument", description: "The user is uploading a document", class: "ChatAction", fields: &[
+    Parameter { name: "progress", type_: "int32", description: "Upload progress, as a percentage" },
+  ] },
+  Constructor { name: "chatActionUploadingPhoto", description: "The user is uploading a photo", class: "ChatAction", fields: &[
+    Parameter { name: "progress", type_: "int32", description: "Upload progress, as a percentage" },
+  ] },
+  Constructor { name: "chatActionUploadingVideo", description: "The user is uploading a video", class: "ChatAction", fields: &[
+    Parameter { name: "progress", type_: "int32", description: "Upload progress, as a percentage" },
+  ] },
+  Constructor { name: "chatActionUploadingVideoNote", description: "The user is uploading a video note", class: "ChatAction", fields: &[
+    Parameter { name: "progress", type_: "int32", description: "Upload progress, as a percentage" },
+  ] },
+  Constructor { name: "chatActionUploadingVoiceNote", description: "The user is uploading a voice note", class: "ChatAction", fields: &[
+    Parameter { name: "progress", type_: "int32", description: "Upload progress, as a percentage" },
+  ] },
+  Constructor { name: "chatActionWatchingAnimations", description: "The user is watching animations sent by the other party by clicking on an animated emoji", class: "ChatAction", fields: &[
+    Parameter { name: "emoji", type_: "string", description: "The animated emoji" },
+  ] },
+  Constructor { name: "chatAdministrator", description: "Contains information about a chat administrator", class: "ChatAdministrator", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier of the administrator" },
+    Parameter { name: "custom_title", type_: "string", description: "Custom title of the administrator" },
+    Parameter { name: "is_owner", type_: "Bool", description: "True, if the user is the owner of the chat" },
+  ] },
+  Constructor { name: "chatAdministrators", description: "Represents a list of chat administrators", class: "ChatAdministrators", fields: &[
+    Parameter { name: "administrators", type_: "vector<chatAdministrator>", description: "A list of chat administrators" },
+  ] },
+  Constructor { name: "chatEvent", description: "Represents a chat event", class: "ChatEvent", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Chat event identifier" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the event happened" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user who performed the action that triggered the event" },
+    Parameter { name: "action", type_: "ChatEventAction", description: "Action performed by the user" },
+  ] },
+  Constructor { name: "chatEventDescriptionChanged", description: "The chat description was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_description", type_: "string", description: "Previous chat description" },
+    Parameter { name: "new_description", type_: "string", description: "New chat description" },
+  ] },
+  Constructor { name: "chatEventInviteLinkDeleted", description: "A revoked chat invite link was deleted", class: "ChatEventAction", fields: &[
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "The invite link" },
+  ] },
+  Constructor { name: "chatEventInviteLinkEdited", description: "A chat invite link was edited", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_invite_link", type_: "chatInviteLink", description: "Previous information about the invite link" },
+    Parameter { name: "new_invite_link", type_: "chatInviteLink", description: "New information about the invite link" },
+  ] },
+  Constructor { name: "chatEventInviteLinkRevoked", description: "A chat invite link was revoked", class: "ChatEventAction", fields: &[
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "The invite link" },
+  ] },
+  Constructor { name: "chatEventInvitesToggled", description: "The can_invite_users permission of a supergroup chat was toggled", class: "ChatEventAction", fields: &[
+    Parameter { name: "can_invite_users", type_: "Bool", description: "New value of can_invite_users permission" },
+  ] },
+  Constructor { name: "chatEventIsAllHistoryAvailableToggled", description: "The is_all_history_available setting of a supergroup was toggled", class: "ChatEventAction", fields: &[
+    Parameter { name: "is_all_history_available", type_: "Bool", description: "New value of is_all_history_available" },
+  ] },
+  Constructor { name: "chatEventLinkedChatChanged", description: "The linked chat of a supergroup was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_linked_chat_id", type_: "int53", description: "Previous supergroup linked chat identifier" },
+    Parameter { name: "new_linked_chat_id", type_: "int53", description: "New supergroup linked chat identifier" },
+  ] },
+  Constructor { name: "chatEventLocationChanged", description: "The supergroup location was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_location", type_: "chatLocation", description: "Previous location; may be null" },
+    Parameter { name: "new_location", type_: "chatLocation", description: "New location; may be null" },
+  ] },
+  Constructor { name: "chatEventLogFilters", description: "Represents a set of filters used to obtain a chat event log", class: "ChatEventLogFilters", fields: &[
+    Parameter { name: "message_edits", type_: "Bool", description: "True, if message edits need to be returned" },
+    Parameter { name: "message_deletions", type_: "Bool", description: "True, if message deletions need to be returned" },
+    Parameter { name: "message_pins", type_: "Bool", description: "True, if pin/unpin events need to be returned" },
+    Parameter { name: "member_joins", type_: "Bool", description: "True, if members joining events need to be returned" },
+    Parameter { name: "member_leaves", type_: "Bool", description: "True, if members leaving events need to be returned" },
+    Parameter { name: "member_invites", type_: "Bool", description: "True, if invited member events need to be returned" },
+    Parameter { name: "member_promotions", type_: "Bool", description: "True, if member promotion/demotion events need to be returned" },
+    Parameter { name: "member_restrictions", type_: "Bool", description: "True, if member restricted/unrestricted/banned/unbanned events need to be returned" },
+    Parameter { name: "info_changes", type_: "Bool", description: "True, if changes in chat information need to be returned" },
+    Parameter { name: "setting_changes", type_: "Bool", description: "True, if changes in chat settings need to be returned" },
+    Parameter { name: "invite_link_changes", type_: "Bool", description: "True, if changes to invite links need to be returned" },
+    Parameter { name: "video_chat_changes", type_: "Bool", description: "True, if video chat actions need to be returned" },
+  ] },
+  Constructor { name: "chatEventMemberInvited", description: "A new chat member was invited", class: "ChatEventAction", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "New member user identifier" },
+    Parameter { name: "status", type_: "ChatMemberStatus", description: "New member status" },
+  ] },
+  Constructor { name: "chatEventMemberJoined", description: "A new member joined the chat", class: "ChatEventAction", fields: &[] },
+  Constructor { name: "chatEventMemberJoinedByInviteLink", description: "A new member joined the chat by an invite link", class: "ChatEventAction", fields: &[
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "Invite link used to join the chat" },
+  ] },
+  Constructor { name: "chatEventMemberJoinedByRequest", description: "A new member was accepted to the chat by an administrator", class: "ChatEventAction", fields: &[
+    Parameter { name: "approver_user_id", type_: "int53", description: "User identifier of the chat administrator, approved user join request" },
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "Invite link used to join the chat; may be null" },
+  ] },
+  Constructor { name: "chatEventMemberLeft", description: "A member left the chat", class: "ChatEventAction", fields: &[] },
+  Constructor { name: "chatEventMemberPromoted", description: "A chat member has gained/lost administrator status, or the list of their administrator privileges has changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "Affected chat member user identifier" },
+    Parameter { name: "old_status", type_: "ChatMemberStatus", description: "Previous status of the chat member" },
+    Parameter { name: "new_status", type_: "ChatMemberStatus", description: "New status of the chat member" },
+  ] },
+  Constructor { name: "chatEventMemberRestricted", description: "A chat member was restricted/unrestricted or banned/unbanned, or the list of their restrictions has changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "member_id", type_: "MessageSender", description: "Affected chat member identifier" },
+    Parameter { name: "old_status", type_: "ChatMemberStatus", description: "Previous status of the chat member" },
+    Parameter { name: "new_status", type_: "ChatMemberStatus", description: "New status of the chat member" },
+  ] },
+  Constructor { name: "chatEventMessageDeleted", description: "A message was deleted", class: "ChatEventAction", fields: &[
+    Parameter { name: "message", type_: "message", description: "Deleted message" },
+  ] },
+  Constructor { name: "chatEventMessageEdited", description: "A message was edited", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_message", type_: "message", description: "The original message before the edit" },
+    Parameter { name: "new_message", type_: "message", description: "The message after it was edited" },
+  ] },
+  Constructor { name: "chatEventMessagePinned", description: "A message was pinned", class: "ChatEventAction", fields: &[
+    Parameter { name: "message", type_: "message", description: "Pinned message" },
+  ] },
+  Constructor { name: "chatEventMessageTtlSettingChanged", description: "The message TTL setting was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_message_ttl_setting", type_: "int32", description: "Previous value of message_ttl_setting" },
+    Parameter { name: "new_message_ttl_setting", type_: "int32", description: "New value of message_ttl_setting" },
+  ] },
+  Constructor { name: "chatEventMessageUnpinned", description: "A message was unpinned", class: "ChatEventAction", fields: &[
+    Parameter { name: "message", type_: "message", description: "Unpinned message" },
+  ] },
+  Constructor { name: "chatEventPermissionsChanged", description: "The chat permissions was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_permissions", type_: "chatPermissions", description: "Previous chat permissions" },
+    Parameter { name: "new_permissions", type_: "chatPermissions", description: "New chat permissions" },
+  ] },
+  Constructor { name: "chatEventPhotoChanged", description: "The chat photo was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_photo", type_: "chatPhoto", description: "Previous chat photo value; may be null" },
+    Parameter { name: "new_photo", type_: "chatPhoto", description: "New chat photo value; may be null" },
+  ] },
+  Constructor { name: "chatEventPollStopped", description: "A poll in a message was stopped", class: "ChatEventAction", fields: &[
+    Parameter { name: "message", type_: "message", description: "The message with the poll" },
+  ] },
+  Constructor { name: "chatEventSignMessagesToggled", description: "The sign_messages setting of a channel was toggled", class: "ChatEventAction", fields: &[
+    Parameter { name: "sign_messages", type_: "Bool", description: "New value of sign_messages" },
+  ] },
+  Constructor { name: "chatEventSlowModeDelayChanged", description: "The slow_mode_delay setting of a supergroup was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_slow_mode_delay", type_: "int32", description: "Previous value of slow_mode_delay, in seconds" },
+    Parameter { name: "new_slow_mode_delay", type_: "int32", description: "New value of slow_mode_delay, in seconds" },
+  ] },
+  Constructor { name: "chatEventStickerSetChanged", description: "The supergroup sticker set was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_sticker_set_id", type_: "int64", description: "Previous identifier of the chat sticker set; 0 if none" },
+    Parameter { name: "new_sticker_set_id", type_: "int64", description: "New identifier of the chat sticker set; 0 if none" },
+  ] },
+  Constructor { name: "chatEventTitleChanged", description: "The chat title was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_title", type_: "string", description: "Previous chat title" },
+    Parameter { name: "new_title", type_: "string", description: "New chat title" },
+  ] },
+  Constructor { name: "chatEventUsernameChanged", description: "The chat username was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "old_username", type_: "string", description: "Previous chat username" },
+    Parameter { name: "new_username", type_: "string", description: "New chat username" },
+  ] },
+  Constructor { name: "chatEventVideoChatCreated", description: "A video chat was created", class: "ChatEventAction", fields: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Identifier of the video chat. The video chat can be received through the method getGroupCall" },
+  ] },
+  Constructor { name: "chatEventVideoChatDiscarded", description: "A video chat was discarded", class: "ChatEventAction", fields: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Identifier of the video chat. The video chat can be received through the method getGroupCall" },
+  ] },
+  Constructor { name: "chatEventVideoChatMuteNewParticipantsToggled", description: "The mute_new_participants setting of a video chat was toggled", class: "ChatEventAction", fields: &[
+    Parameter { name: "mute_new_participants", type_: "Bool", description: "New value of the mute_new_participants setting" },
+  ] },
+  Constructor { name: "chatEventVideoChatParticipantIsMutedToggled", description: "A video chat participant was muted or unmuted", class: "ChatEventAction", fields: &[
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Identifier of the affected group call participant" },
+    Parameter { name: "is_muted", type_: "Bool", description: "New value of is_muted" },
+  ] },
+  Constructor { name: "chatEventVideoChatParticipantVolumeLevelChanged", description: "A video chat participant volume level was changed", class: "ChatEventAction", fields: &[
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Identifier of the affected group call participant" },
+    Parameter { name: "volume_level", type_: "int32", description: "New value of volume_level; 1-20000 in hundreds of percents" },
+  ] },
+  Constructor { name: "chatEvents", description: "Contains a list of chat events", class: "ChatEvents", fields: &[
+    Parameter { name: "events", type_: "vector<chatEvent>", description: "List of events" },
+  ] },
+  Constructor { name: "chatFilter", description: "Represents a filter of user chats", class: "ChatFilter", fields: &[
+    Parameter { name: "title", type_: "string", description: "The title of the filter; 1-12 characters without line feeds" },
+    Parameter { name: "icon_name", type_: "string", description: "The icon name for short filter representation. If non-empty, must be one of \"All\", \"Unread\", \"Unmuted\", \"Bots\", \"Channels\", \"Groups\", \"Private\", \"Custom\", \"Setup\", \"Cat\", \"Crown\", \"Favorite\", \"Flower\", \"Game\", \"Home\", \"Love\", \"Mask\", \"Party\", \"Sport\", \"Study\", \"Trade\", \"Travel\", \"Work\". If empty, use getChatFilterDefaultIconName to get default icon name for the filter" },
+    Parameter { name: "pinned_chat_ids", type_: "vector<int53>", description: "The chat identifiers of pinned chats in the filtered chat list" },
+    Parameter { name: "included_chat_ids", type_: "vector<int53>", description: "The chat identifiers of always included chats in the filtered chat list" },
+    Parameter { name: "excluded_chat_ids", type_: "vector<int53>", description: "The chat identifiers of always excluded chats in the filtered chat list" },
+    Parameter { name: "exclude_muted", type_: "Bool", description: "True, if muted chats need to be excluded" },
+    Parameter { name: "exclude_read", type_: "Bool", description: "True, if read chats need to be excluded" },
+    Parameter { name: "exclude_archived", type_: "Bool", description: "True, if archived chats need to be excluded" },
+    Parameter { name: "include_contacts", type_: "Bool", description: "True, if contacts need to be included" },
+    Parameter { name: "include_non_contacts", type_: "Bool", description: "True, if non-contact users need to be included" },
+    Parameter { name: "include_bots", type_: "Bool", description: "True, if bots need to be included" },
+    Parameter { name: "include_groups", type_: "Bool", description: "True, if basic groups and supergroups need to be included" },
+    Parameter { name: "include_channels", type_: "Bool", description: "True, if channels need to be included" },
+  ] },
+  Constructor { name: "chatFilterInfo", description: "Contains basic information about a chat filter", class: "ChatFilterInfo", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique chat filter identifier" },
+    Parameter { name: "title", type_: "string", description: "The title of the filter; 1-12 characters without line feeds" },
+    Parameter { name: "icon_name", type_: "string", description: "The icon name for short filter representation. One of \"All\", \"Unread\", \"Unmuted\", \"Bots\", \"Channels\", \"Groups\", \"Private\", \"Custom\", \"Setup\", \"Cat\", \"Crown\", \"Favorite\", \"Flower\", \"Game\", \"Home\", \"Love\", \"Mask\", \"Party\", \"Sport\", \"Study\", \"Trade\", \"Travel\", \"Work\"" },
+  ] },
+  Constructor { name: "chatInviteLink", description: "Contains a chat invite link", class: "ChatInviteLink", fields: &[
+    Parameter { name: "invite_link", type_: "string", description: "Chat invite link" },
+    Parameter { name: "name", type_: "string", description: "Name of the link" },
+    Parameter { name: "creator_user_id", type_: "int53", description: "User identifier of an administrator created the link" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the link was created" },
+    Parameter { name: "edit_date", type_: "int32", description: "Point in time (Unix timestamp) when the link was last edited; 0 if never or unknown" },
+    Parameter { name: "expire_date", type_: "int32", description: "Point in time (Unix timestamp) when the link will expire; 0 if never" },
+    Parameter { name: "member_limit", type_: "int32", description: "The maximum number of members, which can join the chat using the link simultaneously; 0 if not limited. Always 0 if the link requires approval" },
+    Parameter { name: "member_count", type_: "int32", description: "Number of chat members, which joined the chat using the link" },
+    Parameter { name: "pending_join_request_count", type_: "int32", description: "Number of pending join requests created using this link" },
+    Parameter { name: "creates_join_request", type_: "Bool", description: "True, if the link only creates join request. If true, total number of joining members will be unlimited" },
+    Parameter { name: "is_primary", type_: "Bool", description: "True, if the link is primary. Primary invite link can't have name, expire date or usage limit. There is exactly one primary invite link for each administrator with can_invite_users right at a given time" },
+    Parameter { name: "is_revoked", type_: "Bool", description: "True, if the link was revoked" },
+  ] },
+  Constructor { name: "chatInviteLinkCount", description: "Describes a chat administrator with a number of active and revoked chat invite links", class: "ChatInviteLinkCount", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "Administrator's user identifier" },
+    Parameter { name: "invite_link_count", type_: "int32", description: "Number of active invite links" },
+    Parameter { name: "revoked_invite_link_count", type_: "int32", description: "Number of revoked invite links" },
+  ] },
+  Constructor { name: "chatInviteLinkCounts", description: "Contains a list of chat invite link counts", class: "ChatInviteLinkCounts", fields: &[
+    Parameter { name: "invite_link_counts", type_: "vector<chatInviteLinkCount>", description: "List of invite link counts" },
+  ] },
+  Constructor { name: "chatInviteLinkInfo", description: "Contains information about a chat invite link", class: "ChatInviteLinkInfo", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the invite link; 0 if the user has no access to the chat before joining" },
+    Parameter { name: "accessible_for", type_: "int32", description: "If non-zero, the amount of time for which read access to the chat will remain available, in seconds" },
+    Parameter { name: "type", type_: "ChatType", description: "Type of the chat" },
+    Parameter { name: "title", type_: "string", description: "Title of the chat" },
+    Parameter { name: "photo", type_: "chatPhotoInfo", description: "Chat photo; may be null" },
+    Parameter { name: "description", type_: "string", description: "Chat description" },
+    Parameter { name: "member_count", type_: "int32", description: "Number of members in the chat" },
+    Parameter { name: "member_user_ids", type_: "vector<int53>", description: "User identifiers of some chat members that may be known to the current user" },
+    Parameter { name: "creates_join_request", type_: "Bool", description: "True, if the link only creates join request" },
+    Parameter { name: "is_public", type_: "Bool", description: "True, if the chat is a public supergroup or channel, i.e. it has a username or it is a location-based supergroup" },
+  ] },
+  Constructor { name: "chatInviteLinkMember", description: "Describes a chat member joined a chat by an invite link", class: "ChatInviteLinkMember", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "joined_chat_date", type_: "int32", description: "Point in time (Unix timestamp) when the user joined the chat" },
+    Parameter { name: "approver_user_id", type_: "int53", description: "User identifier of the chat administrator, approved user join request" },
+  ] },
+  Constructor { name: "chatInviteLinkMembers", description: "Contains a list of chat members joined a chat by an invite link", class: "ChatInviteLinkMembers", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of chat members found" },
+    Parameter { name: "members", type_: "vector<chatInviteLinkMember>", description: "List of chat members, joined a chat by an invite link" },
+  ] },
+  Constructor { name: "chatInviteLinks", description: "Contains a list of chat invite links", class: "ChatInviteLinks", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of chat invite links found" },
+    Parameter { name: "invite_links", type_: "vector<chatInviteLink>", description: "List of invite links" },
+  ] },
+  Constructor { name: "chatJoinRequest", description: "Describes a user that sent a join request and waits for administrator approval", class: "ChatJoinRequest", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the user sent the join request" },
+    Parameter { name: "bio", type_: "string", description: "A short bio of the user" },
+  ] },
+  Constructor { name: "chatJoinRequests", description: "Contains a list of chat join requests", class: "ChatJoinRequests", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of requests found" },
+    Parameter { name: "requests", type_: "vector<chatJoinRequest>", description: "List of the requests" },
+  ] },
+  Constructor { name: "chatJoinRequestsInfo", description: "Contains information about pending chat join requests", class: "ChatJoinRequestsInfo", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Total number of pending join requests" },
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "Identifiers of users sent the newest pending join requests" },
+  ] },
+  Constructor { name: "chatListArchive", description: "A list of chats usually located at the top of the main chat list. Unmuted chats are automatically moved from the Archive to the Main chat list when a new message arrives", class: "ChatList", fields: &[] },
+  Constructor { name: "chatListFilter", description: "A list of chats belonging to a chat filter", class: "ChatList", fields: &[
+    Parameter { name: "chat_filter_id", type_: "int32", description: "Chat filter identifier" },
+  ] },
+  Constructor { name: "chatListMain", description: "A main list of chats", class: "ChatList", fields: &[] },
+  Constructor { name: "chatLists", description: "Contains a list of chat lists", class: "ChatLists", fields: &[
+    Parameter { name: "chat_lists", type_: "vector<ChatList>", description: "List of chat lists" },
+  ] },
+  Constructor { name: "chatLocation", description: "Represents a location to which a chat is connected", class: "ChatLocation", fields: &[
+    Parameter { name: "location", type_: "location", description: "The location" },
+    Parameter { name: "address", type_: "string", description: "Location address; 1-64 characters, as defined by the chat owner" },
+  ] },
+  Constructor { name: "chatMember", description: "Describes a user or a chat as a member of another chat", class: "ChatMember", fields: &[
+    Parameter { name: "member_id", type_: "MessageSender", description: "Identifier of the chat member. Currently, other chats can be only Left or Banned. Only supergroups and channels can have other chats as Left or Banned members and these chats must be supergroups or channels" },
+    Parameter { name: "inviter_user_id", type_: "int53", description: "Identifier of a user that invited/promoted/banned this member in the chat; 0 if unknown" },
+    Parameter { name: "joined_chat_date", type_: "int32", description: "Point in time (Unix timestamp) when the user joined the chat" },
+    Parameter { name: "status", type_: "ChatMemberStatus", description: "Status of the member in the chat" },
+  ] },
+  Constructor { name: "chatMemberStatusAdministrator", description: "The user is a member of the chat and has some additional privileges. In basic groups, administrators can edit and delete messages sent by others, add new members, ban unprivileged members, and manage video chats. In supergroups and channels, there are more detailed options for administrator privileges", class: "ChatMemberStatus", fields: &[
+    Parameter { name: "custom_title", type_: "string", description: "A custom title of the administrator; 0-16 characters without emojis; applicable to supergroups only" },
+    Parameter { name: "can_be_edited", type_: "Bool", description: "True, if the current user can edit the administrator privileges for the called user" },
+    Parameter { name: "can_manage_chat", type_: "Bool", description: "True, if the administrator can get chat event log, get chat statistics, get message statistics in channels, get channel members, see anonymous administrators in supergroups and ignore slow mode. Implied by any other privilege; applicable to supergroups and channels only" },
+    Parameter { name: "can_change_info", type_: "Bool", description: "True, if the administrator can change the chat title, photo, and other settings" },
+    Parameter { name: "can_post_messages", type_: "Bool", description: "True, if the administrator can create channel posts; applicable to channels only" },
+    Parameter { name: "can_edit_messages", type_: "Bool", description: "True, if the administrator can edit messages of other users and pin messages; applicable to channels only" },
+    Parameter { name: "can_delete_messages", type_: "Bool", description: "True, if the administrator can delete messages of other users" },
+    Parameter { name: "can_invite_users", type_: "Bool", description: "True, if the administrator can invite new users to the chat" },
+    Parameter { name: "can_restrict_members", type_: "Bool", description: "True, if the administrator can restrict, ban, or unban chat members; always true for channels" },
+    Parameter { name: "can_pin_messages", type_: "Bool", description: "True, if the administrator can pin messages; applicable to basic groups and supergroups only" },
+    Parameter { name: "can_promote_members", type_: "Bool", description: "True, if the administrator can add new administrators with a subset of their own privileges or demote administrators that were directly or indirectly promoted by them" },
+    Parameter { name: "can_manage_video_chats", type_: "Bool", description: "True, if the administrator can manage video chats" },
+    Parameter { name: "is_anonymous", type_: "Bool", description: "True, if the administrator isn't shown in the chat member list and sends messages anonymously; applicable to supergroups only" },
+  ] },
+  Constructor { name: "chatMemberStatusBanned", description: "The user or the chat was banned (and hence is not a member of the chat). Implies the user can't return to the chat, view messages, or be used as a participant identifier to join a video chat of the chat", class: "ChatMemberStatus", fields: &[
+    Parameter { name: "banned_until_date", type_: "int32", description: "Point in time (Unix timestamp) when the user will be unbanned; 0 if never. If the user is banned for more than 366 days or for less than 30 seconds from the current time, the user is considered to be banned forever. Always 0 in basic groups" },
+  ] },
+  Constructor { name: "chatMemberStatusCreator", description: "The user is the owner of the chat and has all the administrator privileges", class: "ChatMemberStatus", fields: &[
+    Parameter { name: "custom_title", type_: "string", description: "A custom title of the owner; 0-16 characters without emojis; applicable to supergroups only" },
+    Parameter { name: "is_anonymous", type_: "Bool", description: "True, if the creator isn't shown in the chat member list and sends messages anonymously; applicable to supergroups only" },
+    Parameter { name: "is_member", type_: "Bool", description: "True, if the user is a member of the chat" },
+  ] },
+  Constructor { name: "chatMemberStatusLeft", description: "The user or the chat is not a chat member", class: "ChatMemberStatus", fields: &[] },
+  Constructor { name: "chatMemberStatusMember", description: "The user is a member of the chat, without any additional privileges or restrictions", class: "ChatMemberStatus", fields: &[] },
+  Constructor { name: "chatMemberStatusRestricted", description: "The user is under certain restrictions in the chat. Not supported in basic groups and channels", class: "ChatMemberStatus", fields: &[
+    Parameter { name: "is_member", type_: "Bool", description: "True, if the user is a member of the chat" },
+    Parameter { name: "restricted_until_date", type_: "int32", description: "Point in time (Unix timestamp) when restrictions will be lifted from the user; 0 if never. If the user is restricted for more than 366 days or for less than 30 seconds from the current time, the user is considered to be restricted forever" },
+    Parameter { name: "permissions", type_: "chatPermissions", description: "User permissions in the chat" },
+  ] },
+  Constructor { name: "chatMembers", description: "Contains a list of chat members", class: "ChatMembers", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of chat members found" },
+    Parameter { name: "members", type_: "vector<chatMember>", description: "A list of chat members" },
+  ] },
+  Constructor { name: "chatMembersFilterAdministrators", description: "Returns the owner and administrators", class: "ChatMembersFilter", fields: &[] },
+  Constructor { name: "chatMembersFilterBanned", description: "Returns users banned from the chat; can be used only by administrators in a supergroup or in a channel", class: "ChatMembersFilter", fields: &[] },
+  Constructor { name: "chatMembersFilterBots", description: "Returns bot members of the chat", class: "ChatMembersFilter", fields: &[] },
+  Constructor { name: "chatMembersFilterContacts", description: "Returns contacts of the user", class: "ChatMembersFilter", fields: &[] },
+  Constructor { name: "chatMembersFilterMembers", description: "Returns all chat members, including restricted chat members", class: "ChatMembersFilter", fields: &[] },
+  Constructor { name: "chatMembersFilterMention", description: "Returns users which can be mentioned in the chat", class: "ChatMembersFilter", fields: &[
+    Parameter { name: "message_thread_id", type_: "int53", description: "If non-zero, the identifier of the current message thread" },
+  ] },
+  Constructor { name: "chatMembersFilterRestricted", description: "Returns users under certain restrictions in the chat; can be used only by administrators in a supergroup", class: "ChatMembersFilter", fields: &[] },
+  Constructor { name: "chatNearby", description: "Describes a chat located nearby", class: "ChatNearby", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "distance", type_: "int32", description: "Distance to the chat location, in meters" },
+  ] },
+  Constructor { name: "chatNotificationSettings", description: "Contains information about notification settings for a chat", class: "ChatNotificationSettings", fields: &[
+    Parameter { name: "use_default_mute_for", type_: "Bool", description: "If true, mute_for is ignored and the value for the relevant type of chat is used instead" },
+    Parameter { name: "mute_for", type_: "int32", description: "Time left before notifications will be unmuted, in seconds" },
+    Parameter { name: "use_default_sound", type_: "Bool", description: "If true, sound is ignored and the value for the relevant type of chat is used instead" },
+    Parameter { name: "sound", type_: "string", description: "The name of an audio file to be used for notification sounds; only applies to iOS applications" },
+    Parameter { name: "use_default_show_preview", type_: "Bool", description: "If true, show_preview is ignored and the value for the relevant type of chat is used instead" },
+    Parameter { name: "show_preview", type_: "Bool", description: "True, if message content must be displayed in notifications" },
+    Parameter { name: "use_default_disable_pinned_message_notifications", type_: "Bool", description: "If true, disable_pinned_message_notifications is ignored and the value for the relevant type of chat is used instead" },
+    Parameter { name: "disable_pinned_message_notifications", type_: "Bool", description: "If true, notifications for incoming pinned messages will be created as for an ordinary unread message" },
+    Parameter { name: "use_default_disable_mention_notifications", type_: "Bool", description: "If true, disable_mention_notifications is ignored and the value for the relevant type of chat is used instead" },
+    Parameter { name: "disable_mention_notifications", type_: "Bool", description: "If true, notifications for messages with mentions will be created as for an ordinary unread message" },
+  ] },
+  Constructor { name: "chatPermissions", description: "Describes actions that a user is allowed to take in a chat", class: "ChatPermissions", fields: &[
+    Parameter { name: "can_send_messages", type_: "Bool", description: "True, if the user can send text messages, contacts, locations, and venues" },
+    Parameter { name: "can_send_media_messages", type_: "Bool", description: "True, if the user can send audio files, documents, photos, videos, video notes, and voice notes. Implies can_send_messages permissions" },
+    Parameter { name: "can_send_polls", type_: "Bool", description: "True, if the user can send polls. Implies can_send_messages permissions" },
+    Parameter { name: "can_send_other_messages", type_: "Bool", description: "True, if the user can send animations, games, stickers, and dice and use inline bots. Implies can_send_messages permissions" },
+    Parameter { name: "can_add_web_page_previews", type_: "Bool", description: "True, if the user may add a web page preview to their messages. Implies can_send_messages permissions" },
+    Parameter { name: "can_change_info", type_: "Bool", description: "True, if the user can change the chat title, photo, and other settings" },
+    Parameter { name: "can_invite_users", type_: "Bool", description: "True, if the user can invite new users to the chat" },
+    Parameter { name: "can_pin_messages", type_: "Bool", description: "True, if the user can pin messages" },
+  ] },
+  Constructor { name: "chatPhoto", description: "Describes a chat or user profile photo", class: "ChatPhoto", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique photo identifier" },
+    Parameter { name: "added_date", type_: "int32", description: "Point in time (Unix timestamp) when the photo has been added" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "Photo minithumbnail; may be null" },
+    Parameter { name: "sizes", type_: "vector<photoSize>", description: "Available variants of the photo in JPEG format, in different size" },
+    Parameter { name: "animation", type_: "animatedChatPhoto", description: "Animated variant of the photo in MPEG4 format; may be null" },
+  ] },
+  Constructor { name: "chatPhotoInfo", description: "Contains basic information about the photo of a chat", class: "ChatPhotoInfo", fields: &[
+    Parameter { name: "small", type_: "file", description: "A small (160x160) chat photo variant in JPEG format. The file can be downloaded only before the photo is changed" },
+    Parameter { name: "big", type_: "file", description: "A big (640x640) chat photo variant in JPEG format. The file can be downloaded only before the photo is changed" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "Chat photo minithumbnail; may be null" },
+    Parameter { name: "has_animation", type_: "Bool", description: "True, if the photo has animated variant" },
+  ] },
+  Constructor { name: "chatPhotos", description: "Contains a list of chat or user profile photos", class: "ChatPhotos", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Total number of photos" },
+    Parameter { name: "photos", type_: "vector<chatPhoto>", description: "List of photos" },
+  ] },
+  Constructor { name: "chatPosition", description: "Describes a position of a chat in a chat list", class: "ChatPosition", fields: &[
+    Parameter { name: "list", type_: "ChatList", description: "The chat list" },
+    Parameter { name: "order", type_: "int64", description: "A parameter used to determine order of the chat in the chat list. Chats must be sorted by the pair (order, chat.id) in descending order" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the chat is pinned in the chat list" },
+    Parameter { name: "source", type_: "ChatSource", description: "Source of the chat in the chat list; may be null" },
+  ] },
+  Constructor { name: "chatReportReasonChildAbuse", description: "The chat has child abuse related content", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatReportReasonCopyright", description: "The chat contains copyrighted content", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatReportReasonCustom", description: "A custom reason provided by the user", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatReportReasonFake", description: "The chat represents a fake account", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatReportReasonPornography", description: "The chat contains pornographic messages", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatReportReasonSpam", description: "The chat contains spam messages", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatReportReasonUnrelatedLocation", description: "The location-based chat is unrelated to its stated location", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatReportReasonViolence", description: "The chat promotes violence", class: "ChatReportReason", fields: &[] },
+  Constructor { name: "chatSourceMtprotoProxy", description: "The chat is sponsored by the user's MTProxy server", class: "ChatSource", fields: &[] },
+  Constructor { name: "chatSourcePublicServiceAnnouncement", description: "The chat contains a public service announcement", class: "ChatSource", fields: &[
+    Parameter { name: "type", type_: "string", description: "The type of the announcement" },
+    Parameter { name: "text", type_: "string", description: "The text of the announcement" },
+  ] },
+  Constructor { name: "chatStatisticsAdministratorActionsInfo", description: "Contains statistics about administrator actions done by a user", class: "ChatStatisticsAdministratorActionsInfo", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "Administrator user identifier" },
+    Parameter { name: "deleted_message_count", type_: "int32", description: "Number of messages deleted by the administrator" },
+    Parameter { name: "banned_user_count", type_: "int32", description: "Number of users banned by the administrator" },
+    Parameter { name: "restricted_user_count", type_: "int32", description: "Number of users restricted by the administrator" },
+  ] },
+  Constructor { name: "chatStatisticsChannel", description: "A detailed statistics about a channel chat", class: "ChatStatistics", fields: &[
+    Parameter { name: "period", type_: "dateRange", description: "A period to which the statistics applies" },
+    Parameter { name: "member_count", type_: "statisticalValue", description: "Number of members in the chat" },
+    Parameter { name: "mean_view_count", type_: "statisticalValue", description: "Mean number of times the recently sent messages was viewed" },
+    Parameter { name: "mean_share_count", type_: "statisticalValue", description: "Mean number of times the recently sent messages was shared" },
+    Parameter { name: "enabled_notifications_percentage", type_: "double", description: "A percentage of users with enabled notifications for the chat" },
+    Parameter { name: "member_count_graph", type_: "StatisticalGraph", description: "A graph containing number of members in the chat" },
+    Parameter { name: "join_graph", type_: "StatisticalGraph", description: "A graph containing number of members joined and left the chat" },
+    Parameter { name: "mute_graph", type_: "StatisticalGraph", description: "A graph containing number of members muted and unmuted the chat" },
+    Parameter { name: "view_count_by_hour_graph", type_: "StatisticalGraph", description: "A graph containing number of message views in a given hour in the last two weeks" },
+    Parameter { name: "view_count_by_source_graph", type_: "StatisticalGraph", description: "A graph containing number of message views per source" },
+    Parameter { name: "join_by_source_graph", type_: "StatisticalGraph", description: "A graph containing number of new member joins per source" },
+    Parameter { name: "language_graph", type_: "StatisticalGraph", description: "A graph containing number of users viewed chat messages per language" },
+    Parameter { name: "message_interaction_graph", type_: "StatisticalGraph", description: "A graph containing number of chat message views and shares" },
+    Parameter { name: "instant_view_interaction_graph", type_: "StatisticalGraph", description: "A graph containing number of views of associated with the chat instant views" },
+    Parameter { name: "recent_message_interactions", type_: "vector<chatStatisticsMessageInteractionInfo>", description: "Detailed statistics about number of views and shares of recently sent messages" },
+  ] },
+  Constructor { name: "chatStatisticsInviterInfo", description: "Contains statistics about number of new members invited by a user", class: "ChatStatisticsInviterInfo", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "added_member_count", type_: "int32", description: "Number of new members invited by the user" },
+  ] },
+  Constructor { name: "chatStatisticsMessageInteractionInfo", description: "Contains statistics about interactions with a message", class: "ChatStatisticsMessageInteractionInfo", fields: &[
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "view_count", type_: "int32", description: "Number of times the message was viewed" },
+    Parameter { name: "forward_count", type_: "int32", description: "Number of times the message was forwarded" },
+  ] },
+  Constructor { name: "chatStatisticsMessageSenderInfo", description: "Contains statistics about messages sent by a user", class: "ChatStatisticsMessageSenderInfo", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "sent_message_count", type_: "int32", description: "Number of sent messages" },
+    Parameter { name: "average_character_count", type_: "int32", description: "Average number of characters in sent messages; 0 if unknown" },
+  ] },
+  Constructor { name: "chatStatisticsSupergroup", description: "A detailed statistics about a supergroup chat", class: "ChatStatistics", fields: &[
+    Parameter { name: "period", type_: "dateRange", description: "A period to which the statistics applies" },
+    Parameter { name: "member_count", type_: "statisticalValue", description: "Number of members in the chat" },
+    Parameter { name: "message_count", type_: "statisticalValue", description: "Number of messages sent to the chat" },
+    Parameter { name: "viewer_count", type_: "statisticalValue", description: "Number of users who viewed messages in the chat" },
+    Parameter { name: "sender_count", type_: "statisticalValue", description: "Number of users who sent messages to the chat" },
+    Parameter { name: "member_count_graph", type_: "StatisticalGraph", description: "A graph containing number of members in the chat" },
+    Parameter { name: "join_graph", type_: "StatisticalGraph", description: "A graph containing number of members joined and left the chat" },
+    Parameter { name: "join_by_source_graph", type_: "StatisticalGraph", description: "A graph containing number of new member joins per source" },
+    Parameter { name: "language_graph", type_: "StatisticalGraph", description: "A graph containing distribution of active users per language" },
+    Parameter { name: "message_content_graph", type_: "StatisticalGraph", description: "A graph containing distribution of sent messages by content type" },
+    Parameter { name: "action_graph", type_: "StatisticalGraph", description: "A graph containing number of different actions in the chat" },
+    Parameter { name: "day_graph", type_: "StatisticalGraph", description: "A graph containing distribution of message views per hour" },
+    Parameter { name: "week_graph", type_: "StatisticalGraph", description: "A graph containing distribution of message views per day of week" },
+    Parameter { name: "top_senders", type_: "vector<chatStatisticsMessageSenderInfo>", description: "List of users sent most messages in the last week" },
+    Parameter { name: "top_administrators", type_: "vector<chatStatisticsAdministratorActionsInfo>", description: "List of most active administrators in the last week" },
+    Parameter { name: "top_inviters", type_: "vector<chatStatisticsInviterInfo>", description: "List of most active inviters of new members in the last week" },
+  ] },
+  Constructor { name: "chatTheme", description: "Describes a chat theme", class: "ChatTheme", fields: &[
+    Parameter { name: "name", type_: "string", description: "Theme name" },
+    Parameter { name: "light_settings", type_: "themeSettings", description: "Theme settings for a light chat theme" },
+    Parameter { name: "dark_settings", type_: "themeSettings", description: "Theme settings for a dark chat theme" },
+  ] },
+  Constructor { name: "chatTypeBasicGroup", description: "A basic group (a chat with 0-200 other users)", class: "ChatType", fields: &[
+    Parameter { name: "basic_group_id", type_: "int53", description: "Basic group identifier" },
+  ] },
+  Constructor { name: "chatTypePrivate", description: "An ordinary chat with a user", class: "ChatType", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+  ] },
+  Constructor { name: "chatTypeSecret", description: "A secret chat with a user", class: "ChatType", fields: &[
+    Parameter { name: "secret_chat_id", type_: "int53", description: "Secret chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier of the secret chat peer" },
+  ] },
+  Constructor { name: "chatTypeSupergroup", description: "A supergroup or channel (with unlimited members)", class: "ChatType", fields: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Supergroup or channel identifier" },
+    Parameter { name: "is_channel", type_: "Bool", description: "True, if the supergroup is a channel" },
+  ] },
+  Constructor { name: "chats", description: "Represents a list of chats", class: "Chats", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of chats found" },
+    Parameter { name: "chat_ids", type_: "vector<int53>", description: "List of chat identifiers" },
+  ] },
+  Constructor { name: "chatsNearby", description: "Represents a list of chats located nearby", class: "ChatsNearby", fields: &[
+    Parameter { name: "users_nearby", type_: "vector<chatNearby>", description: "List of users nearby" },
+    Parameter { name: "supergroups_nearby", type_: "vector<chatNearby>", description: "List of location-based supergroups nearby" },
+  ] },
+  Constructor { name: "checkChatUsernameResultOk", description: "The username can be set", class: "CheckChatUsernameResult", fields: &[] },
+  Constructor { name: "checkChatUsernameResultPublicChatsTooMuch", description: "The user has too much chats with username, one of them must be made private first", class: "CheckChatUsernameResult", fields: &[] },
+  Constructor { name: "checkChatUsernameResultPublicGroupsUnavailable", description: "The user can't be a member of a public supergroup", class: "CheckChatUsernameResult", fields: &[] },
+  Constructor { name: "checkChatUsernameResultUsernameInvalid", description: "The username is invalid", class: "CheckChatUsernameResult", fields: &[] },
+  Constructor { name: "checkChatUsernameResultUsernameOccupied", description: "The username is occupied", class: "CheckChatUsernameResult", fields: &[] },
+  Constructor { name: "checkStickerSetNameResultNameInvalid", description: "The name is invalid", class: "CheckStickerSetNameResult", fields: &[] },
+  Constructor { name: "checkStickerSetNameResultNameOccupied", description: "The name is occupied", class: "CheckStickerSetNameResult", fields: &[] },
+  Constructor { name: "checkStickerSetNameResultOk", description: "The name can be set", class: "CheckStickerSetNameResult", fields: &[] },
+  Constructor { name: "closedVectorPath", description: "Represents a closed vector path. The path begins at the end point of the last command", class: "ClosedVectorPath", fields: &[
+    Parameter { name: "commands", type_: "vector<VectorPathCommand>", description: "List of vector path commands" },
+  ] },
+  Constructor { name: "colorReplacement", description: "Describes a color replacement for animated emoji", class: "ColorReplacement", fields: &[
+    Parameter { name: "old_color", type_: "int32", description: "Original animated emoji color in the RGB24 format" },
+    Parameter { name: "new_color", type_: "int32", description: "Replacement animated emoji color in the RGB24 format" },
+  ] },
+  Constructor { name: "connectedWebsite", description: "Contains information about one website the current user is logged in with Telegram", class: "ConnectedWebsite", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Website identifier" },
+    Parameter { name: "domain_name", type_: "string", description: "The domain name of the website" },
+    Parameter { name: "bot_user_id", type_: "int53", description: "User identifier of a bot linked with the website" },
+    Parameter { name: "browser", type_: "string", description: "The version of a browser used to log in" },
+    Parameter { name: "platform", type_: "string", description: "Operating system the browser is running on" },
+    Parameter { name: "log_in_date", type_: "int32", description: "Point in time (Unix timestamp) when the user was logged in" },
+    Parameter { name: "last_active_date", type_: "int32", description: "Point in time (Unix timestamp) when obtained authorization was last used" },
+    Parameter { name: "ip", type_: "string", description: "IP address from which the user was logged in, in human-readable format" },
+    Parameter { name: "location", type_: "string", description: "Human-readable description of a country and a region, from which the user was logged in, based on the IP address" },
+  ] },
+  Constructor { name: "connectedWebsites", description: "Contains a list of websites the current user is logged in with Telegram", class: "ConnectedWebsites", fields: &[
+    Parameter { name: "websites", type_: "vector<connectedWebsite>", description: "List of connected websites" },
+  ] },
+  Constructor { name: "connectionStateConnecting", description: "Currently establishing a connection to the Telegram servers", class: "ConnectionState", fields: &[] },
+  Constructor { name: "connectionStateConnectingToProxy", description: "Currently establishing a connection with a proxy server", class: "ConnectionState", fields: &[] },
+  Constructor { name: "connectionStateReady", description: "There is a working connection to the Telegram servers", class: "ConnectionState", fields: &[] },
+  Constructor { name: "connectionStateUpdating", description: "Downloading data received while the application was offline", class: "ConnectionState", fields: &[] },
+  Constructor { name: "connectionStateWaitingForNetwork", description: "Currently waiting for the network to become available. Use setNetworkType to change the available network type", class: "ConnectionState", fields: &[] },
+  Constructor { name: "contact", description: "Describes a user contact", class: "Contact", fields: &[
+    Parameter { name: "phone_number", type_: "string", description: "Phone number of the user" },
+    Parameter { name: "first_name", type_: "string", description: "First name of the user; 1-255 characters in length" },
+    Parameter { name: "last_name", type_: "string", description: "Last name of the user" },
+    Parameter { name: "vcard", type_: "string", description: "Additional data about the user in a form of vCard; 0-2048 bytes in length" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user, if known; otherwise 0" },
+  ] },
+  Constructor { name: "count", description: "Contains a counter", class: "Count", fields: &[
+    Parameter { name: "count", type_: "int32", description: "Count" },
+  ] },
+  Constructor { name: "countries", description: "Contains information about countries", class: "Countries", fields: &[
+    Parameter { name: "countries", type_: "vector<countryInfo>", description: "The list of countries" },
+  ] },
+  Constructor { name: "countryInfo", description: "Contains information about a country", class: "CountryInfo", fields: &[
+    Parameter { name: "country_code", type_: "string", description: "A two-letter ISO 3166-1 alpha-2 country code" },
+    Parameter { name: "name", type_: "string", description: "Native name of the country" },
+    Parameter { name: "english_name", type_: "string", description: "English name of the country" },
+    Parameter { name: "is_hidden", type_: "Bool", description: "True, if the country must be hidden from the list of all countries" },
+    Parameter { name: "calling_codes", type_: "vector<string>", description: "List of country calling codes" },
+  ] },
+  Constructor { name: "customRequestResult", description: "Contains the result of a custom request", class: "CustomRequestResult", fields: &[
+    Parameter { name: "result", type_: "string", description: "A JSON-serialized result" },
+  ] },
+  Constructor { name: "databaseStatistics", description: "Contains database statistics", class: "DatabaseStatistics", fields: &[
+    Parameter { name: "statistics", type_: "string", description: "Database statistics in an unspecified human-readable format" },
+  ] },
+  Constructor { name: "date", description: "Represents a date according to the Gregorian calendar", class: "Date", fields: &[
+    Parameter { name: "day", type_: "int32", description: "Day of the month; 1-31" },
+    Parameter { name: "month", type_: "int32", description: "Month; 1-12" },
+    Parameter { name: "year", type_: "int32", description: "Year; 1-9999" },
+  ] },
+  Constructor { name: "dateRange", description: "Represents a date range", class: "DateRange", fields: &[
+    Parameter { name: "start_date", type_: "int32", description: "Point in time (Unix timestamp) at which the date range begins" },
+    Parameter { name: "end_date", type_: "int32", description: "Point in time (Unix timestamp) at which the date range ends" },
+  ] },
+  Constructor { name: "datedFile", description: "File with the date it was uploaded", class: "DatedFile", fields: &[
+    Parameter { name: "file", type_: "file", description: "The file" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the file was uploaded" },
+  ] },
+  Constructor { name: "deepLinkInfo", description: "Contains information about a tg: deep link", class: "DeepLinkInfo", fields: &[
+    Parameter { name: "text", type_: "formattedText", description: "Text to be shown to the user" },
+    Parameter { name: "need_update_application", type_: "Bool", description: "True, if the user must be asked to update the application" },
+  ] },
+  Constructor { name: "deviceTokenApplePush", description: "A token for Apple Push Notification service", class: "DeviceToken", fields: &[
+    Parameter { name: "device_token", type_: "string", description: "Device token; may be empty to de-register a device" },
+    Parameter { name: "is_app_sandbox", type_: "Bool", description: "True, if App Sandbox is enabled" },
+  ] },
+  Constructor { name: "deviceTokenApplePushVoIP", description: "A token for Apple Push Notification service VoIP notifications", class: "DeviceToken", fields: &[
+    Parameter { name: "device_token", type_: "string", description: "Device token; may be empty to de-register a device" },
+    Parameter { name: "is_app_sandbox", type_: "Bool", description: "True, if App Sandbox is enabled" },
+    Parameter { name: "encrypt", type_: "Bool", description: "True, if push notifications must be additionally encrypted" },
+  ] },
+  Constructor { name: "deviceTokenBlackBerryPush", description: "A token for BlackBerry Push Service", class: "DeviceToken", fields: &[
+    Parameter { name: "token", type_: "string", description: "Token; may be empty to de-register a device" },
+  ] },
+  Constructor { name: "deviceTokenFirebaseCloudMessaging", description: "A token for Firebase Cloud Messaging", class: "DeviceToken", fields: &[
+    Parameter { name: "token", type_: "string", description: "Device registration token; may be empty to de-register a device" },
+    Parameter { name: "encrypt", type_: "Bool", description: "True, if push notifications must be additionally encrypted" },
+  ] },
+  Constructor { name: "deviceTokenMicrosoftPush", description: "A token for Microsoft Push Notification Service", class: "DeviceToken", fields: &[
+    Parameter { name: "channel_uri", type_: "string", description: "Push notification channel URI; may be empty to de-register a device" },
+  ] },
+  Constructor { name: "deviceTokenMicrosoftPushVoIP", description: "A token for Microsoft Push Notification Service VoIP channel", class: "DeviceToken", fields: &[
+    Parameter { name: "channel_uri", type_: "string", description: "Push notification channel URI; may be empty to de-register a device" },
+  ] },
+  Constructor { name: "deviceTokenSimplePush", description: "A token for Simple Push API for Firefox OS", class: "DeviceToken", fields: &[
+    Parameter { name: "endpoint", type_: "string", description: "Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device" },
+  ] },
+  Constructor { name: "deviceTokenTizenPush", description: "A token for Tizen Push Service", class: "DeviceToken", fields: &[
+    Parameter { name: "reg_id", type_: "string", description: "Push service registration identifier; may be empty to de-register a device" },
+  ] },
+  Constructor { name: "deviceTokenUbuntuPush", description: "A token for Ubuntu Push Client service", class: "DeviceToken", fields: &[
+    Parameter { name: "token", type_: "string", description: "Token; may be empty to de-register a device" },
+  ] },
+  Constructor { name: "deviceTokenWebPush", description: "A token for web Push API", class: "DeviceToken", fields: &[
+    Parameter { name: "endpoint", type_: "string", description: "Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device" },
+    Parameter { name: "p256dh_base64url", type_: "string", description: "Base64url-encoded P-256 elliptic curve Diffie-Hellman public key" },
+    Parameter { name: "auth_base64url", type_: "string", description: "Base64url-encoded authentication secret" },
+  ] },
+  Constructor { name: "deviceTokenWindowsPush", description: "A token for Windows Push Notification Services", class: "DeviceToken", fields: &[
+    Parameter { name: "access_token", type_: "string", description: "The access token that will be used to send notifications; may be empty to de-register a device" },
+  ] },
+  Constructor { name: "diceStickersRegular", description: "A regular animated sticker", class: "DiceStickers", fields: &[
+    Parameter { name: "sticker", type_: "sticker", description: "The animated sticker with the dice animation" },
+  ] },
+  Constructor { name: "diceStickersSlotMachine", description: "Animated stickers to be combined into a slot machine", class: "DiceStickers", fields: &[
+    Parameter { name: "background", type_: "sticker", description: "The animated sticker with the slot machine background. The background animation must start playing after all reel animations finish" },
+    Parameter { name: "lever", type_: "sticker", description: "The animated sticker with the lever animation. The lever animation must play once in the initial dice state" },
+    Parameter { name: "left_reel", type_: "sticker", description: "The animated sticker with the left reel" },
+    Parameter { name: "center_reel", type_: "sticker", description: "The animated sticker with the center reel" },
+    Parameter { name: "right_reel", type_: "sticker", description: "The animated sticker with the right reel" },
+  ] },
+  Constructor { name: "document", description: "Describes a document of any type", class: "Document", fields: &[
+    Parameter { name: "file_name", type_: "string", description: "Original name of the file; as defined by the sender" },
+    Parameter { name: "mime_type", type_: "string", description: "MIME type of the file; as defined by the sender" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "Document minithumbnail; may be null" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Document thumbnail in JPEG or PNG format (PNG will be used only for background patterns); as defined by the sender; may be null" },
+    Parameter { name: "document", type_: "file", description: "File containing the document" },
+  ] },
+  Constructor { name: "draftMessage", description: "Contains information about a message draft", class: "DraftMessage", fields: &[
+    Parameter { name: "reply_to_message_id", type_: "int53", description: "Identifier of the message to reply to; 0 if none" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the draft was created" },
+    Parameter { name: "input_message_text", type_: "InputMessageContent", description: "Content of the message draft; must be of the type inputMessageText" },
+  ] },
+  Constructor { name: "emailAddressAuthenticationCodeInfo", description: "Information about the email address authentication code that was sent", class: "EmailAddressAuthenticationCodeInfo", fields: &[
+    Parameter { name: "email_address_pattern", type_: "string", description: "Pattern of the email address to which an authentication code was sent" },
+    Parameter { name: "length", type_: "int32", description: "Length of the code; 0 if unknown" },
+  ] },
+  Constructor { name: "emojis", description: "Represents a list of emoji", class: "Emojis", fields: &[
+    Parameter { name: "emojis", type_: "vector<string>", description: "List of emojis" },
+  ] },
+  Constructor { name: "encryptedCredentials", description: "Contains encrypted Telegram Passport data credentials", class: "EncryptedCredentials", fields: &[
+    Parameter { name: "data", type_: "bytes", description: "The encrypted credentials" },
+    Parameter { name: "hash", type_: "bytes", description: "The decrypted data hash" },
+    Parameter { name: "secret", type_: "bytes", description: "Secret for data decryption, encrypted with the service's public key" },
+  ] },
+  Constructor { name: "encryptedPassportElement", description: "Contains information about an encrypted Telegram Passport element; for bots only", class: "EncryptedPassportElement", fields: &[
+    Parameter { name: "type", type_: "PassportElementType", description: "Type of Telegram Passport element" },
+    Parameter { name: "data", type_: "bytes", description: "Encrypted JSON-encoded data about the user" },
+    Parameter { name: "front_side", type_: "datedFile", description: "The front side of an identity document" },
+    Parameter { name: "reverse_side", type_: "datedFile", description: "The reverse side of an identity document; may be null" },
+    Parameter { name: "selfie", type_: "datedFile", description: "Selfie with the document; may be null" },
+    Parameter { name: "translation", type_: "vector<datedFile>", description: "List of files containing a certified English translation of the document" },
+    Parameter { name: "files", type_: "vector<datedFile>", description: "List of attached files" },
+    Parameter { name: "value", type_: "string", description: "Unencrypted data, phone number or email address" },
+    Parameter { name: "hash", type_: "string", description: "Hash of the entire element" },
+  ] },
+  Constructor { name: "error", description: "An object of this type can be returned on every function call, in case of an error", class: "Error", fields: &[
+    Parameter { name: "code", type_: "int32", description: "Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user" },
+    Parameter { name: "message", type_: "string", description: "Error message; subject to future changes" },
+  ] },
+  Constructor { name: "file", description: "Represents a file", class: "File", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique file identifier" },
+    Parameter { name: "size", type_: "int32", description: "File size, in bytes; 0 if unknown" },
+    Parameter { name: "expected_size", type_: "int32", description: "Approximate file size in bytes in case the exact file size is unknown. Can be used to show download/upload progress" },
+    Parameter { name: "local", type_: "localFile", description: "Information about the local copy of the file" },
+    Parameter { name: "remote", type_: "remoteFile", description: "Information about the remote copy of the file" },
+  ] },
+  Constructor { name: "filePart", description: "Contains a part of a file", class: "FilePart", fields: &[
+    Parameter { name: "data", type_: "bytes", description: "File bytes" },
+  ] },
+  Constructor { name: "fileTypeAnimation", description: "The file is an animation", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeAudio", description: "The file is an audio file", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeDocument", description: "The file is a document", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeNone", description: "The data is not a file", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypePhoto", description: "The file is a photo", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeProfilePhoto", description: "The file is a profile photo", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeSecret", description: "The file was sent to a secret chat (the file type is not known to the server)", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeSecretThumbnail", description: "The file is a thumbnail of a file from a secret chat", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeSecure", description: "The file is a file from Secure storage used for storing Telegram Passport files", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeSticker", description: "The file is a sticker", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeThumbnail", description: "The file is a thumbnail of another file", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeUnknown", description: "The file type is not yet known", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeVideo", description: "The file is a video", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeVideoNote", description: "The file is a video note", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeVoiceNote", description: "The file is a voice note", class: "FileType", fields: &[] },
+  Constructor { name: "fileTypeWallpaper", description: "The file is a wallpaper or a background pattern", class: "FileType", fields: &[] },
+  Constructor { name: "formattedText", description: "A text with some entities", class: "FormattedText", fields: &[
+    Parameter { name: "text", type_: "string", description: "The text" },
+    Parameter { name: "entities", type_: "vector<textEntity>", description: "Entities contained in the text. Entities can be nested, but must not mutually intersect with each other. Pre, Code and PreCode entities can't contain other entities. Bold, Italic, Underline and Strikethrough entities can contain and to be contained in all other entities. All other entities can't contain each other" },
+  ] },
+  Constructor { name: "foundMessages", description: "Contains a list of messages found by a search", class: "FoundMessages", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of messages found; 1 if unknown" },
+    Parameter { name: "messages", type_: "vector<message>", description: "List of messages" },
+    Parameter { name: "next_offset", type_: "string", description: "The offset for the next request. If empty, there are no more results" },
+  ] },
+  Constructor { name: "game", description: "Describes a game", class: "Game", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Game ID" },
+    Parameter { name: "short_name", type_: "string", description: "Game short name. To share a game use the URL https://t.me/{bot_username}?game={game_short_name}" },
+    Parameter { name: "title", type_: "string", description: "Game title" },
+    Parameter { name: "text", type_: "formattedText", description: "Game text, usually containing scoreboards for a game" },
+    Parameter { name: "description", type_: "string", description: "Game description" },
+    Parameter { name: "photo", type_: "photo", description: "Game photo" },
+    Parameter { name: "animation", type_: "animation", description: "Game animation; may be null" },
+  ] },
+  Constructor { name: "gameHighScore", description: "Contains one row of the game high score table", class: "GameHighScore", fields: &[
+    Parameter { name: "position", type_: "int32", description: "Position in the high score table" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "score", type_: "int32", description: "User score" },
+  ] },
+  Constructor { name: "gameHighScores", description: "Contains a list of game high scores", class: "GameHighScores", fields: &[
+    Parameter { name: "scores", type_: "vector<gameHighScore>", description: "A list of game high scores" },
+  ] },
+  Constructor { name: "groupCall", description: "Describes a group call", class: "GroupCall", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "title", type_: "string", description: "Group call title" },
+    Parameter { name: "scheduled_start_date", type_: "int32", description: "Point in time (Unix timestamp) when the group call is supposed to be started by an administrator; 0 if it is already active or was ended" },
+    Parameter { name: "enabled_start_notification", type_: "Bool", description: "True, if the group call is scheduled and the current user will receive a notification when the group call will start" },
+    Parameter { name: "is_active", type_: "Bool", description: "True, if the call is active" },
+    Parameter { name: "is_joined", type_: "Bool", description: "True, if the call is joined" },
+    Parameter { name: "need_rejoin", type_: "Bool", description: "True, if user was kicked from the call because of network loss and the call needs to be rejoined" },
+    Parameter { name: "can_be_managed", type_: "Bool", description: "True, if the current user can manage the group call" },
+    Parameter { name: "participant_count", type_: "int32", description: "Number of participants in the group call" },
+    Parameter { name: "loaded_all_participants", type_: "Bool", description: "True, if all group call participants are loaded" },
+    Parameter { name: "recent_speakers", type_: "vector<groupCallRecentSpeaker>", description: "Recently speaking users in the group call" },
+    Parameter { name: "is_my_video_enabled", type_: "Bool", description: "True, if the current user's video is enabled" },
+    Parameter { name: "is_my_video_paused", type_: "Bool", description: "True, if the current user's video is paused" },
+    Parameter { name: "can_enable_video", type_: "Bool", description: "True, if the current user can broadcast video or share screen" },
+    Parameter { name: "mute_new_participants", type_: "Bool", description: "True, if only group call administrators can unmute new participants" },
+    Parameter { name: "can_toggle_mute_new_participants", type_: "Bool", description: "True, if the current user can enable or disable mute_new_participants setting" },
+    Parameter { name: "record_duration", type_: "int32", description: "Duration of the ongoing group call recording, in seconds; 0 if none. An updateGroupCall update is not triggered when value of this field changes, but the same recording goes on" },
+    Parameter { name: "is_video_recorded", type_: "Bool", description: "True, if a video file is being recorded for the call" },
+    Parameter { name: "duration", type_: "int32", description: "Call duration, in seconds; for ended calls only" },
+  ] },
+  Constructor { name: "groupCallId", description: "Contains the group call identifier", class: "GroupCallId", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Constructor { name: "groupCallParticipant", description: "Represents a group call participant", class: "GroupCallParticipant", fields: &[
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Identifier of the group call participant" },
+    Parameter { name: "audio_source_id", type_: "int32", description: "User's audio channel synchronization source identifier" },
+    Parameter { name: "screen_sharing_audio_source_id", type_: "int32", description: "User's screen sharing audio channel synchronization source identifier" },
+    Parameter { name: "video_info", type_: "groupCallParticipantVideoInfo", description: "Information about user's video channel; may be null if there is no active video" },
+    Parameter { name: "screen_sharing_video_info", type_: "groupCallParticipantVideoInfo", description: "Information about user's screen sharing video channel; may be null if there is no active screen sharing video" },
+    Parameter { name: "bio", type_: "string", description: "The participant user's bio or the participant chat's description" },
+    Parameter { name: "is_current_user", type_: "Bool", description: "True, if the participant is the current user" },
+    Parameter { name: "is_speaking", type_: "Bool", description: "True, if the participant is speaking as set by setGroupCallParticipantIsSpeaking" },
+    Parameter { name: "is_hand_raised", type_: "Bool", description: "True, if the participant hand is raised" },
+    Parameter { name: "can_be_muted_for_all_users", type_: "Bool", description: "True, if the current user can mute the participant for all other group call participants" },
+    Parameter { name: "can_be_unmuted_for_all_users", type_: "Bool", description: "True, if the current user can allow the participant to unmute themselves or unmute the participant (if the participant is the current user)" },
+    Parameter { name: "can_be_muted_for_current_user", type_: "Bool", description: "True, if the current user can mute the participant only for self" },
+    Parameter { name: "can_be_unmuted_for_current_user", type_: "Bool", description: "True, if the current user can unmute the participant for self" },
+    Parameter { name: "is_muted_for_all_users", type_: "Bool", description: "True, if the participant is muted for all users" },
+    Parameter { name: "is_muted_for_current_user", type_: "Bool", description: "True, if the participant is muted for the current user" },
+    Parameter { name: "can_unmute_self", type_: "Bool", description: "True, if the participant is muted for all users, but can unmute themselves" },
+    Parameter { name: "volume_level", type_: "int32", description: "Participant's volume level; 1-20000 in hundreds of percents" },
+    Parameter { name: "order", type_: "string", description: "User's order in the group call participant list. Orders must be compared lexicographically. The bigger is order, the higher is user in the list. If order is empty, the user must be removed from the participant list" },
+  ] },
+  Constructor { name: "groupCallParticipantVideoInfo", description: "Contains information about a group call participant's video channel", class: "GroupCallParticipantVideoInfo", fields: &[
+    Parameter { name: "source_groups", type_: "vector<groupCallVideoSourceGroup>", description: "List of synchronization source groups of the video" },
+    Parameter { name: "endpoint_id", type_: "string", description: "Video channel endpoint identifier" },
+    Parameter { name: "is_paused", type_: "Bool", description: "True if the video is paused. This flag needs to be ignored, if new video frames are received" },
+  ] },
+  Constructor { name: "groupCallRecentSpeaker", description: "Describes a recently speaking participant in a group call", class: "GroupCallRecentSpeaker", fields: &[
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Group call participant identifier" },
+    Parameter { name: "is_speaking", type_: "Bool", description: "True, is the user has spoken recently" },
+  ] },
+  Constructor { name: "groupCallVideoQualityFull", description: "The best available video quality", class: "GroupCallVideoQuality", fields: &[] },
+  Constructor { name: "groupCallVideoQualityMedium", description: "The medium video quality", class: "GroupCallVideoQuality", fields: &[] },
+  Constructor { name: "groupCallVideoQualityThumbnail", description: "The worst available video quality", class: "GroupCallVideoQuality", fields: &[] },
+  Constructor { name: "groupCallVideoSourceGroup", description: "Describes a group of video synchronization source identifiers", class: "GroupCallVideoSourceGroup", fields: &[
+    Parameter { name: "semantics", type_: "string", description: "The semantics of sources, one of \"SIM\" or \"FID\"" },
+    Parameter { name: "source_ids", type_: "vector<int32>", description: "The list of synchronization source identifiers" },
+  ] },
+  Constructor { name: "hashtags", description: "Contains a list of hashtags", class: "Hashtags", fields: &[
+    Parameter { name: "hashtags", type_: "vector<string>", description: "A list of hashtags" },
+  ] },
+  Constructor { name: "httpUrl", description: "Contains an HTTP URL", class: "HttpUrl", fields: &[
+    Parameter { name: "url", type_: "string", description: "The URL" },
+  ] },
+  Constructor { name: "identityDocument", description: "An identity document", class: "IdentityDocument", fields: &[
+    Parameter { name: "number", type_: "string", description: "Document number; 1-24 characters" },
+    Parameter { name: "expiry_date", type_: "date", description: "Document expiry date; may be null if not applicable" },
+    Parameter { name: "front_side", type_: "datedFile", description: "Front side of the document" },
+    Parameter { name: "reverse_side", type_: "datedFile", description: "Reverse side of the document; only for driver license and identity card; may be null" },
+    Parameter { name: "selfie", type_: "datedFile", description: "Selfie with the document; may be null" },
+    Parameter { name: "translation", type_: "vector<datedFile>", description: "List of files containing a certified English translation of the document" },
+  ] },
+  Constructor { name: "importedContacts", description: "Represents the result of an ImportContacts request", class: "ImportedContacts", fields: &[
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "User identifiers of the imported contacts in the same order as they were specified in the request; 0 if the contact is not yet a registered user" },
+    Parameter { name: "importer_count", type_: "vector<int32>", description: "The number of users that imported the corresponding contact; 0 for already registered users or if unavailable" },
+  ] },
+  Constructor { name: "inlineKeyboardButton", description: "Represents a single button in an inline keyboard", class: "InlineKeyboardButton", fields: &[
+    Parameter { name: "text", type_: "string", description: "Text of the button" },
+    Parameter { name: "type", type_: "InlineKeyboardButtonType", description: "Type of the button" },
+  ] },
+  Constructor { name: "inlineKeyboardButtonTypeBuy", description: "A button to buy something. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageInvoice", class: "InlineKeyboardButtonType", fields: &[] },
+  Constructor { name: "inlineKeyboardButtonTypeCallback", description: "A button that sends a callback query to a bot", class: "InlineKeyboardButtonType", fields: &[
+    Parameter { name: "data", type_: "bytes", description: "Data to be sent to the bot via a callback query" },
+  ] },
+  Constructor { name: "inlineKeyboardButtonTypeCallbackGame", description: "A button with a game that sends a callback query to a bot. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageGame", class: "InlineKeyboardButtonType", fields: &[] },
+  Constructor { name: "inlineKeyboardButtonTypeCallbackWithPassword", description: "A button that asks for password of the current user and then sends a callback query to a bot", class: "InlineKeyboardButtonType", fields: &[
+    Parameter { name: "data", type_: "bytes", description: "Data to be sent to the bot via a callback query" },
+  ] },
+  Constructor { name: "inlineKeyboardButtonTypeLoginUrl", description: "A button that opens a specified URL and automatically authorize the current user if allowed to do so", class: "InlineKeyboardButtonType", fields: &[
+    Parameter { name: "url", type_: "string", description: "An HTTP URL to open" },
+    Parameter { name: "id", type_: "int32", description: "Unique button identifier" },
+    Parameter { name: "forward_text", type_: "string", description: "If non-empty, new text of the button in forwarded messages" },
+  ] },
+  Constructor { name: "inlineKeyboardButtonTypeSwitchInline", description: "A button that forces an inline query to the bot to be inserted in the input field", class: "InlineKeyboardButtonType", fields: &[
+    Parameter { name: "query", type_: "string", description: "Inline query to be sent to the bot" },
+    Parameter { name: "in_current_chat", type_: "Bool", description: "True, if the inline query must be sent from the current chat" },
+  ] },
+  Constructor { name: "inlineKeyboardButtonTypeUrl", description: "A button that opens a specified URL", class: "InlineKeyboardButtonType", fields: &[
+    Parameter { name: "url", type_: "string", description: "HTTP or tg:// URL to open" },
+  ] },
+  Constructor { name: "inlineQueryResultAnimation", description: "Represents an animation file", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "animation", type_: "animation", description: "Animation file" },
+    Parameter { name: "title", type_: "string", description: "Animation title" },
+  ] },
+  Constructor { name: "inlineQueryResultArticle", description: "Represents a link to an article or web page", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "url", type_: "string", description: "URL of the result, if it exists" },
+    Parameter { name: "hide_url", type_: "Bool", description: "True, if the URL must be not shown" },
+    Parameter { name: "title", type_: "string", description: "Title of the result" },
+    Parameter { name: "description", type_: "string", description: "Description of the result" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Result thumbnail in JPEG format; may be null" },
+  ] },
+  Constructor { name: "inlineQueryResultAudio", description: "Represents an audio file", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "audio", type_: "audio", description: "Audio file" },
+  ] },
+  Constructor { name: "inlineQueryResultContact", description: "Represents a user contact", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "contact", type_: "contact", description: "A user contact" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Result thumbnail in JPEG format; may be null" },
+  ] },
+  Constructor { name: "inlineQueryResultDocument", description: "Represents a document", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "document", type_: "document", description: "Document" },
+    Parameter { name: "title", type_: "string", description: "Document title" },
+    Parameter { name: "description", type_: "string", description: "Document description" },
+  ] },
+  Constructor { name: "inlineQueryResultGame", description: "Represents information about a game", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "game", type_: "game", description: "Game result" },
+  ] },
+  Constructor { name: "inlineQueryResultLocation", description: "Represents a point on the map", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "location", type_: "location", description: "Location result" },
+    Parameter { name: "title", type_: "string", description: "Title of the result" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Result thumbnail in JPEG format; may be null" },
+  ] },
+  Constructor { name: "inlineQueryResultPhoto", description: "Represents a photo", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "photo", type_: "photo", description: "Photo" },
+    Parameter { name: "title", type_: "string", description: "Title of the result, if known" },
+    Parameter { name: "description", type_: "string", description: "Photo description" },
+  ] },
+  Constructor { name: "inlineQueryResultSticker", description: "Represents a sticker", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "sticker", type_: "sticker", description: "Sticker" },
+  ] },
+  Constructor { name: "inlineQueryResultVenue", description: "Represents information about a venue", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "venue", type_: "venue", description: "Venue result" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Result thumbnail in JPEG format; may be null" },
+  ] },
+  Constructor { name: "inlineQueryResultVideo", description: "Represents a video", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "video", type_: "video", description: "Video" },
+    Parameter { name: "title", type_: "string", description: "Title of the video" },
+    Parameter { name: "description", type_: "string", description: "Description of the video" },
+  ] },
+  Constructor { name: "inlineQueryResultVoiceNote", description: "Represents a voice note", class: "InlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "voice_note", type_: "voiceNote", description: "Voice note" },
+    Parameter { name: "title", type_: "string", description: "Title of the voice note" },
+  ] },
+  Constructor { name: "inlineQueryResults", description: "Represents the results of the inline query. Use sendInlineQueryResultMessage to send the result of the query", class: "InlineQueryResults", fields: &[
+    Parameter { name: "inline_query_id", type_: "int64", description: "Unique identifier of the inline query" },
+    Parameter { name: "next_offset", type_: "string", description: "The offset for the next request. If empty, there are no more results" },
+    Parameter { name: "results", type_: "vector<InlineQueryResult>", description: "Results of the query" },
+    Parameter { name: "switch_pm_text", type_: "string", description: "If non-empty, this text must be shown on the button, which opens a private chat with the bot and sends the bot a start message with the switch_pm_parameter" },
+    Parameter { name: "switch_pm_parameter", type_: "string", description: "Parameter for the bot start message" },
+  ] },
+  Constructor { name: "inputBackgroundLocal", description: "A background from a local file", class: "InputBackground", fields: &[
+    Parameter { name: "background", type_: "InputFile", description: "Background file to use. Only inputFileLocal and inputFileGenerated are supported. The file must be in JPEG format for wallpapers and in PNG format for patterns" },
+  ] },
+  Constructor { name: "inputBackgroundRemote", description: "A background from the server", class: "InputBackground", fields: &[
+    Parameter { name: "background_id", type_: "int64", description: "The background identifier" },
+  ] },
+  Constructor { name: "inputChatPhotoAnimation", description: "An animation in MPEG4 format; must be square, at most 10 seconds long, have width between 160 and 800 and be at most 2MB in size", class: "InputChatPhoto", fields: &[
+    Parameter { name: "animation", type_: "InputFile", description: "Animation to be set as profile photo. Only inputFileLocal and inputFileGenerated are allowed" },
+    Parameter { name: "main_frame_timestamp", type_: "double", description: "Timestamp of the frame, which will be used as static chat photo" },
+  ] },
+  Constructor { name: "inputChatPhotoPrevious", description: "A previously used profile photo of the current user", class: "InputChatPhoto", fields: &[
+    Parameter { name: "chat_photo_id", type_: "int64", description: "Identifier of the current user's profile photo to reuse" },
+  ] },
+  Constructor { name: "inputChatPhotoStatic", description: "A static photo in JPEG format", class: "InputChatPhoto", fields: &[
+    Parameter { name: "photo", type_: "InputFile", description: "Photo to be set as profile photo. Only inputFileLocal and inputFileGenerated are allowed" },
+  ] },
+  Constructor { name: "inputCredentialsApplePay", description: "Applies if a user enters new credentials using Apple Pay", class: "InputCredentials", fields: &[
+    Parameter { name: "data", type_: "string", description: "JSON-encoded data with the credential identifier" },
+  ] },
+  Constructor { name: "inputCredentialsGooglePay", description: "Applies if a user enters new credentials using Google Pay", class: "InputCredentials", fields: &[
+    Parameter { name: "data", type_: "string", description: "JSON-encoded data with the credential identifier" },
+  ] },
+  Constructor { name: "inputCredentialsNew", description: "Applies if a user enters new credentials on a payment provider website", class: "InputCredentials", fields: &[
+    Parameter { name: "data", type_: "string", description: "JSON-encoded data with the credential identifier from the payment provider" },
+    Parameter { name: "allow_save", type_: "Bool", description: "True, if the credential identifier can be saved on the server side" },
+  ] },
+  Constructor { name: "inputCredentialsSaved", description: "Applies if a user chooses some previously saved payment credentials. To use their previously saved credentials, the user must have a valid temporary password", class: "InputCredentials", fields: &[
+    Parameter { name: "saved_credentials_id", type_: "string", description: "Identifier of the saved credentials" },
+  ] },
+  Constructor { name: "inputFileGenerated", description: "A file generated by the application", class: "InputFile", fields: &[
+    Parameter { name: "original_path", type_: "string", description: "Local path to a file from which the file is generated; may be empty if there is no such file" },
+    Parameter { name: "conversion", type_: "string", description: "String specifying the conversion applied to the original file; must be persistent across application restarts. Conversions beginning with '#' are reserved for internal TDLib usage" },
+    Parameter { name: "expected_size", type_: "int32", description: "Expected size of the generated file, in bytes; 0 if unknown" },
+  ] },
+  Constructor { name: "inputFileId", description: "A file defined by its unique ID", class: "InputFile", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique file identifier" },
+  ] },
+  Constructor { name: "inputFileLocal", description: "A file defined by a local path", class: "InputFile", fields: &[
+    Parameter { name: "path", type_: "string", description: "Local path to the file" },
+  ] },
+  Constructor { name: "inputFileRemote", description: "A file defined by its remote ID. The remote ID is guaranteed to be usable only if the corresponding file is still accessible to the user and known to TDLib. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the application", class: "InputFile", fields: &[
+    Parameter { name: "id", type_: "string", description: "Remote file identifier" },
+  ] },
+  Constructor { name: "inputIdentityDocument", description: "An identity document to be saved to Telegram Passport", class: "InputIdentityDocument", fields: &[
+    Parameter { name: "number", type_: "string", description: "Document number; 1-24 characters" },
+    Parameter { name: "expiry_date", type_: "date", description: "Document expiry date; pass null if not applicable" },
+    Parameter { name: "front_side", type_: "InputFile", description: "Front side of the document" },
+    Parameter { name: "reverse_side", type_: "InputFile", description: "Reverse side of the document; only for driver license and identity card; pass null otherwise" },
+    Parameter { name: "selfie", type_: "InputFile", description: "Selfie with the document; pass null if unavailable" },
+    Parameter { name: "translation", type_: "vector<InputFile>", description: "List of files containing a certified English translation of the document" },
+  ] },
+  Constructor { name: "inputInlineQueryResultAnimation", description: "Represents a link to an animated GIF or an animated (i.e., without sound) H.264/MPEG-4 AVC video", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "title", type_: "string", description: "Title of the query result" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "URL of the result thumbnail (JPEG, GIF, or MPEG4), if it exists" },
+    Parameter { name: "thumbnail_mime_type", type_: "string", description: "MIME type of the video thumbnail. If non-empty, must be one of \"image/jpeg\", \"image/gif\" and \"video/mp4\"" },
+    Parameter { name: "video_url", type_: "string", description: "The URL of the video file (file size must not exceed 1MB)" },
+    Parameter { name: "video_mime_type", type_: "string", description: "MIME type of the video file. Must be one of \"image/gif\" and \"video/mp4\"" },
+    Parameter { name: "video_duration", type_: "int32", description: "Duration of the video, in seconds" },
+    Parameter { name: "video_width", type_: "int32", description: "Width of the video" },
+    Parameter { name: "video_height", type_: "int32", description: "Height of the video" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageAnimation, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultArticle", description: "Represents a link to an article or web page", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "url", type_: "string", description: "URL of the result, if it exists" },
+    Parameter { name: "hide_url", type_: "Bool", description: "True, if the URL must be not shown" },
+    Parameter { name: "title", type_: "string", description: "Title of the result" },
+    Parameter { name: "description", type_: "string", description: "A short description of the result" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "URL of the result thumbnail, if it exists" },
+    Parameter { name: "thumbnail_width", type_: "int32", description: "Thumbnail width, if known" },
+    Parameter { name: "thumbnail_height", type_: "int32", description: "Thumbnail height, if known" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultAudio", description: "Represents a link to an MP3 audio file", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "title", type_: "string", description: "Title of the audio file" },
+    Parameter { name: "performer", type_: "string", description: "Performer of the audio file" },
+    Parameter { name: "audio_url", type_: "string", description: "The URL of the audio file" },
+    Parameter { name: "audio_duration", type_: "int32", description: "Audio file duration, in seconds" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageAudio, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultContact", description: "Represents a user contact", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "contact", type_: "contact", description: "User contact" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "URL of the result thumbnail, if it exists" },
+    Parameter { name: "thumbnail_width", type_: "int32", description: "Thumbnail width, if known" },
+    Parameter { name: "thumbnail_height", type_: "int32", description: "Thumbnail height, if known" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultDocument", description: "Represents a link to a file", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "title", type_: "string", description: "Title of the resulting file" },
+    Parameter { name: "description", type_: "string", description: "Short description of the result, if known" },
+    Parameter { name: "document_url", type_: "string", description: "URL of the file" },
+    Parameter { name: "mime_type", type_: "string", description: "MIME type of the file content; only \"application/pdf\" and \"application/zip\" are currently allowed" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "The URL of the file thumbnail, if it exists" },
+    Parameter { name: "thumbnail_width", type_: "int32", description: "Width of the thumbnail" },
+    Parameter { name: "thumbnail_height", type_: "int32", description: "Height of the thumbnail" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageDocument, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultGame", description: "Represents a game", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "game_short_name", type_: "string", description: "Short name of the game" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+  ] },
+  Constructor { name: "inputInlineQueryResultLocation", description: "Represents a point on the map", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "location", type_: "location", description: "Location result" },
+    Parameter { name: "live_period", type_: "int32", description: "Amount of time relative to the message sent time until the location can be updated, in seconds" },
+    Parameter { name: "title", type_: "string", description: "Title of the result" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "URL of the result thumbnail, if it exists" },
+    Parameter { name: "thumbnail_width", type_: "int32", description: "Thumbnail width, if known" },
+    Parameter { name: "thumbnail_height", type_: "int32", description: "Thumbnail height, if known" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultPhoto", description: "Represents link to a JPEG image", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "title", type_: "string", description: "Title of the result, if known" },
+    Parameter { name: "description", type_: "string", description: "A short description of the result, if known" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "URL of the photo thumbnail, if it exists" },
+    Parameter { name: "photo_url", type_: "string", description: "The URL of the JPEG photo (photo size must not exceed 5MB)" },
+    Parameter { name: "photo_width", type_: "int32", description: "Width of the photo" },
+    Parameter { name: "photo_height", type_: "int32", description: "Height of the photo" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessagePhoto, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultSticker", description: "Represents a link to a WEBP or TGS sticker", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "URL of the sticker thumbnail, if it exists" },
+    Parameter { name: "sticker_url", type_: "string", description: "The URL of the WEBP or TGS sticker (sticker file size must not exceed 5MB)" },
+    Parameter { name: "sticker_width", type_: "int32", description: "Width of the sticker" },
+    Parameter { name: "sticker_height", type_: "int32", description: "Height of the sticker" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageSticker, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultVenue", description: "Represents information about a venue", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "venue", type_: "venue", description: "Venue result" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "URL of the result thumbnail, if it exists" },
+    Parameter { name: "thumbnail_width", type_: "int32", description: "Thumbnail width, if known" },
+    Parameter { name: "thumbnail_height", type_: "int32", description: "Thumbnail height, if known" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultVideo", description: "Represents a link to a page containing an embedded video player or a video file", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "title", type_: "string", description: "Title of the result" },
+    Parameter { name: "description", type_: "string", description: "A short description of the result, if known" },
+    Parameter { name: "thumbnail_url", type_: "string", description: "The URL of the video thumbnail (JPEG), if it exists" },
+    Parameter { name: "video_url", type_: "string", description: "URL of the embedded video player or video file" },
+    Parameter { name: "mime_type", type_: "string", description: "MIME type of the content of the video URL, only \"text/html\" or \"video/mp4\" are currently supported" },
+    Parameter { name: "video_width", type_: "int32", description: "Width of the video" },
+    Parameter { name: "video_height", type_: "int32", description: "Height of the video" },
+    Parameter { name: "video_duration", type_: "int32", description: "Video duration, in seconds" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageVideo, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputInlineQueryResultVoiceNote", description: "Represents a link to an opus-encoded audio file within an OGG container, single channel audio", class: "InputInlineQueryResult", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the query result" },
+    Parameter { name: "title", type_: "string", description: "Title of the voice note" },
+    Parameter { name: "voice_note_url", type_: "string", description: "The URL of the voice note file" },
+    Parameter { name: "voice_note_duration", type_: "int32", description: "Duration of the voice note, in seconds" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The message reply markup; pass null if none. Must be of type replyMarkupInlineKeyboard or null" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent. Must be one of the following types: inputMessageText, inputMessageVoiceNote, inputMessageInvoice, inputMessageLocation, inputMessageVenue or inputMessageContact" },
+  ] },
+  Constructor { name: "inputMessageAnimation", description: "An animation message (GIF-style).", class: "InputMessageContent", fields: &[
+    Parameter { name: "animation", type_: "InputFile", description: "Animation file to be sent" },
+    Parameter { name: "thumbnail", type_: "inputThumbnail", description: "Animation thumbnail; pass null to skip thumbnail uploading" },
+    Parameter { name: "added_sticker_file_ids", type_: "vector<int32>", description: "File identifiers of the stickers added to the animation, if applicable" },
+    Parameter { name: "duration", type_: "int32", description: "Duration of the animation, in seconds" },
+    Parameter { name: "width", type_: "int32", description: "Width of the animation; may be replaced by the server" },
+    Parameter { name: "height", type_: "int32", description: "Height of the animation; may be replaced by the server" },
+    Parameter { name: "caption", type_: "formattedText", description: "Animation caption; pass null to use an empty caption; 0-GetOption(\"message_caption_length_max\") characters" },
+  ] },
+  Constructor { name: "inputMessageAudio", description: "An audio message", class: "InputMessageContent", fields: &[
+    Parameter { name: "audio", type_: "InputFile", description: "Audio file to be sent" },
+    Parameter { name: "album_cover_thumbnail", type_: "inputThumbnail", description: "Thumbnail of the cover for the album; pass null to skip thumbnail uploading" },
+    Parameter { name: "duration", type_: "int32", description: "Duration of the audio, in seconds; may be replaced by the server" },
+    Parameter { name: "title", type_: "string", description: "Title of the audio; 0-64 characters; may be replaced by the server" },
+    Parameter { name: "performer", type_: "string", description: "Performer of the audio; 0-64 characters, may be replaced by the server" },
+    Parameter { name: "caption", type_: "formattedText", description: "Audio caption; pass null to use an empty caption; 0-GetOption(\"message_caption_length_max\") characters" },
+  ] },
+  Constructor { name: "inputMessageContact", description: "A message containing a user contact", class: "InputMessageContent", fields: &[
+    Parameter { name: "contact", type_: "contact", description: "Contact to send" },
+  ] },
+  Constructor { name: "inputMessageDice", description: "A dice message", class: "InputMessageContent", fields: &[
+    Parameter { name: "emoji", type_: "string", description: "Emoji on which the dice throw animation is based" },
+    Parameter { name: "clear_draft", type_: "Bool", description: "True, if the chat message draft must be deleted" },
+  ] },
+  Constructor { name: "inputMessageDocument", description: "A document message (general file)", class: "InputMessageContent", fields: &[
+    Parameter { name: "document", type_: "InputFile", description: "Document to be sent" },
+    Parameter { name: "thumbnail", type_: "inputThumbnail", description: "Document thumbnail; pass null to skip thumbnail uploading" },
+    Parameter { name: "disable_content_type_detection", type_: "Bool", description: "If true, automatic file type detection will be disabled and the document will be always sent as file. Always true for files sent to secret chats" },
+    Parameter { name: "caption", type_: "formattedText", description: "Document caption; pass null to use an empty caption; 0-GetOption(\"message_caption_length_max\") characters" },
+  ] },
+  Constructor { name: "inputMessageForwarded", description: "A forwarded message", class: "InputMessageContent", fields: &[
+    Parameter { name: "from_chat_id", type_: "int53", description: "Identifier for the chat this forwarded message came from" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message to forward" },
+    Parameter { name: "in_game_share", type_: "Bool", description: "True, if a game message is being shared from a launched game; applies only to game messages" },
+    Parameter { name: "copy_options", type_: "messageCopyOptions", description: "Options to be used to copy content of the message without reference to the original sender; pass null to try to forward the message as usual" },
+  ] },
+  Constructor { name: "inputMessageGame", description: "A message with a game; not supported for channels or secret chats", class: "InputMessageContent", fields: &[
+    Parameter { name: "bot_user_id", type_: "int53", description: "User identifier of the bot that owns the game" },
+    Parameter { name: "game_short_name", type_: "string", description: "Short name of the game" },
+  ] },
+  Constructor { name: "inputMessageInvoice", description: "A message with an invoice; can be used only by bots", class: "InputMessageContent", fields: &[
+    Parameter { name: "invoice", type_: "invoice", description: "Invoice" },
+    Parameter { name: "title", type_: "string", description: "Product title; 1-32 characters" },
+    Parameter { name: "description", type_: "string", description: "Product description; 0-255 characters" },
+    Parameter { name: "photo_url", type_: "string", description: "Product photo URL; optional" },
+    Parameter { name: "photo_size", type_: "int32", description: "Product photo size" },
+    Parameter { name: "photo_width", type_: "int32", description: "Product photo width" },
+    Parameter { name: "photo_height", type_: "int32", description: "Product photo height" },
+    Parameter { name: "payload", type_: "bytes", description: "The invoice payload" },
+    Parameter { name: "provider_token", type_: "string", description: "Payment provider token" },
+    Parameter { name: "provider_data", type_: "string", description: "JSON-encoded data about the invoice, which will be shared with the payment provider" },
+    Parameter { name: "start_parameter", type_: "string", description: "Unique invoice bot deep link parameter for the generation of this invoice. If empty, it would be possible to pay directly from forwards of the invoice message" },
+  ] },
+  Constructor { name: "inputMessageLocation", description: "A message with a location", class: "InputMessageContent", fields: &[
+    Parameter { name: "location", type_: "location", description: "Location to be sent" },
+    Parameter { name: "live_period", type_: "int32", description: "Period for which the location can be updated, in seconds; must be between 60 and 86400 for a live location and 0 otherwise" },
+    Parameter { name: "heading", type_: "int32", description: "For live locations, a direction in which the location moves, in degrees; 1-360. Pass 0 if unknown" },
+    Parameter { name: "proximity_alert_radius", type_: "int32", description: "For live locations, a maximum distance to another chat member for proximity alerts, in meters (0-100000). Pass 0 if the notification is disabled. Can't be enabled in channels and Saved Messages" },
+  ] },
+  Constructor { name: "inputMessagePhoto", description: "A photo message", class: "InputMessageContent", fields: &[
+    Parameter { name: "photo", type_: "InputFile", description: "Photo to send" },
+    Parameter { name: "thumbnail", type_: "inputThumbnail", description: "Photo thumbnail to be sent; pass null to skip thumbnail uploading. The thumbnail is sent to the other party only in secret chats" },
+    Parameter { name: "added_sticker_file_ids", type_: "vector<int32>", description: "File identifiers of the stickers added to the photo, if applicable" },
+    Parameter { name: "width", type_: "int32", description: "Photo width" },
+    Parameter { name: "height", type_: "int32", description: "Photo height" },
+    Parameter { name: "caption", type_: "formattedText", description: "Photo caption; pass null to use an empty caption; 0-GetOption(\"message_caption_length_max\") characters" },
+    Parameter { name: "ttl", type_: "int32", description: "Photo TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats" },
+  ] },
+  Constructor { name: "inputMessagePoll", description: "A message with a poll. Polls can't be sent to secret chats. Polls can be sent only to a private chat with a bot", class: "InputMessageContent", fields: &[
+    Parameter { name: "question", type_: "string", description: "Poll question; 1-255 characters (up to 300 characters for bots)" },
+    Parameter { name: "options", type_: "vector<string>", description: "List of poll answer options, 2-10 strings 1-100 characters each" },
+    Parameter { name: "is_anonymous", type_: "Bool", description: "True, if the poll voters are anonymous. Non-anonymous polls can't be sent or forwarded to channels" },
+    Parameter { name: "type", type_: "PollType", description: "Type of the poll" },
+    Parameter { name: "open_period", type_: "int32", description: "Amount of time the poll will be active after creation, in seconds; for bots only" },
+    Parameter { name: "close_date", type_: "int32", description: "Point in time (Unix timestamp) when the poll will be automatically closed; for bots only" },
+    Parameter { name: "is_closed", type_: "Bool", description: "True, if the poll needs to be sent already closed; for bots only" },
+  ] },
+  Constructor { name: "inputMessageSticker", description: "A sticker message", class: "InputMessageContent", fields: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker to be sent" },
+    Parameter { name: "thumbnail", type_: "inputThumbnail", description: "Sticker thumbnail; pass null to skip thumbnail uploading" },
+    Parameter { name: "width", type_: "int32", description: "Sticker width" },
+    Parameter { name: "height", type_: "int32", description: "Sticker height" },
+    Parameter { name: "emoji", type_: "string", description: "Emoji used to choose the sticker" },
+  ] },
+  Constructor { name: "inputMessageText", description: "A text message", class: "InputMessageContent", fields: &[
+    Parameter { name: "text", type_: "formattedText", description: "Formatted text to be sent; 1-GetOption(\"message_text_length_max\") characters. Only Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities are allowed to be specified manually" },
+    Parameter { name: "disable_web_page_preview", type_: "Bool", description: "True, if rich web page previews for URLs in the message text must be disabled" },
+    Parameter { name: "clear_draft", type_: "Bool", description: "True, if a chat message draft must be deleted" },
+  ] },
+  Constructor { name: "inputMessageVenue", description: "A message with information about a venue", class: "InputMessageContent", fields: &[
+    Parameter { name: "venue", type_: "venue", description: "Venue to send" },
+  ] },
+  Constructor { name: "inputMessageVideo", description: "A video message", class: "InputMessageContent", fields: &[
+    Parameter { name: "video", type_: "InputFile", description: "Video to be sent" },
+    Parameter { name: "thumbnail", type_: "inputThumbnail", description: "Video thumbnail; pass null to skip thumbnail uploading" },
+    Parameter { name: "added_sticker_file_ids", type_: "vector<int32>", description: "File identifiers of the stickers added to the video, if applicable" },
+    Parameter { name: "duration", type_: "int32", description: "Duration of the video, in seconds" },
+    Parameter { name: "width", type_: "int32", description: "Video width" },
+    Parameter { name: "height", type_: "int32", description: "Video height" },
+    Parameter { name: "supports_streaming", type_: "Bool", description: "True, if the video is supposed to be streamed" },
+    Parameter { name: "caption", type_: "formattedText", description: "Video caption; pass null to use an empty caption; 0-GetOption(\"message_caption_length_max\") characters" },
+    Parameter { name: "ttl", type_: "int32", description: "Video TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats" },
+  ] },
+  Constructor { name: "inputMessageVideoNote", description: "A video note message", class: "InputMessageContent", fields: &[
+    Parameter { name: "video_note", type_: "InputFile", description: "Video note to be sent" },
+    Parameter { name: "thumbnail", type_: "inputThumbnail", description: "Video thumbnail; pass null to skip thumbnail uploading" },
+    Parameter { name: "duration", type_: "int32", description: "Duration of the video, in seconds" },
+    Parameter { name: "length", type_: "int32", description: "Video width and height; must be positive and not greater than 640" },
+  ] },
+  Constructor { name: "inputMessageVoiceNote", description: "A voice note message", class: "InputMessageContent", fields: &[
+    Parameter { name: "voice_note", type_: "InputFile", description: "Voice note to be sent" },
+    Parameter { name: "duration", type_: "int32", description: "Duration of the voice note, in seconds" },
+    Parameter { name: "waveform", type_: "bytes", description: "Waveform representation of the voice note, in 5-bit format" },
+    Parameter { name: "caption", type_: "formattedText", description: "Voice note caption; pass null to use an empty caption; 0-GetOption(\"message_caption_length_max\") characters" },
+  ] },
+  Constructor { name: "inputPassportElementAddress", description: "A Telegram Passport element to be saved containing the user's address", class: "InputPassportElement", fields: &[
+    Parameter { name: "address", type_: "address", description: "The address to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementBankStatement", description: "A Telegram Passport element to be saved containing the user's bank statement", class: "InputPassportElement", fields: &[
+    Parameter { name: "bank_statement", type_: "inputPersonalDocument", description: "The bank statement to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementDriverLicense", description: "A Telegram Passport element to be saved containing the user's driver license", class: "InputPassportElement", fields: &[
+    Parameter { name: "driver_license", type_: "inputIdentityDocument", description: "The driver license to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementEmailAddress", description: "A Telegram Passport element to be saved containing the user's email address", class: "InputPassportElement", fields: &[
+    Parameter { name: "email_address", type_: "string", description: "The email address to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementError", description: "Contains the description of an error in a Telegram Passport element; for bots only", class: "InputPassportElementError", fields: &[
+    Parameter { name: "type", type_: "PassportElementType", description: "Type of Telegram Passport element that has the error" },
+    Parameter { name: "message", type_: "string", description: "Error message" },
+    Parameter { name: "source", type_: "InputPassportElementErrorSource", description: "Error source" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceDataField", description: "A data field contains an error. The error is considered resolved when the field's value changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "field_name", type_: "string", description: "Field name" },
+    Parameter { name: "data_hash", type_: "bytes", description: "Current data hash" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceFile", description: "The file contains an error. The error is considered resolved when the file changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "file_hash", type_: "bytes", description: "Current hash of the file which has the error" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceFiles", description: "The list of attached files contains an error. The error is considered resolved when the file list changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "file_hashes", type_: "vector<bytes>", description: "Current hashes of all attached files" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceFrontSide", description: "The front side of the document contains an error. The error is considered resolved when the file with the front side of the document changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "file_hash", type_: "bytes", description: "Current hash of the file containing the front side" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceReverseSide", description: "The reverse side of the document contains an error. The error is considered resolved when the file with the reverse side of the document changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "file_hash", type_: "bytes", description: "Current hash of the file containing the reverse side" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceSelfie", description: "The selfie contains an error. The error is considered resolved when the file with the selfie changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "file_hash", type_: "bytes", description: "Current hash of the file containing the selfie" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceTranslationFile", description: "One of the files containing the translation of the document contains an error. The error is considered resolved when the file with the translation changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "file_hash", type_: "bytes", description: "Current hash of the file containing the translation" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceTranslationFiles", description: "The translation of the document contains an error. The error is considered resolved when the list of files changes", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "file_hashes", type_: "vector<bytes>", description: "Current hashes of all files with the translation" },
+  ] },
+  Constructor { name: "inputPassportElementErrorSourceUnspecified", description: "The element contains an error in an unspecified place. The error will be considered resolved when new data is added", class: "InputPassportElementErrorSource", fields: &[
+    Parameter { name: "element_hash", type_: "bytes", description: "Current hash of the entire element" },
+  ] },
+  Constructor { name: "inputPassportElementIdentityCard", description: "A Telegram Passport element to be saved containing the user's identity card", class: "InputPassportElement", fields: &[
+    Parameter { name: "identity_card", type_: "inputIdentityDocument", description: "The identity card to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementInternalPassport", description: "A Telegram Passport element to be saved containing the user's internal passport", class: "InputPassportElement", fields: &[
+    Parameter { name: "internal_passport", type_: "inputIdentityDocument", description: "The internal passport to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementPassport", description: "A Telegram Passport element to be saved containing the user's passport", class: "InputPassportElement", fields: &[
+    Parameter { name: "passport", type_: "inputIdentityDocument", description: "The passport to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementPassportRegistration", description: "A Telegram Passport element to be saved containing the user's passport registration", class: "InputPassportElement", fields: &[
+    Parameter { name: "passport_registration", type_: "inputPersonalDocument", description: "The passport registration page to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementPersonalDetails", description: "A Telegram Passport element to be saved containing the user's personal details", class: "InputPassportElement", fields: &[
+    Parameter { name: "personal_details", type_: "personalDetails", description: "Personal details of the user" },
+  ] },
+  Constructor { name: "inputPassportElementPhoneNumber", description: "A Telegram Passport element to be saved containing the user's phone number", class: "InputPassportElement", fields: &[
+    Parameter { name: "phone_number", type_: "string", description: "The phone number to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementRentalAgreement", description: "A Telegram Passport element to be saved containing the user's rental agreement", class: "InputPassportElement", fields: &[
+    Parameter { name: "rental_agreement", type_: "inputPersonalDocument", description: "The rental agreement to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementTemporaryRegistration", description: "A Telegram Passport element to be saved containing the user's temporary registration", class: "InputPassportElement", fields: &[
+    Parameter { name: "temporary_registration", type_: "inputPersonalDocument", description: "The temporary registration document to be saved" },
+  ] },
+  Constructor { name: "inputPassportElementUtilityBill", description: "A Telegram Passport element to be saved containing the user's utility bill", class: "InputPassportElement", fields: &[
+    Parameter { name: "utility_bill", type_: "inputPersonalDocument", description: "The utility bill to be saved" },
+  ] },
+  Constructor { name: "inputPersonalDocument", description: "A personal document to be saved to Telegram Passport", class: "InputPersonalDocument", fields: &[
+    Parameter { name: "files", type_: "vector<InputFile>", description: "List of files containing the pages of the document" },
+    Parameter { name: "translation", type_: "vector<InputFile>", description: "List of files containing a certified English translation of the document" },
+  ] },
+  Constructor { name: "inputStickerAnimated", description: "An animated sticker in TGS format", class: "InputSticker", fields: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "File with the animated sticker. Only local or uploaded within a week files are supported. See https://core.telegram.org/animated_stickers#technical-requirements for technical requirements" },
+    Parameter { name: "emojis", type_: "string", description: "Emojis corresponding to the sticker" },
+  ] },
+  Constructor { name: "inputStickerStatic", description: "A static sticker in PNG format, which will be converted to WEBP server-side", class: "InputSticker", fields: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "PNG image with the sticker; must be up to 512 KB in size and fit in a 512x512 square" },
+    Parameter { name: "emojis", type_: "string", description: "Emojis corresponding to the sticker" },
+    Parameter { name: "mask_position", type_: "maskPosition", description: "For masks, position where the mask is placed; pass null if unspecified" },
+  ] },
+  Constructor { name: "inputThumbnail", description: "A thumbnail to be sent along with a file; must be in JPEG or WEBP format for stickers, and less than 200 KB in size", class: "InputThumbnail", fields: &[
+    Parameter { name: "thumbnail", type_: "InputFile", description: "Thumbnail file to send. Sending thumbnails by file_id is currently not supported" },
+    Parameter { name: "width", type_: "int32", description: "Thumbnail width, usually shouldn't exceed 320. Use 0 if unknown" },
+    Parameter { name: "height", type_: "int32", description: "Thumbnail height, usually shouldn't exceed 320. Use 0 if unknown" },
+  ] },
+  Constructor { name: "internalLinkTypeActiveSessions", description: "The link is a link to the active sessions section of the app. Use getActiveSessions to handle the link", class: "InternalLinkType", fields: &[] },
+  Constructor { name: "internalLinkTypeAuthenticationCode", description: "The link contains an authentication code. Call checkAuthenticationCode with the code if the current authorization state is authorizationStateWaitCode", class: "InternalLinkType", fields: &[
+    Parameter { name: "code", type_: "string", description: "The authentication code" },
+  ] },
+  Constructor { name: "internalLinkTypeBackground", description: "The link is a link to a background. Call searchBackground with the given background name to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "background_name", type_: "string", description: "Name of the background" },
+  ] },
+  Constructor { name: "internalLinkTypeBotStart", description: "The link is a link to a chat with a Telegram bot. Call searchPublicChat with the given bot username, check that the user is a bot, show START button in the chat with the bot, and then call sendBotStartMessage with the given start parameter after the button is pressed", class: "InternalLinkType", fields: &[
+    Parameter { name: "bot_username", type_: "string", description: "Username of the bot" },
+    Parameter { name: "start_parameter", type_: "string", description: "The parameter to be passed to sendBotStartMessage" },
+  ] },
+  Constructor { name: "internalLinkTypeBotStartInGroup", description: "The link is a link to a Telegram bot, which is supposed to be added to a group chat. Call searchPublicChat with the given bot username, check that the user is a bot and can be added to groups, ask the current user to select a group to add the bot to, and then call sendBotStartMessage with the given start parameter and the chosen group chat. Bots can be added to a public group only by administrators of the group", class: "InternalLinkType", fields: &[
+    Parameter { name: "bot_username", type_: "string", description: "Username of the bot" },
+    Parameter { name: "start_parameter", type_: "string", description: "The parameter to be passed to sendBotStartMessage" },
+  ] },
+  Constructor { name: "internalLinkTypeChangePhoneNumber", description: "The link is a link to the change phone number section of the app", class: "InternalLinkType", fields: &[] },
+  Constructor { name: "internalLinkTypeChatInvite", description: "The link is a chat invite link. Call checkChatInviteLink with the given invite link to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "invite_link", type_: "string", description: "Internal representation of the invite link" },
+  ] },
+  Constructor { name: "internalLinkTypeFilterSettings", description: "The link is a link to the filter settings section of the app", class: "InternalLinkType", fields: &[] },
+  Constructor { name: "internalLinkTypeGame", description: "The link is a link to a game. Call searchPublicChat with the given bot username, check that the user is a bot, ask the current user to select a chat to send the game, and then call sendMessage with inputMessageGame", class: "InternalLinkType", fields: &[
+    Parameter { name: "bot_username", type_: "string", description: "Username of the bot that owns the game" },
+    Parameter { name: "game_short_name", type_: "string", description: "Short name of the game" },
+  ] },
+  Constructor { name: "internalLinkTypeLanguagePack", description: "The link is a link to a language pack. Call getLanguagePackInfo with the given language pack identifier to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "language_pack_id", type_: "string", description: "Language pack identifier" },
+  ] },
+  Constructor { name: "internalLinkTypeMessage", description: "The link is a link to a Telegram message. Call getMessageLinkInfo with the given URL to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "url", type_: "string", description: "URL to be passed to getMessageLinkInfo" },
+  ] },
+  Constructor { name: "internalLinkTypeMessageDraft", description: "The link contains a message draft text. A share screen needs to be shown to the user, then the chosen chat must be opened and the text is added to the input field", class: "InternalLinkType", fields: &[
+    Parameter { name: "text", type_: "formattedText", description: "Message draft text" },
+    Parameter { name: "contains_link", type_: "Bool", description: "True, if the first line of the text contains a link. If true, the input field needs to be focused and the text after the link must be selected" },
+  ] },
+  Constructor { name: "internalLinkTypePassportDataRequest", description: "The link contains a request of Telegram passport data. Call getPassportAuthorizationForm with the given parameters to process the link if the link was received from outside of the app, otherwise ignore it", class: "InternalLinkType", fields: &[
+    Parameter { name: "bot_user_id", type_: "int53", description: "User identifier of the service's bot" },
+    Parameter { name: "scope", type_: "string", description: "Telegram Passport element types requested by the service" },
+    Parameter { name: "public_key", type_: "string", description: "Service's public key" },
+    Parameter { name: "nonce", type_: "string", description: "Unique request identifier provided by the service" },
+    Parameter { name: "callback_url", type_: "string", description: "An HTTP URL to open once the request is finished or canceled with the parameter tg_passport=success or tg_passport=cancel respectively. If empty, then the link tgbot{bot_user_id}://passport/success or tgbot{bot_user_id}://passport/cancel needs to be opened instead" },
+  ] },
+  Constructor { name: "internalLinkTypePhoneNumberConfirmation", description: "The link can be used to confirm ownership of a phone number to prevent account deletion. Call sendPhoneNumberConfirmationCode with the given hash and phone number to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "hash", type_: "string", description: "Hash value from the link" },
+    Parameter { name: "phone_number", type_: "string", description: "Phone number value from the link" },
+  ] },
+  Constructor { name: "internalLinkTypeProxy", description: "The link is a link to a proxy. Call addProxy with the given parameters to process the link and add the proxy", class: "InternalLinkType", fields: &[
+    Parameter { name: "server", type_: "string", description: "Proxy server IP address" },
+    Parameter { name: "port", type_: "int32", description: "Proxy server port" },
+    Parameter { name: "type", type_: "ProxyType", description: "Type of the proxy" },
+  ] },
+  Constructor { name: "internalLinkTypePublicChat", description: "The link is a link to a chat by its username. Call searchPublicChat with the given chat username to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "chat_username", type_: "string", description: "Username of the chat" },
+  ] },
+  Constructor { name: "internalLinkTypeQrCodeAuthentication", description: "The link can be used to login the current user on another device, but it must be scanned from QR-code using in-app camera. An alert similar to \"This code can be used to allow someone to log in to your Telegram account. To confirm Telegram login, please go to Settings > Devices > Scan QR and scan the code\" needs to be shown", class: "InternalLinkType", fields: &[] },
+  Constructor { name: "internalLinkTypeSettings", description: "The link is a link to app settings", class: "InternalLinkType", fields: &[] },
+  Constructor { name: "internalLinkTypeStickerSet", description: "The link is a link to a sticker set. Call searchStickerSet with the given sticker set name to process the link and show the sticker set", class: "InternalLinkType", fields: &[
+    Parameter { name: "sticker_set_name", type_: "string", description: "Name of the sticker set" },
+  ] },
+  Constructor { name: "internalLinkTypeTheme", description: "The link is a link to a theme. TDLib has no theme support yet", class: "InternalLinkType", fields: &[
+    Parameter { name: "theme_name", type_: "string", description: "Name of the theme" },
+  ] },
+  Constructor { name: "internalLinkTypeThemeSettings", description: "The link is a link to the theme settings section of the app", class: "InternalLinkType", fields: &[] },
+  Constructor { name: "internalLinkTypeUnknownDeepLink", description: "The link is an unknown tg: link. Call getDeepLinkInfo to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "link", type_: "string", description: "Link to be passed to getDeepLinkInfo" },
+  ] },
+  Constructor { name: "internalLinkTypeUnsupportedProxy", description: "The link is a link to an unsupported proxy. An alert can be shown to the user", class: "InternalLinkType", fields: &[] },
+  Constructor { name: "internalLinkTypeVideoChat", description: "The link is a link to a video chat. Call searchPublicChat with the given chat username, and then joinGoupCall with the given invite hash to process the link", class: "InternalLinkType", fields: &[
+    Parameter { name: "chat_username", type_: "string", description: "Username of the chat with the video chat" },
+    Parameter { name: "invite_hash", type_: "string", description: "If non-empty, invite hash to be used to join the video chat without being muted by administrators" },
+    Parameter { name: "is_live_stream", type_: "Bool", description: "True, if the video chat is expected to be a live stream in a channel or a broadcast group" },
+  ] },
+  Constructor { name: "invoice", description: "Product invoice", class: "Invoice", fields: &[
+    Parameter { name: "currency", type_: "string", description: "ISO 4217 currency code" },
+    Parameter { name: "price_parts", type_: "vector<labeledPricePart>", description: "A list of objects used to calculate the total price of the product" },
+    Parameter { name: "max_tip_amount", type_: "int32", description: "The maximum allowed amount of tip in the smallest units of the currency" },
+    Parameter { name: "suggested_tip_amounts", type_: "vector<int32>", description: "Suggested amounts of tip in the smallest units of the currency" },
+    Parameter { name: "is_test", type_: "Bool", description: "True, if the payment is a test payment" },
+    Parameter { name: "need_name", type_: "Bool", description: "True, if the user's name is needed for payment" },
+    Parameter { name: "need_phone_number", type_: "Bool", description: "True, if the user's phone number is needed for payment" },
+    Parameter { name: "need_email_address", type_: "Bool", description: "True, if the user's email address is needed for payment" },
+    Parameter { name: "need_shipping_address", type_: "Bool", description: "True, if the user's shipping address is needed for payment" },
+    Parameter { name: "send_phone_number_to_provider", type_: "Bool", description: "True, if the user's phone number will be sent to the provider" },
+    Parameter { name: "send_email_address_to_provider", type_: "Bool", description: "True, if the user's email address will be sent to the provider" },
+    Parameter { name: "is_flexible", type_: "Bool", description: "True, if the total price depends on the shipping method" },
+  ] },
+  Constructor { name: "jsonObjectMember", description: "Represents one member of a JSON object", class: "JsonObjectMember", fields: &[
+    Parameter { name: "key", type_: "string", description: "Member's key" },
+    Parameter { name: "value", type_: "JsonValue", description: "Member's value" },
+  ] },
+  Constructor { name: "jsonValueArray", description: "Represents a JSON array", class: "JsonValue", fields: &[
+    Parameter { name: "values", type_: "vector<JsonValue>", description: "The list of array elements" },
+  ] },
+  Constructor { name: "jsonValueBoolean", description: "Represents a boolean JSON value", class: "JsonValue", fields: &[
+    Parameter { name: "value", type_: "Bool", description: "The value" },
+  ] },
+  Constructor { name: "jsonValueNull", description: "Represents a null JSON value", class: "JsonValue", fields: &[] },
+  Constructor { name: "jsonValueNumber", description: "Represents a numeric JSON value", class: "JsonValue", fields: &[
+    Parameter { name: "value", type_: "double", description: "The value" },
+  ] },
+  Constructor { name: "jsonValueObject", description: "Represents a JSON object", class: "JsonValue", fields: &[
+    Parameter { name: "members", type_: "vector<jsonObjectMember>", description: "The list of object members" },
+  ] },
+  Constructor { name: "jsonValueString", description: "Represents a string JSON value", class: "JsonValue", fields: &[
+    Parameter { name: "value", type_: "string", description: "The value" },
+  ] },
+  Constructor { name: "keyboardButton", description: "Represents a single button in a bot keyboard", class: "KeyboardButton", fields: &[
+    Parameter { name: "text", type_: "string", description: "Text of the button" },
+    Parameter { name: "type", type_: "KeyboardButtonType", description: "Type of the button" },
+  ] },
+  Constructor { name: "keyboardButtonTypeRequestLocation", description: "A button that sends the user's location when pressed; available only in private chats", class: "KeyboardButtonType", fields: &[] },
+  Constructor { name: "keyboardButtonTypeRequestPhoneNumber", description: "A button that sends the user's phone number when pressed; available only in private chats", class: "KeyboardButtonType", fields: &[] },
+  Constructor { name: "keyboardButtonTypeRequestPoll", description: "A button that allows the user to create and send a poll when pressed; available only in private chats", class: "KeyboardButtonType", fields: &[
+    Parameter { name: "force_regular", type_: "Bool", description: "If true, only regular polls must be allowed to create" },
+    Parameter { name: "force_quiz", type_: "Bool", description: "If true, only polls in quiz mode must be allowed to create" },
+  ] },
+  Constructor { name: "keyboardButtonTypeText", description: "A simple button, with text that must be sent when the button is pressed", class: "KeyboardButtonType", fields: &[] },
+  Constructor { name: "labeledPricePart", description: "Portion of the price of a product (e.g., \"delivery cost\", \"tax amount\")", class: "LabeledPricePart", fields: &[
+    Parameter { name: "label", type_: "string", description: "Label for this portion of the product price" },
+    Parameter { name: "amount", type_: "int32", description: "Currency amount in the smallest units of the currency" },
+  ] },
+  Constructor { name: "languagePackInfo", description: "Contains information about a language pack", class: "LanguagePackInfo", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique language pack identifier" },
+    Parameter { name: "base_language_pack_id", type_: "string", description: "Identifier of a base language pack; may be empty. If a string is missed in the language pack, then it must be fetched from base language pack. Unsupported in custom language packs" },
+    Parameter { name: "name", type_: "string", description: "Language name" },
+    Parameter { name: "native_name", type_: "string", description: "Name of the language in that language" },
+    Parameter { name: "plural_code", type_: "string", description: "A language code to be used to apply plural forms. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info" },
+    Parameter { name: "is_official", type_: "Bool", description: "True, if the language pack is official" },
+    Parameter { name: "is_rtl", type_: "Bool", description: "True, if the language pack strings are RTL" },
+    Parameter { name: "is_beta", type_: "Bool", description: "True, if the language pack is a beta language pack" },
+    Parameter { name: "is_installed", type_: "Bool", description: "True, if the language pack is installed by the current user" },
+    Parameter { name: "total_string_count", type_: "int32", description: "Total number of non-deleted strings from the language pack" },
+    Parameter { name: "translated_string_count", type_: "int32", description: "Total number of translated strings from the language pack" },
+    Parameter { name: "local_string_count", type_: "int32", description: "Total number of non-deleted strings from the language pack available locally" },
+    Parameter { name: "translation_url", type_: "string", description: "Link to language translation interface; empty for custom local language packs" },
+  ] },
+  Constructor { name: "languagePackString", description: "Represents one language pack string", class: "LanguagePackString", fields: &[
+    Parameter { name: "key", type_: "string", description: "String key" },
+    Parameter { name: "value", type_: "LanguagePackStringValue", description: "String value; pass null if the string needs to be taken from the built-in English language pack" },
+  ] },
+  Constructor { name: "languagePackStringValueDeleted", description: "A deleted language pack string, the value must be taken from the built-in English language pack", class: "LanguagePackStringValue", fields: &[] },
+  Constructor { name: "languagePackStringValueOrdinary", description: "An ordinary language pack string", class: "LanguagePackStringValue", fields: &[
+    Parameter { name: "value", type_: "string", description: "String value" },
+  ] },
+  Constructor { name: "languagePackStringValuePluralized", description: "A language pack string which has different forms based on the number of some object it mentions. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info", class: "LanguagePackStringValue", fields: &[
+    Parameter { name: "zero_value", type_: "string", description: "Value for zero objects" },
+    Parameter { name: "one_value", type_: "string", description: "Value for one object" },
+    Parameter { name: "two_value", type_: "string", description: "Value for two objects" },
+    Parameter { name: "few_value", type_: "string", description: "Value for few objects" },
+    Parameter { name: "many_value", type_: "string", description: "Value for many objects" },
+    Parameter { name: "other_value", type_: "string", description: "Default value" },
+  ] },
+  Constructor { name: "languagePackStrings", description: "Contains a list of language pack strings", class: "LanguagePackStrings", fields: &[
+    Parameter { name: "strings", type_: "vector<languagePackString>", description: "A list of language pack strings" },
+  ] },
+  Constructor { name: "localFile", description: "Represents a local file", class: "LocalFile", fields: &[
+    Parameter { name: "path", type_: "string", description: "Local path to the locally available file part; may be empty" },
+    Parameter { name: "can_be_downloaded", type_: "Bool", description: "True, if it is possible to try to download or generate the file" },
+    Parameter { name: "can_be_deleted", type_: "Bool", description: "True, if the file can be deleted" },
+    Parameter { name: "is_downloading_active", type_: "Bool", description: "True, if the file is currently being downloaded (or a local copy is being generated by some other means)" },
+    Parameter { name: "is_downloading_completed", type_: "Bool", description: "True, if the local copy is fully available" },
+    Parameter { name: "download_offset", type_: "int32", description: "Download will be started from this offset. downloaded_prefix_size is calculated from this offset" },
+    Parameter { name: "downloaded_prefix_size", type_: "int32", description: "If is_downloading_completed is false, then only some prefix of the file starting from download_offset is ready to be read. downloaded_prefix_size is the size of that prefix in bytes" },
+    Parameter { name: "downloaded_size", type_: "int32", description: "Total downloaded file size, in bytes. Can be used only for calculating download progress. The actual file size may be bigger, and some parts of it may contain garbage" },
+  ] },
+  Constructor { name: "localizationTargetInfo", description: "Contains information about the current localization target", class: "LocalizationTargetInfo", fields: &[
+    Parameter { name: "language_packs", type_: "vector<languagePackInfo>", description: "List of available language packs for this application" },
+  ] },
+  Constructor { name: "location", description: "Describes a location on planet Earth", class: "Location", fields: &[
+    Parameter { name: "latitude", type_: "double", description: "Latitude of the location in degrees; as defined by the sender" },
+    Parameter { name: "longitude", type_: "double", description: "Longitude of the location, in degrees; as defined by the sender" },
+    Parameter { name: "horizontal_accuracy", type_: "double", description: "The estimated horizontal accuracy of the location, in meters; as defined by the sender. 0 if unknown" },
+  ] },
+  Constructor { name: "logStreamDefault", description: "The log is written to stderr or an OS specific log", class: "LogStream", fields: &[] },
+  Constructor { name: "logStreamEmpty", description: "The log is written nowhere", class: "LogStream", fields: &[] },
+  Constructor { name: "logStreamFile", description: "The log is written to a file", class: "LogStream", fields: &[
+    Parameter { name: "path", type_: "string", description: "Path to the file to where the internal TDLib log will be written" },
+    Parameter { name: "max_file_size", type_: "int32", description: "The maximum size of the file to where the internal TDLib log is written before the file will be auto-rotated, in bytes" },
+    Parameter { name: "redirect_stderr", type_: "Bool", description: "Pass true to additionally redirect stderr to the log file. Ignored on Windows" },
+  ] },
+  Constructor { name: "logTags", description: "Contains a list of available TDLib internal log tags", class: "LogTags", fields: &[
+    Parameter { name: "tags", type_: "vector<string>", description: "List of log tags" },
+  ] },
+  Constructor { name: "logVerbosityLevel", description: "Contains a TDLib internal log verbosity level", class: "LogVerbosityLevel", fields: &[
+    Parameter { name: "verbosity_level", type_: "int32", description: "Log verbosity level" },
+  ] },
+  Constructor { name: "loginUrlInfoOpen", description: "An HTTP url needs to be open", class: "LoginUrlInfo", fields: &[
+    Parameter { name: "url", type_: "string", description: "The URL to open" },
+    Parameter { name: "skip_confirm", type_: "Bool", description: "True, if there is no need to show an ordinary open URL confirm" },
+  ] },
+  Constructor { name: "loginUrlInfoRequestConfirmation", description: "An authorization confirmation dialog needs to be shown to the user", class: "LoginUrlInfo", fields: &[
+    Parameter { name: "url", type_: "string", description: "An HTTP URL to be opened" },
+    Parameter { name: "domain", type_: "string", description: "A domain of the URL" },
+    Parameter { name: "bot_user_id", type_: "int53", description: "User identifier of a bot linked with the website" },
+    Parameter { name: "request_write_access", type_: "Bool", description: "True, if the user needs to be requested to give the permission to the bot to send them messages" },
+  ] },
+  Constructor { name: "maskPointChin", description: "The mask is placed relatively to the chin", class: "MaskPoint", fields: &[] },
+  Constructor { name: "maskPointEyes", description: "The mask is placed relatively to the eyes", class: "MaskPoint", fields: &[] },
+  Constructor { name: "maskPointForehead", description: "The mask is placed relatively to the forehead", class: "MaskPoint", fields: &[] },
+  Constructor { name: "maskPointMouth", description: "The mask is placed relatively to the mouth", class: "MaskPoint", fields: &[] },
+  Constructor { name: "maskPosition", description: "Position on a photo where a mask is placed", class: "MaskPosition", fields: &[
+    Parameter { name: "point", type_: "MaskPoint", description: "Part of the face, relative to which the mask is placed" },
+    Parameter { name: "x_shift", type_: "double", description: "Shift by X-axis measured in widths of the mask scaled to the face size, from left to right. (For example, 1.0 will place the mask just to the left of the default mask position)" },
+    Parameter { name: "y_shift", type_: "double", description: "Shift by Y-axis measured in heights of the mask scaled to the face size, from top to bottom. (For example, 1.0 will place the mask just below the default mask position)" },
+    Parameter { name: "scale", type_: "double", description: "Mask scaling coefficient. (For example, 2.0 means a doubled size)" },
+  ] },
+  Constructor { name: "message", description: "Describes a message", class: "Message", fields: &[
+    Parameter { name: "id", type_: "int53", description: "Message identifier; unique for the chat to which the message belongs" },
+    Parameter { name: "sender", type_: "MessageSender", description: "The sender of the message" },
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "sending_state", type_: "MessageSendingState", description: "The sending state of the message; may be null" },
+    Parameter { name: "scheduling_state", type_: "MessageSchedulingState", description: "The scheduling state of the message; may be null" },
+    Parameter { name: "is_outgoing", type_: "Bool", description: "True, if the message is outgoing" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is pinned" },
+    Parameter { name: "can_be_edited", type_: "Bool", description: "True, if the message can be edited. For live location and poll messages this fields shows whether editMessageLiveLocation or stopPoll can be used with this message by the application" },
+    Parameter { name: "can_be_forwarded", type_: "Bool", description: "True, if the message can be forwarded" },
+    Parameter { name: "can_be_deleted_only_for_self", type_: "Bool", description: "True, if the message can be deleted only for the current user while other users will continue to see it" },
+    Parameter { name: "can_be_deleted_for_all_users", type_: "Bool", description: "True, if the message can be deleted for all users" },
+    Parameter { name: "can_get_statistics", type_: "Bool", description: "True, if the message statistics are available" },
+    Parameter { name: "can_get_message_thread", type_: "Bool", description: "True, if the message thread info is available" },
+    Parameter { name: "can_get_viewers", type_: "Bool", description: "True, if chat members already viewed the message can be received through getMessageViewers" },
+    Parameter { name: "can_get_media_timestamp_links", type_: "Bool", description: "True, if media timestamp links can be generated for media timestamp entities in the message text, caption or web page description" },
+    Parameter { name: "has_timestamped_media", type_: "Bool", description: "True, if media timestamp entities refers to a media in this message as opposed to a media in the replied message" },
+    Parameter { name: "is_channel_post", type_: "Bool", description: "True, if the message is a channel post. All messages to channels are channel posts, all other messages are not channel posts" },
+    Parameter { name: "contains_unread_mention", type_: "Bool", description: "True, if the message contains an unread mention for the current user" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the message was sent" },
+    Parameter { name: "edit_date", type_: "int32", description: "Point in time (Unix timestamp) when the message was last edited" },
+    Parameter { name: "forward_info", type_: "messageForwardInfo", description: "Information about the initial message sender; may be null" },
+    Parameter { name: "interaction_info", type_: "messageInteractionInfo", description: "Information about interactions with the message; may be null" },
+    Parameter { name: "reply_in_chat_id", type_: "int53", description: "If non-zero, the identifier of the chat to which the replied message belongs; Currently, only messages in the Replies chat can have different reply_in_chat_id and chat_id" },
+    Parameter { name: "reply_to_message_id", type_: "int53", description: "If non-zero, the identifier of the message this message is replying to; can be the identifier of a deleted message" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If non-zero, the identifier of the message thread the message belongs to; unique within the chat to which the message belongs" },
+    Parameter { name: "ttl", type_: "int32", description: "For self-destructing messages, the message's TTL (Time To Live), in seconds; 0 if none. TDLib will send updateDeleteMessages or updateMessageContent once the TTL expires" },
+    Parameter { name: "ttl_expires_in", type_: "double", description: "Time left before the message expires, in seconds. If the TTL timer isn't started yet, equals to the value of the ttl field" },
+    Parameter { name: "via_bot_user_id", type_: "int53", description: "If non-zero, the user identifier of the bot through which this message was sent" },
+    Parameter { name: "author_signature", type_: "string", description: "For channel posts and anonymous group messages, optional author signature" },
+    Parameter { name: "media_album_id", type_: "int64", description: "Unique identifier of an album this message belongs to. Only audios, documents, photos and videos can be grouped together in albums" },
+    Parameter { name: "restriction_reason", type_: "string", description: "If non-empty, contains a human-readable description of the reason why access to this message must be restricted" },
+    Parameter { name: "content", type_: "MessageContent", description: "Content of the message" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "Reply markup for the message; may be null" },
+  ] },
+  Constructor { name: "messageAnimatedEmoji", description: "A message with an animated emoji", class: "MessageContent", fields: &[
+    Parameter { name: "animated_emoji", type_: "animatedEmoji", description: "The animated emoji" },
+    Parameter { name: "emoji", type_: "string", description: "The corresponding emoji" },
+  ] },
+  Constructor { name: "messageAnimation", description: "An animation message (GIF-style).", class: "MessageContent", fields: &[
+    Parameter { name: "animation", type_: "animation", description: "The animation description" },
+    Parameter { name: "caption", type_: "formattedText", description: "Animation caption" },
+    Parameter { name: "is_secret", type_: "Bool", description: "True, if the animation thumbnail must be blurred and the animation must be shown only while tapped" },
+  ] },
+  Constructor { name: "messageAudio", description: "An audio message", class: "MessageContent", fields: &[
+    Parameter { name: "audio", type_: "audio", description: "The audio description" },
+    Parameter { name: "caption", type_: "formattedText", description: "Audio caption" },
+  ] },
+  Constructor { name: "messageBasicGroupChatCreate", description: "A newly created basic group", class: "MessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "Title of the basic group" },
+    Parameter { name: "member_user_ids", type_: "vector<int53>", description: "User identifiers of members in the basic group" },
+  ] },
+  Constructor { name: "messageCalendar", description: "Contains information about found messages, splitted by days according to the option \"utc_time_offset\"", class: "MessageCalendar", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Total number of found messages" },
+    Parameter { name: "days", type_: "vector<messageCalendarDay>", description: "Information about messages sent" },
+  ] },
+  Constructor { name: "messageCalendarDay", description: "Contains information about found messages sent in a specific day", class: "MessageCalendarDay", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Total number of found messages sent in the day" },
+    Parameter { name: "message", type_: "message", description: "First message sent in the day" },
+  ] },
+  Constructor { name: "messageCall", description: "A message with information about an ended call", class: "MessageContent", fields: &[
+    Parameter { name: "is_video", type_: "Bool", description: "True, if the call was a video call" },
+    Parameter { name: "discard_reason", type_: "CallDiscardReason", description: "Reason why the call was discarded" },
+    Parameter { name: "duration", type_: "int32", description: "Call duration, in seconds" },
+  ] },
+  Constructor { name: "messageChatAddMembers", description: "New chat members were added", class: "MessageContent", fields: &[
+    Parameter { name: "member_user_ids", type_: "vector<int53>", description: "User identifiers of the new members" },
+  ] },
+  Constructor { name: "messageChatChangePhoto", description: "An updated chat photo", class: "MessageContent", fields: &[
+    Parameter { name: "photo", type_: "chatPhoto", description: "New chat photo" },
+  ] },
+  Constructor { name: "messageChatChangeTitle", description: "An updated chat title", class: "MessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "New chat title" },
+  ] },
+  Constructor { name: "messageChatDeleteMember", description: "A chat member was deleted", class: "MessageContent", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier of the deleted chat member" },
+  ] },
+  Constructor { name: "messageChatDeletePhoto", description: "A deleted chat photo", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageChatJoinByLink", description: "A new member joined the chat by invite link", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageChatJoinByRequest", description: "A new member was accepted to the chat by an administrator", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageChatSetTheme", description: "A theme in the chat has been changed", class: "MessageContent", fields: &[
+    Parameter { name: "theme_name", type_: "string", description: "If non-empty, name of a new theme, set for the chat. Otherwise chat theme was reset to the default one" },
+  ] },
+  Constructor { name: "messageChatSetTtl", description: "The TTL (Time To Live) setting for messages in the chat has been changed", class: "MessageContent", fields: &[
+    Parameter { name: "ttl", type_: "int32", description: "New message TTL setting" },
+  ] },
+  Constructor { name: "messageChatUpgradeFrom", description: "A supergroup has been created from a basic group", class: "MessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "Title of the newly created supergroup" },
+    Parameter { name: "basic_group_id", type_: "int53", description: "The identifier of the original basic group" },
+  ] },
+  Constructor { name: "messageChatUpgradeTo", description: "A basic group was upgraded to a supergroup and was deactivated as the result", class: "MessageContent", fields: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the supergroup to which the basic group was upgraded" },
+  ] },
+  Constructor { name: "messageContact", description: "A message with a user contact", class: "MessageContent", fields: &[
+    Parameter { name: "contact", type_: "contact", description: "The contact description" },
+  ] },
+  Constructor { name: "messageContactRegistered", description: "A contact has registered with Telegram", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageCopyOptions", description: "Options to be used when a message content is copied without reference to the original sender. Service messages and messageInvoice can't be copied", class: "MessageCopyOptions", fields: &[
+    Parameter { name: "send_copy", type_: "Bool", description: "True, if content of the message needs to be copied without reference to the original sender. Always true if the message is forwarded to a secret chat or is local" },
+    Parameter { name: "replace_caption", type_: "Bool", description: "True, if media caption of the message copy needs to be replaced. Ignored if send_copy is false" },
+    Parameter { name: "new_caption", type_: "formattedText", description: "New message caption; pass null to copy message without caption. Ignored if replace_caption is false" },
+  ] },
+  Constructor { name: "messageCustomServiceAction", description: "A non-standard action has happened in the chat", class: "MessageContent", fields: &[
+    Parameter { name: "text", type_: "string", description: "Message text to be shown in the chat" },
+  ] },
+  Constructor { name: "messageDice", description: "A dice message. The dice value is randomly generated by the server", class: "MessageContent", fields: &[
+    Parameter { name: "initial_state", type_: "DiceStickers", description: "The animated stickers with the initial dice animation; may be null if unknown. updateMessageContent will be sent when the sticker became known" },
+    Parameter { name: "final_state", type_: "DiceStickers", description: "The animated stickers with the final dice animation; may be null if unknown. updateMessageContent will be sent when the sticker became known" },
+    Parameter { name: "emoji", type_: "string", description: "Emoji on which the dice throw animation is based" },
+    Parameter { name: "value", type_: "int32", description: "The dice value. If the value is 0, the dice don't have final state yet" },
+    Parameter { name: "success_animation_frame_number", type_: "int32", description: "Number of frame after which a success animation like a shower of confetti needs to be shown on updateMessageSendSucceeded" },
+  ] },
+  Constructor { name: "messageDocument", description: "A document message (general file)", class: "MessageContent", fields: &[
+    Parameter { name: "document", type_: "document", description: "The document description" },
+    Parameter { name: "caption", type_: "formattedText", description: "Document caption" },
+  ] },
+  Constructor { name: "messageExpiredPhoto", description: "An expired photo message (self-destructed after TTL has elapsed)", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageExpiredVideo", description: "An expired video message (self-destructed after TTL has elapsed)", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageFileTypeGroup", description: "The messages was exported from a group chat", class: "MessageFileType", fields: &[
+    Parameter { name: "title", type_: "string", description: "Title of the group chat; may be empty if unrecognized" },
+  ] },
+  Constructor { name: "messageFileTypePrivate", description: "The messages was exported from a private chat", class: "MessageFileType", fields: &[
+    Parameter { name: "name", type_: "string", description: "Name of the other party; may be empty if unrecognized" },
+  ] },
+  Constructor { name: "messageFileTypeUnknown", description: "The messages was exported from a chat of unknown type", class: "MessageFileType", fields: &[] },
+  Constructor { name: "messageForwardInfo", description: "Contains information about a forwarded message", class: "MessageForwardInfo", fields: &[
+    Parameter { name: "origin", type_: "MessageForwardOrigin", description: "Origin of a forwarded message" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the message was originally sent" },
+    Parameter { name: "public_service_announcement_type", type_: "string", description: "The type of a public service announcement for the forwarded message" },
+    Parameter { name: "from_chat_id", type_: "int53", description: "For messages forwarded to the chat with the current user (Saved Messages), to the Replies bot chat, or to the channel's discussion group, the identifier of the chat from which the message was forwarded last time; 0 if unknown" },
+    Parameter { name: "from_message_id", type_: "int53", description: "For messages forwarded to the chat with the current user (Saved Messages), to the Replies bot chat, or to the channel's discussion group, the identifier of the original message from which the new message was forwarded last time; 0 if unknown" },
+  ] },
+  Constructor { name: "messageForwardOriginChannel", description: "The message was originally a post in a channel", class: "MessageForwardOrigin", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat from which the message was originally forwarded" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier of the original message" },
+    Parameter { name: "author_signature", type_: "string", description: "Original post author signature" },
+  ] },
+  Constructor { name: "messageForwardOriginChat", description: "The message was originally sent by an anonymous chat administrator on behalf of the chat", class: "MessageForwardOrigin", fields: &[
+    Parameter { name: "sender_chat_id", type_: "int53", description: "Identifier of the chat that originally sent the message" },
+    Parameter { name: "author_signature", type_: "string", description: "Original message author signature" },
+  ] },
+  Constructor { name: "messageForwardOriginHiddenUser", description: "The message was originally sent by a user, which is hidden by their privacy settings", class: "MessageForwardOrigin", fields: &[
+    Parameter { name: "sender_name", type_: "string", description: "Name of the sender" },
+  ] },
+  Constructor { name: "messageForwardOriginMessageImport", description: "The message was imported from an exported message history", class: "MessageForwardOrigin", fields: &[
+    Parameter { name: "sender_name", type_: "string", description: "Name of the sender" },
+  ] },
+  Constructor { name: "messageForwardOriginUser", description: "The message was originally sent by a known user", class: "MessageForwardOrigin", fields: &[
+    Parameter { name: "sender_user_id", type_: "int53", description: "Identifier of the user that originally sent the message" },
+  ] },
+  Constructor { name: "messageGame", description: "A message with a game", class: "MessageContent", fields: &[
+    Parameter { name: "game", type_: "game", description: "The game description" },
+  ] },
+  Constructor { name: "messageGameScore", description: "A new high score was achieved in a game", class: "MessageContent", fields: &[
+    Parameter { name: "game_message_id", type_: "int53", description: "Identifier of the message with the game, can be an identifier of a deleted message" },
+    Parameter { name: "game_id", type_: "int64", description: "Identifier of the game; may be different from the games presented in the message with the game" },
+    Parameter { name: "score", type_: "int32", description: "New score" },
+  ] },
+  Constructor { name: "messageInteractionInfo", description: "Contains information about interactions with a message", class: "MessageInteractionInfo", fields: &[
+    Parameter { name: "view_count", type_: "int32", description: "Number of times the message was viewed" },
+    Parameter { name: "forward_count", type_: "int32", description: "Number of times the message was forwarded" },
+    Parameter { name: "reply_info", type_: "messageReplyInfo", description: "Information about direct or indirect replies to the message; may be null. Currently, available only in channels with a discussion supergroup and discussion supergroups for messages, which are not replies itself" },
+  ] },
+  Constructor { name: "messageInviteVideoChatParticipants", description: "A message with information about an invite to a video chat", class: "MessageContent", fields: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Identifier of the video chat. The video chat can be received through the method getGroupCall" },
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "Invited user identifiers" },
+  ] },
+  Constructor { name: "messageInvoice", description: "A message with an invoice from a bot", class: "MessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "Product title" },
+    Parameter { name: "description", type_: "string", description: "Product description" },
+    Parameter { name: "photo", type_: "photo", description: "Product photo; may be null" },
+    Parameter { name: "currency", type_: "string", description: "Currency for the product price" },
+    Parameter { name: "total_amount", type_: "int32", description: "Product total price in the smallest units of the currency" },
+    Parameter { name: "start_parameter", type_: "string", description: "Unique invoice bot start_parameter. To share an invoice use the URL https://t.me/{bot_username}?start={start_parameter}" },
+    Parameter { name: "is_test", type_: "Bool", description: "True, if the invoice is a test invoice" },
+    Parameter { name: "need_shipping_address", type_: "Bool", description: "True, if the shipping address must be specified" },
+    Parameter { name: "receipt_message_id", type_: "int53", description: "The identifier of the message with the receipt, after the product has been purchased" },
+  ] },
+  Constructor { name: "messageLink", description: "Contains an HTTPS link to a message in a supergroup or channel", class: "MessageLink", fields: &[
+    Parameter { name: "link", type_: "string", description: "Message link" },
+    Parameter { name: "is_public", type_: "Bool", description: "True, if the link will work for non-members of the chat" },
+  ] },
+  Constructor { name: "messageLinkInfo", description: "Contains information about a link to a message in a chat", class: "MessageLinkInfo", fields: &[
+    Parameter { name: "is_public", type_: "Bool", description: "True, if the link is a public link for a message in a chat" },
+    Parameter { name: "chat_id", type_: "int53", description: "If found, identifier of the chat to which the message belongs, 0 otherwise" },
+    Parameter { name: "message", type_: "message", description: "If found, the linked message; may be null" },
+    Parameter { name: "media_timestamp", type_: "int32", description: "Timestamp from which the video/audio/video note/voice note playing must start, in seconds; 0 if not specified. The media can be in the message content or in its web page preview" },
+    Parameter { name: "for_album", type_: "Bool", description: "True, if the whole media album to which the message belongs is linked" },
+    Parameter { name: "for_comment", type_: "Bool", description: "True, if the message is linked as a channel post comment or from a message thread" },
+  ] },
+  Constructor { name: "messageLocation", description: "A message with a location", class: "MessageContent", fields: &[
+    Parameter { name: "location", type_: "location", description: "The location description" },
+    Parameter { name: "live_period", type_: "int32", description: "Time relative to the message send date, for which the location can be updated, in seconds" },
+    Parameter { name: "expires_in", type_: "int32", description: "Left time for which the location can be updated, in seconds. updateMessageContent is not sent when this field changes" },
+    Parameter { name: "heading", type_: "int32", description: "For live locations, a direction in which the location moves, in degrees; 1-360. If 0 the direction is unknown" },
+    Parameter { name: "proximity_alert_radius", type_: "int32", description: "For live locations, a maximum distance to another chat member for proximity alerts, in meters (0-100000). 0 if the notification is disabled. Available only for the message sender" },
+  ] },
+  Constructor { name: "messagePassportDataReceived", description: "Telegram Passport data has been received; for bots only", class: "MessageContent", fields: &[
+    Parameter { name: "elements", type_: "vector<encryptedPassportElement>", description: "List of received Telegram Passport elements" },
+    Parameter { name: "credentials", type_: "encryptedCredentials", description: "Encrypted data credentials" },
+  ] },
+  Constructor { name: "messagePassportDataSent", description: "Telegram Passport data has been sent", class: "MessageContent", fields: &[
+    Parameter { name: "types", type_: "vector<PassportElementType>", description: "List of Telegram Passport element types sent" },
+  ] },
+  Constructor { name: "messagePaymentSuccessful", description: "A payment has been completed", class: "MessageContent", fields: &[
+    Parameter { name: "invoice_chat_id", type_: "int53", description: "Identifier of the chat, containing the corresponding invoice message; 0 if unknown" },
+    Parameter { name: "invoice_message_id", type_: "int53", description: "Identifier of the message with the corresponding invoice; can be an identifier of a deleted message" },
+    Parameter { name: "currency", type_: "string", description: "Currency for the price of the product" },
+    Parameter { name: "total_amount", type_: "int32", description: "Total price for the product, in the smallest units of the currency" },
+  ] },
+  Constructor { name: "messagePaymentSuccessfulBot", description: "A payment has been completed; for bots only", class: "MessageContent", fields: &[
+    Parameter { name: "currency", type_: "string", description: "Currency for price of the product" },
+    Parameter { name: "total_amount", type_: "int32", description: "Total price for the product, in the smallest units of the currency" },
+    Parameter { name: "invoice_payload", type_: "bytes", description: "Invoice payload" },
+    Parameter { name: "shipping_option_id", type_: "string", description: "Identifier of the shipping option chosen by the user; may be empty if not applicable" },
+    Parameter { name: "order_info", type_: "orderInfo", description: "Information about the order; may be null" },
+    Parameter { name: "telegram_payment_charge_id", type_: "string", description: "Telegram payment identifier" },
+    Parameter { name: "provider_payment_charge_id", type_: "string", description: "Provider payment identifier" },
+  ] },
+  Constructor { name: "messagePhoto", description: "A photo message", class: "MessageContent", fields: &[
+    Parameter { name: "photo", type_: "photo", description: "The photo description" },
+    Parameter { name: "caption", type_: "formattedText", description: "Photo caption" },
+    Parameter { name: "is_secret", type_: "Bool", description: "True, if the photo must be blurred and must be shown only while tapped" },
+  ] },
+  Constructor { name: "messagePinMessage", description: "A message has been pinned", class: "MessageContent", fields: &[
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the pinned message, can be an identifier of a deleted message or 0" },
+  ] },
+  Constructor { name: "messagePoll", description: "A message with a poll", class: "MessageContent", fields: &[
+    Parameter { name: "poll", type_: "poll", description: "The poll description" },
+  ] },
+  Constructor { name: "messagePosition", description: "Contains information about a message in a specific position", class: "MessagePosition", fields: &[
+    Parameter { name: "position", type_: "int32", description: "0-based message position in the full list of suitable messages" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the message was sent" },
+  ] },
+  Constructor { name: "messagePositions", description: "Contains a list of message positions", class: "MessagePositions", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Total count of messages found" },
+    Parameter { name: "positions", type_: "vector<messagePosition>", description: "List of message positions" },
+  ] },
+  Constructor { name: "messageProximityAlertTriggered", description: "A user in the chat came within proximity alert range", class: "MessageContent", fields: &[
+    Parameter { name: "traveler", type_: "MessageSender", description: "The user or chat, which triggered the proximity alert" },
+    Parameter { name: "watcher", type_: "MessageSender", description: "The user or chat, which subscribed for the proximity alert" },
+    Parameter { name: "distance", type_: "int32", description: "The distance between the users" },
+  ] },
+  Constructor { name: "messageReplyInfo", description: "Contains information about replies to a message", class: "MessageReplyInfo", fields: &[
+    Parameter { name: "reply_count", type_: "int32", description: "Number of times the message was directly or indirectly replied" },
+    Parameter { name: "recent_repliers", type_: "vector<MessageSender>", description: "Recent repliers to the message; available in channels with a discussion supergroup" },
+    Parameter { name: "last_read_inbox_message_id", type_: "int53", description: "Identifier of the last read incoming reply to the message" },
+    Parameter { name: "last_read_outbox_message_id", type_: "int53", description: "Identifier of the last read outgoing reply to the message" },
+    Parameter { name: "last_message_id", type_: "int53", description: "Identifier of the last reply to the message" },
+  ] },
+  Constructor { name: "messageSchedulingStateSendAtDate", description: "The message will be sent at the specified date", class: "MessageSchedulingState", fields: &[
+    Parameter { name: "send_date", type_: "int32", description: "Date the message will be sent. The date must be within 367 days in the future" },
+  ] },
+  Constructor { name: "messageSchedulingStateSendWhenOnline", description: "The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known", class: "MessageSchedulingState", fields: &[] },
+  Constructor { name: "messageScreenshotTaken", description: "A screenshot of a message in the chat has been taken", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageSendOptions", description: "Options to be used when a message is sent", class: "MessageSendOptions", fields: &[
+    Parameter { name: "disable_notification", type_: "Bool", description: "Pass true to disable notification for the message" },
+    Parameter { name: "from_background", type_: "Bool", description: "Pass true if the message is sent from the background" },
+    Parameter { name: "scheduling_state", type_: "MessageSchedulingState", description: "Message scheduling state; pass null to send message immediately. Messages sent to a secret chat, live location messages and self-destructing messages can't be scheduled" },
+  ] },
+  Constructor { name: "messageSenderChat", description: "The message was sent on behalf of a chat", class: "MessageSender", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat that sent the message" },
+  ] },
+  Constructor { name: "messageSenderUser", description: "The message was sent by a known user", class: "MessageSender", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user that sent the message" },
+  ] },
+  Constructor { name: "messageSenders", description: "Represents a list of message senders", class: "MessageSenders", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of messages senders found" },
+    Parameter { name: "senders", type_: "vector<MessageSender>", description: "List of message senders" },
+  ] },
+  Constructor { name: "messageSendingStateFailed", description: "The message failed to be sent", class: "MessageSendingState", fields: &[
+    Parameter { name: "error_code", type_: "int32", description: "An error code; 0 if unknown" },
+    Parameter { name: "error_message", type_: "string", description: "Error message" },
+    Parameter { name: "can_retry", type_: "Bool", description: "True, if the message can be re-sent" },
+    Parameter { name: "retry_after", type_: "double", description: "Time left before the message can be re-sent, in seconds. No update is sent when this field changes" },
+  ] },
+  Constructor { name: "messageSendingStatePending", description: "The message is being sent now, but has not yet been delivered to the server", class: "MessageSendingState", fields: &[] },
+  Constructor { name: "messageStatistics", description: "A detailed statistics about a message", class: "MessageStatistics", fields: &[
+    Parameter { name: "message_interaction_graph", type_: "StatisticalGraph", description: "A graph containing number of message views and shares" },
+  ] },
+  Constructor { name: "messageSticker", description: "A sticker message", class: "MessageContent", fields: &[
+    Parameter { name: "sticker", type_: "sticker", description: "The sticker description" },
+  ] },
+  Constructor { name: "messageSupergroupChatCreate", description: "A newly created supergroup or channel", class: "MessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "Title of the supergroup or channel" },
+  ] },
+  Constructor { name: "messageText", description: "A text message", class: "MessageContent", fields: &[
+    Parameter { name: "text", type_: "formattedText", description: "Text of the message" },
+    Parameter { name: "web_page", type_: "webPage", description: "A preview of the web page that's mentioned in the text; may be null" },
+  ] },
+  Constructor { name: "messageThreadInfo", description: "Contains information about a message thread", class: "MessageThreadInfo", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to which the message thread belongs" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "Message thread identifier, unique within the chat" },
+    Parameter { name: "reply_info", type_: "messageReplyInfo", description: "Information about the message thread" },
+    Parameter { name: "unread_message_count", type_: "int32", description: "Approximate number of unread messages in the message thread" },
+    Parameter { name: "messages", type_: "vector<message>", description: "The messages from which the thread starts. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id)" },
+    Parameter { name: "draft_message", type_: "draftMessage", description: "A draft of a message in the message thread; may be null" },
+  ] },
+  Constructor { name: "messageUnsupported", description: "Message content that is not supported in the current TDLib version", class: "MessageContent", fields: &[] },
+  Constructor { name: "messageVenue", description: "A message with information about a venue", class: "MessageContent", fields: &[
+    Parameter { name: "venue", type_: "venue", description: "The venue description" },
+  ] },
+  Constructor { name: "messageVideo", description: "A video message", class: "MessageContent", fields: &[
+    Parameter { name: "video", type_: "video", description: "The video description" },
+    Parameter { name: "caption", type_: "formattedText", description: "Video caption" },
+    Parameter { name: "is_secret", type_: "Bool", description: "True, if the video thumbnail must be blurred and the video must be shown only while tapped" },
+  ] },
+  Constructor { name: "messageVideoChatEnded", description: "A message with information about an ended video chat", class: "MessageContent", fields: &[
+    Parameter { name: "duration", type_: "int32", description: "Call duration, in seconds" },
+  ] },
+  Constructor { name: "messageVideoChatScheduled", description: "A new video chat was scheduled", class: "MessageContent", fields: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Identifier of the video chat. The video chat can be received through the method getGroupCall" },
+    Parameter { name: "start_date", type_: "int32", description: "Point in time (Unix timestamp) when the group call is supposed to be started by an administrator" },
+  ] },
+  Constructor { name: "messageVideoChatStarted", description: "A newly created video chat", class: "MessageContent", fields: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Identifier of the video chat. The video chat can be received through the method getGroupCall" },
+  ] },
+  Constructor { name: "messageVideoNote", description: "A video note message", class: "MessageContent", fields: &[
+    Parameter { name: "video_note", type_: "videoNote", description: "The video note description" },
+    Parameter { name: "is_viewed", type_: "Bool", description: "True, if at least one of the recipients has viewed the video note" },
+    Parameter { name: "is_secret", type_: "Bool", description: "True, if the video note thumbnail must be blurred and the video note must be shown only while tapped" },
+  ] },
+  Constructor { name: "messageVoiceNote", description: "A voice note message", class: "MessageContent", fields: &[
+    Parameter { name: "voice_note", type_: "voiceNote", description: "The voice note description" },
+    Parameter { name: "caption", type_: "formattedText", description: "Voice note caption" },
+    Parameter { name: "is_listened", type_: "Bool", description: "True, if at least one of the recipients has listened to the voice note" },
+  ] },
+  Constructor { name: "messageWebsiteConnected", description: "The current user has connected a website by logging in using Telegram Login Widget on it", class: "MessageContent", fields: &[
+    Parameter { name: "domain_name", type_: "string", description: "Domain name of the connected website" },
+  ] },
+  Constructor { name: "messages", description: "Contains a list of messages", class: "Messages", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of messages found" },
+    Parameter { name: "messages", type_: "vector<message>", description: "List of messages; messages may be null" },
+  ] },
+  Constructor { name: "minithumbnail", description: "Thumbnail image of a very poor quality and low resolution", class: "Minithumbnail", fields: &[
+    Parameter { name: "width", type_: "int32", description: "Thumbnail width, usually doesn't exceed 40" },
+    Parameter { name: "height", type_: "int32", description: "Thumbnail height, usually doesn't exceed 40" },
+    Parameter { name: "data", type_: "bytes", description: "The thumbnail in JPEG format" },
+  ] },
+  Constructor { name: "networkStatistics", description: "A full list of available network statistic entries", class: "NetworkStatistics", fields: &[
+    Parameter { name: "since_date", type_: "int32", description: "Point in time (Unix timestamp) from which the statistics are collected" },
+    Parameter { name: "entries", type_: "vector<NetworkStatisticsEntry>", description: "Network statistics entries" },
+  ] },
+  Constructor { name: "networkStatisticsEntryCall", description: "Contains information about the total amount of data that was used for calls", class: "NetworkStatisticsEntry", fields: &[
+    Parameter { name: "network_type", type_: "NetworkType", description: "Type of the network the data was sent through. Call setNetworkType to maintain the actual network type" },
+    Parameter { name: "sent_bytes", type_: "int32", description: "Total number of bytes sent" },
+    Parameter { name: "received_bytes", type_: "int32", description: "Total number of bytes received" },
+    Parameter { name: "duration", type_: "double", description: "Total call duration, in seconds" },
+  ] },
+  Constructor { name: "networkStatisticsEntryFile", description: "Contains information about the total amount of data that was used to send and receive files", class: "NetworkStatisticsEntry", fields: &[
+    Parameter { name: "file_type", type_: "FileType", description: "Type of the file the data is part of; pass null if the data isn't related to files" },
+    Parameter { name: "network_type", type_: "NetworkType", description: "Type of the network the data was sent through. Call setNetworkType to maintain the actual network type" },
+    Parameter { name: "sent_bytes", type_: "int32", description: "Total number of bytes sent" },
+    Parameter { name: "received_bytes", type_: "int32", description: "Total number of bytes received" },
+  ] },
+  Constructor { name: "networkTypeMobile", description: "A mobile network", class: "NetworkType", fields: &[] },
+  Constructor { name: "networkTypeMobileRoaming", description: "A mobile roaming network", class: "NetworkType", fields: &[] },
+  Constructor { name: "networkTypeNone", description: "The network is not available", class: "NetworkType", fields: &[] },
+  Constructor { name: "networkTypeOther", description: "A different network type (e.g., Ethernet network)", class: "NetworkType", fields: &[] },
+  Constructor { name: "networkTypeWiFi", description: "A Wi-Fi network", class: "NetworkType", fields: &[] },
+  Constructor { name: "notification", description: "Contains information about a notification", class: "Notification", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique persistent identifier of this notification" },
+    Parameter { name: "date", type_: "int32", description: "Notification date" },
+    Parameter { name: "is_silent", type_: "Bool", description: "True, if the notification was initially silent" },
+    Parameter { name: "type", type_: "NotificationType", description: "Notification type" },
+  ] },
+  Constructor { name: "notificationGroup", description: "Describes a group of notifications", class: "NotificationGroup", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique persistent auto-incremented from 1 identifier of the notification group" },
+    Parameter { name: "type", type_: "NotificationGroupType", description: "Type of the group" },
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of a chat to which all notifications in the group belong" },
+    Parameter { name: "total_count", type_: "int32", description: "Total number of active notifications in the group" },
+    Parameter { name: "notifications", type_: "vector<notification>", description: "The list of active notifications" },
+  ] },
+  Constructor { name: "notificationGroupTypeCalls", description: "A group containing notifications of type notificationTypeNewCall", class: "NotificationGroupType", fields: &[] },
+  Constructor { name: "notificationGroupTypeMentions", description: "A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message", class: "NotificationGroupType", fields: &[] },
+  Constructor { name: "notificationGroupTypeMessages", description: "A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with ordinary unread messages", class: "NotificationGroupType", fields: &[] },
+  Constructor { name: "notificationGroupTypeSecretChat", description: "A group containing a notification of type notificationTypeNewSecretChat", class: "NotificationGroupType", fields: &[] },
+  Constructor { name: "notificationSettingsScopeChannelChats", description: "Notification settings applied to all channels when the corresponding chat setting has a default value", class: "NotificationSettingsScope", fields: &[] },
+  Constructor { name: "notificationSettingsScopeGroupChats", description: "Notification settings applied to all basic groups and supergroups when the corresponding chat setting has a default value", class: "NotificationSettingsScope", fields: &[] },
+  Constructor { name: "notificationSettingsScopePrivateChats", description: "Notification settings applied to all private and secret chats when the corresponding chat setting has a default value", class: "NotificationSettingsScope", fields: &[] },
+  Constructor { name: "notificationTypeNewCall", description: "New call was received", class: "NotificationType", fields: &[
+    Parameter { name: "call_id", type_: "int32", description: "Call identifier" },
+  ] },
+  Constructor { name: "notificationTypeNewMessage", description: "New message was received", class: "NotificationType", fields: &[
+    Parameter { name: "message", type_: "message", description: "The message" },
+  ] },
+  Constructor { name: "notificationTypeNewPushMessage", description: "New message was received through a push notification", class: "NotificationType", fields: &[
+    Parameter { name: "message_id", type_: "int53", description: "The message identifier. The message will not be available in the chat history, but the ID can be used in viewMessages, or as reply_to_message_id" },
+    Parameter { name: "sender", type_: "MessageSender", description: "The sender of the message. Corresponding user or chat may be inaccessible" },
+    Parameter { name: "sender_name", type_: "string", description: "Name of the sender" },
+    Parameter { name: "is_outgoing", type_: "Bool", description: "True, if the message is outgoing" },
+    Parameter { name: "content", type_: "PushMessageContent", description: "Push message content" },
+  ] },
+  Constructor { name: "notificationTypeNewSecretChat", description: "New secret chat was created", class: "NotificationType", fields: &[] },
+  Constructor { name: "ok", description: "An object of this type is returned on a successful function call for certain functions", class: "Ok", fields: &[] },
+  Constructor { name: "optionValueBoolean", description: "Represents a boolean option", class: "OptionValue", fields: &[
+    Parameter { name: "value", type_: "Bool", description: "The value of the option" },
+  ] },
+  Constructor { name: "optionValueEmpty", description: "Represents an unknown option or an option which has a default value", class: "OptionValue", fields: &[] },
+  Constructor { name: "optionValueInteger", description: "Represents an integer option", class: "OptionValue", fields: &[
+    Parameter { name: "value", type_: "int64", description: "The value of the option" },
+  ] },
+  Constructor { name: "optionValueString", description: "Represents a string option", class: "OptionValue", fields: &[
+    Parameter { name: "value", type_: "string", description: "The value of the option" },
+  ] },
+  Constructor { name: "orderInfo", description: "Order information", class: "OrderInfo", fields: &[
+    Parameter { name: "name", type_: "string", description: "Name of the user" },
+    Parameter { name: "phone_number", type_: "string", description: "Phone number of the user" },
+    Parameter { name: "email_address", type_: "string", description: "Email address of the user" },
+    Parameter { name: "shipping_address", type_: "address", description: "Shipping address for this order; may be null" },
+  ] },
+  Constructor { name: "pageBlockAnchor", description: "An invisible anchor on a page, which can be used in a URL to open the page from the specified anchor", class: "PageBlock", fields: &[
+    Parameter { name: "name", type_: "string", description: "Name of the anchor" },
+  ] },
+  Constructor { name: "pageBlockAnimation", description: "An animation", class: "PageBlock", fields: &[
+    Parameter { name: "animation", type_: "animation", description: "Animation file; may be null" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Animation caption" },
+    Parameter { name: "need_autoplay", type_: "Bool", description: "True, if the animation must be played automatically" },
+  ] },
+  Constructor { name: "pageBlockAudio", description: "An audio file", class: "PageBlock", fields: &[
+    Parameter { name: "audio", type_: "audio", description: "Audio file; may be null" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Audio file caption" },
+  ] },
+  Constructor { name: "pageBlockAuthorDate", description: "The author and publishing date of a page", class: "PageBlock", fields: &[
+    Parameter { name: "author", type_: "RichText", description: "Author" },
+    Parameter { name: "publish_date", type_: "int32", description: "Point in time (Unix timestamp) when the article was published; 0 if unknown" },
+  ] },
+  Constructor { name: "pageBlockBlockQuote", description: "A block quote", class: "PageBlock", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Quote text" },
+    Parameter { name: "credit", type_: "RichText", description: "Quote credit" },
+  ] },
+  Constructor { name: "pageBlockCaption", description: "Contains a caption of an instant view web page block, consisting of a text and a trailing credit", class: "PageBlockCaption", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Content of the caption" },
+    Parameter { name: "credit", type_: "RichText", description: "Block credit (like HTML tag <cite>)" },
+  ] },
+  Constructor { name: "pageBlockChatLink", description: "A link to a chat", class: "PageBlock", fields: &[
+    Parameter { name: "title", type_: "string", description: "Chat title" },
+    Parameter { name: "photo", type_: "chatPhotoInfo", description: "Chat photo; may be null" },
+    Parameter { name: "username", type_: "string", description: "Chat username, by which all other information about the chat can be resolved" },
+  ] },
+  Constructor { name: "pageBlockCollage", description: "A collage", class: "PageBlock", fields: &[
+    Parameter { name: "page_blocks", type_: "vector<PageBlock>", description: "Collage item contents" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Block caption" },
+  ] },
+  Constructor { name: "pageBlockCover", description: "A page cover", class: "PageBlock", fields: &[
+    Parameter { name: "cover", type_: "PageBlock", description: "Cover" },
+  ] },
+  Constructor { name: "pageBlockDetails", description: "A collapsible block", class: "PageBlock", fields: &[
+    Parameter { name: "header", type_: "RichText", description: "Always visible heading for the block" },
+    Parameter { name: "page_blocks", type_: "vector<PageBlock>", description: "Block contents" },
+    Parameter { name: "is_open", type_: "Bool", description: "True, if the block is open by default" },
+  ] },
+  Constructor { name: "pageBlockDivider", description: "An empty block separating a page", class: "PageBlock", fields: &[] },
+  Constructor { name: "pageBlockEmbedded", description: "An embedded web page", class: "PageBlock", fields: &[
+    Parameter { name: "url", type_: "string", description: "Web page URL, if available" },
+    Parameter { name: "html", type_: "string", description: "HTML-markup of the embedded page" },
+    Parameter { name: "poster_photo", type_: "photo", description: "Poster photo, if available; may be null" },
+    Parameter { name: "width", type_: "int32", description: "Block width; 0 if unknown" },
+    Parameter { name: "height", type_: "int32", description: "Block height; 0 if unknown" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Block caption" },
+    Parameter { name: "is_full_width", type_: "Bool", description: "True, if the block must be full width" },
+    Parameter { name: "allow_scrolling", type_: "Bool", description: "True, if scrolling needs to be allowed" },
+  ] },
+  Constructor { name: "pageBlockEmbeddedPost", description: "An embedded post", class: "PageBlock", fields: &[
+    Parameter { name: "url", type_: "string", description: "Web page URL" },
+    Parameter { name: "author", type_: "string", description: "Post author" },
+    Parameter { name: "author_photo", type_: "photo", description: "Post author photo; may be null" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the post was created; 0 if unknown" },
+    Parameter { name: "page_blocks", type_: "vector<PageBlock>", description: "Post content" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Post caption" },
+  ] },
+  Constructor { name: "pageBlockFooter", description: "The footer of a page", class: "PageBlock", fields: &[
+    Parameter { name: "footer", type_: "RichText", description: "Footer" },
+  ] },
+  Constructor { name: "pageBlockHeader", description: "A header", class: "PageBlock", fields: &[
+    Parameter { name: "header", type_: "RichText", description: "Header" },
+  ] },
+  Constructor { name: "pageBlockHorizontalAlignmentCenter", description: "The content must be center-aligned", class: "PageBlockHorizontalAlignment", fields: &[] },
+  Constructor { name: "pageBlockHorizontalAlignmentLeft", description: "The content must be left-aligned", class: "PageBlockHorizontalAlignment", fields: &[] },
+  Constructor { name: "pageBlockHorizontalAlignmentRight", description: "The content must be right-aligned", class: "PageBlockHorizontalAlignment", fields: &[] },
+  Constructor { name: "pageBlockKicker", description: "A kicker", class: "PageBlock", fields: &[
+    Parameter { name: "kicker", type_: "RichText", description: "Kicker" },
+  ] },
+  Constructor { name: "pageBlockList", description: "A list of data blocks", class: "PageBlock", fields: &[
+    Parameter { name: "items", type_: "vector<pageBlockListItem>", description: "The items of the list" },
+  ] },
+  Constructor { name: "pageBlockListItem", description: "Describes an item of a list page block", class: "PageBlockListItem", fields: &[
+    Parameter { name: "label", type_: "string", description: "Item label" },
+    Parameter { name: "page_blocks", type_: "vector<PageBlock>", description: "Item blocks" },
+  ] },
+  Constructor { name: "pageBlockMap", description: "A map", class: "PageBlock", fields: &[
+    Parameter { name: "location", type_: "location", description: "Location of the map center" },
+    Parameter { name: "zoom", type_: "int32", description: "Map zoom level" },
+    Parameter { name: "width", type_: "int32", description: "Map width" },
+    Parameter { name: "height", type_: "int32", description: "Map height" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Block caption" },
+  ] },
+  Constructor { name: "pageBlockParagraph", description: "A text paragraph", class: "PageBlock", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Paragraph text" },
+  ] },
+  Constructor { name: "pageBlockPhoto", description: "A photo", class: "PageBlock", fields: &[
+    Parameter { name: "photo", type_: "photo", description: "Photo file; may be null" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Photo caption" },
+    Parameter { name: "url", type_: "string", description: "URL that needs to be opened when the photo is clicked" },
+  ] },
+  Constructor { name: "pageBlockPreformatted", description: "A preformatted text paragraph", class: "PageBlock", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Paragraph text" },
+    Parameter { name: "language", type_: "string", description: "Programming language for which the text needs to be formatted" },
+  ] },
+  Constructor { name: "pageBlockPullQuote", description: "A pull quote", class: "PageBlock", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Quote text" },
+    Parameter { name: "credit", type_: "RichText", description: "Quote credit" },
+  ] },
+  Constructor { name: "pageBlockRelatedArticle", description: "Contains information about a related article", class: "PageBlockRelatedArticle", fields: &[
+    Parameter { name: "url", type_: "string", description: "Related article URL" },
+    Parameter { name: "title", type_: "string", description: "Article title; may be empty" },
+    Parameter { name: "description", type_: "string", description: "Article description; may be empty" },
+    Parameter { name: "photo", type_: "photo", description: "Article photo; may be null" },
+    Parameter { name: "author", type_: "string", description: "Article author; may be empty" },
+    Parameter { name: "publish_date", type_: "int32", description: "Point in time (Unix timestamp) when the article was published; 0 if unknown" },
+  ] },
+  Constructor { name: "pageBlockRelatedArticles", description: "Related articles", class: "PageBlock", fields: &[
+    Parameter { name: "header", type_: "RichText", description: "Block header" },
+    Parameter { name: "articles", type_: "vector<pageBlockRelatedArticle>", description: "List of related articles" },
+  ] },
+  Constructor { name: "pageBlockSlideshow", description: "A slideshow", class: "PageBlock", fields: &[
+    Parameter { name: "page_blocks", type_: "vector<PageBlock>", description: "Slideshow item contents" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Block caption" },
+  ] },
+  Constructor { name: "pageBlockSubheader", description: "A subheader", class: "PageBlock", fields: &[
+    Parameter { name: "subheader", type_: "RichText", description: "Subheader" },
+  ] },
+  Constructor { name: "pageBlockSubtitle", description: "The subtitle of a page", class: "PageBlock", fields: &[
+    Parameter { name: "subtitle", type_: "RichText", description: "Subtitle" },
+  ] },
+  Constructor { name: "pageBlockTable", description: "A table", class: "PageBlock", fields: &[
+    Parameter { name: "caption", type_: "RichText", description: "Table caption" },
+    Parameter { name: "cells", type_: "vector<vector<pageBlockTableCell>>", description: "Table cells" },
+    Parameter { name: "is_bordered", type_: "Bool", description: "True, if the table is bordered" },
+    Parameter { name: "is_striped", type_: "Bool", description: "True, if the table is striped" },
+  ] },
+  Constructor { name: "pageBlockTableCell", description: "Represents a cell of a table", class: "PageBlockTableCell", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Cell text; may be null. If the text is null, then the cell must be invisible" },
+    Parameter { name: "is_header", type_: "Bool", description: "True, if it is a header cell" },
+    Parameter { name: "colspan", type_: "int32", description: "The number of columns the cell spans" },
+    Parameter { name: "rowspan", type_: "int32", description: "The number of rows the cell spans" },
+    Parameter { name: "align", type_: "PageBlockHorizontalAlignment", description: "Horizontal cell content alignment" },
+    Parameter { name: "valign", type_: "PageBlockVerticalAlignment", description: "Vertical cell content alignment" },
+  ] },
+  Constructor { name: "pageBlockTitle", description: "The title of a page", class: "PageBlock", fields: &[
+    Parameter { name: "title", type_: "RichText", description: "Title" },
+  ] },
+  Constructor { name: "pageBlockVerticalAlignmentBottom", description: "The content must be bottom-aligned", class: "PageBlockVerticalAlignment", fields: &[] },
+  Constructor { name: "pageBlockVerticalAlignmentMiddle", description: "The content must be middle-aligned", class: "PageBlockVerticalAlignment", fields: &[] },
+  Constructor { name: "pageBlockVerticalAlignmentTop", description: "The content must be top-aligned", class: "PageBlockVerticalAlignment", fields: &[] },
+  Constructor { name: "pageBlockVideo", description: "A video", class: "PageBlock", fields: &[
+    Parameter { name: "video", type_: "video", description: "Video file; may be null" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Video caption" },
+    Parameter { name: "need_autoplay", type_: "Bool", description: "True, if the video must be played automatically" },
+    Parameter { name: "is_looped", type_: "Bool", description: "True, if the video must be looped" },
+  ] },
+  Constructor { name: "pageBlockVoiceNote", description: "A voice note", class: "PageBlock", fields: &[
+    Parameter { name: "voice_note", type_: "voiceNote", description: "Voice note; may be null" },
+    Parameter { name: "caption", type_: "pageBlockCaption", description: "Voice note caption" },
+  ] },
+  Constructor { name: "passportAuthorizationForm", description: "Contains information about a Telegram Passport authorization form that was requested", class: "PassportAuthorizationForm", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique identifier of the authorization form" },
+    Parameter { name: "required_elements", type_: "vector<passportRequiredElement>", description: "Telegram Passport elements that must be provided to complete the form" },
+    Parameter { name: "privacy_policy_url", type_: "string", description: "URL for the privacy policy of the service; may be empty" },
+  ] },
+  Constructor { name: "passportElementAddress", description: "A Telegram Passport element containing the user's address", class: "PassportElement", fields: &[
+    Parameter { name: "address", type_: "address", description: "Address" },
+  ] },
+  Constructor { name: "passportElementBankStatement", description: "A Telegram Passport element containing the user's bank statement", class: "PassportElement", fields: &[
+    Parameter { name: "bank_statement", type_: "personalDocument", description: "Bank statement" },
+  ] },
+  Constructor { name: "passportElementDriverLicense", description: "A Telegram Passport element containing the user's driver license", class: "PassportElement", fields: &[
+    Parameter { name: "driver_license", type_: "identityDocument", description: "Driver license" },
+  ] },
+  Constructor { name: "passportElementEmailAddress", description: "A Telegram Passport element containing the user's email address", class: "PassportElement", fields: &[
+    Parameter { name: "email_address", type_: "string", description: "Email address" },
+  ] },
+  Constructor { name: "passportElementError", description: "Contains the description of an error in a Telegram Passport element", class: "PassportElementError", fields: &[
+    Parameter { name: "type", type_: "PassportElementType", description: "Type of the Telegram Passport element which has the error" },
+    Parameter { name: "message", type_: "string", description: "Error message" },
+    Parameter { name: "source", type_: "PassportElementErrorSource", description: "Error source" },
+  ] },
+  Constructor { name: "passportElementErrorSourceDataField", description: "One of the data fields contains an error. The error will be considered resolved when the value of the field changes", class: "PassportElementErrorSource", fields: &[
+    Parameter { name: "field_name", type_: "string", description: "Field name" },
+  ] },
+  Constructor { name: "passportElementErrorSourceFile", description: "The file contains an error. The error will be considered resolved when the file changes", class: "PassportElementErrorSource", fields: &[
+    Parameter { name: "file_index", type_: "int32", description: "Index of a file with the error" },
+  ] },
+  Constructor { name: "passportElementErrorSourceFiles", description: "The list of attached files contains an error. The error will be considered resolved when the list of files changes", class: "PassportElementErrorSource", fields: &[] },
+  Constructor { name: "passportElementErrorSourceFrontSide", description: "The front side of the document contains an error. The error will be considered resolved when the file with the front side changes", class: "PassportElementErrorSource", fields: &[] },
+  Constructor { name: "passportElementErrorSourceReverseSide", description: "The reverse side of the document contains an error. The error will be considered resolved when the file with the reverse side changes", class: "PassportElementErrorSource", fields: &[] },
+  Constructor { name: "passportElementErrorSourceSelfie", description: "The selfie with the document contains an error. The error will be considered resolved when the file with the selfie changes", class: "PassportElementErrorSource", fields: &[] },
+  Constructor { name: "passportElementErrorSourceTranslationFile", description: "One of files with the translation of the document contains an error. The error will be considered resolved when the file changes", class: "PassportElementErrorSource", fields: &[
+    Parameter { name: "file_index", type_: "int32", description: "Index of a file with the error" },
+  ] },
+  Constructor { name: "passportElementErrorSourceTranslationFiles", description: "The translation of the document contains an error. The error will be considered resolved when the list of translation files changes", class: "PassportElementErrorSource", fields: &[] },
+  Constructor { name: "passportElementErrorSourceUnspecified", description: "The element contains an error in an unspecified place. The error will be considered resolved when new data is added", class: "PassportElementErrorSource", fields: &[] },
+  Constructor { name: "passportElementIdentityCard", description: "A Telegram Passport element containing the user's identity card", class: "PassportElement", fields: &[
+    Parameter { name: "identity_card", type_: "identityDocument", description: "Identity card" },
+  ] },
+  Constructor { name: "passportElementInternalPassport", description: "A Telegram Passport element containing the user's internal passport", class: "PassportElement", fields: &[
+    Parameter { name: "internal_passport", type_: "identityDocument", description: "Internal passport" },
+  ] },
+  Constructor { name: "passportElementPassport", description: "A Telegram Passport element containing the user's passport", class: "PassportElement", fields: &[
+    Parameter { name: "passport", type_: "identityDocument", description: "Passport" },
+  ] },
+  Constructor { name: "passportElementPassportRegistration", description: "A Telegram Passport element containing the user's passport registration pages", class: "PassportElement", fields: &[
+    Parameter { name: "passport_registration", type_: "personalDocument", description: "Passport registration pages" },
+  ] },
+  Constructor { name: "passportElementPersonalDetails", description: "A Telegram Passport element containing the user's personal details", class: "PassportElement", fields: &[
+    Parameter { name: "personal_details", type_: "personalDetails", description: "Personal details of the user" },
+  ] },
+  Constructor { name: "passportElementPhoneNumber", description: "A Telegram Passport element containing the user's phone number", class: "PassportElement", fields: &[
+    Parameter { name: "phone_number", type_: "string", description: "Phone number" },
+  ] },
+  Constructor { name: "passportElementRentalAgreement", description: "A Telegram Passport element containing the user's rental agreement", class: "PassportElement", fields: &[
+    Parameter { name: "rental_agreement", type_: "personalDocument", description: "Rental agreement" },
+  ] },
+  Constructor { name: "passportElementTemporaryRegistration", description: "A Telegram Passport element containing the user's temporary registration", class: "PassportElement", fields: &[
+    Parameter { name: "temporary_registration", type_: "personalDocument", description: "Temporary registration" },
+  ] },
+  Constructor { name: "passportElementTypeAddress", description: "A Telegram Passport element containing the user's address", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeBankStatement", description: "A Telegram Passport element containing the user's bank statement", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeDriverLicense", description: "A Telegram Passport element containing the user's driver license", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeEmailAddress", description: "A Telegram Passport element containing the user's email address", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeIdentityCard", description: "A Telegram Passport element containing the user's identity card", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeInternalPassport", description: "A Telegram Passport element containing the user's internal passport", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypePassport", description: "A Telegram Passport element containing the user's passport", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypePassportRegistration", description: "A Telegram Passport element containing the registration page of the user's passport", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypePersonalDetails", description: "A Telegram Passport element containing the user's personal details", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypePhoneNumber", description: "A Telegram Passport element containing the user's phone number", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeRentalAgreement", description: "A Telegram Passport element containing the user's rental agreement", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeTemporaryRegistration", description: "A Telegram Passport element containing the user's temporary registration", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementTypeUtilityBill", description: "A Telegram Passport element containing the user's utility bill", class: "PassportElementType", fields: &[] },
+  Constructor { name: "passportElementUtilityBill", description: "A Telegram Passport element containing the user's utility bill", class: "PassportElement", fields: &[
+    Parameter { name: "utility_bill", type_: "personalDocument", description: "Utility bill" },
+  ] },
+  Constructor { name: "passportElements", description: "Contains information about saved Telegram Passport elements", class: "PassportElements", fields: &[
+    Parameter { name: "elements", type_: "vector<PassportElement>", description: "Telegram Passport elements" },
+  ] },
+  Constructor { name: "passportElementsWithErrors", description: "Contains information about a Telegram Passport elements and corresponding errors", class: "PassportElementsWithErrors", fields: &[
+    Parameter { name: "elements", type_: "vector<PassportElement>", description: "Telegram Passport elements" },
+    Parameter { name: "errors", type_: "vector<passportElementError>", description: "Errors in the elements that are already available" },
+  ] },
+  Constructor { name: "passportRequiredElement", description: "Contains a description of the required Telegram Passport element that was requested by a service", class: "PassportRequiredElement", fields: &[
+    Parameter { name: "suitable_elements", type_: "vector<passportSuitableElement>", description: "List of Telegram Passport elements any of which is enough to provide" },
+  ] },
+  Constructor { name: "passportSuitableElement", description: "Contains information about a Telegram Passport element that was requested by a service", class: "PassportSuitableElement", fields: &[
+    Parameter { name: "type", type_: "PassportElementType", description: "Type of the element" },
+    Parameter { name: "is_selfie_required", type_: "Bool", description: "True, if a selfie is required with the identity document" },
+    Parameter { name: "is_translation_required", type_: "Bool", description: "True, if a certified English translation is required with the document" },
+    Parameter { name: "is_native_name_required", type_: "Bool", description: "True, if personal details must include the user's name in the language of their country of residence" },
+  ] },
+  Constructor { name: "passwordState", description: "Represents the current state of 2-step verification", class: "PasswordState", fields: &[
+    Parameter { name: "has_password", type_: "Bool", description: "True, if a 2-step verification password is set" },
+    Parameter { name: "password_hint", type_: "string", description: "Hint for the password; may be empty" },
+    Parameter { name: "has_recovery_email_address", type_: "Bool", description: "True, if a recovery email is set" },
+    Parameter { name: "has_passport_data", type_: "Bool", description: "True, if some Telegram Passport elements were saved" },
+    Parameter { name: "recovery_email_address_code_info", type_: "emailAddressAuthenticationCodeInfo", description: "Information about the recovery email address to which the confirmation email was sent; may be null" },
+    Parameter { name: "pending_reset_date", type_: "int32", description: "If not 0, point in time (Unix timestamp) after which the password can be reset immediately using resetPassword" },
+  ] },
+  Constructor { name: "paymentForm", description: "Contains information about an invoice payment form", class: "PaymentForm", fields: &[
+    Parameter { name: "id", type_: "int64", description: "The payment form identifier" },
+    Parameter { name: "invoice", type_: "invoice", description: "Full information of the invoice" },
+    Parameter { name: "url", type_: "string", description: "Payment form URL" },
+    Parameter { name: "seller_bot_user_id", type_: "int53", description: "User identifier of the seller bot" },
+    Parameter { name: "payments_provider_user_id", type_: "int53", description: "User identifier of the payment provider bot" },
+    Parameter { name: "payments_provider", type_: "paymentsProviderStripe", description: "Information about the payment provider, if available, to support it natively without the need for opening the URL; may be null" },
+    Parameter { name: "saved_order_info", type_: "orderInfo", description: "Saved server-side order information; may be null" },
+    Parameter { name: "saved_credentials", type_: "savedCredentials", description: "Information about saved card credentials; may be null" },
+    Parameter { name: "can_save_credentials", type_: "Bool", description: "True, if the user can choose to save credentials" },
+    Parameter { name: "need_password", type_: "Bool", description: "True, if the user will be able to save credentials protected by a password they set up" },
+  ] },
+  Constructor { name: "paymentFormTheme", description: "Theme colors for a payment form", class: "PaymentFormTheme", fields: &[
+    Parameter { name: "background_color", type_: "int32", description: "A color of the payment form background in the RGB24 format" },
+    Parameter { name: "text_color", type_: "int32", description: "A color of text in the RGB24 format" },
+    Parameter { name: "hint_color", type_: "int32", description: "A color of hints in the RGB24 format" },
+    Parameter { name: "link_color", type_: "int32", description: "A color of links in the RGB24 format" },
+    Parameter { name: "button_color", type_: "int32", description: "A color of the buttons in the RGB24 format" },
+    Parameter { name: "button_text_color", type_: "int32", description: "A color of text on the buttons in the RGB24 format" },
+  ] },
+  Constructor { name: "paymentReceipt", description: "Contains information about a successful payment", class: "PaymentReceipt", fields: &[
+    Parameter { name: "title", type_: "string", description: "Product title" },
+    Parameter { name: "description", type_: "string", description: "Product description" },
+    Parameter { name: "photo", type_: "photo", description: "Product photo; may be null" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the payment was made" },
+    Parameter { name: "seller_bot_user_id", type_: "int53", description: "User identifier of the seller bot" },
+    Parameter { name: "payments_provider_user_id", type_: "int53", description: "User identifier of the payment provider bot" },
+    Parameter { name: "invoice", type_: "invoice", description: "Information about the invoice" },
+    Parameter { name: "order_info", type_: "orderInfo", description: "Order information; may be null" },
+    Parameter { name: "shipping_option", type_: "shippingOption", description: "Chosen shipping option; may be null" },
+    Parameter { name: "credentials_title", type_: "string", description: "Title of the saved credentials chosen by the buyer" },
+    Parameter { name: "tip_amount", type_: "int32", description: "The amount of tip chosen by the buyer in the smallest units of the currency" },
+  ] },
+  Constructor { name: "paymentResult", description: "Contains the result of a payment request", class: "PaymentResult", fields: &[
+    Parameter { name: "success", type_: "Bool", description: "True, if the payment request was successful; otherwise the verification_url will be non-empty" },
+    Parameter { name: "verification_url", type_: "string", description: "URL for additional payment credentials verification" },
+  ] },
+  Constructor { name: "paymentsProviderStripe", description: "Stripe payment provider", class: "PaymentsProviderStripe", fields: &[
+    Parameter { name: "publishable_key", type_: "string", description: "Stripe API publishable key" },
+    Parameter { name: "need_country", type_: "Bool", description: "True, if the user country must be provided" },
+    Parameter { name: "need_postal_code", type_: "Bool", description: "True, if the user ZIP/postal code must be provided" },
+    Parameter { name: "need_cardholder_name", type_: "Bool", description: "True, if the cardholder name must be provided" },
+  ] },
+  Constructor { name: "personalDetails", description: "Contains the user's personal details", class: "PersonalDetails", fields: &[
+    Parameter { name: "first_name", type_: "string", description: "First name of the user written in English; 1-255 characters" },
+    Parameter { name: "middle_name", type_: "string", description: "Middle name of the user written in English; 0-255 characters" },
+    Parameter { name: "last_name", type_: "string", description: "Last name of the user written in English; 1-255 characters" },
+    Parameter { name: "native_first_name", type_: "string", description: "Native first name of the user; 1-255 characters" },
+    Parameter { name: "native_middle_name", type_: "string", description: "Native middle name of the user; 0-255 characters" },
+    Parameter { name: "native_last_name", type_: "string", description: "Native last name of the user; 1-255 characters" },
+    Parameter { name: "birthdate", type_: "date", description: "Birthdate of the user" },
+    Parameter { name: "gender", type_: "string", description: "Gender of the user, \"male\" or \"female\"" },
+    Parameter { name: "country_code", type_: "string", description: "A two-letter ISO 3166-1 alpha-2 country code of the user's country" },
+    Parameter { name: "residence_country_code", type_: "string", description: "A two-letter ISO 3166-1 alpha-2 country code of the user's residence country" },
+  ] },
+  Constructor { name: "personalDocument", description: "A personal document, containing some information about a user", class: "PersonalDocument", fields: &[
+    Parameter { name: "files", type_: "vector<datedFile>", description: "List of files containing the pages of the document" },
+    Parameter { name: "translation", type_: "vector<datedFile>", description: "List of files containing a certified English translation of the document" },
+  ] },
+  Constructor { name: "phoneNumberAuthenticationSettings", description: "Contains settings for the authentication of the user's phone number", class: "PhoneNumberAuthenticationSettings", fields: &[
+    Parameter { name: "allow_flash_call", type_: "Bool", description: "Pass true if the authentication code may be sent via flash call to the specified phone number" },
+    Parameter { name: "is_current_phone_number", type_: "Bool", description: "Pass true if the authenticated phone number is used on the current device" },
+    Parameter { name: "allow_sms_retriever_api", type_: "Bool", description: "For official applications only. True, if the application can use Android SMS Retriever API (requires Google Play Services >= 10.2) to automatically receive the authentication code from the SMS. See https://developers.google.com/identity/sms-retriever/ for more details" },
+  ] },
+  Constructor { name: "phoneNumberInfo", description: "Contains information about a phone number", class: "PhoneNumberInfo", fields: &[
+    Parameter { name: "country", type_: "countryInfo", description: "Information about the country to which the phone number belongs; may be null" },
+    Parameter { name: "country_calling_code", type_: "string", description: "The part of the phone number denoting country calling code or its part" },
+    Parameter { name: "formatted_phone_number", type_: "string", description: "The phone number without country calling code formatted accordingly to local rules. Expected digits are returned as '-', but even more digits might be entered by the user" },
+  ] },
+  Constructor { name: "photo", description: "Describes a photo", class: "Photo", fields: &[
+    Parameter { name: "has_stickers", type_: "Bool", description: "True, if stickers were added to the photo. The list of corresponding sticker sets can be received using getAttachedStickerSets" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "Photo minithumbnail; may be null" },
+    Parameter { name: "sizes", type_: "vector<photoSize>", description: "Available variants of the photo, in different sizes" },
+  ] },
+  Constructor { name: "photoSize", description: "Describes an image in JPEG format", class: "PhotoSize", fields: &[
+    Parameter { name: "type", type_: "string", description: "Image type (see https://core.telegram.org/constructor/photoSize)" },
+    Parameter { name: "photo", type_: "file", description: "Information about the image file" },
+    Parameter { name: "width", type_: "int32", description: "Image width" },
+    Parameter { name: "height", type_: "int32", description: "Image height" },
+    Parameter { name: "progressive_sizes", type_: "vector<int32>", description: "Sizes of progressive JPEG file prefixes, which can be used to preliminarily show the image; in bytes" },
+  ] },
+  Constructor { name: "point", description: "A point on a Cartesian plane", class: "Point", fields: &[
+    Parameter { name: "x", type_: "double", description: "The point's first coordinate" },
+    Parameter { name: "y", type_: "double", description: "The point's second coordinate" },
+  ] },
+  Constructor { name: "poll", description: "Describes a poll", class: "Poll", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique poll identifier" },
+    Parameter { name: "question", type_: "string", description: "Poll question; 1-300 characters" },
+    Parameter { name: "options", type_: "vector<pollOption>", description: "List of poll answer options" },
+    Parameter { name: "total_voter_count", type_: "int32", description: "Total number of voters, participating in the poll" },
+    Parameter { name: "recent_voter_user_ids", type_: "vector<int53>", description: "User identifiers of recent voters, if the poll is non-anonymous" },
+    Parameter { name: "is_anonymous", type_: "Bool", description: "True, if the poll is anonymous" },
+    Parameter { name: "type", type_: "PollType", description: "Type of the poll" },
+    Parameter { name: "open_period", type_: "int32", description: "Amount of time the poll will be active after creation, in seconds" },
+    Parameter { name: "close_date", type_: "int32", description: "Point in time (Unix timestamp) when the poll will be automatically closed" },
+    Parameter { name: "is_closed", type_: "Bool", description: "True, if the poll is closed" },
+  ] },
+  Constructor { name: "pollOption", description: "Describes one answer option of a poll", class: "PollOption", fields: &[
+    Parameter { name: "text", type_: "string", description: "Option text; 1-100 characters" },
+    Parameter { name: "voter_count", type_: "int32", description: "Number of voters for this option, available only for closed or voted polls" },
+    Parameter { name: "vote_percentage", type_: "int32", description: "The percentage of votes for this option; 0-100" },
+    Parameter { name: "is_chosen", type_: "Bool", description: "True, if the option was chosen by the user" },
+    Parameter { name: "is_being_chosen", type_: "Bool", description: "True, if the option is being chosen by a pending setPollAnswer request" },
+  ] },
+  Constructor { name: "pollTypeQuiz", description: "A poll in quiz mode, which has exactly one correct answer option and can be answered only once", class: "PollType", fields: &[
+    Parameter { name: "correct_option_id", type_: "int32", description: "0-based identifier of the correct answer option; 1 for a yet unanswered poll" },
+    Parameter { name: "explanation", type_: "formattedText", description: "Text that is shown when the user chooses an incorrect answer or taps on the lamp icon; 0-200 characters with at most 2 line feeds; empty for a yet unanswered poll" },
+  ] },
+  Constructor { name: "pollTypeRegular", description: "A regular poll", class: "PollType", fields: &[
+    Parameter { name: "allow_multiple_answers", type_: "Bool", description: "True, if multiple answer options can be chosen simultaneously" },
+  ] },
+  Constructor { name: "profilePhoto", description: "Describes a user profile photo", class: "ProfilePhoto", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Photo identifier; 0 for an empty photo. Can be used to find a photo in a list of user profile photos" },
+    Parameter { name: "small", type_: "file", description: "A small (160x160) user profile photo. The file can be downloaded only before the photo is changed" },
+    Parameter { name: "big", type_: "file", description: "A big (640x640) user profile photo. The file can be downloaded only before the photo is changed" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "User profile photo minithumbnail; may be null" },
+    Parameter { name: "has_animation", type_: "Bool", description: "True, if the photo has animated variant" },
+  ] },
+  Constructor { name: "proxies", description: "Represents a list of proxy servers", class: "Proxies", fields: &[
+    Parameter { name: "proxies", type_: "vector<proxy>", description: "List of proxy servers" },
+  ] },
+  Constructor { name: "proxy", description: "Contains information about a proxy server", class: "Proxy", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique identifier of the proxy" },
+    Parameter { name: "server", type_: "string", description: "Proxy server IP address" },
+    Parameter { name: "port", type_: "int32", description: "Proxy server port" },
+    Parameter { name: "last_used_date", type_: "int32", description: "Point in time (Unix timestamp) when the proxy was last used; 0 if never" },
+    Parameter { name: "is_enabled", type_: "Bool", description: "True, if the proxy is enabled now" },
+    Parameter { name: "type", type_: "ProxyType", description: "Type of the proxy" },
+  ] },
+  Constructor { name: "proxyTypeHttp", description: "A HTTP transparent proxy server", class: "ProxyType", fields: &[
+    Parameter { name: "username", type_: "string", description: "Username for logging in; may be empty" },
+    Parameter { name: "password", type_: "string", description: "Password for logging in; may be empty" },
+    Parameter { name: "http_only", type_: "Bool", description: "Pass true if the proxy supports only HTTP requests and doesn't support transparent TCP connections via HTTP CONNECT method" },
+  ] },
+  Constructor { name: "proxyTypeMtproto", description: "An MTProto proxy server", class: "ProxyType", fields: &[
+    Parameter { name: "secret", type_: "string", description: "The proxy's secret in hexadecimal encoding" },
+  ] },
+  Constructor { name: "proxyTypeSocks5", description: "A SOCKS5 proxy server", class: "ProxyType", fields: &[
+    Parameter { name: "username", type_: "string", description: "Username for logging in; may be empty" },
+    Parameter { name: "password", type_: "string", description: "Password for logging in; may be empty" },
+  ] },
+  Constructor { name: "publicChatTypeHasUsername", description: "The chat is public, because it has username", class: "PublicChatType", fields: &[] },
+  Constructor { name: "publicChatTypeIsLocationBased", description: "The chat is public, because it is a location-based supergroup", class: "PublicChatType", fields: &[] },
+  Constructor { name: "pushMessageContentAnimation", description: "An animation message (GIF-style).", class: "PushMessageContent", fields: &[
+    Parameter { name: "animation", type_: "animation", description: "Message content; may be null" },
+    Parameter { name: "caption", type_: "string", description: "Animation caption" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentAudio", description: "An audio message", class: "PushMessageContent", fields: &[
+    Parameter { name: "audio", type_: "audio", description: "Message content; may be null" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentBasicGroupChatCreate", description: "A newly created basic group", class: "PushMessageContent", fields: &[] },
+  Constructor { name: "pushMessageContentChatAddMembers", description: "New chat members were invited to a group", class: "PushMessageContent", fields: &[
+    Parameter { name: "member_name", type_: "string", description: "Name of the added member" },
+    Parameter { name: "is_current_user", type_: "Bool", description: "True, if the current user was added to the group" },
+    Parameter { name: "is_returned", type_: "Bool", description: "True, if the user has returned to the group themselves" },
+  ] },
+  Constructor { name: "pushMessageContentChatChangePhoto", description: "A chat photo was edited", class: "PushMessageContent", fields: &[] },
+  Constructor { name: "pushMessageContentChatChangeTitle", description: "A chat title was edited", class: "PushMessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "New chat title" },
+  ] },
+  Constructor { name: "pushMessageContentChatDeleteMember", description: "A chat member was deleted", class: "PushMessageContent", fields: &[
+    Parameter { name: "member_name", type_: "string", description: "Name of the deleted member" },
+    Parameter { name: "is_current_user", type_: "Bool", description: "True, if the current user was deleted from the group" },
+    Parameter { name: "is_left", type_: "Bool", description: "True, if the user has left the group themselves" },
+  ] },
+  Constructor { name: "pushMessageContentChatJoinByLink", description: "A new member joined the chat by invite link", class: "PushMessageContent", fields: &[] },
+  Constructor { name: "pushMessageContentChatJoinByRequest", description: "A new member was accepted to the chat by an administrator", class: "PushMessageContent", fields: &[] },
+  Constructor { name: "pushMessageContentChatSetTheme", description: "A chat theme was edited", class: "PushMessageContent", fields: &[
+    Parameter { name: "theme_name", type_: "string", description: "If non-empty, name of a new theme, set for the chat. Otherwise chat theme was reset to the default one" },
+  ] },
+  Constructor { name: "pushMessageContentContact", description: "A message with a user contact", class: "PushMessageContent", fields: &[
+    Parameter { name: "name", type_: "string", description: "Contact's name" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentContactRegistered", description: "A contact has registered with Telegram", class: "PushMessageContent", fields: &[] },
+  Constructor { name: "pushMessageContentDocument", description: "A document message (a general file)", class: "PushMessageContent", fields: &[
+    Parameter { name: "document", type_: "document", description: "Message content; may be null" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentGame", description: "A message with a game", class: "PushMessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "Game title, empty for pinned game message" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentGameScore", description: "A new high score was achieved in a game", class: "PushMessageContent", fields: &[
+    Parameter { name: "title", type_: "string", description: "Game title, empty for pinned message" },
+    Parameter { name: "score", type_: "int32", description: "New score, 0 for pinned message" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentHidden", description: "A general message with hidden content", class: "PushMessageContent", fields: &[
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentInvoice", description: "A message with an invoice from a bot", class: "PushMessageContent", fields: &[
+    Parameter { name: "price", type_: "string", description: "Product price" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentLocation", description: "A message with a location", class: "PushMessageContent", fields: &[
+    Parameter { name: "is_live", type_: "Bool", description: "True, if the location is live" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentMediaAlbum", description: "A media album", class: "PushMessageContent", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Number of messages in the album" },
+    Parameter { name: "has_photos", type_: "Bool", description: "True, if the album has at least one photo" },
+    Parameter { name: "has_videos", type_: "Bool", description: "True, if the album has at least one video" },
+    Parameter { name: "has_audios", type_: "Bool", description: "True, if the album has at least one audio file" },
+    Parameter { name: "has_documents", type_: "Bool", description: "True, if the album has at least one document" },
+  ] },
+  Constructor { name: "pushMessageContentMessageForwards", description: "A forwarded messages", class: "PushMessageContent", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Number of forwarded messages" },
+  ] },
+  Constructor { name: "pushMessageContentPhoto", description: "A photo message", class: "PushMessageContent", fields: &[
+    Parameter { name: "photo", type_: "photo", description: "Message content; may be null" },
+    Parameter { name: "caption", type_: "string", description: "Photo caption" },
+    Parameter { name: "is_secret", type_: "Bool", description: "True, if the photo is secret" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentPoll", description: "A message with a poll", class: "PushMessageContent", fields: &[
+    Parameter { name: "question", type_: "string", description: "Poll question" },
+    Parameter { name: "is_regular", type_: "Bool", description: "True, if the poll is regular and not in quiz mode" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentScreenshotTaken", description: "A screenshot of a message in the chat has been taken", class: "PushMessageContent", fields: &[] },
+  Constructor { name: "pushMessageContentSticker", description: "A message with a sticker", class: "PushMessageContent", fields: &[
+    Parameter { name: "sticker", type_: "sticker", description: "Message content; may be null" },
+    Parameter { name: "emoji", type_: "string", description: "Emoji corresponding to the sticker; may be empty" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentText", description: "A text message", class: "PushMessageContent", fields: &[
+    Parameter { name: "text", type_: "string", description: "Message text" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentVideo", description: "A video message", class: "PushMessageContent", fields: &[
+    Parameter { name: "video", type_: "video", description: "Message content; may be null" },
+    Parameter { name: "caption", type_: "string", description: "Video caption" },
+    Parameter { name: "is_secret", type_: "Bool", description: "True, if the video is secret" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentVideoNote", description: "A video note message", class: "PushMessageContent", fields: &[
+    Parameter { name: "video_note", type_: "videoNote", description: "Message content; may be null" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushMessageContentVoiceNote", description: "A voice note message", class: "PushMessageContent", fields: &[
+    Parameter { name: "voice_note", type_: "voiceNote", description: "Message content; may be null" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is a pinned message with the specified content" },
+  ] },
+  Constructor { name: "pushReceiverId", description: "Contains a globally unique push receiver identifier, which can be used to identify which account has received a push notification", class: "PushReceiverId", fields: &[
+    Parameter { name: "id", type_: "int64", description: "The globally unique identifier of push notification subscription" },
+  ] },
+  Constructor { name: "recommendedChatFilter", description: "Describes a recommended chat filter", class: "RecommendedChatFilter", fields: &[
+    Parameter { name: "filter", type_: "chatFilter", description: "The chat filter" },
+    Parameter { name: "description", type_: "string", description: "Chat filter description" },
+  ] },
+  Constructor { name: "recommendedChatFilters", description: "Contains a list of recommended chat filters", class: "RecommendedChatFilters", fields: &[
+    Parameter { name: "chat_filters", type_: "vector<recommendedChatFilter>", description: "List of recommended chat filters" },
+  ] },
+  Constructor { name: "recoveryEmailAddress", description: "Contains information about the current recovery email address", class: "RecoveryEmailAddress", fields: &[
+    Parameter { name: "recovery_email_address", type_: "string", description: "Recovery email address" },
+  ] },
+  Constructor { name: "remoteFile", description: "Represents a remote file", class: "RemoteFile", fields: &[
+    Parameter { name: "id", type_: "string", description: "Remote file identifier; may be empty. Can be used by the current user across application restarts or even from other devices. Uniquely identifies a file, but a file can have a lot of different valid identifiers. If the ID starts with \"http://\" or \"https://\", it represents the HTTP URL of the file. TDLib is currently unable to download files if only their URL is known. If downloadFile is called on such a file or if it is sent to a secret chat, TDLib starts a file generation process by sending updateFileGenerationStart to the application with the HTTP URL in the original_path and \"#url#\" as the conversion string. Application must generate the file by downloading it to the specified location" },
+    Parameter { name: "unique_id", type_: "string", description: "Unique file identifier; may be empty if unknown. The unique file identifier which is the same for the same file even for different users and is persistent over time" },
+    Parameter { name: "is_uploading_active", type_: "Bool", description: "True, if the file is currently being uploaded (or a remote copy is being generated by some other means)" },
+    Parameter { name: "is_uploading_completed", type_: "Bool", description: "True, if a remote copy is fully available" },
+    Parameter { name: "uploaded_size", type_: "int32", description: "Size of the remote available part of the file, in bytes; 0 if unknown" },
+  ] },
+  Constructor { name: "replyMarkupForceReply", description: "Instructs application to force a reply to this message", class: "ReplyMarkup", fields: &[
+    Parameter { name: "is_personal", type_: "Bool", description: "True, if a forced reply must automatically be shown to the current user. For outgoing messages, specify true to show the forced reply only for the mentioned users and for the target user of a reply" },
+    Parameter { name: "input_field_placeholder", type_: "string", description: "If non-empty, the placeholder to be shown in the input field when the reply is active; 0-64 characters" },
+  ] },
+  Constructor { name: "replyMarkupInlineKeyboard", description: "Contains an inline keyboard layout", class: "ReplyMarkup", fields: &[
+    Parameter { name: "rows", type_: "vector<vector<inlineKeyboardButton>>", description: "A list of rows of inline keyboard buttons" },
+  ] },
+  Constructor { name: "replyMarkupRemoveKeyboard", description: "Instructs application to remove the keyboard once this message has been received. This kind of keyboard can't be received in an incoming message; instead, UpdateChatReplyMarkup with message_id == 0 will be sent", class: "ReplyMarkup", fields: &[
+    Parameter { name: "is_personal", type_: "Bool", description: "True, if the keyboard is removed only for the mentioned users or the target user of a reply" },
+  ] },
+  Constructor { name: "replyMarkupShowKeyboard", description: "Contains a custom keyboard layout to quickly reply to bots", class: "ReplyMarkup", fields: &[
+    Parameter { name: "rows", type_: "vector<vector<keyboardButton>>", description: "A list of rows of bot keyboard buttons" },
+    Parameter { name: "resize_keyboard", type_: "Bool", description: "True, if the application needs to resize the keyboard vertically" },
+    Parameter { name: "one_time", type_: "Bool", description: "True, if the application needs to hide the keyboard after use" },
+    Parameter { name: "is_personal", type_: "Bool", description: "True, if the keyboard must automatically be shown to the current user. For outgoing messages, specify true to show the keyboard only for the mentioned users and for the target user of a reply" },
+    Parameter { name: "input_field_placeholder", type_: "string", description: "If non-empty, the placeholder to be shown in the input field when the keyboard is active; 0-64 characters" },
+  ] },
+  Constructor { name: "resetPasswordResultDeclined", description: "The password reset request was declined", class: "ResetPasswordResult", fields: &[
+    Parameter { name: "retry_date", type_: "int32", description: "Point in time (Unix timestamp) when the password reset can be retried" },
+  ] },
+  Constructor { name: "resetPasswordResultOk", description: "The password was reset", class: "ResetPasswordResult", fields: &[] },
+  Constructor { name: "resetPasswordResultPending", description: "The password reset request is pending", class: "ResetPasswordResult", fields: &[
+    Parameter { name: "pending_reset_date", type_: "int32", description: "Point in time (Unix timestamp) after which the password can be reset immediately using resetPassword" },
+  ] },
+  Constructor { name: "richTextAnchor", description: "An anchor", class: "RichText", fields: &[
+    Parameter { name: "name", type_: "string", description: "Anchor name" },
+  ] },
+  Constructor { name: "richTextAnchorLink", description: "A link to an anchor on the same web page", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "The link text" },
+    Parameter { name: "anchor_name", type_: "string", description: "The anchor name. If the name is empty, the link must bring back to top" },
+    Parameter { name: "url", type_: "string", description: "An HTTP URL, opening the anchor" },
+  ] },
+  Constructor { name: "richTextBold", description: "A bold rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextEmailAddress", description: "A rich text email link", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+    Parameter { name: "email_address", type_: "string", description: "Email address" },
+  ] },
+  Constructor { name: "richTextFixed", description: "A fixed-width rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextIcon", description: "A small image inside the text", class: "RichText", fields: &[
+    Parameter { name: "document", type_: "document", description: "The image represented as a document. The image can be in GIF, JPEG or PNG format" },
+    Parameter { name: "width", type_: "int32", description: "Width of a bounding box in which the image must be shown; 0 if unknown" },
+    Parameter { name: "height", type_: "int32", description: "Height of a bounding box in which the image must be shown; 0 if unknown" },
+  ] },
+  Constructor { name: "richTextItalic", description: "An italicized rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextMarked", description: "A marked rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextPhoneNumber", description: "A rich text phone number", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+    Parameter { name: "phone_number", type_: "string", description: "Phone number" },
+  ] },
+  Constructor { name: "richTextPlain", description: "A plain text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextReference", description: "A reference to a richTexts object on the same web page", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "The text" },
+    Parameter { name: "anchor_name", type_: "string", description: "The name of a richTextAnchor object, which is the first element of the target richTexts object" },
+    Parameter { name: "url", type_: "string", description: "An HTTP URL, opening the reference" },
+  ] },
+  Constructor { name: "richTextStrikethrough", description: "A strikethrough rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextSubscript", description: "A subscript rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextSuperscript", description: "A superscript rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextUnderline", description: "An underlined rich text", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+  ] },
+  Constructor { name: "richTextUrl", description: "A rich text URL link", class: "RichText", fields: &[
+    Parameter { name: "text", type_: "RichText", description: "Text" },
+    Parameter { name: "url", type_: "string", description: "URL" },
+    Parameter { name: "is_cached", type_: "Bool", description: "True, if the URL has cached instant view server-side" },
+  ] },
+  Constructor { name: "richTexts", description: "A concatenation of rich texts", class: "RichText", fields: &[
+    Parameter { name: "texts", type_: "vector<RichText>", description: "Texts" },
+  ] },
+  Constructor { name: "savedCredentials", description: "Contains information about saved card credentials", class: "SavedCredentials", fields: &[
+    Parameter { name: "id", type_: "string", description: "Unique identifier of the saved credentials" },
+    Parameter { name: "title", type_: "string", description: "Title of the saved credentials" },
+  ] },
+  Constructor { name: "scopeNotificationSettings", description: "Contains information about notification settings for several chats", class: "ScopeNotificationSettings", fields: &[
+    Parameter { name: "mute_for", type_: "int32", description: "Time left before notifications will be unmuted, in seconds" },
+    Parameter { name: "sound", type_: "string", description: "The name of an audio file to be used for notification sounds; only applies to iOS applications" },
+    Parameter { name: "show_preview", type_: "Bool", description: "True, if message content must be displayed in notifications" },
+    Parameter { name: "disable_pinned_message_notifications", type_: "Bool", description: "True, if notifications for incoming pinned messages will be created as for an ordinary unread message" },
+    Parameter { name: "disable_mention_notifications", type_: "Bool", description: "True, if notifications for messages with mentions will be created as for an ordinary unread message" },
+  ] },
+  Constructor { name: "searchMessagesFilterAnimation", description: "Returns only animation messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterAudio", description: "Returns only audio messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterCall", description: "Returns only call messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterChatPhoto", description: "Returns only messages containing chat photos", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterDocument", description: "Returns only document messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterEmpty", description: "Returns all found messages, no filter is applied", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterFailedToSend", description: "Returns only failed to send messages. This filter can be used only if the message database is used", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterMention", description: "Returns only messages with mentions of the current user, or messages that are replies to their messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterMissedCall", description: "Returns only incoming call messages with missed/declined discard reasons", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterPhoto", description: "Returns only photo messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterPhotoAndVideo", description: "Returns only photo and video messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterPinned", description: "Returns only pinned messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterUnreadMention", description: "Returns only messages with unread mentions of the current user, or messages that are replies to their messages. When using this filter the results can't be additionally filtered by a query, a message thread or by the sending user", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterUrl", description: "Returns only messages containing URLs", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterVideo", description: "Returns only video messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterVideoNote", description: "Returns only video note messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterVoiceAndVideoNote", description: "Returns only voice and video note messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "searchMessagesFilterVoiceNote", description: "Returns only voice note messages", class: "SearchMessagesFilter", fields: &[] },
+  Constructor { name: "seconds", description: "Contains a value representing a number of seconds", class: "Seconds", fields: &[
+    Parameter { name: "seconds", type_: "double", description: "Number of seconds" },
+  ] },
+  Constructor { name: "secretChat", description: "Represents a secret chat", class: "SecretChat", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Secret chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the chat partner" },
+    Parameter { name: "state", type_: "SecretChatState", description: "State of the secret chat" },
+    Parameter { name: "is_outbound", type_: "Bool", description: "True, if the chat was created by the current user; otherwise false" },
+    Parameter { name: "key_hash", type_: "bytes", description: "Hash of the currently used key for comparison with the hash of the chat partner's key. This is a string of 36 little-endian bytes, which must be split into groups of 2 bits, each denoting a pixel of one of 4 colors FFFFFF, D5E6F3, 2D5775, and 2F99C9. The pixels must be used to make a 12x12 square image filled from left to right, top to bottom. Alternatively, the first 32 bytes of the hash can be converted to the hexadecimal format and printed as 32 2-digit hex numbers" },
+    Parameter { name: "layer", type_: "int32", description: "Secret chat layer; determines features supported by the chat partner's application. Nested text entities and underline and strikethrough entities are supported if the layer >= 101" },
+  ] },
+  Constructor { name: "secretChatStateClosed", description: "The secret chat is closed", class: "SecretChatState", fields: &[] },
+  Constructor { name: "secretChatStatePending", description: "The secret chat is not yet created; waiting for the other user to get online", class: "SecretChatState", fields: &[] },
+  Constructor { name: "secretChatStateReady", description: "The secret chat is ready to use", class: "SecretChatState", fields: &[] },
+  Constructor { name: "session", description: "Contains information about one session in a Telegram application used by the current user. Sessions must be shown to the user in the returned order", class: "Session", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Session identifier" },
+    Parameter { name: "is_current", type_: "Bool", description: "True, if this session is the current session" },
+    Parameter { name: "is_password_pending", type_: "Bool", description: "True, if a password is needed to complete authorization of the session" },
+    Parameter { name: "api_id", type_: "int32", description: "Telegram API identifier, as provided by the application" },
+    Parameter { name: "application_name", type_: "string", description: "Name of the application, as provided by the application" },
+    Parameter { name: "application_version", type_: "string", description: "The version of the application, as provided by the application" },
+    Parameter { name: "is_official_application", type_: "Bool", description: "True, if the application is an official application or uses the api_id of an official application" },
+    Parameter { name: "device_model", type_: "string", description: "Model of the device the application has been run or is running on, as provided by the application" },
+    Parameter { name: "platform", type_: "string", description: "Operating system the application has been run or is running on, as provided by the application" },
+    Parameter { name: "system_version", type_: "string", description: "Version of the operating system the application has been run or is running on, as provided by the application" },
+    Parameter { name: "log_in_date", type_: "int32", description: "Point in time (Unix timestamp) when the user has logged in" },
+    Parameter { name: "last_active_date", type_: "int32", description: "Point in time (Unix timestamp) when the session was last used" },
+    Parameter { name: "ip", type_: "string", description: "IP address from which the session was created, in human-readable format" },
+    Parameter { name: "country", type_: "string", description: "A two-letter country code for the country from which the session was created, based on the IP address" },
+    Parameter { name: "region", type_: "string", description: "Region code from which the session was created, based on the IP address" },
+  ] },
+  Constructor { name: "sessions", description: "Contains a list of sessions", class: "Sessions", fields: &[
+    Parameter { name: "sessions", type_: "vector<session>", description: "List of sessions" },
+  ] },
+  Constructor { name: "shippingOption", description: "One shipping option", class: "ShippingOption", fields: &[
+    Parameter { name: "id", type_: "string", description: "Shipping option identifier" },
+    Parameter { name: "title", type_: "string", description: "Option title" },
+    Parameter { name: "price_parts", type_: "vector<labeledPricePart>", description: "A list of objects used to calculate the total shipping costs" },
+  ] },
+  Constructor { name: "sponsoredMessage", description: "Describes a sponsored message", class: "SponsoredMessage", fields: &[
+    Parameter { name: "id", type_: "int32", description: "Unique sponsored message identifier" },
+    Parameter { name: "sponsor_chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "link", type_: "InternalLinkType", description: "An internal link to be opened when the sponsored message is clicked; may be null. If null, the sponsor chat needs to be opened instead" },
+    Parameter { name: "content", type_: "MessageContent", description: "Content of the message" },
+  ] },
+  Constructor { name: "sponsoredMessages", description: "Contains a list of sponsored messages", class: "SponsoredMessages", fields: &[
+    Parameter { name: "messages", type_: "vector<sponsoredMessage>", description: "List of sponsored messages" },
+  ] },
+  Constructor { name: "statisticalGraphAsync", description: "The graph data to be asynchronously loaded through getStatisticalGraph", class: "StatisticalGraph", fields: &[
+    Parameter { name: "token", type_: "string", description: "The token to use for data loading" },
+  ] },
+  Constructor { name: "statisticalGraphData", description: "A graph data", class: "StatisticalGraph", fields: &[
+    Parameter { name: "json_data", type_: "string", description: "Graph data in JSON format" },
+    Parameter { name: "zoom_token", type_: "string", description: "If non-empty, a token which can be used to receive a zoomed in graph" },
+  ] },
+  Constructor { name: "statisticalGraphError", description: "An error message to be shown to the user instead of the graph", class: "StatisticalGraph", fields: &[
+    Parameter { name: "error_message", type_: "string", description: "The error message" },
+  ] },
+  Constructor { name: "statisticalValue", description: "A value with information about its recent changes", class: "StatisticalValue", fields: &[
+    Parameter { name: "value", type_: "double", description: "The current value" },
+    Parameter { name: "previous_value", type_: "double", description: "The value for the previous day" },
+    Parameter { name: "growth_rate_percentage", type_: "double", description: "The growth rate of the value, as a percentage" },
+  ] },
+  Constructor { name: "sticker", description: "Describes a sticker", class: "Sticker", fields: &[
+    Parameter { name: "set_id", type_: "int64", description: "The identifier of the sticker set to which the sticker belongs; 0 if none" },
+    Parameter { name: "width", type_: "int32", description: "Sticker width; as defined by the sender" },
+    Parameter { name: "height", type_: "int32", description: "Sticker height; as defined by the sender" },
+    Parameter { name: "emoji", type_: "string", description: "Emoji corresponding to the sticker" },
+    Parameter { name: "is_animated", type_: "Bool", description: "True, if the sticker is an animated sticker in TGS format" },
+    Parameter { name: "is_mask", type_: "Bool", description: "True, if the sticker is a mask" },
+    Parameter { name: "mask_position", type_: "maskPosition", description: "Position where the mask is placed; may be null" },
+    Parameter { name: "outline", type_: "vector<closedVectorPath>", description: "Sticker's outline represented as a list of closed vector paths; may be empty. The coordinate system origin is in the upper-left corner" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Sticker thumbnail in WEBP or JPEG format; may be null" },
+    Parameter { name: "sticker", type_: "file", description: "File containing the sticker" },
+  ] },
+  Constructor { name: "stickerSet", description: "Represents a sticker set", class: "StickerSet", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Identifier of the sticker set" },
+    Parameter { name: "title", type_: "string", description: "Title of the sticker set" },
+    Parameter { name: "name", type_: "string", description: "Name of the sticker set" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Sticker set thumbnail in WEBP or TGS format with width and height 100; may be null. The file can be downloaded only before the thumbnail is changed" },
+    Parameter { name: "thumbnail_outline", type_: "vector<closedVectorPath>", description: "Sticker set thumbnail's outline represented as a list of closed vector paths; may be empty. The coordinate system origin is in the upper-left corner" },
+    Parameter { name: "is_installed", type_: "Bool", description: "True, if the sticker set has been installed by the current user" },
+    Parameter { name: "is_archived", type_: "Bool", description: "True, if the sticker set has been archived. A sticker set can't be installed and archived simultaneously" },
+    Parameter { name: "is_official", type_: "Bool", description: "True, if the sticker set is official" },
+    Parameter { name: "is_animated", type_: "Bool", description: "True, is the stickers in the set are animated" },
+    Parameter { name: "is_masks", type_: "Bool", description: "True, if the stickers in the set are masks" },
+    Parameter { name: "is_viewed", type_: "Bool", description: "True for already viewed trending sticker sets" },
+    Parameter { name: "stickers", type_: "vector<sticker>", description: "List of stickers in this set" },
+    Parameter { name: "emojis", type_: "vector<emojis>", description: "A list of emoji corresponding to the stickers in the same order. The list is only for informational purposes, because a sticker is always sent with a fixed emoji from the corresponding Sticker object" },
+  ] },
+  Constructor { name: "stickerSetInfo", description: "Represents short information about a sticker set", class: "StickerSetInfo", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Identifier of the sticker set" },
+    Parameter { name: "title", type_: "string", description: "Title of the sticker set" },
+    Parameter { name: "name", type_: "string", description: "Name of the sticker set" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Sticker set thumbnail in WEBP or TGS format with width and height 100; may be null" },
+    Parameter { name: "thumbnail_outline", type_: "vector<closedVectorPath>", description: "Sticker set thumbnail's outline represented as a list of closed vector paths; may be empty. The coordinate system origin is in the upper-left corner" },
+    Parameter { name: "is_installed", type_: "Bool", description: "True, if the sticker set has been installed by the current user" },
+    Parameter { name: "is_archived", type_: "Bool", description: "True, if the sticker set has been archived. A sticker set can't be installed and archived simultaneously" },
+    Parameter { name: "is_official", type_: "Bool", description: "True, if the sticker set is official" },
+    Parameter { name: "is_animated", type_: "Bool", description: "True, is the stickers in the set are animated" },
+    Parameter { name: "is_masks", type_: "Bool", description: "True, if the stickers in the set are masks" },
+    Parameter { name: "is_viewed", type_: "Bool", description: "True for already viewed trending sticker sets" },
+    Parameter { name: "size", type_: "int32", description: "Total number of stickers in the set" },
+    Parameter { name: "covers", type_: "vector<sticker>", description: "Up to the first 5 stickers from the set, depending on the context. If the application needs more stickers the full sticker set needs to be requested" },
+  ] },
+  Constructor { name: "stickerSets", description: "Represents a list of sticker sets", class: "StickerSets", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total number of sticker sets found" },
+    Parameter { name: "sets", type_: "vector<stickerSetInfo>", description: "List of sticker sets" },
+  ] },
+  Constructor { name: "stickers", description: "Represents a list of stickers", class: "Stickers", fields: &[
+    Parameter { name: "stickers", type_: "vector<sticker>", description: "List of stickers" },
+  ] },
+  Constructor { name: "storageStatistics", description: "Contains the exact storage usage statistics split by chats and file type", class: "StorageStatistics", fields: &[
+    Parameter { name: "size", type_: "int32", description: "Total size of files, in bytes" },
+    Parameter { name: "count", type_: "int32", description: "Total number of files" },
+    Parameter { name: "by_chat", type_: "vector<storageStatisticsByChat>", description: "Statistics split by chats" },
+  ] },
+  Constructor { name: "storageStatisticsByChat", description: "Contains the storage usage statistics for a specific chat", class: "StorageStatisticsByChat", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier; 0 if none" },
+    Parameter { name: "size", type_: "int32", description: "Total size of the files in the chat, in bytes" },
+    Parameter { name: "count", type_: "int32", description: "Total number of files in the chat" },
+    Parameter { name: "by_file_type", type_: "vector<storageStatisticsByFileType>", description: "Statistics split by file types" },
+  ] },
+  Constructor { name: "storageStatisticsByFileType", description: "Contains the storage usage statistics for a specific file type", class: "StorageStatisticsByFileType", fields: &[
+    Parameter { name: "file_type", type_: "FileType", description: "File type" },
+    Parameter { name: "size", type_: "int32", description: "Total size of the files, in bytes" },
+    Parameter { name: "count", type_: "int32", description: "Total number of files" },
+  ] },
+  Constructor { name: "storageStatisticsFast", description: "Contains approximate storage usage statistics, excluding files of unknown file type", class: "StorageStatisticsFast", fields: &[
+    Parameter { name: "files_size", type_: "int32", description: "Approximate total size of files, in bytes" },
+    Parameter { name: "file_count", type_: "int32", description: "Approximate number of files" },
+    Parameter { name: "database_size", type_: "int32", description: "Size of the database" },
+    Parameter { name: "language_pack_database_size", type_: "int32", description: "Size of the language pack database" },
+    Parameter { name: "log_size", type_: "int32", description: "Size of the TDLib internal log" },
+  ] },
+  Constructor { name: "suggestedActionCheckPassword", description: "Suggests the user to check whether 2-step verification password is still remembered", class: "SuggestedAction", fields: &[] },
+  Constructor { name: "suggestedActionCheckPhoneNumber", description: "Suggests the user to check whether authorization phone number is correct and change the phone number if it is inaccessible", class: "SuggestedAction", fields: &[] },
+  Constructor { name: "suggestedActionConvertToBroadcastGroup", description: "Suggests the user to convert specified supergroup to a broadcast group", class: "SuggestedAction", fields: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Supergroup identifier" },
+  ] },
+  Constructor { name: "suggestedActionEnableArchiveAndMuteNewChats", description: "Suggests the user to enable \"archive_and_mute_new_chats_from_unknown_users\" option", class: "SuggestedAction", fields: &[] },
+  Constructor { name: "suggestedActionSeeTicksHint", description: "Suggests the user to see a hint about meaning of one and two ticks on sent message", class: "SuggestedAction", fields: &[] },
+  Constructor { name: "supergroup", description: "Represents a supergroup or channel with zero or more members (subscribers in the case of channels). From the point of view of the system, a channel is a special kind of a supergroup: only administrators can post and see the list of members, and posts from all administrators use the name and photo of the channel instead of individual names and profile photos. Unlike supergroups, channels can have an unlimited number of subscribers", class: "Supergroup", fields: &[
+    Parameter { name: "id", type_: "int53", description: "Supergroup or channel identifier" },
+    Parameter { name: "username", type_: "string", description: "Username of the supergroup or channel; empty for private supergroups or channels" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the current user joined, or the point in time when the supergroup or channel was created, in case the user is not a member" },
+    Parameter { name: "status", type_: "ChatMemberStatus", description: "Status of the current user in the supergroup or channel; custom title will be always empty" },
+    Parameter { name: "member_count", type_: "int32", description: "Number of members in the supergroup or channel; 0 if unknown. Currently it is guaranteed to be known only if the supergroup or channel was received through searchPublicChats, searchChatsNearby, getInactiveSupergroupChats, getSuitableDiscussionChats, getGroupsInCommon, or getUserPrivacySettingRules" },
+    Parameter { name: "has_linked_chat", type_: "Bool", description: "True, if the channel has a discussion group, or the supergroup is the designated discussion group for a channel" },
+    Parameter { name: "has_location", type_: "Bool", description: "True, if the supergroup is connected to a location, i.e. the supergroup is a location-based supergroup" },
+    Parameter { name: "sign_messages", type_: "Bool", description: "True, if messages sent to the channel need to contain information about the sender. This field is only applicable to channels" },
+    Parameter { name: "is_slow_mode_enabled", type_: "Bool", description: "True, if the slow mode is enabled in the supergroup" },
+    Parameter { name: "is_channel", type_: "Bool", description: "True, if the supergroup is a channel" },
+    Parameter { name: "is_broadcast_group", type_: "Bool", description: "True, if the supergroup is a broadcast group, i.e. only administrators can send messages and there is no limit on number of members" },
+    Parameter { name: "is_verified", type_: "Bool", description: "True, if the supergroup or channel is verified" },
+    Parameter { name: "restriction_reason", type_: "string", description: "If non-empty, contains a human-readable description of the reason why access to this supergroup or channel must be restricted" },
+    Parameter { name: "is_scam", type_: "Bool", description: "True, if many users reported this supergroup or channel as a scam" },
+    Parameter { name: "is_fake", type_: "Bool", description: "True, if many users reported this supergroup or channel as a fake account" },
+  ] },
+  Constructor { name: "supergroupFullInfo", description: "Contains full information about a supergroup or channel", class: "SupergroupFullInfo", fields: &[
+    Parameter { name: "photo", type_: "chatPhoto", description: "Chat photo; may be null" },
+    Parameter { name: "description", type_: "string", description: "Supergroup or channel description" },
+    Parameter { name: "member_count", type_: "int32", description: "Number of members in the supergroup or channel; 0 if unknown" },
+    Parameter { name: "administrator_count", type_: "int32", description: "Number of privileged users in the supergroup or channel; 0 if unknown" },
+    Parameter { name: "restricted_count", type_: "int32", description: "Number of restricted users in the supergroup; 0 if unknown" },
+    Parameter { name: "banned_count", type_: "int32", description: "Number of users banned from chat; 0 if unknown" },
+    Parameter { name: "linked_chat_id", type_: "int53", description: "Chat identifier of a discussion group for the channel, or a channel, for which the supergroup is the designated discussion group; 0 if none or unknown" },
+    Parameter { name: "slow_mode_delay", type_: "int32", description: "Delay between consecutive sent messages for non-administrator supergroup members, in seconds" },
+    Parameter { name: "slow_mode_delay_expires_in", type_: "double", description: "Time left before next message can be sent in the supergroup, in seconds. An updateSupergroupFullInfo update is not triggered when value of this field changes, but both new and old values are non-zero" },
+    Parameter { name: "can_get_members", type_: "Bool", description: "True, if members of the chat can be retrieved" },
+    Parameter { name: "can_set_username", type_: "Bool", description: "True, if the chat username can be changed" },
+    Parameter { name: "can_set_sticker_set", type_: "Bool", description: "True, if the supergroup sticker set can be changed" },
+    Parameter { name: "can_set_location", type_: "Bool", description: "True, if the supergroup location can be changed" },
+    Parameter { name: "can_get_statistics", type_: "Bool", description: "True, if the supergroup or channel statistics are available" },
+    Parameter { name: "is_all_history_available", type_: "Bool", description: "True, if new chat members will have access to old messages. In public or discussion groups and both public and private channels, old messages are always available, so this option affects only private supergroups without a linked chat. The value of this field is only available for chat administrators" },
+    Parameter { name: "sticker_set_id", type_: "int64", description: "Identifier of the supergroup sticker set; 0 if none" },
+    Parameter { name: "location", type_: "chatLocation", description: "Location to which the supergroup is connected; may be null" },
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "Primary invite link for this chat; may be null. For chat administrators with can_invite_users right only" },
+    Parameter { name: "bot_commands", type_: "vector<botCommands>", description: "List of commands of bots in the group" },
+    Parameter { name: "upgraded_from_basic_group_id", type_: "int53", description: "Identifier of the basic group from which supergroup was upgraded; 0 if none" },
+    Parameter { name: "upgraded_from_max_message_id", type_: "int53", description: "Identifier of the last message in the basic group from which supergroup was upgraded; 0 if none" },
+  ] },
+  Constructor { name: "supergroupMembersFilterAdministrators", description: "Returns the owner and administrators", class: "SupergroupMembersFilter", fields: &[] },
+  Constructor { name: "supergroupMembersFilterBanned", description: "Returns users banned from the supergroup or channel; can be used only by administrators", class: "SupergroupMembersFilter", fields: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+  ] },
+  Constructor { name: "supergroupMembersFilterBots", description: "Returns bot members of the supergroup or channel", class: "SupergroupMembersFilter", fields: &[] },
+  Constructor { name: "supergroupMembersFilterContacts", description: "Returns contacts of the user, which are members of the supergroup or channel", class: "SupergroupMembersFilter", fields: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+  ] },
+  Constructor { name: "supergroupMembersFilterMention", description: "Returns users which can be mentioned in the supergroup", class: "SupergroupMembersFilter", fields: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If non-zero, the identifier of the current message thread" },
+  ] },
+  Constructor { name: "supergroupMembersFilterRecent", description: "Returns recently active users in reverse chronological order", class: "SupergroupMembersFilter", fields: &[] },
+  Constructor { name: "supergroupMembersFilterRestricted", description: "Returns restricted supergroup members; can be used only by administrators", class: "SupergroupMembersFilter", fields: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+  ] },
+  Constructor { name: "supergroupMembersFilterSearch", description: "Used to search for supergroup or channel members via a (string) query", class: "SupergroupMembersFilter", fields: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+  ] },
+  Constructor { name: "tMeUrl", description: "Represents a URL linking to an internal Telegram entity", class: "TMeUrl", fields: &[
+    Parameter { name: "url", type_: "string", description: "URL" },
+    Parameter { name: "type", type_: "TMeUrlType", description: "Type of the URL" },
+  ] },
+  Constructor { name: "tMeUrlTypeChatInvite", description: "A chat invite link", class: "TMeUrlType", fields: &[
+    Parameter { name: "info", type_: "chatInviteLinkInfo", description: "Chat invite link info" },
+  ] },
+  Constructor { name: "tMeUrlTypeStickerSet", description: "A URL linking to a sticker set", class: "TMeUrlType", fields: &[
+    Parameter { name: "sticker_set_id", type_: "int64", description: "Identifier of the sticker set" },
+  ] },
+  Constructor { name: "tMeUrlTypeSupergroup", description: "A URL linking to a public supergroup or channel", class: "TMeUrlType", fields: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the supergroup or channel" },
+  ] },
+  Constructor { name: "tMeUrlTypeUser", description: "A URL linking to a user", class: "TMeUrlType", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user" },
+  ] },
+  Constructor { name: "tMeUrls", description: "Contains a list of t.me URLs", class: "TMeUrls", fields: &[
+    Parameter { name: "urls", type_: "vector<tMeUrl>", description: "List of URLs" },
+  ] },
+  Constructor { name: "tdlibParameters", description: "Contains parameters for TDLib initialization", class: "TdlibParameters", fields: &[
+    Parameter { name: "use_test_dc", type_: "Bool", description: "If set to true, the Telegram test environment will be used instead of the production environment" },
+    Parameter { name: "database_directory", type_: "string", description: "The path to the directory for the persistent database; if empty, the current working directory will be used" },
+    Parameter { name: "files_directory", type_: "string", description: "The path to the directory for storing files; if empty, database_directory will be used" },
+    Parameter { name: "use_file_database", type_: "Bool", description: "If set to true, information about downloaded and uploaded files will be saved between application restarts" },
+    Parameter { name: "use_chat_info_database", type_: "Bool", description: "If set to true, the library will maintain a cache of users, basic groups, supergroups, channels and secret chats. Implies use_file_database" },
+    Parameter { name: "use_message_database", type_: "Bool", description: "If set to true, the library will maintain a cache of chats and messages. Implies use_chat_info_database" },
+    Parameter { name: "use_secret_chats", type_: "Bool", description: "If set to true, support for secret chats will be enabled" },
+    Parameter { name: "api_id", type_: "int32", description: "Application identifier for Telegram API access, which can be obtained at https://my.telegram.org" },
+    Parameter { name: "api_hash", type_: "string", description: "Application identifier hash for Telegram API access, which can be obtained at https://my.telegram.org" },
+    Parameter { name: "system_language_code", type_: "string", description: "IETF language tag of the user's operating system language; must be non-empty" },
+    Parameter { name: "device_model", type_: "string", description: "Model of the device the application is being run on; must be non-empty" },
+    Parameter { name: "system_version", type_: "string", description: "Version of the operating system the application is being run on. If empty, the version is automatically detected by TDLib" },
+    Parameter { name: "application_version", type_: "string", description: "Application version; must be non-empty" },
+    Parameter { name: "enable_storage_optimizer", type_: "Bool", description: "If set to true, old files will automatically be deleted" },
+    Parameter { name: "ignore_file_names", type_: "Bool", description: "If set to true, original file names will be ignored. Otherwise, downloaded files will be saved under names as close as possible to the original name" },
+  ] },
+  Constructor { name: "temporaryPasswordState", description: "Returns information about the availability of a temporary password, which can be used for payments", class: "TemporaryPasswordState", fields: &[
+    Parameter { name: "has_password", type_: "Bool", description: "True, if a temporary password is available" },
+    Parameter { name: "valid_for", type_: "int32", description: "Time left before the temporary password expires, in seconds" },
+  ] },
+  Constructor { name: "termsOfService", description: "Contains Telegram terms of service", class: "TermsOfService", fields: &[
+    Parameter { name: "text", type_: "formattedText", description: "Text of the terms of service" },
+    Parameter { name: "min_user_age", type_: "int32", description: "The minimum age of a user to be able to accept the terms; 0 if any" },
+    Parameter { name: "show_popup", type_: "Bool", description: "True, if a blocking popup with terms of service must be shown to the user" },
+  ] },
+  Constructor { name: "testBytes", description: "A simple object containing a sequence of bytes; for testing only", class: "TestBytes", fields: &[
+    Parameter { name: "value", type_: "bytes", description: "Bytes" },
+  ] },
+  Constructor { name: "testInt", description: "A simple object containing a number; for testing only", class: "TestInt", fields: &[
+    Parameter { name: "value", type_: "int32", description: "Number" },
+  ] },
+  Constructor { name: "testString", description: "A simple object containing a string; for testing only", class: "TestString", fields: &[
+    Parameter { name: "value", type_: "string", description: "String" },
+  ] },
+  Constructor { name: "testVectorInt", description: "A simple object containing a vector of numbers; for testing only", class: "TestVectorInt", fields: &[
+    Parameter { name: "value", type_: "vector<int32>", description: "Vector of numbers" },
+  ] },
+  Constructor { name: "testVectorIntObject", description: "A simple object containing a vector of objects that hold a number; for testing only", class: "TestVectorIntObject", fields: &[
+    Parameter { name: "value", type_: "vector<testInt>", description: "Vector of objects" },
+  ] },
+  Constructor { name: "testVectorString", description: "A simple object containing a vector of strings; for testing only", class: "TestVectorString", fields: &[
+    Parameter { name: "value", type_: "vector<string>", description: "Vector of strings" },
+  ] },
+  Constructor { name: "testVectorStringObject", description: "A simple object containing a vector of objects that hold a string; for testing only", class: "TestVectorStringObject", fields: &[
+    Parameter { name: "value", type_: "vector<testString>", description: "Vector of objects" },
+  ] },
+  Constructor { name: "text", description: "Contains some text", class: "Text", fields: &[
+    Parameter { name: "text", type_: "string", description: "Text" },
+  ] },
+  Constructor { name: "textEntities", description: "Contains a list of text entities", class: "TextEntities", fields: &[
+    Parameter { name: "entities", type_: "vector<textEntity>", description: "List of text entities" },
+  ] },
+  Constructor { name: "textEntity", description: "Represents a part of the text that needs to be formatted in some unusual way", class: "TextEntity", fields: &[
+    Parameter { name: "offset", type_: "int32", description: "Offset of the entity, in UTF-16 code units" },
+    Parameter { name: "length", type_: "int32", description: "Length of the entity, in UTF-16 code units" },
+    Parameter { name: "type", type_: "TextEntityType", description: "Type of the entity" },
+  ] },
+  Constructor { name: "textEntityTypeBankCardNumber", description: "A bank card number. The getBankCardInfo method can be used to get information about the bank card", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeBold", description: "A bold text", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeBotCommand", description: "A bot command, beginning with \"/\"", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeCashtag", description: "A cashtag text, beginning with \"$\" and consisting of capital English letters (e.g., \"$USD\")", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeCode", description: "Text that must be formatted as if inside a code HTML tag", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeEmailAddress", description: "An email address", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeHashtag", description: "A hashtag text, beginning with \"#\"", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeItalic", description: "An italic text", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeMediaTimestamp", description: "A media timestamp", class: "TextEntityType", fields: &[
+    Parameter { name: "media_timestamp", type_: "int32", description: "Timestamp from which a video/audio/video note/voice note playing must start, in seconds. The media can be in the content or the web page preview of the current message, or in the same places in the replied message" },
+  ] },
+  Constructor { name: "textEntityTypeMention", description: "A mention of a user by their username", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeMentionName", description: "A text shows instead of a raw mention of the user (e.g., when the user has no username)", class: "TextEntityType", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the mentioned user" },
+  ] },
+  Constructor { name: "textEntityTypePhoneNumber", description: "A phone number", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypePre", description: "Text that must be formatted as if inside a pre HTML tag", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypePreCode", description: "Text that must be formatted as if inside pre, and code HTML tags", class: "TextEntityType", fields: &[
+    Parameter { name: "language", type_: "string", description: "Programming language of the code; as defined by the sender" },
+  ] },
+  Constructor { name: "textEntityTypeStrikethrough", description: "A strikethrough text", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeTextUrl", description: "A text description shown instead of a raw URL", class: "TextEntityType", fields: &[
+    Parameter { name: "url", type_: "string", description: "HTTP or tg:// URL to be opened when the link is clicked" },
+  ] },
+  Constructor { name: "textEntityTypeUnderline", description: "An underlined text", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textEntityTypeUrl", description: "An HTTP URL", class: "TextEntityType", fields: &[] },
+  Constructor { name: "textParseModeHTML", description: "The text uses HTML-style formatting. The same as Telegram Bot API \"HTML\" parse mode", class: "TextParseMode", fields: &[] },
+  Constructor { name: "textParseModeMarkdown", description: "The text uses Markdown-style formatting", class: "TextParseMode", fields: &[
+    Parameter { name: "version", type_: "int32", description: "Version of the parser: 0 or 1  Telegram Bot API \"Markdown\" parse mode, 2  Telegram Bot API \"MarkdownV2\" parse mode" },
+  ] },
+  Constructor { name: "themeSettings", description: "Describes theme settings", class: "ThemeSettings", fields: &[
+    Parameter { name: "accent_color", type_: "int32", description: "Theme accent color in ARGB format" },
+    Parameter { name: "background", type_: "background", description: "The background to be used in chats; may be null" },
+    Parameter { name: "outgoing_message_fill", type_: "BackgroundFill", description: "The fill to be used as a background for outgoing messages" },
+    Parameter { name: "animate_outgoing_message_fill", type_: "Bool", description: "If true, the freeform gradient fill needs to be animated on every sent message" },
+    Parameter { name: "outgoing_message_accent_color", type_: "int32", description: "Accent color of outgoing messages in ARGB format" },
+  ] },
+  Constructor { name: "thumbnail", description: "Represents a thumbnail", class: "Thumbnail", fields: &[
+    Parameter { name: "format", type_: "ThumbnailFormat", description: "Thumbnail format" },
+    Parameter { name: "width", type_: "int32", description: "Thumbnail width" },
+    Parameter { name: "height", type_: "int32", description: "Thumbnail height" },
+    Parameter { name: "file", type_: "file", description: "The thumbnail" },
+  ] },
+  Constructor { name: "thumbnailFormatGif", description: "The thumbnail is in static GIF format. It will be used only for some bot inline results", class: "ThumbnailFormat", fields: &[] },
+  Constructor { name: "thumbnailFormatJpeg", description: "The thumbnail is in JPEG format", class: "ThumbnailFormat", fields: &[] },
+  Constructor { name: "thumbnailFormatMpeg4", description: "The thumbnail is in MPEG4 format. It will be used only for some animations and videos", class: "ThumbnailFormat", fields: &[] },
+  Constructor { name: "thumbnailFormatPng", description: "The thumbnail is in PNG format. It will be used only for background patterns", class: "ThumbnailFormat", fields: &[] },
+  Constructor { name: "thumbnailFormatTgs", description: "The thumbnail is in TGS format. It will be used only for animated sticker sets", class: "ThumbnailFormat", fields: &[] },
+  Constructor { name: "thumbnailFormatWebp", description: "The thumbnail is in WEBP format. It will be used only for some stickers", class: "ThumbnailFormat", fields: &[] },
+  Constructor { name: "topChatCategoryBots", description: "A category containing frequently used private chats with bot users", class: "TopChatCategory", fields: &[] },
+  Constructor { name: "topChatCategoryCalls", description: "A category containing frequently used chats used for calls", class: "TopChatCategory", fields: &[] },
+  Constructor { name: "topChatCategoryChannels", description: "A category containing frequently used channels", class: "TopChatCategory", fields: &[] },
+  Constructor { name: "topChatCategoryForwardChats", description: "A category containing frequently used chats used to forward messages", class: "TopChatCategory", fields: &[] },
+  Constructor { name: "topChatCategoryGroups", description: "A category containing frequently used basic groups and supergroups", class: "TopChatCategory", fields: &[] },
+  Constructor { name: "topChatCategoryInlineBots", description: "A category containing frequently used chats with inline bots sorted by their usage in inline mode", class: "TopChatCategory", fields: &[] },
+  Constructor { name: "topChatCategoryUsers", description: "A category containing frequently used private chats with non-bot users", class: "TopChatCategory", fields: &[] },
+  Constructor { name: "updateActiveNotifications", description: "Contains active notifications that was shown on previous application launches. This update is sent only if the message database is used. In that case it comes once before any updateNotification and updateNotificationGroup update", class: "Update", fields: &[
+    Parameter { name: "groups", type_: "vector<notificationGroup>", description: "Lists of active notification groups" },
+  ] },
+  Constructor { name: "updateAnimatedEmojiMessageClicked", description: "Some animated emoji message was clicked and a big animated sticker must be played if the message is visible on the screen. chatActionWatchingAnimations with the text of the message needs to be sent if the sticker is played", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "sticker", type_: "sticker", description: "The animated sticker to be played" },
+  ] },
+  Constructor { name: "updateAnimationSearchParameters", description: "The parameters of animation search through GetOption(\"animation_search_bot_username\") bot has changed", class: "Update", fields: &[
+    Parameter { name: "provider", type_: "string", description: "Name of the animation search provider" },
+    Parameter { name: "emojis", type_: "vector<string>", description: "The new list of emojis suggested for searching" },
+  ] },
+  Constructor { name: "updateAuthorizationState", description: "The user authorization state has changed", class: "Update", fields: &[
+    Parameter { name: "authorization_state", type_: "AuthorizationState", description: "New authorization state" },
+  ] },
+  Constructor { name: "updateBasicGroup", description: "Some data of a basic group has changed. This update is guaranteed to come before the basic group identifier is returned to the application", class: "Update", fields: &[
+    Parameter { name: "basic_group", type_: "basicGroup", description: "New data about the group" },
+  ] },
+  Constructor { name: "updateBasicGroupFullInfo", description: "Some data from basicGroupFullInfo has been changed", class: "Update", fields: &[
+    Parameter { name: "basic_group_id", type_: "int53", description: "Identifier of a basic group" },
+    Parameter { name: "basic_group_full_info", type_: "basicGroupFullInfo", description: "New full information about the group" },
+  ] },
+  Constructor { name: "updateCall", description: "New call was created or information about a call was updated", class: "Update", fields: &[
+    Parameter { name: "call", type_: "call", description: "New data about a call" },
+  ] },
+  Constructor { name: "updateChatActionBar", description: "The chat action bar was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "action_bar", type_: "ChatActionBar", description: "The new value of the action bar; may be null" },
+  ] },
+  Constructor { name: "updateChatDefaultDisableNotification", description: "The value of the default disable_notification parameter, used when a message is sent to the chat, was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "default_disable_notification", type_: "Bool", description: "The new default_disable_notification value" },
+  ] },
+  Constructor { name: "updateChatDraftMessage", description: "A chat draft has changed. Be aware that the update may come in the currently opened chat but with old content of the draft. If the user has changed the content of the draft, this update mustn't be applied", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "draft_message", type_: "draftMessage", description: "The new draft message; may be null" },
+    Parameter { name: "positions", type_: "vector<chatPosition>", description: "The new chat positions in the chat lists" },
+  ] },
+  Constructor { name: "updateChatFilters", description: "The list of chat filters or a chat filter has changed", class: "Update", fields: &[
+    Parameter { name: "chat_filters", type_: "vector<chatFilterInfo>", description: "The new list of chat filters" },
+  ] },
+  Constructor { name: "updateChatHasScheduledMessages", description: "A chat's has_scheduled_messages field has changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "has_scheduled_messages", type_: "Bool", description: "New value of has_scheduled_messages" },
+  ] },
+  Constructor { name: "updateChatIsBlocked", description: "A chat was blocked or unblocked", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "is_blocked", type_: "Bool", description: "New value of is_blocked" },
+  ] },
+  Constructor { name: "updateChatIsMarkedAsUnread", description: "A chat was marked as unread or was read", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "is_marked_as_unread", type_: "Bool", description: "New value of is_marked_as_unread" },
+  ] },
+  Constructor { name: "updateChatLastMessage", description: "The last message of a chat was changed. If last_message is null, then the last message in the chat became unknown. Some new unknown messages might be added to the chat in this case", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "last_message", type_: "message", description: "The new last message in the chat; may be null" },
+    Parameter { name: "positions", type_: "vector<chatPosition>", description: "The new chat positions in the chat lists" },
+  ] },
+  Constructor { name: "updateChatMember", description: "User rights changed in a chat; for bots only", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "actor_user_id", type_: "int53", description: "Identifier of the user, changing the rights" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) when the user rights was changed" },
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "If user has joined the chat using an invite link, the invite link; may be null" },
+    Parameter { name: "old_chat_member", type_: "chatMember", description: "Previous chat member" },
+    Parameter { name: "new_chat_member", type_: "chatMember", description: "New chat member" },
+  ] },
+  Constructor { name: "updateChatMessageTtlSetting", description: "The message Time To Live setting for a chat was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_ttl_setting", type_: "int32", description: "New value of message_ttl_setting" },
+  ] },
+  Constructor { name: "updateChatNotificationSettings", description: "Notification settings for a chat were changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "notification_settings", type_: "chatNotificationSettings", description: "The new notification settings" },
+  ] },
+  Constructor { name: "updateChatOnlineMemberCount", description: "The number of online group members has changed. This update with non-zero count is sent only for currently opened chats. There is no guarantee that it will be sent just after the count has changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat" },
+    Parameter { name: "online_member_count", type_: "int32", description: "New number of online members in the chat, or 0 if unknown" },
+  ] },
+  Constructor { name: "updateChatPendingJoinRequests", description: "The chat pending join requests were changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "pending_join_requests", type_: "chatJoinRequestsInfo", description: "The new data about pending join requests; may be null" },
+  ] },
+  Constructor { name: "updateChatPermissions", description: "Chat permissions was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "permissions", type_: "chatPermissions", description: "The new chat permissions" },
+  ] },
+  Constructor { name: "updateChatPhoto", description: "A chat photo was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "photo", type_: "chatPhotoInfo", description: "The new chat photo; may be null" },
+  ] },
+  Constructor { name: "updateChatPosition", description: "The position of a chat in a chat list has changed. Instead of this update updateChatLastMessage or updateChatDraftMessage might be sent", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "position", type_: "chatPosition", description: "New chat position. If new order is 0, then the chat needs to be removed from the list" },
+  ] },
+  Constructor { name: "updateChatReadInbox", description: "Incoming messages were read or the number of unread messages has been changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "last_read_inbox_message_id", type_: "int53", description: "Identifier of the last read incoming message" },
+    Parameter { name: "unread_count", type_: "int32", description: "The number of unread messages left in the chat" },
+  ] },
+  Constructor { name: "updateChatReadOutbox", description: "Outgoing messages were read", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "last_read_outbox_message_id", type_: "int53", description: "Identifier of last read outgoing message" },
+  ] },
+  Constructor { name: "updateChatReplyMarkup", description: "The default chat reply markup was changed. Can occur because new messages with reply markup were received or because an old reply markup was hidden by the user", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "reply_markup_message_id", type_: "int53", description: "Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat" },
+  ] },
+  Constructor { name: "updateChatTheme", description: "The chat theme was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "theme_name", type_: "string", description: "The new name of the chat theme; may be empty if theme was reset to default" },
+  ] },
+  Constructor { name: "updateChatThemes", description: "The list of available chat themes has changed", class: "Update", fields: &[
+    Parameter { name: "chat_themes", type_: "vector<chatTheme>", description: "The new list of chat themes" },
+  ] },
+  Constructor { name: "updateChatTitle", description: "The title of a chat was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "title", type_: "string", description: "The new chat title" },
+  ] },
+  Constructor { name: "updateChatUnreadMentionCount", description: "The chat unread_mention_count has changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "unread_mention_count", type_: "int32", description: "The number of unread mention messages left in the chat" },
+  ] },
+  Constructor { name: "updateChatVideoChat", description: "A chat video chat state has changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "video_chat", type_: "videoChat", description: "New value of video_chat" },
+  ] },
+  Constructor { name: "updateConnectionState", description: "The connection state has changed. This update must be used only to show a human-readable description of the connection state", class: "Update", fields: &[
+    Parameter { name: "state", type_: "ConnectionState", description: "The new connection state" },
+  ] },
+  Constructor { name: "updateDeleteMessages", description: "Some messages were deleted", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "Identifiers of the deleted messages" },
+    Parameter { name: "is_permanent", type_: "Bool", description: "True, if the messages are permanently deleted by a user (as opposed to just becoming inaccessible)" },
+    Parameter { name: "from_cache", type_: "Bool", description: "True, if the messages are deleted only from the cache and can possibly be retrieved again in the future" },
+  ] },
+  Constructor { name: "updateDiceEmojis", description: "The list of supported dice emojis has changed", class: "Update", fields: &[
+    Parameter { name: "emojis", type_: "vector<string>", description: "The new list of supported dice emojis" },
+  ] },
+  Constructor { name: "updateFavoriteStickers", description: "The list of favorite stickers was updated", class: "Update", fields: &[
+    Parameter { name: "sticker_ids", type_: "vector<int32>", description: "The new list of file identifiers of favorite stickers" },
+  ] },
+  Constructor { name: "updateFile", description: "Information about a file was updated", class: "Update", fields: &[
+    Parameter { name: "file", type_: "file", description: "New data about the file" },
+  ] },
+  Constructor { name: "updateFileGenerationStart", description: "The file generation process needs to be started by the application", class: "Update", fields: &[
+    Parameter { name: "generation_id", type_: "int64", description: "Unique identifier for the generation process" },
+    Parameter { name: "original_path", type_: "string", description: "The path to a file from which a new file is generated; may be empty" },
+    Parameter { name: "destination_path", type_: "string", description: "The path to a file that must be created and where the new file is generated" },
+    Parameter { name: "conversion", type_: "string", description: "String specifying the conversion applied to the original file. If conversion is \"#url#\" than original_path contains an HTTP/HTTPS URL of a file, which must be downloaded by the application" },
+  ] },
+  Constructor { name: "updateFileGenerationStop", description: "File generation is no longer needed", class: "Update", fields: &[
+    Parameter { name: "generation_id", type_: "int64", description: "Unique identifier for the generation process" },
+  ] },
+  Constructor { name: "updateGroupCall", description: "Information about a group call was updated", class: "Update", fields: &[
+    Parameter { name: "group_call", type_: "groupCall", description: "New data about a group call" },
+  ] },
+  Constructor { name: "updateGroupCallParticipant", description: "Information about a group call participant was changed. The updates are sent only after the group call is received through getGroupCall and only if the call is joined or being joined", class: "Update", fields: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Identifier of group call" },
+    Parameter { name: "participant", type_: "groupCallParticipant", description: "New data about a participant" },
+  ] },
+  Constructor { name: "updateHavePendingNotifications", description: "Describes whether there are some pending notification updates. Can be used to prevent application from killing, while there are some pending notifications", class: "Update", fields: &[
+    Parameter { name: "have_delayed_notifications", type_: "Bool", description: "True, if there are some delayed notification updates, which will be sent soon" },
+    Parameter { name: "have_unreceived_notifications", type_: "Bool", description: "True, if there can be some yet unreceived notifications, which are being fetched from the server" },
+  ] },
+  Constructor { name: "updateInstalledStickerSets", description: "The list of installed sticker sets was updated", class: "Update", fields: &[
+    Parameter { name: "is_masks", type_: "Bool", description: "True, if the list of installed mask sticker sets was updated" },
+    Parameter { name: "sticker_set_ids", type_: "vector<int64>", description: "The new list of installed ordinary sticker sets" },
+  ] },
+  Constructor { name: "updateLanguagePackStrings", description: "Some language pack strings have been updated", class: "Update", fields: &[
+    Parameter { name: "localization_target", type_: "string", description: "Localization target to which the language pack belongs" },
+    Parameter { name: "language_pack_id", type_: "string", description: "Identifier of the updated language pack" },
+    Parameter { name: "strings", type_: "vector<languagePackString>", description: "List of changed language pack strings" },
+  ] },
+  Constructor { name: "updateMessageContent", description: "The message content has changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "new_content", type_: "MessageContent", description: "New message content" },
+  ] },
+  Constructor { name: "updateMessageContentOpened", description: "The message content was opened. Updates voice note messages to \"listened\", video note messages to \"viewed\" and starts the TTL timer for self-destructing messages", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+  ] },
+  Constructor { name: "updateMessageEdited", description: "A message was edited. Changes in the message content will come in a separate updateMessageContent", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "edit_date", type_: "int32", description: "Point in time (Unix timestamp) when the message was edited" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "New message reply markup; may be null" },
+  ] },
+  Constructor { name: "updateMessageInteractionInfo", description: "The information about interactions with a message has changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "interaction_info", type_: "messageInteractionInfo", description: "New information about interactions with the message; may be null" },
+  ] },
+  Constructor { name: "updateMessageIsPinned", description: "The message pinned state was changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "The message identifier" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the message is pinned" },
+  ] },
+  Constructor { name: "updateMessageLiveLocationViewed", description: "A message with a live location was viewed. When the update is received, the application is supposed to update the live location", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat with the live location message" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message with live location" },
+  ] },
+  Constructor { name: "updateMessageMentionRead", description: "A message with an unread mention was read", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "unread_mention_count", type_: "int32", description: "The new number of unread mention messages left in the chat" },
+  ] },
+  Constructor { name: "updateMessageSendAcknowledged", description: "A request to send a message has reached the Telegram server. This doesn't mean that the message will be sent successfully or even that the send message request will be processed. This update will be sent only if the option \"use_quick_ack\" is set to true. This update may be sent multiple times for the same message", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat identifier of the sent message" },
+    Parameter { name: "message_id", type_: "int53", description: "A temporary message identifier" },
+  ] },
+  Constructor { name: "updateMessageSendFailed", description: "A message failed to send. Be aware that some messages being sent can be irrecoverably deleted, in which case updateDeleteMessages will be received instead of this update", class: "Update", fields: &[
+    Parameter { name: "message", type_: "message", description: "The failed to send message" },
+    Parameter { name: "old_message_id", type_: "int53", description: "The previous temporary message identifier" },
+    Parameter { name: "error_code", type_: "int32", description: "An error code" },
+    Parameter { name: "error_message", type_: "string", description: "Error message" },
+  ] },
+  Constructor { name: "updateMessageSendSucceeded", description: "A message has been successfully sent", class: "Update", fields: &[
+    Parameter { name: "message", type_: "message", description: "The sent message. Usually only the message identifier, date, and content are changed, but almost all other fields can also change" },
+    Parameter { name: "old_message_id", type_: "int53", description: "The previous temporary message identifier" },
+  ] },
+  Constructor { name: "updateNewCallSignalingData", description: "New call signaling data arrived", class: "Update", fields: &[
+    Parameter { name: "call_id", type_: "int32", description: "The call identifier" },
+    Parameter { name: "data", type_: "bytes", description: "The data" },
+  ] },
+  Constructor { name: "updateNewCallbackQuery", description: "A new incoming callback query; for bots only", class: "Update", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique query identifier" },
+    Parameter { name: "sender_user_id", type_: "int53", description: "Identifier of the user who sent the query" },
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat where the query was sent" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message, from which the query originated" },
+    Parameter { name: "chat_instance", type_: "int64", description: "Identifier that uniquely corresponds to the chat to which the message was sent" },
+    Parameter { name: "payload", type_: "CallbackQueryPayload", description: "Query payload" },
+  ] },
+  Constructor { name: "updateNewChat", description: "A new chat has been loaded/created. This update is guaranteed to come before the chat identifier is returned to the application. The chat field changes will be reported through separate updates", class: "Update", fields: &[
+    Parameter { name: "chat", type_: "chat", description: "The chat" },
+  ] },
+  Constructor { name: "updateNewChatJoinRequest", description: "A user sent a join request to a chat; for bots only", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "request", type_: "chatJoinRequest", description: "Join request" },
+    Parameter { name: "invite_link", type_: "chatInviteLink", description: "The invite link, which was used to send join request; may be null" },
+  ] },
+  Constructor { name: "updateNewChosenInlineResult", description: "The user has chosen a result of an inline query; for bots only", class: "Update", fields: &[
+    Parameter { name: "sender_user_id", type_: "int53", description: "Identifier of the user who sent the query" },
+    Parameter { name: "user_location", type_: "location", description: "User location; may be null" },
+    Parameter { name: "query", type_: "string", description: "Text of the query" },
+    Parameter { name: "result_id", type_: "string", description: "Identifier of the chosen result" },
+    Parameter { name: "inline_message_id", type_: "string", description: "Identifier of the sent inline message, if known" },
+  ] },
+  Constructor { name: "updateNewCustomEvent", description: "A new incoming event; for bots only", class: "Update", fields: &[
+    Parameter { name: "event", type_: "string", description: "A JSON-serialized event" },
+  ] },
+  Constructor { name: "updateNewCustomQuery", description: "A new incoming query; for bots only", class: "Update", fields: &[
+    Parameter { name: "id", type_: "int64", description: "The query identifier" },
+    Parameter { name: "data", type_: "string", description: "JSON-serialized query data" },
+    Parameter { name: "timeout", type_: "int32", description: "Query timeout" },
+  ] },
+  Constructor { name: "updateNewInlineCallbackQuery", description: "A new incoming callback query from a message sent via a bot; for bots only", class: "Update", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique query identifier" },
+    Parameter { name: "sender_user_id", type_: "int53", description: "Identifier of the user who sent the query" },
+    Parameter { name: "inline_message_id", type_: "string", description: "Identifier of the inline message, from which the query originated" },
+    Parameter { name: "chat_instance", type_: "int64", description: "An identifier uniquely corresponding to the chat a message was sent to" },
+    Parameter { name: "payload", type_: "CallbackQueryPayload", description: "Query payload" },
+  ] },
+  Constructor { name: "updateNewInlineQuery", description: "A new incoming inline query; for bots only", class: "Update", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique query identifier" },
+    Parameter { name: "sender_user_id", type_: "int53", description: "Identifier of the user who sent the query" },
+    Parameter { name: "user_location", type_: "location", description: "User location; may be null" },
+    Parameter { name: "chat_type", type_: "ChatType", description: "The type of the chat, from which the query originated; may be null if unknown" },
+    Parameter { name: "query", type_: "string", description: "Text of the query" },
+    Parameter { name: "offset", type_: "string", description: "Offset of the first entry to return" },
+  ] },
+  Constructor { name: "updateNewMessage", description: "A new message was received; can also be an outgoing message", class: "Update", fields: &[
+    Parameter { name: "message", type_: "message", description: "The new message" },
+  ] },
+  Constructor { name: "updateNewPreCheckoutQuery", description: "A new incoming pre-checkout query; for bots only. Contains full information about a checkout", class: "Update", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique query identifier" },
+    Parameter { name: "sender_user_id", type_: "int53", description: "Identifier of the user who sent the query" },
+    Parameter { name: "currency", type_: "string", description: "Currency for the product price" },
+    Parameter { name: "total_amount", type_: "int32", description: "Total price for the product, in the smallest units of the currency" },
+    Parameter { name: "invoice_payload", type_: "bytes", description: "Invoice payload" },
+    Parameter { name: "shipping_option_id", type_: "string", description: "Identifier of a shipping option chosen by the user; may be empty if not applicable" },
+    Parameter { name: "order_info", type_: "orderInfo", description: "Information about the order; may be null" },
+  ] },
+  Constructor { name: "updateNewShippingQuery", description: "A new incoming shipping query; for bots only. Only for invoices with flexible price", class: "Update", fields: &[
+    Parameter { name: "id", type_: "int64", description: "Unique query identifier" },
+    Parameter { name: "sender_user_id", type_: "int53", description: "Identifier of the user who sent the query" },
+    Parameter { name: "invoice_payload", type_: "string", description: "Invoice payload" },
+    Parameter { name: "shipping_address", type_: "address", description: "User shipping address" },
+  ] },
+  Constructor { name: "updateNotification", description: "A notification was changed", class: "Update", fields: &[
+    Parameter { name: "notification_group_id", type_: "int32", description: "Unique notification group identifier" },
+    Parameter { name: "notification", type_: "notification", description: "Changed notification" },
+  ] },
+  Constructor { name: "updateNotificationGroup", description: "A list of active notifications in a notification group has changed", class: "Update", fields: &[
+    Parameter { name: "notification_group_id", type_: "int32", description: "Unique notification group identifier" },
+    Parameter { name: "type", type_: "NotificationGroupType", description: "New type of the notification group" },
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of a chat to which all notifications in the group belong" },
+    Parameter { name: "notification_settings_chat_id", type_: "int53", description: "Chat identifier, which notification settings must be applied to the added notifications" },
+    Parameter { name: "is_silent", type_: "Bool", description: "True, if the notifications must be shown without sound" },
+    Parameter { name: "total_count", type_: "int32", description: "Total number of unread notifications in the group, can be bigger than number of active notifications" },
+    Parameter { name: "added_notifications", type_: "vector<notification>", description: "List of added group notifications, sorted by notification ID" },
+    Parameter { name: "removed_notification_ids", type_: "vector<int32>", description: "Identifiers of removed group notifications, sorted by notification ID" },
+  ] },
+  Constructor { name: "updateOption", description: "An option changed its value", class: "Update", fields: &[
+    Parameter { name: "name", type_: "string", description: "The option name" },
+    Parameter { name: "value", type_: "OptionValue", description: "The new option value" },
+  ] },
+  Constructor { name: "updatePoll", description: "A poll was updated; for bots only", class: "Update", fields: &[
+    Parameter { name: "poll", type_: "poll", description: "New data about the poll" },
+  ] },
+  Constructor { name: "updatePollAnswer", description: "A user changed the answer to a poll; for bots only", class: "Update", fields: &[
+    Parameter { name: "poll_id", type_: "int64", description: "Unique poll identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "The user, who changed the answer to the poll" },
+    Parameter { name: "option_ids", type_: "vector<int32>", description: "0-based identifiers of answer options, chosen by the user" },
+  ] },
+  Constructor { name: "updateRecentStickers", description: "The list of recently used stickers was updated", class: "Update", fields: &[
+    Parameter { name: "is_attached", type_: "Bool", description: "True, if the list of stickers attached to photo or video files was updated, otherwise the list of sent stickers is updated" },
+    Parameter { name: "sticker_ids", type_: "vector<int32>", description: "The new list of file identifiers of recently used stickers" },
+  ] },
+  Constructor { name: "updateSavedAnimations", description: "The list of saved animations was updated", class: "Update", fields: &[
+    Parameter { name: "animation_ids", type_: "vector<int32>", description: "The new list of file identifiers of saved animations" },
+  ] },
+  Constructor { name: "updateScopeNotificationSettings", description: "Notification settings for some type of chats were updated", class: "Update", fields: &[
+    Parameter { name: "scope", type_: "NotificationSettingsScope", description: "Types of chats for which notification settings were updated" },
+    Parameter { name: "notification_settings", type_: "scopeNotificationSettings", description: "The new notification settings" },
+  ] },
+  Constructor { name: "updateSecretChat", description: "Some data of a secret chat has changed. This update is guaranteed to come before the secret chat identifier is returned to the application", class: "Update", fields: &[
+    Parameter { name: "secret_chat", type_: "secretChat", description: "New data about the secret chat" },
+  ] },
+  Constructor { name: "updateSelectedBackground", description: "The selected background has changed", class: "Update", fields: &[
+    Parameter { name: "for_dark_theme", type_: "Bool", description: "True, if background for dark theme has changed" },
+    Parameter { name: "background", type_: "background", description: "The new selected background; may be null" },
+  ] },
+  Constructor { name: "updateServiceNotification", description: "Service notification from the server. Upon receiving this the application must show a popup with the content of the notification", class: "Update", fields: &[
+    Parameter { name: "type", type_: "string", description: "Notification type. If type begins with \"AUTH_KEY_DROP_\", then two buttons \"Cancel\" and \"Log out\" must be shown under notification; if user presses the second, all local data must be destroyed using Destroy method" },
+    Parameter { name: "content", type_: "MessageContent", description: "Notification content" },
+  ] },
+  Constructor { name: "updateStickerSet", description: "A sticker set has changed", class: "Update", fields: &[
+    Parameter { name: "sticker_set", type_: "stickerSet", description: "The sticker set" },
+  ] },
+  Constructor { name: "updateSuggestedActions", description: "The list of suggested to the user actions has changed", class: "Update", fields: &[
+    Parameter { name: "added_actions", type_: "vector<SuggestedAction>", description: "Added suggested actions" },
+    Parameter { name: "removed_actions", type_: "vector<SuggestedAction>", description: "Removed suggested actions" },
+  ] },
+  Constructor { name: "updateSupergroup", description: "Some data of a supergroup or a channel has changed. This update is guaranteed to come before the supergroup identifier is returned to the application", class: "Update", fields: &[
+    Parameter { name: "supergroup", type_: "supergroup", description: "New data about the supergroup" },
+  ] },
+  Constructor { name: "updateSupergroupFullInfo", description: "Some data from supergroupFullInfo has been changed", class: "Update", fields: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the supergroup or channel" },
+    Parameter { name: "supergroup_full_info", type_: "supergroupFullInfo", description: "New full information about the supergroup" },
+  ] },
+  Constructor { name: "updateTermsOfService", description: "New terms of service must be accepted by the user. If the terms of service are declined, then the deleteAccount method must be called with the reason \"Decline ToS update\"", class: "Update", fields: &[
+    Parameter { name: "terms_of_service_id", type_: "string", description: "Identifier of the terms of service" },
+    Parameter { name: "terms_of_service", type_: "termsOfService", description: "The new terms of service" },
+  ] },
+  Constructor { name: "updateTrendingStickerSets", description: "The list of trending sticker sets was updated or some of them were viewed", class: "Update", fields: &[
+    Parameter { name: "sticker_sets", type_: "stickerSets", description: "The prefix of the list of trending sticker sets with the newest trending sticker sets" },
+  ] },
+  Constructor { name: "updateUnreadChatCount", description: "Number of unread chats, i.e. with unread messages or marked as unread, has changed. This update is sent only if the message database is used", class: "Update", fields: &[
+    Parameter { name: "chat_list", type_: "ChatList", description: "The chat list with changed number of unread messages" },
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total number of chats in the chat list" },
+    Parameter { name: "unread_count", type_: "int32", description: "Total number of unread chats" },
+    Parameter { name: "unread_unmuted_count", type_: "int32", description: "Total number of unread unmuted chats" },
+    Parameter { name: "marked_as_unread_count", type_: "int32", description: "Total number of chats marked as unread" },
+    Parameter { name: "marked_as_unread_unmuted_count", type_: "int32", description: "Total number of unmuted chats marked as unread" },
+  ] },
+  Constructor { name: "updateUnreadMessageCount", description: "Number of unread messages in a chat list has changed. This update is sent only if the message database is used", class: "Update", fields: &[
+    Parameter { name: "chat_list", type_: "ChatList", description: "The chat list with changed number of unread messages" },
+    Parameter { name: "unread_count", type_: "int32", description: "Total number of unread messages" },
+    Parameter { name: "unread_unmuted_count", type_: "int32", description: "Total number of unread messages in unmuted chats" },
+  ] },
+  Constructor { name: "updateUser", description: "Some data of a user has changed. This update is guaranteed to come before the user identifier is returned to the application", class: "Update", fields: &[
+    Parameter { name: "user", type_: "user", description: "New data about the user" },
+  ] },
+  Constructor { name: "updateUserChatAction", description: "User activity in the chat has changed", class: "Update", fields: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, a message thread identifier in which the action was performed" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of a user performing an action" },
+    Parameter { name: "action", type_: "ChatAction", description: "The action description" },
+  ] },
+  Constructor { name: "updateUserFullInfo", description: "Some data from userFullInfo has been changed", class: "Update", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "user_full_info", type_: "userFullInfo", description: "New full information about the user" },
+  ] },
+  Constructor { name: "updateUserPrivacySettingRules", description: "Some privacy setting rules have been changed", class: "Update", fields: &[
+    Parameter { name: "setting", type_: "UserPrivacySetting", description: "The privacy setting" },
+    Parameter { name: "rules", type_: "userPrivacySettingRules", description: "New privacy rules" },
+  ] },
+  Constructor { name: "updateUserStatus", description: "The user went online or offline", class: "Update", fields: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "status", type_: "UserStatus", description: "New status of the user" },
+  ] },
+  Constructor { name: "updateUsersNearby", description: "The list of users nearby has changed. The update is guaranteed to be sent only 60 seconds after a successful searchChatsNearby request", class: "Update", fields: &[
+    Parameter { name: "users_nearby", type_: "vector<chatNearby>", description: "The new list of users nearby" },
+  ] },
+  Constructor { name: "updates", description: "Contains a list of updates", class: "Updates", fields: &[
+    Parameter { name: "updates", type_: "vector<Update>", description: "List of updates" },
+  ] },
+  Constructor { name: "user", description: "Represents a user", class: "User", fields: &[
+    Parameter { name: "id", type_: "int53", description: "User identifier" },
+    Parameter { name: "first_name", type_: "string", description: "First name of the user" },
+    Parameter { name: "last_name", type_: "string", description: "Last name of the user" },
+    Parameter { name: "username", type_: "string", description: "Username of the user" },
+    Parameter { name: "phone_number", type_: "string", description: "Phone number of the user" },
+    Parameter { name: "status", type_: "UserStatus", description: "Current online status of the user" },
+    Parameter { name: "profile_photo", type_: "profilePhoto", description: "Profile photo of the user; may be null" },
+    Parameter { name: "is_contact", type_: "Bool", description: "The user is a contact of the current user" },
+    Parameter { name: "is_mutual_contact", type_: "Bool", description: "The user is a contact of the current user and the current user is a contact of the user" },
+    Parameter { name: "is_verified", type_: "Bool", description: "True, if the user is verified" },
+    Parameter { name: "is_support", type_: "Bool", description: "True, if the user is Telegram support account" },
+    Parameter { name: "restriction_reason", type_: "string", description: "If non-empty, it contains a human-readable description of the reason why access to this user must be restricted" },
+    Parameter { name: "is_scam", type_: "Bool", description: "True, if many users reported this user as a scam" },
+    Parameter { name: "is_fake", type_: "Bool", description: "True, if many users reported this user as a fake account" },
+    Parameter { name: "have_access", type_: "Bool", description: "If false, the user is inaccessible, and the only information known about the user is inside this class. It can't be passed to any method except GetUser" },
+    Parameter { name: "type", type_: "UserType", description: "Type of the user" },
+    Parameter { name: "language_code", type_: "string", description: "IETF language tag of the user's language; only available to bots" },
+  ] },
+  Constructor { name: "userFullInfo", description: "Contains full information about a user", class: "UserFullInfo", fields: &[
+    Parameter { name: "photo", type_: "chatPhoto", description: "User profile photo; may be null" },
+    Parameter { name: "is_blocked", type_: "Bool", description: "True, if the user is blocked by the current user" },
+    Parameter { name: "can_be_called", type_: "Bool", description: "True, if the user can be called" },
+    Parameter { name: "supports_video_calls", type_: "Bool", description: "True, if a video call can be created with the user" },
+    Parameter { name: "has_private_calls", type_: "Bool", description: "True, if the user can't be called due to their privacy settings" },
+    Parameter { name: "need_phone_number_privacy_exception", type_: "Bool", description: "True, if the current user needs to explicitly allow to share their phone number with the user when the method addContact is used" },
+    Parameter { name: "bio", type_: "string", description: "A short user bio" },
+    Parameter { name: "share_text", type_: "string", description: "For bots, the text that is shown on the bot's profile page and is sent together with the link when users share the bot" },
+    Parameter { name: "description", type_: "string", description: "For bots, the text that is shown on the bot's profile page and is sent together with the link when users share the bot" },
+    Parameter { name: "group_in_common_count", type_: "int32", description: "Number of group chats where both the other user and the current user are a member; 0 for the current user" },
+    Parameter { name: "commands", type_: "vector<botCommand>", description: "For bots, list of the bot commands" },
+  ] },
+  Constructor { name: "userPrivacySettingAllowCalls", description: "A privacy setting for managing whether the user can be called", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userPrivacySettingAllowChatInvites", description: "A privacy setting for managing whether the user can be invited to chats", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userPrivacySettingAllowFindingByPhoneNumber", description: "A privacy setting for managing whether the user can be found by their phone number. Checked only if the phone number is not known to the other user. Can be set only to \"Allow contacts\" or \"Allow all\"", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userPrivacySettingAllowPeerToPeerCalls", description: "A privacy setting for managing whether peer-to-peer connections can be used for calls", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userPrivacySettingRuleAllowAll", description: "A rule to allow all users to do something", class: "UserPrivacySettingRule", fields: &[] },
+  Constructor { name: "userPrivacySettingRuleAllowChatMembers", description: "A rule to allow all members of certain specified basic groups and supergroups to doing something", class: "UserPrivacySettingRule", fields: &[
+    Parameter { name: "chat_ids", type_: "vector<int53>", description: "The chat identifiers, total number of chats in all rules must not exceed 20" },
+  ] },
+  Constructor { name: "userPrivacySettingRuleAllowContacts", description: "A rule to allow all of a user's contacts to do something", class: "UserPrivacySettingRule", fields: &[] },
+  Constructor { name: "userPrivacySettingRuleAllowUsers", description: "A rule to allow certain specified users to do something", class: "UserPrivacySettingRule", fields: &[
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "The user identifiers, total number of users in all rules must not exceed 1000" },
+  ] },
+  Constructor { name: "userPrivacySettingRuleRestrictAll", description: "A rule to restrict all users from doing something", class: "UserPrivacySettingRule", fields: &[] },
+  Constructor { name: "userPrivacySettingRuleRestrictChatMembers", description: "A rule to restrict all members of specified basic groups and supergroups from doing something", class: "UserPrivacySettingRule", fields: &[
+    Parameter { name: "chat_ids", type_: "vector<int53>", description: "The chat identifiers, total number of chats in all rules must not exceed 20" },
+  ] },
+  Constructor { name: "userPrivacySettingRuleRestrictContacts", description: "A rule to restrict all contacts of a user from doing something", class: "UserPrivacySettingRule", fields: &[] },
+  Constructor { name: "userPrivacySettingRuleRestrictUsers", description: "A rule to restrict all specified users from doing something", class: "UserPrivacySettingRule", fields: &[
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "The user identifiers, total number of users in all rules must not exceed 1000" },
+  ] },
+  Constructor { name: "userPrivacySettingRules", description: "A list of privacy rules. Rules are matched in the specified order. The first matched rule defines the privacy setting for a given user. If no rule matches, the action is not allowed", class: "UserPrivacySettingRules", fields: &[
+    Parameter { name: "rules", type_: "vector<UserPrivacySettingRule>", description: "A list of rules" },
+  ] },
+  Constructor { name: "userPrivacySettingShowLinkInForwardedMessages", description: "A privacy setting for managing whether a link to the user's account is included in forwarded messages", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userPrivacySettingShowPhoneNumber", description: "A privacy setting for managing whether the user's phone number is visible", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userPrivacySettingShowProfilePhoto", description: "A privacy setting for managing whether the user's profile photo is visible", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userPrivacySettingShowStatus", description: "A privacy setting for managing whether the user's online status is visible", class: "UserPrivacySetting", fields: &[] },
+  Constructor { name: "userStatusEmpty", description: "The user status was never changed", class: "UserStatus", fields: &[] },
+  Constructor { name: "userStatusLastMonth", description: "The user is offline, but was online last month", class: "UserStatus", fields: &[] },
+  Constructor { name: "userStatusLastWeek", description: "The user is offline, but was online last week", class: "UserStatus", fields: &[] },
+  Constructor { name: "userStatusOffline", description: "The user is offline", class: "UserStatus", fields: &[
+    Parameter { name: "was_online", type_: "int32", description: "Point in time (Unix timestamp) when the user was last online" },
+  ] },
+  Constructor { name: "userStatusOnline", description: "The user is online", class: "UserStatus", fields: &[
+    Parameter { name: "expires", type_: "int32", description: "Point in time (Unix timestamp) when the user's online status will expire" },
+  ] },
+  Constructor { name: "userStatusRecently", description: "The user was online recently", class: "UserStatus", fields: &[] },
+  Constructor { name: "userTypeBot", description: "A bot (see https://core.telegram.org/bots)", class: "UserType", fields: &[
+    Parameter { name: "can_join_groups", type_: "Bool", description: "True, if the bot can be invited to basic group and supergroup chats" },
+    Parameter { name: "can_read_all_group_messages", type_: "Bool", description: "True, if the bot can read all messages in basic group or supergroup chats and not just those addressed to the bot. In private and channel chats a bot can always read all messages" },
+    Parameter { name: "is_inline", type_: "Bool", description: "True, if the bot supports inline queries" },
+    Parameter { name: "inline_query_placeholder", type_: "string", description: "Placeholder for inline queries (displayed on the application input field)" },
+    Parameter { name: "need_location", type_: "Bool", description: "True, if the location of the user is expected to be sent with every inline query to this bot" },
+  ] },
+  Constructor { name: "userTypeDeleted", description: "A deleted user or deleted bot. No information on the user besides the user identifier is available. It is not possible to perform any active actions on this type of user", class: "UserType", fields: &[] },
+  Constructor { name: "userTypeRegular", description: "A regular user", class: "UserType", fields: &[] },
+  Constructor { name: "userTypeUnknown", description: "No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type", class: "UserType", fields: &[] },
+  Constructor { name: "users", description: "Represents a list of users", class: "Users", fields: &[
+    Parameter { name: "total_count", type_: "int32", description: "Approximate total count of users found" },
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "A list of user identifiers" },
+  ] },
+  Constructor { name: "validatedOrderInfo", description: "Contains a temporary identifier of validated order information, which is stored for one hour. Also contains the available shipping options", class: "ValidatedOrderInfo", fields: &[
+    Parameter { name: "order_info_id", type_: "string", description: "Temporary identifier of the order information" },
+    Parameter { name: "shipping_options", type_: "vector<shippingOption>", description: "Available shipping options" },
+  ] },
+  Constructor { name: "vectorPathCommandCubicBezierCurve", description: "A cubic Bézier curve to a given point", class: "VectorPathCommand", fields: &[
+    Parameter { name: "start_control_point", type_: "point", description: "The start control point of the curve" },
+    Parameter { name: "end_control_point", type_: "point", description: "The end control point of the curve" },
+    Parameter { name: "end_point", type_: "point", description: "The end point of the curve" },
+  ] },
+  Constructor { name: "vectorPathCommandLine", description: "A straight line to a given point", class: "VectorPathCommand", fields: &[
+    Parameter { name: "end_point", type_: "point", description: "The end point of the straight line" },
+  ] },
+  Constructor { name: "venue", description: "Describes a venue", class: "Venue", fields: &[
+    Parameter { name: "location", type_: "location", description: "Venue location; as defined by the sender" },
+    Parameter { name: "title", type_: "string", description: "Venue name; as defined by the sender" },
+    Parameter { name: "address", type_: "string", description: "Venue address; as defined by the sender" },
+    Parameter { name: "provider", type_: "string", description: "Provider of the venue database; as defined by the sender. Currently only \"foursquare\" and \"gplaces\" (Google Places) need to be supported" },
+    Parameter { name: "id", type_: "string", description: "Identifier of the venue in the provider database; as defined by the sender" },
+    Parameter { name: "type", type_: "string", description: "Type of the venue in the provider database; as defined by the sender" },
+  ] },
+  Constructor { name: "video", description: "Describes a video file", class: "Video", fields: &[
+    Parameter { name: "duration", type_: "int32", description: "Duration of the video, in seconds; as defined by the sender" },
+    Parameter { name: "width", type_: "int32", description: "Video width; as defined by the sender" },
+    Parameter { name: "height", type_: "int32", description: "Video height; as defined by the sender" },
+    Parameter { name: "file_name", type_: "string", description: "Original name of the file; as defined by the sender" },
+    Parameter { name: "mime_type", type_: "string", description: "MIME type of the file; as defined by the sender" },
+    Parameter { name: "has_stickers", type_: "Bool", description: "True, if stickers were added to the video. The list of corresponding sticker sets can be received using getAttachedStickerSets" },
+    Parameter { name: "supports_streaming", type_: "Bool", description: "True, if the video is supposed to be streamed" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "Video minithumbnail; may be null" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Video thumbnail in JPEG or MPEG4 format; as defined by the sender; may be null" },
+    Parameter { name: "video", type_: "file", description: "File containing the video" },
+  ] },
+  Constructor { name: "videoChat", description: "Describes a video chat", class: "VideoChat", fields: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier of an active video chat; 0 if none. Full information about the video chat can be received through the method getGroupCall" },
+    Parameter { name: "has_participants", type_: "Bool", description: "True, if the video chat has participants" },
+    Parameter { name: "default_participant_id", type_: "MessageSender", description: "Default group call participant identifier to join the video chat; may be null" },
+  ] },
+  Constructor { name: "videoNote", description: "Describes a video note. The video must be equal in width and height, cropped to a circle, and stored in MPEG4 format", class: "VideoNote", fields: &[
+    Parameter { name: "duration", type_: "int32", description: "Duration of the video, in seconds; as defined by the sender" },
+    Parameter { name: "length", type_: "int32", description: "Video width and height; as defined by the sender" },
+    Parameter { name: "minithumbnail", type_: "minithumbnail", description: "Video minithumbnail; may be null" },
+    Parameter { name: "thumbnail", type_: "thumbnail", description: "Video thumbnail in JPEG format; as defined by the sender; may be null" },
+    Parameter { name: "video", type_: "file", description: "File containing the video" },
+  ] },
+  Constructor { name: "voiceNote", description: "Describes a voice note. The voice note must be encoded with the Opus codec, and stored inside an OGG container. Voice notes can have only a single audio channel", class: "VoiceNote", fields: &[
+    Parameter { name: "duration", type_: "int32", description: "Duration of the voice note, in seconds; as defined by the sender" },
+    Parameter { name: "waveform", type_: "bytes", description: "A waveform representation of the voice note in 5-bit format" },
+    Parameter { name: "mime_type", type_: "string", description: "MIME type of the file; as defined by the sender" },
+    Parameter { name: "voice", type_: "file", description: "File containing the voice note" },
+  ] },
+  Constructor { name: "webPage", description: "Describes a web page preview", class: "WebPage", fields: &[
+    Parameter { name: "url", type_: "string", description: "Original URL of the link" },
+    Parameter { name: "display_url", type_: "string", description: "URL to display" },
+    Parameter { name: "type", type_: "string", description: "Type of the web page. Can be: article, photo, audio, video, document, profile, app, or something else" },
+    Parameter { name: "site_name", type_: "string", description: "Short name of the site (e.g., Google Docs, App Store)" },
+    Parameter { name: "title", type_: "string", description: "Title of the content" },
+    Parameter { name: "description", type_: "formattedText", description: "Description of the content" },
+    Parameter { name: "photo", type_: "photo", description: "Image representing the content; may be null" },
+    Parameter { name: "embed_url", type_: "string", description: "URL to show in the embedded preview" },
+    Parameter { name: "embed_type", type_: "string", description: "MIME type of the embedded preview, (e.g., text/html or video/mp4)" },
+    Parameter { name: "embed_width", type_: "int32", description: "Width of the embedded preview" },
+    Parameter { name: "embed_height", type_: "int32", description: "Height of the embedded preview" },
+    Parameter { name: "duration", type_: "int32", description: "Duration of the content, in seconds" },
+    Parameter { name: "author", type_: "string", description: "Author of the content" },
+    Parameter { name: "animation", type_: "animation", description: "Preview of the content as an animation, if available; may be null" },
+    Parameter { name: "audio", type_: "audio", description: "Preview of the content as an audio file, if available; may be null" },
+    Parameter { name: "document", type_: "document", description: "Preview of the content as a document, if available (currently only available for small PDF files and ZIP archives); may be null" },
+    Parameter { name: "sticker", type_: "sticker", description: "Preview of the content as a sticker for small WEBP files, if available; may be null" },
+    Parameter { name: "video", type_: "video", description: "Preview of the content as a video, if available; may be null" },
+    Parameter { name: "video_note", type_: "videoNote", description: "Preview of the content as a video note, if available; may be null" },
+    Parameter { name: "voice_note", type_: "voiceNote", description: "Preview of the content as a voice note, if available; may be null" },
+    Parameter { name: "instant_view_version", type_: "int32", description: "Version of instant view, available for the web page (currently can be 1 or 2), 0 if none" },
+  ] },
+  Constructor { name: "webPageInstantView", description: "Describes an instant view page for a web page", class: "WebPageInstantView", fields: &[
+    Parameter { name: "page_blocks", type_: "vector<PageBlock>", description: "Content of the web page" },
+    Parameter { name: "view_count", type_: "int32", description: "Number of the instant view views; 0 if unknown" },
+    Parameter { name: "version", type_: "int32", description: "Version of the instant view, currently can be 1 or 2" },
+    Parameter { name: "is_rtl", type_: "Bool", description: "True, if the instant view must be shown from right to left" },
+    Parameter { name: "is_full", type_: "Bool", description: "True, if the instant view contains the full page. A network request might be needed to get the full web page instant view" },
+    Parameter { name: "feedback_link", type_: "InternalLinkType", description: "An internal link to be opened to leave feedback about the instant view" },
+  ] },
+];
+
+pub(super) static FUNCTIONS: &[Function] = &[
+  Function { name: "acceptCall", description: "Accepts an incoming call", result: "Ok", parameters: &[
+    Parameter { name: "call_id", type_: "int32", description: "Call identifier" },
+    Parameter { name: "protocol", type_: "callProtocol", description: "The call protocols supported by the application" },
+  ] },
+  Function { name: "acceptTermsOfService", description: "Accepts Telegram terms of services", result: "Ok", parameters: &[
+    Parameter { name: "terms_of_service_id", type_: "string", description: "Terms of service identifier" },
+  ] },
+  Function { name: "addChatMember", description: "Adds a new member to a chat. Members can't be added to private or secret chats", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user" },
+    Parameter { name: "forward_limit", type_: "int32", description: "The number of earlier messages from the chat to be forwarded to the new member; up to 100. Ignored for supergroups and channels, or if the added user is a bot" },
+  ] },
+  Function { name: "addChatMembers", description: "Adds multiple new members to a chat. Currently this method is only available for supergroups and channels. This method can't be used to join a chat. Members can't be added to a channel if it has more than 200 members", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "Identifiers of the users to be added to the chat. The maximum number of added users is 20 for supergroups and 100 for channels" },
+  ] },
+  Function { name: "addChatToList", description: "Adds a chat to a chat list. A chat can't be simultaneously in Main and Archive chat lists, so it is automatically removed from another one if needed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "chat_list", type_: "ChatList", description: "The chat list. Use getChatListsToAddChat to get suitable chat lists" },
+  ] },
+  Function { name: "addContact", description: "Adds a user to the contact list or edits an existing contact by their user identifier", result: "Ok", parameters: &[
+    Parameter { name: "contact", type_: "contact", description: "The contact to add or edit; phone number can be empty and needs to be specified only if known, vCard is ignored" },
+    Parameter { name: "share_phone_number", type_: "Bool", description: "True, if the new contact needs to be allowed to see current user's phone number. A corresponding rule to userPrivacySettingShowPhoneNumber will be added if needed. Use the field userFullInfo.need_phone_number_privacy_exception to check whether the current user needs to be asked to share their phone number" },
+  ] },
+  Function { name: "addCustomServerLanguagePack", description: "Adds a custom server language pack to the list of installed language packs in current localization target. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "language_pack_id", type_: "string", description: "Identifier of a language pack to be added; may be different from a name that is used in an \"https://t.me/setlanguage/\" link" },
+  ] },
+  Function { name: "addFavoriteSticker", description: "Adds a new sticker to the list of favorite stickers. The new sticker is added to the top of the list. If the sticker was already in the list, it is removed from the list first. Only stickers belonging to a sticker set can be added to this list", result: "Ok", parameters: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker file to add" },
+  ] },
+  Function { name: "addLocalMessage", description: "Adds a local message to a chat. The message is persistent across application restarts only if the message database is used. Returns the added message", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Target chat" },
+    Parameter { name: "sender", type_: "MessageSender", description: "The sender of the message" },
+    Parameter { name: "reply_to_message_id", type_: "int53", description: "Identifier of the message to reply to or 0" },
+    Parameter { name: "disable_notification", type_: "Bool", description: "Pass true to disable notification for the message" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be added" },
+  ] },
+  Function { name: "addLogMessage", description: "Adds a message to TDLib internal log. Can be called synchronously", result: "Ok", parameters: &[
+    Parameter { name: "verbosity_level", type_: "int32", description: "The minimum verbosity level needed for the message to be logged; 0-1023" },
+    Parameter { name: "text", type_: "string", description: "Text of a message to log" },
+  ] },
+  Function { name: "addNetworkStatistics", description: "Adds the specified data to data usage statistics. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "entry", type_: "NetworkStatisticsEntry", description: "The network statistics entry with the data to be added to statistics" },
+  ] },
+  Function { name: "addProxy", description: "Adds a proxy server for network requests. Can be called before authorization", result: "Proxy", parameters: &[
+    Parameter { name: "server", type_: "string", description: "Proxy server IP address" },
+    Parameter { name: "port", type_: "int32", description: "Proxy server port" },
+    Parameter { name: "enable", type_: "Bool", description: "True, if the proxy needs to be enabled" },
+    Parameter { name: "type", type_: "ProxyType", description: "Proxy type" },
+  ] },
+  Function { name: "addRecentSticker", description: "Manually adds a new sticker to the list of recently used stickers. The new sticker is added to the top of the list. If the sticker was already in the list, it is removed from the list first. Only stickers belonging to a sticker set can be added to this list", result: "Stickers", parameters: &[
+    Parameter { name: "is_attached", type_: "Bool", description: "Pass true to add the sticker to the list of stickers recently attached to photo or video files; pass false to add the sticker to the list of recently sent stickers" },
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker file to add" },
+  ] },
+  Function { name: "addRecentlyFoundChat", description: "Adds a chat to the list of recently found chats. The chat is added to the beginning of the list. If the chat is already in the list, it will be removed from the list first", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to add" },
+  ] },
+  Function { name: "addSavedAnimation", description: "Manually adds a new animation to the list of saved animations. The new animation is added to the beginning of the list. If the animation was already in the list, it is removed first. Only non-secret video animations with MIME type \"video/mp4\" can be added to the list", result: "Ok", parameters: &[
+    Parameter { name: "animation", type_: "InputFile", description: "The animation file to be added. Only animations known to the server (i.e., successfully sent via a message) can be added to the list" },
+  ] },
+  Function { name: "addStickerToSet", description: "Adds a new sticker to a set; for bots only. Returns the sticker set", result: "StickerSet", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "Sticker set owner" },
+    Parameter { name: "name", type_: "string", description: "Sticker set name" },
+    Parameter { name: "sticker", type_: "InputSticker", description: "Sticker to add to the set" },
+  ] },
+  Function { name: "answerCallbackQuery", description: "Sets the result of a callback query; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "callback_query_id", type_: "int64", description: "Identifier of the callback query" },
+    Parameter { name: "text", type_: "string", description: "Text of the answer" },
+    Parameter { name: "show_alert", type_: "Bool", description: "If true, an alert must be shown to the user instead of a toast notification" },
+    Parameter { name: "url", type_: "string", description: "URL to be opened" },
+    Parameter { name: "cache_time", type_: "int32", description: "Time during which the result of the query can be cached, in seconds" },
+  ] },
+  Function { name: "answerCustomQuery", description: "Answers a custom query; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "custom_query_id", type_: "int64", description: "Identifier of a custom query" },
+    Parameter { name: "data", type_: "string", description: "JSON-serialized answer to the query" },
+  ] },
+  Function { name: "answerInlineQuery", description: "Sets the result of an inline query; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "inline_query_id", type_: "int64", description: "Identifier of the inline query" },
+    Parameter { name: "is_personal", type_: "Bool", description: "True, if the result of the query can be cached for the specified user" },
+    Parameter { name: "results", type_: "vector<InputInlineQueryResult>", description: "The results of the query" },
+    Parameter { name: "cache_time", type_: "int32", description: "Allowed time to cache the results of the query, in seconds" },
+    Parameter { name: "next_offset", type_: "string", description: "Offset for the next inline query; pass an empty string if there are no more results" },
+    Parameter { name: "switch_pm_text", type_: "string", description: "If non-empty, this text must be shown on the button that opens a private chat with the bot and sends a start message to the bot with the parameter switch_pm_parameter" },
+    Parameter { name: "switch_pm_parameter", type_: "string", description: "The parameter for the bot start message" },
+  ] },
+  Function { name: "answerPreCheckoutQuery", description: "Sets the result of a pre-checkout query; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "pre_checkout_query_id", type_: "int64", description: "Identifier of the pre-checkout query" },
+    Parameter { name: "error_message", type_: "string", description: "An error message, empty on success" },
+  ] },
+  Function { name: "answerShippingQuery", description: "Sets the result of a shipping query; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "shipping_query_id", type_: "int64", description: "Identifier of the shipping query" },
+    Parameter { name: "shipping_options", type_: "vector<shippingOption>", description: "Available shipping options" },
+    Parameter { name: "error_message", type_: "string", description: "An error message, empty on success" },
+  ] },
+  Function { name: "approveChatJoinRequest", description: "Approves pending join request in a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user, which request will be approved" },
+  ] },
+  Function { name: "banChatMember", description: "Bans a member in a chat. Members can't be banned in private or secret chats. In supergroups and channels, the user will not be able to return to the group on their own using invite links, etc., unless unbanned first", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "member_id", type_: "MessageSender", description: "Member identifier" },
+    Parameter { name: "banned_until_date", type_: "int32", description: "Point in time (Unix timestamp) when the user will be unbanned; 0 if never. If the user is banned for more than 366 days or for less than 30 seconds from the current time, the user is considered to be banned forever. Ignored in basic groups" },
+    Parameter { name: "revoke_messages", type_: "Bool", description: "Pass true to delete all messages in the chat for the user that is being removed. Always true for supergroups and channels" },
+  ] },
+  Function { name: "blockMessageSenderFromReplies", description: "Blocks an original sender of a message in the Replies chat", result: "Ok", parameters: &[
+    Parameter { name: "message_id", type_: "int53", description: "The identifier of an incoming message in the Replies chat" },
+    Parameter { name: "delete_message", type_: "Bool", description: "Pass true if the message must be deleted" },
+    Parameter { name: "delete_all_messages", type_: "Bool", description: "Pass true if all messages from the same sender must be deleted" },
+    Parameter { name: "report_spam", type_: "Bool", description: "Pass true if the sender must be reported to the Telegram moderators" },
+  ] },
+  Function { name: "canTransferOwnership", description: "Checks whether the current session can be used to transfer a chat ownership to another user", result: "CanTransferOwnershipResult", parameters: &[] },
+  Function { name: "cancelDownloadFile", description: "Stops the downloading of a file. If a file has already been downloaded, does nothing", result: "Ok", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of a file to stop downloading" },
+    Parameter { name: "only_if_pending", type_: "Bool", description: "Pass true to stop downloading only if it hasn't been started, i.e. request hasn't been sent to server" },
+  ] },
+  Function { name: "cancelPasswordReset", description: "Cancels reset of 2-step verification password. The method can be called if passwordState.pending_reset_date > 0", result: "Ok", parameters: &[] },
+  Function { name: "cancelUploadFile", description: "Stops the uploading of a file. Supported only for files uploaded by using uploadFile. For other files the behavior is undefined", result: "Ok", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the file to stop uploading" },
+  ] },
+  Function { name: "changeImportedContacts", description: "Changes imported contacts using the list of contacts saved on the device. Imports newly added contacts and, if at least the file database is enabled, deletes recently deleted contacts. Query result depends on the result of the previous query, so only one query is possible at the same time", result: "ImportedContacts", parameters: &[
+    Parameter { name: "contacts", type_: "vector<contact>", description: "The new list of contacts, contact's vCard are ignored and are not imported" },
+  ] },
+  Function { name: "changePhoneNumber", description: "Changes the phone number of the user and sends an authentication code to the user's new phone number. On success, returns information about the sent code", result: "AuthenticationCodeInfo", parameters: &[
+    Parameter { name: "phone_number", type_: "string", description: "The new phone number of the user in international format" },
+    Parameter { name: "settings", type_: "phoneNumberAuthenticationSettings", description: "Settings for the authentication of the user's phone number; pass null to use default settings" },
+  ] },
+  Function { name: "changeStickerSet", description: "Installs/uninstalls or activates/archives a sticker set", result: "Ok", parameters: &[
+    Parameter { name: "set_id", type_: "int64", description: "Identifier of the sticker set" },
+    Parameter { name: "is_installed", type_: "Bool", description: "The new value of is_installed" },
+    Parameter { name: "is_archived", type_: "Bool", description: "The new value of is_archived. A sticker set can't be installed and archived simultaneously" },
+  ] },
+  Function { name: "checkAuthenticationBotToken", description: "Checks the authentication token of a bot; to log in as a bot. Works only when the current authorization state is authorizationStateWaitPhoneNumber. Can be used instead of setAuthenticationPhoneNumber and checkAuthenticationCode to log in", result: "Ok", parameters: &[
+    Parameter { name: "token", type_: "string", description: "The bot token" },
+  ] },
+  Function { name: "checkAuthenticationCode", description: "Checks the authentication code. Works only when the current authorization state is authorizationStateWaitCode", result: "Ok", parameters: &[
+    Parameter { name: "code", type_: "string", description: "The verification code received via SMS, Telegram message, phone call, or flash call" },
+  ] },
+  Function { name: "checkAuthenticationPassword", description: "Checks the authentication password for correctness. Works only when the current authorization state is authorizationStateWaitPassword", result: "Ok", parameters: &[
+    Parameter { name: "password", type_: "string", description: "The password to check" },
+  ] },
+  Function { name: "checkAuthenticationPasswordRecoveryCode", description: "Checks whether a password recovery code sent to an email address is valid. Works only when the current authorization state is authorizationStateWaitPassword", result: "Ok", parameters: &[
+    Parameter { name: "recovery_code", type_: "string", description: "Recovery code to check" },
+  ] },
+  Function { name: "checkChangePhoneNumberCode", description: "Checks the authentication code sent to confirm a new phone number of the user", result: "Ok", parameters: &[
+    Parameter { name: "code", type_: "string", description: "Verification code received by SMS, phone call or flash call" },
+  ] },
+  Function { name: "checkChatInviteLink", description: "Checks the validity of an invite link for a chat and returns information about the corresponding chat", result: "ChatInviteLinkInfo", parameters: &[
+    Parameter { name: "invite_link", type_: "string", description: "Invite link to be checked" },
+  ] },
+  Function { name: "checkChatUsername", description: "Checks whether a username can be set for a chat", result: "CheckChatUsernameResult", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier; must be identifier of a supergroup chat, or a channel chat, or a private chat with self, or zero if the chat is being created" },
+    Parameter { name: "username", type_: "string", description: "Username to be checked" },
+  ] },
+  Function { name: "checkCreatedPublicChatsLimit", description: "Checks whether the maximum number of owned public chats has been reached. Returns corresponding error if the limit was reached", result: "Ok", parameters: &[
+    Parameter { name: "type", type_: "PublicChatType", description: "Type of the public chats, for which to check the limit" },
+  ] },
+  Function { name: "checkDatabaseEncryptionKey", description: "Checks the database encryption key for correctness. Works only when the current authorization state is authorizationStateWaitEncryptionKey", result: "Ok", parameters: &[
+    Parameter { name: "encryption_key", type_: "bytes", description: "Encryption key to check or set up" },
+  ] },
+  Function { name: "checkEmailAddressVerificationCode", description: "Checks the email address verification code for Telegram Passport", result: "Ok", parameters: &[
+    Parameter { name: "code", type_: "string", description: "Verification code" },
+  ] },
+  Function { name: "checkPasswordRecoveryCode", description: "Checks whether a 2-step verification password recovery code sent to an email address is valid", result: "Ok", parameters: &[
+    Parameter { name: "recovery_code", type_: "string", description: "Recovery code to check" },
+  ] },
+  Function { name: "checkPhoneNumberConfirmationCode", description: "Checks phone number confirmation code", result: "Ok", parameters: &[
+    Parameter { name: "code", type_: "string", description: "The phone number confirmation code" },
+  ] },
+  Function { name: "checkPhoneNumberVerificationCode", description: "Checks the phone number verification code for Telegram Passport", result: "Ok", parameters: &[
+    Parameter { name: "code", type_: "string", description: "Verification code" },
+  ] },
+  Function { name: "checkRecoveryEmailAddressCode", description: "Checks the 2-step verification recovery email address verification code", result: "PasswordState", parameters: &[
+    Parameter { name: "code", type_: "string", description: "Verification code" },
+  ] },
+  Function { name: "checkStickerSetName", description: "Checks whether a name can be used for a new sticker set", result: "CheckStickerSetNameResult", parameters: &[
+    Parameter { name: "name", type_: "string", description: "Name to be checked" },
+  ] },
+  Function { name: "cleanFileName", description: "Removes potentially dangerous characters from the name of a file. The encoding of the file name is supposed to be UTF-8. Returns an empty string on failure. Can be called synchronously", result: "Text", parameters: &[
+    Parameter { name: "file_name", type_: "string", description: "File name or path to the file" },
+  ] },
+  Function { name: "clearAllDraftMessages", description: "Clears draft messages in all chats", result: "Ok", parameters: &[
+    Parameter { name: "exclude_secret_chats", type_: "Bool", description: "If true, local draft messages in secret chats will not be cleared" },
+  ] },
+  Function { name: "clearImportedContacts", description: "Clears all imported contacts, contact list remains unchanged", result: "Ok", parameters: &[] },
+  Function { name: "clearRecentStickers", description: "Clears the list of recently used stickers", result: "Ok", parameters: &[
+    Parameter { name: "is_attached", type_: "Bool", description: "Pass true to clear the list of stickers recently attached to photo or video files; pass false to clear the list of recently sent stickers" },
+  ] },
+  Function { name: "clearRecentlyFoundChats", description: "Clears the list of recently found chats", result: "Ok", parameters: &[] },
+  Function { name: "clickAnimatedEmojiMessage", description: "Informs TDLib that a message with an animated emoji was clicked by the user. Returns a big animated sticker to be played or a 404 error if usual animation needs to be played", result: "Sticker", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the message" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the clicked message" },
+  ] },
+  Function { name: "close", description: "Closes the TDLib instance. All databases will be flushed to disk and properly closed. After the close completes, updateAuthorizationState with authorizationStateClosed will be sent. Can be called before initialization", result: "Ok", parameters: &[] },
+  Function { name: "closeChat", description: "Informs TDLib that the chat is closed by the user. Many useful activities depend on the chat being opened or closed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "closeSecretChat", description: "Closes a secret chat, effectively transferring its state to secretChatStateClosed", result: "Ok", parameters: &[
+    Parameter { name: "secret_chat_id", type_: "int53", description: "Secret chat identifier" },
+  ] },
+  Function { name: "confirmQrCodeAuthentication", description: "Confirms QR code authentication on another device. Returns created session on success", result: "Session", parameters: &[
+    Parameter { name: "link", type_: "string", description: "A link from a QR code. The link must be scanned by the in-app camera" },
+  ] },
+  Function { name: "createBasicGroupChat", description: "Returns an existing chat corresponding to a known basic group", result: "Chat", parameters: &[
+    Parameter { name: "basic_group_id", type_: "int53", description: "Basic group identifier" },
+    Parameter { name: "force", type_: "Bool", description: "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect" },
+  ] },
+  Function { name: "createCall", description: "Creates a new call", result: "CallId", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user to be called" },
+    Parameter { name: "protocol", type_: "callProtocol", description: "The call protocols supported by the application" },
+    Parameter { name: "is_video", type_: "Bool", description: "True, if a video call needs to be created" },
+  ] },
+  Function { name: "createChatFilter", description: "Creates new chat filter. Returns information about the created chat filter", result: "ChatFilterInfo", parameters: &[
+    Parameter { name: "filter", type_: "chatFilter", description: "Chat filter" },
+  ] },
+  Function { name: "createChatInviteLink", description: "Creates a new invite link for a chat. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right in the chat", result: "ChatInviteLink", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "name", type_: "string", description: "Invite link name; 0-32 characters" },
+    Parameter { name: "expire_date", type_: "int32", description: "Point in time (Unix timestamp) when the link will expire; pass 0 if never" },
+    Parameter { name: "member_limit", type_: "int32", description: "The maximum number of chat members that can join the chat by the link simultaneously; 0-99999; pass 0 if not limited" },
+    Parameter { name: "creates_join_request", type_: "Bool", description: "True, if the link only creates join request. If true, member_limit must not be specified" },
+  ] },
+  Function { name: "createNewBasicGroupChat", description: "Creates a new basic group and sends a corresponding messageBasicGroupChatCreate. Returns the newly created chat", result: "Chat", parameters: &[
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "Identifiers of users to be added to the basic group" },
+    Parameter { name: "title", type_: "string", description: "Title of the new basic group; 1-128 characters" },
+  ] },
+  Function { name: "createNewSecretChat", description: "Creates a new secret chat. Returns the newly created chat", result: "Chat", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the target user" },
+  ] },
+  Function { name: "createNewStickerSet", description: "Creates a new sticker set. Returns the newly created sticker set", result: "StickerSet", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "Sticker set owner; ignored for regular users" },
+    Parameter { name: "title", type_: "string", description: "Sticker set title; 1-64 characters" },
+    Parameter { name: "name", type_: "string", description: "Sticker set name. Can contain only English letters, digits and underscores. Must end with *\"_by_<bot username>\"* (*<bot_username>* is case insensitive) for bots; 1-64 characters" },
+    Parameter { name: "is_masks", type_: "Bool", description: "True, if stickers are masks. Animated stickers can't be masks" },
+    Parameter { name: "stickers", type_: "vector<InputSticker>", description: "List of stickers to be added to the set; must be non-empty. All stickers must be of the same type. For animated stickers, uploadStickerFile must be used before the sticker is shown" },
+    Parameter { name: "source", type_: "string", description: "Source of the sticker set; may be empty if unknown" },
+  ] },
+  Function { name: "createNewSupergroupChat", description: "Creates a new supergroup or channel and sends a corresponding messageSupergroupChatCreate. Returns the newly created chat", result: "Chat", parameters: &[
+    Parameter { name: "title", type_: "string", description: "Title of the new chat; 1-128 characters" },
+    Parameter { name: "is_channel", type_: "Bool", description: "True, if a channel chat needs to be created" },
+    Parameter { name: "description", type_: "string", description: "Chat description; 0-255 characters" },
+    Parameter { name: "location", type_: "chatLocation", description: "Chat location if a location-based supergroup is being created; pass null to create an ordinary supergroup chat" },
+    Parameter { name: "for_import", type_: "Bool", description: "True, if the supergroup is created for importing messages using importMessage" },
+  ] },
+  Function { name: "createPrivateChat", description: "Returns an existing chat corresponding to a given user", result: "Chat", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "force", type_: "Bool", description: "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect" },
+  ] },
+  Function { name: "createSecretChat", description: "Returns an existing chat corresponding to a known secret chat", result: "Chat", parameters: &[
+    Parameter { name: "secret_chat_id", type_: "int53", description: "Secret chat identifier" },
+  ] },
+  Function { name: "createSupergroupChat", description: "Returns an existing chat corresponding to a known supergroup or channel", result: "Chat", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Supergroup or channel identifier" },
+    Parameter { name: "force", type_: "Bool", description: "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect" },
+  ] },
+  Function { name: "createTemporaryPassword", description: "Creates a new temporary password for processing payments", result: "Ok", parameters: &[
+    Parameter { name: "password", type_: "string", description: "Persistent user password" },
+    Parameter { name: "valid_for", type_: "int32", description: "Time during which the temporary password will be valid, in seconds; must be between 60 and 86400" },
+  ] },
+  Function { name: "createVideoChat", description: "Creates a video chat (a group call bound to a chat). Available only for basic groups, supergroups and channels; requires can_manage_video_chats rights", result: "GroupCallId", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier, in which the video chat will be created" },
+    Parameter { name: "title", type_: "string", description: "Group call title; if empty, chat title will be used" },
+    Parameter { name: "start_date", type_: "int32", description: "Point in time (Unix timestamp) when the group call is supposed to be started by an administrator; 0 to start the video chat immediately. The date must be at least 10 seconds and at most 8 days in the future" },
+  ] },
+  Function { name: "declineChatJoinRequest", description: "Declines pending join request in a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user, which request will be declined" },
+  ] },
+  Function { name: "deleteAccount", description: "Deletes the account of the current user, deleting all information associated with the user from the server. The phone number of the account can be used to create a new account. Can be called before authorization when the current authorization state is authorizationStateWaitPassword", result: "Ok", parameters: &[
+    Parameter { name: "reason", type_: "string", description: "The reason why the account was deleted; optional" },
+  ] },
+  Function { name: "deleteAllCallMessages", description: "Deletes all call messages", result: "Ok", parameters: &[
+    Parameter { name: "revoke", type_: "Bool", description: "Pass true to delete the messages for all users" },
+  ] },
+  Function { name: "deleteAllRevokedChatInviteLinks", description: "Deletes all revoked chat invite links created by a given chat administrator. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "creator_user_id", type_: "int53", description: "User identifier of a chat administrator, which links will be deleted. Must be an identifier of the current user for non-owner" },
+  ] },
+  Function { name: "deleteChat", description: "Deletes a chat along with all messages in the corresponding chat for all chat members; requires owner privileges. For group chats this will release the username and remove all members. Chats with more than 1000 members can't be deleted using this method", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "deleteChatFilter", description: "Deletes existing chat filter", result: "Ok", parameters: &[
+    Parameter { name: "chat_filter_id", type_: "int32", description: "Chat filter identifier" },
+  ] },
+  Function { name: "deleteChatHistory", description: "Deletes all messages in the chat. Use chat.can_be_deleted_only_for_self and chat.can_be_deleted_for_all_users fields to find whether and how the method can be applied to the chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "remove_from_chat_list", type_: "Bool", description: "Pass true if the chat needs to be removed from the chat list" },
+    Parameter { name: "revoke", type_: "Bool", description: "Pass true to try to delete chat history for all users" },
+  ] },
+  Function { name: "deleteChatMessagesByDate", description: "Deletes all messages between the specified dates in a chat. Supported only for private chats and basic groups. Messages sent in the last 30 seconds will not be deleted", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "min_date", type_: "int32", description: "The minimum date of the messages to delete" },
+    Parameter { name: "max_date", type_: "int32", description: "The maximum date of the messages to delete" },
+    Parameter { name: "revoke", type_: "Bool", description: "Pass true to try to delete chat messages for all users; private chats only" },
+  ] },
+  Function { name: "deleteChatMessagesFromUser", description: "Deletes all messages sent by the specified user to a chat. Supported only for supergroups; requires can_delete_messages administrator privileges", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+  ] },
+  Function { name: "deleteChatReplyMarkup", description: "Deletes the default reply markup from a chat. Must be called after a one-time keyboard or a ForceReply reply markup has been used. UpdateChatReplyMarkup will be sent if the reply markup is changed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "The message identifier of the used keyboard" },
+  ] },
+  Function { name: "deleteCommands", description: "Deletes commands supported by the bot for the given user scope and language; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "scope", type_: "BotCommandScope", description: "The scope to which the commands are relevant; pass null to delete commands in the default bot command scope" },
+    Parameter { name: "language_code", type_: "string", description: "A two-letter ISO 639-1 country code or an empty string" },
+  ] },
+  Function { name: "deleteFile", description: "Deletes a file from the TDLib file cache", result: "Ok", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the file to delete" },
+  ] },
+  Function { name: "deleteLanguagePack", description: "Deletes all information about a language pack in the current localization target. The language pack which is currently in use (including base language pack) or is being synchronized can't be deleted. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "language_pack_id", type_: "string", description: "Identifier of the language pack to delete" },
+  ] },
+  Function { name: "deleteMessages", description: "Deletes messages", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "Identifiers of the messages to be deleted" },
+    Parameter { name: "revoke", type_: "Bool", description: "Pass true to try to delete messages for all chat members. Always true for supergroups, channels and secret chats" },
+  ] },
+  Function { name: "deletePassportElement", description: "Deletes a Telegram Passport element", result: "Ok", parameters: &[
+    Parameter { name: "type", type_: "PassportElementType", description: "Element type" },
+  ] },
+  Function { name: "deleteProfilePhoto", description: "Deletes a profile photo", result: "Ok", parameters: &[
+    Parameter { name: "profile_photo_id", type_: "int64", description: "Identifier of the profile photo to delete" },
+  ] },
+  Function { name: "deleteRevokedChatInviteLink", description: "Deletes revoked chat invite links. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "invite_link", type_: "string", description: "Invite link to revoke" },
+  ] },
+  Function { name: "deleteSavedCredentials", description: "Deletes saved credentials for all payment provider bots", result: "Ok", parameters: &[] },
+  Function { name: "deleteSavedOrderInfo", description: "Deletes saved order info", result: "Ok", parameters: &[] },
+  Function { name: "destroy", description: "Closes the TDLib instance, destroying all local data without a proper logout. The current user session will remain in the list of all active sessions. All local data will be destroyed. After the destruction completes updateAuthorizationState with authorizationStateClosed will be sent. Can be called before authorization", result: "Ok", parameters: &[] },
+  Function { name: "disableProxy", description: "Disables the currently enabled proxy. Can be called before authorization", result: "Ok", parameters: &[] },
+  Function { name: "discardCall", description: "Discards a call", result: "Ok", parameters: &[
+    Parameter { name: "call_id", type_: "int32", description: "Call identifier" },
+    Parameter { name: "is_disconnected", type_: "Bool", description: "True, if the user was disconnected" },
+    Parameter { name: "duration", type_: "int32", description: "The call duration, in seconds" },
+    Parameter { name: "is_video", type_: "Bool", description: "True, if the call was a video call" },
+    Parameter { name: "connection_id", type_: "int64", description: "Identifier of the connection used during the call" },
+  ] },
+  Function { name: "discardGroupCall", description: "Discards a group call. Requires groupCall.can_be_managed", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Function { name: "disconnectAllWebsites", description: "Disconnects all websites from the current user's Telegram account", result: "Ok", parameters: &[] },
+  Function { name: "disconnectWebsite", description: "Disconnects website from the current user's Telegram account", result: "Ok", parameters: &[
+    Parameter { name: "website_id", type_: "int64", description: "Website identifier" },
+  ] },
+  Function { name: "downloadFile", description: "Downloads a file from the cloud. Download progress and completion of the download will be notified through updateFile updates", result: "File", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the file to download" },
+    Parameter { name: "priority", type_: "int32", description: "Priority of the download (1-32). The higher the priority, the earlier the file will be downloaded. If the priorities of two files are equal, then the last one for which downloadFile was called will be downloaded first" },
+    Parameter { name: "offset", type_: "int32", description: "The starting position from which the file needs to be downloaded" },
+    Parameter { name: "limit", type_: "int32", description: "Number of bytes which need to be downloaded starting from the \"offset\" position before the download will be automatically canceled; use 0 to download without a limit" },
+    Parameter { name: "synchronous", type_: "Bool", description: "If false, this request returns file state just after the download has been started. If true, this request returns file state only after the download has succeeded, has failed, has been canceled or a new downloadFile request with different offset/limit parameters was sent" },
+  ] },
+  Function { name: "editChatFilter", description: "Edits existing chat filter. Returns information about the edited chat filter", result: "ChatFilterInfo", parameters: &[
+    Parameter { name: "chat_filter_id", type_: "int32", description: "Chat filter identifier" },
+    Parameter { name: "filter", type_: "chatFilter", description: "The edited chat filter" },
+  ] },
+  Function { name: "editChatInviteLink", description: "Edits a non-primary invite link for a chat. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links", result: "ChatInviteLink", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "invite_link", type_: "string", description: "Invite link to be edited" },
+    Parameter { name: "name", type_: "string", description: "Invite link name; 0-32 characters" },
+    Parameter { name: "expire_date", type_: "int32", description: "Point in time (Unix timestamp) when the link will expire; pass 0 if never" },
+    Parameter { name: "member_limit", type_: "int32", description: "The maximum number of chat members that can join the chat by the link simultaneously; 0-99999; pass 0 if not limited" },
+    Parameter { name: "creates_join_request", type_: "Bool", description: "True, if the link only creates join request. If true, member_limit must not be specified" },
+  ] },
+  Function { name: "editCustomLanguagePackInfo", description: "Edits information about a custom local language pack in the current localization target. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "info", type_: "languagePackInfo", description: "New information about the custom local language pack" },
+  ] },
+  Function { name: "editInlineMessageCaption", description: "Edits the caption of an inline message sent via a bot; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "inline_message_id", type_: "string", description: "Inline message identifier" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none" },
+    Parameter { name: "caption", type_: "formattedText", description: "New message content caption; pass null to remove caption; 0-GetOption(\"message_caption_length_max\") characters" },
+  ] },
+  Function { name: "editInlineMessageLiveLocation", description: "Edits the content of a live location in an inline message sent via a bot; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "inline_message_id", type_: "string", description: "Inline message identifier" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none" },
+    Parameter { name: "location", type_: "location", description: "New location content of the message; pass null to stop sharing the live location" },
+    Parameter { name: "heading", type_: "int32", description: "The new direction in which the location moves, in degrees; 1-360. Pass 0 if unknown" },
+    Parameter { name: "proximity_alert_radius", type_: "int32", description: "The new maximum distance for proximity alerts, in meters (0-100000). Pass 0 if the notification is disabled" },
+  ] },
+  Function { name: "editInlineMessageMedia", description: "Edits the content of a message with an animation, an audio, a document, a photo or a video in an inline message sent via a bot; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "inline_message_id", type_: "string", description: "Inline message identifier" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none; for bots only" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "New content of the message. Must be one of the following types: inputMessageAnimation, inputMessageAudio, inputMessageDocument, inputMessagePhoto or inputMessageVideo" },
+  ] },
+  Function { name: "editInlineMessageReplyMarkup", description: "Edits the reply markup of an inline message sent via a bot; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "inline_message_id", type_: "string", description: "Inline message identifier" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none" },
+  ] },
+  Function { name: "editInlineMessageText", description: "Edits the text of an inline text or game message sent via a bot; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "inline_message_id", type_: "string", description: "Inline message identifier" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "New text content of the message. Must be of type inputMessageText" },
+  ] },
+  Function { name: "editMessageCaption", description: "Edits the message content caption. Returns the edited message after the edit is completed on the server side", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none; for bots only" },
+    Parameter { name: "caption", type_: "formattedText", description: "New message content caption; 0-GetOption(\"message_caption_length_max\") characters; pass null to remove caption" },
+  ] },
+  Function { name: "editMessageLiveLocation", description: "Edits the message content of a live location. Messages can be edited for a limited period of time specified in the live location. Returns the edited message after the edit is completed on the server side", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none; for bots only" },
+    Parameter { name: "location", type_: "location", description: "New location content of the message; pass null to stop sharing the live location" },
+    Parameter { name: "heading", type_: "int32", description: "The new direction in which the location moves, in degrees; 1-360. Pass 0 if unknown" },
+    Parameter { name: "proximity_alert_radius", type_: "int32", description: "The new maximum distance for proximity alerts, in meters (0-100000). Pass 0 if the notification is disabled" },
+  ] },
+  Function { name: "editMessageMedia", description: "Edits the content of a message with an animation, an audio, a document, a photo or a video, including message caption. If only the caption needs to be edited, use editMessageCaption instead. The media can't be edited if the message was set to self-destruct or to a self-destructing media. The type of message content in an album can't be changed with exception of replacing a photo with a video or vice versa. Returns the edited message after the edit is completed on the server side", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none; for bots only" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "New content of the message. Must be one of the following types: inputMessageAnimation, inputMessageAudio, inputMessageDocument, inputMessagePhoto or inputMessageVideo" },
+  ] },
+  Function { name: "editMessageReplyMarkup", description: "Edits the message reply markup; for bots only. Returns the edited message after the edit is completed on the server side", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none" },
+  ] },
+  Function { name: "editMessageSchedulingState", description: "Edits the time when a scheduled message will be sent. Scheduling state of all messages in the same album or forwarded together with the message will be also changed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "scheduling_state", type_: "MessageSchedulingState", description: "The new message scheduling state; pass null to send the message immediately" },
+  ] },
+  Function { name: "editMessageText", description: "Edits the text of a message (or a text of a game message). Returns the edited message after the edit is completed on the server side", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none; for bots only" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "New text content of the message. Must be of type inputMessageText" },
+  ] },
+  Function { name: "editProxy", description: "Edits an existing proxy server for network requests. Can be called before authorization", result: "Proxy", parameters: &[
+    Parameter { name: "proxy_id", type_: "int32", description: "Proxy identifier" },
+    Parameter { name: "server", type_: "string", description: "Proxy server IP address" },
+    Parameter { name: "port", type_: "int32", description: "Proxy server port" },
+    Parameter { name: "enable", type_: "Bool", description: "True, if the proxy needs to be enabled" },
+    Parameter { name: "type", type_: "ProxyType", description: "Proxy type" },
+  ] },
+  Function { name: "enableProxy", description: "Enables a proxy. Only one proxy can be enabled at a time. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "proxy_id", type_: "int32", description: "Proxy identifier" },
+  ] },
+  Function { name: "endGroupCallRecording", description: "Ends recording of an active group call. Requires groupCall.can_be_managed group call flag", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Function { name: "endGroupCallScreenSharing", description: "Ends screen sharing in a joined group call", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Function { name: "finishFileGeneration", description: "Finishes the file generation", result: "Ok", parameters: &[
+    Parameter { name: "generation_id", type_: "int64", description: "The identifier of the generation process" },
+    Parameter { name: "error", type_: "error", description: "If passed, the file generation has failed and must be terminated; pass null if the file generation succeeded" },
+  ] },
+  Function { name: "forwardMessages", description: "Forwards previously sent messages. Returns the forwarded messages in the same order as the message identifiers passed in message_ids. If a message can't be forwarded, null will be returned instead of the message", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to which to forward messages" },
+    Parameter { name: "from_chat_id", type_: "int53", description: "Identifier of the chat from which to forward messages" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "Identifiers of the messages to forward. Message identifiers must be in a strictly increasing order. At most 100 messages can be forwarded simultaneously" },
+    Parameter { name: "options", type_: "messageSendOptions", description: "Options to be used to send the messages; pass null to use default options" },
+    Parameter { name: "send_copy", type_: "Bool", description: "If true, content of the messages will be copied without reference to the original sender. Always true if the messages are forwarded to a secret chat or are local" },
+    Parameter { name: "remove_caption", type_: "Bool", description: "If true, media caption of message copies will be removed. Ignored if send_copy is false" },
+    Parameter { name: "only_preview", type_: "Bool", description: "If true, messages will not be forwarded and instead fake messages will be returned" },
+  ] },
+  Function { name: "getAccountTtl", description: "Returns the period of inactivity after which the account of the current user will automatically be deleted", result: "AccountTtl", parameters: &[] },
+  Function { name: "getActiveLiveLocationMessages", description: "Returns all active live locations that need to be updated by the application. The list is persistent across application restarts only if the message database is used", result: "Messages", parameters: &[] },
+  Function { name: "getActiveSessions", description: "Returns all active sessions of the current user", result: "Sessions", parameters: &[] },
+  Function { name: "getAllPassportElements", description: "Returns all available Telegram Passport elements", result: "PassportElements", parameters: &[
+    Parameter { name: "password", type_: "string", description: "Password of the current user" },
+  ] },
+  Function { name: "getAnimatedEmoji", description: "Returns an animated emoji corresponding to a given emoji. Returns a 404 error if the emoji has no animated emoji", result: "AnimatedEmoji", parameters: &[
+    Parameter { name: "emoji", type_: "string", description: "The emoji" },
+  ] },
+  Function { name: "getApplicationConfig", description: "Returns application config, provided by the server. Can be called before authorization", result: "JsonValue", parameters: &[] },
+  Function { name: "getApplicationDownloadLink", description: "Returns the link for downloading official Telegram application to be used when the current user invites friends to Telegram", result: "HttpUrl", parameters: &[] },
+  Function { name: "getArchivedStickerSets", description: "Returns a list of archived sticker sets", result: "StickerSets", parameters: &[
+    Parameter { name: "is_masks", type_: "Bool", description: "Pass true to return mask stickers sets; pass false to return ordinary sticker sets" },
+    Parameter { name: "offset_sticker_set_id", type_: "int64", description: "Identifier of the sticker set from which to return the result" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of sticker sets to return; up to 100" },
+  ] },
+  Function { name: "getAttachedStickerSets", description: "Returns a list of sticker sets attached to a file. Currently only photos and videos can have attached sticker sets", result: "StickerSets", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "File identifier" },
+  ] },
+  Function { name: "getAuthorizationState", description: "Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization", result: "AuthorizationState", parameters: &[] },
+  Function { name: "getAutoDownloadSettingsPresets", description: "Returns auto-download settings presets for the current user", result: "AutoDownloadSettingsPresets", parameters: &[] },
+  Function { name: "getBackgroundUrl", description: "Constructs a persistent HTTP URL for a background", result: "HttpUrl", parameters: &[
+    Parameter { name: "name", type_: "string", description: "Background name" },
+    Parameter { name: "type", type_: "BackgroundType", description: "Background type" },
+  ] },
+  Function { name: "getBackgrounds", description: "Returns backgrounds installed by the user", result: "Backgrounds", parameters: &[
+    Parameter { name: "for_dark_theme", type_: "Bool", description: "True, if the backgrounds must be ordered for dark theme" },
+  ] },
+  Function { name: "getBankCardInfo", description: "Returns information about a bank card", result: "BankCardInfo", parameters: &[
+    Parameter { name: "bank_card_number", type_: "string", description: "The bank card number" },
+  ] },
+  Function { name: "getBasicGroup", description: "Returns information about a basic group by its identifier. This is an offline request if the current user is not a bot", result: "BasicGroup", parameters: &[
+    Parameter { name: "basic_group_id", type_: "int53", description: "Basic group identifier" },
+  ] },
+  Function { name: "getBasicGroupFullInfo", description: "Returns full information about a basic group by its identifier", result: "BasicGroupFullInfo", parameters: &[
+    Parameter { name: "basic_group_id", type_: "int53", description: "Basic group identifier" },
+  ] },
+  Function { name: "getBlockedMessageSenders", description: "Returns users and chats that were blocked by the current user", result: "MessageSenders", parameters: &[
+    Parameter { name: "offset", type_: "int32", description: "Number of users and chats to skip in the result; must be non-negative" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of users and chats to return; up to 100" },
+  ] },
+  Function { name: "getCallbackQueryAnswer", description: "Sends a callback query to a bot and returns an answer. Returns an error with code 502 if the bot fails to answer the query before the query timeout expires", result: "CallbackQueryAnswer", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat with the message" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message from which the query originated" },
+    Parameter { name: "payload", type_: "CallbackQueryPayload", description: "Query payload" },
+  ] },
+  Function { name: "getCallbackQueryMessage", description: "Returns information about a message with the callback button that originated a callback query; for bots only", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "callback_query_id", type_: "int64", description: "Identifier of the callback query" },
+  ] },
+  Function { name: "getChat", description: "Returns information about a chat by its identifier, this is an offline request if the current user is not a bot", result: "Chat", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "getChatAdministrators", description: "Returns a list of administrators of the chat with their custom titles", result: "ChatAdministrators", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "getChatEventLog", description: "Returns a list of service actions taken by chat members and administrators in the last 48 hours. Available only for supergroups and channels. Requires administrator rights. Returns results in reverse chronological order (i. e., in order of decreasing event_id)", result: "ChatEvents", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "query", type_: "string", description: "Search query by which to filter events" },
+    Parameter { name: "from_event_id", type_: "int64", description: "Identifier of an event from which to return results. Use 0 to get results from the latest events" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of events to return; up to 100" },
+    Parameter { name: "filters", type_: "chatEventLogFilters", description: "The types of events to return; pass null to get chat events of all types" },
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "User identifiers by which to filter events. By default, events relating to all users will be returned" },
+  ] },
+  Function { name: "getChatFilter", description: "Returns information about a chat filter by its identifier", result: "ChatFilter", parameters: &[
+    Parameter { name: "chat_filter_id", type_: "int32", description: "Chat filter identifier" },
+  ] },
+  Function { name: "getChatFilterDefaultIconName", description: "Returns default icon name for a filter. Can be called synchronously", result: "Text", parameters: &[
+    Parameter { name: "filter", type_: "chatFilter", description: "Chat filter" },
+  ] },
+  Function { name: "getChatHistory", description: "Returns messages in a chat. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id). For optimal performance, the number of returned messages is chosen by TDLib. This is an offline request if only_local is true", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "from_message_id", type_: "int53", description: "Identifier of the message starting from which history must be fetched; use 0 to get results from the last message" },
+    Parameter { name: "offset", type_: "int32", description: "Specify 0 to get results from exactly the from_message_id or a negative offset up to 99 to get additionally some newer messages" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned; must be positive and can't be greater than 100. If the offset is negative, the limit must be greater than or equal to offset. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit" },
+    Parameter { name: "only_local", type_: "Bool", description: "If true, returns only messages that are available locally without sending network requests" },
+  ] },
+  Function { name: "getChatInviteLink", description: "Returns information about an invite link. Requires administrator privileges and can_invite_users right in the chat to get own links and owner privileges to get other links", result: "ChatInviteLink", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "invite_link", type_: "string", description: "Invite link to get" },
+  ] },
+  Function { name: "getChatInviteLinkCounts", description: "Returns list of chat administrators with number of their invite links. Requires owner privileges in the chat", result: "ChatInviteLinkCounts", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "getChatInviteLinkMembers", description: "Returns chat members joined a chat by an invite link. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links", result: "ChatInviteLinkMembers", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "invite_link", type_: "string", description: "Invite link for which to return chat members" },
+    Parameter { name: "offset_member", type_: "chatInviteLinkMember", description: "A chat member from which to return next chat members; pass null to get results from the beginning" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chat members to return; up to 100" },
+  ] },
+  Function { name: "getChatInviteLinks", description: "Returns invite links for a chat created by specified administrator. Requires administrator privileges and can_invite_users right in the chat to get own links and owner privileges to get other links", result: "ChatInviteLinks", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "creator_user_id", type_: "int53", description: "User identifier of a chat administrator. Must be an identifier of the current user for non-owner" },
+    Parameter { name: "is_revoked", type_: "Bool", description: "Pass true if revoked links needs to be returned instead of active or expired" },
+    Parameter { name: "offset_date", type_: "int32", description: "Creation date of an invite link starting after which to return invite links; use 0 to get results from the beginning" },
+    Parameter { name: "offset_invite_link", type_: "string", description: "Invite link starting after which to return invite links; use empty string to get results from the beginning" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of invite links to return; up to 100" },
+  ] },
+  Function { name: "getChatJoinRequests", description: "Returns pending join requests in a chat", result: "ChatJoinRequests", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "invite_link", type_: "string", description: "Invite link for which to return join requests. If empty, all join requests will be returned. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links" },
+    Parameter { name: "query", type_: "string", description: "A query to search for in the first names, last names and usernames of the users to return" },
+    Parameter { name: "offset_request", type_: "chatJoinRequest", description: "A chat join request from which to return next requests; pass null to get results from the beginning" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chat join requests to return" },
+  ] },
+  Function { name: "getChatListsToAddChat", description: "Returns chat lists to which the chat can be added. This is an offline request", result: "ChatLists", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "getChatMember", description: "Returns information about a single member of a chat", result: "ChatMember", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "member_id", type_: "MessageSender", description: "Member identifier" },
+  ] },
+  Function { name: "getChatMessageByDate", description: "Returns the last message sent in a chat no later than the specified date", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "date", type_: "int32", description: "Point in time (Unix timestamp) relative to which to search for messages" },
+  ] },
+  Function { name: "getChatMessageCalendar", description: "Returns information about the next messages of the specified type in the chat splitted by days. Returns the results in reverse chronological order. Can return partial result for the last returned day. Behavior of this method depends on the value of the option \"utc_time_offset\"", result: "MessageCalendar", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat in which to return information about messages" },
+    Parameter { name: "filter", type_: "SearchMessagesFilter", description: "Filter for message content. Filters searchMessagesFilterEmpty, searchMessagesFilterCall, searchMessagesFilterMissedCall, searchMessagesFilterMention and searchMessagesFilterUnreadMention are unsupported in this function" },
+    Parameter { name: "from_message_id", type_: "int53", description: "The message identifier from which to return information about messages; use 0 to get results from the last message" },
+  ] },
+  Function { name: "getChatMessageCount", description: "Returns approximate number of messages of the specified type in the chat", result: "Count", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat in which to count messages" },
+    Parameter { name: "filter", type_: "SearchMessagesFilter", description: "Filter for message content; searchMessagesFilterEmpty is unsupported in this function" },
+    Parameter { name: "return_local", type_: "Bool", description: "If true, returns count that is available locally without sending network requests, returning 1 if the number of messages is unknown" },
+  ] },
+  Function { name: "getChatNotificationSettingsExceptions", description: "Returns list of chats with non-default notification settings", result: "Chats", parameters: &[
+    Parameter { name: "scope", type_: "NotificationSettingsScope", description: "If specified, only chats from the scope will be returned; pass null to return chats from all scopes" },
+    Parameter { name: "compare_sound", type_: "Bool", description: "If true, also chats with non-default sound will be returned" },
+  ] },
+  Function { name: "getChatPinnedMessage", description: "Returns information about a newest pinned message in the chat", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat the message belongs to" },
+  ] },
+  Function { name: "getChatScheduledMessages", description: "Returns all scheduled messages in a chat. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id)", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "getChatSparseMessagePositions", description: "Returns sparse positions of messages of the specified type in the chat to be used for shared media scroll implementation. Returns the results in reverse chronological order (i.e., in order of decreasing message_id). Cannot be used in secret chats or with searchMessagesFilterFailedToSend filter without an enabled message database", result: "MessagePositions", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat in which to return information about message positions" },
+    Parameter { name: "filter", type_: "SearchMessagesFilter", description: "Filter for message content. Filters searchMessagesFilterEmpty, searchMessagesFilterCall, searchMessagesFilterMissedCall, searchMessagesFilterMention and searchMessagesFilterUnreadMention are unsupported in this function" },
+    Parameter { name: "from_message_id", type_: "int53", description: "The message identifier from which to return information about message positions" },
+    Parameter { name: "limit", type_: "int32", description: "The expected number of message positions to be returned; 50-2000. A smaller number of positions can be returned, if there are not enough appropriate messages" },
+  ] },
+  Function { name: "getChatSponsoredMessages", description: "Returns sponsored messages to be shown in a chat; for channel chats only", result: "SponsoredMessages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat" },
+  ] },
+  Function { name: "getChatStatistics", description: "Returns detailed statistics about a chat. Currently this method can be used only for supergroups and channels. Can be used only if supergroupFullInfo.can_get_statistics == true", result: "ChatStatistics", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "is_dark", type_: "Bool", description: "Pass true if a dark theme is used by the application" },
+  ] },
+  Function { name: "getChats", description: "Returns an ordered list of chats from the beginning of a chat list. For informational purposes only. Use loadChats and updates processing instead to maintain chat lists in a consistent state", result: "Chats", parameters: &[
+    Parameter { name: "chat_list", type_: "ChatList", description: "The chat list in which to return chats; pass null to get chats from the main chat list" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chats to be returned" },
+  ] },
+  Function { name: "getCommands", description: "Returns the list of commands supported by the bot for the given user scope and language; for bots only", result: "BotCommands", parameters: &[
+    Parameter { name: "scope", type_: "BotCommandScope", description: "The scope to which the commands are relevant; pass null to get commands in the default bot command scope" },
+    Parameter { name: "language_code", type_: "string", description: "A two-letter ISO 639-1 country code or an empty string" },
+  ] },
+  Function { name: "getConnectedWebsites", description: "Returns all website where the current user used Telegram to log in", result: "ConnectedWebsites", parameters: &[] },
+  Function { name: "getContacts", description: "Returns all user contacts", result: "Users", parameters: &[] },
+  Function { name: "getCountries", description: "Returns information about existing countries. Can be called before authorization", result: "Countries", parameters: &[] },
+  Function { name: "getCountryCode", description: "Uses the current IP address to find the current country. Returns two-letter ISO 3166-1 alpha-2 country code. Can be called before authorization", result: "Text", parameters: &[] },
+  Function { name: "getCreatedPublicChats", description: "Returns a list of public chats of the specified type, owned by the user", result: "Chats", parameters: &[
+    Parameter { name: "type", type_: "PublicChatType", description: "Type of the public chats to return" },
+  ] },
+  Function { name: "getCurrentState", description: "Returns all updates needed to restore current TDLib state, i.e. all actual UpdateAuthorizationState/UpdateUser/UpdateNewChat and others. This is especially useful if TDLib is run in a separate process. Can be called before initialization", result: "Updates", parameters: &[] },
+  Function { name: "getDatabaseStatistics", description: "Returns database statistics", result: "DatabaseStatistics", parameters: &[] },
+  Function { name: "getDeepLinkInfo", description: "Returns information about a tg:// deep link. Use \"tg://need_update_for_some_feature\" or \"tg:some_unsupported_feature\" for testing. Returns a 404 error for unknown links. Can be called before authorization", result: "DeepLinkInfo", parameters: &[
+    Parameter { name: "link", type_: "string", description: "The link" },
+  ] },
+  Function { name: "getEmojiSuggestionsUrl", description: "Returns an HTTP URL which can be used to automatically log in to the translation platform and suggest new emoji replacements. The URL will be valid for 30 seconds after generation", result: "HttpUrl", parameters: &[
+    Parameter { name: "language_code", type_: "string", description: "Language code for which the emoji replacements will be suggested" },
+  ] },
+  Function { name: "getExternalLink", description: "Returns an HTTP URL which can be used to automatically authorize the current user on a website after clicking an HTTP link. Use the method getExternalLinkInfo to find whether a prior user confirmation is needed", result: "HttpUrl", parameters: &[
+    Parameter { name: "link", type_: "string", description: "The HTTP link" },
+    Parameter { name: "allow_write_access", type_: "Bool", description: "True, if the current user allowed the bot, returned in getExternalLinkInfo, to send them messages" },
+  ] },
+  Function { name: "getExternalLinkInfo", description: "Returns information about an action to be done when the current user clicks an external link. Don't use this method for links from secret chats if web page preview is disabled in secret chats", result: "LoginUrlInfo", parameters: &[
+    Parameter { name: "link", type_: "string", description: "The link" },
+  ] },
+  Function { name: "getFavoriteStickers", description: "Returns favorite stickers", result: "Stickers", parameters: &[] },
+  Function { name: "getFile", description: "Returns information about a file; this is an offline request", result: "File", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the file to get" },
+  ] },
+  Function { name: "getFileDownloadedPrefixSize", description: "Returns file downloaded prefix size from a given offset, in bytes", result: "Count", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the file" },
+    Parameter { name: "offset", type_: "int32", description: "Offset from which downloaded prefix size needs to be calculated" },
+  ] },
+  Function { name: "getFileExtension", description: "Returns the extension of a file, guessed by its MIME type. Returns an empty string on failure. Can be called synchronously", result: "Text", parameters: &[
+    Parameter { name: "mime_type", type_: "string", description: "The MIME type of the file" },
+  ] },
+  Function { name: "getFileMimeType", description: "Returns the MIME type of a file, guessed by its extension. Returns an empty string on failure. Can be called synchronously", result: "Text", parameters: &[
+    Parameter { name: "file_name", type_: "string", description: "The name of the file or path to the file" },
+  ] },
+  Function { name: "getGameHighScores", description: "Returns the high scores for a game and some part of the high score table in the range of the specified user; for bots only", result: "GameHighScores", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat that contains the message with the game" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+  ] },
+  Function { name: "getGroupCall", description: "Returns information about a group call", result: "GroupCall", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Function { name: "getGroupCallInviteLink", description: "Returns invite link to a video chat in a public chat", result: "HttpUrl", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "can_self_unmute", type_: "Bool", description: "Pass true if the invite link needs to contain an invite hash, passing which to joinGroupCall would allow the invited user to unmute themselves. Requires groupCall.can_be_managed group call flag" },
+  ] },
+  Function { name: "getGroupCallStreamSegment", description: "Returns a file with a segment of a group call stream in a modified OGG format for audio or MPEG-4 format for video", result: "FilePart", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "time_offset", type_: "int32", description: "Point in time when the stream segment begins; Unix timestamp in milliseconds" },
+    Parameter { name: "scale", type_: "int32", description: "Segment duration scale; 0-1. Segment's duration is 1000/(2**scale) milliseconds" },
+    Parameter { name: "channel_id", type_: "int32", description: "Identifier of an audio/video channel to get as received from tgcalls" },
+    Parameter { name: "video_quality", type_: "GroupCallVideoQuality", description: "Video quality as received from tgcalls; pass null to get the worst available quality" },
+  ] },
+  Function { name: "getGroupsInCommon", description: "Returns a list of common group chats with a given user. Chats are sorted by their type and creation date", result: "Chats", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "offset_chat_id", type_: "int53", description: "Chat identifier starting from which to return chats; use 0 for the first request" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chats to be returned; up to 100" },
+  ] },
+  Function { name: "getImportedContactCount", description: "Returns the total number of imported contacts", result: "Count", parameters: &[] },
+  Function { name: "getInactiveSupergroupChats", description: "Returns a list of recently inactive supergroups and channels. Can be used when user reaches limit on the number of joined supergroups and channels and receives CHANNELS_TOO_MUCH error", result: "Chats", parameters: &[] },
+  Function { name: "getInlineGameHighScores", description: "Returns game high scores and some part of the high score table in the range of the specified user; for bots only", result: "GameHighScores", parameters: &[
+    Parameter { name: "inline_message_id", type_: "string", description: "Inline message identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+  ] },
+  Function { name: "getInlineQueryResults", description: "Sends an inline query to a bot and returns its results. Returns an error with code 502 if the bot fails to answer the query before the query timeout expires", result: "InlineQueryResults", parameters: &[
+    Parameter { name: "bot_user_id", type_: "int53", description: "The identifier of the target bot" },
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat where the query was sent" },
+    Parameter { name: "user_location", type_: "location", description: "Location of the user; pass null if unknown or the bot doesn't need user's location" },
+    Parameter { name: "query", type_: "string", description: "Text of the query" },
+    Parameter { name: "offset", type_: "string", description: "Offset of the first entry to return" },
+  ] },
+  Function { name: "getInstalledStickerSets", description: "Returns a list of installed sticker sets", result: "StickerSets", parameters: &[
+    Parameter { name: "is_masks", type_: "Bool", description: "Pass true to return mask sticker sets; pass false to return ordinary sticker sets" },
+  ] },
+  Function { name: "getInternalLinkType", description: "Returns information about the type of an internal link. Returns a 404 error if the link is not internal. Can be called before authorization", result: "InternalLinkType", parameters: &[
+    Parameter { name: "link", type_: "string", description: "The link" },
+  ] },
+  Function { name: "getJsonString", description: "Converts a JsonValue object to corresponding JSON-serialized string. Can be called synchronously", result: "Text", parameters: &[
+    Parameter { name: "json_value", type_: "JsonValue", description: "The JsonValue object" },
+  ] },
+  Function { name: "getJsonValue", description: "Converts a JSON-serialized string to corresponding JsonValue object. Can be called synchronously", result: "JsonValue", parameters: &[
+    Parameter { name: "json", type_: "string", description: "The JSON-serialized string" },
+  ] },
+  Function { name: "getLanguagePackInfo", description: "Returns information about a language pack. Returned language pack identifier may be different from a provided one. Can be called before authorization", result: "LanguagePackInfo", parameters: &[
+    Parameter { name: "language_pack_id", type_: "string", description: "Language pack identifier" },
+  ] },
+  Function { name: "getLanguagePackString", description: "Returns a string stored in the local database from the specified localization target and language pack by its key. Returns a 404 error if the string is not found. Can be called synchronously", result: "LanguagePackStringValue", parameters: &[
+    Parameter { name: "language_pack_database_path", type_: "string", description: "Path to the language pack database in which strings are stored" },
+    Parameter { name: "localization_target", type_: "string", description: "Localization target to which the language pack belongs" },
+    Parameter { name: "language_pack_id", type_: "string", description: "Language pack identifier" },
+    Parameter { name: "key", type_: "string", description: "Language pack key of the string to be returned" },
+  ] },
+  Function { name: "getLanguagePackStrings", description: "Returns strings from a language pack in the current localization target by their keys. Can be called before authorization", result: "LanguagePackStrings", parameters: &[
+    Parameter { name: "language_pack_id", type_: "string", description: "Language pack identifier of the strings to be returned" },
+    Parameter { name: "keys", type_: "vector<string>", description: "Language pack keys of the strings to be returned; leave empty to request all available strings" },
+  ] },
+  Function { name: "getLocalizationTargetInfo", description: "Returns information about the current localization target. This is an offline request if only_local is true. Can be called before authorization", result: "LocalizationTargetInfo", parameters: &[
+    Parameter { name: "only_local", type_: "Bool", description: "If true, returns only locally available information without sending network requests" },
+  ] },
+  Function { name: "getLogStream", description: "Returns information about currently used log stream for internal logging of TDLib. Can be called synchronously", result: "LogStream", parameters: &[] },
+  Function { name: "getLogTagVerbosityLevel", description: "Returns current verbosity level for a specified TDLib internal log tag. Can be called synchronously", result: "LogVerbosityLevel", parameters: &[
+    Parameter { name: "tag", type_: "string", description: "Logging tag to change verbosity level" },
+  ] },
+  Function { name: "getLogTags", description: "Returns list of available TDLib internal log tags, for example, [\"actor\", \"binlog\", \"connections\", \"notifications\", \"proxy\"]. Can be called synchronously", result: "LogTags", parameters: &[] },
+  Function { name: "getLogVerbosityLevel", description: "Returns current verbosity level of the internal logging of TDLib. Can be called synchronously", result: "LogVerbosityLevel", parameters: &[] },
+  Function { name: "getLoginUrl", description: "Returns an HTTP URL which can be used to automatically authorize the user on a website after clicking an inline button of type inlineKeyboardButtonTypeLoginUrl. Use the method getLoginUrlInfo to find whether a prior user confirmation is needed. If an error is returned, then the button must be handled as an ordinary URL button", result: "HttpUrl", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the message with the button" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier of the message with the button" },
+    Parameter { name: "button_id", type_: "int32", description: "Button identifier" },
+    Parameter { name: "allow_write_access", type_: "Bool", description: "True, if the user allowed the bot to send them messages" },
+  ] },
+  Function { name: "getLoginUrlInfo", description: "Returns information about a button of type inlineKeyboardButtonTypeLoginUrl. The method needs to be called when the user presses the button", result: "LoginUrlInfo", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the message with the button" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier of the message with the button" },
+    Parameter { name: "button_id", type_: "int32", description: "Button identifier" },
+  ] },
+  Function { name: "getMapThumbnailFile", description: "Returns information about a file with a map thumbnail in PNG format. Only map thumbnail files with size less than 1MB can be downloaded", result: "File", parameters: &[
+    Parameter { name: "location", type_: "location", description: "Location of the map center" },
+    Parameter { name: "zoom", type_: "int32", description: "Map zoom level; 13-20" },
+    Parameter { name: "width", type_: "int32", description: "Map width in pixels before applying scale; 16-1024" },
+    Parameter { name: "height", type_: "int32", description: "Map height in pixels before applying scale; 16-1024" },
+    Parameter { name: "scale", type_: "int32", description: "Map scale; 1-3" },
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of a chat, in which the thumbnail will be shown. Use 0 if unknown" },
+  ] },
+  Function { name: "getMarkdownText", description: "Replaces text entities with Markdown formatting in a human-friendly format. Entities that can't be represented in Markdown unambiguously are kept as is. Can be called synchronously", result: "FormattedText", parameters: &[
+    Parameter { name: "text", type_: "formattedText", description: "The text" },
+  ] },
+  Function { name: "getMe", description: "Returns the current user", result: "User", parameters: &[] },
+  Function { name: "getMessage", description: "Returns information about a message", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message to get" },
+  ] },
+  Function { name: "getMessageEmbeddingCode", description: "Returns an HTML code for embedding the message. Available only for messages in supergroups and channels with a username", result: "Text", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to which the message belongs" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "for_album", type_: "Bool", description: "Pass true to return an HTML code for embedding of the whole media album" },
+  ] },
+  Function { name: "getMessageFileType", description: "Returns information about a file with messages exported from another app", result: "MessageFileType", parameters: &[
+    Parameter { name: "message_file_head", type_: "string", description: "Beginning of the message file; up to 100 first lines" },
+  ] },
+  Function { name: "getMessageImportConfirmationText", description: "Returns a confirmation text to be shown to the user before starting message import", result: "Text", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of a chat to which the messages will be imported. It must be an identifier of a private chat with a mutual contact or an identifier of a supergroup chat with can_change_info administrator right" },
+  ] },
+  Function { name: "getMessageLink", description: "Returns an HTTPS link to a message in a chat. Available only for already sent messages in supergroups and channels, or if message.can_get_media_timestamp_links and a media timestamp link is generated. This is an offline request", result: "MessageLink", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to which the message belongs" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "media_timestamp", type_: "int32", description: "If not 0, timestamp from which the video/audio/video note/voice note playing must start, in seconds. The media can be in the message content or in its web page preview" },
+    Parameter { name: "for_album", type_: "Bool", description: "Pass true to create a link for the whole media album" },
+    Parameter { name: "for_comment", type_: "Bool", description: "Pass true to create a link to the message as a channel post comment, or from a message thread" },
+  ] },
+  Function { name: "getMessageLinkInfo", description: "Returns information about a public or private message link. Can be called for any internal link of the type internalLinkTypeMessage", result: "MessageLinkInfo", parameters: &[
+    Parameter { name: "url", type_: "string", description: "The message link" },
+  ] },
+  Function { name: "getMessageLocally", description: "Returns information about a message, if it is available locally without sending network request. This is an offline request", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message to get" },
+  ] },
+  Function { name: "getMessagePublicForwards", description: "Returns forwarded copies of a channel message to different public channels. For optimal performance, the number of returned messages is chosen by TDLib", result: "FoundMessages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the message" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "offset", type_: "string", description: "Offset of the first entry to return as received from the previous request; use empty string to get first chunk of results" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned; must be positive and can't be greater than 100. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit" },
+  ] },
+  Function { name: "getMessageStatistics", description: "Returns detailed statistics about a message. Can be used only if message.can_get_statistics == true", result: "MessageStatistics", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "is_dark", type_: "Bool", description: "Pass true if a dark theme is used by the application" },
+  ] },
+  Function { name: "getMessageThread", description: "Returns information about a message thread. Can be used only if message.can_get_message_thread == true", result: "MessageThreadInfo", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+  ] },
+  Function { name: "getMessageThreadHistory", description: "Returns messages in a message thread of a message. Can be used only if message.can_get_message_thread == true. Message thread of a channel message is in the channel's linked supergroup. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id). For optimal performance, the number of returned messages is chosen by TDLib", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier, which thread history needs to be returned" },
+    Parameter { name: "from_message_id", type_: "int53", description: "Identifier of the message starting from which history must be fetched; use 0 to get results from the last message" },
+    Parameter { name: "offset", type_: "int32", description: "Specify 0 to get results from exactly the from_message_id or a negative offset up to 99 to get additionally some newer messages" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned; must be positive and can't be greater than 100. If the offset is negative, the limit must be greater than or equal to offset. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit" },
+  ] },
+  Function { name: "getMessageViewers", description: "Returns viewers of a recent outgoing message in a basic group or a supergroup chat. For video notes and voice notes only users, opened content of the message, are returned. The method can be called if message.can_get_viewers == true", result: "Users", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+  ] },
+  Function { name: "getMessages", description: "Returns information about messages. If a message is not found, returns null on the corresponding position of the result", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat the messages belong to" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "Identifiers of the messages to get" },
+  ] },
+  Function { name: "getNetworkStatistics", description: "Returns network data usage statistics. Can be called before authorization", result: "NetworkStatistics", parameters: &[
+    Parameter { name: "only_current", type_: "Bool", description: "If true, returns only data for the current library launch" },
+  ] },
+  Function { name: "getOption", description: "Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization", result: "OptionValue", parameters: &[
+    Parameter { name: "name", type_: "string", description: "The name of the option" },
+  ] },
+  Function { name: "getPassportAuthorizationForm", description: "Returns a Telegram Passport authorization form for sharing data with a service", result: "PassportAuthorizationForm", parameters: &[
+    Parameter { name: "bot_user_id", type_: "int53", description: "User identifier of the service's bot" },
+    Parameter { name: "scope", type_: "string", description: "Telegram Passport element types requested by the service" },
+    Parameter { name: "public_key", type_: "string", description: "Service's public key" },
+    Parameter { name: "nonce", type_: "string", description: "Unique request identifier provided by the service" },
+  ] },
+  Function { name: "getPassportAuthorizationFormAvailableElements", description: "Returns already available Telegram Passport elements suitable for completing a Telegram Passport authorization form. Result can be received only once for each authorization form", result: "PassportElementsWithErrors", parameters: &[
+    Parameter { name: "autorization_form_id", type_: "int32", description: "Authorization form identifier" },
+    Parameter { name: "password", type_: "string", description: "Password of the current user" },
+  ] },
+  Function { name: "getPassportElement", description: "Returns one of the available Telegram Passport elements", result: "PassportElement", parameters: &[
+    Parameter { name: "type", type_: "PassportElementType", description: "Telegram Passport element type" },
+    Parameter { name: "password", type_: "string", description: "Password of the current user" },
+  ] },
+  Function { name: "getPasswordState", description: "Returns the current state of 2-step verification", result: "PasswordState", parameters: &[] },
+  Function { name: "getPaymentForm", description: "Returns an invoice payment form. This method must be called when the user presses inlineKeyboardButtonBuy", result: "PaymentForm", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the Invoice message" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "theme", type_: "paymentFormTheme", description: "Preferred payment form theme; pass null to use the default theme" },
+  ] },
+  Function { name: "getPaymentReceipt", description: "Returns information about a successful payment", result: "PaymentReceipt", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the PaymentSuccessful message" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+  ] },
+  Function { name: "getPhoneNumberInfo", description: "Returns information about a phone number by its prefix. Can be called before authorization", result: "PhoneNumberInfo", parameters: &[
+    Parameter { name: "phone_number_prefix", type_: "string", description: "The phone number prefix" },
+  ] },
+  Function { name: "getPhoneNumberInfoSync", description: "Returns information about a phone number by its prefix synchronously. getCountries must be called at least once after changing localization to the specified language if properly localized country information is expected. Can be called synchronously", result: "PhoneNumberInfo", parameters: &[
+    Parameter { name: "language_code", type_: "string", description: "A two-letter ISO 639-1 country code for country information localization" },
+    Parameter { name: "phone_number_prefix", type_: "string", description: "The phone number prefix" },
+  ] },
+  Function { name: "getPollVoters", description: "Returns users voted for the specified option in a non-anonymous polls. For optimal performance, the number of returned users is chosen by TDLib", result: "Users", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to which the poll belongs" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message containing the poll" },
+    Parameter { name: "option_id", type_: "int32", description: "0-based identifier of the answer option" },
+    Parameter { name: "offset", type_: "int32", description: "Number of users to skip in the result; must be non-negative" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of users to be returned; must be positive and can't be greater than 50. For optimal performance, the number of returned users is chosen by TDLib and can be smaller than the specified limit, even if the end of the voter list has not been reached" },
+  ] },
+  Function { name: "getPreferredCountryLanguage", description: "Returns an IETF language tag of the language preferred in the country, which must be used to fill native fields in Telegram Passport personal details. Returns a 404 error if unknown", result: "Text", parameters: &[
+    Parameter { name: "country_code", type_: "string", description: "A two-letter ISO 3166-1 alpha-2 country code" },
+  ] },
+  Function { name: "getProxies", description: "Returns list of proxies that are currently set up. Can be called before authorization", result: "Proxies", parameters: &[] },
+  Function { name: "getProxyLink", description: "Returns an HTTPS link, which can be used to add a proxy. Available only for SOCKS5 and MTProto proxies. Can be called before authorization", result: "HttpUrl", parameters: &[
+    Parameter { name: "proxy_id", type_: "int32", description: "Proxy identifier" },
+  ] },
+  Function { name: "getPushReceiverId", description: "Returns a globally unique push notification subscription identifier for identification of an account, which has received a push notification. Can be called synchronously", result: "PushReceiverId", parameters: &[
+    Parameter { name: "payload", type_: "string", description: "JSON-encoded push notification payload" },
+  ] },
+  Function { name: "getRecentInlineBots", description: "Returns up to 20 recently used inline bots in the order of their last usage", result: "Users", parameters: &[] },
+  Function { name: "getRecentStickers", description: "Returns a list of recently used stickers", result: "Stickers", parameters: &[
+    Parameter { name: "is_attached", type_: "Bool", description: "Pass true to return stickers and masks that were recently attached to photos or video files; pass false to return recently sent stickers" },
+  ] },
+  Function { name: "getRecentlyOpenedChats", description: "Returns recently opened chats, this is an offline request. Returns chats in the order of last opening", result: "Chats", parameters: &[
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chats to be returned" },
+  ] },
+  Function { name: "getRecentlyVisitedTMeUrls", description: "Returns t.me URLs recently visited by a newly registered user", result: "TMeUrls", parameters: &[
+    Parameter { name: "referrer", type_: "string", description: "Google Play referrer to identify the user" },
+  ] },
+  Function { name: "getRecommendedChatFilters", description: "Returns recommended chat filters for the current user", result: "RecommendedChatFilters", parameters: &[] },
+  Function { name: "getRecoveryEmailAddress", description: "Returns a 2-step verification recovery email address that was previously set up. This method can be used to verify a password provided by the user", result: "RecoveryEmailAddress", parameters: &[
+    Parameter { name: "password", type_: "string", description: "The password for the current user" },
+  ] },
+  Function { name: "getRemoteFile", description: "Returns information about a file by its remote ID; this is an offline request. Can be used to register a URL as a file for further uploading, or sending as a message. Even the request succeeds, the file can be used only if it is still accessible to the user. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the application", result: "File", parameters: &[
+    Parameter { name: "remote_file_id", type_: "string", description: "Remote identifier of the file to get" },
+    Parameter { name: "file_type", type_: "FileType", description: "File type; pass null if unknown" },
+  ] },
+  Function { name: "getRepliedMessage", description: "Returns information about a message that is replied by a given message. Also returns the pinned message, the game message, and the invoice message for messages of the types messagePinMessage, messageGameScore, and messagePaymentSuccessful respectively", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat the message belongs to" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the reply message" },
+  ] },
+  Function { name: "getSavedAnimations", description: "Returns saved animations", result: "Animations", parameters: &[] },
+  Function { name: "getSavedOrderInfo", description: "Returns saved order info, if any", result: "OrderInfo", parameters: &[] },
+  Function { name: "getScopeNotificationSettings", description: "Returns the notification settings for chats of a given type", result: "ScopeNotificationSettings", parameters: &[
+    Parameter { name: "scope", type_: "NotificationSettingsScope", description: "Types of chats for which to return the notification settings information" },
+  ] },
+  Function { name: "getSecretChat", description: "Returns information about a secret chat by its identifier. This is an offline request", result: "SecretChat", parameters: &[
+    Parameter { name: "secret_chat_id", type_: "int53", description: "Secret chat identifier" },
+  ] },
+  Function { name: "getStatisticalGraph", description: "Loads an asynchronous or a zoomed in statistical graph", result: "StatisticalGraph", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "token", type_: "string", description: "The token for graph loading" },
+    Parameter { name: "x", type_: "int32", description: "X-value for zoomed in graph or 0 otherwise" },
+  ] },
+  Function { name: "getStickerEmojis", description: "Returns emoji corresponding to a sticker. The list is only for informational purposes, because a sticker is always sent with a fixed emoji from the corresponding Sticker object", result: "Emojis", parameters: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker file identifier" },
+  ] },
+  Function { name: "getStickerSet", description: "Returns information about a sticker set by its identifier", result: "StickerSet", parameters: &[
+    Parameter { name: "set_id", type_: "int64", description: "Identifier of the sticker set" },
+  ] },
+  Function { name: "getStickers", description: "Returns stickers from the installed sticker sets that correspond to a given emoji. If the emoji is non-empty, favorite and recently used stickers may also be returned", result: "Stickers", parameters: &[
+    Parameter { name: "emoji", type_: "string", description: "String representation of emoji. If empty, returns all known installed stickers" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of stickers to be returned" },
+  ] },
+  Function { name: "getStorageStatistics", description: "Returns storage usage statistics. Can be called before authorization", result: "StorageStatistics", parameters: &[
+    Parameter { name: "chat_limit", type_: "int32", description: "The maximum number of chats with the largest storage usage for which separate statistics need to be returned. All other chats will be grouped in entries with chat_id == 0. If the chat info database is not used, the chat_limit is ignored and is always set to 0" },
+  ] },
+  Function { name: "getStorageStatisticsFast", description: "Quickly returns approximate storage usage statistics. Can be called before authorization", result: "StorageStatisticsFast", parameters: &[] },
+  Function { name: "getSuggestedFileName", description: "Returns suggested name for saving a file in a given directory", result: "Text", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the file" },
+    Parameter { name: "directory", type_: "string", description: "Directory in which the file is supposed to be saved" },
+  ] },
+  Function { name: "getSuggestedStickerSetName", description: "Returns a suggested name for a new sticker set with a given title", result: "Text", parameters: &[
+    Parameter { name: "title", type_: "string", description: "Sticker set title; 1-64 characters" },
+  ] },
+  Function { name: "getSuitableDiscussionChats", description: "Returns a list of basic group and supergroup chats, which can be used as a discussion group for a channel. Returned basic group chats must be first upgraded to supergroups before they can be set as a discussion group. To set a returned supergroup as a discussion group, access to its old messages must be enabled using toggleSupergroupIsAllHistoryAvailable first", result: "Chats", parameters: &[] },
+  Function { name: "getSupergroup", description: "Returns information about a supergroup or a channel by its identifier. This is an offline request if the current user is not a bot", result: "Supergroup", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Supergroup or channel identifier" },
+  ] },
+  Function { name: "getSupergroupFullInfo", description: "Returns full information about a supergroup or a channel by its identifier, cached for up to 1 minute", result: "SupergroupFullInfo", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Supergroup or channel identifier" },
+  ] },
+  Function { name: "getSupergroupMembers", description: "Returns information about members or banned users in a supergroup or channel. Can be used only if supergroupFullInfo.can_get_members == true; additionally, administrator privileges may be required for some filters", result: "ChatMembers", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the supergroup or channel" },
+    Parameter { name: "filter", type_: "SupergroupMembersFilter", description: "The type of users to return; pass null to use supergroupMembersFilterRecent" },
+    Parameter { name: "offset", type_: "int32", description: "Number of users to skip" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of users be returned; up to 200" },
+  ] },
+  Function { name: "getSupportUser", description: "Returns a user that can be contacted to get support", result: "User", parameters: &[] },
+  Function { name: "getTemporaryPasswordState", description: "Returns information about the current temporary password", result: "TemporaryPasswordState", parameters: &[] },
+  Function { name: "getTextEntities", description: "Returns all entities (mentions, hashtags, cashtags, bot commands, bank card numbers, URLs, and email addresses) contained in the text. Can be called synchronously", result: "TextEntities", parameters: &[
+    Parameter { name: "text", type_: "string", description: "The text in which to look for entites" },
+  ] },
+  Function { name: "getTopChats", description: "Returns a list of frequently used chats. Supported only if the chat info database is enabled", result: "Chats", parameters: &[
+    Parameter { name: "category", type_: "TopChatCategory", description: "Category of chats to be returned" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chats to be returned; up to 30" },
+  ] },
+  Function { name: "getTrendingStickerSets", description: "Returns a list of trending sticker sets. For optimal performance, the number of returned sticker sets is chosen by TDLib", result: "StickerSets", parameters: &[
+    Parameter { name: "offset", type_: "int32", description: "The offset from which to return the sticker sets; must be non-negative" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of sticker sets to be returned; up to 100. For optimal performance, the number of returned sticker sets is chosen by TDLib and can be smaller than the specified limit, even if the end of the list has not been reached" },
+  ] },
+  Function { name: "getUser", description: "Returns information about a user by their identifier. This is an offline request if the current user is not a bot", result: "User", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+  ] },
+  Function { name: "getUserFullInfo", description: "Returns full information about a user by their identifier", result: "UserFullInfo", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+  ] },
+  Function { name: "getUserPrivacySettingRules", description: "Returns the current privacy settings", result: "UserPrivacySettingRules", parameters: &[
+    Parameter { name: "setting", type_: "UserPrivacySetting", description: "The privacy setting" },
+  ] },
+  Function { name: "getUserProfilePhotos", description: "Returns the profile photos of a user. The result of this query may be outdated: some photos might have been deleted already", result: "ChatPhotos", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "offset", type_: "int32", description: "The number of photos to skip; must be non-negative" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of photos to be returned; up to 100" },
+  ] },
+  Function { name: "getVideoChatAvailableParticipants", description: "Returns list of participant identifiers, which can be used to join video chats in a chat", result: "MessageSenders", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "getWebPageInstantView", description: "Returns an instant view version of a web page if available. Returns a 404 error if the web page has no instant view page", result: "WebPageInstantView", parameters: &[
+    Parameter { name: "url", type_: "string", description: "The web page URL" },
+    Parameter { name: "force_full", type_: "Bool", description: "If true, the full instant view for the web page will be returned" },
+  ] },
+  Function { name: "getWebPagePreview", description: "Returns a web page preview by the text of the message. Do not call this function too often. Returns a 404 error if the web page has no preview", result: "WebPage", parameters: &[
+    Parameter { name: "text", type_: "formattedText", description: "Message text with formatting" },
+  ] },
+  Function { name: "hideSuggestedAction", description: "Hides a suggested action", result: "Ok", parameters: &[
+    Parameter { name: "action", type_: "SuggestedAction", description: "Suggested action to hide" },
+  ] },
+  Function { name: "importContacts", description: "Adds new contacts or edits existing contacts by their phone numbers; contacts' user identifiers are ignored", result: "ImportedContacts", parameters: &[
+    Parameter { name: "contacts", type_: "vector<contact>", description: "The list of contacts to import or edit; contacts' vCard are ignored and are not imported" },
+  ] },
+  Function { name: "importMessages", description: "Imports messages exported from another app", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of a chat to which the messages will be imported. It must be an identifier of a private chat with a mutual contact or an identifier of a supergroup chat with can_change_info administrator right" },
+    Parameter { name: "message_file", type_: "InputFile", description: "File with messages to import. Only inputFileLocal and inputFileGenerated are supported. The file must not be previously uploaded" },
+    Parameter { name: "attached_files", type_: "vector<InputFile>", description: "Files used in the imported messages. Only inputFileLocal and inputFileGenerated are supported. The files must not be previously uploaded" },
+  ] },
+  Function { name: "inviteGroupCallParticipants", description: "Invites users to an active group call. Sends a service message of type messageInviteToGroupCall for video chats", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "User identifiers. At most 10 users can be invited simultaneously" },
+  ] },
+  Function { name: "joinChat", description: "Adds the current user as a new member to a chat. Private and secret chats can't be joined using this method", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "joinChatByInviteLink", description: "Uses an invite link to add the current user to the chat if possible", result: "Chat", parameters: &[
+    Parameter { name: "invite_link", type_: "string", description: "Invite link to use" },
+  ] },
+  Function { name: "joinGroupCall", description: "Joins an active group call. Returns join response payload for tgcalls", result: "Text", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Identifier of a group call participant, which will be used to join the call; pass null to join as self; video chats only" },
+    Parameter { name: "audio_source_id", type_: "int32", description: "Caller audio channel synchronization source identifier; received from tgcalls" },
+    Parameter { name: "payload", type_: "string", description: "Group call join payload; received from tgcalls" },
+    Parameter { name: "is_muted", type_: "Bool", description: "True, if the user's microphone is muted" },
+    Parameter { name: "is_my_video_enabled", type_: "Bool", description: "True, if the user's video is enabled" },
+    Parameter { name: "invite_hash", type_: "string", description: "If non-empty, invite hash to be used to join the group call without being muted by administrators" },
+  ] },
+  Function { name: "leaveChat", description: "Removes the current user from chat members. Private and secret chats can't be left using this method", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "leaveGroupCall", description: "Leaves a group call", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Function { name: "loadChats", description: "Loads more chats from a chat list. The loaded chats and their positions in the chat list will be sent through updates. Chats are sorted by the pair (chat.position.order, chat.id) in descending order. Returns a 404 error if all chats have been loaded", result: "Ok", parameters: &[
+    Parameter { name: "chat_list", type_: "ChatList", description: "The chat list in which to load chats; pass null to load chats from the main chat list" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chats to be loaded. For optimal performance, the number of loaded chats is chosen by TDLib and can be smaller than the specified limit, even if the end of the list is not reached" },
+  ] },
+  Function { name: "loadGroupCallParticipants", description: "Loads more participants of a group call. The loaded participants will be received through updates. Use the field groupCall.loaded_all_participants to check whether all participants have already been loaded", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier. The group call must be previously received through getGroupCall and must be joined or being joined" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of participants to load; up to 100" },
+  ] },
+  Function { name: "logOut", description: "Closes the TDLib instance after a proper logout. Requires an available network connection. All local data will be destroyed. After the logout completes, updateAuthorizationState with authorizationStateClosed will be sent", result: "Ok", parameters: &[] },
+  Function { name: "openChat", description: "Informs TDLib that the chat is opened by the user. Many useful activities depend on the chat being opened or closed (e.g., in supergroups and channels all updates are received only for opened chats)", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "openMessageContent", description: "Informs TDLib that the message content has been opened (e.g., the user has opened a photo, video, document, location or venue, or has listened to an audio file or voice note message). An updateMessageContentOpened update will be generated if something has changed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the message" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message with the opened content" },
+  ] },
+  Function { name: "optimizeStorage", description: "Optimizes storage usage, i.e. deletes some files and returns new storage usage statistics. Secret thumbnails can't be deleted", result: "StorageStatistics", parameters: &[
+    Parameter { name: "size", type_: "int32", description: "Limit on the total size of files after deletion, in bytes. Pass 1 to use the default limit" },
+    Parameter { name: "ttl", type_: "int32", description: "Limit on the time that has passed since the last time a file was accessed (or creation time for some filesystems). Pass 1 to use the default limit" },
+    Parameter { name: "count", type_: "int32", description: "Limit on the total count of files after deletion. Pass 1 to use the default limit" },
+    Parameter { name: "immunity_delay", type_: "int32", description: "The amount of time after the creation of a file during which it can't be deleted, in seconds. Pass 1 to use the default value" },
+    Parameter { name: "file_types", type_: "vector<FileType>", description: "If non-empty, only files with the given types are considered. By default, all types except thumbnails, profile photos, stickers and wallpapers are deleted" },
+    Parameter { name: "chat_ids", type_: "vector<int53>", description: "If non-empty, only files from the given chats are considered. Use 0 as chat identifier to delete files not belonging to any chat (e.g., profile photos)" },
+    Parameter { name: "exclude_chat_ids", type_: "vector<int53>", description: "If non-empty, files from the given chats are excluded. Use 0 as chat identifier to exclude all files not belonging to any chat (e.g., profile photos)" },
+    Parameter { name: "return_deleted_file_statistics", type_: "Bool", description: "Pass true if statistics about the files that were deleted must be returned instead of the whole storage usage statistics. Affects only returned statistics" },
+    Parameter { name: "chat_limit", type_: "int32", description: "Same as in getStorageStatistics. Affects only returned statistics" },
+  ] },
+  Function { name: "parseMarkdown", description: "Parses Markdown entities in a human-friendly format, ignoring markup errors. Can be called synchronously", result: "FormattedText", parameters: &[
+    Parameter { name: "text", type_: "formattedText", description: "The text to parse. For example, \"__italic__ ~~strikethrough~~ **bold** `code` ```pre``` __[italic__ text_url](telegram.org) __italic**bold italic__bold**\"" },
+  ] },
+  Function { name: "parseTextEntities", description: "Parses Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities contained in the text. Can be called synchronously", result: "FormattedText", parameters: &[
+    Parameter { name: "text", type_: "string", description: "The text to parse" },
+    Parameter { name: "parse_mode", type_: "TextParseMode", description: "Text parse mode" },
+  ] },
+  Function { name: "pinChatMessage", description: "Pins a message in a chat; requires can_pin_messages rights or can_edit_messages rights in the channel", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the new pinned message" },
+    Parameter { name: "disable_notification", type_: "Bool", description: "True, if there must be no notification about the pinned message. Notifications are always disabled in channels and private chats" },
+    Parameter { name: "only_for_self", type_: "Bool", description: "True, if the message needs to be pinned for one side only; private chats only" },
+  ] },
+  Function { name: "pingProxy", description: "Computes time needed to receive a response from a Telegram server through a proxy. Can be called before authorization", result: "Seconds", parameters: &[
+    Parameter { name: "proxy_id", type_: "int32", description: "Proxy identifier. Use 0 to ping a Telegram server without a proxy" },
+  ] },
+  Function { name: "processPushNotification", description: "Handles a push notification. Returns error with code 406 if the push notification is not supported and connection to the server is required to fetch new data. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "payload", type_: "string", description: "JSON-encoded push notification payload with all fields sent by the server, and \"google.sent_time\" and \"google.notification.sound\" fields added" },
+  ] },
+  Function { name: "readAllChatMentions", description: "Marks all mentions in a chat as read", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "readFilePart", description: "Reads a part of a file from the TDLib file cache and returns read bytes. This method is intended to be used only if the application has no direct access to TDLib's file system, because it is usually slower than a direct read from the file", result: "FilePart", parameters: &[
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the file. The file must be located in the TDLib file cache" },
+    Parameter { name: "offset", type_: "int32", description: "The offset from which to read the file" },
+    Parameter { name: "count", type_: "int32", description: "Number of bytes to read. An error will be returned if there are not enough bytes available in the file from the specified position. Pass 0 to read all available data from the specified position" },
+  ] },
+  Function { name: "recoverAuthenticationPassword", description: "Recovers the password with a password recovery code sent to an email address that was previously set up. Works only when the current authorization state is authorizationStateWaitPassword", result: "Ok", parameters: &[
+    Parameter { name: "recovery_code", type_: "string", description: "Recovery code to check" },
+    Parameter { name: "new_password", type_: "string", description: "New password of the user; may be empty to remove the password" },
+    Parameter { name: "new_hint", type_: "string", description: "New password hint; may be empty" },
+  ] },
+  Function { name: "recoverPassword", description: "Recovers the 2-step verification password using a recovery code sent to an email address that was previously set up", result: "PasswordState", parameters: &[
+    Parameter { name: "recovery_code", type_: "string", description: "Recovery code to check" },
+    Parameter { name: "new_password", type_: "string", description: "New password of the user; may be empty to remove the password" },
+    Parameter { name: "new_hint", type_: "string", description: "New password hint; may be empty" },
+  ] },
+  Function { name: "registerDevice", description: "Registers the currently used device for receiving push notifications. Returns a globally unique identifier of the push notification subscription", result: "PushReceiverId", parameters: &[
+    Parameter { name: "device_token", type_: "DeviceToken", description: "Device token" },
+    Parameter { name: "other_user_ids", type_: "vector<int53>", description: "List of user identifiers of other users currently using the application" },
+  ] },
+  Function { name: "registerUser", description: "Finishes user registration. Works only when the current authorization state is authorizationStateWaitRegistration", result: "Ok", parameters: &[
+    Parameter { name: "first_name", type_: "string", description: "The first name of the user; 1-64 characters" },
+    Parameter { name: "last_name", type_: "string", description: "The last name of the user; 0-64 characters" },
+  ] },
+  Function { name: "removeBackground", description: "Removes background from the list of installed backgrounds", result: "Ok", parameters: &[
+    Parameter { name: "background_id", type_: "int64", description: "The background identifier" },
+  ] },
+  Function { name: "removeChatActionBar", description: "Removes a chat action bar without any other action", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "removeContacts", description: "Removes users from the contact list", result: "Ok", parameters: &[
+    Parameter { name: "user_ids", type_: "vector<int53>", description: "Identifiers of users to be deleted" },
+  ] },
+  Function { name: "removeFavoriteSticker", description: "Removes a sticker from the list of favorite stickers", result: "Ok", parameters: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker file to delete from the list" },
+  ] },
+  Function { name: "removeNotification", description: "Removes an active notification from notification list. Needs to be called only if the notification is removed by the current user", result: "Ok", parameters: &[
+    Parameter { name: "notification_group_id", type_: "int32", description: "Identifier of notification group to which the notification belongs" },
+    Parameter { name: "notification_id", type_: "int32", description: "Identifier of removed notification" },
+  ] },
+  Function { name: "removeNotificationGroup", description: "Removes a group of active notifications. Needs to be called only if the notification group is removed by the current user", result: "Ok", parameters: &[
+    Parameter { name: "notification_group_id", type_: "int32", description: "Notification group identifier" },
+    Parameter { name: "max_notification_id", type_: "int32", description: "The maximum identifier of removed notifications" },
+  ] },
+  Function { name: "removeProxy", description: "Removes a proxy server. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "proxy_id", type_: "int32", description: "Proxy identifier" },
+  ] },
+  Function { name: "removeRecentHashtag", description: "Removes a hashtag from the list of recently used hashtags", result: "Ok", parameters: &[
+    Parameter { name: "hashtag", type_: "string", description: "Hashtag to delete" },
+  ] },
+  Function { name: "removeRecentSticker", description: "Removes a sticker from the list of recently used stickers", result: "Ok", parameters: &[
+    Parameter { name: "is_attached", type_: "Bool", description: "Pass true to remove the sticker from the list of stickers recently attached to photo or video files; pass false to remove the sticker from the list of recently sent stickers" },
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker file to delete" },
+  ] },
+  Function { name: "removeRecentlyFoundChat", description: "Removes a chat from the list of recently found chats", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to be removed" },
+  ] },
+  Function { name: "removeSavedAnimation", description: "Removes an animation from the list of saved animations", result: "Ok", parameters: &[
+    Parameter { name: "animation", type_: "InputFile", description: "Animation file to be removed" },
+  ] },
+  Function { name: "removeStickerFromSet", description: "Removes a sticker from the set to which it belongs; for bots only. The sticker set must have been created by the bot", result: "Ok", parameters: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker" },
+  ] },
+  Function { name: "removeTopChat", description: "Removes a chat from the list of frequently used chats. Supported only if the chat info database is enabled", result: "Ok", parameters: &[
+    Parameter { name: "category", type_: "TopChatCategory", description: "Category of frequently used chats" },
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "reorderChatFilters", description: "Changes the order of chat filters", result: "Ok", parameters: &[
+    Parameter { name: "chat_filter_ids", type_: "vector<int32>", description: "Identifiers of chat filters in the new correct order" },
+  ] },
+  Function { name: "reorderInstalledStickerSets", description: "Changes the order of installed sticker sets", result: "Ok", parameters: &[
+    Parameter { name: "is_masks", type_: "Bool", description: "Pass true to change the order of mask sticker sets; pass false to change the order of ordinary sticker sets" },
+    Parameter { name: "sticker_set_ids", type_: "vector<int64>", description: "Identifiers of installed sticker sets in the new correct order" },
+  ] },
+  Function { name: "replacePrimaryChatInviteLink", description: "Replaces current primary invite link for a chat with a new primary invite link. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right", result: "ChatInviteLink", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "reportChat", description: "Reports a chat to the Telegram moderators. A chat can be reported only from the chat action bar, or if this is a private chat with a bot, a private chat with a user sharing their location, a supergroup, or a channel, since other chats can't be checked by moderators", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "Identifiers of reported messages, if any" },
+    Parameter { name: "reason", type_: "ChatReportReason", description: "The reason for reporting the chat" },
+    Parameter { name: "text", type_: "string", description: "Additional report details; 0-1024 characters" },
+  ] },
+  Function { name: "reportChatPhoto", description: "Reports a chat photo to the Telegram moderators. A chat photo can be reported only if this is a private chat with a bot, a private chat with a user sharing their location, a supergroup, or a channel, since other chats can't be checked by moderators", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "file_id", type_: "int32", description: "Identifier of the photo to report. Only full photos from chatPhoto can be reported" },
+    Parameter { name: "reason", type_: "ChatReportReason", description: "The reason for reporting the chat photo" },
+    Parameter { name: "text", type_: "string", description: "Additional report details; 0-1024 characters" },
+  ] },
+  Function { name: "reportSupergroupSpam", description: "Reports some messages from a user in a supergroup as spam; requires administrator rights in the supergroup", result: "Ok", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Supergroup identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "Identifiers of messages sent in the supergroup by the user. This list must be non-empty" },
+  ] },
+  Function { name: "requestAuthenticationPasswordRecovery", description: "Requests to send a password recovery code to an email address that was previously set up. Works only when the current authorization state is authorizationStateWaitPassword", result: "Ok", parameters: &[] },
+  Function { name: "requestPasswordRecovery", description: "Requests to send a 2-step verification password recovery code to an email address that was previously set up", result: "EmailAddressAuthenticationCodeInfo", parameters: &[] },
+  Function { name: "requestQrCodeAuthentication", description: "Requests QR code authentication by scanning a QR code on another logged in device. Works only when the current authorization state is authorizationStateWaitPhoneNumber, or if there is no pending authentication query and the current authorization state is authorizationStateWaitCode, authorizationStateWaitRegistration, or authorizationStateWaitPassword", result: "Ok", parameters: &[
+    Parameter { name: "other_user_ids", type_: "vector<int53>", description: "List of user identifiers of other users currently using the application" },
+  ] },
+  Function { name: "resendAuthenticationCode", description: "Re-sends an authentication code to the user. Works only when the current authorization state is authorizationStateWaitCode, the next_code_type of the result is not null and the server-specified timeout has passed", result: "Ok", parameters: &[] },
+  Function { name: "resendChangePhoneNumberCode", description: "Re-sends the authentication code sent to confirm a new phone number for the current user. Works only if the previously received authenticationCodeInfo next_code_type was not null and the server-specified timeout has passed", result: "AuthenticationCodeInfo", parameters: &[] },
+  Function { name: "resendEmailAddressVerificationCode", description: "Re-sends the code to verify an email address to be added to a user's Telegram Passport", result: "EmailAddressAuthenticationCodeInfo", parameters: &[] },
+  Function { name: "resendMessages", description: "Resends messages which failed to send. Can be called only for messages for which messageSendingStateFailed.can_retry is true and after specified in messageSendingStateFailed.retry_after time passed. If a message is re-sent, the corresponding failed to send message is deleted. Returns the sent messages in the same order as the message identifiers passed in message_ids. If a message can't be re-sent, null will be returned instead of the message", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to send messages" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "Identifiers of the messages to resend. Message identifiers must be in a strictly increasing order" },
+  ] },
+  Function { name: "resendPhoneNumberConfirmationCode", description: "Resends phone number confirmation code", result: "AuthenticationCodeInfo", parameters: &[] },
+  Function { name: "resendPhoneNumberVerificationCode", description: "Re-sends the code to verify a phone number to be added to a user's Telegram Passport", result: "AuthenticationCodeInfo", parameters: &[] },
+  Function { name: "resendRecoveryEmailAddressCode", description: "Resends the 2-step verification recovery email address verification code", result: "PasswordState", parameters: &[] },
+  Function { name: "resetAllNotificationSettings", description: "Resets all notification settings to their default values. By default, all chats are unmuted, the sound is set to \"default\" and message previews are shown", result: "Ok", parameters: &[] },
+  Function { name: "resetBackgrounds", description: "Resets list of installed backgrounds to its default value", result: "Ok", parameters: &[] },
+  Function { name: "resetNetworkStatistics", description: "Resets all network data usage statistics to zero. Can be called before authorization", result: "Ok", parameters: &[] },
+  Function { name: "resetPassword", description: "Removes 2-step verification password without previous password and access to recovery email address. The password can't be reset immediately and the request needs to be repeated after the specified time", result: "ResetPasswordResult", parameters: &[] },
+  Function { name: "revokeChatInviteLink", description: "Revokes invite link for a chat. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right in the chat for own links and owner privileges for other links. If a primary link is revoked, then additionally to the revoked link returns new primary link", result: "ChatInviteLinks", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "invite_link", type_: "string", description: "Invite link to be revoked" },
+  ] },
+  Function { name: "revokeGroupCallInviteLink", description: "Revokes invite link for a group call. Requires groupCall.can_be_managed group call flag", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Function { name: "saveApplicationLogEvent", description: "Saves application log event on the server. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "type", type_: "string", description: "Event type" },
+    Parameter { name: "chat_id", type_: "int53", description: "Optional chat identifier, associated with the event" },
+    Parameter { name: "data", type_: "JsonValue", description: "The log event data" },
+  ] },
+  Function { name: "searchBackground", description: "Searches for a background by its name", result: "Background", parameters: &[
+    Parameter { name: "name", type_: "string", description: "The name of the background" },
+  ] },
+  Function { name: "searchCallMessages", description: "Searches for call messages. Returns the results in reverse chronological order (i. e., in order of decreasing message_id). For optimal performance, the number of returned messages is chosen by TDLib", result: "Messages", parameters: &[
+    Parameter { name: "from_message_id", type_: "int53", description: "Identifier of the message from which to search; use 0 to get results from the last message" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned; up to 100. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit" },
+    Parameter { name: "only_missed", type_: "Bool", description: "If true, returns only messages with missed calls" },
+  ] },
+  Function { name: "searchChatMembers", description: "Searches for a specified query in the first name, last name and username of the members of a specified chat. Requires administrator rights in channels", result: "ChatMembers", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of users to be returned; up to 200" },
+    Parameter { name: "filter", type_: "ChatMembersFilter", description: "The type of users to search for; pass null to search among all chat members" },
+  ] },
+  Function { name: "searchChatMessages", description: "Searches for messages with given words in the chat. Returns the results in reverse chronological order, i.e. in order of decreasing message_id. Cannot be used in secret chats with a non-empty query (searchSecretMessages must be used instead), or without an enabled message database. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat in which to search messages" },
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+    Parameter { name: "sender", type_: "MessageSender", description: "Sender of messages to search for; pass null to search for messages from any sender. Not supported in secret chats" },
+    Parameter { name: "from_message_id", type_: "int53", description: "Identifier of the message starting from which history must be fetched; use 0 to get results from the last message" },
+    Parameter { name: "offset", type_: "int32", description: "Specify 0 to get results from exactly the from_message_id or a negative offset to get the specified message and some newer messages" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned; must be positive and can't be greater than 100. If the offset is negative, the limit must be greater than offset. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit" },
+    Parameter { name: "filter", type_: "SearchMessagesFilter", description: "Additional filter for messages to search; pass null to search for all messages" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, only messages in the specified thread will be returned; supergroups only" },
+  ] },
+  Function { name: "searchChatRecentLocationMessages", description: "Returns information about the recent locations of chat members that were sent to the chat. Returns up to 1 location message per user", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned" },
+  ] },
+  Function { name: "searchChats", description: "Searches for the specified query in the title and username of already known chats, this is an offline request. Returns chats in the order seen in the main chat list", result: "Chats", parameters: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for. If the query is empty, returns up to 50 recently found chats" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chats to be returned" },
+  ] },
+  Function { name: "searchChatsNearby", description: "Returns a list of users and location-based supergroups nearby. The list of users nearby will be updated for 60 seconds after the request by the updates updateUsersNearby. The request must be sent again every 25 seconds with adjusted location to not miss new chats", result: "ChatsNearby", parameters: &[
+    Parameter { name: "location", type_: "location", description: "Current user location" },
+  ] },
+  Function { name: "searchChatsOnServer", description: "Searches for the specified query in the title and username of already known chats via request to the server. Returns chats in the order seen in the main chat list", result: "Chats", parameters: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of chats to be returned" },
+  ] },
+  Function { name: "searchContacts", description: "Searches for the specified query in the first names, last names and usernames of the known user contacts", result: "Users", parameters: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for; may be empty to return all contacts" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of users to be returned" },
+  ] },
+  Function { name: "searchEmojis", description: "Searches for emojis by keywords. Supported only if the file database is enabled", result: "Emojis", parameters: &[
+    Parameter { name: "text", type_: "string", description: "Text to search for" },
+    Parameter { name: "exact_match", type_: "Bool", description: "True, if only emojis, which exactly match text needs to be returned" },
+    Parameter { name: "input_language_codes", type_: "vector<string>", description: "List of possible IETF language tags of the user's input language; may be empty if unknown" },
+  ] },
+  Function { name: "searchHashtags", description: "Searches for recently used hashtags by their prefix", result: "Hashtags", parameters: &[
+    Parameter { name: "prefix", type_: "string", description: "Hashtag prefix to search for" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of hashtags to be returned" },
+  ] },
+  Function { name: "searchInstalledStickerSets", description: "Searches for installed sticker sets by looking for specified query in their title and name", result: "StickerSets", parameters: &[
+    Parameter { name: "is_masks", type_: "Bool", description: "Pass true to return mask sticker sets; pass false to return ordinary sticker sets" },
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of sticker sets to return" },
+  ] },
+  Function { name: "searchMessages", description: "Searches for messages in all chats except secret chats. Returns the results in reverse chronological order (i.e., in order of decreasing (date, chat_id, message_id)). For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit", result: "Messages", parameters: &[
+    Parameter { name: "chat_list", type_: "ChatList", description: "Chat list in which to search messages; pass null to search in all chats regardless of their chat list. Only Main and Archive chat lists are supported" },
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+    Parameter { name: "offset_date", type_: "int32", description: "The date of the message starting from which the results need to be fetched. Use 0 or any date in the future to get results from the last message" },
+    Parameter { name: "offset_chat_id", type_: "int53", description: "The chat identifier of the last found message, or 0 for the first request" },
+    Parameter { name: "offset_message_id", type_: "int53", description: "The message identifier of the last found message, or 0 for the first request" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned; up to 100. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit" },
+    Parameter { name: "filter", type_: "SearchMessagesFilter", description: "Additional filter for messages to search; pass null to search for all messages. Filters searchMessagesFilterCall, searchMessagesFilterMissedCall, searchMessagesFilterMention, searchMessagesFilterUnreadMention, searchMessagesFilterFailedToSend and searchMessagesFilterPinned are unsupported in this function" },
+    Parameter { name: "min_date", type_: "int32", description: "If not 0, the minimum date of the messages to return" },
+    Parameter { name: "max_date", type_: "int32", description: "If not 0, the maximum date of the messages to return" },
+  ] },
+  Function { name: "searchPublicChat", description: "Searches a public chat by its username. Currently only private chats, supergroups and channels can be public. Returns the chat if found; otherwise an error is returned", result: "Chat", parameters: &[
+    Parameter { name: "username", type_: "string", description: "Username to be resolved" },
+  ] },
+  Function { name: "searchPublicChats", description: "Searches public chats by looking for specified query in their username and title. Currently only private chats, supergroups and channels can be public. Returns a meaningful number of results. Excludes private chats with contacts and chats from the chat list from the results", result: "Chats", parameters: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+  ] },
+  Function { name: "searchSecretMessages", description: "Searches for messages in secret chats. Returns the results in reverse chronological order. For optimal performance, the number of returned messages is chosen by TDLib", result: "FoundMessages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat in which to search. Specify 0 to search in all secret chats" },
+    Parameter { name: "query", type_: "string", description: "Query to search for. If empty, searchChatMessages must be used instead" },
+    Parameter { name: "offset", type_: "string", description: "Offset of the first entry to return as received from the previous request; use empty string to get first chunk of results" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of messages to be returned; up to 100. For optimal performance, the number of returned messages is chosen by TDLib and can be smaller than the specified limit" },
+    Parameter { name: "filter", type_: "SearchMessagesFilter", description: "Additional filter for messages to search; pass null to search for all messages" },
+  ] },
+  Function { name: "searchStickerSet", description: "Searches for a sticker set by its name", result: "StickerSet", parameters: &[
+    Parameter { name: "name", type_: "string", description: "Name of the sticker set" },
+  ] },
+  Function { name: "searchStickerSets", description: "Searches for ordinary sticker sets by looking for specified query in their title and name. Excludes installed sticker sets from the results", result: "StickerSets", parameters: &[
+    Parameter { name: "query", type_: "string", description: "Query to search for" },
+  ] },
+  Function { name: "searchStickers", description: "Searches for stickers from public sticker sets that correspond to a given emoji", result: "Stickers", parameters: &[
+    Parameter { name: "emoji", type_: "string", description: "String representation of emoji; must be non-empty" },
+    Parameter { name: "limit", type_: "int32", description: "The maximum number of stickers to be returned" },
+  ] },
+  Function { name: "sendBotStartMessage", description: "Invites a bot to a chat (if it is not yet a member) and sends it the /start command. Bots can't be invited to a private chat other than the chat with the bot. Bots can't be invited to channels (although they can be added as admins) and secret chats. Returns the sent message", result: "Message", parameters: &[
+    Parameter { name: "bot_user_id", type_: "int53", description: "Identifier of the bot" },
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the target chat" },
+    Parameter { name: "parameter", type_: "string", description: "A hidden parameter sent to the bot for deep linking purposes (https://core.telegram.org/bots#deep-linking)" },
+  ] },
+  Function { name: "sendCallDebugInformation", description: "Sends debug information for a call", result: "Ok", parameters: &[
+    Parameter { name: "call_id", type_: "int32", description: "Call identifier" },
+    Parameter { name: "debug_information", type_: "string", description: "Debug information in application-specific format" },
+  ] },
+  Function { name: "sendCallRating", description: "Sends a call rating", result: "Ok", parameters: &[
+    Parameter { name: "call_id", type_: "int32", description: "Call identifier" },
+    Parameter { name: "rating", type_: "int32", description: "Call rating; 1-5" },
+    Parameter { name: "comment", type_: "string", description: "An optional user comment if the rating is less than 5" },
+    Parameter { name: "problems", type_: "vector<CallProblem>", description: "List of the exact types of problems with the call, specified by the user" },
+  ] },
+  Function { name: "sendCallSignalingData", description: "Sends call signaling data", result: "Ok", parameters: &[
+    Parameter { name: "call_id", type_: "int32", description: "Call identifier" },
+    Parameter { name: "data", type_: "bytes", description: "The data" },
+  ] },
+  Function { name: "sendChatAction", description: "Sends a notification about user activity in a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, a message thread identifier in which the action was performed" },
+    Parameter { name: "action", type_: "ChatAction", description: "The action description; pass null to cancel the currently active action" },
+  ] },
+  Function { name: "sendChatScreenshotTakenNotification", description: "Sends a notification about a screenshot taken in a chat. Supported only in private and secret chats", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+  ] },
+  Function { name: "sendCustomRequest", description: "Sends a custom request; for bots only", result: "CustomRequestResult", parameters: &[
+    Parameter { name: "method", type_: "string", description: "The method name" },
+    Parameter { name: "parameters", type_: "string", description: "JSON-serialized method parameters" },
+  ] },
+  Function { name: "sendEmailAddressVerificationCode", description: "Sends a code to verify an email address to be added to a user's Telegram Passport", result: "EmailAddressAuthenticationCodeInfo", parameters: &[
+    Parameter { name: "email_address", type_: "string", description: "Email address" },
+  ] },
+  Function { name: "sendInlineQueryResultMessage", description: "Sends the result of an inline query as a message. Returns the sent message. Always clears a chat draft message", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Target chat" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, a message thread identifier in which the message will be sent" },
+    Parameter { name: "reply_to_message_id", type_: "int53", description: "Identifier of a message to reply to or 0" },
+    Parameter { name: "options", type_: "messageSendOptions", description: "Options to be used to send the message; pass null to use default options" },
+    Parameter { name: "query_id", type_: "int64", description: "Identifier of the inline query" },
+    Parameter { name: "result_id", type_: "string", description: "Identifier of the inline result" },
+    Parameter { name: "hide_via_bot", type_: "Bool", description: "If true, there will be no mention of a bot, via which the message is sent. Can be used only for bots GetOption(\"animation_search_bot_username\"), GetOption(\"photo_search_bot_username\") and GetOption(\"venue_search_bot_username\")" },
+  ] },
+  Function { name: "sendMessage", description: "Sends a message. Returns the sent message", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Target chat" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, a message thread identifier in which the message will be sent" },
+    Parameter { name: "reply_to_message_id", type_: "int53", description: "Identifier of the message to reply to or 0" },
+    Parameter { name: "options", type_: "messageSendOptions", description: "Options to be used to send the message; pass null to use default options" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "Markup for replying to the message; pass null if none; for bots only" },
+    Parameter { name: "input_message_content", type_: "InputMessageContent", description: "The content of the message to be sent" },
+  ] },
+  Function { name: "sendMessageAlbum", description: "Sends 2-10 messages grouped together into an album. Currently only audio, document, photo and video messages can be grouped into an album. Documents and audio files can be only grouped in an album with messages of the same type. Returns sent messages", result: "Messages", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Target chat" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, a message thread identifier in which the messages will be sent" },
+    Parameter { name: "reply_to_message_id", type_: "int53", description: "Identifier of a message to reply to or 0" },
+    Parameter { name: "options", type_: "messageSendOptions", description: "Options to be used to send the messages; pass null to use default options" },
+    Parameter { name: "input_message_contents", type_: "vector<InputMessageContent>", description: "Contents of messages to be sent. At most 10 messages can be added to an album" },
+  ] },
+  Function { name: "sendPassportAuthorizationForm", description: "Sends a Telegram Passport authorization form, effectively sharing data with the service. This method must be called after getPassportAuthorizationFormAvailableElements if some previously available elements are going to be reused", result: "Ok", parameters: &[
+    Parameter { name: "autorization_form_id", type_: "int32", description: "Authorization form identifier" },
+    Parameter { name: "types", type_: "vector<PassportElementType>", description: "Types of Telegram Passport elements chosen by user to complete the authorization form" },
+  ] },
+  Function { name: "sendPaymentForm", description: "Sends a filled-out payment form to the bot for final verification", result: "PaymentResult", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the Invoice message" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "payment_form_id", type_: "int64", description: "Payment form identifier returned by getPaymentForm" },
+    Parameter { name: "order_info_id", type_: "string", description: "Identifier returned by validateOrderInfo, or an empty string" },
+    Parameter { name: "shipping_option_id", type_: "string", description: "Identifier of a chosen shipping option, if applicable" },
+    Parameter { name: "credentials", type_: "InputCredentials", description: "The credentials chosen by user for payment" },
+    Parameter { name: "tip_amount", type_: "int32", description: "Chosen by the user amount of tip in the smallest units of the currency" },
+  ] },
+  Function { name: "sendPhoneNumberConfirmationCode", description: "Sends phone number confirmation code to handle links of the type internalLinkTypePhoneNumberConfirmation", result: "AuthenticationCodeInfo", parameters: &[
+    Parameter { name: "hash", type_: "string", description: "Hash value from the link" },
+    Parameter { name: "phone_number", type_: "string", description: "Phone number value from the link" },
+    Parameter { name: "settings", type_: "phoneNumberAuthenticationSettings", description: "Settings for the authentication of the user's phone number; pass null to use default settings" },
+  ] },
+  Function { name: "sendPhoneNumberVerificationCode", description: "Sends a code to verify a phone number to be added to a user's Telegram Passport", result: "AuthenticationCodeInfo", parameters: &[
+    Parameter { name: "phone_number", type_: "string", description: "The phone number of the user, in international format" },
+    Parameter { name: "settings", type_: "phoneNumberAuthenticationSettings", description: "Settings for the authentication of the user's phone number; pass null to use default settings" },
+  ] },
+  Function { name: "setAccountTtl", description: "Changes the period of inactivity after which the account of the current user will automatically be deleted", result: "Ok", parameters: &[
+    Parameter { name: "ttl", type_: "accountTtl", description: "New account TTL" },
+  ] },
+  Function { name: "setAlarm", description: "Succeeds after a specified amount of time has passed. Can be called before initialization", result: "Ok", parameters: &[
+    Parameter { name: "seconds", type_: "double", description: "Number of seconds before the function returns" },
+  ] },
+  Function { name: "setAuthenticationPhoneNumber", description: "Sets the phone number of the user and sends an authentication code to the user. Works only when the current authorization state is authorizationStateWaitPhoneNumber, or if there is no pending authentication query and the current authorization state is authorizationStateWaitCode, authorizationStateWaitRegistration, or authorizationStateWaitPassword", result: "Ok", parameters: &[
+    Parameter { name: "phone_number", type_: "string", description: "The phone number of the user, in international format" },
+    Parameter { name: "settings", type_: "phoneNumberAuthenticationSettings", description: "Settings for the authentication of the user's phone number; pass null to use default settings" },
+  ] },
+  Function { name: "setAutoDownloadSettings", description: "Sets auto-download settings", result: "Ok", parameters: &[
+    Parameter { name: "settings", type_: "autoDownloadSettings", description: "New user auto-download settings" },
+    Parameter { name: "type", type_: "NetworkType", description: "Type of the network for which the new settings are relevant" },
+  ] },
+  Function { name: "setBackground", description: "Changes the background selected by the user; adds background to the list of installed backgrounds", result: "Background", parameters: &[
+    Parameter { name: "background", type_: "InputBackground", description: "The input background to use; pass null to create a new filled backgrounds or to remove the current background" },
+    Parameter { name: "type", type_: "BackgroundType", description: "Background type; pass null to use the default type of the remote background or to remove the current background" },
+    Parameter { name: "for_dark_theme", type_: "Bool", description: "True, if the background is chosen for dark theme" },
+  ] },
+  Function { name: "setBio", description: "Changes the bio of the current user", result: "Ok", parameters: &[
+    Parameter { name: "bio", type_: "string", description: "The new value of the user bio; 0-70 characters without line feeds" },
+  ] },
+  Function { name: "setBotUpdatesStatus", description: "Informs the server about the number of pending bot updates if they haven't been processed for a long time; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "pending_update_count", type_: "int32", description: "The number of pending updates" },
+    Parameter { name: "error_message", type_: "string", description: "The last error message" },
+  ] },
+  Function { name: "setChatClientData", description: "Changes application-specific data associated with a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "client_data", type_: "string", description: "New value of client_data" },
+  ] },
+  Function { name: "setChatDescription", description: "Changes information about a chat. Available for basic groups, supergroups, and channels. Requires can_change_info administrator right", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat" },
+    Parameter { name: "description", type_: "string", description: "New chat description; 0-255 characters" },
+  ] },
+  Function { name: "setChatDiscussionGroup", description: "Changes the discussion group of a channel chat; requires can_change_info administrator right in the channel if it is specified", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the channel chat. Pass 0 to remove a link from the supergroup passed in the second argument to a linked channel chat (requires can_pin_messages rights in the supergroup)" },
+    Parameter { name: "discussion_chat_id", type_: "int53", description: "Identifier of a new channel's discussion group. Use 0 to remove the discussion group. Use the method getSuitableDiscussionChats to find all suitable groups. Basic group chats must be first upgraded to supergroup chats. If new chat members don't have access to old messages in the supergroup, then toggleSupergroupIsAllHistoryAvailable must be used first to change that" },
+  ] },
+  Function { name: "setChatDraftMessage", description: "Changes the draft message in a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, a message thread identifier in which the draft was changed" },
+    Parameter { name: "draft_message", type_: "draftMessage", description: "New draft message; pass null to remove the draft" },
+  ] },
+  Function { name: "setChatLocation", description: "Changes the location of a chat. Available only for some location-based supergroups, use supergroupFullInfo.can_set_location to check whether the method is allowed to use", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "location", type_: "chatLocation", description: "New location for the chat; must be valid and not null" },
+  ] },
+  Function { name: "setChatMemberStatus", description: "Changes the status of a chat member, needs appropriate privileges. This function is currently not suitable for transferring chat ownership; use transferChatOwnership instead. Use addChatMember or banChatMember if some additional parameters needs to be passed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "member_id", type_: "MessageSender", description: "Member identifier. Chats can be only banned and unbanned in supergroups and channels" },
+    Parameter { name: "status", type_: "ChatMemberStatus", description: "The new status of the member in the chat" },
+  ] },
+  Function { name: "setChatMessageTtlSetting", description: "Changes the message TTL setting (sets a new self-destruct timer) in a chat. Requires can_delete_messages administrator right in basic groups, supergroups and channels Message TTL setting of a chat with the current user (Saved Messages) and the chat 777000 (Telegram) can't be changed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "ttl", type_: "int32", description: "New TTL value, in seconds; must be one of 0, 86400, 7 * 86400, or 31 * 86400 unless the chat is secret" },
+  ] },
+  Function { name: "setChatNotificationSettings", description: "Changes the notification settings of a chat. Notification settings of a chat with the current user (Saved Messages) can't be changed", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "notification_settings", type_: "chatNotificationSettings", description: "New notification settings for the chat. If the chat is muted for more than 1 week, it is considered to be muted forever" },
+  ] },
+  Function { name: "setChatPermissions", description: "Changes the chat members permissions. Supported only for basic groups and supergroups. Requires can_restrict_members administrator right", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "permissions", type_: "chatPermissions", description: "New non-administrator members permissions in the chat" },
+  ] },
+  Function { name: "setChatPhoto", description: "Changes the photo of a chat. Supported only for basic groups, supergroups and channels. Requires can_change_info administrator right", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "photo", type_: "InputChatPhoto", description: "New chat photo; pass null to delete the chat photo" },
+  ] },
+  Function { name: "setChatSlowModeDelay", description: "Changes the slow mode delay of a chat. Available only for supergroups; requires can_restrict_members rights", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "slow_mode_delay", type_: "int32", description: "New slow mode delay for the chat, in seconds; must be one of 0, 10, 30, 60, 300, 900, 3600" },
+  ] },
+  Function { name: "setChatTheme", description: "Changes the chat theme. Supported only in private and secret chats", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "theme_name", type_: "string", description: "Name of the new chat theme; pass an empty string to return the default theme" },
+  ] },
+  Function { name: "setChatTitle", description: "Changes the chat title. Supported only for basic groups, supergroups and channels. Requires can_change_info administrator right", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "title", type_: "string", description: "New title of the chat; 1-128 characters" },
+  ] },
+  Function { name: "setCommands", description: "Sets the list of commands supported by the bot for the given user scope and language; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "scope", type_: "BotCommandScope", description: "The scope to which the commands are relevant; pass null to change commands in the default bot command scope" },
+    Parameter { name: "language_code", type_: "string", description: "A two-letter ISO 639-1 country code. If empty, the commands will be applied to all users from the given scope, for which language there are no dedicated commands" },
+    Parameter { name: "commands", type_: "vector<botCommand>", description: "List of the bot's commands" },
+  ] },
+  Function { name: "setCustomLanguagePack", description: "Adds or changes a custom local language pack to the current localization target", result: "Ok", parameters: &[
+    Parameter { name: "info", type_: "languagePackInfo", description: "Information about the language pack. Language pack ID must start with 'X', consist only of English letters, digits and hyphens, and must not exceed 64 characters. Can be called before authorization" },
+    Parameter { name: "strings", type_: "vector<languagePackString>", description: "Strings of the new language pack" },
+  ] },
+  Function { name: "setCustomLanguagePackString", description: "Adds, edits or deletes a string in a custom local language pack. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "language_pack_id", type_: "string", description: "Identifier of a previously added custom local language pack in the current localization target" },
+    Parameter { name: "new_string", type_: "languagePackString", description: "New language pack string" },
+  ] },
+  Function { name: "setDatabaseEncryptionKey", description: "Changes the database encryption key. Usually the encryption key is never changed and is stored in some OS keychain", result: "Ok", parameters: &[
+    Parameter { name: "new_encryption_key", type_: "bytes", description: "New encryption key" },
+  ] },
+  Function { name: "setFileGenerationProgress", description: "Informs TDLib on a file generation progress", result: "Ok", parameters: &[
+    Parameter { name: "generation_id", type_: "int64", description: "The identifier of the generation process" },
+    Parameter { name: "expected_size", type_: "int32", description: "Expected size of the generated file, in bytes; 0 if unknown" },
+    Parameter { name: "local_prefix_size", type_: "int32", description: "The number of bytes already generated" },
+  ] },
+  Function { name: "setGameScore", description: "Updates the game score of the specified user in the game; for bots only", result: "Message", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "The chat to which the message with the game belongs" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message" },
+    Parameter { name: "edit_message", type_: "Bool", description: "True, if the message needs to be edited" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "score", type_: "int32", description: "The new score" },
+    Parameter { name: "force", type_: "Bool", description: "Pass true to update the score even if it decreases. If the score is 0, the user will be deleted from the high score table" },
+  ] },
+  Function { name: "setGroupCallParticipantIsSpeaking", description: "Informs TDLib that speaking state of a participant of an active group has changed", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "audio_source", type_: "int32", description: "Group call participant's synchronization audio source identifier, or 0 for the current user" },
+    Parameter { name: "is_speaking", type_: "Bool", description: "True, if the user is speaking" },
+  ] },
+  Function { name: "setGroupCallParticipantVolumeLevel", description: "Changes volume level of a participant of an active group call. If the current user can manage the group call, then the participant's volume level will be changed for all users with the default volume level", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Participant identifier" },
+    Parameter { name: "volume_level", type_: "int32", description: "New participant's volume level; 1-20000 in hundreds of percents" },
+  ] },
+  Function { name: "setGroupCallTitle", description: "Sets group call title. Requires groupCall.can_be_managed group call flag", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "title", type_: "string", description: "New group call title; 1-64 characters" },
+  ] },
+  Function { name: "setInlineGameScore", description: "Updates the game score of the specified user in a game; for bots only", result: "Ok", parameters: &[
+    Parameter { name: "inline_message_id", type_: "string", description: "Inline message identifier" },
+    Parameter { name: "edit_message", type_: "Bool", description: "True, if the message needs to be edited" },
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "score", type_: "int32", description: "The new score" },
+    Parameter { name: "force", type_: "Bool", description: "Pass true to update the score even if it decreases. If the score is 0, the user will be deleted from the high score table" },
+  ] },
+  Function { name: "setLocation", description: "Changes the location of the current user. Needs to be called if GetOption(\"is_location_visible\") is true and location changes for more than 1 kilometer", result: "Ok", parameters: &[
+    Parameter { name: "location", type_: "location", description: "The new location of the user" },
+  ] },
+  Function { name: "setLogStream", description: "Sets new log stream for internal logging of TDLib. Can be called synchronously", result: "Ok", parameters: &[
+    Parameter { name: "log_stream", type_: "LogStream", description: "New log stream" },
+  ] },
+  Function { name: "setLogTagVerbosityLevel", description: "Sets the verbosity level for a specified TDLib internal log tag. Can be called synchronously", result: "Ok", parameters: &[
+    Parameter { name: "tag", type_: "string", description: "Logging tag to change verbosity level" },
+    Parameter { name: "new_verbosity_level", type_: "int32", description: "New verbosity level; 1-1024" },
+  ] },
+  Function { name: "setLogVerbosityLevel", description: "Sets the verbosity level of the internal logging of TDLib. Can be called synchronously", result: "Ok", parameters: &[
+    Parameter { name: "new_verbosity_level", type_: "int32", description: "New value of the verbosity level for logging. Value 0 corresponds to fatal errors, value 1 corresponds to errors, value 2 corresponds to warnings and debug warnings, value 3 corresponds to informational, value 4 corresponds to debug, value 5 corresponds to verbose debug, value greater than 5 and up to 1023 can be used to enable even more logging" },
+  ] },
+  Function { name: "setName", description: "Changes the first and last name of the current user", result: "Ok", parameters: &[
+    Parameter { name: "first_name", type_: "string", description: "The new value of the first name for the current user; 1-64 characters" },
+    Parameter { name: "last_name", type_: "string", description: "The new value of the optional last name for the current user; 0-64 characters" },
+  ] },
+  Function { name: "setNetworkType", description: "Sets the current network type. Can be called before authorization. Calling this method forces all network connections to reopen, mitigating the delay in switching between different networks, so it must be called whenever the network is changed, even if the network type remains the same. Network type is used to check whether the library can use the network at all and also for collecting detailed network data usage statistics", result: "Ok", parameters: &[
+    Parameter { name: "type", type_: "NetworkType", description: "The new network type; pass null to set network type to networkTypeOther" },
+  ] },
+  Function { name: "setOption", description: "Sets the value of an option. (Check the list of available options on https://core.telegram.org/tdlib/options.) Only writable options can be set. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "name", type_: "string", description: "The name of the option" },
+    Parameter { name: "value", type_: "OptionValue", description: "The new value of the option; pass null to reset option value to a default value" },
+  ] },
+  Function { name: "setPassportElement", description: "Adds an element to the user's Telegram Passport. May return an error with a message \"PHONE_VERIFICATION_NEEDED\" or \"EMAIL_VERIFICATION_NEEDED\" if the chosen phone number or the chosen email address must be verified first", result: "PassportElement", parameters: &[
+    Parameter { name: "element", type_: "InputPassportElement", description: "Input Telegram Passport element" },
+    Parameter { name: "password", type_: "string", description: "Password of the current user" },
+  ] },
+  Function { name: "setPassportElementErrors", description: "Informs the user that some of the elements in their Telegram Passport contain errors; for bots only. The user will not be able to resend the elements, until the errors are fixed", result: "Ok", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "User identifier" },
+    Parameter { name: "errors", type_: "vector<inputPassportElementError>", description: "The errors" },
+  ] },
+  Function { name: "setPassword", description: "Changes the password for the current user. If a new recovery email address is specified, then the change will not be applied until the new recovery email address is confirmed", result: "PasswordState", parameters: &[
+    Parameter { name: "old_password", type_: "string", description: "Previous password of the user" },
+    Parameter { name: "new_password", type_: "string", description: "New password of the user; may be empty to remove the password" },
+    Parameter { name: "new_hint", type_: "string", description: "New password hint; may be empty" },
+    Parameter { name: "set_recovery_email_address", type_: "Bool", description: "Pass true if the recovery email address must be changed" },
+    Parameter { name: "new_recovery_email_address", type_: "string", description: "New recovery email address; may be empty" },
+  ] },
+  Function { name: "setPinnedChats", description: "Changes the order of pinned chats", result: "Ok", parameters: &[
+    Parameter { name: "chat_list", type_: "ChatList", description: "Chat list in which to change the order of pinned chats" },
+    Parameter { name: "chat_ids", type_: "vector<int53>", description: "The new list of pinned chats" },
+  ] },
+  Function { name: "setPollAnswer", description: "Changes the user answer to a poll. A poll in quiz mode can be answered only once", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to which the poll belongs" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message containing the poll" },
+    Parameter { name: "option_ids", type_: "vector<int32>", description: "0-based identifiers of answer options, chosen by the user. User can choose more than 1 answer option only is the poll allows multiple answers" },
+  ] },
+  Function { name: "setProfilePhoto", description: "Changes a profile photo for the current user", result: "Ok", parameters: &[
+    Parameter { name: "photo", type_: "InputChatPhoto", description: "Profile photo to set" },
+  ] },
+  Function { name: "setRecoveryEmailAddress", description: "Changes the 2-step verification recovery email address of the user. If a new recovery email address is specified, then the change will not be applied until the new recovery email address is confirmed. If new_recovery_email_address is the same as the email address that is currently set up, this call succeeds immediately and aborts all other requests waiting for an email confirmation", result: "PasswordState", parameters: &[
+    Parameter { name: "password", type_: "string", description: "Password of the current user" },
+    Parameter { name: "new_recovery_email_address", type_: "string", description: "New recovery email address" },
+  ] },
+  Function { name: "setScopeNotificationSettings", description: "Changes notification settings for chats of a given type", result: "Ok", parameters: &[
+    Parameter { name: "scope", type_: "NotificationSettingsScope", description: "Types of chats for which to change the notification settings" },
+    Parameter { name: "notification_settings", type_: "scopeNotificationSettings", description: "The new notification settings for the given scope" },
+  ] },
+  Function { name: "setStickerPositionInSet", description: "Changes the position of a sticker in the set to which it belongs; for bots only. The sticker set must have been created by the bot", result: "Ok", parameters: &[
+    Parameter { name: "sticker", type_: "InputFile", description: "Sticker" },
+    Parameter { name: "position", type_: "int32", description: "New position of the sticker in the set, zero-based" },
+  ] },
+  Function { name: "setStickerSetThumbnail", description: "Sets a sticker set thumbnail; for bots only. Returns the sticker set", result: "StickerSet", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "Sticker set owner" },
+    Parameter { name: "name", type_: "string", description: "Sticker set name" },
+    Parameter { name: "thumbnail", type_: "InputFile", description: "Thumbnail to set in PNG or TGS format; pass null to remove the sticker set thumbnail. Animated thumbnail must be set for animated sticker sets and only for them" },
+  ] },
+  Function { name: "setSupergroupStickerSet", description: "Changes the sticker set of a supergroup; requires can_change_info administrator right", result: "Ok", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the supergroup" },
+    Parameter { name: "sticker_set_id", type_: "int64", description: "New value of the supergroup sticker set identifier. Use 0 to remove the supergroup sticker set" },
+  ] },
+  Function { name: "setSupergroupUsername", description: "Changes the username of a supergroup or channel, requires owner privileges in the supergroup or channel", result: "Ok", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the supergroup or channel" },
+    Parameter { name: "username", type_: "string", description: "New value of the username. Use an empty string to remove the username" },
+  ] },
+  Function { name: "setTdlibParameters", description: "Sets the parameters for TDLib initialization. Works only when the current authorization state is authorizationStateWaitTdlibParameters", result: "Ok", parameters: &[
+    Parameter { name: "parameters", type_: "tdlibParameters", description: "Parameters for TDLib initialization" },
+  ] },
+  Function { name: "setUserPrivacySettingRules", description: "Changes user privacy settings", result: "Ok", parameters: &[
+    Parameter { name: "setting", type_: "UserPrivacySetting", description: "The privacy setting" },
+    Parameter { name: "rules", type_: "userPrivacySettingRules", description: "The new privacy rules" },
+  ] },
+  Function { name: "setUsername", description: "Changes the username of the current user", result: "Ok", parameters: &[
+    Parameter { name: "username", type_: "string", description: "The new value of the username. Use an empty string to remove the username" },
+  ] },
+  Function { name: "setVideoChatDefaultParticipant", description: "Changes default participant identifier, which can be used to join video chats in a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "default_participant_id", type_: "MessageSender", description: "Default group call participant identifier to join the video chats" },
+  ] },
+  Function { name: "sharePhoneNumber", description: "Shares the phone number of the current user with a mutual contact. Supposed to be called when the user clicks on chatActionBarSharePhoneNumber", result: "Ok", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user with whom to share the phone number. The user must be a mutual contact" },
+  ] },
+  Function { name: "startGroupCallRecording", description: "Starts recording of an active group call. Requires groupCall.can_be_managed group call flag", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "title", type_: "string", description: "Group call recording title; 0-64 characters" },
+    Parameter { name: "record_video", type_: "Bool", description: "Pass true to record a video file instead of an audio file" },
+    Parameter { name: "use_portrait_orientation", type_: "Bool", description: "Pass true to use portrait orientation for video instead of landscape one" },
+  ] },
+  Function { name: "startGroupCallScreenSharing", description: "Starts screen sharing in a joined group call. Returns join response payload for tgcalls", result: "Text", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "audio_source_id", type_: "int32", description: "Screen sharing audio channel synchronization source identifier; received from tgcalls" },
+    Parameter { name: "payload", type_: "string", description: "Group call join payload; received from tgcalls" },
+  ] },
+  Function { name: "startScheduledGroupCall", description: "Starts a scheduled group call", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+  ] },
+  Function { name: "stopPoll", description: "Stops a poll. A poll in a message can be stopped when the message has can_be_edited flag set", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to which the poll belongs" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the message containing the poll" },
+    Parameter { name: "reply_markup", type_: "ReplyMarkup", description: "The new message reply markup; pass null if none; for bots only" },
+  ] },
+  Function { name: "synchronizeLanguagePack", description: "Fetches the latest versions of all strings from a language pack in the current localization target from the server. This method doesn't need to be called explicitly for the current used/base language packs. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "language_pack_id", type_: "string", description: "Language pack identifier" },
+  ] },
+  Function { name: "terminateAllOtherSessions", description: "Terminates all other sessions of the current user", result: "Ok", parameters: &[] },
+  Function { name: "terminateSession", description: "Terminates a session of the current user", result: "Ok", parameters: &[
+    Parameter { name: "session_id", type_: "int64", description: "Session identifier" },
+  ] },
+  Function { name: "testCallBytes", description: "Returns the received bytes; for testing only. This is an offline method. Can be called before authorization", result: "TestBytes", parameters: &[
+    Parameter { name: "x", type_: "bytes", description: "Bytes to return" },
+  ] },
+  Function { name: "testCallEmpty", description: "Does nothing; for testing only. This is an offline method. Can be called before authorization", result: "Ok", parameters: &[] },
+  Function { name: "testCallString", description: "Returns the received string; for testing only. This is an offline method. Can be called before authorization", result: "TestString", parameters: &[
+    Parameter { name: "x", type_: "string", description: "String to return" },
+  ] },
+  Function { name: "testCallVectorInt", description: "Returns the received vector of numbers; for testing only. This is an offline method. Can be called before authorization", result: "TestVectorInt", parameters: &[
+    Parameter { name: "x", type_: "vector<int32>", description: "Vector of numbers to return" },
+  ] },
+  Function { name: "testCallVectorIntObject", description: "Returns the received vector of objects containing a number; for testing only. This is an offline method. Can be called before authorization", result: "TestVectorIntObject", parameters: &[
+    Parameter { name: "x", type_: "vector<testInt>", description: "Vector of objects to return" },
+  ] },
+  Function { name: "testCallVectorString", description: "Returns the received vector of strings; for testing only. This is an offline method. Can be called before authorization", result: "TestVectorString", parameters: &[
+    Parameter { name: "x", type_: "vector<string>", description: "Vector of strings to return" },
+  ] },
+  Function { name: "testCallVectorStringObject", description: "Returns the received vector of objects containing a string; for testing only. This is an offline method. Can be called before authorization", result: "TestVectorStringObject", parameters: &[
+    Parameter { name: "x", type_: "vector<testString>", description: "Vector of objects to return" },
+  ] },
+  Function { name: "testGetDifference", description: "Forces an updates.getDifference call to the Telegram servers; for testing only", result: "Ok", parameters: &[] },
+  Function { name: "testNetwork", description: "Sends a simple network request to the Telegram servers; for testing only. Can be called before authorization", result: "Ok", parameters: &[] },
+  Function { name: "testProxy", description: "Sends a simple network request to the Telegram servers via proxy; for testing only. Can be called before authorization", result: "Ok", parameters: &[
+    Parameter { name: "server", type_: "string", description: "Proxy server IP address" },
+    Parameter { name: "port", type_: "int32", description: "Proxy server port" },
+    Parameter { name: "type", type_: "ProxyType", description: "Proxy type" },
+    Parameter { name: "dc_id", type_: "int32", description: "Identifier of a datacenter, with which to test connection" },
+    Parameter { name: "timeout", type_: "double", description: "The maximum overall timeout for the request" },
+  ] },
+  Function { name: "testReturnError", description: "Returns the specified error and ensures that the Error object is used; for testing only. Can be called synchronously", result: "Error", parameters: &[
+    Parameter { name: "error", type_: "error", description: "The error to be returned" },
+  ] },
+  Function { name: "testSquareInt", description: "Returns the squared received number; for testing only. This is an offline method. Can be called before authorization", result: "TestInt", parameters: &[
+    Parameter { name: "x", type_: "int32", description: "Number to square" },
+  ] },
+  Function { name: "testUseUpdate", description: "Does nothing and ensures that the Update object is used; for testing only. This is an offline method. Can be called before authorization", result: "Update", parameters: &[] },
+  Function { name: "toggleChatDefaultDisableNotification", description: "Changes the value of the default disable_notification parameter, used when a message is sent to a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "default_disable_notification", type_: "Bool", description: "New value of default_disable_notification" },
+  ] },
+  Function { name: "toggleChatIsMarkedAsUnread", description: "Changes the marked as unread state of a chat", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "is_marked_as_unread", type_: "Bool", description: "New value of is_marked_as_unread" },
+  ] },
+  Function { name: "toggleChatIsPinned", description: "Changes the pinned state of a chat. There can be up to GetOption(\"pinned_chat_count_max\")/GetOption(\"pinned_archived_chat_count_max\") pinned non-secret chats and the same number of secret chats in the main/arhive chat list", result: "Ok", parameters: &[
+    Parameter { name: "chat_list", type_: "ChatList", description: "Chat list in which to change the pinned state of the chat" },
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "is_pinned", type_: "Bool", description: "True, if the chat is pinned" },
+  ] },
+  Function { name: "toggleGroupCallEnabledStartNotification", description: "Toggles whether the current user will receive a notification when the group call will start; scheduled group calls only", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "enabled_start_notification", type_: "Bool", description: "New value of the enabled_start_notification setting" },
+  ] },
+  Function { name: "toggleGroupCallIsMyVideoEnabled", description: "Toggles whether current user's video is enabled", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "is_my_video_enabled", type_: "Bool", description: "Pass true if the current user's video is enabled" },
+  ] },
+  Function { name: "toggleGroupCallIsMyVideoPaused", description: "Toggles whether current user's video is paused", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "is_my_video_paused", type_: "Bool", description: "Pass true if the current user's video is paused" },
+  ] },
+  Function { name: "toggleGroupCallMuteNewParticipants", description: "Toggles whether new participants of a group call can be unmuted only by administrators of the group call. Requires groupCall.can_toggle_mute_new_participants group call flag", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "mute_new_participants", type_: "Bool", description: "New value of the mute_new_participants setting" },
+  ] },
+  Function { name: "toggleGroupCallParticipantIsHandRaised", description: "Toggles whether a group call participant hand is rased", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Participant identifier" },
+    Parameter { name: "is_hand_raised", type_: "Bool", description: "Pass true if the user's hand needs to be raised. Only self hand can be raised. Requires groupCall.can_be_managed group call flag to lower other's hand" },
+  ] },
+  Function { name: "toggleGroupCallParticipantIsMuted", description: "Toggles whether a participant of an active group call is muted, unmuted, or allowed to unmute themselves", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "participant_id", type_: "MessageSender", description: "Participant identifier" },
+    Parameter { name: "is_muted", type_: "Bool", description: "Pass true if the user must be muted and false otherwise" },
+  ] },
+  Function { name: "toggleGroupCallScreenSharingIsPaused", description: "Pauses or unpauses screen sharing in a joined group call", result: "Ok", parameters: &[
+    Parameter { name: "group_call_id", type_: "int32", description: "Group call identifier" },
+    Parameter { name: "is_paused", type_: "Bool", description: "True if screen sharing is paused" },
+  ] },
+  Function { name: "toggleMessageSenderIsBlocked", description: "Changes the block state of a message sender. Currently, only users and supergroup chats can be blocked", result: "Ok", parameters: &[
+    Parameter { name: "sender", type_: "MessageSender", description: "Message Sender" },
+    Parameter { name: "is_blocked", type_: "Bool", description: "New value of is_blocked" },
+  ] },
+  Function { name: "toggleSupergroupIsAllHistoryAvailable", description: "Toggles whether the message history of a supergroup is available to new members; requires can_change_info administrator right", result: "Ok", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "The identifier of the supergroup" },
+    Parameter { name: "is_all_history_available", type_: "Bool", description: "The new value of is_all_history_available" },
+  ] },
+  Function { name: "toggleSupergroupIsBroadcastGroup", description: "Upgrades supergroup to a broadcast group; requires owner privileges in the supergroup", result: "Ok", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the supergroup" },
+  ] },
+  Function { name: "toggleSupergroupSignMessages", description: "Toggles sender signatures messages sent in a channel; requires can_change_info administrator right", result: "Ok", parameters: &[
+    Parameter { name: "supergroup_id", type_: "int53", description: "Identifier of the channel" },
+    Parameter { name: "sign_messages", type_: "Bool", description: "New value of sign_messages" },
+  ] },
+  Function { name: "transferChatOwnership", description: "Changes the owner of a chat. The current user must be a current owner of the chat. Use the method canTransferOwnership to check whether the ownership can be transferred from the current session. Available only for supergroups and channel chats", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "user_id", type_: "int53", description: "Identifier of the user to which transfer the ownership. The ownership can't be transferred to a bot or to a deleted user" },
+    Parameter { name: "password", type_: "string", description: "The password of the current user" },
+  ] },
+  Function { name: "unpinAllChatMessages", description: "Removes all pinned messages from a chat; requires can_pin_messages rights in the group or can_edit_messages rights in the channel", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat" },
+  ] },
+  Function { name: "unpinChatMessage", description: "Removes a pinned message from a chat; requires can_pin_messages rights in the group or can_edit_messages rights in the channel", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat" },
+    Parameter { name: "message_id", type_: "int53", description: "Identifier of the removed pinned message" },
+  ] },
+  Function { name: "upgradeBasicGroupChatToSupergroupChat", description: "Creates a new supergroup from an existing basic group and sends a corresponding messageChatUpgradeTo and messageChatUpgradeFrom; requires creator privileges. Deactivates the original basic group", result: "Chat", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat to upgrade" },
+  ] },
+  Function { name: "uploadFile", description: "Asynchronously uploads a file to the cloud without sending it in a message. updateFile will be used to notify about upload progress and successful completion of the upload. The file will not have a persistent remote identifier until it will be sent in a message", result: "File", parameters: &[
+    Parameter { name: "file", type_: "InputFile", description: "File to upload" },
+    Parameter { name: "file_type", type_: "FileType", description: "File type; pass null if unknown" },
+    Parameter { name: "priority", type_: "int32", description: "Priority of the upload (1-32). The higher the priority, the earlier the file will be uploaded. If the priorities of two files are equal, then the first one for which uploadFile was called will be uploaded first" },
+  ] },
+  Function { name: "uploadStickerFile", description: "Uploads a PNG image with a sticker; returns the uploaded file", result: "File", parameters: &[
+    Parameter { name: "user_id", type_: "int53", description: "Sticker file owner; ignored for regular users" },
+    Parameter { name: "sticker", type_: "InputSticker", description: "Sticker file to upload" },
+  ] },
+  Function { name: "validateOrderInfo", description: "Validates the order information provided by a user and returns the available shipping options for a flexible invoice", result: "ValidatedOrderInfo", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier of the Invoice message" },
+    Parameter { name: "message_id", type_: "int53", description: "Message identifier" },
+    Parameter { name: "order_info", type_: "orderInfo", description: "The order information, provided by the user; pass null if empty" },
+    Parameter { name: "allow_save", type_: "Bool", description: "True, if the order information can be saved" },
+  ] },
+  Function { name: "viewMessages", description: "Informs TDLib that messages are being viewed by the user. Many useful activities depend on whether the messages are currently being viewed or not (e.g., marking messages as read, incrementing a view counter, updating a view counter, removing deleted messages in supergroups and channels)", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Chat identifier" },
+    Parameter { name: "message_thread_id", type_: "int53", description: "If not 0, a message thread identifier in which the messages are being viewed" },
+    Parameter { name: "message_ids", type_: "vector<int53>", description: "The identifiers of the messages being viewed" },
+    Parameter { name: "force_read", type_: "Bool", description: "True, if messages in closed chats must be marked as read by the request" },
+  ] },
+  Function { name: "viewSponsoredMessage", description: "Informs TDLib that a sponsored message was viewed by the user", result: "Ok", parameters: &[
+    Parameter { name: "chat_id", type_: "int53", description: "Identifier of the chat with the sponsored message" },
+    Parameter { name: "sponsored_message_id", type_: "int53", description: "The identifier of the sponsored message being viewed" },
+  ] },
+  Function { name: "viewTrendingStickerSets", description: "Informs the server that some trending sticker sets have been viewed by the user", result: "Ok", parameters: &[
+    Parameter { name: "sticker_set_ids", type_: "vector<int64>", description: "Identifiers of viewed trending sticker sets" },
+  ] },
+  Function { name: "writeGeneratedFilePart", description: "Writes a part of a generated file. This method is intended to be used only if the application has no direct access to TDLib's file system, because it is usually slower than a direct write to the destination file", result: "Ok", parameters: &[
+    Parameter { name: "generation_id", type_: "int64", description: "The identifier of the generation process" },
+    Parameter { name: "offset", type_: "int32", description: "The offset from which to write the data to the file" },
+    Parameter { name: "data", type_: "bytes", description: "The data to write" },
+  ] },
+];
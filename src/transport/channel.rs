@@ -0,0 +1,73 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::errors::*;
+
+use super::{recv_timeout, TdTransport};
+
+/// In-memory transport, the other side of the channel is a `ChannelPeer` playing tdlib
+///
+/// ```
+/// use std::time::Duration;
+/// use rtdlib::transport::{ChannelTransport, TdTransport};
+///
+/// let (transport, peer) = ChannelTransport::pair();
+/// transport.send(r#"{"@type":"getMe","@extra":"1"}"#).unwrap();
+/// assert!(peer.receive(Duration::from_secs(1)).is_some());
+/// peer.send(r#"{"@type":"ok","@extra":"1"}"#).unwrap();
+/// assert!(transport.receive(Duration::from_secs(1)).is_some());
+/// ```
+pub struct ChannelTransport {
+  requests: Mutex<Sender<String>>,
+  replies: Mutex<Receiver<String>>,
+}
+
+/// The tdlib side of a `ChannelTransport`
+pub struct ChannelPeer {
+  requests: Mutex<Receiver<String>>,
+  replies: Mutex<Sender<String>>,
+}
+
+impl ChannelTransport {
+  /// Create a connected transport and peer
+  pub fn pair() -> (ChannelTransport, ChannelPeer) {
+    let (request_sender, request_receiver) = mpsc::channel();
+    let (reply_sender, reply_receiver) = mpsc::channel();
+    let transport = ChannelTransport {
+      requests: Mutex::new(request_sender),
+      replies: Mutex::new(reply_receiver),
+    };
+    let peer = ChannelPeer {
+      requests: Mutex::new(request_receiver),
+      replies: Mutex::new(reply_sender),
+    };
+    (transport, peer)
+  }
+}
+
+impl TdTransport for ChannelTransport {
+  fn send(&self, request: &str) -> RTDResult<()> {
+    self.requests.lock().unwrap().send(request.to_string())
+      .map_err(|_| RTDError::custom("channel peer is closed".to_string()))
+  }
+
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    recv_timeout(&self.replies, timeout)
+  }
+
+  fn execute(&self, _request: &str) -> Option<String> { None }
+}
+
+impl ChannelPeer {
+  /// Receive the next request sent through the transport, `None` if nothing arrived within `timeout`
+  pub fn receive(&self, timeout: Duration) -> Option<String> {
+    recv_timeout(&self.requests, timeout)
+  }
+
+  /// Send a reply or update to the transport
+  pub fn send<S: AsRef<str>>(&self, json: S) -> RTDResult<()> {
+    self.replies.lock().unwrap().send(json.as_ref().to_string())
+      .map_err(|_| RTDError::custom("channel transport is closed".to_string()))
+  }
+}
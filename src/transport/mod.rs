@@ -0,0 +1,104 @@
+//! The ways to reach tdlib. Clients are generic over `TdTransport`, so the same code runs against
+//! the tdjson dylib (`sys` feature), a subprocess speaking newline delimited json, or an in-memory channel.
+//! `Recorder` and `Replayer` capture the traffic of any of them to a jsonl file and play it back.
+//!
+//! `TdMultiTransport` is tdlib's interface for several clients in one process, `td_create_client_id`,
+//! with one receive queue for all of them: `Tdjson` (`tdjson` feature, part of `sys`) or a `Multiplexer` of transports.
+
+pub use self::channel::*;
+pub use self::multiplex::*;
+pub use self::process::*;
+pub use self::record::*;
+#[cfg(feature = "tdjson")]
+pub use self::tdjson::*;
+
+mod channel;
+mod multiplex;
+mod process;
+mod record;
+#[cfg(feature = "tdjson")]
+mod tdjson;
+
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::errors::*;
+
+/// A connection to tdlib exchanging json strings
+pub trait TdTransport {
+  /// Send a request, the reply is received later by `receive`
+  fn send(&self, request: &str) -> RTDResult<()>;
+  /// Receive the next reply or update, `None` if nothing arrived within `timeout`
+  fn receive(&self, timeout: Duration) -> Option<String>;
+  /// Synchronously execute a request, only for functions tdlib documents as "Can be called synchronously".
+  /// Transports that can't execute synchronously return `None`.
+  fn execute(&self, request: &str) -> Option<String>;
+}
+
+impl<T: TdTransport> TdTransport for &T {
+  fn send(&self, request: &str) -> RTDResult<()> { (*self).send(request) }
+  fn receive(&self, timeout: Duration) -> Option<String> { (*self).receive(timeout) }
+  fn execute(&self, request: &str) -> Option<String> { (*self).execute(request) }
+}
+
+impl<T: TdTransport + ?Sized> TdTransport for Arc<T> {
+  fn send(&self, request: &str) -> RTDResult<()> { (**self).send(request) }
+  fn receive(&self, timeout: Duration) -> Option<String> { (**self).receive(timeout) }
+  fn execute(&self, request: &str) -> Option<String> { (**self).execute(request) }
+}
+
+/// A connection to tdlib serving several clients. Requests are sent to a client, everything
+/// received, from any client, is tagged with the `@client_id` of its client.
+pub trait TdMultiTransport {
+  /// Create a client, tdlib starts it with the first request sent to it
+  fn create_client_id(&self) -> RTDResult<i32>;
+  /// Send a request to the client `client_id`, the reply is received later by `receive`
+  fn send(&self, client_id: i32, request: &str) -> RTDResult<()>;
+  /// Receive the next reply or update of any client, `None` if nothing arrived within `timeout`.
+  /// Only one thread may receive at a time.
+  fn receive(&self, timeout: Duration) -> Option<String>;
+  /// Synchronously execute a request, like `TdTransport::execute`
+  fn execute(&self, request: &str) -> Option<String>;
+}
+
+impl<T: TdMultiTransport> TdMultiTransport for &T {
+  fn create_client_id(&self) -> RTDResult<i32> { (*self).create_client_id() }
+  fn send(&self, client_id: i32, request: &str) -> RTDResult<()> { (*self).send(client_id, request) }
+  fn receive(&self, timeout: Duration) -> Option<String> { (*self).receive(timeout) }
+  fn execute(&self, request: &str) -> Option<String> { (*self).execute(request) }
+}
+
+impl<T: TdMultiTransport + ?Sized> TdMultiTransport for Arc<T> {
+  fn create_client_id(&self) -> RTDResult<i32> { (**self).create_client_id() }
+  fn send(&self, client_id: i32, request: &str) -> RTDResult<()> { (**self).send(client_id, request) }
+  fn receive(&self, timeout: Duration) -> Option<String> { (**self).receive(timeout) }
+  fn execute(&self, request: &str) -> Option<String> { (**self).execute(request) }
+}
+
+#[cfg(feature = "sys")]
+impl TdTransport for crate::Tdlib {
+  fn send(&self, request: &str) -> RTDResult<()> {
+    crate::Tdlib::send(self, request);
+    Ok(())
+  }
+  fn receive(&self, timeout: Duration) -> Option<String> { crate::Tdlib::receive(self, timeout.as_secs_f64()) }
+  fn execute(&self, request: &str) -> Option<String> { crate::Tdlib::execute(self, request) }
+}
+
+/// How long a thread receiving in a loop, like those of the clients, blocks in its transport
+/// before checking whether its owner was dropped
+pub(crate) const RECEIVE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Receive from a channel fed by the other side of a transport
+pub(crate) fn recv_timeout(receiver: &Mutex<Receiver<String>>, timeout: Duration) -> Option<String> {
+  match receiver.lock().unwrap().recv_timeout(timeout) {
+    Ok(json) => Some(json),
+    Err(RecvTimeoutError::Timeout) => None,
+    Err(RecvTimeoutError::Disconnected) => {
+      // mirror tdlib, which blocks for the whole timeout when there is nothing to receive
+      std::thread::sleep(timeout);
+      None
+    }
+  }
+}
@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::errors::*;
+
+use super::{recv_timeout, TdMultiTransport, TdTransport, RECEIVE_TIMEOUT};
+
+/// Several transports of one client each as a `TdMultiTransport`, e.g. a `FakeTdlib` or a
+/// `ProcessTransport` per account. `make` creates the transport of each new client. Like tdlib, a
+/// client starts with the first request sent to it: from then on a thread forwards what its
+/// transport receives into the shared queue, tagged with the `@client_id`.
+///
+/// ```
+/// use rtdlib::transport::{ChannelTransport, Multiplexer, TdMultiTransport};
+///
+/// let tdlib = Multiplexer::new(|_client_id| Ok(ChannelTransport::pair().0));
+/// let client_id = tdlib.create_client_id().unwrap();
+/// ```
+pub struct Multiplexer<T> {
+  make: Box<dyn Fn(i32) -> RTDResult<T> + Send + Sync>,
+  /// The transport of each client, and whether it was started
+  clients: Mutex<BTreeMap<i32, (Arc<T>, bool)>>,
+  next_client_id: AtomicI32,
+  sender: Mutex<Sender<String>>,
+  receiver: Mutex<Receiver<String>>,
+  running: Arc<AtomicBool>,
+}
+
+impl<T: TdTransport + Send + Sync + 'static> Multiplexer<T> {
+  pub fn new<F: Fn(i32) -> RTDResult<T> + Send + Sync + 'static>(make: F) -> Self {
+    let (sender, receiver) = mpsc::channel();
+    Self {
+      make: Box::new(make),
+      clients: Mutex::new(BTreeMap::new()),
+      next_client_id: AtomicI32::new(1),
+      sender: Mutex::new(sender),
+      receiver: Mutex::new(receiver),
+      running: Arc::new(AtomicBool::new(true)),
+    }
+  }
+
+  /// The transport of the client `client_id`
+  pub fn client(&self, client_id: i32) -> Option<Arc<T>> {
+    self.clients.lock().unwrap().get(&client_id).map(|(transport, _)| transport.clone())
+  }
+
+  /// Forward what the transport of a client receives into the shared queue
+  fn start(&self, client_id: i32, transport: Arc<T>) {
+    let sender = self.sender.lock().unwrap().clone();
+    let running = self.running.clone();
+    thread::spawn(move || {
+      while running.load(Ordering::SeqCst) {
+        let json = match transport.receive(RECEIVE_TIMEOUT) {
+          Some(json) => json,
+          None => continue,
+        };
+        if sender.send(tag(&json, client_id)).is_err() { break }
+      }
+    });
+  }
+}
+
+impl<T: TdTransport + Send + Sync + 'static> TdMultiTransport for Multiplexer<T> {
+  fn create_client_id(&self) -> RTDResult<i32> {
+    let client_id = self.next_client_id.fetch_add(1, Ordering::SeqCst);
+    let transport = Arc::new((self.make)(client_id)?);
+    self.clients.lock().unwrap().insert(client_id, (transport, false));
+    Ok(client_id)
+  }
+
+  fn send(&self, client_id: i32, request: &str) -> RTDResult<()> {
+    let (transport, started) = match self.clients.lock().unwrap().get_mut(&client_id) {
+      Some((transport, started)) => (transport.clone(), std::mem::replace(started, true)),
+      None => return Err(RTDError::custom(format!("no tdlib client {}", client_id))),
+    };
+    if !started {
+      self.start(client_id, transport.clone());
+    }
+    transport.send(request)
+  }
+
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    recv_timeout(&self.receiver, timeout)
+  }
+
+  fn execute(&self, _request: &str) -> Option<String> { None }
+}
+
+impl<T> Drop for Multiplexer<T> {
+  fn drop(&mut self) {
+    self.running.store(false, Ordering::SeqCst);
+  }
+}
+
+/// The json with the `@client_id` tdlib tags it with, unchanged if it is no object
+fn tag(json: &str, client_id: i32) -> String {
+  let mut value: serde_json::Value = match serde_json::from_str(json) {
+    Ok(value) => value,
+    Err(_) => return json.to_string(),
+  };
+  match value.as_object_mut() {
+    Some(map) => {
+      map.insert("@client_id".to_string(), client_id.into());
+      value.to_string()
+    }
+    None => json.to_string(),
+  }
+}
@@ -0,0 +1,75 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::errors::*;
+
+use super::{recv_timeout, TdTransport};
+
+/// Transport to a subprocess speaking newline delimited json: one request per line on its stdin,
+/// one reply or update per line on its stdout. The process is killed when the transport is dropped.
+///
+/// ```no_run
+/// use std::process::Command;
+/// use rtdlib::transport::ProcessTransport;
+///
+/// let transport = ProcessTransport::spawn(Command::new("tdjson-bridge")).unwrap();
+/// ```
+pub struct ProcessTransport {
+  child: Mutex<Child>,
+  stdin: Mutex<ChildStdin>,
+  stdout: Mutex<Receiver<String>>,
+}
+
+impl ProcessTransport {
+  /// Spawn `command` with piped stdin and stdout
+  pub fn spawn(mut command: Command) -> RTDResult<Self> {
+    let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+    let stdin = child.stdin.take()
+      .ok_or_else(|| RTDError::custom("subprocess stdin is not piped".to_string()))?;
+    let stdout = child.stdout.take()
+      .ok_or_else(|| RTDError::custom("subprocess stdout is not piped".to_string()))?;
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+      for line in BufReader::new(stdout).lines() {
+        let line = match line {
+          Ok(line) => line,
+          Err(_) => break,
+        };
+        if line.trim().is_empty() { continue }
+        if sender.send(line).is_err() { break }
+      }
+    });
+    Ok(Self {
+      child: Mutex::new(child),
+      stdin: Mutex::new(stdin),
+      stdout: Mutex::new(receiver),
+    })
+  }
+}
+
+impl TdTransport for ProcessTransport {
+  fn send(&self, request: &str) -> RTDResult<()> {
+    let mut stdin = self.stdin.lock().unwrap();
+    stdin.write_all(request.as_bytes())?;
+    stdin.write_all(b"\n")?;
+    Ok(stdin.flush()?)
+  }
+
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    recv_timeout(&self.stdout, timeout)
+  }
+
+  fn execute(&self, _request: &str) -> Option<String> { None }
+}
+
+impl Drop for ProcessTransport {
+  fn drop(&mut self) {
+    let mut child = self.child.lock().unwrap();
+    let _ = child.kill();
+    let _ = child.wait();
+  }
+}
@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::errors::*;
+use crate::types::*;
+
+use super::TdTransport;
+
+/// Which way a recorded json went
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+  /// A request sent to tdlib
+  Send,
+  /// A reply or update received from tdlib
+  Receive,
+  /// A request run synchronously by `execute`
+  Execute,
+  /// The reply `execute` returned, to the `Execute` record before it
+  Executed,
+}
+
+/// One line of a traffic recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficRecord {
+  /// Unix time in milliseconds
+  pub timestamp: u64,
+  pub direction: Direction,
+  /// `@type` of the json
+  #[serde(rename = "type")]
+  pub td_type: Option<String>,
+  /// `@extra` of the json
+  pub extra: Option<Extra>,
+  /// The json exactly as sent or received
+  pub json: String,
+}
+
+impl TrafficRecord {
+  fn new(direction: Direction, json: &str) -> Self {
+    let (td_type, extra) = detect_td_type_and_extra(json);
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_millis() as u64).unwrap_or(0);
+    Self { timestamp, direction, td_type, extra, json: json.to_string() }
+  }
+}
+
+/// Read all records of a jsonl recording
+pub fn read_records<P: AsRef<Path>>(path: P) -> RTDResult<Vec<TrafficRecord>> {
+  BufReader::new(File::open(path)?).lines()
+    .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+    .map(|line| Ok(serde_json::from_str(&line?)?))
+    .collect()
+}
+
+/// Transport wrapper appending every request and every received reply or update to a jsonl recording
+///
+/// ```no_run
+/// use rtdlib::transport::{ChannelTransport, Recorder};
+///
+/// let (transport, _peer) = ChannelTransport::pair();
+/// let transport = Recorder::create(transport, "/tmp/tdlib.jsonl").unwrap();
+/// ```
+pub struct Recorder<T: TdTransport> {
+  transport: T,
+  writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl<T: TdTransport> Recorder<T> {
+  /// Record to `writer`
+  pub fn new<W: Write + Send + 'static>(transport: T, writer: W) -> Self {
+    Self { transport, writer: Mutex::new(Box::new(writer)) }
+  }
+
+  /// Record to the file at `path`, appending if it exists
+  pub fn create<P: AsRef<Path>>(transport: T, path: P) -> RTDResult<Self> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    Ok(Self::new(transport, BufWriter::new(file)))
+  }
+
+  /// The wrapped transport
+  pub fn transport(&self) -> &T { &self.transport }
+
+  fn record(&self, direction: Direction, json: &str) -> RTDResult<()> {
+    let line = serde_json::to_string(&TrafficRecord::new(direction, json))?;
+    let mut writer = self.writer.lock().unwrap();
+    writeln!(writer, "{}", line)?;
+    Ok(writer.flush()?)
+  }
+}
+
+impl<T: TdTransport> TdTransport for Recorder<T> {
+  fn send(&self, request: &str) -> RTDResult<()> {
+    self.record(Direction::Send, request)?;
+    self.transport.send(request)
+  }
+
+  /// Failing to record a received json doesn't lose it, the json is still returned
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    let json = self.transport.receive(timeout)?;
+    let _ = self.record(Direction::Receive, &json);
+    Some(json)
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    let _ = self.record(Direction::Execute, request);
+    let json = self.transport.execute(request)?;
+    let _ = self.record(Direction::Executed, &json);
+    Some(json)
+  }
+}
+
+/// Transport replaying the received side of a recording, in order. Requests sent to it are dropped,
+/// so recorded replies arrive as updates unless their `@extra` is pending. `execute` answers with the
+/// recorded reply of the first recorded `execute` of a request of the same `@type` not answered yet.
+///
+/// With a `speed` the original gaps between records are kept, divided by `speed` (`Some(2.0)` replays
+/// twice as fast), without it records are returned as fast as they are received. The replay starts
+/// with the first `receive`.
+pub struct Replayer {
+  records: Mutex<VecDeque<TrafficRecord>>,
+  /// `@type` of the request and the reply of every recorded `execute`
+  executed: Mutex<Vec<(Option<String>, String)>>,
+  speed: Option<f64>,
+  /// When the first `receive` was called
+  started: Mutex<Option<Instant>>,
+  first_timestamp: u64,
+}
+
+impl Replayer {
+  pub fn new(records: Vec<TrafficRecord>, speed: Option<f64>) -> Self {
+    let mut received = VecDeque::new();
+    let mut executed = vec![];
+    // the requests of `execute` not answered yet, a reply is to the latest of them
+    let mut executing = vec![];
+    for record in records {
+      match record.direction {
+        Direction::Send => {}
+        Direction::Receive => received.push_back(record),
+        Direction::Execute => executing.push(record.td_type),
+        Direction::Executed => executed.extend(executing.pop().map(|td_type| (td_type, record.json))),
+      }
+    }
+    let first_timestamp = received.front().map_or(0, |record| record.timestamp);
+    Self { records: Mutex::new(received), executed: Mutex::new(executed), speed, started: Mutex::new(None), first_timestamp }
+  }
+
+  /// Replay the jsonl recording at `path`
+  pub fn open<P: AsRef<Path>>(path: P, speed: Option<f64>) -> RTDResult<Self> {
+    Ok(Self::new(read_records(path)?, speed))
+  }
+
+  /// Whether every record was replayed
+  pub fn is_finished(&self) -> bool {
+    self.records.lock().unwrap().is_empty()
+  }
+
+  /// When `record` is due, relative to the start of the replay
+  fn due(&self, record: &TrafficRecord) -> Duration {
+    match self.speed {
+      Some(speed) if speed > 0.0 => {
+        let offset = Duration::from_millis(record.timestamp.saturating_sub(self.first_timestamp));
+        offset.div_f64(speed)
+      }
+      _ => Duration::from_secs(0),
+    }
+  }
+}
+
+impl TdTransport for Replayer {
+  fn send(&self, _request: &str) -> RTDResult<()> { Ok(()) }
+
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    let deadline = Instant::now() + timeout;
+    let started = *self.started.lock().unwrap().get_or_insert_with(Instant::now);
+    loop {
+      // sleep without the lock, the next record is checked again after it
+      let wait = {
+        let mut records = self.records.lock().unwrap();
+        match records.front() {
+          Some(record) => match self.due(record).checked_sub(started.elapsed()) {
+            Some(wait) if !wait.is_zero() => wait,
+            _ => return records.pop_front().map(|record| record.json),
+          },
+          None => timeout,
+        }
+      };
+      let remaining = deadline.saturating_duration_since(Instant::now());
+      if remaining.is_zero() {
+        return None;
+      }
+      thread::sleep(wait.min(remaining));
+    }
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    let td_type = detect_td_type(request);
+    let mut executed = self.executed.lock().unwrap();
+    let index = executed.iter().position(|(executed_type, _)| *executed_type == td_type)?;
+    Some(executed.remove(index).1)
+  }
+}
@@ -0,0 +1,84 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_double, c_int};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::errors::*;
+
+use super::TdMultiTransport;
+
+#[link(name = "tdjson")]
+extern "C" {
+  fn td_create_client_id() -> c_int;
+  fn td_send(client_id: c_int, request: *const c_char);
+  fn td_receive(timeout: c_double) -> *const c_char;
+  fn td_execute(request: *const c_char) -> *const c_char;
+}
+
+/// The tdjson dylib serving several clients, through `td_create_client_id`, `td_send` and
+/// `td_receive`. tdjson has one receive queue per process, every `Tdjson` receives from it, so
+/// there should be one `Tdjson`.
+///
+/// ```no_run
+/// use rtdlib::transport::{Tdjson, TdMultiTransport};
+///
+/// let tdjson = Tdjson::new();
+/// let client_id = tdjson.create_client_id().unwrap();
+/// tdjson.send(client_id, r#"{"@type":"getAuthorizationState"}"#).unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct Tdjson {
+  _private: (),
+}
+
+impl Tdjson {
+  pub fn new() -> Self { Self::default() }
+}
+
+/// Held while receiving, by every `Tdjson` of the process, see `receive`
+static RECEIVING: Mutex<()> = Mutex::new(());
+
+/// Copy the json tdjson returned, it is only valid until the next call of the same function
+///
+/// # Safety
+///
+/// `result` is null or what `td_receive` or `td_execute` just returned, with no call of the same
+/// function in between.
+unsafe fn json(result: *const c_char) -> Option<String> {
+  if result.is_null() {
+    return None;
+  }
+  // SAFETY: tdjson returns a nul terminated string, still valid per the contract of `json`. It is
+  // copied before returning, so nothing borrows tdjson's buffer afterwards.
+  Some(CStr::from_ptr(result).to_string_lossy().into_owned())
+}
+
+impl TdMultiTransport for Tdjson {
+  fn create_client_id(&self) -> RTDResult<i32> {
+    // SAFETY: takes nothing and can be called from any thread
+    Ok(unsafe { td_create_client_id() })
+  }
+
+  fn send(&self, client_id: i32, request: &str) -> RTDResult<()> {
+    let request = CString::new(request).map_err(|_| RTDError::custom("request contains a nul byte".to_string()))?;
+    // SAFETY: `request` is nul terminated and outlives the call, tdjson copies it before returning.
+    // An unknown `client_id` is answered with an error by tdjson, not undefined behavior.
+    unsafe { td_send(client_id, request.as_ptr()) };
+    Ok(())
+  }
+
+  fn receive(&self, timeout: Duration) -> Option<String> {
+    // SAFETY: tdjson has one receive queue and result buffer per process, overwritten by the next
+    // `td_receive`, which must not run concurrently. Neither `&self` nor a single `Tdjson` prevents
+    // that, so the process wide lock makes receiving exclusive until the result is copied by `json`.
+    let _receiving = RECEIVING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    unsafe { json(td_receive(timeout.as_secs_f64())) }
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    let request = CString::new(request).ok()?;
+    // SAFETY: as in `send` for `request`. The result buffer of `td_execute` is per thread, so it
+    // stays valid until `json` copies it on this thread.
+    unsafe { json(td_execute(request.as_ptr())) }
+  }
+}
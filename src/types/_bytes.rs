@@ -0,0 +1,81 @@
+use std::fmt;
+use std::ops::Deref;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+
+/// Tdlib `bytes`, base64 encoded in json
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bytes(Vec<u8>);
+
+impl Bytes {
+  pub fn new<B: Into<Vec<u8>>>(bytes: B) -> Self { Bytes(bytes.into()) }
+
+  /// Decode base64, as tdlib sends it
+  pub fn from_base64<S: AsRef<str>>(base64: S) -> Result<Self, base64::DecodeError> {
+    base64::decode(base64.as_ref()).map(Bytes)
+  }
+
+  pub fn to_base64(&self) -> String { base64::encode(&self.0) }
+
+  pub fn as_slice(&self) -> &[u8] { &self.0 }
+
+  pub fn into_vec(self) -> Vec<u8> { self.0 }
+}
+
+impl fmt::Debug for Bytes {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Bytes({})", self.to_base64())
+  }
+}
+
+impl Deref for Bytes {
+  type Target = [u8];
+  fn deref(&self) -> &[u8] { &self.0 }
+}
+
+impl AsRef<[u8]> for Bytes {
+  fn as_ref(&self) -> &[u8] { &self.0 }
+}
+
+impl AsRef<Bytes> for Bytes {
+  fn as_ref(&self) -> &Bytes { self }
+}
+
+impl From<Vec<u8>> for Bytes {
+  fn from(bytes: Vec<u8>) -> Self { Bytes(bytes) }
+}
+
+impl From<&[u8]> for Bytes {
+  fn from(bytes: &[u8]) -> Self { Bytes(bytes.to_vec()) }
+}
+
+impl From<Bytes> for Vec<u8> {
+  fn from(bytes: Bytes) -> Self { bytes.0 }
+}
+
+impl Serialize for Bytes {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&self.to_base64())
+  }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_str(BytesVisitor)
+  }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+  type Value = Bytes;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("base64 encoded bytes")
+  }
+
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Bytes, E> {
+    Bytes::from_base64(value).map_err(|err| E::custom(format!("invalid base64: {}", err)))
+  }
+}
@@ -1,1550 +1,582 @@
-use std::fmt::Debug;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
 
-use serde::de::{Deserialize, Deserializer};
+use serde::de::{self, Deserialize, DeserializeOwned, DeserializeSeed, Deserializer, IgnoredAny, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::de::value::MapAccessDeserializer;
+use serde::de::Error as _;
 
 use crate::errors::*;
+use crate::json::{DefaultJson, JsonBackend};
 use crate::types::*;
+use crate::types::_lenient::keep_diagnostics;
 
 macro_rules! rtd_enum_deserialize {
-  ($type_name:ident, $(($td_name:ident, $enum_item:ident));*;) => {
+  // variants in a feature are prefixed by its `#[cfg(...)]`
+  ($type_name:ident, $unknown_item:ident; $($(#[$attr:meta])* ($td_name:ident, $enum_item:ident));*;) => {
+    rtd_enum_deserialize!(@impl $type_name, $unknown_item, Some($type_name::_Default(())); $($(#[$attr])* ($td_name, $enum_item));*;)
+  };
+  // enums without a `_Default` variant, like `TdType`
+  ($type_name:ident, $unknown_item:ident, no_default; $($(#[$attr:meta])* ($td_name:ident, $enum_item:ident));*;) => {
+    rtd_enum_deserialize!(@impl $type_name, $unknown_item, None; $($(#[$attr])* ($td_name, $enum_item));*;)
+  };
+  (@impl $type_name:ident, $unknown_item:ident, $default:expr; $($(#[$attr:meta])* ($td_name:ident, $enum_item:ident));*;) => {
     // example json
     // {"@type":"authorizationStateWaitEncryptionKey","is_encrypted":false}
     |deserializer: D| -> Result<$type_name, D::Error> {
-      let rtd_trait_value: serde_json::Value = Deserialize::deserialize(deserializer)?;
-      // the `rtd_trait_value` variable type is &serde_json::Value, tdlib trait will return a object, convert this type to object `&Map<String, Value>`
-      let rtd_trait_map = match rtd_trait_value.as_object() {
-        Some(map) => map,
-        None => return Err(
-          D::Error::custom(format!(
-            "{} is not the correct type", stringify!($type_name)
-          ))
-        ) // &format!("{} is not the correct type", stringify!($field))[..]
-      };
-      // get `@type` value, detect specific types
-      let rtd_trait_type = match rtd_trait_map.get("@type") {
-        // the `t` variable type is `serde_json::Value`, convert `t` to str
-        Some(t) => match t.as_str() {
-          Some(s) => s,
-          None => return Err(
+      // lenient decoding works on the parsed value, see `from_json_lenient`
+      if crate::types::_lenient::is_decoding() {
+        return rtd_enum_deserialize!(@value deserializer, $type_name, $unknown_item, $default; $($(#[$attr])* ($td_name, $enum_item));*;);
+      }
+      // dispatch on `@type` as the object is read, the variant reads the rest of it
+      struct Dispatch;
+      impl crate::types::_common::Tagged for Dispatch {
+        type Value = $type_name;
+        const NAME: &'static str = stringify!($type_name);
+        fn deserialize_tagged<'de, O: Deserializer<'de>>(td_type: &str, object: O) -> Result<$type_name, O::Error> {
+          // each arm is a call, a match building the variants inline takes a huge stack frame in debug builds
+          match td_type {
+            $(
+              $(#[$attr])*
+              stringify!($td_name) => crate::types::_common::deserialize_variant(object, $type_name::$enum_item, concat!(
+                stringify!($td_name), " can't deserialize to ", stringify!($type_name), "::", stringify!($enum_item),
+              )),
+            )*
+            // a type this crate doesn't know yet, keep it instead of failing the whole object
+            _ => Ok($type_name::$unknown_item(UnknownObject::new(td_type, Deserialize::deserialize(object)?))),
+          }
+        }
+      }
+      crate::types::_common::deserialize_tagged::<Dispatch, D>(deserializer)
+    }
+  };
+  (@value $deserializer:ident, $type_name:ident, $unknown_item:ident, $default:expr; $($(#[$attr:meta])* ($td_name:ident, $enum_item:ident));*;) => {{
+    let rtd_trait_value: serde_json::Value = Deserialize::deserialize($deserializer)?;
+    // lenient decoding falls back to the default variant instead of failing, see `from_json_lenient`
+    let rtd_trait_fail = |message: String, is_null: bool| -> Result<$type_name, D::Error> {
+      let default: Option<$type_name> = $default;
+      match default {
+        Some(default) if crate::types::_lenient::is_decoding() => {
+          // null is how a default variant serializes, nothing to report
+          if !is_null { crate::types::_lenient::diagnose(message) }
+          Ok(default)
+        }
+        _ => Err(D::Error::custom(message)),
+      }
+    };
+    // the `rtd_trait_value` variable type is &serde_json::Value, tdlib trait will return a object, convert this type to object `&Map<String, Value>`
+    let rtd_trait_map = match rtd_trait_value.as_object() {
+      Some(map) => map,
+      None => return rtd_trait_fail(
+        format!("{} is not the correct type", stringify!($type_name)),
+        rtd_trait_value.is_null(),
+      ) // &format!("{} is not the correct type", stringify!($field))[..]
+    };
+    // get `@type` value, detect specific types
+    let rtd_trait_type = match rtd_trait_map.get("@type") {
+      // the `t` variable type is `serde_json::Value`, convert `t` to str
+      Some(t) => match t.as_str() {
+        Some(s) => s.to_string(),
+        None => return rtd_trait_fail(
+          format!("{} -> @type is not the correct type", stringify!($type_name)),
+          false,
+        ) // &format!("{} -> @type is not the correct type", stringify!($field))[..]
+      },
+      None => return rtd_trait_fail(format!("unknown field {} -> @type", stringify!($type_name)), false)
+    };
+
+    let obj = match rtd_trait_type.as_str() {
+      $(
+        $(#[$attr])*
+        stringify!($td_name) => $type_name::$enum_item(match crate::types::_lenient::from_value(rtd_trait_value) {
+          Ok(t) => t,
+          Err(_e) => return Err(
             D::Error::custom(format!(
-              "{} -> @type is not the correct type", stringify!($type_name)
+              "{} can't deserialize to {}::{}; {:?}",
+              stringify!($td_name),
+              stringify!($type_name),
+              stringify!($enum_item),
+              _e,
             ))
-          ) // &format!("{} -> @type is not the correct type", stringify!($field))[..]
-        },
-        None => return Err(D::Error::custom(format!("unknown field {} -> @type", stringify!($type_name))))
-      };
-
-      let obj = match rtd_trait_type {
-        $(
-          stringify!($td_name) => $type_name::$enum_item(match serde_json::from_value(rtd_trait_value.clone()) {
-            Ok(t) => t,
-            Err(_e) => return Err(
-              D::Error::custom(format!(
-                "{} can't deserialize to {}::{}; {:?}",
-                stringify!($td_name),
-                stringify!($type_name),
-                stringify!($enum_item),
-                _e,
-              ))
-            )
-          }),
-        )*
-        _ => return Err(D::Error::custom(format!("missing field {}", rtd_trait_type)))
-      };
-      Ok(obj)
-    }
-  }
+          )
+        }),
+      )*
+      _ => $type_name::$unknown_item(UnknownObject::new(&rtd_trait_type, rtd_trait_value)),
+    };
+    Ok(obj)
+  }};
 }
 
 
-///// tuple enum is field
-//macro_rules! tuple_enum_is {
-//  ($enum_name:ident, $field:ident) => {
-//    |o: &$enum_name| {
-//      if let $enum_name::$field(_) = o { true } else { false }
-//    }
-//  };
-////  ($e:ident, $t:ident, $namespace:ident) => {
-////    Box::new(|t: &$e| {
-////      match t {
-////        $namespace::$e::$t(_) => true,
-////        _ => false
-////      }
-////    })
-////  };
-//}
-//
-//macro_rules! tuple_enum_on {
-//  ($enum_name:ident, $field:ident, $fnc:expr) => {
-//    |o: &$enum_name| {
-//      if let $enum_name::$field(t) = o { $fnc(t) }
-//    }
-//  };
-//}
-
-pub fn detect_td_type<S: AsRef<str>>(json: S) -> Option<String> {
-  let result: Result<serde_json::Value, serde_json::Error> = serde_json::from_str::<serde_json::Value>(json.as_ref());
-  if let Err(_) = result { return None }
-  let value = result.unwrap();
-  value.as_object().map_or(None, |v| {
-    v.get("@type").map_or(None, |t| t.as_str().map_or(None, |t| {
-      Some(t.to_string())
-    }))
-  })
+/// Items behind the same `#[cfg(...)]`, like a generated type and its impls in a feature
+macro_rules! rtd_cfg {
+  (#[$cfg:meta] $($item:item)*) => {
+    $(#[$cfg] $item)*
+  };
 }
 
-pub fn detect_td_type_and_extra<S: AsRef<str>>(json: S) -> (Option<String>, Option<String>) {
-  let result: Result<serde_json::Value, serde_json::Error> = serde_json::from_str::<serde_json::Value>(json.as_ref());
-  if let Err(_) = result { return (None, None) }
-  let value = result.unwrap();
-  let mut type_ = None;
-  let mut extra = None;
-  if let Some(map) = value.as_object() {
-    map.get("@type").map(|v| v.as_str().map(|t| type_.replace(t.to_string())));
-    map.get("@extra").map(|v| v.as_str().map(|t| extra.replace(t.to_string())));
-  }
-  (type_, extra)
-}
 
-pub fn from_json<'a, T>(json: &'a str) -> RTDResult<T> where T: serde::de::Deserialize<'a>, {
-  Ok(serde_json::from_str(json.as_ref())?)
+pub fn detect_td_type<S: AsRef<str>>(json: S) -> Option<String> {
+  detect_td_type_and_extra(json).0
 }
 
-/// All tdlib type abstract class defined the same behavior
-pub trait RObject: Debug {
-  #[doc(hidden)]
-  fn td_name(&self) -> &'static str;
-  #[doc(hidden)]
-  fn extra(&self) -> Option<String>;
-  /// Return td type to json string
-  fn to_json(&self) -> RTDResult<String>;
+pub fn detect_td_type_and_extra<S: AsRef<str>>(json: S) -> (Option<String>, Option<Extra>) {
+  let header = detect_td_header(json);
+  (header.td_type, header.extra)
 }
 
-pub trait RFunction: Debug + RObject {}
-
-
-impl<'a, RObj: RObject> RObject for &'a RObj {
-  fn td_name(&self) -> &'static str { (*self).td_name() }
-  fn to_json(&self) -> RTDResult<String> { (*self).to_json() }
-  fn extra(&self) -> Option<String> { (*self).extra() }
+/// The `@type`, `@extra` and `@client_id` of a td object, e.g. to route a reply received for one of
+/// several clients without decoding it. Empty if the json is no object.
+pub fn detect_td_header<S: AsRef<str>>(json: S) -> TdHeader {
+  DefaultJson::from_str::<TdHeader>(json.as_ref()).unwrap_or_default()
 }
 
-impl<'a, RObj: RObject> RObject for &'a mut RObj {
-  fn td_name(&self) -> &'static str { (**self).td_name() }
-  fn to_json(&self) -> RTDResult<String> { (**self).to_json() }
-  fn extra(&self) -> Option<String> { (**self).extra() }
+/// The `@type`, `@extra` and `@client_id` of a td object, read skipping the other values without building them
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TdHeader {
+  pub td_type: Option<String>,
+  pub extra: Option<Extra>,
+  /// `None` for a `@client_id` out of the range of `i32` too, it is no client's
+  pub client_id: Option<i32>,
 }
 
+impl<'de> Deserialize<'de> for TdHeader {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_map(HeaderVisitor)
+  }
+}
 
-impl<'a, Fnc: RFunction> RFunction for &'a Fnc {}
-impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {}
-
-
-impl<'a, AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType for &'a AUTHENTICATIONCODETYPE {}
-impl<'a, AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType for &'a mut AUTHENTICATIONCODETYPE {}
-
-impl<'a, AUTHORIZATIONSTATE: TDAuthorizationState> TDAuthorizationState for &'a AUTHORIZATIONSTATE {}
-impl<'a, AUTHORIZATIONSTATE: TDAuthorizationState> TDAuthorizationState for &'a mut AUTHORIZATIONSTATE {}
-
-impl<'a, BACKGROUNDFILL: TDBackgroundFill> TDBackgroundFill for &'a BACKGROUNDFILL {}
-impl<'a, BACKGROUNDFILL: TDBackgroundFill> TDBackgroundFill for &'a mut BACKGROUNDFILL {}
-
-impl<'a, BACKGROUNDTYPE: TDBackgroundType> TDBackgroundType for &'a BACKGROUNDTYPE {}
-impl<'a, BACKGROUNDTYPE: TDBackgroundType> TDBackgroundType for &'a mut BACKGROUNDTYPE {}
-
-impl<'a, BOTCOMMANDSCOPE: TDBotCommandScope> TDBotCommandScope for &'a BOTCOMMANDSCOPE {}
-impl<'a, BOTCOMMANDSCOPE: TDBotCommandScope> TDBotCommandScope for &'a mut BOTCOMMANDSCOPE {}
-
-impl<'a, CALLDISCARDREASON: TDCallDiscardReason> TDCallDiscardReason for &'a CALLDISCARDREASON {}
-impl<'a, CALLDISCARDREASON: TDCallDiscardReason> TDCallDiscardReason for &'a mut CALLDISCARDREASON {}
-
-impl<'a, CALLPROBLEM: TDCallProblem> TDCallProblem for &'a CALLPROBLEM {}
-impl<'a, CALLPROBLEM: TDCallProblem> TDCallProblem for &'a mut CALLPROBLEM {}
-
-impl<'a, CALLSERVERTYPE: TDCallServerType> TDCallServerType for &'a CALLSERVERTYPE {}
-impl<'a, CALLSERVERTYPE: TDCallServerType> TDCallServerType for &'a mut CALLSERVERTYPE {}
-
-impl<'a, CALLSTATE: TDCallState> TDCallState for &'a CALLSTATE {}
-impl<'a, CALLSTATE: TDCallState> TDCallState for &'a mut CALLSTATE {}
-
-impl<'a, CALLBACKQUERYPAYLOAD: TDCallbackQueryPayload> TDCallbackQueryPayload for &'a CALLBACKQUERYPAYLOAD {}
-impl<'a, CALLBACKQUERYPAYLOAD: TDCallbackQueryPayload> TDCallbackQueryPayload for &'a mut CALLBACKQUERYPAYLOAD {}
-
-impl<'a, CANTRANSFEROWNERSHIPRESULT: TDCanTransferOwnershipResult> TDCanTransferOwnershipResult for &'a CANTRANSFEROWNERSHIPRESULT {}
-impl<'a, CANTRANSFEROWNERSHIPRESULT: TDCanTransferOwnershipResult> TDCanTransferOwnershipResult for &'a mut CANTRANSFEROWNERSHIPRESULT {}
-
-impl<'a, CHATACTION: TDChatAction> TDChatAction for &'a CHATACTION {}
-impl<'a, CHATACTION: TDChatAction> TDChatAction for &'a mut CHATACTION {}
-
-impl<'a, CHATACTIONBAR: TDChatActionBar> TDChatActionBar for &'a CHATACTIONBAR {}
-impl<'a, CHATACTIONBAR: TDChatActionBar> TDChatActionBar for &'a mut CHATACTIONBAR {}
-
-impl<'a, CHATEVENTACTION: TDChatEventAction> TDChatEventAction for &'a CHATEVENTACTION {}
-impl<'a, CHATEVENTACTION: TDChatEventAction> TDChatEventAction for &'a mut CHATEVENTACTION {}
-
-impl<'a, CHATLIST: TDChatList> TDChatList for &'a CHATLIST {}
-impl<'a, CHATLIST: TDChatList> TDChatList for &'a mut CHATLIST {}
-
-impl<'a, CHATMEMBERSTATUS: TDChatMemberStatus> TDChatMemberStatus for &'a CHATMEMBERSTATUS {}
-impl<'a, CHATMEMBERSTATUS: TDChatMemberStatus> TDChatMemberStatus for &'a mut CHATMEMBERSTATUS {}
-
-impl<'a, CHATMEMBERSFILTER: TDChatMembersFilter> TDChatMembersFilter for &'a CHATMEMBERSFILTER {}
-impl<'a, CHATMEMBERSFILTER: TDChatMembersFilter> TDChatMembersFilter for &'a mut CHATMEMBERSFILTER {}
-
-impl<'a, CHATREPORTREASON: TDChatReportReason> TDChatReportReason for &'a CHATREPORTREASON {}
-impl<'a, CHATREPORTREASON: TDChatReportReason> TDChatReportReason for &'a mut CHATREPORTREASON {}
-
-impl<'a, CHATSOURCE: TDChatSource> TDChatSource for &'a CHATSOURCE {}
-impl<'a, CHATSOURCE: TDChatSource> TDChatSource for &'a mut CHATSOURCE {}
-
-impl<'a, CHATSTATISTICS: TDChatStatistics> TDChatStatistics for &'a CHATSTATISTICS {}
-impl<'a, CHATSTATISTICS: TDChatStatistics> TDChatStatistics for &'a mut CHATSTATISTICS {}
+struct HeaderVisitor;
 
-impl<'a, CHATTYPE: TDChatType> TDChatType for &'a CHATTYPE {}
-impl<'a, CHATTYPE: TDChatType> TDChatType for &'a mut CHATTYPE {}
+impl<'de> Visitor<'de> for HeaderVisitor {
+  type Value = TdHeader;
 
-impl<'a, CHECKCHATUSERNAMERESULT: TDCheckChatUsernameResult> TDCheckChatUsernameResult for &'a CHECKCHATUSERNAMERESULT {}
-impl<'a, CHECKCHATUSERNAMERESULT: TDCheckChatUsernameResult> TDCheckChatUsernameResult for &'a mut CHECKCHATUSERNAMERESULT {}
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a td object")
+  }
 
-impl<'a, CHECKSTICKERSETNAMERESULT: TDCheckStickerSetNameResult> TDCheckStickerSetNameResult for &'a CHECKSTICKERSETNAMERESULT {}
-impl<'a, CHECKSTICKERSETNAMERESULT: TDCheckStickerSetNameResult> TDCheckStickerSetNameResult for &'a mut CHECKSTICKERSETNAMERESULT {}
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+    let mut header = TdHeader::default();
+    while let Some(key) = map.next_key::<Key>()? {
+      match key {
+        Key::Type => header.td_type = map.next_value::<HeaderValue>()?.string,
+        Key::Extra => header.extra = Some(map.next_value::<serde_json::Value>()?).filter(|extra| !extra.is_null()).map(Extra::from),
+        Key::ClientId => header.client_id = map.next_value::<HeaderValue>()?.integer.and_then(|client_id| i32::try_from(client_id).ok()),
+        Key::Other(_) => { map.next_value::<IgnoredAny>()?; }
+      }
+    }
+    Ok(header)
+  }
+}
 
-impl<'a, CONNECTIONSTATE: TDConnectionState> TDConnectionState for &'a CONNECTIONSTATE {}
-impl<'a, CONNECTIONSTATE: TDConnectionState> TDConnectionState for &'a mut CONNECTIONSTATE {}
+/// `@type` or `@client_id` of a header, a value of another kind is skipped instead of failing the header
+#[derive(Default)]
+struct HeaderValue {
+  string: Option<String>,
+  integer: Option<i64>,
+}
 
-impl<'a, DEVICETOKEN: TDDeviceToken> TDDeviceToken for &'a DEVICETOKEN {}
-impl<'a, DEVICETOKEN: TDDeviceToken> TDDeviceToken for &'a mut DEVICETOKEN {}
+impl<'de> Deserialize<'de> for HeaderValue {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(HeaderValueVisitor)
+  }
+}
 
-impl<'a, DICESTICKERS: TDDiceStickers> TDDiceStickers for &'a DICESTICKERS {}
-impl<'a, DICESTICKERS: TDDiceStickers> TDDiceStickers for &'a mut DICESTICKERS {}
+struct HeaderValueVisitor;
 
-impl<'a, FILETYPE: TDFileType> TDFileType for &'a FILETYPE {}
-impl<'a, FILETYPE: TDFileType> TDFileType for &'a mut FILETYPE {}
+impl<'de> Visitor<'de> for HeaderValueVisitor {
+  type Value = HeaderValue;
 
-impl<'a, GROUPCALLVIDEOQUALITY: TDGroupCallVideoQuality> TDGroupCallVideoQuality for &'a GROUPCALLVIDEOQUALITY {}
-impl<'a, GROUPCALLVIDEOQUALITY: TDGroupCallVideoQuality> TDGroupCallVideoQuality for &'a mut GROUPCALLVIDEOQUALITY {}
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("any value")
+  }
 
-impl<'a, INLINEKEYBOARDBUTTONTYPE: TDInlineKeyboardButtonType> TDInlineKeyboardButtonType for &'a INLINEKEYBOARDBUTTONTYPE {}
-impl<'a, INLINEKEYBOARDBUTTONTYPE: TDInlineKeyboardButtonType> TDInlineKeyboardButtonType for &'a mut INLINEKEYBOARDBUTTONTYPE {}
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<HeaderValue, E> {
+    Ok(HeaderValue { string: Some(value.to_string()), ..HeaderValue::default() })
+  }
 
-impl<'a, INLINEQUERYRESULT: TDInlineQueryResult> TDInlineQueryResult for &'a INLINEQUERYRESULT {}
-impl<'a, INLINEQUERYRESULT: TDInlineQueryResult> TDInlineQueryResult for &'a mut INLINEQUERYRESULT {}
+  fn visit_i64<E: de::Error>(self, value: i64) -> Result<HeaderValue, E> {
+    Ok(HeaderValue { integer: Some(value), ..HeaderValue::default() })
+  }
 
-impl<'a, INPUTBACKGROUND: TDInputBackground> TDInputBackground for &'a INPUTBACKGROUND {}
-impl<'a, INPUTBACKGROUND: TDInputBackground> TDInputBackground for &'a mut INPUTBACKGROUND {}
+  fn visit_u64<E: de::Error>(self, value: u64) -> Result<HeaderValue, E> {
+    Ok(HeaderValue { integer: i64::try_from(value).ok(), ..HeaderValue::default() })
+  }
 
-impl<'a, INPUTCHATPHOTO: TDInputChatPhoto> TDInputChatPhoto for &'a INPUTCHATPHOTO {}
-impl<'a, INPUTCHATPHOTO: TDInputChatPhoto> TDInputChatPhoto for &'a mut INPUTCHATPHOTO {}
+  fn visit_f64<E: de::Error>(self, _value: f64) -> Result<HeaderValue, E> { Ok(HeaderValue::default()) }
+  fn visit_bool<E: de::Error>(self, _value: bool) -> Result<HeaderValue, E> { Ok(HeaderValue::default()) }
+  fn visit_unit<E: de::Error>(self) -> Result<HeaderValue, E> { Ok(HeaderValue::default()) }
+  fn visit_none<E: de::Error>(self) -> Result<HeaderValue, E> { Ok(HeaderValue::default()) }
 
-impl<'a, INPUTCREDENTIALS: TDInputCredentials> TDInputCredentials for &'a INPUTCREDENTIALS {}
-impl<'a, INPUTCREDENTIALS: TDInputCredentials> TDInputCredentials for &'a mut INPUTCREDENTIALS {}
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<HeaderValue, A::Error> {
+    while seq.next_element::<IgnoredAny>()?.is_some() {}
+    Ok(HeaderValue::default())
+  }
 
-impl<'a, INPUTFILE: TDInputFile> TDInputFile for &'a INPUTFILE {}
-impl<'a, INPUTFILE: TDInputFile> TDInputFile for &'a mut INPUTFILE {}
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<HeaderValue, A::Error> {
+    while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+    Ok(HeaderValue::default())
+  }
+}
 
-impl<'a, INPUTINLINEQUERYRESULT: TDInputInlineQueryResult> TDInputInlineQueryResult for &'a INPUTINLINEQUERYRESULT {}
-impl<'a, INPUTINLINEQUERYRESULT: TDInputInlineQueryResult> TDInputInlineQueryResult for &'a mut INPUTINLINEQUERYRESULT {}
+/// Decode json to a td type, leniently after `set_lenient_decoding(true)`
+pub fn from_json<T: DeserializeOwned>(json: &str) -> RTDResult<T> {
+  if is_lenient_decoding() {
+    let (value, diagnostics) = from_json_lenient(json)?;
+    keep_diagnostics(diagnostics);
+    return Ok(value);
+  }
+  from_json_strict(json)
+}
 
-impl<'a, INPUTMESSAGECONTENT: TDInputMessageContent> TDInputMessageContent for &'a INPUTMESSAGECONTENT {}
-impl<'a, INPUTMESSAGECONTENT: TDInputMessageContent> TDInputMessageContent for &'a mut INPUTMESSAGECONTENT {}
+/// Parse tdlib reply json of function `F` to it's response type, an `error` reply will return `RTDError::Tdlib`
+pub fn parse_response<F: RFunction>(json: &str) -> RTDResult<F::Response> {
+  if is_lenient_decoding() {
+    if detect_td_type(json).as_deref() == Some("error") {
+      return Err(RTDError::Tdlib(from_json::<Error>(json)?));
+    }
+    return from_json::<F::Response>(json);
+  }
+  // one pass, an `error` decodes to `Error` and anything else to the response
+  let reply: Reply<F::Response> = from_json_strict(json)?;
+  match reply {
+    Reply::Error(error) => Err(RTDError::Tdlib(error)),
+    Reply::Response(response) => Ok(response),
+  }
+}
 
-impl<'a, INPUTPASSPORTELEMENT: TDInputPassportElement> TDInputPassportElement for &'a INPUTPASSPORTELEMENT {}
-impl<'a, INPUTPASSPORTELEMENT: TDInputPassportElement> TDInputPassportElement for &'a mut INPUTPASSPORTELEMENT {}
+enum Reply<T> {
+  Error(Error),
+  Response(T),
+}
 
-impl<'a, INPUTPASSPORTELEMENTERRORSOURCE: TDInputPassportElementErrorSource> TDInputPassportElementErrorSource for &'a INPUTPASSPORTELEMENTERRORSOURCE {}
-impl<'a, INPUTPASSPORTELEMENTERRORSOURCE: TDInputPassportElementErrorSource> TDInputPassportElementErrorSource for &'a mut INPUTPASSPORTELEMENTERRORSOURCE {}
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Reply<T> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserialize_tagged::<Reply<T>, D>(deserializer)
+  }
+}
 
-impl<'a, INPUTSTICKER: TDInputSticker> TDInputSticker for &'a INPUTSTICKER {}
-impl<'a, INPUTSTICKER: TDInputSticker> TDInputSticker for &'a mut INPUTSTICKER {}
+impl<T: DeserializeOwned> Tagged for Reply<T> {
+  type Value = Self;
+  const NAME: &'static str = "response";
 
-impl<'a, INTERNALLINKTYPE: TDInternalLinkType> TDInternalLinkType for &'a INTERNALLINKTYPE {}
-impl<'a, INTERNALLINKTYPE: TDInternalLinkType> TDInternalLinkType for &'a mut INTERNALLINKTYPE {}
+  fn deserialize_tagged<'de, D: Deserializer<'de>>(td_type: &str, object: D) -> Result<Self, D::Error> {
+    if td_type == "error" {
+      Ok(Reply::Error(Deserialize::deserialize(object)?))
+    } else {
+      Ok(Reply::Response(T::deserialize(object)?))
+    }
+  }
+}
 
-impl<'a, JSONVALUE: TDJsonValue> TDJsonValue for &'a JSONVALUE {}
-impl<'a, JSONVALUE: TDJsonValue> TDJsonValue for &'a mut JSONVALUE {}
+/// A td class, decoded by the `@type` of its objects, see `deserialize_tagged`
+pub(crate) trait Tagged {
+  type Value;
+  /// Name of the class, for errors
+  const NAME: &'static str;
 
-impl<'a, KEYBOARDBUTTONTYPE: TDKeyboardButtonType> TDKeyboardButtonType for &'a KEYBOARDBUTTONTYPE {}
-impl<'a, KEYBOARDBUTTONTYPE: TDKeyboardButtonType> TDKeyboardButtonType for &'a mut KEYBOARDBUTTONTYPE {}
+  /// Decode an object of type `td_type`, `object` yields all of its entries, `@type` included
+  fn deserialize_tagged<'de, D: Deserializer<'de>>(td_type: &str, object: D) -> Result<Self::Value, D::Error>;
+}
 
-impl<'a, LANGUAGEPACKSTRINGVALUE: TDLanguagePackStringValue> TDLanguagePackStringValue for &'a LANGUAGEPACKSTRINGVALUE {}
-impl<'a, LANGUAGEPACKSTRINGVALUE: TDLanguagePackStringValue> TDLanguagePackStringValue for &'a mut LANGUAGEPACKSTRINGVALUE {}
+/// Decode an object of the class `T` in one pass: the entries before `@type`, usually none, are
+/// kept, then the object is handed over to its type with them replayed ahead of the rest.
+pub(crate) fn deserialize_tagged<'de, T: Tagged, D: Deserializer<'de>>(deserializer: D) -> Result<T::Value, D::Error> {
+  deserializer.deserialize_map(TaggedVisitor::<T>(PhantomData))
+}
 
-impl<'a, LOGSTREAM: TDLogStream> TDLogStream for &'a LOGSTREAM {}
-impl<'a, LOGSTREAM: TDLogStream> TDLogStream for &'a mut LOGSTREAM {}
+struct TaggedVisitor<T>(PhantomData<T>);
 
-impl<'a, LOGINURLINFO: TDLoginUrlInfo> TDLoginUrlInfo for &'a LOGINURLINFO {}
-impl<'a, LOGINURLINFO: TDLoginUrlInfo> TDLoginUrlInfo for &'a mut LOGINURLINFO {}
+impl<'de, T: Tagged> Visitor<'de> for TaggedVisitor<T> {
+  type Value = T::Value;
 
-impl<'a, MASKPOINT: TDMaskPoint> TDMaskPoint for &'a MASKPOINT {}
-impl<'a, MASKPOINT: TDMaskPoint> TDMaskPoint for &'a mut MASKPOINT {}
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    write!(formatter, "{} object", T::NAME)
+  }
 
-impl<'a, MESSAGECONTENT: TDMessageContent> TDMessageContent for &'a MESSAGECONTENT {}
-impl<'a, MESSAGECONTENT: TDMessageContent> TDMessageContent for &'a mut MESSAGECONTENT {}
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T::Value, A::Error> {
+    let mut skipped = vec![];
+    while let Some(key) = map.next_key::<Key>()? {
+      let key = match key {
+        Key::Type => {
+          let td_type: String = map.next_value()?;
+          let object = Replay { skipped: skipped.into_iter(), td_type: Some(&td_type), next: None, rest: map };
+          return T::deserialize_tagged(&td_type, MapAccessDeserializer::new(object));
+        }
+        Key::Extra => "@extra".to_string(),
+        Key::ClientId => "@client_id".to_string(),
+        Key::Other(key) => key.into_owned(),
+      };
+      skipped.push((key, map.next_value::<serde_json::Value>()?));
+    }
+    Err(A::Error::custom(format!("unknown field {} -> @type", T::NAME)))
+  }
+}
 
-impl<'a, MESSAGEFILETYPE: TDMessageFileType> TDMessageFileType for &'a MESSAGEFILETYPE {}
-impl<'a, MESSAGEFILETYPE: TDMessageFileType> TDMessageFileType for &'a mut MESSAGEFILETYPE {}
+/// Decode a variant of a td class from its object, `context` prefixes its errors
+pub(crate) fn deserialize_variant<'de, V, T, D, F>(object: D, variant: F, context: &str) -> Result<T, D::Error>
+  where V: Deserialize<'de>, D: Deserializer<'de>, F: FnOnce(V) -> T {
+  match V::deserialize(object) {
+    Ok(value) => Ok(variant(value)),
+    Err(e) => Err(D::Error::custom(format!("{}; {:?}", context, e))),
+  }
+}
 
-impl<'a, MESSAGEFORWARDORIGIN: TDMessageForwardOrigin> TDMessageForwardOrigin for &'a MESSAGEFORWARDORIGIN {}
-impl<'a, MESSAGEFORWARDORIGIN: TDMessageForwardOrigin> TDMessageForwardOrigin for &'a mut MESSAGEFORWARDORIGIN {}
+/// A key of a td object, `@type`, `@extra` and `@client_id` are told apart without allocating, other
+/// keys are borrowed from the json unless they had to be unescaped
+enum Key<'de> {
+  Type,
+  Extra,
+  ClientId,
+  Other(Cow<'de, str>),
+}
 
-impl<'a, MESSAGESCHEDULINGSTATE: TDMessageSchedulingState> TDMessageSchedulingState for &'a MESSAGESCHEDULINGSTATE {}
-impl<'a, MESSAGESCHEDULINGSTATE: TDMessageSchedulingState> TDMessageSchedulingState for &'a mut MESSAGESCHEDULINGSTATE {}
+impl<'de> Deserialize<'de> for Key<'de> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_str(KeyVisitor)
+  }
+}
 
-impl<'a, MESSAGESENDER: TDMessageSender> TDMessageSender for &'a MESSAGESENDER {}
-impl<'a, MESSAGESENDER: TDMessageSender> TDMessageSender for &'a mut MESSAGESENDER {}
+struct KeyVisitor;
 
-impl<'a, MESSAGESENDINGSTATE: TDMessageSendingState> TDMessageSendingState for &'a MESSAGESENDINGSTATE {}
-impl<'a, MESSAGESENDINGSTATE: TDMessageSendingState> TDMessageSendingState for &'a mut MESSAGESENDINGSTATE {}
+impl KeyVisitor {
+  fn key<'de, F: FnOnce() -> Cow<'de, str>>(value: &str, other: F) -> Key<'de> {
+    match value {
+      "@type" => Key::Type,
+      "@extra" => Key::Extra,
+      "@client_id" => Key::ClientId,
+      _ => Key::Other(other()),
+    }
+  }
+}
 
-impl<'a, NETWORKSTATISTICSENTRY: TDNetworkStatisticsEntry> TDNetworkStatisticsEntry for &'a NETWORKSTATISTICSENTRY {}
-impl<'a, NETWORKSTATISTICSENTRY: TDNetworkStatisticsEntry> TDNetworkStatisticsEntry for &'a mut NETWORKSTATISTICSENTRY {}
+impl<'de> Visitor<'de> for KeyVisitor {
+  type Value = Key<'de>;
 
-impl<'a, NETWORKTYPE: TDNetworkType> TDNetworkType for &'a NETWORKTYPE {}
-impl<'a, NETWORKTYPE: TDNetworkType> TDNetworkType for &'a mut NETWORKTYPE {}
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a field name")
+  }
 
-impl<'a, NOTIFICATIONGROUPTYPE: TDNotificationGroupType> TDNotificationGroupType for &'a NOTIFICATIONGROUPTYPE {}
-impl<'a, NOTIFICATIONGROUPTYPE: TDNotificationGroupType> TDNotificationGroupType for &'a mut NOTIFICATIONGROUPTYPE {}
+  fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Key<'de>, E> {
+    Ok(KeyVisitor::key(value, || Cow::Borrowed(value)))
+  }
 
-impl<'a, NOTIFICATIONSETTINGSSCOPE: TDNotificationSettingsScope> TDNotificationSettingsScope for &'a NOTIFICATIONSETTINGSSCOPE {}
-impl<'a, NOTIFICATIONSETTINGSSCOPE: TDNotificationSettingsScope> TDNotificationSettingsScope for &'a mut NOTIFICATIONSETTINGSSCOPE {}
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Key<'de>, E> {
+    Ok(KeyVisitor::key(value, || Cow::Owned(value.to_string())))
+  }
+}
 
-impl<'a, NOTIFICATIONTYPE: TDNotificationType> TDNotificationType for &'a NOTIFICATIONTYPE {}
-impl<'a, NOTIFICATIONTYPE: TDNotificationType> TDNotificationType for &'a mut NOTIFICATIONTYPE {}
+/// The entries of an object whose `@type` was read: those read before it, `@type`, then the rest
+struct Replay<'a, A> {
+  skipped: std::vec::IntoIter<(String, serde_json::Value)>,
+  /// Until its key is replayed
+  td_type: Option<&'a str>,
+  next: Option<Replayed<'a>>,
+  rest: A,
+}
 
-impl<'a, OPTIONVALUE: TDOptionValue> TDOptionValue for &'a OPTIONVALUE {}
-impl<'a, OPTIONVALUE: TDOptionValue> TDOptionValue for &'a mut OPTIONVALUE {}
+/// The value of a replayed key
+enum Replayed<'a> {
+  Skipped(serde_json::Value),
+  TdType(&'a str),
+}
 
-impl<'a, PAGEBLOCK: TDPageBlock> TDPageBlock for &'a PAGEBLOCK {}
-impl<'a, PAGEBLOCK: TDPageBlock> TDPageBlock for &'a mut PAGEBLOCK {}
+impl<'de, 'a, A: MapAccess<'de>> MapAccess<'de> for Replay<'a, A> {
+  type Error = A::Error;
 
-impl<'a, PAGEBLOCKHORIZONTALALIGNMENT: TDPageBlockHorizontalAlignment> TDPageBlockHorizontalAlignment for &'a PAGEBLOCKHORIZONTALALIGNMENT {}
-impl<'a, PAGEBLOCKHORIZONTALALIGNMENT: TDPageBlockHorizontalAlignment> TDPageBlockHorizontalAlignment for &'a mut PAGEBLOCKHORIZONTALALIGNMENT {}
+  fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error> {
+    if let Some((key, value)) = self.skipped.next() {
+      self.next = Some(Replayed::Skipped(value));
+      return seed.deserialize(key.into_deserializer()).map(Some);
+    }
+    if let Some(td_type) = self.td_type.take() {
+      self.next = Some(Replayed::TdType(td_type));
+      return seed.deserialize("@type".into_deserializer()).map(Some);
+    }
+    self.rest.next_key_seed(seed)
+  }
 
-impl<'a, PAGEBLOCKVERTICALALIGNMENT: TDPageBlockVerticalAlignment> TDPageBlockVerticalAlignment for &'a PAGEBLOCKVERTICALALIGNMENT {}
-impl<'a, PAGEBLOCKVERTICALALIGNMENT: TDPageBlockVerticalAlignment> TDPageBlockVerticalAlignment for &'a mut PAGEBLOCKVERTICALALIGNMENT {}
+  fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, A::Error> {
+    match self.next.take() {
+      Some(Replayed::Skipped(value)) => seed.deserialize(value).map_err(A::Error::custom),
+      Some(Replayed::TdType(td_type)) => seed.deserialize(td_type.into_deserializer()),
+      None => self.rest.next_value_seed(seed),
+    }
+  }
 
-impl<'a, PASSPORTELEMENT: TDPassportElement> TDPassportElement for &'a PASSPORTELEMENT {}
-impl<'a, PASSPORTELEMENT: TDPassportElement> TDPassportElement for &'a mut PASSPORTELEMENT {}
+  fn size_hint(&self) -> Option<usize> {
+    let replayed = self.skipped.len() + self.td_type.map_or(0, |_| 1);
+    self.rest.size_hint().map(|rest| rest + replayed)
+  }
+}
 
-impl<'a, PASSPORTELEMENTERRORSOURCE: TDPassportElementErrorSource> TDPassportElementErrorSource for &'a PASSPORTELEMENTERRORSOURCE {}
-impl<'a, PASSPORTELEMENTERRORSOURCE: TDPassportElementErrorSource> TDPassportElementErrorSource for &'a mut PASSPORTELEMENTERRORSOURCE {}
+/// All tdlib type abstract class defined the same behavior
+pub trait RObject: Debug {
+  #[doc(hidden)]
+  fn td_name(&self) -> &str;
+  #[doc(hidden)]
+  fn extra(&self) -> Option<Extra>;
+  /// The `@client_id` tdlib tags objects with when they are received by `td_receive` for one of
+  /// several clients, `None` for objects built here
+  fn client_id(&self) -> Option<i32>;
+  /// Return td type to json string
+  fn to_json(&self) -> RTDResult<String>;
+}
 
-impl<'a, PASSPORTELEMENTTYPE: TDPassportElementType> TDPassportElementType for &'a PASSPORTELEMENTTYPE {}
-impl<'a, PASSPORTELEMENTTYPE: TDPassportElementType> TDPassportElementType for &'a mut PASSPORTELEMENTTYPE {}
+/// All tdlib functions, each of them bound to the td type tdlib returns for it
+pub trait RFunction: Debug + RObject {
+  /// The td type tdlib returns as the result of this function
+  type Response: RObject + DeserializeOwned;
+  /// Serialize with `extra` as the `@extra`, the clients send a function without one this way
+  #[doc(hidden)]
+  fn to_json_with_extra(self, extra: &Extra) -> RTDResult<String> where Self: Sized;
+}
 
-impl<'a, POLLTYPE: TDPollType> TDPollType for &'a POLLTYPE {}
-impl<'a, POLLTYPE: TDPollType> TDPollType for &'a mut POLLTYPE {}
 
-impl<'a, PROXYTYPE: TDProxyType> TDProxyType for &'a PROXYTYPE {}
-impl<'a, PROXYTYPE: TDProxyType> TDProxyType for &'a mut PROXYTYPE {}
+impl<RObj: RObject> RObject for &RObj {
+  fn td_name(&self) -> &str { (*self).td_name() }
+  fn to_json(&self) -> RTDResult<String> { (*self).to_json() }
+  fn extra(&self) -> Option<Extra> { (*self).extra() }
+  fn client_id(&self) -> Option<i32> { (*self).client_id() }
+}
 
-impl<'a, PUBLICCHATTYPE: TDPublicChatType> TDPublicChatType for &'a PUBLICCHATTYPE {}
-impl<'a, PUBLICCHATTYPE: TDPublicChatType> TDPublicChatType for &'a mut PUBLICCHATTYPE {}
+impl<RObj: RObject> RObject for &mut RObj {
+  fn td_name(&self) -> &str { (**self).td_name() }
+  fn to_json(&self) -> RTDResult<String> { (**self).to_json() }
+  fn extra(&self) -> Option<Extra> { (**self).extra() }
+  fn client_id(&self) -> Option<i32> { (**self).client_id() }
+}
 
-impl<'a, PUSHMESSAGECONTENT: TDPushMessageContent> TDPushMessageContent for &'a PUSHMESSAGECONTENT {}
-impl<'a, PUSHMESSAGECONTENT: TDPushMessageContent> TDPushMessageContent for &'a mut PUSHMESSAGECONTENT {}
 
-impl<'a, REPLYMARKUP: TDReplyMarkup> TDReplyMarkup for &'a REPLYMARKUP {}
-impl<'a, REPLYMARKUP: TDReplyMarkup> TDReplyMarkup for &'a mut REPLYMARKUP {}
+// a borrowed function is cloned rather than given an `@extra` its owner didn't set
+impl<Fnc: RFunction + Clone> RFunction for &Fnc {
+  type Response = Fnc::Response;
+  fn to_json_with_extra(self, extra: &Extra) -> RTDResult<String> { self.clone().to_json_with_extra(extra) }
+}
+impl<Fnc: RFunction + Clone> RFunction for &mut Fnc {
+  type Response = Fnc::Response;
+  fn to_json_with_extra(self, extra: &Extra) -> RTDResult<String> { self.clone().to_json_with_extra(extra) }
+}
 
-impl<'a, RESETPASSWORDRESULT: TDResetPasswordResult> TDResetPasswordResult for &'a RESETPASSWORDRESULT {}
-impl<'a, RESETPASSWORDRESULT: TDResetPasswordResult> TDResetPasswordResult for &'a mut RESETPASSWORDRESULT {}
 
-impl<'a, RICHTEXT: TDRichText> TDRichText for &'a RICHTEXT {}
-impl<'a, RICHTEXT: TDRichText> TDRichText for &'a mut RICHTEXT {}
+/// A td object whose `@type` this crate doesn't know, e.g. sent by a newer tdlib. It keeps the
+/// received json, so it serializes back unchanged.
+#[derive(Debug, Clone)]
+pub struct UnknownObject {
+  td_name: String,
+  value: serde_json::Value,
+}
 
-impl<'a, SEARCHMESSAGESFILTER: TDSearchMessagesFilter> TDSearchMessagesFilter for &'a SEARCHMESSAGESFILTER {}
-impl<'a, SEARCHMESSAGESFILTER: TDSearchMessagesFilter> TDSearchMessagesFilter for &'a mut SEARCHMESSAGESFILTER {}
+impl UnknownObject {
+  pub(crate) fn new(td_name: &str, value: serde_json::Value) -> Self {
+    Self { td_name: td_name.to_string(), value }
+  }
 
-impl<'a, SECRETCHATSTATE: TDSecretChatState> TDSecretChatState for &'a SECRETCHATSTATE {}
-impl<'a, SECRETCHATSTATE: TDSecretChatState> TDSecretChatState for &'a mut SECRETCHATSTATE {}
+  /// The json as received
+  pub fn value(&self) -> &serde_json::Value { &self.value }
 
-impl<'a, STATISTICALGRAPH: TDStatisticalGraph> TDStatisticalGraph for &'a STATISTICALGRAPH {}
-impl<'a, STATISTICALGRAPH: TDStatisticalGraph> TDStatisticalGraph for &'a mut STATISTICALGRAPH {}
+  pub fn into_value(self) -> serde_json::Value { self.value }
+}
 
-impl<'a, SUGGESTEDACTION: TDSuggestedAction> TDSuggestedAction for &'a SUGGESTEDACTION {}
-impl<'a, SUGGESTEDACTION: TDSuggestedAction> TDSuggestedAction for &'a mut SUGGESTEDACTION {}
+impl RObject for UnknownObject {
+  #[doc(hidden)]
+  fn td_name(&self) -> &str { &self.td_name }
+  #[doc(hidden)]
+  fn extra(&self) -> Option<Extra> { self.value.get("@extra").filter(|extra| !extra.is_null()).cloned().map(Extra::from) }
+  fn client_id(&self) -> Option<i32> { self.value.get("@client_id").and_then(|client_id| client_id.as_i64()).and_then(|client_id| i32::try_from(client_id).ok()) }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(&self.value)?) }
+}
 
-impl<'a, SUPERGROUPMEMBERSFILTER: TDSupergroupMembersFilter> TDSupergroupMembersFilter for &'a SUPERGROUPMEMBERSFILTER {}
-impl<'a, SUPERGROUPMEMBERSFILTER: TDSupergroupMembersFilter> TDSupergroupMembersFilter for &'a mut SUPERGROUPMEMBERSFILTER {}
+impl PartialEq for UnknownObject {
+  /// The json but its `@extra` and `@client_id`, like the generated types compare
+  fn eq(&self, other: &Self) -> bool {
+    fn fields(value: &serde_json::Value) -> Option<Vec<(&String, &serde_json::Value)>> {
+      value.as_object().map(|object| object.iter().filter(|(key, _)| *key != "@extra" && *key != "@client_id").collect())
+    }
+    match (fields(&self.value), fields(&other.value)) {
+      (Some(fields), Some(other_fields)) => fields == other_fields,
+      _ => self.value == other.value,
+    }
+  }
+}
 
-impl<'a, TMEURLTYPE: TDTMeUrlType> TDTMeUrlType for &'a TMEURLTYPE {}
-impl<'a, TMEURLTYPE: TDTMeUrlType> TDTMeUrlType for &'a mut TMEURLTYPE {}
+impl serde::Serialize for UnknownObject {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.value.serialize(serializer)
+  }
+}
 
-impl<'a, TEXTENTITYTYPE: TDTextEntityType> TDTextEntityType for &'a TEXTENTITYTYPE {}
-impl<'a, TEXTENTITYTYPE: TDTextEntityType> TDTextEntityType for &'a mut TEXTENTITYTYPE {}
+/// Serde of tdlib `int64` fields, sent as json strings so no precision is lost. Numbers are accepted too.
+pub(crate) mod int64 {
+  use std::convert::TryFrom;
+  use std::fmt;
 
-impl<'a, TEXTPARSEMODE: TDTextParseMode> TDTextParseMode for &'a TEXTPARSEMODE {}
-impl<'a, TEXTPARSEMODE: TDTextParseMode> TDTextParseMode for &'a mut TEXTPARSEMODE {}
+  use serde::de::{self, Deserializer, Visitor};
+  use serde::Serializer;
 
-impl<'a, THUMBNAILFORMAT: TDThumbnailFormat> TDThumbnailFormat for &'a THUMBNAILFORMAT {}
-impl<'a, THUMBNAILFORMAT: TDThumbnailFormat> TDThumbnailFormat for &'a mut THUMBNAILFORMAT {}
+  pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+  }
 
-impl<'a, TOPCHATCATEGORY: TDTopChatCategory> TDTopChatCategory for &'a TOPCHATCATEGORY {}
-impl<'a, TOPCHATCATEGORY: TDTopChatCategory> TDTopChatCategory for &'a mut TOPCHATCATEGORY {}
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    deserializer.deserialize_any(Int64Visitor)
+  }
 
-impl<'a, UPDATE: TDUpdate> TDUpdate for &'a UPDATE {}
-impl<'a, UPDATE: TDUpdate> TDUpdate for &'a mut UPDATE {}
+  pub(super) struct Int64Visitor;
 
-impl<'a, USERPRIVACYSETTING: TDUserPrivacySetting> TDUserPrivacySetting for &'a USERPRIVACYSETTING {}
-impl<'a, USERPRIVACYSETTING: TDUserPrivacySetting> TDUserPrivacySetting for &'a mut USERPRIVACYSETTING {}
+  impl<'de> Visitor<'de> for Int64Visitor {
+    type Value = i64;
 
-impl<'a, USERPRIVACYSETTINGRULE: TDUserPrivacySettingRule> TDUserPrivacySettingRule for &'a USERPRIVACYSETTINGRULE {}
-impl<'a, USERPRIVACYSETTINGRULE: TDUserPrivacySettingRule> TDUserPrivacySettingRule for &'a mut USERPRIVACYSETTINGRULE {}
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+      formatter.write_str("an int64 as string or number")
+    }
 
-impl<'a, USERSTATUS: TDUserStatus> TDUserStatus for &'a USERSTATUS {}
-impl<'a, USERSTATUS: TDUserStatus> TDUserStatus for &'a mut USERSTATUS {}
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<i64, E> {
+      Ok(value)
+    }
 
-impl<'a, USERTYPE: TDUserType> TDUserType for &'a USERTYPE {}
-impl<'a, USERTYPE: TDUserType> TDUserType for &'a mut USERTYPE {}
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<i64, E> {
+      i64::try_from(value).map_err(|_| E::custom(format!("int64 {} out of range", value)))
+    }
 
-impl<'a, VECTORPATHCOMMAND: TDVectorPathCommand> TDVectorPathCommand for &'a VECTORPATHCOMMAND {}
-impl<'a, VECTORPATHCOMMAND: TDVectorPathCommand> TDVectorPathCommand for &'a mut VECTORPATHCOMMAND {}
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<i64, E> {
+      value.parse().map_err(|_| E::custom(format!("invalid int64 \"{}\"", value)))
+    }
 
+    // a missing field in lenient decoding
+    fn visit_unit<E: de::Error>(self) -> Result<i64, E> {
+      if crate::types::_lenient::is_decoding() { Ok(0) } else { Err(E::invalid_type(de::Unexpected::Unit, &self)) }
+    }
+  }
+}
 
-#[derive(Debug, Clone)]
-pub enum TdType {
-  TestUseUpdate(TestUseUpdate),
-  UpdateActiveNotifications(UpdateActiveNotifications),
-  UpdateAnimatedEmojiMessageClicked(UpdateAnimatedEmojiMessageClicked),
-  UpdateAnimationSearchParameters(UpdateAnimationSearchParameters),
-  UpdateAuthorizationState(UpdateAuthorizationState),
-  UpdateBasicGroup(UpdateBasicGroup),
-  UpdateBasicGroupFullInfo(UpdateBasicGroupFullInfo),
-  UpdateCall(UpdateCall),
-  UpdateChatActionBar(UpdateChatActionBar),
-  UpdateChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
-  UpdateChatDraftMessage(UpdateChatDraftMessage),
-  UpdateChatFilters(UpdateChatFilters),
-  UpdateChatHasScheduledMessages(UpdateChatHasScheduledMessages),
-  UpdateChatIsBlocked(UpdateChatIsBlocked),
-  UpdateChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
-  UpdateChatLastMessage(UpdateChatLastMessage),
-  UpdateChatMember(UpdateChatMember),
-  UpdateChatMessageTtlSetting(UpdateChatMessageTtlSetting),
-  UpdateChatNotificationSettings(UpdateChatNotificationSettings),
-  UpdateChatOnlineMemberCount(UpdateChatOnlineMemberCount),
-  UpdateChatPendingJoinRequests(UpdateChatPendingJoinRequests),
-  UpdateChatPermissions(UpdateChatPermissions),
-  UpdateChatPhoto(UpdateChatPhoto),
-  UpdateChatPosition(UpdateChatPosition),
-  UpdateChatReadInbox(UpdateChatReadInbox),
-  UpdateChatReadOutbox(UpdateChatReadOutbox),
-  UpdateChatReplyMarkup(UpdateChatReplyMarkup),
-  UpdateChatTheme(UpdateChatTheme),
-  UpdateChatThemes(UpdateChatThemes),
-  UpdateChatTitle(UpdateChatTitle),
-  UpdateChatUnreadMentionCount(UpdateChatUnreadMentionCount),
-  UpdateChatVideoChat(UpdateChatVideoChat),
-  UpdateConnectionState(UpdateConnectionState),
-  UpdateDeleteMessages(UpdateDeleteMessages),
-  UpdateDiceEmojis(UpdateDiceEmojis),
-  UpdateFavoriteStickers(UpdateFavoriteStickers),
-  UpdateFile(UpdateFile),
-  UpdateFileGenerationStart(UpdateFileGenerationStart),
-  UpdateFileGenerationStop(UpdateFileGenerationStop),
-  UpdateGroupCall(UpdateGroupCall),
-  UpdateGroupCallParticipant(UpdateGroupCallParticipant),
-  UpdateHavePendingNotifications(UpdateHavePendingNotifications),
-  UpdateInstalledStickerSets(UpdateInstalledStickerSets),
-  UpdateLanguagePackStrings(UpdateLanguagePackStrings),
-  UpdateMessageContent(UpdateMessageContent),
-  UpdateMessageContentOpened(UpdateMessageContentOpened),
-  UpdateMessageEdited(UpdateMessageEdited),
-  UpdateMessageInteractionInfo(UpdateMessageInteractionInfo),
-  UpdateMessageIsPinned(UpdateMessageIsPinned),
-  UpdateMessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
-  UpdateMessageMentionRead(UpdateMessageMentionRead),
-  UpdateMessageSendAcknowledged(UpdateMessageSendAcknowledged),
-  UpdateMessageSendFailed(UpdateMessageSendFailed),
-  UpdateMessageSendSucceeded(UpdateMessageSendSucceeded),
-  UpdateNewCallSignalingData(UpdateNewCallSignalingData),
-  UpdateNewCallbackQuery(UpdateNewCallbackQuery),
-  UpdateNewChat(UpdateNewChat),
-  UpdateNewChatJoinRequest(UpdateNewChatJoinRequest),
-  UpdateNewChosenInlineResult(UpdateNewChosenInlineResult),
-  UpdateNewCustomEvent(UpdateNewCustomEvent),
-  UpdateNewCustomQuery(UpdateNewCustomQuery),
-  UpdateNewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
-  UpdateNewInlineQuery(UpdateNewInlineQuery),
-  UpdateNewMessage(UpdateNewMessage),
-  UpdateNewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
-  UpdateNewShippingQuery(UpdateNewShippingQuery),
-  UpdateNotification(UpdateNotification),
-  UpdateNotificationGroup(UpdateNotificationGroup),
-  UpdateOption(UpdateOption),
-  UpdatePoll(UpdatePoll),
-  UpdatePollAnswer(UpdatePollAnswer),
-  UpdateRecentStickers(UpdateRecentStickers),
-  UpdateSavedAnimations(UpdateSavedAnimations),
-  UpdateScopeNotificationSettings(UpdateScopeNotificationSettings),
-  UpdateSecretChat(UpdateSecretChat),
-  UpdateSelectedBackground(UpdateSelectedBackground),
-  UpdateServiceNotification(UpdateServiceNotification),
-  UpdateStickerSet(UpdateStickerSet),
-  UpdateSuggestedActions(UpdateSuggestedActions),
-  UpdateSupergroup(UpdateSupergroup),
-  UpdateSupergroupFullInfo(UpdateSupergroupFullInfo),
-  UpdateTermsOfService(UpdateTermsOfService),
-  UpdateTrendingStickerSets(UpdateTrendingStickerSets),
-  UpdateUnreadChatCount(UpdateUnreadChatCount),
-  UpdateUnreadMessageCount(UpdateUnreadMessageCount),
-  UpdateUser(UpdateUser),
-  UpdateUserChatAction(UpdateUserChatAction),
-  UpdateUserFullInfo(UpdateUserFullInfo),
-  UpdateUserPrivacySettingRules(UpdateUserPrivacySettingRules),
-  UpdateUserStatus(UpdateUserStatus),
-  UpdateUsersNearby(UpdateUsersNearby),
-
-  AuthorizationState(AuthorizationState),
-  CanTransferOwnershipResult(CanTransferOwnershipResult),
-  ChatStatistics(ChatStatistics),
-  CheckChatUsernameResult(CheckChatUsernameResult),
-  CheckStickerSetNameResult(CheckStickerSetNameResult),
-  InternalLinkType(InternalLinkType),
-  JsonValue(JsonValue),
-  LanguagePackStringValue(LanguagePackStringValue),
-  LogStream(LogStream),
-  LoginUrlInfo(LoginUrlInfo),
-  MessageFileType(MessageFileType),
-  OptionValue(OptionValue),
-  PassportElement(PassportElement),
-  ResetPasswordResult(ResetPasswordResult),
-  StatisticalGraph(StatisticalGraph),
-  Update(Update),
-  AccountTtl(AccountTtl),
-  AnimatedEmoji(AnimatedEmoji),
-  Animations(Animations),
-  AuthenticationCodeInfo(AuthenticationCodeInfo),
-  AutoDownloadSettingsPresets(AutoDownloadSettingsPresets),
-  Background(Background),
-  Backgrounds(Backgrounds),
-  BankCardInfo(BankCardInfo),
-  BasicGroup(BasicGroup),
-  BasicGroupFullInfo(BasicGroupFullInfo),
-  BotCommands(BotCommands),
-  CallId(CallId),
-  CallbackQueryAnswer(CallbackQueryAnswer),
-  Chat(Chat),
-  ChatAdministrators(ChatAdministrators),
-  ChatEvents(ChatEvents),
-  ChatFilter(ChatFilter),
-  ChatFilterInfo(ChatFilterInfo),
-  ChatInviteLink(ChatInviteLink),
-  ChatInviteLinkCounts(ChatInviteLinkCounts),
-  ChatInviteLinkInfo(ChatInviteLinkInfo),
-  ChatInviteLinkMembers(ChatInviteLinkMembers),
-  ChatInviteLinks(ChatInviteLinks),
-  ChatJoinRequests(ChatJoinRequests),
-  ChatLists(ChatLists),
-  ChatMember(ChatMember),
-  ChatMembers(ChatMembers),
-  ChatPhotos(ChatPhotos),
-  Chats(Chats),
-  ChatsNearby(ChatsNearby),
-  ConnectedWebsites(ConnectedWebsites),
-  Count(Count),
-  Countries(Countries),
-  CustomRequestResult(CustomRequestResult),
-  DatabaseStatistics(DatabaseStatistics),
-  DeepLinkInfo(DeepLinkInfo),
-  EmailAddressAuthenticationCodeInfo(EmailAddressAuthenticationCodeInfo),
-  Emojis(Emojis),
-  Error(Error),
-  File(File),
-  FilePart(FilePart),
-  FormattedText(FormattedText),
-  FoundMessages(FoundMessages),
-  GameHighScores(GameHighScores),
-  GroupCall(GroupCall),
-  GroupCallId(GroupCallId),
-  Hashtags(Hashtags),
-  HttpUrl(HttpUrl),
-  ImportedContacts(ImportedContacts),
-  InlineQueryResults(InlineQueryResults),
-  LanguagePackInfo(LanguagePackInfo),
-  LanguagePackStrings(LanguagePackStrings),
-  LocalizationTargetInfo(LocalizationTargetInfo),
-  LogTags(LogTags),
-  LogVerbosityLevel(LogVerbosityLevel),
-  Message(Message),
-  MessageCalendar(MessageCalendar),
-  MessageLink(MessageLink),
-  MessageLinkInfo(MessageLinkInfo),
-  MessagePositions(MessagePositions),
-  MessageSenders(MessageSenders),
-  MessageStatistics(MessageStatistics),
-  MessageThreadInfo(MessageThreadInfo),
-  Messages(Messages),
-  NetworkStatistics(NetworkStatistics),
-  Ok(Ok),
-  OrderInfo(OrderInfo),
-  PassportAuthorizationForm(PassportAuthorizationForm),
-  PassportElements(PassportElements),
-  PassportElementsWithErrors(PassportElementsWithErrors),
-  PasswordState(PasswordState),
-  PaymentForm(PaymentForm),
-  PaymentReceipt(PaymentReceipt),
-  PaymentResult(PaymentResult),
-  PhoneNumberInfo(PhoneNumberInfo),
-  Proxies(Proxies),
-  Proxy(Proxy),
-  PushReceiverId(PushReceiverId),
-  RecommendedChatFilters(RecommendedChatFilters),
-  RecoveryEmailAddress(RecoveryEmailAddress),
-  ScopeNotificationSettings(ScopeNotificationSettings),
-  Seconds(Seconds),
-  SecretChat(SecretChat),
-  Session(Session),
-  Sessions(Sessions),
-  SponsoredMessages(SponsoredMessages),
-  Sticker(Sticker),
-  StickerSet(StickerSet),
-  StickerSets(StickerSets),
-  Stickers(Stickers),
-  StorageStatistics(StorageStatistics),
-  StorageStatisticsFast(StorageStatisticsFast),
-  Supergroup(Supergroup),
-  SupergroupFullInfo(SupergroupFullInfo),
-  TMeUrls(TMeUrls),
-  TemporaryPasswordState(TemporaryPasswordState),
-  TestBytes(TestBytes),
-  TestInt(TestInt),
-  TestString(TestString),
-  TestVectorInt(TestVectorInt),
-  TestVectorIntObject(TestVectorIntObject),
-  TestVectorString(TestVectorString),
-  TestVectorStringObject(TestVectorStringObject),
-  Text(Text),
-  TextEntities(TextEntities),
-  Updates(Updates),
-  User(User),
-  UserFullInfo(UserFullInfo),
-  UserPrivacySettingRules(UserPrivacySettingRules),
-  Users(Users),
-  ValidatedOrderInfo(ValidatedOrderInfo),
-  WebPage(WebPage),
-  WebPageInstantView(WebPageInstantView),
+/// Serde of tdlib `vector<int64>` fields, each item through `int64::Int64Visitor` so out of range
+/// items fail the same way
+// these fields are all in types of features, like the sticker set ids of `updateInstalledStickerSets`
+#[allow(dead_code)]
+pub(crate) mod vec_int64 {
+  use serde::de::{Deserialize, Deserializer};
+  use serde::Serializer;
 
-}
-impl<'de> Deserialize<'de> for TdType {
-fn deserialize<D>(deserializer: D) -> Result<TdType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
-    rtd_enum_deserialize!(
-      TdType,
-  (testUseUpdate, TestUseUpdate);
-  (updateActiveNotifications, UpdateActiveNotifications);
-  (updateAnimatedEmojiMessageClicked, UpdateAnimatedEmojiMessageClicked);
-  (updateAnimationSearchParameters, UpdateAnimationSearchParameters);
-  (updateAuthorizationState, UpdateAuthorizationState);
-  (updateBasicGroup, UpdateBasicGroup);
-  (updateBasicGroupFullInfo, UpdateBasicGroupFullInfo);
-  (updateCall, UpdateCall);
-  (updateChatActionBar, UpdateChatActionBar);
-  (updateChatDefaultDisableNotification, UpdateChatDefaultDisableNotification);
-  (updateChatDraftMessage, UpdateChatDraftMessage);
-  (updateChatFilters, UpdateChatFilters);
-  (updateChatHasScheduledMessages, UpdateChatHasScheduledMessages);
-  (updateChatIsBlocked, UpdateChatIsBlocked);
-  (updateChatIsMarkedAsUnread, UpdateChatIsMarkedAsUnread);
-  (updateChatLastMessage, UpdateChatLastMessage);
-  (updateChatMember, UpdateChatMember);
-  (updateChatMessageTtlSetting, UpdateChatMessageTtlSetting);
-  (updateChatNotificationSettings, UpdateChatNotificationSettings);
-  (updateChatOnlineMemberCount, UpdateChatOnlineMemberCount);
-  (updateChatPendingJoinRequests, UpdateChatPendingJoinRequests);
-  (updateChatPermissions, UpdateChatPermissions);
-  (updateChatPhoto, UpdateChatPhoto);
-  (updateChatPosition, UpdateChatPosition);
-  (updateChatReadInbox, UpdateChatReadInbox);
-  (updateChatReadOutbox, UpdateChatReadOutbox);
-  (updateChatReplyMarkup, UpdateChatReplyMarkup);
-  (updateChatTheme, UpdateChatTheme);
-  (updateChatThemes, UpdateChatThemes);
-  (updateChatTitle, UpdateChatTitle);
-  (updateChatUnreadMentionCount, UpdateChatUnreadMentionCount);
-  (updateChatVideoChat, UpdateChatVideoChat);
-  (updateConnectionState, UpdateConnectionState);
-  (updateDeleteMessages, UpdateDeleteMessages);
-  (updateDiceEmojis, UpdateDiceEmojis);
-  (updateFavoriteStickers, UpdateFavoriteStickers);
-  (updateFile, UpdateFile);
-  (updateFileGenerationStart, UpdateFileGenerationStart);
-  (updateFileGenerationStop, UpdateFileGenerationStop);
-  (updateGroupCall, UpdateGroupCall);
-  (updateGroupCallParticipant, UpdateGroupCallParticipant);
-  (updateHavePendingNotifications, UpdateHavePendingNotifications);
-  (updateInstalledStickerSets, UpdateInstalledStickerSets);
-  (updateLanguagePackStrings, UpdateLanguagePackStrings);
-  (updateMessageContent, UpdateMessageContent);
-  (updateMessageContentOpened, UpdateMessageContentOpened);
-  (updateMessageEdited, UpdateMessageEdited);
-  (updateMessageInteractionInfo, UpdateMessageInteractionInfo);
-  (updateMessageIsPinned, UpdateMessageIsPinned);
-  (updateMessageLiveLocationViewed, UpdateMessageLiveLocationViewed);
-  (updateMessageMentionRead, UpdateMessageMentionRead);
-  (updateMessageSendAcknowledged, UpdateMessageSendAcknowledged);
-  (updateMessageSendFailed, UpdateMessageSendFailed);
-  (updateMessageSendSucceeded, UpdateMessageSendSucceeded);
-  (updateNewCallSignalingData, UpdateNewCallSignalingData);
-  (updateNewCallbackQuery, UpdateNewCallbackQuery);
-  (updateNewChat, UpdateNewChat);
-  (updateNewChatJoinRequest, UpdateNewChatJoinRequest);
-  (updateNewChosenInlineResult, UpdateNewChosenInlineResult);
-  (updateNewCustomEvent, UpdateNewCustomEvent);
-  (updateNewCustomQuery, UpdateNewCustomQuery);
-  (updateNewInlineCallbackQuery, UpdateNewInlineCallbackQuery);
-  (updateNewInlineQuery, UpdateNewInlineQuery);
-  (updateNewMessage, UpdateNewMessage);
-  (updateNewPreCheckoutQuery, UpdateNewPreCheckoutQuery);
-  (updateNewShippingQuery, UpdateNewShippingQuery);
-  (updateNotification, UpdateNotification);
-  (updateNotificationGroup, UpdateNotificationGroup);
-  (updateOption, UpdateOption);
-  (updatePoll, UpdatePoll);
-  (updatePollAnswer, UpdatePollAnswer);
-  (updateRecentStickers, UpdateRecentStickers);
-  (updateSavedAnimations, UpdateSavedAnimations);
-  (updateScopeNotificationSettings, UpdateScopeNotificationSettings);
-  (updateSecretChat, UpdateSecretChat);
-  (updateSelectedBackground, UpdateSelectedBackground);
-  (updateServiceNotification, UpdateServiceNotification);
-  (updateStickerSet, UpdateStickerSet);
-  (updateSuggestedActions, UpdateSuggestedActions);
-  (updateSupergroup, UpdateSupergroup);
-  (updateSupergroupFullInfo, UpdateSupergroupFullInfo);
-  (updateTermsOfService, UpdateTermsOfService);
-  (updateTrendingStickerSets, UpdateTrendingStickerSets);
-  (updateUnreadChatCount, UpdateUnreadChatCount);
-  (updateUnreadMessageCount, UpdateUnreadMessageCount);
-  (updateUser, UpdateUser);
-  (updateUserChatAction, UpdateUserChatAction);
-  (updateUserFullInfo, UpdateUserFullInfo);
-  (updateUserPrivacySettingRules, UpdateUserPrivacySettingRules);
-  (updateUserStatus, UpdateUserStatus);
-  (updateUsersNearby, UpdateUsersNearby);
-
-  (AuthorizationState, AuthorizationState);
-  (CanTransferOwnershipResult, CanTransferOwnershipResult);
-  (ChatStatistics, ChatStatistics);
-  (CheckChatUsernameResult, CheckChatUsernameResult);
-  (CheckStickerSetNameResult, CheckStickerSetNameResult);
-  (InternalLinkType, InternalLinkType);
-  (JsonValue, JsonValue);
-  (LanguagePackStringValue, LanguagePackStringValue);
-  (LogStream, LogStream);
-  (LoginUrlInfo, LoginUrlInfo);
-  (MessageFileType, MessageFileType);
-  (OptionValue, OptionValue);
-  (PassportElement, PassportElement);
-  (ResetPasswordResult, ResetPasswordResult);
-  (StatisticalGraph, StatisticalGraph);
-  (Update, Update);
-  (accountTtl, AccountTtl);
-  (animatedEmoji, AnimatedEmoji);
-  (animations, Animations);
-  (authenticationCodeInfo, AuthenticationCodeInfo);
-  (autoDownloadSettingsPresets, AutoDownloadSettingsPresets);
-  (background, Background);
-  (backgrounds, Backgrounds);
-  (bankCardInfo, BankCardInfo);
-  (basicGroup, BasicGroup);
-  (basicGroupFullInfo, BasicGroupFullInfo);
-  (botCommands, BotCommands);
-  (callId, CallId);
-  (callbackQueryAnswer, CallbackQueryAnswer);
-  (chat, Chat);
-  (chatAdministrators, ChatAdministrators);
-  (chatEvents, ChatEvents);
-  (chatFilter, ChatFilter);
-  (chatFilterInfo, ChatFilterInfo);
-  (chatInviteLink, ChatInviteLink);
-  (chatInviteLinkCounts, ChatInviteLinkCounts);
-  (chatInviteLinkInfo, ChatInviteLinkInfo);
-  (chatInviteLinkMembers, ChatInviteLinkMembers);
-  (chatInviteLinks, ChatInviteLinks);
-  (chatJoinRequests, ChatJoinRequests);
-  (chatLists, ChatLists);
-  (chatMember, ChatMember);
-  (chatMembers, ChatMembers);
-  (chatPhotos, ChatPhotos);
-  (chats, Chats);
-  (chatsNearby, ChatsNearby);
-  (connectedWebsites, ConnectedWebsites);
-  (count, Count);
-  (countries, Countries);
-  (customRequestResult, CustomRequestResult);
-  (databaseStatistics, DatabaseStatistics);
-  (deepLinkInfo, DeepLinkInfo);
-  (emailAddressAuthenticationCodeInfo, EmailAddressAuthenticationCodeInfo);
-  (emojis, Emojis);
-  (error, Error);
-  (file, File);
-  (filePart, FilePart);
-  (formattedText, FormattedText);
-  (foundMessages, FoundMessages);
-  (gameHighScores, GameHighScores);
-  (groupCall, GroupCall);
-  (groupCallId, GroupCallId);
-  (hashtags, Hashtags);
-  (httpUrl, HttpUrl);
-  (importedContacts, ImportedContacts);
-  (inlineQueryResults, InlineQueryResults);
-  (languagePackInfo, LanguagePackInfo);
-  (languagePackStrings, LanguagePackStrings);
-  (localizationTargetInfo, LocalizationTargetInfo);
-  (logTags, LogTags);
-  (logVerbosityLevel, LogVerbosityLevel);
-  (message, Message);
-  (messageCalendar, MessageCalendar);
-  (messageLink, MessageLink);
-  (messageLinkInfo, MessageLinkInfo);
-  (messagePositions, MessagePositions);
-  (messageSenders, MessageSenders);
-  (messageStatistics, MessageStatistics);
-  (messageThreadInfo, MessageThreadInfo);
-  (messages, Messages);
-  (networkStatistics, NetworkStatistics);
-  (ok, Ok);
-  (orderInfo, OrderInfo);
-  (passportAuthorizationForm, PassportAuthorizationForm);
-  (passportElements, PassportElements);
-  (passportElementsWithErrors, PassportElementsWithErrors);
-  (passwordState, PasswordState);
-  (paymentForm, PaymentForm);
-  (paymentReceipt, PaymentReceipt);
-  (paymentResult, PaymentResult);
-  (phoneNumberInfo, PhoneNumberInfo);
-  (proxies, Proxies);
-  (proxy, Proxy);
-  (pushReceiverId, PushReceiverId);
-  (recommendedChatFilters, RecommendedChatFilters);
-  (recoveryEmailAddress, RecoveryEmailAddress);
-  (scopeNotificationSettings, ScopeNotificationSettings);
-  (seconds, Seconds);
-  (secretChat, SecretChat);
-  (session, Session);
-  (sessions, Sessions);
-  (sponsoredMessages, SponsoredMessages);
-  (sticker, Sticker);
-  (stickerSet, StickerSet);
-  (stickerSets, StickerSets);
-  (stickers, Stickers);
-  (storageStatistics, StorageStatistics);
-  (storageStatisticsFast, StorageStatisticsFast);
-  (supergroup, Supergroup);
-  (supergroupFullInfo, SupergroupFullInfo);
-  (tMeUrls, TMeUrls);
-  (temporaryPasswordState, TemporaryPasswordState);
-  (testBytes, TestBytes);
-  (testInt, TestInt);
-  (testString, TestString);
-  (testVectorInt, TestVectorInt);
-  (testVectorIntObject, TestVectorIntObject);
-  (testVectorString, TestVectorString);
-  (testVectorStringObject, TestVectorStringObject);
-  (text, Text);
-  (textEntities, TextEntities);
-  (updates, Updates);
-  (user, User);
-  (userFullInfo, UserFullInfo);
-  (userPrivacySettingRules, UserPrivacySettingRules);
-  (users, Users);
-  (validatedOrderInfo, ValidatedOrderInfo);
-  (webPage, WebPage);
-  (webPageInstantView, WebPageInstantView);
-
- )(deserializer)
-
- }
-}
+  struct Int64(i64);
 
-impl RObject for TdType {
-  #[doc(hidden)]
-  fn td_name(&self) -> &'static str {
-    match self {
-      Self::TestUseUpdate(value) => value.td_name(),
-      Self::UpdateActiveNotifications(value) => value.td_name(),
-      Self::UpdateAnimatedEmojiMessageClicked(value) => value.td_name(),
-      Self::UpdateAnimationSearchParameters(value) => value.td_name(),
-      Self::UpdateAuthorizationState(value) => value.td_name(),
-      Self::UpdateBasicGroup(value) => value.td_name(),
-      Self::UpdateBasicGroupFullInfo(value) => value.td_name(),
-      Self::UpdateCall(value) => value.td_name(),
-      Self::UpdateChatActionBar(value) => value.td_name(),
-      Self::UpdateChatDefaultDisableNotification(value) => value.td_name(),
-      Self::UpdateChatDraftMessage(value) => value.td_name(),
-      Self::UpdateChatFilters(value) => value.td_name(),
-      Self::UpdateChatHasScheduledMessages(value) => value.td_name(),
-      Self::UpdateChatIsBlocked(value) => value.td_name(),
-      Self::UpdateChatIsMarkedAsUnread(value) => value.td_name(),
-      Self::UpdateChatLastMessage(value) => value.td_name(),
-      Self::UpdateChatMember(value) => value.td_name(),
-      Self::UpdateChatMessageTtlSetting(value) => value.td_name(),
-      Self::UpdateChatNotificationSettings(value) => value.td_name(),
-      Self::UpdateChatOnlineMemberCount(value) => value.td_name(),
-      Self::UpdateChatPendingJoinRequests(value) => value.td_name(),
-      Self::UpdateChatPermissions(value) => value.td_name(),
-      Self::UpdateChatPhoto(value) => value.td_name(),
-      Self::UpdateChatPosition(value) => value.td_name(),
-      Self::UpdateChatReadInbox(value) => value.td_name(),
-      Self::UpdateChatReadOutbox(value) => value.td_name(),
-      Self::UpdateChatReplyMarkup(value) => value.td_name(),
-      Self::UpdateChatTheme(value) => value.td_name(),
-      Self::UpdateChatThemes(value) => value.td_name(),
-      Self::UpdateChatTitle(value) => value.td_name(),
-      Self::UpdateChatUnreadMentionCount(value) => value.td_name(),
-      Self::UpdateChatVideoChat(value) => value.td_name(),
-      Self::UpdateConnectionState(value) => value.td_name(),
-      Self::UpdateDeleteMessages(value) => value.td_name(),
-      Self::UpdateDiceEmojis(value) => value.td_name(),
-      Self::UpdateFavoriteStickers(value) => value.td_name(),
-      Self::UpdateFile(value) => value.td_name(),
-      Self::UpdateFileGenerationStart(value) => value.td_name(),
-      Self::UpdateFileGenerationStop(value) => value.td_name(),
-      Self::UpdateGroupCall(value) => value.td_name(),
-      Self::UpdateGroupCallParticipant(value) => value.td_name(),
-      Self::UpdateHavePendingNotifications(value) => value.td_name(),
-      Self::UpdateInstalledStickerSets(value) => value.td_name(),
-      Self::UpdateLanguagePackStrings(value) => value.td_name(),
-      Self::UpdateMessageContent(value) => value.td_name(),
-      Self::UpdateMessageContentOpened(value) => value.td_name(),
-      Self::UpdateMessageEdited(value) => value.td_name(),
-      Self::UpdateMessageInteractionInfo(value) => value.td_name(),
-      Self::UpdateMessageIsPinned(value) => value.td_name(),
-      Self::UpdateMessageLiveLocationViewed(value) => value.td_name(),
-      Self::UpdateMessageMentionRead(value) => value.td_name(),
-      Self::UpdateMessageSendAcknowledged(value) => value.td_name(),
-      Self::UpdateMessageSendFailed(value) => value.td_name(),
-      Self::UpdateMessageSendSucceeded(value) => value.td_name(),
-      Self::UpdateNewCallSignalingData(value) => value.td_name(),
-      Self::UpdateNewCallbackQuery(value) => value.td_name(),
-      Self::UpdateNewChat(value) => value.td_name(),
-      Self::UpdateNewChatJoinRequest(value) => value.td_name(),
-      Self::UpdateNewChosenInlineResult(value) => value.td_name(),
-      Self::UpdateNewCustomEvent(value) => value.td_name(),
-      Self::UpdateNewCustomQuery(value) => value.td_name(),
-      Self::UpdateNewInlineCallbackQuery(value) => value.td_name(),
-      Self::UpdateNewInlineQuery(value) => value.td_name(),
-      Self::UpdateNewMessage(value) => value.td_name(),
-      Self::UpdateNewPreCheckoutQuery(value) => value.td_name(),
-      Self::UpdateNewShippingQuery(value) => value.td_name(),
-      Self::UpdateNotification(value) => value.td_name(),
-      Self::UpdateNotificationGroup(value) => value.td_name(),
-      Self::UpdateOption(value) => value.td_name(),
-      Self::UpdatePoll(value) => value.td_name(),
-      Self::UpdatePollAnswer(value) => value.td_name(),
-      Self::UpdateRecentStickers(value) => value.td_name(),
-      Self::UpdateSavedAnimations(value) => value.td_name(),
-      Self::UpdateScopeNotificationSettings(value) => value.td_name(),
-      Self::UpdateSecretChat(value) => value.td_name(),
-      Self::UpdateSelectedBackground(value) => value.td_name(),
-      Self::UpdateServiceNotification(value) => value.td_name(),
-      Self::UpdateStickerSet(value) => value.td_name(),
-      Self::UpdateSuggestedActions(value) => value.td_name(),
-      Self::UpdateSupergroup(value) => value.td_name(),
-      Self::UpdateSupergroupFullInfo(value) => value.td_name(),
-      Self::UpdateTermsOfService(value) => value.td_name(),
-      Self::UpdateTrendingStickerSets(value) => value.td_name(),
-      Self::UpdateUnreadChatCount(value) => value.td_name(),
-      Self::UpdateUnreadMessageCount(value) => value.td_name(),
-      Self::UpdateUser(value) => value.td_name(),
-      Self::UpdateUserChatAction(value) => value.td_name(),
-      Self::UpdateUserFullInfo(value) => value.td_name(),
-      Self::UpdateUserPrivacySettingRules(value) => value.td_name(),
-      Self::UpdateUserStatus(value) => value.td_name(),
-      Self::UpdateUsersNearby(value) => value.td_name(),
-    
-      Self::AuthorizationState(value) => value.td_name(),
-      Self::CanTransferOwnershipResult(value) => value.td_name(),
-      Self::ChatStatistics(value) => value.td_name(),
-      Self::CheckChatUsernameResult(value) => value.td_name(),
-      Self::CheckStickerSetNameResult(value) => value.td_name(),
-      Self::InternalLinkType(value) => value.td_name(),
-      Self::JsonValue(value) => value.td_name(),
-      Self::LanguagePackStringValue(value) => value.td_name(),
-      Self::LogStream(value) => value.td_name(),
-      Self::LoginUrlInfo(value) => value.td_name(),
-      Self::MessageFileType(value) => value.td_name(),
-      Self::OptionValue(value) => value.td_name(),
-      Self::PassportElement(value) => value.td_name(),
-      Self::ResetPasswordResult(value) => value.td_name(),
-      Self::StatisticalGraph(value) => value.td_name(),
-      Self::Update(value) => value.td_name(),
-      Self::AccountTtl(value) => value.td_name(),
-      Self::AnimatedEmoji(value) => value.td_name(),
-      Self::Animations(value) => value.td_name(),
-      Self::AuthenticationCodeInfo(value) => value.td_name(),
-      Self::AutoDownloadSettingsPresets(value) => value.td_name(),
-      Self::Background(value) => value.td_name(),
-      Self::Backgrounds(value) => value.td_name(),
-      Self::BankCardInfo(value) => value.td_name(),
-      Self::BasicGroup(value) => value.td_name(),
-      Self::BasicGroupFullInfo(value) => value.td_name(),
-      Self::BotCommands(value) => value.td_name(),
-      Self::CallId(value) => value.td_name(),
-      Self::CallbackQueryAnswer(value) => value.td_name(),
-      Self::Chat(value) => value.td_name(),
-      Self::ChatAdministrators(value) => value.td_name(),
-      Self::ChatEvents(value) => value.td_name(),
-      Self::ChatFilter(value) => value.td_name(),
-      Self::ChatFilterInfo(value) => value.td_name(),
-      Self::ChatInviteLink(value) => value.td_name(),
-      Self::ChatInviteLinkCounts(value) => value.td_name(),
-      Self::ChatInviteLinkInfo(value) => value.td_name(),
-      Self::ChatInviteLinkMembers(value) => value.td_name(),
-      Self::ChatInviteLinks(value) => value.td_name(),
-      Self::ChatJoinRequests(value) => value.td_name(),
-      Self::ChatLists(value) => value.td_name(),
-      Self::ChatMember(value) => value.td_name(),
-      Self::ChatMembers(value) => value.td_name(),
-      Self::ChatPhotos(value) => value.td_name(),
-      Self::Chats(value) => value.td_name(),
-      Self::ChatsNearby(value) => value.td_name(),
-      Self::ConnectedWebsites(value) => value.td_name(),
-      Self::Count(value) => value.td_name(),
-      Self::Countries(value) => value.td_name(),
-      Self::CustomRequestResult(value) => value.td_name(),
-      Self::DatabaseStatistics(value) => value.td_name(),
-      Self::DeepLinkInfo(value) => value.td_name(),
-      Self::EmailAddressAuthenticationCodeInfo(value) => value.td_name(),
-      Self::Emojis(value) => value.td_name(),
-      Self::Error(value) => value.td_name(),
-      Self::File(value) => value.td_name(),
-      Self::FilePart(value) => value.td_name(),
-      Self::FormattedText(value) => value.td_name(),
-      Self::FoundMessages(value) => value.td_name(),
-      Self::GameHighScores(value) => value.td_name(),
-      Self::GroupCall(value) => value.td_name(),
-      Self::GroupCallId(value) => value.td_name(),
-      Self::Hashtags(value) => value.td_name(),
-      Self::HttpUrl(value) => value.td_name(),
-      Self::ImportedContacts(value) => value.td_name(),
-      Self::InlineQueryResults(value) => value.td_name(),
-      Self::LanguagePackInfo(value) => value.td_name(),
-      Self::LanguagePackStrings(value) => value.td_name(),
-      Self::LocalizationTargetInfo(value) => value.td_name(),
-      Self::LogTags(value) => value.td_name(),
-      Self::LogVerbosityLevel(value) => value.td_name(),
-      Self::Message(value) => value.td_name(),
-      Self::MessageCalendar(value) => value.td_name(),
-      Self::MessageLink(value) => value.td_name(),
-      Self::MessageLinkInfo(value) => value.td_name(),
-      Self::MessagePositions(value) => value.td_name(),
-      Self::MessageSenders(value) => value.td_name(),
-      Self::MessageStatistics(value) => value.td_name(),
-      Self::MessageThreadInfo(value) => value.td_name(),
-      Self::Messages(value) => value.td_name(),
-      Self::NetworkStatistics(value) => value.td_name(),
-      Self::Ok(value) => value.td_name(),
-      Self::OrderInfo(value) => value.td_name(),
-      Self::PassportAuthorizationForm(value) => value.td_name(),
-      Self::PassportElements(value) => value.td_name(),
-      Self::PassportElementsWithErrors(value) => value.td_name(),
-      Self::PasswordState(value) => value.td_name(),
-      Self::PaymentForm(value) => value.td_name(),
-      Self::PaymentReceipt(value) => value.td_name(),
-      Self::PaymentResult(value) => value.td_name(),
-      Self::PhoneNumberInfo(value) => value.td_name(),
-      Self::Proxies(value) => value.td_name(),
-      Self::Proxy(value) => value.td_name(),
-      Self::PushReceiverId(value) => value.td_name(),
-      Self::RecommendedChatFilters(value) => value.td_name(),
-      Self::RecoveryEmailAddress(value) => value.td_name(),
-      Self::ScopeNotificationSettings(value) => value.td_name(),
-      Self::Seconds(value) => value.td_name(),
-      Self::SecretChat(value) => value.td_name(),
-      Self::Session(value) => value.td_name(),
-      Self::Sessions(value) => value.td_name(),
-      Self::SponsoredMessages(value) => value.td_name(),
-      Self::Sticker(value) => value.td_name(),
-      Self::StickerSet(value) => value.td_name(),
-      Self::StickerSets(value) => value.td_name(),
-      Self::Stickers(value) => value.td_name(),
-      Self::StorageStatistics(value) => value.td_name(),
-      Self::StorageStatisticsFast(value) => value.td_name(),
-      Self::Supergroup(value) => value.td_name(),
-      Self::SupergroupFullInfo(value) => value.td_name(),
-      Self::TMeUrls(value) => value.td_name(),
-      Self::TemporaryPasswordState(value) => value.td_name(),
-      Self::TestBytes(value) => value.td_name(),
-      Self::TestInt(value) => value.td_name(),
-      Self::TestString(value) => value.td_name(),
-      Self::TestVectorInt(value) => value.td_name(),
-      Self::TestVectorIntObject(value) => value.td_name(),
-      Self::TestVectorString(value) => value.td_name(),
-      Self::TestVectorStringObject(value) => value.td_name(),
-      Self::Text(value) => value.td_name(),
-      Self::TextEntities(value) => value.td_name(),
-      Self::Updates(value) => value.td_name(),
-      Self::User(value) => value.td_name(),
-      Self::UserFullInfo(value) => value.td_name(),
-      Self::UserPrivacySettingRules(value) => value.td_name(),
-      Self::Users(value) => value.td_name(),
-      Self::ValidatedOrderInfo(value) => value.td_name(),
-      Self::WebPage(value) => value.td_name(),
-      Self::WebPageInstantView(value) => value.td_name(),
-    
+  impl<'de> Deserialize<'de> for Int64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      deserializer.deserialize_any(super::int64::Int64Visitor).map(Int64)
     }
   }
-  #[doc(hidden)]
-  fn extra(&self) -> Option<String> {
-    match self {
-        Self::TestUseUpdate(value) => value.extra(),
-        Self::UpdateActiveNotifications(value) => value.extra(),
-        Self::UpdateAnimatedEmojiMessageClicked(value) => value.extra(),
-        Self::UpdateAnimationSearchParameters(value) => value.extra(),
-        Self::UpdateAuthorizationState(value) => value.extra(),
-        Self::UpdateBasicGroup(value) => value.extra(),
-        Self::UpdateBasicGroupFullInfo(value) => value.extra(),
-        Self::UpdateCall(value) => value.extra(),
-        Self::UpdateChatActionBar(value) => value.extra(),
-        Self::UpdateChatDefaultDisableNotification(value) => value.extra(),
-        Self::UpdateChatDraftMessage(value) => value.extra(),
-        Self::UpdateChatFilters(value) => value.extra(),
-        Self::UpdateChatHasScheduledMessages(value) => value.extra(),
-        Self::UpdateChatIsBlocked(value) => value.extra(),
-        Self::UpdateChatIsMarkedAsUnread(value) => value.extra(),
-        Self::UpdateChatLastMessage(value) => value.extra(),
-        Self::UpdateChatMember(value) => value.extra(),
-        Self::UpdateChatMessageTtlSetting(value) => value.extra(),
-        Self::UpdateChatNotificationSettings(value) => value.extra(),
-        Self::UpdateChatOnlineMemberCount(value) => value.extra(),
-        Self::UpdateChatPendingJoinRequests(value) => value.extra(),
-        Self::UpdateChatPermissions(value) => value.extra(),
-        Self::UpdateChatPhoto(value) => value.extra(),
-        Self::UpdateChatPosition(value) => value.extra(),
-        Self::UpdateChatReadInbox(value) => value.extra(),
-        Self::UpdateChatReadOutbox(value) => value.extra(),
-        Self::UpdateChatReplyMarkup(value) => value.extra(),
-        Self::UpdateChatTheme(value) => value.extra(),
-        Self::UpdateChatThemes(value) => value.extra(),
-        Self::UpdateChatTitle(value) => value.extra(),
-        Self::UpdateChatUnreadMentionCount(value) => value.extra(),
-        Self::UpdateChatVideoChat(value) => value.extra(),
-        Self::UpdateConnectionState(value) => value.extra(),
-        Self::UpdateDeleteMessages(value) => value.extra(),
-        Self::UpdateDiceEmojis(value) => value.extra(),
-        Self::UpdateFavoriteStickers(value) => value.extra(),
-        Self::UpdateFile(value) => value.extra(),
-        Self::UpdateFileGenerationStart(value) => value.extra(),
-        Self::UpdateFileGenerationStop(value) => value.extra(),
-        Self::UpdateGroupCall(value) => value.extra(),
-        Self::UpdateGroupCallParticipant(value) => value.extra(),
-        Self::UpdateHavePendingNotifications(value) => value.extra(),
-        Self::UpdateInstalledStickerSets(value) => value.extra(),
-        Self::UpdateLanguagePackStrings(value) => value.extra(),
-        Self::UpdateMessageContent(value) => value.extra(),
-        Self::UpdateMessageContentOpened(value) => value.extra(),
-        Self::UpdateMessageEdited(value) => value.extra(),
-        Self::UpdateMessageInteractionInfo(value) => value.extra(),
-        Self::UpdateMessageIsPinned(value) => value.extra(),
-        Self::UpdateMessageLiveLocationViewed(value) => value.extra(),
-        Self::UpdateMessageMentionRead(value) => value.extra(),
-        Self::UpdateMessageSendAcknowledged(value) => value.extra(),
-        Self::UpdateMessageSendFailed(value) => value.extra(),
-        Self::UpdateMessageSendSucceeded(value) => value.extra(),
-        Self::UpdateNewCallSignalingData(value) => value.extra(),
-        Self::UpdateNewCallbackQuery(value) => value.extra(),
-        Self::UpdateNewChat(value) => value.extra(),
-        Self::UpdateNewChatJoinRequest(value) => value.extra(),
-        Self::UpdateNewChosenInlineResult(value) => value.extra(),
-        Self::UpdateNewCustomEvent(value) => value.extra(),
-        Self::UpdateNewCustomQuery(value) => value.extra(),
-        Self::UpdateNewInlineCallbackQuery(value) => value.extra(),
-        Self::UpdateNewInlineQuery(value) => value.extra(),
-        Self::UpdateNewMessage(value) => value.extra(),
-        Self::UpdateNewPreCheckoutQuery(value) => value.extra(),
-        Self::UpdateNewShippingQuery(value) => value.extra(),
-        Self::UpdateNotification(value) => value.extra(),
-        Self::UpdateNotificationGroup(value) => value.extra(),
-        Self::UpdateOption(value) => value.extra(),
-        Self::UpdatePoll(value) => value.extra(),
-        Self::UpdatePollAnswer(value) => value.extra(),
-        Self::UpdateRecentStickers(value) => value.extra(),
-        Self::UpdateSavedAnimations(value) => value.extra(),
-        Self::UpdateScopeNotificationSettings(value) => value.extra(),
-        Self::UpdateSecretChat(value) => value.extra(),
-        Self::UpdateSelectedBackground(value) => value.extra(),
-        Self::UpdateServiceNotification(value) => value.extra(),
-        Self::UpdateStickerSet(value) => value.extra(),
-        Self::UpdateSuggestedActions(value) => value.extra(),
-        Self::UpdateSupergroup(value) => value.extra(),
-        Self::UpdateSupergroupFullInfo(value) => value.extra(),
-        Self::UpdateTermsOfService(value) => value.extra(),
-        Self::UpdateTrendingStickerSets(value) => value.extra(),
-        Self::UpdateUnreadChatCount(value) => value.extra(),
-        Self::UpdateUnreadMessageCount(value) => value.extra(),
-        Self::UpdateUser(value) => value.extra(),
-        Self::UpdateUserChatAction(value) => value.extra(),
-        Self::UpdateUserFullInfo(value) => value.extra(),
-        Self::UpdateUserPrivacySettingRules(value) => value.extra(),
-        Self::UpdateUserStatus(value) => value.extra(),
-        Self::UpdateUsersNearby(value) => value.extra(),
-      
-        Self::AuthorizationState(value) => value.extra(),
-        Self::CanTransferOwnershipResult(value) => value.extra(),
-        Self::ChatStatistics(value) => value.extra(),
-        Self::CheckChatUsernameResult(value) => value.extra(),
-        Self::CheckStickerSetNameResult(value) => value.extra(),
-        Self::InternalLinkType(value) => value.extra(),
-        Self::JsonValue(value) => value.extra(),
-        Self::LanguagePackStringValue(value) => value.extra(),
-        Self::LogStream(value) => value.extra(),
-        Self::LoginUrlInfo(value) => value.extra(),
-        Self::MessageFileType(value) => value.extra(),
-        Self::OptionValue(value) => value.extra(),
-        Self::PassportElement(value) => value.extra(),
-        Self::ResetPasswordResult(value) => value.extra(),
-        Self::StatisticalGraph(value) => value.extra(),
-        Self::Update(value) => value.extra(),
-        Self::AccountTtl(value) => value.extra(),
-        Self::AnimatedEmoji(value) => value.extra(),
-        Self::Animations(value) => value.extra(),
-        Self::AuthenticationCodeInfo(value) => value.extra(),
-        Self::AutoDownloadSettingsPresets(value) => value.extra(),
-        Self::Background(value) => value.extra(),
-        Self::Backgrounds(value) => value.extra(),
-        Self::BankCardInfo(value) => value.extra(),
-        Self::BasicGroup(value) => value.extra(),
-        Self::BasicGroupFullInfo(value) => value.extra(),
-        Self::BotCommands(value) => value.extra(),
-        Self::CallId(value) => value.extra(),
-        Self::CallbackQueryAnswer(value) => value.extra(),
-        Self::Chat(value) => value.extra(),
-        Self::ChatAdministrators(value) => value.extra(),
-        Self::ChatEvents(value) => value.extra(),
-        Self::ChatFilter(value) => value.extra(),
-        Self::ChatFilterInfo(value) => value.extra(),
-        Self::ChatInviteLink(value) => value.extra(),
-        Self::ChatInviteLinkCounts(value) => value.extra(),
-        Self::ChatInviteLinkInfo(value) => value.extra(),
-        Self::ChatInviteLinkMembers(value) => value.extra(),
-        Self::ChatInviteLinks(value) => value.extra(),
-        Self::ChatJoinRequests(value) => value.extra(),
-        Self::ChatLists(value) => value.extra(),
-        Self::ChatMember(value) => value.extra(),
-        Self::ChatMembers(value) => value.extra(),
-        Self::ChatPhotos(value) => value.extra(),
-        Self::Chats(value) => value.extra(),
-        Self::ChatsNearby(value) => value.extra(),
-        Self::ConnectedWebsites(value) => value.extra(),
-        Self::Count(value) => value.extra(),
-        Self::Countries(value) => value.extra(),
-        Self::CustomRequestResult(value) => value.extra(),
-        Self::DatabaseStatistics(value) => value.extra(),
-        Self::DeepLinkInfo(value) => value.extra(),
-        Self::EmailAddressAuthenticationCodeInfo(value) => value.extra(),
-        Self::Emojis(value) => value.extra(),
-        Self::Error(value) => value.extra(),
-        Self::File(value) => value.extra(),
-        Self::FilePart(value) => value.extra(),
-        Self::FormattedText(value) => value.extra(),
-        Self::FoundMessages(value) => value.extra(),
-        Self::GameHighScores(value) => value.extra(),
-        Self::GroupCall(value) => value.extra(),
-        Self::GroupCallId(value) => value.extra(),
-        Self::Hashtags(value) => value.extra(),
-        Self::HttpUrl(value) => value.extra(),
-        Self::ImportedContacts(value) => value.extra(),
-        Self::InlineQueryResults(value) => value.extra(),
-        Self::LanguagePackInfo(value) => value.extra(),
-        Self::LanguagePackStrings(value) => value.extra(),
-        Self::LocalizationTargetInfo(value) => value.extra(),
-        Self::LogTags(value) => value.extra(),
-        Self::LogVerbosityLevel(value) => value.extra(),
-        Self::Message(value) => value.extra(),
-        Self::MessageCalendar(value) => value.extra(),
-        Self::MessageLink(value) => value.extra(),
-        Self::MessageLinkInfo(value) => value.extra(),
-        Self::MessagePositions(value) => value.extra(),
-        Self::MessageSenders(value) => value.extra(),
-        Self::MessageStatistics(value) => value.extra(),
-        Self::MessageThreadInfo(value) => value.extra(),
-        Self::Messages(value) => value.extra(),
-        Self::NetworkStatistics(value) => value.extra(),
-        Self::Ok(value) => value.extra(),
-        Self::OrderInfo(value) => value.extra(),
-        Self::PassportAuthorizationForm(value) => value.extra(),
-        Self::PassportElements(value) => value.extra(),
-        Self::PassportElementsWithErrors(value) => value.extra(),
-        Self::PasswordState(value) => value.extra(),
-        Self::PaymentForm(value) => value.extra(),
-        Self::PaymentReceipt(value) => value.extra(),
-        Self::PaymentResult(value) => value.extra(),
-        Self::PhoneNumberInfo(value) => value.extra(),
-        Self::Proxies(value) => value.extra(),
-        Self::Proxy(value) => value.extra(),
-        Self::PushReceiverId(value) => value.extra(),
-        Self::RecommendedChatFilters(value) => value.extra(),
-        Self::RecoveryEmailAddress(value) => value.extra(),
-        Self::ScopeNotificationSettings(value) => value.extra(),
-        Self::Seconds(value) => value.extra(),
-        Self::SecretChat(value) => value.extra(),
-        Self::Session(value) => value.extra(),
-        Self::Sessions(value) => value.extra(),
-        Self::SponsoredMessages(value) => value.extra(),
-        Self::Sticker(value) => value.extra(),
-        Self::StickerSet(value) => value.extra(),
-        Self::StickerSets(value) => value.extra(),
-        Self::Stickers(value) => value.extra(),
-        Self::StorageStatistics(value) => value.extra(),
-        Self::StorageStatisticsFast(value) => value.extra(),
-        Self::Supergroup(value) => value.extra(),
-        Self::SupergroupFullInfo(value) => value.extra(),
-        Self::TMeUrls(value) => value.extra(),
-        Self::TemporaryPasswordState(value) => value.extra(),
-        Self::TestBytes(value) => value.extra(),
-        Self::TestInt(value) => value.extra(),
-        Self::TestString(value) => value.extra(),
-        Self::TestVectorInt(value) => value.extra(),
-        Self::TestVectorIntObject(value) => value.extra(),
-        Self::TestVectorString(value) => value.extra(),
-        Self::TestVectorStringObject(value) => value.extra(),
-        Self::Text(value) => value.extra(),
-        Self::TextEntities(value) => value.extra(),
-        Self::Updates(value) => value.extra(),
-        Self::User(value) => value.extra(),
-        Self::UserFullInfo(value) => value.extra(),
-        Self::UserPrivacySettingRules(value) => value.extra(),
-        Self::Users(value) => value.extra(),
-        Self::ValidatedOrderInfo(value) => value.extra(),
-        Self::WebPage(value) => value.extra(),
-        Self::WebPageInstantView(value) => value.extra(),
-      
-    }
+
+  pub fn serialize<S: Serializer>(values: &[i64], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(values.iter().map(|value| value.to_string()))
   }
-  /// Return td type to json string
-  fn to_json(&self) -> RTDResult<String> {
-    match self {
-        Self::TestUseUpdate(value) => value.to_json(),
-        Self::UpdateActiveNotifications(value) => value.to_json(),
-        Self::UpdateAnimatedEmojiMessageClicked(value) => value.to_json(),
-        Self::UpdateAnimationSearchParameters(value) => value.to_json(),
-        Self::UpdateAuthorizationState(value) => value.to_json(),
-        Self::UpdateBasicGroup(value) => value.to_json(),
-        Self::UpdateBasicGroupFullInfo(value) => value.to_json(),
-        Self::UpdateCall(value) => value.to_json(),
-        Self::UpdateChatActionBar(value) => value.to_json(),
-        Self::UpdateChatDefaultDisableNotification(value) => value.to_json(),
-        Self::UpdateChatDraftMessage(value) => value.to_json(),
-        Self::UpdateChatFilters(value) => value.to_json(),
-        Self::UpdateChatHasScheduledMessages(value) => value.to_json(),
-        Self::UpdateChatIsBlocked(value) => value.to_json(),
-        Self::UpdateChatIsMarkedAsUnread(value) => value.to_json(),
-        Self::UpdateChatLastMessage(value) => value.to_json(),
-        Self::UpdateChatMember(value) => value.to_json(),
-        Self::UpdateChatMessageTtlSetting(value) => value.to_json(),
-        Self::UpdateChatNotificationSettings(value) => value.to_json(),
-        Self::UpdateChatOnlineMemberCount(value) => value.to_json(),
-        Self::UpdateChatPendingJoinRequests(value) => value.to_json(),
-        Self::UpdateChatPermissions(value) => value.to_json(),
-        Self::UpdateChatPhoto(value) => value.to_json(),
-        Self::UpdateChatPosition(value) => value.to_json(),
-        Self::UpdateChatReadInbox(value) => value.to_json(),
-        Self::UpdateChatReadOutbox(value) => value.to_json(),
-        Self::UpdateChatReplyMarkup(value) => value.to_json(),
-        Self::UpdateChatTheme(value) => value.to_json(),
-        Self::UpdateChatThemes(value) => value.to_json(),
-        Self::UpdateChatTitle(value) => value.to_json(),
-        Self::UpdateChatUnreadMentionCount(value) => value.to_json(),
-        Self::UpdateChatVideoChat(value) => value.to_json(),
-        Self::UpdateConnectionState(value) => value.to_json(),
-        Self::UpdateDeleteMessages(value) => value.to_json(),
-        Self::UpdateDiceEmojis(value) => value.to_json(),
-        Self::UpdateFavoriteStickers(value) => value.to_json(),
-        Self::UpdateFile(value) => value.to_json(),
-        Self::UpdateFileGenerationStart(value) => value.to_json(),
-        Self::UpdateFileGenerationStop(value) => value.to_json(),
-        Self::UpdateGroupCall(value) => value.to_json(),
-        Self::UpdateGroupCallParticipant(value) => value.to_json(),
-        Self::UpdateHavePendingNotifications(value) => value.to_json(),
-        Self::UpdateInstalledStickerSets(value) => value.to_json(),
-        Self::UpdateLanguagePackStrings(value) => value.to_json(),
-        Self::UpdateMessageContent(value) => value.to_json(),
-        Self::UpdateMessageContentOpened(value) => value.to_json(),
-        Self::UpdateMessageEdited(value) => value.to_json(),
-        Self::UpdateMessageInteractionInfo(value) => value.to_json(),
-        Self::UpdateMessageIsPinned(value) => value.to_json(),
-        Self::UpdateMessageLiveLocationViewed(value) => value.to_json(),
-        Self::UpdateMessageMentionRead(value) => value.to_json(),
-        Self::UpdateMessageSendAcknowledged(value) => value.to_json(),
-        Self::UpdateMessageSendFailed(value) => value.to_json(),
-        Self::UpdateMessageSendSucceeded(value) => value.to_json(),
-        Self::UpdateNewCallSignalingData(value) => value.to_json(),
-        Self::UpdateNewCallbackQuery(value) => value.to_json(),
-        Self::UpdateNewChat(value) => value.to_json(),
-        Self::UpdateNewChatJoinRequest(value) => value.to_json(),
-        Self::UpdateNewChosenInlineResult(value) => value.to_json(),
-        Self::UpdateNewCustomEvent(value) => value.to_json(),
-        Self::UpdateNewCustomQuery(value) => value.to_json(),
-        Self::UpdateNewInlineCallbackQuery(value) => value.to_json(),
-        Self::UpdateNewInlineQuery(value) => value.to_json(),
-        Self::UpdateNewMessage(value) => value.to_json(),
-        Self::UpdateNewPreCheckoutQuery(value) => value.to_json(),
-        Self::UpdateNewShippingQuery(value) => value.to_json(),
-        Self::UpdateNotification(value) => value.to_json(),
-        Self::UpdateNotificationGroup(value) => value.to_json(),
-        Self::UpdateOption(value) => value.to_json(),
-        Self::UpdatePoll(value) => value.to_json(),
-        Self::UpdatePollAnswer(value) => value.to_json(),
-        Self::UpdateRecentStickers(value) => value.to_json(),
-        Self::UpdateSavedAnimations(value) => value.to_json(),
-        Self::UpdateScopeNotificationSettings(value) => value.to_json(),
-        Self::UpdateSecretChat(value) => value.to_json(),
-        Self::UpdateSelectedBackground(value) => value.to_json(),
-        Self::UpdateServiceNotification(value) => value.to_json(),
-        Self::UpdateStickerSet(value) => value.to_json(),
-        Self::UpdateSuggestedActions(value) => value.to_json(),
-        Self::UpdateSupergroup(value) => value.to_json(),
-        Self::UpdateSupergroupFullInfo(value) => value.to_json(),
-        Self::UpdateTermsOfService(value) => value.to_json(),
-        Self::UpdateTrendingStickerSets(value) => value.to_json(),
-        Self::UpdateUnreadChatCount(value) => value.to_json(),
-        Self::UpdateUnreadMessageCount(value) => value.to_json(),
-        Self::UpdateUser(value) => value.to_json(),
-        Self::UpdateUserChatAction(value) => value.to_json(),
-        Self::UpdateUserFullInfo(value) => value.to_json(),
-        Self::UpdateUserPrivacySettingRules(value) => value.to_json(),
-        Self::UpdateUserStatus(value) => value.to_json(),
-        Self::UpdateUsersNearby(value) => value.to_json(),
-      
-        Self::AuthorizationState(value) => value.to_json(),
-        Self::CanTransferOwnershipResult(value) => value.to_json(),
-        Self::ChatStatistics(value) => value.to_json(),
-        Self::CheckChatUsernameResult(value) => value.to_json(),
-        Self::CheckStickerSetNameResult(value) => value.to_json(),
-        Self::InternalLinkType(value) => value.to_json(),
-        Self::JsonValue(value) => value.to_json(),
-        Self::LanguagePackStringValue(value) => value.to_json(),
-        Self::LogStream(value) => value.to_json(),
-        Self::LoginUrlInfo(value) => value.to_json(),
-        Self::MessageFileType(value) => value.to_json(),
-        Self::OptionValue(value) => value.to_json(),
-        Self::PassportElement(value) => value.to_json(),
-        Self::ResetPasswordResult(value) => value.to_json(),
-        Self::StatisticalGraph(value) => value.to_json(),
-        Self::Update(value) => value.to_json(),
-        Self::AccountTtl(value) => value.to_json(),
-        Self::AnimatedEmoji(value) => value.to_json(),
-        Self::Animations(value) => value.to_json(),
-        Self::AuthenticationCodeInfo(value) => value.to_json(),
-        Self::AutoDownloadSettingsPresets(value) => value.to_json(),
-        Self::Background(value) => value.to_json(),
-        Self::Backgrounds(value) => value.to_json(),
-        Self::BankCardInfo(value) => value.to_json(),
-        Self::BasicGroup(value) => value.to_json(),
-        Self::BasicGroupFullInfo(value) => value.to_json(),
-        Self::BotCommands(value) => value.to_json(),
-        Self::CallId(value) => value.to_json(),
-        Self::CallbackQueryAnswer(value) => value.to_json(),
-        Self::Chat(value) => value.to_json(),
-        Self::ChatAdministrators(value) => value.to_json(),
-        Self::ChatEvents(value) => value.to_json(),
-        Self::ChatFilter(value) => value.to_json(),
-        Self::ChatFilterInfo(value) => value.to_json(),
-        Self::ChatInviteLink(value) => value.to_json(),
-        Self::ChatInviteLinkCounts(value) => value.to_json(),
-        Self::ChatInviteLinkInfo(value) => value.to_json(),
-        Self::ChatInviteLinkMembers(value) => value.to_json(),
-        Self::ChatInviteLinks(value) => value.to_json(),
-        Self::ChatJoinRequests(value) => value.to_json(),
-        Self::ChatLists(value) => value.to_json(),
-        Self::ChatMember(value) => value.to_json(),
-        Self::ChatMembers(value) => value.to_json(),
-        Self::ChatPhotos(value) => value.to_json(),
-        Self::Chats(value) => value.to_json(),
-        Self::ChatsNearby(value) => value.to_json(),
-        Self::ConnectedWebsites(value) => value.to_json(),
-        Self::Count(value) => value.to_json(),
-        Self::Countries(value) => value.to_json(),
-        Self::CustomRequestResult(value) => value.to_json(),
-        Self::DatabaseStatistics(value) => value.to_json(),
-        Self::DeepLinkInfo(value) => value.to_json(),
-        Self::EmailAddressAuthenticationCodeInfo(value) => value.to_json(),
-        Self::Emojis(value) => value.to_json(),
-        Self::Error(value) => value.to_json(),
-        Self::File(value) => value.to_json(),
-        Self::FilePart(value) => value.to_json(),
-        Self::FormattedText(value) => value.to_json(),
-        Self::FoundMessages(value) => value.to_json(),
-        Self::GameHighScores(value) => value.to_json(),
-        Self::GroupCall(value) => value.to_json(),
-        Self::GroupCallId(value) => value.to_json(),
-        Self::Hashtags(value) => value.to_json(),
-        Self::HttpUrl(value) => value.to_json(),
-        Self::ImportedContacts(value) => value.to_json(),
-        Self::InlineQueryResults(value) => value.to_json(),
-        Self::LanguagePackInfo(value) => value.to_json(),
-        Self::LanguagePackStrings(value) => value.to_json(),
-        Self::LocalizationTargetInfo(value) => value.to_json(),
-        Self::LogTags(value) => value.to_json(),
-        Self::LogVerbosityLevel(value) => value.to_json(),
-        Self::Message(value) => value.to_json(),
-        Self::MessageCalendar(value) => value.to_json(),
-        Self::MessageLink(value) => value.to_json(),
-        Self::MessageLinkInfo(value) => value.to_json(),
-        Self::MessagePositions(value) => value.to_json(),
-        Self::MessageSenders(value) => value.to_json(),
-        Self::MessageStatistics(value) => value.to_json(),
-        Self::MessageThreadInfo(value) => value.to_json(),
-        Self::Messages(value) => value.to_json(),
-        Self::NetworkStatistics(value) => value.to_json(),
-        Self::Ok(value) => value.to_json(),
-        Self::OrderInfo(value) => value.to_json(),
-        Self::PassportAuthorizationForm(value) => value.to_json(),
-        Self::PassportElements(value) => value.to_json(),
-        Self::PassportElementsWithErrors(value) => value.to_json(),
-        Self::PasswordState(value) => value.to_json(),
-        Self::PaymentForm(value) => value.to_json(),
-        Self::PaymentReceipt(value) => value.to_json(),
-        Self::PaymentResult(value) => value.to_json(),
-        Self::PhoneNumberInfo(value) => value.to_json(),
-        Self::Proxies(value) => value.to_json(),
-        Self::Proxy(value) => value.to_json(),
-        Self::PushReceiverId(value) => value.to_json(),
-        Self::RecommendedChatFilters(value) => value.to_json(),
-        Self::RecoveryEmailAddress(value) => value.to_json(),
-        Self::ScopeNotificationSettings(value) => value.to_json(),
-        Self::Seconds(value) => value.to_json(),
-        Self::SecretChat(value) => value.to_json(),
-        Self::Session(value) => value.to_json(),
-        Self::Sessions(value) => value.to_json(),
-        Self::SponsoredMessages(value) => value.to_json(),
-        Self::Sticker(value) => value.to_json(),
-        Self::StickerSet(value) => value.to_json(),
-        Self::StickerSets(value) => value.to_json(),
-        Self::Stickers(value) => value.to_json(),
-        Self::StorageStatistics(value) => value.to_json(),
-        Self::StorageStatisticsFast(value) => value.to_json(),
-        Self::Supergroup(value) => value.to_json(),
-        Self::SupergroupFullInfo(value) => value.to_json(),
-        Self::TMeUrls(value) => value.to_json(),
-        Self::TemporaryPasswordState(value) => value.to_json(),
-        Self::TestBytes(value) => value.to_json(),
-        Self::TestInt(value) => value.to_json(),
-        Self::TestString(value) => value.to_json(),
-        Self::TestVectorInt(value) => value.to_json(),
-        Self::TestVectorIntObject(value) => value.to_json(),
-        Self::TestVectorString(value) => value.to_json(),
-        Self::TestVectorStringObject(value) => value.to_json(),
-        Self::Text(value) => value.to_json(),
-        Self::TextEntities(value) => value.to_json(),
-        Self::Updates(value) => value.to_json(),
-        Self::User(value) => value.to_json(),
-        Self::UserFullInfo(value) => value.to_json(),
-        Self::UserPrivacySettingRules(value) => value.to_json(),
-        Self::Users(value) => value.to_json(),
-        Self::ValidatedOrderInfo(value) => value.to_json(),
-        Self::WebPage(value) => value.to_json(),
-        Self::WebPageInstantView(value) => value.to_json(),
-      
-    }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<i64>, D::Error> {
+    let values: Vec<Int64> = Deserialize::deserialize(deserializer)?;
+    Ok(values.into_iter().map(|value| value.0).collect())
   }
 }
 
-
-
 #[cfg(test)]
 mod tests {
   use crate::types::{TdType, from_json, UpdateAuthorizationState};
@@ -1552,14 +584,14 @@ mod tests {
   #[test]
   fn test_deserialize_enum() {
     match from_json::<UpdateAuthorizationState>(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitTdlibParameters"}}"#) {
-      Ok(t) => {},
+      Ok(_t) => {},
       Err(e) => {panic!("{}", e)}
     };
 
     match from_json::<TdType>(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitTdlibParameters"}}"#) {
       Ok(t) => {
         match t {
-          TdType::UpdateAuthorizationState(v) => {},
+          TdType::UpdateAuthorizationState(_v) => {},
           _ => panic!("from_json failed: {:?}", t)
         }
       },
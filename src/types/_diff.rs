@@ -0,0 +1,79 @@
+//! Structural diff of td objects: the fields that differ between two values of a type, like a
+//! `Chat` before and after an update. `@type` and `@extra` are bookkeeping and ignored, as the
+//! `PartialEq` impls ignore them.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::types::RObject;
+
+/// A field whose value differs
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+  /// Where the field is, like `permissions.can_send_polls` or `positions[0].order`, named as in
+  /// tdlib's json
+  pub path: String,
+  /// The old json value, `null` if there was none, like an element past the end of the old vector.
+  /// Values of tdlib type `int64` are json strings, as tdlib sends them, like `"5"` for a
+  /// `ChatPosition::order`.
+  pub old: Value,
+  pub new: Value,
+}
+
+impl fmt::Display for FieldChange {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}: {} -> {}", self.path, self.old, self.new)
+  }
+}
+
+/// The fields that differ between `old` and `new`, empty if they are equal. An object changing
+/// its `@type`, like a message content becoming a `messagePhoto`, is one change of the whole object.
+///
+/// ```
+/// use rtdlib::types::*;
+///
+/// let old = ChatPermissions::builder().can_send_messages(true).can_send_polls(true).build();
+/// let new = ChatPermissions::builder().can_send_messages(true).build();
+/// let changes = diff(&old, &new);
+/// assert_eq!(changes[0].to_string(), "can_send_polls: true -> false");
+/// ```
+pub fn diff<T: RObject + Serialize>(old: &T, new: &T) -> Vec<FieldChange> {
+  let old = serde_json::to_value(old).expect("td objects serialize to json");
+  let new = serde_json::to_value(new).expect("td objects serialize to json");
+  let mut changes = vec![];
+  compare(&mut String::new(), &old, &new, &mut changes);
+  changes
+}
+
+const NULL: Value = Value::Null;
+
+fn compare(path: &mut String, old: &Value, new: &Value, changes: &mut Vec<FieldChange>) {
+  match (old, new) {
+    (Value::Object(old_fields), Value::Object(new_fields)) if old_fields.get("@type") == new_fields.get("@type") => {
+      let mut names: Vec<&String> = old_fields.keys().chain(new_fields.keys()).filter(|name| !name.starts_with('@')).collect();
+      names.sort();
+      names.dedup();
+      for name in names {
+        let segment = if path.is_empty() { name.to_string() } else { format!(".{}", name) };
+        enter(path, &segment, |path| compare(path, old_fields.get(name).unwrap_or(&NULL), new_fields.get(name).unwrap_or(&NULL), changes));
+      }
+    }
+    (Value::Array(old_elements), Value::Array(new_elements)) => {
+      for index in 0..old_elements.len().max(new_elements.len()) {
+        let segment = format!("[{}]", index);
+        enter(path, &segment, |path| compare(path, old_elements.get(index).unwrap_or(&NULL), new_elements.get(index).unwrap_or(&NULL), changes));
+      }
+    }
+    _ if old != new => changes.push(FieldChange { path: path.clone(), old: old.clone(), new: new.clone() }),
+    _ => {}
+  }
+}
+
+fn enter<F: FnOnce(&mut String)>(path: &mut String, segment: &str, compare: F) {
+  let len = path.len();
+  path.push_str(segment);
+  compare(path);
+  path.truncate(len);
+}
@@ -0,0 +1,174 @@
+//! `@extra`, any json tdlib echoes back in the reply to a function. The clients give a function
+//! without one a new `@extra` as they send it, from their own `ExtraGenerator` if they have one,
+//! else from the one set by `set_extra_generator`, a UUID v4 each unless another one is set.
+//! `extra(...)` of a builder or `set_extra(...)` of a function sets it explicitly.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use uuid::Uuid;
+
+/// The `@extra` of a td object. tdlib takes any json, the generators make strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Extra(serde_json::Value);
+
+impl Extra {
+  /// The string, if it is one
+  pub fn as_str(&self) -> Option<&str> { self.0.as_str() }
+
+  pub fn value(&self) -> &serde_json::Value { &self.0 }
+
+  pub fn into_value(self) -> serde_json::Value { self.0 }
+}
+
+impl Hash for Extra {
+  /// By the value, object keys sorted so equal objects hash the same whatever their key order
+  fn hash<H: Hasher>(&self, state: &mut H) { hash_value(&self.0, state) }
+}
+
+fn hash_value<H: Hasher>(value: &serde_json::Value, state: &mut H) {
+  use serde_json::Value;
+  std::mem::discriminant(value).hash(state);
+  match value {
+    Value::Null => {}
+    Value::Bool(value) => value.hash(state),
+    // equal numbers print the same, `1` and `1.0` are not equal
+    Value::Number(number) => number.to_string().hash(state),
+    Value::String(string) => string.hash(state),
+    Value::Array(items) => {
+      items.len().hash(state);
+      items.iter().for_each(|item| hash_value(item, state));
+    }
+    Value::Object(object) => {
+      let mut entries: Vec<(&String, &Value)> = object.iter().collect();
+      entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+      entries.len().hash(state);
+      for (key, value) in entries {
+        key.hash(state);
+        hash_value(value, state);
+      }
+    }
+  }
+}
+
+impl fmt::Display for Extra {
+  /// A string as it is, other json serialized
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match &self.0 {
+      serde_json::Value::String(extra) => f.write_str(extra),
+      value => write!(f, "{}", value),
+    }
+  }
+}
+
+impl From<serde_json::Value> for Extra {
+  fn from(value: serde_json::Value) -> Self { Self(value) }
+}
+
+impl From<String> for Extra {
+  fn from(extra: String) -> Self { Self(serde_json::Value::String(extra)) }
+}
+
+impl<'a> From<&'a str> for Extra {
+  fn from(extra: &'a str) -> Self { Self::from(extra.to_string()) }
+}
+
+impl From<i64> for Extra {
+  fn from(extra: i64) -> Self { Self(extra.into()) }
+}
+
+impl From<u64> for Extra {
+  fn from(extra: u64) -> Self { Self(extra.into()) }
+}
+
+impl PartialEq<str> for Extra {
+  fn eq(&self, other: &str) -> bool { self.as_str() == Some(other) }
+}
+
+impl<'a> PartialEq<&'a str> for Extra {
+  fn eq(&self, other: &&'a str) -> bool { self.as_str() == Some(*other) }
+}
+
+static GENERATOR: RwLock<Option<Box<dyn ExtraGenerator>>> = RwLock::new(None);
+
+/// Makes the `@extra` of new objects, tdlib echoes it back in the reply to a function
+pub trait ExtraGenerator: Send + Sync {
+  /// The `@extra` of a new object of type `td_name`, like `sendMessage`
+  fn extra(&self, td_name: &str) -> String;
+}
+
+/// Closures taking the `td_name`, e.g. to prefix the ids of each subsystem differently
+impl<F: Fn(&str) -> String + Send + Sync> ExtraGenerator for F {
+  fn extra(&self, td_name: &str) -> String { self(td_name) }
+}
+
+/// A UUID v4 each, the default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidExtra;
+
+impl ExtraGenerator for UuidExtra {
+  fn extra(&self, _td_name: &str) -> String { Uuid::new_v4().to_string() }
+}
+
+/// `1`, `2`, `3`, ... reproducible and cheap
+#[derive(Debug, Default)]
+pub struct CounterExtra {
+  next: AtomicU64,
+}
+
+impl CounterExtra {
+  pub fn new() -> Self { Self::starting_at(1) }
+
+  pub fn starting_at(first: u64) -> Self { Self { next: AtomicU64::new(first) } }
+}
+
+impl ExtraGenerator for CounterExtra {
+  fn extra(&self, _td_name: &str) -> String { self.next.fetch_add(1, Ordering::Relaxed).to_string() }
+}
+
+/// The `@extra` of another generator after a prefix, like `billing:7`, telling which subsystem sent a request
+pub struct PrefixedExtra<G> {
+  prefix: String,
+  inner: G,
+}
+
+impl<G: ExtraGenerator> PrefixedExtra<G> {
+  /// Ids of `inner` as `<prefix>:<id>`
+  pub fn new<S: AsRef<str>>(prefix: S, inner: G) -> Self {
+    Self { prefix: prefix.as_ref().to_string(), inner }
+  }
+
+  pub fn prefix(&self) -> &str { &self.prefix }
+}
+
+impl<G: ExtraGenerator> ExtraGenerator for PrefixedExtra<G> {
+  fn extra(&self, td_name: &str) -> String { format!("{}:{}", self.prefix, self.inner.extra(td_name)) }
+}
+
+impl<G> fmt::Debug for PrefixedExtra<G> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("PrefixedExtra").field("prefix", &self.prefix).finish()
+  }
+}
+
+/// Make the clients without an `ExtraGenerator` of their own fill `@extra` with `generator`
+pub fn set_extra_generator<G: ExtraGenerator + 'static>(generator: G) {
+  *GENERATOR.write().unwrap() = Some(Box::new(generator));
+}
+
+/// Go back to a UUID v4 each
+pub fn reset_extra_generator() {
+  *GENERATOR.write().unwrap() = None;
+}
+
+/// The `@extra` of a new object of type `td_name`, by the generator `set_extra_generator` set,
+/// what clients without a generator of their own send
+pub fn next_extra(td_name: &str) -> String {
+  match GENERATOR.read().unwrap().as_ref() {
+    Some(generator) => generator.extra(td_name),
+    None => UuidExtra.extra(td_name),
+  }
+}
@@ -0,0 +1,229 @@
+//! Identifiers of chats, users, messages and files. Without the `typed-ids` feature they are `i64`,
+//! with it newtypes serializing as the plain number: passing a user id as a chat id doesn't
+//! compile, and related kinds convert explicitly, like `user_id.chat_id()` for the private chat
+//! with a user. Either way `ChatId::from(id)` and `i64::from(chat_id)` convert from and to `i64`.
+//!
+//! The feature is not additive, it changes the types of public fields for every crate in the
+//! dependency graph. Libraries should convert with `from` and leave enabling it to binaries.
+//!
+//! Tdlib encodes the kind of a chat in its id, as the Bot API does, and the server id of a message
+//! in its id, `ChatKind` and `server_message_id` decode them with or without the feature.
+
+use crate::types::*;
+
+#[cfg(not(feature = "typed-ids"))]
+mod plain {
+  pub type BasicGroupId = i64;
+  pub type ChatId = i64;
+  pub type FileId = i64;
+  pub type MessageId = i64;
+  pub type SecretChatId = i64;
+  pub type SupergroupId = i64;
+  pub type UserId = i64;
+}
+
+#[cfg(not(feature = "typed-ids"))]
+pub use self::plain::*;
+
+#[cfg(feature = "typed-ids")]
+pub use self::newtypes::*;
+
+#[cfg(feature = "typed-ids")]
+mod newtypes {
+  use std::fmt;
+
+  use super::ChatKind;
+
+  macro_rules! id {
+    ($(#[$doc:meta])* $name:ident) => {
+      $(#[$doc])*
+      #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+      #[serde(transparent)]
+      pub struct $name(pub i64);
+
+      impl fmt::Display for $name {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { self.0.fmt(f) }
+      }
+
+      impl From<i64> for $name {
+        fn from(id: i64) -> Self { $name(id) }
+      }
+
+      impl From<$name> for i64 {
+        fn from(id: $name) -> Self { id.0 }
+      }
+    };
+  }
+
+  id!(
+    /// Identifier of a basic group, its chat is `basic_group_id.chat_id()`
+    BasicGroupId
+  );
+  id!(
+    /// Identifier of a chat, of any type
+    ChatId
+  );
+  id!(FileId);
+  id!(
+    /// Identifier of a message in its chat
+    MessageId
+  );
+  id!(
+    /// Identifier of a secret chat, its chat is `secret_chat_id.chat_id()`
+    SecretChatId
+  );
+  id!(
+    /// Identifier of a supergroup or channel, its chat is `supergroup_id.chat_id()`
+    SupergroupId
+  );
+  id!(
+    /// Identifier of a user, their private chat is `user_id.chat_id()`
+    UserId
+  );
+
+  impl ChatId {
+    /// The user of a private chat
+    pub fn user_id(self) -> Option<UserId> {
+      if let Some(ChatKind::Private(id)) = ChatKind::of(self) { Some(id) } else { None }
+    }
+
+    /// The basic group of a basic group chat
+    pub fn basic_group_id(self) -> Option<BasicGroupId> {
+      if let Some(ChatKind::BasicGroup(id)) = ChatKind::of(self) { Some(id) } else { None }
+    }
+
+    /// The supergroup of a supergroup or channel chat
+    pub fn supergroup_id(self) -> Option<SupergroupId> {
+      if let Some(ChatKind::Supergroup(id)) = ChatKind::of(self) { Some(id) } else { None }
+    }
+
+    /// The secret chat of a secret chat's chat
+    pub fn secret_chat_id(self) -> Option<SecretChatId> {
+      if let Some(ChatKind::Secret(id)) = ChatKind::of(self) { Some(id) } else { None }
+    }
+  }
+
+  impl UserId {
+    /// The private chat with the user
+    pub fn chat_id(self) -> Option<ChatId> { ChatKind::Private(self).chat_id() }
+  }
+
+  impl BasicGroupId {
+    pub fn chat_id(self) -> Option<ChatId> { ChatKind::BasicGroup(self).chat_id() }
+  }
+
+  impl SupergroupId {
+    pub fn chat_id(self) -> Option<ChatId> { ChatKind::Supergroup(self).chat_id() }
+  }
+
+  impl SecretChatId {
+    pub fn chat_id(self) -> Option<ChatId> { ChatKind::Secret(self).chat_id() }
+  }
+}
+
+// how tdlib numbers the chats of each kind
+const MAX_USER_ID: i64 = (1 << 40) - 1;
+const MAX_BASIC_GROUP_ID: i64 = 999_999_999_999;
+const ZERO_SUPERGROUP_CHAT_ID: i64 = -1_000_000_000_000;
+const MAX_SUPERGROUP_ID: i64 = 1_000_000_000_000 - (1 << 31);
+const ZERO_SECRET_CHAT_ID: i64 = -2_000_000_000_000;
+
+/// Message ids of server messages are their server ids shifted by this, the lower bits number
+/// local messages, like those being sent
+const SERVER_MESSAGE_ID_SHIFT: u32 = 20;
+
+/// What a chat is, by its id: users are their own ids, basic groups their negated ids,
+/// supergroups and channels `-100` followed by their ids, as in the Bot API, and secret chats
+/// their ids offset by -2000000000000.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChatKind {
+  Private(UserId),
+  BasicGroup(BasicGroupId),
+  Supergroup(SupergroupId),
+  Secret(SecretChatId),
+}
+
+impl ChatKind {
+  /// The kind of the chat, `None` if tdlib doesn't give chats such an id
+  pub fn of(chat_id: ChatId) -> Option<ChatKind> {
+    let id = untyped(chat_id);
+    let supergroup_chat_ids = ZERO_SUPERGROUP_CHAT_ID - MAX_SUPERGROUP_ID..ZERO_SUPERGROUP_CHAT_ID;
+    let secret_chat_ids = ZERO_SECRET_CHAT_ID + i64::from(i32::MIN)..=ZERO_SECRET_CHAT_ID + i64::from(i32::MAX);
+    if (1..=MAX_USER_ID).contains(&id) {
+      Some(ChatKind::Private(typed(id)))
+    } else if (-MAX_BASIC_GROUP_ID..0).contains(&id) {
+      Some(ChatKind::BasicGroup(typed(-id)))
+    } else if supergroup_chat_ids.contains(&id) {
+      Some(ChatKind::Supergroup(typed(ZERO_SUPERGROUP_CHAT_ID - id)))
+    } else if secret_chat_ids.contains(&id) && id != ZERO_SECRET_CHAT_ID {
+      Some(ChatKind::Secret(typed(id - ZERO_SECRET_CHAT_ID)))
+    } else {
+      None
+    }
+  }
+
+  /// Id of the chat, `None` for an id tdlib doesn't give a chat of the kind, like a negative user id
+  pub fn chat_id(self) -> Option<ChatId> {
+    let id = match self {
+      ChatKind::Private(user_id) => Some(untyped(user_id)),
+      ChatKind::BasicGroup(basic_group_id) => untyped(basic_group_id).checked_neg(),
+      ChatKind::Supergroup(supergroup_id) => ZERO_SUPERGROUP_CHAT_ID.checked_sub(untyped(supergroup_id)),
+      ChatKind::Secret(secret_chat_id) => ZERO_SECRET_CHAT_ID.checked_add(untyped(secret_chat_id)),
+    };
+    id.map(typed::<ChatId>).filter(|chat_id| ChatKind::of(*chat_id) == Some(self))
+  }
+}
+
+/// The server id of a message, its id in the Bot API and `t.me` links, `None` for local messages
+/// like those being sent
+pub fn server_message_id(message_id: MessageId) -> Option<i64> {
+  let id = untyped(message_id);
+  if id > 0 && id.trailing_zeros() >= SERVER_MESSAGE_ID_SHIFT { Some(id >> SERVER_MESSAGE_ID_SHIFT) } else { None }
+}
+
+/// The id of a message by its server id
+pub fn message_id_from_server(server_message_id: i64) -> MessageId {
+  typed(server_message_id << SERVER_MESSAGE_ID_SHIFT)
+}
+
+impl ChatType {
+  /// What the chat of this type is, `None` for an unknown type
+  pub fn kind(&self) -> Option<ChatKind> {
+    match self {
+      ChatType::Private(private) => Some(ChatKind::Private(private.user_id())),
+      ChatType::BasicGroup(basic_group) => Some(ChatKind::BasicGroup(basic_group.basic_group_id())),
+      ChatType::Supergroup(supergroup) => Some(ChatKind::Supergroup(supergroup.supergroup_id())),
+      ChatType::Secret(secret) => Some(ChatKind::Secret(secret.secret_chat_id())),
+      _ => None,
+    }
+  }
+
+  /// Id of the chat of this type
+  pub fn chat_id(&self) -> Option<ChatId> { self.kind().and_then(ChatKind::chat_id) }
+}
+
+impl BasicGroup {
+  /// Id of the group's chat
+  pub fn chat_id(&self) -> Option<ChatId> { ChatKind::BasicGroup(self.id()).chat_id() }
+}
+
+impl Supergroup {
+  /// Id of the supergroup's or channel's chat
+  pub fn chat_id(&self) -> Option<ChatId> { ChatKind::Supergroup(self.id()).chat_id() }
+}
+
+impl SecretChat {
+  /// Id of the secret chat's chat
+  pub fn chat_id(&self) -> Option<ChatId> { ChatKind::Secret(self.id()).chat_id() }
+}
+
+impl Message {
+  /// Server id of the message, see `server_message_id`
+  pub fn server_id(&self) -> Option<i64> { server_message_id(self.id()) }
+}
+
+/// An id of its number, for code built with and without the `typed-ids` feature
+pub(crate) fn typed<T: From<i64>>(id: i64) -> T { T::from(id) }
+
+/// The number of an id
+pub(crate) fn untyped<T: Into<i64>>(id: T) -> i64 { id.into() }
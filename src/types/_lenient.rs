@@ -0,0 +1,373 @@
+//! Lenient decoding, for tdlib builds whose json drifted from the generated types. Missing or
+//! mismatched fields fall back to their `Default` and are reported as `Diagnostic`s instead of
+//! failing the whole object. Strict decoding stays the default.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Error, Value};
+
+use crate::errors::*;
+use crate::json::{DefaultJson, JsonBackend};
+
+/// How many diagnostics of global lenient decoding are kept until `take_diagnostics`
+const MAX_DIAGNOSTICS: usize = 1024;
+
+static LENIENT: AtomicBool = AtomicBool::new(false);
+// a `Vec`, `VecDeque::new` is const since 1.68 only
+static DIAGNOSTICS: Mutex<Vec<Diagnostic>> = Mutex::new(Vec::new());
+
+thread_local! {
+  /// The lenient decoding running on this thread, if any
+  static DECODING: RefCell<Option<Decoding>> = const { RefCell::new(None) };
+}
+
+/// A field lenient decoding couldn't decode as is
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+  /// Where the field is, like `message.content.text`
+  pub path: String,
+  pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}: {}", self.path, self.message)
+  }
+}
+
+/// Make `from_json`, `parse_response` and the clients decode leniently, diagnostics are kept for `take_diagnostics`
+/// The switch and its diagnostics are process wide, `from_json_lenient` returns the diagnostics of one call
+pub fn set_lenient_decoding(enabled: bool) {
+  LENIENT.store(enabled, Ordering::SeqCst)
+}
+
+pub fn is_lenient_decoding() -> bool {
+  LENIENT.load(Ordering::SeqCst)
+}
+
+/// Diagnostics of global lenient decoding since the last call, at most the latest 1024
+pub fn take_diagnostics() -> Vec<Diagnostic> {
+  DIAGNOSTICS.lock().unwrap().drain(..).collect()
+}
+
+pub(crate) fn keep_diagnostics(diagnostics: Vec<Diagnostic>) {
+  let mut kept = DIAGNOSTICS.lock().unwrap();
+  kept.extend(diagnostics);
+  let overflow = kept.len().saturating_sub(MAX_DIAGNOSTICS);
+  kept.drain(..overflow);
+}
+
+/// Decode json strictly, whatever `set_lenient_decoding` says
+pub fn from_json_strict<T: DeserializeOwned>(json: &str) -> RTDResult<T> {
+  DefaultJson::from_str(json)
+}
+
+/// Decode json leniently, returning what had to be defaulted along with the value. Still fails on
+/// json that isn't valid or on a top level value that isn't an object.
+pub fn from_json_lenient<T: DeserializeOwned>(json: &str) -> RTDResult<(T, Vec<Diagnostic>)> {
+  let value: Value = DefaultJson::from_str(json)?;
+  if !value.is_object() {
+    return Err(RTDError::custom(format!("expected object, found {}", kind(&value))));
+  }
+  let previous = DECODING.with(|decoding| decoding.borrow_mut().replace(Decoding::default()));
+  let _restore = Restore(previous);
+  let result = T::deserialize(Lenient::Value(value));
+  let diagnostics = DECODING.with(|decoding| decoding.borrow_mut().take().map(|decoding| decoding.diagnostics).unwrap_or_default());
+  Ok((result?, diagnostics))
+}
+
+/// Whether a lenient decoding is running on this thread
+pub(crate) fn is_decoding() -> bool {
+  DECODING.with(|decoding| decoding.borrow().is_some())
+}
+
+/// Report a field of the running lenient decoding
+pub(crate) fn diagnose(message: String) {
+  DECODING.with(|decoding| {
+    if let Some(decoding) = decoding.borrow_mut().as_mut() {
+      let path = decoding.path();
+      decoding.diagnostics.push(Diagnostic { path, message });
+    }
+  })
+}
+
+/// Decode an already parsed value, leniently if a lenient decoding is running on this thread
+pub(crate) fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, Error> {
+  if is_decoding() { T::deserialize(Lenient::Value(value)) } else { serde_json::from_value(value) }
+}
+
+#[derive(Default)]
+struct Decoding {
+  path: Vec<String>,
+  diagnostics: Vec<Diagnostic>,
+}
+
+impl Decoding {
+  fn path(&self) -> String {
+    let mut path = String::new();
+    for segment in &self.path {
+      if !path.is_empty() && !segment.starts_with('[') { path.push('.') }
+      path.push_str(segment);
+    }
+    path
+  }
+}
+
+/// Puts back the lenient decoding `from_json_lenient` replaced, even if decoding panics
+struct Restore(Option<Decoding>);
+
+impl Drop for Restore {
+  fn drop(&mut self) {
+    let previous = self.0.take();
+    DECODING.with(|decoding| *decoding.borrow_mut() = previous);
+  }
+}
+
+fn with_segment<R, F: FnOnce() -> R>(segment: String, fnc: F) -> R {
+  DECODING.with(|decoding| decoding.borrow_mut().as_mut().map(|decoding| decoding.path.push(segment)));
+  let result = fnc();
+  DECODING.with(|decoding| decoding.borrow_mut().as_mut().map(|decoding| decoding.path.pop()));
+  result
+}
+
+fn kind(value: &Value) -> &'static str {
+  match value {
+    Value::Null => "null",
+    Value::Bool(_) => "boolean",
+    Value::Number(_) => "number",
+    Value::String(_) => "string",
+    Value::Array(_) => "array",
+    Value::Object(_) => "object",
+  }
+}
+
+/// A value being decoded leniently
+enum Lenient {
+  Value(Value),
+  /// A field missing from its object
+  Missing,
+  /// Stands in for a missing or mismatched value that was already reported
+  Default,
+}
+
+impl Lenient {
+  /// Report that `self` isn't an `expected`, the caller decodes the default instead
+  fn report(&self, expected: &str) {
+    match self {
+      Lenient::Value(value) => diagnose(format!("expected {}, found {}", expected, kind(value))),
+      Lenient::Missing => diagnose("missing field".to_string()),
+      Lenient::Default => {}
+    }
+  }
+
+  fn integer<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    if let Lenient::Value(Value::Number(number)) = &self {
+      if let Some(number) = number.as_i64() { return visitor.visit_i64(number) }
+      if let Some(number) = number.as_u64() { return visitor.visit_u64(number) }
+    }
+    // tdlib sends int64 as strings
+    if let Lenient::Value(Value::String(string)) = &self {
+      if let Ok(number) = string.parse::<i64>() { return visitor.visit_i64(number) }
+    }
+    self.report("integer");
+    visitor.visit_i64(0)
+  }
+
+  fn float<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    match &self {
+      Lenient::Value(Value::Number(number)) => visitor.visit_f64(number.as_f64().unwrap_or_default()),
+      _ => {
+        self.report("number");
+        visitor.visit_f64(0.0)
+      }
+    }
+  }
+
+  fn seq<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(Value::Array(items)) => visitor.visit_seq(LenientSeq { items: items.into_iter(), index: 0 }),
+      other => {
+        other.report("array");
+        visitor.visit_seq(LenientSeq { items: Vec::new().into_iter(), index: 0 })
+      }
+    }
+  }
+}
+
+impl<'de> Deserializer<'de> for Lenient {
+  type Error = Error;
+
+  fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(Value::Null) => visitor.visit_unit(),
+      Lenient::Value(Value::Bool(value)) => visitor.visit_bool(value),
+      Lenient::Value(Value::Number(number)) => {
+        if let Some(number) = number.as_u64() { return visitor.visit_u64(number) }
+        if let Some(number) = number.as_i64() { return visitor.visit_i64(number) }
+        visitor.visit_f64(number.as_f64().unwrap_or_default())
+      }
+      Lenient::Value(Value::String(value)) => visitor.visit_string(value),
+      Lenient::Value(Value::Array(_)) => self.seq(visitor),
+      Lenient::Value(Value::Object(map)) => visitor.visit_map(LenientMap::new(map, &[])),
+      other => {
+        other.report("value");
+        visitor.visit_unit()
+      }
+    }
+  }
+
+  fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(Value::Bool(value)) => visitor.visit_bool(value),
+      other => {
+        other.report("boolean");
+        visitor.visit_bool(false)
+      }
+    }
+  }
+
+  fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.integer(visitor) }
+  fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.float(visitor) }
+  fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.float(visitor) }
+
+  fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.deserialize_string(visitor) }
+  fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.deserialize_string(visitor) }
+
+  fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(Value::String(value)) => visitor.visit_string(value),
+      other => {
+        other.report("string");
+        visitor.visit_string(String::new())
+      }
+    }
+  }
+
+  fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.deserialize_any(visitor) }
+  fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.deserialize_any(visitor) }
+
+  fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(Value::Null) | Lenient::Missing | Lenient::Default => visitor.visit_none(),
+      value => visitor.visit_some(value),
+    }
+  }
+
+  fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { visitor.visit_unit() }
+
+  fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+    visitor.visit_unit()
+  }
+
+  fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+    visitor.visit_newtype_struct(self)
+  }
+
+  fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.seq(visitor) }
+
+  fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> { self.seq(visitor) }
+
+  fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, _len: usize, visitor: V) -> Result<V::Value, Error> {
+    self.seq(visitor)
+  }
+
+  fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(Value::Object(map)) => visitor.visit_map(LenientMap::new(map, &[])),
+      other => {
+        other.report("object");
+        visitor.visit_map(LenientMap::new(serde_json::Map::new(), &[]))
+      }
+    }
+  }
+
+  fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(Value::Object(map)) => visitor.visit_map(LenientMap::new(map, fields)),
+      other => {
+        // the fields of a defaulted object are defaulted too, without reporting each of them
+        other.report("object");
+        visitor.visit_map(LenientMap { missing: fields.iter().map(|field| (*field, Lenient::Default)).collect(), ..LenientMap::new(serde_json::Map::new(), &[]) })
+      }
+    }
+  }
+
+  fn deserialize_enum<V: Visitor<'de>>(self, name: &'static str, variants: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+    match self {
+      Lenient::Value(value) => value.deserialize_enum(name, variants, visitor),
+      _ => Err(de::Error::custom(format!("missing {}", name))),
+    }
+  }
+
+  fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { self.deserialize_string(visitor) }
+
+  fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> { visitor.visit_unit() }
+}
+
+struct LenientSeq {
+  items: std::vec::IntoIter<Value>,
+  index: usize,
+}
+
+impl<'de> SeqAccess<'de> for LenientSeq {
+  type Error = Error;
+
+  fn next_element_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, Error> {
+    let item = match self.items.next() {
+      Some(item) => item,
+      None => return Ok(None),
+    };
+    self.index += 1;
+    with_segment(format!("[{}]", self.index - 1), || seed.deserialize(Lenient::Value(item))).map(Some)
+  }
+}
+
+/// The entries of an object, followed by the struct fields missing from it
+struct LenientMap {
+  entries: serde_json::map::IntoIter,
+  missing: VecDeque<(&'static str, Lenient)>,
+  next: Option<(String, Lenient)>,
+}
+
+impl LenientMap {
+  fn new(map: serde_json::Map<String, Value>, fields: &'static [&'static str]) -> Self {
+    let missing = fields.iter()
+      .filter(|field| !map.contains_key(**field))
+      .map(|field| (*field, Lenient::Missing))
+      .collect();
+    Self { entries: map.into_iter(), missing, next: None }
+  }
+}
+
+impl<'de> MapAccess<'de> for LenientMap {
+  type Error = Error;
+
+  fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+    let (key, value) = match self.entries.next() {
+      Some((key, value)) => (key, Lenient::Value(value)),
+      None => match self.missing.pop_front() {
+        Some((field, value)) => (field.to_string(), value),
+        None => return Ok(None),
+      },
+    };
+    self.next = Some((key.clone(), value));
+    seed.deserialize(key.into_deserializer()).map(Some)
+  }
+
+  fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, Error> {
+    let (key, value) = self.next.take().ok_or_else(|| de::Error::custom("value requested before key"))?;
+    with_segment(key, || seed.deserialize(value))
+  }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+
+/// Checks a request against the limits tdlib puts on its values, like the length of a message
+/// text or the number of messages forwarded at once, so it can be fixed before sending it.
+///
+/// Implemented by every function, and by the types they contain having limits.
+pub trait Validate {
+  #[doc(hidden)]
+  fn check(&self, _checker: &mut Checker) {}
+
+  /// What tdlib would reject, nothing if the request is fine
+  fn violations(&self) -> Vec<Violation> {
+    let mut checker = Checker::default();
+    self.check(&mut checker);
+    checker.violations
+  }
+
+  fn validate(&self) -> Result<(), Vec<Violation>> {
+    let violations = self.violations();
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+  }
+}
+
+impl<T: Validate> Validate for Option<T> {
+  fn check(&self, checker: &mut Checker) {
+    if let Some(value) = self {
+      value.check(checker);
+    }
+  }
+}
+
+impl<T: Validate> Validate for Box<T> {
+  fn check(&self, checker: &mut Checker) { (**self).check(checker) }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+  fn check(&self, checker: &mut Checker) {
+    for (index, value) in self.iter().enumerate() {
+      checker.enter(&format!("[{}]", index), |checker| value.check(checker));
+    }
+  }
+}
+
+/// A value tdlib would reject
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+  /// Where the value is in the request, like `input_message_content.caption` or `commands[2].command`
+  pub path: String,
+  pub kind: ViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+  /// A string of `actual` characters where tdlib takes `min` to `max`
+  Length { min: usize, max: usize, actual: usize },
+  /// A vector of `actual` elements where tdlib takes `min` to `max`
+  Count { min: usize, max: usize, actual: usize },
+  /// A bot command that isn't 1-32 lowercase English letters, digits and underscores
+  BotCommand,
+}
+
+impl fmt::Display for Violation {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match &self.kind {
+      ViolationKind::Length { min, max, actual } => write!(f, "{}: {} characters, expected {}-{}", self.path, actual, min, max),
+      ViolationKind::Count { min, max, actual } => write!(f, "{}: {} elements, expected {}-{}", self.path, actual, min, max),
+      ViolationKind::BotCommand => write!(f, "{}: expected 1-32 lowercase English letters, digits and underscores", self.path),
+    }
+  }
+}
+
+/// Collects the violations of the fields of a request, used by the generated `Validate` impls
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct Checker {
+  path: String,
+  violations: Vec<Violation>,
+}
+
+impl Checker {
+  pub fn nested<T: Validate>(&mut self, field: &str, value: &T) {
+    let segment = if self.path.is_empty() { field.to_string() } else { format!(".{}", field) };
+    self.enter(&segment, |checker| value.check(checker));
+  }
+
+  pub fn length(&mut self, field: &str, value: &str, min: usize, max: usize) {
+    let actual = value.chars().count();
+    if actual < min || actual > max {
+      self.violate(field, ViolationKind::Length { min, max, actual });
+    }
+  }
+
+  pub fn each_length(&mut self, field: &str, values: &[String], min: usize, max: usize) {
+    for (index, value) in values.iter().enumerate() {
+      self.length(&format!("{}[{}]", field, index), value, min, max);
+    }
+  }
+
+  pub fn count(&mut self, field: &str, actual: usize, min: usize, max: usize) {
+    if actual < min || actual > max {
+      self.violate(field, ViolationKind::Count { min, max, actual });
+    }
+  }
+
+  pub fn bot_command(&mut self, field: &str, value: &str) {
+    let valid = (1..=32).contains(&value.len()) && value.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+      self.violate(field, ViolationKind::BotCommand);
+    }
+  }
+
+  fn enter<F: FnOnce(&mut Checker)>(&mut self, segment: &str, check: F) {
+    let len = self.path.len();
+    self.path.push_str(segment);
+    check(self);
+    self.path.truncate(len);
+  }
+
+  fn violate(&mut self, field: &str, kind: ViolationKind) {
+    let path = if self.path.is_empty() { field.to_string() } else { format!("{}.{}", self.path, field) };
+    self.violations.push(Violation { path, kind });
+  }
+}
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct AccountTtl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Number of days of inactivity before the account will be flagged for deletion; 30-366 days
   days: i64,
   
 }
 
 impl RObject for AccountTtl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "accountTtl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "accountTtl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl AccountTtl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAccountTtlBuilder {
-    let mut inner = AccountTtl::default();
-    inner.td_name = "accountTtl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AccountTtl { td_name: "accountTtl".to_string(), ..Default::default() };
     RTDAccountTtlBuilder { inner }
   }
 
+  /// `AccountTtl` with the fields tdlib requires, the others are set by `AccountTtl::builder()`
+  pub fn new(days: i64) -> Self { Self::builder().days(days).build() }
+
   pub fn days(&self) -> i64 { self.days }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDAccountTtlBuilder {
 impl RTDAccountTtlBuilder {
   pub fn build(&self) -> AccountTtl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn days(&mut self, days: i64) -> &mut Self {
     self.inner.days = days;
@@ -65,5 +75,11 @@ impl AsRef<AccountTtl> for RTDAccountTtlBuilder {
   fn as_ref(&self) -> &AccountTtl { &self.inner }
 }
 
+impl PartialEq for AccountTtl {
+  fn eq(&self, other: &Self) -> bool {
+    self.days == other.days
+  }
+}
+
 
 
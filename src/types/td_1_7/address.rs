@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Address {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A two-letter ISO 3166-1 alpha-2 country code
   country_code: String,
   /// State, if applicable
@@ -31,22 +33,40 @@ pub struct Address {
 }
 
 impl RObject for Address {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "address" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "address" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Address {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAddressBuilder {
-    let mut inner = Address::default();
-    inner.td_name = "address".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Address { td_name: "address".to_string(), ..Default::default() };
     RTDAddressBuilder { inner }
   }
 
+  /// `Address` with the fields tdlib requires, the others are set by `Address::builder()`
+  pub fn new(
+    country_code: impl AsRef<str>,
+    state: impl AsRef<str>,
+    city: impl AsRef<str>,
+    street_line1: impl AsRef<str>,
+    street_line2: impl AsRef<str>,
+    postal_code: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .country_code(country_code)
+      .state(state)
+      .city(city)
+      .street_line1(street_line1)
+      .street_line2(street_line2)
+      .postal_code(postal_code)
+      .build()
+  }
+
   pub fn country_code(&self) -> &String { &self.country_code }
 
   pub fn state(&self) -> &String { &self.state }
@@ -69,6 +89,12 @@ pub struct RTDAddressBuilder {
 impl RTDAddressBuilder {
   pub fn build(&self) -> Address { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
@@ -115,5 +141,16 @@ impl AsRef<Address> for RTDAddressBuilder {
   fn as_ref(&self) -> &Address { &self.inner }
 }
 
+impl PartialEq for Address {
+  fn eq(&self, other: &Self) -> bool {
+    self.country_code == other.country_code
+      && self.state == other.state
+      && self.city == other.city
+      && self.street_line1 == other.street_line1
+      && self.street_line2 == other.street_line2
+      && self.postal_code == other.postal_code
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct AnimatedChatPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Animation width and height
   length: i64,
   /// Information about the animation file
@@ -25,22 +27,24 @@ pub struct AnimatedChatPhoto {
 }
 
 impl RObject for AnimatedChatPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "animatedChatPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "animatedChatPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl AnimatedChatPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAnimatedChatPhotoBuilder {
-    let mut inner = AnimatedChatPhoto::default();
-    inner.td_name = "animatedChatPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AnimatedChatPhoto { td_name: "animatedChatPhoto".to_string(), ..Default::default() };
     RTDAnimatedChatPhotoBuilder { inner }
   }
 
+  /// `AnimatedChatPhoto` with the fields tdlib requires, the others are set by `AnimatedChatPhoto::builder()`
+  pub fn new(length: i64, file: impl AsRef<File>, main_frame_timestamp: f32) -> Self { Self::builder().length(length).file(file).main_frame_timestamp(main_frame_timestamp).build() }
+
   pub fn length(&self) -> i64 { self.length }
 
   pub fn file(&self) -> &File { &self.file }
@@ -57,6 +61,12 @@ pub struct RTDAnimatedChatPhotoBuilder {
 impl RTDAnimatedChatPhotoBuilder {
   pub fn build(&self) -> AnimatedChatPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
@@ -85,5 +95,13 @@ impl AsRef<AnimatedChatPhoto> for RTDAnimatedChatPhotoBuilder {
   fn as_ref(&self) -> &AnimatedChatPhoto { &self.inner }
 }
 
+impl PartialEq for AnimatedChatPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.length == other.length
+      && self.file == other.file
+      && self.main_frame_timestamp == other.main_frame_timestamp
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct AnimatedEmoji {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Animated sticker for the emoji
   sticker: Sticker,
   /// List of colors to be replaced while the sticker is rendered
@@ -25,22 +27,24 @@ pub struct AnimatedEmoji {
 }
 
 impl RObject for AnimatedEmoji {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "animatedEmoji" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "animatedEmoji" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl AnimatedEmoji {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAnimatedEmojiBuilder {
-    let mut inner = AnimatedEmoji::default();
-    inner.td_name = "animatedEmoji".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AnimatedEmoji { td_name: "animatedEmoji".to_string(), ..Default::default() };
     RTDAnimatedEmojiBuilder { inner }
   }
 
+  /// `AnimatedEmoji` with the fields tdlib requires, the others are set by `AnimatedEmoji::builder()`
+  pub fn new(sticker: impl AsRef<Sticker>, color_replacements: Vec<ColorReplacement>) -> Self { Self::builder().sticker(sticker).color_replacements(color_replacements).build() }
+
   pub fn sticker(&self) -> &Sticker { &self.sticker }
 
   pub fn color_replacements(&self) -> &Vec<ColorReplacement> { &self.color_replacements }
@@ -57,6 +61,12 @@ pub struct RTDAnimatedEmojiBuilder {
 impl RTDAnimatedEmojiBuilder {
   pub fn build(&self) -> AnimatedEmoji { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -85,5 +95,13 @@ impl AsRef<AnimatedEmoji> for RTDAnimatedEmojiBuilder {
   fn as_ref(&self) -> &AnimatedEmoji { &self.inner }
 }
 
+impl PartialEq for AnimatedEmoji {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker == other.sticker
+      && self.color_replacements == other.color_replacements
+      && self.sound == other.sound
+  }
+}
+
 
 
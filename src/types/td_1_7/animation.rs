@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Animation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Duration of the animation, in seconds; as defined by the sender
   duration: i64,
   /// Width of the animation
@@ -37,22 +39,42 @@ pub struct Animation {
 }
 
 impl RObject for Animation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "animation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "animation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Animation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAnimationBuilder {
-    let mut inner = Animation::default();
-    inner.td_name = "animation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Animation { td_name: "animation".to_string(), ..Default::default() };
     RTDAnimationBuilder { inner }
   }
 
+  /// `Animation` with the fields tdlib requires, the others are set by `Animation::builder()`
+  pub fn new(
+    duration: i64,
+    width: i64,
+    height: i64,
+    file_name: impl AsRef<str>,
+    mime_type: impl AsRef<str>,
+    has_stickers: bool,
+    animation: impl AsRef<File>,
+  ) -> Self {
+    Self::builder()
+      .duration(duration)
+      .width(width)
+      .height(height)
+      .file_name(file_name)
+      .mime_type(mime_type)
+      .has_stickers(has_stickers)
+      .animation(animation)
+      .build()
+  }
+
   pub fn duration(&self) -> i64 { self.duration }
 
   pub fn width(&self) -> i64 { self.width }
@@ -81,6 +103,12 @@ pub struct RTDAnimationBuilder {
 impl RTDAnimationBuilder {
   pub fn build(&self) -> Animation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -145,5 +173,19 @@ impl AsRef<Animation> for RTDAnimationBuilder {
   fn as_ref(&self) -> &Animation { &self.inner }
 }
 
+impl PartialEq for Animation {
+  fn eq(&self, other: &Self) -> bool {
+    self.duration == other.duration
+      && self.width == other.width
+      && self.height == other.height
+      && self.file_name == other.file_name
+      && self.mime_type == other.mime_type
+      && self.has_stickers == other.has_stickers
+      && self.minithumbnail == other.minithumbnail
+      && self.thumbnail == other.thumbnail
+      && self.animation == other.animation
+  }
+}
+
 
 
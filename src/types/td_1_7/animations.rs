@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Animations {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of animations
   animations: Vec<Animation>,
   
 }
 
 impl RObject for Animations {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "animations" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "animations" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Animations {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAnimationsBuilder {
-    let mut inner = Animations::default();
-    inner.td_name = "animations".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Animations { td_name: "animations".to_string(), ..Default::default() };
     RTDAnimationsBuilder { inner }
   }
 
+  /// `Animations` with the fields tdlib requires, the others are set by `Animations::builder()`
+  pub fn new(animations: Vec<Animation>) -> Self { Self::builder().animations(animations).build() }
+
   pub fn animations(&self) -> &Vec<Animation> { &self.animations }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDAnimationsBuilder {
 impl RTDAnimationsBuilder {
   pub fn build(&self) -> Animations { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animations(&mut self, animations: Vec<Animation>) -> &mut Self {
     self.inner.animations = animations;
@@ -65,5 +75,11 @@ impl AsRef<Animations> for RTDAnimationsBuilder {
   fn as_ref(&self) -> &Animations { &self.inner }
 }
 
+impl PartialEq for Animations {
+  fn eq(&self, other: &Self) -> bool {
+    self.animations == other.animations
+  }
+}
+
 
 
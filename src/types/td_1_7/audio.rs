@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Audio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Duration of the audio, in seconds; as defined by the sender
   duration: i64,
   /// Title of the audio; as defined by the sender
@@ -35,22 +37,40 @@ pub struct Audio {
 }
 
 impl RObject for Audio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "audio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "audio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Audio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAudioBuilder {
-    let mut inner = Audio::default();
-    inner.td_name = "audio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Audio { td_name: "audio".to_string(), ..Default::default() };
     RTDAudioBuilder { inner }
   }
 
+  /// `Audio` with the fields tdlib requires, the others are set by `Audio::builder()`
+  pub fn new(
+    duration: i64,
+    title: impl AsRef<str>,
+    performer: impl AsRef<str>,
+    file_name: impl AsRef<str>,
+    mime_type: impl AsRef<str>,
+    audio: impl AsRef<File>,
+  ) -> Self {
+    Self::builder()
+      .duration(duration)
+      .title(title)
+      .performer(performer)
+      .file_name(file_name)
+      .mime_type(mime_type)
+      .audio(audio)
+      .build()
+  }
+
   pub fn duration(&self) -> i64 { self.duration }
 
   pub fn title(&self) -> &String { &self.title }
@@ -77,6 +97,12 @@ pub struct RTDAudioBuilder {
 impl RTDAudioBuilder {
   pub fn build(&self) -> Audio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -135,5 +161,18 @@ impl AsRef<Audio> for RTDAudioBuilder {
   fn as_ref(&self) -> &Audio { &self.inner }
 }
 
+impl PartialEq for Audio {
+  fn eq(&self, other: &Self) -> bool {
+    self.duration == other.duration
+      && self.title == other.title
+      && self.performer == other.performer
+      && self.file_name == other.file_name
+      && self.mime_type == other.mime_type
+      && self.album_cover_minithumbnail == other.album_cover_minithumbnail
+      && self.album_cover_thumbnail == other.album_cover_thumbnail
+      && self.audio == other.audio
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct AuthenticationCodeInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A phone number that is being authenticated
   phone_number: String,
   /// The way the code was sent to the user
@@ -27,22 +29,24 @@ pub struct AuthenticationCodeInfo {
 }
 
 impl RObject for AuthenticationCodeInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authenticationCodeInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl AuthenticationCodeInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthenticationCodeInfoBuilder {
-    let mut inner = AuthenticationCodeInfo::default();
-    inner.td_name = "authenticationCodeInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthenticationCodeInfo { td_name: "authenticationCodeInfo".to_string(), ..Default::default() };
     RTDAuthenticationCodeInfoBuilder { inner }
   }
 
+  /// `AuthenticationCodeInfo` with the fields tdlib requires, the others are set by `AuthenticationCodeInfo::builder()`
+  pub fn new(phone_number: impl AsRef<str>, type_: impl AsRef<AuthenticationCodeType>, timeout: i64) -> Self { Self::builder().phone_number(phone_number).type_(type_).timeout(timeout).build() }
+
   pub fn phone_number(&self) -> &String { &self.phone_number }
 
   pub fn type_(&self) -> &AuthenticationCodeType { &self.type_ }
@@ -61,6 +65,12 @@ pub struct RTDAuthenticationCodeInfoBuilder {
 impl RTDAuthenticationCodeInfoBuilder {
   pub fn build(&self) -> AuthenticationCodeInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -95,5 +105,14 @@ impl AsRef<AuthenticationCodeInfo> for RTDAuthenticationCodeInfoBuilder {
   fn as_ref(&self) -> &AuthenticationCodeInfo { &self.inner }
 }
 
+impl PartialEq for AuthenticationCodeInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.phone_number == other.phone_number
+      && self.type_ == other.type_
+      && self.next_type == other.next_type
+      && self.timeout == other.timeout
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDAuthenticationCodeType: Debug + RObject {}
 
 /// Provides information about the method by which an authentication code is delivered to the user
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum AuthenticationCodeType {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum AuthenticationCodeType {
   Sms(AuthenticationCodeTypeSms),
   /// An authentication code is delivered via a private Telegram message, which can be viewed from another active session
   TelegramMessage(AuthenticationCodeTypeTelegramMessage),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for AuthenticationCodeType {
   fn deserialize<D>(deserializer: D) -> Result<AuthenticationCodeType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      AuthenticationCodeType,
+      AuthenticationCodeType, Unknown;
       (authenticationCodeTypeCall, Call);
       (authenticationCodeTypeFlashCall, FlashCall);
       (authenticationCodeTypeSms, Sms);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for AuthenticationCodeType {
 }
 
 impl RObject for AuthenticationCodeType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       AuthenticationCodeType::Call(t) => t.td_name(),
       AuthenticationCodeType::FlashCall(t) => t.td_name(),
       AuthenticationCodeType::Sms(t) => t.td_name(),
       AuthenticationCodeType::TelegramMessage(t) => t.td_name(),
+      AuthenticationCodeType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       AuthenticationCodeType::Call(t) => t.extra(),
       AuthenticationCodeType::FlashCall(t) => t.extra(),
       AuthenticationCodeType::Sms(t) => t.extra(),
       AuthenticationCodeType::TelegramMessage(t) => t.extra(),
+      AuthenticationCodeType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      AuthenticationCodeType::Call(t) => t.client_id(),
+      AuthenticationCodeType::FlashCall(t) => t.client_id(),
+      AuthenticationCodeType::Sms(t) => t.client_id(),
+      AuthenticationCodeType::TelegramMessage(t) => t.client_id(),
+      AuthenticationCodeType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for AuthenticationCodeType {
 }
 
 impl AuthenticationCodeType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let AuthenticationCodeType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, AuthenticationCodeType::_Default(_)) }
 
-  pub fn is_call(&self) -> bool { if let AuthenticationCodeType::Call(_) = self { true } else { false } }
-  pub fn is_flash_call(&self) -> bool { if let AuthenticationCodeType::FlashCall(_) = self { true } else { false } }
-  pub fn is_sms(&self) -> bool { if let AuthenticationCodeType::Sms(_) = self { true } else { false } }
-  pub fn is_telegram_message(&self) -> bool { if let AuthenticationCodeType::TelegramMessage(_) = self { true } else { false } }
+  pub fn is_call(&self) -> bool { matches!(self, AuthenticationCodeType::Call(_)) }
+  pub fn is_flash_call(&self) -> bool { matches!(self, AuthenticationCodeType::FlashCall(_)) }
+  pub fn is_sms(&self) -> bool { matches!(self, AuthenticationCodeType::Sms(_)) }
+  pub fn is_telegram_message(&self) -> bool { matches!(self, AuthenticationCodeType::TelegramMessage(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, AuthenticationCodeType::Unknown(_)) }
 
   pub fn on_call<F: FnOnce(&AuthenticationCodeTypeCall)>(&self, fnc: F) -> &Self { if let AuthenticationCodeType::Call(t) = self { fnc(t) }; self }
   pub fn on_flash_call<F: FnOnce(&AuthenticationCodeTypeFlashCall)>(&self, fnc: F) -> &Self { if let AuthenticationCodeType::FlashCall(t) = self { fnc(t) }; self }
   pub fn on_sms<F: FnOnce(&AuthenticationCodeTypeSms)>(&self, fnc: F) -> &Self { if let AuthenticationCodeType::Sms(t) = self { fnc(t) }; self }
   pub fn on_telegram_message<F: FnOnce(&AuthenticationCodeTypeTelegramMessage)>(&self, fnc: F) -> &Self { if let AuthenticationCodeType::TelegramMessage(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let AuthenticationCodeType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_call(&self) -> Option<&AuthenticationCodeTypeCall> { if let AuthenticationCodeType::Call(t) = self { return Some(t) } None }
   pub fn as_flash_call(&self) -> Option<&AuthenticationCodeTypeFlashCall> { if let AuthenticationCodeType::FlashCall(t) = self { return Some(t) } None }
   pub fn as_sms(&self) -> Option<&AuthenticationCodeTypeSms> { if let AuthenticationCodeType::Sms(t) = self { return Some(t) } None }
   pub fn as_telegram_message(&self) -> Option<&AuthenticationCodeTypeTelegramMessage> { if let AuthenticationCodeType::TelegramMessage(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let AuthenticationCodeType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,15 +138,19 @@ pub struct AuthenticationCodeTypeCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Length of the code
   length: i64,
   
 }
 
 impl RObject for AuthenticationCodeTypeCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authenticationCodeTypeCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,14 +160,15 @@ impl TDAuthenticationCodeType for AuthenticationCodeTypeCall {}
 
 
 impl AuthenticationCodeTypeCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthenticationCodeTypeCallBuilder {
-    let mut inner = AuthenticationCodeTypeCall::default();
-    inner.td_name = "authenticationCodeTypeCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthenticationCodeTypeCall { td_name: "authenticationCodeTypeCall".to_string(), ..Default::default() };
     RTDAuthenticationCodeTypeCallBuilder { inner }
   }
 
+  /// `AuthenticationCodeTypeCall` with the fields tdlib requires, the others are set by `AuthenticationCodeTypeCall::builder()`
+  pub fn new(length: i64) -> Self { Self::builder().length(length).build() }
+
   pub fn length(&self) -> i64 { self.length }
 
 }
@@ -159,6 +181,12 @@ pub struct RTDAuthenticationCodeTypeCallBuilder {
 impl RTDAuthenticationCodeTypeCallBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
@@ -175,6 +203,12 @@ impl AsRef<AuthenticationCodeTypeCall> for RTDAuthenticationCodeTypeCallBuilder
   fn as_ref(&self) -> &AuthenticationCodeTypeCall { &self.inner }
 }
 
+impl PartialEq for AuthenticationCodeTypeCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.length == other.length
+  }
+}
+
 
 
 
@@ -189,15 +223,19 @@ pub struct AuthenticationCodeTypeFlashCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Pattern of the phone number from which the call will be made
   pattern: String,
   
 }
 
 impl RObject for AuthenticationCodeTypeFlashCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeFlashCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authenticationCodeTypeFlashCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,14 +245,15 @@ impl TDAuthenticationCodeType for AuthenticationCodeTypeFlashCall {}
 
 
 impl AuthenticationCodeTypeFlashCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthenticationCodeTypeFlashCallBuilder {
-    let mut inner = AuthenticationCodeTypeFlashCall::default();
-    inner.td_name = "authenticationCodeTypeFlashCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthenticationCodeTypeFlashCall { td_name: "authenticationCodeTypeFlashCall".to_string(), ..Default::default() };
     RTDAuthenticationCodeTypeFlashCallBuilder { inner }
   }
 
+  /// `AuthenticationCodeTypeFlashCall` with the fields tdlib requires, the others are set by `AuthenticationCodeTypeFlashCall::builder()`
+  pub fn new(pattern: impl AsRef<str>) -> Self { Self::builder().pattern(pattern).build() }
+
   pub fn pattern(&self) -> &String { &self.pattern }
 
 }
@@ -227,6 +266,12 @@ pub struct RTDAuthenticationCodeTypeFlashCallBuilder {
 impl RTDAuthenticationCodeTypeFlashCallBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeFlashCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn pattern<T: AsRef<str>>(&mut self, pattern: T) -> &mut Self {
     self.inner.pattern = pattern.as_ref().to_string();
@@ -243,6 +288,12 @@ impl AsRef<AuthenticationCodeTypeFlashCall> for RTDAuthenticationCodeTypeFlashCa
   fn as_ref(&self) -> &AuthenticationCodeTypeFlashCall { &self.inner }
 }
 
+impl PartialEq for AuthenticationCodeTypeFlashCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.pattern == other.pattern
+  }
+}
+
 
 
 
@@ -257,15 +308,19 @@ pub struct AuthenticationCodeTypeSms {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Length of the code
   length: i64,
   
 }
 
 impl RObject for AuthenticationCodeTypeSms {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeSms" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authenticationCodeTypeSms" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -275,14 +330,15 @@ impl TDAuthenticationCodeType for AuthenticationCodeTypeSms {}
 
 
 impl AuthenticationCodeTypeSms {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthenticationCodeTypeSmsBuilder {
-    let mut inner = AuthenticationCodeTypeSms::default();
-    inner.td_name = "authenticationCodeTypeSms".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthenticationCodeTypeSms { td_name: "authenticationCodeTypeSms".to_string(), ..Default::default() };
     RTDAuthenticationCodeTypeSmsBuilder { inner }
   }
 
+  /// `AuthenticationCodeTypeSms` with the fields tdlib requires, the others are set by `AuthenticationCodeTypeSms::builder()`
+  pub fn new(length: i64) -> Self { Self::builder().length(length).build() }
+
   pub fn length(&self) -> i64 { self.length }
 
 }
@@ -295,6 +351,12 @@ pub struct RTDAuthenticationCodeTypeSmsBuilder {
 impl RTDAuthenticationCodeTypeSmsBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeSms { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
@@ -311,6 +373,12 @@ impl AsRef<AuthenticationCodeTypeSms> for RTDAuthenticationCodeTypeSmsBuilder {
   fn as_ref(&self) -> &AuthenticationCodeTypeSms { &self.inner }
 }
 
+impl PartialEq for AuthenticationCodeTypeSms {
+  fn eq(&self, other: &Self) -> bool {
+    self.length == other.length
+  }
+}
+
 
 
 
@@ -325,15 +393,19 @@ pub struct AuthenticationCodeTypeTelegramMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Length of the code
   length: i64,
   
 }
 
 impl RObject for AuthenticationCodeTypeTelegramMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authenticationCodeTypeTelegramMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authenticationCodeTypeTelegramMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -343,14 +415,15 @@ impl TDAuthenticationCodeType for AuthenticationCodeTypeTelegramMessage {}
 
 
 impl AuthenticationCodeTypeTelegramMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthenticationCodeTypeTelegramMessageBuilder {
-    let mut inner = AuthenticationCodeTypeTelegramMessage::default();
-    inner.td_name = "authenticationCodeTypeTelegramMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthenticationCodeTypeTelegramMessage { td_name: "authenticationCodeTypeTelegramMessage".to_string(), ..Default::default() };
     RTDAuthenticationCodeTypeTelegramMessageBuilder { inner }
   }
 
+  /// `AuthenticationCodeTypeTelegramMessage` with the fields tdlib requires, the others are set by `AuthenticationCodeTypeTelegramMessage::builder()`
+  pub fn new(length: i64) -> Self { Self::builder().length(length).build() }
+
   pub fn length(&self) -> i64 { self.length }
 
 }
@@ -363,6 +436,12 @@ pub struct RTDAuthenticationCodeTypeTelegramMessageBuilder {
 impl RTDAuthenticationCodeTypeTelegramMessageBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeTelegramMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
@@ -379,5 +458,11 @@ impl AsRef<AuthenticationCodeTypeTelegramMessage> for RTDAuthenticationCodeTypeT
   fn as_ref(&self) -> &AuthenticationCodeTypeTelegramMessage { &self.inner }
 }
 
+impl PartialEq for AuthenticationCodeTypeTelegramMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.length == other.length
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDAuthorizationState: Debug + RObject {}
 
 /// Represents the current authorization state of the TDLib client
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum AuthorizationState {
   #[doc(hidden)] _Default(()),
@@ -43,6 +42,8 @@ pub enum AuthorizationState {
   WaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
   /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
   GetAuthorizationState(GetAuthorizationState),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -54,7 +55,7 @@ impl<'de> Deserialize<'de> for AuthorizationState {
   fn deserialize<D>(deserializer: D) -> Result<AuthorizationState, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      AuthorizationState,
+      AuthorizationState, Unknown;
       (authorizationStateClosed, Closed);
       (authorizationStateClosing, Closing);
       (authorizationStateLoggingOut, LoggingOut);
@@ -73,7 +74,7 @@ impl<'de> Deserialize<'de> for AuthorizationState {
 }
 
 impl RObject for AuthorizationState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       AuthorizationState::Closed(t) => t.td_name(),
       AuthorizationState::Closing(t) => t.td_name(),
@@ -87,11 +88,12 @@ impl RObject for AuthorizationState {
       AuthorizationState::WaitRegistration(t) => t.td_name(),
       AuthorizationState::WaitTdlibParameters(t) => t.td_name(),
       AuthorizationState::GetAuthorizationState(t) => t.td_name(),
+      AuthorizationState::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       AuthorizationState::Closed(t) => t.extra(),
       AuthorizationState::Closing(t) => t.extra(),
@@ -105,6 +107,26 @@ impl RObject for AuthorizationState {
       AuthorizationState::WaitRegistration(t) => t.extra(),
       AuthorizationState::WaitTdlibParameters(t) => t.extra(),
       AuthorizationState::GetAuthorizationState(t) => t.extra(),
+      AuthorizationState::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      AuthorizationState::Closed(t) => t.client_id(),
+      AuthorizationState::Closing(t) => t.client_id(),
+      AuthorizationState::LoggingOut(t) => t.client_id(),
+      AuthorizationState::Ready(t) => t.client_id(),
+      AuthorizationState::WaitCode(t) => t.client_id(),
+      AuthorizationState::WaitEncryptionKey(t) => t.client_id(),
+      AuthorizationState::WaitOtherDeviceConfirmation(t) => t.client_id(),
+      AuthorizationState::WaitPassword(t) => t.client_id(),
+      AuthorizationState::WaitPhoneNumber(t) => t.client_id(),
+      AuthorizationState::WaitRegistration(t) => t.client_id(),
+      AuthorizationState::WaitTdlibParameters(t) => t.client_id(),
+      AuthorizationState::GetAuthorizationState(t) => t.client_id(),
+      AuthorizationState::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -113,21 +135,22 @@ impl RObject for AuthorizationState {
 }
 
 impl AuthorizationState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let AuthorizationState::_Default(_) = self { true } else { false } }
-
-  pub fn is_closed(&self) -> bool { if let AuthorizationState::Closed(_) = self { true } else { false } }
-  pub fn is_closing(&self) -> bool { if let AuthorizationState::Closing(_) = self { true } else { false } }
-  pub fn is_logging_out(&self) -> bool { if let AuthorizationState::LoggingOut(_) = self { true } else { false } }
-  pub fn is_ready(&self) -> bool { if let AuthorizationState::Ready(_) = self { true } else { false } }
-  pub fn is_wait_code(&self) -> bool { if let AuthorizationState::WaitCode(_) = self { true } else { false } }
-  pub fn is_wait_encryption_key(&self) -> bool { if let AuthorizationState::WaitEncryptionKey(_) = self { true } else { false } }
-  pub fn is_wait_other_device_confirmation(&self) -> bool { if let AuthorizationState::WaitOtherDeviceConfirmation(_) = self { true } else { false } }
-  pub fn is_wait_password(&self) -> bool { if let AuthorizationState::WaitPassword(_) = self { true } else { false } }
-  pub fn is_wait_phone_number(&self) -> bool { if let AuthorizationState::WaitPhoneNumber(_) = self { true } else { false } }
-  pub fn is_wait_registration(&self) -> bool { if let AuthorizationState::WaitRegistration(_) = self { true } else { false } }
-  pub fn is_wait_tdlib_parameters(&self) -> bool { if let AuthorizationState::WaitTdlibParameters(_) = self { true } else { false } }
-  pub fn is_get_authorization_state(&self) -> bool { if let AuthorizationState::GetAuthorizationState(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, AuthorizationState::_Default(_)) }
+
+  pub fn is_closed(&self) -> bool { matches!(self, AuthorizationState::Closed(_)) }
+  pub fn is_closing(&self) -> bool { matches!(self, AuthorizationState::Closing(_)) }
+  pub fn is_logging_out(&self) -> bool { matches!(self, AuthorizationState::LoggingOut(_)) }
+  pub fn is_ready(&self) -> bool { matches!(self, AuthorizationState::Ready(_)) }
+  pub fn is_wait_code(&self) -> bool { matches!(self, AuthorizationState::WaitCode(_)) }
+  pub fn is_wait_encryption_key(&self) -> bool { matches!(self, AuthorizationState::WaitEncryptionKey(_)) }
+  pub fn is_wait_other_device_confirmation(&self) -> bool { matches!(self, AuthorizationState::WaitOtherDeviceConfirmation(_)) }
+  pub fn is_wait_password(&self) -> bool { matches!(self, AuthorizationState::WaitPassword(_)) }
+  pub fn is_wait_phone_number(&self) -> bool { matches!(self, AuthorizationState::WaitPhoneNumber(_)) }
+  pub fn is_wait_registration(&self) -> bool { matches!(self, AuthorizationState::WaitRegistration(_)) }
+  pub fn is_wait_tdlib_parameters(&self) -> bool { matches!(self, AuthorizationState::WaitTdlibParameters(_)) }
+  pub fn is_get_authorization_state(&self) -> bool { matches!(self, AuthorizationState::GetAuthorizationState(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, AuthorizationState::Unknown(_)) }
 
   pub fn on_closed<F: FnOnce(&AuthorizationStateClosed)>(&self, fnc: F) -> &Self { if let AuthorizationState::Closed(t) = self { fnc(t) }; self }
   pub fn on_closing<F: FnOnce(&AuthorizationStateClosing)>(&self, fnc: F) -> &Self { if let AuthorizationState::Closing(t) = self { fnc(t) }; self }
@@ -141,6 +164,7 @@ impl AuthorizationState {
   pub fn on_wait_registration<F: FnOnce(&AuthorizationStateWaitRegistration)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitRegistration(t) = self { fnc(t) }; self }
   pub fn on_wait_tdlib_parameters<F: FnOnce(&AuthorizationStateWaitTdlibParameters)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitTdlibParameters(t) = self { fnc(t) }; self }
   pub fn on_get_authorization_state<F: FnOnce(&GetAuthorizationState)>(&self, fnc: F) -> &Self { if let AuthorizationState::GetAuthorizationState(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let AuthorizationState::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_closed(&self) -> Option<&AuthorizationStateClosed> { if let AuthorizationState::Closed(t) = self { return Some(t) } None }
   pub fn as_closing(&self) -> Option<&AuthorizationStateClosing> { if let AuthorizationState::Closing(t) = self { return Some(t) } None }
@@ -154,6 +178,7 @@ impl AuthorizationState {
   pub fn as_wait_registration(&self) -> Option<&AuthorizationStateWaitRegistration> { if let AuthorizationState::WaitRegistration(t) = self { return Some(t) } None }
   pub fn as_wait_tdlib_parameters(&self) -> Option<&AuthorizationStateWaitTdlibParameters> { if let AuthorizationState::WaitTdlibParameters(t) = self { return Some(t) } None }
   pub fn as_get_authorization_state(&self) -> Option<&GetAuthorizationState> { if let AuthorizationState::GetAuthorizationState(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let AuthorizationState::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -201,13 +226,17 @@ pub struct AuthorizationStateClosed {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for AuthorizationStateClosed {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateClosed" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateClosed" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -217,14 +246,15 @@ impl TDAuthorizationState for AuthorizationStateClosed {}
 
 
 impl AuthorizationStateClosed {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateClosedBuilder {
-    let mut inner = AuthorizationStateClosed::default();
-    inner.td_name = "authorizationStateClosed".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateClosed { td_name: "authorizationStateClosed".to_string(), ..Default::default() };
     RTDAuthorizationStateClosedBuilder { inner }
   }
 
+  /// `AuthorizationStateClosed` with the fields tdlib requires, the others are set by `AuthorizationStateClosed::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -235,6 +265,12 @@ pub struct RTDAuthorizationStateClosedBuilder {
 impl RTDAuthorizationStateClosedBuilder {
   pub fn build(&self) -> AuthorizationStateClosed { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateClosed> for AuthorizationStateClosed {
@@ -245,6 +281,10 @@ impl AsRef<AuthorizationStateClosed> for RTDAuthorizationStateClosedBuilder {
   fn as_ref(&self) -> &AuthorizationStateClosed { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateClosed {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -259,13 +299,17 @@ pub struct AuthorizationStateClosing {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for AuthorizationStateClosing {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateClosing" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateClosing" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -275,14 +319,15 @@ impl TDAuthorizationState for AuthorizationStateClosing {}
 
 
 impl AuthorizationStateClosing {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateClosingBuilder {
-    let mut inner = AuthorizationStateClosing::default();
-    inner.td_name = "authorizationStateClosing".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateClosing { td_name: "authorizationStateClosing".to_string(), ..Default::default() };
     RTDAuthorizationStateClosingBuilder { inner }
   }
 
+  /// `AuthorizationStateClosing` with the fields tdlib requires, the others are set by `AuthorizationStateClosing::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -293,6 +338,12 @@ pub struct RTDAuthorizationStateClosingBuilder {
 impl RTDAuthorizationStateClosingBuilder {
   pub fn build(&self) -> AuthorizationStateClosing { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateClosing> for AuthorizationStateClosing {
@@ -303,6 +354,10 @@ impl AsRef<AuthorizationStateClosing> for RTDAuthorizationStateClosingBuilder {
   fn as_ref(&self) -> &AuthorizationStateClosing { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateClosing {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -317,13 +372,17 @@ pub struct AuthorizationStateLoggingOut {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for AuthorizationStateLoggingOut {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateLoggingOut" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateLoggingOut" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -333,14 +392,15 @@ impl TDAuthorizationState for AuthorizationStateLoggingOut {}
 
 
 impl AuthorizationStateLoggingOut {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateLoggingOutBuilder {
-    let mut inner = AuthorizationStateLoggingOut::default();
-    inner.td_name = "authorizationStateLoggingOut".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateLoggingOut { td_name: "authorizationStateLoggingOut".to_string(), ..Default::default() };
     RTDAuthorizationStateLoggingOutBuilder { inner }
   }
 
+  /// `AuthorizationStateLoggingOut` with the fields tdlib requires, the others are set by `AuthorizationStateLoggingOut::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -351,6 +411,12 @@ pub struct RTDAuthorizationStateLoggingOutBuilder {
 impl RTDAuthorizationStateLoggingOutBuilder {
   pub fn build(&self) -> AuthorizationStateLoggingOut { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateLoggingOut> for AuthorizationStateLoggingOut {
@@ -361,6 +427,10 @@ impl AsRef<AuthorizationStateLoggingOut> for RTDAuthorizationStateLoggingOutBuil
   fn as_ref(&self) -> &AuthorizationStateLoggingOut { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateLoggingOut {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -375,13 +445,17 @@ pub struct AuthorizationStateReady {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for AuthorizationStateReady {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateReady" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateReady" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -391,14 +465,15 @@ impl TDAuthorizationState for AuthorizationStateReady {}
 
 
 impl AuthorizationStateReady {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateReadyBuilder {
-    let mut inner = AuthorizationStateReady::default();
-    inner.td_name = "authorizationStateReady".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateReady { td_name: "authorizationStateReady".to_string(), ..Default::default() };
     RTDAuthorizationStateReadyBuilder { inner }
   }
 
+  /// `AuthorizationStateReady` with the fields tdlib requires, the others are set by `AuthorizationStateReady::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -409,6 +484,12 @@ pub struct RTDAuthorizationStateReadyBuilder {
 impl RTDAuthorizationStateReadyBuilder {
   pub fn build(&self) -> AuthorizationStateReady { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateReady> for AuthorizationStateReady {
@@ -419,6 +500,10 @@ impl AsRef<AuthorizationStateReady> for RTDAuthorizationStateReadyBuilder {
   fn as_ref(&self) -> &AuthorizationStateReady { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateReady {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -433,15 +518,19 @@ pub struct AuthorizationStateWaitCode {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Information about the authorization code that was sent
   code_info: AuthenticationCodeInfo,
   
 }
 
 impl RObject for AuthorizationStateWaitCode {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitCode" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateWaitCode" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -451,14 +540,15 @@ impl TDAuthorizationState for AuthorizationStateWaitCode {}
 
 
 impl AuthorizationStateWaitCode {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateWaitCodeBuilder {
-    let mut inner = AuthorizationStateWaitCode::default();
-    inner.td_name = "authorizationStateWaitCode".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateWaitCode { td_name: "authorizationStateWaitCode".to_string(), ..Default::default() };
     RTDAuthorizationStateWaitCodeBuilder { inner }
   }
 
+  /// `AuthorizationStateWaitCode` with the fields tdlib requires, the others are set by `AuthorizationStateWaitCode::builder()`
+  pub fn new(code_info: impl AsRef<AuthenticationCodeInfo>) -> Self { Self::builder().code_info(code_info).build() }
+
   pub fn code_info(&self) -> &AuthenticationCodeInfo { &self.code_info }
 
 }
@@ -471,6 +561,12 @@ pub struct RTDAuthorizationStateWaitCodeBuilder {
 impl RTDAuthorizationStateWaitCodeBuilder {
   pub fn build(&self) -> AuthorizationStateWaitCode { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code_info<T: AsRef<AuthenticationCodeInfo>>(&mut self, code_info: T) -> &mut Self {
     self.inner.code_info = code_info.as_ref().clone();
@@ -487,6 +583,12 @@ impl AsRef<AuthorizationStateWaitCode> for RTDAuthorizationStateWaitCodeBuilder
   fn as_ref(&self) -> &AuthorizationStateWaitCode { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateWaitCode {
+  fn eq(&self, other: &Self) -> bool {
+    self.code_info == other.code_info
+  }
+}
+
 
 
 
@@ -501,15 +603,19 @@ pub struct AuthorizationStateWaitEncryptionKey {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the database is currently encrypted
   is_encrypted: bool,
   
 }
 
 impl RObject for AuthorizationStateWaitEncryptionKey {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitEncryptionKey" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateWaitEncryptionKey" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -519,14 +625,15 @@ impl TDAuthorizationState for AuthorizationStateWaitEncryptionKey {}
 
 
 impl AuthorizationStateWaitEncryptionKey {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateWaitEncryptionKeyBuilder {
-    let mut inner = AuthorizationStateWaitEncryptionKey::default();
-    inner.td_name = "authorizationStateWaitEncryptionKey".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateWaitEncryptionKey { td_name: "authorizationStateWaitEncryptionKey".to_string(), ..Default::default() };
     RTDAuthorizationStateWaitEncryptionKeyBuilder { inner }
   }
 
+  /// `AuthorizationStateWaitEncryptionKey` with the fields tdlib requires, the others are set by `AuthorizationStateWaitEncryptionKey::builder()`
+  pub fn new(is_encrypted: bool) -> Self { Self::builder().is_encrypted(is_encrypted).build() }
+
   pub fn is_encrypted(&self) -> bool { self.is_encrypted }
 
 }
@@ -539,6 +646,12 @@ pub struct RTDAuthorizationStateWaitEncryptionKeyBuilder {
 impl RTDAuthorizationStateWaitEncryptionKeyBuilder {
   pub fn build(&self) -> AuthorizationStateWaitEncryptionKey { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_encrypted(&mut self, is_encrypted: bool) -> &mut Self {
     self.inner.is_encrypted = is_encrypted;
@@ -555,6 +668,12 @@ impl AsRef<AuthorizationStateWaitEncryptionKey> for RTDAuthorizationStateWaitEnc
   fn as_ref(&self) -> &AuthorizationStateWaitEncryptionKey { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateWaitEncryptionKey {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_encrypted == other.is_encrypted
+  }
+}
+
 
 
 
@@ -569,15 +688,19 @@ pub struct AuthorizationStateWaitOtherDeviceConfirmation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A tg:// URL for the QR code. The link will be updated frequently
   link: String,
   
 }
 
 impl RObject for AuthorizationStateWaitOtherDeviceConfirmation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitOtherDeviceConfirmation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateWaitOtherDeviceConfirmation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -587,14 +710,15 @@ impl TDAuthorizationState for AuthorizationStateWaitOtherDeviceConfirmation {}
 
 
 impl AuthorizationStateWaitOtherDeviceConfirmation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
-    let mut inner = AuthorizationStateWaitOtherDeviceConfirmation::default();
-    inner.td_name = "authorizationStateWaitOtherDeviceConfirmation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateWaitOtherDeviceConfirmation { td_name: "authorizationStateWaitOtherDeviceConfirmation".to_string(), ..Default::default() };
     RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder { inner }
   }
 
+  /// `AuthorizationStateWaitOtherDeviceConfirmation` with the fields tdlib requires, the others are set by `AuthorizationStateWaitOtherDeviceConfirmation::builder()`
+  pub fn new(link: impl AsRef<str>) -> Self { Self::builder().link(link).build() }
+
   pub fn link(&self) -> &String { &self.link }
 
 }
@@ -607,6 +731,12 @@ pub struct RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
 impl RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
   pub fn build(&self) -> AuthorizationStateWaitOtherDeviceConfirmation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
@@ -623,6 +753,12 @@ impl AsRef<AuthorizationStateWaitOtherDeviceConfirmation> for RTDAuthorizationSt
   fn as_ref(&self) -> &AuthorizationStateWaitOtherDeviceConfirmation { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateWaitOtherDeviceConfirmation {
+  fn eq(&self, other: &Self) -> bool {
+    self.link == other.link
+  }
+}
+
 
 
 
@@ -637,7 +773,10 @@ pub struct AuthorizationStateWaitPassword {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Hint for the password; may be empty
   password_hint: String,
   /// True, if a recovery email address has been set up
@@ -648,8 +787,9 @@ pub struct AuthorizationStateWaitPassword {
 }
 
 impl RObject for AuthorizationStateWaitPassword {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitPassword" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateWaitPassword" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -659,14 +799,15 @@ impl TDAuthorizationState for AuthorizationStateWaitPassword {}
 
 
 impl AuthorizationStateWaitPassword {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateWaitPasswordBuilder {
-    let mut inner = AuthorizationStateWaitPassword::default();
-    inner.td_name = "authorizationStateWaitPassword".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateWaitPassword { td_name: "authorizationStateWaitPassword".to_string(), ..Default::default() };
     RTDAuthorizationStateWaitPasswordBuilder { inner }
   }
 
+  /// `AuthorizationStateWaitPassword` with the fields tdlib requires, the others are set by `AuthorizationStateWaitPassword::builder()`
+  pub fn new(password_hint: impl AsRef<str>, has_recovery_email_address: bool, recovery_email_address_pattern: impl AsRef<str>) -> Self { Self::builder().password_hint(password_hint).has_recovery_email_address(has_recovery_email_address).recovery_email_address_pattern(recovery_email_address_pattern).build() }
+
   pub fn password_hint(&self) -> &String { &self.password_hint }
 
   pub fn has_recovery_email_address(&self) -> bool { self.has_recovery_email_address }
@@ -683,6 +824,12 @@ pub struct RTDAuthorizationStateWaitPasswordBuilder {
 impl RTDAuthorizationStateWaitPasswordBuilder {
   pub fn build(&self) -> AuthorizationStateWaitPassword { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn password_hint<T: AsRef<str>>(&mut self, password_hint: T) -> &mut Self {
     self.inner.password_hint = password_hint.as_ref().to_string();
@@ -711,6 +858,14 @@ impl AsRef<AuthorizationStateWaitPassword> for RTDAuthorizationStateWaitPassword
   fn as_ref(&self) -> &AuthorizationStateWaitPassword { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateWaitPassword {
+  fn eq(&self, other: &Self) -> bool {
+    self.password_hint == other.password_hint
+      && self.has_recovery_email_address == other.has_recovery_email_address
+      && self.recovery_email_address_pattern == other.recovery_email_address_pattern
+  }
+}
+
 
 
 
@@ -725,13 +880,17 @@ pub struct AuthorizationStateWaitPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for AuthorizationStateWaitPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateWaitPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -741,14 +900,15 @@ impl TDAuthorizationState for AuthorizationStateWaitPhoneNumber {}
 
 
 impl AuthorizationStateWaitPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateWaitPhoneNumberBuilder {
-    let mut inner = AuthorizationStateWaitPhoneNumber::default();
-    inner.td_name = "authorizationStateWaitPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateWaitPhoneNumber { td_name: "authorizationStateWaitPhoneNumber".to_string(), ..Default::default() };
     RTDAuthorizationStateWaitPhoneNumberBuilder { inner }
   }
 
+  /// `AuthorizationStateWaitPhoneNumber` with the fields tdlib requires, the others are set by `AuthorizationStateWaitPhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -759,6 +919,12 @@ pub struct RTDAuthorizationStateWaitPhoneNumberBuilder {
 impl RTDAuthorizationStateWaitPhoneNumberBuilder {
   pub fn build(&self) -> AuthorizationStateWaitPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateWaitPhoneNumber> for AuthorizationStateWaitPhoneNumber {
@@ -769,6 +935,10 @@ impl AsRef<AuthorizationStateWaitPhoneNumber> for RTDAuthorizationStateWaitPhone
   fn as_ref(&self) -> &AuthorizationStateWaitPhoneNumber { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateWaitPhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -783,15 +953,19 @@ pub struct AuthorizationStateWaitRegistration {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Telegram terms of service
   terms_of_service: TermsOfService,
   
 }
 
 impl RObject for AuthorizationStateWaitRegistration {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitRegistration" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateWaitRegistration" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -801,14 +975,15 @@ impl TDAuthorizationState for AuthorizationStateWaitRegistration {}
 
 
 impl AuthorizationStateWaitRegistration {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateWaitRegistrationBuilder {
-    let mut inner = AuthorizationStateWaitRegistration::default();
-    inner.td_name = "authorizationStateWaitRegistration".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateWaitRegistration { td_name: "authorizationStateWaitRegistration".to_string(), ..Default::default() };
     RTDAuthorizationStateWaitRegistrationBuilder { inner }
   }
 
+  /// `AuthorizationStateWaitRegistration` with the fields tdlib requires, the others are set by `AuthorizationStateWaitRegistration::builder()`
+  pub fn new(terms_of_service: impl AsRef<TermsOfService>) -> Self { Self::builder().terms_of_service(terms_of_service).build() }
+
   pub fn terms_of_service(&self) -> &TermsOfService { &self.terms_of_service }
 
 }
@@ -821,6 +996,12 @@ pub struct RTDAuthorizationStateWaitRegistrationBuilder {
 impl RTDAuthorizationStateWaitRegistrationBuilder {
   pub fn build(&self) -> AuthorizationStateWaitRegistration { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn terms_of_service<T: AsRef<TermsOfService>>(&mut self, terms_of_service: T) -> &mut Self {
     self.inner.terms_of_service = terms_of_service.as_ref().clone();
@@ -837,6 +1018,12 @@ impl AsRef<AuthorizationStateWaitRegistration> for RTDAuthorizationStateWaitRegi
   fn as_ref(&self) -> &AuthorizationStateWaitRegistration { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateWaitRegistration {
+  fn eq(&self, other: &Self) -> bool {
+    self.terms_of_service == other.terms_of_service
+  }
+}
+
 
 
 
@@ -851,13 +1038,17 @@ pub struct AuthorizationStateWaitTdlibParameters {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for AuthorizationStateWaitTdlibParameters {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "authorizationStateWaitTdlibParameters" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "authorizationStateWaitTdlibParameters" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -867,14 +1058,15 @@ impl TDAuthorizationState for AuthorizationStateWaitTdlibParameters {}
 
 
 impl AuthorizationStateWaitTdlibParameters {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAuthorizationStateWaitTdlibParametersBuilder {
-    let mut inner = AuthorizationStateWaitTdlibParameters::default();
-    inner.td_name = "authorizationStateWaitTdlibParameters".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AuthorizationStateWaitTdlibParameters { td_name: "authorizationStateWaitTdlibParameters".to_string(), ..Default::default() };
     RTDAuthorizationStateWaitTdlibParametersBuilder { inner }
   }
 
+  /// `AuthorizationStateWaitTdlibParameters` with the fields tdlib requires, the others are set by `AuthorizationStateWaitTdlibParameters::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -885,6 +1077,12 @@ pub struct RTDAuthorizationStateWaitTdlibParametersBuilder {
 impl RTDAuthorizationStateWaitTdlibParametersBuilder {
   pub fn build(&self) -> AuthorizationStateWaitTdlibParameters { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<AuthorizationStateWaitTdlibParameters> for AuthorizationStateWaitTdlibParameters {
@@ -895,5 +1093,9 @@ impl AsRef<AuthorizationStateWaitTdlibParameters> for RTDAuthorizationStateWaitT
   fn as_ref(&self) -> &AuthorizationStateWaitTdlibParameters { &self.inner }
 }
 
+impl PartialEq for AuthorizationStateWaitTdlibParameters {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
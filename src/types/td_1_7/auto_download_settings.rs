@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct AutoDownloadSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the auto-download is enabled
   is_auto_download_enabled: bool,
   /// The maximum size of a photo file to be auto-downloaded, in bytes
@@ -35,22 +37,45 @@ pub struct AutoDownloadSettings {
 }
 
 impl RObject for AutoDownloadSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "autoDownloadSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "autoDownloadSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl AutoDownloadSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAutoDownloadSettingsBuilder {
-    let mut inner = AutoDownloadSettings::default();
-    inner.td_name = "autoDownloadSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AutoDownloadSettings { td_name: "autoDownloadSettings".to_string(), ..Default::default() };
     RTDAutoDownloadSettingsBuilder { inner }
   }
 
+  /// `AutoDownloadSettings` with the fields tdlib requires, the others are set by `AutoDownloadSettings::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    is_auto_download_enabled: bool,
+    max_photo_file_size: i64,
+    max_video_file_size: i64,
+    max_other_file_size: i64,
+    video_upload_bitrate: i64,
+    preload_large_videos: bool,
+    preload_next_audio: bool,
+    use_less_data_for_calls: bool,
+  ) -> Self {
+    Self::builder()
+      .is_auto_download_enabled(is_auto_download_enabled)
+      .max_photo_file_size(max_photo_file_size)
+      .max_video_file_size(max_video_file_size)
+      .max_other_file_size(max_other_file_size)
+      .video_upload_bitrate(video_upload_bitrate)
+      .preload_large_videos(preload_large_videos)
+      .preload_next_audio(preload_next_audio)
+      .use_less_data_for_calls(use_less_data_for_calls)
+      .build()
+  }
+
   pub fn is_auto_download_enabled(&self) -> bool { self.is_auto_download_enabled }
 
   pub fn max_photo_file_size(&self) -> i64 { self.max_photo_file_size }
@@ -77,6 +102,12 @@ pub struct RTDAutoDownloadSettingsBuilder {
 impl RTDAutoDownloadSettingsBuilder {
   pub fn build(&self) -> AutoDownloadSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_auto_download_enabled(&mut self, is_auto_download_enabled: bool) -> &mut Self {
     self.inner.is_auto_download_enabled = is_auto_download_enabled;
@@ -135,5 +166,18 @@ impl AsRef<AutoDownloadSettings> for RTDAutoDownloadSettingsBuilder {
   fn as_ref(&self) -> &AutoDownloadSettings { &self.inner }
 }
 
+impl PartialEq for AutoDownloadSettings {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_auto_download_enabled == other.is_auto_download_enabled
+      && self.max_photo_file_size == other.max_photo_file_size
+      && self.max_video_file_size == other.max_video_file_size
+      && self.max_other_file_size == other.max_other_file_size
+      && self.video_upload_bitrate == other.video_upload_bitrate
+      && self.preload_large_videos == other.preload_large_videos
+      && self.preload_next_audio == other.preload_next_audio
+      && self.use_less_data_for_calls == other.use_less_data_for_calls
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct AutoDownloadSettingsPresets {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Preset with lowest settings; supposed to be used by default when roaming
   low: AutoDownloadSettings,
   /// Preset with medium settings; supposed to be used by default when using mobile data
@@ -25,22 +27,24 @@ pub struct AutoDownloadSettingsPresets {
 }
 
 impl RObject for AutoDownloadSettingsPresets {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "autoDownloadSettingsPresets" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "autoDownloadSettingsPresets" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl AutoDownloadSettingsPresets {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDAutoDownloadSettingsPresetsBuilder {
-    let mut inner = AutoDownloadSettingsPresets::default();
-    inner.td_name = "autoDownloadSettingsPresets".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = AutoDownloadSettingsPresets { td_name: "autoDownloadSettingsPresets".to_string(), ..Default::default() };
     RTDAutoDownloadSettingsPresetsBuilder { inner }
   }
 
+  /// `AutoDownloadSettingsPresets` with the fields tdlib requires, the others are set by `AutoDownloadSettingsPresets::builder()`
+  pub fn new(low: impl AsRef<AutoDownloadSettings>, medium: impl AsRef<AutoDownloadSettings>, high: impl AsRef<AutoDownloadSettings>) -> Self { Self::builder().low(low).medium(medium).high(high).build() }
+
   pub fn low(&self) -> &AutoDownloadSettings { &self.low }
 
   pub fn medium(&self) -> &AutoDownloadSettings { &self.medium }
@@ -57,6 +61,12 @@ pub struct RTDAutoDownloadSettingsPresetsBuilder {
 impl RTDAutoDownloadSettingsPresetsBuilder {
   pub fn build(&self) -> AutoDownloadSettingsPresets { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn low<T: AsRef<AutoDownloadSettings>>(&mut self, low: T) -> &mut Self {
     self.inner.low = low.as_ref().clone();
@@ -85,5 +95,13 @@ impl AsRef<AutoDownloadSettingsPresets> for RTDAutoDownloadSettingsPresetsBuilde
   fn as_ref(&self) -> &AutoDownloadSettingsPresets { &self.inner }
 }
 
+impl PartialEq for AutoDownloadSettingsPresets {
+  fn eq(&self, other: &Self) -> bool {
+    self.low == other.low
+      && self.medium == other.medium
+      && self.high == other.high
+  }
+}
+
 
 
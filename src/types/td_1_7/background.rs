@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct Background {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique background identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// True, if this is one of default backgrounds
   is_default: bool,
   /// True, if the background is dark and is recommended to be used with dark theme
@@ -31,23 +33,39 @@ pub struct Background {
 }
 
 impl RObject for Background {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "background" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "background" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Background {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundBuilder {
-    let mut inner = Background::default();
-    inner.td_name = "background".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Background { td_name: "background".to_string(), ..Default::default() };
     RTDBackgroundBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `Background` with the fields tdlib requires, the others are set by `Background::builder()`
+  pub fn new(
+    id: i64,
+    is_default: bool,
+    is_dark: bool,
+    name: impl AsRef<str>,
+    type_: impl AsRef<BackgroundType>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .is_default(is_default)
+      .is_dark(is_dark)
+      .name(name)
+      .type_(type_)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn is_default(&self) -> bool { self.is_default }
 
@@ -69,8 +87,14 @@ pub struct RTDBackgroundBuilder {
 impl RTDBackgroundBuilder {
   pub fn build(&self) -> Background { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -115,5 +139,16 @@ impl AsRef<Background> for RTDBackgroundBuilder {
   fn as_ref(&self) -> &Background { &self.inner }
 }
 
+impl PartialEq for Background {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.is_default == other.is_default
+      && self.is_dark == other.is_dark
+      && self.name == other.name
+      && self.document == other.document
+      && self.type_ == other.type_
+  }
+}
+
 
 
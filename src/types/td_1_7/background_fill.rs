@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDBackgroundFill: Debug + RObject {}
 
 /// Describes a fill of a background
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum BackgroundFill {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum BackgroundFill {
   Gradient(BackgroundFillGradient),
   /// Describes a solid fill of a background
   Solid(BackgroundFillSolid),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for BackgroundFill {
   fn deserialize<D>(deserializer: D) -> Result<BackgroundFill, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      BackgroundFill,
+      BackgroundFill, Unknown;
       (backgroundFillFreeformGradient, FreeformGradient);
       (backgroundFillGradient, Gradient);
       (backgroundFillSolid, Solid);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for BackgroundFill {
 }
 
 impl RObject for BackgroundFill {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       BackgroundFill::FreeformGradient(t) => t.td_name(),
       BackgroundFill::Gradient(t) => t.td_name(),
       BackgroundFill::Solid(t) => t.td_name(),
+      BackgroundFill::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       BackgroundFill::FreeformGradient(t) => t.extra(),
       BackgroundFill::Gradient(t) => t.extra(),
       BackgroundFill::Solid(t) => t.extra(),
+      BackgroundFill::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      BackgroundFill::FreeformGradient(t) => t.client_id(),
+      BackgroundFill::Gradient(t) => t.client_id(),
+      BackgroundFill::Solid(t) => t.client_id(),
+      BackgroundFill::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for BackgroundFill {
 }
 
 impl BackgroundFill {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let BackgroundFill::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, BackgroundFill::_Default(_)) }
 
-  pub fn is_freeform_gradient(&self) -> bool { if let BackgroundFill::FreeformGradient(_) = self { true } else { false } }
-  pub fn is_gradient(&self) -> bool { if let BackgroundFill::Gradient(_) = self { true } else { false } }
-  pub fn is_solid(&self) -> bool { if let BackgroundFill::Solid(_) = self { true } else { false } }
+  pub fn is_freeform_gradient(&self) -> bool { matches!(self, BackgroundFill::FreeformGradient(_)) }
+  pub fn is_gradient(&self) -> bool { matches!(self, BackgroundFill::Gradient(_)) }
+  pub fn is_solid(&self) -> bool { matches!(self, BackgroundFill::Solid(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, BackgroundFill::Unknown(_)) }
 
   pub fn on_freeform_gradient<F: FnOnce(&BackgroundFillFreeformGradient)>(&self, fnc: F) -> &Self { if let BackgroundFill::FreeformGradient(t) = self { fnc(t) }; self }
   pub fn on_gradient<F: FnOnce(&BackgroundFillGradient)>(&self, fnc: F) -> &Self { if let BackgroundFill::Gradient(t) = self { fnc(t) }; self }
   pub fn on_solid<F: FnOnce(&BackgroundFillSolid)>(&self, fnc: F) -> &Self { if let BackgroundFill::Solid(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let BackgroundFill::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_freeform_gradient(&self) -> Option<&BackgroundFillFreeformGradient> { if let BackgroundFill::FreeformGradient(t) = self { return Some(t) } None }
   pub fn as_gradient(&self) -> Option<&BackgroundFillGradient> { if let BackgroundFill::Gradient(t) = self { return Some(t) } None }
   pub fn as_solid(&self) -> Option<&BackgroundFillSolid> { if let BackgroundFill::Solid(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let BackgroundFill::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,15 +127,19 @@ pub struct BackgroundFillFreeformGradient {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of 3 or 4 colors of the freeform gradients in the RGB24 format
   colors: Vec<i64>,
   
 }
 
 impl RObject for BackgroundFillFreeformGradient {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundFillFreeformGradient" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "backgroundFillFreeformGradient" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -129,14 +149,15 @@ impl TDBackgroundFill for BackgroundFillFreeformGradient {}
 
 
 impl BackgroundFillFreeformGradient {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundFillFreeformGradientBuilder {
-    let mut inner = BackgroundFillFreeformGradient::default();
-    inner.td_name = "backgroundFillFreeformGradient".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BackgroundFillFreeformGradient { td_name: "backgroundFillFreeformGradient".to_string(), ..Default::default() };
     RTDBackgroundFillFreeformGradientBuilder { inner }
   }
 
+  /// `BackgroundFillFreeformGradient` with the fields tdlib requires, the others are set by `BackgroundFillFreeformGradient::builder()`
+  pub fn new(colors: Vec<i64>) -> Self { Self::builder().colors(colors).build() }
+
   pub fn colors(&self) -> &Vec<i64> { &self.colors }
 
 }
@@ -149,6 +170,12 @@ pub struct RTDBackgroundFillFreeformGradientBuilder {
 impl RTDBackgroundFillFreeformGradientBuilder {
   pub fn build(&self) -> BackgroundFillFreeformGradient { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn colors(&mut self, colors: Vec<i64>) -> &mut Self {
     self.inner.colors = colors;
@@ -165,6 +192,12 @@ impl AsRef<BackgroundFillFreeformGradient> for RTDBackgroundFillFreeformGradient
   fn as_ref(&self) -> &BackgroundFillFreeformGradient { &self.inner }
 }
 
+impl PartialEq for BackgroundFillFreeformGradient {
+  fn eq(&self, other: &Self) -> bool {
+    self.colors == other.colors
+  }
+}
+
 
 
 
@@ -179,7 +212,10 @@ pub struct BackgroundFillGradient {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A top color of the background in the RGB24 format
   top_color: i64,
   /// A bottom color of the background in the RGB24 format
@@ -190,8 +226,9 @@ pub struct BackgroundFillGradient {
 }
 
 impl RObject for BackgroundFillGradient {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundFillGradient" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "backgroundFillGradient" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -201,14 +238,15 @@ impl TDBackgroundFill for BackgroundFillGradient {}
 
 
 impl BackgroundFillGradient {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundFillGradientBuilder {
-    let mut inner = BackgroundFillGradient::default();
-    inner.td_name = "backgroundFillGradient".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BackgroundFillGradient { td_name: "backgroundFillGradient".to_string(), ..Default::default() };
     RTDBackgroundFillGradientBuilder { inner }
   }
 
+  /// `BackgroundFillGradient` with the fields tdlib requires, the others are set by `BackgroundFillGradient::builder()`
+  pub fn new(top_color: i64, bottom_color: i64, rotation_angle: i64) -> Self { Self::builder().top_color(top_color).bottom_color(bottom_color).rotation_angle(rotation_angle).build() }
+
   pub fn top_color(&self) -> i64 { self.top_color }
 
   pub fn bottom_color(&self) -> i64 { self.bottom_color }
@@ -225,6 +263,12 @@ pub struct RTDBackgroundFillGradientBuilder {
 impl RTDBackgroundFillGradientBuilder {
   pub fn build(&self) -> BackgroundFillGradient { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn top_color(&mut self, top_color: i64) -> &mut Self {
     self.inner.top_color = top_color;
@@ -253,6 +297,14 @@ impl AsRef<BackgroundFillGradient> for RTDBackgroundFillGradientBuilder {
   fn as_ref(&self) -> &BackgroundFillGradient { &self.inner }
 }
 
+impl PartialEq for BackgroundFillGradient {
+  fn eq(&self, other: &Self) -> bool {
+    self.top_color == other.top_color
+      && self.bottom_color == other.bottom_color
+      && self.rotation_angle == other.rotation_angle
+  }
+}
+
 
 
 
@@ -267,15 +319,19 @@ pub struct BackgroundFillSolid {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A color of the background in the RGB24 format
   color: i64,
   
 }
 
 impl RObject for BackgroundFillSolid {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundFillSolid" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "backgroundFillSolid" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -285,14 +341,15 @@ impl TDBackgroundFill for BackgroundFillSolid {}
 
 
 impl BackgroundFillSolid {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundFillSolidBuilder {
-    let mut inner = BackgroundFillSolid::default();
-    inner.td_name = "backgroundFillSolid".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BackgroundFillSolid { td_name: "backgroundFillSolid".to_string(), ..Default::default() };
     RTDBackgroundFillSolidBuilder { inner }
   }
 
+  /// `BackgroundFillSolid` with the fields tdlib requires, the others are set by `BackgroundFillSolid::builder()`
+  pub fn new(color: i64) -> Self { Self::builder().color(color).build() }
+
   pub fn color(&self) -> i64 { self.color }
 
 }
@@ -305,6 +362,12 @@ pub struct RTDBackgroundFillSolidBuilder {
 impl RTDBackgroundFillSolidBuilder {
   pub fn build(&self) -> BackgroundFillSolid { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn color(&mut self, color: i64) -> &mut Self {
     self.inner.color = color;
@@ -321,5 +384,11 @@ impl AsRef<BackgroundFillSolid> for RTDBackgroundFillSolidBuilder {
   fn as_ref(&self) -> &BackgroundFillSolid { &self.inner }
 }
 
+impl PartialEq for BackgroundFillSolid {
+  fn eq(&self, other: &Self) -> bool {
+    self.color == other.color
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDBackgroundType: Debug + RObject {}
 
 /// Describes the type of a background
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum BackgroundType {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum BackgroundType {
   Pattern(BackgroundTypePattern),
   /// A wallpaper in JPEG format
   Wallpaper(BackgroundTypeWallpaper),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for BackgroundType {
   fn deserialize<D>(deserializer: D) -> Result<BackgroundType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      BackgroundType,
+      BackgroundType, Unknown;
       (backgroundTypeFill, Fill);
       (backgroundTypePattern, Pattern);
       (backgroundTypeWallpaper, Wallpaper);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for BackgroundType {
 }
 
 impl RObject for BackgroundType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       BackgroundType::Fill(t) => t.td_name(),
       BackgroundType::Pattern(t) => t.td_name(),
       BackgroundType::Wallpaper(t) => t.td_name(),
+      BackgroundType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       BackgroundType::Fill(t) => t.extra(),
       BackgroundType::Pattern(t) => t.extra(),
       BackgroundType::Wallpaper(t) => t.extra(),
+      BackgroundType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      BackgroundType::Fill(t) => t.client_id(),
+      BackgroundType::Pattern(t) => t.client_id(),
+      BackgroundType::Wallpaper(t) => t.client_id(),
+      BackgroundType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for BackgroundType {
 }
 
 impl BackgroundType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let BackgroundType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, BackgroundType::_Default(_)) }
 
-  pub fn is_fill(&self) -> bool { if let BackgroundType::Fill(_) = self { true } else { false } }
-  pub fn is_pattern(&self) -> bool { if let BackgroundType::Pattern(_) = self { true } else { false } }
-  pub fn is_wallpaper(&self) -> bool { if let BackgroundType::Wallpaper(_) = self { true } else { false } }
+  pub fn is_fill(&self) -> bool { matches!(self, BackgroundType::Fill(_)) }
+  pub fn is_pattern(&self) -> bool { matches!(self, BackgroundType::Pattern(_)) }
+  pub fn is_wallpaper(&self) -> bool { matches!(self, BackgroundType::Wallpaper(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, BackgroundType::Unknown(_)) }
 
   pub fn on_fill<F: FnOnce(&BackgroundTypeFill)>(&self, fnc: F) -> &Self { if let BackgroundType::Fill(t) = self { fnc(t) }; self }
   pub fn on_pattern<F: FnOnce(&BackgroundTypePattern)>(&self, fnc: F) -> &Self { if let BackgroundType::Pattern(t) = self { fnc(t) }; self }
   pub fn on_wallpaper<F: FnOnce(&BackgroundTypeWallpaper)>(&self, fnc: F) -> &Self { if let BackgroundType::Wallpaper(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let BackgroundType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_fill(&self) -> Option<&BackgroundTypeFill> { if let BackgroundType::Fill(t) = self { return Some(t) } None }
   pub fn as_pattern(&self) -> Option<&BackgroundTypePattern> { if let BackgroundType::Pattern(t) = self { return Some(t) } None }
   pub fn as_wallpaper(&self) -> Option<&BackgroundTypeWallpaper> { if let BackgroundType::Wallpaper(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let BackgroundType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,15 +127,19 @@ pub struct BackgroundTypeFill {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The background fill
   fill: BackgroundFill,
   
 }
 
 impl RObject for BackgroundTypeFill {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundTypeFill" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "backgroundTypeFill" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -129,14 +149,15 @@ impl TDBackgroundType for BackgroundTypeFill {}
 
 
 impl BackgroundTypeFill {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundTypeFillBuilder {
-    let mut inner = BackgroundTypeFill::default();
-    inner.td_name = "backgroundTypeFill".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BackgroundTypeFill { td_name: "backgroundTypeFill".to_string(), ..Default::default() };
     RTDBackgroundTypeFillBuilder { inner }
   }
 
+  /// `BackgroundTypeFill` with the fields tdlib requires, the others are set by `BackgroundTypeFill::builder()`
+  pub fn new(fill: impl AsRef<BackgroundFill>) -> Self { Self::builder().fill(fill).build() }
+
   pub fn fill(&self) -> &BackgroundFill { &self.fill }
 
 }
@@ -149,6 +170,12 @@ pub struct RTDBackgroundTypeFillBuilder {
 impl RTDBackgroundTypeFillBuilder {
   pub fn build(&self) -> BackgroundTypeFill { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
     self.inner.fill = fill.as_ref().clone();
@@ -165,6 +192,12 @@ impl AsRef<BackgroundTypeFill> for RTDBackgroundTypeFillBuilder {
   fn as_ref(&self) -> &BackgroundTypeFill { &self.inner }
 }
 
+impl PartialEq for BackgroundTypeFill {
+  fn eq(&self, other: &Self) -> bool {
+    self.fill == other.fill
+  }
+}
+
 
 
 
@@ -179,7 +212,10 @@ pub struct BackgroundTypePattern {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Fill of the background
   fill: BackgroundFill,
   /// Intensity of the pattern when it is shown above the filled background; 0-100.
@@ -192,8 +228,9 @@ pub struct BackgroundTypePattern {
 }
 
 impl RObject for BackgroundTypePattern {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundTypePattern" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "backgroundTypePattern" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -203,14 +240,27 @@ impl TDBackgroundType for BackgroundTypePattern {}
 
 
 impl BackgroundTypePattern {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundTypePatternBuilder {
-    let mut inner = BackgroundTypePattern::default();
-    inner.td_name = "backgroundTypePattern".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BackgroundTypePattern { td_name: "backgroundTypePattern".to_string(), ..Default::default() };
     RTDBackgroundTypePatternBuilder { inner }
   }
 
+  /// `BackgroundTypePattern` with the fields tdlib requires, the others are set by `BackgroundTypePattern::builder()`
+  pub fn new(
+    fill: impl AsRef<BackgroundFill>,
+    intensity: i64,
+    is_inverted: bool,
+    is_moving: bool,
+  ) -> Self {
+    Self::builder()
+      .fill(fill)
+      .intensity(intensity)
+      .is_inverted(is_inverted)
+      .is_moving(is_moving)
+      .build()
+  }
+
   pub fn fill(&self) -> &BackgroundFill { &self.fill }
 
   pub fn intensity(&self) -> i64 { self.intensity }
@@ -229,6 +279,12 @@ pub struct RTDBackgroundTypePatternBuilder {
 impl RTDBackgroundTypePatternBuilder {
   pub fn build(&self) -> BackgroundTypePattern { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
     self.inner.fill = fill.as_ref().clone();
@@ -263,6 +319,15 @@ impl AsRef<BackgroundTypePattern> for RTDBackgroundTypePatternBuilder {
   fn as_ref(&self) -> &BackgroundTypePattern { &self.inner }
 }
 
+impl PartialEq for BackgroundTypePattern {
+  fn eq(&self, other: &Self) -> bool {
+    self.fill == other.fill
+      && self.intensity == other.intensity
+      && self.is_inverted == other.is_inverted
+      && self.is_moving == other.is_moving
+  }
+}
+
 
 
 
@@ -277,7 +342,10 @@ pub struct BackgroundTypeWallpaper {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the wallpaper must be downscaled to fit in 450x450 square and then box-blurred with radius 12
   is_blurred: bool,
   /// True, if the background needs to be slightly moved when device is tilted
@@ -286,8 +354,9 @@ pub struct BackgroundTypeWallpaper {
 }
 
 impl RObject for BackgroundTypeWallpaper {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "backgroundTypeWallpaper" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "backgroundTypeWallpaper" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -297,14 +366,15 @@ impl TDBackgroundType for BackgroundTypeWallpaper {}
 
 
 impl BackgroundTypeWallpaper {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundTypeWallpaperBuilder {
-    let mut inner = BackgroundTypeWallpaper::default();
-    inner.td_name = "backgroundTypeWallpaper".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BackgroundTypeWallpaper { td_name: "backgroundTypeWallpaper".to_string(), ..Default::default() };
     RTDBackgroundTypeWallpaperBuilder { inner }
   }
 
+  /// `BackgroundTypeWallpaper` with the fields tdlib requires, the others are set by `BackgroundTypeWallpaper::builder()`
+  pub fn new(is_blurred: bool, is_moving: bool) -> Self { Self::builder().is_blurred(is_blurred).is_moving(is_moving).build() }
+
   pub fn is_blurred(&self) -> bool { self.is_blurred }
 
   pub fn is_moving(&self) -> bool { self.is_moving }
@@ -319,6 +389,12 @@ pub struct RTDBackgroundTypeWallpaperBuilder {
 impl RTDBackgroundTypeWallpaperBuilder {
   pub fn build(&self) -> BackgroundTypeWallpaper { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_blurred(&mut self, is_blurred: bool) -> &mut Self {
     self.inner.is_blurred = is_blurred;
@@ -341,5 +417,12 @@ impl AsRef<BackgroundTypeWallpaper> for RTDBackgroundTypeWallpaperBuilder {
   fn as_ref(&self) -> &BackgroundTypeWallpaper { &self.inner }
 }
 
+impl PartialEq for BackgroundTypeWallpaper {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_blurred == other.is_blurred
+      && self.is_moving == other.is_moving
+  }
+}
+
 
 
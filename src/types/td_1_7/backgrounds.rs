@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Backgrounds {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of backgrounds
   backgrounds: Vec<Background>,
   
 }
 
 impl RObject for Backgrounds {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "backgrounds" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "backgrounds" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Backgrounds {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBackgroundsBuilder {
-    let mut inner = Backgrounds::default();
-    inner.td_name = "backgrounds".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Backgrounds { td_name: "backgrounds".to_string(), ..Default::default() };
     RTDBackgroundsBuilder { inner }
   }
 
+  /// `Backgrounds` with the fields tdlib requires, the others are set by `Backgrounds::builder()`
+  pub fn new(backgrounds: Vec<Background>) -> Self { Self::builder().backgrounds(backgrounds).build() }
+
   pub fn backgrounds(&self) -> &Vec<Background> { &self.backgrounds }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDBackgroundsBuilder {
 impl RTDBackgroundsBuilder {
   pub fn build(&self) -> Backgrounds { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn backgrounds(&mut self, backgrounds: Vec<Background>) -> &mut Self {
     self.inner.backgrounds = backgrounds;
@@ -65,5 +75,11 @@ impl AsRef<Backgrounds> for RTDBackgroundsBuilder {
   fn as_ref(&self) -> &Backgrounds { &self.inner }
 }
 
+impl PartialEq for Backgrounds {
+  fn eq(&self, other: &Self) -> bool {
+    self.backgrounds == other.backgrounds
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct BankCardActionOpenUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Action text
   text: String,
   /// The URL to be opened
@@ -23,22 +25,24 @@ pub struct BankCardActionOpenUrl {
 }
 
 impl RObject for BankCardActionOpenUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "bankCardActionOpenUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "bankCardActionOpenUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl BankCardActionOpenUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBankCardActionOpenUrlBuilder {
-    let mut inner = BankCardActionOpenUrl::default();
-    inner.td_name = "bankCardActionOpenUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BankCardActionOpenUrl { td_name: "bankCardActionOpenUrl".to_string(), ..Default::default() };
     RTDBankCardActionOpenUrlBuilder { inner }
   }
 
+  /// `BankCardActionOpenUrl` with the fields tdlib requires, the others are set by `BankCardActionOpenUrl::builder()`
+  pub fn new(text: impl AsRef<str>, url: impl AsRef<str>) -> Self { Self::builder().text(text).url(url).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
   pub fn url(&self) -> &String { &self.url }
@@ -53,6 +57,12 @@ pub struct RTDBankCardActionOpenUrlBuilder {
 impl RTDBankCardActionOpenUrlBuilder {
   pub fn build(&self) -> BankCardActionOpenUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<BankCardActionOpenUrl> for RTDBankCardActionOpenUrlBuilder {
   fn as_ref(&self) -> &BankCardActionOpenUrl { &self.inner }
 }
 
+impl PartialEq for BankCardActionOpenUrl {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.url == other.url
+  }
+}
+
 
 
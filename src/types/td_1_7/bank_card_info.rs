@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct BankCardInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Title of the bank card description
   title: String,
   /// Actions that can be done with the bank card number
@@ -23,22 +25,24 @@ pub struct BankCardInfo {
 }
 
 impl RObject for BankCardInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "bankCardInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "bankCardInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl BankCardInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBankCardInfoBuilder {
-    let mut inner = BankCardInfo::default();
-    inner.td_name = "bankCardInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BankCardInfo { td_name: "bankCardInfo".to_string(), ..Default::default() };
     RTDBankCardInfoBuilder { inner }
   }
 
+  /// `BankCardInfo` with the fields tdlib requires, the others are set by `BankCardInfo::builder()`
+  pub fn new(title: impl AsRef<str>, actions: Vec<BankCardActionOpenUrl>) -> Self { Self::builder().title(title).actions(actions).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
   pub fn actions(&self) -> &Vec<BankCardActionOpenUrl> { &self.actions }
@@ -53,6 +57,12 @@ pub struct RTDBankCardInfoBuilder {
 impl RTDBankCardInfoBuilder {
   pub fn build(&self) -> BankCardInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<BankCardInfo> for RTDBankCardInfoBuilder {
   fn as_ref(&self) -> &BankCardInfo { &self.inner }
 }
 
+impl PartialEq for BankCardInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.actions == other.actions
+  }
+}
+
 
 
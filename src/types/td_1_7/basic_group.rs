@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct BasicGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Group identifier
-  id: i64,
+  id: BasicGroupId,
   /// Number of members in the group
   member_count: i64,
   /// Status of the current user in the group
@@ -24,28 +26,44 @@ pub struct BasicGroup {
   /// True, if the group is active
   is_active: bool,
   /// Identifier of the supergroup to which this group was upgraded; 0 if none
-  upgraded_to_supergroup_id: i64,
+  upgraded_to_supergroup_id: SupergroupId,
   
 }
 
 impl RObject for BasicGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "basicGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "basicGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl BasicGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBasicGroupBuilder {
-    let mut inner = BasicGroup::default();
-    inner.td_name = "basicGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BasicGroup { td_name: "basicGroup".to_string(), ..Default::default() };
     RTDBasicGroupBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `BasicGroup` with the fields tdlib requires, the others are set by `BasicGroup::builder()`
+  pub fn new(
+    id: BasicGroupId,
+    member_count: i64,
+    status: impl AsRef<ChatMemberStatus>,
+    is_active: bool,
+    upgraded_to_supergroup_id: SupergroupId,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .member_count(member_count)
+      .status(status)
+      .is_active(is_active)
+      .upgraded_to_supergroup_id(upgraded_to_supergroup_id)
+      .build()
+  }
+
+  pub fn id(&self) -> BasicGroupId { self.id }
 
   pub fn member_count(&self) -> i64 { self.member_count }
 
@@ -53,7 +71,7 @@ impl BasicGroup {
 
   pub fn is_active(&self) -> bool { self.is_active }
 
-  pub fn upgraded_to_supergroup_id(&self) -> i64 { self.upgraded_to_supergroup_id }
+  pub fn upgraded_to_supergroup_id(&self) -> SupergroupId { self.upgraded_to_supergroup_id }
 
 }
 
@@ -65,8 +83,14 @@ pub struct RTDBasicGroupBuilder {
 impl RTDBasicGroupBuilder {
   pub fn build(&self) -> BasicGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: BasicGroupId) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -90,7 +114,7 @@ impl RTDBasicGroupBuilder {
   }
 
    
-  pub fn upgraded_to_supergroup_id(&mut self, upgraded_to_supergroup_id: i64) -> &mut Self {
+  pub fn upgraded_to_supergroup_id(&mut self, upgraded_to_supergroup_id: SupergroupId) -> &mut Self {
     self.inner.upgraded_to_supergroup_id = upgraded_to_supergroup_id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<BasicGroup> for RTDBasicGroupBuilder {
   fn as_ref(&self) -> &BasicGroup { &self.inner }
 }
 
+impl PartialEq for BasicGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.member_count == other.member_count
+      && self.status == other.status
+      && self.is_active == other.is_active
+      && self.upgraded_to_supergroup_id == other.upgraded_to_supergroup_id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,13 +13,16 @@ pub struct BasicGroupFullInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat photo; may be null
   photo: Option<ChatPhoto>,
-  /// Contains full information about a basic group
+  /// Group description. Updated only after the basic group is opened
   description: String,
   /// User identifier of the creator of the group; 0 if unknown
-  creator_user_id: i64,
+  creator_user_id: UserId,
   /// Group members
   members: Vec<ChatMember>,
   /// Primary invite link for this group; may be null. For chat administrators with can_invite_users right only. Updated only after the basic group is opened
@@ -31,27 +33,41 @@ pub struct BasicGroupFullInfo {
 }
 
 impl RObject for BasicGroupFullInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "basicGroupFullInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "basicGroupFullInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl BasicGroupFullInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBasicGroupFullInfoBuilder {
-    let mut inner = BasicGroupFullInfo::default();
-    inner.td_name = "basicGroupFullInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BasicGroupFullInfo { td_name: "basicGroupFullInfo".to_string(), ..Default::default() };
     RTDBasicGroupFullInfoBuilder { inner }
   }
 
+  /// `BasicGroupFullInfo` with the fields tdlib requires, the others are set by `BasicGroupFullInfo::builder()`
+  pub fn new(
+    description: impl AsRef<str>,
+    creator_user_id: UserId,
+    members: Vec<ChatMember>,
+    bot_commands: Vec<BotCommands>,
+  ) -> Self {
+    Self::builder()
+      .description(description)
+      .creator_user_id(creator_user_id)
+      .members(members)
+      .bot_commands(bot_commands)
+      .build()
+  }
+
   pub fn photo(&self) -> &Option<ChatPhoto> { &self.photo }
 
   pub fn description(&self) -> &String { &self.description }
 
-  pub fn creator_user_id(&self) -> i64 { self.creator_user_id }
+  pub fn creator_user_id(&self) -> UserId { self.creator_user_id }
 
   pub fn members(&self) -> &Vec<ChatMember> { &self.members }
 
@@ -69,6 +85,12 @@ pub struct RTDBasicGroupFullInfoBuilder {
 impl RTDBasicGroupFullInfoBuilder {
   pub fn build(&self) -> BasicGroupFullInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -82,7 +104,7 @@ impl RTDBasicGroupFullInfoBuilder {
   }
 
    
-  pub fn creator_user_id(&mut self, creator_user_id: i64) -> &mut Self {
+  pub fn creator_user_id(&mut self, creator_user_id: UserId) -> &mut Self {
     self.inner.creator_user_id = creator_user_id;
     self
   }
@@ -115,5 +137,16 @@ impl AsRef<BasicGroupFullInfo> for RTDBasicGroupFullInfoBuilder {
   fn as_ref(&self) -> &BasicGroupFullInfo { &self.inner }
 }
 
+impl PartialEq for BasicGroupFullInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+      && self.description == other.description
+      && self.creator_user_id == other.creator_user_id
+      && self.members == other.members
+      && self.invite_link == other.invite_link
+      && self.bot_commands == other.bot_commands
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,31 +13,36 @@ pub struct BotCommand {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text of the bot command
   command: String,
-  /// Represents a command supported by a bot
+  /// Description of the bot command
   description: String,
   
 }
 
 impl RObject for BotCommand {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommand" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommand" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl BotCommand {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandBuilder {
-    let mut inner = BotCommand::default();
-    inner.td_name = "botCommand".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommand { td_name: "botCommand".to_string(), ..Default::default() };
     RTDBotCommandBuilder { inner }
   }
 
+  /// `BotCommand` with the fields tdlib requires, the others are set by `BotCommand::builder()`
+  pub fn new(command: impl AsRef<str>, description: impl AsRef<str>) -> Self { Self::builder().command(command).description(description).build() }
+
   pub fn command(&self) -> &String { &self.command }
 
   pub fn description(&self) -> &String { &self.description }
@@ -53,6 +57,12 @@ pub struct RTDBotCommandBuilder {
 impl RTDBotCommandBuilder {
   pub fn build(&self) -> BotCommand { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn command<T: AsRef<str>>(&mut self, command: T) -> &mut Self {
     self.inner.command = command.as_ref().to_string();
@@ -75,5 +85,19 @@ impl AsRef<BotCommand> for RTDBotCommandBuilder {
   fn as_ref(&self) -> &BotCommand { &self.inner }
 }
 
+impl PartialEq for BotCommand {
+  fn eq(&self, other: &Self) -> bool {
+    self.command == other.command
+      && self.description == other.description
+  }
+}
+
+impl Validate for BotCommand {
+  fn check(&self, checker: &mut Checker) {
+    checker.bot_command("command", &self.command);
+    checker.length("description", &self.description, 1, 256);
+  }
+}
+
 
 
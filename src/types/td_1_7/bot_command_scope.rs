@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDBotCommandScope: Debug + RObject {}
 
 /// Represents the scope to which bot commands are relevant
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum BotCommandScope {
   #[doc(hidden)] _Default(()),
@@ -33,6 +32,8 @@ pub enum BotCommandScope {
   ChatMember(BotCommandScopeChatMember),
   /// A scope covering all users
   Default(BotCommandScopeDefault),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -44,7 +45,7 @@ impl<'de> Deserialize<'de> for BotCommandScope {
   fn deserialize<D>(deserializer: D) -> Result<BotCommandScope, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      BotCommandScope,
+      BotCommandScope, Unknown;
       (botCommandScopeAllChatAdministrators, AllChatAdministrators);
       (botCommandScopeAllGroupChats, AllGroupChats);
       (botCommandScopeAllPrivateChats, AllPrivateChats);
@@ -58,7 +59,7 @@ impl<'de> Deserialize<'de> for BotCommandScope {
 }
 
 impl RObject for BotCommandScope {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       BotCommandScope::AllChatAdministrators(t) => t.td_name(),
       BotCommandScope::AllGroupChats(t) => t.td_name(),
@@ -67,11 +68,12 @@ impl RObject for BotCommandScope {
       BotCommandScope::ChatAdministrators(t) => t.td_name(),
       BotCommandScope::ChatMember(t) => t.td_name(),
       BotCommandScope::Default(t) => t.td_name(),
+      BotCommandScope::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       BotCommandScope::AllChatAdministrators(t) => t.extra(),
       BotCommandScope::AllGroupChats(t) => t.extra(),
@@ -80,6 +82,21 @@ impl RObject for BotCommandScope {
       BotCommandScope::ChatAdministrators(t) => t.extra(),
       BotCommandScope::ChatMember(t) => t.extra(),
       BotCommandScope::Default(t) => t.extra(),
+      BotCommandScope::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      BotCommandScope::AllChatAdministrators(t) => t.client_id(),
+      BotCommandScope::AllGroupChats(t) => t.client_id(),
+      BotCommandScope::AllPrivateChats(t) => t.client_id(),
+      BotCommandScope::Chat(t) => t.client_id(),
+      BotCommandScope::ChatAdministrators(t) => t.client_id(),
+      BotCommandScope::ChatMember(t) => t.client_id(),
+      BotCommandScope::Default(t) => t.client_id(),
+      BotCommandScope::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -88,16 +105,17 @@ impl RObject for BotCommandScope {
 }
 
 impl BotCommandScope {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let BotCommandScope::_Default(_) = self { true } else { false } }
-
-  pub fn is_all_chat_administrators(&self) -> bool { if let BotCommandScope::AllChatAdministrators(_) = self { true } else { false } }
-  pub fn is_all_group_chats(&self) -> bool { if let BotCommandScope::AllGroupChats(_) = self { true } else { false } }
-  pub fn is_all_private_chats(&self) -> bool { if let BotCommandScope::AllPrivateChats(_) = self { true } else { false } }
-  pub fn is_chat(&self) -> bool { if let BotCommandScope::Chat(_) = self { true } else { false } }
-  pub fn is_chat_administrators(&self) -> bool { if let BotCommandScope::ChatAdministrators(_) = self { true } else { false } }
-  pub fn is_chat_member(&self) -> bool { if let BotCommandScope::ChatMember(_) = self { true } else { false } }
-  pub fn is_default(&self) -> bool { if let BotCommandScope::Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, BotCommandScope::_Default(_)) }
+
+  pub fn is_all_chat_administrators(&self) -> bool { matches!(self, BotCommandScope::AllChatAdministrators(_)) }
+  pub fn is_all_group_chats(&self) -> bool { matches!(self, BotCommandScope::AllGroupChats(_)) }
+  pub fn is_all_private_chats(&self) -> bool { matches!(self, BotCommandScope::AllPrivateChats(_)) }
+  pub fn is_chat(&self) -> bool { matches!(self, BotCommandScope::Chat(_)) }
+  pub fn is_chat_administrators(&self) -> bool { matches!(self, BotCommandScope::ChatAdministrators(_)) }
+  pub fn is_chat_member(&self) -> bool { matches!(self, BotCommandScope::ChatMember(_)) }
+  pub fn is_default(&self) -> bool { matches!(self, BotCommandScope::Default(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, BotCommandScope::Unknown(_)) }
 
   pub fn on_all_chat_administrators<F: FnOnce(&BotCommandScopeAllChatAdministrators)>(&self, fnc: F) -> &Self { if let BotCommandScope::AllChatAdministrators(t) = self { fnc(t) }; self }
   pub fn on_all_group_chats<F: FnOnce(&BotCommandScopeAllGroupChats)>(&self, fnc: F) -> &Self { if let BotCommandScope::AllGroupChats(t) = self { fnc(t) }; self }
@@ -106,6 +124,7 @@ impl BotCommandScope {
   pub fn on_chat_administrators<F: FnOnce(&BotCommandScopeChatAdministrators)>(&self, fnc: F) -> &Self { if let BotCommandScope::ChatAdministrators(t) = self { fnc(t) }; self }
   pub fn on_chat_member<F: FnOnce(&BotCommandScopeChatMember)>(&self, fnc: F) -> &Self { if let BotCommandScope::ChatMember(t) = self { fnc(t) }; self }
   pub fn on_default<F: FnOnce(&BotCommandScopeDefault)>(&self, fnc: F) -> &Self { if let BotCommandScope::Default(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let BotCommandScope::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_all_chat_administrators(&self) -> Option<&BotCommandScopeAllChatAdministrators> { if let BotCommandScope::AllChatAdministrators(t) = self { return Some(t) } None }
   pub fn as_all_group_chats(&self) -> Option<&BotCommandScopeAllGroupChats> { if let BotCommandScope::AllGroupChats(t) = self { return Some(t) } None }
@@ -114,6 +133,7 @@ impl BotCommandScope {
   pub fn as_chat_administrators(&self) -> Option<&BotCommandScopeChatAdministrators> { if let BotCommandScope::ChatAdministrators(t) = self { return Some(t) } None }
   pub fn as_chat_member(&self) -> Option<&BotCommandScopeChatMember> { if let BotCommandScope::ChatMember(t) = self { return Some(t) } None }
   pub fn as_default(&self) -> Option<&BotCommandScopeDefault> { if let BotCommandScope::Default(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let BotCommandScope::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -151,13 +171,17 @@ pub struct BotCommandScopeAllChatAdministrators {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for BotCommandScopeAllChatAdministrators {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommandScopeAllChatAdministrators" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommandScopeAllChatAdministrators" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -167,14 +191,15 @@ impl TDBotCommandScope for BotCommandScopeAllChatAdministrators {}
 
 
 impl BotCommandScopeAllChatAdministrators {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandScopeAllChatAdministratorsBuilder {
-    let mut inner = BotCommandScopeAllChatAdministrators::default();
-    inner.td_name = "botCommandScopeAllChatAdministrators".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommandScopeAllChatAdministrators { td_name: "botCommandScopeAllChatAdministrators".to_string(), ..Default::default() };
     RTDBotCommandScopeAllChatAdministratorsBuilder { inner }
   }
 
+  /// `BotCommandScopeAllChatAdministrators` with the fields tdlib requires, the others are set by `BotCommandScopeAllChatAdministrators::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -185,6 +210,12 @@ pub struct RTDBotCommandScopeAllChatAdministratorsBuilder {
 impl RTDBotCommandScopeAllChatAdministratorsBuilder {
   pub fn build(&self) -> BotCommandScopeAllChatAdministrators { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<BotCommandScopeAllChatAdministrators> for BotCommandScopeAllChatAdministrators {
@@ -195,6 +226,10 @@ impl AsRef<BotCommandScopeAllChatAdministrators> for RTDBotCommandScopeAllChatAd
   fn as_ref(&self) -> &BotCommandScopeAllChatAdministrators { &self.inner }
 }
 
+impl PartialEq for BotCommandScopeAllChatAdministrators {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -209,13 +244,17 @@ pub struct BotCommandScopeAllGroupChats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for BotCommandScopeAllGroupChats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommandScopeAllGroupChats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommandScopeAllGroupChats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -225,14 +264,15 @@ impl TDBotCommandScope for BotCommandScopeAllGroupChats {}
 
 
 impl BotCommandScopeAllGroupChats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandScopeAllGroupChatsBuilder {
-    let mut inner = BotCommandScopeAllGroupChats::default();
-    inner.td_name = "botCommandScopeAllGroupChats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommandScopeAllGroupChats { td_name: "botCommandScopeAllGroupChats".to_string(), ..Default::default() };
     RTDBotCommandScopeAllGroupChatsBuilder { inner }
   }
 
+  /// `BotCommandScopeAllGroupChats` with the fields tdlib requires, the others are set by `BotCommandScopeAllGroupChats::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -243,6 +283,12 @@ pub struct RTDBotCommandScopeAllGroupChatsBuilder {
 impl RTDBotCommandScopeAllGroupChatsBuilder {
   pub fn build(&self) -> BotCommandScopeAllGroupChats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<BotCommandScopeAllGroupChats> for BotCommandScopeAllGroupChats {
@@ -253,6 +299,10 @@ impl AsRef<BotCommandScopeAllGroupChats> for RTDBotCommandScopeAllGroupChatsBuil
   fn as_ref(&self) -> &BotCommandScopeAllGroupChats { &self.inner }
 }
 
+impl PartialEq for BotCommandScopeAllGroupChats {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -267,13 +317,17 @@ pub struct BotCommandScopeAllPrivateChats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for BotCommandScopeAllPrivateChats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommandScopeAllPrivateChats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommandScopeAllPrivateChats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -283,14 +337,15 @@ impl TDBotCommandScope for BotCommandScopeAllPrivateChats {}
 
 
 impl BotCommandScopeAllPrivateChats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandScopeAllPrivateChatsBuilder {
-    let mut inner = BotCommandScopeAllPrivateChats::default();
-    inner.td_name = "botCommandScopeAllPrivateChats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommandScopeAllPrivateChats { td_name: "botCommandScopeAllPrivateChats".to_string(), ..Default::default() };
     RTDBotCommandScopeAllPrivateChatsBuilder { inner }
   }
 
+  /// `BotCommandScopeAllPrivateChats` with the fields tdlib requires, the others are set by `BotCommandScopeAllPrivateChats::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -301,6 +356,12 @@ pub struct RTDBotCommandScopeAllPrivateChatsBuilder {
 impl RTDBotCommandScopeAllPrivateChatsBuilder {
   pub fn build(&self) -> BotCommandScopeAllPrivateChats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<BotCommandScopeAllPrivateChats> for BotCommandScopeAllPrivateChats {
@@ -311,6 +372,10 @@ impl AsRef<BotCommandScopeAllPrivateChats> for RTDBotCommandScopeAllPrivateChats
   fn as_ref(&self) -> &BotCommandScopeAllPrivateChats { &self.inner }
 }
 
+impl PartialEq for BotCommandScopeAllPrivateChats {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -325,15 +390,19 @@ pub struct BotCommandScopeChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   
 }
 
 impl RObject for BotCommandScopeChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommandScopeChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommandScopeChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -343,15 +412,16 @@ impl TDBotCommandScope for BotCommandScopeChat {}
 
 
 impl BotCommandScopeChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandScopeChatBuilder {
-    let mut inner = BotCommandScopeChat::default();
-    inner.td_name = "botCommandScopeChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommandScopeChat { td_name: "botCommandScopeChat".to_string(), ..Default::default() };
     RTDBotCommandScopeChatBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `BotCommandScopeChat` with the fields tdlib requires, the others are set by `BotCommandScopeChat::builder()`
+  pub fn new(chat_id: ChatId) -> Self { Self::builder().chat_id(chat_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
 }
 
@@ -363,8 +433,14 @@ pub struct RTDBotCommandScopeChatBuilder {
 impl RTDBotCommandScopeChatBuilder {
   pub fn build(&self) -> BotCommandScopeChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -379,6 +455,12 @@ impl AsRef<BotCommandScopeChat> for RTDBotCommandScopeChatBuilder {
   fn as_ref(&self) -> &BotCommandScopeChat { &self.inner }
 }
 
+impl PartialEq for BotCommandScopeChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+  }
+}
+
 
 
 
@@ -393,15 +475,19 @@ pub struct BotCommandScopeChatAdministrators {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   
 }
 
 impl RObject for BotCommandScopeChatAdministrators {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommandScopeChatAdministrators" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommandScopeChatAdministrators" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -411,15 +497,16 @@ impl TDBotCommandScope for BotCommandScopeChatAdministrators {}
 
 
 impl BotCommandScopeChatAdministrators {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandScopeChatAdministratorsBuilder {
-    let mut inner = BotCommandScopeChatAdministrators::default();
-    inner.td_name = "botCommandScopeChatAdministrators".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommandScopeChatAdministrators { td_name: "botCommandScopeChatAdministrators".to_string(), ..Default::default() };
     RTDBotCommandScopeChatAdministratorsBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `BotCommandScopeChatAdministrators` with the fields tdlib requires, the others are set by `BotCommandScopeChatAdministrators::builder()`
+  pub fn new(chat_id: ChatId) -> Self { Self::builder().chat_id(chat_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
 }
 
@@ -431,8 +518,14 @@ pub struct RTDBotCommandScopeChatAdministratorsBuilder {
 impl RTDBotCommandScopeChatAdministratorsBuilder {
   pub fn build(&self) -> BotCommandScopeChatAdministrators { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -447,6 +540,12 @@ impl AsRef<BotCommandScopeChatAdministrators> for RTDBotCommandScopeChatAdminist
   fn as_ref(&self) -> &BotCommandScopeChatAdministrators { &self.inner }
 }
 
+impl PartialEq for BotCommandScopeChatAdministrators {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+  }
+}
+
 
 
 
@@ -461,17 +560,21 @@ pub struct BotCommandScopeChatMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for BotCommandScopeChatMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommandScopeChatMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommandScopeChatMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -481,17 +584,18 @@ impl TDBotCommandScope for BotCommandScopeChatMember {}
 
 
 impl BotCommandScopeChatMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandScopeChatMemberBuilder {
-    let mut inner = BotCommandScopeChatMember::default();
-    inner.td_name = "botCommandScopeChatMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommandScopeChatMember { td_name: "botCommandScopeChatMember".to_string(), ..Default::default() };
     RTDBotCommandScopeChatMemberBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `BotCommandScopeChatMember` with the fields tdlib requires, the others are set by `BotCommandScopeChatMember::builder()`
+  pub fn new(chat_id: ChatId, user_id: UserId) -> Self { Self::builder().chat_id(chat_id).user_id(user_id).build() }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -503,14 +607,20 @@ pub struct RTDBotCommandScopeChatMemberBuilder {
 impl RTDBotCommandScopeChatMemberBuilder {
   pub fn build(&self) -> BotCommandScopeChatMember { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -525,6 +635,13 @@ impl AsRef<BotCommandScopeChatMember> for RTDBotCommandScopeChatMemberBuilder {
   fn as_ref(&self) -> &BotCommandScopeChatMember { &self.inner }
 }
 
+impl PartialEq for BotCommandScopeChatMember {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.user_id == other.user_id
+  }
+}
+
 
 
 
@@ -539,13 +656,17 @@ pub struct BotCommandScopeDefault {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for BotCommandScopeDefault {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommandScopeDefault" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommandScopeDefault" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -555,14 +676,15 @@ impl TDBotCommandScope for BotCommandScopeDefault {}
 
 
 impl BotCommandScopeDefault {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandScopeDefaultBuilder {
-    let mut inner = BotCommandScopeDefault::default();
-    inner.td_name = "botCommandScopeDefault".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommandScopeDefault { td_name: "botCommandScopeDefault".to_string(), ..Default::default() };
     RTDBotCommandScopeDefaultBuilder { inner }
   }
 
+  /// `BotCommandScopeDefault` with the fields tdlib requires, the others are set by `BotCommandScopeDefault::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -573,6 +695,12 @@ pub struct RTDBotCommandScopeDefaultBuilder {
 impl RTDBotCommandScopeDefaultBuilder {
   pub fn build(&self) -> BotCommandScopeDefault { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<BotCommandScopeDefault> for BotCommandScopeDefault {
@@ -583,5 +711,9 @@ impl AsRef<BotCommandScopeDefault> for RTDBotCommandScopeDefaultBuilder {
   fn as_ref(&self) -> &BotCommandScopeDefault { &self.inner }
 }
 
+impl PartialEq for BotCommandScopeDefault {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,32 +13,37 @@ pub struct BotCommands {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Bot's user identifier
-  bot_user_id: i64,
+  bot_user_id: UserId,
   /// List of bot commands
   commands: Vec<BotCommand>,
   
 }
 
 impl RObject for BotCommands {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "botCommands" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "botCommands" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl BotCommands {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDBotCommandsBuilder {
-    let mut inner = BotCommands::default();
-    inner.td_name = "botCommands".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = BotCommands { td_name: "botCommands".to_string(), ..Default::default() };
     RTDBotCommandsBuilder { inner }
   }
 
-  pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
+  /// `BotCommands` with the fields tdlib requires, the others are set by `BotCommands::builder()`
+  pub fn new(bot_user_id: UserId, commands: Vec<BotCommand>) -> Self { Self::builder().bot_user_id(bot_user_id).commands(commands).build() }
+
+  pub fn bot_user_id(&self) -> UserId { self.bot_user_id }
 
   pub fn commands(&self) -> &Vec<BotCommand> { &self.commands }
 
@@ -53,8 +57,14 @@ pub struct RTDBotCommandsBuilder {
 impl RTDBotCommandsBuilder {
   pub fn build(&self) -> BotCommands { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
+  pub fn bot_user_id(&mut self, bot_user_id: UserId) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
     self
   }
@@ -75,5 +85,12 @@ impl AsRef<BotCommands> for RTDBotCommandsBuilder {
   fn as_ref(&self) -> &BotCommands { &self.inner }
 }
 
+impl PartialEq for BotCommands {
+  fn eq(&self, other: &Self) -> bool {
+    self.bot_user_id == other.bot_user_id
+      && self.commands == other.commands
+  }
+}
+
 
 
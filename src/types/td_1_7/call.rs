@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,11 +13,14 @@ pub struct Call {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Call identifier, not persistent
   id: i64,
   /// Peer user identifier
-  user_id: i64,
+  user_id: UserId,
   /// True, if the call is outgoing
   is_outgoing: bool,
   /// True, if the call is a video call
@@ -29,25 +31,41 @@ pub struct Call {
 }
 
 impl RObject for Call {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "call" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "call" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Call {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallBuilder {
-    let mut inner = Call::default();
-    inner.td_name = "call".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Call { td_name: "call".to_string(), ..Default::default() };
     RTDCallBuilder { inner }
   }
 
+  /// `Call` with the fields tdlib requires, the others are set by `Call::builder()`
+  pub fn new(
+    id: i64,
+    user_id: UserId,
+    is_outgoing: bool,
+    is_video: bool,
+    state: impl AsRef<CallState>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .user_id(user_id)
+      .is_outgoing(is_outgoing)
+      .is_video(is_video)
+      .state(state)
+      .build()
+  }
+
   pub fn id(&self) -> i64 { self.id }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn is_outgoing(&self) -> bool { self.is_outgoing }
 
@@ -65,6 +83,12 @@ pub struct RTDCallBuilder {
 impl RTDCallBuilder {
   pub fn build(&self) -> Call { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -72,7 +96,7 @@ impl RTDCallBuilder {
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<Call> for RTDCallBuilder {
   fn as_ref(&self) -> &Call { &self.inner }
 }
 
+impl PartialEq for Call {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.user_id == other.user_id
+      && self.is_outgoing == other.is_outgoing
+      && self.is_video == other.is_video
+      && self.state == other.state
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCallDiscardReason: Debug + RObject {}
 
 /// Describes the reason why a call was discarded
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CallDiscardReason {
   #[doc(hidden)] _Default(()),
@@ -29,6 +28,8 @@ pub enum CallDiscardReason {
   HungUp(CallDiscardReasonHungUp),
   /// The call was ended before the conversation started. It was canceled by the caller or missed by the other party
   Missed(CallDiscardReasonMissed),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -40,7 +41,7 @@ impl<'de> Deserialize<'de> for CallDiscardReason {
   fn deserialize<D>(deserializer: D) -> Result<CallDiscardReason, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CallDiscardReason,
+      CallDiscardReason, Unknown;
       (callDiscardReasonDeclined, Declined);
       (callDiscardReasonDisconnected, Disconnected);
       (callDiscardReasonEmpty, Empty);
@@ -52,24 +53,38 @@ impl<'de> Deserialize<'de> for CallDiscardReason {
 }
 
 impl RObject for CallDiscardReason {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CallDiscardReason::Declined(t) => t.td_name(),
       CallDiscardReason::Disconnected(t) => t.td_name(),
       CallDiscardReason::Empty(t) => t.td_name(),
       CallDiscardReason::HungUp(t) => t.td_name(),
       CallDiscardReason::Missed(t) => t.td_name(),
+      CallDiscardReason::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CallDiscardReason::Declined(t) => t.extra(),
       CallDiscardReason::Disconnected(t) => t.extra(),
       CallDiscardReason::Empty(t) => t.extra(),
       CallDiscardReason::HungUp(t) => t.extra(),
       CallDiscardReason::Missed(t) => t.extra(),
+      CallDiscardReason::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CallDiscardReason::Declined(t) => t.client_id(),
+      CallDiscardReason::Disconnected(t) => t.client_id(),
+      CallDiscardReason::Empty(t) => t.client_id(),
+      CallDiscardReason::HungUp(t) => t.client_id(),
+      CallDiscardReason::Missed(t) => t.client_id(),
+      CallDiscardReason::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -78,26 +93,29 @@ impl RObject for CallDiscardReason {
 }
 
 impl CallDiscardReason {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallDiscardReason::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CallDiscardReason::_Default(_)) }
 
-  pub fn is_declined(&self) -> bool { if let CallDiscardReason::Declined(_) = self { true } else { false } }
-  pub fn is_disconnected(&self) -> bool { if let CallDiscardReason::Disconnected(_) = self { true } else { false } }
-  pub fn is_empty(&self) -> bool { if let CallDiscardReason::Empty(_) = self { true } else { false } }
-  pub fn is_hung_up(&self) -> bool { if let CallDiscardReason::HungUp(_) = self { true } else { false } }
-  pub fn is_missed(&self) -> bool { if let CallDiscardReason::Missed(_) = self { true } else { false } }
+  pub fn is_declined(&self) -> bool { matches!(self, CallDiscardReason::Declined(_)) }
+  pub fn is_disconnected(&self) -> bool { matches!(self, CallDiscardReason::Disconnected(_)) }
+  pub fn is_empty(&self) -> bool { matches!(self, CallDiscardReason::Empty(_)) }
+  pub fn is_hung_up(&self) -> bool { matches!(self, CallDiscardReason::HungUp(_)) }
+  pub fn is_missed(&self) -> bool { matches!(self, CallDiscardReason::Missed(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CallDiscardReason::Unknown(_)) }
 
   pub fn on_declined<F: FnOnce(&CallDiscardReasonDeclined)>(&self, fnc: F) -> &Self { if let CallDiscardReason::Declined(t) = self { fnc(t) }; self }
   pub fn on_disconnected<F: FnOnce(&CallDiscardReasonDisconnected)>(&self, fnc: F) -> &Self { if let CallDiscardReason::Disconnected(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&CallDiscardReasonEmpty)>(&self, fnc: F) -> &Self { if let CallDiscardReason::Empty(t) = self { fnc(t) }; self }
   pub fn on_hung_up<F: FnOnce(&CallDiscardReasonHungUp)>(&self, fnc: F) -> &Self { if let CallDiscardReason::HungUp(t) = self { fnc(t) }; self }
   pub fn on_missed<F: FnOnce(&CallDiscardReasonMissed)>(&self, fnc: F) -> &Self { if let CallDiscardReason::Missed(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CallDiscardReason::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_declined(&self) -> Option<&CallDiscardReasonDeclined> { if let CallDiscardReason::Declined(t) = self { return Some(t) } None }
   pub fn as_disconnected(&self) -> Option<&CallDiscardReasonDisconnected> { if let CallDiscardReason::Disconnected(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&CallDiscardReasonEmpty> { if let CallDiscardReason::Empty(t) = self { return Some(t) } None }
   pub fn as_hung_up(&self) -> Option<&CallDiscardReasonHungUp> { if let CallDiscardReason::HungUp(t) = self { return Some(t) } None }
   pub fn as_missed(&self) -> Option<&CallDiscardReasonMissed> { if let CallDiscardReason::Missed(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CallDiscardReason::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -131,13 +149,17 @@ pub struct CallDiscardReasonDeclined {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallDiscardReasonDeclined {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonDeclined" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callDiscardReasonDeclined" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -147,14 +169,15 @@ impl TDCallDiscardReason for CallDiscardReasonDeclined {}
 
 
 impl CallDiscardReasonDeclined {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallDiscardReasonDeclinedBuilder {
-    let mut inner = CallDiscardReasonDeclined::default();
-    inner.td_name = "callDiscardReasonDeclined".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallDiscardReasonDeclined { td_name: "callDiscardReasonDeclined".to_string(), ..Default::default() };
     RTDCallDiscardReasonDeclinedBuilder { inner }
   }
 
+  /// `CallDiscardReasonDeclined` with the fields tdlib requires, the others are set by `CallDiscardReasonDeclined::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +188,12 @@ pub struct RTDCallDiscardReasonDeclinedBuilder {
 impl RTDCallDiscardReasonDeclinedBuilder {
   pub fn build(&self) -> CallDiscardReasonDeclined { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonDeclined> for CallDiscardReasonDeclined {
@@ -175,6 +204,10 @@ impl AsRef<CallDiscardReasonDeclined> for RTDCallDiscardReasonDeclinedBuilder {
   fn as_ref(&self) -> &CallDiscardReasonDeclined { &self.inner }
 }
 
+impl PartialEq for CallDiscardReasonDeclined {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -189,13 +222,17 @@ pub struct CallDiscardReasonDisconnected {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallDiscardReasonDisconnected {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonDisconnected" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callDiscardReasonDisconnected" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -205,14 +242,15 @@ impl TDCallDiscardReason for CallDiscardReasonDisconnected {}
 
 
 impl CallDiscardReasonDisconnected {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallDiscardReasonDisconnectedBuilder {
-    let mut inner = CallDiscardReasonDisconnected::default();
-    inner.td_name = "callDiscardReasonDisconnected".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallDiscardReasonDisconnected { td_name: "callDiscardReasonDisconnected".to_string(), ..Default::default() };
     RTDCallDiscardReasonDisconnectedBuilder { inner }
   }
 
+  /// `CallDiscardReasonDisconnected` with the fields tdlib requires, the others are set by `CallDiscardReasonDisconnected::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +261,12 @@ pub struct RTDCallDiscardReasonDisconnectedBuilder {
 impl RTDCallDiscardReasonDisconnectedBuilder {
   pub fn build(&self) -> CallDiscardReasonDisconnected { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonDisconnected> for CallDiscardReasonDisconnected {
@@ -233,6 +277,10 @@ impl AsRef<CallDiscardReasonDisconnected> for RTDCallDiscardReasonDisconnectedBu
   fn as_ref(&self) -> &CallDiscardReasonDisconnected { &self.inner }
 }
 
+impl PartialEq for CallDiscardReasonDisconnected {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -247,13 +295,17 @@ pub struct CallDiscardReasonEmpty {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallDiscardReasonEmpty {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonEmpty" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callDiscardReasonEmpty" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -263,14 +315,15 @@ impl TDCallDiscardReason for CallDiscardReasonEmpty {}
 
 
 impl CallDiscardReasonEmpty {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallDiscardReasonEmptyBuilder {
-    let mut inner = CallDiscardReasonEmpty::default();
-    inner.td_name = "callDiscardReasonEmpty".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallDiscardReasonEmpty { td_name: "callDiscardReasonEmpty".to_string(), ..Default::default() };
     RTDCallDiscardReasonEmptyBuilder { inner }
   }
 
+  /// `CallDiscardReasonEmpty` with the fields tdlib requires, the others are set by `CallDiscardReasonEmpty::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -281,6 +334,12 @@ pub struct RTDCallDiscardReasonEmptyBuilder {
 impl RTDCallDiscardReasonEmptyBuilder {
   pub fn build(&self) -> CallDiscardReasonEmpty { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonEmpty> for CallDiscardReasonEmpty {
@@ -291,6 +350,10 @@ impl AsRef<CallDiscardReasonEmpty> for RTDCallDiscardReasonEmptyBuilder {
   fn as_ref(&self) -> &CallDiscardReasonEmpty { &self.inner }
 }
 
+impl PartialEq for CallDiscardReasonEmpty {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -305,13 +368,17 @@ pub struct CallDiscardReasonHungUp {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallDiscardReasonHungUp {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonHungUp" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callDiscardReasonHungUp" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -321,14 +388,15 @@ impl TDCallDiscardReason for CallDiscardReasonHungUp {}
 
 
 impl CallDiscardReasonHungUp {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallDiscardReasonHungUpBuilder {
-    let mut inner = CallDiscardReasonHungUp::default();
-    inner.td_name = "callDiscardReasonHungUp".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallDiscardReasonHungUp { td_name: "callDiscardReasonHungUp".to_string(), ..Default::default() };
     RTDCallDiscardReasonHungUpBuilder { inner }
   }
 
+  /// `CallDiscardReasonHungUp` with the fields tdlib requires, the others are set by `CallDiscardReasonHungUp::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -339,6 +407,12 @@ pub struct RTDCallDiscardReasonHungUpBuilder {
 impl RTDCallDiscardReasonHungUpBuilder {
   pub fn build(&self) -> CallDiscardReasonHungUp { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonHungUp> for CallDiscardReasonHungUp {
@@ -349,6 +423,10 @@ impl AsRef<CallDiscardReasonHungUp> for RTDCallDiscardReasonHungUpBuilder {
   fn as_ref(&self) -> &CallDiscardReasonHungUp { &self.inner }
 }
 
+impl PartialEq for CallDiscardReasonHungUp {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -363,13 +441,17 @@ pub struct CallDiscardReasonMissed {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallDiscardReasonMissed {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callDiscardReasonMissed" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callDiscardReasonMissed" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -379,14 +461,15 @@ impl TDCallDiscardReason for CallDiscardReasonMissed {}
 
 
 impl CallDiscardReasonMissed {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallDiscardReasonMissedBuilder {
-    let mut inner = CallDiscardReasonMissed::default();
-    inner.td_name = "callDiscardReasonMissed".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallDiscardReasonMissed { td_name: "callDiscardReasonMissed".to_string(), ..Default::default() };
     RTDCallDiscardReasonMissedBuilder { inner }
   }
 
+  /// `CallDiscardReasonMissed` with the fields tdlib requires, the others are set by `CallDiscardReasonMissed::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -397,6 +480,12 @@ pub struct RTDCallDiscardReasonMissedBuilder {
 impl RTDCallDiscardReasonMissedBuilder {
   pub fn build(&self) -> CallDiscardReasonMissed { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallDiscardReasonMissed> for CallDiscardReasonMissed {
@@ -407,5 +496,9 @@ impl AsRef<CallDiscardReasonMissed> for RTDCallDiscardReasonMissedBuilder {
   fn as_ref(&self) -> &CallDiscardReasonMissed { &self.inner }
 }
 
+impl PartialEq for CallDiscardReasonMissed {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCallProblem: Debug + RObject {}
 
 /// Describes the exact type of a problem with a call
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CallProblem {
   #[doc(hidden)] _Default(()),
@@ -37,6 +36,8 @@ pub enum CallProblem {
   SilentLocal(CallProblemSilentLocal),
   /// The other side couldn't hear the user
   SilentRemote(CallProblemSilentRemote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -48,7 +49,7 @@ impl<'de> Deserialize<'de> for CallProblem {
   fn deserialize<D>(deserializer: D) -> Result<CallProblem, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CallProblem,
+      CallProblem, Unknown;
       (callProblemDistortedSpeech, DistortedSpeech);
       (callProblemDistortedVideo, DistortedVideo);
       (callProblemDropped, Dropped);
@@ -64,7 +65,7 @@ impl<'de> Deserialize<'de> for CallProblem {
 }
 
 impl RObject for CallProblem {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CallProblem::DistortedSpeech(t) => t.td_name(),
       CallProblem::DistortedVideo(t) => t.td_name(),
@@ -75,11 +76,12 @@ impl RObject for CallProblem {
       CallProblem::PixelatedVideo(t) => t.td_name(),
       CallProblem::SilentLocal(t) => t.td_name(),
       CallProblem::SilentRemote(t) => t.td_name(),
+      CallProblem::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CallProblem::DistortedSpeech(t) => t.extra(),
       CallProblem::DistortedVideo(t) => t.extra(),
@@ -90,6 +92,23 @@ impl RObject for CallProblem {
       CallProblem::PixelatedVideo(t) => t.extra(),
       CallProblem::SilentLocal(t) => t.extra(),
       CallProblem::SilentRemote(t) => t.extra(),
+      CallProblem::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CallProblem::DistortedSpeech(t) => t.client_id(),
+      CallProblem::DistortedVideo(t) => t.client_id(),
+      CallProblem::Dropped(t) => t.client_id(),
+      CallProblem::Echo(t) => t.client_id(),
+      CallProblem::Interruptions(t) => t.client_id(),
+      CallProblem::Noise(t) => t.client_id(),
+      CallProblem::PixelatedVideo(t) => t.client_id(),
+      CallProblem::SilentLocal(t) => t.client_id(),
+      CallProblem::SilentRemote(t) => t.client_id(),
+      CallProblem::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -98,18 +117,19 @@ impl RObject for CallProblem {
 }
 
 impl CallProblem {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallProblem::_Default(_) = self { true } else { false } }
-
-  pub fn is_distorted_speech(&self) -> bool { if let CallProblem::DistortedSpeech(_) = self { true } else { false } }
-  pub fn is_distorted_video(&self) -> bool { if let CallProblem::DistortedVideo(_) = self { true } else { false } }
-  pub fn is_dropped(&self) -> bool { if let CallProblem::Dropped(_) = self { true } else { false } }
-  pub fn is_echo(&self) -> bool { if let CallProblem::Echo(_) = self { true } else { false } }
-  pub fn is_interruptions(&self) -> bool { if let CallProblem::Interruptions(_) = self { true } else { false } }
-  pub fn is_noise(&self) -> bool { if let CallProblem::Noise(_) = self { true } else { false } }
-  pub fn is_pixelated_video(&self) -> bool { if let CallProblem::PixelatedVideo(_) = self { true } else { false } }
-  pub fn is_silent_local(&self) -> bool { if let CallProblem::SilentLocal(_) = self { true } else { false } }
-  pub fn is_silent_remote(&self) -> bool { if let CallProblem::SilentRemote(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CallProblem::_Default(_)) }
+
+  pub fn is_distorted_speech(&self) -> bool { matches!(self, CallProblem::DistortedSpeech(_)) }
+  pub fn is_distorted_video(&self) -> bool { matches!(self, CallProblem::DistortedVideo(_)) }
+  pub fn is_dropped(&self) -> bool { matches!(self, CallProblem::Dropped(_)) }
+  pub fn is_echo(&self) -> bool { matches!(self, CallProblem::Echo(_)) }
+  pub fn is_interruptions(&self) -> bool { matches!(self, CallProblem::Interruptions(_)) }
+  pub fn is_noise(&self) -> bool { matches!(self, CallProblem::Noise(_)) }
+  pub fn is_pixelated_video(&self) -> bool { matches!(self, CallProblem::PixelatedVideo(_)) }
+  pub fn is_silent_local(&self) -> bool { matches!(self, CallProblem::SilentLocal(_)) }
+  pub fn is_silent_remote(&self) -> bool { matches!(self, CallProblem::SilentRemote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CallProblem::Unknown(_)) }
 
   pub fn on_distorted_speech<F: FnOnce(&CallProblemDistortedSpeech)>(&self, fnc: F) -> &Self { if let CallProblem::DistortedSpeech(t) = self { fnc(t) }; self }
   pub fn on_distorted_video<F: FnOnce(&CallProblemDistortedVideo)>(&self, fnc: F) -> &Self { if let CallProblem::DistortedVideo(t) = self { fnc(t) }; self }
@@ -120,6 +140,7 @@ impl CallProblem {
   pub fn on_pixelated_video<F: FnOnce(&CallProblemPixelatedVideo)>(&self, fnc: F) -> &Self { if let CallProblem::PixelatedVideo(t) = self { fnc(t) }; self }
   pub fn on_silent_local<F: FnOnce(&CallProblemSilentLocal)>(&self, fnc: F) -> &Self { if let CallProblem::SilentLocal(t) = self { fnc(t) }; self }
   pub fn on_silent_remote<F: FnOnce(&CallProblemSilentRemote)>(&self, fnc: F) -> &Self { if let CallProblem::SilentRemote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CallProblem::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_distorted_speech(&self) -> Option<&CallProblemDistortedSpeech> { if let CallProblem::DistortedSpeech(t) = self { return Some(t) } None }
   pub fn as_distorted_video(&self) -> Option<&CallProblemDistortedVideo> { if let CallProblem::DistortedVideo(t) = self { return Some(t) } None }
@@ -130,6 +151,7 @@ impl CallProblem {
   pub fn as_pixelated_video(&self) -> Option<&CallProblemPixelatedVideo> { if let CallProblem::PixelatedVideo(t) = self { return Some(t) } None }
   pub fn as_silent_local(&self) -> Option<&CallProblemSilentLocal> { if let CallProblem::SilentLocal(t) = self { return Some(t) } None }
   pub fn as_silent_remote(&self) -> Option<&CallProblemSilentRemote> { if let CallProblem::SilentRemote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CallProblem::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -171,13 +193,17 @@ pub struct CallProblemDistortedSpeech {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemDistortedSpeech {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemDistortedSpeech" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemDistortedSpeech" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -187,14 +213,15 @@ impl TDCallProblem for CallProblemDistortedSpeech {}
 
 
 impl CallProblemDistortedSpeech {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemDistortedSpeechBuilder {
-    let mut inner = CallProblemDistortedSpeech::default();
-    inner.td_name = "callProblemDistortedSpeech".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemDistortedSpeech { td_name: "callProblemDistortedSpeech".to_string(), ..Default::default() };
     RTDCallProblemDistortedSpeechBuilder { inner }
   }
 
+  /// `CallProblemDistortedSpeech` with the fields tdlib requires, the others are set by `CallProblemDistortedSpeech::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -205,6 +232,12 @@ pub struct RTDCallProblemDistortedSpeechBuilder {
 impl RTDCallProblemDistortedSpeechBuilder {
   pub fn build(&self) -> CallProblemDistortedSpeech { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemDistortedSpeech> for CallProblemDistortedSpeech {
@@ -215,6 +248,10 @@ impl AsRef<CallProblemDistortedSpeech> for RTDCallProblemDistortedSpeechBuilder
   fn as_ref(&self) -> &CallProblemDistortedSpeech { &self.inner }
 }
 
+impl PartialEq for CallProblemDistortedSpeech {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -229,13 +266,17 @@ pub struct CallProblemDistortedVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemDistortedVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemDistortedVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemDistortedVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -245,14 +286,15 @@ impl TDCallProblem for CallProblemDistortedVideo {}
 
 
 impl CallProblemDistortedVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemDistortedVideoBuilder {
-    let mut inner = CallProblemDistortedVideo::default();
-    inner.td_name = "callProblemDistortedVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemDistortedVideo { td_name: "callProblemDistortedVideo".to_string(), ..Default::default() };
     RTDCallProblemDistortedVideoBuilder { inner }
   }
 
+  /// `CallProblemDistortedVideo` with the fields tdlib requires, the others are set by `CallProblemDistortedVideo::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -263,6 +305,12 @@ pub struct RTDCallProblemDistortedVideoBuilder {
 impl RTDCallProblemDistortedVideoBuilder {
   pub fn build(&self) -> CallProblemDistortedVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemDistortedVideo> for CallProblemDistortedVideo {
@@ -273,6 +321,10 @@ impl AsRef<CallProblemDistortedVideo> for RTDCallProblemDistortedVideoBuilder {
   fn as_ref(&self) -> &CallProblemDistortedVideo { &self.inner }
 }
 
+impl PartialEq for CallProblemDistortedVideo {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -287,13 +339,17 @@ pub struct CallProblemDropped {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemDropped {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemDropped" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemDropped" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -303,14 +359,15 @@ impl TDCallProblem for CallProblemDropped {}
 
 
 impl CallProblemDropped {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemDroppedBuilder {
-    let mut inner = CallProblemDropped::default();
-    inner.td_name = "callProblemDropped".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemDropped { td_name: "callProblemDropped".to_string(), ..Default::default() };
     RTDCallProblemDroppedBuilder { inner }
   }
 
+  /// `CallProblemDropped` with the fields tdlib requires, the others are set by `CallProblemDropped::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +378,12 @@ pub struct RTDCallProblemDroppedBuilder {
 impl RTDCallProblemDroppedBuilder {
   pub fn build(&self) -> CallProblemDropped { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemDropped> for CallProblemDropped {
@@ -331,6 +394,10 @@ impl AsRef<CallProblemDropped> for RTDCallProblemDroppedBuilder {
   fn as_ref(&self) -> &CallProblemDropped { &self.inner }
 }
 
+impl PartialEq for CallProblemDropped {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -345,13 +412,17 @@ pub struct CallProblemEcho {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemEcho {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemEcho" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemEcho" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -361,14 +432,15 @@ impl TDCallProblem for CallProblemEcho {}
 
 
 impl CallProblemEcho {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemEchoBuilder {
-    let mut inner = CallProblemEcho::default();
-    inner.td_name = "callProblemEcho".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemEcho { td_name: "callProblemEcho".to_string(), ..Default::default() };
     RTDCallProblemEchoBuilder { inner }
   }
 
+  /// `CallProblemEcho` with the fields tdlib requires, the others are set by `CallProblemEcho::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -379,6 +451,12 @@ pub struct RTDCallProblemEchoBuilder {
 impl RTDCallProblemEchoBuilder {
   pub fn build(&self) -> CallProblemEcho { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemEcho> for CallProblemEcho {
@@ -389,6 +467,10 @@ impl AsRef<CallProblemEcho> for RTDCallProblemEchoBuilder {
   fn as_ref(&self) -> &CallProblemEcho { &self.inner }
 }
 
+impl PartialEq for CallProblemEcho {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -403,13 +485,17 @@ pub struct CallProblemInterruptions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemInterruptions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemInterruptions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemInterruptions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -419,14 +505,15 @@ impl TDCallProblem for CallProblemInterruptions {}
 
 
 impl CallProblemInterruptions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemInterruptionsBuilder {
-    let mut inner = CallProblemInterruptions::default();
-    inner.td_name = "callProblemInterruptions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemInterruptions { td_name: "callProblemInterruptions".to_string(), ..Default::default() };
     RTDCallProblemInterruptionsBuilder { inner }
   }
 
+  /// `CallProblemInterruptions` with the fields tdlib requires, the others are set by `CallProblemInterruptions::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -437,6 +524,12 @@ pub struct RTDCallProblemInterruptionsBuilder {
 impl RTDCallProblemInterruptionsBuilder {
   pub fn build(&self) -> CallProblemInterruptions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemInterruptions> for CallProblemInterruptions {
@@ -447,6 +540,10 @@ impl AsRef<CallProblemInterruptions> for RTDCallProblemInterruptionsBuilder {
   fn as_ref(&self) -> &CallProblemInterruptions { &self.inner }
 }
 
+impl PartialEq for CallProblemInterruptions {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -461,13 +558,17 @@ pub struct CallProblemNoise {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemNoise {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemNoise" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemNoise" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -477,14 +578,15 @@ impl TDCallProblem for CallProblemNoise {}
 
 
 impl CallProblemNoise {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemNoiseBuilder {
-    let mut inner = CallProblemNoise::default();
-    inner.td_name = "callProblemNoise".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemNoise { td_name: "callProblemNoise".to_string(), ..Default::default() };
     RTDCallProblemNoiseBuilder { inner }
   }
 
+  /// `CallProblemNoise` with the fields tdlib requires, the others are set by `CallProblemNoise::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -495,6 +597,12 @@ pub struct RTDCallProblemNoiseBuilder {
 impl RTDCallProblemNoiseBuilder {
   pub fn build(&self) -> CallProblemNoise { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemNoise> for CallProblemNoise {
@@ -505,6 +613,10 @@ impl AsRef<CallProblemNoise> for RTDCallProblemNoiseBuilder {
   fn as_ref(&self) -> &CallProblemNoise { &self.inner }
 }
 
+impl PartialEq for CallProblemNoise {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -519,13 +631,17 @@ pub struct CallProblemPixelatedVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemPixelatedVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemPixelatedVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemPixelatedVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -535,14 +651,15 @@ impl TDCallProblem for CallProblemPixelatedVideo {}
 
 
 impl CallProblemPixelatedVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemPixelatedVideoBuilder {
-    let mut inner = CallProblemPixelatedVideo::default();
-    inner.td_name = "callProblemPixelatedVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemPixelatedVideo { td_name: "callProblemPixelatedVideo".to_string(), ..Default::default() };
     RTDCallProblemPixelatedVideoBuilder { inner }
   }
 
+  /// `CallProblemPixelatedVideo` with the fields tdlib requires, the others are set by `CallProblemPixelatedVideo::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -553,6 +670,12 @@ pub struct RTDCallProblemPixelatedVideoBuilder {
 impl RTDCallProblemPixelatedVideoBuilder {
   pub fn build(&self) -> CallProblemPixelatedVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemPixelatedVideo> for CallProblemPixelatedVideo {
@@ -563,6 +686,10 @@ impl AsRef<CallProblemPixelatedVideo> for RTDCallProblemPixelatedVideoBuilder {
   fn as_ref(&self) -> &CallProblemPixelatedVideo { &self.inner }
 }
 
+impl PartialEq for CallProblemPixelatedVideo {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -577,13 +704,17 @@ pub struct CallProblemSilentLocal {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemSilentLocal {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemSilentLocal" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemSilentLocal" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -593,14 +724,15 @@ impl TDCallProblem for CallProblemSilentLocal {}
 
 
 impl CallProblemSilentLocal {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemSilentLocalBuilder {
-    let mut inner = CallProblemSilentLocal::default();
-    inner.td_name = "callProblemSilentLocal".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemSilentLocal { td_name: "callProblemSilentLocal".to_string(), ..Default::default() };
     RTDCallProblemSilentLocalBuilder { inner }
   }
 
+  /// `CallProblemSilentLocal` with the fields tdlib requires, the others are set by `CallProblemSilentLocal::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -611,6 +743,12 @@ pub struct RTDCallProblemSilentLocalBuilder {
 impl RTDCallProblemSilentLocalBuilder {
   pub fn build(&self) -> CallProblemSilentLocal { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemSilentLocal> for CallProblemSilentLocal {
@@ -621,6 +759,10 @@ impl AsRef<CallProblemSilentLocal> for RTDCallProblemSilentLocalBuilder {
   fn as_ref(&self) -> &CallProblemSilentLocal { &self.inner }
 }
 
+impl PartialEq for CallProblemSilentLocal {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -635,13 +777,17 @@ pub struct CallProblemSilentRemote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallProblemSilentRemote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProblemSilentRemote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProblemSilentRemote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -651,14 +797,15 @@ impl TDCallProblem for CallProblemSilentRemote {}
 
 
 impl CallProblemSilentRemote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProblemSilentRemoteBuilder {
-    let mut inner = CallProblemSilentRemote::default();
-    inner.td_name = "callProblemSilentRemote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProblemSilentRemote { td_name: "callProblemSilentRemote".to_string(), ..Default::default() };
     RTDCallProblemSilentRemoteBuilder { inner }
   }
 
+  /// `CallProblemSilentRemote` with the fields tdlib requires, the others are set by `CallProblemSilentRemote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -669,6 +816,12 @@ pub struct RTDCallProblemSilentRemoteBuilder {
 impl RTDCallProblemSilentRemoteBuilder {
   pub fn build(&self) -> CallProblemSilentRemote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallProblemSilentRemote> for CallProblemSilentRemote {
@@ -679,5 +832,9 @@ impl AsRef<CallProblemSilentRemote> for RTDCallProblemSilentRemoteBuilder {
   fn as_ref(&self) -> &CallProblemSilentRemote { &self.inner }
 }
 
+impl PartialEq for CallProblemSilentRemote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct CallProtocol {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if UDP peer-to-peer connections are supported
   udp_p2p: bool,
   /// True, if connection through UDP reflectors is supported
@@ -29,22 +31,38 @@ pub struct CallProtocol {
 }
 
 impl RObject for CallProtocol {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callProtocol" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callProtocol" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl CallProtocol {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallProtocolBuilder {
-    let mut inner = CallProtocol::default();
-    inner.td_name = "callProtocol".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallProtocol { td_name: "callProtocol".to_string(), ..Default::default() };
     RTDCallProtocolBuilder { inner }
   }
 
+  /// `CallProtocol` with the fields tdlib requires, the others are set by `CallProtocol::builder()`
+  pub fn new(
+    udp_p2p: bool,
+    udp_reflector: bool,
+    min_layer: i64,
+    max_layer: i64,
+    library_versions: Vec<String>,
+  ) -> Self {
+    Self::builder()
+      .udp_p2p(udp_p2p)
+      .udp_reflector(udp_reflector)
+      .min_layer(min_layer)
+      .max_layer(max_layer)
+      .library_versions(library_versions)
+      .build()
+  }
+
   pub fn udp_p2p(&self) -> bool { self.udp_p2p }
 
   pub fn udp_reflector(&self) -> bool { self.udp_reflector }
@@ -65,6 +83,12 @@ pub struct RTDCallProtocolBuilder {
 impl RTDCallProtocolBuilder {
   pub fn build(&self) -> CallProtocol { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn udp_p2p(&mut self, udp_p2p: bool) -> &mut Self {
     self.inner.udp_p2p = udp_p2p;
@@ -105,5 +129,15 @@ impl AsRef<CallProtocol> for RTDCallProtocolBuilder {
   fn as_ref(&self) -> &CallProtocol { &self.inner }
 }
 
+impl PartialEq for CallProtocol {
+  fn eq(&self, other: &Self) -> bool {
+    self.udp_p2p == other.udp_p2p
+      && self.udp_reflector == other.udp_reflector
+      && self.min_layer == other.min_layer
+      && self.max_layer == other.max_layer
+      && self.library_versions == other.library_versions
+  }
+}
+
 
 
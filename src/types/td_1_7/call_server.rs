@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct CallServer {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Server identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Server IPv4 address
   ip_address: String,
   /// Server IPv6 address
@@ -29,23 +31,39 @@ pub struct CallServer {
 }
 
 impl RObject for CallServer {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callServer" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callServer" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl CallServer {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallServerBuilder {
-    let mut inner = CallServer::default();
-    inner.td_name = "callServer".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallServer { td_name: "callServer".to_string(), ..Default::default() };
     RTDCallServerBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `CallServer` with the fields tdlib requires, the others are set by `CallServer::builder()`
+  pub fn new(
+    id: i64,
+    ip_address: impl AsRef<str>,
+    ipv6_address: impl AsRef<str>,
+    port: i64,
+    type_: impl AsRef<CallServerType>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .ip_address(ip_address)
+      .ipv6_address(ipv6_address)
+      .port(port)
+      .type_(type_)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn ip_address(&self) -> &String { &self.ip_address }
 
@@ -65,8 +83,14 @@ pub struct RTDCallServerBuilder {
 impl RTDCallServerBuilder {
   pub fn build(&self) -> CallServer { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<CallServer> for RTDCallServerBuilder {
   fn as_ref(&self) -> &CallServer { &self.inner }
 }
 
+impl PartialEq for CallServer {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.ip_address == other.ip_address
+      && self.ipv6_address == other.ipv6_address
+      && self.port == other.port
+      && self.type_ == other.type_
+  }
+}
+
 
 
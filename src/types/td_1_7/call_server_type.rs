@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCallServerType: Debug + RObject {}
 
 /// Describes the type of a call server
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CallServerType {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum CallServerType {
   TelegramReflector(CallServerTypeTelegramReflector),
   /// A WebRTC server
   Webrtc(CallServerTypeWebrtc),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for CallServerType {
   fn deserialize<D>(deserializer: D) -> Result<CallServerType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CallServerType,
+      CallServerType, Unknown;
       (callServerTypeTelegramReflector, TelegramReflector);
       (callServerTypeWebrtc, Webrtc);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for CallServerType {
 }
 
 impl RObject for CallServerType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CallServerType::TelegramReflector(t) => t.td_name(),
       CallServerType::Webrtc(t) => t.td_name(),
+      CallServerType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CallServerType::TelegramReflector(t) => t.extra(),
       CallServerType::Webrtc(t) => t.extra(),
+      CallServerType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CallServerType::TelegramReflector(t) => t.client_id(),
+      CallServerType::Webrtc(t) => t.client_id(),
+      CallServerType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for CallServerType {
 }
 
 impl CallServerType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallServerType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CallServerType::_Default(_)) }
 
-  pub fn is_telegram_reflector(&self) -> bool { if let CallServerType::TelegramReflector(_) = self { true } else { false } }
-  pub fn is_webrtc(&self) -> bool { if let CallServerType::Webrtc(_) = self { true } else { false } }
+  pub fn is_telegram_reflector(&self) -> bool { matches!(self, CallServerType::TelegramReflector(_)) }
+  pub fn is_webrtc(&self) -> bool { matches!(self, CallServerType::Webrtc(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CallServerType::Unknown(_)) }
 
   pub fn on_telegram_reflector<F: FnOnce(&CallServerTypeTelegramReflector)>(&self, fnc: F) -> &Self { if let CallServerType::TelegramReflector(t) = self { fnc(t) }; self }
   pub fn on_webrtc<F: FnOnce(&CallServerTypeWebrtc)>(&self, fnc: F) -> &Self { if let CallServerType::Webrtc(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CallServerType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_telegram_reflector(&self) -> Option<&CallServerTypeTelegramReflector> { if let CallServerType::TelegramReflector(t) = self { return Some(t) } None }
   pub fn as_webrtc(&self) -> Option<&CallServerTypeWebrtc> { if let CallServerType::Webrtc(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CallServerType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,15 +116,19 @@ pub struct CallServerTypeTelegramReflector {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A peer tag to be used with the reflector
-  peer_tag: String,
+  peer_tag: Bytes,
   
 }
 
 impl RObject for CallServerTypeTelegramReflector {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callServerTypeTelegramReflector" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callServerTypeTelegramReflector" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -119,15 +138,16 @@ impl TDCallServerType for CallServerTypeTelegramReflector {}
 
 
 impl CallServerTypeTelegramReflector {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallServerTypeTelegramReflectorBuilder {
-    let mut inner = CallServerTypeTelegramReflector::default();
-    inner.td_name = "callServerTypeTelegramReflector".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallServerTypeTelegramReflector { td_name: "callServerTypeTelegramReflector".to_string(), ..Default::default() };
     RTDCallServerTypeTelegramReflectorBuilder { inner }
   }
 
-  pub fn peer_tag(&self) -> &String { &self.peer_tag }
+  /// `CallServerTypeTelegramReflector` with the fields tdlib requires, the others are set by `CallServerTypeTelegramReflector::builder()`
+  pub fn new(peer_tag: impl AsRef<Bytes>) -> Self { Self::builder().peer_tag(peer_tag).build() }
+
+  pub fn peer_tag(&self) -> &Bytes { &self.peer_tag }
 
 }
 
@@ -139,9 +159,15 @@ pub struct RTDCallServerTypeTelegramReflectorBuilder {
 impl RTDCallServerTypeTelegramReflectorBuilder {
   pub fn build(&self) -> CallServerTypeTelegramReflector { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn peer_tag<T: AsRef<str>>(&mut self, peer_tag: T) -> &mut Self {
-    self.inner.peer_tag = peer_tag.as_ref().to_string();
+  pub fn peer_tag<T: AsRef<Bytes>>(&mut self, peer_tag: T) -> &mut Self {
+    self.inner.peer_tag = peer_tag.as_ref().clone();
     self
   }
 
@@ -155,6 +181,12 @@ impl AsRef<CallServerTypeTelegramReflector> for RTDCallServerTypeTelegramReflect
   fn as_ref(&self) -> &CallServerTypeTelegramReflector { &self.inner }
 }
 
+impl PartialEq for CallServerTypeTelegramReflector {
+  fn eq(&self, other: &Self) -> bool {
+    self.peer_tag == other.peer_tag
+  }
+}
+
 
 
 
@@ -169,7 +201,10 @@ pub struct CallServerTypeWebrtc {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username to be used for authentication
   username: String,
   /// Authentication password
@@ -182,8 +217,9 @@ pub struct CallServerTypeWebrtc {
 }
 
 impl RObject for CallServerTypeWebrtc {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callServerTypeWebrtc" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callServerTypeWebrtc" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -193,14 +229,27 @@ impl TDCallServerType for CallServerTypeWebrtc {}
 
 
 impl CallServerTypeWebrtc {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallServerTypeWebrtcBuilder {
-    let mut inner = CallServerTypeWebrtc::default();
-    inner.td_name = "callServerTypeWebrtc".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallServerTypeWebrtc { td_name: "callServerTypeWebrtc".to_string(), ..Default::default() };
     RTDCallServerTypeWebrtcBuilder { inner }
   }
 
+  /// `CallServerTypeWebrtc` with the fields tdlib requires, the others are set by `CallServerTypeWebrtc::builder()`
+  pub fn new(
+    username: impl AsRef<str>,
+    password: impl AsRef<str>,
+    supports_turn: bool,
+    supports_stun: bool,
+  ) -> Self {
+    Self::builder()
+      .username(username)
+      .password(password)
+      .supports_turn(supports_turn)
+      .supports_stun(supports_stun)
+      .build()
+  }
+
   pub fn username(&self) -> &String { &self.username }
 
   pub fn password(&self) -> &String { &self.password }
@@ -219,6 +268,12 @@ pub struct RTDCallServerTypeWebrtcBuilder {
 impl RTDCallServerTypeWebrtcBuilder {
   pub fn build(&self) -> CallServerTypeWebrtc { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -253,5 +308,14 @@ impl AsRef<CallServerTypeWebrtc> for RTDCallServerTypeWebrtcBuilder {
   fn as_ref(&self) -> &CallServerTypeWebrtc { &self.inner }
 }
 
+impl PartialEq for CallServerTypeWebrtc {
+  fn eq(&self, other: &Self) -> bool {
+    self.username == other.username
+      && self.password == other.password
+      && self.supports_turn == other.supports_turn
+      && self.supports_stun == other.supports_stun
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCallState: Debug + RObject {}
 
 /// Describes the current call state
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CallState {
   #[doc(hidden)] _Default(()),
@@ -31,6 +30,8 @@ pub enum CallState {
   Pending(CallStatePending),
   /// The call is ready to use
   Ready(CallStateReady),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -42,7 +43,7 @@ impl<'de> Deserialize<'de> for CallState {
   fn deserialize<D>(deserializer: D) -> Result<CallState, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CallState,
+      CallState, Unknown;
       (callStateDiscarded, Discarded);
       (callStateError, Error);
       (callStateExchangingKeys, ExchangingKeys);
@@ -55,7 +56,7 @@ impl<'de> Deserialize<'de> for CallState {
 }
 
 impl RObject for CallState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CallState::Discarded(t) => t.td_name(),
       CallState::Error(t) => t.td_name(),
@@ -63,11 +64,12 @@ impl RObject for CallState {
       CallState::HangingUp(t) => t.td_name(),
       CallState::Pending(t) => t.td_name(),
       CallState::Ready(t) => t.td_name(),
+      CallState::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CallState::Discarded(t) => t.extra(),
       CallState::Error(t) => t.extra(),
@@ -75,6 +77,20 @@ impl RObject for CallState {
       CallState::HangingUp(t) => t.extra(),
       CallState::Pending(t) => t.extra(),
       CallState::Ready(t) => t.extra(),
+      CallState::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CallState::Discarded(t) => t.client_id(),
+      CallState::Error(t) => t.client_id(),
+      CallState::ExchangingKeys(t) => t.client_id(),
+      CallState::HangingUp(t) => t.client_id(),
+      CallState::Pending(t) => t.client_id(),
+      CallState::Ready(t) => t.client_id(),
+      CallState::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -83,15 +99,16 @@ impl RObject for CallState {
 }
 
 impl CallState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallState::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CallState::_Default(_)) }
 
-  pub fn is_discarded(&self) -> bool { if let CallState::Discarded(_) = self { true } else { false } }
-  pub fn is_error(&self) -> bool { if let CallState::Error(_) = self { true } else { false } }
-  pub fn is_exchanging_keys(&self) -> bool { if let CallState::ExchangingKeys(_) = self { true } else { false } }
-  pub fn is_hanging_up(&self) -> bool { if let CallState::HangingUp(_) = self { true } else { false } }
-  pub fn is_pending(&self) -> bool { if let CallState::Pending(_) = self { true } else { false } }
-  pub fn is_ready(&self) -> bool { if let CallState::Ready(_) = self { true } else { false } }
+  pub fn is_discarded(&self) -> bool { matches!(self, CallState::Discarded(_)) }
+  pub fn is_error(&self) -> bool { matches!(self, CallState::Error(_)) }
+  pub fn is_exchanging_keys(&self) -> bool { matches!(self, CallState::ExchangingKeys(_)) }
+  pub fn is_hanging_up(&self) -> bool { matches!(self, CallState::HangingUp(_)) }
+  pub fn is_pending(&self) -> bool { matches!(self, CallState::Pending(_)) }
+  pub fn is_ready(&self) -> bool { matches!(self, CallState::Ready(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CallState::Unknown(_)) }
 
   pub fn on_discarded<F: FnOnce(&CallStateDiscarded)>(&self, fnc: F) -> &Self { if let CallState::Discarded(t) = self { fnc(t) }; self }
   pub fn on_error<F: FnOnce(&CallStateError)>(&self, fnc: F) -> &Self { if let CallState::Error(t) = self { fnc(t) }; self }
@@ -99,6 +116,7 @@ impl CallState {
   pub fn on_hanging_up<F: FnOnce(&CallStateHangingUp)>(&self, fnc: F) -> &Self { if let CallState::HangingUp(t) = self { fnc(t) }; self }
   pub fn on_pending<F: FnOnce(&CallStatePending)>(&self, fnc: F) -> &Self { if let CallState::Pending(t) = self { fnc(t) }; self }
   pub fn on_ready<F: FnOnce(&CallStateReady)>(&self, fnc: F) -> &Self { if let CallState::Ready(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CallState::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_discarded(&self) -> Option<&CallStateDiscarded> { if let CallState::Discarded(t) = self { return Some(t) } None }
   pub fn as_error(&self) -> Option<&CallStateError> { if let CallState::Error(t) = self { return Some(t) } None }
@@ -106,6 +124,7 @@ impl CallState {
   pub fn as_hanging_up(&self) -> Option<&CallStateHangingUp> { if let CallState::HangingUp(t) = self { return Some(t) } None }
   pub fn as_pending(&self) -> Option<&CallStatePending> { if let CallState::Pending(t) = self { return Some(t) } None }
   pub fn as_ready(&self) -> Option<&CallStateReady> { if let CallState::Ready(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CallState::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -141,7 +160,10 @@ pub struct CallStateDiscarded {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The reason, why the call has ended
   reason: CallDiscardReason,
   /// True, if the call rating must be sent to the server
@@ -152,8 +174,9 @@ pub struct CallStateDiscarded {
 }
 
 impl RObject for CallStateDiscarded {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateDiscarded" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callStateDiscarded" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -163,14 +186,15 @@ impl TDCallState for CallStateDiscarded {}
 
 
 impl CallStateDiscarded {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallStateDiscardedBuilder {
-    let mut inner = CallStateDiscarded::default();
-    inner.td_name = "callStateDiscarded".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallStateDiscarded { td_name: "callStateDiscarded".to_string(), ..Default::default() };
     RTDCallStateDiscardedBuilder { inner }
   }
 
+  /// `CallStateDiscarded` with the fields tdlib requires, the others are set by `CallStateDiscarded::builder()`
+  pub fn new(reason: impl AsRef<CallDiscardReason>, need_rating: bool, need_debug_information: bool) -> Self { Self::builder().reason(reason).need_rating(need_rating).need_debug_information(need_debug_information).build() }
+
   pub fn reason(&self) -> &CallDiscardReason { &self.reason }
 
   pub fn need_rating(&self) -> bool { self.need_rating }
@@ -187,6 +211,12 @@ pub struct RTDCallStateDiscardedBuilder {
 impl RTDCallStateDiscardedBuilder {
   pub fn build(&self) -> CallStateDiscarded { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn reason<T: AsRef<CallDiscardReason>>(&mut self, reason: T) -> &mut Self {
     self.inner.reason = reason.as_ref().clone();
@@ -215,6 +245,14 @@ impl AsRef<CallStateDiscarded> for RTDCallStateDiscardedBuilder {
   fn as_ref(&self) -> &CallStateDiscarded { &self.inner }
 }
 
+impl PartialEq for CallStateDiscarded {
+  fn eq(&self, other: &Self) -> bool {
+    self.reason == other.reason
+      && self.need_rating == other.need_rating
+      && self.need_debug_information == other.need_debug_information
+  }
+}
+
 
 
 
@@ -229,15 +267,19 @@ pub struct CallStateError {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Error. An error with the code 4005000 will be returned if an outgoing call is missed because of an expired timeout
   error: Error,
   
 }
 
 impl RObject for CallStateError {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateError" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callStateError" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -247,14 +289,15 @@ impl TDCallState for CallStateError {}
 
 
 impl CallStateError {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallStateErrorBuilder {
-    let mut inner = CallStateError::default();
-    inner.td_name = "callStateError".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallStateError { td_name: "callStateError".to_string(), ..Default::default() };
     RTDCallStateErrorBuilder { inner }
   }
 
+  /// `CallStateError` with the fields tdlib requires, the others are set by `CallStateError::builder()`
+  pub fn new(error: impl AsRef<Error>) -> Self { Self::builder().error(error).build() }
+
   pub fn error(&self) -> &Error { &self.error }
 
 }
@@ -267,6 +310,12 @@ pub struct RTDCallStateErrorBuilder {
 impl RTDCallStateErrorBuilder {
   pub fn build(&self) -> CallStateError { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
     self.inner.error = error.as_ref().clone();
@@ -283,6 +332,12 @@ impl AsRef<CallStateError> for RTDCallStateErrorBuilder {
   fn as_ref(&self) -> &CallStateError { &self.inner }
 }
 
+impl PartialEq for CallStateError {
+  fn eq(&self, other: &Self) -> bool {
+    self.error == other.error
+  }
+}
+
 
 
 
@@ -297,13 +352,17 @@ pub struct CallStateExchangingKeys {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallStateExchangingKeys {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateExchangingKeys" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callStateExchangingKeys" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -313,14 +372,15 @@ impl TDCallState for CallStateExchangingKeys {}
 
 
 impl CallStateExchangingKeys {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallStateExchangingKeysBuilder {
-    let mut inner = CallStateExchangingKeys::default();
-    inner.td_name = "callStateExchangingKeys".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallStateExchangingKeys { td_name: "callStateExchangingKeys".to_string(), ..Default::default() };
     RTDCallStateExchangingKeysBuilder { inner }
   }
 
+  /// `CallStateExchangingKeys` with the fields tdlib requires, the others are set by `CallStateExchangingKeys::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -331,6 +391,12 @@ pub struct RTDCallStateExchangingKeysBuilder {
 impl RTDCallStateExchangingKeysBuilder {
   pub fn build(&self) -> CallStateExchangingKeys { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallStateExchangingKeys> for CallStateExchangingKeys {
@@ -341,6 +407,10 @@ impl AsRef<CallStateExchangingKeys> for RTDCallStateExchangingKeysBuilder {
   fn as_ref(&self) -> &CallStateExchangingKeys { &self.inner }
 }
 
+impl PartialEq for CallStateExchangingKeys {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -355,13 +425,17 @@ pub struct CallStateHangingUp {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CallStateHangingUp {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateHangingUp" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callStateHangingUp" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -371,14 +445,15 @@ impl TDCallState for CallStateHangingUp {}
 
 
 impl CallStateHangingUp {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallStateHangingUpBuilder {
-    let mut inner = CallStateHangingUp::default();
-    inner.td_name = "callStateHangingUp".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallStateHangingUp { td_name: "callStateHangingUp".to_string(), ..Default::default() };
     RTDCallStateHangingUpBuilder { inner }
   }
 
+  /// `CallStateHangingUp` with the fields tdlib requires, the others are set by `CallStateHangingUp::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -389,6 +464,12 @@ pub struct RTDCallStateHangingUpBuilder {
 impl RTDCallStateHangingUpBuilder {
   pub fn build(&self) -> CallStateHangingUp { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CallStateHangingUp> for CallStateHangingUp {
@@ -399,6 +480,10 @@ impl AsRef<CallStateHangingUp> for RTDCallStateHangingUpBuilder {
   fn as_ref(&self) -> &CallStateHangingUp { &self.inner }
 }
 
+impl PartialEq for CallStateHangingUp {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -413,7 +498,10 @@ pub struct CallStatePending {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the call has already been created by the server
   is_created: bool,
   /// True, if the call has already been received by the other party
@@ -422,8 +510,9 @@ pub struct CallStatePending {
 }
 
 impl RObject for CallStatePending {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callStatePending" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callStatePending" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -433,14 +522,15 @@ impl TDCallState for CallStatePending {}
 
 
 impl CallStatePending {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallStatePendingBuilder {
-    let mut inner = CallStatePending::default();
-    inner.td_name = "callStatePending".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallStatePending { td_name: "callStatePending".to_string(), ..Default::default() };
     RTDCallStatePendingBuilder { inner }
   }
 
+  /// `CallStatePending` with the fields tdlib requires, the others are set by `CallStatePending::builder()`
+  pub fn new(is_created: bool, is_received: bool) -> Self { Self::builder().is_created(is_created).is_received(is_received).build() }
+
   pub fn is_created(&self) -> bool { self.is_created }
 
   pub fn is_received(&self) -> bool { self.is_received }
@@ -455,6 +545,12 @@ pub struct RTDCallStatePendingBuilder {
 impl RTDCallStatePendingBuilder {
   pub fn build(&self) -> CallStatePending { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_created(&mut self, is_created: bool) -> &mut Self {
     self.inner.is_created = is_created;
@@ -477,6 +573,13 @@ impl AsRef<CallStatePending> for RTDCallStatePendingBuilder {
   fn as_ref(&self) -> &CallStatePending { &self.inner }
 }
 
+impl PartialEq for CallStatePending {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_created == other.is_created
+      && self.is_received == other.is_received
+  }
+}
+
 
 
 
@@ -491,7 +594,10 @@ pub struct CallStateReady {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Call protocols supported by the peer
   protocol: CallProtocol,
   /// List of available call servers
@@ -499,7 +605,7 @@ pub struct CallStateReady {
   /// A JSON-encoded call config
   config: String,
   /// Call encryption key
-  encryption_key: String,
+  encryption_key: Bytes,
   /// Encryption key emojis fingerprint
   emojis: Vec<String>,
   /// True, if peer-to-peer connection is allowed by users privacy settings
@@ -508,8 +614,9 @@ pub struct CallStateReady {
 }
 
 impl RObject for CallStateReady {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callStateReady" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callStateReady" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -519,21 +626,38 @@ impl TDCallState for CallStateReady {}
 
 
 impl CallStateReady {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallStateReadyBuilder {
-    let mut inner = CallStateReady::default();
-    inner.td_name = "callStateReady".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallStateReady { td_name: "callStateReady".to_string(), ..Default::default() };
     RTDCallStateReadyBuilder { inner }
   }
 
+  /// `CallStateReady` with the fields tdlib requires, the others are set by `CallStateReady::builder()`
+  pub fn new(
+    protocol: impl AsRef<CallProtocol>,
+    servers: Vec<CallServer>,
+    config: impl AsRef<str>,
+    encryption_key: impl AsRef<Bytes>,
+    emojis: Vec<String>,
+    allow_p2p: bool,
+  ) -> Self {
+    Self::builder()
+      .protocol(protocol)
+      .servers(servers)
+      .config(config)
+      .encryption_key(encryption_key)
+      .emojis(emojis)
+      .allow_p2p(allow_p2p)
+      .build()
+  }
+
   pub fn protocol(&self) -> &CallProtocol { &self.protocol }
 
   pub fn servers(&self) -> &Vec<CallServer> { &self.servers }
 
   pub fn config(&self) -> &String { &self.config }
 
-  pub fn encryption_key(&self) -> &String { &self.encryption_key }
+  pub fn encryption_key(&self) -> &Bytes { &self.encryption_key }
 
   pub fn emojis(&self) -> &Vec<String> { &self.emojis }
 
@@ -549,6 +673,12 @@ pub struct RTDCallStateReadyBuilder {
 impl RTDCallStateReadyBuilder {
   pub fn build(&self) -> CallStateReady { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn protocol<T: AsRef<CallProtocol>>(&mut self, protocol: T) -> &mut Self {
     self.inner.protocol = protocol.as_ref().clone();
@@ -568,8 +698,8 @@ impl RTDCallStateReadyBuilder {
   }
 
    
-  pub fn encryption_key<T: AsRef<str>>(&mut self, encryption_key: T) -> &mut Self {
-    self.inner.encryption_key = encryption_key.as_ref().to_string();
+  pub fn encryption_key<T: AsRef<Bytes>>(&mut self, encryption_key: T) -> &mut Self {
+    self.inner.encryption_key = encryption_key.as_ref().clone();
     self
   }
 
@@ -595,5 +725,16 @@ impl AsRef<CallStateReady> for RTDCallStateReadyBuilder {
   fn as_ref(&self) -> &CallStateReady { &self.inner }
 }
 
+impl PartialEq for CallStateReady {
+  fn eq(&self, other: &Self) -> bool {
+    self.protocol == other.protocol
+      && self.servers == other.servers
+      && self.config == other.config
+      && self.encryption_key == other.encryption_key
+      && self.emojis == other.emojis
+      && self.allow_p2p == other.allow_p2p
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct CallbackQueryAnswer {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text of the answer
   text: String,
   /// True, if an alert must be shown to the user instead of a toast notification
@@ -25,22 +27,24 @@ pub struct CallbackQueryAnswer {
 }
 
 impl RObject for CallbackQueryAnswer {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callbackQueryAnswer" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callbackQueryAnswer" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl CallbackQueryAnswer {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallbackQueryAnswerBuilder {
-    let mut inner = CallbackQueryAnswer::default();
-    inner.td_name = "callbackQueryAnswer".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallbackQueryAnswer { td_name: "callbackQueryAnswer".to_string(), ..Default::default() };
     RTDCallbackQueryAnswerBuilder { inner }
   }
 
+  /// `CallbackQueryAnswer` with the fields tdlib requires, the others are set by `CallbackQueryAnswer::builder()`
+  pub fn new(text: impl AsRef<str>, show_alert: bool, url: impl AsRef<str>) -> Self { Self::builder().text(text).show_alert(show_alert).url(url).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
   pub fn show_alert(&self) -> bool { self.show_alert }
@@ -57,6 +61,12 @@ pub struct RTDCallbackQueryAnswerBuilder {
 impl RTDCallbackQueryAnswerBuilder {
   pub fn build(&self) -> CallbackQueryAnswer { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -85,5 +95,13 @@ impl AsRef<CallbackQueryAnswer> for RTDCallbackQueryAnswerBuilder {
   fn as_ref(&self) -> &CallbackQueryAnswer { &self.inner }
 }
 
+impl PartialEq for CallbackQueryAnswer {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.show_alert == other.show_alert
+      && self.url == other.url
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCallbackQueryPayload: Debug + RObject {}
 
 /// Represents a payload of a callback query
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CallbackQueryPayload {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum CallbackQueryPayload {
   DataWithPassword(CallbackQueryPayloadDataWithPassword),
   /// The payload for a game callback button
   Game(CallbackQueryPayloadGame),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for CallbackQueryPayload {
   fn deserialize<D>(deserializer: D) -> Result<CallbackQueryPayload, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CallbackQueryPayload,
+      CallbackQueryPayload, Unknown;
       (callbackQueryPayloadData, Data);
       (callbackQueryPayloadDataWithPassword, DataWithPassword);
       (callbackQueryPayloadGame, Game);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for CallbackQueryPayload {
 }
 
 impl RObject for CallbackQueryPayload {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CallbackQueryPayload::Data(t) => t.td_name(),
       CallbackQueryPayload::DataWithPassword(t) => t.td_name(),
       CallbackQueryPayload::Game(t) => t.td_name(),
+      CallbackQueryPayload::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CallbackQueryPayload::Data(t) => t.extra(),
       CallbackQueryPayload::DataWithPassword(t) => t.extra(),
       CallbackQueryPayload::Game(t) => t.extra(),
+      CallbackQueryPayload::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CallbackQueryPayload::Data(t) => t.client_id(),
+      CallbackQueryPayload::DataWithPassword(t) => t.client_id(),
+      CallbackQueryPayload::Game(t) => t.client_id(),
+      CallbackQueryPayload::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for CallbackQueryPayload {
 }
 
 impl CallbackQueryPayload {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallbackQueryPayload::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CallbackQueryPayload::_Default(_)) }
 
-  pub fn is_data(&self) -> bool { if let CallbackQueryPayload::Data(_) = self { true } else { false } }
-  pub fn is_data_with_password(&self) -> bool { if let CallbackQueryPayload::DataWithPassword(_) = self { true } else { false } }
-  pub fn is_game(&self) -> bool { if let CallbackQueryPayload::Game(_) = self { true } else { false } }
+  pub fn is_data(&self) -> bool { matches!(self, CallbackQueryPayload::Data(_)) }
+  pub fn is_data_with_password(&self) -> bool { matches!(self, CallbackQueryPayload::DataWithPassword(_)) }
+  pub fn is_game(&self) -> bool { matches!(self, CallbackQueryPayload::Game(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CallbackQueryPayload::Unknown(_)) }
 
   pub fn on_data<F: FnOnce(&CallbackQueryPayloadData)>(&self, fnc: F) -> &Self { if let CallbackQueryPayload::Data(t) = self { fnc(t) }; self }
   pub fn on_data_with_password<F: FnOnce(&CallbackQueryPayloadDataWithPassword)>(&self, fnc: F) -> &Self { if let CallbackQueryPayload::DataWithPassword(t) = self { fnc(t) }; self }
   pub fn on_game<F: FnOnce(&CallbackQueryPayloadGame)>(&self, fnc: F) -> &Self { if let CallbackQueryPayload::Game(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CallbackQueryPayload::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_data(&self) -> Option<&CallbackQueryPayloadData> { if let CallbackQueryPayload::Data(t) = self { return Some(t) } None }
   pub fn as_data_with_password(&self) -> Option<&CallbackQueryPayloadDataWithPassword> { if let CallbackQueryPayload::DataWithPassword(t) = self { return Some(t) } None }
   pub fn as_game(&self) -> Option<&CallbackQueryPayloadGame> { if let CallbackQueryPayload::Game(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CallbackQueryPayload::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,15 +127,19 @@ pub struct CallbackQueryPayloadData {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Data that was attached to the callback button
-  data: String,
+  data: Bytes,
   
 }
 
 impl RObject for CallbackQueryPayloadData {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callbackQueryPayloadData" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callbackQueryPayloadData" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -129,15 +149,16 @@ impl TDCallbackQueryPayload for CallbackQueryPayloadData {}
 
 
 impl CallbackQueryPayloadData {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallbackQueryPayloadDataBuilder {
-    let mut inner = CallbackQueryPayloadData::default();
-    inner.td_name = "callbackQueryPayloadData".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallbackQueryPayloadData { td_name: "callbackQueryPayloadData".to_string(), ..Default::default() };
     RTDCallbackQueryPayloadDataBuilder { inner }
   }
 
-  pub fn data(&self) -> &String { &self.data }
+  /// `CallbackQueryPayloadData` with the fields tdlib requires, the others are set by `CallbackQueryPayloadData::builder()`
+  pub fn new(data: impl AsRef<Bytes>) -> Self { Self::builder().data(data).build() }
+
+  pub fn data(&self) -> &Bytes { &self.data }
 
 }
 
@@ -149,9 +170,15 @@ pub struct RTDCallbackQueryPayloadDataBuilder {
 impl RTDCallbackQueryPayloadDataBuilder {
   pub fn build(&self) -> CallbackQueryPayloadData { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
     self
   }
 
@@ -165,6 +192,12 @@ impl AsRef<CallbackQueryPayloadData> for RTDCallbackQueryPayloadDataBuilder {
   fn as_ref(&self) -> &CallbackQueryPayloadData { &self.inner }
 }
 
+impl PartialEq for CallbackQueryPayloadData {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+  }
+}
+
 
 
 
@@ -179,17 +212,21 @@ pub struct CallbackQueryPayloadDataWithPassword {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The password for the current user
   password: String,
   /// Data that was attached to the callback button
-  data: String,
+  data: Bytes,
   
 }
 
 impl RObject for CallbackQueryPayloadDataWithPassword {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callbackQueryPayloadDataWithPassword" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callbackQueryPayloadDataWithPassword" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -199,17 +236,18 @@ impl TDCallbackQueryPayload for CallbackQueryPayloadDataWithPassword {}
 
 
 impl CallbackQueryPayloadDataWithPassword {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallbackQueryPayloadDataWithPasswordBuilder {
-    let mut inner = CallbackQueryPayloadDataWithPassword::default();
-    inner.td_name = "callbackQueryPayloadDataWithPassword".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallbackQueryPayloadDataWithPassword { td_name: "callbackQueryPayloadDataWithPassword".to_string(), ..Default::default() };
     RTDCallbackQueryPayloadDataWithPasswordBuilder { inner }
   }
 
+  /// `CallbackQueryPayloadDataWithPassword` with the fields tdlib requires, the others are set by `CallbackQueryPayloadDataWithPassword::builder()`
+  pub fn new(password: impl AsRef<str>, data: impl AsRef<Bytes>) -> Self { Self::builder().password(password).data(data).build() }
+
   pub fn password(&self) -> &String { &self.password }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &Bytes { &self.data }
 
 }
 
@@ -221,6 +259,12 @@ pub struct RTDCallbackQueryPayloadDataWithPasswordBuilder {
 impl RTDCallbackQueryPayloadDataWithPasswordBuilder {
   pub fn build(&self) -> CallbackQueryPayloadDataWithPassword { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
@@ -228,8 +272,8 @@ impl RTDCallbackQueryPayloadDataWithPasswordBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
     self
   }
 
@@ -243,6 +287,13 @@ impl AsRef<CallbackQueryPayloadDataWithPassword> for RTDCallbackQueryPayloadData
   fn as_ref(&self) -> &CallbackQueryPayloadDataWithPassword { &self.inner }
 }
 
+impl PartialEq for CallbackQueryPayloadDataWithPassword {
+  fn eq(&self, other: &Self) -> bool {
+    self.password == other.password
+      && self.data == other.data
+  }
+}
+
 
 
 
@@ -257,15 +308,19 @@ pub struct CallbackQueryPayloadGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A short name of the game that was attached to the callback button
   game_short_name: String,
   
 }
 
 impl RObject for CallbackQueryPayloadGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "callbackQueryPayloadGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "callbackQueryPayloadGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -275,14 +330,15 @@ impl TDCallbackQueryPayload for CallbackQueryPayloadGame {}
 
 
 impl CallbackQueryPayloadGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCallbackQueryPayloadGameBuilder {
-    let mut inner = CallbackQueryPayloadGame::default();
-    inner.td_name = "callbackQueryPayloadGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CallbackQueryPayloadGame { td_name: "callbackQueryPayloadGame".to_string(), ..Default::default() };
     RTDCallbackQueryPayloadGameBuilder { inner }
   }
 
+  /// `CallbackQueryPayloadGame` with the fields tdlib requires, the others are set by `CallbackQueryPayloadGame::builder()`
+  pub fn new(game_short_name: impl AsRef<str>) -> Self { Self::builder().game_short_name(game_short_name).build() }
+
   pub fn game_short_name(&self) -> &String { &self.game_short_name }
 
 }
@@ -295,6 +351,12 @@ pub struct RTDCallbackQueryPayloadGameBuilder {
 impl RTDCallbackQueryPayloadGameBuilder {
   pub fn build(&self) -> CallbackQueryPayloadGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
     self.inner.game_short_name = game_short_name.as_ref().to_string();
@@ -311,5 +373,11 @@ impl AsRef<CallbackQueryPayloadGame> for RTDCallbackQueryPayloadGameBuilder {
   fn as_ref(&self) -> &CallbackQueryPayloadGame { &self.inner }
 }
 
+impl PartialEq for CallbackQueryPayloadGame {
+  fn eq(&self, other: &Self) -> bool {
+    self.game_short_name == other.game_short_name
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCanTransferOwnershipResult: Debug + RObject {}
 
 /// Represents result of checking whether the current session can be used to transfer a chat ownership to another user
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CanTransferOwnershipResult {
   #[doc(hidden)] _Default(()),
@@ -29,6 +28,8 @@ pub enum CanTransferOwnershipResult {
   PasswordTooFresh(CanTransferOwnershipResultPasswordTooFresh),
   /// The session was created recently, user needs to wait
   SessionTooFresh(CanTransferOwnershipResultSessionTooFresh),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -40,7 +41,7 @@ impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
   fn deserialize<D>(deserializer: D) -> Result<CanTransferOwnershipResult, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CanTransferOwnershipResult,
+      CanTransferOwnershipResult, Unknown;
       (canTransferOwnership, CanTransferOwnership);
       (canTransferOwnershipResultOk, Ok);
       (canTransferOwnershipResultPasswordNeeded, PasswordNeeded);
@@ -52,24 +53,38 @@ impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
 }
 
 impl RObject for CanTransferOwnershipResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CanTransferOwnershipResult::CanTransferOwnership(t) => t.td_name(),
       CanTransferOwnershipResult::Ok(t) => t.td_name(),
       CanTransferOwnershipResult::PasswordNeeded(t) => t.td_name(),
       CanTransferOwnershipResult::PasswordTooFresh(t) => t.td_name(),
       CanTransferOwnershipResult::SessionTooFresh(t) => t.td_name(),
+      CanTransferOwnershipResult::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CanTransferOwnershipResult::CanTransferOwnership(t) => t.extra(),
       CanTransferOwnershipResult::Ok(t) => t.extra(),
       CanTransferOwnershipResult::PasswordNeeded(t) => t.extra(),
       CanTransferOwnershipResult::PasswordTooFresh(t) => t.extra(),
       CanTransferOwnershipResult::SessionTooFresh(t) => t.extra(),
+      CanTransferOwnershipResult::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CanTransferOwnershipResult::CanTransferOwnership(t) => t.client_id(),
+      CanTransferOwnershipResult::Ok(t) => t.client_id(),
+      CanTransferOwnershipResult::PasswordNeeded(t) => t.client_id(),
+      CanTransferOwnershipResult::PasswordTooFresh(t) => t.client_id(),
+      CanTransferOwnershipResult::SessionTooFresh(t) => t.client_id(),
+      CanTransferOwnershipResult::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -78,26 +93,29 @@ impl RObject for CanTransferOwnershipResult {
 }
 
 impl CanTransferOwnershipResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CanTransferOwnershipResult::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CanTransferOwnershipResult::_Default(_)) }
 
-  pub fn is_can_transfer_ownership(&self) -> bool { if let CanTransferOwnershipResult::CanTransferOwnership(_) = self { true } else { false } }
-  pub fn is_ok(&self) -> bool { if let CanTransferOwnershipResult::Ok(_) = self { true } else { false } }
-  pub fn is_password_needed(&self) -> bool { if let CanTransferOwnershipResult::PasswordNeeded(_) = self { true } else { false } }
-  pub fn is_password_too_fresh(&self) -> bool { if let CanTransferOwnershipResult::PasswordTooFresh(_) = self { true } else { false } }
-  pub fn is_session_too_fresh(&self) -> bool { if let CanTransferOwnershipResult::SessionTooFresh(_) = self { true } else { false } }
+  pub fn is_can_transfer_ownership(&self) -> bool { matches!(self, CanTransferOwnershipResult::CanTransferOwnership(_)) }
+  pub fn is_ok(&self) -> bool { matches!(self, CanTransferOwnershipResult::Ok(_)) }
+  pub fn is_password_needed(&self) -> bool { matches!(self, CanTransferOwnershipResult::PasswordNeeded(_)) }
+  pub fn is_password_too_fresh(&self) -> bool { matches!(self, CanTransferOwnershipResult::PasswordTooFresh(_)) }
+  pub fn is_session_too_fresh(&self) -> bool { matches!(self, CanTransferOwnershipResult::SessionTooFresh(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CanTransferOwnershipResult::Unknown(_)) }
 
   pub fn on_can_transfer_ownership<F: FnOnce(&CanTransferOwnership)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::CanTransferOwnership(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&CanTransferOwnershipResultOk)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::Ok(t) = self { fnc(t) }; self }
   pub fn on_password_needed<F: FnOnce(&CanTransferOwnershipResultPasswordNeeded)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::PasswordNeeded(t) = self { fnc(t) }; self }
   pub fn on_password_too_fresh<F: FnOnce(&CanTransferOwnershipResultPasswordTooFresh)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::PasswordTooFresh(t) = self { fnc(t) }; self }
   pub fn on_session_too_fresh<F: FnOnce(&CanTransferOwnershipResultSessionTooFresh)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::SessionTooFresh(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_can_transfer_ownership(&self) -> Option<&CanTransferOwnership> { if let CanTransferOwnershipResult::CanTransferOwnership(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&CanTransferOwnershipResultOk> { if let CanTransferOwnershipResult::Ok(t) = self { return Some(t) } None }
   pub fn as_password_needed(&self) -> Option<&CanTransferOwnershipResultPasswordNeeded> { if let CanTransferOwnershipResult::PasswordNeeded(t) = self { return Some(t) } None }
   pub fn as_password_too_fresh(&self) -> Option<&CanTransferOwnershipResultPasswordTooFresh> { if let CanTransferOwnershipResult::PasswordTooFresh(t) = self { return Some(t) } None }
   pub fn as_session_too_fresh(&self) -> Option<&CanTransferOwnershipResultSessionTooFresh> { if let CanTransferOwnershipResult::SessionTooFresh(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CanTransferOwnershipResult::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -131,13 +149,17 @@ pub struct CanTransferOwnershipResultOk {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CanTransferOwnershipResultOk {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultOk" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "canTransferOwnershipResultOk" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -147,14 +169,15 @@ impl TDCanTransferOwnershipResult for CanTransferOwnershipResultOk {}
 
 
 impl CanTransferOwnershipResultOk {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCanTransferOwnershipResultOkBuilder {
-    let mut inner = CanTransferOwnershipResultOk::default();
-    inner.td_name = "canTransferOwnershipResultOk".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CanTransferOwnershipResultOk { td_name: "canTransferOwnershipResultOk".to_string(), ..Default::default() };
     RTDCanTransferOwnershipResultOkBuilder { inner }
   }
 
+  /// `CanTransferOwnershipResultOk` with the fields tdlib requires, the others are set by `CanTransferOwnershipResultOk::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +188,12 @@ pub struct RTDCanTransferOwnershipResultOkBuilder {
 impl RTDCanTransferOwnershipResultOkBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultOk { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CanTransferOwnershipResultOk> for CanTransferOwnershipResultOk {
@@ -175,6 +204,10 @@ impl AsRef<CanTransferOwnershipResultOk> for RTDCanTransferOwnershipResultOkBuil
   fn as_ref(&self) -> &CanTransferOwnershipResultOk { &self.inner }
 }
 
+impl PartialEq for CanTransferOwnershipResultOk {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -189,13 +222,17 @@ pub struct CanTransferOwnershipResultPasswordNeeded {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CanTransferOwnershipResultPasswordNeeded {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultPasswordNeeded" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "canTransferOwnershipResultPasswordNeeded" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -205,14 +242,15 @@ impl TDCanTransferOwnershipResult for CanTransferOwnershipResultPasswordNeeded {
 
 
 impl CanTransferOwnershipResultPasswordNeeded {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCanTransferOwnershipResultPasswordNeededBuilder {
-    let mut inner = CanTransferOwnershipResultPasswordNeeded::default();
-    inner.td_name = "canTransferOwnershipResultPasswordNeeded".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CanTransferOwnershipResultPasswordNeeded { td_name: "canTransferOwnershipResultPasswordNeeded".to_string(), ..Default::default() };
     RTDCanTransferOwnershipResultPasswordNeededBuilder { inner }
   }
 
+  /// `CanTransferOwnershipResultPasswordNeeded` with the fields tdlib requires, the others are set by `CanTransferOwnershipResultPasswordNeeded::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +261,12 @@ pub struct RTDCanTransferOwnershipResultPasswordNeededBuilder {
 impl RTDCanTransferOwnershipResultPasswordNeededBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultPasswordNeeded { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CanTransferOwnershipResultPasswordNeeded> for CanTransferOwnershipResultPasswordNeeded {
@@ -233,6 +277,10 @@ impl AsRef<CanTransferOwnershipResultPasswordNeeded> for RTDCanTransferOwnership
   fn as_ref(&self) -> &CanTransferOwnershipResultPasswordNeeded { &self.inner }
 }
 
+impl PartialEq for CanTransferOwnershipResultPasswordNeeded {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -247,15 +295,19 @@ pub struct CanTransferOwnershipResultPasswordTooFresh {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Time left before the session can be used to transfer ownership of a chat, in seconds
   retry_after: i64,
   
 }
 
 impl RObject for CanTransferOwnershipResultPasswordTooFresh {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultPasswordTooFresh" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "canTransferOwnershipResultPasswordTooFresh" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -265,14 +317,15 @@ impl TDCanTransferOwnershipResult for CanTransferOwnershipResultPasswordTooFresh
 
 
 impl CanTransferOwnershipResultPasswordTooFresh {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
-    let mut inner = CanTransferOwnershipResultPasswordTooFresh::default();
-    inner.td_name = "canTransferOwnershipResultPasswordTooFresh".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CanTransferOwnershipResultPasswordTooFresh { td_name: "canTransferOwnershipResultPasswordTooFresh".to_string(), ..Default::default() };
     RTDCanTransferOwnershipResultPasswordTooFreshBuilder { inner }
   }
 
+  /// `CanTransferOwnershipResultPasswordTooFresh` with the fields tdlib requires, the others are set by `CanTransferOwnershipResultPasswordTooFresh::builder()`
+  pub fn new(retry_after: i64) -> Self { Self::builder().retry_after(retry_after).build() }
+
   pub fn retry_after(&self) -> i64 { self.retry_after }
 
 }
@@ -285,6 +338,12 @@ pub struct RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
 impl RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultPasswordTooFresh { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
     self.inner.retry_after = retry_after;
@@ -301,6 +360,12 @@ impl AsRef<CanTransferOwnershipResultPasswordTooFresh> for RTDCanTransferOwnersh
   fn as_ref(&self) -> &CanTransferOwnershipResultPasswordTooFresh { &self.inner }
 }
 
+impl PartialEq for CanTransferOwnershipResultPasswordTooFresh {
+  fn eq(&self, other: &Self) -> bool {
+    self.retry_after == other.retry_after
+  }
+}
+
 
 
 
@@ -315,15 +380,19 @@ pub struct CanTransferOwnershipResultSessionTooFresh {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Time left before the session can be used to transfer ownership of a chat, in seconds
   retry_after: i64,
   
 }
 
 impl RObject for CanTransferOwnershipResultSessionTooFresh {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "canTransferOwnershipResultSessionTooFresh" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "canTransferOwnershipResultSessionTooFresh" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -333,14 +402,15 @@ impl TDCanTransferOwnershipResult for CanTransferOwnershipResultSessionTooFresh
 
 
 impl CanTransferOwnershipResultSessionTooFresh {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCanTransferOwnershipResultSessionTooFreshBuilder {
-    let mut inner = CanTransferOwnershipResultSessionTooFresh::default();
-    inner.td_name = "canTransferOwnershipResultSessionTooFresh".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CanTransferOwnershipResultSessionTooFresh { td_name: "canTransferOwnershipResultSessionTooFresh".to_string(), ..Default::default() };
     RTDCanTransferOwnershipResultSessionTooFreshBuilder { inner }
   }
 
+  /// `CanTransferOwnershipResultSessionTooFresh` with the fields tdlib requires, the others are set by `CanTransferOwnershipResultSessionTooFresh::builder()`
+  pub fn new(retry_after: i64) -> Self { Self::builder().retry_after(retry_after).build() }
+
   pub fn retry_after(&self) -> i64 { self.retry_after }
 
 }
@@ -353,6 +423,12 @@ pub struct RTDCanTransferOwnershipResultSessionTooFreshBuilder {
 impl RTDCanTransferOwnershipResultSessionTooFreshBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultSessionTooFresh { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
     self.inner.retry_after = retry_after;
@@ -369,5 +445,11 @@ impl AsRef<CanTransferOwnershipResultSessionTooFresh> for RTDCanTransferOwnershi
   fn as_ref(&self) -> &CanTransferOwnershipResultSessionTooFresh { &self.inner }
 }
 
+impl PartialEq for CanTransferOwnershipResultSessionTooFresh {
+  fn eq(&self, other: &Self) -> bool {
+    self.retry_after == other.retry_after
+  }
+}
+
 
 
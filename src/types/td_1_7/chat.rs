@@ -1,6 +1,6 @@
+
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -13,9 +13,12 @@ pub struct Chat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat unique identifier
-  id: i64,
+  id: ChatId,
   /// Type of the chat
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: ChatType,
   /// Chat title
@@ -45,9 +48,9 @@ pub struct Chat {
   /// Number of unread messages in the chat
   unread_count: i64,
   /// Identifier of the last read incoming message
-  last_read_inbox_message_id: i64,
+  last_read_inbox_message_id: MessageId,
   /// Identifier of the last read outgoing message
-  last_read_outbox_message_id: i64,
+  last_read_outbox_message_id: MessageId,
   /// Number of unread messages with a mention/reply in the chat
   unread_mention_count: i64,
   /// Notification settings for this chat
@@ -63,7 +66,7 @@ pub struct Chat {
   /// Information about pending join requests; may be null
   pending_join_requests: Option<ChatJoinRequestsInfo>,
   /// Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat
-  reply_markup_message_id: i64,
+  reply_markup_message_id: MessageId,
   /// A draft of a message in the chat; may be null
   draft_message: Option<DraftMessage>,
   /// Application-specific data associated with the chat. (For example, the chat scroll position or local chat notification settings can be stored here.) Persistent if the message database is used
@@ -72,23 +75,72 @@ pub struct Chat {
 }
 
 impl RObject for Chat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Chat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatBuilder {
-    let mut inner = Chat::default();
-    inner.td_name = "chat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Chat { td_name: "chat".to_string(), ..Default::default() };
     RTDChatBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `Chat` with the fields tdlib requires, the others are set by `Chat::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: ChatId,
+    type_: impl AsRef<ChatType>,
+    title: impl AsRef<str>,
+    permissions: impl AsRef<ChatPermissions>,
+    is_marked_as_unread: bool,
+    is_blocked: bool,
+    has_scheduled_messages: bool,
+    can_be_deleted_only_for_self: bool,
+    can_be_deleted_for_all_users: bool,
+    can_be_reported: bool,
+    default_disable_notification: bool,
+    unread_count: i64,
+    last_read_inbox_message_id: MessageId,
+    last_read_outbox_message_id: MessageId,
+    unread_mention_count: i64,
+    notification_settings: impl AsRef<ChatNotificationSettings>,
+    message_ttl_setting: i64,
+    theme_name: impl AsRef<str>,
+    video_chat: impl AsRef<VideoChat>,
+    reply_markup_message_id: MessageId,
+    client_data: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .type_(type_)
+      .title(title)
+      .permissions(permissions)
+      .is_marked_as_unread(is_marked_as_unread)
+      .is_blocked(is_blocked)
+      .has_scheduled_messages(has_scheduled_messages)
+      .can_be_deleted_only_for_self(can_be_deleted_only_for_self)
+      .can_be_deleted_for_all_users(can_be_deleted_for_all_users)
+      .can_be_reported(can_be_reported)
+      .default_disable_notification(default_disable_notification)
+      .unread_count(unread_count)
+      .last_read_inbox_message_id(last_read_inbox_message_id)
+      .last_read_outbox_message_id(last_read_outbox_message_id)
+      .unread_mention_count(unread_mention_count)
+      .notification_settings(notification_settings)
+      .message_ttl_setting(message_ttl_setting)
+      .theme_name(theme_name)
+      .video_chat(video_chat)
+      .reply_markup_message_id(reply_markup_message_id)
+      .client_data(client_data)
+      .build()
+  }
+
+  pub fn id(&self) -> ChatId { self.id }
 
   pub fn type_(&self) -> &ChatType { &self.type_ }
 
@@ -118,9 +170,9 @@ impl Chat {
 
   pub fn unread_count(&self) -> i64 { self.unread_count }
 
-  pub fn last_read_inbox_message_id(&self) -> i64 { self.last_read_inbox_message_id }
+  pub fn last_read_inbox_message_id(&self) -> MessageId { self.last_read_inbox_message_id }
 
-  pub fn last_read_outbox_message_id(&self) -> i64 { self.last_read_outbox_message_id }
+  pub fn last_read_outbox_message_id(&self) -> MessageId { self.last_read_outbox_message_id }
 
   pub fn unread_mention_count(&self) -> i64 { self.unread_mention_count }
 
@@ -136,7 +188,7 @@ impl Chat {
 
   pub fn pending_join_requests(&self) -> &Option<ChatJoinRequestsInfo> { &self.pending_join_requests }
 
-  pub fn reply_markup_message_id(&self) -> i64 { self.reply_markup_message_id }
+  pub fn reply_markup_message_id(&self) -> MessageId { self.reply_markup_message_id }
 
   pub fn draft_message(&self) -> &Option<DraftMessage> { &self.draft_message }
 
@@ -152,8 +204,14 @@ pub struct RTDChatBuilder {
 impl RTDChatBuilder {
   pub fn build(&self) -> Chat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: ChatId) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -243,13 +301,13 @@ impl RTDChatBuilder {
   }
 
    
-  pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
+  pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: MessageId) -> &mut Self {
     self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
     self
   }
 
    
-  pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
+  pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: MessageId) -> &mut Self {
     self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
     self
   }
@@ -284,7 +342,7 @@ impl RTDChatBuilder {
     self
   }
 
-
+   
   pub fn video_chat<T: AsRef<VideoChat>>(&mut self, video_chat: T) -> &mut Self {
     self.inner.video_chat = video_chat.as_ref().clone();
     self
@@ -297,7 +355,7 @@ impl RTDChatBuilder {
   }
 
    
-  pub fn reply_markup_message_id(&mut self, reply_markup_message_id: i64) -> &mut Self {
+  pub fn reply_markup_message_id(&mut self, reply_markup_message_id: MessageId) -> &mut Self {
     self.inner.reply_markup_message_id = reply_markup_message_id;
     self
   }
@@ -324,5 +382,37 @@ impl AsRef<Chat> for RTDChatBuilder {
   fn as_ref(&self) -> &Chat { &self.inner }
 }
 
+impl PartialEq for Chat {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.type_ == other.type_
+      && self.title == other.title
+      && self.photo == other.photo
+      && self.permissions == other.permissions
+      && self.last_message == other.last_message
+      && self.positions == other.positions
+      && self.is_marked_as_unread == other.is_marked_as_unread
+      && self.is_blocked == other.is_blocked
+      && self.has_scheduled_messages == other.has_scheduled_messages
+      && self.can_be_deleted_only_for_self == other.can_be_deleted_only_for_self
+      && self.can_be_deleted_for_all_users == other.can_be_deleted_for_all_users
+      && self.can_be_reported == other.can_be_reported
+      && self.default_disable_notification == other.default_disable_notification
+      && self.unread_count == other.unread_count
+      && self.last_read_inbox_message_id == other.last_read_inbox_message_id
+      && self.last_read_outbox_message_id == other.last_read_outbox_message_id
+      && self.unread_mention_count == other.unread_mention_count
+      && self.notification_settings == other.notification_settings
+      && self.message_ttl_setting == other.message_ttl_setting
+      && self.theme_name == other.theme_name
+      && self.action_bar == other.action_bar
+      && self.video_chat == other.video_chat
+      && self.pending_join_requests == other.pending_join_requests
+      && self.reply_markup_message_id == other.reply_markup_message_id
+      && self.draft_message == other.draft_message
+      && self.client_data == other.client_data
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatAction: Debug + RObject {}
 
 /// Describes the different types of activity in a chat
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatAction {
   #[doc(hidden)] _Default(()),
@@ -49,6 +48,8 @@ pub enum ChatAction {
   UploadingVoiceNote(ChatActionUploadingVoiceNote),
   /// The user is watching animations sent by the other party by clicking on an animated emoji
   WatchingAnimations(ChatActionWatchingAnimations),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -60,7 +61,7 @@ impl<'de> Deserialize<'de> for ChatAction {
   fn deserialize<D>(deserializer: D) -> Result<ChatAction, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatAction,
+      ChatAction, Unknown;
       (chatActionCancel, Cancel);
       (chatActionChoosingContact, ChoosingContact);
       (chatActionChoosingLocation, ChoosingLocation);
@@ -82,7 +83,7 @@ impl<'de> Deserialize<'de> for ChatAction {
 }
 
 impl RObject for ChatAction {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatAction::Cancel(t) => t.td_name(),
       ChatAction::ChoosingContact(t) => t.td_name(),
@@ -99,11 +100,12 @@ impl RObject for ChatAction {
       ChatAction::UploadingVideoNote(t) => t.td_name(),
       ChatAction::UploadingVoiceNote(t) => t.td_name(),
       ChatAction::WatchingAnimations(t) => t.td_name(),
+      ChatAction::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatAction::Cancel(t) => t.extra(),
       ChatAction::ChoosingContact(t) => t.extra(),
@@ -120,6 +122,29 @@ impl RObject for ChatAction {
       ChatAction::UploadingVideoNote(t) => t.extra(),
       ChatAction::UploadingVoiceNote(t) => t.extra(),
       ChatAction::WatchingAnimations(t) => t.extra(),
+      ChatAction::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatAction::Cancel(t) => t.client_id(),
+      ChatAction::ChoosingContact(t) => t.client_id(),
+      ChatAction::ChoosingLocation(t) => t.client_id(),
+      ChatAction::ChoosingSticker(t) => t.client_id(),
+      ChatAction::RecordingVideo(t) => t.client_id(),
+      ChatAction::RecordingVideoNote(t) => t.client_id(),
+      ChatAction::RecordingVoiceNote(t) => t.client_id(),
+      ChatAction::StartPlayingGame(t) => t.client_id(),
+      ChatAction::Typing(t) => t.client_id(),
+      ChatAction::UploadingDocument(t) => t.client_id(),
+      ChatAction::UploadingPhoto(t) => t.client_id(),
+      ChatAction::UploadingVideo(t) => t.client_id(),
+      ChatAction::UploadingVideoNote(t) => t.client_id(),
+      ChatAction::UploadingVoiceNote(t) => t.client_id(),
+      ChatAction::WatchingAnimations(t) => t.client_id(),
+      ChatAction::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -128,24 +153,25 @@ impl RObject for ChatAction {
 }
 
 impl ChatAction {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatAction::_Default(_) = self { true } else { false } }
-
-  pub fn is_cancel(&self) -> bool { if let ChatAction::Cancel(_) = self { true } else { false } }
-  pub fn is_choosing_contact(&self) -> bool { if let ChatAction::ChoosingContact(_) = self { true } else { false } }
-  pub fn is_choosing_location(&self) -> bool { if let ChatAction::ChoosingLocation(_) = self { true } else { false } }
-  pub fn is_choosing_sticker(&self) -> bool { if let ChatAction::ChoosingSticker(_) = self { true } else { false } }
-  pub fn is_recording_video(&self) -> bool { if let ChatAction::RecordingVideo(_) = self { true } else { false } }
-  pub fn is_recording_video_note(&self) -> bool { if let ChatAction::RecordingVideoNote(_) = self { true } else { false } }
-  pub fn is_recording_voice_note(&self) -> bool { if let ChatAction::RecordingVoiceNote(_) = self { true } else { false } }
-  pub fn is_start_playing_game(&self) -> bool { if let ChatAction::StartPlayingGame(_) = self { true } else { false } }
-  pub fn is_typing(&self) -> bool { if let ChatAction::Typing(_) = self { true } else { false } }
-  pub fn is_uploading_document(&self) -> bool { if let ChatAction::UploadingDocument(_) = self { true } else { false } }
-  pub fn is_uploading_photo(&self) -> bool { if let ChatAction::UploadingPhoto(_) = self { true } else { false } }
-  pub fn is_uploading_video(&self) -> bool { if let ChatAction::UploadingVideo(_) = self { true } else { false } }
-  pub fn is_uploading_video_note(&self) -> bool { if let ChatAction::UploadingVideoNote(_) = self { true } else { false } }
-  pub fn is_uploading_voice_note(&self) -> bool { if let ChatAction::UploadingVoiceNote(_) = self { true } else { false } }
-  pub fn is_watching_animations(&self) -> bool { if let ChatAction::WatchingAnimations(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatAction::_Default(_)) }
+
+  pub fn is_cancel(&self) -> bool { matches!(self, ChatAction::Cancel(_)) }
+  pub fn is_choosing_contact(&self) -> bool { matches!(self, ChatAction::ChoosingContact(_)) }
+  pub fn is_choosing_location(&self) -> bool { matches!(self, ChatAction::ChoosingLocation(_)) }
+  pub fn is_choosing_sticker(&self) -> bool { matches!(self, ChatAction::ChoosingSticker(_)) }
+  pub fn is_recording_video(&self) -> bool { matches!(self, ChatAction::RecordingVideo(_)) }
+  pub fn is_recording_video_note(&self) -> bool { matches!(self, ChatAction::RecordingVideoNote(_)) }
+  pub fn is_recording_voice_note(&self) -> bool { matches!(self, ChatAction::RecordingVoiceNote(_)) }
+  pub fn is_start_playing_game(&self) -> bool { matches!(self, ChatAction::StartPlayingGame(_)) }
+  pub fn is_typing(&self) -> bool { matches!(self, ChatAction::Typing(_)) }
+  pub fn is_uploading_document(&self) -> bool { matches!(self, ChatAction::UploadingDocument(_)) }
+  pub fn is_uploading_photo(&self) -> bool { matches!(self, ChatAction::UploadingPhoto(_)) }
+  pub fn is_uploading_video(&self) -> bool { matches!(self, ChatAction::UploadingVideo(_)) }
+  pub fn is_uploading_video_note(&self) -> bool { matches!(self, ChatAction::UploadingVideoNote(_)) }
+  pub fn is_uploading_voice_note(&self) -> bool { matches!(self, ChatAction::UploadingVoiceNote(_)) }
+  pub fn is_watching_animations(&self) -> bool { matches!(self, ChatAction::WatchingAnimations(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatAction::Unknown(_)) }
 
   pub fn on_cancel<F: FnOnce(&ChatActionCancel)>(&self, fnc: F) -> &Self { if let ChatAction::Cancel(t) = self { fnc(t) }; self }
   pub fn on_choosing_contact<F: FnOnce(&ChatActionChoosingContact)>(&self, fnc: F) -> &Self { if let ChatAction::ChoosingContact(t) = self { fnc(t) }; self }
@@ -162,6 +188,7 @@ impl ChatAction {
   pub fn on_uploading_video_note<F: FnOnce(&ChatActionUploadingVideoNote)>(&self, fnc: F) -> &Self { if let ChatAction::UploadingVideoNote(t) = self { fnc(t) }; self }
   pub fn on_uploading_voice_note<F: FnOnce(&ChatActionUploadingVoiceNote)>(&self, fnc: F) -> &Self { if let ChatAction::UploadingVoiceNote(t) = self { fnc(t) }; self }
   pub fn on_watching_animations<F: FnOnce(&ChatActionWatchingAnimations)>(&self, fnc: F) -> &Self { if let ChatAction::WatchingAnimations(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatAction::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_cancel(&self) -> Option<&ChatActionCancel> { if let ChatAction::Cancel(t) = self { return Some(t) } None }
   pub fn as_choosing_contact(&self) -> Option<&ChatActionChoosingContact> { if let ChatAction::ChoosingContact(t) = self { return Some(t) } None }
@@ -178,6 +205,7 @@ impl ChatAction {
   pub fn as_uploading_video_note(&self) -> Option<&ChatActionUploadingVideoNote> { if let ChatAction::UploadingVideoNote(t) = self { return Some(t) } None }
   pub fn as_uploading_voice_note(&self) -> Option<&ChatActionUploadingVoiceNote> { if let ChatAction::UploadingVoiceNote(t) = self { return Some(t) } None }
   pub fn as_watching_animations(&self) -> Option<&ChatActionWatchingAnimations> { if let ChatAction::WatchingAnimations(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatAction::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -231,13 +259,17 @@ pub struct ChatActionCancel {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionCancel {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionCancel" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionCancel" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -247,14 +279,15 @@ impl TDChatAction for ChatActionCancel {}
 
 
 impl ChatActionCancel {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionCancelBuilder {
-    let mut inner = ChatActionCancel::default();
-    inner.td_name = "chatActionCancel".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionCancel { td_name: "chatActionCancel".to_string(), ..Default::default() };
     RTDChatActionCancelBuilder { inner }
   }
 
+  /// `ChatActionCancel` with the fields tdlib requires, the others are set by `ChatActionCancel::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -265,6 +298,12 @@ pub struct RTDChatActionCancelBuilder {
 impl RTDChatActionCancelBuilder {
   pub fn build(&self) -> ChatActionCancel { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionCancel> for ChatActionCancel {
@@ -275,6 +314,10 @@ impl AsRef<ChatActionCancel> for RTDChatActionCancelBuilder {
   fn as_ref(&self) -> &ChatActionCancel { &self.inner }
 }
 
+impl PartialEq for ChatActionCancel {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -289,13 +332,17 @@ pub struct ChatActionChoosingContact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionChoosingContact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionChoosingContact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionChoosingContact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -305,14 +352,15 @@ impl TDChatAction for ChatActionChoosingContact {}
 
 
 impl ChatActionChoosingContact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionChoosingContactBuilder {
-    let mut inner = ChatActionChoosingContact::default();
-    inner.td_name = "chatActionChoosingContact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionChoosingContact { td_name: "chatActionChoosingContact".to_string(), ..Default::default() };
     RTDChatActionChoosingContactBuilder { inner }
   }
 
+  /// `ChatActionChoosingContact` with the fields tdlib requires, the others are set by `ChatActionChoosingContact::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -323,6 +371,12 @@ pub struct RTDChatActionChoosingContactBuilder {
 impl RTDChatActionChoosingContactBuilder {
   pub fn build(&self) -> ChatActionChoosingContact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionChoosingContact> for ChatActionChoosingContact {
@@ -333,6 +387,10 @@ impl AsRef<ChatActionChoosingContact> for RTDChatActionChoosingContactBuilder {
   fn as_ref(&self) -> &ChatActionChoosingContact { &self.inner }
 }
 
+impl PartialEq for ChatActionChoosingContact {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -347,13 +405,17 @@ pub struct ChatActionChoosingLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionChoosingLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionChoosingLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionChoosingLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -363,14 +425,15 @@ impl TDChatAction for ChatActionChoosingLocation {}
 
 
 impl ChatActionChoosingLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionChoosingLocationBuilder {
-    let mut inner = ChatActionChoosingLocation::default();
-    inner.td_name = "chatActionChoosingLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionChoosingLocation { td_name: "chatActionChoosingLocation".to_string(), ..Default::default() };
     RTDChatActionChoosingLocationBuilder { inner }
   }
 
+  /// `ChatActionChoosingLocation` with the fields tdlib requires, the others are set by `ChatActionChoosingLocation::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -381,6 +444,12 @@ pub struct RTDChatActionChoosingLocationBuilder {
 impl RTDChatActionChoosingLocationBuilder {
   pub fn build(&self) -> ChatActionChoosingLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionChoosingLocation> for ChatActionChoosingLocation {
@@ -391,6 +460,10 @@ impl AsRef<ChatActionChoosingLocation> for RTDChatActionChoosingLocationBuilder
   fn as_ref(&self) -> &ChatActionChoosingLocation { &self.inner }
 }
 
+impl PartialEq for ChatActionChoosingLocation {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -405,13 +478,17 @@ pub struct ChatActionChoosingSticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionChoosingSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionChoosingSticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionChoosingSticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -421,14 +498,15 @@ impl TDChatAction for ChatActionChoosingSticker {}
 
 
 impl ChatActionChoosingSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionChoosingStickerBuilder {
-    let mut inner = ChatActionChoosingSticker::default();
-    inner.td_name = "chatActionChoosingSticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionChoosingSticker { td_name: "chatActionChoosingSticker".to_string(), ..Default::default() };
     RTDChatActionChoosingStickerBuilder { inner }
   }
 
+  /// `ChatActionChoosingSticker` with the fields tdlib requires, the others are set by `ChatActionChoosingSticker::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -439,6 +517,12 @@ pub struct RTDChatActionChoosingStickerBuilder {
 impl RTDChatActionChoosingStickerBuilder {
   pub fn build(&self) -> ChatActionChoosingSticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionChoosingSticker> for ChatActionChoosingSticker {
@@ -449,6 +533,10 @@ impl AsRef<ChatActionChoosingSticker> for RTDChatActionChoosingStickerBuilder {
   fn as_ref(&self) -> &ChatActionChoosingSticker { &self.inner }
 }
 
+impl PartialEq for ChatActionChoosingSticker {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -463,13 +551,17 @@ pub struct ChatActionRecordingVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionRecordingVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionRecordingVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionRecordingVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -479,14 +571,15 @@ impl TDChatAction for ChatActionRecordingVideo {}
 
 
 impl ChatActionRecordingVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionRecordingVideoBuilder {
-    let mut inner = ChatActionRecordingVideo::default();
-    inner.td_name = "chatActionRecordingVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionRecordingVideo { td_name: "chatActionRecordingVideo".to_string(), ..Default::default() };
     RTDChatActionRecordingVideoBuilder { inner }
   }
 
+  /// `ChatActionRecordingVideo` with the fields tdlib requires, the others are set by `ChatActionRecordingVideo::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -497,6 +590,12 @@ pub struct RTDChatActionRecordingVideoBuilder {
 impl RTDChatActionRecordingVideoBuilder {
   pub fn build(&self) -> ChatActionRecordingVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionRecordingVideo> for ChatActionRecordingVideo {
@@ -507,6 +606,10 @@ impl AsRef<ChatActionRecordingVideo> for RTDChatActionRecordingVideoBuilder {
   fn as_ref(&self) -> &ChatActionRecordingVideo { &self.inner }
 }
 
+impl PartialEq for ChatActionRecordingVideo {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -521,13 +624,17 @@ pub struct ChatActionRecordingVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionRecordingVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionRecordingVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionRecordingVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -537,14 +644,15 @@ impl TDChatAction for ChatActionRecordingVideoNote {}
 
 
 impl ChatActionRecordingVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionRecordingVideoNoteBuilder {
-    let mut inner = ChatActionRecordingVideoNote::default();
-    inner.td_name = "chatActionRecordingVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionRecordingVideoNote { td_name: "chatActionRecordingVideoNote".to_string(), ..Default::default() };
     RTDChatActionRecordingVideoNoteBuilder { inner }
   }
 
+  /// `ChatActionRecordingVideoNote` with the fields tdlib requires, the others are set by `ChatActionRecordingVideoNote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -555,6 +663,12 @@ pub struct RTDChatActionRecordingVideoNoteBuilder {
 impl RTDChatActionRecordingVideoNoteBuilder {
   pub fn build(&self) -> ChatActionRecordingVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionRecordingVideoNote> for ChatActionRecordingVideoNote {
@@ -565,6 +679,10 @@ impl AsRef<ChatActionRecordingVideoNote> for RTDChatActionRecordingVideoNoteBuil
   fn as_ref(&self) -> &ChatActionRecordingVideoNote { &self.inner }
 }
 
+impl PartialEq for ChatActionRecordingVideoNote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -579,13 +697,17 @@ pub struct ChatActionRecordingVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionRecordingVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionRecordingVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionRecordingVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -595,14 +717,15 @@ impl TDChatAction for ChatActionRecordingVoiceNote {}
 
 
 impl ChatActionRecordingVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionRecordingVoiceNoteBuilder {
-    let mut inner = ChatActionRecordingVoiceNote::default();
-    inner.td_name = "chatActionRecordingVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionRecordingVoiceNote { td_name: "chatActionRecordingVoiceNote".to_string(), ..Default::default() };
     RTDChatActionRecordingVoiceNoteBuilder { inner }
   }
 
+  /// `ChatActionRecordingVoiceNote` with the fields tdlib requires, the others are set by `ChatActionRecordingVoiceNote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -613,6 +736,12 @@ pub struct RTDChatActionRecordingVoiceNoteBuilder {
 impl RTDChatActionRecordingVoiceNoteBuilder {
   pub fn build(&self) -> ChatActionRecordingVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionRecordingVoiceNote> for ChatActionRecordingVoiceNote {
@@ -623,6 +752,10 @@ impl AsRef<ChatActionRecordingVoiceNote> for RTDChatActionRecordingVoiceNoteBuil
   fn as_ref(&self) -> &ChatActionRecordingVoiceNote { &self.inner }
 }
 
+impl PartialEq for ChatActionRecordingVoiceNote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -637,13 +770,17 @@ pub struct ChatActionStartPlayingGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionStartPlayingGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionStartPlayingGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionStartPlayingGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -653,14 +790,15 @@ impl TDChatAction for ChatActionStartPlayingGame {}
 
 
 impl ChatActionStartPlayingGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionStartPlayingGameBuilder {
-    let mut inner = ChatActionStartPlayingGame::default();
-    inner.td_name = "chatActionStartPlayingGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionStartPlayingGame { td_name: "chatActionStartPlayingGame".to_string(), ..Default::default() };
     RTDChatActionStartPlayingGameBuilder { inner }
   }
 
+  /// `ChatActionStartPlayingGame` with the fields tdlib requires, the others are set by `ChatActionStartPlayingGame::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -671,6 +809,12 @@ pub struct RTDChatActionStartPlayingGameBuilder {
 impl RTDChatActionStartPlayingGameBuilder {
   pub fn build(&self) -> ChatActionStartPlayingGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionStartPlayingGame> for ChatActionStartPlayingGame {
@@ -681,6 +825,10 @@ impl AsRef<ChatActionStartPlayingGame> for RTDChatActionStartPlayingGameBuilder
   fn as_ref(&self) -> &ChatActionStartPlayingGame { &self.inner }
 }
 
+impl PartialEq for ChatActionStartPlayingGame {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -695,13 +843,17 @@ pub struct ChatActionTyping {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionTyping {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionTyping" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionTyping" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -711,14 +863,15 @@ impl TDChatAction for ChatActionTyping {}
 
 
 impl ChatActionTyping {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionTypingBuilder {
-    let mut inner = ChatActionTyping::default();
-    inner.td_name = "chatActionTyping".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionTyping { td_name: "chatActionTyping".to_string(), ..Default::default() };
     RTDChatActionTypingBuilder { inner }
   }
 
+  /// `ChatActionTyping` with the fields tdlib requires, the others are set by `ChatActionTyping::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -729,6 +882,12 @@ pub struct RTDChatActionTypingBuilder {
 impl RTDChatActionTypingBuilder {
   pub fn build(&self) -> ChatActionTyping { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionTyping> for ChatActionTyping {
@@ -739,6 +898,10 @@ impl AsRef<ChatActionTyping> for RTDChatActionTypingBuilder {
   fn as_ref(&self) -> &ChatActionTyping { &self.inner }
 }
 
+impl PartialEq for ChatActionTyping {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -753,15 +916,19 @@ pub struct ChatActionUploadingDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Upload progress, as a percentage
   progress: i64,
   
 }
 
 impl RObject for ChatActionUploadingDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionUploadingDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -771,14 +938,15 @@ impl TDChatAction for ChatActionUploadingDocument {}
 
 
 impl ChatActionUploadingDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionUploadingDocumentBuilder {
-    let mut inner = ChatActionUploadingDocument::default();
-    inner.td_name = "chatActionUploadingDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionUploadingDocument { td_name: "chatActionUploadingDocument".to_string(), ..Default::default() };
     RTDChatActionUploadingDocumentBuilder { inner }
   }
 
+  /// `ChatActionUploadingDocument` with the fields tdlib requires, the others are set by `ChatActionUploadingDocument::builder()`
+  pub fn new(progress: i64) -> Self { Self::builder().progress(progress).build() }
+
   pub fn progress(&self) -> i64 { self.progress }
 
 }
@@ -791,6 +959,12 @@ pub struct RTDChatActionUploadingDocumentBuilder {
 impl RTDChatActionUploadingDocumentBuilder {
   pub fn build(&self) -> ChatActionUploadingDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -807,6 +981,12 @@ impl AsRef<ChatActionUploadingDocument> for RTDChatActionUploadingDocumentBuilde
   fn as_ref(&self) -> &ChatActionUploadingDocument { &self.inner }
 }
 
+impl PartialEq for ChatActionUploadingDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.progress == other.progress
+  }
+}
+
 
 
 
@@ -821,15 +1001,19 @@ pub struct ChatActionUploadingPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Upload progress, as a percentage
   progress: i64,
   
 }
 
 impl RObject for ChatActionUploadingPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionUploadingPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -839,14 +1023,15 @@ impl TDChatAction for ChatActionUploadingPhoto {}
 
 
 impl ChatActionUploadingPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionUploadingPhotoBuilder {
-    let mut inner = ChatActionUploadingPhoto::default();
-    inner.td_name = "chatActionUploadingPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionUploadingPhoto { td_name: "chatActionUploadingPhoto".to_string(), ..Default::default() };
     RTDChatActionUploadingPhotoBuilder { inner }
   }
 
+  /// `ChatActionUploadingPhoto` with the fields tdlib requires, the others are set by `ChatActionUploadingPhoto::builder()`
+  pub fn new(progress: i64) -> Self { Self::builder().progress(progress).build() }
+
   pub fn progress(&self) -> i64 { self.progress }
 
 }
@@ -859,6 +1044,12 @@ pub struct RTDChatActionUploadingPhotoBuilder {
 impl RTDChatActionUploadingPhotoBuilder {
   pub fn build(&self) -> ChatActionUploadingPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -875,6 +1066,12 @@ impl AsRef<ChatActionUploadingPhoto> for RTDChatActionUploadingPhotoBuilder {
   fn as_ref(&self) -> &ChatActionUploadingPhoto { &self.inner }
 }
 
+impl PartialEq for ChatActionUploadingPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.progress == other.progress
+  }
+}
+
 
 
 
@@ -889,15 +1086,19 @@ pub struct ChatActionUploadingVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Upload progress, as a percentage
   progress: i64,
   
 }
 
 impl RObject for ChatActionUploadingVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionUploadingVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -907,14 +1108,15 @@ impl TDChatAction for ChatActionUploadingVideo {}
 
 
 impl ChatActionUploadingVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionUploadingVideoBuilder {
-    let mut inner = ChatActionUploadingVideo::default();
-    inner.td_name = "chatActionUploadingVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionUploadingVideo { td_name: "chatActionUploadingVideo".to_string(), ..Default::default() };
     RTDChatActionUploadingVideoBuilder { inner }
   }
 
+  /// `ChatActionUploadingVideo` with the fields tdlib requires, the others are set by `ChatActionUploadingVideo::builder()`
+  pub fn new(progress: i64) -> Self { Self::builder().progress(progress).build() }
+
   pub fn progress(&self) -> i64 { self.progress }
 
 }
@@ -927,6 +1129,12 @@ pub struct RTDChatActionUploadingVideoBuilder {
 impl RTDChatActionUploadingVideoBuilder {
   pub fn build(&self) -> ChatActionUploadingVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -943,6 +1151,12 @@ impl AsRef<ChatActionUploadingVideo> for RTDChatActionUploadingVideoBuilder {
   fn as_ref(&self) -> &ChatActionUploadingVideo { &self.inner }
 }
 
+impl PartialEq for ChatActionUploadingVideo {
+  fn eq(&self, other: &Self) -> bool {
+    self.progress == other.progress
+  }
+}
+
 
 
 
@@ -957,15 +1171,19 @@ pub struct ChatActionUploadingVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Upload progress, as a percentage
   progress: i64,
   
 }
 
 impl RObject for ChatActionUploadingVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionUploadingVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -975,14 +1193,15 @@ impl TDChatAction for ChatActionUploadingVideoNote {}
 
 
 impl ChatActionUploadingVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionUploadingVideoNoteBuilder {
-    let mut inner = ChatActionUploadingVideoNote::default();
-    inner.td_name = "chatActionUploadingVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionUploadingVideoNote { td_name: "chatActionUploadingVideoNote".to_string(), ..Default::default() };
     RTDChatActionUploadingVideoNoteBuilder { inner }
   }
 
+  /// `ChatActionUploadingVideoNote` with the fields tdlib requires, the others are set by `ChatActionUploadingVideoNote::builder()`
+  pub fn new(progress: i64) -> Self { Self::builder().progress(progress).build() }
+
   pub fn progress(&self) -> i64 { self.progress }
 
 }
@@ -995,6 +1214,12 @@ pub struct RTDChatActionUploadingVideoNoteBuilder {
 impl RTDChatActionUploadingVideoNoteBuilder {
   pub fn build(&self) -> ChatActionUploadingVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -1011,6 +1236,12 @@ impl AsRef<ChatActionUploadingVideoNote> for RTDChatActionUploadingVideoNoteBuil
   fn as_ref(&self) -> &ChatActionUploadingVideoNote { &self.inner }
 }
 
+impl PartialEq for ChatActionUploadingVideoNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.progress == other.progress
+  }
+}
+
 
 
 
@@ -1025,15 +1256,19 @@ pub struct ChatActionUploadingVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Upload progress, as a percentage
   progress: i64,
   
 }
 
 impl RObject for ChatActionUploadingVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionUploadingVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionUploadingVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1043,14 +1278,15 @@ impl TDChatAction for ChatActionUploadingVoiceNote {}
 
 
 impl ChatActionUploadingVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionUploadingVoiceNoteBuilder {
-    let mut inner = ChatActionUploadingVoiceNote::default();
-    inner.td_name = "chatActionUploadingVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionUploadingVoiceNote { td_name: "chatActionUploadingVoiceNote".to_string(), ..Default::default() };
     RTDChatActionUploadingVoiceNoteBuilder { inner }
   }
 
+  /// `ChatActionUploadingVoiceNote` with the fields tdlib requires, the others are set by `ChatActionUploadingVoiceNote::builder()`
+  pub fn new(progress: i64) -> Self { Self::builder().progress(progress).build() }
+
   pub fn progress(&self) -> i64 { self.progress }
 
 }
@@ -1063,6 +1299,12 @@ pub struct RTDChatActionUploadingVoiceNoteBuilder {
 impl RTDChatActionUploadingVoiceNoteBuilder {
   pub fn build(&self) -> ChatActionUploadingVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
@@ -1079,6 +1321,12 @@ impl AsRef<ChatActionUploadingVoiceNote> for RTDChatActionUploadingVoiceNoteBuil
   fn as_ref(&self) -> &ChatActionUploadingVoiceNote { &self.inner }
 }
 
+impl PartialEq for ChatActionUploadingVoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.progress == other.progress
+  }
+}
+
 
 
 
@@ -1093,15 +1341,19 @@ pub struct ChatActionWatchingAnimations {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The animated emoji
   emoji: String,
   
 }
 
 impl RObject for ChatActionWatchingAnimations {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionWatchingAnimations" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionWatchingAnimations" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1111,14 +1363,15 @@ impl TDChatAction for ChatActionWatchingAnimations {}
 
 
 impl ChatActionWatchingAnimations {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionWatchingAnimationsBuilder {
-    let mut inner = ChatActionWatchingAnimations::default();
-    inner.td_name = "chatActionWatchingAnimations".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionWatchingAnimations { td_name: "chatActionWatchingAnimations".to_string(), ..Default::default() };
     RTDChatActionWatchingAnimationsBuilder { inner }
   }
 
+  /// `ChatActionWatchingAnimations` with the fields tdlib requires, the others are set by `ChatActionWatchingAnimations::builder()`
+  pub fn new(emoji: impl AsRef<str>) -> Self { Self::builder().emoji(emoji).build() }
+
   pub fn emoji(&self) -> &String { &self.emoji }
 
 }
@@ -1131,6 +1384,12 @@ pub struct RTDChatActionWatchingAnimationsBuilder {
 impl RTDChatActionWatchingAnimationsBuilder {
   pub fn build(&self) -> ChatActionWatchingAnimations { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
@@ -1147,5 +1406,11 @@ impl AsRef<ChatActionWatchingAnimations> for RTDChatActionWatchingAnimationsBuil
   fn as_ref(&self) -> &ChatActionWatchingAnimations { &self.inner }
 }
 
+impl PartialEq for ChatActionWatchingAnimations {
+  fn eq(&self, other: &Self) -> bool {
+    self.emoji == other.emoji
+  }
+}
+
 
 
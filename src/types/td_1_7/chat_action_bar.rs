@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatActionBar: Debug + RObject {}
 
 /// Describes actions which must be possible to do through a chat action bar
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatActionBar {
   #[doc(hidden)] _Default(()),
@@ -31,6 +30,8 @@ pub enum ChatActionBar {
   ReportUnrelatedLocation(ChatActionBarReportUnrelatedLocation),
   /// The chat is a private or secret chat with a mutual contact and the user's phone number can be shared with the other user using the method sharePhoneNumber
   SharePhoneNumber(ChatActionBarSharePhoneNumber),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -42,7 +43,7 @@ impl<'de> Deserialize<'de> for ChatActionBar {
   fn deserialize<D>(deserializer: D) -> Result<ChatActionBar, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatActionBar,
+      ChatActionBar, Unknown;
       (chatActionBarAddContact, AddContact);
       (chatActionBarInviteMembers, InviteMembers);
       (chatActionBarReportAddBlock, ReportAddBlock);
@@ -55,7 +56,7 @@ impl<'de> Deserialize<'de> for ChatActionBar {
 }
 
 impl RObject for ChatActionBar {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatActionBar::AddContact(t) => t.td_name(),
       ChatActionBar::InviteMembers(t) => t.td_name(),
@@ -63,11 +64,12 @@ impl RObject for ChatActionBar {
       ChatActionBar::ReportSpam(t) => t.td_name(),
       ChatActionBar::ReportUnrelatedLocation(t) => t.td_name(),
       ChatActionBar::SharePhoneNumber(t) => t.td_name(),
+      ChatActionBar::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatActionBar::AddContact(t) => t.extra(),
       ChatActionBar::InviteMembers(t) => t.extra(),
@@ -75,6 +77,20 @@ impl RObject for ChatActionBar {
       ChatActionBar::ReportSpam(t) => t.extra(),
       ChatActionBar::ReportUnrelatedLocation(t) => t.extra(),
       ChatActionBar::SharePhoneNumber(t) => t.extra(),
+      ChatActionBar::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatActionBar::AddContact(t) => t.client_id(),
+      ChatActionBar::InviteMembers(t) => t.client_id(),
+      ChatActionBar::ReportAddBlock(t) => t.client_id(),
+      ChatActionBar::ReportSpam(t) => t.client_id(),
+      ChatActionBar::ReportUnrelatedLocation(t) => t.client_id(),
+      ChatActionBar::SharePhoneNumber(t) => t.client_id(),
+      ChatActionBar::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -83,15 +99,16 @@ impl RObject for ChatActionBar {
 }
 
 impl ChatActionBar {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatActionBar::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatActionBar::_Default(_)) }
 
-  pub fn is_add_contact(&self) -> bool { if let ChatActionBar::AddContact(_) = self { true } else { false } }
-  pub fn is_invite_members(&self) -> bool { if let ChatActionBar::InviteMembers(_) = self { true } else { false } }
-  pub fn is_report_add_block(&self) -> bool { if let ChatActionBar::ReportAddBlock(_) = self { true } else { false } }
-  pub fn is_report_spam(&self) -> bool { if let ChatActionBar::ReportSpam(_) = self { true } else { false } }
-  pub fn is_report_unrelated_location(&self) -> bool { if let ChatActionBar::ReportUnrelatedLocation(_) = self { true } else { false } }
-  pub fn is_share_phone_number(&self) -> bool { if let ChatActionBar::SharePhoneNumber(_) = self { true } else { false } }
+  pub fn is_add_contact(&self) -> bool { matches!(self, ChatActionBar::AddContact(_)) }
+  pub fn is_invite_members(&self) -> bool { matches!(self, ChatActionBar::InviteMembers(_)) }
+  pub fn is_report_add_block(&self) -> bool { matches!(self, ChatActionBar::ReportAddBlock(_)) }
+  pub fn is_report_spam(&self) -> bool { matches!(self, ChatActionBar::ReportSpam(_)) }
+  pub fn is_report_unrelated_location(&self) -> bool { matches!(self, ChatActionBar::ReportUnrelatedLocation(_)) }
+  pub fn is_share_phone_number(&self) -> bool { matches!(self, ChatActionBar::SharePhoneNumber(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatActionBar::Unknown(_)) }
 
   pub fn on_add_contact<F: FnOnce(&ChatActionBarAddContact)>(&self, fnc: F) -> &Self { if let ChatActionBar::AddContact(t) = self { fnc(t) }; self }
   pub fn on_invite_members<F: FnOnce(&ChatActionBarInviteMembers)>(&self, fnc: F) -> &Self { if let ChatActionBar::InviteMembers(t) = self { fnc(t) }; self }
@@ -99,6 +116,7 @@ impl ChatActionBar {
   pub fn on_report_spam<F: FnOnce(&ChatActionBarReportSpam)>(&self, fnc: F) -> &Self { if let ChatActionBar::ReportSpam(t) = self { fnc(t) }; self }
   pub fn on_report_unrelated_location<F: FnOnce(&ChatActionBarReportUnrelatedLocation)>(&self, fnc: F) -> &Self { if let ChatActionBar::ReportUnrelatedLocation(t) = self { fnc(t) }; self }
   pub fn on_share_phone_number<F: FnOnce(&ChatActionBarSharePhoneNumber)>(&self, fnc: F) -> &Self { if let ChatActionBar::SharePhoneNumber(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatActionBar::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_add_contact(&self) -> Option<&ChatActionBarAddContact> { if let ChatActionBar::AddContact(t) = self { return Some(t) } None }
   pub fn as_invite_members(&self) -> Option<&ChatActionBarInviteMembers> { if let ChatActionBar::InviteMembers(t) = self { return Some(t) } None }
@@ -106,6 +124,7 @@ impl ChatActionBar {
   pub fn as_report_spam(&self) -> Option<&ChatActionBarReportSpam> { if let ChatActionBar::ReportSpam(t) = self { return Some(t) } None }
   pub fn as_report_unrelated_location(&self) -> Option<&ChatActionBarReportUnrelatedLocation> { if let ChatActionBar::ReportUnrelatedLocation(t) = self { return Some(t) } None }
   pub fn as_share_phone_number(&self) -> Option<&ChatActionBarSharePhoneNumber> { if let ChatActionBar::SharePhoneNumber(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatActionBar::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -141,13 +160,17 @@ pub struct ChatActionBarAddContact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionBarAddContact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarAddContact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionBarAddContact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,14 +180,15 @@ impl TDChatActionBar for ChatActionBarAddContact {}
 
 
 impl ChatActionBarAddContact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionBarAddContactBuilder {
-    let mut inner = ChatActionBarAddContact::default();
-    inner.td_name = "chatActionBarAddContact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionBarAddContact { td_name: "chatActionBarAddContact".to_string(), ..Default::default() };
     RTDChatActionBarAddContactBuilder { inner }
   }
 
+  /// `ChatActionBarAddContact` with the fields tdlib requires, the others are set by `ChatActionBarAddContact::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +199,12 @@ pub struct RTDChatActionBarAddContactBuilder {
 impl RTDChatActionBarAddContactBuilder {
   pub fn build(&self) -> ChatActionBarAddContact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionBarAddContact> for ChatActionBarAddContact {
@@ -185,6 +215,10 @@ impl AsRef<ChatActionBarAddContact> for RTDChatActionBarAddContactBuilder {
   fn as_ref(&self) -> &ChatActionBarAddContact { &self.inner }
 }
 
+impl PartialEq for ChatActionBarAddContact {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -199,13 +233,17 @@ pub struct ChatActionBarInviteMembers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionBarInviteMembers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarInviteMembers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionBarInviteMembers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -215,14 +253,15 @@ impl TDChatActionBar for ChatActionBarInviteMembers {}
 
 
 impl ChatActionBarInviteMembers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionBarInviteMembersBuilder {
-    let mut inner = ChatActionBarInviteMembers::default();
-    inner.td_name = "chatActionBarInviteMembers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionBarInviteMembers { td_name: "chatActionBarInviteMembers".to_string(), ..Default::default() };
     RTDChatActionBarInviteMembersBuilder { inner }
   }
 
+  /// `ChatActionBarInviteMembers` with the fields tdlib requires, the others are set by `ChatActionBarInviteMembers::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +272,12 @@ pub struct RTDChatActionBarInviteMembersBuilder {
 impl RTDChatActionBarInviteMembersBuilder {
   pub fn build(&self) -> ChatActionBarInviteMembers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionBarInviteMembers> for ChatActionBarInviteMembers {
@@ -243,6 +288,10 @@ impl AsRef<ChatActionBarInviteMembers> for RTDChatActionBarInviteMembersBuilder
   fn as_ref(&self) -> &ChatActionBarInviteMembers { &self.inner }
 }
 
+impl PartialEq for ChatActionBarInviteMembers {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -257,7 +306,10 @@ pub struct ChatActionBarReportAddBlock {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If true, the chat was automatically archived and can be moved back to the main chat list using addChatToList simultaneously with setting chat notification settings to default using setChatNotificationSettings
   can_unarchive: bool,
   /// If non-negative, the current user was found by the peer through searchChatsNearby and this is the distance between the users
@@ -266,8 +318,9 @@ pub struct ChatActionBarReportAddBlock {
 }
 
 impl RObject for ChatActionBarReportAddBlock {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarReportAddBlock" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionBarReportAddBlock" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -277,14 +330,15 @@ impl TDChatActionBar for ChatActionBarReportAddBlock {}
 
 
 impl ChatActionBarReportAddBlock {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionBarReportAddBlockBuilder {
-    let mut inner = ChatActionBarReportAddBlock::default();
-    inner.td_name = "chatActionBarReportAddBlock".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionBarReportAddBlock { td_name: "chatActionBarReportAddBlock".to_string(), ..Default::default() };
     RTDChatActionBarReportAddBlockBuilder { inner }
   }
 
+  /// `ChatActionBarReportAddBlock` with the fields tdlib requires, the others are set by `ChatActionBarReportAddBlock::builder()`
+  pub fn new(can_unarchive: bool, distance: i64) -> Self { Self::builder().can_unarchive(can_unarchive).distance(distance).build() }
+
   pub fn can_unarchive(&self) -> bool { self.can_unarchive }
 
   pub fn distance(&self) -> i64 { self.distance }
@@ -299,6 +353,12 @@ pub struct RTDChatActionBarReportAddBlockBuilder {
 impl RTDChatActionBarReportAddBlockBuilder {
   pub fn build(&self) -> ChatActionBarReportAddBlock { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_unarchive(&mut self, can_unarchive: bool) -> &mut Self {
     self.inner.can_unarchive = can_unarchive;
@@ -321,6 +381,13 @@ impl AsRef<ChatActionBarReportAddBlock> for RTDChatActionBarReportAddBlockBuilde
   fn as_ref(&self) -> &ChatActionBarReportAddBlock { &self.inner }
 }
 
+impl PartialEq for ChatActionBarReportAddBlock {
+  fn eq(&self, other: &Self) -> bool {
+    self.can_unarchive == other.can_unarchive
+      && self.distance == other.distance
+  }
+}
+
 
 
 
@@ -335,15 +402,19 @@ pub struct ChatActionBarReportSpam {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If true, the chat was automatically archived and can be moved back to the main chat list using addChatToList simultaneously with setting chat notification settings to default using setChatNotificationSettings
   can_unarchive: bool,
   
 }
 
 impl RObject for ChatActionBarReportSpam {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarReportSpam" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionBarReportSpam" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -353,14 +424,15 @@ impl TDChatActionBar for ChatActionBarReportSpam {}
 
 
 impl ChatActionBarReportSpam {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionBarReportSpamBuilder {
-    let mut inner = ChatActionBarReportSpam::default();
-    inner.td_name = "chatActionBarReportSpam".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionBarReportSpam { td_name: "chatActionBarReportSpam".to_string(), ..Default::default() };
     RTDChatActionBarReportSpamBuilder { inner }
   }
 
+  /// `ChatActionBarReportSpam` with the fields tdlib requires, the others are set by `ChatActionBarReportSpam::builder()`
+  pub fn new(can_unarchive: bool) -> Self { Self::builder().can_unarchive(can_unarchive).build() }
+
   pub fn can_unarchive(&self) -> bool { self.can_unarchive }
 
 }
@@ -373,6 +445,12 @@ pub struct RTDChatActionBarReportSpamBuilder {
 impl RTDChatActionBarReportSpamBuilder {
   pub fn build(&self) -> ChatActionBarReportSpam { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_unarchive(&mut self, can_unarchive: bool) -> &mut Self {
     self.inner.can_unarchive = can_unarchive;
@@ -389,6 +467,12 @@ impl AsRef<ChatActionBarReportSpam> for RTDChatActionBarReportSpamBuilder {
   fn as_ref(&self) -> &ChatActionBarReportSpam { &self.inner }
 }
 
+impl PartialEq for ChatActionBarReportSpam {
+  fn eq(&self, other: &Self) -> bool {
+    self.can_unarchive == other.can_unarchive
+  }
+}
+
 
 
 
@@ -403,13 +487,17 @@ pub struct ChatActionBarReportUnrelatedLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionBarReportUnrelatedLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarReportUnrelatedLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionBarReportUnrelatedLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -419,14 +507,15 @@ impl TDChatActionBar for ChatActionBarReportUnrelatedLocation {}
 
 
 impl ChatActionBarReportUnrelatedLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionBarReportUnrelatedLocationBuilder {
-    let mut inner = ChatActionBarReportUnrelatedLocation::default();
-    inner.td_name = "chatActionBarReportUnrelatedLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionBarReportUnrelatedLocation { td_name: "chatActionBarReportUnrelatedLocation".to_string(), ..Default::default() };
     RTDChatActionBarReportUnrelatedLocationBuilder { inner }
   }
 
+  /// `ChatActionBarReportUnrelatedLocation` with the fields tdlib requires, the others are set by `ChatActionBarReportUnrelatedLocation::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -437,6 +526,12 @@ pub struct RTDChatActionBarReportUnrelatedLocationBuilder {
 impl RTDChatActionBarReportUnrelatedLocationBuilder {
   pub fn build(&self) -> ChatActionBarReportUnrelatedLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionBarReportUnrelatedLocation> for ChatActionBarReportUnrelatedLocation {
@@ -447,6 +542,10 @@ impl AsRef<ChatActionBarReportUnrelatedLocation> for RTDChatActionBarReportUnrel
   fn as_ref(&self) -> &ChatActionBarReportUnrelatedLocation { &self.inner }
 }
 
+impl PartialEq for ChatActionBarReportUnrelatedLocation {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -461,13 +560,17 @@ pub struct ChatActionBarSharePhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatActionBarSharePhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatActionBarSharePhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatActionBarSharePhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -477,14 +580,15 @@ impl TDChatActionBar for ChatActionBarSharePhoneNumber {}
 
 
 impl ChatActionBarSharePhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatActionBarSharePhoneNumberBuilder {
-    let mut inner = ChatActionBarSharePhoneNumber::default();
-    inner.td_name = "chatActionBarSharePhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatActionBarSharePhoneNumber { td_name: "chatActionBarSharePhoneNumber".to_string(), ..Default::default() };
     RTDChatActionBarSharePhoneNumberBuilder { inner }
   }
 
+  /// `ChatActionBarSharePhoneNumber` with the fields tdlib requires, the others are set by `ChatActionBarSharePhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -495,6 +599,12 @@ pub struct RTDChatActionBarSharePhoneNumberBuilder {
 impl RTDChatActionBarSharePhoneNumberBuilder {
   pub fn build(&self) -> ChatActionBarSharePhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatActionBarSharePhoneNumber> for ChatActionBarSharePhoneNumber {
@@ -505,5 +615,9 @@ impl AsRef<ChatActionBarSharePhoneNumber> for RTDChatActionBarSharePhoneNumberBu
   fn as_ref(&self) -> &ChatActionBarSharePhoneNumber { &self.inner }
 }
 
+impl PartialEq for ChatActionBarSharePhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatAdministrator {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier of the administrator
-  user_id: i64,
+  user_id: UserId,
   /// Custom title of the administrator
   custom_title: String,
   /// True, if the user is the owner of the chat
@@ -25,23 +27,25 @@ pub struct ChatAdministrator {
 }
 
 impl RObject for ChatAdministrator {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatAdministrator" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatAdministrator" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatAdministrator {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatAdministratorBuilder {
-    let mut inner = ChatAdministrator::default();
-    inner.td_name = "chatAdministrator".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatAdministrator { td_name: "chatAdministrator".to_string(), ..Default::default() };
     RTDChatAdministratorBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatAdministrator` with the fields tdlib requires, the others are set by `ChatAdministrator::builder()`
+  pub fn new(user_id: UserId, custom_title: impl AsRef<str>, is_owner: bool) -> Self { Self::builder().user_id(user_id).custom_title(custom_title).is_owner(is_owner).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn custom_title(&self) -> &String { &self.custom_title }
 
@@ -57,8 +61,14 @@ pub struct RTDChatAdministratorBuilder {
 impl RTDChatAdministratorBuilder {
   pub fn build(&self) -> ChatAdministrator { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<ChatAdministrator> for RTDChatAdministratorBuilder {
   fn as_ref(&self) -> &ChatAdministrator { &self.inner }
 }
 
+impl PartialEq for ChatAdministrator {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.custom_title == other.custom_title
+      && self.is_owner == other.is_owner
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct ChatAdministrators {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of chat administrators
   administrators: Vec<ChatAdministrator>,
   
 }
 
 impl RObject for ChatAdministrators {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatAdministrators" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatAdministrators" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatAdministrators {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatAdministratorsBuilder {
-    let mut inner = ChatAdministrators::default();
-    inner.td_name = "chatAdministrators".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatAdministrators { td_name: "chatAdministrators".to_string(), ..Default::default() };
     RTDChatAdministratorsBuilder { inner }
   }
 
+  /// `ChatAdministrators` with the fields tdlib requires, the others are set by `ChatAdministrators::builder()`
+  pub fn new(administrators: Vec<ChatAdministrator>) -> Self { Self::builder().administrators(administrators).build() }
+
   pub fn administrators(&self) -> &Vec<ChatAdministrator> { &self.administrators }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDChatAdministratorsBuilder {
 impl RTDChatAdministratorsBuilder {
   pub fn build(&self) -> ChatAdministrators { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn administrators(&mut self, administrators: Vec<ChatAdministrator>) -> &mut Self {
     self.inner.administrators = administrators;
@@ -65,5 +75,11 @@ impl AsRef<ChatAdministrators> for RTDChatAdministratorsBuilder {
   fn as_ref(&self) -> &ChatAdministrators { &self.inner }
 }
 
+impl PartialEq for ChatAdministrators {
+  fn eq(&self, other: &Self) -> bool {
+    self.administrators == other.administrators
+  }
+}
+
 
 
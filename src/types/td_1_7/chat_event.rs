@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,40 +13,57 @@ pub struct ChatEvent {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat event identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Point in time (Unix timestamp) when the event happened
   date: i64,
   /// Identifier of the user who performed the action that triggered the event
-  user_id: i64,
+  user_id: UserId,
   /// Action performed by the user
   action: ChatEventAction,
   
 }
 
 impl RObject for ChatEvent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEvent" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEvent" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatEvent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventBuilder {
-    let mut inner = ChatEvent::default();
-    inner.td_name = "chatEvent".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEvent { td_name: "chatEvent".to_string(), ..Default::default() };
     RTDChatEventBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `ChatEvent` with the fields tdlib requires, the others are set by `ChatEvent::builder()`
+  pub fn new(
+    id: i64,
+    date: i64,
+    user_id: UserId,
+    action: impl AsRef<ChatEventAction>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .date(date)
+      .user_id(user_id)
+      .action(action)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn date(&self) -> i64 { self.date }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn action(&self) -> &ChatEventAction { &self.action }
 
@@ -61,8 +77,14 @@ pub struct RTDChatEventBuilder {
 impl RTDChatEventBuilder {
   pub fn build(&self) -> ChatEvent { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -74,7 +96,7 @@ impl RTDChatEventBuilder {
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -95,5 +117,14 @@ impl AsRef<ChatEvent> for RTDChatEventBuilder {
   fn as_ref(&self) -> &ChatEvent { &self.inner }
 }
 
+impl PartialEq for ChatEvent {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.date == other.date
+      && self.user_id == other.user_id
+      && self.action == other.action
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatEventAction: Debug + RObject {}
 
 /// Represents a chat event
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatEventAction {
   #[doc(hidden)] _Default(()),
@@ -70,6 +69,7 @@ pub enum ChatEventAction {
   /// The slow_mode_delay setting of a supergroup was changed
   ChatEventSlowModeDelayChanged(ChatEventSlowModeDelayChanged),
   /// The supergroup sticker set was changed
+  #[cfg(feature = "stickers")]
   ChatEventStickerSetChanged(ChatEventStickerSetChanged),
   /// The chat title was changed
   ChatEventTitleChanged(ChatEventTitleChanged),
@@ -85,6 +85,8 @@ pub enum ChatEventAction {
   ChatEventVideoChatParticipantIsMutedToggled(ChatEventVideoChatParticipantIsMutedToggled),
   /// A video chat participant volume level was changed
   ChatEventVideoChatParticipantVolumeLevelChanged(ChatEventVideoChatParticipantVolumeLevelChanged),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -96,7 +98,7 @@ impl<'de> Deserialize<'de> for ChatEventAction {
   fn deserialize<D>(deserializer: D) -> Result<ChatEventAction, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatEventAction,
+      ChatEventAction, Unknown;
       (chatEventDescriptionChanged, ChatEventDescriptionChanged);
       (chatEventInviteLinkDeleted, ChatEventInviteLinkDeleted);
       (chatEventInviteLinkEdited, ChatEventInviteLinkEdited);
@@ -122,7 +124,7 @@ impl<'de> Deserialize<'de> for ChatEventAction {
       (chatEventPollStopped, ChatEventPollStopped);
       (chatEventSignMessagesToggled, ChatEventSignMessagesToggled);
       (chatEventSlowModeDelayChanged, ChatEventSlowModeDelayChanged);
-      (chatEventStickerSetChanged, ChatEventStickerSetChanged);
+      #[cfg(feature = "stickers")] (chatEventStickerSetChanged, ChatEventStickerSetChanged);
       (chatEventTitleChanged, ChatEventTitleChanged);
       (chatEventUsernameChanged, ChatEventUsernameChanged);
       (chatEventVideoChatCreated, ChatEventVideoChatCreated);
@@ -136,7 +138,7 @@ impl<'de> Deserialize<'de> for ChatEventAction {
 }
 
 impl RObject for ChatEventAction {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatEventAction::ChatEventDescriptionChanged(t) => t.td_name(),
       ChatEventAction::ChatEventInviteLinkDeleted(t) => t.td_name(),
@@ -163,7 +165,7 @@ impl RObject for ChatEventAction {
       ChatEventAction::ChatEventPollStopped(t) => t.td_name(),
       ChatEventAction::ChatEventSignMessagesToggled(t) => t.td_name(),
       ChatEventAction::ChatEventSlowModeDelayChanged(t) => t.td_name(),
-      ChatEventAction::ChatEventStickerSetChanged(t) => t.td_name(),
+      #[cfg(feature = "stickers")] ChatEventAction::ChatEventStickerSetChanged(t) => t.td_name(),
       ChatEventAction::ChatEventTitleChanged(t) => t.td_name(),
       ChatEventAction::ChatEventUsernameChanged(t) => t.td_name(),
       ChatEventAction::ChatEventVideoChatCreated(t) => t.td_name(),
@@ -171,11 +173,12 @@ impl RObject for ChatEventAction {
       ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(t) => t.td_name(),
       ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(t) => t.td_name(),
       ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(t) => t.td_name(),
+      ChatEventAction::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatEventAction::ChatEventDescriptionChanged(t) => t.extra(),
       ChatEventAction::ChatEventInviteLinkDeleted(t) => t.extra(),
@@ -202,7 +205,7 @@ impl RObject for ChatEventAction {
       ChatEventAction::ChatEventPollStopped(t) => t.extra(),
       ChatEventAction::ChatEventSignMessagesToggled(t) => t.extra(),
       ChatEventAction::ChatEventSlowModeDelayChanged(t) => t.extra(),
-      ChatEventAction::ChatEventStickerSetChanged(t) => t.extra(),
+      #[cfg(feature = "stickers")] ChatEventAction::ChatEventStickerSetChanged(t) => t.extra(),
       ChatEventAction::ChatEventTitleChanged(t) => t.extra(),
       ChatEventAction::ChatEventUsernameChanged(t) => t.extra(),
       ChatEventAction::ChatEventVideoChatCreated(t) => t.extra(),
@@ -210,6 +213,47 @@ impl RObject for ChatEventAction {
       ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(t) => t.extra(),
       ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(t) => t.extra(),
       ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(t) => t.extra(),
+      ChatEventAction::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatEventAction::ChatEventDescriptionChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventInviteLinkDeleted(t) => t.client_id(),
+      ChatEventAction::ChatEventInviteLinkEdited(t) => t.client_id(),
+      ChatEventAction::ChatEventInviteLinkRevoked(t) => t.client_id(),
+      ChatEventAction::ChatEventInvitesToggled(t) => t.client_id(),
+      ChatEventAction::ChatEventIsAllHistoryAvailableToggled(t) => t.client_id(),
+      ChatEventAction::ChatEventLinkedChatChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventLocationChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventMemberInvited(t) => t.client_id(),
+      ChatEventAction::ChatEventMemberJoined(t) => t.client_id(),
+      ChatEventAction::ChatEventMemberJoinedByInviteLink(t) => t.client_id(),
+      ChatEventAction::ChatEventMemberJoinedByRequest(t) => t.client_id(),
+      ChatEventAction::ChatEventMemberLeft(t) => t.client_id(),
+      ChatEventAction::ChatEventMemberPromoted(t) => t.client_id(),
+      ChatEventAction::ChatEventMemberRestricted(t) => t.client_id(),
+      ChatEventAction::ChatEventMessageDeleted(t) => t.client_id(),
+      ChatEventAction::ChatEventMessageEdited(t) => t.client_id(),
+      ChatEventAction::ChatEventMessagePinned(t) => t.client_id(),
+      ChatEventAction::ChatEventMessageTtlSettingChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventMessageUnpinned(t) => t.client_id(),
+      ChatEventAction::ChatEventPermissionsChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventPhotoChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventPollStopped(t) => t.client_id(),
+      ChatEventAction::ChatEventSignMessagesToggled(t) => t.client_id(),
+      ChatEventAction::ChatEventSlowModeDelayChanged(t) => t.client_id(),
+      #[cfg(feature = "stickers")] ChatEventAction::ChatEventStickerSetChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventTitleChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventUsernameChanged(t) => t.client_id(),
+      ChatEventAction::ChatEventVideoChatCreated(t) => t.client_id(),
+      ChatEventAction::ChatEventVideoChatDiscarded(t) => t.client_id(),
+      ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(t) => t.client_id(),
+      ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(t) => t.client_id(),
+      ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(t) => t.client_id(),
+      ChatEventAction::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -218,42 +262,43 @@ impl RObject for ChatEventAction {
 }
 
 impl ChatEventAction {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatEventAction::_Default(_) = self { true } else { false } }
-
-  pub fn is_chat_event_description_changed(&self) -> bool { if let ChatEventAction::ChatEventDescriptionChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_invite_link_deleted(&self) -> bool { if let ChatEventAction::ChatEventInviteLinkDeleted(_) = self { true } else { false } }
-  pub fn is_chat_event_invite_link_edited(&self) -> bool { if let ChatEventAction::ChatEventInviteLinkEdited(_) = self { true } else { false } }
-  pub fn is_chat_event_invite_link_revoked(&self) -> bool { if let ChatEventAction::ChatEventInviteLinkRevoked(_) = self { true } else { false } }
-  pub fn is_chat_event_invites_toggled(&self) -> bool { if let ChatEventAction::ChatEventInvitesToggled(_) = self { true } else { false } }
-  pub fn is_chat_event_is_all_history_available_toggled(&self) -> bool { if let ChatEventAction::ChatEventIsAllHistoryAvailableToggled(_) = self { true } else { false } }
-  pub fn is_chat_event_linked_chat_changed(&self) -> bool { if let ChatEventAction::ChatEventLinkedChatChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_location_changed(&self) -> bool { if let ChatEventAction::ChatEventLocationChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_member_invited(&self) -> bool { if let ChatEventAction::ChatEventMemberInvited(_) = self { true } else { false } }
-  pub fn is_chat_event_member_joined(&self) -> bool { if let ChatEventAction::ChatEventMemberJoined(_) = self { true } else { false } }
-  pub fn is_chat_event_member_joined_by_invite_link(&self) -> bool { if let ChatEventAction::ChatEventMemberJoinedByInviteLink(_) = self { true } else { false } }
-  pub fn is_chat_event_member_joined_by_request(&self) -> bool { if let ChatEventAction::ChatEventMemberJoinedByRequest(_) = self { true } else { false } }
-  pub fn is_chat_event_member_left(&self) -> bool { if let ChatEventAction::ChatEventMemberLeft(_) = self { true } else { false } }
-  pub fn is_chat_event_member_promoted(&self) -> bool { if let ChatEventAction::ChatEventMemberPromoted(_) = self { true } else { false } }
-  pub fn is_chat_event_member_restricted(&self) -> bool { if let ChatEventAction::ChatEventMemberRestricted(_) = self { true } else { false } }
-  pub fn is_chat_event_message_deleted(&self) -> bool { if let ChatEventAction::ChatEventMessageDeleted(_) = self { true } else { false } }
-  pub fn is_chat_event_message_edited(&self) -> bool { if let ChatEventAction::ChatEventMessageEdited(_) = self { true } else { false } }
-  pub fn is_chat_event_message_pinned(&self) -> bool { if let ChatEventAction::ChatEventMessagePinned(_) = self { true } else { false } }
-  pub fn is_chat_event_message_ttl_setting_changed(&self) -> bool { if let ChatEventAction::ChatEventMessageTtlSettingChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_message_unpinned(&self) -> bool { if let ChatEventAction::ChatEventMessageUnpinned(_) = self { true } else { false } }
-  pub fn is_chat_event_permissions_changed(&self) -> bool { if let ChatEventAction::ChatEventPermissionsChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_photo_changed(&self) -> bool { if let ChatEventAction::ChatEventPhotoChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_poll_stopped(&self) -> bool { if let ChatEventAction::ChatEventPollStopped(_) = self { true } else { false } }
-  pub fn is_chat_event_sign_messages_toggled(&self) -> bool { if let ChatEventAction::ChatEventSignMessagesToggled(_) = self { true } else { false } }
-  pub fn is_chat_event_slow_mode_delay_changed(&self) -> bool { if let ChatEventAction::ChatEventSlowModeDelayChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_sticker_set_changed(&self) -> bool { if let ChatEventAction::ChatEventStickerSetChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_title_changed(&self) -> bool { if let ChatEventAction::ChatEventTitleChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_username_changed(&self) -> bool { if let ChatEventAction::ChatEventUsernameChanged(_) = self { true } else { false } }
-  pub fn is_chat_event_video_chat_created(&self) -> bool { if let ChatEventAction::ChatEventVideoChatCreated(_) = self { true } else { false } }
-  pub fn is_chat_event_video_chat_discarded(&self) -> bool { if let ChatEventAction::ChatEventVideoChatDiscarded(_) = self { true } else { false } }
-  pub fn is_chat_event_video_chat_mute_new_participants_toggled(&self) -> bool { if let ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(_) = self { true } else { false } }
-  pub fn is_chat_event_video_chat_participant_is_muted_toggled(&self) -> bool { if let ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(_) = self { true } else { false } }
-  pub fn is_chat_event_video_chat_participant_volume_level_changed(&self) -> bool { if let ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatEventAction::_Default(_)) }
+
+  pub fn is_chat_event_description_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventDescriptionChanged(_)) }
+  pub fn is_chat_event_invite_link_deleted(&self) -> bool { matches!(self, ChatEventAction::ChatEventInviteLinkDeleted(_)) }
+  pub fn is_chat_event_invite_link_edited(&self) -> bool { matches!(self, ChatEventAction::ChatEventInviteLinkEdited(_)) }
+  pub fn is_chat_event_invite_link_revoked(&self) -> bool { matches!(self, ChatEventAction::ChatEventInviteLinkRevoked(_)) }
+  pub fn is_chat_event_invites_toggled(&self) -> bool { matches!(self, ChatEventAction::ChatEventInvitesToggled(_)) }
+  pub fn is_chat_event_is_all_history_available_toggled(&self) -> bool { matches!(self, ChatEventAction::ChatEventIsAllHistoryAvailableToggled(_)) }
+  pub fn is_chat_event_linked_chat_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventLinkedChatChanged(_)) }
+  pub fn is_chat_event_location_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventLocationChanged(_)) }
+  pub fn is_chat_event_member_invited(&self) -> bool { matches!(self, ChatEventAction::ChatEventMemberInvited(_)) }
+  pub fn is_chat_event_member_joined(&self) -> bool { matches!(self, ChatEventAction::ChatEventMemberJoined(_)) }
+  pub fn is_chat_event_member_joined_by_invite_link(&self) -> bool { matches!(self, ChatEventAction::ChatEventMemberJoinedByInviteLink(_)) }
+  pub fn is_chat_event_member_joined_by_request(&self) -> bool { matches!(self, ChatEventAction::ChatEventMemberJoinedByRequest(_)) }
+  pub fn is_chat_event_member_left(&self) -> bool { matches!(self, ChatEventAction::ChatEventMemberLeft(_)) }
+  pub fn is_chat_event_member_promoted(&self) -> bool { matches!(self, ChatEventAction::ChatEventMemberPromoted(_)) }
+  pub fn is_chat_event_member_restricted(&self) -> bool { matches!(self, ChatEventAction::ChatEventMemberRestricted(_)) }
+  pub fn is_chat_event_message_deleted(&self) -> bool { matches!(self, ChatEventAction::ChatEventMessageDeleted(_)) }
+  pub fn is_chat_event_message_edited(&self) -> bool { matches!(self, ChatEventAction::ChatEventMessageEdited(_)) }
+  pub fn is_chat_event_message_pinned(&self) -> bool { matches!(self, ChatEventAction::ChatEventMessagePinned(_)) }
+  pub fn is_chat_event_message_ttl_setting_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventMessageTtlSettingChanged(_)) }
+  pub fn is_chat_event_message_unpinned(&self) -> bool { matches!(self, ChatEventAction::ChatEventMessageUnpinned(_)) }
+  pub fn is_chat_event_permissions_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventPermissionsChanged(_)) }
+  pub fn is_chat_event_photo_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventPhotoChanged(_)) }
+  pub fn is_chat_event_poll_stopped(&self) -> bool { matches!(self, ChatEventAction::ChatEventPollStopped(_)) }
+  pub fn is_chat_event_sign_messages_toggled(&self) -> bool { matches!(self, ChatEventAction::ChatEventSignMessagesToggled(_)) }
+  pub fn is_chat_event_slow_mode_delay_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventSlowModeDelayChanged(_)) }
+  #[cfg(feature = "stickers")] pub fn is_chat_event_sticker_set_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventStickerSetChanged(_)) }
+  pub fn is_chat_event_title_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventTitleChanged(_)) }
+  pub fn is_chat_event_username_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventUsernameChanged(_)) }
+  pub fn is_chat_event_video_chat_created(&self) -> bool { matches!(self, ChatEventAction::ChatEventVideoChatCreated(_)) }
+  pub fn is_chat_event_video_chat_discarded(&self) -> bool { matches!(self, ChatEventAction::ChatEventVideoChatDiscarded(_)) }
+  pub fn is_chat_event_video_chat_mute_new_participants_toggled(&self) -> bool { matches!(self, ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(_)) }
+  pub fn is_chat_event_video_chat_participant_is_muted_toggled(&self) -> bool { matches!(self, ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(_)) }
+  pub fn is_chat_event_video_chat_participant_volume_level_changed(&self) -> bool { matches!(self, ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatEventAction::Unknown(_)) }
 
   pub fn on_chat_event_description_changed<F: FnOnce(&ChatEventDescriptionChanged)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventDescriptionChanged(t) = self { fnc(t) }; self }
   pub fn on_chat_event_invite_link_deleted<F: FnOnce(&ChatEventInviteLinkDeleted)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventInviteLinkDeleted(t) = self { fnc(t) }; self }
@@ -280,7 +325,7 @@ impl ChatEventAction {
   pub fn on_chat_event_poll_stopped<F: FnOnce(&ChatEventPollStopped)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventPollStopped(t) = self { fnc(t) }; self }
   pub fn on_chat_event_sign_messages_toggled<F: FnOnce(&ChatEventSignMessagesToggled)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventSignMessagesToggled(t) = self { fnc(t) }; self }
   pub fn on_chat_event_slow_mode_delay_changed<F: FnOnce(&ChatEventSlowModeDelayChanged)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventSlowModeDelayChanged(t) = self { fnc(t) }; self }
-  pub fn on_chat_event_sticker_set_changed<F: FnOnce(&ChatEventStickerSetChanged)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventStickerSetChanged(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_chat_event_sticker_set_changed<F: FnOnce(&ChatEventStickerSetChanged)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventStickerSetChanged(t) = self { fnc(t) }; self }
   pub fn on_chat_event_title_changed<F: FnOnce(&ChatEventTitleChanged)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventTitleChanged(t) = self { fnc(t) }; self }
   pub fn on_chat_event_username_changed<F: FnOnce(&ChatEventUsernameChanged)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventUsernameChanged(t) = self { fnc(t) }; self }
   pub fn on_chat_event_video_chat_created<F: FnOnce(&ChatEventVideoChatCreated)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventVideoChatCreated(t) = self { fnc(t) }; self }
@@ -288,6 +333,7 @@ impl ChatEventAction {
   pub fn on_chat_event_video_chat_mute_new_participants_toggled<F: FnOnce(&ChatEventVideoChatMuteNewParticipantsToggled)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(t) = self { fnc(t) }; self }
   pub fn on_chat_event_video_chat_participant_is_muted_toggled<F: FnOnce(&ChatEventVideoChatParticipantIsMutedToggled)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(t) = self { fnc(t) }; self }
   pub fn on_chat_event_video_chat_participant_volume_level_changed<F: FnOnce(&ChatEventVideoChatParticipantVolumeLevelChanged)>(&self, fnc: F) -> &Self { if let ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatEventAction::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_chat_event_description_changed(&self) -> Option<&ChatEventDescriptionChanged> { if let ChatEventAction::ChatEventDescriptionChanged(t) = self { return Some(t) } None }
   pub fn as_chat_event_invite_link_deleted(&self) -> Option<&ChatEventInviteLinkDeleted> { if let ChatEventAction::ChatEventInviteLinkDeleted(t) = self { return Some(t) } None }
@@ -314,7 +360,7 @@ impl ChatEventAction {
   pub fn as_chat_event_poll_stopped(&self) -> Option<&ChatEventPollStopped> { if let ChatEventAction::ChatEventPollStopped(t) = self { return Some(t) } None }
   pub fn as_chat_event_sign_messages_toggled(&self) -> Option<&ChatEventSignMessagesToggled> { if let ChatEventAction::ChatEventSignMessagesToggled(t) = self { return Some(t) } None }
   pub fn as_chat_event_slow_mode_delay_changed(&self) -> Option<&ChatEventSlowModeDelayChanged> { if let ChatEventAction::ChatEventSlowModeDelayChanged(t) = self { return Some(t) } None }
-  pub fn as_chat_event_sticker_set_changed(&self) -> Option<&ChatEventStickerSetChanged> { if let ChatEventAction::ChatEventStickerSetChanged(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_chat_event_sticker_set_changed(&self) -> Option<&ChatEventStickerSetChanged> { if let ChatEventAction::ChatEventStickerSetChanged(t) = self { return Some(t) } None }
   pub fn as_chat_event_title_changed(&self) -> Option<&ChatEventTitleChanged> { if let ChatEventAction::ChatEventTitleChanged(t) = self { return Some(t) } None }
   pub fn as_chat_event_username_changed(&self) -> Option<&ChatEventUsernameChanged> { if let ChatEventAction::ChatEventUsernameChanged(t) = self { return Some(t) } None }
   pub fn as_chat_event_video_chat_created(&self) -> Option<&ChatEventVideoChatCreated> { if let ChatEventAction::ChatEventVideoChatCreated(t) = self { return Some(t) } None }
@@ -322,6 +368,7 @@ impl ChatEventAction {
   pub fn as_chat_event_video_chat_mute_new_participants_toggled(&self) -> Option<&ChatEventVideoChatMuteNewParticipantsToggled> { if let ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(t) = self { return Some(t) } None }
   pub fn as_chat_event_video_chat_participant_is_muted_toggled(&self) -> Option<&ChatEventVideoChatParticipantIsMutedToggled> { if let ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(t) = self { return Some(t) } None }
   pub fn as_chat_event_video_chat_participant_volume_level_changed(&self) -> Option<&ChatEventVideoChatParticipantVolumeLevelChanged> { if let ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatEventAction::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -375,7 +422,7 @@ impl ChatEventAction {
 
   pub fn chat_event_slow_mode_delay_changed<T: AsRef<ChatEventSlowModeDelayChanged>>(t: T) -> Self { ChatEventAction::ChatEventSlowModeDelayChanged(t.as_ref().clone()) }
 
-  pub fn chat_event_sticker_set_changed<T: AsRef<ChatEventStickerSetChanged>>(t: T) -> Self { ChatEventAction::ChatEventStickerSetChanged(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn chat_event_sticker_set_changed<T: AsRef<ChatEventStickerSetChanged>>(t: T) -> Self { ChatEventAction::ChatEventStickerSetChanged(t.as_ref().clone()) }
 
   pub fn chat_event_title_changed<T: AsRef<ChatEventTitleChanged>>(t: T) -> Self { ChatEventAction::ChatEventTitleChanged(t.as_ref().clone()) }
 
@@ -411,7 +458,10 @@ pub struct ChatEventDescriptionChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous chat description
   old_description: String,
   /// New chat description
@@ -420,8 +470,9 @@ pub struct ChatEventDescriptionChanged {
 }
 
 impl RObject for ChatEventDescriptionChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventDescriptionChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventDescriptionChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -431,14 +482,15 @@ impl TDChatEventAction for ChatEventDescriptionChanged {}
 
 
 impl ChatEventDescriptionChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventDescriptionChangedBuilder {
-    let mut inner = ChatEventDescriptionChanged::default();
-    inner.td_name = "chatEventDescriptionChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventDescriptionChanged { td_name: "chatEventDescriptionChanged".to_string(), ..Default::default() };
     RTDChatEventDescriptionChangedBuilder { inner }
   }
 
+  /// `ChatEventDescriptionChanged` with the fields tdlib requires, the others are set by `ChatEventDescriptionChanged::builder()`
+  pub fn new(old_description: impl AsRef<str>, new_description: impl AsRef<str>) -> Self { Self::builder().old_description(old_description).new_description(new_description).build() }
+
   pub fn old_description(&self) -> &String { &self.old_description }
 
   pub fn new_description(&self) -> &String { &self.new_description }
@@ -453,6 +505,12 @@ pub struct RTDChatEventDescriptionChangedBuilder {
 impl RTDChatEventDescriptionChangedBuilder {
   pub fn build(&self) -> ChatEventDescriptionChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_description<T: AsRef<str>>(&mut self, old_description: T) -> &mut Self {
     self.inner.old_description = old_description.as_ref().to_string();
@@ -475,6 +533,13 @@ impl AsRef<ChatEventDescriptionChanged> for RTDChatEventDescriptionChangedBuilde
   fn as_ref(&self) -> &ChatEventDescriptionChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventDescriptionChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_description == other.old_description
+      && self.new_description == other.new_description
+  }
+}
+
 
 
 
@@ -489,15 +554,19 @@ pub struct ChatEventInviteLinkDeleted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The invite link
   invite_link: ChatInviteLink,
   
 }
 
 impl RObject for ChatEventInviteLinkDeleted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventInviteLinkDeleted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventInviteLinkDeleted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -507,14 +576,15 @@ impl TDChatEventAction for ChatEventInviteLinkDeleted {}
 
 
 impl ChatEventInviteLinkDeleted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventInviteLinkDeletedBuilder {
-    let mut inner = ChatEventInviteLinkDeleted::default();
-    inner.td_name = "chatEventInviteLinkDeleted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventInviteLinkDeleted { td_name: "chatEventInviteLinkDeleted".to_string(), ..Default::default() };
     RTDChatEventInviteLinkDeletedBuilder { inner }
   }
 
+  /// `ChatEventInviteLinkDeleted` with the fields tdlib requires, the others are set by `ChatEventInviteLinkDeleted::builder()`
+  pub fn new(invite_link: impl AsRef<ChatInviteLink>) -> Self { Self::builder().invite_link(invite_link).build() }
+
   pub fn invite_link(&self) -> &ChatInviteLink { &self.invite_link }
 
 }
@@ -527,6 +597,12 @@ pub struct RTDChatEventInviteLinkDeletedBuilder {
 impl RTDChatEventInviteLinkDeletedBuilder {
   pub fn build(&self) -> ChatEventInviteLinkDeleted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<ChatInviteLink>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().clone();
@@ -543,6 +619,12 @@ impl AsRef<ChatEventInviteLinkDeleted> for RTDChatEventInviteLinkDeletedBuilder
   fn as_ref(&self) -> &ChatEventInviteLinkDeleted { &self.inner }
 }
 
+impl PartialEq for ChatEventInviteLinkDeleted {
+  fn eq(&self, other: &Self) -> bool {
+    self.invite_link == other.invite_link
+  }
+}
+
 
 
 
@@ -557,7 +639,10 @@ pub struct ChatEventInviteLinkEdited {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous information about the invite link
   old_invite_link: ChatInviteLink,
   /// New information about the invite link
@@ -566,8 +651,9 @@ pub struct ChatEventInviteLinkEdited {
 }
 
 impl RObject for ChatEventInviteLinkEdited {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventInviteLinkEdited" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventInviteLinkEdited" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -577,14 +663,15 @@ impl TDChatEventAction for ChatEventInviteLinkEdited {}
 
 
 impl ChatEventInviteLinkEdited {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventInviteLinkEditedBuilder {
-    let mut inner = ChatEventInviteLinkEdited::default();
-    inner.td_name = "chatEventInviteLinkEdited".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventInviteLinkEdited { td_name: "chatEventInviteLinkEdited".to_string(), ..Default::default() };
     RTDChatEventInviteLinkEditedBuilder { inner }
   }
 
+  /// `ChatEventInviteLinkEdited` with the fields tdlib requires, the others are set by `ChatEventInviteLinkEdited::builder()`
+  pub fn new(old_invite_link: impl AsRef<ChatInviteLink>, new_invite_link: impl AsRef<ChatInviteLink>) -> Self { Self::builder().old_invite_link(old_invite_link).new_invite_link(new_invite_link).build() }
+
   pub fn old_invite_link(&self) -> &ChatInviteLink { &self.old_invite_link }
 
   pub fn new_invite_link(&self) -> &ChatInviteLink { &self.new_invite_link }
@@ -599,6 +686,12 @@ pub struct RTDChatEventInviteLinkEditedBuilder {
 impl RTDChatEventInviteLinkEditedBuilder {
   pub fn build(&self) -> ChatEventInviteLinkEdited { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_invite_link<T: AsRef<ChatInviteLink>>(&mut self, old_invite_link: T) -> &mut Self {
     self.inner.old_invite_link = old_invite_link.as_ref().clone();
@@ -621,6 +714,13 @@ impl AsRef<ChatEventInviteLinkEdited> for RTDChatEventInviteLinkEditedBuilder {
   fn as_ref(&self) -> &ChatEventInviteLinkEdited { &self.inner }
 }
 
+impl PartialEq for ChatEventInviteLinkEdited {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_invite_link == other.old_invite_link
+      && self.new_invite_link == other.new_invite_link
+  }
+}
+
 
 
 
@@ -635,15 +735,19 @@ pub struct ChatEventInviteLinkRevoked {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The invite link
   invite_link: ChatInviteLink,
   
 }
 
 impl RObject for ChatEventInviteLinkRevoked {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventInviteLinkRevoked" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventInviteLinkRevoked" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -653,14 +757,15 @@ impl TDChatEventAction for ChatEventInviteLinkRevoked {}
 
 
 impl ChatEventInviteLinkRevoked {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventInviteLinkRevokedBuilder {
-    let mut inner = ChatEventInviteLinkRevoked::default();
-    inner.td_name = "chatEventInviteLinkRevoked".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventInviteLinkRevoked { td_name: "chatEventInviteLinkRevoked".to_string(), ..Default::default() };
     RTDChatEventInviteLinkRevokedBuilder { inner }
   }
 
+  /// `ChatEventInviteLinkRevoked` with the fields tdlib requires, the others are set by `ChatEventInviteLinkRevoked::builder()`
+  pub fn new(invite_link: impl AsRef<ChatInviteLink>) -> Self { Self::builder().invite_link(invite_link).build() }
+
   pub fn invite_link(&self) -> &ChatInviteLink { &self.invite_link }
 
 }
@@ -673,6 +778,12 @@ pub struct RTDChatEventInviteLinkRevokedBuilder {
 impl RTDChatEventInviteLinkRevokedBuilder {
   pub fn build(&self) -> ChatEventInviteLinkRevoked { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<ChatInviteLink>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().clone();
@@ -689,6 +800,12 @@ impl AsRef<ChatEventInviteLinkRevoked> for RTDChatEventInviteLinkRevokedBuilder
   fn as_ref(&self) -> &ChatEventInviteLinkRevoked { &self.inner }
 }
 
+impl PartialEq for ChatEventInviteLinkRevoked {
+  fn eq(&self, other: &Self) -> bool {
+    self.invite_link == other.invite_link
+  }
+}
+
 
 
 
@@ -703,15 +820,19 @@ pub struct ChatEventInvitesToggled {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New value of can_invite_users permission
   can_invite_users: bool,
   
 }
 
 impl RObject for ChatEventInvitesToggled {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventInvitesToggled" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventInvitesToggled" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -721,14 +842,15 @@ impl TDChatEventAction for ChatEventInvitesToggled {}
 
 
 impl ChatEventInvitesToggled {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventInvitesToggledBuilder {
-    let mut inner = ChatEventInvitesToggled::default();
-    inner.td_name = "chatEventInvitesToggled".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventInvitesToggled { td_name: "chatEventInvitesToggled".to_string(), ..Default::default() };
     RTDChatEventInvitesToggledBuilder { inner }
   }
 
+  /// `ChatEventInvitesToggled` with the fields tdlib requires, the others are set by `ChatEventInvitesToggled::builder()`
+  pub fn new(can_invite_users: bool) -> Self { Self::builder().can_invite_users(can_invite_users).build() }
+
   pub fn can_invite_users(&self) -> bool { self.can_invite_users }
 
 }
@@ -741,6 +863,12 @@ pub struct RTDChatEventInvitesToggledBuilder {
 impl RTDChatEventInvitesToggledBuilder {
   pub fn build(&self) -> ChatEventInvitesToggled { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_invite_users(&mut self, can_invite_users: bool) -> &mut Self {
     self.inner.can_invite_users = can_invite_users;
@@ -757,6 +885,12 @@ impl AsRef<ChatEventInvitesToggled> for RTDChatEventInvitesToggledBuilder {
   fn as_ref(&self) -> &ChatEventInvitesToggled { &self.inner }
 }
 
+impl PartialEq for ChatEventInvitesToggled {
+  fn eq(&self, other: &Self) -> bool {
+    self.can_invite_users == other.can_invite_users
+  }
+}
+
 
 
 
@@ -771,15 +905,19 @@ pub struct ChatEventIsAllHistoryAvailableToggled {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New value of is_all_history_available
   is_all_history_available: bool,
   
 }
 
 impl RObject for ChatEventIsAllHistoryAvailableToggled {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventIsAllHistoryAvailableToggled" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventIsAllHistoryAvailableToggled" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -789,14 +927,15 @@ impl TDChatEventAction for ChatEventIsAllHistoryAvailableToggled {}
 
 
 impl ChatEventIsAllHistoryAvailableToggled {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventIsAllHistoryAvailableToggledBuilder {
-    let mut inner = ChatEventIsAllHistoryAvailableToggled::default();
-    inner.td_name = "chatEventIsAllHistoryAvailableToggled".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventIsAllHistoryAvailableToggled { td_name: "chatEventIsAllHistoryAvailableToggled".to_string(), ..Default::default() };
     RTDChatEventIsAllHistoryAvailableToggledBuilder { inner }
   }
 
+  /// `ChatEventIsAllHistoryAvailableToggled` with the fields tdlib requires, the others are set by `ChatEventIsAllHistoryAvailableToggled::builder()`
+  pub fn new(is_all_history_available: bool) -> Self { Self::builder().is_all_history_available(is_all_history_available).build() }
+
   pub fn is_all_history_available(&self) -> bool { self.is_all_history_available }
 
 }
@@ -809,6 +948,12 @@ pub struct RTDChatEventIsAllHistoryAvailableToggledBuilder {
 impl RTDChatEventIsAllHistoryAvailableToggledBuilder {
   pub fn build(&self) -> ChatEventIsAllHistoryAvailableToggled { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_all_history_available(&mut self, is_all_history_available: bool) -> &mut Self {
     self.inner.is_all_history_available = is_all_history_available;
@@ -825,6 +970,12 @@ impl AsRef<ChatEventIsAllHistoryAvailableToggled> for RTDChatEventIsAllHistoryAv
   fn as_ref(&self) -> &ChatEventIsAllHistoryAvailableToggled { &self.inner }
 }
 
+impl PartialEq for ChatEventIsAllHistoryAvailableToggled {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_all_history_available == other.is_all_history_available
+  }
+}
+
 
 
 
@@ -839,17 +990,21 @@ pub struct ChatEventLinkedChatChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous supergroup linked chat identifier
-  old_linked_chat_id: i64,
+  old_linked_chat_id: ChatId,
   /// New supergroup linked chat identifier
-  new_linked_chat_id: i64,
+  new_linked_chat_id: ChatId,
   
 }
 
 impl RObject for ChatEventLinkedChatChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventLinkedChatChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventLinkedChatChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -859,17 +1014,18 @@ impl TDChatEventAction for ChatEventLinkedChatChanged {}
 
 
 impl ChatEventLinkedChatChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventLinkedChatChangedBuilder {
-    let mut inner = ChatEventLinkedChatChanged::default();
-    inner.td_name = "chatEventLinkedChatChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventLinkedChatChanged { td_name: "chatEventLinkedChatChanged".to_string(), ..Default::default() };
     RTDChatEventLinkedChatChangedBuilder { inner }
   }
 
-  pub fn old_linked_chat_id(&self) -> i64 { self.old_linked_chat_id }
+  /// `ChatEventLinkedChatChanged` with the fields tdlib requires, the others are set by `ChatEventLinkedChatChanged::builder()`
+  pub fn new(old_linked_chat_id: ChatId, new_linked_chat_id: ChatId) -> Self { Self::builder().old_linked_chat_id(old_linked_chat_id).new_linked_chat_id(new_linked_chat_id).build() }
 
-  pub fn new_linked_chat_id(&self) -> i64 { self.new_linked_chat_id }
+  pub fn old_linked_chat_id(&self) -> ChatId { self.old_linked_chat_id }
+
+  pub fn new_linked_chat_id(&self) -> ChatId { self.new_linked_chat_id }
 
 }
 
@@ -881,14 +1037,20 @@ pub struct RTDChatEventLinkedChatChangedBuilder {
 impl RTDChatEventLinkedChatChangedBuilder {
   pub fn build(&self) -> ChatEventLinkedChatChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn old_linked_chat_id(&mut self, old_linked_chat_id: i64) -> &mut Self {
+  pub fn old_linked_chat_id(&mut self, old_linked_chat_id: ChatId) -> &mut Self {
     self.inner.old_linked_chat_id = old_linked_chat_id;
     self
   }
 
    
-  pub fn new_linked_chat_id(&mut self, new_linked_chat_id: i64) -> &mut Self {
+  pub fn new_linked_chat_id(&mut self, new_linked_chat_id: ChatId) -> &mut Self {
     self.inner.new_linked_chat_id = new_linked_chat_id;
     self
   }
@@ -903,6 +1065,13 @@ impl AsRef<ChatEventLinkedChatChanged> for RTDChatEventLinkedChatChangedBuilder
   fn as_ref(&self) -> &ChatEventLinkedChatChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventLinkedChatChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_linked_chat_id == other.old_linked_chat_id
+      && self.new_linked_chat_id == other.new_linked_chat_id
+  }
+}
+
 
 
 
@@ -917,7 +1086,10 @@ pub struct ChatEventLocationChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous location; may be null
   old_location: Option<ChatLocation>,
   /// New location; may be null
@@ -926,8 +1098,9 @@ pub struct ChatEventLocationChanged {
 }
 
 impl RObject for ChatEventLocationChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventLocationChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventLocationChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -937,14 +1110,15 @@ impl TDChatEventAction for ChatEventLocationChanged {}
 
 
 impl ChatEventLocationChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventLocationChangedBuilder {
-    let mut inner = ChatEventLocationChanged::default();
-    inner.td_name = "chatEventLocationChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventLocationChanged { td_name: "chatEventLocationChanged".to_string(), ..Default::default() };
     RTDChatEventLocationChangedBuilder { inner }
   }
 
+  /// `ChatEventLocationChanged` with the fields tdlib requires, the others are set by `ChatEventLocationChanged::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
   pub fn old_location(&self) -> &Option<ChatLocation> { &self.old_location }
 
   pub fn new_location(&self) -> &Option<ChatLocation> { &self.new_location }
@@ -959,6 +1133,12 @@ pub struct RTDChatEventLocationChangedBuilder {
 impl RTDChatEventLocationChangedBuilder {
   pub fn build(&self) -> ChatEventLocationChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_location<T: AsRef<ChatLocation>>(&mut self, old_location: T) -> &mut Self {
     self.inner.old_location = Some(old_location.as_ref().clone());
@@ -981,6 +1161,13 @@ impl AsRef<ChatEventLocationChanged> for RTDChatEventLocationChangedBuilder {
   fn as_ref(&self) -> &ChatEventLocationChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventLocationChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_location == other.old_location
+      && self.new_location == other.new_location
+  }
+}
+
 
 
 
@@ -995,17 +1182,21 @@ pub struct ChatEventMemberInvited {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New member user identifier
-  user_id: i64,
+  user_id: UserId,
   /// New member status
   status: ChatMemberStatus,
   
 }
 
 impl RObject for ChatEventMemberInvited {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberInvited" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMemberInvited" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1015,15 +1206,16 @@ impl TDChatEventAction for ChatEventMemberInvited {}
 
 
 impl ChatEventMemberInvited {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMemberInvitedBuilder {
-    let mut inner = ChatEventMemberInvited::default();
-    inner.td_name = "chatEventMemberInvited".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMemberInvited { td_name: "chatEventMemberInvited".to_string(), ..Default::default() };
     RTDChatEventMemberInvitedBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatEventMemberInvited` with the fields tdlib requires, the others are set by `ChatEventMemberInvited::builder()`
+  pub fn new(user_id: UserId, status: impl AsRef<ChatMemberStatus>) -> Self { Self::builder().user_id(user_id).status(status).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn status(&self) -> &ChatMemberStatus { &self.status }
 
@@ -1037,8 +1229,14 @@ pub struct RTDChatEventMemberInvitedBuilder {
 impl RTDChatEventMemberInvitedBuilder {
   pub fn build(&self) -> ChatEventMemberInvited { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -1059,6 +1257,13 @@ impl AsRef<ChatEventMemberInvited> for RTDChatEventMemberInvitedBuilder {
   fn as_ref(&self) -> &ChatEventMemberInvited { &self.inner }
 }
 
+impl PartialEq for ChatEventMemberInvited {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.status == other.status
+  }
+}
+
 
 
 
@@ -1073,13 +1278,17 @@ pub struct ChatEventMemberJoined {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatEventMemberJoined {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberJoined" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMemberJoined" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1089,14 +1298,15 @@ impl TDChatEventAction for ChatEventMemberJoined {}
 
 
 impl ChatEventMemberJoined {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMemberJoinedBuilder {
-    let mut inner = ChatEventMemberJoined::default();
-    inner.td_name = "chatEventMemberJoined".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMemberJoined { td_name: "chatEventMemberJoined".to_string(), ..Default::default() };
     RTDChatEventMemberJoinedBuilder { inner }
   }
 
+  /// `ChatEventMemberJoined` with the fields tdlib requires, the others are set by `ChatEventMemberJoined::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1107,6 +1317,12 @@ pub struct RTDChatEventMemberJoinedBuilder {
 impl RTDChatEventMemberJoinedBuilder {
   pub fn build(&self) -> ChatEventMemberJoined { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatEventMemberJoined> for ChatEventMemberJoined {
@@ -1117,6 +1333,10 @@ impl AsRef<ChatEventMemberJoined> for RTDChatEventMemberJoinedBuilder {
   fn as_ref(&self) -> &ChatEventMemberJoined { &self.inner }
 }
 
+impl PartialEq for ChatEventMemberJoined {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1131,15 +1351,19 @@ pub struct ChatEventMemberJoinedByInviteLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Invite link used to join the chat
   invite_link: ChatInviteLink,
   
 }
 
 impl RObject for ChatEventMemberJoinedByInviteLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberJoinedByInviteLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMemberJoinedByInviteLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1149,14 +1373,15 @@ impl TDChatEventAction for ChatEventMemberJoinedByInviteLink {}
 
 
 impl ChatEventMemberJoinedByInviteLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMemberJoinedByInviteLinkBuilder {
-    let mut inner = ChatEventMemberJoinedByInviteLink::default();
-    inner.td_name = "chatEventMemberJoinedByInviteLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMemberJoinedByInviteLink { td_name: "chatEventMemberJoinedByInviteLink".to_string(), ..Default::default() };
     RTDChatEventMemberJoinedByInviteLinkBuilder { inner }
   }
 
+  /// `ChatEventMemberJoinedByInviteLink` with the fields tdlib requires, the others are set by `ChatEventMemberJoinedByInviteLink::builder()`
+  pub fn new(invite_link: impl AsRef<ChatInviteLink>) -> Self { Self::builder().invite_link(invite_link).build() }
+
   pub fn invite_link(&self) -> &ChatInviteLink { &self.invite_link }
 
 }
@@ -1169,6 +1394,12 @@ pub struct RTDChatEventMemberJoinedByInviteLinkBuilder {
 impl RTDChatEventMemberJoinedByInviteLinkBuilder {
   pub fn build(&self) -> ChatEventMemberJoinedByInviteLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<ChatInviteLink>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().clone();
@@ -1185,6 +1416,12 @@ impl AsRef<ChatEventMemberJoinedByInviteLink> for RTDChatEventMemberJoinedByInvi
   fn as_ref(&self) -> &ChatEventMemberJoinedByInviteLink { &self.inner }
 }
 
+impl PartialEq for ChatEventMemberJoinedByInviteLink {
+  fn eq(&self, other: &Self) -> bool {
+    self.invite_link == other.invite_link
+  }
+}
+
 
 
 
@@ -1199,17 +1436,21 @@ pub struct ChatEventMemberJoinedByRequest {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier of the chat administrator, approved user join request
-  approver_user_id: i64,
+  approver_user_id: UserId,
   /// Invite link used to join the chat; may be null
   invite_link: Option<ChatInviteLink>,
   
 }
 
 impl RObject for ChatEventMemberJoinedByRequest {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberJoinedByRequest" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMemberJoinedByRequest" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1219,15 +1460,16 @@ impl TDChatEventAction for ChatEventMemberJoinedByRequest {}
 
 
 impl ChatEventMemberJoinedByRequest {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMemberJoinedByRequestBuilder {
-    let mut inner = ChatEventMemberJoinedByRequest::default();
-    inner.td_name = "chatEventMemberJoinedByRequest".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMemberJoinedByRequest { td_name: "chatEventMemberJoinedByRequest".to_string(), ..Default::default() };
     RTDChatEventMemberJoinedByRequestBuilder { inner }
   }
 
-  pub fn approver_user_id(&self) -> i64 { self.approver_user_id }
+  /// `ChatEventMemberJoinedByRequest` with the fields tdlib requires, the others are set by `ChatEventMemberJoinedByRequest::builder()`
+  pub fn new(approver_user_id: UserId) -> Self { Self::builder().approver_user_id(approver_user_id).build() }
+
+  pub fn approver_user_id(&self) -> UserId { self.approver_user_id }
 
   pub fn invite_link(&self) -> &Option<ChatInviteLink> { &self.invite_link }
 
@@ -1241,8 +1483,14 @@ pub struct RTDChatEventMemberJoinedByRequestBuilder {
 impl RTDChatEventMemberJoinedByRequestBuilder {
   pub fn build(&self) -> ChatEventMemberJoinedByRequest { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn approver_user_id(&mut self, approver_user_id: i64) -> &mut Self {
+  pub fn approver_user_id(&mut self, approver_user_id: UserId) -> &mut Self {
     self.inner.approver_user_id = approver_user_id;
     self
   }
@@ -1263,6 +1511,13 @@ impl AsRef<ChatEventMemberJoinedByRequest> for RTDChatEventMemberJoinedByRequest
   fn as_ref(&self) -> &ChatEventMemberJoinedByRequest { &self.inner }
 }
 
+impl PartialEq for ChatEventMemberJoinedByRequest {
+  fn eq(&self, other: &Self) -> bool {
+    self.approver_user_id == other.approver_user_id
+      && self.invite_link == other.invite_link
+  }
+}
+
 
 
 
@@ -1277,13 +1532,17 @@ pub struct ChatEventMemberLeft {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatEventMemberLeft {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberLeft" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMemberLeft" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1293,14 +1552,15 @@ impl TDChatEventAction for ChatEventMemberLeft {}
 
 
 impl ChatEventMemberLeft {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMemberLeftBuilder {
-    let mut inner = ChatEventMemberLeft::default();
-    inner.td_name = "chatEventMemberLeft".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMemberLeft { td_name: "chatEventMemberLeft".to_string(), ..Default::default() };
     RTDChatEventMemberLeftBuilder { inner }
   }
 
+  /// `ChatEventMemberLeft` with the fields tdlib requires, the others are set by `ChatEventMemberLeft::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1311,6 +1571,12 @@ pub struct RTDChatEventMemberLeftBuilder {
 impl RTDChatEventMemberLeftBuilder {
   pub fn build(&self) -> ChatEventMemberLeft { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatEventMemberLeft> for ChatEventMemberLeft {
@@ -1321,6 +1587,10 @@ impl AsRef<ChatEventMemberLeft> for RTDChatEventMemberLeftBuilder {
   fn as_ref(&self) -> &ChatEventMemberLeft { &self.inner }
 }
 
+impl PartialEq for ChatEventMemberLeft {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1335,9 +1605,12 @@ pub struct ChatEventMemberPromoted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Affected chat member user identifier
-  user_id: i64,
+  user_id: UserId,
   /// Previous status of the chat member
   old_status: ChatMemberStatus,
   /// New status of the chat member
@@ -1346,8 +1619,9 @@ pub struct ChatEventMemberPromoted {
 }
 
 impl RObject for ChatEventMemberPromoted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberPromoted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMemberPromoted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1357,15 +1631,16 @@ impl TDChatEventAction for ChatEventMemberPromoted {}
 
 
 impl ChatEventMemberPromoted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMemberPromotedBuilder {
-    let mut inner = ChatEventMemberPromoted::default();
-    inner.td_name = "chatEventMemberPromoted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMemberPromoted { td_name: "chatEventMemberPromoted".to_string(), ..Default::default() };
     RTDChatEventMemberPromotedBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatEventMemberPromoted` with the fields tdlib requires, the others are set by `ChatEventMemberPromoted::builder()`
+  pub fn new(user_id: UserId, old_status: impl AsRef<ChatMemberStatus>, new_status: impl AsRef<ChatMemberStatus>) -> Self { Self::builder().user_id(user_id).old_status(old_status).new_status(new_status).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn old_status(&self) -> &ChatMemberStatus { &self.old_status }
 
@@ -1381,8 +1656,14 @@ pub struct RTDChatEventMemberPromotedBuilder {
 impl RTDChatEventMemberPromotedBuilder {
   pub fn build(&self) -> ChatEventMemberPromoted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -1409,6 +1690,14 @@ impl AsRef<ChatEventMemberPromoted> for RTDChatEventMemberPromotedBuilder {
   fn as_ref(&self) -> &ChatEventMemberPromoted { &self.inner }
 }
 
+impl PartialEq for ChatEventMemberPromoted {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.old_status == other.old_status
+      && self.new_status == other.new_status
+  }
+}
+
 
 
 
@@ -1423,7 +1712,10 @@ pub struct ChatEventMemberRestricted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Affected chat member identifier
   member_id: MessageSender,
   /// Previous status of the chat member
@@ -1434,8 +1726,9 @@ pub struct ChatEventMemberRestricted {
 }
 
 impl RObject for ChatEventMemberRestricted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMemberRestricted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMemberRestricted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1445,14 +1738,15 @@ impl TDChatEventAction for ChatEventMemberRestricted {}
 
 
 impl ChatEventMemberRestricted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMemberRestrictedBuilder {
-    let mut inner = ChatEventMemberRestricted::default();
-    inner.td_name = "chatEventMemberRestricted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMemberRestricted { td_name: "chatEventMemberRestricted".to_string(), ..Default::default() };
     RTDChatEventMemberRestrictedBuilder { inner }
   }
 
+  /// `ChatEventMemberRestricted` with the fields tdlib requires, the others are set by `ChatEventMemberRestricted::builder()`
+  pub fn new(member_id: impl AsRef<MessageSender>, old_status: impl AsRef<ChatMemberStatus>, new_status: impl AsRef<ChatMemberStatus>) -> Self { Self::builder().member_id(member_id).old_status(old_status).new_status(new_status).build() }
+
   pub fn member_id(&self) -> &MessageSender { &self.member_id }
 
   pub fn old_status(&self) -> &ChatMemberStatus { &self.old_status }
@@ -1469,6 +1763,12 @@ pub struct RTDChatEventMemberRestrictedBuilder {
 impl RTDChatEventMemberRestrictedBuilder {
   pub fn build(&self) -> ChatEventMemberRestricted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn member_id<T: AsRef<MessageSender>>(&mut self, member_id: T) -> &mut Self {
     self.inner.member_id = member_id.as_ref().clone();
@@ -1497,6 +1797,14 @@ impl AsRef<ChatEventMemberRestricted> for RTDChatEventMemberRestrictedBuilder {
   fn as_ref(&self) -> &ChatEventMemberRestricted { &self.inner }
 }
 
+impl PartialEq for ChatEventMemberRestricted {
+  fn eq(&self, other: &Self) -> bool {
+    self.member_id == other.member_id
+      && self.old_status == other.old_status
+      && self.new_status == other.new_status
+  }
+}
+
 
 
 
@@ -1511,15 +1819,19 @@ pub struct ChatEventMessageDeleted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Deleted message
   message: Message,
   
 }
 
 impl RObject for ChatEventMessageDeleted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessageDeleted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMessageDeleted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1529,14 +1841,15 @@ impl TDChatEventAction for ChatEventMessageDeleted {}
 
 
 impl ChatEventMessageDeleted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMessageDeletedBuilder {
-    let mut inner = ChatEventMessageDeleted::default();
-    inner.td_name = "chatEventMessageDeleted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMessageDeleted { td_name: "chatEventMessageDeleted".to_string(), ..Default::default() };
     RTDChatEventMessageDeletedBuilder { inner }
   }
 
+  /// `ChatEventMessageDeleted` with the fields tdlib requires, the others are set by `ChatEventMessageDeleted::builder()`
+  pub fn new(message: impl AsRef<Message>) -> Self { Self::builder().message(message).build() }
+
   pub fn message(&self) -> &Message { &self.message }
 
 }
@@ -1549,6 +1862,12 @@ pub struct RTDChatEventMessageDeletedBuilder {
 impl RTDChatEventMessageDeletedBuilder {
   pub fn build(&self) -> ChatEventMessageDeleted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -1565,6 +1884,12 @@ impl AsRef<ChatEventMessageDeleted> for RTDChatEventMessageDeletedBuilder {
   fn as_ref(&self) -> &ChatEventMessageDeleted { &self.inner }
 }
 
+impl PartialEq for ChatEventMessageDeleted {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+  }
+}
+
 
 
 
@@ -1579,7 +1904,10 @@ pub struct ChatEventMessageEdited {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The original message before the edit
   old_message: Message,
   /// The message after it was edited
@@ -1588,8 +1916,9 @@ pub struct ChatEventMessageEdited {
 }
 
 impl RObject for ChatEventMessageEdited {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessageEdited" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMessageEdited" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1599,14 +1928,15 @@ impl TDChatEventAction for ChatEventMessageEdited {}
 
 
 impl ChatEventMessageEdited {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMessageEditedBuilder {
-    let mut inner = ChatEventMessageEdited::default();
-    inner.td_name = "chatEventMessageEdited".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMessageEdited { td_name: "chatEventMessageEdited".to_string(), ..Default::default() };
     RTDChatEventMessageEditedBuilder { inner }
   }
 
+  /// `ChatEventMessageEdited` with the fields tdlib requires, the others are set by `ChatEventMessageEdited::builder()`
+  pub fn new(old_message: impl AsRef<Message>, new_message: impl AsRef<Message>) -> Self { Self::builder().old_message(old_message).new_message(new_message).build() }
+
   pub fn old_message(&self) -> &Message { &self.old_message }
 
   pub fn new_message(&self) -> &Message { &self.new_message }
@@ -1621,6 +1951,12 @@ pub struct RTDChatEventMessageEditedBuilder {
 impl RTDChatEventMessageEditedBuilder {
   pub fn build(&self) -> ChatEventMessageEdited { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_message<T: AsRef<Message>>(&mut self, old_message: T) -> &mut Self {
     self.inner.old_message = old_message.as_ref().clone();
@@ -1643,6 +1979,13 @@ impl AsRef<ChatEventMessageEdited> for RTDChatEventMessageEditedBuilder {
   fn as_ref(&self) -> &ChatEventMessageEdited { &self.inner }
 }
 
+impl PartialEq for ChatEventMessageEdited {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_message == other.old_message
+      && self.new_message == other.new_message
+  }
+}
+
 
 
 
@@ -1657,15 +2000,19 @@ pub struct ChatEventMessagePinned {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Pinned message
   message: Message,
   
 }
 
 impl RObject for ChatEventMessagePinned {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessagePinned" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMessagePinned" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1675,14 +2022,15 @@ impl TDChatEventAction for ChatEventMessagePinned {}
 
 
 impl ChatEventMessagePinned {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMessagePinnedBuilder {
-    let mut inner = ChatEventMessagePinned::default();
-    inner.td_name = "chatEventMessagePinned".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMessagePinned { td_name: "chatEventMessagePinned".to_string(), ..Default::default() };
     RTDChatEventMessagePinnedBuilder { inner }
   }
 
+  /// `ChatEventMessagePinned` with the fields tdlib requires, the others are set by `ChatEventMessagePinned::builder()`
+  pub fn new(message: impl AsRef<Message>) -> Self { Self::builder().message(message).build() }
+
   pub fn message(&self) -> &Message { &self.message }
 
 }
@@ -1695,6 +2043,12 @@ pub struct RTDChatEventMessagePinnedBuilder {
 impl RTDChatEventMessagePinnedBuilder {
   pub fn build(&self) -> ChatEventMessagePinned { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -1711,6 +2065,12 @@ impl AsRef<ChatEventMessagePinned> for RTDChatEventMessagePinnedBuilder {
   fn as_ref(&self) -> &ChatEventMessagePinned { &self.inner }
 }
 
+impl PartialEq for ChatEventMessagePinned {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+  }
+}
+
 
 
 
@@ -1725,7 +2085,10 @@ pub struct ChatEventMessageTtlSettingChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous value of message_ttl_setting
   old_message_ttl_setting: i64,
   /// New value of message_ttl_setting
@@ -1734,8 +2097,9 @@ pub struct ChatEventMessageTtlSettingChanged {
 }
 
 impl RObject for ChatEventMessageTtlSettingChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessageTtlSettingChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMessageTtlSettingChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1745,14 +2109,15 @@ impl TDChatEventAction for ChatEventMessageTtlSettingChanged {}
 
 
 impl ChatEventMessageTtlSettingChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMessageTtlSettingChangedBuilder {
-    let mut inner = ChatEventMessageTtlSettingChanged::default();
-    inner.td_name = "chatEventMessageTtlSettingChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMessageTtlSettingChanged { td_name: "chatEventMessageTtlSettingChanged".to_string(), ..Default::default() };
     RTDChatEventMessageTtlSettingChangedBuilder { inner }
   }
 
+  /// `ChatEventMessageTtlSettingChanged` with the fields tdlib requires, the others are set by `ChatEventMessageTtlSettingChanged::builder()`
+  pub fn new(old_message_ttl_setting: i64, new_message_ttl_setting: i64) -> Self { Self::builder().old_message_ttl_setting(old_message_ttl_setting).new_message_ttl_setting(new_message_ttl_setting).build() }
+
   pub fn old_message_ttl_setting(&self) -> i64 { self.old_message_ttl_setting }
 
   pub fn new_message_ttl_setting(&self) -> i64 { self.new_message_ttl_setting }
@@ -1767,6 +2132,12 @@ pub struct RTDChatEventMessageTtlSettingChangedBuilder {
 impl RTDChatEventMessageTtlSettingChangedBuilder {
   pub fn build(&self) -> ChatEventMessageTtlSettingChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_message_ttl_setting(&mut self, old_message_ttl_setting: i64) -> &mut Self {
     self.inner.old_message_ttl_setting = old_message_ttl_setting;
@@ -1789,6 +2160,13 @@ impl AsRef<ChatEventMessageTtlSettingChanged> for RTDChatEventMessageTtlSettingC
   fn as_ref(&self) -> &ChatEventMessageTtlSettingChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventMessageTtlSettingChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_message_ttl_setting == other.old_message_ttl_setting
+      && self.new_message_ttl_setting == other.new_message_ttl_setting
+  }
+}
+
 
 
 
@@ -1803,15 +2181,19 @@ pub struct ChatEventMessageUnpinned {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unpinned message
   message: Message,
   
 }
 
 impl RObject for ChatEventMessageUnpinned {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventMessageUnpinned" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventMessageUnpinned" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1821,14 +2203,15 @@ impl TDChatEventAction for ChatEventMessageUnpinned {}
 
 
 impl ChatEventMessageUnpinned {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventMessageUnpinnedBuilder {
-    let mut inner = ChatEventMessageUnpinned::default();
-    inner.td_name = "chatEventMessageUnpinned".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventMessageUnpinned { td_name: "chatEventMessageUnpinned".to_string(), ..Default::default() };
     RTDChatEventMessageUnpinnedBuilder { inner }
   }
 
+  /// `ChatEventMessageUnpinned` with the fields tdlib requires, the others are set by `ChatEventMessageUnpinned::builder()`
+  pub fn new(message: impl AsRef<Message>) -> Self { Self::builder().message(message).build() }
+
   pub fn message(&self) -> &Message { &self.message }
 
 }
@@ -1841,6 +2224,12 @@ pub struct RTDChatEventMessageUnpinnedBuilder {
 impl RTDChatEventMessageUnpinnedBuilder {
   pub fn build(&self) -> ChatEventMessageUnpinned { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -1857,6 +2246,12 @@ impl AsRef<ChatEventMessageUnpinned> for RTDChatEventMessageUnpinnedBuilder {
   fn as_ref(&self) -> &ChatEventMessageUnpinned { &self.inner }
 }
 
+impl PartialEq for ChatEventMessageUnpinned {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+  }
+}
+
 
 
 
@@ -1871,7 +2266,10 @@ pub struct ChatEventPermissionsChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous chat permissions
   old_permissions: ChatPermissions,
   /// New chat permissions
@@ -1880,8 +2278,9 @@ pub struct ChatEventPermissionsChanged {
 }
 
 impl RObject for ChatEventPermissionsChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventPermissionsChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventPermissionsChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1891,14 +2290,15 @@ impl TDChatEventAction for ChatEventPermissionsChanged {}
 
 
 impl ChatEventPermissionsChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventPermissionsChangedBuilder {
-    let mut inner = ChatEventPermissionsChanged::default();
-    inner.td_name = "chatEventPermissionsChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventPermissionsChanged { td_name: "chatEventPermissionsChanged".to_string(), ..Default::default() };
     RTDChatEventPermissionsChangedBuilder { inner }
   }
 
+  /// `ChatEventPermissionsChanged` with the fields tdlib requires, the others are set by `ChatEventPermissionsChanged::builder()`
+  pub fn new(old_permissions: impl AsRef<ChatPermissions>, new_permissions: impl AsRef<ChatPermissions>) -> Self { Self::builder().old_permissions(old_permissions).new_permissions(new_permissions).build() }
+
   pub fn old_permissions(&self) -> &ChatPermissions { &self.old_permissions }
 
   pub fn new_permissions(&self) -> &ChatPermissions { &self.new_permissions }
@@ -1913,6 +2313,12 @@ pub struct RTDChatEventPermissionsChangedBuilder {
 impl RTDChatEventPermissionsChangedBuilder {
   pub fn build(&self) -> ChatEventPermissionsChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_permissions<T: AsRef<ChatPermissions>>(&mut self, old_permissions: T) -> &mut Self {
     self.inner.old_permissions = old_permissions.as_ref().clone();
@@ -1935,6 +2341,13 @@ impl AsRef<ChatEventPermissionsChanged> for RTDChatEventPermissionsChangedBuilde
   fn as_ref(&self) -> &ChatEventPermissionsChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventPermissionsChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_permissions == other.old_permissions
+      && self.new_permissions == other.new_permissions
+  }
+}
+
 
 
 
@@ -1949,7 +2362,10 @@ pub struct ChatEventPhotoChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous chat photo value; may be null
   old_photo: Option<ChatPhoto>,
   /// New chat photo value; may be null
@@ -1958,8 +2374,9 @@ pub struct ChatEventPhotoChanged {
 }
 
 impl RObject for ChatEventPhotoChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventPhotoChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventPhotoChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1969,14 +2386,15 @@ impl TDChatEventAction for ChatEventPhotoChanged {}
 
 
 impl ChatEventPhotoChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventPhotoChangedBuilder {
-    let mut inner = ChatEventPhotoChanged::default();
-    inner.td_name = "chatEventPhotoChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventPhotoChanged { td_name: "chatEventPhotoChanged".to_string(), ..Default::default() };
     RTDChatEventPhotoChangedBuilder { inner }
   }
 
+  /// `ChatEventPhotoChanged` with the fields tdlib requires, the others are set by `ChatEventPhotoChanged::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
   pub fn old_photo(&self) -> &Option<ChatPhoto> { &self.old_photo }
 
   pub fn new_photo(&self) -> &Option<ChatPhoto> { &self.new_photo }
@@ -1991,6 +2409,12 @@ pub struct RTDChatEventPhotoChangedBuilder {
 impl RTDChatEventPhotoChangedBuilder {
   pub fn build(&self) -> ChatEventPhotoChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_photo<T: AsRef<ChatPhoto>>(&mut self, old_photo: T) -> &mut Self {
     self.inner.old_photo = Some(old_photo.as_ref().clone());
@@ -2013,6 +2437,13 @@ impl AsRef<ChatEventPhotoChanged> for RTDChatEventPhotoChangedBuilder {
   fn as_ref(&self) -> &ChatEventPhotoChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventPhotoChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_photo == other.old_photo
+      && self.new_photo == other.new_photo
+  }
+}
+
 
 
 
@@ -2027,15 +2458,19 @@ pub struct ChatEventPollStopped {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The message with the poll
   message: Message,
   
 }
 
 impl RObject for ChatEventPollStopped {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventPollStopped" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventPollStopped" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2045,14 +2480,15 @@ impl TDChatEventAction for ChatEventPollStopped {}
 
 
 impl ChatEventPollStopped {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventPollStoppedBuilder {
-    let mut inner = ChatEventPollStopped::default();
-    inner.td_name = "chatEventPollStopped".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventPollStopped { td_name: "chatEventPollStopped".to_string(), ..Default::default() };
     RTDChatEventPollStoppedBuilder { inner }
   }
 
+  /// `ChatEventPollStopped` with the fields tdlib requires, the others are set by `ChatEventPollStopped::builder()`
+  pub fn new(message: impl AsRef<Message>) -> Self { Self::builder().message(message).build() }
+
   pub fn message(&self) -> &Message { &self.message }
 
 }
@@ -2065,6 +2501,12 @@ pub struct RTDChatEventPollStoppedBuilder {
 impl RTDChatEventPollStoppedBuilder {
   pub fn build(&self) -> ChatEventPollStopped { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -2081,6 +2523,12 @@ impl AsRef<ChatEventPollStopped> for RTDChatEventPollStoppedBuilder {
   fn as_ref(&self) -> &ChatEventPollStopped { &self.inner }
 }
 
+impl PartialEq for ChatEventPollStopped {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+  }
+}
+
 
 
 
@@ -2095,15 +2543,19 @@ pub struct ChatEventSignMessagesToggled {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New value of sign_messages
   sign_messages: bool,
   
 }
 
 impl RObject for ChatEventSignMessagesToggled {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventSignMessagesToggled" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventSignMessagesToggled" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2113,14 +2565,15 @@ impl TDChatEventAction for ChatEventSignMessagesToggled {}
 
 
 impl ChatEventSignMessagesToggled {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventSignMessagesToggledBuilder {
-    let mut inner = ChatEventSignMessagesToggled::default();
-    inner.td_name = "chatEventSignMessagesToggled".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventSignMessagesToggled { td_name: "chatEventSignMessagesToggled".to_string(), ..Default::default() };
     RTDChatEventSignMessagesToggledBuilder { inner }
   }
 
+  /// `ChatEventSignMessagesToggled` with the fields tdlib requires, the others are set by `ChatEventSignMessagesToggled::builder()`
+  pub fn new(sign_messages: bool) -> Self { Self::builder().sign_messages(sign_messages).build() }
+
   pub fn sign_messages(&self) -> bool { self.sign_messages }
 
 }
@@ -2133,6 +2586,12 @@ pub struct RTDChatEventSignMessagesToggledBuilder {
 impl RTDChatEventSignMessagesToggledBuilder {
   pub fn build(&self) -> ChatEventSignMessagesToggled { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sign_messages(&mut self, sign_messages: bool) -> &mut Self {
     self.inner.sign_messages = sign_messages;
@@ -2149,6 +2608,12 @@ impl AsRef<ChatEventSignMessagesToggled> for RTDChatEventSignMessagesToggledBuil
   fn as_ref(&self) -> &ChatEventSignMessagesToggled { &self.inner }
 }
 
+impl PartialEq for ChatEventSignMessagesToggled {
+  fn eq(&self, other: &Self) -> bool {
+    self.sign_messages == other.sign_messages
+  }
+}
+
 
 
 
@@ -2163,7 +2628,10 @@ pub struct ChatEventSlowModeDelayChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous value of slow_mode_delay, in seconds
   old_slow_mode_delay: i64,
   /// New value of slow_mode_delay, in seconds
@@ -2172,8 +2640,9 @@ pub struct ChatEventSlowModeDelayChanged {
 }
 
 impl RObject for ChatEventSlowModeDelayChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventSlowModeDelayChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventSlowModeDelayChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2183,14 +2652,15 @@ impl TDChatEventAction for ChatEventSlowModeDelayChanged {}
 
 
 impl ChatEventSlowModeDelayChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventSlowModeDelayChangedBuilder {
-    let mut inner = ChatEventSlowModeDelayChanged::default();
-    inner.td_name = "chatEventSlowModeDelayChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventSlowModeDelayChanged { td_name: "chatEventSlowModeDelayChanged".to_string(), ..Default::default() };
     RTDChatEventSlowModeDelayChangedBuilder { inner }
   }
 
+  /// `ChatEventSlowModeDelayChanged` with the fields tdlib requires, the others are set by `ChatEventSlowModeDelayChanged::builder()`
+  pub fn new(old_slow_mode_delay: i64, new_slow_mode_delay: i64) -> Self { Self::builder().old_slow_mode_delay(old_slow_mode_delay).new_slow_mode_delay(new_slow_mode_delay).build() }
+
   pub fn old_slow_mode_delay(&self) -> i64 { self.old_slow_mode_delay }
 
   pub fn new_slow_mode_delay(&self) -> i64 { self.new_slow_mode_delay }
@@ -2205,6 +2675,12 @@ pub struct RTDChatEventSlowModeDelayChangedBuilder {
 impl RTDChatEventSlowModeDelayChangedBuilder {
   pub fn build(&self) -> ChatEventSlowModeDelayChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_slow_mode_delay(&mut self, old_slow_mode_delay: i64) -> &mut Self {
     self.inner.old_slow_mode_delay = old_slow_mode_delay;
@@ -2227,12 +2703,21 @@ impl AsRef<ChatEventSlowModeDelayChanged> for RTDChatEventSlowModeDelayChangedBu
   fn as_ref(&self) -> &ChatEventSlowModeDelayChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventSlowModeDelayChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_slow_mode_delay == other.old_slow_mode_delay
+      && self.new_slow_mode_delay == other.new_slow_mode_delay
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// The supergroup sticker set was changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChatEventStickerSetChanged {
@@ -2241,17 +2726,21 @@ pub struct ChatEventStickerSetChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous identifier of the chat sticker set; 0 if none
-  old_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] old_sticker_set_id: i64,
   /// New identifier of the chat sticker set; 0 if none
-  new_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] new_sticker_set_id: i64,
   
 }
 
 impl RObject for ChatEventStickerSetChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventStickerSetChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventStickerSetChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2261,17 +2750,18 @@ impl TDChatEventAction for ChatEventStickerSetChanged {}
 
 
 impl ChatEventStickerSetChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventStickerSetChangedBuilder {
-    let mut inner = ChatEventStickerSetChanged::default();
-    inner.td_name = "chatEventStickerSetChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventStickerSetChanged { td_name: "chatEventStickerSetChanged".to_string(), ..Default::default() };
     RTDChatEventStickerSetChangedBuilder { inner }
   }
 
-  pub fn old_sticker_set_id(&self) -> isize { self.old_sticker_set_id }
+  /// `ChatEventStickerSetChanged` with the fields tdlib requires, the others are set by `ChatEventStickerSetChanged::builder()`
+  pub fn new(old_sticker_set_id: i64, new_sticker_set_id: i64) -> Self { Self::builder().old_sticker_set_id(old_sticker_set_id).new_sticker_set_id(new_sticker_set_id).build() }
+
+  pub fn old_sticker_set_id(&self) -> i64 { self.old_sticker_set_id }
 
-  pub fn new_sticker_set_id(&self) -> isize { self.new_sticker_set_id }
+  pub fn new_sticker_set_id(&self) -> i64 { self.new_sticker_set_id }
 
 }
 
@@ -2283,14 +2773,20 @@ pub struct RTDChatEventStickerSetChangedBuilder {
 impl RTDChatEventStickerSetChangedBuilder {
   pub fn build(&self) -> ChatEventStickerSetChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn old_sticker_set_id(&mut self, old_sticker_set_id: isize) -> &mut Self {
+  pub fn old_sticker_set_id(&mut self, old_sticker_set_id: i64) -> &mut Self {
     self.inner.old_sticker_set_id = old_sticker_set_id;
     self
   }
 
    
-  pub fn new_sticker_set_id(&mut self, new_sticker_set_id: isize) -> &mut Self {
+  pub fn new_sticker_set_id(&mut self, new_sticker_set_id: i64) -> &mut Self {
     self.inner.new_sticker_set_id = new_sticker_set_id;
     self
   }
@@ -2305,6 +2801,15 @@ impl AsRef<ChatEventStickerSetChanged> for RTDChatEventStickerSetChangedBuilder
   fn as_ref(&self) -> &ChatEventStickerSetChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventStickerSetChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_sticker_set_id == other.old_sticker_set_id
+      && self.new_sticker_set_id == other.new_sticker_set_id
+  }
+}
+
+}
+
 
 
 
@@ -2319,7 +2824,10 @@ pub struct ChatEventTitleChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous chat title
   old_title: String,
   /// New chat title
@@ -2328,8 +2836,9 @@ pub struct ChatEventTitleChanged {
 }
 
 impl RObject for ChatEventTitleChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventTitleChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventTitleChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2339,14 +2848,15 @@ impl TDChatEventAction for ChatEventTitleChanged {}
 
 
 impl ChatEventTitleChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventTitleChangedBuilder {
-    let mut inner = ChatEventTitleChanged::default();
-    inner.td_name = "chatEventTitleChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventTitleChanged { td_name: "chatEventTitleChanged".to_string(), ..Default::default() };
     RTDChatEventTitleChangedBuilder { inner }
   }
 
+  /// `ChatEventTitleChanged` with the fields tdlib requires, the others are set by `ChatEventTitleChanged::builder()`
+  pub fn new(old_title: impl AsRef<str>, new_title: impl AsRef<str>) -> Self { Self::builder().old_title(old_title).new_title(new_title).build() }
+
   pub fn old_title(&self) -> &String { &self.old_title }
 
   pub fn new_title(&self) -> &String { &self.new_title }
@@ -2361,6 +2871,12 @@ pub struct RTDChatEventTitleChangedBuilder {
 impl RTDChatEventTitleChangedBuilder {
   pub fn build(&self) -> ChatEventTitleChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_title<T: AsRef<str>>(&mut self, old_title: T) -> &mut Self {
     self.inner.old_title = old_title.as_ref().to_string();
@@ -2383,6 +2899,13 @@ impl AsRef<ChatEventTitleChanged> for RTDChatEventTitleChangedBuilder {
   fn as_ref(&self) -> &ChatEventTitleChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventTitleChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_title == other.old_title
+      && self.new_title == other.new_title
+  }
+}
+
 
 
 
@@ -2397,7 +2920,10 @@ pub struct ChatEventUsernameChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Previous chat username
   old_username: String,
   /// New chat username
@@ -2406,8 +2932,9 @@ pub struct ChatEventUsernameChanged {
 }
 
 impl RObject for ChatEventUsernameChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventUsernameChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventUsernameChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2417,14 +2944,15 @@ impl TDChatEventAction for ChatEventUsernameChanged {}
 
 
 impl ChatEventUsernameChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventUsernameChangedBuilder {
-    let mut inner = ChatEventUsernameChanged::default();
-    inner.td_name = "chatEventUsernameChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventUsernameChanged { td_name: "chatEventUsernameChanged".to_string(), ..Default::default() };
     RTDChatEventUsernameChangedBuilder { inner }
   }
 
+  /// `ChatEventUsernameChanged` with the fields tdlib requires, the others are set by `ChatEventUsernameChanged::builder()`
+  pub fn new(old_username: impl AsRef<str>, new_username: impl AsRef<str>) -> Self { Self::builder().old_username(old_username).new_username(new_username).build() }
+
   pub fn old_username(&self) -> &String { &self.old_username }
 
   pub fn new_username(&self) -> &String { &self.new_username }
@@ -2439,6 +2967,12 @@ pub struct RTDChatEventUsernameChangedBuilder {
 impl RTDChatEventUsernameChangedBuilder {
   pub fn build(&self) -> ChatEventUsernameChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_username<T: AsRef<str>>(&mut self, old_username: T) -> &mut Self {
     self.inner.old_username = old_username.as_ref().to_string();
@@ -2461,6 +2995,13 @@ impl AsRef<ChatEventUsernameChanged> for RTDChatEventUsernameChangedBuilder {
   fn as_ref(&self) -> &ChatEventUsernameChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventUsernameChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_username == other.old_username
+      && self.new_username == other.new_username
+  }
+}
+
 
 
 
@@ -2475,15 +3016,19 @@ pub struct ChatEventVideoChatCreated {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the video chat. The video chat can be received through the method getGroupCall
   group_call_id: i64,
   
 }
 
 impl RObject for ChatEventVideoChatCreated {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventVideoChatCreated" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventVideoChatCreated" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2493,14 +3038,15 @@ impl TDChatEventAction for ChatEventVideoChatCreated {}
 
 
 impl ChatEventVideoChatCreated {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventVideoChatCreatedBuilder {
-    let mut inner = ChatEventVideoChatCreated::default();
-    inner.td_name = "chatEventVideoChatCreated".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventVideoChatCreated { td_name: "chatEventVideoChatCreated".to_string(), ..Default::default() };
     RTDChatEventVideoChatCreatedBuilder { inner }
   }
 
+  /// `ChatEventVideoChatCreated` with the fields tdlib requires, the others are set by `ChatEventVideoChatCreated::builder()`
+  pub fn new(group_call_id: i64) -> Self { Self::builder().group_call_id(group_call_id).build() }
+
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
 
 }
@@ -2513,6 +3059,12 @@ pub struct RTDChatEventVideoChatCreatedBuilder {
 impl RTDChatEventVideoChatCreatedBuilder {
   pub fn build(&self) -> ChatEventVideoChatCreated { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
@@ -2529,6 +3081,12 @@ impl AsRef<ChatEventVideoChatCreated> for RTDChatEventVideoChatCreatedBuilder {
   fn as_ref(&self) -> &ChatEventVideoChatCreated { &self.inner }
 }
 
+impl PartialEq for ChatEventVideoChatCreated {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call_id == other.group_call_id
+  }
+}
+
 
 
 
@@ -2543,15 +3101,19 @@ pub struct ChatEventVideoChatDiscarded {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the video chat. The video chat can be received through the method getGroupCall
   group_call_id: i64,
   
 }
 
 impl RObject for ChatEventVideoChatDiscarded {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventVideoChatDiscarded" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventVideoChatDiscarded" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2561,14 +3123,15 @@ impl TDChatEventAction for ChatEventVideoChatDiscarded {}
 
 
 impl ChatEventVideoChatDiscarded {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventVideoChatDiscardedBuilder {
-    let mut inner = ChatEventVideoChatDiscarded::default();
-    inner.td_name = "chatEventVideoChatDiscarded".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventVideoChatDiscarded { td_name: "chatEventVideoChatDiscarded".to_string(), ..Default::default() };
     RTDChatEventVideoChatDiscardedBuilder { inner }
   }
 
+  /// `ChatEventVideoChatDiscarded` with the fields tdlib requires, the others are set by `ChatEventVideoChatDiscarded::builder()`
+  pub fn new(group_call_id: i64) -> Self { Self::builder().group_call_id(group_call_id).build() }
+
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
 
 }
@@ -2581,6 +3144,12 @@ pub struct RTDChatEventVideoChatDiscardedBuilder {
 impl RTDChatEventVideoChatDiscardedBuilder {
   pub fn build(&self) -> ChatEventVideoChatDiscarded { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
@@ -2597,6 +3166,12 @@ impl AsRef<ChatEventVideoChatDiscarded> for RTDChatEventVideoChatDiscardedBuilde
   fn as_ref(&self) -> &ChatEventVideoChatDiscarded { &self.inner }
 }
 
+impl PartialEq for ChatEventVideoChatDiscarded {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call_id == other.group_call_id
+  }
+}
+
 
 
 
@@ -2611,15 +3186,19 @@ pub struct ChatEventVideoChatMuteNewParticipantsToggled {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New value of the mute_new_participants setting
   mute_new_participants: bool,
   
 }
 
 impl RObject for ChatEventVideoChatMuteNewParticipantsToggled {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventVideoChatMuteNewParticipantsToggled" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventVideoChatMuteNewParticipantsToggled" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2629,14 +3208,15 @@ impl TDChatEventAction for ChatEventVideoChatMuteNewParticipantsToggled {}
 
 
 impl ChatEventVideoChatMuteNewParticipantsToggled {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventVideoChatMuteNewParticipantsToggledBuilder {
-    let mut inner = ChatEventVideoChatMuteNewParticipantsToggled::default();
-    inner.td_name = "chatEventVideoChatMuteNewParticipantsToggled".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventVideoChatMuteNewParticipantsToggled { td_name: "chatEventVideoChatMuteNewParticipantsToggled".to_string(), ..Default::default() };
     RTDChatEventVideoChatMuteNewParticipantsToggledBuilder { inner }
   }
 
+  /// `ChatEventVideoChatMuteNewParticipantsToggled` with the fields tdlib requires, the others are set by `ChatEventVideoChatMuteNewParticipantsToggled::builder()`
+  pub fn new(mute_new_participants: bool) -> Self { Self::builder().mute_new_participants(mute_new_participants).build() }
+
   pub fn mute_new_participants(&self) -> bool { self.mute_new_participants }
 
 }
@@ -2649,6 +3229,12 @@ pub struct RTDChatEventVideoChatMuteNewParticipantsToggledBuilder {
 impl RTDChatEventVideoChatMuteNewParticipantsToggledBuilder {
   pub fn build(&self) -> ChatEventVideoChatMuteNewParticipantsToggled { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn mute_new_participants(&mut self, mute_new_participants: bool) -> &mut Self {
     self.inner.mute_new_participants = mute_new_participants;
@@ -2665,6 +3251,12 @@ impl AsRef<ChatEventVideoChatMuteNewParticipantsToggled> for RTDChatEventVideoCh
   fn as_ref(&self) -> &ChatEventVideoChatMuteNewParticipantsToggled { &self.inner }
 }
 
+impl PartialEq for ChatEventVideoChatMuteNewParticipantsToggled {
+  fn eq(&self, other: &Self) -> bool {
+    self.mute_new_participants == other.mute_new_participants
+  }
+}
+
 
 
 
@@ -2679,7 +3271,10 @@ pub struct ChatEventVideoChatParticipantIsMutedToggled {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the affected group call participant
   participant_id: MessageSender,
   /// New value of is_muted
@@ -2688,8 +3283,9 @@ pub struct ChatEventVideoChatParticipantIsMutedToggled {
 }
 
 impl RObject for ChatEventVideoChatParticipantIsMutedToggled {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventVideoChatParticipantIsMutedToggled" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventVideoChatParticipantIsMutedToggled" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2699,14 +3295,15 @@ impl TDChatEventAction for ChatEventVideoChatParticipantIsMutedToggled {}
 
 
 impl ChatEventVideoChatParticipantIsMutedToggled {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventVideoChatParticipantIsMutedToggledBuilder {
-    let mut inner = ChatEventVideoChatParticipantIsMutedToggled::default();
-    inner.td_name = "chatEventVideoChatParticipantIsMutedToggled".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventVideoChatParticipantIsMutedToggled { td_name: "chatEventVideoChatParticipantIsMutedToggled".to_string(), ..Default::default() };
     RTDChatEventVideoChatParticipantIsMutedToggledBuilder { inner }
   }
 
+  /// `ChatEventVideoChatParticipantIsMutedToggled` with the fields tdlib requires, the others are set by `ChatEventVideoChatParticipantIsMutedToggled::builder()`
+  pub fn new(participant_id: impl AsRef<MessageSender>, is_muted: bool) -> Self { Self::builder().participant_id(participant_id).is_muted(is_muted).build() }
+
   pub fn participant_id(&self) -> &MessageSender { &self.participant_id }
 
   pub fn is_muted(&self) -> bool { self.is_muted }
@@ -2721,6 +3318,12 @@ pub struct RTDChatEventVideoChatParticipantIsMutedToggledBuilder {
 impl RTDChatEventVideoChatParticipantIsMutedToggledBuilder {
   pub fn build(&self) -> ChatEventVideoChatParticipantIsMutedToggled { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn participant_id<T: AsRef<MessageSender>>(&mut self, participant_id: T) -> &mut Self {
     self.inner.participant_id = participant_id.as_ref().clone();
@@ -2743,6 +3346,13 @@ impl AsRef<ChatEventVideoChatParticipantIsMutedToggled> for RTDChatEventVideoCha
   fn as_ref(&self) -> &ChatEventVideoChatParticipantIsMutedToggled { &self.inner }
 }
 
+impl PartialEq for ChatEventVideoChatParticipantIsMutedToggled {
+  fn eq(&self, other: &Self) -> bool {
+    self.participant_id == other.participant_id
+      && self.is_muted == other.is_muted
+  }
+}
+
 
 
 
@@ -2757,7 +3367,10 @@ pub struct ChatEventVideoChatParticipantVolumeLevelChanged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the affected group call participant
   participant_id: MessageSender,
   /// New value of volume_level; 1-20000 in hundreds of percents
@@ -2766,8 +3379,9 @@ pub struct ChatEventVideoChatParticipantVolumeLevelChanged {
 }
 
 impl RObject for ChatEventVideoChatParticipantVolumeLevelChanged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventVideoChatParticipantVolumeLevelChanged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventVideoChatParticipantVolumeLevelChanged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2777,14 +3391,15 @@ impl TDChatEventAction for ChatEventVideoChatParticipantVolumeLevelChanged {}
 
 
 impl ChatEventVideoChatParticipantVolumeLevelChanged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventVideoChatParticipantVolumeLevelChangedBuilder {
-    let mut inner = ChatEventVideoChatParticipantVolumeLevelChanged::default();
-    inner.td_name = "chatEventVideoChatParticipantVolumeLevelChanged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventVideoChatParticipantVolumeLevelChanged { td_name: "chatEventVideoChatParticipantVolumeLevelChanged".to_string(), ..Default::default() };
     RTDChatEventVideoChatParticipantVolumeLevelChangedBuilder { inner }
   }
 
+  /// `ChatEventVideoChatParticipantVolumeLevelChanged` with the fields tdlib requires, the others are set by `ChatEventVideoChatParticipantVolumeLevelChanged::builder()`
+  pub fn new(participant_id: impl AsRef<MessageSender>, volume_level: i64) -> Self { Self::builder().participant_id(participant_id).volume_level(volume_level).build() }
+
   pub fn participant_id(&self) -> &MessageSender { &self.participant_id }
 
   pub fn volume_level(&self) -> i64 { self.volume_level }
@@ -2799,6 +3414,12 @@ pub struct RTDChatEventVideoChatParticipantVolumeLevelChangedBuilder {
 impl RTDChatEventVideoChatParticipantVolumeLevelChangedBuilder {
   pub fn build(&self) -> ChatEventVideoChatParticipantVolumeLevelChanged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn participant_id<T: AsRef<MessageSender>>(&mut self, participant_id: T) -> &mut Self {
     self.inner.participant_id = participant_id.as_ref().clone();
@@ -2821,5 +3442,12 @@ impl AsRef<ChatEventVideoChatParticipantVolumeLevelChanged> for RTDChatEventVide
   fn as_ref(&self) -> &ChatEventVideoChatParticipantVolumeLevelChanged { &self.inner }
 }
 
+impl PartialEq for ChatEventVideoChatParticipantVolumeLevelChanged {
+  fn eq(&self, other: &Self) -> bool {
+    self.participant_id == other.participant_id
+      && self.volume_level == other.volume_level
+  }
+}
+
 
 
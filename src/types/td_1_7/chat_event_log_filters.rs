@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatEventLogFilters {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if message edits need to be returned
   message_edits: bool,
   /// True, if message deletions need to be returned
@@ -43,22 +45,53 @@ pub struct ChatEventLogFilters {
 }
 
 impl RObject for ChatEventLogFilters {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEventLogFilters" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEventLogFilters" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatEventLogFilters {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventLogFiltersBuilder {
-    let mut inner = ChatEventLogFilters::default();
-    inner.td_name = "chatEventLogFilters".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEventLogFilters { td_name: "chatEventLogFilters".to_string(), ..Default::default() };
     RTDChatEventLogFiltersBuilder { inner }
   }
 
+  /// `ChatEventLogFilters` with the fields tdlib requires, the others are set by `ChatEventLogFilters::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    message_edits: bool,
+    message_deletions: bool,
+    message_pins: bool,
+    member_joins: bool,
+    member_leaves: bool,
+    member_invites: bool,
+    member_promotions: bool,
+    member_restrictions: bool,
+    info_changes: bool,
+    setting_changes: bool,
+    invite_link_changes: bool,
+    video_chat_changes: bool,
+  ) -> Self {
+    Self::builder()
+      .message_edits(message_edits)
+      .message_deletions(message_deletions)
+      .message_pins(message_pins)
+      .member_joins(member_joins)
+      .member_leaves(member_leaves)
+      .member_invites(member_invites)
+      .member_promotions(member_promotions)
+      .member_restrictions(member_restrictions)
+      .info_changes(info_changes)
+      .setting_changes(setting_changes)
+      .invite_link_changes(invite_link_changes)
+      .video_chat_changes(video_chat_changes)
+      .build()
+  }
+
   pub fn message_edits(&self) -> bool { self.message_edits }
 
   pub fn message_deletions(&self) -> bool { self.message_deletions }
@@ -93,6 +126,12 @@ pub struct RTDChatEventLogFiltersBuilder {
 impl RTDChatEventLogFiltersBuilder {
   pub fn build(&self) -> ChatEventLogFilters { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message_edits(&mut self, message_edits: bool) -> &mut Self {
     self.inner.message_edits = message_edits;
@@ -175,5 +214,22 @@ impl AsRef<ChatEventLogFilters> for RTDChatEventLogFiltersBuilder {
   fn as_ref(&self) -> &ChatEventLogFilters { &self.inner }
 }
 
+impl PartialEq for ChatEventLogFilters {
+  fn eq(&self, other: &Self) -> bool {
+    self.message_edits == other.message_edits
+      && self.message_deletions == other.message_deletions
+      && self.message_pins == other.message_pins
+      && self.member_joins == other.member_joins
+      && self.member_leaves == other.member_leaves
+      && self.member_invites == other.member_invites
+      && self.member_promotions == other.member_promotions
+      && self.member_restrictions == other.member_restrictions
+      && self.info_changes == other.info_changes
+      && self.setting_changes == other.setting_changes
+      && self.invite_link_changes == other.invite_link_changes
+      && self.video_chat_changes == other.video_chat_changes
+  }
+}
+
 
 
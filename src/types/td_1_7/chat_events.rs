@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct ChatEvents {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of events
   events: Vec<ChatEvent>,
   
 }
 
 impl RObject for ChatEvents {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatEvents" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatEvents" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatEvents {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatEventsBuilder {
-    let mut inner = ChatEvents::default();
-    inner.td_name = "chatEvents".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatEvents { td_name: "chatEvents".to_string(), ..Default::default() };
     RTDChatEventsBuilder { inner }
   }
 
+  /// `ChatEvents` with the fields tdlib requires, the others are set by `ChatEvents::builder()`
+  pub fn new(events: Vec<ChatEvent>) -> Self { Self::builder().events(events).build() }
+
   pub fn events(&self) -> &Vec<ChatEvent> { &self.events }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDChatEventsBuilder {
 impl RTDChatEventsBuilder {
   pub fn build(&self) -> ChatEvents { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn events(&mut self, events: Vec<ChatEvent>) -> &mut Self {
     self.inner.events = events;
@@ -65,5 +75,11 @@ impl AsRef<ChatEvents> for RTDChatEventsBuilder {
   fn as_ref(&self) -> &ChatEvents { &self.inner }
 }
 
+impl PartialEq for ChatEvents {
+  fn eq(&self, other: &Self) -> bool {
+    self.events == other.events
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,17 +13,20 @@ pub struct ChatFilter {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The title of the filter; 1-12 characters without line feeds
   title: String,
   /// The icon name for short filter representation. If non-empty, must be one of "All", "Unread", "Unmuted", "Bots", "Channels", "Groups", "Private", "Custom", "Setup", "Cat", "Crown", "Favorite", "Flower", "Game", "Home", "Love", "Mask", "Party", "Sport", "Study", "Trade", "Travel", "Work". If empty, use getChatFilterDefaultIconName to get default icon name for the filter
   icon_name: String,
   /// The chat identifiers of pinned chats in the filtered chat list
-  pinned_chat_ids: Vec<i64>,
+  pinned_chat_ids: Vec<ChatId>,
   /// The chat identifiers of always included chats in the filtered chat list
-  included_chat_ids: Vec<i64>,
+  included_chat_ids: Vec<ChatId>,
   /// The chat identifiers of always excluded chats in the filtered chat list
-  excluded_chat_ids: Vec<i64>,
+  excluded_chat_ids: Vec<ChatId>,
   /// True, if muted chats need to be excluded
   exclude_muted: bool,
   /// True, if read chats need to be excluded
@@ -45,31 +47,64 @@ pub struct ChatFilter {
 }
 
 impl RObject for ChatFilter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatFilter" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatFilter" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatFilter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatFilterBuilder {
-    let mut inner = ChatFilter::default();
-    inner.td_name = "chatFilter".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatFilter { td_name: "chatFilter".to_string(), ..Default::default() };
     RTDChatFilterBuilder { inner }
   }
 
+  /// `ChatFilter` with the fields tdlib requires, the others are set by `ChatFilter::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    title: impl AsRef<str>,
+    icon_name: impl AsRef<str>,
+    pinned_chat_ids: Vec<ChatId>,
+    included_chat_ids: Vec<ChatId>,
+    excluded_chat_ids: Vec<ChatId>,
+    exclude_muted: bool,
+    exclude_read: bool,
+    exclude_archived: bool,
+    include_contacts: bool,
+    include_non_contacts: bool,
+    include_bots: bool,
+    include_groups: bool,
+    include_channels: bool,
+  ) -> Self {
+    Self::builder()
+      .title(title)
+      .icon_name(icon_name)
+      .pinned_chat_ids(pinned_chat_ids)
+      .included_chat_ids(included_chat_ids)
+      .excluded_chat_ids(excluded_chat_ids)
+      .exclude_muted(exclude_muted)
+      .exclude_read(exclude_read)
+      .exclude_archived(exclude_archived)
+      .include_contacts(include_contacts)
+      .include_non_contacts(include_non_contacts)
+      .include_bots(include_bots)
+      .include_groups(include_groups)
+      .include_channels(include_channels)
+      .build()
+  }
+
   pub fn title(&self) -> &String { &self.title }
 
   pub fn icon_name(&self) -> &String { &self.icon_name }
 
-  pub fn pinned_chat_ids(&self) -> &Vec<i64> { &self.pinned_chat_ids }
+  pub fn pinned_chat_ids(&self) -> &Vec<ChatId> { &self.pinned_chat_ids }
 
-  pub fn included_chat_ids(&self) -> &Vec<i64> { &self.included_chat_ids }
+  pub fn included_chat_ids(&self) -> &Vec<ChatId> { &self.included_chat_ids }
 
-  pub fn excluded_chat_ids(&self) -> &Vec<i64> { &self.excluded_chat_ids }
+  pub fn excluded_chat_ids(&self) -> &Vec<ChatId> { &self.excluded_chat_ids }
 
   pub fn exclude_muted(&self) -> bool { self.exclude_muted }
 
@@ -97,6 +132,12 @@ pub struct RTDChatFilterBuilder {
 impl RTDChatFilterBuilder {
   pub fn build(&self) -> ChatFilter { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -110,19 +151,19 @@ impl RTDChatFilterBuilder {
   }
 
    
-  pub fn pinned_chat_ids(&mut self, pinned_chat_ids: Vec<i64>) -> &mut Self {
+  pub fn pinned_chat_ids(&mut self, pinned_chat_ids: Vec<ChatId>) -> &mut Self {
     self.inner.pinned_chat_ids = pinned_chat_ids;
     self
   }
 
    
-  pub fn included_chat_ids(&mut self, included_chat_ids: Vec<i64>) -> &mut Self {
+  pub fn included_chat_ids(&mut self, included_chat_ids: Vec<ChatId>) -> &mut Self {
     self.inner.included_chat_ids = included_chat_ids;
     self
   }
 
    
-  pub fn excluded_chat_ids(&mut self, excluded_chat_ids: Vec<i64>) -> &mut Self {
+  pub fn excluded_chat_ids(&mut self, excluded_chat_ids: Vec<ChatId>) -> &mut Self {
     self.inner.excluded_chat_ids = excluded_chat_ids;
     self
   }
@@ -185,5 +226,29 @@ impl AsRef<ChatFilter> for RTDChatFilterBuilder {
   fn as_ref(&self) -> &ChatFilter { &self.inner }
 }
 
+impl PartialEq for ChatFilter {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.icon_name == other.icon_name
+      && self.pinned_chat_ids == other.pinned_chat_ids
+      && self.included_chat_ids == other.included_chat_ids
+      && self.excluded_chat_ids == other.excluded_chat_ids
+      && self.exclude_muted == other.exclude_muted
+      && self.exclude_read == other.exclude_read
+      && self.exclude_archived == other.exclude_archived
+      && self.include_contacts == other.include_contacts
+      && self.include_non_contacts == other.include_non_contacts
+      && self.include_bots == other.include_bots
+      && self.include_groups == other.include_groups
+      && self.include_channels == other.include_channels
+  }
+}
+
+impl Validate for ChatFilter {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("title", &self.title, 1, 12);
+  }
+}
+
 
 
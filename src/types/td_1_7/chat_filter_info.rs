@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatFilterInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique chat filter identifier
   id: i64,
   /// The title of the filter; 1-12 characters without line feeds
@@ -25,22 +27,24 @@ pub struct ChatFilterInfo {
 }
 
 impl RObject for ChatFilterInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatFilterInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatFilterInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatFilterInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatFilterInfoBuilder {
-    let mut inner = ChatFilterInfo::default();
-    inner.td_name = "chatFilterInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatFilterInfo { td_name: "chatFilterInfo".to_string(), ..Default::default() };
     RTDChatFilterInfoBuilder { inner }
   }
 
+  /// `ChatFilterInfo` with the fields tdlib requires, the others are set by `ChatFilterInfo::builder()`
+  pub fn new(id: i64, title: impl AsRef<str>, icon_name: impl AsRef<str>) -> Self { Self::builder().id(id).title(title).icon_name(icon_name).build() }
+
   pub fn id(&self) -> i64 { self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -57,6 +61,12 @@ pub struct RTDChatFilterInfoBuilder {
 impl RTDChatFilterInfoBuilder {
   pub fn build(&self) -> ChatFilterInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -85,5 +95,13 @@ impl AsRef<ChatFilterInfo> for RTDChatFilterInfoBuilder {
   fn as_ref(&self) -> &ChatFilterInfo { &self.inner }
 }
 
+impl PartialEq for ChatFilterInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.icon_name == other.icon_name
+  }
+}
+
 
 
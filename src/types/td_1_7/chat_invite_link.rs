@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,13 +13,16 @@ pub struct ChatInviteLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat invite link
   invite_link: String,
   /// Name of the link
   name: String,
   /// User identifier of an administrator created the link
-  creator_user_id: i64,
+  creator_user_id: UserId,
   /// Point in time (Unix timestamp) when the link was created
   date: i64,
   /// Point in time (Unix timestamp) when the link was last edited; 0 if never or unknown
@@ -43,27 +45,58 @@ pub struct ChatInviteLink {
 }
 
 impl RObject for ChatInviteLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatInviteLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatInviteLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatInviteLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatInviteLinkBuilder {
-    let mut inner = ChatInviteLink::default();
-    inner.td_name = "chatInviteLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatInviteLink { td_name: "chatInviteLink".to_string(), ..Default::default() };
     RTDChatInviteLinkBuilder { inner }
   }
 
+  /// `ChatInviteLink` with the fields tdlib requires, the others are set by `ChatInviteLink::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    invite_link: impl AsRef<str>,
+    name: impl AsRef<str>,
+    creator_user_id: UserId,
+    date: i64,
+    edit_date: i64,
+    expire_date: i64,
+    member_limit: i64,
+    member_count: i64,
+    pending_join_request_count: i64,
+    creates_join_request: bool,
+    is_primary: bool,
+    is_revoked: bool,
+  ) -> Self {
+    Self::builder()
+      .invite_link(invite_link)
+      .name(name)
+      .creator_user_id(creator_user_id)
+      .date(date)
+      .edit_date(edit_date)
+      .expire_date(expire_date)
+      .member_limit(member_limit)
+      .member_count(member_count)
+      .pending_join_request_count(pending_join_request_count)
+      .creates_join_request(creates_join_request)
+      .is_primary(is_primary)
+      .is_revoked(is_revoked)
+      .build()
+  }
+
   pub fn invite_link(&self) -> &String { &self.invite_link }
 
   pub fn name(&self) -> &String { &self.name }
 
-  pub fn creator_user_id(&self) -> i64 { self.creator_user_id }
+  pub fn creator_user_id(&self) -> UserId { self.creator_user_id }
 
   pub fn date(&self) -> i64 { self.date }
 
@@ -93,6 +126,12 @@ pub struct RTDChatInviteLinkBuilder {
 impl RTDChatInviteLinkBuilder {
   pub fn build(&self) -> ChatInviteLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
@@ -106,7 +145,7 @@ impl RTDChatInviteLinkBuilder {
   }
 
    
-  pub fn creator_user_id(&mut self, creator_user_id: i64) -> &mut Self {
+  pub fn creator_user_id(&mut self, creator_user_id: UserId) -> &mut Self {
     self.inner.creator_user_id = creator_user_id;
     self
   }
@@ -175,5 +214,22 @@ impl AsRef<ChatInviteLink> for RTDChatInviteLinkBuilder {
   fn as_ref(&self) -> &ChatInviteLink { &self.inner }
 }
 
+impl PartialEq for ChatInviteLink {
+  fn eq(&self, other: &Self) -> bool {
+    self.invite_link == other.invite_link
+      && self.name == other.name
+      && self.creator_user_id == other.creator_user_id
+      && self.date == other.date
+      && self.edit_date == other.edit_date
+      && self.expire_date == other.expire_date
+      && self.member_limit == other.member_limit
+      && self.member_count == other.member_count
+      && self.pending_join_request_count == other.pending_join_request_count
+      && self.creates_join_request == other.creates_join_request
+      && self.is_primary == other.is_primary
+      && self.is_revoked == other.is_revoked
+  }
+}
+
 
 
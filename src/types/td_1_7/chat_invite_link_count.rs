@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatInviteLinkCount {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Administrator's user identifier
-  user_id: i64,
+  user_id: UserId,
   /// Number of active invite links
   invite_link_count: i64,
   /// Number of revoked invite links
@@ -25,23 +27,25 @@ pub struct ChatInviteLinkCount {
 }
 
 impl RObject for ChatInviteLinkCount {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatInviteLinkCount" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatInviteLinkCount" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatInviteLinkCount {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatInviteLinkCountBuilder {
-    let mut inner = ChatInviteLinkCount::default();
-    inner.td_name = "chatInviteLinkCount".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatInviteLinkCount { td_name: "chatInviteLinkCount".to_string(), ..Default::default() };
     RTDChatInviteLinkCountBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatInviteLinkCount` with the fields tdlib requires, the others are set by `ChatInviteLinkCount::builder()`
+  pub fn new(user_id: UserId, invite_link_count: i64, revoked_invite_link_count: i64) -> Self { Self::builder().user_id(user_id).invite_link_count(invite_link_count).revoked_invite_link_count(revoked_invite_link_count).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn invite_link_count(&self) -> i64 { self.invite_link_count }
 
@@ -57,8 +61,14 @@ pub struct RTDChatInviteLinkCountBuilder {
 impl RTDChatInviteLinkCountBuilder {
   pub fn build(&self) -> ChatInviteLinkCount { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<ChatInviteLinkCount> for RTDChatInviteLinkCountBuilder {
   fn as_ref(&self) -> &ChatInviteLinkCount { &self.inner }
 }
 
+impl PartialEq for ChatInviteLinkCount {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.invite_link_count == other.invite_link_count
+      && self.revoked_invite_link_count == other.revoked_invite_link_count
+  }
+}
+
 
 
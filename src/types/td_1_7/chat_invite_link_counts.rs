@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct ChatInviteLinkCounts {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of invite link counts
   invite_link_counts: Vec<ChatInviteLinkCount>,
   
 }
 
 impl RObject for ChatInviteLinkCounts {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatInviteLinkCounts" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatInviteLinkCounts" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatInviteLinkCounts {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatInviteLinkCountsBuilder {
-    let mut inner = ChatInviteLinkCounts::default();
-    inner.td_name = "chatInviteLinkCounts".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatInviteLinkCounts { td_name: "chatInviteLinkCounts".to_string(), ..Default::default() };
     RTDChatInviteLinkCountsBuilder { inner }
   }
 
+  /// `ChatInviteLinkCounts` with the fields tdlib requires, the others are set by `ChatInviteLinkCounts::builder()`
+  pub fn new(invite_link_counts: Vec<ChatInviteLinkCount>) -> Self { Self::builder().invite_link_counts(invite_link_counts).build() }
+
   pub fn invite_link_counts(&self) -> &Vec<ChatInviteLinkCount> { &self.invite_link_counts }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDChatInviteLinkCountsBuilder {
 impl RTDChatInviteLinkCountsBuilder {
   pub fn build(&self) -> ChatInviteLinkCounts { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link_counts(&mut self, invite_link_counts: Vec<ChatInviteLinkCount>) -> &mut Self {
     self.inner.invite_link_counts = invite_link_counts;
@@ -65,5 +75,11 @@ impl AsRef<ChatInviteLinkCounts> for RTDChatInviteLinkCountsBuilder {
   fn as_ref(&self) -> &ChatInviteLinkCounts { &self.inner }
 }
 
+impl PartialEq for ChatInviteLinkCounts {
+  fn eq(&self, other: &Self) -> bool {
+    self.invite_link_counts == other.invite_link_counts
+  }
+}
+
 
 
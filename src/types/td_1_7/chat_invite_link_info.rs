@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatInviteLinkInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier of the invite link; 0 if the user has no access to the chat before joining
-  chat_id: i64,
+  chat_id: ChatId,
   /// If non-zero, the amount of time for which read access to the chat will remain available, in seconds
   accessible_for: i64,
   /// Type of the chat
@@ -25,12 +27,12 @@ pub struct ChatInviteLinkInfo {
   title: String,
   /// Chat photo; may be null
   photo: Option<ChatPhotoInfo>,
-  /// Contains information about a chat invite link
+  /// Chat description
   description: String,
   /// Number of members in the chat
   member_count: i64,
   /// User identifiers of some chat members that may be known to the current user
-  member_user_ids: Vec<i64>,
+  member_user_ids: Vec<UserId>,
   /// True, if the link only creates join request
   creates_join_request: bool,
   /// True, if the chat is a public supergroup or channel, i.e. it has a username or it is a location-based supergroup
@@ -39,23 +41,48 @@ pub struct ChatInviteLinkInfo {
 }
 
 impl RObject for ChatInviteLinkInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatInviteLinkInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatInviteLinkInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatInviteLinkInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatInviteLinkInfoBuilder {
-    let mut inner = ChatInviteLinkInfo::default();
-    inner.td_name = "chatInviteLinkInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatInviteLinkInfo { td_name: "chatInviteLinkInfo".to_string(), ..Default::default() };
     RTDChatInviteLinkInfoBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `ChatInviteLinkInfo` with the fields tdlib requires, the others are set by `ChatInviteLinkInfo::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    chat_id: ChatId,
+    accessible_for: i64,
+    type_: impl AsRef<ChatType>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    member_count: i64,
+    member_user_ids: Vec<UserId>,
+    creates_join_request: bool,
+    is_public: bool,
+  ) -> Self {
+    Self::builder()
+      .chat_id(chat_id)
+      .accessible_for(accessible_for)
+      .type_(type_)
+      .title(title)
+      .description(description)
+      .member_count(member_count)
+      .member_user_ids(member_user_ids)
+      .creates_join_request(creates_join_request)
+      .is_public(is_public)
+      .build()
+  }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn accessible_for(&self) -> i64 { self.accessible_for }
 
@@ -69,7 +96,7 @@ impl ChatInviteLinkInfo {
 
   pub fn member_count(&self) -> i64 { self.member_count }
 
-  pub fn member_user_ids(&self) -> &Vec<i64> { &self.member_user_ids }
+  pub fn member_user_ids(&self) -> &Vec<UserId> { &self.member_user_ids }
 
   pub fn creates_join_request(&self) -> bool { self.creates_join_request }
 
@@ -85,8 +112,14 @@ pub struct RTDChatInviteLinkInfoBuilder {
 impl RTDChatInviteLinkInfoBuilder {
   pub fn build(&self) -> ChatInviteLinkInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -128,7 +161,7 @@ impl RTDChatInviteLinkInfoBuilder {
   }
 
    
-  pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
+  pub fn member_user_ids(&mut self, member_user_ids: Vec<UserId>) -> &mut Self {
     self.inner.member_user_ids = member_user_ids;
     self
   }
@@ -155,5 +188,20 @@ impl AsRef<ChatInviteLinkInfo> for RTDChatInviteLinkInfoBuilder {
   fn as_ref(&self) -> &ChatInviteLinkInfo { &self.inner }
 }
 
+impl PartialEq for ChatInviteLinkInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.accessible_for == other.accessible_for
+      && self.type_ == other.type_
+      && self.title == other.title
+      && self.photo == other.photo
+      && self.description == other.description
+      && self.member_count == other.member_count
+      && self.member_user_ids == other.member_user_ids
+      && self.creates_join_request == other.creates_join_request
+      && self.is_public == other.is_public
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,38 +13,43 @@ pub struct ChatInviteLinkMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   /// Point in time (Unix timestamp) when the user joined the chat
   joined_chat_date: i64,
   /// User identifier of the chat administrator, approved user join request
-  approver_user_id: i64,
+  approver_user_id: UserId,
   
 }
 
 impl RObject for ChatInviteLinkMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatInviteLinkMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatInviteLinkMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatInviteLinkMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatInviteLinkMemberBuilder {
-    let mut inner = ChatInviteLinkMember::default();
-    inner.td_name = "chatInviteLinkMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatInviteLinkMember { td_name: "chatInviteLinkMember".to_string(), ..Default::default() };
     RTDChatInviteLinkMemberBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatInviteLinkMember` with the fields tdlib requires, the others are set by `ChatInviteLinkMember::builder()`
+  pub fn new(user_id: UserId, joined_chat_date: i64, approver_user_id: UserId) -> Self { Self::builder().user_id(user_id).joined_chat_date(joined_chat_date).approver_user_id(approver_user_id).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn joined_chat_date(&self) -> i64 { self.joined_chat_date }
 
-  pub fn approver_user_id(&self) -> i64 { self.approver_user_id }
+  pub fn approver_user_id(&self) -> UserId { self.approver_user_id }
 
 }
 
@@ -57,8 +61,14 @@ pub struct RTDChatInviteLinkMemberBuilder {
 impl RTDChatInviteLinkMemberBuilder {
   pub fn build(&self) -> ChatInviteLinkMember { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -70,7 +80,7 @@ impl RTDChatInviteLinkMemberBuilder {
   }
 
    
-  pub fn approver_user_id(&mut self, approver_user_id: i64) -> &mut Self {
+  pub fn approver_user_id(&mut self, approver_user_id: UserId) -> &mut Self {
     self.inner.approver_user_id = approver_user_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<ChatInviteLinkMember> for RTDChatInviteLinkMemberBuilder {
   fn as_ref(&self) -> &ChatInviteLinkMember { &self.inner }
 }
 
+impl PartialEq for ChatInviteLinkMember {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.joined_chat_date == other.joined_chat_date
+      && self.approver_user_id == other.approver_user_id
+  }
+}
+
 
 
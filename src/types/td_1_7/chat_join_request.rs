@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatJoinRequest {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   /// Point in time (Unix timestamp) when the user sent the join request
   date: i64,
   /// A short bio of the user
@@ -25,23 +27,25 @@ pub struct ChatJoinRequest {
 }
 
 impl RObject for ChatJoinRequest {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatJoinRequest" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatJoinRequest" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatJoinRequest {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatJoinRequestBuilder {
-    let mut inner = ChatJoinRequest::default();
-    inner.td_name = "chatJoinRequest".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatJoinRequest { td_name: "chatJoinRequest".to_string(), ..Default::default() };
     RTDChatJoinRequestBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatJoinRequest` with the fields tdlib requires, the others are set by `ChatJoinRequest::builder()`
+  pub fn new(user_id: UserId, date: i64, bio: impl AsRef<str>) -> Self { Self::builder().user_id(user_id).date(date).bio(bio).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn date(&self) -> i64 { self.date }
 
@@ -57,8 +61,14 @@ pub struct RTDChatJoinRequestBuilder {
 impl RTDChatJoinRequestBuilder {
   pub fn build(&self) -> ChatJoinRequest { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<ChatJoinRequest> for RTDChatJoinRequestBuilder {
   fn as_ref(&self) -> &ChatJoinRequest { &self.inner }
 }
 
+impl PartialEq for ChatJoinRequest {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.date == other.date
+      && self.bio == other.bio
+  }
+}
+
 
 
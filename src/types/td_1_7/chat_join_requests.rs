@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatJoinRequests {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Approximate total count of requests found
   total_count: i64,
   /// List of the requests
@@ -23,22 +25,24 @@ pub struct ChatJoinRequests {
 }
 
 impl RObject for ChatJoinRequests {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatJoinRequests" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatJoinRequests" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatJoinRequests {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatJoinRequestsBuilder {
-    let mut inner = ChatJoinRequests::default();
-    inner.td_name = "chatJoinRequests".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatJoinRequests { td_name: "chatJoinRequests".to_string(), ..Default::default() };
     RTDChatJoinRequestsBuilder { inner }
   }
 
+  /// `ChatJoinRequests` with the fields tdlib requires, the others are set by `ChatJoinRequests::builder()`
+  pub fn new(total_count: i64, requests: Vec<ChatJoinRequest>) -> Self { Self::builder().total_count(total_count).requests(requests).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
   pub fn requests(&self) -> &Vec<ChatJoinRequest> { &self.requests }
@@ -53,6 +57,12 @@ pub struct RTDChatJoinRequestsBuilder {
 impl RTDChatJoinRequestsBuilder {
   pub fn build(&self) -> ChatJoinRequests { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -75,5 +85,12 @@ impl AsRef<ChatJoinRequests> for RTDChatJoinRequestsBuilder {
   fn as_ref(&self) -> &ChatJoinRequests { &self.inner }
 }
 
+impl PartialEq for ChatJoinRequests {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.requests == other.requests
+  }
+}
+
 
 
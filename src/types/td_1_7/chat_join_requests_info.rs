@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,34 +13,39 @@ pub struct ChatJoinRequestsInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Total number of pending join requests
   total_count: i64,
   /// Identifiers of users sent the newest pending join requests
-  user_ids: Vec<i64>,
+  user_ids: Vec<UserId>,
   
 }
 
 impl RObject for ChatJoinRequestsInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatJoinRequestsInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatJoinRequestsInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatJoinRequestsInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatJoinRequestsInfoBuilder {
-    let mut inner = ChatJoinRequestsInfo::default();
-    inner.td_name = "chatJoinRequestsInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatJoinRequestsInfo { td_name: "chatJoinRequestsInfo".to_string(), ..Default::default() };
     RTDChatJoinRequestsInfoBuilder { inner }
   }
 
+  /// `ChatJoinRequestsInfo` with the fields tdlib requires, the others are set by `ChatJoinRequestsInfo::builder()`
+  pub fn new(total_count: i64, user_ids: Vec<UserId>) -> Self { Self::builder().total_count(total_count).user_ids(user_ids).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
-  pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
+  pub fn user_ids(&self) -> &Vec<UserId> { &self.user_ids }
 
 }
 
@@ -53,6 +57,12 @@ pub struct RTDChatJoinRequestsInfoBuilder {
 impl RTDChatJoinRequestsInfoBuilder {
   pub fn build(&self) -> ChatJoinRequestsInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -60,7 +70,7 @@ impl RTDChatJoinRequestsInfoBuilder {
   }
 
    
-  pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+  pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
     self.inner.user_ids = user_ids;
     self
   }
@@ -75,5 +85,12 @@ impl AsRef<ChatJoinRequestsInfo> for RTDChatJoinRequestsInfoBuilder {
   fn as_ref(&self) -> &ChatJoinRequestsInfo { &self.inner }
 }
 
+impl PartialEq for ChatJoinRequestsInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.user_ids == other.user_ids
+  }
+}
+
 
 
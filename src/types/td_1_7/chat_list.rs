@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatList: Debug + RObject {}
 
 /// Describes a list of chats
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatList {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum ChatList {
   Filter(ChatListFilter),
   /// A main list of chats
   Main(ChatListMain),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for ChatList {
   fn deserialize<D>(deserializer: D) -> Result<ChatList, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatList,
+      ChatList, Unknown;
       (chatListArchive, Archive);
       (chatListFilter, Filter);
       (chatListMain, Main);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for ChatList {
 }
 
 impl RObject for ChatList {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatList::Archive(t) => t.td_name(),
       ChatList::Filter(t) => t.td_name(),
       ChatList::Main(t) => t.td_name(),
+      ChatList::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatList::Archive(t) => t.extra(),
       ChatList::Filter(t) => t.extra(),
       ChatList::Main(t) => t.extra(),
+      ChatList::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatList::Archive(t) => t.client_id(),
+      ChatList::Filter(t) => t.client_id(),
+      ChatList::Main(t) => t.client_id(),
+      ChatList::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for ChatList {
 }
 
 impl ChatList {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatList::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatList::_Default(_)) }
 
-  pub fn is_archive(&self) -> bool { if let ChatList::Archive(_) = self { true } else { false } }
-  pub fn is_filter(&self) -> bool { if let ChatList::Filter(_) = self { true } else { false } }
-  pub fn is_main(&self) -> bool { if let ChatList::Main(_) = self { true } else { false } }
+  pub fn is_archive(&self) -> bool { matches!(self, ChatList::Archive(_)) }
+  pub fn is_filter(&self) -> bool { matches!(self, ChatList::Filter(_)) }
+  pub fn is_main(&self) -> bool { matches!(self, ChatList::Main(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatList::Unknown(_)) }
 
   pub fn on_archive<F: FnOnce(&ChatListArchive)>(&self, fnc: F) -> &Self { if let ChatList::Archive(t) = self { fnc(t) }; self }
   pub fn on_filter<F: FnOnce(&ChatListFilter)>(&self, fnc: F) -> &Self { if let ChatList::Filter(t) = self { fnc(t) }; self }
   pub fn on_main<F: FnOnce(&ChatListMain)>(&self, fnc: F) -> &Self { if let ChatList::Main(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatList::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_archive(&self) -> Option<&ChatListArchive> { if let ChatList::Archive(t) = self { return Some(t) } None }
   pub fn as_filter(&self) -> Option<&ChatListFilter> { if let ChatList::Filter(t) = self { return Some(t) } None }
   pub fn as_main(&self) -> Option<&ChatListMain> { if let ChatList::Main(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatList::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,13 +127,17 @@ pub struct ChatListArchive {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatListArchive {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatListArchive" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatListArchive" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -127,14 +147,15 @@ impl TDChatList for ChatListArchive {}
 
 
 impl ChatListArchive {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatListArchiveBuilder {
-    let mut inner = ChatListArchive::default();
-    inner.td_name = "chatListArchive".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatListArchive { td_name: "chatListArchive".to_string(), ..Default::default() };
     RTDChatListArchiveBuilder { inner }
   }
 
+  /// `ChatListArchive` with the fields tdlib requires, the others are set by `ChatListArchive::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +166,12 @@ pub struct RTDChatListArchiveBuilder {
 impl RTDChatListArchiveBuilder {
   pub fn build(&self) -> ChatListArchive { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatListArchive> for ChatListArchive {
@@ -155,6 +182,10 @@ impl AsRef<ChatListArchive> for RTDChatListArchiveBuilder {
   fn as_ref(&self) -> &ChatListArchive { &self.inner }
 }
 
+impl PartialEq for ChatListArchive {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -169,15 +200,19 @@ pub struct ChatListFilter {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat filter identifier
   chat_filter_id: i64,
   
 }
 
 impl RObject for ChatListFilter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatListFilter" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatListFilter" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -187,14 +222,15 @@ impl TDChatList for ChatListFilter {}
 
 
 impl ChatListFilter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatListFilterBuilder {
-    let mut inner = ChatListFilter::default();
-    inner.td_name = "chatListFilter".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatListFilter { td_name: "chatListFilter".to_string(), ..Default::default() };
     RTDChatListFilterBuilder { inner }
   }
 
+  /// `ChatListFilter` with the fields tdlib requires, the others are set by `ChatListFilter::builder()`
+  pub fn new(chat_filter_id: i64) -> Self { Self::builder().chat_filter_id(chat_filter_id).build() }
+
   pub fn chat_filter_id(&self) -> i64 { self.chat_filter_id }
 
 }
@@ -207,6 +243,12 @@ pub struct RTDChatListFilterBuilder {
 impl RTDChatListFilterBuilder {
   pub fn build(&self) -> ChatListFilter { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
@@ -223,6 +265,12 @@ impl AsRef<ChatListFilter> for RTDChatListFilterBuilder {
   fn as_ref(&self) -> &ChatListFilter { &self.inner }
 }
 
+impl PartialEq for ChatListFilter {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_filter_id == other.chat_filter_id
+  }
+}
+
 
 
 
@@ -237,13 +285,17 @@ pub struct ChatListMain {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatListMain {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatListMain" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatListMain" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -253,14 +305,15 @@ impl TDChatList for ChatListMain {}
 
 
 impl ChatListMain {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatListMainBuilder {
-    let mut inner = ChatListMain::default();
-    inner.td_name = "chatListMain".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatListMain { td_name: "chatListMain".to_string(), ..Default::default() };
     RTDChatListMainBuilder { inner }
   }
 
+  /// `ChatListMain` with the fields tdlib requires, the others are set by `ChatListMain::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -271,6 +324,12 @@ pub struct RTDChatListMainBuilder {
 impl RTDChatListMainBuilder {
   pub fn build(&self) -> ChatListMain { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatListMain> for ChatListMain {
@@ -281,5 +340,9 @@ impl AsRef<ChatListMain> for RTDChatListMainBuilder {
   fn as_ref(&self) -> &ChatListMain { &self.inner }
 }
 
+impl PartialEq for ChatListMain {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
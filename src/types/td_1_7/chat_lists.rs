@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct ChatLists {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of chat lists
   chat_lists: Vec<ChatList>,
   
 }
 
 impl RObject for ChatLists {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatLists" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatLists" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatLists {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatListsBuilder {
-    let mut inner = ChatLists::default();
-    inner.td_name = "chatLists".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatLists { td_name: "chatLists".to_string(), ..Default::default() };
     RTDChatListsBuilder { inner }
   }
 
+  /// `ChatLists` with the fields tdlib requires, the others are set by `ChatLists::builder()`
+  pub fn new(chat_lists: Vec<ChatList>) -> Self { Self::builder().chat_lists(chat_lists).build() }
+
   pub fn chat_lists(&self) -> &Vec<ChatList> { &self.chat_lists }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDChatListsBuilder {
 impl RTDChatListsBuilder {
   pub fn build(&self) -> ChatLists { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_lists(&mut self, chat_lists: Vec<ChatList>) -> &mut Self {
     self.inner.chat_lists = chat_lists;
@@ -65,5 +75,11 @@ impl AsRef<ChatLists> for RTDChatListsBuilder {
   fn as_ref(&self) -> &ChatLists { &self.inner }
 }
 
+impl PartialEq for ChatLists {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_lists == other.chat_lists
+  }
+}
+
 
 
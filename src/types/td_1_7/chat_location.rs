@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The location
   location: Location,
   /// Location address; 1-64 characters, as defined by the chat owner
@@ -23,22 +25,24 @@ pub struct ChatLocation {
 }
 
 impl RObject for ChatLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatLocationBuilder {
-    let mut inner = ChatLocation::default();
-    inner.td_name = "chatLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatLocation { td_name: "chatLocation".to_string(), ..Default::default() };
     RTDChatLocationBuilder { inner }
   }
 
+  /// `ChatLocation` with the fields tdlib requires, the others are set by `ChatLocation::builder()`
+  pub fn new(location: impl AsRef<Location>, address: impl AsRef<str>) -> Self { Self::builder().location(location).address(address).build() }
+
   pub fn location(&self) -> &Location { &self.location }
 
   pub fn address(&self) -> &String { &self.address }
@@ -53,6 +57,12 @@ pub struct RTDChatLocationBuilder {
 impl RTDChatLocationBuilder {
   pub fn build(&self) -> ChatLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -75,5 +85,18 @@ impl AsRef<ChatLocation> for RTDChatLocationBuilder {
   fn as_ref(&self) -> &ChatLocation { &self.inner }
 }
 
+impl PartialEq for ChatLocation {
+  fn eq(&self, other: &Self) -> bool {
+    self.location == other.location
+      && self.address == other.address
+  }
+}
+
+impl Validate for ChatLocation {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("address", &self.address, 1, 64);
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,13 +13,16 @@ pub struct ChatMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat member. Currently, other chats can be only Left or Banned. Only supergroups and channels can have other chats as Left or Banned members and these chats must be supergroups or channels
   member_id: Option<MessageSender>,
   /// User identifier of the chat member.
-  user_id: Option<i64>,
+  user_id: Option<UserId>,
   /// Identifier of a user that invited/promoted/banned this member in the chat; 0 if unknown
-  inviter_user_id: i64,
+  inviter_user_id: UserId,
   /// Point in time (Unix timestamp) when the user joined the chat
   joined_chat_date: i64,
   /// Status of the member in the chat
@@ -29,26 +31,29 @@ pub struct ChatMember {
 }
 
 impl RObject for ChatMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMemberBuilder {
-    let mut inner = ChatMember::default();
-    inner.td_name = "chatMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMember { td_name: "chatMember".to_string(), ..Default::default() };
     RTDChatMemberBuilder { inner }
   }
+
+  /// `ChatMember` with the fields tdlib requires, the others are set by `ChatMember::builder()`
+  pub fn new(inviter_user_id: UserId, joined_chat_date: i64, status: impl AsRef<ChatMemberStatus>) -> Self { Self::builder().inviter_user_id(inviter_user_id).joined_chat_date(joined_chat_date).status(status).build() }
+
   pub fn member_id(&self) -> &Option<MessageSender> { &self.member_id }
 
-  pub fn user_id(&self) -> Option<i64> { self.user_id }
+  pub fn user_id(&self) -> Option<UserId> { self.user_id }
 
-  pub fn inviter_user_id(&self) -> i64 { self.inviter_user_id }
+  pub fn inviter_user_id(&self) -> UserId { self.inviter_user_id }
 
   pub fn joined_chat_date(&self) -> i64 { self.joined_chat_date }
 
@@ -64,18 +69,26 @@ pub struct RTDChatMemberBuilder {
 impl RTDChatMemberBuilder {
   pub fn build(&self) -> ChatMember { self.inner.clone() }
 
-   pub fn member_id<T: AsRef<Option<MessageSender>>>(&mut self, member_id: T) -> &mut Self {
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
+   
+  pub fn member_id<T: AsRef<Option<MessageSender>>>(&mut self, member_id: T) -> &mut Self {
     self.inner.member_id = member_id.as_ref().clone();
     self
   }
 
-  pub fn user_id(&mut self, user_id: Option<i64>) -> &mut Self {
+   
+  pub fn user_id(&mut self, user_id: Option<UserId>) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
 
    
-  pub fn inviter_user_id(&mut self, inviter_user_id: i64) -> &mut Self {
+  pub fn inviter_user_id(&mut self, inviter_user_id: UserId) -> &mut Self {
     self.inner.inviter_user_id = inviter_user_id;
     self
   }
@@ -102,5 +115,15 @@ impl AsRef<ChatMember> for RTDChatMemberBuilder {
   fn as_ref(&self) -> &ChatMember { &self.inner }
 }
 
+impl PartialEq for ChatMember {
+  fn eq(&self, other: &Self) -> bool {
+    self.member_id == other.member_id
+      && self.user_id == other.user_id
+      && self.inviter_user_id == other.inviter_user_id
+      && self.joined_chat_date == other.joined_chat_date
+      && self.status == other.status
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatMemberStatus: Debug + RObject {}
 
 /// Provides information about the status of a member in a chat
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatMemberStatus {
   #[doc(hidden)] _Default(()),
@@ -31,6 +30,8 @@ pub enum ChatMemberStatus {
   Member(ChatMemberStatusMember),
   /// The user is under certain restrictions in the chat. Not supported in basic groups and channels
   Restricted(ChatMemberStatusRestricted),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -42,7 +43,7 @@ impl<'de> Deserialize<'de> for ChatMemberStatus {
   fn deserialize<D>(deserializer: D) -> Result<ChatMemberStatus, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatMemberStatus,
+      ChatMemberStatus, Unknown;
       (chatMemberStatusAdministrator, Administrator);
       (chatMemberStatusBanned, Banned);
       (chatMemberStatusCreator, Creator);
@@ -55,7 +56,7 @@ impl<'de> Deserialize<'de> for ChatMemberStatus {
 }
 
 impl RObject for ChatMemberStatus {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatMemberStatus::Administrator(t) => t.td_name(),
       ChatMemberStatus::Banned(t) => t.td_name(),
@@ -63,11 +64,12 @@ impl RObject for ChatMemberStatus {
       ChatMemberStatus::Left(t) => t.td_name(),
       ChatMemberStatus::Member(t) => t.td_name(),
       ChatMemberStatus::Restricted(t) => t.td_name(),
+      ChatMemberStatus::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatMemberStatus::Administrator(t) => t.extra(),
       ChatMemberStatus::Banned(t) => t.extra(),
@@ -75,6 +77,20 @@ impl RObject for ChatMemberStatus {
       ChatMemberStatus::Left(t) => t.extra(),
       ChatMemberStatus::Member(t) => t.extra(),
       ChatMemberStatus::Restricted(t) => t.extra(),
+      ChatMemberStatus::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatMemberStatus::Administrator(t) => t.client_id(),
+      ChatMemberStatus::Banned(t) => t.client_id(),
+      ChatMemberStatus::Creator(t) => t.client_id(),
+      ChatMemberStatus::Left(t) => t.client_id(),
+      ChatMemberStatus::Member(t) => t.client_id(),
+      ChatMemberStatus::Restricted(t) => t.client_id(),
+      ChatMemberStatus::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -83,15 +99,16 @@ impl RObject for ChatMemberStatus {
 }
 
 impl ChatMemberStatus {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatMemberStatus::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatMemberStatus::_Default(_)) }
 
-  pub fn is_administrator(&self) -> bool { if let ChatMemberStatus::Administrator(_) = self { true } else { false } }
-  pub fn is_banned(&self) -> bool { if let ChatMemberStatus::Banned(_) = self { true } else { false } }
-  pub fn is_creator(&self) -> bool { if let ChatMemberStatus::Creator(_) = self { true } else { false } }
-  pub fn is_left(&self) -> bool { if let ChatMemberStatus::Left(_) = self { true } else { false } }
-  pub fn is_member(&self) -> bool { if let ChatMemberStatus::Member(_) = self { true } else { false } }
-  pub fn is_restricted(&self) -> bool { if let ChatMemberStatus::Restricted(_) = self { true } else { false } }
+  pub fn is_administrator(&self) -> bool { matches!(self, ChatMemberStatus::Administrator(_)) }
+  pub fn is_banned(&self) -> bool { matches!(self, ChatMemberStatus::Banned(_)) }
+  pub fn is_creator(&self) -> bool { matches!(self, ChatMemberStatus::Creator(_)) }
+  pub fn is_left(&self) -> bool { matches!(self, ChatMemberStatus::Left(_)) }
+  pub fn is_member(&self) -> bool { matches!(self, ChatMemberStatus::Member(_)) }
+  pub fn is_restricted(&self) -> bool { matches!(self, ChatMemberStatus::Restricted(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatMemberStatus::Unknown(_)) }
 
   pub fn on_administrator<F: FnOnce(&ChatMemberStatusAdministrator)>(&self, fnc: F) -> &Self { if let ChatMemberStatus::Administrator(t) = self { fnc(t) }; self }
   pub fn on_banned<F: FnOnce(&ChatMemberStatusBanned)>(&self, fnc: F) -> &Self { if let ChatMemberStatus::Banned(t) = self { fnc(t) }; self }
@@ -99,6 +116,7 @@ impl ChatMemberStatus {
   pub fn on_left<F: FnOnce(&ChatMemberStatusLeft)>(&self, fnc: F) -> &Self { if let ChatMemberStatus::Left(t) = self { fnc(t) }; self }
   pub fn on_member<F: FnOnce(&ChatMemberStatusMember)>(&self, fnc: F) -> &Self { if let ChatMemberStatus::Member(t) = self { fnc(t) }; self }
   pub fn on_restricted<F: FnOnce(&ChatMemberStatusRestricted)>(&self, fnc: F) -> &Self { if let ChatMemberStatus::Restricted(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatMemberStatus::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_administrator(&self) -> Option<&ChatMemberStatusAdministrator> { if let ChatMemberStatus::Administrator(t) = self { return Some(t) } None }
   pub fn as_banned(&self) -> Option<&ChatMemberStatusBanned> { if let ChatMemberStatus::Banned(t) = self { return Some(t) } None }
@@ -106,6 +124,7 @@ impl ChatMemberStatus {
   pub fn as_left(&self) -> Option<&ChatMemberStatusLeft> { if let ChatMemberStatus::Left(t) = self { return Some(t) } None }
   pub fn as_member(&self) -> Option<&ChatMemberStatusMember> { if let ChatMemberStatus::Member(t) = self { return Some(t) } None }
   pub fn as_restricted(&self) -> Option<&ChatMemberStatusRestricted> { if let ChatMemberStatus::Restricted(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatMemberStatus::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -127,6 +146,16 @@ impl AsRef<ChatMemberStatus> for ChatMemberStatus {
   fn as_ref(&self) -> &ChatMemberStatus { self }
 }
 
+impl Validate for ChatMemberStatus {
+  fn check(&self, checker: &mut Checker) {
+    match self {
+      ChatMemberStatus::Administrator(value) => value.check(checker),
+      ChatMemberStatus::Creator(value) => value.check(checker),
+      _ => {}
+    }
+  }
+}
+
 
 
 
@@ -141,7 +170,10 @@ pub struct ChatMemberStatusAdministrator {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A custom title of the administrator; 0-16 characters without emojis; applicable to supergroups only
   custom_title: String,
   /// True, if the current user can edit the administrator privileges for the called user
@@ -172,8 +204,9 @@ pub struct ChatMemberStatusAdministrator {
 }
 
 impl RObject for ChatMemberStatusAdministrator {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusAdministrator" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMemberStatusAdministrator" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -183,14 +216,44 @@ impl TDChatMemberStatus for ChatMemberStatusAdministrator {}
 
 
 impl ChatMemberStatusAdministrator {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMemberStatusAdministratorBuilder {
-    let mut inner = ChatMemberStatusAdministrator::default();
-    inner.td_name = "chatMemberStatusAdministrator".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMemberStatusAdministrator { td_name: "chatMemberStatusAdministrator".to_string(), ..Default::default() };
     RTDChatMemberStatusAdministratorBuilder { inner }
   }
 
+  /// `ChatMemberStatusAdministrator` with the fields tdlib requires, the others are set by `ChatMemberStatusAdministrator::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    custom_title: impl AsRef<str>,
+    can_be_edited: bool,
+    can_change_info: bool,
+    can_post_messages: bool,
+    can_edit_messages: bool,
+    can_delete_messages: bool,
+    can_invite_users: bool,
+    can_restrict_members: bool,
+    can_pin_messages: bool,
+    can_promote_members: bool,
+    can_manage_video_chats: bool,
+    is_anonymous: bool,
+  ) -> Self {
+    Self::builder()
+      .custom_title(custom_title)
+      .can_be_edited(can_be_edited)
+      .can_change_info(can_change_info)
+      .can_post_messages(can_post_messages)
+      .can_edit_messages(can_edit_messages)
+      .can_delete_messages(can_delete_messages)
+      .can_invite_users(can_invite_users)
+      .can_restrict_members(can_restrict_members)
+      .can_pin_messages(can_pin_messages)
+      .can_promote_members(can_promote_members)
+      .can_manage_video_chats(can_manage_video_chats)
+      .is_anonymous(is_anonymous)
+      .build()
+  }
+
   pub fn custom_title(&self) -> &String { &self.custom_title }
 
   pub fn can_be_edited(&self) -> bool { self.can_be_edited }
@@ -227,6 +290,12 @@ pub struct RTDChatMemberStatusAdministratorBuilder {
 impl RTDChatMemberStatusAdministratorBuilder {
   pub fn build(&self) -> ChatMemberStatusAdministrator { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
     self.inner.custom_title = custom_title.as_ref().to_string();
@@ -293,7 +362,7 @@ impl RTDChatMemberStatusAdministratorBuilder {
     self
   }
 
-
+   
   pub fn can_manage_video_chats(&mut self, can_manage_video_chats: bool) -> &mut Self {
     self.inner.can_manage_video_chats = can_manage_video_chats;
     self
@@ -315,6 +384,30 @@ impl AsRef<ChatMemberStatusAdministrator> for RTDChatMemberStatusAdministratorBu
   fn as_ref(&self) -> &ChatMemberStatusAdministrator { &self.inner }
 }
 
+impl PartialEq for ChatMemberStatusAdministrator {
+  fn eq(&self, other: &Self) -> bool {
+    self.custom_title == other.custom_title
+      && self.can_be_edited == other.can_be_edited
+      && self.can_manage_chat == other.can_manage_chat
+      && self.can_change_info == other.can_change_info
+      && self.can_post_messages == other.can_post_messages
+      && self.can_edit_messages == other.can_edit_messages
+      && self.can_delete_messages == other.can_delete_messages
+      && self.can_invite_users == other.can_invite_users
+      && self.can_restrict_members == other.can_restrict_members
+      && self.can_pin_messages == other.can_pin_messages
+      && self.can_promote_members == other.can_promote_members
+      && self.can_manage_video_chats == other.can_manage_video_chats
+      && self.is_anonymous == other.is_anonymous
+  }
+}
+
+impl Validate for ChatMemberStatusAdministrator {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("custom_title", &self.custom_title, 0, 16);
+  }
+}
+
 
 
 
@@ -329,15 +422,19 @@ pub struct ChatMemberStatusBanned {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Point in time (Unix timestamp) when the user will be unbanned; 0 if never. If the user is banned for more than 366 days or for less than 30 seconds from the current time, the user is considered to be banned forever. Always 0 in basic groups
   banned_until_date: i64,
   
 }
 
 impl RObject for ChatMemberStatusBanned {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusBanned" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMemberStatusBanned" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -347,14 +444,15 @@ impl TDChatMemberStatus for ChatMemberStatusBanned {}
 
 
 impl ChatMemberStatusBanned {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMemberStatusBannedBuilder {
-    let mut inner = ChatMemberStatusBanned::default();
-    inner.td_name = "chatMemberStatusBanned".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMemberStatusBanned { td_name: "chatMemberStatusBanned".to_string(), ..Default::default() };
     RTDChatMemberStatusBannedBuilder { inner }
   }
 
+  /// `ChatMemberStatusBanned` with the fields tdlib requires, the others are set by `ChatMemberStatusBanned::builder()`
+  pub fn new(banned_until_date: i64) -> Self { Self::builder().banned_until_date(banned_until_date).build() }
+
   pub fn banned_until_date(&self) -> i64 { self.banned_until_date }
 
 }
@@ -367,6 +465,12 @@ pub struct RTDChatMemberStatusBannedBuilder {
 impl RTDChatMemberStatusBannedBuilder {
   pub fn build(&self) -> ChatMemberStatusBanned { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn banned_until_date(&mut self, banned_until_date: i64) -> &mut Self {
     self.inner.banned_until_date = banned_until_date;
@@ -383,6 +487,12 @@ impl AsRef<ChatMemberStatusBanned> for RTDChatMemberStatusBannedBuilder {
   fn as_ref(&self) -> &ChatMemberStatusBanned { &self.inner }
 }
 
+impl PartialEq for ChatMemberStatusBanned {
+  fn eq(&self, other: &Self) -> bool {
+    self.banned_until_date == other.banned_until_date
+  }
+}
+
 
 
 
@@ -397,7 +507,10 @@ pub struct ChatMemberStatusCreator {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A custom title of the owner; 0-16 characters without emojis; applicable to supergroups only
   custom_title: String,
   /// True, if the creator isn't shown in the chat member list and sends messages anonymously; applicable to supergroups only
@@ -408,8 +521,9 @@ pub struct ChatMemberStatusCreator {
 }
 
 impl RObject for ChatMemberStatusCreator {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusCreator" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMemberStatusCreator" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -419,14 +533,15 @@ impl TDChatMemberStatus for ChatMemberStatusCreator {}
 
 
 impl ChatMemberStatusCreator {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMemberStatusCreatorBuilder {
-    let mut inner = ChatMemberStatusCreator::default();
-    inner.td_name = "chatMemberStatusCreator".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMemberStatusCreator { td_name: "chatMemberStatusCreator".to_string(), ..Default::default() };
     RTDChatMemberStatusCreatorBuilder { inner }
   }
 
+  /// `ChatMemberStatusCreator` with the fields tdlib requires, the others are set by `ChatMemberStatusCreator::builder()`
+  pub fn new(custom_title: impl AsRef<str>, is_anonymous: bool, is_member: bool) -> Self { Self::builder().custom_title(custom_title).is_anonymous(is_anonymous).is_member(is_member).build() }
+
   pub fn custom_title(&self) -> &String { &self.custom_title }
 
   pub fn is_anonymous(&self) -> bool { self.is_anonymous }
@@ -443,6 +558,12 @@ pub struct RTDChatMemberStatusCreatorBuilder {
 impl RTDChatMemberStatusCreatorBuilder {
   pub fn build(&self) -> ChatMemberStatusCreator { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
     self.inner.custom_title = custom_title.as_ref().to_string();
@@ -471,6 +592,20 @@ impl AsRef<ChatMemberStatusCreator> for RTDChatMemberStatusCreatorBuilder {
   fn as_ref(&self) -> &ChatMemberStatusCreator { &self.inner }
 }
 
+impl PartialEq for ChatMemberStatusCreator {
+  fn eq(&self, other: &Self) -> bool {
+    self.custom_title == other.custom_title
+      && self.is_anonymous == other.is_anonymous
+      && self.is_member == other.is_member
+  }
+}
+
+impl Validate for ChatMemberStatusCreator {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("custom_title", &self.custom_title, 0, 16);
+  }
+}
+
 
 
 
@@ -485,13 +620,17 @@ pub struct ChatMemberStatusLeft {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMemberStatusLeft {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusLeft" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMemberStatusLeft" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -501,14 +640,15 @@ impl TDChatMemberStatus for ChatMemberStatusLeft {}
 
 
 impl ChatMemberStatusLeft {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMemberStatusLeftBuilder {
-    let mut inner = ChatMemberStatusLeft::default();
-    inner.td_name = "chatMemberStatusLeft".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMemberStatusLeft { td_name: "chatMemberStatusLeft".to_string(), ..Default::default() };
     RTDChatMemberStatusLeftBuilder { inner }
   }
 
+  /// `ChatMemberStatusLeft` with the fields tdlib requires, the others are set by `ChatMemberStatusLeft::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -519,6 +659,12 @@ pub struct RTDChatMemberStatusLeftBuilder {
 impl RTDChatMemberStatusLeftBuilder {
   pub fn build(&self) -> ChatMemberStatusLeft { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMemberStatusLeft> for ChatMemberStatusLeft {
@@ -529,6 +675,10 @@ impl AsRef<ChatMemberStatusLeft> for RTDChatMemberStatusLeftBuilder {
   fn as_ref(&self) -> &ChatMemberStatusLeft { &self.inner }
 }
 
+impl PartialEq for ChatMemberStatusLeft {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -543,13 +693,17 @@ pub struct ChatMemberStatusMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMemberStatusMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMemberStatusMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -559,14 +713,15 @@ impl TDChatMemberStatus for ChatMemberStatusMember {}
 
 
 impl ChatMemberStatusMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMemberStatusMemberBuilder {
-    let mut inner = ChatMemberStatusMember::default();
-    inner.td_name = "chatMemberStatusMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMemberStatusMember { td_name: "chatMemberStatusMember".to_string(), ..Default::default() };
     RTDChatMemberStatusMemberBuilder { inner }
   }
 
+  /// `ChatMemberStatusMember` with the fields tdlib requires, the others are set by `ChatMemberStatusMember::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -577,6 +732,12 @@ pub struct RTDChatMemberStatusMemberBuilder {
 impl RTDChatMemberStatusMemberBuilder {
   pub fn build(&self) -> ChatMemberStatusMember { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMemberStatusMember> for ChatMemberStatusMember {
@@ -587,6 +748,10 @@ impl AsRef<ChatMemberStatusMember> for RTDChatMemberStatusMemberBuilder {
   fn as_ref(&self) -> &ChatMemberStatusMember { &self.inner }
 }
 
+impl PartialEq for ChatMemberStatusMember {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -601,7 +766,10 @@ pub struct ChatMemberStatusRestricted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the user is a member of the chat
   is_member: bool,
   /// Point in time (Unix timestamp) when restrictions will be lifted from the user; 0 if never. If the user is restricted for more than 366 days or for less than 30 seconds from the current time, the user is considered to be restricted forever
@@ -612,8 +780,9 @@ pub struct ChatMemberStatusRestricted {
 }
 
 impl RObject for ChatMemberStatusRestricted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMemberStatusRestricted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMemberStatusRestricted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -623,14 +792,15 @@ impl TDChatMemberStatus for ChatMemberStatusRestricted {}
 
 
 impl ChatMemberStatusRestricted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMemberStatusRestrictedBuilder {
-    let mut inner = ChatMemberStatusRestricted::default();
-    inner.td_name = "chatMemberStatusRestricted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMemberStatusRestricted { td_name: "chatMemberStatusRestricted".to_string(), ..Default::default() };
     RTDChatMemberStatusRestrictedBuilder { inner }
   }
 
+  /// `ChatMemberStatusRestricted` with the fields tdlib requires, the others are set by `ChatMemberStatusRestricted::builder()`
+  pub fn new(is_member: bool, restricted_until_date: i64, permissions: impl AsRef<ChatPermissions>) -> Self { Self::builder().is_member(is_member).restricted_until_date(restricted_until_date).permissions(permissions).build() }
+
   pub fn is_member(&self) -> bool { self.is_member }
 
   pub fn restricted_until_date(&self) -> i64 { self.restricted_until_date }
@@ -647,6 +817,12 @@ pub struct RTDChatMemberStatusRestrictedBuilder {
 impl RTDChatMemberStatusRestrictedBuilder {
   pub fn build(&self) -> ChatMemberStatusRestricted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_member(&mut self, is_member: bool) -> &mut Self {
     self.inner.is_member = is_member;
@@ -675,5 +851,13 @@ impl AsRef<ChatMemberStatusRestricted> for RTDChatMemberStatusRestrictedBuilder
   fn as_ref(&self) -> &ChatMemberStatusRestricted { &self.inner }
 }
 
+impl PartialEq for ChatMemberStatusRestricted {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_member == other.is_member
+      && self.restricted_until_date == other.restricted_until_date
+      && self.permissions == other.permissions
+  }
+}
+
 
 
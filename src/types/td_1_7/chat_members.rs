@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatMembers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Approximate total count of chat members found
   total_count: i64,
   /// A list of chat members
@@ -23,22 +25,24 @@ pub struct ChatMembers {
 }
 
 impl RObject for ChatMembers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatMembers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersBuilder {
-    let mut inner = ChatMembers::default();
-    inner.td_name = "chatMembers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembers { td_name: "chatMembers".to_string(), ..Default::default() };
     RTDChatMembersBuilder { inner }
   }
 
+  /// `ChatMembers` with the fields tdlib requires, the others are set by `ChatMembers::builder()`
+  pub fn new(total_count: i64, members: Vec<ChatMember>) -> Self { Self::builder().total_count(total_count).members(members).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
   pub fn members(&self) -> &Vec<ChatMember> { &self.members }
@@ -53,6 +57,12 @@ pub struct RTDChatMembersBuilder {
 impl RTDChatMembersBuilder {
   pub fn build(&self) -> ChatMembers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -75,5 +85,12 @@ impl AsRef<ChatMembers> for RTDChatMembersBuilder {
   fn as_ref(&self) -> &ChatMembers { &self.inner }
 }
 
+impl PartialEq for ChatMembers {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.members == other.members
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatMembersFilter: Debug + RObject {}
 
 /// Specifies the kind of chat members to return in searchChatMembers
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatMembersFilter {
   #[doc(hidden)] _Default(()),
@@ -33,6 +32,8 @@ pub enum ChatMembersFilter {
   Mention(ChatMembersFilterMention),
   /// Returns users under certain restrictions in the chat; can be used only by administrators in a supergroup
   Restricted(ChatMembersFilterRestricted),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -44,7 +45,7 @@ impl<'de> Deserialize<'de> for ChatMembersFilter {
   fn deserialize<D>(deserializer: D) -> Result<ChatMembersFilter, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatMembersFilter,
+      ChatMembersFilter, Unknown;
       (chatMembersFilterAdministrators, Administrators);
       (chatMembersFilterBanned, Banned);
       (chatMembersFilterBots, Bots);
@@ -58,7 +59,7 @@ impl<'de> Deserialize<'de> for ChatMembersFilter {
 }
 
 impl RObject for ChatMembersFilter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatMembersFilter::Administrators(t) => t.td_name(),
       ChatMembersFilter::Banned(t) => t.td_name(),
@@ -67,11 +68,12 @@ impl RObject for ChatMembersFilter {
       ChatMembersFilter::Members(t) => t.td_name(),
       ChatMembersFilter::Mention(t) => t.td_name(),
       ChatMembersFilter::Restricted(t) => t.td_name(),
+      ChatMembersFilter::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatMembersFilter::Administrators(t) => t.extra(),
       ChatMembersFilter::Banned(t) => t.extra(),
@@ -80,6 +82,21 @@ impl RObject for ChatMembersFilter {
       ChatMembersFilter::Members(t) => t.extra(),
       ChatMembersFilter::Mention(t) => t.extra(),
       ChatMembersFilter::Restricted(t) => t.extra(),
+      ChatMembersFilter::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatMembersFilter::Administrators(t) => t.client_id(),
+      ChatMembersFilter::Banned(t) => t.client_id(),
+      ChatMembersFilter::Bots(t) => t.client_id(),
+      ChatMembersFilter::Contacts(t) => t.client_id(),
+      ChatMembersFilter::Members(t) => t.client_id(),
+      ChatMembersFilter::Mention(t) => t.client_id(),
+      ChatMembersFilter::Restricted(t) => t.client_id(),
+      ChatMembersFilter::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -88,16 +105,17 @@ impl RObject for ChatMembersFilter {
 }
 
 impl ChatMembersFilter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatMembersFilter::_Default(_) = self { true } else { false } }
-
-  pub fn is_administrators(&self) -> bool { if let ChatMembersFilter::Administrators(_) = self { true } else { false } }
-  pub fn is_banned(&self) -> bool { if let ChatMembersFilter::Banned(_) = self { true } else { false } }
-  pub fn is_bots(&self) -> bool { if let ChatMembersFilter::Bots(_) = self { true } else { false } }
-  pub fn is_contacts(&self) -> bool { if let ChatMembersFilter::Contacts(_) = self { true } else { false } }
-  pub fn is_members(&self) -> bool { if let ChatMembersFilter::Members(_) = self { true } else { false } }
-  pub fn is_mention(&self) -> bool { if let ChatMembersFilter::Mention(_) = self { true } else { false } }
-  pub fn is_restricted(&self) -> bool { if let ChatMembersFilter::Restricted(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatMembersFilter::_Default(_)) }
+
+  pub fn is_administrators(&self) -> bool { matches!(self, ChatMembersFilter::Administrators(_)) }
+  pub fn is_banned(&self) -> bool { matches!(self, ChatMembersFilter::Banned(_)) }
+  pub fn is_bots(&self) -> bool { matches!(self, ChatMembersFilter::Bots(_)) }
+  pub fn is_contacts(&self) -> bool { matches!(self, ChatMembersFilter::Contacts(_)) }
+  pub fn is_members(&self) -> bool { matches!(self, ChatMembersFilter::Members(_)) }
+  pub fn is_mention(&self) -> bool { matches!(self, ChatMembersFilter::Mention(_)) }
+  pub fn is_restricted(&self) -> bool { matches!(self, ChatMembersFilter::Restricted(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatMembersFilter::Unknown(_)) }
 
   pub fn on_administrators<F: FnOnce(&ChatMembersFilterAdministrators)>(&self, fnc: F) -> &Self { if let ChatMembersFilter::Administrators(t) = self { fnc(t) }; self }
   pub fn on_banned<F: FnOnce(&ChatMembersFilterBanned)>(&self, fnc: F) -> &Self { if let ChatMembersFilter::Banned(t) = self { fnc(t) }; self }
@@ -106,6 +124,7 @@ impl ChatMembersFilter {
   pub fn on_members<F: FnOnce(&ChatMembersFilterMembers)>(&self, fnc: F) -> &Self { if let ChatMembersFilter::Members(t) = self { fnc(t) }; self }
   pub fn on_mention<F: FnOnce(&ChatMembersFilterMention)>(&self, fnc: F) -> &Self { if let ChatMembersFilter::Mention(t) = self { fnc(t) }; self }
   pub fn on_restricted<F: FnOnce(&ChatMembersFilterRestricted)>(&self, fnc: F) -> &Self { if let ChatMembersFilter::Restricted(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatMembersFilter::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_administrators(&self) -> Option<&ChatMembersFilterAdministrators> { if let ChatMembersFilter::Administrators(t) = self { return Some(t) } None }
   pub fn as_banned(&self) -> Option<&ChatMembersFilterBanned> { if let ChatMembersFilter::Banned(t) = self { return Some(t) } None }
@@ -114,6 +133,7 @@ impl ChatMembersFilter {
   pub fn as_members(&self) -> Option<&ChatMembersFilterMembers> { if let ChatMembersFilter::Members(t) = self { return Some(t) } None }
   pub fn as_mention(&self) -> Option<&ChatMembersFilterMention> { if let ChatMembersFilter::Mention(t) = self { return Some(t) } None }
   pub fn as_restricted(&self) -> Option<&ChatMembersFilterRestricted> { if let ChatMembersFilter::Restricted(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatMembersFilter::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -151,13 +171,17 @@ pub struct ChatMembersFilterAdministrators {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMembersFilterAdministrators {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterAdministrators" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembersFilterAdministrators" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -167,14 +191,15 @@ impl TDChatMembersFilter for ChatMembersFilterAdministrators {}
 
 
 impl ChatMembersFilterAdministrators {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersFilterAdministratorsBuilder {
-    let mut inner = ChatMembersFilterAdministrators::default();
-    inner.td_name = "chatMembersFilterAdministrators".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembersFilterAdministrators { td_name: "chatMembersFilterAdministrators".to_string(), ..Default::default() };
     RTDChatMembersFilterAdministratorsBuilder { inner }
   }
 
+  /// `ChatMembersFilterAdministrators` with the fields tdlib requires, the others are set by `ChatMembersFilterAdministrators::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -185,6 +210,12 @@ pub struct RTDChatMembersFilterAdministratorsBuilder {
 impl RTDChatMembersFilterAdministratorsBuilder {
   pub fn build(&self) -> ChatMembersFilterAdministrators { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterAdministrators> for ChatMembersFilterAdministrators {
@@ -195,6 +226,10 @@ impl AsRef<ChatMembersFilterAdministrators> for RTDChatMembersFilterAdministrato
   fn as_ref(&self) -> &ChatMembersFilterAdministrators { &self.inner }
 }
 
+impl PartialEq for ChatMembersFilterAdministrators {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -209,13 +244,17 @@ pub struct ChatMembersFilterBanned {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMembersFilterBanned {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterBanned" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembersFilterBanned" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -225,14 +264,15 @@ impl TDChatMembersFilter for ChatMembersFilterBanned {}
 
 
 impl ChatMembersFilterBanned {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersFilterBannedBuilder {
-    let mut inner = ChatMembersFilterBanned::default();
-    inner.td_name = "chatMembersFilterBanned".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembersFilterBanned { td_name: "chatMembersFilterBanned".to_string(), ..Default::default() };
     RTDChatMembersFilterBannedBuilder { inner }
   }
 
+  /// `ChatMembersFilterBanned` with the fields tdlib requires, the others are set by `ChatMembersFilterBanned::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -243,6 +283,12 @@ pub struct RTDChatMembersFilterBannedBuilder {
 impl RTDChatMembersFilterBannedBuilder {
   pub fn build(&self) -> ChatMembersFilterBanned { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterBanned> for ChatMembersFilterBanned {
@@ -253,6 +299,10 @@ impl AsRef<ChatMembersFilterBanned> for RTDChatMembersFilterBannedBuilder {
   fn as_ref(&self) -> &ChatMembersFilterBanned { &self.inner }
 }
 
+impl PartialEq for ChatMembersFilterBanned {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -267,13 +317,17 @@ pub struct ChatMembersFilterBots {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMembersFilterBots {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterBots" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembersFilterBots" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -283,14 +337,15 @@ impl TDChatMembersFilter for ChatMembersFilterBots {}
 
 
 impl ChatMembersFilterBots {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersFilterBotsBuilder {
-    let mut inner = ChatMembersFilterBots::default();
-    inner.td_name = "chatMembersFilterBots".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembersFilterBots { td_name: "chatMembersFilterBots".to_string(), ..Default::default() };
     RTDChatMembersFilterBotsBuilder { inner }
   }
 
+  /// `ChatMembersFilterBots` with the fields tdlib requires, the others are set by `ChatMembersFilterBots::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -301,6 +356,12 @@ pub struct RTDChatMembersFilterBotsBuilder {
 impl RTDChatMembersFilterBotsBuilder {
   pub fn build(&self) -> ChatMembersFilterBots { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterBots> for ChatMembersFilterBots {
@@ -311,6 +372,10 @@ impl AsRef<ChatMembersFilterBots> for RTDChatMembersFilterBotsBuilder {
   fn as_ref(&self) -> &ChatMembersFilterBots { &self.inner }
 }
 
+impl PartialEq for ChatMembersFilterBots {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -325,13 +390,17 @@ pub struct ChatMembersFilterContacts {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMembersFilterContacts {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterContacts" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembersFilterContacts" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -341,14 +410,15 @@ impl TDChatMembersFilter for ChatMembersFilterContacts {}
 
 
 impl ChatMembersFilterContacts {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersFilterContactsBuilder {
-    let mut inner = ChatMembersFilterContacts::default();
-    inner.td_name = "chatMembersFilterContacts".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembersFilterContacts { td_name: "chatMembersFilterContacts".to_string(), ..Default::default() };
     RTDChatMembersFilterContactsBuilder { inner }
   }
 
+  /// `ChatMembersFilterContacts` with the fields tdlib requires, the others are set by `ChatMembersFilterContacts::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -359,6 +429,12 @@ pub struct RTDChatMembersFilterContactsBuilder {
 impl RTDChatMembersFilterContactsBuilder {
   pub fn build(&self) -> ChatMembersFilterContacts { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterContacts> for ChatMembersFilterContacts {
@@ -369,6 +445,10 @@ impl AsRef<ChatMembersFilterContacts> for RTDChatMembersFilterContactsBuilder {
   fn as_ref(&self) -> &ChatMembersFilterContacts { &self.inner }
 }
 
+impl PartialEq for ChatMembersFilterContacts {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -383,13 +463,17 @@ pub struct ChatMembersFilterMembers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMembersFilterMembers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterMembers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembersFilterMembers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -399,14 +483,15 @@ impl TDChatMembersFilter for ChatMembersFilterMembers {}
 
 
 impl ChatMembersFilterMembers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersFilterMembersBuilder {
-    let mut inner = ChatMembersFilterMembers::default();
-    inner.td_name = "chatMembersFilterMembers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembersFilterMembers { td_name: "chatMembersFilterMembers".to_string(), ..Default::default() };
     RTDChatMembersFilterMembersBuilder { inner }
   }
 
+  /// `ChatMembersFilterMembers` with the fields tdlib requires, the others are set by `ChatMembersFilterMembers::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -417,6 +502,12 @@ pub struct RTDChatMembersFilterMembersBuilder {
 impl RTDChatMembersFilterMembersBuilder {
   pub fn build(&self) -> ChatMembersFilterMembers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterMembers> for ChatMembersFilterMembers {
@@ -427,6 +518,10 @@ impl AsRef<ChatMembersFilterMembers> for RTDChatMembersFilterMembersBuilder {
   fn as_ref(&self) -> &ChatMembersFilterMembers { &self.inner }
 }
 
+impl PartialEq for ChatMembersFilterMembers {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -441,15 +536,19 @@ pub struct ChatMembersFilterMention {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If non-zero, the identifier of the current message thread
   message_thread_id: i64,
   
 }
 
 impl RObject for ChatMembersFilterMention {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterMention" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembersFilterMention" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -459,14 +558,15 @@ impl TDChatMembersFilter for ChatMembersFilterMention {}
 
 
 impl ChatMembersFilterMention {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersFilterMentionBuilder {
-    let mut inner = ChatMembersFilterMention::default();
-    inner.td_name = "chatMembersFilterMention".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembersFilterMention { td_name: "chatMembersFilterMention".to_string(), ..Default::default() };
     RTDChatMembersFilterMentionBuilder { inner }
   }
 
+  /// `ChatMembersFilterMention` with the fields tdlib requires, the others are set by `ChatMembersFilterMention::builder()`
+  pub fn new(message_thread_id: i64) -> Self { Self::builder().message_thread_id(message_thread_id).build() }
+
   pub fn message_thread_id(&self) -> i64 { self.message_thread_id }
 
 }
@@ -479,6 +579,12 @@ pub struct RTDChatMembersFilterMentionBuilder {
 impl RTDChatMembersFilterMentionBuilder {
   pub fn build(&self) -> ChatMembersFilterMention { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message_thread_id(&mut self, message_thread_id: i64) -> &mut Self {
     self.inner.message_thread_id = message_thread_id;
@@ -495,6 +601,12 @@ impl AsRef<ChatMembersFilterMention> for RTDChatMembersFilterMentionBuilder {
   fn as_ref(&self) -> &ChatMembersFilterMention { &self.inner }
 }
 
+impl PartialEq for ChatMembersFilterMention {
+  fn eq(&self, other: &Self) -> bool {
+    self.message_thread_id == other.message_thread_id
+  }
+}
+
 
 
 
@@ -509,13 +621,17 @@ pub struct ChatMembersFilterRestricted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatMembersFilterRestricted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatMembersFilterRestricted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatMembersFilterRestricted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -525,14 +641,15 @@ impl TDChatMembersFilter for ChatMembersFilterRestricted {}
 
 
 impl ChatMembersFilterRestricted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatMembersFilterRestrictedBuilder {
-    let mut inner = ChatMembersFilterRestricted::default();
-    inner.td_name = "chatMembersFilterRestricted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatMembersFilterRestricted { td_name: "chatMembersFilterRestricted".to_string(), ..Default::default() };
     RTDChatMembersFilterRestrictedBuilder { inner }
   }
 
+  /// `ChatMembersFilterRestricted` with the fields tdlib requires, the others are set by `ChatMembersFilterRestricted::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -543,6 +660,12 @@ pub struct RTDChatMembersFilterRestrictedBuilder {
 impl RTDChatMembersFilterRestrictedBuilder {
   pub fn build(&self) -> ChatMembersFilterRestricted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatMembersFilterRestricted> for ChatMembersFilterRestricted {
@@ -553,5 +676,9 @@ impl AsRef<ChatMembersFilterRestricted> for RTDChatMembersFilterRestrictedBuilde
   fn as_ref(&self) -> &ChatMembersFilterRestricted { &self.inner }
 }
 
+impl PartialEq for ChatMembersFilterRestricted {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
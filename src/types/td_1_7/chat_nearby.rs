@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,32 +13,37 @@ pub struct ChatNearby {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Distance to the chat location, in meters
   distance: i64,
   
 }
 
 impl RObject for ChatNearby {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatNearby" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatNearby" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatNearby {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatNearbyBuilder {
-    let mut inner = ChatNearby::default();
-    inner.td_name = "chatNearby".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatNearby { td_name: "chatNearby".to_string(), ..Default::default() };
     RTDChatNearbyBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `ChatNearby` with the fields tdlib requires, the others are set by `ChatNearby::builder()`
+  pub fn new(chat_id: ChatId, distance: i64) -> Self { Self::builder().chat_id(chat_id).distance(distance).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn distance(&self) -> i64 { self.distance }
 
@@ -53,8 +57,14 @@ pub struct RTDChatNearbyBuilder {
 impl RTDChatNearbyBuilder {
   pub fn build(&self) -> ChatNearby { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -75,5 +85,12 @@ impl AsRef<ChatNearby> for RTDChatNearbyBuilder {
   fn as_ref(&self) -> &ChatNearby { &self.inner }
 }
 
+impl PartialEq for ChatNearby {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.distance == other.distance
+  }
+}
+
 
 
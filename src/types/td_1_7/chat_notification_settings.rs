@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatNotificationSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If true, mute_for is ignored and the value for the relevant type of chat is used instead
   use_default_mute_for: bool,
   /// Time left before notifications will be unmuted, in seconds
@@ -39,22 +41,49 @@ pub struct ChatNotificationSettings {
 }
 
 impl RObject for ChatNotificationSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatNotificationSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatNotificationSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatNotificationSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatNotificationSettingsBuilder {
-    let mut inner = ChatNotificationSettings::default();
-    inner.td_name = "chatNotificationSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatNotificationSettings { td_name: "chatNotificationSettings".to_string(), ..Default::default() };
     RTDChatNotificationSettingsBuilder { inner }
   }
 
+  /// `ChatNotificationSettings` with the fields tdlib requires, the others are set by `ChatNotificationSettings::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    use_default_mute_for: bool,
+    mute_for: i64,
+    use_default_sound: bool,
+    sound: impl AsRef<str>,
+    use_default_show_preview: bool,
+    show_preview: bool,
+    use_default_disable_pinned_message_notifications: bool,
+    disable_pinned_message_notifications: bool,
+    use_default_disable_mention_notifications: bool,
+    disable_mention_notifications: bool,
+  ) -> Self {
+    Self::builder()
+      .use_default_mute_for(use_default_mute_for)
+      .mute_for(mute_for)
+      .use_default_sound(use_default_sound)
+      .sound(sound)
+      .use_default_show_preview(use_default_show_preview)
+      .show_preview(show_preview)
+      .use_default_disable_pinned_message_notifications(use_default_disable_pinned_message_notifications)
+      .disable_pinned_message_notifications(disable_pinned_message_notifications)
+      .use_default_disable_mention_notifications(use_default_disable_mention_notifications)
+      .disable_mention_notifications(disable_mention_notifications)
+      .build()
+  }
+
   pub fn use_default_mute_for(&self) -> bool { self.use_default_mute_for }
 
   pub fn mute_for(&self) -> i64 { self.mute_for }
@@ -85,6 +114,12 @@ pub struct RTDChatNotificationSettingsBuilder {
 impl RTDChatNotificationSettingsBuilder {
   pub fn build(&self) -> ChatNotificationSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn use_default_mute_for(&mut self, use_default_mute_for: bool) -> &mut Self {
     self.inner.use_default_mute_for = use_default_mute_for;
@@ -155,5 +190,20 @@ impl AsRef<ChatNotificationSettings> for RTDChatNotificationSettingsBuilder {
   fn as_ref(&self) -> &ChatNotificationSettings { &self.inner }
 }
 
+impl PartialEq for ChatNotificationSettings {
+  fn eq(&self, other: &Self) -> bool {
+    self.use_default_mute_for == other.use_default_mute_for
+      && self.mute_for == other.mute_for
+      && self.use_default_sound == other.use_default_sound
+      && self.sound == other.sound
+      && self.use_default_show_preview == other.use_default_show_preview
+      && self.show_preview == other.show_preview
+      && self.use_default_disable_pinned_message_notifications == other.use_default_disable_pinned_message_notifications
+      && self.disable_pinned_message_notifications == other.disable_pinned_message_notifications
+      && self.use_default_disable_mention_notifications == other.use_default_disable_mention_notifications
+      && self.disable_mention_notifications == other.disable_mention_notifications
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatPermissions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the user can send text messages, contacts, locations, and venues
   can_send_messages: bool,
   /// True, if the user can send audio files, documents, photos, videos, video notes, and voice notes. Implies can_send_messages permissions
@@ -35,22 +37,45 @@ pub struct ChatPermissions {
 }
 
 impl RObject for ChatPermissions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPermissions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatPermissions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatPermissions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatPermissionsBuilder {
-    let mut inner = ChatPermissions::default();
-    inner.td_name = "chatPermissions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatPermissions { td_name: "chatPermissions".to_string(), ..Default::default() };
     RTDChatPermissionsBuilder { inner }
   }
 
+  /// `ChatPermissions` with the fields tdlib requires, the others are set by `ChatPermissions::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    can_send_messages: bool,
+    can_send_media_messages: bool,
+    can_send_polls: bool,
+    can_send_other_messages: bool,
+    can_add_web_page_previews: bool,
+    can_change_info: bool,
+    can_invite_users: bool,
+    can_pin_messages: bool,
+  ) -> Self {
+    Self::builder()
+      .can_send_messages(can_send_messages)
+      .can_send_media_messages(can_send_media_messages)
+      .can_send_polls(can_send_polls)
+      .can_send_other_messages(can_send_other_messages)
+      .can_add_web_page_previews(can_add_web_page_previews)
+      .can_change_info(can_change_info)
+      .can_invite_users(can_invite_users)
+      .can_pin_messages(can_pin_messages)
+      .build()
+  }
+
   pub fn can_send_messages(&self) -> bool { self.can_send_messages }
 
   pub fn can_send_media_messages(&self) -> bool { self.can_send_media_messages }
@@ -77,6 +102,12 @@ pub struct RTDChatPermissionsBuilder {
 impl RTDChatPermissionsBuilder {
   pub fn build(&self) -> ChatPermissions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_send_messages(&mut self, can_send_messages: bool) -> &mut Self {
     self.inner.can_send_messages = can_send_messages;
@@ -135,5 +166,18 @@ impl AsRef<ChatPermissions> for RTDChatPermissionsBuilder {
   fn as_ref(&self) -> &ChatPermissions { &self.inner }
 }
 
+impl PartialEq for ChatPermissions {
+  fn eq(&self, other: &Self) -> bool {
+    self.can_send_messages == other.can_send_messages
+      && self.can_send_media_messages == other.can_send_media_messages
+      && self.can_send_polls == other.can_send_polls
+      && self.can_send_other_messages == other.can_send_other_messages
+      && self.can_add_web_page_previews == other.can_add_web_page_previews
+      && self.can_change_info == other.can_change_info
+      && self.can_invite_users == other.can_invite_users
+      && self.can_pin_messages == other.can_pin_messages
+  }
+}
+
 
 
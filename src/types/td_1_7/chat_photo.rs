@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique photo identifier
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Point in time (Unix timestamp) when the photo has been added
   added_date: i64,
   /// Photo minithumbnail; may be null
@@ -29,23 +31,25 @@ pub struct ChatPhoto {
 }
 
 impl RObject for ChatPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatPhotoBuilder {
-    let mut inner = ChatPhoto::default();
-    inner.td_name = "chatPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatPhoto { td_name: "chatPhoto".to_string(), ..Default::default() };
     RTDChatPhotoBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `ChatPhoto` with the fields tdlib requires, the others are set by `ChatPhoto::builder()`
+  pub fn new(id: i64, added_date: i64, sizes: Vec<PhotoSize>) -> Self { Self::builder().id(id).added_date(added_date).sizes(sizes).build() }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn added_date(&self) -> i64 { self.added_date }
 
@@ -65,8 +69,14 @@ pub struct RTDChatPhotoBuilder {
 impl RTDChatPhotoBuilder {
   pub fn build(&self) -> ChatPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -105,5 +115,15 @@ impl AsRef<ChatPhoto> for RTDChatPhotoBuilder {
   fn as_ref(&self) -> &ChatPhoto { &self.inner }
 }
 
+impl PartialEq for ChatPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.added_date == other.added_date
+      && self.minithumbnail == other.minithumbnail
+      && self.sizes == other.sizes
+      && self.animation == other.animation
+  }
+}
+
 
 
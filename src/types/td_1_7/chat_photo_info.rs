@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatPhotoInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A small (160x160) chat photo variant in JPEG format. The file can be downloaded only before the photo is changed
   small: File,
   /// A big (640x640) chat photo variant in JPEG format. The file can be downloaded only before the photo is changed
@@ -27,22 +29,24 @@ pub struct ChatPhotoInfo {
 }
 
 impl RObject for ChatPhotoInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPhotoInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatPhotoInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatPhotoInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatPhotoInfoBuilder {
-    let mut inner = ChatPhotoInfo::default();
-    inner.td_name = "chatPhotoInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatPhotoInfo { td_name: "chatPhotoInfo".to_string(), ..Default::default() };
     RTDChatPhotoInfoBuilder { inner }
   }
 
+  /// `ChatPhotoInfo` with the fields tdlib requires, the others are set by `ChatPhotoInfo::builder()`
+  pub fn new(small: impl AsRef<File>, big: impl AsRef<File>) -> Self { Self::builder().small(small).big(big).build() }
+
   pub fn small(&self) -> &File { &self.small }
 
   pub fn big(&self) -> &File { &self.big }
@@ -61,6 +65,12 @@ pub struct RTDChatPhotoInfoBuilder {
 impl RTDChatPhotoInfoBuilder {
   pub fn build(&self) -> ChatPhotoInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn small<T: AsRef<File>>(&mut self, small: T) -> &mut Self {
     self.inner.small = small.as_ref().clone();
@@ -95,5 +105,14 @@ impl AsRef<ChatPhotoInfo> for RTDChatPhotoInfoBuilder {
   fn as_ref(&self) -> &ChatPhotoInfo { &self.inner }
 }
 
+impl PartialEq for ChatPhotoInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.small == other.small
+      && self.big == other.big
+      && self.minithumbnail == other.minithumbnail
+      && self.has_animation == other.has_animation
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatPhotos {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Total number of photos
   total_count: i64,
   /// List of photos
@@ -23,22 +25,24 @@ pub struct ChatPhotos {
 }
 
 impl RObject for ChatPhotos {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPhotos" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatPhotos" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatPhotos {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatPhotosBuilder {
-    let mut inner = ChatPhotos::default();
-    inner.td_name = "chatPhotos".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatPhotos { td_name: "chatPhotos".to_string(), ..Default::default() };
     RTDChatPhotosBuilder { inner }
   }
 
+  /// `ChatPhotos` with the fields tdlib requires, the others are set by `ChatPhotos::builder()`
+  pub fn new(total_count: i64, photos: Vec<ChatPhoto>) -> Self { Self::builder().total_count(total_count).photos(photos).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
   pub fn photos(&self) -> &Vec<ChatPhoto> { &self.photos }
@@ -53,6 +57,12 @@ pub struct RTDChatPhotosBuilder {
 impl RTDChatPhotosBuilder {
   pub fn build(&self) -> ChatPhotos { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -75,5 +85,12 @@ impl AsRef<ChatPhotos> for RTDChatPhotosBuilder {
   fn as_ref(&self) -> &ChatPhotos { &self.inner }
 }
 
+impl PartialEq for ChatPhotos {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.photos == other.photos
+  }
+}
+
 
 
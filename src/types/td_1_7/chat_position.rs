@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,11 +13,14 @@ pub struct ChatPosition {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat list
   list: ChatList,
   /// A parameter used to determine order of the chat in the chat list. Chats must be sorted by the pair (order, chat.id) in descending order
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] order: isize,
+  #[serde(with = "crate::types::_common::int64")] order: i64,
   /// True, if the chat is pinned in the chat list
   is_pinned: bool,
   /// Source of the chat in the chat list; may be null
@@ -27,25 +29,27 @@ pub struct ChatPosition {
 }
 
 impl RObject for ChatPosition {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatPosition" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatPosition" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatPosition {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatPositionBuilder {
-    let mut inner = ChatPosition::default();
-    inner.td_name = "chatPosition".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatPosition { td_name: "chatPosition".to_string(), ..Default::default() };
     RTDChatPositionBuilder { inner }
   }
 
+  /// `ChatPosition` with the fields tdlib requires, the others are set by `ChatPosition::builder()`
+  pub fn new(list: impl AsRef<ChatList>, order: i64, is_pinned: bool) -> Self { Self::builder().list(list).order(order).is_pinned(is_pinned).build() }
+
   pub fn list(&self) -> &ChatList { &self.list }
 
-  pub fn order(&self) -> isize { self.order }
+  pub fn order(&self) -> i64 { self.order }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
 
@@ -61,6 +65,12 @@ pub struct RTDChatPositionBuilder {
 impl RTDChatPositionBuilder {
   pub fn build(&self) -> ChatPosition { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn list<T: AsRef<ChatList>>(&mut self, list: T) -> &mut Self {
     self.inner.list = list.as_ref().clone();
@@ -68,7 +78,7 @@ impl RTDChatPositionBuilder {
   }
 
    
-  pub fn order(&mut self, order: isize) -> &mut Self {
+  pub fn order(&mut self, order: i64) -> &mut Self {
     self.inner.order = order;
     self
   }
@@ -95,5 +105,14 @@ impl AsRef<ChatPosition> for RTDChatPositionBuilder {
   fn as_ref(&self) -> &ChatPosition { &self.inner }
 }
 
+impl PartialEq for ChatPosition {
+  fn eq(&self, other: &Self) -> bool {
+    self.list == other.list
+      && self.order == other.order
+      && self.is_pinned == other.is_pinned
+      && self.source == other.source
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatReportReason: Debug + RObject {}
 
 /// Describes the reason why a chat is reported
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatReportReason {
   #[doc(hidden)] _Default(()),
@@ -35,6 +34,8 @@ pub enum ChatReportReason {
   UnrelatedLocation(ChatReportReasonUnrelatedLocation),
   /// The chat promotes violence
   Violence(ChatReportReasonViolence),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -46,7 +47,7 @@ impl<'de> Deserialize<'de> for ChatReportReason {
   fn deserialize<D>(deserializer: D) -> Result<ChatReportReason, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatReportReason,
+      ChatReportReason, Unknown;
       (chatReportReasonChildAbuse, ChildAbuse);
       (chatReportReasonCopyright, Copyright);
       (chatReportReasonCustom, Custom);
@@ -61,7 +62,7 @@ impl<'de> Deserialize<'de> for ChatReportReason {
 }
 
 impl RObject for ChatReportReason {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatReportReason::ChildAbuse(t) => t.td_name(),
       ChatReportReason::Copyright(t) => t.td_name(),
@@ -71,11 +72,12 @@ impl RObject for ChatReportReason {
       ChatReportReason::Spam(t) => t.td_name(),
       ChatReportReason::UnrelatedLocation(t) => t.td_name(),
       ChatReportReason::Violence(t) => t.td_name(),
+      ChatReportReason::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatReportReason::ChildAbuse(t) => t.extra(),
       ChatReportReason::Copyright(t) => t.extra(),
@@ -85,6 +87,22 @@ impl RObject for ChatReportReason {
       ChatReportReason::Spam(t) => t.extra(),
       ChatReportReason::UnrelatedLocation(t) => t.extra(),
       ChatReportReason::Violence(t) => t.extra(),
+      ChatReportReason::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatReportReason::ChildAbuse(t) => t.client_id(),
+      ChatReportReason::Copyright(t) => t.client_id(),
+      ChatReportReason::Custom(t) => t.client_id(),
+      ChatReportReason::Fake(t) => t.client_id(),
+      ChatReportReason::Pornography(t) => t.client_id(),
+      ChatReportReason::Spam(t) => t.client_id(),
+      ChatReportReason::UnrelatedLocation(t) => t.client_id(),
+      ChatReportReason::Violence(t) => t.client_id(),
+      ChatReportReason::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -93,17 +111,18 @@ impl RObject for ChatReportReason {
 }
 
 impl ChatReportReason {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatReportReason::_Default(_) = self { true } else { false } }
-
-  pub fn is_child_abuse(&self) -> bool { if let ChatReportReason::ChildAbuse(_) = self { true } else { false } }
-  pub fn is_copyright(&self) -> bool { if let ChatReportReason::Copyright(_) = self { true } else { false } }
-  pub fn is_custom(&self) -> bool { if let ChatReportReason::Custom(_) = self { true } else { false } }
-  pub fn is_fake(&self) -> bool { if let ChatReportReason::Fake(_) = self { true } else { false } }
-  pub fn is_pornography(&self) -> bool { if let ChatReportReason::Pornography(_) = self { true } else { false } }
-  pub fn is_spam(&self) -> bool { if let ChatReportReason::Spam(_) = self { true } else { false } }
-  pub fn is_unrelated_location(&self) -> bool { if let ChatReportReason::UnrelatedLocation(_) = self { true } else { false } }
-  pub fn is_violence(&self) -> bool { if let ChatReportReason::Violence(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatReportReason::_Default(_)) }
+
+  pub fn is_child_abuse(&self) -> bool { matches!(self, ChatReportReason::ChildAbuse(_)) }
+  pub fn is_copyright(&self) -> bool { matches!(self, ChatReportReason::Copyright(_)) }
+  pub fn is_custom(&self) -> bool { matches!(self, ChatReportReason::Custom(_)) }
+  pub fn is_fake(&self) -> bool { matches!(self, ChatReportReason::Fake(_)) }
+  pub fn is_pornography(&self) -> bool { matches!(self, ChatReportReason::Pornography(_)) }
+  pub fn is_spam(&self) -> bool { matches!(self, ChatReportReason::Spam(_)) }
+  pub fn is_unrelated_location(&self) -> bool { matches!(self, ChatReportReason::UnrelatedLocation(_)) }
+  pub fn is_violence(&self) -> bool { matches!(self, ChatReportReason::Violence(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatReportReason::Unknown(_)) }
 
   pub fn on_child_abuse<F: FnOnce(&ChatReportReasonChildAbuse)>(&self, fnc: F) -> &Self { if let ChatReportReason::ChildAbuse(t) = self { fnc(t) }; self }
   pub fn on_copyright<F: FnOnce(&ChatReportReasonCopyright)>(&self, fnc: F) -> &Self { if let ChatReportReason::Copyright(t) = self { fnc(t) }; self }
@@ -113,6 +132,7 @@ impl ChatReportReason {
   pub fn on_spam<F: FnOnce(&ChatReportReasonSpam)>(&self, fnc: F) -> &Self { if let ChatReportReason::Spam(t) = self { fnc(t) }; self }
   pub fn on_unrelated_location<F: FnOnce(&ChatReportReasonUnrelatedLocation)>(&self, fnc: F) -> &Self { if let ChatReportReason::UnrelatedLocation(t) = self { fnc(t) }; self }
   pub fn on_violence<F: FnOnce(&ChatReportReasonViolence)>(&self, fnc: F) -> &Self { if let ChatReportReason::Violence(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatReportReason::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_child_abuse(&self) -> Option<&ChatReportReasonChildAbuse> { if let ChatReportReason::ChildAbuse(t) = self { return Some(t) } None }
   pub fn as_copyright(&self) -> Option<&ChatReportReasonCopyright> { if let ChatReportReason::Copyright(t) = self { return Some(t) } None }
@@ -122,6 +142,7 @@ impl ChatReportReason {
   pub fn as_spam(&self) -> Option<&ChatReportReasonSpam> { if let ChatReportReason::Spam(t) = self { return Some(t) } None }
   pub fn as_unrelated_location(&self) -> Option<&ChatReportReasonUnrelatedLocation> { if let ChatReportReason::UnrelatedLocation(t) = self { return Some(t) } None }
   pub fn as_violence(&self) -> Option<&ChatReportReasonViolence> { if let ChatReportReason::Violence(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatReportReason::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -161,13 +182,17 @@ pub struct ChatReportReasonChildAbuse {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonChildAbuse {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonChildAbuse" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonChildAbuse" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -177,14 +202,15 @@ impl TDChatReportReason for ChatReportReasonChildAbuse {}
 
 
 impl ChatReportReasonChildAbuse {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonChildAbuseBuilder {
-    let mut inner = ChatReportReasonChildAbuse::default();
-    inner.td_name = "chatReportReasonChildAbuse".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonChildAbuse { td_name: "chatReportReasonChildAbuse".to_string(), ..Default::default() };
     RTDChatReportReasonChildAbuseBuilder { inner }
   }
 
+  /// `ChatReportReasonChildAbuse` with the fields tdlib requires, the others are set by `ChatReportReasonChildAbuse::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +221,12 @@ pub struct RTDChatReportReasonChildAbuseBuilder {
 impl RTDChatReportReasonChildAbuseBuilder {
   pub fn build(&self) -> ChatReportReasonChildAbuse { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonChildAbuse> for ChatReportReasonChildAbuse {
@@ -205,6 +237,10 @@ impl AsRef<ChatReportReasonChildAbuse> for RTDChatReportReasonChildAbuseBuilder
   fn as_ref(&self) -> &ChatReportReasonChildAbuse { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonChildAbuse {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -219,13 +255,17 @@ pub struct ChatReportReasonCopyright {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonCopyright {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonCopyright" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonCopyright" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -235,14 +275,15 @@ impl TDChatReportReason for ChatReportReasonCopyright {}
 
 
 impl ChatReportReasonCopyright {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonCopyrightBuilder {
-    let mut inner = ChatReportReasonCopyright::default();
-    inner.td_name = "chatReportReasonCopyright".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonCopyright { td_name: "chatReportReasonCopyright".to_string(), ..Default::default() };
     RTDChatReportReasonCopyrightBuilder { inner }
   }
 
+  /// `ChatReportReasonCopyright` with the fields tdlib requires, the others are set by `ChatReportReasonCopyright::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -253,6 +294,12 @@ pub struct RTDChatReportReasonCopyrightBuilder {
 impl RTDChatReportReasonCopyrightBuilder {
   pub fn build(&self) -> ChatReportReasonCopyright { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonCopyright> for ChatReportReasonCopyright {
@@ -263,6 +310,10 @@ impl AsRef<ChatReportReasonCopyright> for RTDChatReportReasonCopyrightBuilder {
   fn as_ref(&self) -> &ChatReportReasonCopyright { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonCopyright {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -277,13 +328,17 @@ pub struct ChatReportReasonCustom {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonCustom {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonCustom" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonCustom" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -293,14 +348,15 @@ impl TDChatReportReason for ChatReportReasonCustom {}
 
 
 impl ChatReportReasonCustom {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonCustomBuilder {
-    let mut inner = ChatReportReasonCustom::default();
-    inner.td_name = "chatReportReasonCustom".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonCustom { td_name: "chatReportReasonCustom".to_string(), ..Default::default() };
     RTDChatReportReasonCustomBuilder { inner }
   }
 
+  /// `ChatReportReasonCustom` with the fields tdlib requires, the others are set by `ChatReportReasonCustom::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -311,6 +367,12 @@ pub struct RTDChatReportReasonCustomBuilder {
 impl RTDChatReportReasonCustomBuilder {
   pub fn build(&self) -> ChatReportReasonCustom { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonCustom> for ChatReportReasonCustom {
@@ -321,6 +383,10 @@ impl AsRef<ChatReportReasonCustom> for RTDChatReportReasonCustomBuilder {
   fn as_ref(&self) -> &ChatReportReasonCustom { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonCustom {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -335,13 +401,17 @@ pub struct ChatReportReasonFake {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonFake {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonFake" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonFake" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -351,14 +421,15 @@ impl TDChatReportReason for ChatReportReasonFake {}
 
 
 impl ChatReportReasonFake {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonFakeBuilder {
-    let mut inner = ChatReportReasonFake::default();
-    inner.td_name = "chatReportReasonFake".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonFake { td_name: "chatReportReasonFake".to_string(), ..Default::default() };
     RTDChatReportReasonFakeBuilder { inner }
   }
 
+  /// `ChatReportReasonFake` with the fields tdlib requires, the others are set by `ChatReportReasonFake::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -369,6 +440,12 @@ pub struct RTDChatReportReasonFakeBuilder {
 impl RTDChatReportReasonFakeBuilder {
   pub fn build(&self) -> ChatReportReasonFake { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonFake> for ChatReportReasonFake {
@@ -379,6 +456,10 @@ impl AsRef<ChatReportReasonFake> for RTDChatReportReasonFakeBuilder {
   fn as_ref(&self) -> &ChatReportReasonFake { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonFake {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -393,13 +474,17 @@ pub struct ChatReportReasonPornography {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonPornography {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonPornography" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonPornography" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -409,14 +494,15 @@ impl TDChatReportReason for ChatReportReasonPornography {}
 
 
 impl ChatReportReasonPornography {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonPornographyBuilder {
-    let mut inner = ChatReportReasonPornography::default();
-    inner.td_name = "chatReportReasonPornography".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonPornography { td_name: "chatReportReasonPornography".to_string(), ..Default::default() };
     RTDChatReportReasonPornographyBuilder { inner }
   }
 
+  /// `ChatReportReasonPornography` with the fields tdlib requires, the others are set by `ChatReportReasonPornography::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -427,6 +513,12 @@ pub struct RTDChatReportReasonPornographyBuilder {
 impl RTDChatReportReasonPornographyBuilder {
   pub fn build(&self) -> ChatReportReasonPornography { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonPornography> for ChatReportReasonPornography {
@@ -437,6 +529,10 @@ impl AsRef<ChatReportReasonPornography> for RTDChatReportReasonPornographyBuilde
   fn as_ref(&self) -> &ChatReportReasonPornography { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonPornography {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -451,13 +547,17 @@ pub struct ChatReportReasonSpam {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonSpam {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonSpam" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonSpam" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -467,14 +567,15 @@ impl TDChatReportReason for ChatReportReasonSpam {}
 
 
 impl ChatReportReasonSpam {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonSpamBuilder {
-    let mut inner = ChatReportReasonSpam::default();
-    inner.td_name = "chatReportReasonSpam".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonSpam { td_name: "chatReportReasonSpam".to_string(), ..Default::default() };
     RTDChatReportReasonSpamBuilder { inner }
   }
 
+  /// `ChatReportReasonSpam` with the fields tdlib requires, the others are set by `ChatReportReasonSpam::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -485,6 +586,12 @@ pub struct RTDChatReportReasonSpamBuilder {
 impl RTDChatReportReasonSpamBuilder {
   pub fn build(&self) -> ChatReportReasonSpam { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonSpam> for ChatReportReasonSpam {
@@ -495,6 +602,10 @@ impl AsRef<ChatReportReasonSpam> for RTDChatReportReasonSpamBuilder {
   fn as_ref(&self) -> &ChatReportReasonSpam { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonSpam {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -509,13 +620,17 @@ pub struct ChatReportReasonUnrelatedLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonUnrelatedLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonUnrelatedLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonUnrelatedLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -525,14 +640,15 @@ impl TDChatReportReason for ChatReportReasonUnrelatedLocation {}
 
 
 impl ChatReportReasonUnrelatedLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonUnrelatedLocationBuilder {
-    let mut inner = ChatReportReasonUnrelatedLocation::default();
-    inner.td_name = "chatReportReasonUnrelatedLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonUnrelatedLocation { td_name: "chatReportReasonUnrelatedLocation".to_string(), ..Default::default() };
     RTDChatReportReasonUnrelatedLocationBuilder { inner }
   }
 
+  /// `ChatReportReasonUnrelatedLocation` with the fields tdlib requires, the others are set by `ChatReportReasonUnrelatedLocation::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -543,6 +659,12 @@ pub struct RTDChatReportReasonUnrelatedLocationBuilder {
 impl RTDChatReportReasonUnrelatedLocationBuilder {
   pub fn build(&self) -> ChatReportReasonUnrelatedLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonUnrelatedLocation> for ChatReportReasonUnrelatedLocation {
@@ -553,6 +675,10 @@ impl AsRef<ChatReportReasonUnrelatedLocation> for RTDChatReportReasonUnrelatedLo
   fn as_ref(&self) -> &ChatReportReasonUnrelatedLocation { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonUnrelatedLocation {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -567,13 +693,17 @@ pub struct ChatReportReasonViolence {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatReportReasonViolence {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatReportReasonViolence" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatReportReasonViolence" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -583,14 +713,15 @@ impl TDChatReportReason for ChatReportReasonViolence {}
 
 
 impl ChatReportReasonViolence {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatReportReasonViolenceBuilder {
-    let mut inner = ChatReportReasonViolence::default();
-    inner.td_name = "chatReportReasonViolence".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatReportReasonViolence { td_name: "chatReportReasonViolence".to_string(), ..Default::default() };
     RTDChatReportReasonViolenceBuilder { inner }
   }
 
+  /// `ChatReportReasonViolence` with the fields tdlib requires, the others are set by `ChatReportReasonViolence::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -601,6 +732,12 @@ pub struct RTDChatReportReasonViolenceBuilder {
 impl RTDChatReportReasonViolenceBuilder {
   pub fn build(&self) -> ChatReportReasonViolence { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatReportReasonViolence> for ChatReportReasonViolence {
@@ -611,5 +748,9 @@ impl AsRef<ChatReportReasonViolence> for RTDChatReportReasonViolenceBuilder {
   fn as_ref(&self) -> &ChatReportReasonViolence { &self.inner }
 }
 
+impl PartialEq for ChatReportReasonViolence {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
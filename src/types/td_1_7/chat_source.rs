@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatSource: Debug + RObject {}
 
 /// Describes a reason why an external chat is shown in a chat list
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatSource {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum ChatSource {
   MtprotoProxy(ChatSourceMtprotoProxy),
   /// The chat contains a public service announcement
   PublicServiceAnnouncement(ChatSourcePublicServiceAnnouncement),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for ChatSource {
   fn deserialize<D>(deserializer: D) -> Result<ChatSource, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatSource,
+      ChatSource, Unknown;
       (chatSourceMtprotoProxy, MtprotoProxy);
       (chatSourcePublicServiceAnnouncement, PublicServiceAnnouncement);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for ChatSource {
 }
 
 impl RObject for ChatSource {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatSource::MtprotoProxy(t) => t.td_name(),
       ChatSource::PublicServiceAnnouncement(t) => t.td_name(),
+      ChatSource::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatSource::MtprotoProxy(t) => t.extra(),
       ChatSource::PublicServiceAnnouncement(t) => t.extra(),
+      ChatSource::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatSource::MtprotoProxy(t) => t.client_id(),
+      ChatSource::PublicServiceAnnouncement(t) => t.client_id(),
+      ChatSource::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for ChatSource {
 }
 
 impl ChatSource {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatSource::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatSource::_Default(_)) }
 
-  pub fn is_mtproto_proxy(&self) -> bool { if let ChatSource::MtprotoProxy(_) = self { true } else { false } }
-  pub fn is_public_service_announcement(&self) -> bool { if let ChatSource::PublicServiceAnnouncement(_) = self { true } else { false } }
+  pub fn is_mtproto_proxy(&self) -> bool { matches!(self, ChatSource::MtprotoProxy(_)) }
+  pub fn is_public_service_announcement(&self) -> bool { matches!(self, ChatSource::PublicServiceAnnouncement(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatSource::Unknown(_)) }
 
   pub fn on_mtproto_proxy<F: FnOnce(&ChatSourceMtprotoProxy)>(&self, fnc: F) -> &Self { if let ChatSource::MtprotoProxy(t) = self { fnc(t) }; self }
   pub fn on_public_service_announcement<F: FnOnce(&ChatSourcePublicServiceAnnouncement)>(&self, fnc: F) -> &Self { if let ChatSource::PublicServiceAnnouncement(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatSource::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_mtproto_proxy(&self) -> Option<&ChatSourceMtprotoProxy> { if let ChatSource::MtprotoProxy(t) = self { return Some(t) } None }
   pub fn as_public_service_announcement(&self) -> Option<&ChatSourcePublicServiceAnnouncement> { if let ChatSource::PublicServiceAnnouncement(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatSource::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,13 +116,17 @@ pub struct ChatSourceMtprotoProxy {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ChatSourceMtprotoProxy {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatSourceMtprotoProxy" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatSourceMtprotoProxy" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -117,14 +136,15 @@ impl TDChatSource for ChatSourceMtprotoProxy {}
 
 
 impl ChatSourceMtprotoProxy {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatSourceMtprotoProxyBuilder {
-    let mut inner = ChatSourceMtprotoProxy::default();
-    inner.td_name = "chatSourceMtprotoProxy".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatSourceMtprotoProxy { td_name: "chatSourceMtprotoProxy".to_string(), ..Default::default() };
     RTDChatSourceMtprotoProxyBuilder { inner }
   }
 
+  /// `ChatSourceMtprotoProxy` with the fields tdlib requires, the others are set by `ChatSourceMtprotoProxy::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -135,6 +155,12 @@ pub struct RTDChatSourceMtprotoProxyBuilder {
 impl RTDChatSourceMtprotoProxyBuilder {
   pub fn build(&self) -> ChatSourceMtprotoProxy { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ChatSourceMtprotoProxy> for ChatSourceMtprotoProxy {
@@ -145,6 +171,10 @@ impl AsRef<ChatSourceMtprotoProxy> for RTDChatSourceMtprotoProxyBuilder {
   fn as_ref(&self) -> &ChatSourceMtprotoProxy { &self.inner }
 }
 
+impl PartialEq for ChatSourceMtprotoProxy {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -159,7 +189,10 @@ pub struct ChatSourcePublicServiceAnnouncement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The type of the announcement
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: String,
   /// The text of the announcement
@@ -168,8 +201,9 @@ pub struct ChatSourcePublicServiceAnnouncement {
 }
 
 impl RObject for ChatSourcePublicServiceAnnouncement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatSourcePublicServiceAnnouncement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatSourcePublicServiceAnnouncement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -179,14 +213,15 @@ impl TDChatSource for ChatSourcePublicServiceAnnouncement {}
 
 
 impl ChatSourcePublicServiceAnnouncement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatSourcePublicServiceAnnouncementBuilder {
-    let mut inner = ChatSourcePublicServiceAnnouncement::default();
-    inner.td_name = "chatSourcePublicServiceAnnouncement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatSourcePublicServiceAnnouncement { td_name: "chatSourcePublicServiceAnnouncement".to_string(), ..Default::default() };
     RTDChatSourcePublicServiceAnnouncementBuilder { inner }
   }
 
+  /// `ChatSourcePublicServiceAnnouncement` with the fields tdlib requires, the others are set by `ChatSourcePublicServiceAnnouncement::builder()`
+  pub fn new(type_: impl AsRef<str>, text: impl AsRef<str>) -> Self { Self::builder().type_(type_).text(text).build() }
+
   pub fn type_(&self) -> &String { &self.type_ }
 
   pub fn text(&self) -> &String { &self.text }
@@ -201,6 +236,12 @@ pub struct RTDChatSourcePublicServiceAnnouncementBuilder {
 impl RTDChatSourcePublicServiceAnnouncementBuilder {
   pub fn build(&self) -> ChatSourcePublicServiceAnnouncement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
@@ -223,5 +264,12 @@ impl AsRef<ChatSourcePublicServiceAnnouncement> for RTDChatSourcePublicServiceAn
   fn as_ref(&self) -> &ChatSourcePublicServiceAnnouncement { &self.inner }
 }
 
+impl PartialEq for ChatSourcePublicServiceAnnouncement {
+  fn eq(&self, other: &Self) -> bool {
+    self.type_ == other.type_
+      && self.text == other.text
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatStatistics: Debug + RObject {}
 
 /// Contains a detailed statistics about a chat
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatStatistics {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum ChatStatistics {
   Supergroup(ChatStatisticsSupergroup),
   /// Returns detailed statistics about a chat. Currently this method can be used only for supergroups and channels. Can be used only if supergroupFullInfo.can_get_statistics == true
   GetChatStatistics(GetChatStatistics),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for ChatStatistics {
   fn deserialize<D>(deserializer: D) -> Result<ChatStatistics, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatStatistics,
+      ChatStatistics, Unknown;
       (chatStatisticsChannel, Channel);
       (chatStatisticsSupergroup, Supergroup);
       (getChatStatistics, GetChatStatistics);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for ChatStatistics {
 }
 
 impl RObject for ChatStatistics {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatStatistics::Channel(t) => t.td_name(),
       ChatStatistics::Supergroup(t) => t.td_name(),
       ChatStatistics::GetChatStatistics(t) => t.td_name(),
+      ChatStatistics::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatStatistics::Channel(t) => t.extra(),
       ChatStatistics::Supergroup(t) => t.extra(),
       ChatStatistics::GetChatStatistics(t) => t.extra(),
+      ChatStatistics::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatStatistics::Channel(t) => t.client_id(),
+      ChatStatistics::Supergroup(t) => t.client_id(),
+      ChatStatistics::GetChatStatistics(t) => t.client_id(),
+      ChatStatistics::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for ChatStatistics {
 }
 
 impl ChatStatistics {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatStatistics::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatStatistics::_Default(_)) }
 
-  pub fn is_channel(&self) -> bool { if let ChatStatistics::Channel(_) = self { true } else { false } }
-  pub fn is_supergroup(&self) -> bool { if let ChatStatistics::Supergroup(_) = self { true } else { false } }
-  pub fn is_get_chat_statistics(&self) -> bool { if let ChatStatistics::GetChatStatistics(_) = self { true } else { false } }
+  pub fn is_channel(&self) -> bool { matches!(self, ChatStatistics::Channel(_)) }
+  pub fn is_supergroup(&self) -> bool { matches!(self, ChatStatistics::Supergroup(_)) }
+  pub fn is_get_chat_statistics(&self) -> bool { matches!(self, ChatStatistics::GetChatStatistics(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatStatistics::Unknown(_)) }
 
   pub fn on_channel<F: FnOnce(&ChatStatisticsChannel)>(&self, fnc: F) -> &Self { if let ChatStatistics::Channel(t) = self { fnc(t) }; self }
   pub fn on_supergroup<F: FnOnce(&ChatStatisticsSupergroup)>(&self, fnc: F) -> &Self { if let ChatStatistics::Supergroup(t) = self { fnc(t) }; self }
   pub fn on_get_chat_statistics<F: FnOnce(&GetChatStatistics)>(&self, fnc: F) -> &Self { if let ChatStatistics::GetChatStatistics(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatStatistics::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_channel(&self) -> Option<&ChatStatisticsChannel> { if let ChatStatistics::Channel(t) = self { return Some(t) } None }
   pub fn as_supergroup(&self) -> Option<&ChatStatisticsSupergroup> { if let ChatStatistics::Supergroup(t) = self { return Some(t) } None }
   pub fn as_get_chat_statistics(&self) -> Option<&GetChatStatistics> { if let ChatStatistics::GetChatStatistics(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatStatistics::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,7 +127,10 @@ pub struct ChatStatisticsChannel {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A period to which the statistics applies
   period: DateRange,
   /// Number of members in the chat
@@ -146,8 +165,9 @@ pub struct ChatStatisticsChannel {
 }
 
 impl RObject for ChatStatisticsChannel {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsChannel" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatStatisticsChannel" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,14 +177,50 @@ impl TDChatStatistics for ChatStatisticsChannel {}
 
 
 impl ChatStatisticsChannel {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatStatisticsChannelBuilder {
-    let mut inner = ChatStatisticsChannel::default();
-    inner.td_name = "chatStatisticsChannel".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatStatisticsChannel { td_name: "chatStatisticsChannel".to_string(), ..Default::default() };
     RTDChatStatisticsChannelBuilder { inner }
   }
 
+  /// `ChatStatisticsChannel` with the fields tdlib requires, the others are set by `ChatStatisticsChannel::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    period: impl AsRef<DateRange>,
+    member_count: impl AsRef<StatisticalValue>,
+    mean_view_count: impl AsRef<StatisticalValue>,
+    mean_share_count: impl AsRef<StatisticalValue>,
+    enabled_notifications_percentage: f32,
+    member_count_graph: impl AsRef<StatisticalGraph>,
+    join_graph: impl AsRef<StatisticalGraph>,
+    mute_graph: impl AsRef<StatisticalGraph>,
+    view_count_by_hour_graph: impl AsRef<StatisticalGraph>,
+    view_count_by_source_graph: impl AsRef<StatisticalGraph>,
+    join_by_source_graph: impl AsRef<StatisticalGraph>,
+    language_graph: impl AsRef<StatisticalGraph>,
+    message_interaction_graph: impl AsRef<StatisticalGraph>,
+    instant_view_interaction_graph: impl AsRef<StatisticalGraph>,
+    recent_message_interactions: Vec<ChatStatisticsMessageInteractionInfo>,
+  ) -> Self {
+    Self::builder()
+      .period(period)
+      .member_count(member_count)
+      .mean_view_count(mean_view_count)
+      .mean_share_count(mean_share_count)
+      .enabled_notifications_percentage(enabled_notifications_percentage)
+      .member_count_graph(member_count_graph)
+      .join_graph(join_graph)
+      .mute_graph(mute_graph)
+      .view_count_by_hour_graph(view_count_by_hour_graph)
+      .view_count_by_source_graph(view_count_by_source_graph)
+      .join_by_source_graph(join_by_source_graph)
+      .language_graph(language_graph)
+      .message_interaction_graph(message_interaction_graph)
+      .instant_view_interaction_graph(instant_view_interaction_graph)
+      .recent_message_interactions(recent_message_interactions)
+      .build()
+  }
+
   pub fn period(&self) -> &DateRange { &self.period }
 
   pub fn member_count(&self) -> &StatisticalValue { &self.member_count }
@@ -205,6 +261,12 @@ pub struct RTDChatStatisticsChannelBuilder {
 impl RTDChatStatisticsChannelBuilder {
   pub fn build(&self) -> ChatStatisticsChannel { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn period<T: AsRef<DateRange>>(&mut self, period: T) -> &mut Self {
     self.inner.period = period.as_ref().clone();
@@ -305,6 +367,26 @@ impl AsRef<ChatStatisticsChannel> for RTDChatStatisticsChannelBuilder {
   fn as_ref(&self) -> &ChatStatisticsChannel { &self.inner }
 }
 
+impl PartialEq for ChatStatisticsChannel {
+  fn eq(&self, other: &Self) -> bool {
+    self.period == other.period
+      && self.member_count == other.member_count
+      && self.mean_view_count == other.mean_view_count
+      && self.mean_share_count == other.mean_share_count
+      && self.enabled_notifications_percentage == other.enabled_notifications_percentage
+      && self.member_count_graph == other.member_count_graph
+      && self.join_graph == other.join_graph
+      && self.mute_graph == other.mute_graph
+      && self.view_count_by_hour_graph == other.view_count_by_hour_graph
+      && self.view_count_by_source_graph == other.view_count_by_source_graph
+      && self.join_by_source_graph == other.join_by_source_graph
+      && self.language_graph == other.language_graph
+      && self.message_interaction_graph == other.message_interaction_graph
+      && self.instant_view_interaction_graph == other.instant_view_interaction_graph
+      && self.recent_message_interactions == other.recent_message_interactions
+  }
+}
+
 
 
 
@@ -319,7 +401,10 @@ pub struct ChatStatisticsSupergroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A period to which the statistics applies
   period: DateRange,
   /// Number of members in the chat
@@ -356,8 +441,9 @@ pub struct ChatStatisticsSupergroup {
 }
 
 impl RObject for ChatStatisticsSupergroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsSupergroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatStatisticsSupergroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -367,14 +453,52 @@ impl TDChatStatistics for ChatStatisticsSupergroup {}
 
 
 impl ChatStatisticsSupergroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatStatisticsSupergroupBuilder {
-    let mut inner = ChatStatisticsSupergroup::default();
-    inner.td_name = "chatStatisticsSupergroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatStatisticsSupergroup { td_name: "chatStatisticsSupergroup".to_string(), ..Default::default() };
     RTDChatStatisticsSupergroupBuilder { inner }
   }
 
+  /// `ChatStatisticsSupergroup` with the fields tdlib requires, the others are set by `ChatStatisticsSupergroup::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    period: impl AsRef<DateRange>,
+    member_count: impl AsRef<StatisticalValue>,
+    message_count: impl AsRef<StatisticalValue>,
+    viewer_count: impl AsRef<StatisticalValue>,
+    sender_count: impl AsRef<StatisticalValue>,
+    member_count_graph: impl AsRef<StatisticalGraph>,
+    join_graph: impl AsRef<StatisticalGraph>,
+    join_by_source_graph: impl AsRef<StatisticalGraph>,
+    language_graph: impl AsRef<StatisticalGraph>,
+    message_content_graph: impl AsRef<StatisticalGraph>,
+    action_graph: impl AsRef<StatisticalGraph>,
+    day_graph: impl AsRef<StatisticalGraph>,
+    week_graph: impl AsRef<StatisticalGraph>,
+    top_senders: Vec<ChatStatisticsMessageSenderInfo>,
+    top_administrators: Vec<ChatStatisticsAdministratorActionsInfo>,
+    top_inviters: Vec<ChatStatisticsInviterInfo>,
+  ) -> Self {
+    Self::builder()
+      .period(period)
+      .member_count(member_count)
+      .message_count(message_count)
+      .viewer_count(viewer_count)
+      .sender_count(sender_count)
+      .member_count_graph(member_count_graph)
+      .join_graph(join_graph)
+      .join_by_source_graph(join_by_source_graph)
+      .language_graph(language_graph)
+      .message_content_graph(message_content_graph)
+      .action_graph(action_graph)
+      .day_graph(day_graph)
+      .week_graph(week_graph)
+      .top_senders(top_senders)
+      .top_administrators(top_administrators)
+      .top_inviters(top_inviters)
+      .build()
+  }
+
   pub fn period(&self) -> &DateRange { &self.period }
 
   pub fn member_count(&self) -> &StatisticalValue { &self.member_count }
@@ -417,6 +541,12 @@ pub struct RTDChatStatisticsSupergroupBuilder {
 impl RTDChatStatisticsSupergroupBuilder {
   pub fn build(&self) -> ChatStatisticsSupergroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn period<T: AsRef<DateRange>>(&mut self, period: T) -> &mut Self {
     self.inner.period = period.as_ref().clone();
@@ -523,5 +653,26 @@ impl AsRef<ChatStatisticsSupergroup> for RTDChatStatisticsSupergroupBuilder {
   fn as_ref(&self) -> &ChatStatisticsSupergroup { &self.inner }
 }
 
+impl PartialEq for ChatStatisticsSupergroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.period == other.period
+      && self.member_count == other.member_count
+      && self.message_count == other.message_count
+      && self.viewer_count == other.viewer_count
+      && self.sender_count == other.sender_count
+      && self.member_count_graph == other.member_count_graph
+      && self.join_graph == other.join_graph
+      && self.join_by_source_graph == other.join_by_source_graph
+      && self.language_graph == other.language_graph
+      && self.message_content_graph == other.message_content_graph
+      && self.action_graph == other.action_graph
+      && self.day_graph == other.day_graph
+      && self.week_graph == other.week_graph
+      && self.top_senders == other.top_senders
+      && self.top_administrators == other.top_administrators
+      && self.top_inviters == other.top_inviters
+  }
+}
+
 
 
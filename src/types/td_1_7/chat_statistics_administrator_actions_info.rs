@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatStatisticsAdministratorActionsInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Administrator user identifier
-  user_id: i64,
+  user_id: UserId,
   /// Number of messages deleted by the administrator
   deleted_message_count: i64,
   /// Number of users banned by the administrator
@@ -27,23 +29,37 @@ pub struct ChatStatisticsAdministratorActionsInfo {
 }
 
 impl RObject for ChatStatisticsAdministratorActionsInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsAdministratorActionsInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatStatisticsAdministratorActionsInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatStatisticsAdministratorActionsInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatStatisticsAdministratorActionsInfoBuilder {
-    let mut inner = ChatStatisticsAdministratorActionsInfo::default();
-    inner.td_name = "chatStatisticsAdministratorActionsInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatStatisticsAdministratorActionsInfo { td_name: "chatStatisticsAdministratorActionsInfo".to_string(), ..Default::default() };
     RTDChatStatisticsAdministratorActionsInfoBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatStatisticsAdministratorActionsInfo` with the fields tdlib requires, the others are set by `ChatStatisticsAdministratorActionsInfo::builder()`
+  pub fn new(
+    user_id: UserId,
+    deleted_message_count: i64,
+    banned_user_count: i64,
+    restricted_user_count: i64,
+  ) -> Self {
+    Self::builder()
+      .user_id(user_id)
+      .deleted_message_count(deleted_message_count)
+      .banned_user_count(banned_user_count)
+      .restricted_user_count(restricted_user_count)
+      .build()
+  }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn deleted_message_count(&self) -> i64 { self.deleted_message_count }
 
@@ -61,8 +77,14 @@ pub struct RTDChatStatisticsAdministratorActionsInfoBuilder {
 impl RTDChatStatisticsAdministratorActionsInfoBuilder {
   pub fn build(&self) -> ChatStatisticsAdministratorActionsInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -95,5 +117,14 @@ impl AsRef<ChatStatisticsAdministratorActionsInfo> for RTDChatStatisticsAdminist
   fn as_ref(&self) -> &ChatStatisticsAdministratorActionsInfo { &self.inner }
 }
 
+impl PartialEq for ChatStatisticsAdministratorActionsInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.deleted_message_count == other.deleted_message_count
+      && self.banned_user_count == other.banned_user_count
+      && self.restricted_user_count == other.restricted_user_count
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,32 +13,37 @@ pub struct ChatStatisticsInviterInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   /// Number of new members invited by the user
   added_member_count: i64,
   
 }
 
 impl RObject for ChatStatisticsInviterInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsInviterInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatStatisticsInviterInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatStatisticsInviterInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatStatisticsInviterInfoBuilder {
-    let mut inner = ChatStatisticsInviterInfo::default();
-    inner.td_name = "chatStatisticsInviterInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatStatisticsInviterInfo { td_name: "chatStatisticsInviterInfo".to_string(), ..Default::default() };
     RTDChatStatisticsInviterInfoBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatStatisticsInviterInfo` with the fields tdlib requires, the others are set by `ChatStatisticsInviterInfo::builder()`
+  pub fn new(user_id: UserId, added_member_count: i64) -> Self { Self::builder().user_id(user_id).added_member_count(added_member_count).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn added_member_count(&self) -> i64 { self.added_member_count }
 
@@ -53,8 +57,14 @@ pub struct RTDChatStatisticsInviterInfoBuilder {
 impl RTDChatStatisticsInviterInfoBuilder {
   pub fn build(&self) -> ChatStatisticsInviterInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -75,5 +85,12 @@ impl AsRef<ChatStatisticsInviterInfo> for RTDChatStatisticsInviterInfoBuilder {
   fn as_ref(&self) -> &ChatStatisticsInviterInfo { &self.inner }
 }
 
+impl PartialEq for ChatStatisticsInviterInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.added_member_count == other.added_member_count
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatStatisticsMessageInteractionInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// Number of times the message was viewed
   view_count: i64,
   /// Number of times the message was forwarded
@@ -25,23 +27,25 @@ pub struct ChatStatisticsMessageInteractionInfo {
 }
 
 impl RObject for ChatStatisticsMessageInteractionInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsMessageInteractionInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatStatisticsMessageInteractionInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatStatisticsMessageInteractionInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatStatisticsMessageInteractionInfoBuilder {
-    let mut inner = ChatStatisticsMessageInteractionInfo::default();
-    inner.td_name = "chatStatisticsMessageInteractionInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatStatisticsMessageInteractionInfo { td_name: "chatStatisticsMessageInteractionInfo".to_string(), ..Default::default() };
     RTDChatStatisticsMessageInteractionInfoBuilder { inner }
   }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  /// `ChatStatisticsMessageInteractionInfo` with the fields tdlib requires, the others are set by `ChatStatisticsMessageInteractionInfo::builder()`
+  pub fn new(message_id: MessageId, view_count: i64, forward_count: i64) -> Self { Self::builder().message_id(message_id).view_count(view_count).forward_count(forward_count).build() }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn view_count(&self) -> i64 { self.view_count }
 
@@ -57,8 +61,14 @@ pub struct RTDChatStatisticsMessageInteractionInfoBuilder {
 impl RTDChatStatisticsMessageInteractionInfoBuilder {
   pub fn build(&self) -> ChatStatisticsMessageInteractionInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<ChatStatisticsMessageInteractionInfo> for RTDChatStatisticsMessageInt
   fn as_ref(&self) -> &ChatStatisticsMessageInteractionInfo { &self.inner }
 }
 
+impl PartialEq for ChatStatisticsMessageInteractionInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.message_id == other.message_id
+      && self.view_count == other.view_count
+      && self.forward_count == other.forward_count
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ChatStatisticsMessageSenderInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   /// Number of sent messages
   sent_message_count: i64,
   /// Average number of characters in sent messages; 0 if unknown
@@ -25,23 +27,25 @@ pub struct ChatStatisticsMessageSenderInfo {
 }
 
 impl RObject for ChatStatisticsMessageSenderInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatStatisticsMessageSenderInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatStatisticsMessageSenderInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatStatisticsMessageSenderInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatStatisticsMessageSenderInfoBuilder {
-    let mut inner = ChatStatisticsMessageSenderInfo::default();
-    inner.td_name = "chatStatisticsMessageSenderInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatStatisticsMessageSenderInfo { td_name: "chatStatisticsMessageSenderInfo".to_string(), ..Default::default() };
     RTDChatStatisticsMessageSenderInfoBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatStatisticsMessageSenderInfo` with the fields tdlib requires, the others are set by `ChatStatisticsMessageSenderInfo::builder()`
+  pub fn new(user_id: UserId, sent_message_count: i64, average_character_count: i64) -> Self { Self::builder().user_id(user_id).sent_message_count(sent_message_count).average_character_count(average_character_count).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn sent_message_count(&self) -> i64 { self.sent_message_count }
 
@@ -57,8 +61,14 @@ pub struct RTDChatStatisticsMessageSenderInfoBuilder {
 impl RTDChatStatisticsMessageSenderInfoBuilder {
   pub fn build(&self) -> ChatStatisticsMessageSenderInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<ChatStatisticsMessageSenderInfo> for RTDChatStatisticsMessageSenderIn
   fn as_ref(&self) -> &ChatStatisticsMessageSenderInfo { &self.inner }
 }
 
+impl PartialEq for ChatStatisticsMessageSenderInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.sent_message_count == other.sent_message_count
+      && self.average_character_count == other.average_character_count
+  }
+}
+
 
 
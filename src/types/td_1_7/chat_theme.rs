@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatTheme {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Theme name
   name: String,
   /// Theme settings for a light chat theme
@@ -25,22 +27,24 @@ pub struct ChatTheme {
 }
 
 impl RObject for ChatTheme {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTheme" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatTheme" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatTheme {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatThemeBuilder {
-    let mut inner = ChatTheme::default();
-    inner.td_name = "chatTheme".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatTheme { td_name: "chatTheme".to_string(), ..Default::default() };
     RTDChatThemeBuilder { inner }
   }
 
+  /// `ChatTheme` with the fields tdlib requires, the others are set by `ChatTheme::builder()`
+  pub fn new(name: impl AsRef<str>, light_settings: impl AsRef<ThemeSettings>, dark_settings: impl AsRef<ThemeSettings>) -> Self { Self::builder().name(name).light_settings(light_settings).dark_settings(dark_settings).build() }
+
   pub fn name(&self) -> &String { &self.name }
 
   pub fn light_settings(&self) -> &ThemeSettings { &self.light_settings }
@@ -57,6 +61,12 @@ pub struct RTDChatThemeBuilder {
 impl RTDChatThemeBuilder {
   pub fn build(&self) -> ChatTheme { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -85,5 +95,13 @@ impl AsRef<ChatTheme> for RTDChatThemeBuilder {
   fn as_ref(&self) -> &ChatTheme { &self.inner }
 }
 
+impl PartialEq for ChatTheme {
+  fn eq(&self, other: &Self) -> bool {
+    self.name == other.name
+      && self.light_settings == other.light_settings
+      && self.dark_settings == other.dark_settings
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDChatType: Debug + RObject {}
 
 /// Describes the type of a chat
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ChatType {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum ChatType {
   Secret(ChatTypeSecret),
   /// A supergroup or channel (with unlimited members)
   Supergroup(ChatTypeSupergroup),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for ChatType {
   fn deserialize<D>(deserializer: D) -> Result<ChatType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ChatType,
+      ChatType, Unknown;
       (chatTypeBasicGroup, BasicGroup);
       (chatTypePrivate, Private);
       (chatTypeSecret, Secret);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for ChatType {
 }
 
 impl RObject for ChatType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ChatType::BasicGroup(t) => t.td_name(),
       ChatType::Private(t) => t.td_name(),
       ChatType::Secret(t) => t.td_name(),
       ChatType::Supergroup(t) => t.td_name(),
+      ChatType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ChatType::BasicGroup(t) => t.extra(),
       ChatType::Private(t) => t.extra(),
       ChatType::Secret(t) => t.extra(),
       ChatType::Supergroup(t) => t.extra(),
+      ChatType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ChatType::BasicGroup(t) => t.client_id(),
+      ChatType::Private(t) => t.client_id(),
+      ChatType::Secret(t) => t.client_id(),
+      ChatType::Supergroup(t) => t.client_id(),
+      ChatType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for ChatType {
 }
 
 impl ChatType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ChatType::_Default(_)) }
 
-  pub fn is_basic_group(&self) -> bool { if let ChatType::BasicGroup(_) = self { true } else { false } }
-  pub fn is_private(&self) -> bool { if let ChatType::Private(_) = self { true } else { false } }
-  pub fn is_secret(&self) -> bool { if let ChatType::Secret(_) = self { true } else { false } }
-  pub fn is_supergroup(&self) -> bool { if let ChatType::Supergroup(_) = self { true } else { false } }
+  pub fn is_basic_group(&self) -> bool { matches!(self, ChatType::BasicGroup(_)) }
+  pub fn is_private(&self) -> bool { matches!(self, ChatType::Private(_)) }
+  pub fn is_secret(&self) -> bool { matches!(self, ChatType::Secret(_)) }
+  pub fn is_supergroup(&self) -> bool { matches!(self, ChatType::Supergroup(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ChatType::Unknown(_)) }
 
   pub fn on_basic_group<F: FnOnce(&ChatTypeBasicGroup)>(&self, fnc: F) -> &Self { if let ChatType::BasicGroup(t) = self { fnc(t) }; self }
   pub fn on_private<F: FnOnce(&ChatTypePrivate)>(&self, fnc: F) -> &Self { if let ChatType::Private(t) = self { fnc(t) }; self }
   pub fn on_secret<F: FnOnce(&ChatTypeSecret)>(&self, fnc: F) -> &Self { if let ChatType::Secret(t) = self { fnc(t) }; self }
   pub fn on_supergroup<F: FnOnce(&ChatTypeSupergroup)>(&self, fnc: F) -> &Self { if let ChatType::Supergroup(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ChatType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_basic_group(&self) -> Option<&ChatTypeBasicGroup> { if let ChatType::BasicGroup(t) = self { return Some(t) } None }
   pub fn as_private(&self) -> Option<&ChatTypePrivate> { if let ChatType::Private(t) = self { return Some(t) } None }
   pub fn as_secret(&self) -> Option<&ChatTypeSecret> { if let ChatType::Secret(t) = self { return Some(t) } None }
   pub fn as_supergroup(&self) -> Option<&ChatTypeSupergroup> { if let ChatType::Supergroup(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ChatType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,15 +138,19 @@ pub struct ChatTypeBasicGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Basic group identifier
-  basic_group_id: i64,
+  basic_group_id: BasicGroupId,
   
 }
 
 impl RObject for ChatTypeBasicGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypeBasicGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatTypeBasicGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,15 +160,16 @@ impl TDChatType for ChatTypeBasicGroup {}
 
 
 impl ChatTypeBasicGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatTypeBasicGroupBuilder {
-    let mut inner = ChatTypeBasicGroup::default();
-    inner.td_name = "chatTypeBasicGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatTypeBasicGroup { td_name: "chatTypeBasicGroup".to_string(), ..Default::default() };
     RTDChatTypeBasicGroupBuilder { inner }
   }
 
-  pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
+  /// `ChatTypeBasicGroup` with the fields tdlib requires, the others are set by `ChatTypeBasicGroup::builder()`
+  pub fn new(basic_group_id: BasicGroupId) -> Self { Self::builder().basic_group_id(basic_group_id).build() }
+
+  pub fn basic_group_id(&self) -> BasicGroupId { self.basic_group_id }
 
 }
 
@@ -159,8 +181,14 @@ pub struct RTDChatTypeBasicGroupBuilder {
 impl RTDChatTypeBasicGroupBuilder {
   pub fn build(&self) -> ChatTypeBasicGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
+  pub fn basic_group_id(&mut self, basic_group_id: BasicGroupId) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
     self
   }
@@ -175,6 +203,12 @@ impl AsRef<ChatTypeBasicGroup> for RTDChatTypeBasicGroupBuilder {
   fn as_ref(&self) -> &ChatTypeBasicGroup { &self.inner }
 }
 
+impl PartialEq for ChatTypeBasicGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.basic_group_id == other.basic_group_id
+  }
+}
+
 
 
 
@@ -189,15 +223,19 @@ pub struct ChatTypePrivate {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for ChatTypePrivate {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypePrivate" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatTypePrivate" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,15 +245,16 @@ impl TDChatType for ChatTypePrivate {}
 
 
 impl ChatTypePrivate {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatTypePrivateBuilder {
-    let mut inner = ChatTypePrivate::default();
-    inner.td_name = "chatTypePrivate".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatTypePrivate { td_name: "chatTypePrivate".to_string(), ..Default::default() };
     RTDChatTypePrivateBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `ChatTypePrivate` with the fields tdlib requires, the others are set by `ChatTypePrivate::builder()`
+  pub fn new(user_id: UserId) -> Self { Self::builder().user_id(user_id).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -227,8 +266,14 @@ pub struct RTDChatTypePrivateBuilder {
 impl RTDChatTypePrivateBuilder {
   pub fn build(&self) -> ChatTypePrivate { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -243,6 +288,12 @@ impl AsRef<ChatTypePrivate> for RTDChatTypePrivateBuilder {
   fn as_ref(&self) -> &ChatTypePrivate { &self.inner }
 }
 
+impl PartialEq for ChatTypePrivate {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+  }
+}
+
 
 
 
@@ -257,17 +308,21 @@ pub struct ChatTypeSecret {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Secret chat identifier
-  secret_chat_id: i64,
+  secret_chat_id: SecretChatId,
   /// User identifier of the secret chat peer
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for ChatTypeSecret {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypeSecret" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatTypeSecret" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -277,17 +332,18 @@ impl TDChatType for ChatTypeSecret {}
 
 
 impl ChatTypeSecret {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatTypeSecretBuilder {
-    let mut inner = ChatTypeSecret::default();
-    inner.td_name = "chatTypeSecret".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatTypeSecret { td_name: "chatTypeSecret".to_string(), ..Default::default() };
     RTDChatTypeSecretBuilder { inner }
   }
 
-  pub fn secret_chat_id(&self) -> i64 { self.secret_chat_id }
+  /// `ChatTypeSecret` with the fields tdlib requires, the others are set by `ChatTypeSecret::builder()`
+  pub fn new(secret_chat_id: SecretChatId, user_id: UserId) -> Self { Self::builder().secret_chat_id(secret_chat_id).user_id(user_id).build() }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn secret_chat_id(&self) -> SecretChatId { self.secret_chat_id }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -299,14 +355,20 @@ pub struct RTDChatTypeSecretBuilder {
 impl RTDChatTypeSecretBuilder {
   pub fn build(&self) -> ChatTypeSecret { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
+  pub fn secret_chat_id(&mut self, secret_chat_id: SecretChatId) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
     self
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -321,6 +383,13 @@ impl AsRef<ChatTypeSecret> for RTDChatTypeSecretBuilder {
   fn as_ref(&self) -> &ChatTypeSecret { &self.inner }
 }
 
+impl PartialEq for ChatTypeSecret {
+  fn eq(&self, other: &Self) -> bool {
+    self.secret_chat_id == other.secret_chat_id
+      && self.user_id == other.user_id
+  }
+}
+
 
 
 
@@ -335,17 +404,21 @@ pub struct ChatTypeSupergroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Supergroup or channel identifier
-  supergroup_id: i64,
+  supergroup_id: SupergroupId,
   /// True, if the supergroup is a channel
   is_channel: bool,
   
 }
 
 impl RObject for ChatTypeSupergroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatTypeSupergroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatTypeSupergroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -355,15 +428,16 @@ impl TDChatType for ChatTypeSupergroup {}
 
 
 impl ChatTypeSupergroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatTypeSupergroupBuilder {
-    let mut inner = ChatTypeSupergroup::default();
-    inner.td_name = "chatTypeSupergroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatTypeSupergroup { td_name: "chatTypeSupergroup".to_string(), ..Default::default() };
     RTDChatTypeSupergroupBuilder { inner }
   }
 
-  pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
+  /// `ChatTypeSupergroup` with the fields tdlib requires, the others are set by `ChatTypeSupergroup::builder()`
+  pub fn new(supergroup_id: SupergroupId, is_channel: bool) -> Self { Self::builder().supergroup_id(supergroup_id).is_channel(is_channel).build() }
+
+  pub fn supergroup_id(&self) -> SupergroupId { self.supergroup_id }
 
   pub fn is_channel(&self) -> bool { self.is_channel }
 
@@ -377,8 +451,14 @@ pub struct RTDChatTypeSupergroupBuilder {
 impl RTDChatTypeSupergroupBuilder {
   pub fn build(&self) -> ChatTypeSupergroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
+  pub fn supergroup_id(&mut self, supergroup_id: SupergroupId) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
     self
   }
@@ -399,5 +479,12 @@ impl AsRef<ChatTypeSupergroup> for RTDChatTypeSupergroupBuilder {
   fn as_ref(&self) -> &ChatTypeSupergroup { &self.inner }
 }
 
+impl PartialEq for ChatTypeSupergroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.supergroup_id == other.supergroup_id
+      && self.is_channel == other.is_channel
+  }
+}
+
 
 
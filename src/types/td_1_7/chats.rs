@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,34 +13,39 @@ pub struct Chats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Approximate total count of chats found
   total_count: i64,
   /// List of chat identifiers
-  chat_ids: Vec<i64>,
+  chat_ids: Vec<ChatId>,
   
 }
 
 impl RObject for Chats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Chats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatsBuilder {
-    let mut inner = Chats::default();
-    inner.td_name = "chats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Chats { td_name: "chats".to_string(), ..Default::default() };
     RTDChatsBuilder { inner }
   }
 
+  /// `Chats` with the fields tdlib requires, the others are set by `Chats::builder()`
+  pub fn new(total_count: i64, chat_ids: Vec<ChatId>) -> Self { Self::builder().total_count(total_count).chat_ids(chat_ids).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
-  pub fn chat_ids(&self) -> &Vec<i64> { &self.chat_ids }
+  pub fn chat_ids(&self) -> &Vec<ChatId> { &self.chat_ids }
 
 }
 
@@ -53,6 +57,12 @@ pub struct RTDChatsBuilder {
 impl RTDChatsBuilder {
   pub fn build(&self) -> Chats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -60,7 +70,7 @@ impl RTDChatsBuilder {
   }
 
    
-  pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
+  pub fn chat_ids(&mut self, chat_ids: Vec<ChatId>) -> &mut Self {
     self.inner.chat_ids = chat_ids;
     self
   }
@@ -75,5 +85,12 @@ impl AsRef<Chats> for RTDChatsBuilder {
   fn as_ref(&self) -> &Chats { &self.inner }
 }
 
+impl PartialEq for Chats {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.chat_ids == other.chat_ids
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ChatsNearby {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of users nearby
   users_nearby: Vec<ChatNearby>,
   /// List of location-based supergroups nearby
@@ -23,22 +25,24 @@ pub struct ChatsNearby {
 }
 
 impl RObject for ChatsNearby {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "chatsNearby" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "chatsNearby" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ChatsNearby {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDChatsNearbyBuilder {
-    let mut inner = ChatsNearby::default();
-    inner.td_name = "chatsNearby".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ChatsNearby { td_name: "chatsNearby".to_string(), ..Default::default() };
     RTDChatsNearbyBuilder { inner }
   }
 
+  /// `ChatsNearby` with the fields tdlib requires, the others are set by `ChatsNearby::builder()`
+  pub fn new(users_nearby: Vec<ChatNearby>, supergroups_nearby: Vec<ChatNearby>) -> Self { Self::builder().users_nearby(users_nearby).supergroups_nearby(supergroups_nearby).build() }
+
   pub fn users_nearby(&self) -> &Vec<ChatNearby> { &self.users_nearby }
 
   pub fn supergroups_nearby(&self) -> &Vec<ChatNearby> { &self.supergroups_nearby }
@@ -53,6 +57,12 @@ pub struct RTDChatsNearbyBuilder {
 impl RTDChatsNearbyBuilder {
   pub fn build(&self) -> ChatsNearby { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
     self.inner.users_nearby = users_nearby;
@@ -75,5 +85,12 @@ impl AsRef<ChatsNearby> for RTDChatsNearbyBuilder {
   fn as_ref(&self) -> &ChatsNearby { &self.inner }
 }
 
+impl PartialEq for ChatsNearby {
+  fn eq(&self, other: &Self) -> bool {
+    self.users_nearby == other.users_nearby
+      && self.supergroups_nearby == other.supergroups_nearby
+  }
+}
+
 
 
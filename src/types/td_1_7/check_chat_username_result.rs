@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCheckChatUsernameResult: Debug + RObject {}
 
 /// Represents result of checking whether a username can be set for a chat
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CheckChatUsernameResult {
   #[doc(hidden)] _Default(()),
@@ -31,6 +30,8 @@ pub enum CheckChatUsernameResult {
   UsernameInvalid(CheckChatUsernameResultUsernameInvalid),
   /// The username is occupied
   UsernameOccupied(CheckChatUsernameResultUsernameOccupied),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -42,7 +43,7 @@ impl<'de> Deserialize<'de> for CheckChatUsernameResult {
   fn deserialize<D>(deserializer: D) -> Result<CheckChatUsernameResult, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CheckChatUsernameResult,
+      CheckChatUsernameResult, Unknown;
       (checkChatUsername, CheckChatUsername);
       (checkChatUsernameResultOk, Ok);
       (checkChatUsernameResultPublicChatsTooMuch, PublicChatsTooMuch);
@@ -55,7 +56,7 @@ impl<'de> Deserialize<'de> for CheckChatUsernameResult {
 }
 
 impl RObject for CheckChatUsernameResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CheckChatUsernameResult::CheckChatUsername(t) => t.td_name(),
       CheckChatUsernameResult::Ok(t) => t.td_name(),
@@ -63,11 +64,12 @@ impl RObject for CheckChatUsernameResult {
       CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.td_name(),
       CheckChatUsernameResult::UsernameInvalid(t) => t.td_name(),
       CheckChatUsernameResult::UsernameOccupied(t) => t.td_name(),
+      CheckChatUsernameResult::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CheckChatUsernameResult::CheckChatUsername(t) => t.extra(),
       CheckChatUsernameResult::Ok(t) => t.extra(),
@@ -75,6 +77,20 @@ impl RObject for CheckChatUsernameResult {
       CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.extra(),
       CheckChatUsernameResult::UsernameInvalid(t) => t.extra(),
       CheckChatUsernameResult::UsernameOccupied(t) => t.extra(),
+      CheckChatUsernameResult::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CheckChatUsernameResult::CheckChatUsername(t) => t.client_id(),
+      CheckChatUsernameResult::Ok(t) => t.client_id(),
+      CheckChatUsernameResult::PublicChatsTooMuch(t) => t.client_id(),
+      CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.client_id(),
+      CheckChatUsernameResult::UsernameInvalid(t) => t.client_id(),
+      CheckChatUsernameResult::UsernameOccupied(t) => t.client_id(),
+      CheckChatUsernameResult::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -83,15 +99,16 @@ impl RObject for CheckChatUsernameResult {
 }
 
 impl CheckChatUsernameResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CheckChatUsernameResult::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CheckChatUsernameResult::_Default(_)) }
 
-  pub fn is_check_chat_username(&self) -> bool { if let CheckChatUsernameResult::CheckChatUsername(_) = self { true } else { false } }
-  pub fn is_ok(&self) -> bool { if let CheckChatUsernameResult::Ok(_) = self { true } else { false } }
-  pub fn is_public_chats_too_much(&self) -> bool { if let CheckChatUsernameResult::PublicChatsTooMuch(_) = self { true } else { false } }
-  pub fn is_public_groups_unavailable(&self) -> bool { if let CheckChatUsernameResult::PublicGroupsUnavailable(_) = self { true } else { false } }
-  pub fn is_username_invalid(&self) -> bool { if let CheckChatUsernameResult::UsernameInvalid(_) = self { true } else { false } }
-  pub fn is_username_occupied(&self) -> bool { if let CheckChatUsernameResult::UsernameOccupied(_) = self { true } else { false } }
+  pub fn is_check_chat_username(&self) -> bool { matches!(self, CheckChatUsernameResult::CheckChatUsername(_)) }
+  pub fn is_ok(&self) -> bool { matches!(self, CheckChatUsernameResult::Ok(_)) }
+  pub fn is_public_chats_too_much(&self) -> bool { matches!(self, CheckChatUsernameResult::PublicChatsTooMuch(_)) }
+  pub fn is_public_groups_unavailable(&self) -> bool { matches!(self, CheckChatUsernameResult::PublicGroupsUnavailable(_)) }
+  pub fn is_username_invalid(&self) -> bool { matches!(self, CheckChatUsernameResult::UsernameInvalid(_)) }
+  pub fn is_username_occupied(&self) -> bool { matches!(self, CheckChatUsernameResult::UsernameOccupied(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CheckChatUsernameResult::Unknown(_)) }
 
   pub fn on_check_chat_username<F: FnOnce(&CheckChatUsername)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::CheckChatUsername(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&CheckChatUsernameResultOk)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::Ok(t) = self { fnc(t) }; self }
@@ -99,6 +116,7 @@ impl CheckChatUsernameResult {
   pub fn on_public_groups_unavailable<F: FnOnce(&CheckChatUsernameResultPublicGroupsUnavailable)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::PublicGroupsUnavailable(t) = self { fnc(t) }; self }
   pub fn on_username_invalid<F: FnOnce(&CheckChatUsernameResultUsernameInvalid)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::UsernameInvalid(t) = self { fnc(t) }; self }
   pub fn on_username_occupied<F: FnOnce(&CheckChatUsernameResultUsernameOccupied)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::UsernameOccupied(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_check_chat_username(&self) -> Option<&CheckChatUsername> { if let CheckChatUsernameResult::CheckChatUsername(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&CheckChatUsernameResultOk> { if let CheckChatUsernameResult::Ok(t) = self { return Some(t) } None }
@@ -106,6 +124,7 @@ impl CheckChatUsernameResult {
   pub fn as_public_groups_unavailable(&self) -> Option<&CheckChatUsernameResultPublicGroupsUnavailable> { if let CheckChatUsernameResult::PublicGroupsUnavailable(t) = self { return Some(t) } None }
   pub fn as_username_invalid(&self) -> Option<&CheckChatUsernameResultUsernameInvalid> { if let CheckChatUsernameResult::UsernameInvalid(t) = self { return Some(t) } None }
   pub fn as_username_occupied(&self) -> Option<&CheckChatUsernameResultUsernameOccupied> { if let CheckChatUsernameResult::UsernameOccupied(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CheckChatUsernameResult::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -141,13 +160,17 @@ pub struct CheckChatUsernameResultOk {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckChatUsernameResultOk {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultOk" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkChatUsernameResultOk" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,14 +180,15 @@ impl TDCheckChatUsernameResult for CheckChatUsernameResultOk {}
 
 
 impl CheckChatUsernameResultOk {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckChatUsernameResultOkBuilder {
-    let mut inner = CheckChatUsernameResultOk::default();
-    inner.td_name = "checkChatUsernameResultOk".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckChatUsernameResultOk { td_name: "checkChatUsernameResultOk".to_string(), ..Default::default() };
     RTDCheckChatUsernameResultOkBuilder { inner }
   }
 
+  /// `CheckChatUsernameResultOk` with the fields tdlib requires, the others are set by `CheckChatUsernameResultOk::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +199,12 @@ pub struct RTDCheckChatUsernameResultOkBuilder {
 impl RTDCheckChatUsernameResultOkBuilder {
   pub fn build(&self) -> CheckChatUsernameResultOk { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultOk> for CheckChatUsernameResultOk {
@@ -185,6 +215,10 @@ impl AsRef<CheckChatUsernameResultOk> for RTDCheckChatUsernameResultOkBuilder {
   fn as_ref(&self) -> &CheckChatUsernameResultOk { &self.inner }
 }
 
+impl PartialEq for CheckChatUsernameResultOk {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -199,13 +233,17 @@ pub struct CheckChatUsernameResultPublicChatsTooMuch {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckChatUsernameResultPublicChatsTooMuch {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultPublicChatsTooMuch" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkChatUsernameResultPublicChatsTooMuch" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -215,14 +253,15 @@ impl TDCheckChatUsernameResult for CheckChatUsernameResultPublicChatsTooMuch {}
 
 
 impl CheckChatUsernameResultPublicChatsTooMuch {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
-    let mut inner = CheckChatUsernameResultPublicChatsTooMuch::default();
-    inner.td_name = "checkChatUsernameResultPublicChatsTooMuch".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckChatUsernameResultPublicChatsTooMuch { td_name: "checkChatUsernameResultPublicChatsTooMuch".to_string(), ..Default::default() };
     RTDCheckChatUsernameResultPublicChatsTooMuchBuilder { inner }
   }
 
+  /// `CheckChatUsernameResultPublicChatsTooMuch` with the fields tdlib requires, the others are set by `CheckChatUsernameResultPublicChatsTooMuch::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +272,12 @@ pub struct RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
 impl RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
   pub fn build(&self) -> CheckChatUsernameResultPublicChatsTooMuch { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultPublicChatsTooMuch> for CheckChatUsernameResultPublicChatsTooMuch {
@@ -243,6 +288,10 @@ impl AsRef<CheckChatUsernameResultPublicChatsTooMuch> for RTDCheckChatUsernameRe
   fn as_ref(&self) -> &CheckChatUsernameResultPublicChatsTooMuch { &self.inner }
 }
 
+impl PartialEq for CheckChatUsernameResultPublicChatsTooMuch {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -257,13 +306,17 @@ pub struct CheckChatUsernameResultPublicGroupsUnavailable {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckChatUsernameResultPublicGroupsUnavailable {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultPublicGroupsUnavailable" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkChatUsernameResultPublicGroupsUnavailable" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,14 +326,15 @@ impl TDCheckChatUsernameResult for CheckChatUsernameResultPublicGroupsUnavailabl
 
 
 impl CheckChatUsernameResultPublicGroupsUnavailable {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
-    let mut inner = CheckChatUsernameResultPublicGroupsUnavailable::default();
-    inner.td_name = "checkChatUsernameResultPublicGroupsUnavailable".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckChatUsernameResultPublicGroupsUnavailable { td_name: "checkChatUsernameResultPublicGroupsUnavailable".to_string(), ..Default::default() };
     RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder { inner }
   }
 
+  /// `CheckChatUsernameResultPublicGroupsUnavailable` with the fields tdlib requires, the others are set by `CheckChatUsernameResultPublicGroupsUnavailable::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +345,12 @@ pub struct RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
 impl RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
   pub fn build(&self) -> CheckChatUsernameResultPublicGroupsUnavailable { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultPublicGroupsUnavailable> for CheckChatUsernameResultPublicGroupsUnavailable {
@@ -301,6 +361,10 @@ impl AsRef<CheckChatUsernameResultPublicGroupsUnavailable> for RTDCheckChatUsern
   fn as_ref(&self) -> &CheckChatUsernameResultPublicGroupsUnavailable { &self.inner }
 }
 
+impl PartialEq for CheckChatUsernameResultPublicGroupsUnavailable {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -315,13 +379,17 @@ pub struct CheckChatUsernameResultUsernameInvalid {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckChatUsernameResultUsernameInvalid {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultUsernameInvalid" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkChatUsernameResultUsernameInvalid" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -331,14 +399,15 @@ impl TDCheckChatUsernameResult for CheckChatUsernameResultUsernameInvalid {}
 
 
 impl CheckChatUsernameResultUsernameInvalid {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckChatUsernameResultUsernameInvalidBuilder {
-    let mut inner = CheckChatUsernameResultUsernameInvalid::default();
-    inner.td_name = "checkChatUsernameResultUsernameInvalid".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckChatUsernameResultUsernameInvalid { td_name: "checkChatUsernameResultUsernameInvalid".to_string(), ..Default::default() };
     RTDCheckChatUsernameResultUsernameInvalidBuilder { inner }
   }
 
+  /// `CheckChatUsernameResultUsernameInvalid` with the fields tdlib requires, the others are set by `CheckChatUsernameResultUsernameInvalid::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -349,6 +418,12 @@ pub struct RTDCheckChatUsernameResultUsernameInvalidBuilder {
 impl RTDCheckChatUsernameResultUsernameInvalidBuilder {
   pub fn build(&self) -> CheckChatUsernameResultUsernameInvalid { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultUsernameInvalid> for CheckChatUsernameResultUsernameInvalid {
@@ -359,6 +434,10 @@ impl AsRef<CheckChatUsernameResultUsernameInvalid> for RTDCheckChatUsernameResul
   fn as_ref(&self) -> &CheckChatUsernameResultUsernameInvalid { &self.inner }
 }
 
+impl PartialEq for CheckChatUsernameResultUsernameInvalid {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -373,13 +452,17 @@ pub struct CheckChatUsernameResultUsernameOccupied {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckChatUsernameResultUsernameOccupied {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkChatUsernameResultUsernameOccupied" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkChatUsernameResultUsernameOccupied" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -389,14 +472,15 @@ impl TDCheckChatUsernameResult for CheckChatUsernameResultUsernameOccupied {}
 
 
 impl CheckChatUsernameResultUsernameOccupied {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckChatUsernameResultUsernameOccupiedBuilder {
-    let mut inner = CheckChatUsernameResultUsernameOccupied::default();
-    inner.td_name = "checkChatUsernameResultUsernameOccupied".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckChatUsernameResultUsernameOccupied { td_name: "checkChatUsernameResultUsernameOccupied".to_string(), ..Default::default() };
     RTDCheckChatUsernameResultUsernameOccupiedBuilder { inner }
   }
 
+  /// `CheckChatUsernameResultUsernameOccupied` with the fields tdlib requires, the others are set by `CheckChatUsernameResultUsernameOccupied::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -407,6 +491,12 @@ pub struct RTDCheckChatUsernameResultUsernameOccupiedBuilder {
 impl RTDCheckChatUsernameResultUsernameOccupiedBuilder {
   pub fn build(&self) -> CheckChatUsernameResultUsernameOccupied { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckChatUsernameResultUsernameOccupied> for CheckChatUsernameResultUsernameOccupied {
@@ -417,5 +507,9 @@ impl AsRef<CheckChatUsernameResultUsernameOccupied> for RTDCheckChatUsernameResu
   fn as_ref(&self) -> &CheckChatUsernameResultUsernameOccupied { &self.inner }
 }
 
+impl PartialEq for CheckChatUsernameResultUsernameOccupied {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
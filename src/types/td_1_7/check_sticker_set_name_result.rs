@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDCheckStickerSetNameResult: Debug + RObject {}
 
 /// Represents result of checking whether a name can be used for a new sticker set
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CheckStickerSetNameResult {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum CheckStickerSetNameResult {
   NameOccupied(CheckStickerSetNameResultNameOccupied),
   /// The name can be set
   Ok(CheckStickerSetNameResultOk),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for CheckStickerSetNameResult {
   fn deserialize<D>(deserializer: D) -> Result<CheckStickerSetNameResult, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      CheckStickerSetNameResult,
+      CheckStickerSetNameResult, Unknown;
       (checkStickerSetName, CheckStickerSetName);
       (checkStickerSetNameResultNameInvalid, NameInvalid);
       (checkStickerSetNameResultNameOccupied, NameOccupied);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for CheckStickerSetNameResult {
 }
 
 impl RObject for CheckStickerSetNameResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       CheckStickerSetNameResult::CheckStickerSetName(t) => t.td_name(),
       CheckStickerSetNameResult::NameInvalid(t) => t.td_name(),
       CheckStickerSetNameResult::NameOccupied(t) => t.td_name(),
       CheckStickerSetNameResult::Ok(t) => t.td_name(),
+      CheckStickerSetNameResult::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       CheckStickerSetNameResult::CheckStickerSetName(t) => t.extra(),
       CheckStickerSetNameResult::NameInvalid(t) => t.extra(),
       CheckStickerSetNameResult::NameOccupied(t) => t.extra(),
       CheckStickerSetNameResult::Ok(t) => t.extra(),
+      CheckStickerSetNameResult::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      CheckStickerSetNameResult::CheckStickerSetName(t) => t.client_id(),
+      CheckStickerSetNameResult::NameInvalid(t) => t.client_id(),
+      CheckStickerSetNameResult::NameOccupied(t) => t.client_id(),
+      CheckStickerSetNameResult::Ok(t) => t.client_id(),
+      CheckStickerSetNameResult::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for CheckStickerSetNameResult {
 }
 
 impl CheckStickerSetNameResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CheckStickerSetNameResult::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, CheckStickerSetNameResult::_Default(_)) }
 
-  pub fn is_check_sticker_set_name(&self) -> bool { if let CheckStickerSetNameResult::CheckStickerSetName(_) = self { true } else { false } }
-  pub fn is_name_invalid(&self) -> bool { if let CheckStickerSetNameResult::NameInvalid(_) = self { true } else { false } }
-  pub fn is_name_occupied(&self) -> bool { if let CheckStickerSetNameResult::NameOccupied(_) = self { true } else { false } }
-  pub fn is_ok(&self) -> bool { if let CheckStickerSetNameResult::Ok(_) = self { true } else { false } }
+  pub fn is_check_sticker_set_name(&self) -> bool { matches!(self, CheckStickerSetNameResult::CheckStickerSetName(_)) }
+  pub fn is_name_invalid(&self) -> bool { matches!(self, CheckStickerSetNameResult::NameInvalid(_)) }
+  pub fn is_name_occupied(&self) -> bool { matches!(self, CheckStickerSetNameResult::NameOccupied(_)) }
+  pub fn is_ok(&self) -> bool { matches!(self, CheckStickerSetNameResult::Ok(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, CheckStickerSetNameResult::Unknown(_)) }
 
   pub fn on_check_sticker_set_name<F: FnOnce(&CheckStickerSetName)>(&self, fnc: F) -> &Self { if let CheckStickerSetNameResult::CheckStickerSetName(t) = self { fnc(t) }; self }
   pub fn on_name_invalid<F: FnOnce(&CheckStickerSetNameResultNameInvalid)>(&self, fnc: F) -> &Self { if let CheckStickerSetNameResult::NameInvalid(t) = self { fnc(t) }; self }
   pub fn on_name_occupied<F: FnOnce(&CheckStickerSetNameResultNameOccupied)>(&self, fnc: F) -> &Self { if let CheckStickerSetNameResult::NameOccupied(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&CheckStickerSetNameResultOk)>(&self, fnc: F) -> &Self { if let CheckStickerSetNameResult::Ok(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let CheckStickerSetNameResult::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_check_sticker_set_name(&self) -> Option<&CheckStickerSetName> { if let CheckStickerSetNameResult::CheckStickerSetName(t) = self { return Some(t) } None }
   pub fn as_name_invalid(&self) -> Option<&CheckStickerSetNameResultNameInvalid> { if let CheckStickerSetNameResult::NameInvalid(t) = self { return Some(t) } None }
   pub fn as_name_occupied(&self) -> Option<&CheckStickerSetNameResultNameOccupied> { if let CheckStickerSetNameResult::NameOccupied(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&CheckStickerSetNameResultOk> { if let CheckStickerSetNameResult::Ok(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let CheckStickerSetNameResult::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,13 +138,17 @@ pub struct CheckStickerSetNameResultNameInvalid {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckStickerSetNameResultNameInvalid {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkStickerSetNameResultNameInvalid" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkStickerSetNameResultNameInvalid" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,14 +158,15 @@ impl TDCheckStickerSetNameResult for CheckStickerSetNameResultNameInvalid {}
 
 
 impl CheckStickerSetNameResultNameInvalid {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckStickerSetNameResultNameInvalidBuilder {
-    let mut inner = CheckStickerSetNameResultNameInvalid::default();
-    inner.td_name = "checkStickerSetNameResultNameInvalid".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckStickerSetNameResultNameInvalid { td_name: "checkStickerSetNameResultNameInvalid".to_string(), ..Default::default() };
     RTDCheckStickerSetNameResultNameInvalidBuilder { inner }
   }
 
+  /// `CheckStickerSetNameResultNameInvalid` with the fields tdlib requires, the others are set by `CheckStickerSetNameResultNameInvalid::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +177,12 @@ pub struct RTDCheckStickerSetNameResultNameInvalidBuilder {
 impl RTDCheckStickerSetNameResultNameInvalidBuilder {
   pub fn build(&self) -> CheckStickerSetNameResultNameInvalid { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckStickerSetNameResultNameInvalid> for CheckStickerSetNameResultNameInvalid {
@@ -165,6 +193,10 @@ impl AsRef<CheckStickerSetNameResultNameInvalid> for RTDCheckStickerSetNameResul
   fn as_ref(&self) -> &CheckStickerSetNameResultNameInvalid { &self.inner }
 }
 
+impl PartialEq for CheckStickerSetNameResultNameInvalid {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -179,13 +211,17 @@ pub struct CheckStickerSetNameResultNameOccupied {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckStickerSetNameResultNameOccupied {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkStickerSetNameResultNameOccupied" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkStickerSetNameResultNameOccupied" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -195,14 +231,15 @@ impl TDCheckStickerSetNameResult for CheckStickerSetNameResultNameOccupied {}
 
 
 impl CheckStickerSetNameResultNameOccupied {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckStickerSetNameResultNameOccupiedBuilder {
-    let mut inner = CheckStickerSetNameResultNameOccupied::default();
-    inner.td_name = "checkStickerSetNameResultNameOccupied".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckStickerSetNameResultNameOccupied { td_name: "checkStickerSetNameResultNameOccupied".to_string(), ..Default::default() };
     RTDCheckStickerSetNameResultNameOccupiedBuilder { inner }
   }
 
+  /// `CheckStickerSetNameResultNameOccupied` with the fields tdlib requires, the others are set by `CheckStickerSetNameResultNameOccupied::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +250,12 @@ pub struct RTDCheckStickerSetNameResultNameOccupiedBuilder {
 impl RTDCheckStickerSetNameResultNameOccupiedBuilder {
   pub fn build(&self) -> CheckStickerSetNameResultNameOccupied { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckStickerSetNameResultNameOccupied> for CheckStickerSetNameResultNameOccupied {
@@ -223,6 +266,10 @@ impl AsRef<CheckStickerSetNameResultNameOccupied> for RTDCheckStickerSetNameResu
   fn as_ref(&self) -> &CheckStickerSetNameResultNameOccupied { &self.inner }
 }
 
+impl PartialEq for CheckStickerSetNameResultNameOccupied {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -237,13 +284,17 @@ pub struct CheckStickerSetNameResultOk {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for CheckStickerSetNameResultOk {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "checkStickerSetNameResultOk" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "checkStickerSetNameResultOk" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -253,14 +304,15 @@ impl TDCheckStickerSetNameResult for CheckStickerSetNameResultOk {}
 
 
 impl CheckStickerSetNameResultOk {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCheckStickerSetNameResultOkBuilder {
-    let mut inner = CheckStickerSetNameResultOk::default();
-    inner.td_name = "checkStickerSetNameResultOk".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CheckStickerSetNameResultOk { td_name: "checkStickerSetNameResultOk".to_string(), ..Default::default() };
     RTDCheckStickerSetNameResultOkBuilder { inner }
   }
 
+  /// `CheckStickerSetNameResultOk` with the fields tdlib requires, the others are set by `CheckStickerSetNameResultOk::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -271,6 +323,12 @@ pub struct RTDCheckStickerSetNameResultOkBuilder {
 impl RTDCheckStickerSetNameResultOkBuilder {
   pub fn build(&self) -> CheckStickerSetNameResultOk { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<CheckStickerSetNameResultOk> for CheckStickerSetNameResultOk {
@@ -281,5 +339,9 @@ impl AsRef<CheckStickerSetNameResultOk> for RTDCheckStickerSetNameResultOkBuilde
   fn as_ref(&self) -> &CheckStickerSetNameResultOk { &self.inner }
 }
 
+impl PartialEq for CheckStickerSetNameResultOk {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct ClosedVectorPath {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of vector path commands
   commands: Vec<VectorPathCommand>,
   
 }
 
 impl RObject for ClosedVectorPath {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "closedVectorPath" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "closedVectorPath" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ClosedVectorPath {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDClosedVectorPathBuilder {
-    let mut inner = ClosedVectorPath::default();
-    inner.td_name = "closedVectorPath".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ClosedVectorPath { td_name: "closedVectorPath".to_string(), ..Default::default() };
     RTDClosedVectorPathBuilder { inner }
   }
 
+  /// `ClosedVectorPath` with the fields tdlib requires, the others are set by `ClosedVectorPath::builder()`
+  pub fn new(commands: Vec<VectorPathCommand>) -> Self { Self::builder().commands(commands).build() }
+
   pub fn commands(&self) -> &Vec<VectorPathCommand> { &self.commands }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDClosedVectorPathBuilder {
 impl RTDClosedVectorPathBuilder {
   pub fn build(&self) -> ClosedVectorPath { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn commands(&mut self, commands: Vec<VectorPathCommand>) -> &mut Self {
     self.inner.commands = commands;
@@ -65,5 +75,11 @@ impl AsRef<ClosedVectorPath> for RTDClosedVectorPathBuilder {
   fn as_ref(&self) -> &ClosedVectorPath { &self.inner }
 }
 
+impl PartialEq for ClosedVectorPath {
+  fn eq(&self, other: &Self) -> bool {
+    self.commands == other.commands
+  }
+}
+
 
 
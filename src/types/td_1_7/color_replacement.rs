@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ColorReplacement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Original animated emoji color in the RGB24 format
   old_color: i64,
   /// Replacement animated emoji color in the RGB24 format
@@ -23,22 +25,24 @@ pub struct ColorReplacement {
 }
 
 impl RObject for ColorReplacement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "colorReplacement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "colorReplacement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ColorReplacement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDColorReplacementBuilder {
-    let mut inner = ColorReplacement::default();
-    inner.td_name = "colorReplacement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ColorReplacement { td_name: "colorReplacement".to_string(), ..Default::default() };
     RTDColorReplacementBuilder { inner }
   }
 
+  /// `ColorReplacement` with the fields tdlib requires, the others are set by `ColorReplacement::builder()`
+  pub fn new(old_color: i64, new_color: i64) -> Self { Self::builder().old_color(old_color).new_color(new_color).build() }
+
   pub fn old_color(&self) -> i64 { self.old_color }
 
   pub fn new_color(&self) -> i64 { self.new_color }
@@ -53,6 +57,12 @@ pub struct RTDColorReplacementBuilder {
 impl RTDColorReplacementBuilder {
   pub fn build(&self) -> ColorReplacement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn old_color(&mut self, old_color: i64) -> &mut Self {
     self.inner.old_color = old_color;
@@ -75,5 +85,12 @@ impl AsRef<ColorReplacement> for RTDColorReplacementBuilder {
   fn as_ref(&self) -> &ColorReplacement { &self.inner }
 }
 
+impl PartialEq for ColorReplacement {
+  fn eq(&self, other: &Self) -> bool {
+    self.old_color == other.old_color
+      && self.new_color == other.new_color
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,13 +13,16 @@ pub struct ConnectedWebsite {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Website identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// The domain name of the website
   domain_name: String,
   /// User identifier of a bot linked with the website
-  bot_user_id: i64,
+  bot_user_id: UserId,
   /// The version of a browser used to log in
   browser: String,
   /// Operating system the browser is running on
@@ -37,27 +39,52 @@ pub struct ConnectedWebsite {
 }
 
 impl RObject for ConnectedWebsite {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "connectedWebsite" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "connectedWebsite" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ConnectedWebsite {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDConnectedWebsiteBuilder {
-    let mut inner = ConnectedWebsite::default();
-    inner.td_name = "connectedWebsite".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ConnectedWebsite { td_name: "connectedWebsite".to_string(), ..Default::default() };
     RTDConnectedWebsiteBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `ConnectedWebsite` with the fields tdlib requires, the others are set by `ConnectedWebsite::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: i64,
+    domain_name: impl AsRef<str>,
+    bot_user_id: UserId,
+    browser: impl AsRef<str>,
+    platform: impl AsRef<str>,
+    log_in_date: i64,
+    last_active_date: i64,
+    ip: impl AsRef<str>,
+    location: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .domain_name(domain_name)
+      .bot_user_id(bot_user_id)
+      .browser(browser)
+      .platform(platform)
+      .log_in_date(log_in_date)
+      .last_active_date(last_active_date)
+      .ip(ip)
+      .location(location)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn domain_name(&self) -> &String { &self.domain_name }
 
-  pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
+  pub fn bot_user_id(&self) -> UserId { self.bot_user_id }
 
   pub fn browser(&self) -> &String { &self.browser }
 
@@ -81,8 +108,14 @@ pub struct RTDConnectedWebsiteBuilder {
 impl RTDConnectedWebsiteBuilder {
   pub fn build(&self) -> ConnectedWebsite { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -94,7 +127,7 @@ impl RTDConnectedWebsiteBuilder {
   }
 
    
-  pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
+  pub fn bot_user_id(&mut self, bot_user_id: UserId) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
     self
   }
@@ -145,5 +178,19 @@ impl AsRef<ConnectedWebsite> for RTDConnectedWebsiteBuilder {
   fn as_ref(&self) -> &ConnectedWebsite { &self.inner }
 }
 
+impl PartialEq for ConnectedWebsite {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.domain_name == other.domain_name
+      && self.bot_user_id == other.bot_user_id
+      && self.browser == other.browser
+      && self.platform == other.platform
+      && self.log_in_date == other.log_in_date
+      && self.last_active_date == other.last_active_date
+      && self.ip == other.ip
+      && self.location == other.location
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct ConnectedWebsites {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of connected websites
   websites: Vec<ConnectedWebsite>,
   
 }
 
 impl RObject for ConnectedWebsites {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "connectedWebsites" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "connectedWebsites" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ConnectedWebsites {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDConnectedWebsitesBuilder {
-    let mut inner = ConnectedWebsites::default();
-    inner.td_name = "connectedWebsites".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ConnectedWebsites { td_name: "connectedWebsites".to_string(), ..Default::default() };
     RTDConnectedWebsitesBuilder { inner }
   }
 
+  /// `ConnectedWebsites` with the fields tdlib requires, the others are set by `ConnectedWebsites::builder()`
+  pub fn new(websites: Vec<ConnectedWebsite>) -> Self { Self::builder().websites(websites).build() }
+
   pub fn websites(&self) -> &Vec<ConnectedWebsite> { &self.websites }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDConnectedWebsitesBuilder {
 impl RTDConnectedWebsitesBuilder {
   pub fn build(&self) -> ConnectedWebsites { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn websites(&mut self, websites: Vec<ConnectedWebsite>) -> &mut Self {
     self.inner.websites = websites;
@@ -65,5 +75,11 @@ impl AsRef<ConnectedWebsites> for RTDConnectedWebsitesBuilder {
   fn as_ref(&self) -> &ConnectedWebsites { &self.inner }
 }
 
+impl PartialEq for ConnectedWebsites {
+  fn eq(&self, other: &Self) -> bool {
+    self.websites == other.websites
+  }
+}
+
 
 
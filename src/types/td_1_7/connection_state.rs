@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDConnectionState: Debug + RObject {}
 
 /// Describes the current state of the connection to Telegram servers
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ConnectionState {
   #[doc(hidden)] _Default(()),
@@ -29,6 +28,8 @@ pub enum ConnectionState {
   Updating(ConnectionStateUpdating),
   /// Currently waiting for the network to become available. Use setNetworkType to change the available network type
   WaitingForNetwork(ConnectionStateWaitingForNetwork),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -40,7 +41,7 @@ impl<'de> Deserialize<'de> for ConnectionState {
   fn deserialize<D>(deserializer: D) -> Result<ConnectionState, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ConnectionState,
+      ConnectionState, Unknown;
       (connectionStateConnecting, Connecting);
       (connectionStateConnectingToProxy, ConnectingToProxy);
       (connectionStateReady, Ready);
@@ -52,24 +53,38 @@ impl<'de> Deserialize<'de> for ConnectionState {
 }
 
 impl RObject for ConnectionState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ConnectionState::Connecting(t) => t.td_name(),
       ConnectionState::ConnectingToProxy(t) => t.td_name(),
       ConnectionState::Ready(t) => t.td_name(),
       ConnectionState::Updating(t) => t.td_name(),
       ConnectionState::WaitingForNetwork(t) => t.td_name(),
+      ConnectionState::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ConnectionState::Connecting(t) => t.extra(),
       ConnectionState::ConnectingToProxy(t) => t.extra(),
       ConnectionState::Ready(t) => t.extra(),
       ConnectionState::Updating(t) => t.extra(),
       ConnectionState::WaitingForNetwork(t) => t.extra(),
+      ConnectionState::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ConnectionState::Connecting(t) => t.client_id(),
+      ConnectionState::ConnectingToProxy(t) => t.client_id(),
+      ConnectionState::Ready(t) => t.client_id(),
+      ConnectionState::Updating(t) => t.client_id(),
+      ConnectionState::WaitingForNetwork(t) => t.client_id(),
+      ConnectionState::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -78,26 +93,29 @@ impl RObject for ConnectionState {
 }
 
 impl ConnectionState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ConnectionState::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ConnectionState::_Default(_)) }
 
-  pub fn is_connecting(&self) -> bool { if let ConnectionState::Connecting(_) = self { true } else { false } }
-  pub fn is_connecting_to_proxy(&self) -> bool { if let ConnectionState::ConnectingToProxy(_) = self { true } else { false } }
-  pub fn is_ready(&self) -> bool { if let ConnectionState::Ready(_) = self { true } else { false } }
-  pub fn is_updating(&self) -> bool { if let ConnectionState::Updating(_) = self { true } else { false } }
-  pub fn is_waiting_for_network(&self) -> bool { if let ConnectionState::WaitingForNetwork(_) = self { true } else { false } }
+  pub fn is_connecting(&self) -> bool { matches!(self, ConnectionState::Connecting(_)) }
+  pub fn is_connecting_to_proxy(&self) -> bool { matches!(self, ConnectionState::ConnectingToProxy(_)) }
+  pub fn is_ready(&self) -> bool { matches!(self, ConnectionState::Ready(_)) }
+  pub fn is_updating(&self) -> bool { matches!(self, ConnectionState::Updating(_)) }
+  pub fn is_waiting_for_network(&self) -> bool { matches!(self, ConnectionState::WaitingForNetwork(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ConnectionState::Unknown(_)) }
 
   pub fn on_connecting<F: FnOnce(&ConnectionStateConnecting)>(&self, fnc: F) -> &Self { if let ConnectionState::Connecting(t) = self { fnc(t) }; self }
   pub fn on_connecting_to_proxy<F: FnOnce(&ConnectionStateConnectingToProxy)>(&self, fnc: F) -> &Self { if let ConnectionState::ConnectingToProxy(t) = self { fnc(t) }; self }
   pub fn on_ready<F: FnOnce(&ConnectionStateReady)>(&self, fnc: F) -> &Self { if let ConnectionState::Ready(t) = self { fnc(t) }; self }
   pub fn on_updating<F: FnOnce(&ConnectionStateUpdating)>(&self, fnc: F) -> &Self { if let ConnectionState::Updating(t) = self { fnc(t) }; self }
   pub fn on_waiting_for_network<F: FnOnce(&ConnectionStateWaitingForNetwork)>(&self, fnc: F) -> &Self { if let ConnectionState::WaitingForNetwork(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ConnectionState::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_connecting(&self) -> Option<&ConnectionStateConnecting> { if let ConnectionState::Connecting(t) = self { return Some(t) } None }
   pub fn as_connecting_to_proxy(&self) -> Option<&ConnectionStateConnectingToProxy> { if let ConnectionState::ConnectingToProxy(t) = self { return Some(t) } None }
   pub fn as_ready(&self) -> Option<&ConnectionStateReady> { if let ConnectionState::Ready(t) = self { return Some(t) } None }
   pub fn as_updating(&self) -> Option<&ConnectionStateUpdating> { if let ConnectionState::Updating(t) = self { return Some(t) } None }
   pub fn as_waiting_for_network(&self) -> Option<&ConnectionStateWaitingForNetwork> { if let ConnectionState::WaitingForNetwork(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ConnectionState::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -131,13 +149,17 @@ pub struct ConnectionStateConnecting {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ConnectionStateConnecting {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateConnecting" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "connectionStateConnecting" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -147,14 +169,15 @@ impl TDConnectionState for ConnectionStateConnecting {}
 
 
 impl ConnectionStateConnecting {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDConnectionStateConnectingBuilder {
-    let mut inner = ConnectionStateConnecting::default();
-    inner.td_name = "connectionStateConnecting".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ConnectionStateConnecting { td_name: "connectionStateConnecting".to_string(), ..Default::default() };
     RTDConnectionStateConnectingBuilder { inner }
   }
 
+  /// `ConnectionStateConnecting` with the fields tdlib requires, the others are set by `ConnectionStateConnecting::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +188,12 @@ pub struct RTDConnectionStateConnectingBuilder {
 impl RTDConnectionStateConnectingBuilder {
   pub fn build(&self) -> ConnectionStateConnecting { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateConnecting> for ConnectionStateConnecting {
@@ -175,6 +204,10 @@ impl AsRef<ConnectionStateConnecting> for RTDConnectionStateConnectingBuilder {
   fn as_ref(&self) -> &ConnectionStateConnecting { &self.inner }
 }
 
+impl PartialEq for ConnectionStateConnecting {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -189,13 +222,17 @@ pub struct ConnectionStateConnectingToProxy {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ConnectionStateConnectingToProxy {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateConnectingToProxy" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "connectionStateConnectingToProxy" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -205,14 +242,15 @@ impl TDConnectionState for ConnectionStateConnectingToProxy {}
 
 
 impl ConnectionStateConnectingToProxy {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDConnectionStateConnectingToProxyBuilder {
-    let mut inner = ConnectionStateConnectingToProxy::default();
-    inner.td_name = "connectionStateConnectingToProxy".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ConnectionStateConnectingToProxy { td_name: "connectionStateConnectingToProxy".to_string(), ..Default::default() };
     RTDConnectionStateConnectingToProxyBuilder { inner }
   }
 
+  /// `ConnectionStateConnectingToProxy` with the fields tdlib requires, the others are set by `ConnectionStateConnectingToProxy::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +261,12 @@ pub struct RTDConnectionStateConnectingToProxyBuilder {
 impl RTDConnectionStateConnectingToProxyBuilder {
   pub fn build(&self) -> ConnectionStateConnectingToProxy { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateConnectingToProxy> for ConnectionStateConnectingToProxy {
@@ -233,6 +277,10 @@ impl AsRef<ConnectionStateConnectingToProxy> for RTDConnectionStateConnectingToP
   fn as_ref(&self) -> &ConnectionStateConnectingToProxy { &self.inner }
 }
 
+impl PartialEq for ConnectionStateConnectingToProxy {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -247,13 +295,17 @@ pub struct ConnectionStateReady {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ConnectionStateReady {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateReady" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "connectionStateReady" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -263,14 +315,15 @@ impl TDConnectionState for ConnectionStateReady {}
 
 
 impl ConnectionStateReady {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDConnectionStateReadyBuilder {
-    let mut inner = ConnectionStateReady::default();
-    inner.td_name = "connectionStateReady".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ConnectionStateReady { td_name: "connectionStateReady".to_string(), ..Default::default() };
     RTDConnectionStateReadyBuilder { inner }
   }
 
+  /// `ConnectionStateReady` with the fields tdlib requires, the others are set by `ConnectionStateReady::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -281,6 +334,12 @@ pub struct RTDConnectionStateReadyBuilder {
 impl RTDConnectionStateReadyBuilder {
   pub fn build(&self) -> ConnectionStateReady { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateReady> for ConnectionStateReady {
@@ -291,6 +350,10 @@ impl AsRef<ConnectionStateReady> for RTDConnectionStateReadyBuilder {
   fn as_ref(&self) -> &ConnectionStateReady { &self.inner }
 }
 
+impl PartialEq for ConnectionStateReady {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -305,13 +368,17 @@ pub struct ConnectionStateUpdating {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ConnectionStateUpdating {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateUpdating" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "connectionStateUpdating" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -321,14 +388,15 @@ impl TDConnectionState for ConnectionStateUpdating {}
 
 
 impl ConnectionStateUpdating {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDConnectionStateUpdatingBuilder {
-    let mut inner = ConnectionStateUpdating::default();
-    inner.td_name = "connectionStateUpdating".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ConnectionStateUpdating { td_name: "connectionStateUpdating".to_string(), ..Default::default() };
     RTDConnectionStateUpdatingBuilder { inner }
   }
 
+  /// `ConnectionStateUpdating` with the fields tdlib requires, the others are set by `ConnectionStateUpdating::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -339,6 +407,12 @@ pub struct RTDConnectionStateUpdatingBuilder {
 impl RTDConnectionStateUpdatingBuilder {
   pub fn build(&self) -> ConnectionStateUpdating { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateUpdating> for ConnectionStateUpdating {
@@ -349,6 +423,10 @@ impl AsRef<ConnectionStateUpdating> for RTDConnectionStateUpdatingBuilder {
   fn as_ref(&self) -> &ConnectionStateUpdating { &self.inner }
 }
 
+impl PartialEq for ConnectionStateUpdating {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -363,13 +441,17 @@ pub struct ConnectionStateWaitingForNetwork {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ConnectionStateWaitingForNetwork {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "connectionStateWaitingForNetwork" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "connectionStateWaitingForNetwork" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -379,14 +461,15 @@ impl TDConnectionState for ConnectionStateWaitingForNetwork {}
 
 
 impl ConnectionStateWaitingForNetwork {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDConnectionStateWaitingForNetworkBuilder {
-    let mut inner = ConnectionStateWaitingForNetwork::default();
-    inner.td_name = "connectionStateWaitingForNetwork".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ConnectionStateWaitingForNetwork { td_name: "connectionStateWaitingForNetwork".to_string(), ..Default::default() };
     RTDConnectionStateWaitingForNetworkBuilder { inner }
   }
 
+  /// `ConnectionStateWaitingForNetwork` with the fields tdlib requires, the others are set by `ConnectionStateWaitingForNetwork::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -397,6 +480,12 @@ pub struct RTDConnectionStateWaitingForNetworkBuilder {
 impl RTDConnectionStateWaitingForNetworkBuilder {
   pub fn build(&self) -> ConnectionStateWaitingForNetwork { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ConnectionStateWaitingForNetwork> for ConnectionStateWaitingForNetwork {
@@ -407,5 +496,9 @@ impl AsRef<ConnectionStateWaitingForNetwork> for RTDConnectionStateWaitingForNet
   fn as_ref(&self) -> &ConnectionStateWaitingForNetwork { &self.inner }
 }
 
+impl PartialEq for ConnectionStateWaitingForNetwork {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Contact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Phone number of the user
   phone_number: String,
   /// First name of the user; 1-255 characters in length
@@ -24,27 +26,43 @@ pub struct Contact {
   /// Additional data about the user in a form of vCard; 0-2048 bytes in length
   vcard: String,
   /// Identifier of the user, if known; otherwise 0
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for Contact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "contact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "contact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Contact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDContactBuilder {
-    let mut inner = Contact::default();
-    inner.td_name = "contact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Contact { td_name: "contact".to_string(), ..Default::default() };
     RTDContactBuilder { inner }
   }
 
+  /// `Contact` with the fields tdlib requires, the others are set by `Contact::builder()`
+  pub fn new(
+    phone_number: impl AsRef<str>,
+    first_name: impl AsRef<str>,
+    last_name: impl AsRef<str>,
+    vcard: impl AsRef<str>,
+    user_id: UserId,
+  ) -> Self {
+    Self::builder()
+      .phone_number(phone_number)
+      .first_name(first_name)
+      .last_name(last_name)
+      .vcard(vcard)
+      .user_id(user_id)
+      .build()
+  }
+
   pub fn phone_number(&self) -> &String { &self.phone_number }
 
   pub fn first_name(&self) -> &String { &self.first_name }
@@ -53,7 +71,7 @@ impl Contact {
 
   pub fn vcard(&self) -> &String { &self.vcard }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -65,6 +83,12 @@ pub struct RTDContactBuilder {
 impl RTDContactBuilder {
   pub fn build(&self) -> Contact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -90,7 +114,7 @@ impl RTDContactBuilder {
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -105,5 +129,21 @@ impl AsRef<Contact> for RTDContactBuilder {
   fn as_ref(&self) -> &Contact { &self.inner }
 }
 
+impl PartialEq for Contact {
+  fn eq(&self, other: &Self) -> bool {
+    self.phone_number == other.phone_number
+      && self.first_name == other.first_name
+      && self.last_name == other.last_name
+      && self.vcard == other.vcard
+      && self.user_id == other.user_id
+  }
+}
+
+impl Validate for Contact {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("first_name", &self.first_name, 1, 255);
+  }
+}
+
 
 
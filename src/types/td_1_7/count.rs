@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Count {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Count
   count: i64,
   
 }
 
 impl RObject for Count {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "count" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "count" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Count {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCountBuilder {
-    let mut inner = Count::default();
-    inner.td_name = "count".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Count { td_name: "count".to_string(), ..Default::default() };
     RTDCountBuilder { inner }
   }
 
+  /// `Count` with the fields tdlib requires, the others are set by `Count::builder()`
+  pub fn new(count: i64) -> Self { Self::builder().count(count).build() }
+
   pub fn count(&self) -> i64 { self.count }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDCountBuilder {
 impl RTDCountBuilder {
   pub fn build(&self) -> Count { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn count(&mut self, count: i64) -> &mut Self {
     self.inner.count = count;
@@ -65,5 +75,11 @@ impl AsRef<Count> for RTDCountBuilder {
   fn as_ref(&self) -> &Count { &self.inner }
 }
 
+impl PartialEq for Count {
+  fn eq(&self, other: &Self) -> bool {
+    self.count == other.count
+  }
+}
+
 
 
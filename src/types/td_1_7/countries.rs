@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Countries {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The list of countries
   countries: Vec<CountryInfo>,
   
 }
 
 impl RObject for Countries {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "countries" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "countries" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Countries {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCountriesBuilder {
-    let mut inner = Countries::default();
-    inner.td_name = "countries".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Countries { td_name: "countries".to_string(), ..Default::default() };
     RTDCountriesBuilder { inner }
   }
 
+  /// `Countries` with the fields tdlib requires, the others are set by `Countries::builder()`
+  pub fn new(countries: Vec<CountryInfo>) -> Self { Self::builder().countries(countries).build() }
+
   pub fn countries(&self) -> &Vec<CountryInfo> { &self.countries }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDCountriesBuilder {
 impl RTDCountriesBuilder {
   pub fn build(&self) -> Countries { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn countries(&mut self, countries: Vec<CountryInfo>) -> &mut Self {
     self.inner.countries = countries;
@@ -65,5 +75,11 @@ impl AsRef<Countries> for RTDCountriesBuilder {
   fn as_ref(&self) -> &Countries { &self.inner }
 }
 
+impl PartialEq for Countries {
+  fn eq(&self, other: &Self) -> bool {
+    self.countries == other.countries
+  }
+}
+
 
 
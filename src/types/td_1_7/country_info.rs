@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct CountryInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A two-letter ISO 3166-1 alpha-2 country code
   country_code: String,
   /// Native name of the country
@@ -29,22 +31,38 @@ pub struct CountryInfo {
 }
 
 impl RObject for CountryInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "countryInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "countryInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl CountryInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCountryInfoBuilder {
-    let mut inner = CountryInfo::default();
-    inner.td_name = "countryInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CountryInfo { td_name: "countryInfo".to_string(), ..Default::default() };
     RTDCountryInfoBuilder { inner }
   }
 
+  /// `CountryInfo` with the fields tdlib requires, the others are set by `CountryInfo::builder()`
+  pub fn new(
+    country_code: impl AsRef<str>,
+    name: impl AsRef<str>,
+    english_name: impl AsRef<str>,
+    is_hidden: bool,
+    calling_codes: Vec<String>,
+  ) -> Self {
+    Self::builder()
+      .country_code(country_code)
+      .name(name)
+      .english_name(english_name)
+      .is_hidden(is_hidden)
+      .calling_codes(calling_codes)
+      .build()
+  }
+
   pub fn country_code(&self) -> &String { &self.country_code }
 
   pub fn name(&self) -> &String { &self.name }
@@ -65,6 +83,12 @@ pub struct RTDCountryInfoBuilder {
 impl RTDCountryInfoBuilder {
   pub fn build(&self) -> CountryInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
@@ -105,5 +129,15 @@ impl AsRef<CountryInfo> for RTDCountryInfoBuilder {
   fn as_ref(&self) -> &CountryInfo { &self.inner }
 }
 
+impl PartialEq for CountryInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.country_code == other.country_code
+      && self.name == other.name
+      && self.english_name == other.english_name
+      && self.is_hidden == other.is_hidden
+      && self.calling_codes == other.calling_codes
+  }
+}
+
 
 
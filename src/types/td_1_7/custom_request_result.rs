@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct CustomRequestResult {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A JSON-serialized result
   result: String,
   
 }
 
 impl RObject for CustomRequestResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "customRequestResult" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "customRequestResult" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl CustomRequestResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDCustomRequestResultBuilder {
-    let mut inner = CustomRequestResult::default();
-    inner.td_name = "customRequestResult".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = CustomRequestResult { td_name: "customRequestResult".to_string(), ..Default::default() };
     RTDCustomRequestResultBuilder { inner }
   }
 
+  /// `CustomRequestResult` with the fields tdlib requires, the others are set by `CustomRequestResult::builder()`
+  pub fn new(result: impl AsRef<str>) -> Self { Self::builder().result(result).build() }
+
   pub fn result(&self) -> &String { &self.result }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDCustomRequestResultBuilder {
 impl RTDCustomRequestResultBuilder {
   pub fn build(&self) -> CustomRequestResult { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn result<T: AsRef<str>>(&mut self, result: T) -> &mut Self {
     self.inner.result = result.as_ref().to_string();
@@ -65,5 +75,11 @@ impl AsRef<CustomRequestResult> for RTDCustomRequestResultBuilder {
   fn as_ref(&self) -> &CustomRequestResult { &self.inner }
 }
 
+impl PartialEq for CustomRequestResult {
+  fn eq(&self, other: &Self) -> bool {
+    self.result == other.result
+  }
+}
+
 
 
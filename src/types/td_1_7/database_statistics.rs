@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct DatabaseStatistics {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Database statistics in an unspecified human-readable format
   statistics: String,
   
 }
 
 impl RObject for DatabaseStatistics {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "databaseStatistics" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "databaseStatistics" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl DatabaseStatistics {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDatabaseStatisticsBuilder {
-    let mut inner = DatabaseStatistics::default();
-    inner.td_name = "databaseStatistics".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DatabaseStatistics { td_name: "databaseStatistics".to_string(), ..Default::default() };
     RTDDatabaseStatisticsBuilder { inner }
   }
 
+  /// `DatabaseStatistics` with the fields tdlib requires, the others are set by `DatabaseStatistics::builder()`
+  pub fn new(statistics: impl AsRef<str>) -> Self { Self::builder().statistics(statistics).build() }
+
   pub fn statistics(&self) -> &String { &self.statistics }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDDatabaseStatisticsBuilder {
 impl RTDDatabaseStatisticsBuilder {
   pub fn build(&self) -> DatabaseStatistics { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn statistics<T: AsRef<str>>(&mut self, statistics: T) -> &mut Self {
     self.inner.statistics = statistics.as_ref().to_string();
@@ -65,5 +75,11 @@ impl AsRef<DatabaseStatistics> for RTDDatabaseStatisticsBuilder {
   fn as_ref(&self) -> &DatabaseStatistics { &self.inner }
 }
 
+impl PartialEq for DatabaseStatistics {
+  fn eq(&self, other: &Self) -> bool {
+    self.statistics == other.statistics
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Date {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Day of the month; 1-31
   day: i64,
   /// Month; 1-12
@@ -25,22 +27,24 @@ pub struct Date {
 }
 
 impl RObject for Date {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "date" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "date" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Date {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDateBuilder {
-    let mut inner = Date::default();
-    inner.td_name = "date".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Date { td_name: "date".to_string(), ..Default::default() };
     RTDDateBuilder { inner }
   }
 
+  /// `Date` with the fields tdlib requires, the others are set by `Date::builder()`
+  pub fn new(day: i64, month: i64, year: i64) -> Self { Self::builder().day(day).month(month).year(year).build() }
+
   pub fn day(&self) -> i64 { self.day }
 
   pub fn month(&self) -> i64 { self.month }
@@ -57,6 +61,12 @@ pub struct RTDDateBuilder {
 impl RTDDateBuilder {
   pub fn build(&self) -> Date { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn day(&mut self, day: i64) -> &mut Self {
     self.inner.day = day;
@@ -85,5 +95,13 @@ impl AsRef<Date> for RTDDateBuilder {
   fn as_ref(&self) -> &Date { &self.inner }
 }
 
+impl PartialEq for Date {
+  fn eq(&self, other: &Self) -> bool {
+    self.day == other.day
+      && self.month == other.month
+      && self.year == other.year
+  }
+}
+
 
 
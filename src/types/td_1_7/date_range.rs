@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct DateRange {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Point in time (Unix timestamp) at which the date range begins
   start_date: i64,
   /// Point in time (Unix timestamp) at which the date range ends
@@ -23,22 +25,24 @@ pub struct DateRange {
 }
 
 impl RObject for DateRange {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "dateRange" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "dateRange" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl DateRange {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDateRangeBuilder {
-    let mut inner = DateRange::default();
-    inner.td_name = "dateRange".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DateRange { td_name: "dateRange".to_string(), ..Default::default() };
     RTDDateRangeBuilder { inner }
   }
 
+  /// `DateRange` with the fields tdlib requires, the others are set by `DateRange::builder()`
+  pub fn new(start_date: i64, end_date: i64) -> Self { Self::builder().start_date(start_date).end_date(end_date).build() }
+
   pub fn start_date(&self) -> i64 { self.start_date }
 
   pub fn end_date(&self) -> i64 { self.end_date }
@@ -53,6 +57,12 @@ pub struct RTDDateRangeBuilder {
 impl RTDDateRangeBuilder {
   pub fn build(&self) -> DateRange { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn start_date(&mut self, start_date: i64) -> &mut Self {
     self.inner.start_date = start_date;
@@ -75,5 +85,12 @@ impl AsRef<DateRange> for RTDDateRangeBuilder {
   fn as_ref(&self) -> &DateRange { &self.inner }
 }
 
+impl PartialEq for DateRange {
+  fn eq(&self, other: &Self) -> bool {
+    self.start_date == other.start_date
+      && self.end_date == other.end_date
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct DatedFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The file
   file: File,
   /// Point in time (Unix timestamp) when the file was uploaded
@@ -23,22 +25,24 @@ pub struct DatedFile {
 }
 
 impl RObject for DatedFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "datedFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "datedFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl DatedFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDatedFileBuilder {
-    let mut inner = DatedFile::default();
-    inner.td_name = "datedFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DatedFile { td_name: "datedFile".to_string(), ..Default::default() };
     RTDDatedFileBuilder { inner }
   }
 
+  /// `DatedFile` with the fields tdlib requires, the others are set by `DatedFile::builder()`
+  pub fn new(file: impl AsRef<File>, date: i64) -> Self { Self::builder().file(file).date(date).build() }
+
   pub fn file(&self) -> &File { &self.file }
 
   pub fn date(&self) -> i64 { self.date }
@@ -53,6 +57,12 @@ pub struct RTDDatedFileBuilder {
 impl RTDDatedFileBuilder {
   pub fn build(&self) -> DatedFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
@@ -75,5 +85,12 @@ impl AsRef<DatedFile> for RTDDatedFileBuilder {
   fn as_ref(&self) -> &DatedFile { &self.inner }
 }
 
+impl PartialEq for DatedFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.file == other.file
+      && self.date == other.date
+  }
+}
+
 
 
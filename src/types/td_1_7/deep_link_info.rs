@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct DeepLinkInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text to be shown to the user
   text: FormattedText,
   /// True, if the user must be asked to update the application
@@ -23,22 +25,24 @@ pub struct DeepLinkInfo {
 }
 
 impl RObject for DeepLinkInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deepLinkInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deepLinkInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl DeepLinkInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeepLinkInfoBuilder {
-    let mut inner = DeepLinkInfo::default();
-    inner.td_name = "deepLinkInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeepLinkInfo { td_name: "deepLinkInfo".to_string(), ..Default::default() };
     RTDDeepLinkInfoBuilder { inner }
   }
 
+  /// `DeepLinkInfo` with the fields tdlib requires, the others are set by `DeepLinkInfo::builder()`
+  pub fn new(text: impl AsRef<FormattedText>, need_update_application: bool) -> Self { Self::builder().text(text).need_update_application(need_update_application).build() }
+
   pub fn text(&self) -> &FormattedText { &self.text }
 
   pub fn need_update_application(&self) -> bool { self.need_update_application }
@@ -53,6 +57,12 @@ pub struct RTDDeepLinkInfoBuilder {
 impl RTDDeepLinkInfoBuilder {
   pub fn build(&self) -> DeepLinkInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -75,5 +85,12 @@ impl AsRef<DeepLinkInfo> for RTDDeepLinkInfoBuilder {
   fn as_ref(&self) -> &DeepLinkInfo { &self.inner }
 }
 
+impl PartialEq for DeepLinkInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.need_update_application == other.need_update_application
+  }
+}
+
 
 
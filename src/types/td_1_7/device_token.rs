@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDDeviceToken: Debug + RObject {}
 
 /// Represents a data needed to subscribe for push notifications through registerDevice method. To use specific push notification service, the correct application platform must be specified and a valid server authentication data must be uploaded at https://my.telegram.org
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum DeviceToken {
   #[doc(hidden)] _Default(()),
@@ -41,6 +40,8 @@ pub enum DeviceToken {
   WebPush(DeviceTokenWebPush),
   /// A token for Windows Push Notification Services
   WindowsPush(DeviceTokenWindowsPush),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -52,7 +53,7 @@ impl<'de> Deserialize<'de> for DeviceToken {
   fn deserialize<D>(deserializer: D) -> Result<DeviceToken, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      DeviceToken,
+      DeviceToken, Unknown;
       (deviceTokenApplePush, ApplePush);
       (deviceTokenApplePushVoIP, ApplePushVoIP);
       (deviceTokenBlackBerryPush, BlackBerryPush);
@@ -70,7 +71,7 @@ impl<'de> Deserialize<'de> for DeviceToken {
 }
 
 impl RObject for DeviceToken {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       DeviceToken::ApplePush(t) => t.td_name(),
       DeviceToken::ApplePushVoIP(t) => t.td_name(),
@@ -83,11 +84,12 @@ impl RObject for DeviceToken {
       DeviceToken::UbuntuPush(t) => t.td_name(),
       DeviceToken::WebPush(t) => t.td_name(),
       DeviceToken::WindowsPush(t) => t.td_name(),
+      DeviceToken::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       DeviceToken::ApplePush(t) => t.extra(),
       DeviceToken::ApplePushVoIP(t) => t.extra(),
@@ -100,6 +102,25 @@ impl RObject for DeviceToken {
       DeviceToken::UbuntuPush(t) => t.extra(),
       DeviceToken::WebPush(t) => t.extra(),
       DeviceToken::WindowsPush(t) => t.extra(),
+      DeviceToken::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      DeviceToken::ApplePush(t) => t.client_id(),
+      DeviceToken::ApplePushVoIP(t) => t.client_id(),
+      DeviceToken::BlackBerryPush(t) => t.client_id(),
+      DeviceToken::FirebaseCloudMessaging(t) => t.client_id(),
+      DeviceToken::MicrosoftPush(t) => t.client_id(),
+      DeviceToken::MicrosoftPushVoIP(t) => t.client_id(),
+      DeviceToken::SimplePush(t) => t.client_id(),
+      DeviceToken::TizenPush(t) => t.client_id(),
+      DeviceToken::UbuntuPush(t) => t.client_id(),
+      DeviceToken::WebPush(t) => t.client_id(),
+      DeviceToken::WindowsPush(t) => t.client_id(),
+      DeviceToken::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -108,20 +129,21 @@ impl RObject for DeviceToken {
 }
 
 impl DeviceToken {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let DeviceToken::_Default(_) = self { true } else { false } }
-
-  pub fn is_apple_push(&self) -> bool { if let DeviceToken::ApplePush(_) = self { true } else { false } }
-  pub fn is_apple_push_vo_i_p(&self) -> bool { if let DeviceToken::ApplePushVoIP(_) = self { true } else { false } }
-  pub fn is_black_berry_push(&self) -> bool { if let DeviceToken::BlackBerryPush(_) = self { true } else { false } }
-  pub fn is_firebase_cloud_messaging(&self) -> bool { if let DeviceToken::FirebaseCloudMessaging(_) = self { true } else { false } }
-  pub fn is_microsoft_push(&self) -> bool { if let DeviceToken::MicrosoftPush(_) = self { true } else { false } }
-  pub fn is_microsoft_push_vo_i_p(&self) -> bool { if let DeviceToken::MicrosoftPushVoIP(_) = self { true } else { false } }
-  pub fn is_simple_push(&self) -> bool { if let DeviceToken::SimplePush(_) = self { true } else { false } }
-  pub fn is_tizen_push(&self) -> bool { if let DeviceToken::TizenPush(_) = self { true } else { false } }
-  pub fn is_ubuntu_push(&self) -> bool { if let DeviceToken::UbuntuPush(_) = self { true } else { false } }
-  pub fn is_web_push(&self) -> bool { if let DeviceToken::WebPush(_) = self { true } else { false } }
-  pub fn is_windows_push(&self) -> bool { if let DeviceToken::WindowsPush(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, DeviceToken::_Default(_)) }
+
+  pub fn is_apple_push(&self) -> bool { matches!(self, DeviceToken::ApplePush(_)) }
+  pub fn is_apple_push_vo_i_p(&self) -> bool { matches!(self, DeviceToken::ApplePushVoIP(_)) }
+  pub fn is_black_berry_push(&self) -> bool { matches!(self, DeviceToken::BlackBerryPush(_)) }
+  pub fn is_firebase_cloud_messaging(&self) -> bool { matches!(self, DeviceToken::FirebaseCloudMessaging(_)) }
+  pub fn is_microsoft_push(&self) -> bool { matches!(self, DeviceToken::MicrosoftPush(_)) }
+  pub fn is_microsoft_push_vo_i_p(&self) -> bool { matches!(self, DeviceToken::MicrosoftPushVoIP(_)) }
+  pub fn is_simple_push(&self) -> bool { matches!(self, DeviceToken::SimplePush(_)) }
+  pub fn is_tizen_push(&self) -> bool { matches!(self, DeviceToken::TizenPush(_)) }
+  pub fn is_ubuntu_push(&self) -> bool { matches!(self, DeviceToken::UbuntuPush(_)) }
+  pub fn is_web_push(&self) -> bool { matches!(self, DeviceToken::WebPush(_)) }
+  pub fn is_windows_push(&self) -> bool { matches!(self, DeviceToken::WindowsPush(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, DeviceToken::Unknown(_)) }
 
   pub fn on_apple_push<F: FnOnce(&DeviceTokenApplePush)>(&self, fnc: F) -> &Self { if let DeviceToken::ApplePush(t) = self { fnc(t) }; self }
   pub fn on_apple_push_vo_i_p<F: FnOnce(&DeviceTokenApplePushVoIP)>(&self, fnc: F) -> &Self { if let DeviceToken::ApplePushVoIP(t) = self { fnc(t) }; self }
@@ -134,6 +156,7 @@ impl DeviceToken {
   pub fn on_ubuntu_push<F: FnOnce(&DeviceTokenUbuntuPush)>(&self, fnc: F) -> &Self { if let DeviceToken::UbuntuPush(t) = self { fnc(t) }; self }
   pub fn on_web_push<F: FnOnce(&DeviceTokenWebPush)>(&self, fnc: F) -> &Self { if let DeviceToken::WebPush(t) = self { fnc(t) }; self }
   pub fn on_windows_push<F: FnOnce(&DeviceTokenWindowsPush)>(&self, fnc: F) -> &Self { if let DeviceToken::WindowsPush(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let DeviceToken::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_apple_push(&self) -> Option<&DeviceTokenApplePush> { if let DeviceToken::ApplePush(t) = self { return Some(t) } None }
   pub fn as_apple_push_vo_i_p(&self) -> Option<&DeviceTokenApplePushVoIP> { if let DeviceToken::ApplePushVoIP(t) = self { return Some(t) } None }
@@ -146,6 +169,7 @@ impl DeviceToken {
   pub fn as_ubuntu_push(&self) -> Option<&DeviceTokenUbuntuPush> { if let DeviceToken::UbuntuPush(t) = self { return Some(t) } None }
   pub fn as_web_push(&self) -> Option<&DeviceTokenWebPush> { if let DeviceToken::WebPush(t) = self { return Some(t) } None }
   pub fn as_windows_push(&self) -> Option<&DeviceTokenWindowsPush> { if let DeviceToken::WindowsPush(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let DeviceToken::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -191,7 +215,10 @@ pub struct DeviceTokenApplePush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Device token; may be empty to de-register a device
   device_token: String,
   /// True, if App Sandbox is enabled
@@ -200,8 +227,9 @@ pub struct DeviceTokenApplePush {
 }
 
 impl RObject for DeviceTokenApplePush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenApplePush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenApplePush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -211,14 +239,15 @@ impl TDDeviceToken for DeviceTokenApplePush {}
 
 
 impl DeviceTokenApplePush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenApplePushBuilder {
-    let mut inner = DeviceTokenApplePush::default();
-    inner.td_name = "deviceTokenApplePush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenApplePush { td_name: "deviceTokenApplePush".to_string(), ..Default::default() };
     RTDDeviceTokenApplePushBuilder { inner }
   }
 
+  /// `DeviceTokenApplePush` with the fields tdlib requires, the others are set by `DeviceTokenApplePush::builder()`
+  pub fn new(device_token: impl AsRef<str>, is_app_sandbox: bool) -> Self { Self::builder().device_token(device_token).is_app_sandbox(is_app_sandbox).build() }
+
   pub fn device_token(&self) -> &String { &self.device_token }
 
   pub fn is_app_sandbox(&self) -> bool { self.is_app_sandbox }
@@ -233,6 +262,12 @@ pub struct RTDDeviceTokenApplePushBuilder {
 impl RTDDeviceTokenApplePushBuilder {
   pub fn build(&self) -> DeviceTokenApplePush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().to_string();
@@ -255,6 +290,13 @@ impl AsRef<DeviceTokenApplePush> for RTDDeviceTokenApplePushBuilder {
   fn as_ref(&self) -> &DeviceTokenApplePush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenApplePush {
+  fn eq(&self, other: &Self) -> bool {
+    self.device_token == other.device_token
+      && self.is_app_sandbox == other.is_app_sandbox
+  }
+}
+
 
 
 
@@ -269,7 +311,10 @@ pub struct DeviceTokenApplePushVoIP {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Device token; may be empty to de-register a device
   device_token: String,
   /// True, if App Sandbox is enabled
@@ -280,8 +325,9 @@ pub struct DeviceTokenApplePushVoIP {
 }
 
 impl RObject for DeviceTokenApplePushVoIP {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenApplePushVoIP" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenApplePushVoIP" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -291,14 +337,15 @@ impl TDDeviceToken for DeviceTokenApplePushVoIP {}
 
 
 impl DeviceTokenApplePushVoIP {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenApplePushVoIPBuilder {
-    let mut inner = DeviceTokenApplePushVoIP::default();
-    inner.td_name = "deviceTokenApplePushVoIP".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenApplePushVoIP { td_name: "deviceTokenApplePushVoIP".to_string(), ..Default::default() };
     RTDDeviceTokenApplePushVoIPBuilder { inner }
   }
 
+  /// `DeviceTokenApplePushVoIP` with the fields tdlib requires, the others are set by `DeviceTokenApplePushVoIP::builder()`
+  pub fn new(device_token: impl AsRef<str>, is_app_sandbox: bool, encrypt: bool) -> Self { Self::builder().device_token(device_token).is_app_sandbox(is_app_sandbox).encrypt(encrypt).build() }
+
   pub fn device_token(&self) -> &String { &self.device_token }
 
   pub fn is_app_sandbox(&self) -> bool { self.is_app_sandbox }
@@ -315,6 +362,12 @@ pub struct RTDDeviceTokenApplePushVoIPBuilder {
 impl RTDDeviceTokenApplePushVoIPBuilder {
   pub fn build(&self) -> DeviceTokenApplePushVoIP { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().to_string();
@@ -343,6 +396,14 @@ impl AsRef<DeviceTokenApplePushVoIP> for RTDDeviceTokenApplePushVoIPBuilder {
   fn as_ref(&self) -> &DeviceTokenApplePushVoIP { &self.inner }
 }
 
+impl PartialEq for DeviceTokenApplePushVoIP {
+  fn eq(&self, other: &Self) -> bool {
+    self.device_token == other.device_token
+      && self.is_app_sandbox == other.is_app_sandbox
+      && self.encrypt == other.encrypt
+  }
+}
+
 
 
 
@@ -357,15 +418,19 @@ pub struct DeviceTokenBlackBerryPush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Token; may be empty to de-register a device
   token: String,
   
 }
 
 impl RObject for DeviceTokenBlackBerryPush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenBlackBerryPush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenBlackBerryPush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -375,14 +440,15 @@ impl TDDeviceToken for DeviceTokenBlackBerryPush {}
 
 
 impl DeviceTokenBlackBerryPush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenBlackBerryPushBuilder {
-    let mut inner = DeviceTokenBlackBerryPush::default();
-    inner.td_name = "deviceTokenBlackBerryPush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenBlackBerryPush { td_name: "deviceTokenBlackBerryPush".to_string(), ..Default::default() };
     RTDDeviceTokenBlackBerryPushBuilder { inner }
   }
 
+  /// `DeviceTokenBlackBerryPush` with the fields tdlib requires, the others are set by `DeviceTokenBlackBerryPush::builder()`
+  pub fn new(token: impl AsRef<str>) -> Self { Self::builder().token(token).build() }
+
   pub fn token(&self) -> &String { &self.token }
 
 }
@@ -395,6 +461,12 @@ pub struct RTDDeviceTokenBlackBerryPushBuilder {
 impl RTDDeviceTokenBlackBerryPushBuilder {
   pub fn build(&self) -> DeviceTokenBlackBerryPush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -411,6 +483,12 @@ impl AsRef<DeviceTokenBlackBerryPush> for RTDDeviceTokenBlackBerryPushBuilder {
   fn as_ref(&self) -> &DeviceTokenBlackBerryPush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenBlackBerryPush {
+  fn eq(&self, other: &Self) -> bool {
+    self.token == other.token
+  }
+}
+
 
 
 
@@ -425,7 +503,10 @@ pub struct DeviceTokenFirebaseCloudMessaging {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Device registration token; may be empty to de-register a device
   token: String,
   /// True, if push notifications must be additionally encrypted
@@ -434,8 +515,9 @@ pub struct DeviceTokenFirebaseCloudMessaging {
 }
 
 impl RObject for DeviceTokenFirebaseCloudMessaging {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenFirebaseCloudMessaging" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenFirebaseCloudMessaging" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -445,14 +527,15 @@ impl TDDeviceToken for DeviceTokenFirebaseCloudMessaging {}
 
 
 impl DeviceTokenFirebaseCloudMessaging {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenFirebaseCloudMessagingBuilder {
-    let mut inner = DeviceTokenFirebaseCloudMessaging::default();
-    inner.td_name = "deviceTokenFirebaseCloudMessaging".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenFirebaseCloudMessaging { td_name: "deviceTokenFirebaseCloudMessaging".to_string(), ..Default::default() };
     RTDDeviceTokenFirebaseCloudMessagingBuilder { inner }
   }
 
+  /// `DeviceTokenFirebaseCloudMessaging` with the fields tdlib requires, the others are set by `DeviceTokenFirebaseCloudMessaging::builder()`
+  pub fn new(token: impl AsRef<str>, encrypt: bool) -> Self { Self::builder().token(token).encrypt(encrypt).build() }
+
   pub fn token(&self) -> &String { &self.token }
 
   pub fn encrypt(&self) -> bool { self.encrypt }
@@ -467,6 +550,12 @@ pub struct RTDDeviceTokenFirebaseCloudMessagingBuilder {
 impl RTDDeviceTokenFirebaseCloudMessagingBuilder {
   pub fn build(&self) -> DeviceTokenFirebaseCloudMessaging { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -489,6 +578,13 @@ impl AsRef<DeviceTokenFirebaseCloudMessaging> for RTDDeviceTokenFirebaseCloudMes
   fn as_ref(&self) -> &DeviceTokenFirebaseCloudMessaging { &self.inner }
 }
 
+impl PartialEq for DeviceTokenFirebaseCloudMessaging {
+  fn eq(&self, other: &Self) -> bool {
+    self.token == other.token
+      && self.encrypt == other.encrypt
+  }
+}
+
 
 
 
@@ -503,15 +599,19 @@ pub struct DeviceTokenMicrosoftPush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Push notification channel URI; may be empty to de-register a device
   channel_uri: String,
   
 }
 
 impl RObject for DeviceTokenMicrosoftPush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenMicrosoftPush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenMicrosoftPush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -521,14 +621,15 @@ impl TDDeviceToken for DeviceTokenMicrosoftPush {}
 
 
 impl DeviceTokenMicrosoftPush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenMicrosoftPushBuilder {
-    let mut inner = DeviceTokenMicrosoftPush::default();
-    inner.td_name = "deviceTokenMicrosoftPush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenMicrosoftPush { td_name: "deviceTokenMicrosoftPush".to_string(), ..Default::default() };
     RTDDeviceTokenMicrosoftPushBuilder { inner }
   }
 
+  /// `DeviceTokenMicrosoftPush` with the fields tdlib requires, the others are set by `DeviceTokenMicrosoftPush::builder()`
+  pub fn new(channel_uri: impl AsRef<str>) -> Self { Self::builder().channel_uri(channel_uri).build() }
+
   pub fn channel_uri(&self) -> &String { &self.channel_uri }
 
 }
@@ -541,6 +642,12 @@ pub struct RTDDeviceTokenMicrosoftPushBuilder {
 impl RTDDeviceTokenMicrosoftPushBuilder {
   pub fn build(&self) -> DeviceTokenMicrosoftPush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
     self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -557,6 +664,12 @@ impl AsRef<DeviceTokenMicrosoftPush> for RTDDeviceTokenMicrosoftPushBuilder {
   fn as_ref(&self) -> &DeviceTokenMicrosoftPush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenMicrosoftPush {
+  fn eq(&self, other: &Self) -> bool {
+    self.channel_uri == other.channel_uri
+  }
+}
+
 
 
 
@@ -571,15 +684,19 @@ pub struct DeviceTokenMicrosoftPushVoIP {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Push notification channel URI; may be empty to de-register a device
   channel_uri: String,
   
 }
 
 impl RObject for DeviceTokenMicrosoftPushVoIP {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenMicrosoftPushVoIP" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenMicrosoftPushVoIP" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -589,14 +706,15 @@ impl TDDeviceToken for DeviceTokenMicrosoftPushVoIP {}
 
 
 impl DeviceTokenMicrosoftPushVoIP {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenMicrosoftPushVoIPBuilder {
-    let mut inner = DeviceTokenMicrosoftPushVoIP::default();
-    inner.td_name = "deviceTokenMicrosoftPushVoIP".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenMicrosoftPushVoIP { td_name: "deviceTokenMicrosoftPushVoIP".to_string(), ..Default::default() };
     RTDDeviceTokenMicrosoftPushVoIPBuilder { inner }
   }
 
+  /// `DeviceTokenMicrosoftPushVoIP` with the fields tdlib requires, the others are set by `DeviceTokenMicrosoftPushVoIP::builder()`
+  pub fn new(channel_uri: impl AsRef<str>) -> Self { Self::builder().channel_uri(channel_uri).build() }
+
   pub fn channel_uri(&self) -> &String { &self.channel_uri }
 
 }
@@ -609,6 +727,12 @@ pub struct RTDDeviceTokenMicrosoftPushVoIPBuilder {
 impl RTDDeviceTokenMicrosoftPushVoIPBuilder {
   pub fn build(&self) -> DeviceTokenMicrosoftPushVoIP { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
     self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -625,6 +749,12 @@ impl AsRef<DeviceTokenMicrosoftPushVoIP> for RTDDeviceTokenMicrosoftPushVoIPBuil
   fn as_ref(&self) -> &DeviceTokenMicrosoftPushVoIP { &self.inner }
 }
 
+impl PartialEq for DeviceTokenMicrosoftPushVoIP {
+  fn eq(&self, other: &Self) -> bool {
+    self.channel_uri == other.channel_uri
+  }
+}
+
 
 
 
@@ -639,15 +769,19 @@ pub struct DeviceTokenSimplePush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device
   endpoint: String,
   
 }
 
 impl RObject for DeviceTokenSimplePush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenSimplePush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenSimplePush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -657,14 +791,15 @@ impl TDDeviceToken for DeviceTokenSimplePush {}
 
 
 impl DeviceTokenSimplePush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenSimplePushBuilder {
-    let mut inner = DeviceTokenSimplePush::default();
-    inner.td_name = "deviceTokenSimplePush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenSimplePush { td_name: "deviceTokenSimplePush".to_string(), ..Default::default() };
     RTDDeviceTokenSimplePushBuilder { inner }
   }
 
+  /// `DeviceTokenSimplePush` with the fields tdlib requires, the others are set by `DeviceTokenSimplePush::builder()`
+  pub fn new(endpoint: impl AsRef<str>) -> Self { Self::builder().endpoint(endpoint).build() }
+
   pub fn endpoint(&self) -> &String { &self.endpoint }
 
 }
@@ -677,6 +812,12 @@ pub struct RTDDeviceTokenSimplePushBuilder {
 impl RTDDeviceTokenSimplePushBuilder {
   pub fn build(&self) -> DeviceTokenSimplePush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
     self.inner.endpoint = endpoint.as_ref().to_string();
@@ -693,6 +834,12 @@ impl AsRef<DeviceTokenSimplePush> for RTDDeviceTokenSimplePushBuilder {
   fn as_ref(&self) -> &DeviceTokenSimplePush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenSimplePush {
+  fn eq(&self, other: &Self) -> bool {
+    self.endpoint == other.endpoint
+  }
+}
+
 
 
 
@@ -707,15 +854,19 @@ pub struct DeviceTokenTizenPush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Push service registration identifier; may be empty to de-register a device
   reg_id: String,
   
 }
 
 impl RObject for DeviceTokenTizenPush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenTizenPush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenTizenPush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -725,14 +876,15 @@ impl TDDeviceToken for DeviceTokenTizenPush {}
 
 
 impl DeviceTokenTizenPush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenTizenPushBuilder {
-    let mut inner = DeviceTokenTizenPush::default();
-    inner.td_name = "deviceTokenTizenPush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenTizenPush { td_name: "deviceTokenTizenPush".to_string(), ..Default::default() };
     RTDDeviceTokenTizenPushBuilder { inner }
   }
 
+  /// `DeviceTokenTizenPush` with the fields tdlib requires, the others are set by `DeviceTokenTizenPush::builder()`
+  pub fn new(reg_id: impl AsRef<str>) -> Self { Self::builder().reg_id(reg_id).build() }
+
   pub fn reg_id(&self) -> &String { &self.reg_id }
 
 }
@@ -745,6 +897,12 @@ pub struct RTDDeviceTokenTizenPushBuilder {
 impl RTDDeviceTokenTizenPushBuilder {
   pub fn build(&self) -> DeviceTokenTizenPush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn reg_id<T: AsRef<str>>(&mut self, reg_id: T) -> &mut Self {
     self.inner.reg_id = reg_id.as_ref().to_string();
@@ -761,6 +919,12 @@ impl AsRef<DeviceTokenTizenPush> for RTDDeviceTokenTizenPushBuilder {
   fn as_ref(&self) -> &DeviceTokenTizenPush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenTizenPush {
+  fn eq(&self, other: &Self) -> bool {
+    self.reg_id == other.reg_id
+  }
+}
+
 
 
 
@@ -775,15 +939,19 @@ pub struct DeviceTokenUbuntuPush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Token; may be empty to de-register a device
   token: String,
   
 }
 
 impl RObject for DeviceTokenUbuntuPush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenUbuntuPush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenUbuntuPush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -793,14 +961,15 @@ impl TDDeviceToken for DeviceTokenUbuntuPush {}
 
 
 impl DeviceTokenUbuntuPush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenUbuntuPushBuilder {
-    let mut inner = DeviceTokenUbuntuPush::default();
-    inner.td_name = "deviceTokenUbuntuPush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenUbuntuPush { td_name: "deviceTokenUbuntuPush".to_string(), ..Default::default() };
     RTDDeviceTokenUbuntuPushBuilder { inner }
   }
 
+  /// `DeviceTokenUbuntuPush` with the fields tdlib requires, the others are set by `DeviceTokenUbuntuPush::builder()`
+  pub fn new(token: impl AsRef<str>) -> Self { Self::builder().token(token).build() }
+
   pub fn token(&self) -> &String { &self.token }
 
 }
@@ -813,6 +982,12 @@ pub struct RTDDeviceTokenUbuntuPushBuilder {
 impl RTDDeviceTokenUbuntuPushBuilder {
   pub fn build(&self) -> DeviceTokenUbuntuPush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -829,6 +1004,12 @@ impl AsRef<DeviceTokenUbuntuPush> for RTDDeviceTokenUbuntuPushBuilder {
   fn as_ref(&self) -> &DeviceTokenUbuntuPush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenUbuntuPush {
+  fn eq(&self, other: &Self) -> bool {
+    self.token == other.token
+  }
+}
+
 
 
 
@@ -843,7 +1024,10 @@ pub struct DeviceTokenWebPush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device
   endpoint: String,
   /// Base64url-encoded P-256 elliptic curve Diffie-Hellman public key
@@ -854,8 +1038,9 @@ pub struct DeviceTokenWebPush {
 }
 
 impl RObject for DeviceTokenWebPush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenWebPush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenWebPush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -865,14 +1050,15 @@ impl TDDeviceToken for DeviceTokenWebPush {}
 
 
 impl DeviceTokenWebPush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenWebPushBuilder {
-    let mut inner = DeviceTokenWebPush::default();
-    inner.td_name = "deviceTokenWebPush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenWebPush { td_name: "deviceTokenWebPush".to_string(), ..Default::default() };
     RTDDeviceTokenWebPushBuilder { inner }
   }
 
+  /// `DeviceTokenWebPush` with the fields tdlib requires, the others are set by `DeviceTokenWebPush::builder()`
+  pub fn new(endpoint: impl AsRef<str>, p256dh_base64url: impl AsRef<str>, auth_base64url: impl AsRef<str>) -> Self { Self::builder().endpoint(endpoint).p256dh_base64url(p256dh_base64url).auth_base64url(auth_base64url).build() }
+
   pub fn endpoint(&self) -> &String { &self.endpoint }
 
   pub fn p256dh_base64url(&self) -> &String { &self.p256dh_base64url }
@@ -889,6 +1075,12 @@ pub struct RTDDeviceTokenWebPushBuilder {
 impl RTDDeviceTokenWebPushBuilder {
   pub fn build(&self) -> DeviceTokenWebPush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
     self.inner.endpoint = endpoint.as_ref().to_string();
@@ -917,6 +1109,14 @@ impl AsRef<DeviceTokenWebPush> for RTDDeviceTokenWebPushBuilder {
   fn as_ref(&self) -> &DeviceTokenWebPush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenWebPush {
+  fn eq(&self, other: &Self) -> bool {
+    self.endpoint == other.endpoint
+      && self.p256dh_base64url == other.p256dh_base64url
+      && self.auth_base64url == other.auth_base64url
+  }
+}
+
 
 
 
@@ -931,15 +1131,19 @@ pub struct DeviceTokenWindowsPush {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The access token that will be used to send notifications; may be empty to de-register a device
   access_token: String,
   
 }
 
 impl RObject for DeviceTokenWindowsPush {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "deviceTokenWindowsPush" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "deviceTokenWindowsPush" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -949,14 +1153,15 @@ impl TDDeviceToken for DeviceTokenWindowsPush {}
 
 
 impl DeviceTokenWindowsPush {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDeviceTokenWindowsPushBuilder {
-    let mut inner = DeviceTokenWindowsPush::default();
-    inner.td_name = "deviceTokenWindowsPush".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DeviceTokenWindowsPush { td_name: "deviceTokenWindowsPush".to_string(), ..Default::default() };
     RTDDeviceTokenWindowsPushBuilder { inner }
   }
 
+  /// `DeviceTokenWindowsPush` with the fields tdlib requires, the others are set by `DeviceTokenWindowsPush::builder()`
+  pub fn new(access_token: impl AsRef<str>) -> Self { Self::builder().access_token(access_token).build() }
+
   pub fn access_token(&self) -> &String { &self.access_token }
 
 }
@@ -969,6 +1174,12 @@ pub struct RTDDeviceTokenWindowsPushBuilder {
 impl RTDDeviceTokenWindowsPushBuilder {
   pub fn build(&self) -> DeviceTokenWindowsPush { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn access_token<T: AsRef<str>>(&mut self, access_token: T) -> &mut Self {
     self.inner.access_token = access_token.as_ref().to_string();
@@ -985,5 +1196,11 @@ impl AsRef<DeviceTokenWindowsPush> for RTDDeviceTokenWindowsPushBuilder {
   fn as_ref(&self) -> &DeviceTokenWindowsPush { &self.inner }
 }
 
+impl PartialEq for DeviceTokenWindowsPush {
+  fn eq(&self, other: &Self) -> bool {
+    self.access_token == other.access_token
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDDiceStickers: Debug + RObject {}
 
 /// Contains animated stickers which must be used for dice animation rendering
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum DiceStickers {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum DiceStickers {
   Regular(DiceStickersRegular),
   /// Animated stickers to be combined into a slot machine
   SlotMachine(DiceStickersSlotMachine),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for DiceStickers {
   fn deserialize<D>(deserializer: D) -> Result<DiceStickers, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      DiceStickers,
+      DiceStickers, Unknown;
       (diceStickersRegular, Regular);
       (diceStickersSlotMachine, SlotMachine);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for DiceStickers {
 }
 
 impl RObject for DiceStickers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       DiceStickers::Regular(t) => t.td_name(),
       DiceStickers::SlotMachine(t) => t.td_name(),
+      DiceStickers::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       DiceStickers::Regular(t) => t.extra(),
       DiceStickers::SlotMachine(t) => t.extra(),
+      DiceStickers::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      DiceStickers::Regular(t) => t.client_id(),
+      DiceStickers::SlotMachine(t) => t.client_id(),
+      DiceStickers::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for DiceStickers {
 }
 
 impl DiceStickers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let DiceStickers::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, DiceStickers::_Default(_)) }
 
-  pub fn is_regular(&self) -> bool { if let DiceStickers::Regular(_) = self { true } else { false } }
-  pub fn is_slot_machine(&self) -> bool { if let DiceStickers::SlotMachine(_) = self { true } else { false } }
+  pub fn is_regular(&self) -> bool { matches!(self, DiceStickers::Regular(_)) }
+  pub fn is_slot_machine(&self) -> bool { matches!(self, DiceStickers::SlotMachine(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, DiceStickers::Unknown(_)) }
 
   pub fn on_regular<F: FnOnce(&DiceStickersRegular)>(&self, fnc: F) -> &Self { if let DiceStickers::Regular(t) = self { fnc(t) }; self }
   pub fn on_slot_machine<F: FnOnce(&DiceStickersSlotMachine)>(&self, fnc: F) -> &Self { if let DiceStickers::SlotMachine(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let DiceStickers::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_regular(&self) -> Option<&DiceStickersRegular> { if let DiceStickers::Regular(t) = self { return Some(t) } None }
   pub fn as_slot_machine(&self) -> Option<&DiceStickersSlotMachine> { if let DiceStickers::SlotMachine(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let DiceStickers::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,15 +116,19 @@ pub struct DiceStickersRegular {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The animated sticker with the dice animation
   sticker: Sticker,
   
 }
 
 impl RObject for DiceStickersRegular {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "diceStickersRegular" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "diceStickersRegular" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -119,14 +138,15 @@ impl TDDiceStickers for DiceStickersRegular {}
 
 
 impl DiceStickersRegular {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDiceStickersRegularBuilder {
-    let mut inner = DiceStickersRegular::default();
-    inner.td_name = "diceStickersRegular".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DiceStickersRegular { td_name: "diceStickersRegular".to_string(), ..Default::default() };
     RTDDiceStickersRegularBuilder { inner }
   }
 
+  /// `DiceStickersRegular` with the fields tdlib requires, the others are set by `DiceStickersRegular::builder()`
+  pub fn new(sticker: impl AsRef<Sticker>) -> Self { Self::builder().sticker(sticker).build() }
+
   pub fn sticker(&self) -> &Sticker { &self.sticker }
 
 }
@@ -139,6 +159,12 @@ pub struct RTDDiceStickersRegularBuilder {
 impl RTDDiceStickersRegularBuilder {
   pub fn build(&self) -> DiceStickersRegular { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -155,6 +181,12 @@ impl AsRef<DiceStickersRegular> for RTDDiceStickersRegularBuilder {
   fn as_ref(&self) -> &DiceStickersRegular { &self.inner }
 }
 
+impl PartialEq for DiceStickersRegular {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker == other.sticker
+  }
+}
+
 
 
 
@@ -169,7 +201,10 @@ pub struct DiceStickersSlotMachine {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The animated sticker with the slot machine background. The background animation must start playing after all reel animations finish
   background: Sticker,
   /// The animated sticker with the lever animation. The lever animation must play once in the initial dice state
@@ -184,8 +219,9 @@ pub struct DiceStickersSlotMachine {
 }
 
 impl RObject for DiceStickersSlotMachine {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "diceStickersSlotMachine" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "diceStickersSlotMachine" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -195,14 +231,29 @@ impl TDDiceStickers for DiceStickersSlotMachine {}
 
 
 impl DiceStickersSlotMachine {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDiceStickersSlotMachineBuilder {
-    let mut inner = DiceStickersSlotMachine::default();
-    inner.td_name = "diceStickersSlotMachine".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DiceStickersSlotMachine { td_name: "diceStickersSlotMachine".to_string(), ..Default::default() };
     RTDDiceStickersSlotMachineBuilder { inner }
   }
 
+  /// `DiceStickersSlotMachine` with the fields tdlib requires, the others are set by `DiceStickersSlotMachine::builder()`
+  pub fn new(
+    background: impl AsRef<Sticker>,
+    lever: impl AsRef<Sticker>,
+    left_reel: impl AsRef<Sticker>,
+    center_reel: impl AsRef<Sticker>,
+    right_reel: impl AsRef<Sticker>,
+  ) -> Self {
+    Self::builder()
+      .background(background)
+      .lever(lever)
+      .left_reel(left_reel)
+      .center_reel(center_reel)
+      .right_reel(right_reel)
+      .build()
+  }
+
   pub fn background(&self) -> &Sticker { &self.background }
 
   pub fn lever(&self) -> &Sticker { &self.lever }
@@ -223,6 +274,12 @@ pub struct RTDDiceStickersSlotMachineBuilder {
 impl RTDDiceStickersSlotMachineBuilder {
   pub fn build(&self) -> DiceStickersSlotMachine { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn background<T: AsRef<Sticker>>(&mut self, background: T) -> &mut Self {
     self.inner.background = background.as_ref().clone();
@@ -263,5 +320,15 @@ impl AsRef<DiceStickersSlotMachine> for RTDDiceStickersSlotMachineBuilder {
   fn as_ref(&self) -> &DiceStickersSlotMachine { &self.inner }
 }
 
+impl PartialEq for DiceStickersSlotMachine {
+  fn eq(&self, other: &Self) -> bool {
+    self.background == other.background
+      && self.lever == other.lever
+      && self.left_reel == other.left_reel
+      && self.center_reel == other.center_reel
+      && self.right_reel == other.right_reel
+  }
+}
+
 
 
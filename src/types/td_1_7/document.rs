@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Document {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Original name of the file; as defined by the sender
   file_name: String,
   /// MIME type of the file; as defined by the sender
@@ -29,22 +31,24 @@ pub struct Document {
 }
 
 impl RObject for Document {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "document" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "document" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Document {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDocumentBuilder {
-    let mut inner = Document::default();
-    inner.td_name = "document".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Document { td_name: "document".to_string(), ..Default::default() };
     RTDDocumentBuilder { inner }
   }
 
+  /// `Document` with the fields tdlib requires, the others are set by `Document::builder()`
+  pub fn new(file_name: impl AsRef<str>, mime_type: impl AsRef<str>, document: impl AsRef<File>) -> Self { Self::builder().file_name(file_name).mime_type(mime_type).document(document).build() }
+
   pub fn file_name(&self) -> &String { &self.file_name }
 
   pub fn mime_type(&self) -> &String { &self.mime_type }
@@ -65,6 +69,12 @@ pub struct RTDDocumentBuilder {
 impl RTDDocumentBuilder {
   pub fn build(&self) -> Document { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
@@ -105,5 +115,15 @@ impl AsRef<Document> for RTDDocumentBuilder {
   fn as_ref(&self) -> &Document { &self.inner }
 }
 
+impl PartialEq for Document {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_name == other.file_name
+      && self.mime_type == other.mime_type
+      && self.minithumbnail == other.minithumbnail
+      && self.thumbnail == other.thumbnail
+      && self.document == other.document
+  }
+}
+
 
 
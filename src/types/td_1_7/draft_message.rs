@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct DraftMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the message to reply to; 0 if none
-  reply_to_message_id: i64,
+  reply_to_message_id: MessageId,
   /// Point in time (Unix timestamp) when the draft was created
   date: i64,
   /// Content of the message draft; must be of the type inputMessageText
@@ -25,23 +27,25 @@ pub struct DraftMessage {
 }
 
 impl RObject for DraftMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "draftMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "draftMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl DraftMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDDraftMessageBuilder {
-    let mut inner = DraftMessage::default();
-    inner.td_name = "draftMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = DraftMessage { td_name: "draftMessage".to_string(), ..Default::default() };
     RTDDraftMessageBuilder { inner }
   }
 
-  pub fn reply_to_message_id(&self) -> i64 { self.reply_to_message_id }
+  /// `DraftMessage` with the fields tdlib requires, the others are set by `DraftMessage::builder()`
+  pub fn new(reply_to_message_id: MessageId, date: i64, input_message_text: impl AsRef<InputMessageContent>) -> Self { Self::builder().reply_to_message_id(reply_to_message_id).date(date).input_message_text(input_message_text).build() }
+
+  pub fn reply_to_message_id(&self) -> MessageId { self.reply_to_message_id }
 
   pub fn date(&self) -> i64 { self.date }
 
@@ -57,8 +61,14 @@ pub struct RTDDraftMessageBuilder {
 impl RTDDraftMessageBuilder {
   pub fn build(&self) -> DraftMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn reply_to_message_id(&mut self, reply_to_message_id: i64) -> &mut Self {
+  pub fn reply_to_message_id(&mut self, reply_to_message_id: MessageId) -> &mut Self {
     self.inner.reply_to_message_id = reply_to_message_id;
     self
   }
@@ -85,5 +95,19 @@ impl AsRef<DraftMessage> for RTDDraftMessageBuilder {
   fn as_ref(&self) -> &DraftMessage { &self.inner }
 }
 
+impl PartialEq for DraftMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.reply_to_message_id == other.reply_to_message_id
+      && self.date == other.date
+      && self.input_message_text == other.input_message_text
+  }
+}
+
+impl Validate for DraftMessage {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("input_message_text", &self.input_message_text);
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct EmailAddressAuthenticationCodeInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Pattern of the email address to which an authentication code was sent
   email_address_pattern: String,
   /// Length of the code; 0 if unknown
@@ -23,22 +25,24 @@ pub struct EmailAddressAuthenticationCodeInfo {
 }
 
 impl RObject for EmailAddressAuthenticationCodeInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "emailAddressAuthenticationCodeInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "emailAddressAuthenticationCodeInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl EmailAddressAuthenticationCodeInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDEmailAddressAuthenticationCodeInfoBuilder {
-    let mut inner = EmailAddressAuthenticationCodeInfo::default();
-    inner.td_name = "emailAddressAuthenticationCodeInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = EmailAddressAuthenticationCodeInfo { td_name: "emailAddressAuthenticationCodeInfo".to_string(), ..Default::default() };
     RTDEmailAddressAuthenticationCodeInfoBuilder { inner }
   }
 
+  /// `EmailAddressAuthenticationCodeInfo` with the fields tdlib requires, the others are set by `EmailAddressAuthenticationCodeInfo::builder()`
+  pub fn new(email_address_pattern: impl AsRef<str>, length: i64) -> Self { Self::builder().email_address_pattern(email_address_pattern).length(length).build() }
+
   pub fn email_address_pattern(&self) -> &String { &self.email_address_pattern }
 
   pub fn length(&self) -> i64 { self.length }
@@ -53,6 +57,12 @@ pub struct RTDEmailAddressAuthenticationCodeInfoBuilder {
 impl RTDEmailAddressAuthenticationCodeInfoBuilder {
   pub fn build(&self) -> EmailAddressAuthenticationCodeInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn email_address_pattern<T: AsRef<str>>(&mut self, email_address_pattern: T) -> &mut Self {
     self.inner.email_address_pattern = email_address_pattern.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<EmailAddressAuthenticationCodeInfo> for RTDEmailAddressAuthentication
   fn as_ref(&self) -> &EmailAddressAuthenticationCodeInfo { &self.inner }
 }
 
+impl PartialEq for EmailAddressAuthenticationCodeInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.email_address_pattern == other.email_address_pattern
+      && self.length == other.length
+  }
+}
+
 
 
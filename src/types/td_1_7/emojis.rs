@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Emojis {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of emojis
   emojis: Vec<String>,
   
 }
 
 impl RObject for Emojis {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "emojis" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "emojis" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Emojis {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDEmojisBuilder {
-    let mut inner = Emojis::default();
-    inner.td_name = "emojis".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Emojis { td_name: "emojis".to_string(), ..Default::default() };
     RTDEmojisBuilder { inner }
   }
 
+  /// `Emojis` with the fields tdlib requires, the others are set by `Emojis::builder()`
+  pub fn new(emojis: Vec<String>) -> Self { Self::builder().emojis(emojis).build() }
+
   pub fn emojis(&self) -> &Vec<String> { &self.emojis }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDEmojisBuilder {
 impl RTDEmojisBuilder {
   pub fn build(&self) -> Emojis { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emojis(&mut self, emojis: Vec<String>) -> &mut Self {
     self.inner.emojis = emojis;
@@ -65,5 +75,11 @@ impl AsRef<Emojis> for RTDEmojisBuilder {
   fn as_ref(&self) -> &Emojis { &self.inner }
 }
 
+impl PartialEq for Emojis {
+  fn eq(&self, other: &Self) -> bool {
+    self.emojis == other.emojis
+  }
+}
+
 
 
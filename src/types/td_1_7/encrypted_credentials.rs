@@ -0,0 +1,107 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Contains encrypted Telegram Passport data credentials
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EncryptedCredentials {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
+  /// The encrypted credentials
+  data: Bytes,
+  /// The decrypted data hash
+  hash: Bytes,
+  /// Secret for data decryption, encrypted with the service's public key
+  secret: Bytes,
+  
+}
+
+impl RObject for EncryptedCredentials {
+  #[doc(hidden)] fn td_name(&self) -> &str { "encryptedCredentials" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+
+impl EncryptedCredentials {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  pub fn builder() -> RTDEncryptedCredentialsBuilder {
+    let inner = EncryptedCredentials { td_name: "encryptedCredentials".to_string(), ..Default::default() };
+    RTDEncryptedCredentialsBuilder { inner }
+  }
+
+  /// `EncryptedCredentials` with the fields tdlib requires, the others are set by `EncryptedCredentials::builder()`
+  pub fn new(data: impl AsRef<Bytes>, hash: impl AsRef<Bytes>, secret: impl AsRef<Bytes>) -> Self { Self::builder().data(data).hash(hash).secret(secret).build() }
+
+  pub fn data(&self) -> &Bytes { &self.data }
+
+  pub fn hash(&self) -> &Bytes { &self.hash }
+
+  pub fn secret(&self) -> &Bytes { &self.secret }
+
+}
+
+#[doc(hidden)]
+pub struct RTDEncryptedCredentialsBuilder {
+  inner: EncryptedCredentials
+}
+
+impl RTDEncryptedCredentialsBuilder {
+  pub fn build(&self) -> EncryptedCredentials { self.inner.clone() }
+
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
+   
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
+    self
+  }
+
+   
+  pub fn hash<T: AsRef<Bytes>>(&mut self, hash: T) -> &mut Self {
+    self.inner.hash = hash.as_ref().clone();
+    self
+  }
+
+   
+  pub fn secret<T: AsRef<Bytes>>(&mut self, secret: T) -> &mut Self {
+    self.inner.secret = secret.as_ref().clone();
+    self
+  }
+
+}
+
+impl AsRef<EncryptedCredentials> for EncryptedCredentials {
+  fn as_ref(&self) -> &EncryptedCredentials { self }
+}
+
+impl AsRef<EncryptedCredentials> for RTDEncryptedCredentialsBuilder {
+  fn as_ref(&self) -> &EncryptedCredentials { &self.inner }
+}
+
+impl PartialEq for EncryptedCredentials {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+      && self.hash == other.hash
+      && self.secret == other.secret
+  }
+}
+
+
+
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,11 +13,14 @@ pub struct EncryptedPassportElement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Type of Telegram Passport element
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// Encrypted JSON-encoded data about the user
-  data: String,
+  data: Bytes,
   /// The front side of an identity document
   front_side: DatedFile,
   /// The reverse side of an identity document; may be null
@@ -37,25 +39,45 @@ pub struct EncryptedPassportElement {
 }
 
 impl RObject for EncryptedPassportElement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "encryptedPassportElement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "encryptedPassportElement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl EncryptedPassportElement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDEncryptedPassportElementBuilder {
-    let mut inner = EncryptedPassportElement::default();
-    inner.td_name = "encryptedPassportElement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = EncryptedPassportElement { td_name: "encryptedPassportElement".to_string(), ..Default::default() };
     RTDEncryptedPassportElementBuilder { inner }
   }
 
+  /// `EncryptedPassportElement` with the fields tdlib requires, the others are set by `EncryptedPassportElement::builder()`
+  pub fn new(
+    type_: impl AsRef<PassportElementType>,
+    data: impl AsRef<Bytes>,
+    front_side: impl AsRef<DatedFile>,
+    translation: Vec<DatedFile>,
+    files: Vec<DatedFile>,
+    value: impl AsRef<str>,
+    hash: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .type_(type_)
+      .data(data)
+      .front_side(front_side)
+      .translation(translation)
+      .files(files)
+      .value(value)
+      .hash(hash)
+      .build()
+  }
+
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &Bytes { &self.data }
 
   pub fn front_side(&self) -> &DatedFile { &self.front_side }
 
@@ -81,6 +103,12 @@ pub struct RTDEncryptedPassportElementBuilder {
 impl RTDEncryptedPassportElementBuilder {
   pub fn build(&self) -> EncryptedPassportElement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -88,8 +116,8 @@ impl RTDEncryptedPassportElementBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
     self
   }
 
@@ -145,5 +173,19 @@ impl AsRef<EncryptedPassportElement> for RTDEncryptedPassportElementBuilder {
   fn as_ref(&self) -> &EncryptedPassportElement { &self.inner }
 }
 
+impl PartialEq for EncryptedPassportElement {
+  fn eq(&self, other: &Self) -> bool {
+    self.type_ == other.type_
+      && self.data == other.data
+      && self.front_side == other.front_side
+      && self.reverse_side == other.reverse_side
+      && self.selfie == other.selfie
+      && self.translation == other.translation
+      && self.files == other.files
+      && self.value == other.value
+      && self.hash == other.hash
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Error {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user
   code: i64,
   /// Error message; subject to future changes
@@ -23,22 +25,24 @@ pub struct Error {
 }
 
 impl RObject for Error {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "error" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "error" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Error {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDErrorBuilder {
-    let mut inner = Error::default();
-    inner.td_name = "error".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Error { td_name: "error".to_string(), ..Default::default() };
     RTDErrorBuilder { inner }
   }
 
+  /// `Error` with the fields tdlib requires, the others are set by `Error::builder()`
+  pub fn new(code: i64, message: impl AsRef<str>) -> Self { Self::builder().code(code).message(message).build() }
+
   pub fn code(&self) -> i64 { self.code }
 
   pub fn message(&self) -> &String { &self.message }
@@ -53,6 +57,12 @@ pub struct RTDErrorBuilder {
 impl RTDErrorBuilder {
   pub fn build(&self) -> Error { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code(&mut self, code: i64) -> &mut Self {
     self.inner.code = code;
@@ -75,5 +85,12 @@ impl AsRef<Error> for RTDErrorBuilder {
   fn as_ref(&self) -> &Error { &self.inner }
 }
 
+impl PartialEq for Error {
+  fn eq(&self, other: &Self) -> bool {
+    self.code == other.code
+      && self.message == other.message
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct File {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique file identifier
-  id: i64,
+  id: FileId,
   /// File size, in bytes; 0 if unknown
   size: i64,
   /// Approximate file size in bytes in case the exact file size is unknown. Can be used to show download/upload progress
@@ -29,23 +31,39 @@ pub struct File {
 }
 
 impl RObject for File {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "file" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "file" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl File {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileBuilder {
-    let mut inner = File::default();
-    inner.td_name = "file".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = File { td_name: "file".to_string(), ..Default::default() };
     RTDFileBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `File` with the fields tdlib requires, the others are set by `File::builder()`
+  pub fn new(
+    id: FileId,
+    size: i64,
+    expected_size: i64,
+    local: impl AsRef<LocalFile>,
+    remote: impl AsRef<RemoteFile>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .size(size)
+      .expected_size(expected_size)
+      .local(local)
+      .remote(remote)
+      .build()
+  }
+
+  pub fn id(&self) -> FileId { self.id }
 
   pub fn size(&self) -> i64 { self.size }
 
@@ -65,8 +83,14 @@ pub struct RTDFileBuilder {
 impl RTDFileBuilder {
   pub fn build(&self) -> File { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: FileId) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<File> for RTDFileBuilder {
   fn as_ref(&self) -> &File { &self.inner }
 }
 
+impl PartialEq for File {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.size == other.size
+      && self.expected_size == other.expected_size
+      && self.local == other.local
+      && self.remote == other.remote
+  }
+}
+
 
 
@@ -0,0 +1,85 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// Contains a part of a file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FilePart {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
+  /// File bytes
+  data: Bytes,
+  
+}
+
+impl RObject for FilePart {
+  #[doc(hidden)] fn td_name(&self) -> &str { "filePart" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+
+impl FilePart {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  pub fn builder() -> RTDFilePartBuilder {
+    let inner = FilePart { td_name: "filePart".to_string(), ..Default::default() };
+    RTDFilePartBuilder { inner }
+  }
+
+  /// `FilePart` with the fields tdlib requires, the others are set by `FilePart::builder()`
+  pub fn new(data: impl AsRef<Bytes>) -> Self { Self::builder().data(data).build() }
+
+  pub fn data(&self) -> &Bytes { &self.data }
+
+}
+
+#[doc(hidden)]
+pub struct RTDFilePartBuilder {
+  inner: FilePart
+}
+
+impl RTDFilePartBuilder {
+  pub fn build(&self) -> FilePart { self.inner.clone() }
+
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
+   
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
+    self
+  }
+
+}
+
+impl AsRef<FilePart> for FilePart {
+  fn as_ref(&self) -> &FilePart { self }
+}
+
+impl AsRef<FilePart> for RTDFilePartBuilder {
+  fn as_ref(&self) -> &FilePart { &self.inner }
+}
+
+impl PartialEq for FilePart {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+  }
+}
+
+
+
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDFileType: Debug + RObject {}
 
 /// Represents the type of a file
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum FileType {
   #[doc(hidden)] _Default(()),
@@ -51,6 +50,8 @@ pub enum FileType {
   VoiceNote(FileTypeVoiceNote),
   /// The file is a wallpaper or a background pattern
   Wallpaper(FileTypeWallpaper),
+  /// An object of a type this crate doesn't know, kept as received
+  UnknownType(UnknownObject),
 
 }
 
@@ -62,7 +63,7 @@ impl<'de> Deserialize<'de> for FileType {
   fn deserialize<D>(deserializer: D) -> Result<FileType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      FileType,
+      FileType, UnknownType;
       (fileTypeAnimation, Animation);
       (fileTypeAudio, Audio);
       (fileTypeDocument, Document);
@@ -85,7 +86,7 @@ impl<'de> Deserialize<'de> for FileType {
 }
 
 impl RObject for FileType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       FileType::Animation(t) => t.td_name(),
       FileType::Audio(t) => t.td_name(),
@@ -103,11 +104,12 @@ impl RObject for FileType {
       FileType::VideoNote(t) => t.td_name(),
       FileType::VoiceNote(t) => t.td_name(),
       FileType::Wallpaper(t) => t.td_name(),
+      FileType::UnknownType(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       FileType::Animation(t) => t.extra(),
       FileType::Audio(t) => t.extra(),
@@ -125,6 +127,30 @@ impl RObject for FileType {
       FileType::VideoNote(t) => t.extra(),
       FileType::VoiceNote(t) => t.extra(),
       FileType::Wallpaper(t) => t.extra(),
+      FileType::UnknownType(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      FileType::Animation(t) => t.client_id(),
+      FileType::Audio(t) => t.client_id(),
+      FileType::Document(t) => t.client_id(),
+      FileType::None(t) => t.client_id(),
+      FileType::Photo(t) => t.client_id(),
+      FileType::ProfilePhoto(t) => t.client_id(),
+      FileType::Secret(t) => t.client_id(),
+      FileType::SecretThumbnail(t) => t.client_id(),
+      FileType::Secure(t) => t.client_id(),
+      FileType::Sticker(t) => t.client_id(),
+      FileType::Thumbnail(t) => t.client_id(),
+      FileType::Unknown(t) => t.client_id(),
+      FileType::Video(t) => t.client_id(),
+      FileType::VideoNote(t) => t.client_id(),
+      FileType::VoiceNote(t) => t.client_id(),
+      FileType::Wallpaper(t) => t.client_id(),
+      FileType::UnknownType(t) => t.client_id(),
 
       _ => None,
     }
@@ -133,25 +159,26 @@ impl RObject for FileType {
 }
 
 impl FileType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let FileType::_Default(_) = self { true } else { false } }
-
-  pub fn is_animation(&self) -> bool { if let FileType::Animation(_) = self { true } else { false } }
-  pub fn is_audio(&self) -> bool { if let FileType::Audio(_) = self { true } else { false } }
-  pub fn is_document(&self) -> bool { if let FileType::Document(_) = self { true } else { false } }
-  pub fn is_none(&self) -> bool { if let FileType::None(_) = self { true } else { false } }
-  pub fn is_photo(&self) -> bool { if let FileType::Photo(_) = self { true } else { false } }
-  pub fn is_profile_photo(&self) -> bool { if let FileType::ProfilePhoto(_) = self { true } else { false } }
-  pub fn is_secret(&self) -> bool { if let FileType::Secret(_) = self { true } else { false } }
-  pub fn is_secret_thumbnail(&self) -> bool { if let FileType::SecretThumbnail(_) = self { true } else { false } }
-  pub fn is_secure(&self) -> bool { if let FileType::Secure(_) = self { true } else { false } }
-  pub fn is_sticker(&self) -> bool { if let FileType::Sticker(_) = self { true } else { false } }
-  pub fn is_thumbnail(&self) -> bool { if let FileType::Thumbnail(_) = self { true } else { false } }
-  pub fn is_unknown(&self) -> bool { if let FileType::Unknown(_) = self { true } else { false } }
-  pub fn is_video(&self) -> bool { if let FileType::Video(_) = self { true } else { false } }
-  pub fn is_video_note(&self) -> bool { if let FileType::VideoNote(_) = self { true } else { false } }
-  pub fn is_voice_note(&self) -> bool { if let FileType::VoiceNote(_) = self { true } else { false } }
-  pub fn is_wallpaper(&self) -> bool { if let FileType::Wallpaper(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, FileType::_Default(_)) }
+
+  pub fn is_animation(&self) -> bool { matches!(self, FileType::Animation(_)) }
+  pub fn is_audio(&self) -> bool { matches!(self, FileType::Audio(_)) }
+  pub fn is_document(&self) -> bool { matches!(self, FileType::Document(_)) }
+  pub fn is_none(&self) -> bool { matches!(self, FileType::None(_)) }
+  pub fn is_photo(&self) -> bool { matches!(self, FileType::Photo(_)) }
+  pub fn is_profile_photo(&self) -> bool { matches!(self, FileType::ProfilePhoto(_)) }
+  pub fn is_secret(&self) -> bool { matches!(self, FileType::Secret(_)) }
+  pub fn is_secret_thumbnail(&self) -> bool { matches!(self, FileType::SecretThumbnail(_)) }
+  pub fn is_secure(&self) -> bool { matches!(self, FileType::Secure(_)) }
+  pub fn is_sticker(&self) -> bool { matches!(self, FileType::Sticker(_)) }
+  pub fn is_thumbnail(&self) -> bool { matches!(self, FileType::Thumbnail(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, FileType::Unknown(_)) }
+  pub fn is_video(&self) -> bool { matches!(self, FileType::Video(_)) }
+  pub fn is_video_note(&self) -> bool { matches!(self, FileType::VideoNote(_)) }
+  pub fn is_voice_note(&self) -> bool { matches!(self, FileType::VoiceNote(_)) }
+  pub fn is_wallpaper(&self) -> bool { matches!(self, FileType::Wallpaper(_)) }
+  pub fn is_unknown_type(&self) -> bool { matches!(self, FileType::UnknownType(_)) }
 
   pub fn on_animation<F: FnOnce(&FileTypeAnimation)>(&self, fnc: F) -> &Self { if let FileType::Animation(t) = self { fnc(t) }; self }
   pub fn on_audio<F: FnOnce(&FileTypeAudio)>(&self, fnc: F) -> &Self { if let FileType::Audio(t) = self { fnc(t) }; self }
@@ -169,6 +196,7 @@ impl FileType {
   pub fn on_video_note<F: FnOnce(&FileTypeVideoNote)>(&self, fnc: F) -> &Self { if let FileType::VideoNote(t) = self { fnc(t) }; self }
   pub fn on_voice_note<F: FnOnce(&FileTypeVoiceNote)>(&self, fnc: F) -> &Self { if let FileType::VoiceNote(t) = self { fnc(t) }; self }
   pub fn on_wallpaper<F: FnOnce(&FileTypeWallpaper)>(&self, fnc: F) -> &Self { if let FileType::Wallpaper(t) = self { fnc(t) }; self }
+  pub fn on_unknown_type<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let FileType::UnknownType(t) = self { fnc(t) }; self }
 
   pub fn as_animation(&self) -> Option<&FileTypeAnimation> { if let FileType::Animation(t) = self { return Some(t) } None }
   pub fn as_audio(&self) -> Option<&FileTypeAudio> { if let FileType::Audio(t) = self { return Some(t) } None }
@@ -186,6 +214,7 @@ impl FileType {
   pub fn as_video_note(&self) -> Option<&FileTypeVideoNote> { if let FileType::VideoNote(t) = self { return Some(t) } None }
   pub fn as_voice_note(&self) -> Option<&FileTypeVoiceNote> { if let FileType::VoiceNote(t) = self { return Some(t) } None }
   pub fn as_wallpaper(&self) -> Option<&FileTypeWallpaper> { if let FileType::Wallpaper(t) = self { return Some(t) } None }
+  pub fn as_unknown_type(&self) -> Option<&UnknownObject> { if let FileType::UnknownType(t) = self { return Some(t) } None }
 
 
 
@@ -241,13 +270,17 @@ pub struct FileTypeAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -257,14 +290,15 @@ impl TDFileType for FileTypeAnimation {}
 
 
 impl FileTypeAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeAnimationBuilder {
-    let mut inner = FileTypeAnimation::default();
-    inner.td_name = "fileTypeAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeAnimation { td_name: "fileTypeAnimation".to_string(), ..Default::default() };
     RTDFileTypeAnimationBuilder { inner }
   }
 
+  /// `FileTypeAnimation` with the fields tdlib requires, the others are set by `FileTypeAnimation::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -275,6 +309,12 @@ pub struct RTDFileTypeAnimationBuilder {
 impl RTDFileTypeAnimationBuilder {
   pub fn build(&self) -> FileTypeAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeAnimation> for FileTypeAnimation {
@@ -285,6 +325,10 @@ impl AsRef<FileTypeAnimation> for RTDFileTypeAnimationBuilder {
   fn as_ref(&self) -> &FileTypeAnimation { &self.inner }
 }
 
+impl PartialEq for FileTypeAnimation {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -299,13 +343,17 @@ pub struct FileTypeAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -315,14 +363,15 @@ impl TDFileType for FileTypeAudio {}
 
 
 impl FileTypeAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeAudioBuilder {
-    let mut inner = FileTypeAudio::default();
-    inner.td_name = "fileTypeAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeAudio { td_name: "fileTypeAudio".to_string(), ..Default::default() };
     RTDFileTypeAudioBuilder { inner }
   }
 
+  /// `FileTypeAudio` with the fields tdlib requires, the others are set by `FileTypeAudio::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -333,6 +382,12 @@ pub struct RTDFileTypeAudioBuilder {
 impl RTDFileTypeAudioBuilder {
   pub fn build(&self) -> FileTypeAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeAudio> for FileTypeAudio {
@@ -343,6 +398,10 @@ impl AsRef<FileTypeAudio> for RTDFileTypeAudioBuilder {
   fn as_ref(&self) -> &FileTypeAudio { &self.inner }
 }
 
+impl PartialEq for FileTypeAudio {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -357,13 +416,17 @@ pub struct FileTypeDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -373,14 +436,15 @@ impl TDFileType for FileTypeDocument {}
 
 
 impl FileTypeDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeDocumentBuilder {
-    let mut inner = FileTypeDocument::default();
-    inner.td_name = "fileTypeDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeDocument { td_name: "fileTypeDocument".to_string(), ..Default::default() };
     RTDFileTypeDocumentBuilder { inner }
   }
 
+  /// `FileTypeDocument` with the fields tdlib requires, the others are set by `FileTypeDocument::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -391,6 +455,12 @@ pub struct RTDFileTypeDocumentBuilder {
 impl RTDFileTypeDocumentBuilder {
   pub fn build(&self) -> FileTypeDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeDocument> for FileTypeDocument {
@@ -401,6 +471,10 @@ impl AsRef<FileTypeDocument> for RTDFileTypeDocumentBuilder {
   fn as_ref(&self) -> &FileTypeDocument { &self.inner }
 }
 
+impl PartialEq for FileTypeDocument {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -415,13 +489,17 @@ pub struct FileTypeNone {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeNone {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeNone" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeNone" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -431,14 +509,15 @@ impl TDFileType for FileTypeNone {}
 
 
 impl FileTypeNone {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeNoneBuilder {
-    let mut inner = FileTypeNone::default();
-    inner.td_name = "fileTypeNone".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeNone { td_name: "fileTypeNone".to_string(), ..Default::default() };
     RTDFileTypeNoneBuilder { inner }
   }
 
+  /// `FileTypeNone` with the fields tdlib requires, the others are set by `FileTypeNone::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -449,6 +528,12 @@ pub struct RTDFileTypeNoneBuilder {
 impl RTDFileTypeNoneBuilder {
   pub fn build(&self) -> FileTypeNone { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeNone> for FileTypeNone {
@@ -459,6 +544,10 @@ impl AsRef<FileTypeNone> for RTDFileTypeNoneBuilder {
   fn as_ref(&self) -> &FileTypeNone { &self.inner }
 }
 
+impl PartialEq for FileTypeNone {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -473,13 +562,17 @@ pub struct FileTypePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -489,14 +582,15 @@ impl TDFileType for FileTypePhoto {}
 
 
 impl FileTypePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypePhotoBuilder {
-    let mut inner = FileTypePhoto::default();
-    inner.td_name = "fileTypePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypePhoto { td_name: "fileTypePhoto".to_string(), ..Default::default() };
     RTDFileTypePhotoBuilder { inner }
   }
 
+  /// `FileTypePhoto` with the fields tdlib requires, the others are set by `FileTypePhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -507,6 +601,12 @@ pub struct RTDFileTypePhotoBuilder {
 impl RTDFileTypePhotoBuilder {
   pub fn build(&self) -> FileTypePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypePhoto> for FileTypePhoto {
@@ -517,6 +617,10 @@ impl AsRef<FileTypePhoto> for RTDFileTypePhotoBuilder {
   fn as_ref(&self) -> &FileTypePhoto { &self.inner }
 }
 
+impl PartialEq for FileTypePhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -531,13 +635,17 @@ pub struct FileTypeProfilePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeProfilePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeProfilePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeProfilePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -547,14 +655,15 @@ impl TDFileType for FileTypeProfilePhoto {}
 
 
 impl FileTypeProfilePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeProfilePhotoBuilder {
-    let mut inner = FileTypeProfilePhoto::default();
-    inner.td_name = "fileTypeProfilePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeProfilePhoto { td_name: "fileTypeProfilePhoto".to_string(), ..Default::default() };
     RTDFileTypeProfilePhotoBuilder { inner }
   }
 
+  /// `FileTypeProfilePhoto` with the fields tdlib requires, the others are set by `FileTypeProfilePhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -565,6 +674,12 @@ pub struct RTDFileTypeProfilePhotoBuilder {
 impl RTDFileTypeProfilePhotoBuilder {
   pub fn build(&self) -> FileTypeProfilePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeProfilePhoto> for FileTypeProfilePhoto {
@@ -575,6 +690,10 @@ impl AsRef<FileTypeProfilePhoto> for RTDFileTypeProfilePhotoBuilder {
   fn as_ref(&self) -> &FileTypeProfilePhoto { &self.inner }
 }
 
+impl PartialEq for FileTypeProfilePhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -589,13 +708,17 @@ pub struct FileTypeSecret {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeSecret {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSecret" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeSecret" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -605,14 +728,15 @@ impl TDFileType for FileTypeSecret {}
 
 
 impl FileTypeSecret {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeSecretBuilder {
-    let mut inner = FileTypeSecret::default();
-    inner.td_name = "fileTypeSecret".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeSecret { td_name: "fileTypeSecret".to_string(), ..Default::default() };
     RTDFileTypeSecretBuilder { inner }
   }
 
+  /// `FileTypeSecret` with the fields tdlib requires, the others are set by `FileTypeSecret::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -623,6 +747,12 @@ pub struct RTDFileTypeSecretBuilder {
 impl RTDFileTypeSecretBuilder {
   pub fn build(&self) -> FileTypeSecret { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSecret> for FileTypeSecret {
@@ -633,6 +763,10 @@ impl AsRef<FileTypeSecret> for RTDFileTypeSecretBuilder {
   fn as_ref(&self) -> &FileTypeSecret { &self.inner }
 }
 
+impl PartialEq for FileTypeSecret {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -647,13 +781,17 @@ pub struct FileTypeSecretThumbnail {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeSecretThumbnail {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSecretThumbnail" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeSecretThumbnail" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -663,14 +801,15 @@ impl TDFileType for FileTypeSecretThumbnail {}
 
 
 impl FileTypeSecretThumbnail {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeSecretThumbnailBuilder {
-    let mut inner = FileTypeSecretThumbnail::default();
-    inner.td_name = "fileTypeSecretThumbnail".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeSecretThumbnail { td_name: "fileTypeSecretThumbnail".to_string(), ..Default::default() };
     RTDFileTypeSecretThumbnailBuilder { inner }
   }
 
+  /// `FileTypeSecretThumbnail` with the fields tdlib requires, the others are set by `FileTypeSecretThumbnail::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -681,6 +820,12 @@ pub struct RTDFileTypeSecretThumbnailBuilder {
 impl RTDFileTypeSecretThumbnailBuilder {
   pub fn build(&self) -> FileTypeSecretThumbnail { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSecretThumbnail> for FileTypeSecretThumbnail {
@@ -691,6 +836,10 @@ impl AsRef<FileTypeSecretThumbnail> for RTDFileTypeSecretThumbnailBuilder {
   fn as_ref(&self) -> &FileTypeSecretThumbnail { &self.inner }
 }
 
+impl PartialEq for FileTypeSecretThumbnail {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -705,13 +854,17 @@ pub struct FileTypeSecure {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeSecure {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSecure" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeSecure" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -721,14 +874,15 @@ impl TDFileType for FileTypeSecure {}
 
 
 impl FileTypeSecure {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeSecureBuilder {
-    let mut inner = FileTypeSecure::default();
-    inner.td_name = "fileTypeSecure".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeSecure { td_name: "fileTypeSecure".to_string(), ..Default::default() };
     RTDFileTypeSecureBuilder { inner }
   }
 
+  /// `FileTypeSecure` with the fields tdlib requires, the others are set by `FileTypeSecure::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -739,6 +893,12 @@ pub struct RTDFileTypeSecureBuilder {
 impl RTDFileTypeSecureBuilder {
   pub fn build(&self) -> FileTypeSecure { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSecure> for FileTypeSecure {
@@ -749,6 +909,10 @@ impl AsRef<FileTypeSecure> for RTDFileTypeSecureBuilder {
   fn as_ref(&self) -> &FileTypeSecure { &self.inner }
 }
 
+impl PartialEq for FileTypeSecure {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -763,13 +927,17 @@ pub struct FileTypeSticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeSticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeSticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -779,14 +947,15 @@ impl TDFileType for FileTypeSticker {}
 
 
 impl FileTypeSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeStickerBuilder {
-    let mut inner = FileTypeSticker::default();
-    inner.td_name = "fileTypeSticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeSticker { td_name: "fileTypeSticker".to_string(), ..Default::default() };
     RTDFileTypeStickerBuilder { inner }
   }
 
+  /// `FileTypeSticker` with the fields tdlib requires, the others are set by `FileTypeSticker::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -797,6 +966,12 @@ pub struct RTDFileTypeStickerBuilder {
 impl RTDFileTypeStickerBuilder {
   pub fn build(&self) -> FileTypeSticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeSticker> for FileTypeSticker {
@@ -807,6 +982,10 @@ impl AsRef<FileTypeSticker> for RTDFileTypeStickerBuilder {
   fn as_ref(&self) -> &FileTypeSticker { &self.inner }
 }
 
+impl PartialEq for FileTypeSticker {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -821,13 +1000,17 @@ pub struct FileTypeThumbnail {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeThumbnail {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeThumbnail" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeThumbnail" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -837,14 +1020,15 @@ impl TDFileType for FileTypeThumbnail {}
 
 
 impl FileTypeThumbnail {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeThumbnailBuilder {
-    let mut inner = FileTypeThumbnail::default();
-    inner.td_name = "fileTypeThumbnail".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeThumbnail { td_name: "fileTypeThumbnail".to_string(), ..Default::default() };
     RTDFileTypeThumbnailBuilder { inner }
   }
 
+  /// `FileTypeThumbnail` with the fields tdlib requires, the others are set by `FileTypeThumbnail::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -855,6 +1039,12 @@ pub struct RTDFileTypeThumbnailBuilder {
 impl RTDFileTypeThumbnailBuilder {
   pub fn build(&self) -> FileTypeThumbnail { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeThumbnail> for FileTypeThumbnail {
@@ -865,6 +1055,10 @@ impl AsRef<FileTypeThumbnail> for RTDFileTypeThumbnailBuilder {
   fn as_ref(&self) -> &FileTypeThumbnail { &self.inner }
 }
 
+impl PartialEq for FileTypeThumbnail {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -879,13 +1073,17 @@ pub struct FileTypeUnknown {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeUnknown {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeUnknown" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeUnknown" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -895,14 +1093,15 @@ impl TDFileType for FileTypeUnknown {}
 
 
 impl FileTypeUnknown {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeUnknownBuilder {
-    let mut inner = FileTypeUnknown::default();
-    inner.td_name = "fileTypeUnknown".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeUnknown { td_name: "fileTypeUnknown".to_string(), ..Default::default() };
     RTDFileTypeUnknownBuilder { inner }
   }
 
+  /// `FileTypeUnknown` with the fields tdlib requires, the others are set by `FileTypeUnknown::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -913,6 +1112,12 @@ pub struct RTDFileTypeUnknownBuilder {
 impl RTDFileTypeUnknownBuilder {
   pub fn build(&self) -> FileTypeUnknown { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeUnknown> for FileTypeUnknown {
@@ -923,6 +1128,10 @@ impl AsRef<FileTypeUnknown> for RTDFileTypeUnknownBuilder {
   fn as_ref(&self) -> &FileTypeUnknown { &self.inner }
 }
 
+impl PartialEq for FileTypeUnknown {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -937,13 +1146,17 @@ pub struct FileTypeVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -953,14 +1166,15 @@ impl TDFileType for FileTypeVideo {}
 
 
 impl FileTypeVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeVideoBuilder {
-    let mut inner = FileTypeVideo::default();
-    inner.td_name = "fileTypeVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeVideo { td_name: "fileTypeVideo".to_string(), ..Default::default() };
     RTDFileTypeVideoBuilder { inner }
   }
 
+  /// `FileTypeVideo` with the fields tdlib requires, the others are set by `FileTypeVideo::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -971,6 +1185,12 @@ pub struct RTDFileTypeVideoBuilder {
 impl RTDFileTypeVideoBuilder {
   pub fn build(&self) -> FileTypeVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeVideo> for FileTypeVideo {
@@ -981,6 +1201,10 @@ impl AsRef<FileTypeVideo> for RTDFileTypeVideoBuilder {
   fn as_ref(&self) -> &FileTypeVideo { &self.inner }
 }
 
+impl PartialEq for FileTypeVideo {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -995,13 +1219,17 @@ pub struct FileTypeVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1011,14 +1239,15 @@ impl TDFileType for FileTypeVideoNote {}
 
 
 impl FileTypeVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeVideoNoteBuilder {
-    let mut inner = FileTypeVideoNote::default();
-    inner.td_name = "fileTypeVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeVideoNote { td_name: "fileTypeVideoNote".to_string(), ..Default::default() };
     RTDFileTypeVideoNoteBuilder { inner }
   }
 
+  /// `FileTypeVideoNote` with the fields tdlib requires, the others are set by `FileTypeVideoNote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1029,6 +1258,12 @@ pub struct RTDFileTypeVideoNoteBuilder {
 impl RTDFileTypeVideoNoteBuilder {
   pub fn build(&self) -> FileTypeVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeVideoNote> for FileTypeVideoNote {
@@ -1039,6 +1274,10 @@ impl AsRef<FileTypeVideoNote> for RTDFileTypeVideoNoteBuilder {
   fn as_ref(&self) -> &FileTypeVideoNote { &self.inner }
 }
 
+impl PartialEq for FileTypeVideoNote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1053,13 +1292,17 @@ pub struct FileTypeVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1069,14 +1312,15 @@ impl TDFileType for FileTypeVoiceNote {}
 
 
 impl FileTypeVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeVoiceNoteBuilder {
-    let mut inner = FileTypeVoiceNote::default();
-    inner.td_name = "fileTypeVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeVoiceNote { td_name: "fileTypeVoiceNote".to_string(), ..Default::default() };
     RTDFileTypeVoiceNoteBuilder { inner }
   }
 
+  /// `FileTypeVoiceNote` with the fields tdlib requires, the others are set by `FileTypeVoiceNote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1087,6 +1331,12 @@ pub struct RTDFileTypeVoiceNoteBuilder {
 impl RTDFileTypeVoiceNoteBuilder {
   pub fn build(&self) -> FileTypeVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeVoiceNote> for FileTypeVoiceNote {
@@ -1097,6 +1347,10 @@ impl AsRef<FileTypeVoiceNote> for RTDFileTypeVoiceNoteBuilder {
   fn as_ref(&self) -> &FileTypeVoiceNote { &self.inner }
 }
 
+impl PartialEq for FileTypeVoiceNote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1111,13 +1365,17 @@ pub struct FileTypeWallpaper {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for FileTypeWallpaper {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "fileTypeWallpaper" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "fileTypeWallpaper" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1127,14 +1385,15 @@ impl TDFileType for FileTypeWallpaper {}
 
 
 impl FileTypeWallpaper {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFileTypeWallpaperBuilder {
-    let mut inner = FileTypeWallpaper::default();
-    inner.td_name = "fileTypeWallpaper".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FileTypeWallpaper { td_name: "fileTypeWallpaper".to_string(), ..Default::default() };
     RTDFileTypeWallpaperBuilder { inner }
   }
 
+  /// `FileTypeWallpaper` with the fields tdlib requires, the others are set by `FileTypeWallpaper::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1145,6 +1404,12 @@ pub struct RTDFileTypeWallpaperBuilder {
 impl RTDFileTypeWallpaperBuilder {
   pub fn build(&self) -> FileTypeWallpaper { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<FileTypeWallpaper> for FileTypeWallpaper {
@@ -1155,5 +1420,9 @@ impl AsRef<FileTypeWallpaper> for RTDFileTypeWallpaperBuilder {
   fn as_ref(&self) -> &FileTypeWallpaper { &self.inner }
 }
 
+impl PartialEq for FileTypeWallpaper {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
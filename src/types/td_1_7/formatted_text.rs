@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct FormattedText {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The text
   text: String,
   /// Entities contained in the text. Entities can be nested, but must not mutually intersect with each other. Pre, Code and PreCode entities can't contain other entities. Bold, Italic, Underline and Strikethrough entities can contain and to be contained in all other entities. All other entities can't contain each other
@@ -23,22 +25,24 @@ pub struct FormattedText {
 }
 
 impl RObject for FormattedText {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "formattedText" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "formattedText" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl FormattedText {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFormattedTextBuilder {
-    let mut inner = FormattedText::default();
-    inner.td_name = "formattedText".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FormattedText { td_name: "formattedText".to_string(), ..Default::default() };
     RTDFormattedTextBuilder { inner }
   }
 
+  /// `FormattedText` with the fields tdlib requires, the others are set by `FormattedText::builder()`
+  pub fn new(text: impl AsRef<str>, entities: Vec<TextEntity>) -> Self { Self::builder().text(text).entities(entities).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
   pub fn entities(&self) -> &Vec<TextEntity> { &self.entities }
@@ -53,6 +57,12 @@ pub struct RTDFormattedTextBuilder {
 impl RTDFormattedTextBuilder {
   pub fn build(&self) -> FormattedText { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<FormattedText> for RTDFormattedTextBuilder {
   fn as_ref(&self) -> &FormattedText { &self.inner }
 }
 
+impl PartialEq for FormattedText {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.entities == other.entities
+  }
+}
+
 
 
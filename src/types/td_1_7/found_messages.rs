@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct FoundMessages {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Approximate total count of messages found; 1 if unknown
   total_count: i64,
   /// List of messages
@@ -25,22 +27,24 @@ pub struct FoundMessages {
 }
 
 impl RObject for FoundMessages {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "foundMessages" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "foundMessages" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl FoundMessages {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDFoundMessagesBuilder {
-    let mut inner = FoundMessages::default();
-    inner.td_name = "foundMessages".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = FoundMessages { td_name: "foundMessages".to_string(), ..Default::default() };
     RTDFoundMessagesBuilder { inner }
   }
 
+  /// `FoundMessages` with the fields tdlib requires, the others are set by `FoundMessages::builder()`
+  pub fn new(total_count: i64, messages: Vec<Message>, next_offset: impl AsRef<str>) -> Self { Self::builder().total_count(total_count).messages(messages).next_offset(next_offset).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
   pub fn messages(&self) -> &Vec<Message> { &self.messages }
@@ -57,6 +61,12 @@ pub struct RTDFoundMessagesBuilder {
 impl RTDFoundMessagesBuilder {
   pub fn build(&self) -> FoundMessages { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -85,5 +95,13 @@ impl AsRef<FoundMessages> for RTDFoundMessagesBuilder {
   fn as_ref(&self) -> &FoundMessages { &self.inner }
 }
 
+impl PartialEq for FoundMessages {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.messages == other.messages
+      && self.next_offset == other.next_offset
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,16 +13,19 @@ pub struct Game {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Game ID
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Game short name. To share a game use the URL https://t.me/{bot_username}?game={game_short_name}
   short_name: String,
   /// Game title
   title: String,
   /// Game text, usually containing scoreboards for a game
   text: FormattedText,
-  /// Describes a game
+  /// Game description
   description: String,
   /// Game photo
   photo: Photo,
@@ -33,23 +35,41 @@ pub struct Game {
 }
 
 impl RObject for Game {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "game" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "game" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Game {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGameBuilder {
-    let mut inner = Game::default();
-    inner.td_name = "game".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Game { td_name: "game".to_string(), ..Default::default() };
     RTDGameBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `Game` with the fields tdlib requires, the others are set by `Game::builder()`
+  pub fn new(
+    id: i64,
+    short_name: impl AsRef<str>,
+    title: impl AsRef<str>,
+    text: impl AsRef<FormattedText>,
+    description: impl AsRef<str>,
+    photo: impl AsRef<Photo>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .short_name(short_name)
+      .title(title)
+      .text(text)
+      .description(description)
+      .photo(photo)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn short_name(&self) -> &String { &self.short_name }
 
@@ -73,8 +93,14 @@ pub struct RTDGameBuilder {
 impl RTDGameBuilder {
   pub fn build(&self) -> Game { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -125,5 +151,17 @@ impl AsRef<Game> for RTDGameBuilder {
   fn as_ref(&self) -> &Game { &self.inner }
 }
 
+impl PartialEq for Game {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.short_name == other.short_name
+      && self.title == other.title
+      && self.text == other.text
+      && self.description == other.description
+      && self.photo == other.photo
+      && self.animation == other.animation
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,36 +13,41 @@ pub struct GameHighScore {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Position in the high score table
   position: i64,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   /// User score
   score: i64,
   
 }
 
 impl RObject for GameHighScore {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "gameHighScore" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "gameHighScore" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GameHighScore {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGameHighScoreBuilder {
-    let mut inner = GameHighScore::default();
-    inner.td_name = "gameHighScore".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GameHighScore { td_name: "gameHighScore".to_string(), ..Default::default() };
     RTDGameHighScoreBuilder { inner }
   }
 
+  /// `GameHighScore` with the fields tdlib requires, the others are set by `GameHighScore::builder()`
+  pub fn new(position: i64, user_id: UserId, score: i64) -> Self { Self::builder().position(position).user_id(user_id).score(score).build() }
+
   pub fn position(&self) -> i64 { self.position }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn score(&self) -> i64 { self.score }
 
@@ -57,6 +61,12 @@ pub struct RTDGameHighScoreBuilder {
 impl RTDGameHighScoreBuilder {
   pub fn build(&self) -> GameHighScore { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn position(&mut self, position: i64) -> &mut Self {
     self.inner.position = position;
@@ -64,7 +74,7 @@ impl RTDGameHighScoreBuilder {
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<GameHighScore> for RTDGameHighScoreBuilder {
   fn as_ref(&self) -> &GameHighScore { &self.inner }
 }
 
+impl PartialEq for GameHighScore {
+  fn eq(&self, other: &Self) -> bool {
+    self.position == other.position
+      && self.user_id == other.user_id
+      && self.score == other.score
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct GameHighScores {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of game high scores
   scores: Vec<GameHighScore>,
   
 }
 
 impl RObject for GameHighScores {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "gameHighScores" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "gameHighScores" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GameHighScores {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGameHighScoresBuilder {
-    let mut inner = GameHighScores::default();
-    inner.td_name = "gameHighScores".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GameHighScores { td_name: "gameHighScores".to_string(), ..Default::default() };
     RTDGameHighScoresBuilder { inner }
   }
 
+  /// `GameHighScores` with the fields tdlib requires, the others are set by `GameHighScores::builder()`
+  pub fn new(scores: Vec<GameHighScore>) -> Self { Self::builder().scores(scores).build() }
+
   pub fn scores(&self) -> &Vec<GameHighScore> { &self.scores }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDGameHighScoresBuilder {
 impl RTDGameHighScoresBuilder {
   pub fn build(&self) -> GameHighScores { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn scores(&mut self, scores: Vec<GameHighScore>) -> &mut Self {
     self.inner.scores = scores;
@@ -65,5 +75,11 @@ impl AsRef<GameHighScores> for RTDGameHighScoresBuilder {
   fn as_ref(&self) -> &GameHighScores { &self.inner }
 }
 
+impl PartialEq for GameHighScores {
+  fn eq(&self, other: &Self) -> bool {
+    self.scores == other.scores
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct GroupCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Group call identifier
   id: i64,
   /// Group call title
@@ -57,22 +59,67 @@ pub struct GroupCall {
 }
 
 impl RObject for GroupCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GroupCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallBuilder {
-    let mut inner = GroupCall::default();
-    inner.td_name = "groupCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCall { td_name: "groupCall".to_string(), ..Default::default() };
     RTDGroupCallBuilder { inner }
   }
 
+  /// `GroupCall` with the fields tdlib requires, the others are set by `GroupCall::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: i64,
+    title: impl AsRef<str>,
+    scheduled_start_date: i64,
+    enabled_start_notification: bool,
+    is_active: bool,
+    is_joined: bool,
+    need_rejoin: bool,
+    can_be_managed: bool,
+    participant_count: i64,
+    loaded_all_participants: bool,
+    recent_speakers: Vec<GroupCallRecentSpeaker>,
+    is_my_video_enabled: bool,
+    is_my_video_paused: bool,
+    can_enable_video: bool,
+    mute_new_participants: bool,
+    can_toggle_mute_new_participants: bool,
+    record_duration: i64,
+    is_video_recorded: bool,
+    duration: i64,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .scheduled_start_date(scheduled_start_date)
+      .enabled_start_notification(enabled_start_notification)
+      .is_active(is_active)
+      .is_joined(is_joined)
+      .need_rejoin(need_rejoin)
+      .can_be_managed(can_be_managed)
+      .participant_count(participant_count)
+      .loaded_all_participants(loaded_all_participants)
+      .recent_speakers(recent_speakers)
+      .is_my_video_enabled(is_my_video_enabled)
+      .is_my_video_paused(is_my_video_paused)
+      .can_enable_video(can_enable_video)
+      .mute_new_participants(mute_new_participants)
+      .can_toggle_mute_new_participants(can_toggle_mute_new_participants)
+      .record_duration(record_duration)
+      .is_video_recorded(is_video_recorded)
+      .duration(duration)
+      .build()
+  }
+
   pub fn id(&self) -> i64 { self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -121,6 +168,12 @@ pub struct RTDGroupCallBuilder {
 impl RTDGroupCallBuilder {
   pub fn build(&self) -> GroupCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -245,5 +298,29 @@ impl AsRef<GroupCall> for RTDGroupCallBuilder {
   fn as_ref(&self) -> &GroupCall { &self.inner }
 }
 
+impl PartialEq for GroupCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.scheduled_start_date == other.scheduled_start_date
+      && self.enabled_start_notification == other.enabled_start_notification
+      && self.is_active == other.is_active
+      && self.is_joined == other.is_joined
+      && self.need_rejoin == other.need_rejoin
+      && self.can_be_managed == other.can_be_managed
+      && self.participant_count == other.participant_count
+      && self.loaded_all_participants == other.loaded_all_participants
+      && self.recent_speakers == other.recent_speakers
+      && self.is_my_video_enabled == other.is_my_video_enabled
+      && self.is_my_video_paused == other.is_my_video_paused
+      && self.can_enable_video == other.can_enable_video
+      && self.mute_new_participants == other.mute_new_participants
+      && self.can_toggle_mute_new_participants == other.can_toggle_mute_new_participants
+      && self.record_duration == other.record_duration
+      && self.is_video_recorded == other.is_video_recorded
+      && self.duration == other.duration
+  }
+}
+
 
 
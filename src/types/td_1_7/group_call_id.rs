@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct GroupCallId {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Group call identifier
   id: i64,
   
 }
 
 impl RObject for GroupCallId {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallId" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallId" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GroupCallId {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallIdBuilder {
-    let mut inner = GroupCallId::default();
-    inner.td_name = "groupCallId".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallId { td_name: "groupCallId".to_string(), ..Default::default() };
     RTDGroupCallIdBuilder { inner }
   }
 
+  /// `GroupCallId` with the fields tdlib requires, the others are set by `GroupCallId::builder()`
+  pub fn new(id: i64) -> Self { Self::builder().id(id).build() }
+
   pub fn id(&self) -> i64 { self.id }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDGroupCallIdBuilder {
 impl RTDGroupCallIdBuilder {
   pub fn build(&self) -> GroupCallId { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -65,5 +75,11 @@ impl AsRef<GroupCallId> for RTDGroupCallIdBuilder {
   fn as_ref(&self) -> &GroupCallId { &self.inner }
 }
 
+impl PartialEq for GroupCallId {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct GroupCallParticipant {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the group call participant
   participant_id: MessageSender,
   /// User's audio channel synchronization source identifier
@@ -55,22 +57,61 @@ pub struct GroupCallParticipant {
 }
 
 impl RObject for GroupCallParticipant {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallParticipant" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallParticipant" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GroupCallParticipant {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallParticipantBuilder {
-    let mut inner = GroupCallParticipant::default();
-    inner.td_name = "groupCallParticipant".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallParticipant { td_name: "groupCallParticipant".to_string(), ..Default::default() };
     RTDGroupCallParticipantBuilder { inner }
   }
 
+  /// `GroupCallParticipant` with the fields tdlib requires, the others are set by `GroupCallParticipant::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    participant_id: impl AsRef<MessageSender>,
+    audio_source_id: i64,
+    screen_sharing_audio_source_id: i64,
+    bio: impl AsRef<str>,
+    is_current_user: bool,
+    is_speaking: bool,
+    is_hand_raised: bool,
+    can_be_muted_for_all_users: bool,
+    can_be_unmuted_for_all_users: bool,
+    can_be_muted_for_current_user: bool,
+    can_be_unmuted_for_current_user: bool,
+    is_muted_for_all_users: bool,
+    is_muted_for_current_user: bool,
+    can_unmute_self: bool,
+    volume_level: i64,
+    order: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .participant_id(participant_id)
+      .audio_source_id(audio_source_id)
+      .screen_sharing_audio_source_id(screen_sharing_audio_source_id)
+      .bio(bio)
+      .is_current_user(is_current_user)
+      .is_speaking(is_speaking)
+      .is_hand_raised(is_hand_raised)
+      .can_be_muted_for_all_users(can_be_muted_for_all_users)
+      .can_be_unmuted_for_all_users(can_be_unmuted_for_all_users)
+      .can_be_muted_for_current_user(can_be_muted_for_current_user)
+      .can_be_unmuted_for_current_user(can_be_unmuted_for_current_user)
+      .is_muted_for_all_users(is_muted_for_all_users)
+      .is_muted_for_current_user(is_muted_for_current_user)
+      .can_unmute_self(can_unmute_self)
+      .volume_level(volume_level)
+      .order(order)
+      .build()
+  }
+
   pub fn participant_id(&self) -> &MessageSender { &self.participant_id }
 
   pub fn audio_source_id(&self) -> i64 { self.audio_source_id }
@@ -117,6 +158,12 @@ pub struct RTDGroupCallParticipantBuilder {
 impl RTDGroupCallParticipantBuilder {
   pub fn build(&self) -> GroupCallParticipant { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn participant_id<T: AsRef<MessageSender>>(&mut self, participant_id: T) -> &mut Self {
     self.inner.participant_id = participant_id.as_ref().clone();
@@ -235,5 +282,28 @@ impl AsRef<GroupCallParticipant> for RTDGroupCallParticipantBuilder {
   fn as_ref(&self) -> &GroupCallParticipant { &self.inner }
 }
 
+impl PartialEq for GroupCallParticipant {
+  fn eq(&self, other: &Self) -> bool {
+    self.participant_id == other.participant_id
+      && self.audio_source_id == other.audio_source_id
+      && self.screen_sharing_audio_source_id == other.screen_sharing_audio_source_id
+      && self.video_info == other.video_info
+      && self.screen_sharing_video_info == other.screen_sharing_video_info
+      && self.bio == other.bio
+      && self.is_current_user == other.is_current_user
+      && self.is_speaking == other.is_speaking
+      && self.is_hand_raised == other.is_hand_raised
+      && self.can_be_muted_for_all_users == other.can_be_muted_for_all_users
+      && self.can_be_unmuted_for_all_users == other.can_be_unmuted_for_all_users
+      && self.can_be_muted_for_current_user == other.can_be_muted_for_current_user
+      && self.can_be_unmuted_for_current_user == other.can_be_unmuted_for_current_user
+      && self.is_muted_for_all_users == other.is_muted_for_all_users
+      && self.is_muted_for_current_user == other.is_muted_for_current_user
+      && self.can_unmute_self == other.can_unmute_self
+      && self.volume_level == other.volume_level
+      && self.order == other.order
+  }
+}
+
 
 
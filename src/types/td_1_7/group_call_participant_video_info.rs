@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct GroupCallParticipantVideoInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of synchronization source groups of the video
   source_groups: Vec<GroupCallVideoSourceGroup>,
   /// Video channel endpoint identifier
@@ -25,22 +27,24 @@ pub struct GroupCallParticipantVideoInfo {
 }
 
 impl RObject for GroupCallParticipantVideoInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallParticipantVideoInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallParticipantVideoInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GroupCallParticipantVideoInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallParticipantVideoInfoBuilder {
-    let mut inner = GroupCallParticipantVideoInfo::default();
-    inner.td_name = "groupCallParticipantVideoInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallParticipantVideoInfo { td_name: "groupCallParticipantVideoInfo".to_string(), ..Default::default() };
     RTDGroupCallParticipantVideoInfoBuilder { inner }
   }
 
+  /// `GroupCallParticipantVideoInfo` with the fields tdlib requires, the others are set by `GroupCallParticipantVideoInfo::builder()`
+  pub fn new(source_groups: Vec<GroupCallVideoSourceGroup>, endpoint_id: impl AsRef<str>, is_paused: bool) -> Self { Self::builder().source_groups(source_groups).endpoint_id(endpoint_id).is_paused(is_paused).build() }
+
   pub fn source_groups(&self) -> &Vec<GroupCallVideoSourceGroup> { &self.source_groups }
 
   pub fn endpoint_id(&self) -> &String { &self.endpoint_id }
@@ -57,6 +61,12 @@ pub struct RTDGroupCallParticipantVideoInfoBuilder {
 impl RTDGroupCallParticipantVideoInfoBuilder {
   pub fn build(&self) -> GroupCallParticipantVideoInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn source_groups(&mut self, source_groups: Vec<GroupCallVideoSourceGroup>) -> &mut Self {
     self.inner.source_groups = source_groups;
@@ -85,5 +95,13 @@ impl AsRef<GroupCallParticipantVideoInfo> for RTDGroupCallParticipantVideoInfoBu
   fn as_ref(&self) -> &GroupCallParticipantVideoInfo { &self.inner }
 }
 
+impl PartialEq for GroupCallParticipantVideoInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.source_groups == other.source_groups
+      && self.endpoint_id == other.endpoint_id
+      && self.is_paused == other.is_paused
+  }
+}
+
 
 
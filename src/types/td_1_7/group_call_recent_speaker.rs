@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct GroupCallRecentSpeaker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Group call participant identifier
   participant_id: MessageSender,
   /// True, is the user has spoken recently
@@ -23,22 +25,24 @@ pub struct GroupCallRecentSpeaker {
 }
 
 impl RObject for GroupCallRecentSpeaker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallRecentSpeaker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallRecentSpeaker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GroupCallRecentSpeaker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallRecentSpeakerBuilder {
-    let mut inner = GroupCallRecentSpeaker::default();
-    inner.td_name = "groupCallRecentSpeaker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallRecentSpeaker { td_name: "groupCallRecentSpeaker".to_string(), ..Default::default() };
     RTDGroupCallRecentSpeakerBuilder { inner }
   }
 
+  /// `GroupCallRecentSpeaker` with the fields tdlib requires, the others are set by `GroupCallRecentSpeaker::builder()`
+  pub fn new(participant_id: impl AsRef<MessageSender>, is_speaking: bool) -> Self { Self::builder().participant_id(participant_id).is_speaking(is_speaking).build() }
+
   pub fn participant_id(&self) -> &MessageSender { &self.participant_id }
 
   pub fn is_speaking(&self) -> bool { self.is_speaking }
@@ -53,6 +57,12 @@ pub struct RTDGroupCallRecentSpeakerBuilder {
 impl RTDGroupCallRecentSpeakerBuilder {
   pub fn build(&self) -> GroupCallRecentSpeaker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn participant_id<T: AsRef<MessageSender>>(&mut self, participant_id: T) -> &mut Self {
     self.inner.participant_id = participant_id.as_ref().clone();
@@ -75,5 +85,12 @@ impl AsRef<GroupCallRecentSpeaker> for RTDGroupCallRecentSpeakerBuilder {
   fn as_ref(&self) -> &GroupCallRecentSpeaker { &self.inner }
 }
 
+impl PartialEq for GroupCallRecentSpeaker {
+  fn eq(&self, other: &Self) -> bool {
+    self.participant_id == other.participant_id
+      && self.is_speaking == other.is_speaking
+  }
+}
+
 
 
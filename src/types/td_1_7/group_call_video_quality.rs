@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDGroupCallVideoQuality: Debug + RObject {}
 
 /// Describes the quality of a group call video
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum GroupCallVideoQuality {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum GroupCallVideoQuality {
   Medium(GroupCallVideoQualityMedium),
   /// The worst available video quality
   Thumbnail(GroupCallVideoQualityThumbnail),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for GroupCallVideoQuality {
   fn deserialize<D>(deserializer: D) -> Result<GroupCallVideoQuality, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      GroupCallVideoQuality,
+      GroupCallVideoQuality, Unknown;
       (groupCallVideoQualityFull, Full);
       (groupCallVideoQualityMedium, Medium);
       (groupCallVideoQualityThumbnail, Thumbnail);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for GroupCallVideoQuality {
 }
 
 impl RObject for GroupCallVideoQuality {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       GroupCallVideoQuality::Full(t) => t.td_name(),
       GroupCallVideoQuality::Medium(t) => t.td_name(),
       GroupCallVideoQuality::Thumbnail(t) => t.td_name(),
+      GroupCallVideoQuality::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       GroupCallVideoQuality::Full(t) => t.extra(),
       GroupCallVideoQuality::Medium(t) => t.extra(),
       GroupCallVideoQuality::Thumbnail(t) => t.extra(),
+      GroupCallVideoQuality::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      GroupCallVideoQuality::Full(t) => t.client_id(),
+      GroupCallVideoQuality::Medium(t) => t.client_id(),
+      GroupCallVideoQuality::Thumbnail(t) => t.client_id(),
+      GroupCallVideoQuality::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for GroupCallVideoQuality {
 }
 
 impl GroupCallVideoQuality {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let GroupCallVideoQuality::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, GroupCallVideoQuality::_Default(_)) }
 
-  pub fn is_full(&self) -> bool { if let GroupCallVideoQuality::Full(_) = self { true } else { false } }
-  pub fn is_medium(&self) -> bool { if let GroupCallVideoQuality::Medium(_) = self { true } else { false } }
-  pub fn is_thumbnail(&self) -> bool { if let GroupCallVideoQuality::Thumbnail(_) = self { true } else { false } }
+  pub fn is_full(&self) -> bool { matches!(self, GroupCallVideoQuality::Full(_)) }
+  pub fn is_medium(&self) -> bool { matches!(self, GroupCallVideoQuality::Medium(_)) }
+  pub fn is_thumbnail(&self) -> bool { matches!(self, GroupCallVideoQuality::Thumbnail(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, GroupCallVideoQuality::Unknown(_)) }
 
   pub fn on_full<F: FnOnce(&GroupCallVideoQualityFull)>(&self, fnc: F) -> &Self { if let GroupCallVideoQuality::Full(t) = self { fnc(t) }; self }
   pub fn on_medium<F: FnOnce(&GroupCallVideoQualityMedium)>(&self, fnc: F) -> &Self { if let GroupCallVideoQuality::Medium(t) = self { fnc(t) }; self }
   pub fn on_thumbnail<F: FnOnce(&GroupCallVideoQualityThumbnail)>(&self, fnc: F) -> &Self { if let GroupCallVideoQuality::Thumbnail(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let GroupCallVideoQuality::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_full(&self) -> Option<&GroupCallVideoQualityFull> { if let GroupCallVideoQuality::Full(t) = self { return Some(t) } None }
   pub fn as_medium(&self) -> Option<&GroupCallVideoQualityMedium> { if let GroupCallVideoQuality::Medium(t) = self { return Some(t) } None }
   pub fn as_thumbnail(&self) -> Option<&GroupCallVideoQualityThumbnail> { if let GroupCallVideoQuality::Thumbnail(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let GroupCallVideoQuality::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,13 +127,17 @@ pub struct GroupCallVideoQualityFull {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for GroupCallVideoQualityFull {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallVideoQualityFull" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallVideoQualityFull" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -127,14 +147,15 @@ impl TDGroupCallVideoQuality for GroupCallVideoQualityFull {}
 
 
 impl GroupCallVideoQualityFull {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallVideoQualityFullBuilder {
-    let mut inner = GroupCallVideoQualityFull::default();
-    inner.td_name = "groupCallVideoQualityFull".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallVideoQualityFull { td_name: "groupCallVideoQualityFull".to_string(), ..Default::default() };
     RTDGroupCallVideoQualityFullBuilder { inner }
   }
 
+  /// `GroupCallVideoQualityFull` with the fields tdlib requires, the others are set by `GroupCallVideoQualityFull::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +166,12 @@ pub struct RTDGroupCallVideoQualityFullBuilder {
 impl RTDGroupCallVideoQualityFullBuilder {
   pub fn build(&self) -> GroupCallVideoQualityFull { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GroupCallVideoQualityFull> for GroupCallVideoQualityFull {
@@ -155,6 +182,10 @@ impl AsRef<GroupCallVideoQualityFull> for RTDGroupCallVideoQualityFullBuilder {
   fn as_ref(&self) -> &GroupCallVideoQualityFull { &self.inner }
 }
 
+impl PartialEq for GroupCallVideoQualityFull {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -169,13 +200,17 @@ pub struct GroupCallVideoQualityMedium {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for GroupCallVideoQualityMedium {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallVideoQualityMedium" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallVideoQualityMedium" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -185,14 +220,15 @@ impl TDGroupCallVideoQuality for GroupCallVideoQualityMedium {}
 
 
 impl GroupCallVideoQualityMedium {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallVideoQualityMediumBuilder {
-    let mut inner = GroupCallVideoQualityMedium::default();
-    inner.td_name = "groupCallVideoQualityMedium".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallVideoQualityMedium { td_name: "groupCallVideoQualityMedium".to_string(), ..Default::default() };
     RTDGroupCallVideoQualityMediumBuilder { inner }
   }
 
+  /// `GroupCallVideoQualityMedium` with the fields tdlib requires, the others are set by `GroupCallVideoQualityMedium::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,12 @@ pub struct RTDGroupCallVideoQualityMediumBuilder {
 impl RTDGroupCallVideoQualityMediumBuilder {
   pub fn build(&self) -> GroupCallVideoQualityMedium { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GroupCallVideoQualityMedium> for GroupCallVideoQualityMedium {
@@ -213,6 +255,10 @@ impl AsRef<GroupCallVideoQualityMedium> for RTDGroupCallVideoQualityMediumBuilde
   fn as_ref(&self) -> &GroupCallVideoQualityMedium { &self.inner }
 }
 
+impl PartialEq for GroupCallVideoQualityMedium {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -227,13 +273,17 @@ pub struct GroupCallVideoQualityThumbnail {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for GroupCallVideoQualityThumbnail {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallVideoQualityThumbnail" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallVideoQualityThumbnail" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -243,14 +293,15 @@ impl TDGroupCallVideoQuality for GroupCallVideoQualityThumbnail {}
 
 
 impl GroupCallVideoQualityThumbnail {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallVideoQualityThumbnailBuilder {
-    let mut inner = GroupCallVideoQualityThumbnail::default();
-    inner.td_name = "groupCallVideoQualityThumbnail".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallVideoQualityThumbnail { td_name: "groupCallVideoQualityThumbnail".to_string(), ..Default::default() };
     RTDGroupCallVideoQualityThumbnailBuilder { inner }
   }
 
+  /// `GroupCallVideoQualityThumbnail` with the fields tdlib requires, the others are set by `GroupCallVideoQualityThumbnail::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +312,12 @@ pub struct RTDGroupCallVideoQualityThumbnailBuilder {
 impl RTDGroupCallVideoQualityThumbnailBuilder {
   pub fn build(&self) -> GroupCallVideoQualityThumbnail { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<GroupCallVideoQualityThumbnail> for GroupCallVideoQualityThumbnail {
@@ -271,5 +328,9 @@ impl AsRef<GroupCallVideoQualityThumbnail> for RTDGroupCallVideoQualityThumbnail
   fn as_ref(&self) -> &GroupCallVideoQualityThumbnail { &self.inner }
 }
 
+impl PartialEq for GroupCallVideoQualityThumbnail {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
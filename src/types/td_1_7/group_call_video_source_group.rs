@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct GroupCallVideoSourceGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The semantics of sources, one of "SIM" or "FID"
   semantics: String,
   /// The list of synchronization source identifiers
@@ -23,22 +25,24 @@ pub struct GroupCallVideoSourceGroup {
 }
 
 impl RObject for GroupCallVideoSourceGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "groupCallVideoSourceGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "groupCallVideoSourceGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl GroupCallVideoSourceGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDGroupCallVideoSourceGroupBuilder {
-    let mut inner = GroupCallVideoSourceGroup::default();
-    inner.td_name = "groupCallVideoSourceGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = GroupCallVideoSourceGroup { td_name: "groupCallVideoSourceGroup".to_string(), ..Default::default() };
     RTDGroupCallVideoSourceGroupBuilder { inner }
   }
 
+  /// `GroupCallVideoSourceGroup` with the fields tdlib requires, the others are set by `GroupCallVideoSourceGroup::builder()`
+  pub fn new(semantics: impl AsRef<str>, source_ids: Vec<i64>) -> Self { Self::builder().semantics(semantics).source_ids(source_ids).build() }
+
   pub fn semantics(&self) -> &String { &self.semantics }
 
   pub fn source_ids(&self) -> &Vec<i64> { &self.source_ids }
@@ -53,6 +57,12 @@ pub struct RTDGroupCallVideoSourceGroupBuilder {
 impl RTDGroupCallVideoSourceGroupBuilder {
   pub fn build(&self) -> GroupCallVideoSourceGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn semantics<T: AsRef<str>>(&mut self, semantics: T) -> &mut Self {
     self.inner.semantics = semantics.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<GroupCallVideoSourceGroup> for RTDGroupCallVideoSourceGroupBuilder {
   fn as_ref(&self) -> &GroupCallVideoSourceGroup { &self.inner }
 }
 
+impl PartialEq for GroupCallVideoSourceGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.semantics == other.semantics
+      && self.source_ids == other.source_ids
+  }
+}
+
 
 
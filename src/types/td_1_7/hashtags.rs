@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Hashtags {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of hashtags
   hashtags: Vec<String>,
   
 }
 
 impl RObject for Hashtags {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "hashtags" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "hashtags" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Hashtags {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDHashtagsBuilder {
-    let mut inner = Hashtags::default();
-    inner.td_name = "hashtags".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Hashtags { td_name: "hashtags".to_string(), ..Default::default() };
     RTDHashtagsBuilder { inner }
   }
 
+  /// `Hashtags` with the fields tdlib requires, the others are set by `Hashtags::builder()`
+  pub fn new(hashtags: Vec<String>) -> Self { Self::builder().hashtags(hashtags).build() }
+
   pub fn hashtags(&self) -> &Vec<String> { &self.hashtags }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDHashtagsBuilder {
 impl RTDHashtagsBuilder {
   pub fn build(&self) -> Hashtags { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn hashtags(&mut self, hashtags: Vec<String>) -> &mut Self {
     self.inner.hashtags = hashtags;
@@ -65,5 +75,11 @@ impl AsRef<Hashtags> for RTDHashtagsBuilder {
   fn as_ref(&self) -> &Hashtags { &self.inner }
 }
 
+impl PartialEq for Hashtags {
+  fn eq(&self, other: &Self) -> bool {
+    self.hashtags == other.hashtags
+  }
+}
+
 
 
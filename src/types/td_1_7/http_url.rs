@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct HttpUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The URL
   url: String,
   
 }
 
 impl RObject for HttpUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "httpUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "httpUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl HttpUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDHttpUrlBuilder {
-    let mut inner = HttpUrl::default();
-    inner.td_name = "httpUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = HttpUrl { td_name: "httpUrl".to_string(), ..Default::default() };
     RTDHttpUrlBuilder { inner }
   }
 
+  /// `HttpUrl` with the fields tdlib requires, the others are set by `HttpUrl::builder()`
+  pub fn new(url: impl AsRef<str>) -> Self { Self::builder().url(url).build() }
+
   pub fn url(&self) -> &String { &self.url }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDHttpUrlBuilder {
 impl RTDHttpUrlBuilder {
   pub fn build(&self) -> HttpUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -65,5 +75,11 @@ impl AsRef<HttpUrl> for RTDHttpUrlBuilder {
   fn as_ref(&self) -> &HttpUrl { &self.inner }
 }
 
+impl PartialEq for HttpUrl {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+  }
+}
+
 
 
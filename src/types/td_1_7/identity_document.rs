@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct IdentityDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Document number; 1-24 characters
   number: String,
   /// Document expiry date; may be null if not applicable
@@ -31,22 +33,24 @@ pub struct IdentityDocument {
 }
 
 impl RObject for IdentityDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "identityDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "identityDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl IdentityDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDIdentityDocumentBuilder {
-    let mut inner = IdentityDocument::default();
-    inner.td_name = "identityDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = IdentityDocument { td_name: "identityDocument".to_string(), ..Default::default() };
     RTDIdentityDocumentBuilder { inner }
   }
 
+  /// `IdentityDocument` with the fields tdlib requires, the others are set by `IdentityDocument::builder()`
+  pub fn new(number: impl AsRef<str>, front_side: impl AsRef<DatedFile>, translation: Vec<DatedFile>) -> Self { Self::builder().number(number).front_side(front_side).translation(translation).build() }
+
   pub fn number(&self) -> &String { &self.number }
 
   pub fn expiry_date(&self) -> &Option<Date> { &self.expiry_date }
@@ -69,6 +73,12 @@ pub struct RTDIdentityDocumentBuilder {
 impl RTDIdentityDocumentBuilder {
   pub fn build(&self) -> IdentityDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn number<T: AsRef<str>>(&mut self, number: T) -> &mut Self {
     self.inner.number = number.as_ref().to_string();
@@ -115,5 +125,16 @@ impl AsRef<IdentityDocument> for RTDIdentityDocumentBuilder {
   fn as_ref(&self) -> &IdentityDocument { &self.inner }
 }
 
+impl PartialEq for IdentityDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.number == other.number
+      && self.expiry_date == other.expiry_date
+      && self.front_side == other.front_side
+      && self.reverse_side == other.reverse_side
+      && self.selfie == other.selfie
+      && self.translation == other.translation
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,32 +13,37 @@ pub struct ImportedContacts {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifiers of the imported contacts in the same order as they were specified in the request; 0 if the contact is not yet a registered user
-  user_ids: Vec<i64>,
+  user_ids: Vec<UserId>,
   /// The number of users that imported the corresponding contact; 0 for already registered users or if unavailable
   importer_count: Vec<i64>,
   
 }
 
 impl RObject for ImportedContacts {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "importedContacts" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "importedContacts" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ImportedContacts {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDImportedContactsBuilder {
-    let mut inner = ImportedContacts::default();
-    inner.td_name = "importedContacts".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ImportedContacts { td_name: "importedContacts".to_string(), ..Default::default() };
     RTDImportedContactsBuilder { inner }
   }
 
-  pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
+  /// `ImportedContacts` with the fields tdlib requires, the others are set by `ImportedContacts::builder()`
+  pub fn new(user_ids: Vec<UserId>, importer_count: Vec<i64>) -> Self { Self::builder().user_ids(user_ids).importer_count(importer_count).build() }
+
+  pub fn user_ids(&self) -> &Vec<UserId> { &self.user_ids }
 
   pub fn importer_count(&self) -> &Vec<i64> { &self.importer_count }
 
@@ -53,8 +57,14 @@ pub struct RTDImportedContactsBuilder {
 impl RTDImportedContactsBuilder {
   pub fn build(&self) -> ImportedContacts { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+  pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
     self.inner.user_ids = user_ids;
     self
   }
@@ -75,5 +85,12 @@ impl AsRef<ImportedContacts> for RTDImportedContactsBuilder {
   fn as_ref(&self) -> &ImportedContacts { &self.inner }
 }
 
+impl PartialEq for ImportedContacts {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_ids == other.user_ids
+      && self.importer_count == other.importer_count
+  }
+}
+
 
 
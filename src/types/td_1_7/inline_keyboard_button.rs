@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct InlineKeyboardButton {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text of the button
   text: String,
   /// Type of the button
@@ -23,22 +25,24 @@ pub struct InlineKeyboardButton {
 }
 
 impl RObject for InlineKeyboardButton {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButton" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButton" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl InlineKeyboardButton {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonBuilder {
-    let mut inner = InlineKeyboardButton::default();
-    inner.td_name = "inlineKeyboardButton".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButton { td_name: "inlineKeyboardButton".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonBuilder { inner }
   }
 
+  /// `InlineKeyboardButton` with the fields tdlib requires, the others are set by `InlineKeyboardButton::builder()`
+  pub fn new(text: impl AsRef<str>, type_: impl AsRef<InlineKeyboardButtonType>) -> Self { Self::builder().text(text).type_(type_).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
   pub fn type_(&self) -> &InlineKeyboardButtonType { &self.type_ }
@@ -53,6 +57,12 @@ pub struct RTDInlineKeyboardButtonBuilder {
 impl RTDInlineKeyboardButtonBuilder {
   pub fn build(&self) -> InlineKeyboardButton { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<InlineKeyboardButton> for RTDInlineKeyboardButtonBuilder {
   fn as_ref(&self) -> &InlineKeyboardButton { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButton {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.type_ == other.type_
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInlineKeyboardButtonType: Debug + RObject {}
 
 /// Describes the type of an inline keyboard button
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InlineKeyboardButtonType {
   #[doc(hidden)] _Default(()),
@@ -33,6 +32,8 @@ pub enum InlineKeyboardButtonType {
   SwitchInline(InlineKeyboardButtonTypeSwitchInline),
   /// A button that opens a specified URL
   Url(InlineKeyboardButtonTypeUrl),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -44,7 +45,7 @@ impl<'de> Deserialize<'de> for InlineKeyboardButtonType {
   fn deserialize<D>(deserializer: D) -> Result<InlineKeyboardButtonType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InlineKeyboardButtonType,
+      InlineKeyboardButtonType, Unknown;
       (inlineKeyboardButtonTypeBuy, Buy);
       (inlineKeyboardButtonTypeCallback, Callback);
       (inlineKeyboardButtonTypeCallbackGame, CallbackGame);
@@ -58,7 +59,7 @@ impl<'de> Deserialize<'de> for InlineKeyboardButtonType {
 }
 
 impl RObject for InlineKeyboardButtonType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InlineKeyboardButtonType::Buy(t) => t.td_name(),
       InlineKeyboardButtonType::Callback(t) => t.td_name(),
@@ -67,11 +68,12 @@ impl RObject for InlineKeyboardButtonType {
       InlineKeyboardButtonType::LoginUrl(t) => t.td_name(),
       InlineKeyboardButtonType::SwitchInline(t) => t.td_name(),
       InlineKeyboardButtonType::Url(t) => t.td_name(),
+      InlineKeyboardButtonType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InlineKeyboardButtonType::Buy(t) => t.extra(),
       InlineKeyboardButtonType::Callback(t) => t.extra(),
@@ -80,6 +82,21 @@ impl RObject for InlineKeyboardButtonType {
       InlineKeyboardButtonType::LoginUrl(t) => t.extra(),
       InlineKeyboardButtonType::SwitchInline(t) => t.extra(),
       InlineKeyboardButtonType::Url(t) => t.extra(),
+      InlineKeyboardButtonType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InlineKeyboardButtonType::Buy(t) => t.client_id(),
+      InlineKeyboardButtonType::Callback(t) => t.client_id(),
+      InlineKeyboardButtonType::CallbackGame(t) => t.client_id(),
+      InlineKeyboardButtonType::CallbackWithPassword(t) => t.client_id(),
+      InlineKeyboardButtonType::LoginUrl(t) => t.client_id(),
+      InlineKeyboardButtonType::SwitchInline(t) => t.client_id(),
+      InlineKeyboardButtonType::Url(t) => t.client_id(),
+      InlineKeyboardButtonType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -88,16 +105,17 @@ impl RObject for InlineKeyboardButtonType {
 }
 
 impl InlineKeyboardButtonType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InlineKeyboardButtonType::_Default(_) = self { true } else { false } }
-
-  pub fn is_buy(&self) -> bool { if let InlineKeyboardButtonType::Buy(_) = self { true } else { false } }
-  pub fn is_callback(&self) -> bool { if let InlineKeyboardButtonType::Callback(_) = self { true } else { false } }
-  pub fn is_callback_game(&self) -> bool { if let InlineKeyboardButtonType::CallbackGame(_) = self { true } else { false } }
-  pub fn is_callback_with_password(&self) -> bool { if let InlineKeyboardButtonType::CallbackWithPassword(_) = self { true } else { false } }
-  pub fn is_login_url(&self) -> bool { if let InlineKeyboardButtonType::LoginUrl(_) = self { true } else { false } }
-  pub fn is_switch_inline(&self) -> bool { if let InlineKeyboardButtonType::SwitchInline(_) = self { true } else { false } }
-  pub fn is_url(&self) -> bool { if let InlineKeyboardButtonType::Url(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InlineKeyboardButtonType::_Default(_)) }
+
+  pub fn is_buy(&self) -> bool { matches!(self, InlineKeyboardButtonType::Buy(_)) }
+  pub fn is_callback(&self) -> bool { matches!(self, InlineKeyboardButtonType::Callback(_)) }
+  pub fn is_callback_game(&self) -> bool { matches!(self, InlineKeyboardButtonType::CallbackGame(_)) }
+  pub fn is_callback_with_password(&self) -> bool { matches!(self, InlineKeyboardButtonType::CallbackWithPassword(_)) }
+  pub fn is_login_url(&self) -> bool { matches!(self, InlineKeyboardButtonType::LoginUrl(_)) }
+  pub fn is_switch_inline(&self) -> bool { matches!(self, InlineKeyboardButtonType::SwitchInline(_)) }
+  pub fn is_url(&self) -> bool { matches!(self, InlineKeyboardButtonType::Url(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InlineKeyboardButtonType::Unknown(_)) }
 
   pub fn on_buy<F: FnOnce(&InlineKeyboardButtonTypeBuy)>(&self, fnc: F) -> &Self { if let InlineKeyboardButtonType::Buy(t) = self { fnc(t) }; self }
   pub fn on_callback<F: FnOnce(&InlineKeyboardButtonTypeCallback)>(&self, fnc: F) -> &Self { if let InlineKeyboardButtonType::Callback(t) = self { fnc(t) }; self }
@@ -106,6 +124,7 @@ impl InlineKeyboardButtonType {
   pub fn on_login_url<F: FnOnce(&InlineKeyboardButtonTypeLoginUrl)>(&self, fnc: F) -> &Self { if let InlineKeyboardButtonType::LoginUrl(t) = self { fnc(t) }; self }
   pub fn on_switch_inline<F: FnOnce(&InlineKeyboardButtonTypeSwitchInline)>(&self, fnc: F) -> &Self { if let InlineKeyboardButtonType::SwitchInline(t) = self { fnc(t) }; self }
   pub fn on_url<F: FnOnce(&InlineKeyboardButtonTypeUrl)>(&self, fnc: F) -> &Self { if let InlineKeyboardButtonType::Url(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InlineKeyboardButtonType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_buy(&self) -> Option<&InlineKeyboardButtonTypeBuy> { if let InlineKeyboardButtonType::Buy(t) = self { return Some(t) } None }
   pub fn as_callback(&self) -> Option<&InlineKeyboardButtonTypeCallback> { if let InlineKeyboardButtonType::Callback(t) = self { return Some(t) } None }
@@ -114,6 +133,7 @@ impl InlineKeyboardButtonType {
   pub fn as_login_url(&self) -> Option<&InlineKeyboardButtonTypeLoginUrl> { if let InlineKeyboardButtonType::LoginUrl(t) = self { return Some(t) } None }
   pub fn as_switch_inline(&self) -> Option<&InlineKeyboardButtonTypeSwitchInline> { if let InlineKeyboardButtonType::SwitchInline(t) = self { return Some(t) } None }
   pub fn as_url(&self) -> Option<&InlineKeyboardButtonTypeUrl> { if let InlineKeyboardButtonType::Url(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InlineKeyboardButtonType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -151,13 +171,17 @@ pub struct InlineKeyboardButtonTypeBuy {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeBuy {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeBuy" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButtonTypeBuy" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -167,14 +191,15 @@ impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeBuy {}
 
 
 impl InlineKeyboardButtonTypeBuy {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonTypeBuyBuilder {
-    let mut inner = InlineKeyboardButtonTypeBuy::default();
-    inner.td_name = "inlineKeyboardButtonTypeBuy".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButtonTypeBuy { td_name: "inlineKeyboardButtonTypeBuy".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonTypeBuyBuilder { inner }
   }
 
+  /// `InlineKeyboardButtonTypeBuy` with the fields tdlib requires, the others are set by `InlineKeyboardButtonTypeBuy::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -185,6 +210,12 @@ pub struct RTDInlineKeyboardButtonTypeBuyBuilder {
 impl RTDInlineKeyboardButtonTypeBuyBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeBuy { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InlineKeyboardButtonTypeBuy> for InlineKeyboardButtonTypeBuy {
@@ -195,6 +226,10 @@ impl AsRef<InlineKeyboardButtonTypeBuy> for RTDInlineKeyboardButtonTypeBuyBuilde
   fn as_ref(&self) -> &InlineKeyboardButtonTypeBuy { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeBuy {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -209,15 +244,19 @@ pub struct InlineKeyboardButtonTypeCallback {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Data to be sent to the bot via a callback query
-  data: String,
+  data: Bytes,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeCallback {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeCallback" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButtonTypeCallback" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -227,15 +266,16 @@ impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeCallback {}
 
 
 impl InlineKeyboardButtonTypeCallback {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonTypeCallbackBuilder {
-    let mut inner = InlineKeyboardButtonTypeCallback::default();
-    inner.td_name = "inlineKeyboardButtonTypeCallback".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButtonTypeCallback { td_name: "inlineKeyboardButtonTypeCallback".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonTypeCallbackBuilder { inner }
   }
 
-  pub fn data(&self) -> &String { &self.data }
+  /// `InlineKeyboardButtonTypeCallback` with the fields tdlib requires, the others are set by `InlineKeyboardButtonTypeCallback::builder()`
+  pub fn new(data: impl AsRef<Bytes>) -> Self { Self::builder().data(data).build() }
+
+  pub fn data(&self) -> &Bytes { &self.data }
 
 }
 
@@ -247,9 +287,15 @@ pub struct RTDInlineKeyboardButtonTypeCallbackBuilder {
 impl RTDInlineKeyboardButtonTypeCallbackBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallback { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
     self
   }
 
@@ -263,6 +309,12 @@ impl AsRef<InlineKeyboardButtonTypeCallback> for RTDInlineKeyboardButtonTypeCall
   fn as_ref(&self) -> &InlineKeyboardButtonTypeCallback { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeCallback {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+  }
+}
+
 
 
 
@@ -277,13 +329,17 @@ pub struct InlineKeyboardButtonTypeCallbackGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeCallbackGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeCallbackGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButtonTypeCallbackGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -293,14 +349,15 @@ impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeCallbackGame {}
 
 
 impl InlineKeyboardButtonTypeCallbackGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonTypeCallbackGameBuilder {
-    let mut inner = InlineKeyboardButtonTypeCallbackGame::default();
-    inner.td_name = "inlineKeyboardButtonTypeCallbackGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButtonTypeCallbackGame { td_name: "inlineKeyboardButtonTypeCallbackGame".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonTypeCallbackGameBuilder { inner }
   }
 
+  /// `InlineKeyboardButtonTypeCallbackGame` with the fields tdlib requires, the others are set by `InlineKeyboardButtonTypeCallbackGame::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -311,6 +368,12 @@ pub struct RTDInlineKeyboardButtonTypeCallbackGameBuilder {
 impl RTDInlineKeyboardButtonTypeCallbackGameBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallbackGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InlineKeyboardButtonTypeCallbackGame> for InlineKeyboardButtonTypeCallbackGame {
@@ -321,6 +384,10 @@ impl AsRef<InlineKeyboardButtonTypeCallbackGame> for RTDInlineKeyboardButtonType
   fn as_ref(&self) -> &InlineKeyboardButtonTypeCallbackGame { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeCallbackGame {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -335,15 +402,19 @@ pub struct InlineKeyboardButtonTypeCallbackWithPassword {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Data to be sent to the bot via a callback query
-  data: String,
+  data: Bytes,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeCallbackWithPassword {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeCallbackWithPassword" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButtonTypeCallbackWithPassword" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -353,15 +424,16 @@ impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeCallbackWithPassword
 
 
 impl InlineKeyboardButtonTypeCallbackWithPassword {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder {
-    let mut inner = InlineKeyboardButtonTypeCallbackWithPassword::default();
-    inner.td_name = "inlineKeyboardButtonTypeCallbackWithPassword".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButtonTypeCallbackWithPassword { td_name: "inlineKeyboardButtonTypeCallbackWithPassword".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder { inner }
   }
 
-  pub fn data(&self) -> &String { &self.data }
+  /// `InlineKeyboardButtonTypeCallbackWithPassword` with the fields tdlib requires, the others are set by `InlineKeyboardButtonTypeCallbackWithPassword::builder()`
+  pub fn new(data: impl AsRef<Bytes>) -> Self { Self::builder().data(data).build() }
+
+  pub fn data(&self) -> &Bytes { &self.data }
 
 }
 
@@ -373,9 +445,15 @@ pub struct RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder {
 impl RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallbackWithPassword { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
     self
   }
 
@@ -389,6 +467,12 @@ impl AsRef<InlineKeyboardButtonTypeCallbackWithPassword> for RTDInlineKeyboardBu
   fn as_ref(&self) -> &InlineKeyboardButtonTypeCallbackWithPassword { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeCallbackWithPassword {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+  }
+}
+
 
 
 
@@ -403,7 +487,10 @@ pub struct InlineKeyboardButtonTypeLoginUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// An HTTP URL to open
   url: String,
   /// Unique button identifier
@@ -414,8 +501,9 @@ pub struct InlineKeyboardButtonTypeLoginUrl {
 }
 
 impl RObject for InlineKeyboardButtonTypeLoginUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeLoginUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButtonTypeLoginUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -425,14 +513,15 @@ impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeLoginUrl {}
 
 
 impl InlineKeyboardButtonTypeLoginUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonTypeLoginUrlBuilder {
-    let mut inner = InlineKeyboardButtonTypeLoginUrl::default();
-    inner.td_name = "inlineKeyboardButtonTypeLoginUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButtonTypeLoginUrl { td_name: "inlineKeyboardButtonTypeLoginUrl".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonTypeLoginUrlBuilder { inner }
   }
 
+  /// `InlineKeyboardButtonTypeLoginUrl` with the fields tdlib requires, the others are set by `InlineKeyboardButtonTypeLoginUrl::builder()`
+  pub fn new(url: impl AsRef<str>, id: i64, forward_text: impl AsRef<str>) -> Self { Self::builder().url(url).id(id).forward_text(forward_text).build() }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn id(&self) -> i64 { self.id }
@@ -449,6 +538,12 @@ pub struct RTDInlineKeyboardButtonTypeLoginUrlBuilder {
 impl RTDInlineKeyboardButtonTypeLoginUrlBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeLoginUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -477,6 +572,14 @@ impl AsRef<InlineKeyboardButtonTypeLoginUrl> for RTDInlineKeyboardButtonTypeLogi
   fn as_ref(&self) -> &InlineKeyboardButtonTypeLoginUrl { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeLoginUrl {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.id == other.id
+      && self.forward_text == other.forward_text
+  }
+}
+
 
 
 
@@ -491,7 +594,10 @@ pub struct InlineKeyboardButtonTypeSwitchInline {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Inline query to be sent to the bot
   query: String,
   /// True, if the inline query must be sent from the current chat
@@ -500,8 +606,9 @@ pub struct InlineKeyboardButtonTypeSwitchInline {
 }
 
 impl RObject for InlineKeyboardButtonTypeSwitchInline {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeSwitchInline" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButtonTypeSwitchInline" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -511,14 +618,15 @@ impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeSwitchInline {}
 
 
 impl InlineKeyboardButtonTypeSwitchInline {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
-    let mut inner = InlineKeyboardButtonTypeSwitchInline::default();
-    inner.td_name = "inlineKeyboardButtonTypeSwitchInline".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButtonTypeSwitchInline { td_name: "inlineKeyboardButtonTypeSwitchInline".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonTypeSwitchInlineBuilder { inner }
   }
 
+  /// `InlineKeyboardButtonTypeSwitchInline` with the fields tdlib requires, the others are set by `InlineKeyboardButtonTypeSwitchInline::builder()`
+  pub fn new(query: impl AsRef<str>, in_current_chat: bool) -> Self { Self::builder().query(query).in_current_chat(in_current_chat).build() }
+
   pub fn query(&self) -> &String { &self.query }
 
   pub fn in_current_chat(&self) -> bool { self.in_current_chat }
@@ -533,6 +641,12 @@ pub struct RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
 impl RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeSwitchInline { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -555,6 +669,13 @@ impl AsRef<InlineKeyboardButtonTypeSwitchInline> for RTDInlineKeyboardButtonType
   fn as_ref(&self) -> &InlineKeyboardButtonTypeSwitchInline { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeSwitchInline {
+  fn eq(&self, other: &Self) -> bool {
+    self.query == other.query
+      && self.in_current_chat == other.in_current_chat
+  }
+}
+
 
 
 
@@ -569,15 +690,19 @@ pub struct InlineKeyboardButtonTypeUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// HTTP or tg:// URL to open
   url: String,
   
 }
 
 impl RObject for InlineKeyboardButtonTypeUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineKeyboardButtonTypeUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineKeyboardButtonTypeUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -587,14 +712,15 @@ impl TDInlineKeyboardButtonType for InlineKeyboardButtonTypeUrl {}
 
 
 impl InlineKeyboardButtonTypeUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineKeyboardButtonTypeUrlBuilder {
-    let mut inner = InlineKeyboardButtonTypeUrl::default();
-    inner.td_name = "inlineKeyboardButtonTypeUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineKeyboardButtonTypeUrl { td_name: "inlineKeyboardButtonTypeUrl".to_string(), ..Default::default() };
     RTDInlineKeyboardButtonTypeUrlBuilder { inner }
   }
 
+  /// `InlineKeyboardButtonTypeUrl` with the fields tdlib requires, the others are set by `InlineKeyboardButtonTypeUrl::builder()`
+  pub fn new(url: impl AsRef<str>) -> Self { Self::builder().url(url).build() }
+
   pub fn url(&self) -> &String { &self.url }
 
 }
@@ -607,6 +733,12 @@ pub struct RTDInlineKeyboardButtonTypeUrlBuilder {
 impl RTDInlineKeyboardButtonTypeUrlBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -623,5 +755,11 @@ impl AsRef<InlineKeyboardButtonTypeUrl> for RTDInlineKeyboardButtonTypeUrlBuilde
   fn as_ref(&self) -> &InlineKeyboardButtonTypeUrl { &self.inner }
 }
 
+impl PartialEq for InlineKeyboardButtonTypeUrl {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInlineQueryResult: Debug + RObject {}
 
 /// Represents a single result of an inline query
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InlineQueryResult {
   #[doc(hidden)] _Default(()),
@@ -36,6 +35,7 @@ pub enum InlineQueryResult {
   /// Represents a photo
   Photo(InlineQueryResultPhoto),
   /// Represents a sticker
+  #[cfg(feature = "stickers")]
   Sticker(InlineQueryResultSticker),
   /// Represents information about a venue
   Venue(InlineQueryResultVenue),
@@ -43,6 +43,8 @@ pub enum InlineQueryResult {
   Video(InlineQueryResultVideo),
   /// Represents a voice note
   VoiceNote(InlineQueryResultVoiceNote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -54,7 +56,7 @@ impl<'de> Deserialize<'de> for InlineQueryResult {
   fn deserialize<D>(deserializer: D) -> Result<InlineQueryResult, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InlineQueryResult,
+      InlineQueryResult, Unknown;
       (inlineQueryResultAnimation, Animation);
       (inlineQueryResultArticle, Article);
       (inlineQueryResultAudio, Audio);
@@ -63,7 +65,7 @@ impl<'de> Deserialize<'de> for InlineQueryResult {
       (inlineQueryResultGame, Game);
       (inlineQueryResultLocation, Location);
       (inlineQueryResultPhoto, Photo);
-      (inlineQueryResultSticker, Sticker);
+      #[cfg(feature = "stickers")] (inlineQueryResultSticker, Sticker);
       (inlineQueryResultVenue, Venue);
       (inlineQueryResultVideo, Video);
       (inlineQueryResultVoiceNote, VoiceNote);
@@ -73,7 +75,7 @@ impl<'de> Deserialize<'de> for InlineQueryResult {
 }
 
 impl RObject for InlineQueryResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InlineQueryResult::Animation(t) => t.td_name(),
       InlineQueryResult::Article(t) => t.td_name(),
@@ -83,15 +85,16 @@ impl RObject for InlineQueryResult {
       InlineQueryResult::Game(t) => t.td_name(),
       InlineQueryResult::Location(t) => t.td_name(),
       InlineQueryResult::Photo(t) => t.td_name(),
-      InlineQueryResult::Sticker(t) => t.td_name(),
+      #[cfg(feature = "stickers")] InlineQueryResult::Sticker(t) => t.td_name(),
       InlineQueryResult::Venue(t) => t.td_name(),
       InlineQueryResult::Video(t) => t.td_name(),
       InlineQueryResult::VoiceNote(t) => t.td_name(),
+      InlineQueryResult::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InlineQueryResult::Animation(t) => t.extra(),
       InlineQueryResult::Article(t) => t.extra(),
@@ -101,10 +104,30 @@ impl RObject for InlineQueryResult {
       InlineQueryResult::Game(t) => t.extra(),
       InlineQueryResult::Location(t) => t.extra(),
       InlineQueryResult::Photo(t) => t.extra(),
-      InlineQueryResult::Sticker(t) => t.extra(),
+      #[cfg(feature = "stickers")] InlineQueryResult::Sticker(t) => t.extra(),
       InlineQueryResult::Venue(t) => t.extra(),
       InlineQueryResult::Video(t) => t.extra(),
       InlineQueryResult::VoiceNote(t) => t.extra(),
+      InlineQueryResult::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InlineQueryResult::Animation(t) => t.client_id(),
+      InlineQueryResult::Article(t) => t.client_id(),
+      InlineQueryResult::Audio(t) => t.client_id(),
+      InlineQueryResult::Contact(t) => t.client_id(),
+      InlineQueryResult::Document(t) => t.client_id(),
+      InlineQueryResult::Game(t) => t.client_id(),
+      InlineQueryResult::Location(t) => t.client_id(),
+      InlineQueryResult::Photo(t) => t.client_id(),
+      #[cfg(feature = "stickers")] InlineQueryResult::Sticker(t) => t.client_id(),
+      InlineQueryResult::Venue(t) => t.client_id(),
+      InlineQueryResult::Video(t) => t.client_id(),
+      InlineQueryResult::VoiceNote(t) => t.client_id(),
+      InlineQueryResult::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -113,21 +136,22 @@ impl RObject for InlineQueryResult {
 }
 
 impl InlineQueryResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InlineQueryResult::_Default(_) = self { true } else { false } }
-
-  pub fn is_animation(&self) -> bool { if let InlineQueryResult::Animation(_) = self { true } else { false } }
-  pub fn is_article(&self) -> bool { if let InlineQueryResult::Article(_) = self { true } else { false } }
-  pub fn is_audio(&self) -> bool { if let InlineQueryResult::Audio(_) = self { true } else { false } }
-  pub fn is_contact(&self) -> bool { if let InlineQueryResult::Contact(_) = self { true } else { false } }
-  pub fn is_document(&self) -> bool { if let InlineQueryResult::Document(_) = self { true } else { false } }
-  pub fn is_game(&self) -> bool { if let InlineQueryResult::Game(_) = self { true } else { false } }
-  pub fn is_location(&self) -> bool { if let InlineQueryResult::Location(_) = self { true } else { false } }
-  pub fn is_photo(&self) -> bool { if let InlineQueryResult::Photo(_) = self { true } else { false } }
-  pub fn is_sticker(&self) -> bool { if let InlineQueryResult::Sticker(_) = self { true } else { false } }
-  pub fn is_venue(&self) -> bool { if let InlineQueryResult::Venue(_) = self { true } else { false } }
-  pub fn is_video(&self) -> bool { if let InlineQueryResult::Video(_) = self { true } else { false } }
-  pub fn is_voice_note(&self) -> bool { if let InlineQueryResult::VoiceNote(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InlineQueryResult::_Default(_)) }
+
+  pub fn is_animation(&self) -> bool { matches!(self, InlineQueryResult::Animation(_)) }
+  pub fn is_article(&self) -> bool { matches!(self, InlineQueryResult::Article(_)) }
+  pub fn is_audio(&self) -> bool { matches!(self, InlineQueryResult::Audio(_)) }
+  pub fn is_contact(&self) -> bool { matches!(self, InlineQueryResult::Contact(_)) }
+  pub fn is_document(&self) -> bool { matches!(self, InlineQueryResult::Document(_)) }
+  pub fn is_game(&self) -> bool { matches!(self, InlineQueryResult::Game(_)) }
+  pub fn is_location(&self) -> bool { matches!(self, InlineQueryResult::Location(_)) }
+  pub fn is_photo(&self) -> bool { matches!(self, InlineQueryResult::Photo(_)) }
+  #[cfg(feature = "stickers")] pub fn is_sticker(&self) -> bool { matches!(self, InlineQueryResult::Sticker(_)) }
+  pub fn is_venue(&self) -> bool { matches!(self, InlineQueryResult::Venue(_)) }
+  pub fn is_video(&self) -> bool { matches!(self, InlineQueryResult::Video(_)) }
+  pub fn is_voice_note(&self) -> bool { matches!(self, InlineQueryResult::VoiceNote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InlineQueryResult::Unknown(_)) }
 
   pub fn on_animation<F: FnOnce(&InlineQueryResultAnimation)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Animation(t) = self { fnc(t) }; self }
   pub fn on_article<F: FnOnce(&InlineQueryResultArticle)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Article(t) = self { fnc(t) }; self }
@@ -137,10 +161,11 @@ impl InlineQueryResult {
   pub fn on_game<F: FnOnce(&InlineQueryResultGame)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Game(t) = self { fnc(t) }; self }
   pub fn on_location<F: FnOnce(&InlineQueryResultLocation)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Location(t) = self { fnc(t) }; self }
   pub fn on_photo<F: FnOnce(&InlineQueryResultPhoto)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Photo(t) = self { fnc(t) }; self }
-  pub fn on_sticker<F: FnOnce(&InlineQueryResultSticker)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Sticker(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_sticker<F: FnOnce(&InlineQueryResultSticker)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Sticker(t) = self { fnc(t) }; self }
   pub fn on_venue<F: FnOnce(&InlineQueryResultVenue)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Venue(t) = self { fnc(t) }; self }
   pub fn on_video<F: FnOnce(&InlineQueryResultVideo)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Video(t) = self { fnc(t) }; self }
   pub fn on_voice_note<F: FnOnce(&InlineQueryResultVoiceNote)>(&self, fnc: F) -> &Self { if let InlineQueryResult::VoiceNote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InlineQueryResult::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_animation(&self) -> Option<&InlineQueryResultAnimation> { if let InlineQueryResult::Animation(t) = self { return Some(t) } None }
   pub fn as_article(&self) -> Option<&InlineQueryResultArticle> { if let InlineQueryResult::Article(t) = self { return Some(t) } None }
@@ -150,10 +175,11 @@ impl InlineQueryResult {
   pub fn as_game(&self) -> Option<&InlineQueryResultGame> { if let InlineQueryResult::Game(t) = self { return Some(t) } None }
   pub fn as_location(&self) -> Option<&InlineQueryResultLocation> { if let InlineQueryResult::Location(t) = self { return Some(t) } None }
   pub fn as_photo(&self) -> Option<&InlineQueryResultPhoto> { if let InlineQueryResult::Photo(t) = self { return Some(t) } None }
-  pub fn as_sticker(&self) -> Option<&InlineQueryResultSticker> { if let InlineQueryResult::Sticker(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_sticker(&self) -> Option<&InlineQueryResultSticker> { if let InlineQueryResult::Sticker(t) = self { return Some(t) } None }
   pub fn as_venue(&self) -> Option<&InlineQueryResultVenue> { if let InlineQueryResult::Venue(t) = self { return Some(t) } None }
   pub fn as_video(&self) -> Option<&InlineQueryResultVideo> { if let InlineQueryResult::Video(t) = self { return Some(t) } None }
   pub fn as_voice_note(&self) -> Option<&InlineQueryResultVoiceNote> { if let InlineQueryResult::VoiceNote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InlineQueryResult::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -173,7 +199,7 @@ impl InlineQueryResult {
 
   pub fn photo<T: AsRef<InlineQueryResultPhoto>>(t: T) -> Self { InlineQueryResult::Photo(t.as_ref().clone()) }
 
-  pub fn sticker<T: AsRef<InlineQueryResultSticker>>(t: T) -> Self { InlineQueryResult::Sticker(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn sticker<T: AsRef<InlineQueryResultSticker>>(t: T) -> Self { InlineQueryResult::Sticker(t.as_ref().clone()) }
 
   pub fn venue<T: AsRef<InlineQueryResultVenue>>(t: T) -> Self { InlineQueryResult::Venue(t.as_ref().clone()) }
 
@@ -201,7 +227,10 @@ pub struct InlineQueryResultAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Animation file
@@ -212,8 +241,9 @@ pub struct InlineQueryResultAnimation {
 }
 
 impl RObject for InlineQueryResultAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -223,14 +253,15 @@ impl TDInlineQueryResult for InlineQueryResultAnimation {}
 
 
 impl InlineQueryResultAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultAnimationBuilder {
-    let mut inner = InlineQueryResultAnimation::default();
-    inner.td_name = "inlineQueryResultAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultAnimation { td_name: "inlineQueryResultAnimation".to_string(), ..Default::default() };
     RTDInlineQueryResultAnimationBuilder { inner }
   }
 
+  /// `InlineQueryResultAnimation` with the fields tdlib requires, the others are set by `InlineQueryResultAnimation::builder()`
+  pub fn new(id: impl AsRef<str>, animation: impl AsRef<Animation>, title: impl AsRef<str>) -> Self { Self::builder().id(id).animation(animation).title(title).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn animation(&self) -> &Animation { &self.animation }
@@ -247,6 +278,12 @@ pub struct RTDInlineQueryResultAnimationBuilder {
 impl RTDInlineQueryResultAnimationBuilder {
   pub fn build(&self) -> InlineQueryResultAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -275,6 +312,14 @@ impl AsRef<InlineQueryResultAnimation> for RTDInlineQueryResultAnimationBuilder
   fn as_ref(&self) -> &InlineQueryResultAnimation { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultAnimation {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.animation == other.animation
+      && self.title == other.title
+  }
+}
+
 
 
 
@@ -289,7 +334,10 @@ pub struct InlineQueryResultArticle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// URL of the result, if it exists
@@ -298,7 +346,7 @@ pub struct InlineQueryResultArticle {
   hide_url: bool,
   /// Title of the result
   title: String,
-  /// Represents a link to an article or web page
+  /// Description of the result
   description: String,
   /// Result thumbnail in JPEG format; may be null
   thumbnail: Option<Thumbnail>,
@@ -306,8 +354,9 @@ pub struct InlineQueryResultArticle {
 }
 
 impl RObject for InlineQueryResultArticle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultArticle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultArticle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -317,14 +366,29 @@ impl TDInlineQueryResult for InlineQueryResultArticle {}
 
 
 impl InlineQueryResultArticle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultArticleBuilder {
-    let mut inner = InlineQueryResultArticle::default();
-    inner.td_name = "inlineQueryResultArticle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultArticle { td_name: "inlineQueryResultArticle".to_string(), ..Default::default() };
     RTDInlineQueryResultArticleBuilder { inner }
   }
 
+  /// `InlineQueryResultArticle` with the fields tdlib requires, the others are set by `InlineQueryResultArticle::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    url: impl AsRef<str>,
+    hide_url: bool,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .url(url)
+      .hide_url(hide_url)
+      .title(title)
+      .description(description)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn url(&self) -> &String { &self.url }
@@ -347,6 +411,12 @@ pub struct RTDInlineQueryResultArticleBuilder {
 impl RTDInlineQueryResultArticleBuilder {
   pub fn build(&self) -> InlineQueryResultArticle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -393,6 +463,17 @@ impl AsRef<InlineQueryResultArticle> for RTDInlineQueryResultArticleBuilder {
   fn as_ref(&self) -> &InlineQueryResultArticle { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultArticle {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.url == other.url
+      && self.hide_url == other.hide_url
+      && self.title == other.title
+      && self.description == other.description
+      && self.thumbnail == other.thumbnail
+  }
+}
+
 
 
 
@@ -407,7 +488,10 @@ pub struct InlineQueryResultAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Audio file
@@ -416,8 +500,9 @@ pub struct InlineQueryResultAudio {
 }
 
 impl RObject for InlineQueryResultAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -427,14 +512,15 @@ impl TDInlineQueryResult for InlineQueryResultAudio {}
 
 
 impl InlineQueryResultAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultAudioBuilder {
-    let mut inner = InlineQueryResultAudio::default();
-    inner.td_name = "inlineQueryResultAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultAudio { td_name: "inlineQueryResultAudio".to_string(), ..Default::default() };
     RTDInlineQueryResultAudioBuilder { inner }
   }
 
+  /// `InlineQueryResultAudio` with the fields tdlib requires, the others are set by `InlineQueryResultAudio::builder()`
+  pub fn new(id: impl AsRef<str>, audio: impl AsRef<Audio>) -> Self { Self::builder().id(id).audio(audio).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn audio(&self) -> &Audio { &self.audio }
@@ -449,6 +535,12 @@ pub struct RTDInlineQueryResultAudioBuilder {
 impl RTDInlineQueryResultAudioBuilder {
   pub fn build(&self) -> InlineQueryResultAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -471,6 +563,13 @@ impl AsRef<InlineQueryResultAudio> for RTDInlineQueryResultAudioBuilder {
   fn as_ref(&self) -> &InlineQueryResultAudio { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultAudio {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.audio == other.audio
+  }
+}
+
 
 
 
@@ -485,7 +584,10 @@ pub struct InlineQueryResultContact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// A user contact
@@ -496,8 +598,9 @@ pub struct InlineQueryResultContact {
 }
 
 impl RObject for InlineQueryResultContact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultContact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultContact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -507,14 +610,15 @@ impl TDInlineQueryResult for InlineQueryResultContact {}
 
 
 impl InlineQueryResultContact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultContactBuilder {
-    let mut inner = InlineQueryResultContact::default();
-    inner.td_name = "inlineQueryResultContact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultContact { td_name: "inlineQueryResultContact".to_string(), ..Default::default() };
     RTDInlineQueryResultContactBuilder { inner }
   }
 
+  /// `InlineQueryResultContact` with the fields tdlib requires, the others are set by `InlineQueryResultContact::builder()`
+  pub fn new(id: impl AsRef<str>, contact: impl AsRef<Contact>) -> Self { Self::builder().id(id).contact(contact).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn contact(&self) -> &Contact { &self.contact }
@@ -531,6 +635,12 @@ pub struct RTDInlineQueryResultContactBuilder {
 impl RTDInlineQueryResultContactBuilder {
   pub fn build(&self) -> InlineQueryResultContact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -559,6 +669,14 @@ impl AsRef<InlineQueryResultContact> for RTDInlineQueryResultContactBuilder {
   fn as_ref(&self) -> &InlineQueryResultContact { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultContact {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.contact == other.contact
+      && self.thumbnail == other.thumbnail
+  }
+}
+
 
 
 
@@ -573,21 +691,25 @@ pub struct InlineQueryResultDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Document
   document: Document,
   /// Document title
   title: String,
-  /// Represents a document
+  /// Document description
   description: String,
   
 }
 
 impl RObject for InlineQueryResultDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -597,14 +719,27 @@ impl TDInlineQueryResult for InlineQueryResultDocument {}
 
 
 impl InlineQueryResultDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultDocumentBuilder {
-    let mut inner = InlineQueryResultDocument::default();
-    inner.td_name = "inlineQueryResultDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultDocument { td_name: "inlineQueryResultDocument".to_string(), ..Default::default() };
     RTDInlineQueryResultDocumentBuilder { inner }
   }
 
+  /// `InlineQueryResultDocument` with the fields tdlib requires, the others are set by `InlineQueryResultDocument::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    document: impl AsRef<Document>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .document(document)
+      .title(title)
+      .description(description)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn document(&self) -> &Document { &self.document }
@@ -623,6 +758,12 @@ pub struct RTDInlineQueryResultDocumentBuilder {
 impl RTDInlineQueryResultDocumentBuilder {
   pub fn build(&self) -> InlineQueryResultDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -657,6 +798,15 @@ impl AsRef<InlineQueryResultDocument> for RTDInlineQueryResultDocumentBuilder {
   fn as_ref(&self) -> &InlineQueryResultDocument { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.document == other.document
+      && self.title == other.title
+      && self.description == other.description
+  }
+}
+
 
 
 
@@ -671,7 +821,10 @@ pub struct InlineQueryResultGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Game result
@@ -680,8 +833,9 @@ pub struct InlineQueryResultGame {
 }
 
 impl RObject for InlineQueryResultGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -691,14 +845,15 @@ impl TDInlineQueryResult for InlineQueryResultGame {}
 
 
 impl InlineQueryResultGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultGameBuilder {
-    let mut inner = InlineQueryResultGame::default();
-    inner.td_name = "inlineQueryResultGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultGame { td_name: "inlineQueryResultGame".to_string(), ..Default::default() };
     RTDInlineQueryResultGameBuilder { inner }
   }
 
+  /// `InlineQueryResultGame` with the fields tdlib requires, the others are set by `InlineQueryResultGame::builder()`
+  pub fn new(id: impl AsRef<str>, game: impl AsRef<Game>) -> Self { Self::builder().id(id).game(game).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn game(&self) -> &Game { &self.game }
@@ -713,6 +868,12 @@ pub struct RTDInlineQueryResultGameBuilder {
 impl RTDInlineQueryResultGameBuilder {
   pub fn build(&self) -> InlineQueryResultGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -735,6 +896,13 @@ impl AsRef<InlineQueryResultGame> for RTDInlineQueryResultGameBuilder {
   fn as_ref(&self) -> &InlineQueryResultGame { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultGame {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.game == other.game
+  }
+}
+
 
 
 
@@ -749,7 +917,10 @@ pub struct InlineQueryResultLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Location result
@@ -762,8 +933,9 @@ pub struct InlineQueryResultLocation {
 }
 
 impl RObject for InlineQueryResultLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -773,14 +945,15 @@ impl TDInlineQueryResult for InlineQueryResultLocation {}
 
 
 impl InlineQueryResultLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultLocationBuilder {
-    let mut inner = InlineQueryResultLocation::default();
-    inner.td_name = "inlineQueryResultLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultLocation { td_name: "inlineQueryResultLocation".to_string(), ..Default::default() };
     RTDInlineQueryResultLocationBuilder { inner }
   }
 
+  /// `InlineQueryResultLocation` with the fields tdlib requires, the others are set by `InlineQueryResultLocation::builder()`
+  pub fn new(id: impl AsRef<str>, location: impl AsRef<Location>, title: impl AsRef<str>) -> Self { Self::builder().id(id).location(location).title(title).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -799,6 +972,12 @@ pub struct RTDInlineQueryResultLocationBuilder {
 impl RTDInlineQueryResultLocationBuilder {
   pub fn build(&self) -> InlineQueryResultLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -833,6 +1012,15 @@ impl AsRef<InlineQueryResultLocation> for RTDInlineQueryResultLocationBuilder {
   fn as_ref(&self) -> &InlineQueryResultLocation { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultLocation {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.location == other.location
+      && self.title == other.title
+      && self.thumbnail == other.thumbnail
+  }
+}
+
 
 
 
@@ -847,21 +1035,25 @@ pub struct InlineQueryResultPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Photo
   photo: Photo,
   /// Title of the result, if known
   title: String,
-  /// Represents a photo
+  /// Photo description
   description: String,
   
 }
 
 impl RObject for InlineQueryResultPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -871,14 +1063,27 @@ impl TDInlineQueryResult for InlineQueryResultPhoto {}
 
 
 impl InlineQueryResultPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultPhotoBuilder {
-    let mut inner = InlineQueryResultPhoto::default();
-    inner.td_name = "inlineQueryResultPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultPhoto { td_name: "inlineQueryResultPhoto".to_string(), ..Default::default() };
     RTDInlineQueryResultPhotoBuilder { inner }
   }
 
+  /// `InlineQueryResultPhoto` with the fields tdlib requires, the others are set by `InlineQueryResultPhoto::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    photo: impl AsRef<Photo>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .photo(photo)
+      .title(title)
+      .description(description)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn photo(&self) -> &Photo { &self.photo }
@@ -897,6 +1102,12 @@ pub struct RTDInlineQueryResultPhotoBuilder {
 impl RTDInlineQueryResultPhotoBuilder {
   pub fn build(&self) -> InlineQueryResultPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -931,12 +1142,23 @@ impl AsRef<InlineQueryResultPhoto> for RTDInlineQueryResultPhotoBuilder {
   fn as_ref(&self) -> &InlineQueryResultPhoto { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.photo == other.photo
+      && self.title == other.title
+      && self.description == other.description
+  }
+}
 
 
 
 
 
 
+
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// Represents a sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InlineQueryResultSticker {
@@ -945,7 +1167,10 @@ pub struct InlineQueryResultSticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Sticker
@@ -954,8 +1179,9 @@ pub struct InlineQueryResultSticker {
 }
 
 impl RObject for InlineQueryResultSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultSticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultSticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -965,14 +1191,15 @@ impl TDInlineQueryResult for InlineQueryResultSticker {}
 
 
 impl InlineQueryResultSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultStickerBuilder {
-    let mut inner = InlineQueryResultSticker::default();
-    inner.td_name = "inlineQueryResultSticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultSticker { td_name: "inlineQueryResultSticker".to_string(), ..Default::default() };
     RTDInlineQueryResultStickerBuilder { inner }
   }
 
+  /// `InlineQueryResultSticker` with the fields tdlib requires, the others are set by `InlineQueryResultSticker::builder()`
+  pub fn new(id: impl AsRef<str>, sticker: impl AsRef<Sticker>) -> Self { Self::builder().id(id).sticker(sticker).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn sticker(&self) -> &Sticker { &self.sticker }
@@ -987,6 +1214,12 @@ pub struct RTDInlineQueryResultStickerBuilder {
 impl RTDInlineQueryResultStickerBuilder {
   pub fn build(&self) -> InlineQueryResultSticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1009,6 +1242,15 @@ impl AsRef<InlineQueryResultSticker> for RTDInlineQueryResultStickerBuilder {
   fn as_ref(&self) -> &InlineQueryResultSticker { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultSticker {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sticker == other.sticker
+  }
+}
+
+}
+
 
 
 
@@ -1023,7 +1265,10 @@ pub struct InlineQueryResultVenue {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Venue result
@@ -1034,8 +1279,9 @@ pub struct InlineQueryResultVenue {
 }
 
 impl RObject for InlineQueryResultVenue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultVenue" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultVenue" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1045,14 +1291,15 @@ impl TDInlineQueryResult for InlineQueryResultVenue {}
 
 
 impl InlineQueryResultVenue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultVenueBuilder {
-    let mut inner = InlineQueryResultVenue::default();
-    inner.td_name = "inlineQueryResultVenue".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultVenue { td_name: "inlineQueryResultVenue".to_string(), ..Default::default() };
     RTDInlineQueryResultVenueBuilder { inner }
   }
 
+  /// `InlineQueryResultVenue` with the fields tdlib requires, the others are set by `InlineQueryResultVenue::builder()`
+  pub fn new(id: impl AsRef<str>, venue: impl AsRef<Venue>) -> Self { Self::builder().id(id).venue(venue).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn venue(&self) -> &Venue { &self.venue }
@@ -1069,6 +1316,12 @@ pub struct RTDInlineQueryResultVenueBuilder {
 impl RTDInlineQueryResultVenueBuilder {
   pub fn build(&self) -> InlineQueryResultVenue { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1097,6 +1350,14 @@ impl AsRef<InlineQueryResultVenue> for RTDInlineQueryResultVenueBuilder {
   fn as_ref(&self) -> &InlineQueryResultVenue { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultVenue {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.venue == other.venue
+      && self.thumbnail == other.thumbnail
+  }
+}
+
 
 
 
@@ -1111,21 +1372,25 @@ pub struct InlineQueryResultVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Video
   video: Video,
   /// Title of the video
   title: String,
-  /// Represents a video
+  /// Description of the video
   description: String,
   
 }
 
 impl RObject for InlineQueryResultVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1135,14 +1400,27 @@ impl TDInlineQueryResult for InlineQueryResultVideo {}
 
 
 impl InlineQueryResultVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultVideoBuilder {
-    let mut inner = InlineQueryResultVideo::default();
-    inner.td_name = "inlineQueryResultVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultVideo { td_name: "inlineQueryResultVideo".to_string(), ..Default::default() };
     RTDInlineQueryResultVideoBuilder { inner }
   }
 
+  /// `InlineQueryResultVideo` with the fields tdlib requires, the others are set by `InlineQueryResultVideo::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    video: impl AsRef<Video>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .video(video)
+      .title(title)
+      .description(description)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn video(&self) -> &Video { &self.video }
@@ -1161,6 +1439,12 @@ pub struct RTDInlineQueryResultVideoBuilder {
 impl RTDInlineQueryResultVideoBuilder {
   pub fn build(&self) -> InlineQueryResultVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1195,6 +1479,15 @@ impl AsRef<InlineQueryResultVideo> for RTDInlineQueryResultVideoBuilder {
   fn as_ref(&self) -> &InlineQueryResultVideo { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultVideo {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.video == other.video
+      && self.title == other.title
+      && self.description == other.description
+  }
+}
+
 
 
 
@@ -1209,7 +1502,10 @@ pub struct InlineQueryResultVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Voice note
@@ -1220,8 +1516,9 @@ pub struct InlineQueryResultVoiceNote {
 }
 
 impl RObject for InlineQueryResultVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResultVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResultVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1231,14 +1528,15 @@ impl TDInlineQueryResult for InlineQueryResultVoiceNote {}
 
 
 impl InlineQueryResultVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultVoiceNoteBuilder {
-    let mut inner = InlineQueryResultVoiceNote::default();
-    inner.td_name = "inlineQueryResultVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResultVoiceNote { td_name: "inlineQueryResultVoiceNote".to_string(), ..Default::default() };
     RTDInlineQueryResultVoiceNoteBuilder { inner }
   }
 
+  /// `InlineQueryResultVoiceNote` with the fields tdlib requires, the others are set by `InlineQueryResultVoiceNote::builder()`
+  pub fn new(id: impl AsRef<str>, voice_note: impl AsRef<VoiceNote>, title: impl AsRef<str>) -> Self { Self::builder().id(id).voice_note(voice_note).title(title).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn voice_note(&self) -> &VoiceNote { &self.voice_note }
@@ -1255,6 +1553,12 @@ pub struct RTDInlineQueryResultVoiceNoteBuilder {
 impl RTDInlineQueryResultVoiceNoteBuilder {
   pub fn build(&self) -> InlineQueryResultVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1283,5 +1587,13 @@ impl AsRef<InlineQueryResultVoiceNote> for RTDInlineQueryResultVoiceNoteBuilder
   fn as_ref(&self) -> &InlineQueryResultVoiceNote { &self.inner }
 }
 
+impl PartialEq for InlineQueryResultVoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.voice_note == other.voice_note
+      && self.title == other.title
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct InlineQueryResults {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the inline query
-  inline_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] inline_query_id: i64,
   /// The offset for the next request. If empty, there are no more results
   next_offset: String,
   /// Results of the query
@@ -29,23 +31,39 @@ pub struct InlineQueryResults {
 }
 
 impl RObject for InlineQueryResults {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inlineQueryResults" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inlineQueryResults" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl InlineQueryResults {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInlineQueryResultsBuilder {
-    let mut inner = InlineQueryResults::default();
-    inner.td_name = "inlineQueryResults".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InlineQueryResults { td_name: "inlineQueryResults".to_string(), ..Default::default() };
     RTDInlineQueryResultsBuilder { inner }
   }
 
-  pub fn inline_query_id(&self) -> isize { self.inline_query_id }
+  /// `InlineQueryResults` with the fields tdlib requires, the others are set by `InlineQueryResults::builder()`
+  pub fn new(
+    inline_query_id: i64,
+    next_offset: impl AsRef<str>,
+    results: Vec<InlineQueryResult>,
+    switch_pm_text: impl AsRef<str>,
+    switch_pm_parameter: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .inline_query_id(inline_query_id)
+      .next_offset(next_offset)
+      .results(results)
+      .switch_pm_text(switch_pm_text)
+      .switch_pm_parameter(switch_pm_parameter)
+      .build()
+  }
+
+  pub fn inline_query_id(&self) -> i64 { self.inline_query_id }
 
   pub fn next_offset(&self) -> &String { &self.next_offset }
 
@@ -65,8 +83,14 @@ pub struct RTDInlineQueryResultsBuilder {
 impl RTDInlineQueryResultsBuilder {
   pub fn build(&self) -> InlineQueryResults { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn inline_query_id(&mut self, inline_query_id: isize) -> &mut Self {
+  pub fn inline_query_id(&mut self, inline_query_id: i64) -> &mut Self {
     self.inner.inline_query_id = inline_query_id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<InlineQueryResults> for RTDInlineQueryResultsBuilder {
   fn as_ref(&self) -> &InlineQueryResults { &self.inner }
 }
 
+impl PartialEq for InlineQueryResults {
+  fn eq(&self, other: &Self) -> bool {
+    self.inline_query_id == other.inline_query_id
+      && self.next_offset == other.next_offset
+      && self.results == other.results
+      && self.switch_pm_text == other.switch_pm_text
+      && self.switch_pm_parameter == other.switch_pm_parameter
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputBackground: Debug + RObject {}
 
 /// Contains information about background to set
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputBackground {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum InputBackground {
   Local(InputBackgroundLocal),
   /// A background from the server
   Remote(InputBackgroundRemote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for InputBackground {
   fn deserialize<D>(deserializer: D) -> Result<InputBackground, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputBackground,
+      InputBackground, Unknown;
       (inputBackgroundLocal, Local);
       (inputBackgroundRemote, Remote);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for InputBackground {
 }
 
 impl RObject for InputBackground {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputBackground::Local(t) => t.td_name(),
       InputBackground::Remote(t) => t.td_name(),
+      InputBackground::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputBackground::Local(t) => t.extra(),
       InputBackground::Remote(t) => t.extra(),
+      InputBackground::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputBackground::Local(t) => t.client_id(),
+      InputBackground::Remote(t) => t.client_id(),
+      InputBackground::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for InputBackground {
 }
 
 impl InputBackground {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputBackground::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputBackground::_Default(_)) }
 
-  pub fn is_local(&self) -> bool { if let InputBackground::Local(_) = self { true } else { false } }
-  pub fn is_remote(&self) -> bool { if let InputBackground::Remote(_) = self { true } else { false } }
+  pub fn is_local(&self) -> bool { matches!(self, InputBackground::Local(_)) }
+  pub fn is_remote(&self) -> bool { matches!(self, InputBackground::Remote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputBackground::Unknown(_)) }
 
   pub fn on_local<F: FnOnce(&InputBackgroundLocal)>(&self, fnc: F) -> &Self { if let InputBackground::Local(t) = self { fnc(t) }; self }
   pub fn on_remote<F: FnOnce(&InputBackgroundRemote)>(&self, fnc: F) -> &Self { if let InputBackground::Remote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputBackground::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_local(&self) -> Option<&InputBackgroundLocal> { if let InputBackground::Local(t) = self { return Some(t) } None }
   pub fn as_remote(&self) -> Option<&InputBackgroundRemote> { if let InputBackground::Remote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputBackground::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,15 +116,19 @@ pub struct InputBackgroundLocal {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Background file to use. Only inputFileLocal and inputFileGenerated are supported. The file must be in JPEG format for wallpapers and in PNG format for patterns
   background: InputFile,
   
 }
 
 impl RObject for InputBackgroundLocal {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputBackgroundLocal" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputBackgroundLocal" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -119,14 +138,15 @@ impl TDInputBackground for InputBackgroundLocal {}
 
 
 impl InputBackgroundLocal {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputBackgroundLocalBuilder {
-    let mut inner = InputBackgroundLocal::default();
-    inner.td_name = "inputBackgroundLocal".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputBackgroundLocal { td_name: "inputBackgroundLocal".to_string(), ..Default::default() };
     RTDInputBackgroundLocalBuilder { inner }
   }
 
+  /// `InputBackgroundLocal` with the fields tdlib requires, the others are set by `InputBackgroundLocal::builder()`
+  pub fn new(background: impl AsRef<InputFile>) -> Self { Self::builder().background(background).build() }
+
   pub fn background(&self) -> &InputFile { &self.background }
 
 }
@@ -139,6 +159,12 @@ pub struct RTDInputBackgroundLocalBuilder {
 impl RTDInputBackgroundLocalBuilder {
   pub fn build(&self) -> InputBackgroundLocal { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn background<T: AsRef<InputFile>>(&mut self, background: T) -> &mut Self {
     self.inner.background = background.as_ref().clone();
@@ -155,6 +181,12 @@ impl AsRef<InputBackgroundLocal> for RTDInputBackgroundLocalBuilder {
   fn as_ref(&self) -> &InputBackgroundLocal { &self.inner }
 }
 
+impl PartialEq for InputBackgroundLocal {
+  fn eq(&self, other: &Self) -> bool {
+    self.background == other.background
+  }
+}
+
 
 
 
@@ -169,15 +201,19 @@ pub struct InputBackgroundRemote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The background identifier
-  background_id: isize,
+  #[serde(with = "crate::types::_common::int64")] background_id: i64,
   
 }
 
 impl RObject for InputBackgroundRemote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputBackgroundRemote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputBackgroundRemote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -187,15 +223,16 @@ impl TDInputBackground for InputBackgroundRemote {}
 
 
 impl InputBackgroundRemote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputBackgroundRemoteBuilder {
-    let mut inner = InputBackgroundRemote::default();
-    inner.td_name = "inputBackgroundRemote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputBackgroundRemote { td_name: "inputBackgroundRemote".to_string(), ..Default::default() };
     RTDInputBackgroundRemoteBuilder { inner }
   }
 
-  pub fn background_id(&self) -> isize { self.background_id }
+  /// `InputBackgroundRemote` with the fields tdlib requires, the others are set by `InputBackgroundRemote::builder()`
+  pub fn new(background_id: i64) -> Self { Self::builder().background_id(background_id).build() }
+
+  pub fn background_id(&self) -> i64 { self.background_id }
 
 }
 
@@ -207,8 +244,14 @@ pub struct RTDInputBackgroundRemoteBuilder {
 impl RTDInputBackgroundRemoteBuilder {
   pub fn build(&self) -> InputBackgroundRemote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn background_id(&mut self, background_id: isize) -> &mut Self {
+  pub fn background_id(&mut self, background_id: i64) -> &mut Self {
     self.inner.background_id = background_id;
     self
   }
@@ -223,5 +266,11 @@ impl AsRef<InputBackgroundRemote> for RTDInputBackgroundRemoteBuilder {
   fn as_ref(&self) -> &InputBackgroundRemote { &self.inner }
 }
 
+impl PartialEq for InputBackgroundRemote {
+  fn eq(&self, other: &Self) -> bool {
+    self.background_id == other.background_id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputChatPhoto: Debug + RObject {}
 
 /// Describes a photo to be set as a user profile or chat photo
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputChatPhoto {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum InputChatPhoto {
   Previous(InputChatPhotoPrevious),
   /// A static photo in JPEG format
   Static(InputChatPhotoStatic),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for InputChatPhoto {
   fn deserialize<D>(deserializer: D) -> Result<InputChatPhoto, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputChatPhoto,
+      InputChatPhoto, Unknown;
       (inputChatPhotoAnimation, Animation);
       (inputChatPhotoPrevious, Previous);
       (inputChatPhotoStatic, Static);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for InputChatPhoto {
 }
 
 impl RObject for InputChatPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputChatPhoto::Animation(t) => t.td_name(),
       InputChatPhoto::Previous(t) => t.td_name(),
       InputChatPhoto::Static(t) => t.td_name(),
+      InputChatPhoto::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputChatPhoto::Animation(t) => t.extra(),
       InputChatPhoto::Previous(t) => t.extra(),
       InputChatPhoto::Static(t) => t.extra(),
+      InputChatPhoto::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputChatPhoto::Animation(t) => t.client_id(),
+      InputChatPhoto::Previous(t) => t.client_id(),
+      InputChatPhoto::Static(t) => t.client_id(),
+      InputChatPhoto::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for InputChatPhoto {
 }
 
 impl InputChatPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputChatPhoto::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputChatPhoto::_Default(_)) }
 
-  pub fn is_animation(&self) -> bool { if let InputChatPhoto::Animation(_) = self { true } else { false } }
-  pub fn is_previous(&self) -> bool { if let InputChatPhoto::Previous(_) = self { true } else { false } }
-  pub fn is_static(&self) -> bool { if let InputChatPhoto::Static(_) = self { true } else { false } }
+  pub fn is_animation(&self) -> bool { matches!(self, InputChatPhoto::Animation(_)) }
+  pub fn is_previous(&self) -> bool { matches!(self, InputChatPhoto::Previous(_)) }
+  pub fn is_static(&self) -> bool { matches!(self, InputChatPhoto::Static(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputChatPhoto::Unknown(_)) }
 
   pub fn on_animation<F: FnOnce(&InputChatPhotoAnimation)>(&self, fnc: F) -> &Self { if let InputChatPhoto::Animation(t) = self { fnc(t) }; self }
   pub fn on_previous<F: FnOnce(&InputChatPhotoPrevious)>(&self, fnc: F) -> &Self { if let InputChatPhoto::Previous(t) = self { fnc(t) }; self }
   pub fn on_static<F: FnOnce(&InputChatPhotoStatic)>(&self, fnc: F) -> &Self { if let InputChatPhoto::Static(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputChatPhoto::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_animation(&self) -> Option<&InputChatPhotoAnimation> { if let InputChatPhoto::Animation(t) = self { return Some(t) } None }
   pub fn as_previous(&self) -> Option<&InputChatPhotoPrevious> { if let InputChatPhoto::Previous(t) = self { return Some(t) } None }
   pub fn as_static(&self) -> Option<&InputChatPhotoStatic> { if let InputChatPhoto::Static(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputChatPhoto::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,7 +127,10 @@ pub struct InputChatPhotoAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Animation to be set as profile photo. Only inputFileLocal and inputFileGenerated are allowed
   animation: InputFile,
   /// Timestamp of the frame, which will be used as static chat photo
@@ -120,8 +139,9 @@ pub struct InputChatPhotoAnimation {
 }
 
 impl RObject for InputChatPhotoAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputChatPhotoAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputChatPhotoAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -131,14 +151,15 @@ impl TDInputChatPhoto for InputChatPhotoAnimation {}
 
 
 impl InputChatPhotoAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputChatPhotoAnimationBuilder {
-    let mut inner = InputChatPhotoAnimation::default();
-    inner.td_name = "inputChatPhotoAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputChatPhotoAnimation { td_name: "inputChatPhotoAnimation".to_string(), ..Default::default() };
     RTDInputChatPhotoAnimationBuilder { inner }
   }
 
+  /// `InputChatPhotoAnimation` with the fields tdlib requires, the others are set by `InputChatPhotoAnimation::builder()`
+  pub fn new(animation: impl AsRef<InputFile>, main_frame_timestamp: f32) -> Self { Self::builder().animation(animation).main_frame_timestamp(main_frame_timestamp).build() }
+
   pub fn animation(&self) -> &InputFile { &self.animation }
 
   pub fn main_frame_timestamp(&self) -> f32 { self.main_frame_timestamp }
@@ -153,6 +174,12 @@ pub struct RTDInputChatPhotoAnimationBuilder {
 impl RTDInputChatPhotoAnimationBuilder {
   pub fn build(&self) -> InputChatPhotoAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -175,6 +202,13 @@ impl AsRef<InputChatPhotoAnimation> for RTDInputChatPhotoAnimationBuilder {
   fn as_ref(&self) -> &InputChatPhotoAnimation { &self.inner }
 }
 
+impl PartialEq for InputChatPhotoAnimation {
+  fn eq(&self, other: &Self) -> bool {
+    self.animation == other.animation
+      && self.main_frame_timestamp == other.main_frame_timestamp
+  }
+}
+
 
 
 
@@ -189,15 +223,19 @@ pub struct InputChatPhotoPrevious {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the current user's profile photo to reuse
-  chat_photo_id: isize,
+  #[serde(with = "crate::types::_common::int64")] chat_photo_id: i64,
   
 }
 
 impl RObject for InputChatPhotoPrevious {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputChatPhotoPrevious" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputChatPhotoPrevious" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,15 +245,16 @@ impl TDInputChatPhoto for InputChatPhotoPrevious {}
 
 
 impl InputChatPhotoPrevious {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputChatPhotoPreviousBuilder {
-    let mut inner = InputChatPhotoPrevious::default();
-    inner.td_name = "inputChatPhotoPrevious".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputChatPhotoPrevious { td_name: "inputChatPhotoPrevious".to_string(), ..Default::default() };
     RTDInputChatPhotoPreviousBuilder { inner }
   }
 
-  pub fn chat_photo_id(&self) -> isize { self.chat_photo_id }
+  /// `InputChatPhotoPrevious` with the fields tdlib requires, the others are set by `InputChatPhotoPrevious::builder()`
+  pub fn new(chat_photo_id: i64) -> Self { Self::builder().chat_photo_id(chat_photo_id).build() }
+
+  pub fn chat_photo_id(&self) -> i64 { self.chat_photo_id }
 
 }
 
@@ -227,8 +266,14 @@ pub struct RTDInputChatPhotoPreviousBuilder {
 impl RTDInputChatPhotoPreviousBuilder {
   pub fn build(&self) -> InputChatPhotoPrevious { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_photo_id(&mut self, chat_photo_id: isize) -> &mut Self {
+  pub fn chat_photo_id(&mut self, chat_photo_id: i64) -> &mut Self {
     self.inner.chat_photo_id = chat_photo_id;
     self
   }
@@ -243,6 +288,12 @@ impl AsRef<InputChatPhotoPrevious> for RTDInputChatPhotoPreviousBuilder {
   fn as_ref(&self) -> &InputChatPhotoPrevious { &self.inner }
 }
 
+impl PartialEq for InputChatPhotoPrevious {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_photo_id == other.chat_photo_id
+  }
+}
+
 
 
 
@@ -257,15 +308,19 @@ pub struct InputChatPhotoStatic {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Photo to be set as profile photo. Only inputFileLocal and inputFileGenerated are allowed
   photo: InputFile,
   
 }
 
 impl RObject for InputChatPhotoStatic {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputChatPhotoStatic" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputChatPhotoStatic" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -275,14 +330,15 @@ impl TDInputChatPhoto for InputChatPhotoStatic {}
 
 
 impl InputChatPhotoStatic {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputChatPhotoStaticBuilder {
-    let mut inner = InputChatPhotoStatic::default();
-    inner.td_name = "inputChatPhotoStatic".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputChatPhotoStatic { td_name: "inputChatPhotoStatic".to_string(), ..Default::default() };
     RTDInputChatPhotoStaticBuilder { inner }
   }
 
+  /// `InputChatPhotoStatic` with the fields tdlib requires, the others are set by `InputChatPhotoStatic::builder()`
+  pub fn new(photo: impl AsRef<InputFile>) -> Self { Self::builder().photo(photo).build() }
+
   pub fn photo(&self) -> &InputFile { &self.photo }
 
 }
@@ -295,6 +351,12 @@ pub struct RTDInputChatPhotoStaticBuilder {
 impl RTDInputChatPhotoStaticBuilder {
   pub fn build(&self) -> InputChatPhotoStatic { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<InputFile>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -311,5 +373,11 @@ impl AsRef<InputChatPhotoStatic> for RTDInputChatPhotoStaticBuilder {
   fn as_ref(&self) -> &InputChatPhotoStatic { &self.inner }
 }
 
+impl PartialEq for InputChatPhotoStatic {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+  }
+}
+
 
 
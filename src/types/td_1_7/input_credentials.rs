@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputCredentials: Debug + RObject {}
 
 /// Contains information about the payment method chosen by the user
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputCredentials {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum InputCredentials {
   New(InputCredentialsNew),
   /// Applies if a user chooses some previously saved payment credentials. To use their previously saved credentials, the user must have a valid temporary password
   Saved(InputCredentialsSaved),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for InputCredentials {
   fn deserialize<D>(deserializer: D) -> Result<InputCredentials, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputCredentials,
+      InputCredentials, Unknown;
       (inputCredentialsApplePay, ApplePay);
       (inputCredentialsGooglePay, GooglePay);
       (inputCredentialsNew, New);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for InputCredentials {
 }
 
 impl RObject for InputCredentials {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputCredentials::ApplePay(t) => t.td_name(),
       InputCredentials::GooglePay(t) => t.td_name(),
       InputCredentials::New(t) => t.td_name(),
       InputCredentials::Saved(t) => t.td_name(),
+      InputCredentials::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputCredentials::ApplePay(t) => t.extra(),
       InputCredentials::GooglePay(t) => t.extra(),
       InputCredentials::New(t) => t.extra(),
       InputCredentials::Saved(t) => t.extra(),
+      InputCredentials::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputCredentials::ApplePay(t) => t.client_id(),
+      InputCredentials::GooglePay(t) => t.client_id(),
+      InputCredentials::New(t) => t.client_id(),
+      InputCredentials::Saved(t) => t.client_id(),
+      InputCredentials::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for InputCredentials {
 }
 
 impl InputCredentials {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputCredentials::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputCredentials::_Default(_)) }
 
-  pub fn is_apple_pay(&self) -> bool { if let InputCredentials::ApplePay(_) = self { true } else { false } }
-  pub fn is_google_pay(&self) -> bool { if let InputCredentials::GooglePay(_) = self { true } else { false } }
-  pub fn is_new(&self) -> bool { if let InputCredentials::New(_) = self { true } else { false } }
-  pub fn is_saved(&self) -> bool { if let InputCredentials::Saved(_) = self { true } else { false } }
+  pub fn is_apple_pay(&self) -> bool { matches!(self, InputCredentials::ApplePay(_)) }
+  pub fn is_google_pay(&self) -> bool { matches!(self, InputCredentials::GooglePay(_)) }
+  pub fn is_new(&self) -> bool { matches!(self, InputCredentials::New(_)) }
+  pub fn is_saved(&self) -> bool { matches!(self, InputCredentials::Saved(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputCredentials::Unknown(_)) }
 
   pub fn on_apple_pay<F: FnOnce(&InputCredentialsApplePay)>(&self, fnc: F) -> &Self { if let InputCredentials::ApplePay(t) = self { fnc(t) }; self }
   pub fn on_google_pay<F: FnOnce(&InputCredentialsGooglePay)>(&self, fnc: F) -> &Self { if let InputCredentials::GooglePay(t) = self { fnc(t) }; self }
   pub fn on_new<F: FnOnce(&InputCredentialsNew)>(&self, fnc: F) -> &Self { if let InputCredentials::New(t) = self { fnc(t) }; self }
   pub fn on_saved<F: FnOnce(&InputCredentialsSaved)>(&self, fnc: F) -> &Self { if let InputCredentials::Saved(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputCredentials::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_apple_pay(&self) -> Option<&InputCredentialsApplePay> { if let InputCredentials::ApplePay(t) = self { return Some(t) } None }
   pub fn as_google_pay(&self) -> Option<&InputCredentialsGooglePay> { if let InputCredentials::GooglePay(t) = self { return Some(t) } None }
   pub fn as_new(&self) -> Option<&InputCredentialsNew> { if let InputCredentials::New(t) = self { return Some(t) } None }
   pub fn as_saved(&self) -> Option<&InputCredentialsSaved> { if let InputCredentials::Saved(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputCredentials::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,15 +138,19 @@ pub struct InputCredentialsApplePay {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// JSON-encoded data with the credential identifier
   data: String,
   
 }
 
 impl RObject for InputCredentialsApplePay {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsApplePay" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputCredentialsApplePay" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,14 +160,15 @@ impl TDInputCredentials for InputCredentialsApplePay {}
 
 
 impl InputCredentialsApplePay {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputCredentialsApplePayBuilder {
-    let mut inner = InputCredentialsApplePay::default();
-    inner.td_name = "inputCredentialsApplePay".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputCredentialsApplePay { td_name: "inputCredentialsApplePay".to_string(), ..Default::default() };
     RTDInputCredentialsApplePayBuilder { inner }
   }
 
+  /// `InputCredentialsApplePay` with the fields tdlib requires, the others are set by `InputCredentialsApplePay::builder()`
+  pub fn new(data: impl AsRef<str>) -> Self { Self::builder().data(data).build() }
+
   pub fn data(&self) -> &String { &self.data }
 
 }
@@ -159,6 +181,12 @@ pub struct RTDInputCredentialsApplePayBuilder {
 impl RTDInputCredentialsApplePayBuilder {
   pub fn build(&self) -> InputCredentialsApplePay { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
@@ -175,6 +203,12 @@ impl AsRef<InputCredentialsApplePay> for RTDInputCredentialsApplePayBuilder {
   fn as_ref(&self) -> &InputCredentialsApplePay { &self.inner }
 }
 
+impl PartialEq for InputCredentialsApplePay {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+  }
+}
+
 
 
 
@@ -189,15 +223,19 @@ pub struct InputCredentialsGooglePay {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// JSON-encoded data with the credential identifier
   data: String,
   
 }
 
 impl RObject for InputCredentialsGooglePay {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsGooglePay" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputCredentialsGooglePay" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,14 +245,15 @@ impl TDInputCredentials for InputCredentialsGooglePay {}
 
 
 impl InputCredentialsGooglePay {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputCredentialsGooglePayBuilder {
-    let mut inner = InputCredentialsGooglePay::default();
-    inner.td_name = "inputCredentialsGooglePay".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputCredentialsGooglePay { td_name: "inputCredentialsGooglePay".to_string(), ..Default::default() };
     RTDInputCredentialsGooglePayBuilder { inner }
   }
 
+  /// `InputCredentialsGooglePay` with the fields tdlib requires, the others are set by `InputCredentialsGooglePay::builder()`
+  pub fn new(data: impl AsRef<str>) -> Self { Self::builder().data(data).build() }
+
   pub fn data(&self) -> &String { &self.data }
 
 }
@@ -227,6 +266,12 @@ pub struct RTDInputCredentialsGooglePayBuilder {
 impl RTDInputCredentialsGooglePayBuilder {
   pub fn build(&self) -> InputCredentialsGooglePay { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
@@ -243,6 +288,12 @@ impl AsRef<InputCredentialsGooglePay> for RTDInputCredentialsGooglePayBuilder {
   fn as_ref(&self) -> &InputCredentialsGooglePay { &self.inner }
 }
 
+impl PartialEq for InputCredentialsGooglePay {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+  }
+}
+
 
 
 
@@ -257,7 +308,10 @@ pub struct InputCredentialsNew {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// JSON-encoded data with the credential identifier from the payment provider
   data: String,
   /// True, if the credential identifier can be saved on the server side
@@ -266,8 +320,9 @@ pub struct InputCredentialsNew {
 }
 
 impl RObject for InputCredentialsNew {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsNew" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputCredentialsNew" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -277,14 +332,15 @@ impl TDInputCredentials for InputCredentialsNew {}
 
 
 impl InputCredentialsNew {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputCredentialsNewBuilder {
-    let mut inner = InputCredentialsNew::default();
-    inner.td_name = "inputCredentialsNew".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputCredentialsNew { td_name: "inputCredentialsNew".to_string(), ..Default::default() };
     RTDInputCredentialsNewBuilder { inner }
   }
 
+  /// `InputCredentialsNew` with the fields tdlib requires, the others are set by `InputCredentialsNew::builder()`
+  pub fn new(data: impl AsRef<str>, allow_save: bool) -> Self { Self::builder().data(data).allow_save(allow_save).build() }
+
   pub fn data(&self) -> &String { &self.data }
 
   pub fn allow_save(&self) -> bool { self.allow_save }
@@ -299,6 +355,12 @@ pub struct RTDInputCredentialsNewBuilder {
 impl RTDInputCredentialsNewBuilder {
   pub fn build(&self) -> InputCredentialsNew { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
@@ -321,6 +383,13 @@ impl AsRef<InputCredentialsNew> for RTDInputCredentialsNewBuilder {
   fn as_ref(&self) -> &InputCredentialsNew { &self.inner }
 }
 
+impl PartialEq for InputCredentialsNew {
+  fn eq(&self, other: &Self) -> bool {
+    self.data == other.data
+      && self.allow_save == other.allow_save
+  }
+}
+
 
 
 
@@ -335,15 +404,19 @@ pub struct InputCredentialsSaved {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the saved credentials
   saved_credentials_id: String,
   
 }
 
 impl RObject for InputCredentialsSaved {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputCredentialsSaved" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputCredentialsSaved" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -353,14 +426,15 @@ impl TDInputCredentials for InputCredentialsSaved {}
 
 
 impl InputCredentialsSaved {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputCredentialsSavedBuilder {
-    let mut inner = InputCredentialsSaved::default();
-    inner.td_name = "inputCredentialsSaved".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputCredentialsSaved { td_name: "inputCredentialsSaved".to_string(), ..Default::default() };
     RTDInputCredentialsSavedBuilder { inner }
   }
 
+  /// `InputCredentialsSaved` with the fields tdlib requires, the others are set by `InputCredentialsSaved::builder()`
+  pub fn new(saved_credentials_id: impl AsRef<str>) -> Self { Self::builder().saved_credentials_id(saved_credentials_id).build() }
+
   pub fn saved_credentials_id(&self) -> &String { &self.saved_credentials_id }
 
 }
@@ -373,6 +447,12 @@ pub struct RTDInputCredentialsSavedBuilder {
 impl RTDInputCredentialsSavedBuilder {
   pub fn build(&self) -> InputCredentialsSaved { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn saved_credentials_id<T: AsRef<str>>(&mut self, saved_credentials_id: T) -> &mut Self {
     self.inner.saved_credentials_id = saved_credentials_id.as_ref().to_string();
@@ -389,5 +469,11 @@ impl AsRef<InputCredentialsSaved> for RTDInputCredentialsSavedBuilder {
   fn as_ref(&self) -> &InputCredentialsSaved { &self.inner }
 }
 
+impl PartialEq for InputCredentialsSaved {
+  fn eq(&self, other: &Self) -> bool {
+    self.saved_credentials_id == other.saved_credentials_id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputFile: Debug + RObject {}
 
 /// Points to a file
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputFile {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum InputFile {
   Local(InputFileLocal),
   /// A file defined by its remote ID. The remote ID is guaranteed to be usable only if the corresponding file is still accessible to the user and known to TDLib. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the application
   Remote(InputFileRemote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for InputFile {
   fn deserialize<D>(deserializer: D) -> Result<InputFile, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputFile,
+      InputFile, Unknown;
       (inputFileGenerated, Generated);
       (inputFileId, Id);
       (inputFileLocal, Local);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for InputFile {
 }
 
 impl RObject for InputFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputFile::Generated(t) => t.td_name(),
       InputFile::Id(t) => t.td_name(),
       InputFile::Local(t) => t.td_name(),
       InputFile::Remote(t) => t.td_name(),
+      InputFile::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputFile::Generated(t) => t.extra(),
       InputFile::Id(t) => t.extra(),
       InputFile::Local(t) => t.extra(),
       InputFile::Remote(t) => t.extra(),
+      InputFile::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputFile::Generated(t) => t.client_id(),
+      InputFile::Id(t) => t.client_id(),
+      InputFile::Local(t) => t.client_id(),
+      InputFile::Remote(t) => t.client_id(),
+      InputFile::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for InputFile {
 }
 
 impl InputFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputFile::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputFile::_Default(_)) }
 
-  pub fn is_generated(&self) -> bool { if let InputFile::Generated(_) = self { true } else { false } }
-  pub fn is_id(&self) -> bool { if let InputFile::Id(_) = self { true } else { false } }
-  pub fn is_local(&self) -> bool { if let InputFile::Local(_) = self { true } else { false } }
-  pub fn is_remote(&self) -> bool { if let InputFile::Remote(_) = self { true } else { false } }
+  pub fn is_generated(&self) -> bool { matches!(self, InputFile::Generated(_)) }
+  pub fn is_id(&self) -> bool { matches!(self, InputFile::Id(_)) }
+  pub fn is_local(&self) -> bool { matches!(self, InputFile::Local(_)) }
+  pub fn is_remote(&self) -> bool { matches!(self, InputFile::Remote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputFile::Unknown(_)) }
 
   pub fn on_generated<F: FnOnce(&InputFileGenerated)>(&self, fnc: F) -> &Self { if let InputFile::Generated(t) = self { fnc(t) }; self }
   pub fn on_id<F: FnOnce(&InputFileId)>(&self, fnc: F) -> &Self { if let InputFile::Id(t) = self { fnc(t) }; self }
   pub fn on_local<F: FnOnce(&InputFileLocal)>(&self, fnc: F) -> &Self { if let InputFile::Local(t) = self { fnc(t) }; self }
   pub fn on_remote<F: FnOnce(&InputFileRemote)>(&self, fnc: F) -> &Self { if let InputFile::Remote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputFile::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_generated(&self) -> Option<&InputFileGenerated> { if let InputFile::Generated(t) = self { return Some(t) } None }
   pub fn as_id(&self) -> Option<&InputFileId> { if let InputFile::Id(t) = self { return Some(t) } None }
   pub fn as_local(&self) -> Option<&InputFileLocal> { if let InputFile::Local(t) = self { return Some(t) } None }
   pub fn as_remote(&self) -> Option<&InputFileRemote> { if let InputFile::Remote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputFile::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,7 +138,10 @@ pub struct InputFileGenerated {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Local path to a file from which the file is generated; may be empty if there is no such file
   original_path: String,
   /// String specifying the conversion applied to the original file; must be persistent across application restarts. Conversions beginning with '#' are reserved for internal TDLib usage
@@ -132,8 +152,9 @@ pub struct InputFileGenerated {
 }
 
 impl RObject for InputFileGenerated {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileGenerated" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputFileGenerated" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -143,14 +164,15 @@ impl TDInputFile for InputFileGenerated {}
 
 
 impl InputFileGenerated {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputFileGeneratedBuilder {
-    let mut inner = InputFileGenerated::default();
-    inner.td_name = "inputFileGenerated".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputFileGenerated { td_name: "inputFileGenerated".to_string(), ..Default::default() };
     RTDInputFileGeneratedBuilder { inner }
   }
 
+  /// `InputFileGenerated` with the fields tdlib requires, the others are set by `InputFileGenerated::builder()`
+  pub fn new(original_path: impl AsRef<str>, conversion: impl AsRef<str>, expected_size: i64) -> Self { Self::builder().original_path(original_path).conversion(conversion).expected_size(expected_size).build() }
+
   pub fn original_path(&self) -> &String { &self.original_path }
 
   pub fn conversion(&self) -> &String { &self.conversion }
@@ -167,6 +189,12 @@ pub struct RTDInputFileGeneratedBuilder {
 impl RTDInputFileGeneratedBuilder {
   pub fn build(&self) -> InputFileGenerated { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn original_path<T: AsRef<str>>(&mut self, original_path: T) -> &mut Self {
     self.inner.original_path = original_path.as_ref().to_string();
@@ -195,6 +223,14 @@ impl AsRef<InputFileGenerated> for RTDInputFileGeneratedBuilder {
   fn as_ref(&self) -> &InputFileGenerated { &self.inner }
 }
 
+impl PartialEq for InputFileGenerated {
+  fn eq(&self, other: &Self) -> bool {
+    self.original_path == other.original_path
+      && self.conversion == other.conversion
+      && self.expected_size == other.expected_size
+  }
+}
+
 
 
 
@@ -209,15 +245,19 @@ pub struct InputFileId {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique file identifier
-  id: i64,
+  id: FileId,
   
 }
 
 impl RObject for InputFileId {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileId" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputFileId" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -227,15 +267,16 @@ impl TDInputFile for InputFileId {}
 
 
 impl InputFileId {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputFileIdBuilder {
-    let mut inner = InputFileId::default();
-    inner.td_name = "inputFileId".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputFileId { td_name: "inputFileId".to_string(), ..Default::default() };
     RTDInputFileIdBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `InputFileId` with the fields tdlib requires, the others are set by `InputFileId::builder()`
+  pub fn new(id: FileId) -> Self { Self::builder().id(id).build() }
+
+  pub fn id(&self) -> FileId { self.id }
 
 }
 
@@ -247,8 +288,14 @@ pub struct RTDInputFileIdBuilder {
 impl RTDInputFileIdBuilder {
   pub fn build(&self) -> InputFileId { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: FileId) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -263,6 +310,12 @@ impl AsRef<InputFileId> for RTDInputFileIdBuilder {
   fn as_ref(&self) -> &InputFileId { &self.inner }
 }
 
+impl PartialEq for InputFileId {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+  }
+}
+
 
 
 
@@ -277,15 +330,19 @@ pub struct InputFileLocal {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Local path to the file
   path: String,
   
 }
 
 impl RObject for InputFileLocal {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileLocal" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputFileLocal" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -295,14 +352,15 @@ impl TDInputFile for InputFileLocal {}
 
 
 impl InputFileLocal {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputFileLocalBuilder {
-    let mut inner = InputFileLocal::default();
-    inner.td_name = "inputFileLocal".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputFileLocal { td_name: "inputFileLocal".to_string(), ..Default::default() };
     RTDInputFileLocalBuilder { inner }
   }
 
+  /// `InputFileLocal` with the fields tdlib requires, the others are set by `InputFileLocal::builder()`
+  pub fn new(path: impl AsRef<str>) -> Self { Self::builder().path(path).build() }
+
   pub fn path(&self) -> &String { &self.path }
 
 }
@@ -315,6 +373,12 @@ pub struct RTDInputFileLocalBuilder {
 impl RTDInputFileLocalBuilder {
   pub fn build(&self) -> InputFileLocal { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
@@ -331,6 +395,12 @@ impl AsRef<InputFileLocal> for RTDInputFileLocalBuilder {
   fn as_ref(&self) -> &InputFileLocal { &self.inner }
 }
 
+impl PartialEq for InputFileLocal {
+  fn eq(&self, other: &Self) -> bool {
+    self.path == other.path
+  }
+}
+
 
 
 
@@ -345,15 +415,19 @@ pub struct InputFileRemote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Remote file identifier
   id: String,
   
 }
 
 impl RObject for InputFileRemote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputFileRemote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputFileRemote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -363,14 +437,15 @@ impl TDInputFile for InputFileRemote {}
 
 
 impl InputFileRemote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputFileRemoteBuilder {
-    let mut inner = InputFileRemote::default();
-    inner.td_name = "inputFileRemote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputFileRemote { td_name: "inputFileRemote".to_string(), ..Default::default() };
     RTDInputFileRemoteBuilder { inner }
   }
 
+  /// `InputFileRemote` with the fields tdlib requires, the others are set by `InputFileRemote::builder()`
+  pub fn new(id: impl AsRef<str>) -> Self { Self::builder().id(id).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
 }
@@ -383,6 +458,12 @@ pub struct RTDInputFileRemoteBuilder {
 impl RTDInputFileRemoteBuilder {
   pub fn build(&self) -> InputFileRemote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -399,5 +480,11 @@ impl AsRef<InputFileRemote> for RTDInputFileRemoteBuilder {
   fn as_ref(&self) -> &InputFileRemote { &self.inner }
 }
 
+impl PartialEq for InputFileRemote {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputInlineQueryResult: Debug + RObject {}
 
 /// Represents a single result of an inline query; for bots only
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputInlineQueryResult {
   #[doc(hidden)] _Default(()),
@@ -36,6 +35,7 @@ pub enum InputInlineQueryResult {
   /// Represents link to a JPEG image
   Photo(InputInlineQueryResultPhoto),
   /// Represents a link to a WEBP or TGS sticker
+  #[cfg(feature = "stickers")]
   Sticker(InputInlineQueryResultSticker),
   /// Represents information about a venue
   Venue(InputInlineQueryResultVenue),
@@ -43,6 +43,8 @@ pub enum InputInlineQueryResult {
   Video(InputInlineQueryResultVideo),
   /// Represents a link to an opus-encoded audio file within an OGG container, single channel audio
   VoiceNote(InputInlineQueryResultVoiceNote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -54,7 +56,7 @@ impl<'de> Deserialize<'de> for InputInlineQueryResult {
   fn deserialize<D>(deserializer: D) -> Result<InputInlineQueryResult, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputInlineQueryResult,
+      InputInlineQueryResult, Unknown;
       (inputInlineQueryResultAnimation, Animation);
       (inputInlineQueryResultArticle, Article);
       (inputInlineQueryResultAudio, Audio);
@@ -63,7 +65,7 @@ impl<'de> Deserialize<'de> for InputInlineQueryResult {
       (inputInlineQueryResultGame, Game);
       (inputInlineQueryResultLocation, Location);
       (inputInlineQueryResultPhoto, Photo);
-      (inputInlineQueryResultSticker, Sticker);
+      #[cfg(feature = "stickers")] (inputInlineQueryResultSticker, Sticker);
       (inputInlineQueryResultVenue, Venue);
       (inputInlineQueryResultVideo, Video);
       (inputInlineQueryResultVoiceNote, VoiceNote);
@@ -73,7 +75,7 @@ impl<'de> Deserialize<'de> for InputInlineQueryResult {
 }
 
 impl RObject for InputInlineQueryResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputInlineQueryResult::Animation(t) => t.td_name(),
       InputInlineQueryResult::Article(t) => t.td_name(),
@@ -83,15 +85,16 @@ impl RObject for InputInlineQueryResult {
       InputInlineQueryResult::Game(t) => t.td_name(),
       InputInlineQueryResult::Location(t) => t.td_name(),
       InputInlineQueryResult::Photo(t) => t.td_name(),
-      InputInlineQueryResult::Sticker(t) => t.td_name(),
+      #[cfg(feature = "stickers")] InputInlineQueryResult::Sticker(t) => t.td_name(),
       InputInlineQueryResult::Venue(t) => t.td_name(),
       InputInlineQueryResult::Video(t) => t.td_name(),
       InputInlineQueryResult::VoiceNote(t) => t.td_name(),
+      InputInlineQueryResult::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputInlineQueryResult::Animation(t) => t.extra(),
       InputInlineQueryResult::Article(t) => t.extra(),
@@ -101,10 +104,30 @@ impl RObject for InputInlineQueryResult {
       InputInlineQueryResult::Game(t) => t.extra(),
       InputInlineQueryResult::Location(t) => t.extra(),
       InputInlineQueryResult::Photo(t) => t.extra(),
-      InputInlineQueryResult::Sticker(t) => t.extra(),
+      #[cfg(feature = "stickers")] InputInlineQueryResult::Sticker(t) => t.extra(),
       InputInlineQueryResult::Venue(t) => t.extra(),
       InputInlineQueryResult::Video(t) => t.extra(),
       InputInlineQueryResult::VoiceNote(t) => t.extra(),
+      InputInlineQueryResult::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputInlineQueryResult::Animation(t) => t.client_id(),
+      InputInlineQueryResult::Article(t) => t.client_id(),
+      InputInlineQueryResult::Audio(t) => t.client_id(),
+      InputInlineQueryResult::Contact(t) => t.client_id(),
+      InputInlineQueryResult::Document(t) => t.client_id(),
+      InputInlineQueryResult::Game(t) => t.client_id(),
+      InputInlineQueryResult::Location(t) => t.client_id(),
+      InputInlineQueryResult::Photo(t) => t.client_id(),
+      #[cfg(feature = "stickers")] InputInlineQueryResult::Sticker(t) => t.client_id(),
+      InputInlineQueryResult::Venue(t) => t.client_id(),
+      InputInlineQueryResult::Video(t) => t.client_id(),
+      InputInlineQueryResult::VoiceNote(t) => t.client_id(),
+      InputInlineQueryResult::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -113,21 +136,22 @@ impl RObject for InputInlineQueryResult {
 }
 
 impl InputInlineQueryResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputInlineQueryResult::_Default(_) = self { true } else { false } }
-
-  pub fn is_animation(&self) -> bool { if let InputInlineQueryResult::Animation(_) = self { true } else { false } }
-  pub fn is_article(&self) -> bool { if let InputInlineQueryResult::Article(_) = self { true } else { false } }
-  pub fn is_audio(&self) -> bool { if let InputInlineQueryResult::Audio(_) = self { true } else { false } }
-  pub fn is_contact(&self) -> bool { if let InputInlineQueryResult::Contact(_) = self { true } else { false } }
-  pub fn is_document(&self) -> bool { if let InputInlineQueryResult::Document(_) = self { true } else { false } }
-  pub fn is_game(&self) -> bool { if let InputInlineQueryResult::Game(_) = self { true } else { false } }
-  pub fn is_location(&self) -> bool { if let InputInlineQueryResult::Location(_) = self { true } else { false } }
-  pub fn is_photo(&self) -> bool { if let InputInlineQueryResult::Photo(_) = self { true } else { false } }
-  pub fn is_sticker(&self) -> bool { if let InputInlineQueryResult::Sticker(_) = self { true } else { false } }
-  pub fn is_venue(&self) -> bool { if let InputInlineQueryResult::Venue(_) = self { true } else { false } }
-  pub fn is_video(&self) -> bool { if let InputInlineQueryResult::Video(_) = self { true } else { false } }
-  pub fn is_voice_note(&self) -> bool { if let InputInlineQueryResult::VoiceNote(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputInlineQueryResult::_Default(_)) }
+
+  pub fn is_animation(&self) -> bool { matches!(self, InputInlineQueryResult::Animation(_)) }
+  pub fn is_article(&self) -> bool { matches!(self, InputInlineQueryResult::Article(_)) }
+  pub fn is_audio(&self) -> bool { matches!(self, InputInlineQueryResult::Audio(_)) }
+  pub fn is_contact(&self) -> bool { matches!(self, InputInlineQueryResult::Contact(_)) }
+  pub fn is_document(&self) -> bool { matches!(self, InputInlineQueryResult::Document(_)) }
+  pub fn is_game(&self) -> bool { matches!(self, InputInlineQueryResult::Game(_)) }
+  pub fn is_location(&self) -> bool { matches!(self, InputInlineQueryResult::Location(_)) }
+  pub fn is_photo(&self) -> bool { matches!(self, InputInlineQueryResult::Photo(_)) }
+  #[cfg(feature = "stickers")] pub fn is_sticker(&self) -> bool { matches!(self, InputInlineQueryResult::Sticker(_)) }
+  pub fn is_venue(&self) -> bool { matches!(self, InputInlineQueryResult::Venue(_)) }
+  pub fn is_video(&self) -> bool { matches!(self, InputInlineQueryResult::Video(_)) }
+  pub fn is_voice_note(&self) -> bool { matches!(self, InputInlineQueryResult::VoiceNote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputInlineQueryResult::Unknown(_)) }
 
   pub fn on_animation<F: FnOnce(&InputInlineQueryResultAnimation)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Animation(t) = self { fnc(t) }; self }
   pub fn on_article<F: FnOnce(&InputInlineQueryResultArticle)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Article(t) = self { fnc(t) }; self }
@@ -137,10 +161,11 @@ impl InputInlineQueryResult {
   pub fn on_game<F: FnOnce(&InputInlineQueryResultGame)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Game(t) = self { fnc(t) }; self }
   pub fn on_location<F: FnOnce(&InputInlineQueryResultLocation)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Location(t) = self { fnc(t) }; self }
   pub fn on_photo<F: FnOnce(&InputInlineQueryResultPhoto)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Photo(t) = self { fnc(t) }; self }
-  pub fn on_sticker<F: FnOnce(&InputInlineQueryResultSticker)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Sticker(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_sticker<F: FnOnce(&InputInlineQueryResultSticker)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Sticker(t) = self { fnc(t) }; self }
   pub fn on_venue<F: FnOnce(&InputInlineQueryResultVenue)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Venue(t) = self { fnc(t) }; self }
   pub fn on_video<F: FnOnce(&InputInlineQueryResultVideo)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Video(t) = self { fnc(t) }; self }
   pub fn on_voice_note<F: FnOnce(&InputInlineQueryResultVoiceNote)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::VoiceNote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputInlineQueryResult::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_animation(&self) -> Option<&InputInlineQueryResultAnimation> { if let InputInlineQueryResult::Animation(t) = self { return Some(t) } None }
   pub fn as_article(&self) -> Option<&InputInlineQueryResultArticle> { if let InputInlineQueryResult::Article(t) = self { return Some(t) } None }
@@ -150,10 +175,11 @@ impl InputInlineQueryResult {
   pub fn as_game(&self) -> Option<&InputInlineQueryResultGame> { if let InputInlineQueryResult::Game(t) = self { return Some(t) } None }
   pub fn as_location(&self) -> Option<&InputInlineQueryResultLocation> { if let InputInlineQueryResult::Location(t) = self { return Some(t) } None }
   pub fn as_photo(&self) -> Option<&InputInlineQueryResultPhoto> { if let InputInlineQueryResult::Photo(t) = self { return Some(t) } None }
-  pub fn as_sticker(&self) -> Option<&InputInlineQueryResultSticker> { if let InputInlineQueryResult::Sticker(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_sticker(&self) -> Option<&InputInlineQueryResultSticker> { if let InputInlineQueryResult::Sticker(t) = self { return Some(t) } None }
   pub fn as_venue(&self) -> Option<&InputInlineQueryResultVenue> { if let InputInlineQueryResult::Venue(t) = self { return Some(t) } None }
   pub fn as_video(&self) -> Option<&InputInlineQueryResultVideo> { if let InputInlineQueryResult::Video(t) = self { return Some(t) } None }
   pub fn as_voice_note(&self) -> Option<&InputInlineQueryResultVoiceNote> { if let InputInlineQueryResult::VoiceNote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputInlineQueryResult::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -173,7 +199,7 @@ impl InputInlineQueryResult {
 
   pub fn photo<T: AsRef<InputInlineQueryResultPhoto>>(t: T) -> Self { InputInlineQueryResult::Photo(t.as_ref().clone()) }
 
-  pub fn sticker<T: AsRef<InputInlineQueryResultSticker>>(t: T) -> Self { InputInlineQueryResult::Sticker(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn sticker<T: AsRef<InputInlineQueryResultSticker>>(t: T) -> Self { InputInlineQueryResult::Sticker(t.as_ref().clone()) }
 
   pub fn venue<T: AsRef<InputInlineQueryResultVenue>>(t: T) -> Self { InputInlineQueryResult::Venue(t.as_ref().clone()) }
 
@@ -187,6 +213,26 @@ impl AsRef<InputInlineQueryResult> for InputInlineQueryResult {
   fn as_ref(&self) -> &InputInlineQueryResult { self }
 }
 
+impl Validate for InputInlineQueryResult {
+  fn check(&self, checker: &mut Checker) {
+    match self {
+      InputInlineQueryResult::Animation(value) => value.check(checker),
+      InputInlineQueryResult::Article(value) => value.check(checker),
+      InputInlineQueryResult::Audio(value) => value.check(checker),
+      InputInlineQueryResult::Contact(value) => value.check(checker),
+      InputInlineQueryResult::Document(value) => value.check(checker),
+      InputInlineQueryResult::Game(value) => value.check(checker),
+      InputInlineQueryResult::Location(value) => value.check(checker),
+      InputInlineQueryResult::Photo(value) => value.check(checker),
+      #[cfg(feature = "stickers")] InputInlineQueryResult::Sticker(value) => value.check(checker),
+      InputInlineQueryResult::Venue(value) => value.check(checker),
+      InputInlineQueryResult::Video(value) => value.check(checker),
+      InputInlineQueryResult::VoiceNote(value) => value.check(checker),
+      _ => {}
+    }
+  }
+}
+
 
 
 
@@ -201,7 +247,10 @@ pub struct InputInlineQueryResultAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the query result
@@ -228,8 +277,9 @@ pub struct InputInlineQueryResultAnimation {
 }
 
 impl RObject for InputInlineQueryResultAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -239,14 +289,40 @@ impl TDInputInlineQueryResult for InputInlineQueryResultAnimation {}
 
 
 impl InputInlineQueryResultAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultAnimationBuilder {
-    let mut inner = InputInlineQueryResultAnimation::default();
-    inner.td_name = "inputInlineQueryResultAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultAnimation { td_name: "inputInlineQueryResultAnimation".to_string(), ..Default::default() };
     RTDInputInlineQueryResultAnimationBuilder { inner }
   }
 
+  /// `InputInlineQueryResultAnimation` with the fields tdlib requires, the others are set by `InputInlineQueryResultAnimation::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: impl AsRef<str>,
+    title: impl AsRef<str>,
+    thumbnail_url: impl AsRef<str>,
+    thumbnail_mime_type: impl AsRef<str>,
+    video_url: impl AsRef<str>,
+    video_mime_type: impl AsRef<str>,
+    video_duration: i64,
+    video_width: i64,
+    video_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .thumbnail_url(thumbnail_url)
+      .thumbnail_mime_type(thumbnail_mime_type)
+      .video_url(video_url)
+      .video_mime_type(video_mime_type)
+      .video_duration(video_duration)
+      .video_width(video_width)
+      .video_height(video_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -279,6 +355,12 @@ pub struct RTDInputInlineQueryResultAnimationBuilder {
 impl RTDInputInlineQueryResultAnimationBuilder {
   pub fn build(&self) -> InputInlineQueryResultAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -355,6 +437,29 @@ impl AsRef<InputInlineQueryResultAnimation> for RTDInputInlineQueryResultAnimati
   fn as_ref(&self) -> &InputInlineQueryResultAnimation { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultAnimation {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.thumbnail_url == other.thumbnail_url
+      && self.thumbnail_mime_type == other.thumbnail_mime_type
+      && self.video_url == other.video_url
+      && self.video_mime_type == other.video_mime_type
+      && self.video_duration == other.video_duration
+      && self.video_width == other.video_width
+      && self.video_height == other.video_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultAnimation {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -369,7 +474,10 @@ pub struct InputInlineQueryResultArticle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// URL of the result, if it exists
@@ -378,7 +486,7 @@ pub struct InputInlineQueryResultArticle {
   hide_url: bool,
   /// Title of the result
   title: String,
-  /// Represents a link to an article or web page
+  /// A short description of the result
   description: String,
   /// URL of the result thumbnail, if it exists
   thumbnail_url: String,
@@ -394,8 +502,9 @@ pub struct InputInlineQueryResultArticle {
 }
 
 impl RObject for InputInlineQueryResultArticle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultArticle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultArticle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -405,14 +514,38 @@ impl TDInputInlineQueryResult for InputInlineQueryResultArticle {}
 
 
 impl InputInlineQueryResultArticle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultArticleBuilder {
-    let mut inner = InputInlineQueryResultArticle::default();
-    inner.td_name = "inputInlineQueryResultArticle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultArticle { td_name: "inputInlineQueryResultArticle".to_string(), ..Default::default() };
     RTDInputInlineQueryResultArticleBuilder { inner }
   }
 
+  /// `InputInlineQueryResultArticle` with the fields tdlib requires, the others are set by `InputInlineQueryResultArticle::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: impl AsRef<str>,
+    url: impl AsRef<str>,
+    hide_url: bool,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    thumbnail_url: impl AsRef<str>,
+    thumbnail_width: i64,
+    thumbnail_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .url(url)
+      .hide_url(hide_url)
+      .title(title)
+      .description(description)
+      .thumbnail_url(thumbnail_url)
+      .thumbnail_width(thumbnail_width)
+      .thumbnail_height(thumbnail_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn url(&self) -> &String { &self.url }
@@ -443,6 +576,12 @@ pub struct RTDInputInlineQueryResultArticleBuilder {
 impl RTDInputInlineQueryResultArticleBuilder {
   pub fn build(&self) -> InputInlineQueryResultArticle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -513,6 +652,28 @@ impl AsRef<InputInlineQueryResultArticle> for RTDInputInlineQueryResultArticleBu
   fn as_ref(&self) -> &InputInlineQueryResultArticle { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultArticle {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.url == other.url
+      && self.hide_url == other.hide_url
+      && self.title == other.title
+      && self.description == other.description
+      && self.thumbnail_url == other.thumbnail_url
+      && self.thumbnail_width == other.thumbnail_width
+      && self.thumbnail_height == other.thumbnail_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultArticle {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -527,7 +688,10 @@ pub struct InputInlineQueryResultAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the audio file
@@ -546,8 +710,9 @@ pub struct InputInlineQueryResultAudio {
 }
 
 impl RObject for InputInlineQueryResultAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -557,14 +722,31 @@ impl TDInputInlineQueryResult for InputInlineQueryResultAudio {}
 
 
 impl InputInlineQueryResultAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultAudioBuilder {
-    let mut inner = InputInlineQueryResultAudio::default();
-    inner.td_name = "inputInlineQueryResultAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultAudio { td_name: "inputInlineQueryResultAudio".to_string(), ..Default::default() };
     RTDInputInlineQueryResultAudioBuilder { inner }
   }
 
+  /// `InputInlineQueryResultAudio` with the fields tdlib requires, the others are set by `InputInlineQueryResultAudio::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    title: impl AsRef<str>,
+    performer: impl AsRef<str>,
+    audio_url: impl AsRef<str>,
+    audio_duration: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .performer(performer)
+      .audio_url(audio_url)
+      .audio_duration(audio_duration)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -589,6 +771,12 @@ pub struct RTDInputInlineQueryResultAudioBuilder {
 impl RTDInputInlineQueryResultAudioBuilder {
   pub fn build(&self) -> InputInlineQueryResultAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -641,6 +829,25 @@ impl AsRef<InputInlineQueryResultAudio> for RTDInputInlineQueryResultAudioBuilde
   fn as_ref(&self) -> &InputInlineQueryResultAudio { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultAudio {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.performer == other.performer
+      && self.audio_url == other.audio_url
+      && self.audio_duration == other.audio_duration
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultAudio {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -655,7 +862,10 @@ pub struct InputInlineQueryResultContact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// User contact
@@ -674,8 +884,9 @@ pub struct InputInlineQueryResultContact {
 }
 
 impl RObject for InputInlineQueryResultContact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultContact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultContact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -685,14 +896,31 @@ impl TDInputInlineQueryResult for InputInlineQueryResultContact {}
 
 
 impl InputInlineQueryResultContact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultContactBuilder {
-    let mut inner = InputInlineQueryResultContact::default();
-    inner.td_name = "inputInlineQueryResultContact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultContact { td_name: "inputInlineQueryResultContact".to_string(), ..Default::default() };
     RTDInputInlineQueryResultContactBuilder { inner }
   }
 
+  /// `InputInlineQueryResultContact` with the fields tdlib requires, the others are set by `InputInlineQueryResultContact::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    contact: impl AsRef<Contact>,
+    thumbnail_url: impl AsRef<str>,
+    thumbnail_width: i64,
+    thumbnail_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .contact(contact)
+      .thumbnail_url(thumbnail_url)
+      .thumbnail_width(thumbnail_width)
+      .thumbnail_height(thumbnail_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn contact(&self) -> &Contact { &self.contact }
@@ -717,6 +945,12 @@ pub struct RTDInputInlineQueryResultContactBuilder {
 impl RTDInputInlineQueryResultContactBuilder {
   pub fn build(&self) -> InputInlineQueryResultContact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -769,6 +1003,26 @@ impl AsRef<InputInlineQueryResultContact> for RTDInputInlineQueryResultContactBu
   fn as_ref(&self) -> &InputInlineQueryResultContact { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultContact {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.contact == other.contact
+      && self.thumbnail_url == other.thumbnail_url
+      && self.thumbnail_width == other.thumbnail_width
+      && self.thumbnail_height == other.thumbnail_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultContact {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("contact", &self.contact);
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -783,12 +1037,15 @@ pub struct InputInlineQueryResultDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the resulting file
   title: String,
-  /// Represents a link to a file
+  /// Short description of the result, if known
   description: String,
   /// URL of the file
   document_url: String,
@@ -808,8 +1065,9 @@ pub struct InputInlineQueryResultDocument {
 }
 
 impl RObject for InputInlineQueryResultDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -819,14 +1077,38 @@ impl TDInputInlineQueryResult for InputInlineQueryResultDocument {}
 
 
 impl InputInlineQueryResultDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultDocumentBuilder {
-    let mut inner = InputInlineQueryResultDocument::default();
-    inner.td_name = "inputInlineQueryResultDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultDocument { td_name: "inputInlineQueryResultDocument".to_string(), ..Default::default() };
     RTDInputInlineQueryResultDocumentBuilder { inner }
   }
 
+  /// `InputInlineQueryResultDocument` with the fields tdlib requires, the others are set by `InputInlineQueryResultDocument::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: impl AsRef<str>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    document_url: impl AsRef<str>,
+    mime_type: impl AsRef<str>,
+    thumbnail_url: impl AsRef<str>,
+    thumbnail_width: i64,
+    thumbnail_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .description(description)
+      .document_url(document_url)
+      .mime_type(mime_type)
+      .thumbnail_url(thumbnail_url)
+      .thumbnail_width(thumbnail_width)
+      .thumbnail_height(thumbnail_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -857,6 +1139,12 @@ pub struct RTDInputInlineQueryResultDocumentBuilder {
 impl RTDInputInlineQueryResultDocumentBuilder {
   pub fn build(&self) -> InputInlineQueryResultDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -927,6 +1215,28 @@ impl AsRef<InputInlineQueryResultDocument> for RTDInputInlineQueryResultDocument
   fn as_ref(&self) -> &InputInlineQueryResultDocument { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.description == other.description
+      && self.document_url == other.document_url
+      && self.mime_type == other.mime_type
+      && self.thumbnail_url == other.thumbnail_url
+      && self.thumbnail_width == other.thumbnail_width
+      && self.thumbnail_height == other.thumbnail_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultDocument {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -941,7 +1251,10 @@ pub struct InputInlineQueryResultGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Short name of the game
@@ -952,8 +1265,9 @@ pub struct InputInlineQueryResultGame {
 }
 
 impl RObject for InputInlineQueryResultGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -963,14 +1277,15 @@ impl TDInputInlineQueryResult for InputInlineQueryResultGame {}
 
 
 impl InputInlineQueryResultGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultGameBuilder {
-    let mut inner = InputInlineQueryResultGame::default();
-    inner.td_name = "inputInlineQueryResultGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultGame { td_name: "inputInlineQueryResultGame".to_string(), ..Default::default() };
     RTDInputInlineQueryResultGameBuilder { inner }
   }
 
+  /// `InputInlineQueryResultGame` with the fields tdlib requires, the others are set by `InputInlineQueryResultGame::builder()`
+  pub fn new(id: impl AsRef<str>, game_short_name: impl AsRef<str>) -> Self { Self::builder().id(id).game_short_name(game_short_name).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn game_short_name(&self) -> &String { &self.game_short_name }
@@ -987,6 +1302,12 @@ pub struct RTDInputInlineQueryResultGameBuilder {
 impl RTDInputInlineQueryResultGameBuilder {
   pub fn build(&self) -> InputInlineQueryResultGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1015,6 +1336,20 @@ impl AsRef<InputInlineQueryResultGame> for RTDInputInlineQueryResultGameBuilder
   fn as_ref(&self) -> &InputInlineQueryResultGame { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultGame {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.game_short_name == other.game_short_name
+      && self.reply_markup == other.reply_markup
+  }
+}
+
+impl Validate for InputInlineQueryResultGame {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+  }
+}
+
 
 
 
@@ -1029,7 +1364,10 @@ pub struct InputInlineQueryResultLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Location result
@@ -1052,8 +1390,9 @@ pub struct InputInlineQueryResultLocation {
 }
 
 impl RObject for InputInlineQueryResultLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1063,14 +1402,36 @@ impl TDInputInlineQueryResult for InputInlineQueryResultLocation {}
 
 
 impl InputInlineQueryResultLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultLocationBuilder {
-    let mut inner = InputInlineQueryResultLocation::default();
-    inner.td_name = "inputInlineQueryResultLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultLocation { td_name: "inputInlineQueryResultLocation".to_string(), ..Default::default() };
     RTDInputInlineQueryResultLocationBuilder { inner }
   }
 
+  /// `InputInlineQueryResultLocation` with the fields tdlib requires, the others are set by `InputInlineQueryResultLocation::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: impl AsRef<str>,
+    location: impl AsRef<Location>,
+    live_period: i64,
+    title: impl AsRef<str>,
+    thumbnail_url: impl AsRef<str>,
+    thumbnail_width: i64,
+    thumbnail_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .location(location)
+      .live_period(live_period)
+      .title(title)
+      .thumbnail_url(thumbnail_url)
+      .thumbnail_width(thumbnail_width)
+      .thumbnail_height(thumbnail_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -1099,6 +1460,12 @@ pub struct RTDInputInlineQueryResultLocationBuilder {
 impl RTDInputInlineQueryResultLocationBuilder {
   pub fn build(&self) -> InputInlineQueryResultLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1163,6 +1530,27 @@ impl AsRef<InputInlineQueryResultLocation> for RTDInputInlineQueryResultLocation
   fn as_ref(&self) -> &InputInlineQueryResultLocation { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultLocation {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.location == other.location
+      && self.live_period == other.live_period
+      && self.title == other.title
+      && self.thumbnail_url == other.thumbnail_url
+      && self.thumbnail_width == other.thumbnail_width
+      && self.thumbnail_height == other.thumbnail_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultLocation {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -1177,12 +1565,15 @@ pub struct InputInlineQueryResultPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the result, if known
   title: String,
-  /// Represents link to a JPEG image
+  /// A short description of the result, if known
   description: String,
   /// URL of the photo thumbnail, if it exists
   thumbnail_url: String,
@@ -1200,8 +1591,9 @@ pub struct InputInlineQueryResultPhoto {
 }
 
 impl RObject for InputInlineQueryResultPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1211,14 +1603,36 @@ impl TDInputInlineQueryResult for InputInlineQueryResultPhoto {}
 
 
 impl InputInlineQueryResultPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultPhotoBuilder {
-    let mut inner = InputInlineQueryResultPhoto::default();
-    inner.td_name = "inputInlineQueryResultPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultPhoto { td_name: "inputInlineQueryResultPhoto".to_string(), ..Default::default() };
     RTDInputInlineQueryResultPhotoBuilder { inner }
   }
 
+  /// `InputInlineQueryResultPhoto` with the fields tdlib requires, the others are set by `InputInlineQueryResultPhoto::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: impl AsRef<str>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    thumbnail_url: impl AsRef<str>,
+    photo_url: impl AsRef<str>,
+    photo_width: i64,
+    photo_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .description(description)
+      .thumbnail_url(thumbnail_url)
+      .photo_url(photo_url)
+      .photo_width(photo_width)
+      .photo_height(photo_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -1247,6 +1661,12 @@ pub struct RTDInputInlineQueryResultPhotoBuilder {
 impl RTDInputInlineQueryResultPhotoBuilder {
   pub fn build(&self) -> InputInlineQueryResultPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1311,12 +1731,35 @@ impl AsRef<InputInlineQueryResultPhoto> for RTDInputInlineQueryResultPhotoBuilde
   fn as_ref(&self) -> &InputInlineQueryResultPhoto { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.description == other.description
+      && self.thumbnail_url == other.thumbnail_url
+      && self.photo_url == other.photo_url
+      && self.photo_width == other.photo_width
+      && self.photo_height == other.photo_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultPhoto {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// Represents a link to a WEBP or TGS sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InputInlineQueryResultSticker {
@@ -1325,7 +1768,10 @@ pub struct InputInlineQueryResultSticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// URL of the sticker thumbnail, if it exists
@@ -1344,8 +1790,9 @@ pub struct InputInlineQueryResultSticker {
 }
 
 impl RObject for InputInlineQueryResultSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultSticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultSticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1355,14 +1802,31 @@ impl TDInputInlineQueryResult for InputInlineQueryResultSticker {}
 
 
 impl InputInlineQueryResultSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultStickerBuilder {
-    let mut inner = InputInlineQueryResultSticker::default();
-    inner.td_name = "inputInlineQueryResultSticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultSticker { td_name: "inputInlineQueryResultSticker".to_string(), ..Default::default() };
     RTDInputInlineQueryResultStickerBuilder { inner }
   }
 
+  /// `InputInlineQueryResultSticker` with the fields tdlib requires, the others are set by `InputInlineQueryResultSticker::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    thumbnail_url: impl AsRef<str>,
+    sticker_url: impl AsRef<str>,
+    sticker_width: i64,
+    sticker_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .thumbnail_url(thumbnail_url)
+      .sticker_url(sticker_url)
+      .sticker_width(sticker_width)
+      .sticker_height(sticker_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn thumbnail_url(&self) -> &String { &self.thumbnail_url }
@@ -1387,6 +1851,12 @@ pub struct RTDInputInlineQueryResultStickerBuilder {
 impl RTDInputInlineQueryResultStickerBuilder {
   pub fn build(&self) -> InputInlineQueryResultSticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1439,6 +1909,27 @@ impl AsRef<InputInlineQueryResultSticker> for RTDInputInlineQueryResultStickerBu
   fn as_ref(&self) -> &InputInlineQueryResultSticker { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultSticker {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.thumbnail_url == other.thumbnail_url
+      && self.sticker_url == other.sticker_url
+      && self.sticker_width == other.sticker_width
+      && self.sticker_height == other.sticker_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultSticker {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
+}
+
 
 
 
@@ -1453,7 +1944,10 @@ pub struct InputInlineQueryResultVenue {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Venue result
@@ -1472,8 +1966,9 @@ pub struct InputInlineQueryResultVenue {
 }
 
 impl RObject for InputInlineQueryResultVenue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultVenue" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultVenue" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1483,14 +1978,31 @@ impl TDInputInlineQueryResult for InputInlineQueryResultVenue {}
 
 
 impl InputInlineQueryResultVenue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultVenueBuilder {
-    let mut inner = InputInlineQueryResultVenue::default();
-    inner.td_name = "inputInlineQueryResultVenue".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultVenue { td_name: "inputInlineQueryResultVenue".to_string(), ..Default::default() };
     RTDInputInlineQueryResultVenueBuilder { inner }
   }
 
+  /// `InputInlineQueryResultVenue` with the fields tdlib requires, the others are set by `InputInlineQueryResultVenue::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    venue: impl AsRef<Venue>,
+    thumbnail_url: impl AsRef<str>,
+    thumbnail_width: i64,
+    thumbnail_height: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .venue(venue)
+      .thumbnail_url(thumbnail_url)
+      .thumbnail_width(thumbnail_width)
+      .thumbnail_height(thumbnail_height)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn venue(&self) -> &Venue { &self.venue }
@@ -1515,6 +2027,12 @@ pub struct RTDInputInlineQueryResultVenueBuilder {
 impl RTDInputInlineQueryResultVenueBuilder {
   pub fn build(&self) -> InputInlineQueryResultVenue { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1567,6 +2085,25 @@ impl AsRef<InputInlineQueryResultVenue> for RTDInputInlineQueryResultVenueBuilde
   fn as_ref(&self) -> &InputInlineQueryResultVenue { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultVenue {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.venue == other.venue
+      && self.thumbnail_url == other.thumbnail_url
+      && self.thumbnail_width == other.thumbnail_width
+      && self.thumbnail_height == other.thumbnail_height
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultVenue {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -1581,12 +2118,15 @@ pub struct InputInlineQueryResultVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the result
   title: String,
-  /// Represents a link to a page containing an embedded video player or a video file
+  /// A short description of the result, if known
   description: String,
   /// The URL of the video thumbnail (JPEG), if it exists
   thumbnail_url: String,
@@ -1608,8 +2148,9 @@ pub struct InputInlineQueryResultVideo {
 }
 
 impl RObject for InputInlineQueryResultVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1619,14 +2160,40 @@ impl TDInputInlineQueryResult for InputInlineQueryResultVideo {}
 
 
 impl InputInlineQueryResultVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultVideoBuilder {
-    let mut inner = InputInlineQueryResultVideo::default();
-    inner.td_name = "inputInlineQueryResultVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultVideo { td_name: "inputInlineQueryResultVideo".to_string(), ..Default::default() };
     RTDInputInlineQueryResultVideoBuilder { inner }
   }
 
+  /// `InputInlineQueryResultVideo` with the fields tdlib requires, the others are set by `InputInlineQueryResultVideo::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: impl AsRef<str>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    thumbnail_url: impl AsRef<str>,
+    video_url: impl AsRef<str>,
+    mime_type: impl AsRef<str>,
+    video_width: i64,
+    video_height: i64,
+    video_duration: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .description(description)
+      .thumbnail_url(thumbnail_url)
+      .video_url(video_url)
+      .mime_type(mime_type)
+      .video_width(video_width)
+      .video_height(video_height)
+      .video_duration(video_duration)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -1659,6 +2226,12 @@ pub struct RTDInputInlineQueryResultVideoBuilder {
 impl RTDInputInlineQueryResultVideoBuilder {
   pub fn build(&self) -> InputInlineQueryResultVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1735,6 +2308,29 @@ impl AsRef<InputInlineQueryResultVideo> for RTDInputInlineQueryResultVideoBuilde
   fn as_ref(&self) -> &InputInlineQueryResultVideo { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultVideo {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.description == other.description
+      && self.thumbnail_url == other.thumbnail_url
+      && self.video_url == other.video_url
+      && self.mime_type == other.mime_type
+      && self.video_width == other.video_width
+      && self.video_height == other.video_height
+      && self.video_duration == other.video_duration
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultVideo {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
 
@@ -1749,7 +2345,10 @@ pub struct InputInlineQueryResultVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the query result
   id: String,
   /// Title of the voice note
@@ -1766,8 +2365,9 @@ pub struct InputInlineQueryResultVoiceNote {
 }
 
 impl RObject for InputInlineQueryResultVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputInlineQueryResultVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputInlineQueryResultVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1777,14 +2377,29 @@ impl TDInputInlineQueryResult for InputInlineQueryResultVoiceNote {}
 
 
 impl InputInlineQueryResultVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputInlineQueryResultVoiceNoteBuilder {
-    let mut inner = InputInlineQueryResultVoiceNote::default();
-    inner.td_name = "inputInlineQueryResultVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputInlineQueryResultVoiceNote { td_name: "inputInlineQueryResultVoiceNote".to_string(), ..Default::default() };
     RTDInputInlineQueryResultVoiceNoteBuilder { inner }
   }
 
+  /// `InputInlineQueryResultVoiceNote` with the fields tdlib requires, the others are set by `InputInlineQueryResultVoiceNote::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    title: impl AsRef<str>,
+    voice_note_url: impl AsRef<str>,
+    voice_note_duration: i64,
+    input_message_content: impl AsRef<InputMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .voice_note_url(voice_note_url)
+      .voice_note_duration(voice_note_duration)
+      .input_message_content(input_message_content)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -1807,6 +2422,12 @@ pub struct RTDInputInlineQueryResultVoiceNoteBuilder {
 impl RTDInputInlineQueryResultVoiceNoteBuilder {
   pub fn build(&self) -> InputInlineQueryResultVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -1853,5 +2474,23 @@ impl AsRef<InputInlineQueryResultVoiceNote> for RTDInputInlineQueryResultVoiceNo
   fn as_ref(&self) -> &InputInlineQueryResultVoiceNote { &self.inner }
 }
 
+impl PartialEq for InputInlineQueryResultVoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.voice_note_url == other.voice_note_url
+      && self.voice_note_duration == other.voice_note_duration
+      && self.reply_markup == other.reply_markup
+      && self.input_message_content == other.input_message_content
+  }
+}
+
+impl Validate for InputInlineQueryResultVoiceNote {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("reply_markup", &self.reply_markup);
+    checker.nested("input_message_content", &self.input_message_content);
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputMessageContent: Debug + RObject {}
 
 /// The content of a message to send
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputMessageContent {
   #[doc(hidden)] _Default(()),
@@ -34,6 +33,7 @@ pub enum InputMessageContent {
   /// A message with a game; not supported for channels or secret chats
   InputMessageGame(InputMessageGame),
   /// A message with an invoice; can be used only by bots
+  #[cfg(feature = "payments")]
   InputMessageInvoice(InputMessageInvoice),
   /// A message with a location
   InputMessageLocation(InputMessageLocation),
@@ -42,6 +42,7 @@ pub enum InputMessageContent {
   /// A message with a poll. Polls can't be sent to secret chats. Polls can be sent only to a private chat with a bot
   InputMessagePoll(InputMessagePoll),
   /// A sticker message
+  #[cfg(feature = "stickers")]
   InputMessageSticker(InputMessageSticker),
   /// A text message
   InputMessageText(InputMessageText),
@@ -53,6 +54,8 @@ pub enum InputMessageContent {
   InputMessageVideoNote(InputMessageVideoNote),
   /// A voice note message
   InputMessageVoiceNote(InputMessageVoiceNote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -64,7 +67,7 @@ impl<'de> Deserialize<'de> for InputMessageContent {
   fn deserialize<D>(deserializer: D) -> Result<InputMessageContent, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputMessageContent,
+      InputMessageContent, Unknown;
       (inputMessageAnimation, InputMessageAnimation);
       (inputMessageAudio, InputMessageAudio);
       (inputMessageContact, InputMessageContact);
@@ -72,11 +75,11 @@ impl<'de> Deserialize<'de> for InputMessageContent {
       (inputMessageDocument, InputMessageDocument);
       (inputMessageForwarded, InputMessageForwarded);
       (inputMessageGame, InputMessageGame);
-      (inputMessageInvoice, InputMessageInvoice);
+      #[cfg(feature = "payments")] (inputMessageInvoice, InputMessageInvoice);
       (inputMessageLocation, InputMessageLocation);
       (inputMessagePhoto, InputMessagePhoto);
       (inputMessagePoll, InputMessagePoll);
-      (inputMessageSticker, InputMessageSticker);
+      #[cfg(feature = "stickers")] (inputMessageSticker, InputMessageSticker);
       (inputMessageText, InputMessageText);
       (inputMessageVenue, InputMessageVenue);
       (inputMessageVideo, InputMessageVideo);
@@ -88,7 +91,7 @@ impl<'de> Deserialize<'de> for InputMessageContent {
 }
 
 impl RObject for InputMessageContent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputMessageContent::InputMessageAnimation(t) => t.td_name(),
       InputMessageContent::InputMessageAudio(t) => t.td_name(),
@@ -97,21 +100,22 @@ impl RObject for InputMessageContent {
       InputMessageContent::InputMessageDocument(t) => t.td_name(),
       InputMessageContent::InputMessageForwarded(t) => t.td_name(),
       InputMessageContent::InputMessageGame(t) => t.td_name(),
-      InputMessageContent::InputMessageInvoice(t) => t.td_name(),
+      #[cfg(feature = "payments")] InputMessageContent::InputMessageInvoice(t) => t.td_name(),
       InputMessageContent::InputMessageLocation(t) => t.td_name(),
       InputMessageContent::InputMessagePhoto(t) => t.td_name(),
       InputMessageContent::InputMessagePoll(t) => t.td_name(),
-      InputMessageContent::InputMessageSticker(t) => t.td_name(),
+      #[cfg(feature = "stickers")] InputMessageContent::InputMessageSticker(t) => t.td_name(),
       InputMessageContent::InputMessageText(t) => t.td_name(),
       InputMessageContent::InputMessageVenue(t) => t.td_name(),
       InputMessageContent::InputMessageVideo(t) => t.td_name(),
       InputMessageContent::InputMessageVideoNote(t) => t.td_name(),
       InputMessageContent::InputMessageVoiceNote(t) => t.td_name(),
+      InputMessageContent::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputMessageContent::InputMessageAnimation(t) => t.extra(),
       InputMessageContent::InputMessageAudio(t) => t.extra(),
@@ -120,16 +124,41 @@ impl RObject for InputMessageContent {
       InputMessageContent::InputMessageDocument(t) => t.extra(),
       InputMessageContent::InputMessageForwarded(t) => t.extra(),
       InputMessageContent::InputMessageGame(t) => t.extra(),
-      InputMessageContent::InputMessageInvoice(t) => t.extra(),
+      #[cfg(feature = "payments")] InputMessageContent::InputMessageInvoice(t) => t.extra(),
       InputMessageContent::InputMessageLocation(t) => t.extra(),
       InputMessageContent::InputMessagePhoto(t) => t.extra(),
       InputMessageContent::InputMessagePoll(t) => t.extra(),
-      InputMessageContent::InputMessageSticker(t) => t.extra(),
+      #[cfg(feature = "stickers")] InputMessageContent::InputMessageSticker(t) => t.extra(),
       InputMessageContent::InputMessageText(t) => t.extra(),
       InputMessageContent::InputMessageVenue(t) => t.extra(),
       InputMessageContent::InputMessageVideo(t) => t.extra(),
       InputMessageContent::InputMessageVideoNote(t) => t.extra(),
       InputMessageContent::InputMessageVoiceNote(t) => t.extra(),
+      InputMessageContent::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputMessageContent::InputMessageAnimation(t) => t.client_id(),
+      InputMessageContent::InputMessageAudio(t) => t.client_id(),
+      InputMessageContent::InputMessageContact(t) => t.client_id(),
+      InputMessageContent::InputMessageDice(t) => t.client_id(),
+      InputMessageContent::InputMessageDocument(t) => t.client_id(),
+      InputMessageContent::InputMessageForwarded(t) => t.client_id(),
+      InputMessageContent::InputMessageGame(t) => t.client_id(),
+      #[cfg(feature = "payments")] InputMessageContent::InputMessageInvoice(t) => t.client_id(),
+      InputMessageContent::InputMessageLocation(t) => t.client_id(),
+      InputMessageContent::InputMessagePhoto(t) => t.client_id(),
+      InputMessageContent::InputMessagePoll(t) => t.client_id(),
+      #[cfg(feature = "stickers")] InputMessageContent::InputMessageSticker(t) => t.client_id(),
+      InputMessageContent::InputMessageText(t) => t.client_id(),
+      InputMessageContent::InputMessageVenue(t) => t.client_id(),
+      InputMessageContent::InputMessageVideo(t) => t.client_id(),
+      InputMessageContent::InputMessageVideoNote(t) => t.client_id(),
+      InputMessageContent::InputMessageVoiceNote(t) => t.client_id(),
+      InputMessageContent::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -138,26 +167,27 @@ impl RObject for InputMessageContent {
 }
 
 impl InputMessageContent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputMessageContent::_Default(_) = self { true } else { false } }
-
-  pub fn is_input_message_animation(&self) -> bool { if let InputMessageContent::InputMessageAnimation(_) = self { true } else { false } }
-  pub fn is_input_message_audio(&self) -> bool { if let InputMessageContent::InputMessageAudio(_) = self { true } else { false } }
-  pub fn is_input_message_contact(&self) -> bool { if let InputMessageContent::InputMessageContact(_) = self { true } else { false } }
-  pub fn is_input_message_dice(&self) -> bool { if let InputMessageContent::InputMessageDice(_) = self { true } else { false } }
-  pub fn is_input_message_document(&self) -> bool { if let InputMessageContent::InputMessageDocument(_) = self { true } else { false } }
-  pub fn is_input_message_forwarded(&self) -> bool { if let InputMessageContent::InputMessageForwarded(_) = self { true } else { false } }
-  pub fn is_input_message_game(&self) -> bool { if let InputMessageContent::InputMessageGame(_) = self { true } else { false } }
-  pub fn is_input_message_invoice(&self) -> bool { if let InputMessageContent::InputMessageInvoice(_) = self { true } else { false } }
-  pub fn is_input_message_location(&self) -> bool { if let InputMessageContent::InputMessageLocation(_) = self { true } else { false } }
-  pub fn is_input_message_photo(&self) -> bool { if let InputMessageContent::InputMessagePhoto(_) = self { true } else { false } }
-  pub fn is_input_message_poll(&self) -> bool { if let InputMessageContent::InputMessagePoll(_) = self { true } else { false } }
-  pub fn is_input_message_sticker(&self) -> bool { if let InputMessageContent::InputMessageSticker(_) = self { true } else { false } }
-  pub fn is_input_message_text(&self) -> bool { if let InputMessageContent::InputMessageText(_) = self { true } else { false } }
-  pub fn is_input_message_venue(&self) -> bool { if let InputMessageContent::InputMessageVenue(_) = self { true } else { false } }
-  pub fn is_input_message_video(&self) -> bool { if let InputMessageContent::InputMessageVideo(_) = self { true } else { false } }
-  pub fn is_input_message_video_note(&self) -> bool { if let InputMessageContent::InputMessageVideoNote(_) = self { true } else { false } }
-  pub fn is_input_message_voice_note(&self) -> bool { if let InputMessageContent::InputMessageVoiceNote(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputMessageContent::_Default(_)) }
+
+  pub fn is_input_message_animation(&self) -> bool { matches!(self, InputMessageContent::InputMessageAnimation(_)) }
+  pub fn is_input_message_audio(&self) -> bool { matches!(self, InputMessageContent::InputMessageAudio(_)) }
+  pub fn is_input_message_contact(&self) -> bool { matches!(self, InputMessageContent::InputMessageContact(_)) }
+  pub fn is_input_message_dice(&self) -> bool { matches!(self, InputMessageContent::InputMessageDice(_)) }
+  pub fn is_input_message_document(&self) -> bool { matches!(self, InputMessageContent::InputMessageDocument(_)) }
+  pub fn is_input_message_forwarded(&self) -> bool { matches!(self, InputMessageContent::InputMessageForwarded(_)) }
+  pub fn is_input_message_game(&self) -> bool { matches!(self, InputMessageContent::InputMessageGame(_)) }
+  #[cfg(feature = "payments")] pub fn is_input_message_invoice(&self) -> bool { matches!(self, InputMessageContent::InputMessageInvoice(_)) }
+  pub fn is_input_message_location(&self) -> bool { matches!(self, InputMessageContent::InputMessageLocation(_)) }
+  pub fn is_input_message_photo(&self) -> bool { matches!(self, InputMessageContent::InputMessagePhoto(_)) }
+  pub fn is_input_message_poll(&self) -> bool { matches!(self, InputMessageContent::InputMessagePoll(_)) }
+  #[cfg(feature = "stickers")] pub fn is_input_message_sticker(&self) -> bool { matches!(self, InputMessageContent::InputMessageSticker(_)) }
+  pub fn is_input_message_text(&self) -> bool { matches!(self, InputMessageContent::InputMessageText(_)) }
+  pub fn is_input_message_venue(&self) -> bool { matches!(self, InputMessageContent::InputMessageVenue(_)) }
+  pub fn is_input_message_video(&self) -> bool { matches!(self, InputMessageContent::InputMessageVideo(_)) }
+  pub fn is_input_message_video_note(&self) -> bool { matches!(self, InputMessageContent::InputMessageVideoNote(_)) }
+  pub fn is_input_message_voice_note(&self) -> bool { matches!(self, InputMessageContent::InputMessageVoiceNote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputMessageContent::Unknown(_)) }
 
   pub fn on_input_message_animation<F: FnOnce(&InputMessageAnimation)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageAnimation(t) = self { fnc(t) }; self }
   pub fn on_input_message_audio<F: FnOnce(&InputMessageAudio)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageAudio(t) = self { fnc(t) }; self }
@@ -166,16 +196,17 @@ impl InputMessageContent {
   pub fn on_input_message_document<F: FnOnce(&InputMessageDocument)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageDocument(t) = self { fnc(t) }; self }
   pub fn on_input_message_forwarded<F: FnOnce(&InputMessageForwarded)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageForwarded(t) = self { fnc(t) }; self }
   pub fn on_input_message_game<F: FnOnce(&InputMessageGame)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageGame(t) = self { fnc(t) }; self }
-  pub fn on_input_message_invoice<F: FnOnce(&InputMessageInvoice)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageInvoice(t) = self { fnc(t) }; self }
+  #[cfg(feature = "payments")] pub fn on_input_message_invoice<F: FnOnce(&InputMessageInvoice)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageInvoice(t) = self { fnc(t) }; self }
   pub fn on_input_message_location<F: FnOnce(&InputMessageLocation)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageLocation(t) = self { fnc(t) }; self }
   pub fn on_input_message_photo<F: FnOnce(&InputMessagePhoto)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessagePhoto(t) = self { fnc(t) }; self }
   pub fn on_input_message_poll<F: FnOnce(&InputMessagePoll)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessagePoll(t) = self { fnc(t) }; self }
-  pub fn on_input_message_sticker<F: FnOnce(&InputMessageSticker)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageSticker(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_input_message_sticker<F: FnOnce(&InputMessageSticker)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageSticker(t) = self { fnc(t) }; self }
   pub fn on_input_message_text<F: FnOnce(&InputMessageText)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageText(t) = self { fnc(t) }; self }
   pub fn on_input_message_venue<F: FnOnce(&InputMessageVenue)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageVenue(t) = self { fnc(t) }; self }
   pub fn on_input_message_video<F: FnOnce(&InputMessageVideo)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageVideo(t) = self { fnc(t) }; self }
   pub fn on_input_message_video_note<F: FnOnce(&InputMessageVideoNote)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageVideoNote(t) = self { fnc(t) }; self }
   pub fn on_input_message_voice_note<F: FnOnce(&InputMessageVoiceNote)>(&self, fnc: F) -> &Self { if let InputMessageContent::InputMessageVoiceNote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputMessageContent::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_input_message_animation(&self) -> Option<&InputMessageAnimation> { if let InputMessageContent::InputMessageAnimation(t) = self { return Some(t) } None }
   pub fn as_input_message_audio(&self) -> Option<&InputMessageAudio> { if let InputMessageContent::InputMessageAudio(t) = self { return Some(t) } None }
@@ -184,16 +215,17 @@ impl InputMessageContent {
   pub fn as_input_message_document(&self) -> Option<&InputMessageDocument> { if let InputMessageContent::InputMessageDocument(t) = self { return Some(t) } None }
   pub fn as_input_message_forwarded(&self) -> Option<&InputMessageForwarded> { if let InputMessageContent::InputMessageForwarded(t) = self { return Some(t) } None }
   pub fn as_input_message_game(&self) -> Option<&InputMessageGame> { if let InputMessageContent::InputMessageGame(t) = self { return Some(t) } None }
-  pub fn as_input_message_invoice(&self) -> Option<&InputMessageInvoice> { if let InputMessageContent::InputMessageInvoice(t) = self { return Some(t) } None }
+  #[cfg(feature = "payments")] pub fn as_input_message_invoice(&self) -> Option<&InputMessageInvoice> { if let InputMessageContent::InputMessageInvoice(t) = self { return Some(t) } None }
   pub fn as_input_message_location(&self) -> Option<&InputMessageLocation> { if let InputMessageContent::InputMessageLocation(t) = self { return Some(t) } None }
   pub fn as_input_message_photo(&self) -> Option<&InputMessagePhoto> { if let InputMessageContent::InputMessagePhoto(t) = self { return Some(t) } None }
   pub fn as_input_message_poll(&self) -> Option<&InputMessagePoll> { if let InputMessageContent::InputMessagePoll(t) = self { return Some(t) } None }
-  pub fn as_input_message_sticker(&self) -> Option<&InputMessageSticker> { if let InputMessageContent::InputMessageSticker(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_input_message_sticker(&self) -> Option<&InputMessageSticker> { if let InputMessageContent::InputMessageSticker(t) = self { return Some(t) } None }
   pub fn as_input_message_text(&self) -> Option<&InputMessageText> { if let InputMessageContent::InputMessageText(t) = self { return Some(t) } None }
   pub fn as_input_message_venue(&self) -> Option<&InputMessageVenue> { if let InputMessageContent::InputMessageVenue(t) = self { return Some(t) } None }
   pub fn as_input_message_video(&self) -> Option<&InputMessageVideo> { if let InputMessageContent::InputMessageVideo(t) = self { return Some(t) } None }
   pub fn as_input_message_video_note(&self) -> Option<&InputMessageVideoNote> { if let InputMessageContent::InputMessageVideoNote(t) = self { return Some(t) } None }
   pub fn as_input_message_voice_note(&self) -> Option<&InputMessageVoiceNote> { if let InputMessageContent::InputMessageVoiceNote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputMessageContent::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -211,7 +243,7 @@ impl InputMessageContent {
 
   pub fn input_message_game<T: AsRef<InputMessageGame>>(t: T) -> Self { InputMessageContent::InputMessageGame(t.as_ref().clone()) }
 
-  pub fn input_message_invoice<T: AsRef<InputMessageInvoice>>(t: T) -> Self { InputMessageContent::InputMessageInvoice(t.as_ref().clone()) }
+  #[cfg(feature = "payments")] pub fn input_message_invoice<T: AsRef<InputMessageInvoice>>(t: T) -> Self { InputMessageContent::InputMessageInvoice(t.as_ref().clone()) }
 
   pub fn input_message_location<T: AsRef<InputMessageLocation>>(t: T) -> Self { InputMessageContent::InputMessageLocation(t.as_ref().clone()) }
 
@@ -219,7 +251,7 @@ impl InputMessageContent {
 
   pub fn input_message_poll<T: AsRef<InputMessagePoll>>(t: T) -> Self { InputMessageContent::InputMessagePoll(t.as_ref().clone()) }
 
-  pub fn input_message_sticker<T: AsRef<InputMessageSticker>>(t: T) -> Self { InputMessageContent::InputMessageSticker(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn input_message_sticker<T: AsRef<InputMessageSticker>>(t: T) -> Self { InputMessageContent::InputMessageSticker(t.as_ref().clone()) }
 
   pub fn input_message_text<T: AsRef<InputMessageText>>(t: T) -> Self { InputMessageContent::InputMessageText(t.as_ref().clone()) }
 
@@ -237,6 +269,24 @@ impl AsRef<InputMessageContent> for InputMessageContent {
   fn as_ref(&self) -> &InputMessageContent { self }
 }
 
+impl Validate for InputMessageContent {
+  fn check(&self, checker: &mut Checker) {
+    match self {
+      InputMessageContent::InputMessageAnimation(value) => value.check(checker),
+      InputMessageContent::InputMessageAudio(value) => value.check(checker),
+      InputMessageContent::InputMessageContact(value) => value.check(checker),
+      InputMessageContent::InputMessageDocument(value) => value.check(checker),
+      #[cfg(feature = "payments")] InputMessageContent::InputMessageInvoice(value) => value.check(checker),
+      InputMessageContent::InputMessagePhoto(value) => value.check(checker),
+      InputMessageContent::InputMessagePoll(value) => value.check(checker),
+      InputMessageContent::InputMessageText(value) => value.check(checker),
+      InputMessageContent::InputMessageVideo(value) => value.check(checker),
+      InputMessageContent::InputMessageVoiceNote(value) => value.check(checker),
+      _ => {}
+    }
+  }
+}
+
 
 
 
@@ -251,13 +301,16 @@ pub struct InputMessageAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Animation file to be sent
   animation: InputFile,
   /// Animation thumbnail; pass null to skip thumbnail uploading
   thumbnail: InputThumbnail,
   /// File identifiers of the stickers added to the animation, if applicable
-  added_sticker_file_ids: Vec<i64>,
+  added_sticker_file_ids: Vec<FileId>,
   /// Duration of the animation, in seconds
   duration: i64,
   /// Width of the animation; may be replaced by the server
@@ -270,8 +323,9 @@ pub struct InputMessageAnimation {
 }
 
 impl RObject for InputMessageAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -281,19 +335,34 @@ impl TDInputMessageContent for InputMessageAnimation {}
 
 
 impl InputMessageAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageAnimationBuilder {
-    let mut inner = InputMessageAnimation::default();
-    inner.td_name = "inputMessageAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageAnimation { td_name: "inputMessageAnimation".to_string(), ..Default::default() };
     RTDInputMessageAnimationBuilder { inner }
   }
 
+  /// `InputMessageAnimation` with the fields tdlib requires, the others are set by `InputMessageAnimation::builder()`
+  pub fn new(
+    animation: impl AsRef<InputFile>,
+    added_sticker_file_ids: Vec<FileId>,
+    duration: i64,
+    width: i64,
+    height: i64,
+  ) -> Self {
+    Self::builder()
+      .animation(animation)
+      .added_sticker_file_ids(added_sticker_file_ids)
+      .duration(duration)
+      .width(width)
+      .height(height)
+      .build()
+  }
+
   pub fn animation(&self) -> &InputFile { &self.animation }
 
   pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
 
-  pub fn added_sticker_file_ids(&self) -> &Vec<i64> { &self.added_sticker_file_ids }
+  pub fn added_sticker_file_ids(&self) -> &Vec<FileId> { &self.added_sticker_file_ids }
 
   pub fn duration(&self) -> i64 { self.duration }
 
@@ -313,6 +382,12 @@ pub struct RTDInputMessageAnimationBuilder {
 impl RTDInputMessageAnimationBuilder {
   pub fn build(&self) -> InputMessageAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -326,7 +401,7 @@ impl RTDInputMessageAnimationBuilder {
   }
 
    
-  pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<i64>) -> &mut Self {
+  pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<FileId>) -> &mut Self {
     self.inner.added_sticker_file_ids = added_sticker_file_ids;
     self
   }
@@ -365,6 +440,24 @@ impl AsRef<InputMessageAnimation> for RTDInputMessageAnimationBuilder {
   fn as_ref(&self) -> &InputMessageAnimation { &self.inner }
 }
 
+impl PartialEq for InputMessageAnimation {
+  fn eq(&self, other: &Self) -> bool {
+    self.animation == other.animation
+      && self.thumbnail == other.thumbnail
+      && self.added_sticker_file_ids == other.added_sticker_file_ids
+      && self.duration == other.duration
+      && self.width == other.width
+      && self.height == other.height
+      && self.caption == other.caption
+  }
+}
+
+impl Validate for InputMessageAnimation {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("caption", self.caption.text(), 0, 1024);
+  }
+}
+
 
 
 
@@ -379,7 +472,10 @@ pub struct InputMessageAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Audio file to be sent
   audio: InputFile,
   /// Thumbnail of the cover for the album; pass null to skip thumbnail uploading
@@ -396,8 +492,9 @@ pub struct InputMessageAudio {
 }
 
 impl RObject for InputMessageAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -407,14 +504,27 @@ impl TDInputMessageContent for InputMessageAudio {}
 
 
 impl InputMessageAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageAudioBuilder {
-    let mut inner = InputMessageAudio::default();
-    inner.td_name = "inputMessageAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageAudio { td_name: "inputMessageAudio".to_string(), ..Default::default() };
     RTDInputMessageAudioBuilder { inner }
   }
 
+  /// `InputMessageAudio` with the fields tdlib requires, the others are set by `InputMessageAudio::builder()`
+  pub fn new(
+    audio: impl AsRef<InputFile>,
+    duration: i64,
+    title: impl AsRef<str>,
+    performer: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .audio(audio)
+      .duration(duration)
+      .title(title)
+      .performer(performer)
+      .build()
+  }
+
   pub fn audio(&self) -> &InputFile { &self.audio }
 
   pub fn album_cover_thumbnail(&self) -> &InputThumbnail { &self.album_cover_thumbnail }
@@ -437,6 +547,12 @@ pub struct RTDInputMessageAudioBuilder {
 impl RTDInputMessageAudioBuilder {
   pub fn build(&self) -> InputMessageAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<InputFile>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
@@ -483,6 +599,25 @@ impl AsRef<InputMessageAudio> for RTDInputMessageAudioBuilder {
   fn as_ref(&self) -> &InputMessageAudio { &self.inner }
 }
 
+impl PartialEq for InputMessageAudio {
+  fn eq(&self, other: &Self) -> bool {
+    self.audio == other.audio
+      && self.album_cover_thumbnail == other.album_cover_thumbnail
+      && self.duration == other.duration
+      && self.title == other.title
+      && self.performer == other.performer
+      && self.caption == other.caption
+  }
+}
+
+impl Validate for InputMessageAudio {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("title", &self.title, 0, 64);
+    checker.length("performer", &self.performer, 0, 64);
+    checker.length("caption", self.caption.text(), 0, 1024);
+  }
+}
+
 
 
 
@@ -497,15 +632,19 @@ pub struct InputMessageContact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Contact to send
   contact: Contact,
   
 }
 
 impl RObject for InputMessageContact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageContact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageContact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -515,14 +654,15 @@ impl TDInputMessageContent for InputMessageContact {}
 
 
 impl InputMessageContact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageContactBuilder {
-    let mut inner = InputMessageContact::default();
-    inner.td_name = "inputMessageContact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageContact { td_name: "inputMessageContact".to_string(), ..Default::default() };
     RTDInputMessageContactBuilder { inner }
   }
 
+  /// `InputMessageContact` with the fields tdlib requires, the others are set by `InputMessageContact::builder()`
+  pub fn new(contact: impl AsRef<Contact>) -> Self { Self::builder().contact(contact).build() }
+
   pub fn contact(&self) -> &Contact { &self.contact }
 
 }
@@ -535,6 +675,12 @@ pub struct RTDInputMessageContactBuilder {
 impl RTDInputMessageContactBuilder {
   pub fn build(&self) -> InputMessageContact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
@@ -551,6 +697,18 @@ impl AsRef<InputMessageContact> for RTDInputMessageContactBuilder {
   fn as_ref(&self) -> &InputMessageContact { &self.inner }
 }
 
+impl PartialEq for InputMessageContact {
+  fn eq(&self, other: &Self) -> bool {
+    self.contact == other.contact
+  }
+}
+
+impl Validate for InputMessageContact {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("contact", &self.contact);
+  }
+}
+
 
 
 
@@ -565,7 +723,10 @@ pub struct InputMessageDice {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Emoji on which the dice throw animation is based
   emoji: String,
   /// True, if the chat message draft must be deleted
@@ -574,8 +735,9 @@ pub struct InputMessageDice {
 }
 
 impl RObject for InputMessageDice {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageDice" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageDice" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -585,14 +747,15 @@ impl TDInputMessageContent for InputMessageDice {}
 
 
 impl InputMessageDice {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageDiceBuilder {
-    let mut inner = InputMessageDice::default();
-    inner.td_name = "inputMessageDice".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageDice { td_name: "inputMessageDice".to_string(), ..Default::default() };
     RTDInputMessageDiceBuilder { inner }
   }
 
+  /// `InputMessageDice` with the fields tdlib requires, the others are set by `InputMessageDice::builder()`
+  pub fn new(emoji: impl AsRef<str>, clear_draft: bool) -> Self { Self::builder().emoji(emoji).clear_draft(clear_draft).build() }
+
   pub fn emoji(&self) -> &String { &self.emoji }
 
   pub fn clear_draft(&self) -> bool { self.clear_draft }
@@ -607,6 +770,12 @@ pub struct RTDInputMessageDiceBuilder {
 impl RTDInputMessageDiceBuilder {
   pub fn build(&self) -> InputMessageDice { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
@@ -629,6 +798,13 @@ impl AsRef<InputMessageDice> for RTDInputMessageDiceBuilder {
   fn as_ref(&self) -> &InputMessageDice { &self.inner }
 }
 
+impl PartialEq for InputMessageDice {
+  fn eq(&self, other: &Self) -> bool {
+    self.emoji == other.emoji
+      && self.clear_draft == other.clear_draft
+  }
+}
+
 
 
 
@@ -643,7 +819,10 @@ pub struct InputMessageDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Document to be sent
   document: InputFile,
   /// Document thumbnail; pass null to skip thumbnail uploading
@@ -656,8 +835,9 @@ pub struct InputMessageDocument {
 }
 
 impl RObject for InputMessageDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -667,14 +847,15 @@ impl TDInputMessageContent for InputMessageDocument {}
 
 
 impl InputMessageDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageDocumentBuilder {
-    let mut inner = InputMessageDocument::default();
-    inner.td_name = "inputMessageDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageDocument { td_name: "inputMessageDocument".to_string(), ..Default::default() };
     RTDInputMessageDocumentBuilder { inner }
   }
 
+  /// `InputMessageDocument` with the fields tdlib requires, the others are set by `InputMessageDocument::builder()`
+  pub fn new(document: impl AsRef<InputFile>, disable_content_type_detection: bool) -> Self { Self::builder().document(document).disable_content_type_detection(disable_content_type_detection).build() }
+
   pub fn document(&self) -> &InputFile { &self.document }
 
   pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
@@ -693,6 +874,12 @@ pub struct RTDInputMessageDocumentBuilder {
 impl RTDInputMessageDocumentBuilder {
   pub fn build(&self) -> InputMessageDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<InputFile>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
@@ -727,6 +914,21 @@ impl AsRef<InputMessageDocument> for RTDInputMessageDocumentBuilder {
   fn as_ref(&self) -> &InputMessageDocument { &self.inner }
 }
 
+impl PartialEq for InputMessageDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.document == other.document
+      && self.thumbnail == other.thumbnail
+      && self.disable_content_type_detection == other.disable_content_type_detection
+      && self.caption == other.caption
+  }
+}
+
+impl Validate for InputMessageDocument {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("caption", self.caption.text(), 0, 1024);
+  }
+}
+
 
 
 
@@ -741,11 +943,14 @@ pub struct InputMessageForwarded {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier for the chat this forwarded message came from
-  from_chat_id: i64,
+  from_chat_id: ChatId,
   /// Identifier of the message to forward
-  message_id: i64,
+  message_id: MessageId,
   /// True, if a game message is being shared from a launched game; applies only to game messages
   in_game_share: bool,
   /// Options to be used to copy content of the message without reference to the original sender; pass null to try to forward the message as usual
@@ -754,8 +959,9 @@ pub struct InputMessageForwarded {
 }
 
 impl RObject for InputMessageForwarded {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageForwarded" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageForwarded" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -765,17 +971,18 @@ impl TDInputMessageContent for InputMessageForwarded {}
 
 
 impl InputMessageForwarded {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageForwardedBuilder {
-    let mut inner = InputMessageForwarded::default();
-    inner.td_name = "inputMessageForwarded".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageForwarded { td_name: "inputMessageForwarded".to_string(), ..Default::default() };
     RTDInputMessageForwardedBuilder { inner }
   }
 
-  pub fn from_chat_id(&self) -> i64 { self.from_chat_id }
+  /// `InputMessageForwarded` with the fields tdlib requires, the others are set by `InputMessageForwarded::builder()`
+  pub fn new(from_chat_id: ChatId, message_id: MessageId, in_game_share: bool) -> Self { Self::builder().from_chat_id(from_chat_id).message_id(message_id).in_game_share(in_game_share).build() }
+
+  pub fn from_chat_id(&self) -> ChatId { self.from_chat_id }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn in_game_share(&self) -> bool { self.in_game_share }
 
@@ -791,14 +998,20 @@ pub struct RTDInputMessageForwardedBuilder {
 impl RTDInputMessageForwardedBuilder {
   pub fn build(&self) -> InputMessageForwarded { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn from_chat_id(&mut self, from_chat_id: i64) -> &mut Self {
+  pub fn from_chat_id(&mut self, from_chat_id: ChatId) -> &mut Self {
     self.inner.from_chat_id = from_chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -825,6 +1038,15 @@ impl AsRef<InputMessageForwarded> for RTDInputMessageForwardedBuilder {
   fn as_ref(&self) -> &InputMessageForwarded { &self.inner }
 }
 
+impl PartialEq for InputMessageForwarded {
+  fn eq(&self, other: &Self) -> bool {
+    self.from_chat_id == other.from_chat_id
+      && self.message_id == other.message_id
+      && self.in_game_share == other.in_game_share
+      && self.copy_options == other.copy_options
+  }
+}
+
 
 
 
@@ -839,17 +1061,21 @@ pub struct InputMessageGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier of the bot that owns the game
-  bot_user_id: i64,
+  bot_user_id: UserId,
   /// Short name of the game
   game_short_name: String,
   
 }
 
 impl RObject for InputMessageGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -859,15 +1085,16 @@ impl TDInputMessageContent for InputMessageGame {}
 
 
 impl InputMessageGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageGameBuilder {
-    let mut inner = InputMessageGame::default();
-    inner.td_name = "inputMessageGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageGame { td_name: "inputMessageGame".to_string(), ..Default::default() };
     RTDInputMessageGameBuilder { inner }
   }
 
-  pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
+  /// `InputMessageGame` with the fields tdlib requires, the others are set by `InputMessageGame::builder()`
+  pub fn new(bot_user_id: UserId, game_short_name: impl AsRef<str>) -> Self { Self::builder().bot_user_id(bot_user_id).game_short_name(game_short_name).build() }
+
+  pub fn bot_user_id(&self) -> UserId { self.bot_user_id }
 
   pub fn game_short_name(&self) -> &String { &self.game_short_name }
 
@@ -881,8 +1108,14 @@ pub struct RTDInputMessageGameBuilder {
 impl RTDInputMessageGameBuilder {
   pub fn build(&self) -> InputMessageGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
+  pub fn bot_user_id(&mut self, bot_user_id: UserId) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
     self
   }
@@ -903,11 +1136,20 @@ impl AsRef<InputMessageGame> for RTDInputMessageGameBuilder {
   fn as_ref(&self) -> &InputMessageGame { &self.inner }
 }
 
+impl PartialEq for InputMessageGame {
+  fn eq(&self, other: &Self) -> bool {
+    self.bot_user_id == other.bot_user_id
+      && self.game_short_name == other.game_short_name
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "payments")]
 
 /// A message with an invoice; can be used only by bots
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -917,12 +1159,15 @@ pub struct InputMessageInvoice {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Invoice
   invoice: Invoice,
   /// Product title; 1-32 characters
   title: String,
-  /// A message with an invoice; can be used only by bots
+  /// Product description; 0-255 characters
   description: String,
   /// Product photo URL; optional
   photo_url: String,
@@ -933,7 +1178,7 @@ pub struct InputMessageInvoice {
   /// Product photo height
   photo_height: i64,
   /// The invoice payload
-  payload: String,
+  payload: Bytes,
   /// Payment provider token
   provider_token: String,
   /// JSON-encoded data about the invoice, which will be shared with the payment provider
@@ -944,8 +1189,9 @@ pub struct InputMessageInvoice {
 }
 
 impl RObject for InputMessageInvoice {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageInvoice" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageInvoice" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -955,14 +1201,42 @@ impl TDInputMessageContent for InputMessageInvoice {}
 
 
 impl InputMessageInvoice {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageInvoiceBuilder {
-    let mut inner = InputMessageInvoice::default();
-    inner.td_name = "inputMessageInvoice".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageInvoice { td_name: "inputMessageInvoice".to_string(), ..Default::default() };
     RTDInputMessageInvoiceBuilder { inner }
   }
 
+  /// `InputMessageInvoice` with the fields tdlib requires, the others are set by `InputMessageInvoice::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    invoice: impl AsRef<Invoice>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    photo_url: impl AsRef<str>,
+    photo_size: i64,
+    photo_width: i64,
+    photo_height: i64,
+    payload: impl AsRef<Bytes>,
+    provider_token: impl AsRef<str>,
+    provider_data: impl AsRef<str>,
+    start_parameter: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .invoice(invoice)
+      .title(title)
+      .description(description)
+      .photo_url(photo_url)
+      .photo_size(photo_size)
+      .photo_width(photo_width)
+      .photo_height(photo_height)
+      .payload(payload)
+      .provider_token(provider_token)
+      .provider_data(provider_data)
+      .start_parameter(start_parameter)
+      .build()
+  }
+
   pub fn invoice(&self) -> &Invoice { &self.invoice }
 
   pub fn title(&self) -> &String { &self.title }
@@ -977,7 +1251,7 @@ impl InputMessageInvoice {
 
   pub fn photo_height(&self) -> i64 { self.photo_height }
 
-  pub fn payload(&self) -> &String { &self.payload }
+  pub fn payload(&self) -> &Bytes { &self.payload }
 
   pub fn provider_token(&self) -> &String { &self.provider_token }
 
@@ -995,6 +1269,12 @@ pub struct RTDInputMessageInvoiceBuilder {
 impl RTDInputMessageInvoiceBuilder {
   pub fn build(&self) -> InputMessageInvoice { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
     self.inner.invoice = invoice.as_ref().clone();
@@ -1038,8 +1318,8 @@ impl RTDInputMessageInvoiceBuilder {
   }
 
    
-  pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
-    self.inner.payload = payload.as_ref().to_string();
+  pub fn payload<T: AsRef<Bytes>>(&mut self, payload: T) -> &mut Self {
+    self.inner.payload = payload.as_ref().clone();
     self
   }
 
@@ -1071,6 +1351,31 @@ impl AsRef<InputMessageInvoice> for RTDInputMessageInvoiceBuilder {
   fn as_ref(&self) -> &InputMessageInvoice { &self.inner }
 }
 
+impl PartialEq for InputMessageInvoice {
+  fn eq(&self, other: &Self) -> bool {
+    self.invoice == other.invoice
+      && self.title == other.title
+      && self.description == other.description
+      && self.photo_url == other.photo_url
+      && self.photo_size == other.photo_size
+      && self.photo_width == other.photo_width
+      && self.photo_height == other.photo_height
+      && self.payload == other.payload
+      && self.provider_token == other.provider_token
+      && self.provider_data == other.provider_data
+      && self.start_parameter == other.start_parameter
+  }
+}
+
+impl Validate for InputMessageInvoice {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("title", &self.title, 1, 32);
+    checker.length("description", &self.description, 0, 255);
+  }
+}
+
+}
+
 
 
 
@@ -1085,7 +1390,10 @@ pub struct InputMessageLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Location to be sent
   location: Location,
   /// Period for which the location can be updated, in seconds; must be between 60 and 86400 for a live location and 0 otherwise
@@ -1098,8 +1406,9 @@ pub struct InputMessageLocation {
 }
 
 impl RObject for InputMessageLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1109,14 +1418,27 @@ impl TDInputMessageContent for InputMessageLocation {}
 
 
 impl InputMessageLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageLocationBuilder {
-    let mut inner = InputMessageLocation::default();
-    inner.td_name = "inputMessageLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageLocation { td_name: "inputMessageLocation".to_string(), ..Default::default() };
     RTDInputMessageLocationBuilder { inner }
   }
 
+  /// `InputMessageLocation` with the fields tdlib requires, the others are set by `InputMessageLocation::builder()`
+  pub fn new(
+    location: impl AsRef<Location>,
+    live_period: i64,
+    heading: i64,
+    proximity_alert_radius: i64,
+  ) -> Self {
+    Self::builder()
+      .location(location)
+      .live_period(live_period)
+      .heading(heading)
+      .proximity_alert_radius(proximity_alert_radius)
+      .build()
+  }
+
   pub fn location(&self) -> &Location { &self.location }
 
   pub fn live_period(&self) -> i64 { self.live_period }
@@ -1135,6 +1457,12 @@ pub struct RTDInputMessageLocationBuilder {
 impl RTDInputMessageLocationBuilder {
   pub fn build(&self) -> InputMessageLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -1169,6 +1497,15 @@ impl AsRef<InputMessageLocation> for RTDInputMessageLocationBuilder {
   fn as_ref(&self) -> &InputMessageLocation { &self.inner }
 }
 
+impl PartialEq for InputMessageLocation {
+  fn eq(&self, other: &Self) -> bool {
+    self.location == other.location
+      && self.live_period == other.live_period
+      && self.heading == other.heading
+      && self.proximity_alert_radius == other.proximity_alert_radius
+  }
+}
+
 
 
 
@@ -1183,13 +1520,16 @@ pub struct InputMessagePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Photo to send
   photo: InputFile,
   /// Photo thumbnail to be sent; pass null to skip thumbnail uploading. The thumbnail is sent to the other party only in secret chats
   thumbnail: InputThumbnail,
   /// File identifiers of the stickers added to the photo, if applicable
-  added_sticker_file_ids: Vec<i64>,
+  added_sticker_file_ids: Vec<FileId>,
   /// Photo width
   width: i64,
   /// Photo height
@@ -1202,8 +1542,9 @@ pub struct InputMessagePhoto {
 }
 
 impl RObject for InputMessagePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessagePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessagePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1213,19 +1554,34 @@ impl TDInputMessageContent for InputMessagePhoto {}
 
 
 impl InputMessagePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessagePhotoBuilder {
-    let mut inner = InputMessagePhoto::default();
-    inner.td_name = "inputMessagePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessagePhoto { td_name: "inputMessagePhoto".to_string(), ..Default::default() };
     RTDInputMessagePhotoBuilder { inner }
   }
 
+  /// `InputMessagePhoto` with the fields tdlib requires, the others are set by `InputMessagePhoto::builder()`
+  pub fn new(
+    photo: impl AsRef<InputFile>,
+    added_sticker_file_ids: Vec<FileId>,
+    width: i64,
+    height: i64,
+    ttl: i64,
+  ) -> Self {
+    Self::builder()
+      .photo(photo)
+      .added_sticker_file_ids(added_sticker_file_ids)
+      .width(width)
+      .height(height)
+      .ttl(ttl)
+      .build()
+  }
+
   pub fn photo(&self) -> &InputFile { &self.photo }
 
   pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
 
-  pub fn added_sticker_file_ids(&self) -> &Vec<i64> { &self.added_sticker_file_ids }
+  pub fn added_sticker_file_ids(&self) -> &Vec<FileId> { &self.added_sticker_file_ids }
 
   pub fn width(&self) -> i64 { self.width }
 
@@ -1245,6 +1601,12 @@ pub struct RTDInputMessagePhotoBuilder {
 impl RTDInputMessagePhotoBuilder {
   pub fn build(&self) -> InputMessagePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<InputFile>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -1258,7 +1620,7 @@ impl RTDInputMessagePhotoBuilder {
   }
 
    
-  pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<i64>) -> &mut Self {
+  pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<FileId>) -> &mut Self {
     self.inner.added_sticker_file_ids = added_sticker_file_ids;
     self
   }
@@ -1297,6 +1659,24 @@ impl AsRef<InputMessagePhoto> for RTDInputMessagePhotoBuilder {
   fn as_ref(&self) -> &InputMessagePhoto { &self.inner }
 }
 
+impl PartialEq for InputMessagePhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+      && self.thumbnail == other.thumbnail
+      && self.added_sticker_file_ids == other.added_sticker_file_ids
+      && self.width == other.width
+      && self.height == other.height
+      && self.caption == other.caption
+      && self.ttl == other.ttl
+  }
+}
+
+impl Validate for InputMessagePhoto {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("caption", self.caption.text(), 0, 1024);
+  }
+}
+
 
 
 
@@ -1311,7 +1691,10 @@ pub struct InputMessagePoll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Poll question; 1-255 characters (up to 300 characters for bots)
   question: String,
   /// List of poll answer options, 2-10 strings 1-100 characters each
@@ -1330,8 +1713,9 @@ pub struct InputMessagePoll {
 }
 
 impl RObject for InputMessagePoll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessagePoll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessagePoll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1341,14 +1725,33 @@ impl TDInputMessageContent for InputMessagePoll {}
 
 
 impl InputMessagePoll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessagePollBuilder {
-    let mut inner = InputMessagePoll::default();
-    inner.td_name = "inputMessagePoll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessagePoll { td_name: "inputMessagePoll".to_string(), ..Default::default() };
     RTDInputMessagePollBuilder { inner }
   }
 
+  /// `InputMessagePoll` with the fields tdlib requires, the others are set by `InputMessagePoll::builder()`
+  pub fn new(
+    question: impl AsRef<str>,
+    options: Vec<String>,
+    is_anonymous: bool,
+    type_: impl AsRef<PollType>,
+    open_period: i64,
+    close_date: i64,
+    is_closed: bool,
+  ) -> Self {
+    Self::builder()
+      .question(question)
+      .options(options)
+      .is_anonymous(is_anonymous)
+      .type_(type_)
+      .open_period(open_period)
+      .close_date(close_date)
+      .is_closed(is_closed)
+      .build()
+  }
+
   pub fn question(&self) -> &String { &self.question }
 
   pub fn options(&self) -> &Vec<String> { &self.options }
@@ -1373,6 +1776,12 @@ pub struct RTDInputMessagePollBuilder {
 impl RTDInputMessagePollBuilder {
   pub fn build(&self) -> InputMessagePoll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
     self.inner.question = question.as_ref().to_string();
@@ -1425,12 +1834,35 @@ impl AsRef<InputMessagePoll> for RTDInputMessagePollBuilder {
   fn as_ref(&self) -> &InputMessagePoll { &self.inner }
 }
 
+impl PartialEq for InputMessagePoll {
+  fn eq(&self, other: &Self) -> bool {
+    self.question == other.question
+      && self.options == other.options
+      && self.is_anonymous == other.is_anonymous
+      && self.type_ == other.type_
+      && self.open_period == other.open_period
+      && self.close_date == other.close_date
+      && self.is_closed == other.is_closed
+  }
+}
+
+impl Validate for InputMessagePoll {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("question", &self.question, 1, 300);
+    checker.each_length("options", &self.options, 1, 100);
+    checker.count("options", self.options.len(), 2, 10);
+    checker.nested("type", &self.type_);
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// A sticker message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InputMessageSticker {
@@ -1439,7 +1871,10 @@ pub struct InputMessageSticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Sticker to be sent
   sticker: InputFile,
   /// Sticker thumbnail; pass null to skip thumbnail uploading
@@ -1454,8 +1889,9 @@ pub struct InputMessageSticker {
 }
 
 impl RObject for InputMessageSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageSticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageSticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1465,14 +1901,27 @@ impl TDInputMessageContent for InputMessageSticker {}
 
 
 impl InputMessageSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageStickerBuilder {
-    let mut inner = InputMessageSticker::default();
-    inner.td_name = "inputMessageSticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageSticker { td_name: "inputMessageSticker".to_string(), ..Default::default() };
     RTDInputMessageStickerBuilder { inner }
   }
 
+  /// `InputMessageSticker` with the fields tdlib requires, the others are set by `InputMessageSticker::builder()`
+  pub fn new(
+    sticker: impl AsRef<InputFile>,
+    width: i64,
+    height: i64,
+    emoji: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .sticker(sticker)
+      .width(width)
+      .height(height)
+      .emoji(emoji)
+      .build()
+  }
+
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
   pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
@@ -1493,6 +1942,12 @@ pub struct RTDInputMessageStickerBuilder {
 impl RTDInputMessageStickerBuilder {
   pub fn build(&self) -> InputMessageSticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -1533,6 +1988,18 @@ impl AsRef<InputMessageSticker> for RTDInputMessageStickerBuilder {
   fn as_ref(&self) -> &InputMessageSticker { &self.inner }
 }
 
+impl PartialEq for InputMessageSticker {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker == other.sticker
+      && self.thumbnail == other.thumbnail
+      && self.width == other.width
+      && self.height == other.height
+      && self.emoji == other.emoji
+  }
+}
+
+}
+
 
 
 
@@ -1547,7 +2014,10 @@ pub struct InputMessageText {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Formatted text to be sent; 1-GetOption("message_text_length_max") characters. Only Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities are allowed to be specified manually
   text: FormattedText,
   /// True, if rich web page previews for URLs in the message text must be disabled
@@ -1558,8 +2028,9 @@ pub struct InputMessageText {
 }
 
 impl RObject for InputMessageText {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageText" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageText" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1569,14 +2040,15 @@ impl TDInputMessageContent for InputMessageText {}
 
 
 impl InputMessageText {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageTextBuilder {
-    let mut inner = InputMessageText::default();
-    inner.td_name = "inputMessageText".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageText { td_name: "inputMessageText".to_string(), ..Default::default() };
     RTDInputMessageTextBuilder { inner }
   }
 
+  /// `InputMessageText` with the fields tdlib requires, the others are set by `InputMessageText::builder()`
+  pub fn new(text: impl AsRef<FormattedText>, disable_web_page_preview: bool, clear_draft: bool) -> Self { Self::builder().text(text).disable_web_page_preview(disable_web_page_preview).clear_draft(clear_draft).build() }
+
   pub fn text(&self) -> &FormattedText { &self.text }
 
   pub fn disable_web_page_preview(&self) -> bool { self.disable_web_page_preview }
@@ -1593,6 +2065,12 @@ pub struct RTDInputMessageTextBuilder {
 impl RTDInputMessageTextBuilder {
   pub fn build(&self) -> InputMessageText { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1621,6 +2099,20 @@ impl AsRef<InputMessageText> for RTDInputMessageTextBuilder {
   fn as_ref(&self) -> &InputMessageText { &self.inner }
 }
 
+impl PartialEq for InputMessageText {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.disable_web_page_preview == other.disable_web_page_preview
+      && self.clear_draft == other.clear_draft
+  }
+}
+
+impl Validate for InputMessageText {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("text", self.text.text(), 1, 4096);
+  }
+}
+
 
 
 
@@ -1635,15 +2127,19 @@ pub struct InputMessageVenue {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Venue to send
   venue: Venue,
   
 }
 
 impl RObject for InputMessageVenue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVenue" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageVenue" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1653,14 +2149,15 @@ impl TDInputMessageContent for InputMessageVenue {}
 
 
 impl InputMessageVenue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageVenueBuilder {
-    let mut inner = InputMessageVenue::default();
-    inner.td_name = "inputMessageVenue".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageVenue { td_name: "inputMessageVenue".to_string(), ..Default::default() };
     RTDInputMessageVenueBuilder { inner }
   }
 
+  /// `InputMessageVenue` with the fields tdlib requires, the others are set by `InputMessageVenue::builder()`
+  pub fn new(venue: impl AsRef<Venue>) -> Self { Self::builder().venue(venue).build() }
+
   pub fn venue(&self) -> &Venue { &self.venue }
 
 }
@@ -1673,6 +2170,12 @@ pub struct RTDInputMessageVenueBuilder {
 impl RTDInputMessageVenueBuilder {
   pub fn build(&self) -> InputMessageVenue { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
@@ -1689,6 +2192,12 @@ impl AsRef<InputMessageVenue> for RTDInputMessageVenueBuilder {
   fn as_ref(&self) -> &InputMessageVenue { &self.inner }
 }
 
+impl PartialEq for InputMessageVenue {
+  fn eq(&self, other: &Self) -> bool {
+    self.venue == other.venue
+  }
+}
+
 
 
 
@@ -1703,13 +2212,16 @@ pub struct InputMessageVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Video to be sent
   video: InputFile,
   /// Video thumbnail; pass null to skip thumbnail uploading
   thumbnail: InputThumbnail,
   /// File identifiers of the stickers added to the video, if applicable
-  added_sticker_file_ids: Vec<i64>,
+  added_sticker_file_ids: Vec<FileId>,
   /// Duration of the video, in seconds
   duration: i64,
   /// Video width
@@ -1726,8 +2238,9 @@ pub struct InputMessageVideo {
 }
 
 impl RObject for InputMessageVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1737,19 +2250,38 @@ impl TDInputMessageContent for InputMessageVideo {}
 
 
 impl InputMessageVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageVideoBuilder {
-    let mut inner = InputMessageVideo::default();
-    inner.td_name = "inputMessageVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageVideo { td_name: "inputMessageVideo".to_string(), ..Default::default() };
     RTDInputMessageVideoBuilder { inner }
   }
 
+  /// `InputMessageVideo` with the fields tdlib requires, the others are set by `InputMessageVideo::builder()`
+  pub fn new(
+    video: impl AsRef<InputFile>,
+    added_sticker_file_ids: Vec<FileId>,
+    duration: i64,
+    width: i64,
+    height: i64,
+    supports_streaming: bool,
+    ttl: i64,
+  ) -> Self {
+    Self::builder()
+      .video(video)
+      .added_sticker_file_ids(added_sticker_file_ids)
+      .duration(duration)
+      .width(width)
+      .height(height)
+      .supports_streaming(supports_streaming)
+      .ttl(ttl)
+      .build()
+  }
+
   pub fn video(&self) -> &InputFile { &self.video }
 
   pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
 
-  pub fn added_sticker_file_ids(&self) -> &Vec<i64> { &self.added_sticker_file_ids }
+  pub fn added_sticker_file_ids(&self) -> &Vec<FileId> { &self.added_sticker_file_ids }
 
   pub fn duration(&self) -> i64 { self.duration }
 
@@ -1773,6 +2305,12 @@ pub struct RTDInputMessageVideoBuilder {
 impl RTDInputMessageVideoBuilder {
   pub fn build(&self) -> InputMessageVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<InputFile>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
@@ -1786,7 +2324,7 @@ impl RTDInputMessageVideoBuilder {
   }
 
    
-  pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<i64>) -> &mut Self {
+  pub fn added_sticker_file_ids(&mut self, added_sticker_file_ids: Vec<FileId>) -> &mut Self {
     self.inner.added_sticker_file_ids = added_sticker_file_ids;
     self
   }
@@ -1837,6 +2375,26 @@ impl AsRef<InputMessageVideo> for RTDInputMessageVideoBuilder {
   fn as_ref(&self) -> &InputMessageVideo { &self.inner }
 }
 
+impl PartialEq for InputMessageVideo {
+  fn eq(&self, other: &Self) -> bool {
+    self.video == other.video
+      && self.thumbnail == other.thumbnail
+      && self.added_sticker_file_ids == other.added_sticker_file_ids
+      && self.duration == other.duration
+      && self.width == other.width
+      && self.height == other.height
+      && self.supports_streaming == other.supports_streaming
+      && self.caption == other.caption
+      && self.ttl == other.ttl
+  }
+}
+
+impl Validate for InputMessageVideo {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("caption", self.caption.text(), 0, 1024);
+  }
+}
+
 
 
 
@@ -1851,7 +2409,10 @@ pub struct InputMessageVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Video note to be sent
   video_note: InputFile,
   /// Video thumbnail; pass null to skip thumbnail uploading
@@ -1864,8 +2425,9 @@ pub struct InputMessageVideoNote {
 }
 
 impl RObject for InputMessageVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1875,14 +2437,15 @@ impl TDInputMessageContent for InputMessageVideoNote {}
 
 
 impl InputMessageVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageVideoNoteBuilder {
-    let mut inner = InputMessageVideoNote::default();
-    inner.td_name = "inputMessageVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageVideoNote { td_name: "inputMessageVideoNote".to_string(), ..Default::default() };
     RTDInputMessageVideoNoteBuilder { inner }
   }
 
+  /// `InputMessageVideoNote` with the fields tdlib requires, the others are set by `InputMessageVideoNote::builder()`
+  pub fn new(video_note: impl AsRef<InputFile>, duration: i64, length: i64) -> Self { Self::builder().video_note(video_note).duration(duration).length(length).build() }
+
   pub fn video_note(&self) -> &InputFile { &self.video_note }
 
   pub fn thumbnail(&self) -> &InputThumbnail { &self.thumbnail }
@@ -1901,6 +2464,12 @@ pub struct RTDInputMessageVideoNoteBuilder {
 impl RTDInputMessageVideoNoteBuilder {
   pub fn build(&self) -> InputMessageVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video_note<T: AsRef<InputFile>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = video_note.as_ref().clone();
@@ -1935,6 +2504,15 @@ impl AsRef<InputMessageVideoNote> for RTDInputMessageVideoNoteBuilder {
   fn as_ref(&self) -> &InputMessageVideoNote { &self.inner }
 }
 
+impl PartialEq for InputMessageVideoNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.video_note == other.video_note
+      && self.thumbnail == other.thumbnail
+      && self.duration == other.duration
+      && self.length == other.length
+  }
+}
+
 
 
 
@@ -1949,21 +2527,25 @@ pub struct InputMessageVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Voice note to be sent
   voice_note: InputFile,
   /// Duration of the voice note, in seconds
   duration: i64,
   /// Waveform representation of the voice note, in 5-bit format
-  waveform: String,
+  waveform: Bytes,
   /// Voice note caption; pass null to use an empty caption; 0-GetOption("message_caption_length_max") characters
   caption: FormattedText,
   
 }
 
 impl RObject for InputMessageVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputMessageVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputMessageVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1973,19 +2555,20 @@ impl TDInputMessageContent for InputMessageVoiceNote {}
 
 
 impl InputMessageVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputMessageVoiceNoteBuilder {
-    let mut inner = InputMessageVoiceNote::default();
-    inner.td_name = "inputMessageVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputMessageVoiceNote { td_name: "inputMessageVoiceNote".to_string(), ..Default::default() };
     RTDInputMessageVoiceNoteBuilder { inner }
   }
 
+  /// `InputMessageVoiceNote` with the fields tdlib requires, the others are set by `InputMessageVoiceNote::builder()`
+  pub fn new(voice_note: impl AsRef<InputFile>, duration: i64, waveform: impl AsRef<Bytes>) -> Self { Self::builder().voice_note(voice_note).duration(duration).waveform(waveform).build() }
+
   pub fn voice_note(&self) -> &InputFile { &self.voice_note }
 
   pub fn duration(&self) -> i64 { self.duration }
 
-  pub fn waveform(&self) -> &String { &self.waveform }
+  pub fn waveform(&self) -> &Bytes { &self.waveform }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
 
@@ -1999,6 +2582,12 @@ pub struct RTDInputMessageVoiceNoteBuilder {
 impl RTDInputMessageVoiceNoteBuilder {
   pub fn build(&self) -> InputMessageVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<InputFile>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = voice_note.as_ref().clone();
@@ -2012,8 +2601,8 @@ impl RTDInputMessageVoiceNoteBuilder {
   }
 
    
-  pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
-    self.inner.waveform = waveform.as_ref().to_string();
+  pub fn waveform<T: AsRef<Bytes>>(&mut self, waveform: T) -> &mut Self {
+    self.inner.waveform = waveform.as_ref().clone();
     self
   }
 
@@ -2033,5 +2622,20 @@ impl AsRef<InputMessageVoiceNote> for RTDInputMessageVoiceNoteBuilder {
   fn as_ref(&self) -> &InputMessageVoiceNote { &self.inner }
 }
 
+impl PartialEq for InputMessageVoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.voice_note == other.voice_note
+      && self.duration == other.duration
+      && self.waveform == other.waveform
+      && self.caption == other.caption
+  }
+}
+
+impl Validate for InputMessageVoiceNote {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("caption", self.caption.text(), 0, 1024);
+  }
+}
+
 
 
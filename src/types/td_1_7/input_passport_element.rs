@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputPassportElement: Debug + RObject {}
 
 /// Contains information about a Telegram Passport element to be saved
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputPassportElement {
   #[doc(hidden)] _Default(()),
@@ -45,6 +44,8 @@ pub enum InputPassportElement {
   TemporaryRegistration(InputPassportElementTemporaryRegistration),
   /// A Telegram Passport element to be saved containing the user's utility bill
   UtilityBill(InputPassportElementUtilityBill),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -56,7 +57,7 @@ impl<'de> Deserialize<'de> for InputPassportElement {
   fn deserialize<D>(deserializer: D) -> Result<InputPassportElement, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputPassportElement,
+      InputPassportElement, Unknown;
       (inputPassportElementAddress, Address);
       (inputPassportElementBankStatement, BankStatement);
       (inputPassportElementDriverLicense, DriverLicense);
@@ -76,7 +77,7 @@ impl<'de> Deserialize<'de> for InputPassportElement {
 }
 
 impl RObject for InputPassportElement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputPassportElement::Address(t) => t.td_name(),
       InputPassportElement::BankStatement(t) => t.td_name(),
@@ -91,11 +92,12 @@ impl RObject for InputPassportElement {
       InputPassportElement::RentalAgreement(t) => t.td_name(),
       InputPassportElement::TemporaryRegistration(t) => t.td_name(),
       InputPassportElement::UtilityBill(t) => t.td_name(),
+      InputPassportElement::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputPassportElement::Address(t) => t.extra(),
       InputPassportElement::BankStatement(t) => t.extra(),
@@ -110,6 +112,27 @@ impl RObject for InputPassportElement {
       InputPassportElement::RentalAgreement(t) => t.extra(),
       InputPassportElement::TemporaryRegistration(t) => t.extra(),
       InputPassportElement::UtilityBill(t) => t.extra(),
+      InputPassportElement::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputPassportElement::Address(t) => t.client_id(),
+      InputPassportElement::BankStatement(t) => t.client_id(),
+      InputPassportElement::DriverLicense(t) => t.client_id(),
+      InputPassportElement::EmailAddress(t) => t.client_id(),
+      InputPassportElement::IdentityCard(t) => t.client_id(),
+      InputPassportElement::InternalPassport(t) => t.client_id(),
+      InputPassportElement::Passport(t) => t.client_id(),
+      InputPassportElement::PassportRegistration(t) => t.client_id(),
+      InputPassportElement::PersonalDetails(t) => t.client_id(),
+      InputPassportElement::PhoneNumber(t) => t.client_id(),
+      InputPassportElement::RentalAgreement(t) => t.client_id(),
+      InputPassportElement::TemporaryRegistration(t) => t.client_id(),
+      InputPassportElement::UtilityBill(t) => t.client_id(),
+      InputPassportElement::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -118,22 +141,23 @@ impl RObject for InputPassportElement {
 }
 
 impl InputPassportElement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputPassportElement::_Default(_) = self { true } else { false } }
-
-  pub fn is_address(&self) -> bool { if let InputPassportElement::Address(_) = self { true } else { false } }
-  pub fn is_bank_statement(&self) -> bool { if let InputPassportElement::BankStatement(_) = self { true } else { false } }
-  pub fn is_driver_license(&self) -> bool { if let InputPassportElement::DriverLicense(_) = self { true } else { false } }
-  pub fn is_email_address(&self) -> bool { if let InputPassportElement::EmailAddress(_) = self { true } else { false } }
-  pub fn is_identity_card(&self) -> bool { if let InputPassportElement::IdentityCard(_) = self { true } else { false } }
-  pub fn is_internal_passport(&self) -> bool { if let InputPassportElement::InternalPassport(_) = self { true } else { false } }
-  pub fn is_passport(&self) -> bool { if let InputPassportElement::Passport(_) = self { true } else { false } }
-  pub fn is_passport_registration(&self) -> bool { if let InputPassportElement::PassportRegistration(_) = self { true } else { false } }
-  pub fn is_personal_details(&self) -> bool { if let InputPassportElement::PersonalDetails(_) = self { true } else { false } }
-  pub fn is_phone_number(&self) -> bool { if let InputPassportElement::PhoneNumber(_) = self { true } else { false } }
-  pub fn is_rental_agreement(&self) -> bool { if let InputPassportElement::RentalAgreement(_) = self { true } else { false } }
-  pub fn is_temporary_registration(&self) -> bool { if let InputPassportElement::TemporaryRegistration(_) = self { true } else { false } }
-  pub fn is_utility_bill(&self) -> bool { if let InputPassportElement::UtilityBill(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputPassportElement::_Default(_)) }
+
+  pub fn is_address(&self) -> bool { matches!(self, InputPassportElement::Address(_)) }
+  pub fn is_bank_statement(&self) -> bool { matches!(self, InputPassportElement::BankStatement(_)) }
+  pub fn is_driver_license(&self) -> bool { matches!(self, InputPassportElement::DriverLicense(_)) }
+  pub fn is_email_address(&self) -> bool { matches!(self, InputPassportElement::EmailAddress(_)) }
+  pub fn is_identity_card(&self) -> bool { matches!(self, InputPassportElement::IdentityCard(_)) }
+  pub fn is_internal_passport(&self) -> bool { matches!(self, InputPassportElement::InternalPassport(_)) }
+  pub fn is_passport(&self) -> bool { matches!(self, InputPassportElement::Passport(_)) }
+  pub fn is_passport_registration(&self) -> bool { matches!(self, InputPassportElement::PassportRegistration(_)) }
+  pub fn is_personal_details(&self) -> bool { matches!(self, InputPassportElement::PersonalDetails(_)) }
+  pub fn is_phone_number(&self) -> bool { matches!(self, InputPassportElement::PhoneNumber(_)) }
+  pub fn is_rental_agreement(&self) -> bool { matches!(self, InputPassportElement::RentalAgreement(_)) }
+  pub fn is_temporary_registration(&self) -> bool { matches!(self, InputPassportElement::TemporaryRegistration(_)) }
+  pub fn is_utility_bill(&self) -> bool { matches!(self, InputPassportElement::UtilityBill(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputPassportElement::Unknown(_)) }
 
   pub fn on_address<F: FnOnce(&InputPassportElementAddress)>(&self, fnc: F) -> &Self { if let InputPassportElement::Address(t) = self { fnc(t) }; self }
   pub fn on_bank_statement<F: FnOnce(&InputPassportElementBankStatement)>(&self, fnc: F) -> &Self { if let InputPassportElement::BankStatement(t) = self { fnc(t) }; self }
@@ -148,6 +172,7 @@ impl InputPassportElement {
   pub fn on_rental_agreement<F: FnOnce(&InputPassportElementRentalAgreement)>(&self, fnc: F) -> &Self { if let InputPassportElement::RentalAgreement(t) = self { fnc(t) }; self }
   pub fn on_temporary_registration<F: FnOnce(&InputPassportElementTemporaryRegistration)>(&self, fnc: F) -> &Self { if let InputPassportElement::TemporaryRegistration(t) = self { fnc(t) }; self }
   pub fn on_utility_bill<F: FnOnce(&InputPassportElementUtilityBill)>(&self, fnc: F) -> &Self { if let InputPassportElement::UtilityBill(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputPassportElement::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_address(&self) -> Option<&InputPassportElementAddress> { if let InputPassportElement::Address(t) = self { return Some(t) } None }
   pub fn as_bank_statement(&self) -> Option<&InputPassportElementBankStatement> { if let InputPassportElement::BankStatement(t) = self { return Some(t) } None }
@@ -162,6 +187,7 @@ impl InputPassportElement {
   pub fn as_rental_agreement(&self) -> Option<&InputPassportElementRentalAgreement> { if let InputPassportElement::RentalAgreement(t) = self { return Some(t) } None }
   pub fn as_temporary_registration(&self) -> Option<&InputPassportElementTemporaryRegistration> { if let InputPassportElement::TemporaryRegistration(t) = self { return Some(t) } None }
   pub fn as_utility_bill(&self) -> Option<&InputPassportElementUtilityBill> { if let InputPassportElement::UtilityBill(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputPassportElement::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -197,6 +223,19 @@ impl AsRef<InputPassportElement> for InputPassportElement {
   fn as_ref(&self) -> &InputPassportElement { self }
 }
 
+impl Validate for InputPassportElement {
+  fn check(&self, checker: &mut Checker) {
+    match self {
+      InputPassportElement::DriverLicense(value) => value.check(checker),
+      InputPassportElement::IdentityCard(value) => value.check(checker),
+      InputPassportElement::InternalPassport(value) => value.check(checker),
+      InputPassportElement::Passport(value) => value.check(checker),
+      InputPassportElement::PersonalDetails(value) => value.check(checker),
+      _ => {}
+    }
+  }
+}
+
 
 
 
@@ -211,15 +250,19 @@ pub struct InputPassportElementAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The address to be saved
   address: Address,
   
 }
 
 impl RObject for InputPassportElementAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -229,14 +272,15 @@ impl TDInputPassportElement for InputPassportElementAddress {}
 
 
 impl InputPassportElementAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementAddressBuilder {
-    let mut inner = InputPassportElementAddress::default();
-    inner.td_name = "inputPassportElementAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementAddress { td_name: "inputPassportElementAddress".to_string(), ..Default::default() };
     RTDInputPassportElementAddressBuilder { inner }
   }
 
+  /// `InputPassportElementAddress` with the fields tdlib requires, the others are set by `InputPassportElementAddress::builder()`
+  pub fn new(address: impl AsRef<Address>) -> Self { Self::builder().address(address).build() }
+
   pub fn address(&self) -> &Address { &self.address }
 
 }
@@ -249,6 +293,12 @@ pub struct RTDInputPassportElementAddressBuilder {
 impl RTDInputPassportElementAddressBuilder {
   pub fn build(&self) -> InputPassportElementAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().clone();
@@ -265,6 +315,12 @@ impl AsRef<InputPassportElementAddress> for RTDInputPassportElementAddressBuilde
   fn as_ref(&self) -> &InputPassportElementAddress { &self.inner }
 }
 
+impl PartialEq for InputPassportElementAddress {
+  fn eq(&self, other: &Self) -> bool {
+    self.address == other.address
+  }
+}
+
 
 
 
@@ -279,15 +335,19 @@ pub struct InputPassportElementBankStatement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The bank statement to be saved
   bank_statement: InputPersonalDocument,
   
 }
 
 impl RObject for InputPassportElementBankStatement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementBankStatement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementBankStatement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -297,14 +357,15 @@ impl TDInputPassportElement for InputPassportElementBankStatement {}
 
 
 impl InputPassportElementBankStatement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementBankStatementBuilder {
-    let mut inner = InputPassportElementBankStatement::default();
-    inner.td_name = "inputPassportElementBankStatement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementBankStatement { td_name: "inputPassportElementBankStatement".to_string(), ..Default::default() };
     RTDInputPassportElementBankStatementBuilder { inner }
   }
 
+  /// `InputPassportElementBankStatement` with the fields tdlib requires, the others are set by `InputPassportElementBankStatement::builder()`
+  pub fn new(bank_statement: impl AsRef<InputPersonalDocument>) -> Self { Self::builder().bank_statement(bank_statement).build() }
+
   pub fn bank_statement(&self) -> &InputPersonalDocument { &self.bank_statement }
 
 }
@@ -317,6 +378,12 @@ pub struct RTDInputPassportElementBankStatementBuilder {
 impl RTDInputPassportElementBankStatementBuilder {
   pub fn build(&self) -> InputPassportElementBankStatement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bank_statement<T: AsRef<InputPersonalDocument>>(&mut self, bank_statement: T) -> &mut Self {
     self.inner.bank_statement = bank_statement.as_ref().clone();
@@ -333,6 +400,12 @@ impl AsRef<InputPassportElementBankStatement> for RTDInputPassportElementBankSta
   fn as_ref(&self) -> &InputPassportElementBankStatement { &self.inner }
 }
 
+impl PartialEq for InputPassportElementBankStatement {
+  fn eq(&self, other: &Self) -> bool {
+    self.bank_statement == other.bank_statement
+  }
+}
+
 
 
 
@@ -347,15 +420,19 @@ pub struct InputPassportElementDriverLicense {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The driver license to be saved
   driver_license: InputIdentityDocument,
   
 }
 
 impl RObject for InputPassportElementDriverLicense {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementDriverLicense" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementDriverLicense" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -365,14 +442,15 @@ impl TDInputPassportElement for InputPassportElementDriverLicense {}
 
 
 impl InputPassportElementDriverLicense {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementDriverLicenseBuilder {
-    let mut inner = InputPassportElementDriverLicense::default();
-    inner.td_name = "inputPassportElementDriverLicense".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementDriverLicense { td_name: "inputPassportElementDriverLicense".to_string(), ..Default::default() };
     RTDInputPassportElementDriverLicenseBuilder { inner }
   }
 
+  /// `InputPassportElementDriverLicense` with the fields tdlib requires, the others are set by `InputPassportElementDriverLicense::builder()`
+  pub fn new(driver_license: impl AsRef<InputIdentityDocument>) -> Self { Self::builder().driver_license(driver_license).build() }
+
   pub fn driver_license(&self) -> &InputIdentityDocument { &self.driver_license }
 
 }
@@ -385,6 +463,12 @@ pub struct RTDInputPassportElementDriverLicenseBuilder {
 impl RTDInputPassportElementDriverLicenseBuilder {
   pub fn build(&self) -> InputPassportElementDriverLicense { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn driver_license<T: AsRef<InputIdentityDocument>>(&mut self, driver_license: T) -> &mut Self {
     self.inner.driver_license = driver_license.as_ref().clone();
@@ -401,6 +485,18 @@ impl AsRef<InputPassportElementDriverLicense> for RTDInputPassportElementDriverL
   fn as_ref(&self) -> &InputPassportElementDriverLicense { &self.inner }
 }
 
+impl PartialEq for InputPassportElementDriverLicense {
+  fn eq(&self, other: &Self) -> bool {
+    self.driver_license == other.driver_license
+  }
+}
+
+impl Validate for InputPassportElementDriverLicense {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("driver_license", &self.driver_license);
+  }
+}
+
 
 
 
@@ -415,15 +511,19 @@ pub struct InputPassportElementEmailAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The email address to be saved
   email_address: String,
   
 }
 
 impl RObject for InputPassportElementEmailAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementEmailAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementEmailAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -433,14 +533,15 @@ impl TDInputPassportElement for InputPassportElementEmailAddress {}
 
 
 impl InputPassportElementEmailAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementEmailAddressBuilder {
-    let mut inner = InputPassportElementEmailAddress::default();
-    inner.td_name = "inputPassportElementEmailAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementEmailAddress { td_name: "inputPassportElementEmailAddress".to_string(), ..Default::default() };
     RTDInputPassportElementEmailAddressBuilder { inner }
   }
 
+  /// `InputPassportElementEmailAddress` with the fields tdlib requires, the others are set by `InputPassportElementEmailAddress::builder()`
+  pub fn new(email_address: impl AsRef<str>) -> Self { Self::builder().email_address(email_address).build() }
+
   pub fn email_address(&self) -> &String { &self.email_address }
 
 }
@@ -453,6 +554,12 @@ pub struct RTDInputPassportElementEmailAddressBuilder {
 impl RTDInputPassportElementEmailAddressBuilder {
   pub fn build(&self) -> InputPassportElementEmailAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
@@ -469,6 +576,12 @@ impl AsRef<InputPassportElementEmailAddress> for RTDInputPassportElementEmailAdd
   fn as_ref(&self) -> &InputPassportElementEmailAddress { &self.inner }
 }
 
+impl PartialEq for InputPassportElementEmailAddress {
+  fn eq(&self, other: &Self) -> bool {
+    self.email_address == other.email_address
+  }
+}
+
 
 
 
@@ -483,15 +596,19 @@ pub struct InputPassportElementIdentityCard {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The identity card to be saved
   identity_card: InputIdentityDocument,
   
 }
 
 impl RObject for InputPassportElementIdentityCard {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementIdentityCard" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementIdentityCard" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -501,14 +618,15 @@ impl TDInputPassportElement for InputPassportElementIdentityCard {}
 
 
 impl InputPassportElementIdentityCard {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementIdentityCardBuilder {
-    let mut inner = InputPassportElementIdentityCard::default();
-    inner.td_name = "inputPassportElementIdentityCard".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementIdentityCard { td_name: "inputPassportElementIdentityCard".to_string(), ..Default::default() };
     RTDInputPassportElementIdentityCardBuilder { inner }
   }
 
+  /// `InputPassportElementIdentityCard` with the fields tdlib requires, the others are set by `InputPassportElementIdentityCard::builder()`
+  pub fn new(identity_card: impl AsRef<InputIdentityDocument>) -> Self { Self::builder().identity_card(identity_card).build() }
+
   pub fn identity_card(&self) -> &InputIdentityDocument { &self.identity_card }
 
 }
@@ -521,6 +639,12 @@ pub struct RTDInputPassportElementIdentityCardBuilder {
 impl RTDInputPassportElementIdentityCardBuilder {
   pub fn build(&self) -> InputPassportElementIdentityCard { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn identity_card<T: AsRef<InputIdentityDocument>>(&mut self, identity_card: T) -> &mut Self {
     self.inner.identity_card = identity_card.as_ref().clone();
@@ -537,6 +661,18 @@ impl AsRef<InputPassportElementIdentityCard> for RTDInputPassportElementIdentity
   fn as_ref(&self) -> &InputPassportElementIdentityCard { &self.inner }
 }
 
+impl PartialEq for InputPassportElementIdentityCard {
+  fn eq(&self, other: &Self) -> bool {
+    self.identity_card == other.identity_card
+  }
+}
+
+impl Validate for InputPassportElementIdentityCard {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("identity_card", &self.identity_card);
+  }
+}
+
 
 
 
@@ -551,15 +687,19 @@ pub struct InputPassportElementInternalPassport {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The internal passport to be saved
   internal_passport: InputIdentityDocument,
   
 }
 
 impl RObject for InputPassportElementInternalPassport {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementInternalPassport" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementInternalPassport" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -569,14 +709,15 @@ impl TDInputPassportElement for InputPassportElementInternalPassport {}
 
 
 impl InputPassportElementInternalPassport {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementInternalPassportBuilder {
-    let mut inner = InputPassportElementInternalPassport::default();
-    inner.td_name = "inputPassportElementInternalPassport".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementInternalPassport { td_name: "inputPassportElementInternalPassport".to_string(), ..Default::default() };
     RTDInputPassportElementInternalPassportBuilder { inner }
   }
 
+  /// `InputPassportElementInternalPassport` with the fields tdlib requires, the others are set by `InputPassportElementInternalPassport::builder()`
+  pub fn new(internal_passport: impl AsRef<InputIdentityDocument>) -> Self { Self::builder().internal_passport(internal_passport).build() }
+
   pub fn internal_passport(&self) -> &InputIdentityDocument { &self.internal_passport }
 
 }
@@ -589,6 +730,12 @@ pub struct RTDInputPassportElementInternalPassportBuilder {
 impl RTDInputPassportElementInternalPassportBuilder {
   pub fn build(&self) -> InputPassportElementInternalPassport { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn internal_passport<T: AsRef<InputIdentityDocument>>(&mut self, internal_passport: T) -> &mut Self {
     self.inner.internal_passport = internal_passport.as_ref().clone();
@@ -605,6 +752,18 @@ impl AsRef<InputPassportElementInternalPassport> for RTDInputPassportElementInte
   fn as_ref(&self) -> &InputPassportElementInternalPassport { &self.inner }
 }
 
+impl PartialEq for InputPassportElementInternalPassport {
+  fn eq(&self, other: &Self) -> bool {
+    self.internal_passport == other.internal_passport
+  }
+}
+
+impl Validate for InputPassportElementInternalPassport {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("internal_passport", &self.internal_passport);
+  }
+}
+
 
 
 
@@ -619,15 +778,19 @@ pub struct InputPassportElementPassport {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The passport to be saved
   passport: InputIdentityDocument,
   
 }
 
 impl RObject for InputPassportElementPassport {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPassport" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementPassport" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -637,14 +800,15 @@ impl TDInputPassportElement for InputPassportElementPassport {}
 
 
 impl InputPassportElementPassport {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementPassportBuilder {
-    let mut inner = InputPassportElementPassport::default();
-    inner.td_name = "inputPassportElementPassport".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementPassport { td_name: "inputPassportElementPassport".to_string(), ..Default::default() };
     RTDInputPassportElementPassportBuilder { inner }
   }
 
+  /// `InputPassportElementPassport` with the fields tdlib requires, the others are set by `InputPassportElementPassport::builder()`
+  pub fn new(passport: impl AsRef<InputIdentityDocument>) -> Self { Self::builder().passport(passport).build() }
+
   pub fn passport(&self) -> &InputIdentityDocument { &self.passport }
 
 }
@@ -657,6 +821,12 @@ pub struct RTDInputPassportElementPassportBuilder {
 impl RTDInputPassportElementPassportBuilder {
   pub fn build(&self) -> InputPassportElementPassport { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport<T: AsRef<InputIdentityDocument>>(&mut self, passport: T) -> &mut Self {
     self.inner.passport = passport.as_ref().clone();
@@ -673,6 +843,18 @@ impl AsRef<InputPassportElementPassport> for RTDInputPassportElementPassportBuil
   fn as_ref(&self) -> &InputPassportElementPassport { &self.inner }
 }
 
+impl PartialEq for InputPassportElementPassport {
+  fn eq(&self, other: &Self) -> bool {
+    self.passport == other.passport
+  }
+}
+
+impl Validate for InputPassportElementPassport {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("passport", &self.passport);
+  }
+}
+
 
 
 
@@ -687,15 +869,19 @@ pub struct InputPassportElementPassportRegistration {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The passport registration page to be saved
   passport_registration: InputPersonalDocument,
   
 }
 
 impl RObject for InputPassportElementPassportRegistration {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPassportRegistration" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementPassportRegistration" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -705,14 +891,15 @@ impl TDInputPassportElement for InputPassportElementPassportRegistration {}
 
 
 impl InputPassportElementPassportRegistration {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementPassportRegistrationBuilder {
-    let mut inner = InputPassportElementPassportRegistration::default();
-    inner.td_name = "inputPassportElementPassportRegistration".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementPassportRegistration { td_name: "inputPassportElementPassportRegistration".to_string(), ..Default::default() };
     RTDInputPassportElementPassportRegistrationBuilder { inner }
   }
 
+  /// `InputPassportElementPassportRegistration` with the fields tdlib requires, the others are set by `InputPassportElementPassportRegistration::builder()`
+  pub fn new(passport_registration: impl AsRef<InputPersonalDocument>) -> Self { Self::builder().passport_registration(passport_registration).build() }
+
   pub fn passport_registration(&self) -> &InputPersonalDocument { &self.passport_registration }
 
 }
@@ -725,6 +912,12 @@ pub struct RTDInputPassportElementPassportRegistrationBuilder {
 impl RTDInputPassportElementPassportRegistrationBuilder {
   pub fn build(&self) -> InputPassportElementPassportRegistration { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport_registration<T: AsRef<InputPersonalDocument>>(&mut self, passport_registration: T) -> &mut Self {
     self.inner.passport_registration = passport_registration.as_ref().clone();
@@ -741,6 +934,12 @@ impl AsRef<InputPassportElementPassportRegistration> for RTDInputPassportElement
   fn as_ref(&self) -> &InputPassportElementPassportRegistration { &self.inner }
 }
 
+impl PartialEq for InputPassportElementPassportRegistration {
+  fn eq(&self, other: &Self) -> bool {
+    self.passport_registration == other.passport_registration
+  }
+}
+
 
 
 
@@ -755,15 +954,19 @@ pub struct InputPassportElementPersonalDetails {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Personal details of the user
   personal_details: PersonalDetails,
   
 }
 
 impl RObject for InputPassportElementPersonalDetails {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPersonalDetails" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementPersonalDetails" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -773,14 +976,15 @@ impl TDInputPassportElement for InputPassportElementPersonalDetails {}
 
 
 impl InputPassportElementPersonalDetails {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementPersonalDetailsBuilder {
-    let mut inner = InputPassportElementPersonalDetails::default();
-    inner.td_name = "inputPassportElementPersonalDetails".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementPersonalDetails { td_name: "inputPassportElementPersonalDetails".to_string(), ..Default::default() };
     RTDInputPassportElementPersonalDetailsBuilder { inner }
   }
 
+  /// `InputPassportElementPersonalDetails` with the fields tdlib requires, the others are set by `InputPassportElementPersonalDetails::builder()`
+  pub fn new(personal_details: impl AsRef<PersonalDetails>) -> Self { Self::builder().personal_details(personal_details).build() }
+
   pub fn personal_details(&self) -> &PersonalDetails { &self.personal_details }
 
 }
@@ -793,6 +997,12 @@ pub struct RTDInputPassportElementPersonalDetailsBuilder {
 impl RTDInputPassportElementPersonalDetailsBuilder {
   pub fn build(&self) -> InputPassportElementPersonalDetails { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn personal_details<T: AsRef<PersonalDetails>>(&mut self, personal_details: T) -> &mut Self {
     self.inner.personal_details = personal_details.as_ref().clone();
@@ -809,6 +1019,18 @@ impl AsRef<InputPassportElementPersonalDetails> for RTDInputPassportElementPerso
   fn as_ref(&self) -> &InputPassportElementPersonalDetails { &self.inner }
 }
 
+impl PartialEq for InputPassportElementPersonalDetails {
+  fn eq(&self, other: &Self) -> bool {
+    self.personal_details == other.personal_details
+  }
+}
+
+impl Validate for InputPassportElementPersonalDetails {
+  fn check(&self, checker: &mut Checker) {
+    checker.nested("personal_details", &self.personal_details);
+  }
+}
+
 
 
 
@@ -823,15 +1045,19 @@ pub struct InputPassportElementPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The phone number to be saved
   phone_number: String,
   
 }
 
 impl RObject for InputPassportElementPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -841,14 +1067,15 @@ impl TDInputPassportElement for InputPassportElementPhoneNumber {}
 
 
 impl InputPassportElementPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementPhoneNumberBuilder {
-    let mut inner = InputPassportElementPhoneNumber::default();
-    inner.td_name = "inputPassportElementPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementPhoneNumber { td_name: "inputPassportElementPhoneNumber".to_string(), ..Default::default() };
     RTDInputPassportElementPhoneNumberBuilder { inner }
   }
 
+  /// `InputPassportElementPhoneNumber` with the fields tdlib requires, the others are set by `InputPassportElementPhoneNumber::builder()`
+  pub fn new(phone_number: impl AsRef<str>) -> Self { Self::builder().phone_number(phone_number).build() }
+
   pub fn phone_number(&self) -> &String { &self.phone_number }
 
 }
@@ -861,6 +1088,12 @@ pub struct RTDInputPassportElementPhoneNumberBuilder {
 impl RTDInputPassportElementPhoneNumberBuilder {
   pub fn build(&self) -> InputPassportElementPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -877,6 +1110,12 @@ impl AsRef<InputPassportElementPhoneNumber> for RTDInputPassportElementPhoneNumb
   fn as_ref(&self) -> &InputPassportElementPhoneNumber { &self.inner }
 }
 
+impl PartialEq for InputPassportElementPhoneNumber {
+  fn eq(&self, other: &Self) -> bool {
+    self.phone_number == other.phone_number
+  }
+}
+
 
 
 
@@ -891,15 +1130,19 @@ pub struct InputPassportElementRentalAgreement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The rental agreement to be saved
   rental_agreement: InputPersonalDocument,
   
 }
 
 impl RObject for InputPassportElementRentalAgreement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementRentalAgreement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementRentalAgreement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -909,14 +1152,15 @@ impl TDInputPassportElement for InputPassportElementRentalAgreement {}
 
 
 impl InputPassportElementRentalAgreement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementRentalAgreementBuilder {
-    let mut inner = InputPassportElementRentalAgreement::default();
-    inner.td_name = "inputPassportElementRentalAgreement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementRentalAgreement { td_name: "inputPassportElementRentalAgreement".to_string(), ..Default::default() };
     RTDInputPassportElementRentalAgreementBuilder { inner }
   }
 
+  /// `InputPassportElementRentalAgreement` with the fields tdlib requires, the others are set by `InputPassportElementRentalAgreement::builder()`
+  pub fn new(rental_agreement: impl AsRef<InputPersonalDocument>) -> Self { Self::builder().rental_agreement(rental_agreement).build() }
+
   pub fn rental_agreement(&self) -> &InputPersonalDocument { &self.rental_agreement }
 
 }
@@ -929,6 +1173,12 @@ pub struct RTDInputPassportElementRentalAgreementBuilder {
 impl RTDInputPassportElementRentalAgreementBuilder {
   pub fn build(&self) -> InputPassportElementRentalAgreement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rental_agreement<T: AsRef<InputPersonalDocument>>(&mut self, rental_agreement: T) -> &mut Self {
     self.inner.rental_agreement = rental_agreement.as_ref().clone();
@@ -945,6 +1195,12 @@ impl AsRef<InputPassportElementRentalAgreement> for RTDInputPassportElementRenta
   fn as_ref(&self) -> &InputPassportElementRentalAgreement { &self.inner }
 }
 
+impl PartialEq for InputPassportElementRentalAgreement {
+  fn eq(&self, other: &Self) -> bool {
+    self.rental_agreement == other.rental_agreement
+  }
+}
+
 
 
 
@@ -959,15 +1215,19 @@ pub struct InputPassportElementTemporaryRegistration {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The temporary registration document to be saved
   temporary_registration: InputPersonalDocument,
   
 }
 
 impl RObject for InputPassportElementTemporaryRegistration {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementTemporaryRegistration" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementTemporaryRegistration" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -977,14 +1237,15 @@ impl TDInputPassportElement for InputPassportElementTemporaryRegistration {}
 
 
 impl InputPassportElementTemporaryRegistration {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementTemporaryRegistrationBuilder {
-    let mut inner = InputPassportElementTemporaryRegistration::default();
-    inner.td_name = "inputPassportElementTemporaryRegistration".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementTemporaryRegistration { td_name: "inputPassportElementTemporaryRegistration".to_string(), ..Default::default() };
     RTDInputPassportElementTemporaryRegistrationBuilder { inner }
   }
 
+  /// `InputPassportElementTemporaryRegistration` with the fields tdlib requires, the others are set by `InputPassportElementTemporaryRegistration::builder()`
+  pub fn new(temporary_registration: impl AsRef<InputPersonalDocument>) -> Self { Self::builder().temporary_registration(temporary_registration).build() }
+
   pub fn temporary_registration(&self) -> &InputPersonalDocument { &self.temporary_registration }
 
 }
@@ -997,6 +1258,12 @@ pub struct RTDInputPassportElementTemporaryRegistrationBuilder {
 impl RTDInputPassportElementTemporaryRegistrationBuilder {
   pub fn build(&self) -> InputPassportElementTemporaryRegistration { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn temporary_registration<T: AsRef<InputPersonalDocument>>(&mut self, temporary_registration: T) -> &mut Self {
     self.inner.temporary_registration = temporary_registration.as_ref().clone();
@@ -1013,6 +1280,12 @@ impl AsRef<InputPassportElementTemporaryRegistration> for RTDInputPassportElemen
   fn as_ref(&self) -> &InputPassportElementTemporaryRegistration { &self.inner }
 }
 
+impl PartialEq for InputPassportElementTemporaryRegistration {
+  fn eq(&self, other: &Self) -> bool {
+    self.temporary_registration == other.temporary_registration
+  }
+}
+
 
 
 
@@ -1027,15 +1300,19 @@ pub struct InputPassportElementUtilityBill {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The utility bill to be saved
   utility_bill: InputPersonalDocument,
   
 }
 
 impl RObject for InputPassportElementUtilityBill {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementUtilityBill" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementUtilityBill" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1045,14 +1322,15 @@ impl TDInputPassportElement for InputPassportElementUtilityBill {}
 
 
 impl InputPassportElementUtilityBill {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementUtilityBillBuilder {
-    let mut inner = InputPassportElementUtilityBill::default();
-    inner.td_name = "inputPassportElementUtilityBill".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementUtilityBill { td_name: "inputPassportElementUtilityBill".to_string(), ..Default::default() };
     RTDInputPassportElementUtilityBillBuilder { inner }
   }
 
+  /// `InputPassportElementUtilityBill` with the fields tdlib requires, the others are set by `InputPassportElementUtilityBill::builder()`
+  pub fn new(utility_bill: impl AsRef<InputPersonalDocument>) -> Self { Self::builder().utility_bill(utility_bill).build() }
+
   pub fn utility_bill(&self) -> &InputPersonalDocument { &self.utility_bill }
 
 }
@@ -1065,6 +1343,12 @@ pub struct RTDInputPassportElementUtilityBillBuilder {
 impl RTDInputPassportElementUtilityBillBuilder {
   pub fn build(&self) -> InputPassportElementUtilityBill { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn utility_bill<T: AsRef<InputPersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
     self.inner.utility_bill = utility_bill.as_ref().clone();
@@ -1081,5 +1365,11 @@ impl AsRef<InputPassportElementUtilityBill> for RTDInputPassportElementUtilityBi
   fn as_ref(&self) -> &InputPassportElementUtilityBill { &self.inner }
 }
 
+impl PartialEq for InputPassportElementUtilityBill {
+  fn eq(&self, other: &Self) -> bool {
+    self.utility_bill == other.utility_bill
+  }
+}
+
 
 
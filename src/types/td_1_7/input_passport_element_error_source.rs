@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputPassportElementErrorSource: Debug + RObject {}
 
 /// Contains the description of an error in a Telegram Passport element; for bots only
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputPassportElementErrorSource {
   #[doc(hidden)] _Default(()),
@@ -37,6 +36,8 @@ pub enum InputPassportElementErrorSource {
   TranslationFiles(InputPassportElementErrorSourceTranslationFiles),
   /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
   Unspecified(InputPassportElementErrorSourceUnspecified),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -48,7 +49,7 @@ impl<'de> Deserialize<'de> for InputPassportElementErrorSource {
   fn deserialize<D>(deserializer: D) -> Result<InputPassportElementErrorSource, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputPassportElementErrorSource,
+      InputPassportElementErrorSource, Unknown;
       (inputPassportElementErrorSourceDataField, DataField);
       (inputPassportElementErrorSourceFile, File);
       (inputPassportElementErrorSourceFiles, Files);
@@ -64,7 +65,7 @@ impl<'de> Deserialize<'de> for InputPassportElementErrorSource {
 }
 
 impl RObject for InputPassportElementErrorSource {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputPassportElementErrorSource::DataField(t) => t.td_name(),
       InputPassportElementErrorSource::File(t) => t.td_name(),
@@ -75,11 +76,12 @@ impl RObject for InputPassportElementErrorSource {
       InputPassportElementErrorSource::TranslationFile(t) => t.td_name(),
       InputPassportElementErrorSource::TranslationFiles(t) => t.td_name(),
       InputPassportElementErrorSource::Unspecified(t) => t.td_name(),
+      InputPassportElementErrorSource::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputPassportElementErrorSource::DataField(t) => t.extra(),
       InputPassportElementErrorSource::File(t) => t.extra(),
@@ -90,6 +92,23 @@ impl RObject for InputPassportElementErrorSource {
       InputPassportElementErrorSource::TranslationFile(t) => t.extra(),
       InputPassportElementErrorSource::TranslationFiles(t) => t.extra(),
       InputPassportElementErrorSource::Unspecified(t) => t.extra(),
+      InputPassportElementErrorSource::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputPassportElementErrorSource::DataField(t) => t.client_id(),
+      InputPassportElementErrorSource::File(t) => t.client_id(),
+      InputPassportElementErrorSource::Files(t) => t.client_id(),
+      InputPassportElementErrorSource::FrontSide(t) => t.client_id(),
+      InputPassportElementErrorSource::ReverseSide(t) => t.client_id(),
+      InputPassportElementErrorSource::Selfie(t) => t.client_id(),
+      InputPassportElementErrorSource::TranslationFile(t) => t.client_id(),
+      InputPassportElementErrorSource::TranslationFiles(t) => t.client_id(),
+      InputPassportElementErrorSource::Unspecified(t) => t.client_id(),
+      InputPassportElementErrorSource::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -98,18 +117,19 @@ impl RObject for InputPassportElementErrorSource {
 }
 
 impl InputPassportElementErrorSource {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputPassportElementErrorSource::_Default(_) = self { true } else { false } }
-
-  pub fn is_data_field(&self) -> bool { if let InputPassportElementErrorSource::DataField(_) = self { true } else { false } }
-  pub fn is_file(&self) -> bool { if let InputPassportElementErrorSource::File(_) = self { true } else { false } }
-  pub fn is_files(&self) -> bool { if let InputPassportElementErrorSource::Files(_) = self { true } else { false } }
-  pub fn is_front_side(&self) -> bool { if let InputPassportElementErrorSource::FrontSide(_) = self { true } else { false } }
-  pub fn is_reverse_side(&self) -> bool { if let InputPassportElementErrorSource::ReverseSide(_) = self { true } else { false } }
-  pub fn is_selfie(&self) -> bool { if let InputPassportElementErrorSource::Selfie(_) = self { true } else { false } }
-  pub fn is_translation_file(&self) -> bool { if let InputPassportElementErrorSource::TranslationFile(_) = self { true } else { false } }
-  pub fn is_translation_files(&self) -> bool { if let InputPassportElementErrorSource::TranslationFiles(_) = self { true } else { false } }
-  pub fn is_unspecified(&self) -> bool { if let InputPassportElementErrorSource::Unspecified(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputPassportElementErrorSource::_Default(_)) }
+
+  pub fn is_data_field(&self) -> bool { matches!(self, InputPassportElementErrorSource::DataField(_)) }
+  pub fn is_file(&self) -> bool { matches!(self, InputPassportElementErrorSource::File(_)) }
+  pub fn is_files(&self) -> bool { matches!(self, InputPassportElementErrorSource::Files(_)) }
+  pub fn is_front_side(&self) -> bool { matches!(self, InputPassportElementErrorSource::FrontSide(_)) }
+  pub fn is_reverse_side(&self) -> bool { matches!(self, InputPassportElementErrorSource::ReverseSide(_)) }
+  pub fn is_selfie(&self) -> bool { matches!(self, InputPassportElementErrorSource::Selfie(_)) }
+  pub fn is_translation_file(&self) -> bool { matches!(self, InputPassportElementErrorSource::TranslationFile(_)) }
+  pub fn is_translation_files(&self) -> bool { matches!(self, InputPassportElementErrorSource::TranslationFiles(_)) }
+  pub fn is_unspecified(&self) -> bool { matches!(self, InputPassportElementErrorSource::Unspecified(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputPassportElementErrorSource::Unknown(_)) }
 
   pub fn on_data_field<F: FnOnce(&InputPassportElementErrorSourceDataField)>(&self, fnc: F) -> &Self { if let InputPassportElementErrorSource::DataField(t) = self { fnc(t) }; self }
   pub fn on_file<F: FnOnce(&InputPassportElementErrorSourceFile)>(&self, fnc: F) -> &Self { if let InputPassportElementErrorSource::File(t) = self { fnc(t) }; self }
@@ -120,6 +140,7 @@ impl InputPassportElementErrorSource {
   pub fn on_translation_file<F: FnOnce(&InputPassportElementErrorSourceTranslationFile)>(&self, fnc: F) -> &Self { if let InputPassportElementErrorSource::TranslationFile(t) = self { fnc(t) }; self }
   pub fn on_translation_files<F: FnOnce(&InputPassportElementErrorSourceTranslationFiles)>(&self, fnc: F) -> &Self { if let InputPassportElementErrorSource::TranslationFiles(t) = self { fnc(t) }; self }
   pub fn on_unspecified<F: FnOnce(&InputPassportElementErrorSourceUnspecified)>(&self, fnc: F) -> &Self { if let InputPassportElementErrorSource::Unspecified(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputPassportElementErrorSource::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_data_field(&self) -> Option<&InputPassportElementErrorSourceDataField> { if let InputPassportElementErrorSource::DataField(t) = self { return Some(t) } None }
   pub fn as_file(&self) -> Option<&InputPassportElementErrorSourceFile> { if let InputPassportElementErrorSource::File(t) = self { return Some(t) } None }
@@ -130,6 +151,7 @@ impl InputPassportElementErrorSource {
   pub fn as_translation_file(&self) -> Option<&InputPassportElementErrorSourceTranslationFile> { if let InputPassportElementErrorSource::TranslationFile(t) = self { return Some(t) } None }
   pub fn as_translation_files(&self) -> Option<&InputPassportElementErrorSourceTranslationFiles> { if let InputPassportElementErrorSource::TranslationFiles(t) = self { return Some(t) } None }
   pub fn as_unspecified(&self) -> Option<&InputPassportElementErrorSourceUnspecified> { if let InputPassportElementErrorSource::Unspecified(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputPassportElementErrorSource::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -171,17 +193,21 @@ pub struct InputPassportElementErrorSourceDataField {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Field name
   field_name: String,
   /// Current data hash
-  data_hash: String,
+  data_hash: Bytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceDataField {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceDataField" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceDataField" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -191,17 +217,18 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceDataFi
 
 
 impl InputPassportElementErrorSourceDataField {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceDataFieldBuilder {
-    let mut inner = InputPassportElementErrorSourceDataField::default();
-    inner.td_name = "inputPassportElementErrorSourceDataField".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceDataField { td_name: "inputPassportElementErrorSourceDataField".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceDataFieldBuilder { inner }
   }
 
+  /// `InputPassportElementErrorSourceDataField` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceDataField::builder()`
+  pub fn new(field_name: impl AsRef<str>, data_hash: impl AsRef<Bytes>) -> Self { Self::builder().field_name(field_name).data_hash(data_hash).build() }
+
   pub fn field_name(&self) -> &String { &self.field_name }
 
-  pub fn data_hash(&self) -> &String { &self.data_hash }
+  pub fn data_hash(&self) -> &Bytes { &self.data_hash }
 
 }
 
@@ -213,6 +240,12 @@ pub struct RTDInputPassportElementErrorSourceDataFieldBuilder {
 impl RTDInputPassportElementErrorSourceDataFieldBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceDataField { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
     self.inner.field_name = field_name.as_ref().to_string();
@@ -220,8 +253,8 @@ impl RTDInputPassportElementErrorSourceDataFieldBuilder {
   }
 
    
-  pub fn data_hash<T: AsRef<str>>(&mut self, data_hash: T) -> &mut Self {
-    self.inner.data_hash = data_hash.as_ref().to_string();
+  pub fn data_hash<T: AsRef<Bytes>>(&mut self, data_hash: T) -> &mut Self {
+    self.inner.data_hash = data_hash.as_ref().clone();
     self
   }
 
@@ -235,6 +268,13 @@ impl AsRef<InputPassportElementErrorSourceDataField> for RTDInputPassportElement
   fn as_ref(&self) -> &InputPassportElementErrorSourceDataField { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceDataField {
+  fn eq(&self, other: &Self) -> bool {
+    self.field_name == other.field_name
+      && self.data_hash == other.data_hash
+  }
+}
+
 
 
 
@@ -249,15 +289,19 @@ pub struct InputPassportElementErrorSourceFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hash of the file which has the error
-  file_hash: String,
+  file_hash: Bytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -267,15 +311,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceFile {
 
 
 impl InputPassportElementErrorSourceFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceFileBuilder {
-    let mut inner = InputPassportElementErrorSourceFile::default();
-    inner.td_name = "inputPassportElementErrorSourceFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceFile { td_name: "inputPassportElementErrorSourceFile".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceFileBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  /// `InputPassportElementErrorSourceFile` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceFile::builder()`
+  pub fn new(file_hash: impl AsRef<Bytes>) -> Self { Self::builder().file_hash(file_hash).build() }
+
+  pub fn file_hash(&self) -> &Bytes { &self.file_hash }
 
 }
 
@@ -287,9 +332,15 @@ pub struct RTDInputPassportElementErrorSourceFileBuilder {
 impl RTDInputPassportElementErrorSourceFileBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<Bytes>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = file_hash.as_ref().clone();
     self
   }
 
@@ -303,6 +354,12 @@ impl AsRef<InputPassportElementErrorSourceFile> for RTDInputPassportElementError
   fn as_ref(&self) -> &InputPassportElementErrorSourceFile { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_hash == other.file_hash
+  }
+}
+
 
 
 
@@ -317,15 +374,19 @@ pub struct InputPassportElementErrorSourceFiles {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hashes of all attached files
-  file_hashes: Vec<String>,
+  file_hashes: Vec<Bytes>,
   
 }
 
 impl RObject for InputPassportElementErrorSourceFiles {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceFiles" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceFiles" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -335,15 +396,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceFiles
 
 
 impl InputPassportElementErrorSourceFiles {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceFilesBuilder {
-    let mut inner = InputPassportElementErrorSourceFiles::default();
-    inner.td_name = "inputPassportElementErrorSourceFiles".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceFiles { td_name: "inputPassportElementErrorSourceFiles".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceFilesBuilder { inner }
   }
 
-  pub fn file_hashes(&self) -> &Vec<String> { &self.file_hashes }
+  /// `InputPassportElementErrorSourceFiles` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceFiles::builder()`
+  pub fn new(file_hashes: Vec<Bytes>) -> Self { Self::builder().file_hashes(file_hashes).build() }
+
+  pub fn file_hashes(&self) -> &Vec<Bytes> { &self.file_hashes }
 
 }
 
@@ -355,8 +417,14 @@ pub struct RTDInputPassportElementErrorSourceFilesBuilder {
 impl RTDInputPassportElementErrorSourceFilesBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFiles { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
+  pub fn file_hashes(&mut self, file_hashes: Vec<Bytes>) -> &mut Self {
     self.inner.file_hashes = file_hashes;
     self
   }
@@ -371,6 +439,12 @@ impl AsRef<InputPassportElementErrorSourceFiles> for RTDInputPassportElementErro
   fn as_ref(&self) -> &InputPassportElementErrorSourceFiles { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceFiles {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_hashes == other.file_hashes
+  }
+}
+
 
 
 
@@ -385,15 +459,19 @@ pub struct InputPassportElementErrorSourceFrontSide {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hash of the file containing the front side
-  file_hash: String,
+  file_hash: Bytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceFrontSide {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceFrontSide" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceFrontSide" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -403,15 +481,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceFrontS
 
 
 impl InputPassportElementErrorSourceFrontSide {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceFrontSideBuilder {
-    let mut inner = InputPassportElementErrorSourceFrontSide::default();
-    inner.td_name = "inputPassportElementErrorSourceFrontSide".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceFrontSide { td_name: "inputPassportElementErrorSourceFrontSide".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceFrontSideBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  /// `InputPassportElementErrorSourceFrontSide` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceFrontSide::builder()`
+  pub fn new(file_hash: impl AsRef<Bytes>) -> Self { Self::builder().file_hash(file_hash).build() }
+
+  pub fn file_hash(&self) -> &Bytes { &self.file_hash }
 
 }
 
@@ -423,9 +502,15 @@ pub struct RTDInputPassportElementErrorSourceFrontSideBuilder {
 impl RTDInputPassportElementErrorSourceFrontSideBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFrontSide { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<Bytes>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = file_hash.as_ref().clone();
     self
   }
 
@@ -439,6 +524,12 @@ impl AsRef<InputPassportElementErrorSourceFrontSide> for RTDInputPassportElement
   fn as_ref(&self) -> &InputPassportElementErrorSourceFrontSide { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceFrontSide {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_hash == other.file_hash
+  }
+}
+
 
 
 
@@ -453,15 +544,19 @@ pub struct InputPassportElementErrorSourceReverseSide {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hash of the file containing the reverse side
-  file_hash: String,
+  file_hash: Bytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceReverseSide {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceReverseSide" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceReverseSide" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -471,15 +566,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceRevers
 
 
 impl InputPassportElementErrorSourceReverseSide {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceReverseSideBuilder {
-    let mut inner = InputPassportElementErrorSourceReverseSide::default();
-    inner.td_name = "inputPassportElementErrorSourceReverseSide".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceReverseSide { td_name: "inputPassportElementErrorSourceReverseSide".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceReverseSideBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  /// `InputPassportElementErrorSourceReverseSide` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceReverseSide::builder()`
+  pub fn new(file_hash: impl AsRef<Bytes>) -> Self { Self::builder().file_hash(file_hash).build() }
+
+  pub fn file_hash(&self) -> &Bytes { &self.file_hash }
 
 }
 
@@ -491,9 +587,15 @@ pub struct RTDInputPassportElementErrorSourceReverseSideBuilder {
 impl RTDInputPassportElementErrorSourceReverseSideBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceReverseSide { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<Bytes>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = file_hash.as_ref().clone();
     self
   }
 
@@ -507,6 +609,12 @@ impl AsRef<InputPassportElementErrorSourceReverseSide> for RTDInputPassportEleme
   fn as_ref(&self) -> &InputPassportElementErrorSourceReverseSide { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceReverseSide {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_hash == other.file_hash
+  }
+}
+
 
 
 
@@ -521,15 +629,19 @@ pub struct InputPassportElementErrorSourceSelfie {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hash of the file containing the selfie
-  file_hash: String,
+  file_hash: Bytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceSelfie {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceSelfie" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceSelfie" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -539,15 +651,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceSelfie
 
 
 impl InputPassportElementErrorSourceSelfie {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceSelfieBuilder {
-    let mut inner = InputPassportElementErrorSourceSelfie::default();
-    inner.td_name = "inputPassportElementErrorSourceSelfie".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceSelfie { td_name: "inputPassportElementErrorSourceSelfie".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceSelfieBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  /// `InputPassportElementErrorSourceSelfie` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceSelfie::builder()`
+  pub fn new(file_hash: impl AsRef<Bytes>) -> Self { Self::builder().file_hash(file_hash).build() }
+
+  pub fn file_hash(&self) -> &Bytes { &self.file_hash }
 
 }
 
@@ -559,9 +672,15 @@ pub struct RTDInputPassportElementErrorSourceSelfieBuilder {
 impl RTDInputPassportElementErrorSourceSelfieBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceSelfie { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<Bytes>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = file_hash.as_ref().clone();
     self
   }
 
@@ -575,6 +694,12 @@ impl AsRef<InputPassportElementErrorSourceSelfie> for RTDInputPassportElementErr
   fn as_ref(&self) -> &InputPassportElementErrorSourceSelfie { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceSelfie {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_hash == other.file_hash
+  }
+}
+
 
 
 
@@ -589,15 +714,19 @@ pub struct InputPassportElementErrorSourceTranslationFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hash of the file containing the translation
-  file_hash: String,
+  file_hash: Bytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceTranslationFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceTranslationFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceTranslationFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -607,15 +736,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceTransl
 
 
 impl InputPassportElementErrorSourceTranslationFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceTranslationFileBuilder {
-    let mut inner = InputPassportElementErrorSourceTranslationFile::default();
-    inner.td_name = "inputPassportElementErrorSourceTranslationFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceTranslationFile { td_name: "inputPassportElementErrorSourceTranslationFile".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceTranslationFileBuilder { inner }
   }
 
-  pub fn file_hash(&self) -> &String { &self.file_hash }
+  /// `InputPassportElementErrorSourceTranslationFile` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceTranslationFile::builder()`
+  pub fn new(file_hash: impl AsRef<Bytes>) -> Self { Self::builder().file_hash(file_hash).build() }
+
+  pub fn file_hash(&self) -> &Bytes { &self.file_hash }
 
 }
 
@@ -627,9 +757,15 @@ pub struct RTDInputPassportElementErrorSourceTranslationFileBuilder {
 impl RTDInputPassportElementErrorSourceTranslationFileBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceTranslationFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
-    self.inner.file_hash = file_hash.as_ref().to_string();
+  pub fn file_hash<T: AsRef<Bytes>>(&mut self, file_hash: T) -> &mut Self {
+    self.inner.file_hash = file_hash.as_ref().clone();
     self
   }
 
@@ -643,6 +779,12 @@ impl AsRef<InputPassportElementErrorSourceTranslationFile> for RTDInputPassportE
   fn as_ref(&self) -> &InputPassportElementErrorSourceTranslationFile { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceTranslationFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_hash == other.file_hash
+  }
+}
+
 
 
 
@@ -657,15 +799,19 @@ pub struct InputPassportElementErrorSourceTranslationFiles {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hashes of all files with the translation
-  file_hashes: Vec<String>,
+  file_hashes: Vec<Bytes>,
   
 }
 
 impl RObject for InputPassportElementErrorSourceTranslationFiles {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceTranslationFiles" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceTranslationFiles" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -675,15 +821,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceTransl
 
 
 impl InputPassportElementErrorSourceTranslationFiles {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceTranslationFilesBuilder {
-    let mut inner = InputPassportElementErrorSourceTranslationFiles::default();
-    inner.td_name = "inputPassportElementErrorSourceTranslationFiles".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceTranslationFiles { td_name: "inputPassportElementErrorSourceTranslationFiles".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceTranslationFilesBuilder { inner }
   }
 
-  pub fn file_hashes(&self) -> &Vec<String> { &self.file_hashes }
+  /// `InputPassportElementErrorSourceTranslationFiles` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceTranslationFiles::builder()`
+  pub fn new(file_hashes: Vec<Bytes>) -> Self { Self::builder().file_hashes(file_hashes).build() }
+
+  pub fn file_hashes(&self) -> &Vec<Bytes> { &self.file_hashes }
 
 }
 
@@ -695,8 +842,14 @@ pub struct RTDInputPassportElementErrorSourceTranslationFilesBuilder {
 impl RTDInputPassportElementErrorSourceTranslationFilesBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceTranslationFiles { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
+  pub fn file_hashes(&mut self, file_hashes: Vec<Bytes>) -> &mut Self {
     self.inner.file_hashes = file_hashes;
     self
   }
@@ -711,6 +864,12 @@ impl AsRef<InputPassportElementErrorSourceTranslationFiles> for RTDInputPassport
   fn as_ref(&self) -> &InputPassportElementErrorSourceTranslationFiles { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceTranslationFiles {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_hashes == other.file_hashes
+  }
+}
+
 
 
 
@@ -725,15 +884,19 @@ pub struct InputPassportElementErrorSourceUnspecified {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Current hash of the entire element
-  element_hash: String,
+  element_hash: Bytes,
   
 }
 
 impl RObject for InputPassportElementErrorSourceUnspecified {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPassportElementErrorSourceUnspecified" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPassportElementErrorSourceUnspecified" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -743,15 +906,16 @@ impl TDInputPassportElementErrorSource for InputPassportElementErrorSourceUnspec
 
 
 impl InputPassportElementErrorSourceUnspecified {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPassportElementErrorSourceUnspecifiedBuilder {
-    let mut inner = InputPassportElementErrorSourceUnspecified::default();
-    inner.td_name = "inputPassportElementErrorSourceUnspecified".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPassportElementErrorSourceUnspecified { td_name: "inputPassportElementErrorSourceUnspecified".to_string(), ..Default::default() };
     RTDInputPassportElementErrorSourceUnspecifiedBuilder { inner }
   }
 
-  pub fn element_hash(&self) -> &String { &self.element_hash }
+  /// `InputPassportElementErrorSourceUnspecified` with the fields tdlib requires, the others are set by `InputPassportElementErrorSourceUnspecified::builder()`
+  pub fn new(element_hash: impl AsRef<Bytes>) -> Self { Self::builder().element_hash(element_hash).build() }
+
+  pub fn element_hash(&self) -> &Bytes { &self.element_hash }
 
 }
 
@@ -763,9 +927,15 @@ pub struct RTDInputPassportElementErrorSourceUnspecifiedBuilder {
 impl RTDInputPassportElementErrorSourceUnspecifiedBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceUnspecified { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn element_hash<T: AsRef<str>>(&mut self, element_hash: T) -> &mut Self {
-    self.inner.element_hash = element_hash.as_ref().to_string();
+  pub fn element_hash<T: AsRef<Bytes>>(&mut self, element_hash: T) -> &mut Self {
+    self.inner.element_hash = element_hash.as_ref().clone();
     self
   }
 
@@ -779,5 +949,11 @@ impl AsRef<InputPassportElementErrorSourceUnspecified> for RTDInputPassportEleme
   fn as_ref(&self) -> &InputPassportElementErrorSourceUnspecified { &self.inner }
 }
 
+impl PartialEq for InputPassportElementErrorSourceUnspecified {
+  fn eq(&self, other: &Self) -> bool {
+    self.element_hash == other.element_hash
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct InputPersonalDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of files containing the pages of the document
   files: Vec<InputFile>,
   /// List of files containing a certified English translation of the document
@@ -23,22 +25,24 @@ pub struct InputPersonalDocument {
 }
 
 impl RObject for InputPersonalDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputPersonalDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputPersonalDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl InputPersonalDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputPersonalDocumentBuilder {
-    let mut inner = InputPersonalDocument::default();
-    inner.td_name = "inputPersonalDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputPersonalDocument { td_name: "inputPersonalDocument".to_string(), ..Default::default() };
     RTDInputPersonalDocumentBuilder { inner }
   }
 
+  /// `InputPersonalDocument` with the fields tdlib requires, the others are set by `InputPersonalDocument::builder()`
+  pub fn new(files: Vec<InputFile>, translation: Vec<InputFile>) -> Self { Self::builder().files(files).translation(translation).build() }
+
   pub fn files(&self) -> &Vec<InputFile> { &self.files }
 
   pub fn translation(&self) -> &Vec<InputFile> { &self.translation }
@@ -53,6 +57,12 @@ pub struct RTDInputPersonalDocumentBuilder {
 impl RTDInputPersonalDocumentBuilder {
   pub fn build(&self) -> InputPersonalDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn files(&mut self, files: Vec<InputFile>) -> &mut Self {
     self.inner.files = files;
@@ -75,5 +85,12 @@ impl AsRef<InputPersonalDocument> for RTDInputPersonalDocumentBuilder {
   fn as_ref(&self) -> &InputPersonalDocument { &self.inner }
 }
 
+impl PartialEq for InputPersonalDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.files == other.files
+      && self.translation == other.translation
+  }
+}
+
 
 
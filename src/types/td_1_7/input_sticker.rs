@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInputSticker: Debug + RObject {}
 
 /// Describes a sticker that needs to be added to a sticker set
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InputSticker {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum InputSticker {
   Animated(InputStickerAnimated),
   /// A static sticker in PNG format, which will be converted to WEBP server-side
   Static(InputStickerStatic),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for InputSticker {
   fn deserialize<D>(deserializer: D) -> Result<InputSticker, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InputSticker,
+      InputSticker, Unknown;
       (inputStickerAnimated, Animated);
       (inputStickerStatic, Static);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for InputSticker {
 }
 
 impl RObject for InputSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InputSticker::Animated(t) => t.td_name(),
       InputSticker::Static(t) => t.td_name(),
+      InputSticker::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InputSticker::Animated(t) => t.extra(),
       InputSticker::Static(t) => t.extra(),
+      InputSticker::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InputSticker::Animated(t) => t.client_id(),
+      InputSticker::Static(t) => t.client_id(),
+      InputSticker::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for InputSticker {
 }
 
 impl InputSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputSticker::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InputSticker::_Default(_)) }
 
-  pub fn is_animated(&self) -> bool { if let InputSticker::Animated(_) = self { true } else { false } }
-  pub fn is_static(&self) -> bool { if let InputSticker::Static(_) = self { true } else { false } }
+  pub fn is_animated(&self) -> bool { matches!(self, InputSticker::Animated(_)) }
+  pub fn is_static(&self) -> bool { matches!(self, InputSticker::Static(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InputSticker::Unknown(_)) }
 
   pub fn on_animated<F: FnOnce(&InputStickerAnimated)>(&self, fnc: F) -> &Self { if let InputSticker::Animated(t) = self { fnc(t) }; self }
   pub fn on_static<F: FnOnce(&InputStickerStatic)>(&self, fnc: F) -> &Self { if let InputSticker::Static(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InputSticker::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_animated(&self) -> Option<&InputStickerAnimated> { if let InputSticker::Animated(t) = self { return Some(t) } None }
   pub fn as_static(&self) -> Option<&InputStickerStatic> { if let InputSticker::Static(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InputSticker::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,7 +116,10 @@ pub struct InputStickerAnimated {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// File with the animated sticker. Only local or uploaded within a week files are supported. See https://core.telegram.org/animated_stickers#technical-requirements for technical requirements
   sticker: InputFile,
   /// Emojis corresponding to the sticker
@@ -110,8 +128,9 @@ pub struct InputStickerAnimated {
 }
 
 impl RObject for InputStickerAnimated {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputStickerAnimated" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputStickerAnimated" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -121,14 +140,15 @@ impl TDInputSticker for InputStickerAnimated {}
 
 
 impl InputStickerAnimated {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputStickerAnimatedBuilder {
-    let mut inner = InputStickerAnimated::default();
-    inner.td_name = "inputStickerAnimated".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputStickerAnimated { td_name: "inputStickerAnimated".to_string(), ..Default::default() };
     RTDInputStickerAnimatedBuilder { inner }
   }
 
+  /// `InputStickerAnimated` with the fields tdlib requires, the others are set by `InputStickerAnimated::builder()`
+  pub fn new(sticker: impl AsRef<InputFile>, emojis: impl AsRef<str>) -> Self { Self::builder().sticker(sticker).emojis(emojis).build() }
+
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
   pub fn emojis(&self) -> &String { &self.emojis }
@@ -143,6 +163,12 @@ pub struct RTDInputStickerAnimatedBuilder {
 impl RTDInputStickerAnimatedBuilder {
   pub fn build(&self) -> InputStickerAnimated { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -165,6 +191,13 @@ impl AsRef<InputStickerAnimated> for RTDInputStickerAnimatedBuilder {
   fn as_ref(&self) -> &InputStickerAnimated { &self.inner }
 }
 
+impl PartialEq for InputStickerAnimated {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker == other.sticker
+      && self.emojis == other.emojis
+  }
+}
+
 
 
 
@@ -179,7 +212,10 @@ pub struct InputStickerStatic {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// PNG image with the sticker; must be up to 512 KB in size and fit in a 512x512 square
   sticker: InputFile,
   /// Emojis corresponding to the sticker
@@ -190,8 +226,9 @@ pub struct InputStickerStatic {
 }
 
 impl RObject for InputStickerStatic {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputStickerStatic" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputStickerStatic" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -201,14 +238,15 @@ impl TDInputSticker for InputStickerStatic {}
 
 
 impl InputStickerStatic {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputStickerStaticBuilder {
-    let mut inner = InputStickerStatic::default();
-    inner.td_name = "inputStickerStatic".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputStickerStatic { td_name: "inputStickerStatic".to_string(), ..Default::default() };
     RTDInputStickerStaticBuilder { inner }
   }
 
+  /// `InputStickerStatic` with the fields tdlib requires, the others are set by `InputStickerStatic::builder()`
+  pub fn new(sticker: impl AsRef<InputFile>, emojis: impl AsRef<str>) -> Self { Self::builder().sticker(sticker).emojis(emojis).build() }
+
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
   pub fn emojis(&self) -> &String { &self.emojis }
@@ -225,6 +263,12 @@ pub struct RTDInputStickerStaticBuilder {
 impl RTDInputStickerStaticBuilder {
   pub fn build(&self) -> InputStickerStatic { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -253,5 +297,13 @@ impl AsRef<InputStickerStatic> for RTDInputStickerStaticBuilder {
   fn as_ref(&self) -> &InputStickerStatic { &self.inner }
 }
 
+impl PartialEq for InputStickerStatic {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker == other.sticker
+      && self.emojis == other.emojis
+      && self.mask_position == other.mask_position
+  }
+}
+
 
 
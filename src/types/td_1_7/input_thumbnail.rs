@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct InputThumbnail {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Thumbnail file to send. Sending thumbnails by file_id is currently not supported
   thumbnail: InputFile,
   /// Thumbnail width, usually shouldn't exceed 320. Use 0 if unknown
@@ -25,22 +27,24 @@ pub struct InputThumbnail {
 }
 
 impl RObject for InputThumbnail {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "inputThumbnail" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "inputThumbnail" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl InputThumbnail {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInputThumbnailBuilder {
-    let mut inner = InputThumbnail::default();
-    inner.td_name = "inputThumbnail".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InputThumbnail { td_name: "inputThumbnail".to_string(), ..Default::default() };
     RTDInputThumbnailBuilder { inner }
   }
 
+  /// `InputThumbnail` with the fields tdlib requires, the others are set by `InputThumbnail::builder()`
+  pub fn new(thumbnail: impl AsRef<InputFile>, width: i64, height: i64) -> Self { Self::builder().thumbnail(thumbnail).width(width).height(height).build() }
+
   pub fn thumbnail(&self) -> &InputFile { &self.thumbnail }
 
   pub fn width(&self) -> i64 { self.width }
@@ -57,6 +61,12 @@ pub struct RTDInputThumbnailBuilder {
 impl RTDInputThumbnailBuilder {
   pub fn build(&self) -> InputThumbnail { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn thumbnail<T: AsRef<InputFile>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
@@ -85,5 +95,13 @@ impl AsRef<InputThumbnail> for RTDInputThumbnailBuilder {
   fn as_ref(&self) -> &InputThumbnail { &self.inner }
 }
 
+impl PartialEq for InputThumbnail {
+  fn eq(&self, other: &Self) -> bool {
+    self.thumbnail == other.thumbnail
+      && self.width == other.width
+      && self.height == other.height
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDInternalLinkType: Debug + RObject {}
 
 /// Describes an internal https://t.me or tg: link, which must be processed by the app in a special way
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum InternalLinkType {
   #[doc(hidden)] _Default(()),
@@ -46,6 +45,7 @@ pub enum InternalLinkType {
   /// The link contains a message draft text. A share screen needs to be shown to the user, then the chosen chat must be opened and the text is added to the input field
   MessageDraft(InternalLinkTypeMessageDraft),
   /// The link contains a request of Telegram passport data. Call getPassportAuthorizationForm with the given parameters to process the link if the link was received from outside of the app, otherwise ignore it
+  #[cfg(feature = "passport")]
   PassportDataRequest(InternalLinkTypePassportDataRequest),
   /// The link can be used to confirm ownership of a phone number to prevent account deletion. Call sendPhoneNumberConfirmationCode with the given hash and phone number to process the link
   PhoneNumberConfirmation(InternalLinkTypePhoneNumberConfirmation),
@@ -58,6 +58,7 @@ pub enum InternalLinkType {
   /// The link is a link to app settings
   Settings(InternalLinkTypeSettings),
   /// The link is a link to a sticker set. Call searchStickerSet with the given sticker set name to process the link and show the sticker set
+  #[cfg(feature = "stickers")]
   StickerSet(InternalLinkTypeStickerSet),
   /// The link is a link to a theme. TDLib has no theme support yet
   Theme(InternalLinkTypeTheme),
@@ -69,6 +70,8 @@ pub enum InternalLinkType {
   UnsupportedProxy(InternalLinkTypeUnsupportedProxy),
   /// The link is a link to a video chat. Call searchPublicChat with the given chat username, and then joinGoupCall with the given invite hash to process the link
   VideoChat(InternalLinkTypeVideoChat),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -80,7 +83,7 @@ impl<'de> Deserialize<'de> for InternalLinkType {
   fn deserialize<D>(deserializer: D) -> Result<InternalLinkType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      InternalLinkType,
+      InternalLinkType, Unknown;
       (getInternalLinkType, GetInternalLinkType);
       (internalLinkTypeActiveSessions, ActiveSessions);
       (internalLinkTypeAuthenticationCode, AuthenticationCode);
@@ -94,13 +97,13 @@ impl<'de> Deserialize<'de> for InternalLinkType {
       (internalLinkTypeLanguagePack, LanguagePack);
       (internalLinkTypeMessage, Message);
       (internalLinkTypeMessageDraft, MessageDraft);
-      (internalLinkTypePassportDataRequest, PassportDataRequest);
+      #[cfg(feature = "passport")] (internalLinkTypePassportDataRequest, PassportDataRequest);
       (internalLinkTypePhoneNumberConfirmation, PhoneNumberConfirmation);
       (internalLinkTypeProxy, Proxy);
       (internalLinkTypePublicChat, PublicChat);
       (internalLinkTypeQrCodeAuthentication, QrCodeAuthentication);
       (internalLinkTypeSettings, Settings);
-      (internalLinkTypeStickerSet, StickerSet);
+      #[cfg(feature = "stickers")] (internalLinkTypeStickerSet, StickerSet);
       (internalLinkTypeTheme, Theme);
       (internalLinkTypeThemeSettings, ThemeSettings);
       (internalLinkTypeUnknownDeepLink, UnknownDeepLink);
@@ -112,7 +115,7 @@ impl<'de> Deserialize<'de> for InternalLinkType {
 }
 
 impl RObject for InternalLinkType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       InternalLinkType::GetInternalLinkType(t) => t.td_name(),
       InternalLinkType::ActiveSessions(t) => t.td_name(),
@@ -127,23 +130,24 @@ impl RObject for InternalLinkType {
       InternalLinkType::LanguagePack(t) => t.td_name(),
       InternalLinkType::Message(t) => t.td_name(),
       InternalLinkType::MessageDraft(t) => t.td_name(),
-      InternalLinkType::PassportDataRequest(t) => t.td_name(),
+      #[cfg(feature = "passport")] InternalLinkType::PassportDataRequest(t) => t.td_name(),
       InternalLinkType::PhoneNumberConfirmation(t) => t.td_name(),
       InternalLinkType::Proxy(t) => t.td_name(),
       InternalLinkType::PublicChat(t) => t.td_name(),
       InternalLinkType::QrCodeAuthentication(t) => t.td_name(),
       InternalLinkType::Settings(t) => t.td_name(),
-      InternalLinkType::StickerSet(t) => t.td_name(),
+      #[cfg(feature = "stickers")] InternalLinkType::StickerSet(t) => t.td_name(),
       InternalLinkType::Theme(t) => t.td_name(),
       InternalLinkType::ThemeSettings(t) => t.td_name(),
       InternalLinkType::UnknownDeepLink(t) => t.td_name(),
       InternalLinkType::UnsupportedProxy(t) => t.td_name(),
       InternalLinkType::VideoChat(t) => t.td_name(),
+      InternalLinkType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       InternalLinkType::GetInternalLinkType(t) => t.extra(),
       InternalLinkType::ActiveSessions(t) => t.extra(),
@@ -158,18 +162,51 @@ impl RObject for InternalLinkType {
       InternalLinkType::LanguagePack(t) => t.extra(),
       InternalLinkType::Message(t) => t.extra(),
       InternalLinkType::MessageDraft(t) => t.extra(),
-      InternalLinkType::PassportDataRequest(t) => t.extra(),
+      #[cfg(feature = "passport")] InternalLinkType::PassportDataRequest(t) => t.extra(),
       InternalLinkType::PhoneNumberConfirmation(t) => t.extra(),
       InternalLinkType::Proxy(t) => t.extra(),
       InternalLinkType::PublicChat(t) => t.extra(),
       InternalLinkType::QrCodeAuthentication(t) => t.extra(),
       InternalLinkType::Settings(t) => t.extra(),
-      InternalLinkType::StickerSet(t) => t.extra(),
+      #[cfg(feature = "stickers")] InternalLinkType::StickerSet(t) => t.extra(),
       InternalLinkType::Theme(t) => t.extra(),
       InternalLinkType::ThemeSettings(t) => t.extra(),
       InternalLinkType::UnknownDeepLink(t) => t.extra(),
       InternalLinkType::UnsupportedProxy(t) => t.extra(),
       InternalLinkType::VideoChat(t) => t.extra(),
+      InternalLinkType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      InternalLinkType::GetInternalLinkType(t) => t.client_id(),
+      InternalLinkType::ActiveSessions(t) => t.client_id(),
+      InternalLinkType::AuthenticationCode(t) => t.client_id(),
+      InternalLinkType::Background(t) => t.client_id(),
+      InternalLinkType::BotStart(t) => t.client_id(),
+      InternalLinkType::BotStartInGroup(t) => t.client_id(),
+      InternalLinkType::ChangePhoneNumber(t) => t.client_id(),
+      InternalLinkType::ChatInvite(t) => t.client_id(),
+      InternalLinkType::FilterSettings(t) => t.client_id(),
+      InternalLinkType::Game(t) => t.client_id(),
+      InternalLinkType::LanguagePack(t) => t.client_id(),
+      InternalLinkType::Message(t) => t.client_id(),
+      InternalLinkType::MessageDraft(t) => t.client_id(),
+      #[cfg(feature = "passport")] InternalLinkType::PassportDataRequest(t) => t.client_id(),
+      InternalLinkType::PhoneNumberConfirmation(t) => t.client_id(),
+      InternalLinkType::Proxy(t) => t.client_id(),
+      InternalLinkType::PublicChat(t) => t.client_id(),
+      InternalLinkType::QrCodeAuthentication(t) => t.client_id(),
+      InternalLinkType::Settings(t) => t.client_id(),
+      #[cfg(feature = "stickers")] InternalLinkType::StickerSet(t) => t.client_id(),
+      InternalLinkType::Theme(t) => t.client_id(),
+      InternalLinkType::ThemeSettings(t) => t.client_id(),
+      InternalLinkType::UnknownDeepLink(t) => t.client_id(),
+      InternalLinkType::UnsupportedProxy(t) => t.client_id(),
+      InternalLinkType::VideoChat(t) => t.client_id(),
+      InternalLinkType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -178,34 +215,35 @@ impl RObject for InternalLinkType {
 }
 
 impl InternalLinkType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InternalLinkType::_Default(_) = self { true } else { false } }
-
-  pub fn is_get_internal_link_type(&self) -> bool { if let InternalLinkType::GetInternalLinkType(_) = self { true } else { false } }
-  pub fn is_active_sessions(&self) -> bool { if let InternalLinkType::ActiveSessions(_) = self { true } else { false } }
-  pub fn is_authentication_code(&self) -> bool { if let InternalLinkType::AuthenticationCode(_) = self { true } else { false } }
-  pub fn is_background(&self) -> bool { if let InternalLinkType::Background(_) = self { true } else { false } }
-  pub fn is_bot_start(&self) -> bool { if let InternalLinkType::BotStart(_) = self { true } else { false } }
-  pub fn is_bot_start_in_group(&self) -> bool { if let InternalLinkType::BotStartInGroup(_) = self { true } else { false } }
-  pub fn is_change_phone_number(&self) -> bool { if let InternalLinkType::ChangePhoneNumber(_) = self { true } else { false } }
-  pub fn is_chat_invite(&self) -> bool { if let InternalLinkType::ChatInvite(_) = self { true } else { false } }
-  pub fn is_filter_settings(&self) -> bool { if let InternalLinkType::FilterSettings(_) = self { true } else { false } }
-  pub fn is_game(&self) -> bool { if let InternalLinkType::Game(_) = self { true } else { false } }
-  pub fn is_language_pack(&self) -> bool { if let InternalLinkType::LanguagePack(_) = self { true } else { false } }
-  pub fn is_message(&self) -> bool { if let InternalLinkType::Message(_) = self { true } else { false } }
-  pub fn is_message_draft(&self) -> bool { if let InternalLinkType::MessageDraft(_) = self { true } else { false } }
-  pub fn is_passport_data_request(&self) -> bool { if let InternalLinkType::PassportDataRequest(_) = self { true } else { false } }
-  pub fn is_phone_number_confirmation(&self) -> bool { if let InternalLinkType::PhoneNumberConfirmation(_) = self { true } else { false } }
-  pub fn is_proxy(&self) -> bool { if let InternalLinkType::Proxy(_) = self { true } else { false } }
-  pub fn is_public_chat(&self) -> bool { if let InternalLinkType::PublicChat(_) = self { true } else { false } }
-  pub fn is_qr_code_authentication(&self) -> bool { if let InternalLinkType::QrCodeAuthentication(_) = self { true } else { false } }
-  pub fn is_settings(&self) -> bool { if let InternalLinkType::Settings(_) = self { true } else { false } }
-  pub fn is_sticker_set(&self) -> bool { if let InternalLinkType::StickerSet(_) = self { true } else { false } }
-  pub fn is_theme(&self) -> bool { if let InternalLinkType::Theme(_) = self { true } else { false } }
-  pub fn is_theme_settings(&self) -> bool { if let InternalLinkType::ThemeSettings(_) = self { true } else { false } }
-  pub fn is_unknown_deep_link(&self) -> bool { if let InternalLinkType::UnknownDeepLink(_) = self { true } else { false } }
-  pub fn is_unsupported_proxy(&self) -> bool { if let InternalLinkType::UnsupportedProxy(_) = self { true } else { false } }
-  pub fn is_video_chat(&self) -> bool { if let InternalLinkType::VideoChat(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, InternalLinkType::_Default(_)) }
+
+  pub fn is_get_internal_link_type(&self) -> bool { matches!(self, InternalLinkType::GetInternalLinkType(_)) }
+  pub fn is_active_sessions(&self) -> bool { matches!(self, InternalLinkType::ActiveSessions(_)) }
+  pub fn is_authentication_code(&self) -> bool { matches!(self, InternalLinkType::AuthenticationCode(_)) }
+  pub fn is_background(&self) -> bool { matches!(self, InternalLinkType::Background(_)) }
+  pub fn is_bot_start(&self) -> bool { matches!(self, InternalLinkType::BotStart(_)) }
+  pub fn is_bot_start_in_group(&self) -> bool { matches!(self, InternalLinkType::BotStartInGroup(_)) }
+  pub fn is_change_phone_number(&self) -> bool { matches!(self, InternalLinkType::ChangePhoneNumber(_)) }
+  pub fn is_chat_invite(&self) -> bool { matches!(self, InternalLinkType::ChatInvite(_)) }
+  pub fn is_filter_settings(&self) -> bool { matches!(self, InternalLinkType::FilterSettings(_)) }
+  pub fn is_game(&self) -> bool { matches!(self, InternalLinkType::Game(_)) }
+  pub fn is_language_pack(&self) -> bool { matches!(self, InternalLinkType::LanguagePack(_)) }
+  pub fn is_message(&self) -> bool { matches!(self, InternalLinkType::Message(_)) }
+  pub fn is_message_draft(&self) -> bool { matches!(self, InternalLinkType::MessageDraft(_)) }
+  #[cfg(feature = "passport")] pub fn is_passport_data_request(&self) -> bool { matches!(self, InternalLinkType::PassportDataRequest(_)) }
+  pub fn is_phone_number_confirmation(&self) -> bool { matches!(self, InternalLinkType::PhoneNumberConfirmation(_)) }
+  pub fn is_proxy(&self) -> bool { matches!(self, InternalLinkType::Proxy(_)) }
+  pub fn is_public_chat(&self) -> bool { matches!(self, InternalLinkType::PublicChat(_)) }
+  pub fn is_qr_code_authentication(&self) -> bool { matches!(self, InternalLinkType::QrCodeAuthentication(_)) }
+  pub fn is_settings(&self) -> bool { matches!(self, InternalLinkType::Settings(_)) }
+  #[cfg(feature = "stickers")] pub fn is_sticker_set(&self) -> bool { matches!(self, InternalLinkType::StickerSet(_)) }
+  pub fn is_theme(&self) -> bool { matches!(self, InternalLinkType::Theme(_)) }
+  pub fn is_theme_settings(&self) -> bool { matches!(self, InternalLinkType::ThemeSettings(_)) }
+  pub fn is_unknown_deep_link(&self) -> bool { matches!(self, InternalLinkType::UnknownDeepLink(_)) }
+  pub fn is_unsupported_proxy(&self) -> bool { matches!(self, InternalLinkType::UnsupportedProxy(_)) }
+  pub fn is_video_chat(&self) -> bool { matches!(self, InternalLinkType::VideoChat(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, InternalLinkType::Unknown(_)) }
 
   pub fn on_get_internal_link_type<F: FnOnce(&GetInternalLinkType)>(&self, fnc: F) -> &Self { if let InternalLinkType::GetInternalLinkType(t) = self { fnc(t) }; self }
   pub fn on_active_sessions<F: FnOnce(&InternalLinkTypeActiveSessions)>(&self, fnc: F) -> &Self { if let InternalLinkType::ActiveSessions(t) = self { fnc(t) }; self }
@@ -220,18 +258,19 @@ impl InternalLinkType {
   pub fn on_language_pack<F: FnOnce(&InternalLinkTypeLanguagePack)>(&self, fnc: F) -> &Self { if let InternalLinkType::LanguagePack(t) = self { fnc(t) }; self }
   pub fn on_message<F: FnOnce(&InternalLinkTypeMessage)>(&self, fnc: F) -> &Self { if let InternalLinkType::Message(t) = self { fnc(t) }; self }
   pub fn on_message_draft<F: FnOnce(&InternalLinkTypeMessageDraft)>(&self, fnc: F) -> &Self { if let InternalLinkType::MessageDraft(t) = self { fnc(t) }; self }
-  pub fn on_passport_data_request<F: FnOnce(&InternalLinkTypePassportDataRequest)>(&self, fnc: F) -> &Self { if let InternalLinkType::PassportDataRequest(t) = self { fnc(t) }; self }
+  #[cfg(feature = "passport")] pub fn on_passport_data_request<F: FnOnce(&InternalLinkTypePassportDataRequest)>(&self, fnc: F) -> &Self { if let InternalLinkType::PassportDataRequest(t) = self { fnc(t) }; self }
   pub fn on_phone_number_confirmation<F: FnOnce(&InternalLinkTypePhoneNumberConfirmation)>(&self, fnc: F) -> &Self { if let InternalLinkType::PhoneNumberConfirmation(t) = self { fnc(t) }; self }
   pub fn on_proxy<F: FnOnce(&InternalLinkTypeProxy)>(&self, fnc: F) -> &Self { if let InternalLinkType::Proxy(t) = self { fnc(t) }; self }
   pub fn on_public_chat<F: FnOnce(&InternalLinkTypePublicChat)>(&self, fnc: F) -> &Self { if let InternalLinkType::PublicChat(t) = self { fnc(t) }; self }
   pub fn on_qr_code_authentication<F: FnOnce(&InternalLinkTypeQrCodeAuthentication)>(&self, fnc: F) -> &Self { if let InternalLinkType::QrCodeAuthentication(t) = self { fnc(t) }; self }
   pub fn on_settings<F: FnOnce(&InternalLinkTypeSettings)>(&self, fnc: F) -> &Self { if let InternalLinkType::Settings(t) = self { fnc(t) }; self }
-  pub fn on_sticker_set<F: FnOnce(&InternalLinkTypeStickerSet)>(&self, fnc: F) -> &Self { if let InternalLinkType::StickerSet(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_sticker_set<F: FnOnce(&InternalLinkTypeStickerSet)>(&self, fnc: F) -> &Self { if let InternalLinkType::StickerSet(t) = self { fnc(t) }; self }
   pub fn on_theme<F: FnOnce(&InternalLinkTypeTheme)>(&self, fnc: F) -> &Self { if let InternalLinkType::Theme(t) = self { fnc(t) }; self }
   pub fn on_theme_settings<F: FnOnce(&InternalLinkTypeThemeSettings)>(&self, fnc: F) -> &Self { if let InternalLinkType::ThemeSettings(t) = self { fnc(t) }; self }
   pub fn on_unknown_deep_link<F: FnOnce(&InternalLinkTypeUnknownDeepLink)>(&self, fnc: F) -> &Self { if let InternalLinkType::UnknownDeepLink(t) = self { fnc(t) }; self }
   pub fn on_unsupported_proxy<F: FnOnce(&InternalLinkTypeUnsupportedProxy)>(&self, fnc: F) -> &Self { if let InternalLinkType::UnsupportedProxy(t) = self { fnc(t) }; self }
   pub fn on_video_chat<F: FnOnce(&InternalLinkTypeVideoChat)>(&self, fnc: F) -> &Self { if let InternalLinkType::VideoChat(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let InternalLinkType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_internal_link_type(&self) -> Option<&GetInternalLinkType> { if let InternalLinkType::GetInternalLinkType(t) = self { return Some(t) } None }
   pub fn as_active_sessions(&self) -> Option<&InternalLinkTypeActiveSessions> { if let InternalLinkType::ActiveSessions(t) = self { return Some(t) } None }
@@ -246,18 +285,19 @@ impl InternalLinkType {
   pub fn as_language_pack(&self) -> Option<&InternalLinkTypeLanguagePack> { if let InternalLinkType::LanguagePack(t) = self { return Some(t) } None }
   pub fn as_message(&self) -> Option<&InternalLinkTypeMessage> { if let InternalLinkType::Message(t) = self { return Some(t) } None }
   pub fn as_message_draft(&self) -> Option<&InternalLinkTypeMessageDraft> { if let InternalLinkType::MessageDraft(t) = self { return Some(t) } None }
-  pub fn as_passport_data_request(&self) -> Option<&InternalLinkTypePassportDataRequest> { if let InternalLinkType::PassportDataRequest(t) = self { return Some(t) } None }
+  #[cfg(feature = "passport")] pub fn as_passport_data_request(&self) -> Option<&InternalLinkTypePassportDataRequest> { if let InternalLinkType::PassportDataRequest(t) = self { return Some(t) } None }
   pub fn as_phone_number_confirmation(&self) -> Option<&InternalLinkTypePhoneNumberConfirmation> { if let InternalLinkType::PhoneNumberConfirmation(t) = self { return Some(t) } None }
   pub fn as_proxy(&self) -> Option<&InternalLinkTypeProxy> { if let InternalLinkType::Proxy(t) = self { return Some(t) } None }
   pub fn as_public_chat(&self) -> Option<&InternalLinkTypePublicChat> { if let InternalLinkType::PublicChat(t) = self { return Some(t) } None }
   pub fn as_qr_code_authentication(&self) -> Option<&InternalLinkTypeQrCodeAuthentication> { if let InternalLinkType::QrCodeAuthentication(t) = self { return Some(t) } None }
   pub fn as_settings(&self) -> Option<&InternalLinkTypeSettings> { if let InternalLinkType::Settings(t) = self { return Some(t) } None }
-  pub fn as_sticker_set(&self) -> Option<&InternalLinkTypeStickerSet> { if let InternalLinkType::StickerSet(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_sticker_set(&self) -> Option<&InternalLinkTypeStickerSet> { if let InternalLinkType::StickerSet(t) = self { return Some(t) } None }
   pub fn as_theme(&self) -> Option<&InternalLinkTypeTheme> { if let InternalLinkType::Theme(t) = self { return Some(t) } None }
   pub fn as_theme_settings(&self) -> Option<&InternalLinkTypeThemeSettings> { if let InternalLinkType::ThemeSettings(t) = self { return Some(t) } None }
   pub fn as_unknown_deep_link(&self) -> Option<&InternalLinkTypeUnknownDeepLink> { if let InternalLinkType::UnknownDeepLink(t) = self { return Some(t) } None }
   pub fn as_unsupported_proxy(&self) -> Option<&InternalLinkTypeUnsupportedProxy> { if let InternalLinkType::UnsupportedProxy(t) = self { return Some(t) } None }
   pub fn as_video_chat(&self) -> Option<&InternalLinkTypeVideoChat> { if let InternalLinkType::VideoChat(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let InternalLinkType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -287,7 +327,7 @@ impl InternalLinkType {
 
   pub fn message_draft<T: AsRef<InternalLinkTypeMessageDraft>>(t: T) -> Self { InternalLinkType::MessageDraft(t.as_ref().clone()) }
 
-  pub fn passport_data_request<T: AsRef<InternalLinkTypePassportDataRequest>>(t: T) -> Self { InternalLinkType::PassportDataRequest(t.as_ref().clone()) }
+  #[cfg(feature = "passport")] pub fn passport_data_request<T: AsRef<InternalLinkTypePassportDataRequest>>(t: T) -> Self { InternalLinkType::PassportDataRequest(t.as_ref().clone()) }
 
   pub fn phone_number_confirmation<T: AsRef<InternalLinkTypePhoneNumberConfirmation>>(t: T) -> Self { InternalLinkType::PhoneNumberConfirmation(t.as_ref().clone()) }
 
@@ -299,7 +339,7 @@ impl InternalLinkType {
 
   pub fn settings<T: AsRef<InternalLinkTypeSettings>>(t: T) -> Self { InternalLinkType::Settings(t.as_ref().clone()) }
 
-  pub fn sticker_set<T: AsRef<InternalLinkTypeStickerSet>>(t: T) -> Self { InternalLinkType::StickerSet(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn sticker_set<T: AsRef<InternalLinkTypeStickerSet>>(t: T) -> Self { InternalLinkType::StickerSet(t.as_ref().clone()) }
 
   pub fn theme<T: AsRef<InternalLinkTypeTheme>>(t: T) -> Self { InternalLinkType::Theme(t.as_ref().clone()) }
 
@@ -331,13 +371,17 @@ pub struct InternalLinkTypeActiveSessions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InternalLinkTypeActiveSessions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeActiveSessions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeActiveSessions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -347,14 +391,15 @@ impl TDInternalLinkType for InternalLinkTypeActiveSessions {}
 
 
 impl InternalLinkTypeActiveSessions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeActiveSessionsBuilder {
-    let mut inner = InternalLinkTypeActiveSessions::default();
-    inner.td_name = "internalLinkTypeActiveSessions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeActiveSessions { td_name: "internalLinkTypeActiveSessions".to_string(), ..Default::default() };
     RTDInternalLinkTypeActiveSessionsBuilder { inner }
   }
 
+  /// `InternalLinkTypeActiveSessions` with the fields tdlib requires, the others are set by `InternalLinkTypeActiveSessions::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -365,6 +410,12 @@ pub struct RTDInternalLinkTypeActiveSessionsBuilder {
 impl RTDInternalLinkTypeActiveSessionsBuilder {
   pub fn build(&self) -> InternalLinkTypeActiveSessions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InternalLinkTypeActiveSessions> for InternalLinkTypeActiveSessions {
@@ -375,6 +426,10 @@ impl AsRef<InternalLinkTypeActiveSessions> for RTDInternalLinkTypeActiveSessions
   fn as_ref(&self) -> &InternalLinkTypeActiveSessions { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeActiveSessions {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -389,15 +444,19 @@ pub struct InternalLinkTypeAuthenticationCode {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The authentication code
   code: String,
   
 }
 
 impl RObject for InternalLinkTypeAuthenticationCode {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeAuthenticationCode" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeAuthenticationCode" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -407,14 +466,15 @@ impl TDInternalLinkType for InternalLinkTypeAuthenticationCode {}
 
 
 impl InternalLinkTypeAuthenticationCode {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeAuthenticationCodeBuilder {
-    let mut inner = InternalLinkTypeAuthenticationCode::default();
-    inner.td_name = "internalLinkTypeAuthenticationCode".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeAuthenticationCode { td_name: "internalLinkTypeAuthenticationCode".to_string(), ..Default::default() };
     RTDInternalLinkTypeAuthenticationCodeBuilder { inner }
   }
 
+  /// `InternalLinkTypeAuthenticationCode` with the fields tdlib requires, the others are set by `InternalLinkTypeAuthenticationCode::builder()`
+  pub fn new(code: impl AsRef<str>) -> Self { Self::builder().code(code).build() }
+
   pub fn code(&self) -> &String { &self.code }
 
 }
@@ -427,6 +487,12 @@ pub struct RTDInternalLinkTypeAuthenticationCodeBuilder {
 impl RTDInternalLinkTypeAuthenticationCodeBuilder {
   pub fn build(&self) -> InternalLinkTypeAuthenticationCode { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
@@ -443,6 +509,12 @@ impl AsRef<InternalLinkTypeAuthenticationCode> for RTDInternalLinkTypeAuthentica
   fn as_ref(&self) -> &InternalLinkTypeAuthenticationCode { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeAuthenticationCode {
+  fn eq(&self, other: &Self) -> bool {
+    self.code == other.code
+  }
+}
+
 
 
 
@@ -457,15 +529,19 @@ pub struct InternalLinkTypeBackground {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the background
   background_name: String,
   
 }
 
 impl RObject for InternalLinkTypeBackground {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeBackground" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeBackground" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -475,14 +551,15 @@ impl TDInternalLinkType for InternalLinkTypeBackground {}
 
 
 impl InternalLinkTypeBackground {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeBackgroundBuilder {
-    let mut inner = InternalLinkTypeBackground::default();
-    inner.td_name = "internalLinkTypeBackground".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeBackground { td_name: "internalLinkTypeBackground".to_string(), ..Default::default() };
     RTDInternalLinkTypeBackgroundBuilder { inner }
   }
 
+  /// `InternalLinkTypeBackground` with the fields tdlib requires, the others are set by `InternalLinkTypeBackground::builder()`
+  pub fn new(background_name: impl AsRef<str>) -> Self { Self::builder().background_name(background_name).build() }
+
   pub fn background_name(&self) -> &String { &self.background_name }
 
 }
@@ -495,6 +572,12 @@ pub struct RTDInternalLinkTypeBackgroundBuilder {
 impl RTDInternalLinkTypeBackgroundBuilder {
   pub fn build(&self) -> InternalLinkTypeBackground { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn background_name<T: AsRef<str>>(&mut self, background_name: T) -> &mut Self {
     self.inner.background_name = background_name.as_ref().to_string();
@@ -511,6 +594,12 @@ impl AsRef<InternalLinkTypeBackground> for RTDInternalLinkTypeBackgroundBuilder
   fn as_ref(&self) -> &InternalLinkTypeBackground { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeBackground {
+  fn eq(&self, other: &Self) -> bool {
+    self.background_name == other.background_name
+  }
+}
+
 
 
 
@@ -525,7 +614,10 @@ pub struct InternalLinkTypeBotStart {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username of the bot
   bot_username: String,
   /// The parameter to be passed to sendBotStartMessage
@@ -534,8 +626,9 @@ pub struct InternalLinkTypeBotStart {
 }
 
 impl RObject for InternalLinkTypeBotStart {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeBotStart" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeBotStart" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -545,14 +638,15 @@ impl TDInternalLinkType for InternalLinkTypeBotStart {}
 
 
 impl InternalLinkTypeBotStart {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeBotStartBuilder {
-    let mut inner = InternalLinkTypeBotStart::default();
-    inner.td_name = "internalLinkTypeBotStart".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeBotStart { td_name: "internalLinkTypeBotStart".to_string(), ..Default::default() };
     RTDInternalLinkTypeBotStartBuilder { inner }
   }
 
+  /// `InternalLinkTypeBotStart` with the fields tdlib requires, the others are set by `InternalLinkTypeBotStart::builder()`
+  pub fn new(bot_username: impl AsRef<str>, start_parameter: impl AsRef<str>) -> Self { Self::builder().bot_username(bot_username).start_parameter(start_parameter).build() }
+
   pub fn bot_username(&self) -> &String { &self.bot_username }
 
   pub fn start_parameter(&self) -> &String { &self.start_parameter }
@@ -567,6 +661,12 @@ pub struct RTDInternalLinkTypeBotStartBuilder {
 impl RTDInternalLinkTypeBotStartBuilder {
   pub fn build(&self) -> InternalLinkTypeBotStart { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bot_username<T: AsRef<str>>(&mut self, bot_username: T) -> &mut Self {
     self.inner.bot_username = bot_username.as_ref().to_string();
@@ -589,6 +689,13 @@ impl AsRef<InternalLinkTypeBotStart> for RTDInternalLinkTypeBotStartBuilder {
   fn as_ref(&self) -> &InternalLinkTypeBotStart { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeBotStart {
+  fn eq(&self, other: &Self) -> bool {
+    self.bot_username == other.bot_username
+      && self.start_parameter == other.start_parameter
+  }
+}
+
 
 
 
@@ -603,7 +710,10 @@ pub struct InternalLinkTypeBotStartInGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username of the bot
   bot_username: String,
   /// The parameter to be passed to sendBotStartMessage
@@ -612,8 +722,9 @@ pub struct InternalLinkTypeBotStartInGroup {
 }
 
 impl RObject for InternalLinkTypeBotStartInGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeBotStartInGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeBotStartInGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -623,14 +734,15 @@ impl TDInternalLinkType for InternalLinkTypeBotStartInGroup {}
 
 
 impl InternalLinkTypeBotStartInGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeBotStartInGroupBuilder {
-    let mut inner = InternalLinkTypeBotStartInGroup::default();
-    inner.td_name = "internalLinkTypeBotStartInGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeBotStartInGroup { td_name: "internalLinkTypeBotStartInGroup".to_string(), ..Default::default() };
     RTDInternalLinkTypeBotStartInGroupBuilder { inner }
   }
 
+  /// `InternalLinkTypeBotStartInGroup` with the fields tdlib requires, the others are set by `InternalLinkTypeBotStartInGroup::builder()`
+  pub fn new(bot_username: impl AsRef<str>, start_parameter: impl AsRef<str>) -> Self { Self::builder().bot_username(bot_username).start_parameter(start_parameter).build() }
+
   pub fn bot_username(&self) -> &String { &self.bot_username }
 
   pub fn start_parameter(&self) -> &String { &self.start_parameter }
@@ -645,6 +757,12 @@ pub struct RTDInternalLinkTypeBotStartInGroupBuilder {
 impl RTDInternalLinkTypeBotStartInGroupBuilder {
   pub fn build(&self) -> InternalLinkTypeBotStartInGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bot_username<T: AsRef<str>>(&mut self, bot_username: T) -> &mut Self {
     self.inner.bot_username = bot_username.as_ref().to_string();
@@ -667,6 +785,13 @@ impl AsRef<InternalLinkTypeBotStartInGroup> for RTDInternalLinkTypeBotStartInGro
   fn as_ref(&self) -> &InternalLinkTypeBotStartInGroup { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeBotStartInGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.bot_username == other.bot_username
+      && self.start_parameter == other.start_parameter
+  }
+}
+
 
 
 
@@ -681,13 +806,17 @@ pub struct InternalLinkTypeChangePhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InternalLinkTypeChangePhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeChangePhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeChangePhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -697,14 +826,15 @@ impl TDInternalLinkType for InternalLinkTypeChangePhoneNumber {}
 
 
 impl InternalLinkTypeChangePhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeChangePhoneNumberBuilder {
-    let mut inner = InternalLinkTypeChangePhoneNumber::default();
-    inner.td_name = "internalLinkTypeChangePhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeChangePhoneNumber { td_name: "internalLinkTypeChangePhoneNumber".to_string(), ..Default::default() };
     RTDInternalLinkTypeChangePhoneNumberBuilder { inner }
   }
 
+  /// `InternalLinkTypeChangePhoneNumber` with the fields tdlib requires, the others are set by `InternalLinkTypeChangePhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -715,6 +845,12 @@ pub struct RTDInternalLinkTypeChangePhoneNumberBuilder {
 impl RTDInternalLinkTypeChangePhoneNumberBuilder {
   pub fn build(&self) -> InternalLinkTypeChangePhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InternalLinkTypeChangePhoneNumber> for InternalLinkTypeChangePhoneNumber {
@@ -725,6 +861,10 @@ impl AsRef<InternalLinkTypeChangePhoneNumber> for RTDInternalLinkTypeChangePhone
   fn as_ref(&self) -> &InternalLinkTypeChangePhoneNumber { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeChangePhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -739,15 +879,19 @@ pub struct InternalLinkTypeChatInvite {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Internal representation of the invite link
   invite_link: String,
   
 }
 
 impl RObject for InternalLinkTypeChatInvite {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeChatInvite" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeChatInvite" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -757,14 +901,15 @@ impl TDInternalLinkType for InternalLinkTypeChatInvite {}
 
 
 impl InternalLinkTypeChatInvite {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeChatInviteBuilder {
-    let mut inner = InternalLinkTypeChatInvite::default();
-    inner.td_name = "internalLinkTypeChatInvite".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeChatInvite { td_name: "internalLinkTypeChatInvite".to_string(), ..Default::default() };
     RTDInternalLinkTypeChatInviteBuilder { inner }
   }
 
+  /// `InternalLinkTypeChatInvite` with the fields tdlib requires, the others are set by `InternalLinkTypeChatInvite::builder()`
+  pub fn new(invite_link: impl AsRef<str>) -> Self { Self::builder().invite_link(invite_link).build() }
+
   pub fn invite_link(&self) -> &String { &self.invite_link }
 
 }
@@ -777,6 +922,12 @@ pub struct RTDInternalLinkTypeChatInviteBuilder {
 impl RTDInternalLinkTypeChatInviteBuilder {
   pub fn build(&self) -> InternalLinkTypeChatInvite { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
@@ -793,6 +944,12 @@ impl AsRef<InternalLinkTypeChatInvite> for RTDInternalLinkTypeChatInviteBuilder
   fn as_ref(&self) -> &InternalLinkTypeChatInvite { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeChatInvite {
+  fn eq(&self, other: &Self) -> bool {
+    self.invite_link == other.invite_link
+  }
+}
+
 
 
 
@@ -807,13 +964,17 @@ pub struct InternalLinkTypeFilterSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InternalLinkTypeFilterSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeFilterSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeFilterSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -823,14 +984,15 @@ impl TDInternalLinkType for InternalLinkTypeFilterSettings {}
 
 
 impl InternalLinkTypeFilterSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeFilterSettingsBuilder {
-    let mut inner = InternalLinkTypeFilterSettings::default();
-    inner.td_name = "internalLinkTypeFilterSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeFilterSettings { td_name: "internalLinkTypeFilterSettings".to_string(), ..Default::default() };
     RTDInternalLinkTypeFilterSettingsBuilder { inner }
   }
 
+  /// `InternalLinkTypeFilterSettings` with the fields tdlib requires, the others are set by `InternalLinkTypeFilterSettings::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -841,6 +1003,12 @@ pub struct RTDInternalLinkTypeFilterSettingsBuilder {
 impl RTDInternalLinkTypeFilterSettingsBuilder {
   pub fn build(&self) -> InternalLinkTypeFilterSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InternalLinkTypeFilterSettings> for InternalLinkTypeFilterSettings {
@@ -851,6 +1019,10 @@ impl AsRef<InternalLinkTypeFilterSettings> for RTDInternalLinkTypeFilterSettings
   fn as_ref(&self) -> &InternalLinkTypeFilterSettings { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeFilterSettings {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -865,7 +1037,10 @@ pub struct InternalLinkTypeGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username of the bot that owns the game
   bot_username: String,
   /// Short name of the game
@@ -874,8 +1049,9 @@ pub struct InternalLinkTypeGame {
 }
 
 impl RObject for InternalLinkTypeGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -885,14 +1061,15 @@ impl TDInternalLinkType for InternalLinkTypeGame {}
 
 
 impl InternalLinkTypeGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeGameBuilder {
-    let mut inner = InternalLinkTypeGame::default();
-    inner.td_name = "internalLinkTypeGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeGame { td_name: "internalLinkTypeGame".to_string(), ..Default::default() };
     RTDInternalLinkTypeGameBuilder { inner }
   }
 
+  /// `InternalLinkTypeGame` with the fields tdlib requires, the others are set by `InternalLinkTypeGame::builder()`
+  pub fn new(bot_username: impl AsRef<str>, game_short_name: impl AsRef<str>) -> Self { Self::builder().bot_username(bot_username).game_short_name(game_short_name).build() }
+
   pub fn bot_username(&self) -> &String { &self.bot_username }
 
   pub fn game_short_name(&self) -> &String { &self.game_short_name }
@@ -907,6 +1084,12 @@ pub struct RTDInternalLinkTypeGameBuilder {
 impl RTDInternalLinkTypeGameBuilder {
   pub fn build(&self) -> InternalLinkTypeGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bot_username<T: AsRef<str>>(&mut self, bot_username: T) -> &mut Self {
     self.inner.bot_username = bot_username.as_ref().to_string();
@@ -929,6 +1112,13 @@ impl AsRef<InternalLinkTypeGame> for RTDInternalLinkTypeGameBuilder {
   fn as_ref(&self) -> &InternalLinkTypeGame { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeGame {
+  fn eq(&self, other: &Self) -> bool {
+    self.bot_username == other.bot_username
+      && self.game_short_name == other.game_short_name
+  }
+}
+
 
 
 
@@ -943,15 +1133,19 @@ pub struct InternalLinkTypeLanguagePack {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Language pack identifier
   language_pack_id: String,
   
 }
 
 impl RObject for InternalLinkTypeLanguagePack {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeLanguagePack" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeLanguagePack" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -961,14 +1155,15 @@ impl TDInternalLinkType for InternalLinkTypeLanguagePack {}
 
 
 impl InternalLinkTypeLanguagePack {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeLanguagePackBuilder {
-    let mut inner = InternalLinkTypeLanguagePack::default();
-    inner.td_name = "internalLinkTypeLanguagePack".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeLanguagePack { td_name: "internalLinkTypeLanguagePack".to_string(), ..Default::default() };
     RTDInternalLinkTypeLanguagePackBuilder { inner }
   }
 
+  /// `InternalLinkTypeLanguagePack` with the fields tdlib requires, the others are set by `InternalLinkTypeLanguagePack::builder()`
+  pub fn new(language_pack_id: impl AsRef<str>) -> Self { Self::builder().language_pack_id(language_pack_id).build() }
+
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
 
 }
@@ -981,6 +1176,12 @@ pub struct RTDInternalLinkTypeLanguagePackBuilder {
 impl RTDInternalLinkTypeLanguagePackBuilder {
   pub fn build(&self) -> InternalLinkTypeLanguagePack { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
@@ -997,6 +1198,12 @@ impl AsRef<InternalLinkTypeLanguagePack> for RTDInternalLinkTypeLanguagePackBuil
   fn as_ref(&self) -> &InternalLinkTypeLanguagePack { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeLanguagePack {
+  fn eq(&self, other: &Self) -> bool {
+    self.language_pack_id == other.language_pack_id
+  }
+}
+
 
 
 
@@ -1011,15 +1218,19 @@ pub struct InternalLinkTypeMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// URL to be passed to getMessageLinkInfo
   url: String,
   
 }
 
 impl RObject for InternalLinkTypeMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1029,14 +1240,15 @@ impl TDInternalLinkType for InternalLinkTypeMessage {}
 
 
 impl InternalLinkTypeMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeMessageBuilder {
-    let mut inner = InternalLinkTypeMessage::default();
-    inner.td_name = "internalLinkTypeMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeMessage { td_name: "internalLinkTypeMessage".to_string(), ..Default::default() };
     RTDInternalLinkTypeMessageBuilder { inner }
   }
 
+  /// `InternalLinkTypeMessage` with the fields tdlib requires, the others are set by `InternalLinkTypeMessage::builder()`
+  pub fn new(url: impl AsRef<str>) -> Self { Self::builder().url(url).build() }
+
   pub fn url(&self) -> &String { &self.url }
 
 }
@@ -1049,6 +1261,12 @@ pub struct RTDInternalLinkTypeMessageBuilder {
 impl RTDInternalLinkTypeMessageBuilder {
   pub fn build(&self) -> InternalLinkTypeMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -1065,6 +1283,12 @@ impl AsRef<InternalLinkTypeMessage> for RTDInternalLinkTypeMessageBuilder {
   fn as_ref(&self) -> &InternalLinkTypeMessage { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+  }
+}
+
 
 
 
@@ -1079,7 +1303,10 @@ pub struct InternalLinkTypeMessageDraft {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message draft text
   text: FormattedText,
   /// True, if the first line of the text contains a link. If true, the input field needs to be focused and the text after the link must be selected
@@ -1088,8 +1315,9 @@ pub struct InternalLinkTypeMessageDraft {
 }
 
 impl RObject for InternalLinkTypeMessageDraft {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeMessageDraft" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeMessageDraft" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1099,14 +1327,15 @@ impl TDInternalLinkType for InternalLinkTypeMessageDraft {}
 
 
 impl InternalLinkTypeMessageDraft {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeMessageDraftBuilder {
-    let mut inner = InternalLinkTypeMessageDraft::default();
-    inner.td_name = "internalLinkTypeMessageDraft".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeMessageDraft { td_name: "internalLinkTypeMessageDraft".to_string(), ..Default::default() };
     RTDInternalLinkTypeMessageDraftBuilder { inner }
   }
 
+  /// `InternalLinkTypeMessageDraft` with the fields tdlib requires, the others are set by `InternalLinkTypeMessageDraft::builder()`
+  pub fn new(text: impl AsRef<FormattedText>, contains_link: bool) -> Self { Self::builder().text(text).contains_link(contains_link).build() }
+
   pub fn text(&self) -> &FormattedText { &self.text }
 
   pub fn contains_link(&self) -> bool { self.contains_link }
@@ -1121,6 +1350,12 @@ pub struct RTDInternalLinkTypeMessageDraftBuilder {
 impl RTDInternalLinkTypeMessageDraftBuilder {
   pub fn build(&self) -> InternalLinkTypeMessageDraft { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1143,11 +1378,20 @@ impl AsRef<InternalLinkTypeMessageDraft> for RTDInternalLinkTypeMessageDraftBuil
   fn as_ref(&self) -> &InternalLinkTypeMessageDraft { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeMessageDraft {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.contains_link == other.contains_link
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "passport")]
 
 /// The link contains a request of Telegram passport data. Call getPassportAuthorizationForm with the given parameters to process the link if the link was received from outside of the app, otherwise ignore it
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -1157,9 +1401,12 @@ pub struct InternalLinkTypePassportDataRequest {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier of the service's bot
-  bot_user_id: i64,
+  bot_user_id: UserId,
   /// Telegram Passport element types requested by the service
   scope: String,
   /// Service's public key
@@ -1172,8 +1419,9 @@ pub struct InternalLinkTypePassportDataRequest {
 }
 
 impl RObject for InternalLinkTypePassportDataRequest {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypePassportDataRequest" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypePassportDataRequest" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1183,15 +1431,30 @@ impl TDInternalLinkType for InternalLinkTypePassportDataRequest {}
 
 
 impl InternalLinkTypePassportDataRequest {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypePassportDataRequestBuilder {
-    let mut inner = InternalLinkTypePassportDataRequest::default();
-    inner.td_name = "internalLinkTypePassportDataRequest".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypePassportDataRequest { td_name: "internalLinkTypePassportDataRequest".to_string(), ..Default::default() };
     RTDInternalLinkTypePassportDataRequestBuilder { inner }
   }
 
-  pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
+  /// `InternalLinkTypePassportDataRequest` with the fields tdlib requires, the others are set by `InternalLinkTypePassportDataRequest::builder()`
+  pub fn new(
+    bot_user_id: UserId,
+    scope: impl AsRef<str>,
+    public_key: impl AsRef<str>,
+    nonce: impl AsRef<str>,
+    callback_url: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .bot_user_id(bot_user_id)
+      .scope(scope)
+      .public_key(public_key)
+      .nonce(nonce)
+      .callback_url(callback_url)
+      .build()
+  }
+
+  pub fn bot_user_id(&self) -> UserId { self.bot_user_id }
 
   pub fn scope(&self) -> &String { &self.scope }
 
@@ -1211,8 +1474,14 @@ pub struct RTDInternalLinkTypePassportDataRequestBuilder {
 impl RTDInternalLinkTypePassportDataRequestBuilder {
   pub fn build(&self) -> InternalLinkTypePassportDataRequest { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
+  pub fn bot_user_id(&mut self, bot_user_id: UserId) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
     self
   }
@@ -1251,6 +1520,18 @@ impl AsRef<InternalLinkTypePassportDataRequest> for RTDInternalLinkTypePassportD
   fn as_ref(&self) -> &InternalLinkTypePassportDataRequest { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypePassportDataRequest {
+  fn eq(&self, other: &Self) -> bool {
+    self.bot_user_id == other.bot_user_id
+      && self.scope == other.scope
+      && self.public_key == other.public_key
+      && self.nonce == other.nonce
+      && self.callback_url == other.callback_url
+  }
+}
+
+}
+
 
 
 
@@ -1265,7 +1546,10 @@ pub struct InternalLinkTypePhoneNumberConfirmation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Hash value from the link
   hash: String,
   /// Phone number value from the link
@@ -1274,8 +1558,9 @@ pub struct InternalLinkTypePhoneNumberConfirmation {
 }
 
 impl RObject for InternalLinkTypePhoneNumberConfirmation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypePhoneNumberConfirmation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypePhoneNumberConfirmation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1285,14 +1570,15 @@ impl TDInternalLinkType for InternalLinkTypePhoneNumberConfirmation {}
 
 
 impl InternalLinkTypePhoneNumberConfirmation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypePhoneNumberConfirmationBuilder {
-    let mut inner = InternalLinkTypePhoneNumberConfirmation::default();
-    inner.td_name = "internalLinkTypePhoneNumberConfirmation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypePhoneNumberConfirmation { td_name: "internalLinkTypePhoneNumberConfirmation".to_string(), ..Default::default() };
     RTDInternalLinkTypePhoneNumberConfirmationBuilder { inner }
   }
 
+  /// `InternalLinkTypePhoneNumberConfirmation` with the fields tdlib requires, the others are set by `InternalLinkTypePhoneNumberConfirmation::builder()`
+  pub fn new(hash: impl AsRef<str>, phone_number: impl AsRef<str>) -> Self { Self::builder().hash(hash).phone_number(phone_number).build() }
+
   pub fn hash(&self) -> &String { &self.hash }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -1307,6 +1593,12 @@ pub struct RTDInternalLinkTypePhoneNumberConfirmationBuilder {
 impl RTDInternalLinkTypePhoneNumberConfirmationBuilder {
   pub fn build(&self) -> InternalLinkTypePhoneNumberConfirmation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
     self.inner.hash = hash.as_ref().to_string();
@@ -1329,6 +1621,13 @@ impl AsRef<InternalLinkTypePhoneNumberConfirmation> for RTDInternalLinkTypePhone
   fn as_ref(&self) -> &InternalLinkTypePhoneNumberConfirmation { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypePhoneNumberConfirmation {
+  fn eq(&self, other: &Self) -> bool {
+    self.hash == other.hash
+      && self.phone_number == other.phone_number
+  }
+}
+
 
 
 
@@ -1343,7 +1642,10 @@ pub struct InternalLinkTypeProxy {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Proxy server IP address
   server: String,
   /// Proxy server port
@@ -1354,8 +1656,9 @@ pub struct InternalLinkTypeProxy {
 }
 
 impl RObject for InternalLinkTypeProxy {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeProxy" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeProxy" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1365,14 +1668,15 @@ impl TDInternalLinkType for InternalLinkTypeProxy {}
 
 
 impl InternalLinkTypeProxy {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeProxyBuilder {
-    let mut inner = InternalLinkTypeProxy::default();
-    inner.td_name = "internalLinkTypeProxy".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeProxy { td_name: "internalLinkTypeProxy".to_string(), ..Default::default() };
     RTDInternalLinkTypeProxyBuilder { inner }
   }
 
+  /// `InternalLinkTypeProxy` with the fields tdlib requires, the others are set by `InternalLinkTypeProxy::builder()`
+  pub fn new(server: impl AsRef<str>, port: i64, type_: impl AsRef<ProxyType>) -> Self { Self::builder().server(server).port(port).type_(type_).build() }
+
   pub fn server(&self) -> &String { &self.server }
 
   pub fn port(&self) -> i64 { self.port }
@@ -1389,6 +1693,12 @@ pub struct RTDInternalLinkTypeProxyBuilder {
 impl RTDInternalLinkTypeProxyBuilder {
   pub fn build(&self) -> InternalLinkTypeProxy { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
     self.inner.server = server.as_ref().to_string();
@@ -1417,6 +1727,14 @@ impl AsRef<InternalLinkTypeProxy> for RTDInternalLinkTypeProxyBuilder {
   fn as_ref(&self) -> &InternalLinkTypeProxy { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeProxy {
+  fn eq(&self, other: &Self) -> bool {
+    self.server == other.server
+      && self.port == other.port
+      && self.type_ == other.type_
+  }
+}
+
 
 
 
@@ -1431,15 +1749,19 @@ pub struct InternalLinkTypePublicChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username of the chat
   chat_username: String,
   
 }
 
 impl RObject for InternalLinkTypePublicChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypePublicChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypePublicChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1449,14 +1771,15 @@ impl TDInternalLinkType for InternalLinkTypePublicChat {}
 
 
 impl InternalLinkTypePublicChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypePublicChatBuilder {
-    let mut inner = InternalLinkTypePublicChat::default();
-    inner.td_name = "internalLinkTypePublicChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypePublicChat { td_name: "internalLinkTypePublicChat".to_string(), ..Default::default() };
     RTDInternalLinkTypePublicChatBuilder { inner }
   }
 
+  /// `InternalLinkTypePublicChat` with the fields tdlib requires, the others are set by `InternalLinkTypePublicChat::builder()`
+  pub fn new(chat_username: impl AsRef<str>) -> Self { Self::builder().chat_username(chat_username).build() }
+
   pub fn chat_username(&self) -> &String { &self.chat_username }
 
 }
@@ -1469,6 +1792,12 @@ pub struct RTDInternalLinkTypePublicChatBuilder {
 impl RTDInternalLinkTypePublicChatBuilder {
   pub fn build(&self) -> InternalLinkTypePublicChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_username<T: AsRef<str>>(&mut self, chat_username: T) -> &mut Self {
     self.inner.chat_username = chat_username.as_ref().to_string();
@@ -1485,6 +1814,12 @@ impl AsRef<InternalLinkTypePublicChat> for RTDInternalLinkTypePublicChatBuilder
   fn as_ref(&self) -> &InternalLinkTypePublicChat { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypePublicChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_username == other.chat_username
+  }
+}
+
 
 
 
@@ -1499,13 +1834,17 @@ pub struct InternalLinkTypeQrCodeAuthentication {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InternalLinkTypeQrCodeAuthentication {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeQrCodeAuthentication" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeQrCodeAuthentication" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1515,14 +1854,15 @@ impl TDInternalLinkType for InternalLinkTypeQrCodeAuthentication {}
 
 
 impl InternalLinkTypeQrCodeAuthentication {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeQrCodeAuthenticationBuilder {
-    let mut inner = InternalLinkTypeQrCodeAuthentication::default();
-    inner.td_name = "internalLinkTypeQrCodeAuthentication".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeQrCodeAuthentication { td_name: "internalLinkTypeQrCodeAuthentication".to_string(), ..Default::default() };
     RTDInternalLinkTypeQrCodeAuthenticationBuilder { inner }
   }
 
+  /// `InternalLinkTypeQrCodeAuthentication` with the fields tdlib requires, the others are set by `InternalLinkTypeQrCodeAuthentication::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1533,6 +1873,12 @@ pub struct RTDInternalLinkTypeQrCodeAuthenticationBuilder {
 impl RTDInternalLinkTypeQrCodeAuthenticationBuilder {
   pub fn build(&self) -> InternalLinkTypeQrCodeAuthentication { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InternalLinkTypeQrCodeAuthentication> for InternalLinkTypeQrCodeAuthentication {
@@ -1543,6 +1889,10 @@ impl AsRef<InternalLinkTypeQrCodeAuthentication> for RTDInternalLinkTypeQrCodeAu
   fn as_ref(&self) -> &InternalLinkTypeQrCodeAuthentication { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeQrCodeAuthentication {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1557,13 +1907,17 @@ pub struct InternalLinkTypeSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InternalLinkTypeSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1573,14 +1927,15 @@ impl TDInternalLinkType for InternalLinkTypeSettings {}
 
 
 impl InternalLinkTypeSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeSettingsBuilder {
-    let mut inner = InternalLinkTypeSettings::default();
-    inner.td_name = "internalLinkTypeSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeSettings { td_name: "internalLinkTypeSettings".to_string(), ..Default::default() };
     RTDInternalLinkTypeSettingsBuilder { inner }
   }
 
+  /// `InternalLinkTypeSettings` with the fields tdlib requires, the others are set by `InternalLinkTypeSettings::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1591,6 +1946,12 @@ pub struct RTDInternalLinkTypeSettingsBuilder {
 impl RTDInternalLinkTypeSettingsBuilder {
   pub fn build(&self) -> InternalLinkTypeSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InternalLinkTypeSettings> for InternalLinkTypeSettings {
@@ -1601,12 +1962,18 @@ impl AsRef<InternalLinkTypeSettings> for RTDInternalLinkTypeSettingsBuilder {
   fn as_ref(&self) -> &InternalLinkTypeSettings { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeSettings {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// The link is a link to a sticker set. Call searchStickerSet with the given sticker set name to process the link and show the sticker set
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InternalLinkTypeStickerSet {
@@ -1615,15 +1982,19 @@ pub struct InternalLinkTypeStickerSet {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the sticker set
   sticker_set_name: String,
   
 }
 
 impl RObject for InternalLinkTypeStickerSet {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeStickerSet" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeStickerSet" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1633,14 +2004,15 @@ impl TDInternalLinkType for InternalLinkTypeStickerSet {}
 
 
 impl InternalLinkTypeStickerSet {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeStickerSetBuilder {
-    let mut inner = InternalLinkTypeStickerSet::default();
-    inner.td_name = "internalLinkTypeStickerSet".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeStickerSet { td_name: "internalLinkTypeStickerSet".to_string(), ..Default::default() };
     RTDInternalLinkTypeStickerSetBuilder { inner }
   }
 
+  /// `InternalLinkTypeStickerSet` with the fields tdlib requires, the others are set by `InternalLinkTypeStickerSet::builder()`
+  pub fn new(sticker_set_name: impl AsRef<str>) -> Self { Self::builder().sticker_set_name(sticker_set_name).build() }
+
   pub fn sticker_set_name(&self) -> &String { &self.sticker_set_name }
 
 }
@@ -1653,6 +2025,12 @@ pub struct RTDInternalLinkTypeStickerSetBuilder {
 impl RTDInternalLinkTypeStickerSetBuilder {
   pub fn build(&self) -> InternalLinkTypeStickerSet { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker_set_name<T: AsRef<str>>(&mut self, sticker_set_name: T) -> &mut Self {
     self.inner.sticker_set_name = sticker_set_name.as_ref().to_string();
@@ -1669,6 +2047,14 @@ impl AsRef<InternalLinkTypeStickerSet> for RTDInternalLinkTypeStickerSetBuilder
   fn as_ref(&self) -> &InternalLinkTypeStickerSet { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeStickerSet {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker_set_name == other.sticker_set_name
+  }
+}
+
+}
+
 
 
 
@@ -1683,15 +2069,19 @@ pub struct InternalLinkTypeTheme {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the theme
   theme_name: String,
   
 }
 
 impl RObject for InternalLinkTypeTheme {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeTheme" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeTheme" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1701,14 +2091,15 @@ impl TDInternalLinkType for InternalLinkTypeTheme {}
 
 
 impl InternalLinkTypeTheme {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeThemeBuilder {
-    let mut inner = InternalLinkTypeTheme::default();
-    inner.td_name = "internalLinkTypeTheme".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeTheme { td_name: "internalLinkTypeTheme".to_string(), ..Default::default() };
     RTDInternalLinkTypeThemeBuilder { inner }
   }
 
+  /// `InternalLinkTypeTheme` with the fields tdlib requires, the others are set by `InternalLinkTypeTheme::builder()`
+  pub fn new(theme_name: impl AsRef<str>) -> Self { Self::builder().theme_name(theme_name).build() }
+
   pub fn theme_name(&self) -> &String { &self.theme_name }
 
 }
@@ -1721,6 +2112,12 @@ pub struct RTDInternalLinkTypeThemeBuilder {
 impl RTDInternalLinkTypeThemeBuilder {
   pub fn build(&self) -> InternalLinkTypeTheme { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn theme_name<T: AsRef<str>>(&mut self, theme_name: T) -> &mut Self {
     self.inner.theme_name = theme_name.as_ref().to_string();
@@ -1737,6 +2134,12 @@ impl AsRef<InternalLinkTypeTheme> for RTDInternalLinkTypeThemeBuilder {
   fn as_ref(&self) -> &InternalLinkTypeTheme { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeTheme {
+  fn eq(&self, other: &Self) -> bool {
+    self.theme_name == other.theme_name
+  }
+}
+
 
 
 
@@ -1751,13 +2154,17 @@ pub struct InternalLinkTypeThemeSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InternalLinkTypeThemeSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeThemeSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeThemeSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1767,14 +2174,15 @@ impl TDInternalLinkType for InternalLinkTypeThemeSettings {}
 
 
 impl InternalLinkTypeThemeSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeThemeSettingsBuilder {
-    let mut inner = InternalLinkTypeThemeSettings::default();
-    inner.td_name = "internalLinkTypeThemeSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeThemeSettings { td_name: "internalLinkTypeThemeSettings".to_string(), ..Default::default() };
     RTDInternalLinkTypeThemeSettingsBuilder { inner }
   }
 
+  /// `InternalLinkTypeThemeSettings` with the fields tdlib requires, the others are set by `InternalLinkTypeThemeSettings::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1785,6 +2193,12 @@ pub struct RTDInternalLinkTypeThemeSettingsBuilder {
 impl RTDInternalLinkTypeThemeSettingsBuilder {
   pub fn build(&self) -> InternalLinkTypeThemeSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InternalLinkTypeThemeSettings> for InternalLinkTypeThemeSettings {
@@ -1795,6 +2209,10 @@ impl AsRef<InternalLinkTypeThemeSettings> for RTDInternalLinkTypeThemeSettingsBu
   fn as_ref(&self) -> &InternalLinkTypeThemeSettings { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeThemeSettings {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1809,15 +2227,19 @@ pub struct InternalLinkTypeUnknownDeepLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Link to be passed to getDeepLinkInfo
   link: String,
   
 }
 
 impl RObject for InternalLinkTypeUnknownDeepLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeUnknownDeepLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeUnknownDeepLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1827,14 +2249,15 @@ impl TDInternalLinkType for InternalLinkTypeUnknownDeepLink {}
 
 
 impl InternalLinkTypeUnknownDeepLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeUnknownDeepLinkBuilder {
-    let mut inner = InternalLinkTypeUnknownDeepLink::default();
-    inner.td_name = "internalLinkTypeUnknownDeepLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeUnknownDeepLink { td_name: "internalLinkTypeUnknownDeepLink".to_string(), ..Default::default() };
     RTDInternalLinkTypeUnknownDeepLinkBuilder { inner }
   }
 
+  /// `InternalLinkTypeUnknownDeepLink` with the fields tdlib requires, the others are set by `InternalLinkTypeUnknownDeepLink::builder()`
+  pub fn new(link: impl AsRef<str>) -> Self { Self::builder().link(link).build() }
+
   pub fn link(&self) -> &String { &self.link }
 
 }
@@ -1847,6 +2270,12 @@ pub struct RTDInternalLinkTypeUnknownDeepLinkBuilder {
 impl RTDInternalLinkTypeUnknownDeepLinkBuilder {
   pub fn build(&self) -> InternalLinkTypeUnknownDeepLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
@@ -1863,6 +2292,12 @@ impl AsRef<InternalLinkTypeUnknownDeepLink> for RTDInternalLinkTypeUnknownDeepLi
   fn as_ref(&self) -> &InternalLinkTypeUnknownDeepLink { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeUnknownDeepLink {
+  fn eq(&self, other: &Self) -> bool {
+    self.link == other.link
+  }
+}
+
 
 
 
@@ -1877,13 +2312,17 @@ pub struct InternalLinkTypeUnsupportedProxy {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for InternalLinkTypeUnsupportedProxy {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeUnsupportedProxy" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeUnsupportedProxy" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1893,14 +2332,15 @@ impl TDInternalLinkType for InternalLinkTypeUnsupportedProxy {}
 
 
 impl InternalLinkTypeUnsupportedProxy {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeUnsupportedProxyBuilder {
-    let mut inner = InternalLinkTypeUnsupportedProxy::default();
-    inner.td_name = "internalLinkTypeUnsupportedProxy".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeUnsupportedProxy { td_name: "internalLinkTypeUnsupportedProxy".to_string(), ..Default::default() };
     RTDInternalLinkTypeUnsupportedProxyBuilder { inner }
   }
 
+  /// `InternalLinkTypeUnsupportedProxy` with the fields tdlib requires, the others are set by `InternalLinkTypeUnsupportedProxy::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1911,6 +2351,12 @@ pub struct RTDInternalLinkTypeUnsupportedProxyBuilder {
 impl RTDInternalLinkTypeUnsupportedProxyBuilder {
   pub fn build(&self) -> InternalLinkTypeUnsupportedProxy { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<InternalLinkTypeUnsupportedProxy> for InternalLinkTypeUnsupportedProxy {
@@ -1921,6 +2367,10 @@ impl AsRef<InternalLinkTypeUnsupportedProxy> for RTDInternalLinkTypeUnsupportedP
   fn as_ref(&self) -> &InternalLinkTypeUnsupportedProxy { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeUnsupportedProxy {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1935,7 +2385,10 @@ pub struct InternalLinkTypeVideoChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username of the chat with the video chat
   chat_username: String,
   /// If non-empty, invite hash to be used to join the video chat without being muted by administrators
@@ -1946,8 +2399,9 @@ pub struct InternalLinkTypeVideoChat {
 }
 
 impl RObject for InternalLinkTypeVideoChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "internalLinkTypeVideoChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "internalLinkTypeVideoChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1957,14 +2411,15 @@ impl TDInternalLinkType for InternalLinkTypeVideoChat {}
 
 
 impl InternalLinkTypeVideoChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInternalLinkTypeVideoChatBuilder {
-    let mut inner = InternalLinkTypeVideoChat::default();
-    inner.td_name = "internalLinkTypeVideoChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = InternalLinkTypeVideoChat { td_name: "internalLinkTypeVideoChat".to_string(), ..Default::default() };
     RTDInternalLinkTypeVideoChatBuilder { inner }
   }
 
+  /// `InternalLinkTypeVideoChat` with the fields tdlib requires, the others are set by `InternalLinkTypeVideoChat::builder()`
+  pub fn new(chat_username: impl AsRef<str>, invite_hash: impl AsRef<str>, is_live_stream: bool) -> Self { Self::builder().chat_username(chat_username).invite_hash(invite_hash).is_live_stream(is_live_stream).build() }
+
   pub fn chat_username(&self) -> &String { &self.chat_username }
 
   pub fn invite_hash(&self) -> &String { &self.invite_hash }
@@ -1981,6 +2436,12 @@ pub struct RTDInternalLinkTypeVideoChatBuilder {
 impl RTDInternalLinkTypeVideoChatBuilder {
   pub fn build(&self) -> InternalLinkTypeVideoChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_username<T: AsRef<str>>(&mut self, chat_username: T) -> &mut Self {
     self.inner.chat_username = chat_username.as_ref().to_string();
@@ -2009,5 +2470,13 @@ impl AsRef<InternalLinkTypeVideoChat> for RTDInternalLinkTypeVideoChatBuilder {
   fn as_ref(&self) -> &InternalLinkTypeVideoChat { &self.inner }
 }
 
+impl PartialEq for InternalLinkTypeVideoChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_username == other.chat_username
+      && self.invite_hash == other.invite_hash
+      && self.is_live_stream == other.is_live_stream
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Invoice {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// ISO 4217 currency code
   currency: String,
   /// A list of objects used to calculate the total price of the product
@@ -43,22 +45,53 @@ pub struct Invoice {
 }
 
 impl RObject for Invoice {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "invoice" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "invoice" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Invoice {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDInvoiceBuilder {
-    let mut inner = Invoice::default();
-    inner.td_name = "invoice".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Invoice { td_name: "invoice".to_string(), ..Default::default() };
     RTDInvoiceBuilder { inner }
   }
 
+  /// `Invoice` with the fields tdlib requires, the others are set by `Invoice::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    currency: impl AsRef<str>,
+    price_parts: Vec<LabeledPricePart>,
+    max_tip_amount: i64,
+    suggested_tip_amounts: Vec<i64>,
+    is_test: bool,
+    need_name: bool,
+    need_phone_number: bool,
+    need_email_address: bool,
+    need_shipping_address: bool,
+    send_phone_number_to_provider: bool,
+    send_email_address_to_provider: bool,
+    is_flexible: bool,
+  ) -> Self {
+    Self::builder()
+      .currency(currency)
+      .price_parts(price_parts)
+      .max_tip_amount(max_tip_amount)
+      .suggested_tip_amounts(suggested_tip_amounts)
+      .is_test(is_test)
+      .need_name(need_name)
+      .need_phone_number(need_phone_number)
+      .need_email_address(need_email_address)
+      .need_shipping_address(need_shipping_address)
+      .send_phone_number_to_provider(send_phone_number_to_provider)
+      .send_email_address_to_provider(send_email_address_to_provider)
+      .is_flexible(is_flexible)
+      .build()
+  }
+
   pub fn currency(&self) -> &String { &self.currency }
 
   pub fn price_parts(&self) -> &Vec<LabeledPricePart> { &self.price_parts }
@@ -93,6 +126,12 @@ pub struct RTDInvoiceBuilder {
 impl RTDInvoiceBuilder {
   pub fn build(&self) -> Invoice { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
@@ -175,5 +214,22 @@ impl AsRef<Invoice> for RTDInvoiceBuilder {
   fn as_ref(&self) -> &Invoice { &self.inner }
 }
 
+impl PartialEq for Invoice {
+  fn eq(&self, other: &Self) -> bool {
+    self.currency == other.currency
+      && self.price_parts == other.price_parts
+      && self.max_tip_amount == other.max_tip_amount
+      && self.suggested_tip_amounts == other.suggested_tip_amounts
+      && self.is_test == other.is_test
+      && self.need_name == other.need_name
+      && self.need_phone_number == other.need_phone_number
+      && self.need_email_address == other.need_email_address
+      && self.need_shipping_address == other.need_shipping_address
+      && self.send_phone_number_to_provider == other.send_phone_number_to_provider
+      && self.send_email_address_to_provider == other.send_email_address_to_provider
+      && self.is_flexible == other.is_flexible
+  }
+}
+
 
 
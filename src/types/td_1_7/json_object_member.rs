@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct JsonObjectMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Member's key
   key: String,
   /// Member's value
@@ -23,22 +25,24 @@ pub struct JsonObjectMember {
 }
 
 impl RObject for JsonObjectMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonObjectMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "jsonObjectMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl JsonObjectMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDJsonObjectMemberBuilder {
-    let mut inner = JsonObjectMember::default();
-    inner.td_name = "jsonObjectMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = JsonObjectMember { td_name: "jsonObjectMember".to_string(), ..Default::default() };
     RTDJsonObjectMemberBuilder { inner }
   }
 
+  /// `JsonObjectMember` with the fields tdlib requires, the others are set by `JsonObjectMember::builder()`
+  pub fn new(key: impl AsRef<str>, value: impl AsRef<JsonValue>) -> Self { Self::builder().key(key).value(value).build() }
+
   pub fn key(&self) -> &String { &self.key }
 
   pub fn value(&self) -> &JsonValue { &self.value }
@@ -53,6 +57,12 @@ pub struct RTDJsonObjectMemberBuilder {
 impl RTDJsonObjectMemberBuilder {
   pub fn build(&self) -> JsonObjectMember { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
     self.inner.key = key.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<JsonObjectMember> for RTDJsonObjectMemberBuilder {
   fn as_ref(&self) -> &JsonObjectMember { &self.inner }
 }
 
+impl PartialEq for JsonObjectMember {
+  fn eq(&self, other: &Self) -> bool {
+    self.key == other.key
+      && self.value == other.value
+  }
+}
+
 
 
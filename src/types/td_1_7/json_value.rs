@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDJsonValue: Debug + RObject {}
 
 /// Represents a JSON value
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum JsonValue {
   #[doc(hidden)] _Default(()),
@@ -35,6 +34,8 @@ pub enum JsonValue {
   Object(JsonValueObject),
   /// Represents a string JSON value
   String(JsonValueString),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -46,7 +47,7 @@ impl<'de> Deserialize<'de> for JsonValue {
   fn deserialize<D>(deserializer: D) -> Result<JsonValue, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      JsonValue,
+      JsonValue, Unknown;
       (getApplicationConfig, GetApplicationConfig);
       (getJsonValue, GetJsonValue);
       (jsonValueArray, Array);
@@ -61,7 +62,7 @@ impl<'de> Deserialize<'de> for JsonValue {
 }
 
 impl RObject for JsonValue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       JsonValue::GetApplicationConfig(t) => t.td_name(),
       JsonValue::GetJsonValue(t) => t.td_name(),
@@ -71,11 +72,12 @@ impl RObject for JsonValue {
       JsonValue::Number(t) => t.td_name(),
       JsonValue::Object(t) => t.td_name(),
       JsonValue::String(t) => t.td_name(),
+      JsonValue::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       JsonValue::GetApplicationConfig(t) => t.extra(),
       JsonValue::GetJsonValue(t) => t.extra(),
@@ -85,6 +87,22 @@ impl RObject for JsonValue {
       JsonValue::Number(t) => t.extra(),
       JsonValue::Object(t) => t.extra(),
       JsonValue::String(t) => t.extra(),
+      JsonValue::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      JsonValue::GetApplicationConfig(t) => t.client_id(),
+      JsonValue::GetJsonValue(t) => t.client_id(),
+      JsonValue::Array(t) => t.client_id(),
+      JsonValue::Boolean(t) => t.client_id(),
+      JsonValue::Null(t) => t.client_id(),
+      JsonValue::Number(t) => t.client_id(),
+      JsonValue::Object(t) => t.client_id(),
+      JsonValue::String(t) => t.client_id(),
+      JsonValue::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -93,17 +111,18 @@ impl RObject for JsonValue {
 }
 
 impl JsonValue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let JsonValue::_Default(_) = self { true } else { false } }
-
-  pub fn is_get_application_config(&self) -> bool { if let JsonValue::GetApplicationConfig(_) = self { true } else { false } }
-  pub fn is_get_json_value(&self) -> bool { if let JsonValue::GetJsonValue(_) = self { true } else { false } }
-  pub fn is_array(&self) -> bool { if let JsonValue::Array(_) = self { true } else { false } }
-  pub fn is_boolean(&self) -> bool { if let JsonValue::Boolean(_) = self { true } else { false } }
-  pub fn is_null(&self) -> bool { if let JsonValue::Null(_) = self { true } else { false } }
-  pub fn is_number(&self) -> bool { if let JsonValue::Number(_) = self { true } else { false } }
-  pub fn is_object(&self) -> bool { if let JsonValue::Object(_) = self { true } else { false } }
-  pub fn is_string(&self) -> bool { if let JsonValue::String(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, JsonValue::_Default(_)) }
+
+  pub fn is_get_application_config(&self) -> bool { matches!(self, JsonValue::GetApplicationConfig(_)) }
+  pub fn is_get_json_value(&self) -> bool { matches!(self, JsonValue::GetJsonValue(_)) }
+  pub fn is_array(&self) -> bool { matches!(self, JsonValue::Array(_)) }
+  pub fn is_boolean(&self) -> bool { matches!(self, JsonValue::Boolean(_)) }
+  pub fn is_null(&self) -> bool { matches!(self, JsonValue::Null(_)) }
+  pub fn is_number(&self) -> bool { matches!(self, JsonValue::Number(_)) }
+  pub fn is_object(&self) -> bool { matches!(self, JsonValue::Object(_)) }
+  pub fn is_string(&self) -> bool { matches!(self, JsonValue::String(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, JsonValue::Unknown(_)) }
 
   pub fn on_get_application_config<F: FnOnce(&GetApplicationConfig)>(&self, fnc: F) -> &Self { if let JsonValue::GetApplicationConfig(t) = self { fnc(t) }; self }
   pub fn on_get_json_value<F: FnOnce(&GetJsonValue)>(&self, fnc: F) -> &Self { if let JsonValue::GetJsonValue(t) = self { fnc(t) }; self }
@@ -113,6 +132,7 @@ impl JsonValue {
   pub fn on_number<F: FnOnce(&JsonValueNumber)>(&self, fnc: F) -> &Self { if let JsonValue::Number(t) = self { fnc(t) }; self }
   pub fn on_object<F: FnOnce(&JsonValueObject)>(&self, fnc: F) -> &Self { if let JsonValue::Object(t) = self { fnc(t) }; self }
   pub fn on_string<F: FnOnce(&JsonValueString)>(&self, fnc: F) -> &Self { if let JsonValue::String(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let JsonValue::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_application_config(&self) -> Option<&GetApplicationConfig> { if let JsonValue::GetApplicationConfig(t) = self { return Some(t) } None }
   pub fn as_get_json_value(&self) -> Option<&GetJsonValue> { if let JsonValue::GetJsonValue(t) = self { return Some(t) } None }
@@ -122,6 +142,7 @@ impl JsonValue {
   pub fn as_number(&self) -> Option<&JsonValueNumber> { if let JsonValue::Number(t) = self { return Some(t) } None }
   pub fn as_object(&self) -> Option<&JsonValueObject> { if let JsonValue::Object(t) = self { return Some(t) } None }
   pub fn as_string(&self) -> Option<&JsonValueString> { if let JsonValue::String(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let JsonValue::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -161,15 +182,19 @@ pub struct JsonValueArray {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The list of array elements
   values: Vec<JsonValue>,
   
 }
 
 impl RObject for JsonValueArray {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueArray" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "jsonValueArray" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -179,14 +204,15 @@ impl TDJsonValue for JsonValueArray {}
 
 
 impl JsonValueArray {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDJsonValueArrayBuilder {
-    let mut inner = JsonValueArray::default();
-    inner.td_name = "jsonValueArray".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = JsonValueArray { td_name: "jsonValueArray".to_string(), ..Default::default() };
     RTDJsonValueArrayBuilder { inner }
   }
 
+  /// `JsonValueArray` with the fields tdlib requires, the others are set by `JsonValueArray::builder()`
+  pub fn new(values: Vec<JsonValue>) -> Self { Self::builder().values(values).build() }
+
   pub fn values(&self) -> &Vec<JsonValue> { &self.values }
 
 }
@@ -199,6 +225,12 @@ pub struct RTDJsonValueArrayBuilder {
 impl RTDJsonValueArrayBuilder {
   pub fn build(&self) -> JsonValueArray { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn values(&mut self, values: Vec<JsonValue>) -> &mut Self {
     self.inner.values = values;
@@ -215,6 +247,12 @@ impl AsRef<JsonValueArray> for RTDJsonValueArrayBuilder {
   fn as_ref(&self) -> &JsonValueArray { &self.inner }
 }
 
+impl PartialEq for JsonValueArray {
+  fn eq(&self, other: &Self) -> bool {
+    self.values == other.values
+  }
+}
+
 
 
 
@@ -229,15 +267,19 @@ pub struct JsonValueBoolean {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The value
   value: bool,
   
 }
 
 impl RObject for JsonValueBoolean {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueBoolean" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "jsonValueBoolean" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -247,14 +289,15 @@ impl TDJsonValue for JsonValueBoolean {}
 
 
 impl JsonValueBoolean {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDJsonValueBooleanBuilder {
-    let mut inner = JsonValueBoolean::default();
-    inner.td_name = "jsonValueBoolean".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = JsonValueBoolean { td_name: "jsonValueBoolean".to_string(), ..Default::default() };
     RTDJsonValueBooleanBuilder { inner }
   }
 
+  /// `JsonValueBoolean` with the fields tdlib requires, the others are set by `JsonValueBoolean::builder()`
+  pub fn new(value: bool) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> bool { self.value }
 
 }
@@ -267,6 +310,12 @@ pub struct RTDJsonValueBooleanBuilder {
 impl RTDJsonValueBooleanBuilder {
   pub fn build(&self) -> JsonValueBoolean { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: bool) -> &mut Self {
     self.inner.value = value;
@@ -283,6 +332,12 @@ impl AsRef<JsonValueBoolean> for RTDJsonValueBooleanBuilder {
   fn as_ref(&self) -> &JsonValueBoolean { &self.inner }
 }
 
+impl PartialEq for JsonValueBoolean {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
 
@@ -297,13 +352,17 @@ pub struct JsonValueNull {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for JsonValueNull {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueNull" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "jsonValueNull" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -313,14 +372,15 @@ impl TDJsonValue for JsonValueNull {}
 
 
 impl JsonValueNull {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDJsonValueNullBuilder {
-    let mut inner = JsonValueNull::default();
-    inner.td_name = "jsonValueNull".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = JsonValueNull { td_name: "jsonValueNull".to_string(), ..Default::default() };
     RTDJsonValueNullBuilder { inner }
   }
 
+  /// `JsonValueNull` with the fields tdlib requires, the others are set by `JsonValueNull::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -331,6 +391,12 @@ pub struct RTDJsonValueNullBuilder {
 impl RTDJsonValueNullBuilder {
   pub fn build(&self) -> JsonValueNull { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<JsonValueNull> for JsonValueNull {
@@ -341,6 +407,10 @@ impl AsRef<JsonValueNull> for RTDJsonValueNullBuilder {
   fn as_ref(&self) -> &JsonValueNull { &self.inner }
 }
 
+impl PartialEq for JsonValueNull {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -355,15 +425,19 @@ pub struct JsonValueNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The value
   value: f32,
   
 }
 
 impl RObject for JsonValueNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "jsonValueNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -373,14 +447,15 @@ impl TDJsonValue for JsonValueNumber {}
 
 
 impl JsonValueNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDJsonValueNumberBuilder {
-    let mut inner = JsonValueNumber::default();
-    inner.td_name = "jsonValueNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = JsonValueNumber { td_name: "jsonValueNumber".to_string(), ..Default::default() };
     RTDJsonValueNumberBuilder { inner }
   }
 
+  /// `JsonValueNumber` with the fields tdlib requires, the others are set by `JsonValueNumber::builder()`
+  pub fn new(value: f32) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> f32 { self.value }
 
 }
@@ -393,6 +468,12 @@ pub struct RTDJsonValueNumberBuilder {
 impl RTDJsonValueNumberBuilder {
   pub fn build(&self) -> JsonValueNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: f32) -> &mut Self {
     self.inner.value = value;
@@ -409,6 +490,12 @@ impl AsRef<JsonValueNumber> for RTDJsonValueNumberBuilder {
   fn as_ref(&self) -> &JsonValueNumber { &self.inner }
 }
 
+impl PartialEq for JsonValueNumber {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
 
@@ -423,15 +510,19 @@ pub struct JsonValueObject {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The list of object members
   members: Vec<JsonObjectMember>,
   
 }
 
 impl RObject for JsonValueObject {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueObject" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "jsonValueObject" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -441,14 +532,15 @@ impl TDJsonValue for JsonValueObject {}
 
 
 impl JsonValueObject {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDJsonValueObjectBuilder {
-    let mut inner = JsonValueObject::default();
-    inner.td_name = "jsonValueObject".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = JsonValueObject { td_name: "jsonValueObject".to_string(), ..Default::default() };
     RTDJsonValueObjectBuilder { inner }
   }
 
+  /// `JsonValueObject` with the fields tdlib requires, the others are set by `JsonValueObject::builder()`
+  pub fn new(members: Vec<JsonObjectMember>) -> Self { Self::builder().members(members).build() }
+
   pub fn members(&self) -> &Vec<JsonObjectMember> { &self.members }
 
 }
@@ -461,6 +553,12 @@ pub struct RTDJsonValueObjectBuilder {
 impl RTDJsonValueObjectBuilder {
   pub fn build(&self) -> JsonValueObject { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn members(&mut self, members: Vec<JsonObjectMember>) -> &mut Self {
     self.inner.members = members;
@@ -477,6 +575,12 @@ impl AsRef<JsonValueObject> for RTDJsonValueObjectBuilder {
   fn as_ref(&self) -> &JsonValueObject { &self.inner }
 }
 
+impl PartialEq for JsonValueObject {
+  fn eq(&self, other: &Self) -> bool {
+    self.members == other.members
+  }
+}
+
 
 
 
@@ -491,15 +595,19 @@ pub struct JsonValueString {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The value
   value: String,
   
 }
 
 impl RObject for JsonValueString {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "jsonValueString" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "jsonValueString" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -509,14 +617,15 @@ impl TDJsonValue for JsonValueString {}
 
 
 impl JsonValueString {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDJsonValueStringBuilder {
-    let mut inner = JsonValueString::default();
-    inner.td_name = "jsonValueString".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = JsonValueString { td_name: "jsonValueString".to_string(), ..Default::default() };
     RTDJsonValueStringBuilder { inner }
   }
 
+  /// `JsonValueString` with the fields tdlib requires, the others are set by `JsonValueString::builder()`
+  pub fn new(value: impl AsRef<str>) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> &String { &self.value }
 
 }
@@ -529,6 +638,12 @@ pub struct RTDJsonValueStringBuilder {
 impl RTDJsonValueStringBuilder {
   pub fn build(&self) -> JsonValueString { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
@@ -545,5 +660,11 @@ impl AsRef<JsonValueString> for RTDJsonValueStringBuilder {
   fn as_ref(&self) -> &JsonValueString { &self.inner }
 }
 
+impl PartialEq for JsonValueString {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct KeyboardButton {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text of the button
   text: String,
   /// Type of the button
@@ -23,22 +25,24 @@ pub struct KeyboardButton {
 }
 
 impl RObject for KeyboardButton {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButton" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "keyboardButton" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl KeyboardButton {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDKeyboardButtonBuilder {
-    let mut inner = KeyboardButton::default();
-    inner.td_name = "keyboardButton".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = KeyboardButton { td_name: "keyboardButton".to_string(), ..Default::default() };
     RTDKeyboardButtonBuilder { inner }
   }
 
+  /// `KeyboardButton` with the fields tdlib requires, the others are set by `KeyboardButton::builder()`
+  pub fn new(text: impl AsRef<str>, type_: impl AsRef<KeyboardButtonType>) -> Self { Self::builder().text(text).type_(type_).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
   pub fn type_(&self) -> &KeyboardButtonType { &self.type_ }
@@ -53,6 +57,12 @@ pub struct RTDKeyboardButtonBuilder {
 impl RTDKeyboardButtonBuilder {
   pub fn build(&self) -> KeyboardButton { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<KeyboardButton> for RTDKeyboardButtonBuilder {
   fn as_ref(&self) -> &KeyboardButton { &self.inner }
 }
 
+impl PartialEq for KeyboardButton {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.type_ == other.type_
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDKeyboardButtonType: Debug + RObject {}
 
 /// Describes a keyboard button type
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum KeyboardButtonType {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum KeyboardButtonType {
   RequestPoll(KeyboardButtonTypeRequestPoll),
   /// A simple button, with text that must be sent when the button is pressed
   Text(KeyboardButtonTypeText),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for KeyboardButtonType {
   fn deserialize<D>(deserializer: D) -> Result<KeyboardButtonType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      KeyboardButtonType,
+      KeyboardButtonType, Unknown;
       (keyboardButtonTypeRequestLocation, RequestLocation);
       (keyboardButtonTypeRequestPhoneNumber, RequestPhoneNumber);
       (keyboardButtonTypeRequestPoll, RequestPoll);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for KeyboardButtonType {
 }
 
 impl RObject for KeyboardButtonType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       KeyboardButtonType::RequestLocation(t) => t.td_name(),
       KeyboardButtonType::RequestPhoneNumber(t) => t.td_name(),
       KeyboardButtonType::RequestPoll(t) => t.td_name(),
       KeyboardButtonType::Text(t) => t.td_name(),
+      KeyboardButtonType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       KeyboardButtonType::RequestLocation(t) => t.extra(),
       KeyboardButtonType::RequestPhoneNumber(t) => t.extra(),
       KeyboardButtonType::RequestPoll(t) => t.extra(),
       KeyboardButtonType::Text(t) => t.extra(),
+      KeyboardButtonType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      KeyboardButtonType::RequestLocation(t) => t.client_id(),
+      KeyboardButtonType::RequestPhoneNumber(t) => t.client_id(),
+      KeyboardButtonType::RequestPoll(t) => t.client_id(),
+      KeyboardButtonType::Text(t) => t.client_id(),
+      KeyboardButtonType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for KeyboardButtonType {
 }
 
 impl KeyboardButtonType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let KeyboardButtonType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, KeyboardButtonType::_Default(_)) }
 
-  pub fn is_request_location(&self) -> bool { if let KeyboardButtonType::RequestLocation(_) = self { true } else { false } }
-  pub fn is_request_phone_number(&self) -> bool { if let KeyboardButtonType::RequestPhoneNumber(_) = self { true } else { false } }
-  pub fn is_request_poll(&self) -> bool { if let KeyboardButtonType::RequestPoll(_) = self { true } else { false } }
-  pub fn is_text(&self) -> bool { if let KeyboardButtonType::Text(_) = self { true } else { false } }
+  pub fn is_request_location(&self) -> bool { matches!(self, KeyboardButtonType::RequestLocation(_)) }
+  pub fn is_request_phone_number(&self) -> bool { matches!(self, KeyboardButtonType::RequestPhoneNumber(_)) }
+  pub fn is_request_poll(&self) -> bool { matches!(self, KeyboardButtonType::RequestPoll(_)) }
+  pub fn is_text(&self) -> bool { matches!(self, KeyboardButtonType::Text(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, KeyboardButtonType::Unknown(_)) }
 
   pub fn on_request_location<F: FnOnce(&KeyboardButtonTypeRequestLocation)>(&self, fnc: F) -> &Self { if let KeyboardButtonType::RequestLocation(t) = self { fnc(t) }; self }
   pub fn on_request_phone_number<F: FnOnce(&KeyboardButtonTypeRequestPhoneNumber)>(&self, fnc: F) -> &Self { if let KeyboardButtonType::RequestPhoneNumber(t) = self { fnc(t) }; self }
   pub fn on_request_poll<F: FnOnce(&KeyboardButtonTypeRequestPoll)>(&self, fnc: F) -> &Self { if let KeyboardButtonType::RequestPoll(t) = self { fnc(t) }; self }
   pub fn on_text<F: FnOnce(&KeyboardButtonTypeText)>(&self, fnc: F) -> &Self { if let KeyboardButtonType::Text(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let KeyboardButtonType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_request_location(&self) -> Option<&KeyboardButtonTypeRequestLocation> { if let KeyboardButtonType::RequestLocation(t) = self { return Some(t) } None }
   pub fn as_request_phone_number(&self) -> Option<&KeyboardButtonTypeRequestPhoneNumber> { if let KeyboardButtonType::RequestPhoneNumber(t) = self { return Some(t) } None }
   pub fn as_request_poll(&self) -> Option<&KeyboardButtonTypeRequestPoll> { if let KeyboardButtonType::RequestPoll(t) = self { return Some(t) } None }
   pub fn as_text(&self) -> Option<&KeyboardButtonTypeText> { if let KeyboardButtonType::Text(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let KeyboardButtonType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,13 +138,17 @@ pub struct KeyboardButtonTypeRequestLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for KeyboardButtonTypeRequestLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeRequestLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "keyboardButtonTypeRequestLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,14 +158,15 @@ impl TDKeyboardButtonType for KeyboardButtonTypeRequestLocation {}
 
 
 impl KeyboardButtonTypeRequestLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDKeyboardButtonTypeRequestLocationBuilder {
-    let mut inner = KeyboardButtonTypeRequestLocation::default();
-    inner.td_name = "keyboardButtonTypeRequestLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = KeyboardButtonTypeRequestLocation { td_name: "keyboardButtonTypeRequestLocation".to_string(), ..Default::default() };
     RTDKeyboardButtonTypeRequestLocationBuilder { inner }
   }
 
+  /// `KeyboardButtonTypeRequestLocation` with the fields tdlib requires, the others are set by `KeyboardButtonTypeRequestLocation::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +177,12 @@ pub struct RTDKeyboardButtonTypeRequestLocationBuilder {
 impl RTDKeyboardButtonTypeRequestLocationBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<KeyboardButtonTypeRequestLocation> for KeyboardButtonTypeRequestLocation {
@@ -165,6 +193,10 @@ impl AsRef<KeyboardButtonTypeRequestLocation> for RTDKeyboardButtonTypeRequestLo
   fn as_ref(&self) -> &KeyboardButtonTypeRequestLocation { &self.inner }
 }
 
+impl PartialEq for KeyboardButtonTypeRequestLocation {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -179,13 +211,17 @@ pub struct KeyboardButtonTypeRequestPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for KeyboardButtonTypeRequestPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeRequestPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "keyboardButtonTypeRequestPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -195,14 +231,15 @@ impl TDKeyboardButtonType for KeyboardButtonTypeRequestPhoneNumber {}
 
 
 impl KeyboardButtonTypeRequestPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
-    let mut inner = KeyboardButtonTypeRequestPhoneNumber::default();
-    inner.td_name = "keyboardButtonTypeRequestPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = KeyboardButtonTypeRequestPhoneNumber { td_name: "keyboardButtonTypeRequestPhoneNumber".to_string(), ..Default::default() };
     RTDKeyboardButtonTypeRequestPhoneNumberBuilder { inner }
   }
 
+  /// `KeyboardButtonTypeRequestPhoneNumber` with the fields tdlib requires, the others are set by `KeyboardButtonTypeRequestPhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +250,12 @@ pub struct RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
 impl RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<KeyboardButtonTypeRequestPhoneNumber> for KeyboardButtonTypeRequestPhoneNumber {
@@ -223,6 +266,10 @@ impl AsRef<KeyboardButtonTypeRequestPhoneNumber> for RTDKeyboardButtonTypeReques
   fn as_ref(&self) -> &KeyboardButtonTypeRequestPhoneNumber { &self.inner }
 }
 
+impl PartialEq for KeyboardButtonTypeRequestPhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -237,7 +284,10 @@ pub struct KeyboardButtonTypeRequestPoll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If true, only regular polls must be allowed to create
   force_regular: bool,
   /// If true, only polls in quiz mode must be allowed to create
@@ -246,8 +296,9 @@ pub struct KeyboardButtonTypeRequestPoll {
 }
 
 impl RObject for KeyboardButtonTypeRequestPoll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeRequestPoll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "keyboardButtonTypeRequestPoll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -257,14 +308,15 @@ impl TDKeyboardButtonType for KeyboardButtonTypeRequestPoll {}
 
 
 impl KeyboardButtonTypeRequestPoll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDKeyboardButtonTypeRequestPollBuilder {
-    let mut inner = KeyboardButtonTypeRequestPoll::default();
-    inner.td_name = "keyboardButtonTypeRequestPoll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = KeyboardButtonTypeRequestPoll { td_name: "keyboardButtonTypeRequestPoll".to_string(), ..Default::default() };
     RTDKeyboardButtonTypeRequestPollBuilder { inner }
   }
 
+  /// `KeyboardButtonTypeRequestPoll` with the fields tdlib requires, the others are set by `KeyboardButtonTypeRequestPoll::builder()`
+  pub fn new(force_regular: bool, force_quiz: bool) -> Self { Self::builder().force_regular(force_regular).force_quiz(force_quiz).build() }
+
   pub fn force_regular(&self) -> bool { self.force_regular }
 
   pub fn force_quiz(&self) -> bool { self.force_quiz }
@@ -279,6 +331,12 @@ pub struct RTDKeyboardButtonTypeRequestPollBuilder {
 impl RTDKeyboardButtonTypeRequestPollBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestPoll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn force_regular(&mut self, force_regular: bool) -> &mut Self {
     self.inner.force_regular = force_regular;
@@ -301,6 +359,13 @@ impl AsRef<KeyboardButtonTypeRequestPoll> for RTDKeyboardButtonTypeRequestPollBu
   fn as_ref(&self) -> &KeyboardButtonTypeRequestPoll { &self.inner }
 }
 
+impl PartialEq for KeyboardButtonTypeRequestPoll {
+  fn eq(&self, other: &Self) -> bool {
+    self.force_regular == other.force_regular
+      && self.force_quiz == other.force_quiz
+  }
+}
+
 
 
 
@@ -315,13 +380,17 @@ pub struct KeyboardButtonTypeText {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for KeyboardButtonTypeText {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "keyboardButtonTypeText" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "keyboardButtonTypeText" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -331,14 +400,15 @@ impl TDKeyboardButtonType for KeyboardButtonTypeText {}
 
 
 impl KeyboardButtonTypeText {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDKeyboardButtonTypeTextBuilder {
-    let mut inner = KeyboardButtonTypeText::default();
-    inner.td_name = "keyboardButtonTypeText".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = KeyboardButtonTypeText { td_name: "keyboardButtonTypeText".to_string(), ..Default::default() };
     RTDKeyboardButtonTypeTextBuilder { inner }
   }
 
+  /// `KeyboardButtonTypeText` with the fields tdlib requires, the others are set by `KeyboardButtonTypeText::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -349,6 +419,12 @@ pub struct RTDKeyboardButtonTypeTextBuilder {
 impl RTDKeyboardButtonTypeTextBuilder {
   pub fn build(&self) -> KeyboardButtonTypeText { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<KeyboardButtonTypeText> for KeyboardButtonTypeText {
@@ -359,5 +435,9 @@ impl AsRef<KeyboardButtonTypeText> for RTDKeyboardButtonTypeTextBuilder {
   fn as_ref(&self) -> &KeyboardButtonTypeText { &self.inner }
 }
 
+impl PartialEq for KeyboardButtonTypeText {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
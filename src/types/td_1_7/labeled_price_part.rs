@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct LabeledPricePart {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Label for this portion of the product price
   label: String,
   /// Currency amount in the smallest units of the currency
@@ -23,22 +25,24 @@ pub struct LabeledPricePart {
 }
 
 impl RObject for LabeledPricePart {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "labeledPricePart" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "labeledPricePart" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LabeledPricePart {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLabeledPricePartBuilder {
-    let mut inner = LabeledPricePart::default();
-    inner.td_name = "labeledPricePart".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LabeledPricePart { td_name: "labeledPricePart".to_string(), ..Default::default() };
     RTDLabeledPricePartBuilder { inner }
   }
 
+  /// `LabeledPricePart` with the fields tdlib requires, the others are set by `LabeledPricePart::builder()`
+  pub fn new(label: impl AsRef<str>, amount: i64) -> Self { Self::builder().label(label).amount(amount).build() }
+
   pub fn label(&self) -> &String { &self.label }
 
   pub fn amount(&self) -> i64 { self.amount }
@@ -53,6 +57,12 @@ pub struct RTDLabeledPricePartBuilder {
 impl RTDLabeledPricePartBuilder {
   pub fn build(&self) -> LabeledPricePart { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn label<T: AsRef<str>>(&mut self, label: T) -> &mut Self {
     self.inner.label = label.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<LabeledPricePart> for RTDLabeledPricePartBuilder {
   fn as_ref(&self) -> &LabeledPricePart { &self.inner }
 }
 
+impl PartialEq for LabeledPricePart {
+  fn eq(&self, other: &Self) -> bool {
+    self.label == other.label
+      && self.amount == other.amount
+  }
+}
+
 
 
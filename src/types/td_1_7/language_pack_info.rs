@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct LanguagePackInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique language pack identifier
   id: String,
   /// Identifier of a base language pack; may be empty. If a string is missed in the language pack, then it must be fetched from base language pack. Unsupported in custom language packs
@@ -45,22 +47,55 @@ pub struct LanguagePackInfo {
 }
 
 impl RObject for LanguagePackInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "languagePackInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LanguagePackInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLanguagePackInfoBuilder {
-    let mut inner = LanguagePackInfo::default();
-    inner.td_name = "languagePackInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LanguagePackInfo { td_name: "languagePackInfo".to_string(), ..Default::default() };
     RTDLanguagePackInfoBuilder { inner }
   }
 
+  /// `LanguagePackInfo` with the fields tdlib requires, the others are set by `LanguagePackInfo::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: impl AsRef<str>,
+    base_language_pack_id: impl AsRef<str>,
+    name: impl AsRef<str>,
+    native_name: impl AsRef<str>,
+    plural_code: impl AsRef<str>,
+    is_official: bool,
+    is_rtl: bool,
+    is_beta: bool,
+    is_installed: bool,
+    total_string_count: i64,
+    translated_string_count: i64,
+    local_string_count: i64,
+    translation_url: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .base_language_pack_id(base_language_pack_id)
+      .name(name)
+      .native_name(native_name)
+      .plural_code(plural_code)
+      .is_official(is_official)
+      .is_rtl(is_rtl)
+      .is_beta(is_beta)
+      .is_installed(is_installed)
+      .total_string_count(total_string_count)
+      .translated_string_count(translated_string_count)
+      .local_string_count(local_string_count)
+      .translation_url(translation_url)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn base_language_pack_id(&self) -> &String { &self.base_language_pack_id }
@@ -97,6 +132,12 @@ pub struct RTDLanguagePackInfoBuilder {
 impl RTDLanguagePackInfoBuilder {
   pub fn build(&self) -> LanguagePackInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -185,5 +226,23 @@ impl AsRef<LanguagePackInfo> for RTDLanguagePackInfoBuilder {
   fn as_ref(&self) -> &LanguagePackInfo { &self.inner }
 }
 
+impl PartialEq for LanguagePackInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.base_language_pack_id == other.base_language_pack_id
+      && self.name == other.name
+      && self.native_name == other.native_name
+      && self.plural_code == other.plural_code
+      && self.is_official == other.is_official
+      && self.is_rtl == other.is_rtl
+      && self.is_beta == other.is_beta
+      && self.is_installed == other.is_installed
+      && self.total_string_count == other.total_string_count
+      && self.translated_string_count == other.translated_string_count
+      && self.local_string_count == other.local_string_count
+      && self.translation_url == other.translation_url
+  }
+}
+
 
 
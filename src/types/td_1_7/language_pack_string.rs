@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct LanguagePackString {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// String key
   key: String,
   /// String value; pass null if the string needs to be taken from the built-in English language pack
@@ -23,22 +25,24 @@ pub struct LanguagePackString {
 }
 
 impl RObject for LanguagePackString {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackString" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "languagePackString" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LanguagePackString {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLanguagePackStringBuilder {
-    let mut inner = LanguagePackString::default();
-    inner.td_name = "languagePackString".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LanguagePackString { td_name: "languagePackString".to_string(), ..Default::default() };
     RTDLanguagePackStringBuilder { inner }
   }
 
+  /// `LanguagePackString` with the fields tdlib requires, the others are set by `LanguagePackString::builder()`
+  pub fn new(key: impl AsRef<str>) -> Self { Self::builder().key(key).build() }
+
   pub fn key(&self) -> &String { &self.key }
 
   pub fn value(&self) -> &LanguagePackStringValue { &self.value }
@@ -53,6 +57,12 @@ pub struct RTDLanguagePackStringBuilder {
 impl RTDLanguagePackStringBuilder {
   pub fn build(&self) -> LanguagePackString { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
     self.inner.key = key.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<LanguagePackString> for RTDLanguagePackStringBuilder {
   fn as_ref(&self) -> &LanguagePackString { &self.inner }
 }
 
+impl PartialEq for LanguagePackString {
+  fn eq(&self, other: &Self) -> bool {
+    self.key == other.key
+      && self.value == other.value
+  }
+}
+
 
 
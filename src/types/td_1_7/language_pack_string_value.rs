@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDLanguagePackStringValue: Debug + RObject {}
 
 /// Represents the value of a string in a language pack
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum LanguagePackStringValue {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum LanguagePackStringValue {
   Ordinary(LanguagePackStringValueOrdinary),
   /// A language pack string which has different forms based on the number of some object it mentions. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info
   Pluralized(LanguagePackStringValuePluralized),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for LanguagePackStringValue {
   fn deserialize<D>(deserializer: D) -> Result<LanguagePackStringValue, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      LanguagePackStringValue,
+      LanguagePackStringValue, Unknown;
       (getLanguagePackString, GetLanguagePackString);
       (languagePackStringValueDeleted, Deleted);
       (languagePackStringValueOrdinary, Ordinary);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for LanguagePackStringValue {
 }
 
 impl RObject for LanguagePackStringValue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       LanguagePackStringValue::GetLanguagePackString(t) => t.td_name(),
       LanguagePackStringValue::Deleted(t) => t.td_name(),
       LanguagePackStringValue::Ordinary(t) => t.td_name(),
       LanguagePackStringValue::Pluralized(t) => t.td_name(),
+      LanguagePackStringValue::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       LanguagePackStringValue::GetLanguagePackString(t) => t.extra(),
       LanguagePackStringValue::Deleted(t) => t.extra(),
       LanguagePackStringValue::Ordinary(t) => t.extra(),
       LanguagePackStringValue::Pluralized(t) => t.extra(),
+      LanguagePackStringValue::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      LanguagePackStringValue::GetLanguagePackString(t) => t.client_id(),
+      LanguagePackStringValue::Deleted(t) => t.client_id(),
+      LanguagePackStringValue::Ordinary(t) => t.client_id(),
+      LanguagePackStringValue::Pluralized(t) => t.client_id(),
+      LanguagePackStringValue::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for LanguagePackStringValue {
 }
 
 impl LanguagePackStringValue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LanguagePackStringValue::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, LanguagePackStringValue::_Default(_)) }
 
-  pub fn is_get_language_pack_string(&self) -> bool { if let LanguagePackStringValue::GetLanguagePackString(_) = self { true } else { false } }
-  pub fn is_deleted(&self) -> bool { if let LanguagePackStringValue::Deleted(_) = self { true } else { false } }
-  pub fn is_ordinary(&self) -> bool { if let LanguagePackStringValue::Ordinary(_) = self { true } else { false } }
-  pub fn is_pluralized(&self) -> bool { if let LanguagePackStringValue::Pluralized(_) = self { true } else { false } }
+  pub fn is_get_language_pack_string(&self) -> bool { matches!(self, LanguagePackStringValue::GetLanguagePackString(_)) }
+  pub fn is_deleted(&self) -> bool { matches!(self, LanguagePackStringValue::Deleted(_)) }
+  pub fn is_ordinary(&self) -> bool { matches!(self, LanguagePackStringValue::Ordinary(_)) }
+  pub fn is_pluralized(&self) -> bool { matches!(self, LanguagePackStringValue::Pluralized(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, LanguagePackStringValue::Unknown(_)) }
 
   pub fn on_get_language_pack_string<F: FnOnce(&GetLanguagePackString)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::GetLanguagePackString(t) = self { fnc(t) }; self }
   pub fn on_deleted<F: FnOnce(&LanguagePackStringValueDeleted)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Deleted(t) = self { fnc(t) }; self }
   pub fn on_ordinary<F: FnOnce(&LanguagePackStringValueOrdinary)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Ordinary(t) = self { fnc(t) }; self }
   pub fn on_pluralized<F: FnOnce(&LanguagePackStringValuePluralized)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Pluralized(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_language_pack_string(&self) -> Option<&GetLanguagePackString> { if let LanguagePackStringValue::GetLanguagePackString(t) = self { return Some(t) } None }
   pub fn as_deleted(&self) -> Option<&LanguagePackStringValueDeleted> { if let LanguagePackStringValue::Deleted(t) = self { return Some(t) } None }
   pub fn as_ordinary(&self) -> Option<&LanguagePackStringValueOrdinary> { if let LanguagePackStringValue::Ordinary(t) = self { return Some(t) } None }
   pub fn as_pluralized(&self) -> Option<&LanguagePackStringValuePluralized> { if let LanguagePackStringValue::Pluralized(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let LanguagePackStringValue::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,13 +138,17 @@ pub struct LanguagePackStringValueDeleted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for LanguagePackStringValueDeleted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStringValueDeleted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "languagePackStringValueDeleted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,14 +158,15 @@ impl TDLanguagePackStringValue for LanguagePackStringValueDeleted {}
 
 
 impl LanguagePackStringValueDeleted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLanguagePackStringValueDeletedBuilder {
-    let mut inner = LanguagePackStringValueDeleted::default();
-    inner.td_name = "languagePackStringValueDeleted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LanguagePackStringValueDeleted { td_name: "languagePackStringValueDeleted".to_string(), ..Default::default() };
     RTDLanguagePackStringValueDeletedBuilder { inner }
   }
 
+  /// `LanguagePackStringValueDeleted` with the fields tdlib requires, the others are set by `LanguagePackStringValueDeleted::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +177,12 @@ pub struct RTDLanguagePackStringValueDeletedBuilder {
 impl RTDLanguagePackStringValueDeletedBuilder {
   pub fn build(&self) -> LanguagePackStringValueDeleted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<LanguagePackStringValueDeleted> for LanguagePackStringValueDeleted {
@@ -165,6 +193,10 @@ impl AsRef<LanguagePackStringValueDeleted> for RTDLanguagePackStringValueDeleted
   fn as_ref(&self) -> &LanguagePackStringValueDeleted { &self.inner }
 }
 
+impl PartialEq for LanguagePackStringValueDeleted {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -179,15 +211,19 @@ pub struct LanguagePackStringValueOrdinary {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// String value
   value: String,
   
 }
 
 impl RObject for LanguagePackStringValueOrdinary {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStringValueOrdinary" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "languagePackStringValueOrdinary" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -197,14 +233,15 @@ impl TDLanguagePackStringValue for LanguagePackStringValueOrdinary {}
 
 
 impl LanguagePackStringValueOrdinary {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLanguagePackStringValueOrdinaryBuilder {
-    let mut inner = LanguagePackStringValueOrdinary::default();
-    inner.td_name = "languagePackStringValueOrdinary".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LanguagePackStringValueOrdinary { td_name: "languagePackStringValueOrdinary".to_string(), ..Default::default() };
     RTDLanguagePackStringValueOrdinaryBuilder { inner }
   }
 
+  /// `LanguagePackStringValueOrdinary` with the fields tdlib requires, the others are set by `LanguagePackStringValueOrdinary::builder()`
+  pub fn new(value: impl AsRef<str>) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> &String { &self.value }
 
 }
@@ -217,6 +254,12 @@ pub struct RTDLanguagePackStringValueOrdinaryBuilder {
 impl RTDLanguagePackStringValueOrdinaryBuilder {
   pub fn build(&self) -> LanguagePackStringValueOrdinary { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
@@ -233,6 +276,12 @@ impl AsRef<LanguagePackStringValueOrdinary> for RTDLanguagePackStringValueOrdina
   fn as_ref(&self) -> &LanguagePackStringValueOrdinary { &self.inner }
 }
 
+impl PartialEq for LanguagePackStringValueOrdinary {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
 
@@ -247,7 +296,10 @@ pub struct LanguagePackStringValuePluralized {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Value for zero objects
   zero_value: String,
   /// Value for one object
@@ -264,8 +316,9 @@ pub struct LanguagePackStringValuePluralized {
 }
 
 impl RObject for LanguagePackStringValuePluralized {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStringValuePluralized" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "languagePackStringValuePluralized" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -275,14 +328,31 @@ impl TDLanguagePackStringValue for LanguagePackStringValuePluralized {}
 
 
 impl LanguagePackStringValuePluralized {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLanguagePackStringValuePluralizedBuilder {
-    let mut inner = LanguagePackStringValuePluralized::default();
-    inner.td_name = "languagePackStringValuePluralized".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LanguagePackStringValuePluralized { td_name: "languagePackStringValuePluralized".to_string(), ..Default::default() };
     RTDLanguagePackStringValuePluralizedBuilder { inner }
   }
 
+  /// `LanguagePackStringValuePluralized` with the fields tdlib requires, the others are set by `LanguagePackStringValuePluralized::builder()`
+  pub fn new(
+    zero_value: impl AsRef<str>,
+    one_value: impl AsRef<str>,
+    two_value: impl AsRef<str>,
+    few_value: impl AsRef<str>,
+    many_value: impl AsRef<str>,
+    other_value: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .zero_value(zero_value)
+      .one_value(one_value)
+      .two_value(two_value)
+      .few_value(few_value)
+      .many_value(many_value)
+      .other_value(other_value)
+      .build()
+  }
+
   pub fn zero_value(&self) -> &String { &self.zero_value }
 
   pub fn one_value(&self) -> &String { &self.one_value }
@@ -305,6 +375,12 @@ pub struct RTDLanguagePackStringValuePluralizedBuilder {
 impl RTDLanguagePackStringValuePluralizedBuilder {
   pub fn build(&self) -> LanguagePackStringValuePluralized { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn zero_value<T: AsRef<str>>(&mut self, zero_value: T) -> &mut Self {
     self.inner.zero_value = zero_value.as_ref().to_string();
@@ -351,5 +427,16 @@ impl AsRef<LanguagePackStringValuePluralized> for RTDLanguagePackStringValuePlur
   fn as_ref(&self) -> &LanguagePackStringValuePluralized { &self.inner }
 }
 
+impl PartialEq for LanguagePackStringValuePluralized {
+  fn eq(&self, other: &Self) -> bool {
+    self.zero_value == other.zero_value
+      && self.one_value == other.one_value
+      && self.two_value == other.two_value
+      && self.few_value == other.few_value
+      && self.many_value == other.many_value
+      && self.other_value == other.other_value
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct LanguagePackStrings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of language pack strings
   strings: Vec<LanguagePackString>,
   
 }
 
 impl RObject for LanguagePackStrings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "languagePackStrings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "languagePackStrings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LanguagePackStrings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLanguagePackStringsBuilder {
-    let mut inner = LanguagePackStrings::default();
-    inner.td_name = "languagePackStrings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LanguagePackStrings { td_name: "languagePackStrings".to_string(), ..Default::default() };
     RTDLanguagePackStringsBuilder { inner }
   }
 
+  /// `LanguagePackStrings` with the fields tdlib requires, the others are set by `LanguagePackStrings::builder()`
+  pub fn new(strings: Vec<LanguagePackString>) -> Self { Self::builder().strings(strings).build() }
+
   pub fn strings(&self) -> &Vec<LanguagePackString> { &self.strings }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDLanguagePackStringsBuilder {
 impl RTDLanguagePackStringsBuilder {
   pub fn build(&self) -> LanguagePackStrings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn strings(&mut self, strings: Vec<LanguagePackString>) -> &mut Self {
     self.inner.strings = strings;
@@ -65,5 +75,11 @@ impl AsRef<LanguagePackStrings> for RTDLanguagePackStringsBuilder {
   fn as_ref(&self) -> &LanguagePackStrings { &self.inner }
 }
 
+impl PartialEq for LanguagePackStrings {
+  fn eq(&self, other: &Self) -> bool {
+    self.strings == other.strings
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct LocalFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Local path to the locally available file part; may be empty
   path: String,
   /// True, if it is possible to try to download or generate the file
@@ -35,22 +37,45 @@ pub struct LocalFile {
 }
 
 impl RObject for LocalFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "localFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "localFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LocalFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLocalFileBuilder {
-    let mut inner = LocalFile::default();
-    inner.td_name = "localFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LocalFile { td_name: "localFile".to_string(), ..Default::default() };
     RTDLocalFileBuilder { inner }
   }
 
+  /// `LocalFile` with the fields tdlib requires, the others are set by `LocalFile::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    path: impl AsRef<str>,
+    can_be_downloaded: bool,
+    can_be_deleted: bool,
+    is_downloading_active: bool,
+    is_downloading_completed: bool,
+    download_offset: i64,
+    downloaded_prefix_size: i64,
+    downloaded_size: i64,
+  ) -> Self {
+    Self::builder()
+      .path(path)
+      .can_be_downloaded(can_be_downloaded)
+      .can_be_deleted(can_be_deleted)
+      .is_downloading_active(is_downloading_active)
+      .is_downloading_completed(is_downloading_completed)
+      .download_offset(download_offset)
+      .downloaded_prefix_size(downloaded_prefix_size)
+      .downloaded_size(downloaded_size)
+      .build()
+  }
+
   pub fn path(&self) -> &String { &self.path }
 
   pub fn can_be_downloaded(&self) -> bool { self.can_be_downloaded }
@@ -77,6 +102,12 @@ pub struct RTDLocalFileBuilder {
 impl RTDLocalFileBuilder {
   pub fn build(&self) -> LocalFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
@@ -135,5 +166,18 @@ impl AsRef<LocalFile> for RTDLocalFileBuilder {
   fn as_ref(&self) -> &LocalFile { &self.inner }
 }
 
+impl PartialEq for LocalFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.path == other.path
+      && self.can_be_downloaded == other.can_be_downloaded
+      && self.can_be_deleted == other.can_be_deleted
+      && self.is_downloading_active == other.is_downloading_active
+      && self.is_downloading_completed == other.is_downloading_completed
+      && self.download_offset == other.download_offset
+      && self.downloaded_prefix_size == other.downloaded_prefix_size
+      && self.downloaded_size == other.downloaded_size
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct LocalizationTargetInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of available language packs for this application
   language_packs: Vec<LanguagePackInfo>,
   
 }
 
 impl RObject for LocalizationTargetInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "localizationTargetInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "localizationTargetInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LocalizationTargetInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLocalizationTargetInfoBuilder {
-    let mut inner = LocalizationTargetInfo::default();
-    inner.td_name = "localizationTargetInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LocalizationTargetInfo { td_name: "localizationTargetInfo".to_string(), ..Default::default() };
     RTDLocalizationTargetInfoBuilder { inner }
   }
 
+  /// `LocalizationTargetInfo` with the fields tdlib requires, the others are set by `LocalizationTargetInfo::builder()`
+  pub fn new(language_packs: Vec<LanguagePackInfo>) -> Self { Self::builder().language_packs(language_packs).build() }
+
   pub fn language_packs(&self) -> &Vec<LanguagePackInfo> { &self.language_packs }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDLocalizationTargetInfoBuilder {
 impl RTDLocalizationTargetInfoBuilder {
   pub fn build(&self) -> LocalizationTargetInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language_packs(&mut self, language_packs: Vec<LanguagePackInfo>) -> &mut Self {
     self.inner.language_packs = language_packs;
@@ -65,5 +75,11 @@ impl AsRef<LocalizationTargetInfo> for RTDLocalizationTargetInfoBuilder {
   fn as_ref(&self) -> &LocalizationTargetInfo { &self.inner }
 }
 
+impl PartialEq for LocalizationTargetInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.language_packs == other.language_packs
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Location {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Latitude of the location in degrees; as defined by the sender
   latitude: f32,
   /// Longitude of the location, in degrees; as defined by the sender
@@ -25,22 +27,24 @@ pub struct Location {
 }
 
 impl RObject for Location {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "location" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "location" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Location {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLocationBuilder {
-    let mut inner = Location::default();
-    inner.td_name = "location".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Location { td_name: "location".to_string(), ..Default::default() };
     RTDLocationBuilder { inner }
   }
 
+  /// `Location` with the fields tdlib requires, the others are set by `Location::builder()`
+  pub fn new(latitude: f32, longitude: f32, horizontal_accuracy: f32) -> Self { Self::builder().latitude(latitude).longitude(longitude).horizontal_accuracy(horizontal_accuracy).build() }
+
   pub fn latitude(&self) -> f32 { self.latitude }
 
   pub fn longitude(&self) -> f32 { self.longitude }
@@ -57,6 +61,12 @@ pub struct RTDLocationBuilder {
 impl RTDLocationBuilder {
   pub fn build(&self) -> Location { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn latitude(&mut self, latitude: f32) -> &mut Self {
     self.inner.latitude = latitude;
@@ -85,5 +95,13 @@ impl AsRef<Location> for RTDLocationBuilder {
   fn as_ref(&self) -> &Location { &self.inner }
 }
 
+impl PartialEq for Location {
+  fn eq(&self, other: &Self) -> bool {
+    self.latitude == other.latitude
+      && self.longitude == other.longitude
+      && self.horizontal_accuracy == other.horizontal_accuracy
+  }
+}
+
 
 
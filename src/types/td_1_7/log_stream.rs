@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDLogStream: Debug + RObject {}
 
 /// Describes a stream to which TDLib internal log is written
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum LogStream {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum LogStream {
   Empty(LogStreamEmpty),
   /// The log is written to a file
   File(LogStreamFile),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for LogStream {
   fn deserialize<D>(deserializer: D) -> Result<LogStream, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      LogStream,
+      LogStream, Unknown;
       (getLogStream, GetLogStream);
       (logStreamDefault, Default);
       (logStreamEmpty, Empty);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for LogStream {
 }
 
 impl RObject for LogStream {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       LogStream::GetLogStream(t) => t.td_name(),
       LogStream::Default(t) => t.td_name(),
       LogStream::Empty(t) => t.td_name(),
       LogStream::File(t) => t.td_name(),
+      LogStream::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       LogStream::GetLogStream(t) => t.extra(),
       LogStream::Default(t) => t.extra(),
       LogStream::Empty(t) => t.extra(),
       LogStream::File(t) => t.extra(),
+      LogStream::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      LogStream::GetLogStream(t) => t.client_id(),
+      LogStream::Default(t) => t.client_id(),
+      LogStream::Empty(t) => t.client_id(),
+      LogStream::File(t) => t.client_id(),
+      LogStream::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for LogStream {
 }
 
 impl LogStream {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LogStream::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, LogStream::_Default(_)) }
 
-  pub fn is_get_log_stream(&self) -> bool { if let LogStream::GetLogStream(_) = self { true } else { false } }
-  pub fn is_default(&self) -> bool { if let LogStream::Default(_) = self { true } else { false } }
-  pub fn is_empty(&self) -> bool { if let LogStream::Empty(_) = self { true } else { false } }
-  pub fn is_file(&self) -> bool { if let LogStream::File(_) = self { true } else { false } }
+  pub fn is_get_log_stream(&self) -> bool { matches!(self, LogStream::GetLogStream(_)) }
+  pub fn is_default(&self) -> bool { matches!(self, LogStream::Default(_)) }
+  pub fn is_empty(&self) -> bool { matches!(self, LogStream::Empty(_)) }
+  pub fn is_file(&self) -> bool { matches!(self, LogStream::File(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, LogStream::Unknown(_)) }
 
   pub fn on_get_log_stream<F: FnOnce(&GetLogStream)>(&self, fnc: F) -> &Self { if let LogStream::GetLogStream(t) = self { fnc(t) }; self }
   pub fn on_default<F: FnOnce(&LogStreamDefault)>(&self, fnc: F) -> &Self { if let LogStream::Default(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&LogStreamEmpty)>(&self, fnc: F) -> &Self { if let LogStream::Empty(t) = self { fnc(t) }; self }
   pub fn on_file<F: FnOnce(&LogStreamFile)>(&self, fnc: F) -> &Self { if let LogStream::File(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let LogStream::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_log_stream(&self) -> Option<&GetLogStream> { if let LogStream::GetLogStream(t) = self { return Some(t) } None }
   pub fn as_default(&self) -> Option<&LogStreamDefault> { if let LogStream::Default(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&LogStreamEmpty> { if let LogStream::Empty(t) = self { return Some(t) } None }
   pub fn as_file(&self) -> Option<&LogStreamFile> { if let LogStream::File(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let LogStream::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,13 +138,17 @@ pub struct LogStreamDefault {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for LogStreamDefault {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "logStreamDefault" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "logStreamDefault" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,14 +158,15 @@ impl TDLogStream for LogStreamDefault {}
 
 
 impl LogStreamDefault {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLogStreamDefaultBuilder {
-    let mut inner = LogStreamDefault::default();
-    inner.td_name = "logStreamDefault".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LogStreamDefault { td_name: "logStreamDefault".to_string(), ..Default::default() };
     RTDLogStreamDefaultBuilder { inner }
   }
 
+  /// `LogStreamDefault` with the fields tdlib requires, the others are set by `LogStreamDefault::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +177,12 @@ pub struct RTDLogStreamDefaultBuilder {
 impl RTDLogStreamDefaultBuilder {
   pub fn build(&self) -> LogStreamDefault { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<LogStreamDefault> for LogStreamDefault {
@@ -165,6 +193,10 @@ impl AsRef<LogStreamDefault> for RTDLogStreamDefaultBuilder {
   fn as_ref(&self) -> &LogStreamDefault { &self.inner }
 }
 
+impl PartialEq for LogStreamDefault {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -179,13 +211,17 @@ pub struct LogStreamEmpty {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for LogStreamEmpty {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "logStreamEmpty" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "logStreamEmpty" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -195,14 +231,15 @@ impl TDLogStream for LogStreamEmpty {}
 
 
 impl LogStreamEmpty {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLogStreamEmptyBuilder {
-    let mut inner = LogStreamEmpty::default();
-    inner.td_name = "logStreamEmpty".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LogStreamEmpty { td_name: "logStreamEmpty".to_string(), ..Default::default() };
     RTDLogStreamEmptyBuilder { inner }
   }
 
+  /// `LogStreamEmpty` with the fields tdlib requires, the others are set by `LogStreamEmpty::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +250,12 @@ pub struct RTDLogStreamEmptyBuilder {
 impl RTDLogStreamEmptyBuilder {
   pub fn build(&self) -> LogStreamEmpty { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<LogStreamEmpty> for LogStreamEmpty {
@@ -223,6 +266,10 @@ impl AsRef<LogStreamEmpty> for RTDLogStreamEmptyBuilder {
   fn as_ref(&self) -> &LogStreamEmpty { &self.inner }
 }
 
+impl PartialEq for LogStreamEmpty {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -237,7 +284,10 @@ pub struct LogStreamFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Path to the file to where the internal TDLib log will be written
   path: String,
   /// The maximum size of the file to where the internal TDLib log is written before the file will be auto-rotated, in bytes
@@ -248,8 +298,9 @@ pub struct LogStreamFile {
 }
 
 impl RObject for LogStreamFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "logStreamFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "logStreamFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -259,14 +310,15 @@ impl TDLogStream for LogStreamFile {}
 
 
 impl LogStreamFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLogStreamFileBuilder {
-    let mut inner = LogStreamFile::default();
-    inner.td_name = "logStreamFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LogStreamFile { td_name: "logStreamFile".to_string(), ..Default::default() };
     RTDLogStreamFileBuilder { inner }
   }
 
+  /// `LogStreamFile` with the fields tdlib requires, the others are set by `LogStreamFile::builder()`
+  pub fn new(path: impl AsRef<str>, max_file_size: i64, redirect_stderr: bool) -> Self { Self::builder().path(path).max_file_size(max_file_size).redirect_stderr(redirect_stderr).build() }
+
   pub fn path(&self) -> &String { &self.path }
 
   pub fn max_file_size(&self) -> i64 { self.max_file_size }
@@ -283,6 +335,12 @@ pub struct RTDLogStreamFileBuilder {
 impl RTDLogStreamFileBuilder {
   pub fn build(&self) -> LogStreamFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
@@ -311,5 +369,13 @@ impl AsRef<LogStreamFile> for RTDLogStreamFileBuilder {
   fn as_ref(&self) -> &LogStreamFile { &self.inner }
 }
 
+impl PartialEq for LogStreamFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.path == other.path
+      && self.max_file_size == other.max_file_size
+      && self.redirect_stderr == other.redirect_stderr
+  }
+}
+
 
 
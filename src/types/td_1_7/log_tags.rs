@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct LogTags {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of log tags
   tags: Vec<String>,
   
 }
 
 impl RObject for LogTags {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "logTags" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "logTags" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LogTags {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLogTagsBuilder {
-    let mut inner = LogTags::default();
-    inner.td_name = "logTags".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LogTags { td_name: "logTags".to_string(), ..Default::default() };
     RTDLogTagsBuilder { inner }
   }
 
+  /// `LogTags` with the fields tdlib requires, the others are set by `LogTags::builder()`
+  pub fn new(tags: Vec<String>) -> Self { Self::builder().tags(tags).build() }
+
   pub fn tags(&self) -> &Vec<String> { &self.tags }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDLogTagsBuilder {
 impl RTDLogTagsBuilder {
   pub fn build(&self) -> LogTags { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn tags(&mut self, tags: Vec<String>) -> &mut Self {
     self.inner.tags = tags;
@@ -65,5 +75,11 @@ impl AsRef<LogTags> for RTDLogTagsBuilder {
   fn as_ref(&self) -> &LogTags { &self.inner }
 }
 
+impl PartialEq for LogTags {
+  fn eq(&self, other: &Self) -> bool {
+    self.tags == other.tags
+  }
+}
+
 
 
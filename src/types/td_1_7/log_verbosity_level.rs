@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct LogVerbosityLevel {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Log verbosity level
   verbosity_level: i64,
   
 }
 
 impl RObject for LogVerbosityLevel {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "logVerbosityLevel" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "logVerbosityLevel" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl LogVerbosityLevel {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLogVerbosityLevelBuilder {
-    let mut inner = LogVerbosityLevel::default();
-    inner.td_name = "logVerbosityLevel".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LogVerbosityLevel { td_name: "logVerbosityLevel".to_string(), ..Default::default() };
     RTDLogVerbosityLevelBuilder { inner }
   }
 
+  /// `LogVerbosityLevel` with the fields tdlib requires, the others are set by `LogVerbosityLevel::builder()`
+  pub fn new(verbosity_level: i64) -> Self { Self::builder().verbosity_level(verbosity_level).build() }
+
   pub fn verbosity_level(&self) -> i64 { self.verbosity_level }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDLogVerbosityLevelBuilder {
 impl RTDLogVerbosityLevelBuilder {
   pub fn build(&self) -> LogVerbosityLevel { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn verbosity_level(&mut self, verbosity_level: i64) -> &mut Self {
     self.inner.verbosity_level = verbosity_level;
@@ -65,5 +75,11 @@ impl AsRef<LogVerbosityLevel> for RTDLogVerbosityLevelBuilder {
   fn as_ref(&self) -> &LogVerbosityLevel { &self.inner }
 }
 
+impl PartialEq for LogVerbosityLevel {
+  fn eq(&self, other: &Self) -> bool {
+    self.verbosity_level == other.verbosity_level
+  }
+}
+
 
 
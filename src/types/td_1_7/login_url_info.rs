@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDLoginUrlInfo: Debug + RObject {}
 
 /// Contains information about an inline button of type inlineKeyboardButtonTypeLoginUrl
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum LoginUrlInfo {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum LoginUrlInfo {
   Open(LoginUrlInfoOpen),
   /// An authorization confirmation dialog needs to be shown to the user
   RequestConfirmation(LoginUrlInfoRequestConfirmation),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for LoginUrlInfo {
   fn deserialize<D>(deserializer: D) -> Result<LoginUrlInfo, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      LoginUrlInfo,
+      LoginUrlInfo, Unknown;
       (getExternalLinkInfo, GetExternalLinkInfo);
       (getLoginUrlInfo, GetLoginUrlInfo);
       (loginUrlInfoOpen, Open);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for LoginUrlInfo {
 }
 
 impl RObject for LoginUrlInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       LoginUrlInfo::GetExternalLinkInfo(t) => t.td_name(),
       LoginUrlInfo::GetLoginUrlInfo(t) => t.td_name(),
       LoginUrlInfo::Open(t) => t.td_name(),
       LoginUrlInfo::RequestConfirmation(t) => t.td_name(),
+      LoginUrlInfo::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       LoginUrlInfo::GetExternalLinkInfo(t) => t.extra(),
       LoginUrlInfo::GetLoginUrlInfo(t) => t.extra(),
       LoginUrlInfo::Open(t) => t.extra(),
       LoginUrlInfo::RequestConfirmation(t) => t.extra(),
+      LoginUrlInfo::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      LoginUrlInfo::GetExternalLinkInfo(t) => t.client_id(),
+      LoginUrlInfo::GetLoginUrlInfo(t) => t.client_id(),
+      LoginUrlInfo::Open(t) => t.client_id(),
+      LoginUrlInfo::RequestConfirmation(t) => t.client_id(),
+      LoginUrlInfo::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for LoginUrlInfo {
 }
 
 impl LoginUrlInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LoginUrlInfo::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, LoginUrlInfo::_Default(_)) }
 
-  pub fn is_get_external_link_info(&self) -> bool { if let LoginUrlInfo::GetExternalLinkInfo(_) = self { true } else { false } }
-  pub fn is_get_login_url_info(&self) -> bool { if let LoginUrlInfo::GetLoginUrlInfo(_) = self { true } else { false } }
-  pub fn is_open(&self) -> bool { if let LoginUrlInfo::Open(_) = self { true } else { false } }
-  pub fn is_request_confirmation(&self) -> bool { if let LoginUrlInfo::RequestConfirmation(_) = self { true } else { false } }
+  pub fn is_get_external_link_info(&self) -> bool { matches!(self, LoginUrlInfo::GetExternalLinkInfo(_)) }
+  pub fn is_get_login_url_info(&self) -> bool { matches!(self, LoginUrlInfo::GetLoginUrlInfo(_)) }
+  pub fn is_open(&self) -> bool { matches!(self, LoginUrlInfo::Open(_)) }
+  pub fn is_request_confirmation(&self) -> bool { matches!(self, LoginUrlInfo::RequestConfirmation(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, LoginUrlInfo::Unknown(_)) }
 
   pub fn on_get_external_link_info<F: FnOnce(&GetExternalLinkInfo)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::GetExternalLinkInfo(t) = self { fnc(t) }; self }
   pub fn on_get_login_url_info<F: FnOnce(&GetLoginUrlInfo)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::GetLoginUrlInfo(t) = self { fnc(t) }; self }
   pub fn on_open<F: FnOnce(&LoginUrlInfoOpen)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::Open(t) = self { fnc(t) }; self }
   pub fn on_request_confirmation<F: FnOnce(&LoginUrlInfoRequestConfirmation)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::RequestConfirmation(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_external_link_info(&self) -> Option<&GetExternalLinkInfo> { if let LoginUrlInfo::GetExternalLinkInfo(t) = self { return Some(t) } None }
   pub fn as_get_login_url_info(&self) -> Option<&GetLoginUrlInfo> { if let LoginUrlInfo::GetLoginUrlInfo(t) = self { return Some(t) } None }
   pub fn as_open(&self) -> Option<&LoginUrlInfoOpen> { if let LoginUrlInfo::Open(t) = self { return Some(t) } None }
   pub fn as_request_confirmation(&self) -> Option<&LoginUrlInfoRequestConfirmation> { if let LoginUrlInfo::RequestConfirmation(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let LoginUrlInfo::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,7 +138,10 @@ pub struct LoginUrlInfoOpen {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The URL to open
   url: String,
   /// True, if there is no need to show an ordinary open URL confirm
@@ -130,8 +150,9 @@ pub struct LoginUrlInfoOpen {
 }
 
 impl RObject for LoginUrlInfoOpen {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "loginUrlInfoOpen" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "loginUrlInfoOpen" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -141,14 +162,15 @@ impl TDLoginUrlInfo for LoginUrlInfoOpen {}
 
 
 impl LoginUrlInfoOpen {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLoginUrlInfoOpenBuilder {
-    let mut inner = LoginUrlInfoOpen::default();
-    inner.td_name = "loginUrlInfoOpen".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LoginUrlInfoOpen { td_name: "loginUrlInfoOpen".to_string(), ..Default::default() };
     RTDLoginUrlInfoOpenBuilder { inner }
   }
 
+  /// `LoginUrlInfoOpen` with the fields tdlib requires, the others are set by `LoginUrlInfoOpen::builder()`
+  pub fn new(url: impl AsRef<str>, skip_confirm: bool) -> Self { Self::builder().url(url).skip_confirm(skip_confirm).build() }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn skip_confirm(&self) -> bool { self.skip_confirm }
@@ -163,6 +185,12 @@ pub struct RTDLoginUrlInfoOpenBuilder {
 impl RTDLoginUrlInfoOpenBuilder {
   pub fn build(&self) -> LoginUrlInfoOpen { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -185,6 +213,13 @@ impl AsRef<LoginUrlInfoOpen> for RTDLoginUrlInfoOpenBuilder {
   fn as_ref(&self) -> &LoginUrlInfoOpen { &self.inner }
 }
 
+impl PartialEq for LoginUrlInfoOpen {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.skip_confirm == other.skip_confirm
+  }
+}
+
 
 
 
@@ -199,21 +234,25 @@ pub struct LoginUrlInfoRequestConfirmation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// An HTTP URL to be opened
   url: String,
   /// A domain of the URL
   domain: String,
   /// User identifier of a bot linked with the website
-  bot_user_id: i64,
+  bot_user_id: UserId,
   /// True, if the user needs to be requested to give the permission to the bot to send them messages
   request_write_access: bool,
   
 }
 
 impl RObject for LoginUrlInfoRequestConfirmation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "loginUrlInfoRequestConfirmation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "loginUrlInfoRequestConfirmation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -223,19 +262,32 @@ impl TDLoginUrlInfo for LoginUrlInfoRequestConfirmation {}
 
 
 impl LoginUrlInfoRequestConfirmation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDLoginUrlInfoRequestConfirmationBuilder {
-    let mut inner = LoginUrlInfoRequestConfirmation::default();
-    inner.td_name = "loginUrlInfoRequestConfirmation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = LoginUrlInfoRequestConfirmation { td_name: "loginUrlInfoRequestConfirmation".to_string(), ..Default::default() };
     RTDLoginUrlInfoRequestConfirmationBuilder { inner }
   }
 
+  /// `LoginUrlInfoRequestConfirmation` with the fields tdlib requires, the others are set by `LoginUrlInfoRequestConfirmation::builder()`
+  pub fn new(
+    url: impl AsRef<str>,
+    domain: impl AsRef<str>,
+    bot_user_id: UserId,
+    request_write_access: bool,
+  ) -> Self {
+    Self::builder()
+      .url(url)
+      .domain(domain)
+      .bot_user_id(bot_user_id)
+      .request_write_access(request_write_access)
+      .build()
+  }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn domain(&self) -> &String { &self.domain }
 
-  pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
+  pub fn bot_user_id(&self) -> UserId { self.bot_user_id }
 
   pub fn request_write_access(&self) -> bool { self.request_write_access }
 
@@ -249,6 +301,12 @@ pub struct RTDLoginUrlInfoRequestConfirmationBuilder {
 impl RTDLoginUrlInfoRequestConfirmationBuilder {
   pub fn build(&self) -> LoginUrlInfoRequestConfirmation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -262,7 +320,7 @@ impl RTDLoginUrlInfoRequestConfirmationBuilder {
   }
 
    
-  pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
+  pub fn bot_user_id(&mut self, bot_user_id: UserId) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
     self
   }
@@ -283,5 +341,14 @@ impl AsRef<LoginUrlInfoRequestConfirmation> for RTDLoginUrlInfoRequestConfirmati
   fn as_ref(&self) -> &LoginUrlInfoRequestConfirmation { &self.inner }
 }
 
+impl PartialEq for LoginUrlInfoRequestConfirmation {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.domain == other.domain
+      && self.bot_user_id == other.bot_user_id
+      && self.request_write_access == other.request_write_access
+  }
+}
+
 
 
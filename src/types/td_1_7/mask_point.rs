@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDMaskPoint: Debug + RObject {}
 
 /// Part of the face, relative to which a mask is placed
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MaskPoint {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum MaskPoint {
   Forehead(MaskPointForehead),
   /// The mask is placed relatively to the mouth
   Mouth(MaskPointMouth),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for MaskPoint {
   fn deserialize<D>(deserializer: D) -> Result<MaskPoint, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      MaskPoint,
+      MaskPoint, Unknown;
       (maskPointChin, Chin);
       (maskPointEyes, Eyes);
       (maskPointForehead, Forehead);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for MaskPoint {
 }
 
 impl RObject for MaskPoint {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       MaskPoint::Chin(t) => t.td_name(),
       MaskPoint::Eyes(t) => t.td_name(),
       MaskPoint::Forehead(t) => t.td_name(),
       MaskPoint::Mouth(t) => t.td_name(),
+      MaskPoint::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       MaskPoint::Chin(t) => t.extra(),
       MaskPoint::Eyes(t) => t.extra(),
       MaskPoint::Forehead(t) => t.extra(),
       MaskPoint::Mouth(t) => t.extra(),
+      MaskPoint::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      MaskPoint::Chin(t) => t.client_id(),
+      MaskPoint::Eyes(t) => t.client_id(),
+      MaskPoint::Forehead(t) => t.client_id(),
+      MaskPoint::Mouth(t) => t.client_id(),
+      MaskPoint::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for MaskPoint {
 }
 
 impl MaskPoint {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MaskPoint::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, MaskPoint::_Default(_)) }
 
-  pub fn is_chin(&self) -> bool { if let MaskPoint::Chin(_) = self { true } else { false } }
-  pub fn is_eyes(&self) -> bool { if let MaskPoint::Eyes(_) = self { true } else { false } }
-  pub fn is_forehead(&self) -> bool { if let MaskPoint::Forehead(_) = self { true } else { false } }
-  pub fn is_mouth(&self) -> bool { if let MaskPoint::Mouth(_) = self { true } else { false } }
+  pub fn is_chin(&self) -> bool { matches!(self, MaskPoint::Chin(_)) }
+  pub fn is_eyes(&self) -> bool { matches!(self, MaskPoint::Eyes(_)) }
+  pub fn is_forehead(&self) -> bool { matches!(self, MaskPoint::Forehead(_)) }
+  pub fn is_mouth(&self) -> bool { matches!(self, MaskPoint::Mouth(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, MaskPoint::Unknown(_)) }
 
   pub fn on_chin<F: FnOnce(&MaskPointChin)>(&self, fnc: F) -> &Self { if let MaskPoint::Chin(t) = self { fnc(t) }; self }
   pub fn on_eyes<F: FnOnce(&MaskPointEyes)>(&self, fnc: F) -> &Self { if let MaskPoint::Eyes(t) = self { fnc(t) }; self }
   pub fn on_forehead<F: FnOnce(&MaskPointForehead)>(&self, fnc: F) -> &Self { if let MaskPoint::Forehead(t) = self { fnc(t) }; self }
   pub fn on_mouth<F: FnOnce(&MaskPointMouth)>(&self, fnc: F) -> &Self { if let MaskPoint::Mouth(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let MaskPoint::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_chin(&self) -> Option<&MaskPointChin> { if let MaskPoint::Chin(t) = self { return Some(t) } None }
   pub fn as_eyes(&self) -> Option<&MaskPointEyes> { if let MaskPoint::Eyes(t) = self { return Some(t) } None }
   pub fn as_forehead(&self) -> Option<&MaskPointForehead> { if let MaskPoint::Forehead(t) = self { return Some(t) } None }
   pub fn as_mouth(&self) -> Option<&MaskPointMouth> { if let MaskPoint::Mouth(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let MaskPoint::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,13 +138,17 @@ pub struct MaskPointChin {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MaskPointChin {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointChin" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "maskPointChin" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,14 +158,15 @@ impl TDMaskPoint for MaskPointChin {}
 
 
 impl MaskPointChin {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMaskPointChinBuilder {
-    let mut inner = MaskPointChin::default();
-    inner.td_name = "maskPointChin".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MaskPointChin { td_name: "maskPointChin".to_string(), ..Default::default() };
     RTDMaskPointChinBuilder { inner }
   }
 
+  /// `MaskPointChin` with the fields tdlib requires, the others are set by `MaskPointChin::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +177,12 @@ pub struct RTDMaskPointChinBuilder {
 impl RTDMaskPointChinBuilder {
   pub fn build(&self) -> MaskPointChin { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointChin> for MaskPointChin {
@@ -165,6 +193,10 @@ impl AsRef<MaskPointChin> for RTDMaskPointChinBuilder {
   fn as_ref(&self) -> &MaskPointChin { &self.inner }
 }
 
+impl PartialEq for MaskPointChin {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -179,13 +211,17 @@ pub struct MaskPointEyes {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MaskPointEyes {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointEyes" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "maskPointEyes" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -195,14 +231,15 @@ impl TDMaskPoint for MaskPointEyes {}
 
 
 impl MaskPointEyes {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMaskPointEyesBuilder {
-    let mut inner = MaskPointEyes::default();
-    inner.td_name = "maskPointEyes".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MaskPointEyes { td_name: "maskPointEyes".to_string(), ..Default::default() };
     RTDMaskPointEyesBuilder { inner }
   }
 
+  /// `MaskPointEyes` with the fields tdlib requires, the others are set by `MaskPointEyes::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +250,12 @@ pub struct RTDMaskPointEyesBuilder {
 impl RTDMaskPointEyesBuilder {
   pub fn build(&self) -> MaskPointEyes { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointEyes> for MaskPointEyes {
@@ -223,6 +266,10 @@ impl AsRef<MaskPointEyes> for RTDMaskPointEyesBuilder {
   fn as_ref(&self) -> &MaskPointEyes { &self.inner }
 }
 
+impl PartialEq for MaskPointEyes {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -237,13 +284,17 @@ pub struct MaskPointForehead {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MaskPointForehead {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointForehead" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "maskPointForehead" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -253,14 +304,15 @@ impl TDMaskPoint for MaskPointForehead {}
 
 
 impl MaskPointForehead {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMaskPointForeheadBuilder {
-    let mut inner = MaskPointForehead::default();
-    inner.td_name = "maskPointForehead".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MaskPointForehead { td_name: "maskPointForehead".to_string(), ..Default::default() };
     RTDMaskPointForeheadBuilder { inner }
   }
 
+  /// `MaskPointForehead` with the fields tdlib requires, the others are set by `MaskPointForehead::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -271,6 +323,12 @@ pub struct RTDMaskPointForeheadBuilder {
 impl RTDMaskPointForeheadBuilder {
   pub fn build(&self) -> MaskPointForehead { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointForehead> for MaskPointForehead {
@@ -281,6 +339,10 @@ impl AsRef<MaskPointForehead> for RTDMaskPointForeheadBuilder {
   fn as_ref(&self) -> &MaskPointForehead { &self.inner }
 }
 
+impl PartialEq for MaskPointForehead {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -295,13 +357,17 @@ pub struct MaskPointMouth {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MaskPointMouth {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPointMouth" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "maskPointMouth" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -311,14 +377,15 @@ impl TDMaskPoint for MaskPointMouth {}
 
 
 impl MaskPointMouth {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMaskPointMouthBuilder {
-    let mut inner = MaskPointMouth::default();
-    inner.td_name = "maskPointMouth".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MaskPointMouth { td_name: "maskPointMouth".to_string(), ..Default::default() };
     RTDMaskPointMouthBuilder { inner }
   }
 
+  /// `MaskPointMouth` with the fields tdlib requires, the others are set by `MaskPointMouth::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -329,6 +396,12 @@ pub struct RTDMaskPointMouthBuilder {
 impl RTDMaskPointMouthBuilder {
   pub fn build(&self) -> MaskPointMouth { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MaskPointMouth> for MaskPointMouth {
@@ -339,5 +412,9 @@ impl AsRef<MaskPointMouth> for RTDMaskPointMouthBuilder {
   fn as_ref(&self) -> &MaskPointMouth { &self.inner }
 }
 
+impl PartialEq for MaskPointMouth {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
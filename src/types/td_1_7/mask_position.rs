@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MaskPosition {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Part of the face, relative to which the mask is placed
   point: MaskPoint,
   /// Shift by X-axis measured in widths of the mask scaled to the face size, from left to right. (For example, 1.0 will place the mask just to the left of the default mask position)
@@ -27,22 +29,36 @@ pub struct MaskPosition {
 }
 
 impl RObject for MaskPosition {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "maskPosition" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "maskPosition" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MaskPosition {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMaskPositionBuilder {
-    let mut inner = MaskPosition::default();
-    inner.td_name = "maskPosition".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MaskPosition { td_name: "maskPosition".to_string(), ..Default::default() };
     RTDMaskPositionBuilder { inner }
   }
 
+  /// `MaskPosition` with the fields tdlib requires, the others are set by `MaskPosition::builder()`
+  pub fn new(
+    point: impl AsRef<MaskPoint>,
+    x_shift: f32,
+    y_shift: f32,
+    scale: f32,
+  ) -> Self {
+    Self::builder()
+      .point(point)
+      .x_shift(x_shift)
+      .y_shift(y_shift)
+      .scale(scale)
+      .build()
+  }
+
   pub fn point(&self) -> &MaskPoint { &self.point }
 
   pub fn x_shift(&self) -> f32 { self.x_shift }
@@ -61,6 +77,12 @@ pub struct RTDMaskPositionBuilder {
 impl RTDMaskPositionBuilder {
   pub fn build(&self) -> MaskPosition { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn point<T: AsRef<MaskPoint>>(&mut self, point: T) -> &mut Self {
     self.inner.point = point.as_ref().clone();
@@ -95,5 +117,14 @@ impl AsRef<MaskPosition> for RTDMaskPositionBuilder {
   fn as_ref(&self) -> &MaskPosition { &self.inner }
 }
 
+impl PartialEq for MaskPosition {
+  fn eq(&self, other: &Self) -> bool {
+    self.point == other.point
+      && self.x_shift == other.x_shift
+      && self.y_shift == other.y_shift
+      && self.scale == other.scale
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,13 +13,16 @@ pub struct Message {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message identifier; unique for the chat to which the message belongs
-  id: i64,
+  id: MessageId,
   /// The sender of the message
   sender: MessageSender,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The sending state of the message; may be null
   sending_state: Option<MessageSendingState>,
   /// The scheduling state of the message; may be null
@@ -60,9 +62,9 @@ pub struct Message {
   /// Information about interactions with the message; may be null
   interaction_info: Option<MessageInteractionInfo>,
   /// If non-zero, the identifier of the chat to which the replied message belongs; Currently, only messages in the Replies chat can have different reply_in_chat_id and chat_id
-  reply_in_chat_id: i64,
+  reply_in_chat_id: ChatId,
   /// If non-zero, the identifier of the message this message is replying to; can be the identifier of a deleted message
-  reply_to_message_id: i64,
+  reply_to_message_id: MessageId,
   /// If non-zero, the identifier of the message thread the message belongs to; unique within the chat to which the message belongs
   message_thread_id: i64,
   /// For self-destructing messages, the message's TTL (Time To Live), in seconds; 0 if none. TDLib will send updateDeleteMessages or updateMessageContent once the TTL expires
@@ -70,11 +72,11 @@ pub struct Message {
   /// Time left before the message expires, in seconds. If the TTL timer isn't started yet, equals to the value of the ttl field
   ttl_expires_in: f32,
   /// If non-zero, the user identifier of the bot through which this message was sent
-  via_bot_user_id: i64,
+  via_bot_user_id: UserId,
   /// For channel posts and anonymous group messages, optional author signature
   author_signature: String,
   /// Unique identifier of an album this message belongs to. Only audios, documents, photos and videos can be grouped together in albums
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] media_album_id: isize,
+  #[serde(with = "crate::types::_common::int64")] media_album_id: i64,
   /// If non-empty, contains a human-readable description of the reason why access to this message must be restricted
   restriction_reason: String,
   /// Content of the message
@@ -85,27 +87,88 @@ pub struct Message {
 }
 
 impl RObject for Message {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "message" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "message" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Message {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageBuilder {
-    let mut inner = Message::default();
-    inner.td_name = "message".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Message { td_name: "message".to_string(), ..Default::default() };
     RTDMessageBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `Message` with the fields tdlib requires, the others are set by `Message::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: MessageId,
+    sender: impl AsRef<MessageSender>,
+    chat_id: ChatId,
+    is_outgoing: bool,
+    is_pinned: bool,
+    can_be_edited: bool,
+    can_be_forwarded: bool,
+    can_be_deleted_only_for_self: bool,
+    can_be_deleted_for_all_users: bool,
+    can_get_statistics: bool,
+    can_get_message_thread: bool,
+    can_get_viewers: bool,
+    can_get_media_timestamp_links: bool,
+    is_channel_post: bool,
+    contains_unread_mention: bool,
+    date: i64,
+    edit_date: i64,
+    reply_in_chat_id: ChatId,
+    reply_to_message_id: MessageId,
+    message_thread_id: i64,
+    ttl: i64,
+    ttl_expires_in: f32,
+    via_bot_user_id: UserId,
+    author_signature: impl AsRef<str>,
+    media_album_id: i64,
+    restriction_reason: impl AsRef<str>,
+    content: impl AsRef<MessageContent>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .sender(sender)
+      .chat_id(chat_id)
+      .is_outgoing(is_outgoing)
+      .is_pinned(is_pinned)
+      .can_be_edited(can_be_edited)
+      .can_be_forwarded(can_be_forwarded)
+      .can_be_deleted_only_for_self(can_be_deleted_only_for_self)
+      .can_be_deleted_for_all_users(can_be_deleted_for_all_users)
+      .can_get_statistics(can_get_statistics)
+      .can_get_message_thread(can_get_message_thread)
+      .can_get_viewers(can_get_viewers)
+      .can_get_media_timestamp_links(can_get_media_timestamp_links)
+      .is_channel_post(is_channel_post)
+      .contains_unread_mention(contains_unread_mention)
+      .date(date)
+      .edit_date(edit_date)
+      .reply_in_chat_id(reply_in_chat_id)
+      .reply_to_message_id(reply_to_message_id)
+      .message_thread_id(message_thread_id)
+      .ttl(ttl)
+      .ttl_expires_in(ttl_expires_in)
+      .via_bot_user_id(via_bot_user_id)
+      .author_signature(author_signature)
+      .media_album_id(media_album_id)
+      .restriction_reason(restriction_reason)
+      .content(content)
+      .build()
+  }
+
+  pub fn id(&self) -> MessageId { self.id }
 
   pub fn sender(&self) -> &MessageSender { &self.sender }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn sending_state(&self) -> &Option<MessageSendingState> { &self.sending_state }
 
@@ -145,9 +208,9 @@ impl Message {
 
   pub fn interaction_info(&self) -> &Option<MessageInteractionInfo> { &self.interaction_info }
 
-  pub fn reply_in_chat_id(&self) -> i64 { self.reply_in_chat_id }
+  pub fn reply_in_chat_id(&self) -> ChatId { self.reply_in_chat_id }
 
-  pub fn reply_to_message_id(&self) -> i64 { self.reply_to_message_id }
+  pub fn reply_to_message_id(&self) -> MessageId { self.reply_to_message_id }
 
   pub fn message_thread_id(&self) -> i64 { self.message_thread_id }
 
@@ -155,11 +218,11 @@ impl Message {
 
   pub fn ttl_expires_in(&self) -> f32 { self.ttl_expires_in }
 
-  pub fn via_bot_user_id(&self) -> i64 { self.via_bot_user_id }
+  pub fn via_bot_user_id(&self) -> UserId { self.via_bot_user_id }
 
   pub fn author_signature(&self) -> &String { &self.author_signature }
 
-  pub fn media_album_id(&self) -> isize { self.media_album_id }
+  pub fn media_album_id(&self) -> i64 { self.media_album_id }
 
   pub fn restriction_reason(&self) -> &String { &self.restriction_reason }
 
@@ -177,8 +240,14 @@ pub struct RTDMessageBuilder {
 impl RTDMessageBuilder {
   pub fn build(&self) -> Message { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: MessageId) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -190,7 +259,7 @@ impl RTDMessageBuilder {
   }
 
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -255,11 +324,12 @@ impl RTDMessageBuilder {
     self
   }
 
-
+   
   pub fn can_get_viewers(&mut self, can_get_viewers: bool) -> &mut Self {
     self.inner.can_get_viewers = can_get_viewers;
     self
   }
+
    
   pub fn can_get_media_timestamp_links(&mut self, can_get_media_timestamp_links: bool) -> &mut Self {
     self.inner.can_get_media_timestamp_links = can_get_media_timestamp_links;
@@ -309,13 +379,13 @@ impl RTDMessageBuilder {
   }
 
    
-  pub fn reply_in_chat_id(&mut self, reply_in_chat_id: i64) -> &mut Self {
+  pub fn reply_in_chat_id(&mut self, reply_in_chat_id: ChatId) -> &mut Self {
     self.inner.reply_in_chat_id = reply_in_chat_id;
     self
   }
 
    
-  pub fn reply_to_message_id(&mut self, reply_to_message_id: i64) -> &mut Self {
+  pub fn reply_to_message_id(&mut self, reply_to_message_id: MessageId) -> &mut Self {
     self.inner.reply_to_message_id = reply_to_message_id;
     self
   }
@@ -339,7 +409,7 @@ impl RTDMessageBuilder {
   }
 
    
-  pub fn via_bot_user_id(&mut self, via_bot_user_id: i64) -> &mut Self {
+  pub fn via_bot_user_id(&mut self, via_bot_user_id: UserId) -> &mut Self {
     self.inner.via_bot_user_id = via_bot_user_id;
     self
   }
@@ -351,7 +421,7 @@ impl RTDMessageBuilder {
   }
 
    
-  pub fn media_album_id(&mut self, media_album_id: isize) -> &mut Self {
+  pub fn media_album_id(&mut self, media_album_id: i64) -> &mut Self {
     self.inner.media_album_id = media_album_id;
     self
   }
@@ -384,5 +454,43 @@ impl AsRef<Message> for RTDMessageBuilder {
   fn as_ref(&self) -> &Message { &self.inner }
 }
 
+impl PartialEq for Message {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sender == other.sender
+      && self.chat_id == other.chat_id
+      && self.sending_state == other.sending_state
+      && self.scheduling_state == other.scheduling_state
+      && self.is_outgoing == other.is_outgoing
+      && self.is_pinned == other.is_pinned
+      && self.can_be_edited == other.can_be_edited
+      && self.can_be_forwarded == other.can_be_forwarded
+      && self.can_be_deleted_only_for_self == other.can_be_deleted_only_for_self
+      && self.can_be_deleted_for_all_users == other.can_be_deleted_for_all_users
+      && self.can_get_statistics == other.can_get_statistics
+      && self.can_get_message_thread == other.can_get_message_thread
+      && self.can_get_viewers == other.can_get_viewers
+      && self.can_get_media_timestamp_links == other.can_get_media_timestamp_links
+      && self.has_timestamped_media == other.has_timestamped_media
+      && self.is_channel_post == other.is_channel_post
+      && self.contains_unread_mention == other.contains_unread_mention
+      && self.date == other.date
+      && self.edit_date == other.edit_date
+      && self.forward_info == other.forward_info
+      && self.interaction_info == other.interaction_info
+      && self.reply_in_chat_id == other.reply_in_chat_id
+      && self.reply_to_message_id == other.reply_to_message_id
+      && self.message_thread_id == other.message_thread_id
+      && self.ttl == other.ttl
+      && self.ttl_expires_in == other.ttl_expires_in
+      && self.via_bot_user_id == other.via_bot_user_id
+      && self.author_signature == other.author_signature
+      && self.media_album_id == other.media_album_id
+      && self.restriction_reason == other.restriction_reason
+      && self.content == other.content
+      && self.reply_markup == other.reply_markup
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MessageCalendar {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Total number of found messages
   total_count: i64,
   /// Information about messages sent
@@ -23,22 +25,24 @@ pub struct MessageCalendar {
 }
 
 impl RObject for MessageCalendar {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCalendar" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageCalendar" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageCalendar {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageCalendarBuilder {
-    let mut inner = MessageCalendar::default();
-    inner.td_name = "messageCalendar".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageCalendar { td_name: "messageCalendar".to_string(), ..Default::default() };
     RTDMessageCalendarBuilder { inner }
   }
 
+  /// `MessageCalendar` with the fields tdlib requires, the others are set by `MessageCalendar::builder()`
+  pub fn new(total_count: i64, days: Vec<MessageCalendarDay>) -> Self { Self::builder().total_count(total_count).days(days).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
   pub fn days(&self) -> &Vec<MessageCalendarDay> { &self.days }
@@ -53,6 +57,12 @@ pub struct RTDMessageCalendarBuilder {
 impl RTDMessageCalendarBuilder {
   pub fn build(&self) -> MessageCalendar { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -75,5 +85,12 @@ impl AsRef<MessageCalendar> for RTDMessageCalendarBuilder {
   fn as_ref(&self) -> &MessageCalendar { &self.inner }
 }
 
+impl PartialEq for MessageCalendar {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.days == other.days
+  }
+}
+
 
 
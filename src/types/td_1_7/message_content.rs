@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDMessageContent: Debug + RObject {}
 
 /// Contains the content of a message
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MessageContent {
   #[doc(hidden)] _Default(()),
@@ -28,6 +27,7 @@ pub enum MessageContent {
   /// A newly created basic group
   MessageBasicGroupChatCreate(MessageBasicGroupChatCreate),
   /// A message with information about an ended call
+  #[cfg(feature = "calls")]
   MessageCall(MessageCall),
   /// New chat members were added
   MessageChatAddMembers(MessageChatAddMembers),
@@ -72,16 +72,21 @@ pub enum MessageContent {
   /// A message with information about an invite to a video chat
   MessageInviteVideoChatParticipants(MessageInviteVideoChatParticipants),
   /// A message with an invoice from a bot
+  #[cfg(feature = "payments")]
   MessageInvoice(MessageInvoice),
   /// A message with a location
   MessageLocation(MessageLocation),
   /// Telegram Passport data has been received; for bots only
+  #[cfg(feature = "passport")]
   MessagePassportDataReceived(MessagePassportDataReceived),
   /// Telegram Passport data has been sent
+  #[cfg(feature = "passport")]
   MessagePassportDataSent(MessagePassportDataSent),
   /// A payment has been completed
+  #[cfg(feature = "payments")]
   MessagePaymentSuccessful(MessagePaymentSuccessful),
   /// A payment has been completed; for bots only
+  #[cfg(feature = "payments")]
   MessagePaymentSuccessfulBot(MessagePaymentSuccessfulBot),
   /// A photo message
   MessagePhoto(MessagePhoto),
@@ -94,6 +99,7 @@ pub enum MessageContent {
   /// A screenshot of a message in the chat has been taken
   MessageScreenshotTaken(MessageScreenshotTaken),
   /// A sticker message
+  #[cfg(feature = "stickers")]
   MessageSticker(MessageSticker),
   /// A newly created supergroup or channel
   MessageSupergroupChatCreate(MessageSupergroupChatCreate),
@@ -117,6 +123,8 @@ pub enum MessageContent {
   MessageVoiceNote(MessageVoiceNote),
   /// The current user has connected a website by logging in using Telegram Login Widget on it
   MessageWebsiteConnected(MessageWebsiteConnected),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -128,12 +136,12 @@ impl<'de> Deserialize<'de> for MessageContent {
   fn deserialize<D>(deserializer: D) -> Result<MessageContent, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      MessageContent,
+      MessageContent, Unknown;
       (messageAnimatedEmoji, MessageAnimatedEmoji);
       (messageAnimation, MessageAnimation);
       (messageAudio, MessageAudio);
       (messageBasicGroupChatCreate, MessageBasicGroupChatCreate);
-      (messageCall, MessageCall);
+      #[cfg(feature = "calls")] (messageCall, MessageCall);
       (messageChatAddMembers, MessageChatAddMembers);
       (messageChatChangePhoto, MessageChatChangePhoto);
       (messageChatChangeTitle, MessageChatChangeTitle);
@@ -155,18 +163,18 @@ impl<'de> Deserialize<'de> for MessageContent {
       (messageGame, MessageGame);
       (messageGameScore, MessageGameScore);
       (messageInviteVideoChatParticipants, MessageInviteVideoChatParticipants);
-      (messageInvoice, MessageInvoice);
+      #[cfg(feature = "payments")] (messageInvoice, MessageInvoice);
       (messageLocation, MessageLocation);
-      (messagePassportDataReceived, MessagePassportDataReceived);
-      (messagePassportDataSent, MessagePassportDataSent);
-      (messagePaymentSuccessful, MessagePaymentSuccessful);
-      (messagePaymentSuccessfulBot, MessagePaymentSuccessfulBot);
+      #[cfg(feature = "passport")] (messagePassportDataReceived, MessagePassportDataReceived);
+      #[cfg(feature = "passport")] (messagePassportDataSent, MessagePassportDataSent);
+      #[cfg(feature = "payments")] (messagePaymentSuccessful, MessagePaymentSuccessful);
+      #[cfg(feature = "payments")] (messagePaymentSuccessfulBot, MessagePaymentSuccessfulBot);
       (messagePhoto, MessagePhoto);
       (messagePinMessage, MessagePinMessage);
       (messagePoll, MessagePoll);
       (messageProximityAlertTriggered, MessageProximityAlertTriggered);
       (messageScreenshotTaken, MessageScreenshotTaken);
-      (messageSticker, MessageSticker);
+      #[cfg(feature = "stickers")] (messageSticker, MessageSticker);
       (messageSupergroupChatCreate, MessageSupergroupChatCreate);
       (messageText, MessageText);
       (messageUnsupported, MessageUnsupported);
@@ -184,13 +192,13 @@ impl<'de> Deserialize<'de> for MessageContent {
 }
 
 impl RObject for MessageContent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       MessageContent::MessageAnimatedEmoji(t) => t.td_name(),
       MessageContent::MessageAnimation(t) => t.td_name(),
       MessageContent::MessageAudio(t) => t.td_name(),
       MessageContent::MessageBasicGroupChatCreate(t) => t.td_name(),
-      MessageContent::MessageCall(t) => t.td_name(),
+      #[cfg(feature = "calls")] MessageContent::MessageCall(t) => t.td_name(),
       MessageContent::MessageChatAddMembers(t) => t.td_name(),
       MessageContent::MessageChatChangePhoto(t) => t.td_name(),
       MessageContent::MessageChatChangeTitle(t) => t.td_name(),
@@ -212,18 +220,18 @@ impl RObject for MessageContent {
       MessageContent::MessageGame(t) => t.td_name(),
       MessageContent::MessageGameScore(t) => t.td_name(),
       MessageContent::MessageInviteVideoChatParticipants(t) => t.td_name(),
-      MessageContent::MessageInvoice(t) => t.td_name(),
+      #[cfg(feature = "payments")] MessageContent::MessageInvoice(t) => t.td_name(),
       MessageContent::MessageLocation(t) => t.td_name(),
-      MessageContent::MessagePassportDataReceived(t) => t.td_name(),
-      MessageContent::MessagePassportDataSent(t) => t.td_name(),
-      MessageContent::MessagePaymentSuccessful(t) => t.td_name(),
-      MessageContent::MessagePaymentSuccessfulBot(t) => t.td_name(),
+      #[cfg(feature = "passport")] MessageContent::MessagePassportDataReceived(t) => t.td_name(),
+      #[cfg(feature = "passport")] MessageContent::MessagePassportDataSent(t) => t.td_name(),
+      #[cfg(feature = "payments")] MessageContent::MessagePaymentSuccessful(t) => t.td_name(),
+      #[cfg(feature = "payments")] MessageContent::MessagePaymentSuccessfulBot(t) => t.td_name(),
       MessageContent::MessagePhoto(t) => t.td_name(),
       MessageContent::MessagePinMessage(t) => t.td_name(),
       MessageContent::MessagePoll(t) => t.td_name(),
       MessageContent::MessageProximityAlertTriggered(t) => t.td_name(),
       MessageContent::MessageScreenshotTaken(t) => t.td_name(),
-      MessageContent::MessageSticker(t) => t.td_name(),
+      #[cfg(feature = "stickers")] MessageContent::MessageSticker(t) => t.td_name(),
       MessageContent::MessageSupergroupChatCreate(t) => t.td_name(),
       MessageContent::MessageText(t) => t.td_name(),
       MessageContent::MessageUnsupported(t) => t.td_name(),
@@ -235,17 +243,18 @@ impl RObject for MessageContent {
       MessageContent::MessageVideoNote(t) => t.td_name(),
       MessageContent::MessageVoiceNote(t) => t.td_name(),
       MessageContent::MessageWebsiteConnected(t) => t.td_name(),
+      MessageContent::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       MessageContent::MessageAnimatedEmoji(t) => t.extra(),
       MessageContent::MessageAnimation(t) => t.extra(),
       MessageContent::MessageAudio(t) => t.extra(),
       MessageContent::MessageBasicGroupChatCreate(t) => t.extra(),
-      MessageContent::MessageCall(t) => t.extra(),
+      #[cfg(feature = "calls")] MessageContent::MessageCall(t) => t.extra(),
       MessageContent::MessageChatAddMembers(t) => t.extra(),
       MessageContent::MessageChatChangePhoto(t) => t.extra(),
       MessageContent::MessageChatChangeTitle(t) => t.extra(),
@@ -267,18 +276,18 @@ impl RObject for MessageContent {
       MessageContent::MessageGame(t) => t.extra(),
       MessageContent::MessageGameScore(t) => t.extra(),
       MessageContent::MessageInviteVideoChatParticipants(t) => t.extra(),
-      MessageContent::MessageInvoice(t) => t.extra(),
+      #[cfg(feature = "payments")] MessageContent::MessageInvoice(t) => t.extra(),
       MessageContent::MessageLocation(t) => t.extra(),
-      MessageContent::MessagePassportDataReceived(t) => t.extra(),
-      MessageContent::MessagePassportDataSent(t) => t.extra(),
-      MessageContent::MessagePaymentSuccessful(t) => t.extra(),
-      MessageContent::MessagePaymentSuccessfulBot(t) => t.extra(),
+      #[cfg(feature = "passport")] MessageContent::MessagePassportDataReceived(t) => t.extra(),
+      #[cfg(feature = "passport")] MessageContent::MessagePassportDataSent(t) => t.extra(),
+      #[cfg(feature = "payments")] MessageContent::MessagePaymentSuccessful(t) => t.extra(),
+      #[cfg(feature = "payments")] MessageContent::MessagePaymentSuccessfulBot(t) => t.extra(),
       MessageContent::MessagePhoto(t) => t.extra(),
       MessageContent::MessagePinMessage(t) => t.extra(),
       MessageContent::MessagePoll(t) => t.extra(),
       MessageContent::MessageProximityAlertTriggered(t) => t.extra(),
       MessageContent::MessageScreenshotTaken(t) => t.extra(),
-      MessageContent::MessageSticker(t) => t.extra(),
+      #[cfg(feature = "stickers")] MessageContent::MessageSticker(t) => t.extra(),
       MessageContent::MessageSupergroupChatCreate(t) => t.extra(),
       MessageContent::MessageText(t) => t.extra(),
       MessageContent::MessageUnsupported(t) => t.extra(),
@@ -290,6 +299,63 @@ impl RObject for MessageContent {
       MessageContent::MessageVideoNote(t) => t.extra(),
       MessageContent::MessageVoiceNote(t) => t.extra(),
       MessageContent::MessageWebsiteConnected(t) => t.extra(),
+      MessageContent::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      MessageContent::MessageAnimatedEmoji(t) => t.client_id(),
+      MessageContent::MessageAnimation(t) => t.client_id(),
+      MessageContent::MessageAudio(t) => t.client_id(),
+      MessageContent::MessageBasicGroupChatCreate(t) => t.client_id(),
+      #[cfg(feature = "calls")] MessageContent::MessageCall(t) => t.client_id(),
+      MessageContent::MessageChatAddMembers(t) => t.client_id(),
+      MessageContent::MessageChatChangePhoto(t) => t.client_id(),
+      MessageContent::MessageChatChangeTitle(t) => t.client_id(),
+      MessageContent::MessageChatDeleteMember(t) => t.client_id(),
+      MessageContent::MessageChatDeletePhoto(t) => t.client_id(),
+      MessageContent::MessageChatJoinByLink(t) => t.client_id(),
+      MessageContent::MessageChatJoinByRequest(t) => t.client_id(),
+      MessageContent::MessageChatSetTheme(t) => t.client_id(),
+      MessageContent::MessageChatSetTtl(t) => t.client_id(),
+      MessageContent::MessageChatUpgradeFrom(t) => t.client_id(),
+      MessageContent::MessageChatUpgradeTo(t) => t.client_id(),
+      MessageContent::MessageContact(t) => t.client_id(),
+      MessageContent::MessageContactRegistered(t) => t.client_id(),
+      MessageContent::MessageCustomServiceAction(t) => t.client_id(),
+      MessageContent::MessageDice(t) => t.client_id(),
+      MessageContent::MessageDocument(t) => t.client_id(),
+      MessageContent::MessageExpiredPhoto(t) => t.client_id(),
+      MessageContent::MessageExpiredVideo(t) => t.client_id(),
+      MessageContent::MessageGame(t) => t.client_id(),
+      MessageContent::MessageGameScore(t) => t.client_id(),
+      MessageContent::MessageInviteVideoChatParticipants(t) => t.client_id(),
+      #[cfg(feature = "payments")] MessageContent::MessageInvoice(t) => t.client_id(),
+      MessageContent::MessageLocation(t) => t.client_id(),
+      #[cfg(feature = "passport")] MessageContent::MessagePassportDataReceived(t) => t.client_id(),
+      #[cfg(feature = "passport")] MessageContent::MessagePassportDataSent(t) => t.client_id(),
+      #[cfg(feature = "payments")] MessageContent::MessagePaymentSuccessful(t) => t.client_id(),
+      #[cfg(feature = "payments")] MessageContent::MessagePaymentSuccessfulBot(t) => t.client_id(),
+      MessageContent::MessagePhoto(t) => t.client_id(),
+      MessageContent::MessagePinMessage(t) => t.client_id(),
+      MessageContent::MessagePoll(t) => t.client_id(),
+      MessageContent::MessageProximityAlertTriggered(t) => t.client_id(),
+      MessageContent::MessageScreenshotTaken(t) => t.client_id(),
+      #[cfg(feature = "stickers")] MessageContent::MessageSticker(t) => t.client_id(),
+      MessageContent::MessageSupergroupChatCreate(t) => t.client_id(),
+      MessageContent::MessageText(t) => t.client_id(),
+      MessageContent::MessageUnsupported(t) => t.client_id(),
+      MessageContent::MessageVenue(t) => t.client_id(),
+      MessageContent::MessageVideo(t) => t.client_id(),
+      MessageContent::MessageVideoChatEnded(t) => t.client_id(),
+      MessageContent::MessageVideoChatScheduled(t) => t.client_id(),
+      MessageContent::MessageVideoChatStarted(t) => t.client_id(),
+      MessageContent::MessageVideoNote(t) => t.client_id(),
+      MessageContent::MessageVoiceNote(t) => t.client_id(),
+      MessageContent::MessageWebsiteConnected(t) => t.client_id(),
+      MessageContent::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -298,64 +364,65 @@ impl RObject for MessageContent {
 }
 
 impl MessageContent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageContent::_Default(_) = self { true } else { false } }
-
-  pub fn is_message_animated_emoji(&self) -> bool { if let MessageContent::MessageAnimatedEmoji(_) = self { true } else { false } }
-  pub fn is_message_animation(&self) -> bool { if let MessageContent::MessageAnimation(_) = self { true } else { false } }
-  pub fn is_message_audio(&self) -> bool { if let MessageContent::MessageAudio(_) = self { true } else { false } }
-  pub fn is_message_basic_group_chat_create(&self) -> bool { if let MessageContent::MessageBasicGroupChatCreate(_) = self { true } else { false } }
-  pub fn is_message_call(&self) -> bool { if let MessageContent::MessageCall(_) = self { true } else { false } }
-  pub fn is_message_chat_add_members(&self) -> bool { if let MessageContent::MessageChatAddMembers(_) = self { true } else { false } }
-  pub fn is_message_chat_change_photo(&self) -> bool { if let MessageContent::MessageChatChangePhoto(_) = self { true } else { false } }
-  pub fn is_message_chat_change_title(&self) -> bool { if let MessageContent::MessageChatChangeTitle(_) = self { true } else { false } }
-  pub fn is_message_chat_delete_member(&self) -> bool { if let MessageContent::MessageChatDeleteMember(_) = self { true } else { false } }
-  pub fn is_message_chat_delete_photo(&self) -> bool { if let MessageContent::MessageChatDeletePhoto(_) = self { true } else { false } }
-  pub fn is_message_chat_join_by_link(&self) -> bool { if let MessageContent::MessageChatJoinByLink(_) = self { true } else { false } }
-  pub fn is_message_chat_join_by_request(&self) -> bool { if let MessageContent::MessageChatJoinByRequest(_) = self { true } else { false } }
-  pub fn is_message_chat_set_theme(&self) -> bool { if let MessageContent::MessageChatSetTheme(_) = self { true } else { false } }
-  pub fn is_message_chat_set_ttl(&self) -> bool { if let MessageContent::MessageChatSetTtl(_) = self { true } else { false } }
-  pub fn is_message_chat_upgrade_from(&self) -> bool { if let MessageContent::MessageChatUpgradeFrom(_) = self { true } else { false } }
-  pub fn is_message_chat_upgrade_to(&self) -> bool { if let MessageContent::MessageChatUpgradeTo(_) = self { true } else { false } }
-  pub fn is_message_contact(&self) -> bool { if let MessageContent::MessageContact(_) = self { true } else { false } }
-  pub fn is_message_contact_registered(&self) -> bool { if let MessageContent::MessageContactRegistered(_) = self { true } else { false } }
-  pub fn is_message_custom_service_action(&self) -> bool { if let MessageContent::MessageCustomServiceAction(_) = self { true } else { false } }
-  pub fn is_message_dice(&self) -> bool { if let MessageContent::MessageDice(_) = self { true } else { false } }
-  pub fn is_message_document(&self) -> bool { if let MessageContent::MessageDocument(_) = self { true } else { false } }
-  pub fn is_message_expired_photo(&self) -> bool { if let MessageContent::MessageExpiredPhoto(_) = self { true } else { false } }
-  pub fn is_message_expired_video(&self) -> bool { if let MessageContent::MessageExpiredVideo(_) = self { true } else { false } }
-  pub fn is_message_game(&self) -> bool { if let MessageContent::MessageGame(_) = self { true } else { false } }
-  pub fn is_message_game_score(&self) -> bool { if let MessageContent::MessageGameScore(_) = self { true } else { false } }
-  pub fn is_message_invite_video_chat_participants(&self) -> bool { if let MessageContent::MessageInviteVideoChatParticipants(_) = self { true } else { false } }
-  pub fn is_message_invoice(&self) -> bool { if let MessageContent::MessageInvoice(_) = self { true } else { false } }
-  pub fn is_message_location(&self) -> bool { if let MessageContent::MessageLocation(_) = self { true } else { false } }
-  pub fn is_message_passport_data_received(&self) -> bool { if let MessageContent::MessagePassportDataReceived(_) = self { true } else { false } }
-  pub fn is_message_passport_data_sent(&self) -> bool { if let MessageContent::MessagePassportDataSent(_) = self { true } else { false } }
-  pub fn is_message_payment_successful(&self) -> bool { if let MessageContent::MessagePaymentSuccessful(_) = self { true } else { false } }
-  pub fn is_message_payment_successful_bot(&self) -> bool { if let MessageContent::MessagePaymentSuccessfulBot(_) = self { true } else { false } }
-  pub fn is_message_photo(&self) -> bool { if let MessageContent::MessagePhoto(_) = self { true } else { false } }
-  pub fn is_message_pin_message(&self) -> bool { if let MessageContent::MessagePinMessage(_) = self { true } else { false } }
-  pub fn is_message_poll(&self) -> bool { if let MessageContent::MessagePoll(_) = self { true } else { false } }
-  pub fn is_message_proximity_alert_triggered(&self) -> bool { if let MessageContent::MessageProximityAlertTriggered(_) = self { true } else { false } }
-  pub fn is_message_screenshot_taken(&self) -> bool { if let MessageContent::MessageScreenshotTaken(_) = self { true } else { false } }
-  pub fn is_message_sticker(&self) -> bool { if let MessageContent::MessageSticker(_) = self { true } else { false } }
-  pub fn is_message_supergroup_chat_create(&self) -> bool { if let MessageContent::MessageSupergroupChatCreate(_) = self { true } else { false } }
-  pub fn is_message_text(&self) -> bool { if let MessageContent::MessageText(_) = self { true } else { false } }
-  pub fn is_message_unsupported(&self) -> bool { if let MessageContent::MessageUnsupported(_) = self { true } else { false } }
-  pub fn is_message_venue(&self) -> bool { if let MessageContent::MessageVenue(_) = self { true } else { false } }
-  pub fn is_message_video(&self) -> bool { if let MessageContent::MessageVideo(_) = self { true } else { false } }
-  pub fn is_message_video_chat_ended(&self) -> bool { if let MessageContent::MessageVideoChatEnded(_) = self { true } else { false } }
-  pub fn is_message_video_chat_scheduled(&self) -> bool { if let MessageContent::MessageVideoChatScheduled(_) = self { true } else { false } }
-  pub fn is_message_video_chat_started(&self) -> bool { if let MessageContent::MessageVideoChatStarted(_) = self { true } else { false } }
-  pub fn is_message_video_note(&self) -> bool { if let MessageContent::MessageVideoNote(_) = self { true } else { false } }
-  pub fn is_message_voice_note(&self) -> bool { if let MessageContent::MessageVoiceNote(_) = self { true } else { false } }
-  pub fn is_message_website_connected(&self) -> bool { if let MessageContent::MessageWebsiteConnected(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, MessageContent::_Default(_)) }
+
+  pub fn is_message_animated_emoji(&self) -> bool { matches!(self, MessageContent::MessageAnimatedEmoji(_)) }
+  pub fn is_message_animation(&self) -> bool { matches!(self, MessageContent::MessageAnimation(_)) }
+  pub fn is_message_audio(&self) -> bool { matches!(self, MessageContent::MessageAudio(_)) }
+  pub fn is_message_basic_group_chat_create(&self) -> bool { matches!(self, MessageContent::MessageBasicGroupChatCreate(_)) }
+  #[cfg(feature = "calls")] pub fn is_message_call(&self) -> bool { matches!(self, MessageContent::MessageCall(_)) }
+  pub fn is_message_chat_add_members(&self) -> bool { matches!(self, MessageContent::MessageChatAddMembers(_)) }
+  pub fn is_message_chat_change_photo(&self) -> bool { matches!(self, MessageContent::MessageChatChangePhoto(_)) }
+  pub fn is_message_chat_change_title(&self) -> bool { matches!(self, MessageContent::MessageChatChangeTitle(_)) }
+  pub fn is_message_chat_delete_member(&self) -> bool { matches!(self, MessageContent::MessageChatDeleteMember(_)) }
+  pub fn is_message_chat_delete_photo(&self) -> bool { matches!(self, MessageContent::MessageChatDeletePhoto(_)) }
+  pub fn is_message_chat_join_by_link(&self) -> bool { matches!(self, MessageContent::MessageChatJoinByLink(_)) }
+  pub fn is_message_chat_join_by_request(&self) -> bool { matches!(self, MessageContent::MessageChatJoinByRequest(_)) }
+  pub fn is_message_chat_set_theme(&self) -> bool { matches!(self, MessageContent::MessageChatSetTheme(_)) }
+  pub fn is_message_chat_set_ttl(&self) -> bool { matches!(self, MessageContent::MessageChatSetTtl(_)) }
+  pub fn is_message_chat_upgrade_from(&self) -> bool { matches!(self, MessageContent::MessageChatUpgradeFrom(_)) }
+  pub fn is_message_chat_upgrade_to(&self) -> bool { matches!(self, MessageContent::MessageChatUpgradeTo(_)) }
+  pub fn is_message_contact(&self) -> bool { matches!(self, MessageContent::MessageContact(_)) }
+  pub fn is_message_contact_registered(&self) -> bool { matches!(self, MessageContent::MessageContactRegistered(_)) }
+  pub fn is_message_custom_service_action(&self) -> bool { matches!(self, MessageContent::MessageCustomServiceAction(_)) }
+  pub fn is_message_dice(&self) -> bool { matches!(self, MessageContent::MessageDice(_)) }
+  pub fn is_message_document(&self) -> bool { matches!(self, MessageContent::MessageDocument(_)) }
+  pub fn is_message_expired_photo(&self) -> bool { matches!(self, MessageContent::MessageExpiredPhoto(_)) }
+  pub fn is_message_expired_video(&self) -> bool { matches!(self, MessageContent::MessageExpiredVideo(_)) }
+  pub fn is_message_game(&self) -> bool { matches!(self, MessageContent::MessageGame(_)) }
+  pub fn is_message_game_score(&self) -> bool { matches!(self, MessageContent::MessageGameScore(_)) }
+  pub fn is_message_invite_video_chat_participants(&self) -> bool { matches!(self, MessageContent::MessageInviteVideoChatParticipants(_)) }
+  #[cfg(feature = "payments")] pub fn is_message_invoice(&self) -> bool { matches!(self, MessageContent::MessageInvoice(_)) }
+  pub fn is_message_location(&self) -> bool { matches!(self, MessageContent::MessageLocation(_)) }
+  #[cfg(feature = "passport")] pub fn is_message_passport_data_received(&self) -> bool { matches!(self, MessageContent::MessagePassportDataReceived(_)) }
+  #[cfg(feature = "passport")] pub fn is_message_passport_data_sent(&self) -> bool { matches!(self, MessageContent::MessagePassportDataSent(_)) }
+  #[cfg(feature = "payments")] pub fn is_message_payment_successful(&self) -> bool { matches!(self, MessageContent::MessagePaymentSuccessful(_)) }
+  #[cfg(feature = "payments")] pub fn is_message_payment_successful_bot(&self) -> bool { matches!(self, MessageContent::MessagePaymentSuccessfulBot(_)) }
+  pub fn is_message_photo(&self) -> bool { matches!(self, MessageContent::MessagePhoto(_)) }
+  pub fn is_message_pin_message(&self) -> bool { matches!(self, MessageContent::MessagePinMessage(_)) }
+  pub fn is_message_poll(&self) -> bool { matches!(self, MessageContent::MessagePoll(_)) }
+  pub fn is_message_proximity_alert_triggered(&self) -> bool { matches!(self, MessageContent::MessageProximityAlertTriggered(_)) }
+  pub fn is_message_screenshot_taken(&self) -> bool { matches!(self, MessageContent::MessageScreenshotTaken(_)) }
+  #[cfg(feature = "stickers")] pub fn is_message_sticker(&self) -> bool { matches!(self, MessageContent::MessageSticker(_)) }
+  pub fn is_message_supergroup_chat_create(&self) -> bool { matches!(self, MessageContent::MessageSupergroupChatCreate(_)) }
+  pub fn is_message_text(&self) -> bool { matches!(self, MessageContent::MessageText(_)) }
+  pub fn is_message_unsupported(&self) -> bool { matches!(self, MessageContent::MessageUnsupported(_)) }
+  pub fn is_message_venue(&self) -> bool { matches!(self, MessageContent::MessageVenue(_)) }
+  pub fn is_message_video(&self) -> bool { matches!(self, MessageContent::MessageVideo(_)) }
+  pub fn is_message_video_chat_ended(&self) -> bool { matches!(self, MessageContent::MessageVideoChatEnded(_)) }
+  pub fn is_message_video_chat_scheduled(&self) -> bool { matches!(self, MessageContent::MessageVideoChatScheduled(_)) }
+  pub fn is_message_video_chat_started(&self) -> bool { matches!(self, MessageContent::MessageVideoChatStarted(_)) }
+  pub fn is_message_video_note(&self) -> bool { matches!(self, MessageContent::MessageVideoNote(_)) }
+  pub fn is_message_voice_note(&self) -> bool { matches!(self, MessageContent::MessageVoiceNote(_)) }
+  pub fn is_message_website_connected(&self) -> bool { matches!(self, MessageContent::MessageWebsiteConnected(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, MessageContent::Unknown(_)) }
 
   pub fn on_message_animated_emoji<F: FnOnce(&MessageAnimatedEmoji)>(&self, fnc: F) -> &Self { if let MessageContent::MessageAnimatedEmoji(t) = self { fnc(t) }; self }
   pub fn on_message_animation<F: FnOnce(&MessageAnimation)>(&self, fnc: F) -> &Self { if let MessageContent::MessageAnimation(t) = self { fnc(t) }; self }
   pub fn on_message_audio<F: FnOnce(&MessageAudio)>(&self, fnc: F) -> &Self { if let MessageContent::MessageAudio(t) = self { fnc(t) }; self }
   pub fn on_message_basic_group_chat_create<F: FnOnce(&MessageBasicGroupChatCreate)>(&self, fnc: F) -> &Self { if let MessageContent::MessageBasicGroupChatCreate(t) = self { fnc(t) }; self }
-  pub fn on_message_call<F: FnOnce(&MessageCall)>(&self, fnc: F) -> &Self { if let MessageContent::MessageCall(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_message_call<F: FnOnce(&MessageCall)>(&self, fnc: F) -> &Self { if let MessageContent::MessageCall(t) = self { fnc(t) }; self }
   pub fn on_message_chat_add_members<F: FnOnce(&MessageChatAddMembers)>(&self, fnc: F) -> &Self { if let MessageContent::MessageChatAddMembers(t) = self { fnc(t) }; self }
   pub fn on_message_chat_change_photo<F: FnOnce(&MessageChatChangePhoto)>(&self, fnc: F) -> &Self { if let MessageContent::MessageChatChangePhoto(t) = self { fnc(t) }; self }
   pub fn on_message_chat_change_title<F: FnOnce(&MessageChatChangeTitle)>(&self, fnc: F) -> &Self { if let MessageContent::MessageChatChangeTitle(t) = self { fnc(t) }; self }
@@ -377,18 +444,18 @@ impl MessageContent {
   pub fn on_message_game<F: FnOnce(&MessageGame)>(&self, fnc: F) -> &Self { if let MessageContent::MessageGame(t) = self { fnc(t) }; self }
   pub fn on_message_game_score<F: FnOnce(&MessageGameScore)>(&self, fnc: F) -> &Self { if let MessageContent::MessageGameScore(t) = self { fnc(t) }; self }
   pub fn on_message_invite_video_chat_participants<F: FnOnce(&MessageInviteVideoChatParticipants)>(&self, fnc: F) -> &Self { if let MessageContent::MessageInviteVideoChatParticipants(t) = self { fnc(t) }; self }
-  pub fn on_message_invoice<F: FnOnce(&MessageInvoice)>(&self, fnc: F) -> &Self { if let MessageContent::MessageInvoice(t) = self { fnc(t) }; self }
+  #[cfg(feature = "payments")] pub fn on_message_invoice<F: FnOnce(&MessageInvoice)>(&self, fnc: F) -> &Self { if let MessageContent::MessageInvoice(t) = self { fnc(t) }; self }
   pub fn on_message_location<F: FnOnce(&MessageLocation)>(&self, fnc: F) -> &Self { if let MessageContent::MessageLocation(t) = self { fnc(t) }; self }
-  pub fn on_message_passport_data_received<F: FnOnce(&MessagePassportDataReceived)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePassportDataReceived(t) = self { fnc(t) }; self }
-  pub fn on_message_passport_data_sent<F: FnOnce(&MessagePassportDataSent)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePassportDataSent(t) = self { fnc(t) }; self }
-  pub fn on_message_payment_successful<F: FnOnce(&MessagePaymentSuccessful)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePaymentSuccessful(t) = self { fnc(t) }; self }
-  pub fn on_message_payment_successful_bot<F: FnOnce(&MessagePaymentSuccessfulBot)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePaymentSuccessfulBot(t) = self { fnc(t) }; self }
+  #[cfg(feature = "passport")] pub fn on_message_passport_data_received<F: FnOnce(&MessagePassportDataReceived)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePassportDataReceived(t) = self { fnc(t) }; self }
+  #[cfg(feature = "passport")] pub fn on_message_passport_data_sent<F: FnOnce(&MessagePassportDataSent)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePassportDataSent(t) = self { fnc(t) }; self }
+  #[cfg(feature = "payments")] pub fn on_message_payment_successful<F: FnOnce(&MessagePaymentSuccessful)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePaymentSuccessful(t) = self { fnc(t) }; self }
+  #[cfg(feature = "payments")] pub fn on_message_payment_successful_bot<F: FnOnce(&MessagePaymentSuccessfulBot)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePaymentSuccessfulBot(t) = self { fnc(t) }; self }
   pub fn on_message_photo<F: FnOnce(&MessagePhoto)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePhoto(t) = self { fnc(t) }; self }
   pub fn on_message_pin_message<F: FnOnce(&MessagePinMessage)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePinMessage(t) = self { fnc(t) }; self }
   pub fn on_message_poll<F: FnOnce(&MessagePoll)>(&self, fnc: F) -> &Self { if let MessageContent::MessagePoll(t) = self { fnc(t) }; self }
   pub fn on_message_proximity_alert_triggered<F: FnOnce(&MessageProximityAlertTriggered)>(&self, fnc: F) -> &Self { if let MessageContent::MessageProximityAlertTriggered(t) = self { fnc(t) }; self }
   pub fn on_message_screenshot_taken<F: FnOnce(&MessageScreenshotTaken)>(&self, fnc: F) -> &Self { if let MessageContent::MessageScreenshotTaken(t) = self { fnc(t) }; self }
-  pub fn on_message_sticker<F: FnOnce(&MessageSticker)>(&self, fnc: F) -> &Self { if let MessageContent::MessageSticker(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_message_sticker<F: FnOnce(&MessageSticker)>(&self, fnc: F) -> &Self { if let MessageContent::MessageSticker(t) = self { fnc(t) }; self }
   pub fn on_message_supergroup_chat_create<F: FnOnce(&MessageSupergroupChatCreate)>(&self, fnc: F) -> &Self { if let MessageContent::MessageSupergroupChatCreate(t) = self { fnc(t) }; self }
   pub fn on_message_text<F: FnOnce(&MessageText)>(&self, fnc: F) -> &Self { if let MessageContent::MessageText(t) = self { fnc(t) }; self }
   pub fn on_message_unsupported<F: FnOnce(&MessageUnsupported)>(&self, fnc: F) -> &Self { if let MessageContent::MessageUnsupported(t) = self { fnc(t) }; self }
@@ -400,12 +467,13 @@ impl MessageContent {
   pub fn on_message_video_note<F: FnOnce(&MessageVideoNote)>(&self, fnc: F) -> &Self { if let MessageContent::MessageVideoNote(t) = self { fnc(t) }; self }
   pub fn on_message_voice_note<F: FnOnce(&MessageVoiceNote)>(&self, fnc: F) -> &Self { if let MessageContent::MessageVoiceNote(t) = self { fnc(t) }; self }
   pub fn on_message_website_connected<F: FnOnce(&MessageWebsiteConnected)>(&self, fnc: F) -> &Self { if let MessageContent::MessageWebsiteConnected(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let MessageContent::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_message_animated_emoji(&self) -> Option<&MessageAnimatedEmoji> { if let MessageContent::MessageAnimatedEmoji(t) = self { return Some(t) } None }
   pub fn as_message_animation(&self) -> Option<&MessageAnimation> { if let MessageContent::MessageAnimation(t) = self { return Some(t) } None }
   pub fn as_message_audio(&self) -> Option<&MessageAudio> { if let MessageContent::MessageAudio(t) = self { return Some(t) } None }
   pub fn as_message_basic_group_chat_create(&self) -> Option<&MessageBasicGroupChatCreate> { if let MessageContent::MessageBasicGroupChatCreate(t) = self { return Some(t) } None }
-  pub fn as_message_call(&self) -> Option<&MessageCall> { if let MessageContent::MessageCall(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_message_call(&self) -> Option<&MessageCall> { if let MessageContent::MessageCall(t) = self { return Some(t) } None }
   pub fn as_message_chat_add_members(&self) -> Option<&MessageChatAddMembers> { if let MessageContent::MessageChatAddMembers(t) = self { return Some(t) } None }
   pub fn as_message_chat_change_photo(&self) -> Option<&MessageChatChangePhoto> { if let MessageContent::MessageChatChangePhoto(t) = self { return Some(t) } None }
   pub fn as_message_chat_change_title(&self) -> Option<&MessageChatChangeTitle> { if let MessageContent::MessageChatChangeTitle(t) = self { return Some(t) } None }
@@ -427,18 +495,18 @@ impl MessageContent {
   pub fn as_message_game(&self) -> Option<&MessageGame> { if let MessageContent::MessageGame(t) = self { return Some(t) } None }
   pub fn as_message_game_score(&self) -> Option<&MessageGameScore> { if let MessageContent::MessageGameScore(t) = self { return Some(t) } None }
   pub fn as_message_invite_video_chat_participants(&self) -> Option<&MessageInviteVideoChatParticipants> { if let MessageContent::MessageInviteVideoChatParticipants(t) = self { return Some(t) } None }
-  pub fn as_message_invoice(&self) -> Option<&MessageInvoice> { if let MessageContent::MessageInvoice(t) = self { return Some(t) } None }
+  #[cfg(feature = "payments")] pub fn as_message_invoice(&self) -> Option<&MessageInvoice> { if let MessageContent::MessageInvoice(t) = self { return Some(t) } None }
   pub fn as_message_location(&self) -> Option<&MessageLocation> { if let MessageContent::MessageLocation(t) = self { return Some(t) } None }
-  pub fn as_message_passport_data_received(&self) -> Option<&MessagePassportDataReceived> { if let MessageContent::MessagePassportDataReceived(t) = self { return Some(t) } None }
-  pub fn as_message_passport_data_sent(&self) -> Option<&MessagePassportDataSent> { if let MessageContent::MessagePassportDataSent(t) = self { return Some(t) } None }
-  pub fn as_message_payment_successful(&self) -> Option<&MessagePaymentSuccessful> { if let MessageContent::MessagePaymentSuccessful(t) = self { return Some(t) } None }
-  pub fn as_message_payment_successful_bot(&self) -> Option<&MessagePaymentSuccessfulBot> { if let MessageContent::MessagePaymentSuccessfulBot(t) = self { return Some(t) } None }
+  #[cfg(feature = "passport")] pub fn as_message_passport_data_received(&self) -> Option<&MessagePassportDataReceived> { if let MessageContent::MessagePassportDataReceived(t) = self { return Some(t) } None }
+  #[cfg(feature = "passport")] pub fn as_message_passport_data_sent(&self) -> Option<&MessagePassportDataSent> { if let MessageContent::MessagePassportDataSent(t) = self { return Some(t) } None }
+  #[cfg(feature = "payments")] pub fn as_message_payment_successful(&self) -> Option<&MessagePaymentSuccessful> { if let MessageContent::MessagePaymentSuccessful(t) = self { return Some(t) } None }
+  #[cfg(feature = "payments")] pub fn as_message_payment_successful_bot(&self) -> Option<&MessagePaymentSuccessfulBot> { if let MessageContent::MessagePaymentSuccessfulBot(t) = self { return Some(t) } None }
   pub fn as_message_photo(&self) -> Option<&MessagePhoto> { if let MessageContent::MessagePhoto(t) = self { return Some(t) } None }
   pub fn as_message_pin_message(&self) -> Option<&MessagePinMessage> { if let MessageContent::MessagePinMessage(t) = self { return Some(t) } None }
   pub fn as_message_poll(&self) -> Option<&MessagePoll> { if let MessageContent::MessagePoll(t) = self { return Some(t) } None }
   pub fn as_message_proximity_alert_triggered(&self) -> Option<&MessageProximityAlertTriggered> { if let MessageContent::MessageProximityAlertTriggered(t) = self { return Some(t) } None }
   pub fn as_message_screenshot_taken(&self) -> Option<&MessageScreenshotTaken> { if let MessageContent::MessageScreenshotTaken(t) = self { return Some(t) } None }
-  pub fn as_message_sticker(&self) -> Option<&MessageSticker> { if let MessageContent::MessageSticker(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_message_sticker(&self) -> Option<&MessageSticker> { if let MessageContent::MessageSticker(t) = self { return Some(t) } None }
   pub fn as_message_supergroup_chat_create(&self) -> Option<&MessageSupergroupChatCreate> { if let MessageContent::MessageSupergroupChatCreate(t) = self { return Some(t) } None }
   pub fn as_message_text(&self) -> Option<&MessageText> { if let MessageContent::MessageText(t) = self { return Some(t) } None }
   pub fn as_message_unsupported(&self) -> Option<&MessageUnsupported> { if let MessageContent::MessageUnsupported(t) = self { return Some(t) } None }
@@ -450,6 +518,7 @@ impl MessageContent {
   pub fn as_message_video_note(&self) -> Option<&MessageVideoNote> { if let MessageContent::MessageVideoNote(t) = self { return Some(t) } None }
   pub fn as_message_voice_note(&self) -> Option<&MessageVoiceNote> { if let MessageContent::MessageVoiceNote(t) = self { return Some(t) } None }
   pub fn as_message_website_connected(&self) -> Option<&MessageWebsiteConnected> { if let MessageContent::MessageWebsiteConnected(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let MessageContent::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -461,7 +530,7 @@ impl MessageContent {
 
   pub fn message_basic_group_chat_create<T: AsRef<MessageBasicGroupChatCreate>>(t: T) -> Self { MessageContent::MessageBasicGroupChatCreate(t.as_ref().clone()) }
 
-  pub fn message_call<T: AsRef<MessageCall>>(t: T) -> Self { MessageContent::MessageCall(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn message_call<T: AsRef<MessageCall>>(t: T) -> Self { MessageContent::MessageCall(t.as_ref().clone()) }
 
   pub fn message_chat_add_members<T: AsRef<MessageChatAddMembers>>(t: T) -> Self { MessageContent::MessageChatAddMembers(t.as_ref().clone()) }
 
@@ -505,17 +574,17 @@ impl MessageContent {
 
   pub fn message_invite_video_chat_participants<T: AsRef<MessageInviteVideoChatParticipants>>(t: T) -> Self { MessageContent::MessageInviteVideoChatParticipants(t.as_ref().clone()) }
 
-  pub fn message_invoice<T: AsRef<MessageInvoice>>(t: T) -> Self { MessageContent::MessageInvoice(t.as_ref().clone()) }
+  #[cfg(feature = "payments")] pub fn message_invoice<T: AsRef<MessageInvoice>>(t: T) -> Self { MessageContent::MessageInvoice(t.as_ref().clone()) }
 
   pub fn message_location<T: AsRef<MessageLocation>>(t: T) -> Self { MessageContent::MessageLocation(t.as_ref().clone()) }
 
-  pub fn message_passport_data_received<T: AsRef<MessagePassportDataReceived>>(t: T) -> Self { MessageContent::MessagePassportDataReceived(t.as_ref().clone()) }
+  #[cfg(feature = "passport")] pub fn message_passport_data_received<T: AsRef<MessagePassportDataReceived>>(t: T) -> Self { MessageContent::MessagePassportDataReceived(t.as_ref().clone()) }
 
-  pub fn message_passport_data_sent<T: AsRef<MessagePassportDataSent>>(t: T) -> Self { MessageContent::MessagePassportDataSent(t.as_ref().clone()) }
+  #[cfg(feature = "passport")] pub fn message_passport_data_sent<T: AsRef<MessagePassportDataSent>>(t: T) -> Self { MessageContent::MessagePassportDataSent(t.as_ref().clone()) }
 
-  pub fn message_payment_successful<T: AsRef<MessagePaymentSuccessful>>(t: T) -> Self { MessageContent::MessagePaymentSuccessful(t.as_ref().clone()) }
+  #[cfg(feature = "payments")] pub fn message_payment_successful<T: AsRef<MessagePaymentSuccessful>>(t: T) -> Self { MessageContent::MessagePaymentSuccessful(t.as_ref().clone()) }
 
-  pub fn message_payment_successful_bot<T: AsRef<MessagePaymentSuccessfulBot>>(t: T) -> Self { MessageContent::MessagePaymentSuccessfulBot(t.as_ref().clone()) }
+  #[cfg(feature = "payments")] pub fn message_payment_successful_bot<T: AsRef<MessagePaymentSuccessfulBot>>(t: T) -> Self { MessageContent::MessagePaymentSuccessfulBot(t.as_ref().clone()) }
 
   pub fn message_photo<T: AsRef<MessagePhoto>>(t: T) -> Self { MessageContent::MessagePhoto(t.as_ref().clone()) }
 
@@ -527,7 +596,7 @@ impl MessageContent {
 
   pub fn message_screenshot_taken<T: AsRef<MessageScreenshotTaken>>(t: T) -> Self { MessageContent::MessageScreenshotTaken(t.as_ref().clone()) }
 
-  pub fn message_sticker<T: AsRef<MessageSticker>>(t: T) -> Self { MessageContent::MessageSticker(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn message_sticker<T: AsRef<MessageSticker>>(t: T) -> Self { MessageContent::MessageSticker(t.as_ref().clone()) }
 
   pub fn message_supergroup_chat_create<T: AsRef<MessageSupergroupChatCreate>>(t: T) -> Self { MessageContent::MessageSupergroupChatCreate(t.as_ref().clone()) }
 
@@ -571,7 +640,10 @@ pub struct MessageAnimatedEmoji {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The animated emoji
   animated_emoji: AnimatedEmoji,
   /// The corresponding emoji
@@ -580,8 +652,9 @@ pub struct MessageAnimatedEmoji {
 }
 
 impl RObject for MessageAnimatedEmoji {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageAnimatedEmoji" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageAnimatedEmoji" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -591,14 +664,15 @@ impl TDMessageContent for MessageAnimatedEmoji {}
 
 
 impl MessageAnimatedEmoji {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageAnimatedEmojiBuilder {
-    let mut inner = MessageAnimatedEmoji::default();
-    inner.td_name = "messageAnimatedEmoji".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageAnimatedEmoji { td_name: "messageAnimatedEmoji".to_string(), ..Default::default() };
     RTDMessageAnimatedEmojiBuilder { inner }
   }
 
+  /// `MessageAnimatedEmoji` with the fields tdlib requires, the others are set by `MessageAnimatedEmoji::builder()`
+  pub fn new(animated_emoji: impl AsRef<AnimatedEmoji>, emoji: impl AsRef<str>) -> Self { Self::builder().animated_emoji(animated_emoji).emoji(emoji).build() }
+
   pub fn animated_emoji(&self) -> &AnimatedEmoji { &self.animated_emoji }
 
   pub fn emoji(&self) -> &String { &self.emoji }
@@ -613,6 +687,12 @@ pub struct RTDMessageAnimatedEmojiBuilder {
 impl RTDMessageAnimatedEmojiBuilder {
   pub fn build(&self) -> MessageAnimatedEmoji { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animated_emoji<T: AsRef<AnimatedEmoji>>(&mut self, animated_emoji: T) -> &mut Self {
     self.inner.animated_emoji = animated_emoji.as_ref().clone();
@@ -635,6 +715,13 @@ impl AsRef<MessageAnimatedEmoji> for RTDMessageAnimatedEmojiBuilder {
   fn as_ref(&self) -> &MessageAnimatedEmoji { &self.inner }
 }
 
+impl PartialEq for MessageAnimatedEmoji {
+  fn eq(&self, other: &Self) -> bool {
+    self.animated_emoji == other.animated_emoji
+      && self.emoji == other.emoji
+  }
+}
+
 
 
 
@@ -649,7 +736,10 @@ pub struct MessageAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The animation description
   animation: Animation,
   /// Animation caption
@@ -660,8 +750,9 @@ pub struct MessageAnimation {
 }
 
 impl RObject for MessageAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -671,14 +762,15 @@ impl TDMessageContent for MessageAnimation {}
 
 
 impl MessageAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageAnimationBuilder {
-    let mut inner = MessageAnimation::default();
-    inner.td_name = "messageAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageAnimation { td_name: "messageAnimation".to_string(), ..Default::default() };
     RTDMessageAnimationBuilder { inner }
   }
 
+  /// `MessageAnimation` with the fields tdlib requires, the others are set by `MessageAnimation::builder()`
+  pub fn new(animation: impl AsRef<Animation>, caption: impl AsRef<FormattedText>, is_secret: bool) -> Self { Self::builder().animation(animation).caption(caption).is_secret(is_secret).build() }
+
   pub fn animation(&self) -> &Animation { &self.animation }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
@@ -695,6 +787,12 @@ pub struct RTDMessageAnimationBuilder {
 impl RTDMessageAnimationBuilder {
   pub fn build(&self) -> MessageAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -723,6 +821,14 @@ impl AsRef<MessageAnimation> for RTDMessageAnimationBuilder {
   fn as_ref(&self) -> &MessageAnimation { &self.inner }
 }
 
+impl PartialEq for MessageAnimation {
+  fn eq(&self, other: &Self) -> bool {
+    self.animation == other.animation
+      && self.caption == other.caption
+      && self.is_secret == other.is_secret
+  }
+}
+
 
 
 
@@ -737,7 +843,10 @@ pub struct MessageAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The audio description
   audio: Audio,
   /// Audio caption
@@ -746,8 +855,9 @@ pub struct MessageAudio {
 }
 
 impl RObject for MessageAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -757,14 +867,15 @@ impl TDMessageContent for MessageAudio {}
 
 
 impl MessageAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageAudioBuilder {
-    let mut inner = MessageAudio::default();
-    inner.td_name = "messageAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageAudio { td_name: "messageAudio".to_string(), ..Default::default() };
     RTDMessageAudioBuilder { inner }
   }
 
+  /// `MessageAudio` with the fields tdlib requires, the others are set by `MessageAudio::builder()`
+  pub fn new(audio: impl AsRef<Audio>, caption: impl AsRef<FormattedText>) -> Self { Self::builder().audio(audio).caption(caption).build() }
+
   pub fn audio(&self) -> &Audio { &self.audio }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
@@ -779,6 +890,12 @@ pub struct RTDMessageAudioBuilder {
 impl RTDMessageAudioBuilder {
   pub fn build(&self) -> MessageAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
@@ -801,6 +918,13 @@ impl AsRef<MessageAudio> for RTDMessageAudioBuilder {
   fn as_ref(&self) -> &MessageAudio { &self.inner }
 }
 
+impl PartialEq for MessageAudio {
+  fn eq(&self, other: &Self) -> bool {
+    self.audio == other.audio
+      && self.caption == other.caption
+  }
+}
+
 
 
 
@@ -815,17 +939,21 @@ pub struct MessageBasicGroupChatCreate {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Title of the basic group
   title: String,
   /// User identifiers of members in the basic group
-  member_user_ids: Vec<i64>,
+  member_user_ids: Vec<UserId>,
   
 }
 
 impl RObject for MessageBasicGroupChatCreate {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageBasicGroupChatCreate" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageBasicGroupChatCreate" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -835,17 +963,18 @@ impl TDMessageContent for MessageBasicGroupChatCreate {}
 
 
 impl MessageBasicGroupChatCreate {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageBasicGroupChatCreateBuilder {
-    let mut inner = MessageBasicGroupChatCreate::default();
-    inner.td_name = "messageBasicGroupChatCreate".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageBasicGroupChatCreate { td_name: "messageBasicGroupChatCreate".to_string(), ..Default::default() };
     RTDMessageBasicGroupChatCreateBuilder { inner }
   }
 
+  /// `MessageBasicGroupChatCreate` with the fields tdlib requires, the others are set by `MessageBasicGroupChatCreate::builder()`
+  pub fn new(title: impl AsRef<str>, member_user_ids: Vec<UserId>) -> Self { Self::builder().title(title).member_user_ids(member_user_ids).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
-  pub fn member_user_ids(&self) -> &Vec<i64> { &self.member_user_ids }
+  pub fn member_user_ids(&self) -> &Vec<UserId> { &self.member_user_ids }
 
 }
 
@@ -857,6 +986,12 @@ pub struct RTDMessageBasicGroupChatCreateBuilder {
 impl RTDMessageBasicGroupChatCreateBuilder {
   pub fn build(&self) -> MessageBasicGroupChatCreate { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -864,7 +999,7 @@ impl RTDMessageBasicGroupChatCreateBuilder {
   }
 
    
-  pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
+  pub fn member_user_ids(&mut self, member_user_ids: Vec<UserId>) -> &mut Self {
     self.inner.member_user_ids = member_user_ids;
     self
   }
@@ -879,11 +1014,20 @@ impl AsRef<MessageBasicGroupChatCreate> for RTDMessageBasicGroupChatCreateBuilde
   fn as_ref(&self) -> &MessageBasicGroupChatCreate { &self.inner }
 }
 
+impl PartialEq for MessageBasicGroupChatCreate {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.member_user_ids == other.member_user_ids
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
 
 /// A message with information about an ended call
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -893,7 +1037,10 @@ pub struct MessageCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the call was a video call
   is_video: bool,
   /// Reason why the call was discarded
@@ -904,8 +1051,9 @@ pub struct MessageCall {
 }
 
 impl RObject for MessageCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -915,14 +1063,15 @@ impl TDMessageContent for MessageCall {}
 
 
 impl MessageCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageCallBuilder {
-    let mut inner = MessageCall::default();
-    inner.td_name = "messageCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageCall { td_name: "messageCall".to_string(), ..Default::default() };
     RTDMessageCallBuilder { inner }
   }
 
+  /// `MessageCall` with the fields tdlib requires, the others are set by `MessageCall::builder()`
+  pub fn new(is_video: bool, discard_reason: impl AsRef<CallDiscardReason>, duration: i64) -> Self { Self::builder().is_video(is_video).discard_reason(discard_reason).duration(duration).build() }
+
   pub fn is_video(&self) -> bool { self.is_video }
 
   pub fn discard_reason(&self) -> &CallDiscardReason { &self.discard_reason }
@@ -939,6 +1088,12 @@ pub struct RTDMessageCallBuilder {
 impl RTDMessageCallBuilder {
   pub fn build(&self) -> MessageCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_video(&mut self, is_video: bool) -> &mut Self {
     self.inner.is_video = is_video;
@@ -967,6 +1122,16 @@ impl AsRef<MessageCall> for RTDMessageCallBuilder {
   fn as_ref(&self) -> &MessageCall { &self.inner }
 }
 
+impl PartialEq for MessageCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_video == other.is_video
+      && self.discard_reason == other.discard_reason
+      && self.duration == other.duration
+  }
+}
+
+}
+
 
 
 
@@ -981,15 +1146,19 @@ pub struct MessageChatAddMembers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifiers of the new members
-  member_user_ids: Vec<i64>,
+  member_user_ids: Vec<UserId>,
   
 }
 
 impl RObject for MessageChatAddMembers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatAddMembers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatAddMembers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -999,15 +1168,16 @@ impl TDMessageContent for MessageChatAddMembers {}
 
 
 impl MessageChatAddMembers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatAddMembersBuilder {
-    let mut inner = MessageChatAddMembers::default();
-    inner.td_name = "messageChatAddMembers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatAddMembers { td_name: "messageChatAddMembers".to_string(), ..Default::default() };
     RTDMessageChatAddMembersBuilder { inner }
   }
 
-  pub fn member_user_ids(&self) -> &Vec<i64> { &self.member_user_ids }
+  /// `MessageChatAddMembers` with the fields tdlib requires, the others are set by `MessageChatAddMembers::builder()`
+  pub fn new(member_user_ids: Vec<UserId>) -> Self { Self::builder().member_user_ids(member_user_ids).build() }
+
+  pub fn member_user_ids(&self) -> &Vec<UserId> { &self.member_user_ids }
 
 }
 
@@ -1019,8 +1189,14 @@ pub struct RTDMessageChatAddMembersBuilder {
 impl RTDMessageChatAddMembersBuilder {
   pub fn build(&self) -> MessageChatAddMembers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
+  pub fn member_user_ids(&mut self, member_user_ids: Vec<UserId>) -> &mut Self {
     self.inner.member_user_ids = member_user_ids;
     self
   }
@@ -1035,6 +1211,12 @@ impl AsRef<MessageChatAddMembers> for RTDMessageChatAddMembersBuilder {
   fn as_ref(&self) -> &MessageChatAddMembers { &self.inner }
 }
 
+impl PartialEq for MessageChatAddMembers {
+  fn eq(&self, other: &Self) -> bool {
+    self.member_user_ids == other.member_user_ids
+  }
+}
+
 
 
 
@@ -1049,15 +1231,19 @@ pub struct MessageChatChangePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New chat photo
   photo: ChatPhoto,
   
 }
 
 impl RObject for MessageChatChangePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatChangePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatChangePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1067,14 +1253,15 @@ impl TDMessageContent for MessageChatChangePhoto {}
 
 
 impl MessageChatChangePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatChangePhotoBuilder {
-    let mut inner = MessageChatChangePhoto::default();
-    inner.td_name = "messageChatChangePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatChangePhoto { td_name: "messageChatChangePhoto".to_string(), ..Default::default() };
     RTDMessageChatChangePhotoBuilder { inner }
   }
 
+  /// `MessageChatChangePhoto` with the fields tdlib requires, the others are set by `MessageChatChangePhoto::builder()`
+  pub fn new(photo: impl AsRef<ChatPhoto>) -> Self { Self::builder().photo(photo).build() }
+
   pub fn photo(&self) -> &ChatPhoto { &self.photo }
 
 }
@@ -1087,6 +1274,12 @@ pub struct RTDMessageChatChangePhotoBuilder {
 impl RTDMessageChatChangePhotoBuilder {
   pub fn build(&self) -> MessageChatChangePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -1103,6 +1296,12 @@ impl AsRef<MessageChatChangePhoto> for RTDMessageChatChangePhotoBuilder {
   fn as_ref(&self) -> &MessageChatChangePhoto { &self.inner }
 }
 
+impl PartialEq for MessageChatChangePhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+  }
+}
+
 
 
 
@@ -1117,15 +1316,19 @@ pub struct MessageChatChangeTitle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New chat title
   title: String,
   
 }
 
 impl RObject for MessageChatChangeTitle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatChangeTitle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatChangeTitle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1135,14 +1338,15 @@ impl TDMessageContent for MessageChatChangeTitle {}
 
 
 impl MessageChatChangeTitle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatChangeTitleBuilder {
-    let mut inner = MessageChatChangeTitle::default();
-    inner.td_name = "messageChatChangeTitle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatChangeTitle { td_name: "messageChatChangeTitle".to_string(), ..Default::default() };
     RTDMessageChatChangeTitleBuilder { inner }
   }
 
+  /// `MessageChatChangeTitle` with the fields tdlib requires, the others are set by `MessageChatChangeTitle::builder()`
+  pub fn new(title: impl AsRef<str>) -> Self { Self::builder().title(title).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
 }
@@ -1155,6 +1359,12 @@ pub struct RTDMessageChatChangeTitleBuilder {
 impl RTDMessageChatChangeTitleBuilder {
   pub fn build(&self) -> MessageChatChangeTitle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -1171,6 +1381,12 @@ impl AsRef<MessageChatChangeTitle> for RTDMessageChatChangeTitleBuilder {
   fn as_ref(&self) -> &MessageChatChangeTitle { &self.inner }
 }
 
+impl PartialEq for MessageChatChangeTitle {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+  }
+}
+
 
 
 
@@ -1185,15 +1401,19 @@ pub struct MessageChatDeleteMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier of the deleted chat member
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for MessageChatDeleteMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatDeleteMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatDeleteMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1203,15 +1423,16 @@ impl TDMessageContent for MessageChatDeleteMember {}
 
 
 impl MessageChatDeleteMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatDeleteMemberBuilder {
-    let mut inner = MessageChatDeleteMember::default();
-    inner.td_name = "messageChatDeleteMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatDeleteMember { td_name: "messageChatDeleteMember".to_string(), ..Default::default() };
     RTDMessageChatDeleteMemberBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `MessageChatDeleteMember` with the fields tdlib requires, the others are set by `MessageChatDeleteMember::builder()`
+  pub fn new(user_id: UserId) -> Self { Self::builder().user_id(user_id).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -1223,8 +1444,14 @@ pub struct RTDMessageChatDeleteMemberBuilder {
 impl RTDMessageChatDeleteMemberBuilder {
   pub fn build(&self) -> MessageChatDeleteMember { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -1239,6 +1466,12 @@ impl AsRef<MessageChatDeleteMember> for RTDMessageChatDeleteMemberBuilder {
   fn as_ref(&self) -> &MessageChatDeleteMember { &self.inner }
 }
 
+impl PartialEq for MessageChatDeleteMember {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+  }
+}
+
 
 
 
@@ -1253,13 +1486,17 @@ pub struct MessageChatDeletePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageChatDeletePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatDeletePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatDeletePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1269,14 +1506,15 @@ impl TDMessageContent for MessageChatDeletePhoto {}
 
 
 impl MessageChatDeletePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatDeletePhotoBuilder {
-    let mut inner = MessageChatDeletePhoto::default();
-    inner.td_name = "messageChatDeletePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatDeletePhoto { td_name: "messageChatDeletePhoto".to_string(), ..Default::default() };
     RTDMessageChatDeletePhotoBuilder { inner }
   }
 
+  /// `MessageChatDeletePhoto` with the fields tdlib requires, the others are set by `MessageChatDeletePhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1287,6 +1525,12 @@ pub struct RTDMessageChatDeletePhotoBuilder {
 impl RTDMessageChatDeletePhotoBuilder {
   pub fn build(&self) -> MessageChatDeletePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageChatDeletePhoto> for MessageChatDeletePhoto {
@@ -1297,6 +1541,10 @@ impl AsRef<MessageChatDeletePhoto> for RTDMessageChatDeletePhotoBuilder {
   fn as_ref(&self) -> &MessageChatDeletePhoto { &self.inner }
 }
 
+impl PartialEq for MessageChatDeletePhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1311,13 +1559,17 @@ pub struct MessageChatJoinByLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageChatJoinByLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatJoinByLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatJoinByLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1327,14 +1579,15 @@ impl TDMessageContent for MessageChatJoinByLink {}
 
 
 impl MessageChatJoinByLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatJoinByLinkBuilder {
-    let mut inner = MessageChatJoinByLink::default();
-    inner.td_name = "messageChatJoinByLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatJoinByLink { td_name: "messageChatJoinByLink".to_string(), ..Default::default() };
     RTDMessageChatJoinByLinkBuilder { inner }
   }
 
+  /// `MessageChatJoinByLink` with the fields tdlib requires, the others are set by `MessageChatJoinByLink::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1345,6 +1598,12 @@ pub struct RTDMessageChatJoinByLinkBuilder {
 impl RTDMessageChatJoinByLinkBuilder {
   pub fn build(&self) -> MessageChatJoinByLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageChatJoinByLink> for MessageChatJoinByLink {
@@ -1355,6 +1614,10 @@ impl AsRef<MessageChatJoinByLink> for RTDMessageChatJoinByLinkBuilder {
   fn as_ref(&self) -> &MessageChatJoinByLink { &self.inner }
 }
 
+impl PartialEq for MessageChatJoinByLink {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1369,13 +1632,17 @@ pub struct MessageChatJoinByRequest {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageChatJoinByRequest {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatJoinByRequest" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatJoinByRequest" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1385,14 +1652,15 @@ impl TDMessageContent for MessageChatJoinByRequest {}
 
 
 impl MessageChatJoinByRequest {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatJoinByRequestBuilder {
-    let mut inner = MessageChatJoinByRequest::default();
-    inner.td_name = "messageChatJoinByRequest".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatJoinByRequest { td_name: "messageChatJoinByRequest".to_string(), ..Default::default() };
     RTDMessageChatJoinByRequestBuilder { inner }
   }
 
+  /// `MessageChatJoinByRequest` with the fields tdlib requires, the others are set by `MessageChatJoinByRequest::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1403,6 +1671,12 @@ pub struct RTDMessageChatJoinByRequestBuilder {
 impl RTDMessageChatJoinByRequestBuilder {
   pub fn build(&self) -> MessageChatJoinByRequest { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageChatJoinByRequest> for MessageChatJoinByRequest {
@@ -1413,6 +1687,10 @@ impl AsRef<MessageChatJoinByRequest> for RTDMessageChatJoinByRequestBuilder {
   fn as_ref(&self) -> &MessageChatJoinByRequest { &self.inner }
 }
 
+impl PartialEq for MessageChatJoinByRequest {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1427,15 +1705,19 @@ pub struct MessageChatSetTheme {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If non-empty, name of a new theme, set for the chat. Otherwise chat theme was reset to the default one
   theme_name: String,
   
 }
 
 impl RObject for MessageChatSetTheme {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatSetTheme" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatSetTheme" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1445,14 +1727,15 @@ impl TDMessageContent for MessageChatSetTheme {}
 
 
 impl MessageChatSetTheme {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatSetThemeBuilder {
-    let mut inner = MessageChatSetTheme::default();
-    inner.td_name = "messageChatSetTheme".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatSetTheme { td_name: "messageChatSetTheme".to_string(), ..Default::default() };
     RTDMessageChatSetThemeBuilder { inner }
   }
 
+  /// `MessageChatSetTheme` with the fields tdlib requires, the others are set by `MessageChatSetTheme::builder()`
+  pub fn new(theme_name: impl AsRef<str>) -> Self { Self::builder().theme_name(theme_name).build() }
+
   pub fn theme_name(&self) -> &String { &self.theme_name }
 
 }
@@ -1465,6 +1748,12 @@ pub struct RTDMessageChatSetThemeBuilder {
 impl RTDMessageChatSetThemeBuilder {
   pub fn build(&self) -> MessageChatSetTheme { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn theme_name<T: AsRef<str>>(&mut self, theme_name: T) -> &mut Self {
     self.inner.theme_name = theme_name.as_ref().to_string();
@@ -1481,6 +1770,12 @@ impl AsRef<MessageChatSetTheme> for RTDMessageChatSetThemeBuilder {
   fn as_ref(&self) -> &MessageChatSetTheme { &self.inner }
 }
 
+impl PartialEq for MessageChatSetTheme {
+  fn eq(&self, other: &Self) -> bool {
+    self.theme_name == other.theme_name
+  }
+}
+
 
 
 
@@ -1495,15 +1790,19 @@ pub struct MessageChatSetTtl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New message TTL setting
   ttl: i64,
   
 }
 
 impl RObject for MessageChatSetTtl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatSetTtl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatSetTtl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1513,14 +1812,15 @@ impl TDMessageContent for MessageChatSetTtl {}
 
 
 impl MessageChatSetTtl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatSetTtlBuilder {
-    let mut inner = MessageChatSetTtl::default();
-    inner.td_name = "messageChatSetTtl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatSetTtl { td_name: "messageChatSetTtl".to_string(), ..Default::default() };
     RTDMessageChatSetTtlBuilder { inner }
   }
 
+  /// `MessageChatSetTtl` with the fields tdlib requires, the others are set by `MessageChatSetTtl::builder()`
+  pub fn new(ttl: i64) -> Self { Self::builder().ttl(ttl).build() }
+
   pub fn ttl(&self) -> i64 { self.ttl }
 
 }
@@ -1533,6 +1833,12 @@ pub struct RTDMessageChatSetTtlBuilder {
 impl RTDMessageChatSetTtlBuilder {
   pub fn build(&self) -> MessageChatSetTtl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
@@ -1549,6 +1855,12 @@ impl AsRef<MessageChatSetTtl> for RTDMessageChatSetTtlBuilder {
   fn as_ref(&self) -> &MessageChatSetTtl { &self.inner }
 }
 
+impl PartialEq for MessageChatSetTtl {
+  fn eq(&self, other: &Self) -> bool {
+    self.ttl == other.ttl
+  }
+}
+
 
 
 
@@ -1563,17 +1875,21 @@ pub struct MessageChatUpgradeFrom {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Title of the newly created supergroup
   title: String,
   /// The identifier of the original basic group
-  basic_group_id: i64,
+  basic_group_id: BasicGroupId,
   
 }
 
 impl RObject for MessageChatUpgradeFrom {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatUpgradeFrom" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatUpgradeFrom" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1583,17 +1899,18 @@ impl TDMessageContent for MessageChatUpgradeFrom {}
 
 
 impl MessageChatUpgradeFrom {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatUpgradeFromBuilder {
-    let mut inner = MessageChatUpgradeFrom::default();
-    inner.td_name = "messageChatUpgradeFrom".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatUpgradeFrom { td_name: "messageChatUpgradeFrom".to_string(), ..Default::default() };
     RTDMessageChatUpgradeFromBuilder { inner }
   }
 
+  /// `MessageChatUpgradeFrom` with the fields tdlib requires, the others are set by `MessageChatUpgradeFrom::builder()`
+  pub fn new(title: impl AsRef<str>, basic_group_id: BasicGroupId) -> Self { Self::builder().title(title).basic_group_id(basic_group_id).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
-  pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
+  pub fn basic_group_id(&self) -> BasicGroupId { self.basic_group_id }
 
 }
 
@@ -1605,6 +1922,12 @@ pub struct RTDMessageChatUpgradeFromBuilder {
 impl RTDMessageChatUpgradeFromBuilder {
   pub fn build(&self) -> MessageChatUpgradeFrom { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -1612,7 +1935,7 @@ impl RTDMessageChatUpgradeFromBuilder {
   }
 
    
-  pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
+  pub fn basic_group_id(&mut self, basic_group_id: BasicGroupId) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
     self
   }
@@ -1627,6 +1950,13 @@ impl AsRef<MessageChatUpgradeFrom> for RTDMessageChatUpgradeFromBuilder {
   fn as_ref(&self) -> &MessageChatUpgradeFrom { &self.inner }
 }
 
+impl PartialEq for MessageChatUpgradeFrom {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.basic_group_id == other.basic_group_id
+  }
+}
+
 
 
 
@@ -1641,15 +1971,19 @@ pub struct MessageChatUpgradeTo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the supergroup to which the basic group was upgraded
-  supergroup_id: i64,
+  supergroup_id: SupergroupId,
   
 }
 
 impl RObject for MessageChatUpgradeTo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageChatUpgradeTo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageChatUpgradeTo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1659,15 +1993,16 @@ impl TDMessageContent for MessageChatUpgradeTo {}
 
 
 impl MessageChatUpgradeTo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageChatUpgradeToBuilder {
-    let mut inner = MessageChatUpgradeTo::default();
-    inner.td_name = "messageChatUpgradeTo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageChatUpgradeTo { td_name: "messageChatUpgradeTo".to_string(), ..Default::default() };
     RTDMessageChatUpgradeToBuilder { inner }
   }
 
-  pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
+  /// `MessageChatUpgradeTo` with the fields tdlib requires, the others are set by `MessageChatUpgradeTo::builder()`
+  pub fn new(supergroup_id: SupergroupId) -> Self { Self::builder().supergroup_id(supergroup_id).build() }
+
+  pub fn supergroup_id(&self) -> SupergroupId { self.supergroup_id }
 
 }
 
@@ -1679,8 +2014,14 @@ pub struct RTDMessageChatUpgradeToBuilder {
 impl RTDMessageChatUpgradeToBuilder {
   pub fn build(&self) -> MessageChatUpgradeTo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
+  pub fn supergroup_id(&mut self, supergroup_id: SupergroupId) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
     self
   }
@@ -1695,6 +2036,12 @@ impl AsRef<MessageChatUpgradeTo> for RTDMessageChatUpgradeToBuilder {
   fn as_ref(&self) -> &MessageChatUpgradeTo { &self.inner }
 }
 
+impl PartialEq for MessageChatUpgradeTo {
+  fn eq(&self, other: &Self) -> bool {
+    self.supergroup_id == other.supergroup_id
+  }
+}
+
 
 
 
@@ -1709,15 +2056,19 @@ pub struct MessageContact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The contact description
   contact: Contact,
   
 }
 
 impl RObject for MessageContact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageContact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageContact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1727,14 +2078,15 @@ impl TDMessageContent for MessageContact {}
 
 
 impl MessageContact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageContactBuilder {
-    let mut inner = MessageContact::default();
-    inner.td_name = "messageContact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageContact { td_name: "messageContact".to_string(), ..Default::default() };
     RTDMessageContactBuilder { inner }
   }
 
+  /// `MessageContact` with the fields tdlib requires, the others are set by `MessageContact::builder()`
+  pub fn new(contact: impl AsRef<Contact>) -> Self { Self::builder().contact(contact).build() }
+
   pub fn contact(&self) -> &Contact { &self.contact }
 
 }
@@ -1747,6 +2099,12 @@ pub struct RTDMessageContactBuilder {
 impl RTDMessageContactBuilder {
   pub fn build(&self) -> MessageContact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
@@ -1763,6 +2121,12 @@ impl AsRef<MessageContact> for RTDMessageContactBuilder {
   fn as_ref(&self) -> &MessageContact { &self.inner }
 }
 
+impl PartialEq for MessageContact {
+  fn eq(&self, other: &Self) -> bool {
+    self.contact == other.contact
+  }
+}
+
 
 
 
@@ -1777,13 +2141,17 @@ pub struct MessageContactRegistered {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageContactRegistered {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageContactRegistered" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageContactRegistered" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1793,14 +2161,15 @@ impl TDMessageContent for MessageContactRegistered {}
 
 
 impl MessageContactRegistered {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageContactRegisteredBuilder {
-    let mut inner = MessageContactRegistered::default();
-    inner.td_name = "messageContactRegistered".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageContactRegistered { td_name: "messageContactRegistered".to_string(), ..Default::default() };
     RTDMessageContactRegisteredBuilder { inner }
   }
 
+  /// `MessageContactRegistered` with the fields tdlib requires, the others are set by `MessageContactRegistered::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1811,6 +2180,12 @@ pub struct RTDMessageContactRegisteredBuilder {
 impl RTDMessageContactRegisteredBuilder {
   pub fn build(&self) -> MessageContactRegistered { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageContactRegistered> for MessageContactRegistered {
@@ -1821,6 +2196,10 @@ impl AsRef<MessageContactRegistered> for RTDMessageContactRegisteredBuilder {
   fn as_ref(&self) -> &MessageContactRegistered { &self.inner }
 }
 
+impl PartialEq for MessageContactRegistered {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1835,15 +2214,19 @@ pub struct MessageCustomServiceAction {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message text to be shown in the chat
   text: String,
   
 }
 
 impl RObject for MessageCustomServiceAction {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCustomServiceAction" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageCustomServiceAction" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1853,14 +2236,15 @@ impl TDMessageContent for MessageCustomServiceAction {}
 
 
 impl MessageCustomServiceAction {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageCustomServiceActionBuilder {
-    let mut inner = MessageCustomServiceAction::default();
-    inner.td_name = "messageCustomServiceAction".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageCustomServiceAction { td_name: "messageCustomServiceAction".to_string(), ..Default::default() };
     RTDMessageCustomServiceActionBuilder { inner }
   }
 
+  /// `MessageCustomServiceAction` with the fields tdlib requires, the others are set by `MessageCustomServiceAction::builder()`
+  pub fn new(text: impl AsRef<str>) -> Self { Self::builder().text(text).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
 }
@@ -1873,6 +2257,12 @@ pub struct RTDMessageCustomServiceActionBuilder {
 impl RTDMessageCustomServiceActionBuilder {
   pub fn build(&self) -> MessageCustomServiceAction { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -1889,6 +2279,12 @@ impl AsRef<MessageCustomServiceAction> for RTDMessageCustomServiceActionBuilder
   fn as_ref(&self) -> &MessageCustomServiceAction { &self.inner }
 }
 
+impl PartialEq for MessageCustomServiceAction {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -1903,7 +2299,10 @@ pub struct MessageDice {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The animated stickers with the initial dice animation; may be null if unknown. updateMessageContent will be sent when the sticker became known
   initial_state: Option<DiceStickers>,
   /// The animated stickers with the final dice animation; may be null if unknown. updateMessageContent will be sent when the sticker became known
@@ -1918,8 +2317,9 @@ pub struct MessageDice {
 }
 
 impl RObject for MessageDice {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageDice" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageDice" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1929,14 +2329,15 @@ impl TDMessageContent for MessageDice {}
 
 
 impl MessageDice {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageDiceBuilder {
-    let mut inner = MessageDice::default();
-    inner.td_name = "messageDice".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageDice { td_name: "messageDice".to_string(), ..Default::default() };
     RTDMessageDiceBuilder { inner }
   }
 
+  /// `MessageDice` with the fields tdlib requires, the others are set by `MessageDice::builder()`
+  pub fn new(emoji: impl AsRef<str>, value: i64, success_animation_frame_number: i64) -> Self { Self::builder().emoji(emoji).value(value).success_animation_frame_number(success_animation_frame_number).build() }
+
   pub fn initial_state(&self) -> &Option<DiceStickers> { &self.initial_state }
 
   pub fn final_state(&self) -> &Option<DiceStickers> { &self.final_state }
@@ -1957,6 +2358,12 @@ pub struct RTDMessageDiceBuilder {
 impl RTDMessageDiceBuilder {
   pub fn build(&self) -> MessageDice { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn initial_state<T: AsRef<DiceStickers>>(&mut self, initial_state: T) -> &mut Self {
     self.inner.initial_state = Some(initial_state.as_ref().clone());
@@ -1997,6 +2404,16 @@ impl AsRef<MessageDice> for RTDMessageDiceBuilder {
   fn as_ref(&self) -> &MessageDice { &self.inner }
 }
 
+impl PartialEq for MessageDice {
+  fn eq(&self, other: &Self) -> bool {
+    self.initial_state == other.initial_state
+      && self.final_state == other.final_state
+      && self.emoji == other.emoji
+      && self.value == other.value
+      && self.success_animation_frame_number == other.success_animation_frame_number
+  }
+}
+
 
 
 
@@ -2011,7 +2428,10 @@ pub struct MessageDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The document description
   document: Document,
   /// Document caption
@@ -2020,8 +2440,9 @@ pub struct MessageDocument {
 }
 
 impl RObject for MessageDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2031,14 +2452,15 @@ impl TDMessageContent for MessageDocument {}
 
 
 impl MessageDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageDocumentBuilder {
-    let mut inner = MessageDocument::default();
-    inner.td_name = "messageDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageDocument { td_name: "messageDocument".to_string(), ..Default::default() };
     RTDMessageDocumentBuilder { inner }
   }
 
+  /// `MessageDocument` with the fields tdlib requires, the others are set by `MessageDocument::builder()`
+  pub fn new(document: impl AsRef<Document>, caption: impl AsRef<FormattedText>) -> Self { Self::builder().document(document).caption(caption).build() }
+
   pub fn document(&self) -> &Document { &self.document }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
@@ -2053,6 +2475,12 @@ pub struct RTDMessageDocumentBuilder {
 impl RTDMessageDocumentBuilder {
   pub fn build(&self) -> MessageDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
@@ -2075,6 +2503,13 @@ impl AsRef<MessageDocument> for RTDMessageDocumentBuilder {
   fn as_ref(&self) -> &MessageDocument { &self.inner }
 }
 
+impl PartialEq for MessageDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.document == other.document
+      && self.caption == other.caption
+  }
+}
+
 
 
 
@@ -2089,13 +2524,17 @@ pub struct MessageExpiredPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageExpiredPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageExpiredPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageExpiredPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2105,14 +2544,15 @@ impl TDMessageContent for MessageExpiredPhoto {}
 
 
 impl MessageExpiredPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageExpiredPhotoBuilder {
-    let mut inner = MessageExpiredPhoto::default();
-    inner.td_name = "messageExpiredPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageExpiredPhoto { td_name: "messageExpiredPhoto".to_string(), ..Default::default() };
     RTDMessageExpiredPhotoBuilder { inner }
   }
 
+  /// `MessageExpiredPhoto` with the fields tdlib requires, the others are set by `MessageExpiredPhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -2123,6 +2563,12 @@ pub struct RTDMessageExpiredPhotoBuilder {
 impl RTDMessageExpiredPhotoBuilder {
   pub fn build(&self) -> MessageExpiredPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageExpiredPhoto> for MessageExpiredPhoto {
@@ -2133,6 +2579,10 @@ impl AsRef<MessageExpiredPhoto> for RTDMessageExpiredPhotoBuilder {
   fn as_ref(&self) -> &MessageExpiredPhoto { &self.inner }
 }
 
+impl PartialEq for MessageExpiredPhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -2147,13 +2597,17 @@ pub struct MessageExpiredVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageExpiredVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageExpiredVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageExpiredVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2163,14 +2617,15 @@ impl TDMessageContent for MessageExpiredVideo {}
 
 
 impl MessageExpiredVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageExpiredVideoBuilder {
-    let mut inner = MessageExpiredVideo::default();
-    inner.td_name = "messageExpiredVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageExpiredVideo { td_name: "messageExpiredVideo".to_string(), ..Default::default() };
     RTDMessageExpiredVideoBuilder { inner }
   }
 
+  /// `MessageExpiredVideo` with the fields tdlib requires, the others are set by `MessageExpiredVideo::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -2181,6 +2636,12 @@ pub struct RTDMessageExpiredVideoBuilder {
 impl RTDMessageExpiredVideoBuilder {
   pub fn build(&self) -> MessageExpiredVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageExpiredVideo> for MessageExpiredVideo {
@@ -2191,6 +2652,10 @@ impl AsRef<MessageExpiredVideo> for RTDMessageExpiredVideoBuilder {
   fn as_ref(&self) -> &MessageExpiredVideo { &self.inner }
 }
 
+impl PartialEq for MessageExpiredVideo {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -2205,15 +2670,19 @@ pub struct MessageGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The game description
   game: Game,
   
 }
 
 impl RObject for MessageGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2223,14 +2692,15 @@ impl TDMessageContent for MessageGame {}
 
 
 impl MessageGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageGameBuilder {
-    let mut inner = MessageGame::default();
-    inner.td_name = "messageGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageGame { td_name: "messageGame".to_string(), ..Default::default() };
     RTDMessageGameBuilder { inner }
   }
 
+  /// `MessageGame` with the fields tdlib requires, the others are set by `MessageGame::builder()`
+  pub fn new(game: impl AsRef<Game>) -> Self { Self::builder().game(game).build() }
+
   pub fn game(&self) -> &Game { &self.game }
 
 }
@@ -2243,6 +2713,12 @@ pub struct RTDMessageGameBuilder {
 impl RTDMessageGameBuilder {
   pub fn build(&self) -> MessageGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn game<T: AsRef<Game>>(&mut self, game: T) -> &mut Self {
     self.inner.game = game.as_ref().clone();
@@ -2259,6 +2735,12 @@ impl AsRef<MessageGame> for RTDMessageGameBuilder {
   fn as_ref(&self) -> &MessageGame { &self.inner }
 }
 
+impl PartialEq for MessageGame {
+  fn eq(&self, other: &Self) -> bool {
+    self.game == other.game
+  }
+}
+
 
 
 
@@ -2273,19 +2755,23 @@ pub struct MessageGameScore {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the message with the game, can be an identifier of a deleted message
-  game_message_id: i64,
+  game_message_id: MessageId,
   /// Identifier of the game; may be different from the games presented in the message with the game
-  game_id: isize,
+  #[serde(with = "crate::types::_common::int64")] game_id: i64,
   /// New score
   score: i64,
   
 }
 
 impl RObject for MessageGameScore {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageGameScore" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageGameScore" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2295,17 +2781,18 @@ impl TDMessageContent for MessageGameScore {}
 
 
 impl MessageGameScore {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageGameScoreBuilder {
-    let mut inner = MessageGameScore::default();
-    inner.td_name = "messageGameScore".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageGameScore { td_name: "messageGameScore".to_string(), ..Default::default() };
     RTDMessageGameScoreBuilder { inner }
   }
 
-  pub fn game_message_id(&self) -> i64 { self.game_message_id }
+  /// `MessageGameScore` with the fields tdlib requires, the others are set by `MessageGameScore::builder()`
+  pub fn new(game_message_id: MessageId, game_id: i64, score: i64) -> Self { Self::builder().game_message_id(game_message_id).game_id(game_id).score(score).build() }
 
-  pub fn game_id(&self) -> isize { self.game_id }
+  pub fn game_message_id(&self) -> MessageId { self.game_message_id }
+
+  pub fn game_id(&self) -> i64 { self.game_id }
 
   pub fn score(&self) -> i64 { self.score }
 
@@ -2319,14 +2806,20 @@ pub struct RTDMessageGameScoreBuilder {
 impl RTDMessageGameScoreBuilder {
   pub fn build(&self) -> MessageGameScore { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn game_message_id(&mut self, game_message_id: i64) -> &mut Self {
+  pub fn game_message_id(&mut self, game_message_id: MessageId) -> &mut Self {
     self.inner.game_message_id = game_message_id;
     self
   }
 
    
-  pub fn game_id(&mut self, game_id: isize) -> &mut Self {
+  pub fn game_id(&mut self, game_id: i64) -> &mut Self {
     self.inner.game_id = game_id;
     self
   }
@@ -2347,6 +2840,14 @@ impl AsRef<MessageGameScore> for RTDMessageGameScoreBuilder {
   fn as_ref(&self) -> &MessageGameScore { &self.inner }
 }
 
+impl PartialEq for MessageGameScore {
+  fn eq(&self, other: &Self) -> bool {
+    self.game_message_id == other.game_message_id
+      && self.game_id == other.game_id
+      && self.score == other.score
+  }
+}
+
 
 
 
@@ -2361,17 +2862,21 @@ pub struct MessageInviteVideoChatParticipants {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the video chat. The video chat can be received through the method getGroupCall
   group_call_id: i64,
   /// Invited user identifiers
-  user_ids: Vec<i64>,
+  user_ids: Vec<UserId>,
   
 }
 
 impl RObject for MessageInviteVideoChatParticipants {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageInviteVideoChatParticipants" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageInviteVideoChatParticipants" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2381,17 +2886,18 @@ impl TDMessageContent for MessageInviteVideoChatParticipants {}
 
 
 impl MessageInviteVideoChatParticipants {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageInviteVideoChatParticipantsBuilder {
-    let mut inner = MessageInviteVideoChatParticipants::default();
-    inner.td_name = "messageInviteVideoChatParticipants".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageInviteVideoChatParticipants { td_name: "messageInviteVideoChatParticipants".to_string(), ..Default::default() };
     RTDMessageInviteVideoChatParticipantsBuilder { inner }
   }
 
+  /// `MessageInviteVideoChatParticipants` with the fields tdlib requires, the others are set by `MessageInviteVideoChatParticipants::builder()`
+  pub fn new(group_call_id: i64, user_ids: Vec<UserId>) -> Self { Self::builder().group_call_id(group_call_id).user_ids(user_ids).build() }
+
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
 
-  pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
+  pub fn user_ids(&self) -> &Vec<UserId> { &self.user_ids }
 
 }
 
@@ -2403,6 +2909,12 @@ pub struct RTDMessageInviteVideoChatParticipantsBuilder {
 impl RTDMessageInviteVideoChatParticipantsBuilder {
   pub fn build(&self) -> MessageInviteVideoChatParticipants { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
@@ -2410,7 +2922,7 @@ impl RTDMessageInviteVideoChatParticipantsBuilder {
   }
 
    
-  pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+  pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
     self.inner.user_ids = user_ids;
     self
   }
@@ -2425,12 +2937,21 @@ impl AsRef<MessageInviteVideoChatParticipants> for RTDMessageInviteVideoChatPart
   fn as_ref(&self) -> &MessageInviteVideoChatParticipants { &self.inner }
 }
 
+impl PartialEq for MessageInviteVideoChatParticipants {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call_id == other.group_call_id
+      && self.user_ids == other.user_ids
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "payments")]
+
 /// A message with an invoice from a bot
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MessageInvoice {
@@ -2439,10 +2960,13 @@ pub struct MessageInvoice {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Product title
   title: String,
-  /// A message with an invoice from a bot
+  /// Product description
   description: String,
   /// Product photo; may be null
   photo: Option<Photo>,
@@ -2457,13 +2981,14 @@ pub struct MessageInvoice {
   /// True, if the shipping address must be specified
   need_shipping_address: bool,
   /// The identifier of the message with the receipt, after the product has been purchased
-  receipt_message_id: i64,
+  receipt_message_id: MessageId,
   
 }
 
 impl RObject for MessageInvoice {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageInvoice" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageInvoice" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2473,14 +2998,36 @@ impl TDMessageContent for MessageInvoice {}
 
 
 impl MessageInvoice {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageInvoiceBuilder {
-    let mut inner = MessageInvoice::default();
-    inner.td_name = "messageInvoice".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageInvoice { td_name: "messageInvoice".to_string(), ..Default::default() };
     RTDMessageInvoiceBuilder { inner }
   }
 
+  /// `MessageInvoice` with the fields tdlib requires, the others are set by `MessageInvoice::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    currency: impl AsRef<str>,
+    total_amount: i64,
+    start_parameter: impl AsRef<str>,
+    is_test: bool,
+    need_shipping_address: bool,
+    receipt_message_id: MessageId,
+  ) -> Self {
+    Self::builder()
+      .title(title)
+      .description(description)
+      .currency(currency)
+      .total_amount(total_amount)
+      .start_parameter(start_parameter)
+      .is_test(is_test)
+      .need_shipping_address(need_shipping_address)
+      .receipt_message_id(receipt_message_id)
+      .build()
+  }
+
   pub fn title(&self) -> &String { &self.title }
 
   pub fn description(&self) -> &String { &self.description }
@@ -2497,7 +3044,7 @@ impl MessageInvoice {
 
   pub fn need_shipping_address(&self) -> bool { self.need_shipping_address }
 
-  pub fn receipt_message_id(&self) -> i64 { self.receipt_message_id }
+  pub fn receipt_message_id(&self) -> MessageId { self.receipt_message_id }
 
 }
 
@@ -2509,6 +3056,12 @@ pub struct RTDMessageInvoiceBuilder {
 impl RTDMessageInvoiceBuilder {
   pub fn build(&self) -> MessageInvoice { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -2558,7 +3111,7 @@ impl RTDMessageInvoiceBuilder {
   }
 
    
-  pub fn receipt_message_id(&mut self, receipt_message_id: i64) -> &mut Self {
+  pub fn receipt_message_id(&mut self, receipt_message_id: MessageId) -> &mut Self {
     self.inner.receipt_message_id = receipt_message_id;
     self
   }
@@ -2573,6 +3126,22 @@ impl AsRef<MessageInvoice> for RTDMessageInvoiceBuilder {
   fn as_ref(&self) -> &MessageInvoice { &self.inner }
 }
 
+impl PartialEq for MessageInvoice {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.description == other.description
+      && self.photo == other.photo
+      && self.currency == other.currency
+      && self.total_amount == other.total_amount
+      && self.start_parameter == other.start_parameter
+      && self.is_test == other.is_test
+      && self.need_shipping_address == other.need_shipping_address
+      && self.receipt_message_id == other.receipt_message_id
+  }
+}
+
+}
+
 
 
 
@@ -2587,7 +3156,10 @@ pub struct MessageLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The location description
   location: Location,
   /// Time relative to the message send date, for which the location can be updated, in seconds
@@ -2602,8 +3174,9 @@ pub struct MessageLocation {
 }
 
 impl RObject for MessageLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2613,14 +3186,29 @@ impl TDMessageContent for MessageLocation {}
 
 
 impl MessageLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageLocationBuilder {
-    let mut inner = MessageLocation::default();
-    inner.td_name = "messageLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageLocation { td_name: "messageLocation".to_string(), ..Default::default() };
     RTDMessageLocationBuilder { inner }
   }
 
+  /// `MessageLocation` with the fields tdlib requires, the others are set by `MessageLocation::builder()`
+  pub fn new(
+    location: impl AsRef<Location>,
+    live_period: i64,
+    expires_in: i64,
+    heading: i64,
+    proximity_alert_radius: i64,
+  ) -> Self {
+    Self::builder()
+      .location(location)
+      .live_period(live_period)
+      .expires_in(expires_in)
+      .heading(heading)
+      .proximity_alert_radius(proximity_alert_radius)
+      .build()
+  }
+
   pub fn location(&self) -> &Location { &self.location }
 
   pub fn live_period(&self) -> i64 { self.live_period }
@@ -2641,6 +3229,12 @@ pub struct RTDMessageLocationBuilder {
 impl RTDMessageLocationBuilder {
   pub fn build(&self) -> MessageLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -2681,11 +3275,23 @@ impl AsRef<MessageLocation> for RTDMessageLocationBuilder {
   fn as_ref(&self) -> &MessageLocation { &self.inner }
 }
 
+impl PartialEq for MessageLocation {
+  fn eq(&self, other: &Self) -> bool {
+    self.location == other.location
+      && self.live_period == other.live_period
+      && self.expires_in == other.expires_in
+      && self.heading == other.heading
+      && self.proximity_alert_radius == other.proximity_alert_radius
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "passport")]
 
 /// Telegram Passport data has been received; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -2695,7 +3301,10 @@ pub struct MessagePassportDataReceived {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of received Telegram Passport elements
   elements: Vec<EncryptedPassportElement>,
   /// Encrypted data credentials
@@ -2704,8 +3313,9 @@ pub struct MessagePassportDataReceived {
 }
 
 impl RObject for MessagePassportDataReceived {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePassportDataReceived" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePassportDataReceived" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2715,14 +3325,15 @@ impl TDMessageContent for MessagePassportDataReceived {}
 
 
 impl MessagePassportDataReceived {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePassportDataReceivedBuilder {
-    let mut inner = MessagePassportDataReceived::default();
-    inner.td_name = "messagePassportDataReceived".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePassportDataReceived { td_name: "messagePassportDataReceived".to_string(), ..Default::default() };
     RTDMessagePassportDataReceivedBuilder { inner }
   }
 
+  /// `MessagePassportDataReceived` with the fields tdlib requires, the others are set by `MessagePassportDataReceived::builder()`
+  pub fn new(elements: Vec<EncryptedPassportElement>, credentials: impl AsRef<EncryptedCredentials>) -> Self { Self::builder().elements(elements).credentials(credentials).build() }
+
   pub fn elements(&self) -> &Vec<EncryptedPassportElement> { &self.elements }
 
   pub fn credentials(&self) -> &EncryptedCredentials { &self.credentials }
@@ -2737,6 +3348,12 @@ pub struct RTDMessagePassportDataReceivedBuilder {
 impl RTDMessagePassportDataReceivedBuilder {
   pub fn build(&self) -> MessagePassportDataReceived { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn elements(&mut self, elements: Vec<EncryptedPassportElement>) -> &mut Self {
     self.inner.elements = elements;
@@ -2759,12 +3376,23 @@ impl AsRef<MessagePassportDataReceived> for RTDMessagePassportDataReceivedBuilde
   fn as_ref(&self) -> &MessagePassportDataReceived { &self.inner }
 }
 
+impl PartialEq for MessagePassportDataReceived {
+  fn eq(&self, other: &Self) -> bool {
+    self.elements == other.elements
+      && self.credentials == other.credentials
+  }
+}
 
+}
 
 
 
 
 
+
+
+rtd_cfg! { #[cfg(feature = "passport")]
+
 /// Telegram Passport data has been sent
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MessagePassportDataSent {
@@ -2773,15 +3401,19 @@ pub struct MessagePassportDataSent {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of Telegram Passport element types sent
   types: Vec<PassportElementType>,
   
 }
 
 impl RObject for MessagePassportDataSent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePassportDataSent" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePassportDataSent" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2791,14 +3423,15 @@ impl TDMessageContent for MessagePassportDataSent {}
 
 
 impl MessagePassportDataSent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePassportDataSentBuilder {
-    let mut inner = MessagePassportDataSent::default();
-    inner.td_name = "messagePassportDataSent".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePassportDataSent { td_name: "messagePassportDataSent".to_string(), ..Default::default() };
     RTDMessagePassportDataSentBuilder { inner }
   }
 
+  /// `MessagePassportDataSent` with the fields tdlib requires, the others are set by `MessagePassportDataSent::builder()`
+  pub fn new(types: Vec<PassportElementType>) -> Self { Self::builder().types(types).build() }
+
   pub fn types(&self) -> &Vec<PassportElementType> { &self.types }
 
 }
@@ -2811,6 +3444,12 @@ pub struct RTDMessagePassportDataSentBuilder {
 impl RTDMessagePassportDataSentBuilder {
   pub fn build(&self) -> MessagePassportDataSent { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn types(&mut self, types: Vec<PassportElementType>) -> &mut Self {
     self.inner.types = types;
@@ -2827,12 +3466,22 @@ impl AsRef<MessagePassportDataSent> for RTDMessagePassportDataSentBuilder {
   fn as_ref(&self) -> &MessagePassportDataSent { &self.inner }
 }
 
+impl PartialEq for MessagePassportDataSent {
+  fn eq(&self, other: &Self) -> bool {
+    self.types == other.types
+  }
+}
+
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "payments")]
+
 /// A payment has been completed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MessagePaymentSuccessful {
@@ -2841,11 +3490,14 @@ pub struct MessagePaymentSuccessful {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat, containing the corresponding invoice message; 0 if unknown
-  invoice_chat_id: i64,
+  invoice_chat_id: ChatId,
   /// Identifier of the message with the corresponding invoice; can be an identifier of a deleted message
-  invoice_message_id: i64,
+  invoice_message_id: MessageId,
   /// Currency for the price of the product
   currency: String,
   /// Total price for the product, in the smallest units of the currency
@@ -2854,8 +3506,9 @@ pub struct MessagePaymentSuccessful {
 }
 
 impl RObject for MessagePaymentSuccessful {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePaymentSuccessful" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePaymentSuccessful" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2865,17 +3518,30 @@ impl TDMessageContent for MessagePaymentSuccessful {}
 
 
 impl MessagePaymentSuccessful {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePaymentSuccessfulBuilder {
-    let mut inner = MessagePaymentSuccessful::default();
-    inner.td_name = "messagePaymentSuccessful".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePaymentSuccessful { td_name: "messagePaymentSuccessful".to_string(), ..Default::default() };
     RTDMessagePaymentSuccessfulBuilder { inner }
   }
 
-  pub fn invoice_chat_id(&self) -> i64 { self.invoice_chat_id }
+  /// `MessagePaymentSuccessful` with the fields tdlib requires, the others are set by `MessagePaymentSuccessful::builder()`
+  pub fn new(
+    invoice_chat_id: ChatId,
+    invoice_message_id: MessageId,
+    currency: impl AsRef<str>,
+    total_amount: i64,
+  ) -> Self {
+    Self::builder()
+      .invoice_chat_id(invoice_chat_id)
+      .invoice_message_id(invoice_message_id)
+      .currency(currency)
+      .total_amount(total_amount)
+      .build()
+  }
+
+  pub fn invoice_chat_id(&self) -> ChatId { self.invoice_chat_id }
 
-  pub fn invoice_message_id(&self) -> i64 { self.invoice_message_id }
+  pub fn invoice_message_id(&self) -> MessageId { self.invoice_message_id }
 
   pub fn currency(&self) -> &String { &self.currency }
 
@@ -2891,14 +3557,20 @@ pub struct RTDMessagePaymentSuccessfulBuilder {
 impl RTDMessagePaymentSuccessfulBuilder {
   pub fn build(&self) -> MessagePaymentSuccessful { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn invoice_chat_id(&mut self, invoice_chat_id: i64) -> &mut Self {
+  pub fn invoice_chat_id(&mut self, invoice_chat_id: ChatId) -> &mut Self {
     self.inner.invoice_chat_id = invoice_chat_id;
     self
   }
 
    
-  pub fn invoice_message_id(&mut self, invoice_message_id: i64) -> &mut Self {
+  pub fn invoice_message_id(&mut self, invoice_message_id: MessageId) -> &mut Self {
     self.inner.invoice_message_id = invoice_message_id;
     self
   }
@@ -2925,11 +3597,24 @@ impl AsRef<MessagePaymentSuccessful> for RTDMessagePaymentSuccessfulBuilder {
   fn as_ref(&self) -> &MessagePaymentSuccessful { &self.inner }
 }
 
+impl PartialEq for MessagePaymentSuccessful {
+  fn eq(&self, other: &Self) -> bool {
+    self.invoice_chat_id == other.invoice_chat_id
+      && self.invoice_message_id == other.invoice_message_id
+      && self.currency == other.currency
+      && self.total_amount == other.total_amount
+  }
+}
+
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "payments")]
 
 /// A payment has been completed; for bots only
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -2939,13 +3624,16 @@ pub struct MessagePaymentSuccessfulBot {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Currency for price of the product
   currency: String,
   /// Total price for the product, in the smallest units of the currency
   total_amount: i64,
   /// Invoice payload
-  invoice_payload: String,
+  invoice_payload: Bytes,
   /// Identifier of the shipping option chosen by the user; may be empty if not applicable
   shipping_option_id: String,
   /// Information about the order; may be null
@@ -2958,8 +3646,9 @@ pub struct MessagePaymentSuccessfulBot {
 }
 
 impl RObject for MessagePaymentSuccessfulBot {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePaymentSuccessfulBot" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePaymentSuccessfulBot" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2969,19 +3658,36 @@ impl TDMessageContent for MessagePaymentSuccessfulBot {}
 
 
 impl MessagePaymentSuccessfulBot {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePaymentSuccessfulBotBuilder {
-    let mut inner = MessagePaymentSuccessfulBot::default();
-    inner.td_name = "messagePaymentSuccessfulBot".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePaymentSuccessfulBot { td_name: "messagePaymentSuccessfulBot".to_string(), ..Default::default() };
     RTDMessagePaymentSuccessfulBotBuilder { inner }
   }
 
+  /// `MessagePaymentSuccessfulBot` with the fields tdlib requires, the others are set by `MessagePaymentSuccessfulBot::builder()`
+  pub fn new(
+    currency: impl AsRef<str>,
+    total_amount: i64,
+    invoice_payload: impl AsRef<Bytes>,
+    shipping_option_id: impl AsRef<str>,
+    telegram_payment_charge_id: impl AsRef<str>,
+    provider_payment_charge_id: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .currency(currency)
+      .total_amount(total_amount)
+      .invoice_payload(invoice_payload)
+      .shipping_option_id(shipping_option_id)
+      .telegram_payment_charge_id(telegram_payment_charge_id)
+      .provider_payment_charge_id(provider_payment_charge_id)
+      .build()
+  }
+
   pub fn currency(&self) -> &String { &self.currency }
 
   pub fn total_amount(&self) -> i64 { self.total_amount }
 
-  pub fn invoice_payload(&self) -> &String { &self.invoice_payload }
+  pub fn invoice_payload(&self) -> &Bytes { &self.invoice_payload }
 
   pub fn shipping_option_id(&self) -> &String { &self.shipping_option_id }
 
@@ -3001,6 +3707,12 @@ pub struct RTDMessagePaymentSuccessfulBotBuilder {
 impl RTDMessagePaymentSuccessfulBotBuilder {
   pub fn build(&self) -> MessagePaymentSuccessfulBot { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
@@ -3014,8 +3726,8 @@ impl RTDMessagePaymentSuccessfulBotBuilder {
   }
 
    
-  pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
-    self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+  pub fn invoice_payload<T: AsRef<Bytes>>(&mut self, invoice_payload: T) -> &mut Self {
+    self.inner.invoice_payload = invoice_payload.as_ref().clone();
     self
   }
 
@@ -3053,6 +3765,20 @@ impl AsRef<MessagePaymentSuccessfulBot> for RTDMessagePaymentSuccessfulBotBuilde
   fn as_ref(&self) -> &MessagePaymentSuccessfulBot { &self.inner }
 }
 
+impl PartialEq for MessagePaymentSuccessfulBot {
+  fn eq(&self, other: &Self) -> bool {
+    self.currency == other.currency
+      && self.total_amount == other.total_amount
+      && self.invoice_payload == other.invoice_payload
+      && self.shipping_option_id == other.shipping_option_id
+      && self.order_info == other.order_info
+      && self.telegram_payment_charge_id == other.telegram_payment_charge_id
+      && self.provider_payment_charge_id == other.provider_payment_charge_id
+  }
+}
+
+}
+
 
 
 
@@ -3067,7 +3793,10 @@ pub struct MessagePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The photo description
   photo: Photo,
   /// Photo caption
@@ -3078,8 +3807,9 @@ pub struct MessagePhoto {
 }
 
 impl RObject for MessagePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3089,14 +3819,15 @@ impl TDMessageContent for MessagePhoto {}
 
 
 impl MessagePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePhotoBuilder {
-    let mut inner = MessagePhoto::default();
-    inner.td_name = "messagePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePhoto { td_name: "messagePhoto".to_string(), ..Default::default() };
     RTDMessagePhotoBuilder { inner }
   }
 
+  /// `MessagePhoto` with the fields tdlib requires, the others are set by `MessagePhoto::builder()`
+  pub fn new(photo: impl AsRef<Photo>, caption: impl AsRef<FormattedText>, is_secret: bool) -> Self { Self::builder().photo(photo).caption(caption).is_secret(is_secret).build() }
+
   pub fn photo(&self) -> &Photo { &self.photo }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
@@ -3113,6 +3844,12 @@ pub struct RTDMessagePhotoBuilder {
 impl RTDMessagePhotoBuilder {
   pub fn build(&self) -> MessagePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -3141,6 +3878,14 @@ impl AsRef<MessagePhoto> for RTDMessagePhotoBuilder {
   fn as_ref(&self) -> &MessagePhoto { &self.inner }
 }
 
+impl PartialEq for MessagePhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+      && self.caption == other.caption
+      && self.is_secret == other.is_secret
+  }
+}
+
 
 
 
@@ -3155,15 +3900,19 @@ pub struct MessagePinMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the pinned message, can be an identifier of a deleted message or 0
-  message_id: i64,
+  message_id: MessageId,
   
 }
 
 impl RObject for MessagePinMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePinMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePinMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3173,15 +3922,16 @@ impl TDMessageContent for MessagePinMessage {}
 
 
 impl MessagePinMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePinMessageBuilder {
-    let mut inner = MessagePinMessage::default();
-    inner.td_name = "messagePinMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePinMessage { td_name: "messagePinMessage".to_string(), ..Default::default() };
     RTDMessagePinMessageBuilder { inner }
   }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  /// `MessagePinMessage` with the fields tdlib requires, the others are set by `MessagePinMessage::builder()`
+  pub fn new(message_id: MessageId) -> Self { Self::builder().message_id(message_id).build() }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
 }
 
@@ -3193,8 +3943,14 @@ pub struct RTDMessagePinMessageBuilder {
 impl RTDMessagePinMessageBuilder {
   pub fn build(&self) -> MessagePinMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -3209,6 +3965,12 @@ impl AsRef<MessagePinMessage> for RTDMessagePinMessageBuilder {
   fn as_ref(&self) -> &MessagePinMessage { &self.inner }
 }
 
+impl PartialEq for MessagePinMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.message_id == other.message_id
+  }
+}
+
 
 
 
@@ -3223,15 +3985,19 @@ pub struct MessagePoll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The poll description
   poll: Poll,
   
 }
 
 impl RObject for MessagePoll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePoll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePoll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3241,14 +4007,15 @@ impl TDMessageContent for MessagePoll {}
 
 
 impl MessagePoll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePollBuilder {
-    let mut inner = MessagePoll::default();
-    inner.td_name = "messagePoll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePoll { td_name: "messagePoll".to_string(), ..Default::default() };
     RTDMessagePollBuilder { inner }
   }
 
+  /// `MessagePoll` with the fields tdlib requires, the others are set by `MessagePoll::builder()`
+  pub fn new(poll: impl AsRef<Poll>) -> Self { Self::builder().poll(poll).build() }
+
   pub fn poll(&self) -> &Poll { &self.poll }
 
 }
@@ -3261,6 +4028,12 @@ pub struct RTDMessagePollBuilder {
 impl RTDMessagePollBuilder {
   pub fn build(&self) -> MessagePoll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
     self.inner.poll = poll.as_ref().clone();
@@ -3277,6 +4050,12 @@ impl AsRef<MessagePoll> for RTDMessagePollBuilder {
   fn as_ref(&self) -> &MessagePoll { &self.inner }
 }
 
+impl PartialEq for MessagePoll {
+  fn eq(&self, other: &Self) -> bool {
+    self.poll == other.poll
+  }
+}
+
 
 
 
@@ -3291,7 +4070,10 @@ pub struct MessageProximityAlertTriggered {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The user or chat, which triggered the proximity alert
   traveler: MessageSender,
   /// The user or chat, which subscribed for the proximity alert
@@ -3302,8 +4084,9 @@ pub struct MessageProximityAlertTriggered {
 }
 
 impl RObject for MessageProximityAlertTriggered {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageProximityAlertTriggered" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageProximityAlertTriggered" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3313,14 +4096,15 @@ impl TDMessageContent for MessageProximityAlertTriggered {}
 
 
 impl MessageProximityAlertTriggered {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageProximityAlertTriggeredBuilder {
-    let mut inner = MessageProximityAlertTriggered::default();
-    inner.td_name = "messageProximityAlertTriggered".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageProximityAlertTriggered { td_name: "messageProximityAlertTriggered".to_string(), ..Default::default() };
     RTDMessageProximityAlertTriggeredBuilder { inner }
   }
 
+  /// `MessageProximityAlertTriggered` with the fields tdlib requires, the others are set by `MessageProximityAlertTriggered::builder()`
+  pub fn new(traveler: impl AsRef<MessageSender>, watcher: impl AsRef<MessageSender>, distance: i64) -> Self { Self::builder().traveler(traveler).watcher(watcher).distance(distance).build() }
+
   pub fn traveler(&self) -> &MessageSender { &self.traveler }
 
   pub fn watcher(&self) -> &MessageSender { &self.watcher }
@@ -3337,6 +4121,12 @@ pub struct RTDMessageProximityAlertTriggeredBuilder {
 impl RTDMessageProximityAlertTriggeredBuilder {
   pub fn build(&self) -> MessageProximityAlertTriggered { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn traveler<T: AsRef<MessageSender>>(&mut self, traveler: T) -> &mut Self {
     self.inner.traveler = traveler.as_ref().clone();
@@ -3365,6 +4155,14 @@ impl AsRef<MessageProximityAlertTriggered> for RTDMessageProximityAlertTriggered
   fn as_ref(&self) -> &MessageProximityAlertTriggered { &self.inner }
 }
 
+impl PartialEq for MessageProximityAlertTriggered {
+  fn eq(&self, other: &Self) -> bool {
+    self.traveler == other.traveler
+      && self.watcher == other.watcher
+      && self.distance == other.distance
+  }
+}
+
 
 
 
@@ -3379,13 +4177,17 @@ pub struct MessageScreenshotTaken {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageScreenshotTaken {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageScreenshotTaken" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageScreenshotTaken" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3395,14 +4197,15 @@ impl TDMessageContent for MessageScreenshotTaken {}
 
 
 impl MessageScreenshotTaken {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageScreenshotTakenBuilder {
-    let mut inner = MessageScreenshotTaken::default();
-    inner.td_name = "messageScreenshotTaken".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageScreenshotTaken { td_name: "messageScreenshotTaken".to_string(), ..Default::default() };
     RTDMessageScreenshotTakenBuilder { inner }
   }
 
+  /// `MessageScreenshotTaken` with the fields tdlib requires, the others are set by `MessageScreenshotTaken::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -3413,6 +4216,12 @@ pub struct RTDMessageScreenshotTakenBuilder {
 impl RTDMessageScreenshotTakenBuilder {
   pub fn build(&self) -> MessageScreenshotTaken { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageScreenshotTaken> for MessageScreenshotTaken {
@@ -3423,12 +4232,18 @@ impl AsRef<MessageScreenshotTaken> for RTDMessageScreenshotTakenBuilder {
   fn as_ref(&self) -> &MessageScreenshotTaken { &self.inner }
 }
 
+impl PartialEq for MessageScreenshotTaken {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
 
 
 
 
 
 
+
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// A sticker message
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MessageSticker {
@@ -3437,15 +4252,19 @@ pub struct MessageSticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The sticker description
   sticker: Sticker,
   
 }
 
 impl RObject for MessageSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3455,14 +4274,15 @@ impl TDMessageContent for MessageSticker {}
 
 
 impl MessageSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageStickerBuilder {
-    let mut inner = MessageSticker::default();
-    inner.td_name = "messageSticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSticker { td_name: "messageSticker".to_string(), ..Default::default() };
     RTDMessageStickerBuilder { inner }
   }
 
+  /// `MessageSticker` with the fields tdlib requires, the others are set by `MessageSticker::builder()`
+  pub fn new(sticker: impl AsRef<Sticker>) -> Self { Self::builder().sticker(sticker).build() }
+
   pub fn sticker(&self) -> &Sticker { &self.sticker }
 
 }
@@ -3475,6 +4295,12 @@ pub struct RTDMessageStickerBuilder {
 impl RTDMessageStickerBuilder {
   pub fn build(&self) -> MessageSticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -3491,6 +4317,14 @@ impl AsRef<MessageSticker> for RTDMessageStickerBuilder {
   fn as_ref(&self) -> &MessageSticker { &self.inner }
 }
 
+impl PartialEq for MessageSticker {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker == other.sticker
+  }
+}
+
+}
+
 
 
 
@@ -3505,15 +4339,19 @@ pub struct MessageSupergroupChatCreate {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Title of the supergroup or channel
   title: String,
   
 }
 
 impl RObject for MessageSupergroupChatCreate {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSupergroupChatCreate" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSupergroupChatCreate" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3523,14 +4361,15 @@ impl TDMessageContent for MessageSupergroupChatCreate {}
 
 
 impl MessageSupergroupChatCreate {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSupergroupChatCreateBuilder {
-    let mut inner = MessageSupergroupChatCreate::default();
-    inner.td_name = "messageSupergroupChatCreate".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSupergroupChatCreate { td_name: "messageSupergroupChatCreate".to_string(), ..Default::default() };
     RTDMessageSupergroupChatCreateBuilder { inner }
   }
 
+  /// `MessageSupergroupChatCreate` with the fields tdlib requires, the others are set by `MessageSupergroupChatCreate::builder()`
+  pub fn new(title: impl AsRef<str>) -> Self { Self::builder().title(title).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
 }
@@ -3543,6 +4382,12 @@ pub struct RTDMessageSupergroupChatCreateBuilder {
 impl RTDMessageSupergroupChatCreateBuilder {
   pub fn build(&self) -> MessageSupergroupChatCreate { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -3559,6 +4404,12 @@ impl AsRef<MessageSupergroupChatCreate> for RTDMessageSupergroupChatCreateBuilde
   fn as_ref(&self) -> &MessageSupergroupChatCreate { &self.inner }
 }
 
+impl PartialEq for MessageSupergroupChatCreate {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+  }
+}
+
 
 
 
@@ -3573,7 +4424,10 @@ pub struct MessageText {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text of the message
   text: FormattedText,
   /// A preview of the web page that's mentioned in the text; may be null
@@ -3582,8 +4436,9 @@ pub struct MessageText {
 }
 
 impl RObject for MessageText {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageText" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageText" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3593,14 +4448,15 @@ impl TDMessageContent for MessageText {}
 
 
 impl MessageText {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageTextBuilder {
-    let mut inner = MessageText::default();
-    inner.td_name = "messageText".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageText { td_name: "messageText".to_string(), ..Default::default() };
     RTDMessageTextBuilder { inner }
   }
 
+  /// `MessageText` with the fields tdlib requires, the others are set by `MessageText::builder()`
+  pub fn new(text: impl AsRef<FormattedText>) -> Self { Self::builder().text(text).build() }
+
   pub fn text(&self) -> &FormattedText { &self.text }
 
   pub fn web_page(&self) -> &Option<WebPage> { &self.web_page }
@@ -3615,6 +4471,12 @@ pub struct RTDMessageTextBuilder {
 impl RTDMessageTextBuilder {
   pub fn build(&self) -> MessageText { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -3637,6 +4499,13 @@ impl AsRef<MessageText> for RTDMessageTextBuilder {
   fn as_ref(&self) -> &MessageText { &self.inner }
 }
 
+impl PartialEq for MessageText {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.web_page == other.web_page
+  }
+}
+
 
 
 
@@ -3651,13 +4520,17 @@ pub struct MessageUnsupported {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageUnsupported {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageUnsupported" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageUnsupported" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3667,14 +4540,15 @@ impl TDMessageContent for MessageUnsupported {}
 
 
 impl MessageUnsupported {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageUnsupportedBuilder {
-    let mut inner = MessageUnsupported::default();
-    inner.td_name = "messageUnsupported".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageUnsupported { td_name: "messageUnsupported".to_string(), ..Default::default() };
     RTDMessageUnsupportedBuilder { inner }
   }
 
+  /// `MessageUnsupported` with the fields tdlib requires, the others are set by `MessageUnsupported::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -3685,6 +4559,12 @@ pub struct RTDMessageUnsupportedBuilder {
 impl RTDMessageUnsupportedBuilder {
   pub fn build(&self) -> MessageUnsupported { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageUnsupported> for MessageUnsupported {
@@ -3695,6 +4575,10 @@ impl AsRef<MessageUnsupported> for RTDMessageUnsupportedBuilder {
   fn as_ref(&self) -> &MessageUnsupported { &self.inner }
 }
 
+impl PartialEq for MessageUnsupported {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -3709,15 +4593,19 @@ pub struct MessageVenue {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The venue description
   venue: Venue,
   
 }
 
 impl RObject for MessageVenue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVenue" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageVenue" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3727,14 +4615,15 @@ impl TDMessageContent for MessageVenue {}
 
 
 impl MessageVenue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageVenueBuilder {
-    let mut inner = MessageVenue::default();
-    inner.td_name = "messageVenue".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageVenue { td_name: "messageVenue".to_string(), ..Default::default() };
     RTDMessageVenueBuilder { inner }
   }
 
+  /// `MessageVenue` with the fields tdlib requires, the others are set by `MessageVenue::builder()`
+  pub fn new(venue: impl AsRef<Venue>) -> Self { Self::builder().venue(venue).build() }
+
   pub fn venue(&self) -> &Venue { &self.venue }
 
 }
@@ -3747,6 +4636,12 @@ pub struct RTDMessageVenueBuilder {
 impl RTDMessageVenueBuilder {
   pub fn build(&self) -> MessageVenue { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
@@ -3763,6 +4658,12 @@ impl AsRef<MessageVenue> for RTDMessageVenueBuilder {
   fn as_ref(&self) -> &MessageVenue { &self.inner }
 }
 
+impl PartialEq for MessageVenue {
+  fn eq(&self, other: &Self) -> bool {
+    self.venue == other.venue
+  }
+}
+
 
 
 
@@ -3777,7 +4678,10 @@ pub struct MessageVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The video description
   video: Video,
   /// Video caption
@@ -3788,8 +4692,9 @@ pub struct MessageVideo {
 }
 
 impl RObject for MessageVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3799,14 +4704,15 @@ impl TDMessageContent for MessageVideo {}
 
 
 impl MessageVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageVideoBuilder {
-    let mut inner = MessageVideo::default();
-    inner.td_name = "messageVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageVideo { td_name: "messageVideo".to_string(), ..Default::default() };
     RTDMessageVideoBuilder { inner }
   }
 
+  /// `MessageVideo` with the fields tdlib requires, the others are set by `MessageVideo::builder()`
+  pub fn new(video: impl AsRef<Video>, caption: impl AsRef<FormattedText>, is_secret: bool) -> Self { Self::builder().video(video).caption(caption).is_secret(is_secret).build() }
+
   pub fn video(&self) -> &Video { &self.video }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
@@ -3823,6 +4729,12 @@ pub struct RTDMessageVideoBuilder {
 impl RTDMessageVideoBuilder {
   pub fn build(&self) -> MessageVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
@@ -3851,6 +4763,14 @@ impl AsRef<MessageVideo> for RTDMessageVideoBuilder {
   fn as_ref(&self) -> &MessageVideo { &self.inner }
 }
 
+impl PartialEq for MessageVideo {
+  fn eq(&self, other: &Self) -> bool {
+    self.video == other.video
+      && self.caption == other.caption
+      && self.is_secret == other.is_secret
+  }
+}
+
 
 
 
@@ -3865,15 +4785,19 @@ pub struct MessageVideoChatEnded {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Call duration, in seconds
   duration: i64,
   
 }
 
 impl RObject for MessageVideoChatEnded {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVideoChatEnded" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageVideoChatEnded" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3883,14 +4807,15 @@ impl TDMessageContent for MessageVideoChatEnded {}
 
 
 impl MessageVideoChatEnded {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageVideoChatEndedBuilder {
-    let mut inner = MessageVideoChatEnded::default();
-    inner.td_name = "messageVideoChatEnded".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageVideoChatEnded { td_name: "messageVideoChatEnded".to_string(), ..Default::default() };
     RTDMessageVideoChatEndedBuilder { inner }
   }
 
+  /// `MessageVideoChatEnded` with the fields tdlib requires, the others are set by `MessageVideoChatEnded::builder()`
+  pub fn new(duration: i64) -> Self { Self::builder().duration(duration).build() }
+
   pub fn duration(&self) -> i64 { self.duration }
 
 }
@@ -3903,6 +4828,12 @@ pub struct RTDMessageVideoChatEndedBuilder {
 impl RTDMessageVideoChatEndedBuilder {
   pub fn build(&self) -> MessageVideoChatEnded { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -3919,6 +4850,12 @@ impl AsRef<MessageVideoChatEnded> for RTDMessageVideoChatEndedBuilder {
   fn as_ref(&self) -> &MessageVideoChatEnded { &self.inner }
 }
 
+impl PartialEq for MessageVideoChatEnded {
+  fn eq(&self, other: &Self) -> bool {
+    self.duration == other.duration
+  }
+}
+
 
 
 
@@ -3933,7 +4870,10 @@ pub struct MessageVideoChatScheduled {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the video chat. The video chat can be received through the method getGroupCall
   group_call_id: i64,
   /// Point in time (Unix timestamp) when the group call is supposed to be started by an administrator
@@ -3942,8 +4882,9 @@ pub struct MessageVideoChatScheduled {
 }
 
 impl RObject for MessageVideoChatScheduled {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVideoChatScheduled" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageVideoChatScheduled" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3953,14 +4894,15 @@ impl TDMessageContent for MessageVideoChatScheduled {}
 
 
 impl MessageVideoChatScheduled {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageVideoChatScheduledBuilder {
-    let mut inner = MessageVideoChatScheduled::default();
-    inner.td_name = "messageVideoChatScheduled".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageVideoChatScheduled { td_name: "messageVideoChatScheduled".to_string(), ..Default::default() };
     RTDMessageVideoChatScheduledBuilder { inner }
   }
 
+  /// `MessageVideoChatScheduled` with the fields tdlib requires, the others are set by `MessageVideoChatScheduled::builder()`
+  pub fn new(group_call_id: i64, start_date: i64) -> Self { Self::builder().group_call_id(group_call_id).start_date(start_date).build() }
+
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
 
   pub fn start_date(&self) -> i64 { self.start_date }
@@ -3975,6 +4917,12 @@ pub struct RTDMessageVideoChatScheduledBuilder {
 impl RTDMessageVideoChatScheduledBuilder {
   pub fn build(&self) -> MessageVideoChatScheduled { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
@@ -3997,6 +4945,13 @@ impl AsRef<MessageVideoChatScheduled> for RTDMessageVideoChatScheduledBuilder {
   fn as_ref(&self) -> &MessageVideoChatScheduled { &self.inner }
 }
 
+impl PartialEq for MessageVideoChatScheduled {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call_id == other.group_call_id
+      && self.start_date == other.start_date
+  }
+}
+
 
 
 
@@ -4011,15 +4966,19 @@ pub struct MessageVideoChatStarted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the video chat. The video chat can be received through the method getGroupCall
   group_call_id: i64,
   
 }
 
 impl RObject for MessageVideoChatStarted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVideoChatStarted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageVideoChatStarted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4029,14 +4988,15 @@ impl TDMessageContent for MessageVideoChatStarted {}
 
 
 impl MessageVideoChatStarted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageVideoChatStartedBuilder {
-    let mut inner = MessageVideoChatStarted::default();
-    inner.td_name = "messageVideoChatStarted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageVideoChatStarted { td_name: "messageVideoChatStarted".to_string(), ..Default::default() };
     RTDMessageVideoChatStartedBuilder { inner }
   }
 
+  /// `MessageVideoChatStarted` with the fields tdlib requires, the others are set by `MessageVideoChatStarted::builder()`
+  pub fn new(group_call_id: i64) -> Self { Self::builder().group_call_id(group_call_id).build() }
+
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
 
 }
@@ -4049,6 +5009,12 @@ pub struct RTDMessageVideoChatStartedBuilder {
 impl RTDMessageVideoChatStartedBuilder {
   pub fn build(&self) -> MessageVideoChatStarted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
@@ -4065,6 +5031,12 @@ impl AsRef<MessageVideoChatStarted> for RTDMessageVideoChatStartedBuilder {
   fn as_ref(&self) -> &MessageVideoChatStarted { &self.inner }
 }
 
+impl PartialEq for MessageVideoChatStarted {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call_id == other.group_call_id
+  }
+}
+
 
 
 
@@ -4079,7 +5051,10 @@ pub struct MessageVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The video note description
   video_note: VideoNote,
   /// True, if at least one of the recipients has viewed the video note
@@ -4090,8 +5065,9 @@ pub struct MessageVideoNote {
 }
 
 impl RObject for MessageVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4101,14 +5077,15 @@ impl TDMessageContent for MessageVideoNote {}
 
 
 impl MessageVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageVideoNoteBuilder {
-    let mut inner = MessageVideoNote::default();
-    inner.td_name = "messageVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageVideoNote { td_name: "messageVideoNote".to_string(), ..Default::default() };
     RTDMessageVideoNoteBuilder { inner }
   }
 
+  /// `MessageVideoNote` with the fields tdlib requires, the others are set by `MessageVideoNote::builder()`
+  pub fn new(video_note: impl AsRef<VideoNote>, is_viewed: bool, is_secret: bool) -> Self { Self::builder().video_note(video_note).is_viewed(is_viewed).is_secret(is_secret).build() }
+
   pub fn video_note(&self) -> &VideoNote { &self.video_note }
 
   pub fn is_viewed(&self) -> bool { self.is_viewed }
@@ -4125,6 +5102,12 @@ pub struct RTDMessageVideoNoteBuilder {
 impl RTDMessageVideoNoteBuilder {
   pub fn build(&self) -> MessageVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = video_note.as_ref().clone();
@@ -4153,6 +5136,14 @@ impl AsRef<MessageVideoNote> for RTDMessageVideoNoteBuilder {
   fn as_ref(&self) -> &MessageVideoNote { &self.inner }
 }
 
+impl PartialEq for MessageVideoNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.video_note == other.video_note
+      && self.is_viewed == other.is_viewed
+      && self.is_secret == other.is_secret
+  }
+}
+
 
 
 
@@ -4167,7 +5158,10 @@ pub struct MessageVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The voice note description
   voice_note: VoiceNote,
   /// Voice note caption
@@ -4178,8 +5172,9 @@ pub struct MessageVoiceNote {
 }
 
 impl RObject for MessageVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4189,14 +5184,15 @@ impl TDMessageContent for MessageVoiceNote {}
 
 
 impl MessageVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageVoiceNoteBuilder {
-    let mut inner = MessageVoiceNote::default();
-    inner.td_name = "messageVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageVoiceNote { td_name: "messageVoiceNote".to_string(), ..Default::default() };
     RTDMessageVoiceNoteBuilder { inner }
   }
 
+  /// `MessageVoiceNote` with the fields tdlib requires, the others are set by `MessageVoiceNote::builder()`
+  pub fn new(voice_note: impl AsRef<VoiceNote>, caption: impl AsRef<FormattedText>, is_listened: bool) -> Self { Self::builder().voice_note(voice_note).caption(caption).is_listened(is_listened).build() }
+
   pub fn voice_note(&self) -> &VoiceNote { &self.voice_note }
 
   pub fn caption(&self) -> &FormattedText { &self.caption }
@@ -4213,6 +5209,12 @@ pub struct RTDMessageVoiceNoteBuilder {
 impl RTDMessageVoiceNoteBuilder {
   pub fn build(&self) -> MessageVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = voice_note.as_ref().clone();
@@ -4241,6 +5243,14 @@ impl AsRef<MessageVoiceNote> for RTDMessageVoiceNoteBuilder {
   fn as_ref(&self) -> &MessageVoiceNote { &self.inner }
 }
 
+impl PartialEq for MessageVoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.voice_note == other.voice_note
+      && self.caption == other.caption
+      && self.is_listened == other.is_listened
+  }
+}
+
 
 
 
@@ -4255,15 +5265,19 @@ pub struct MessageWebsiteConnected {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Domain name of the connected website
   domain_name: String,
   
 }
 
 impl RObject for MessageWebsiteConnected {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageWebsiteConnected" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageWebsiteConnected" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4273,14 +5287,15 @@ impl TDMessageContent for MessageWebsiteConnected {}
 
 
 impl MessageWebsiteConnected {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageWebsiteConnectedBuilder {
-    let mut inner = MessageWebsiteConnected::default();
-    inner.td_name = "messageWebsiteConnected".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageWebsiteConnected { td_name: "messageWebsiteConnected".to_string(), ..Default::default() };
     RTDMessageWebsiteConnectedBuilder { inner }
   }
 
+  /// `MessageWebsiteConnected` with the fields tdlib requires, the others are set by `MessageWebsiteConnected::builder()`
+  pub fn new(domain_name: impl AsRef<str>) -> Self { Self::builder().domain_name(domain_name).build() }
+
   pub fn domain_name(&self) -> &String { &self.domain_name }
 
 }
@@ -4293,6 +5308,12 @@ pub struct RTDMessageWebsiteConnectedBuilder {
 impl RTDMessageWebsiteConnectedBuilder {
   pub fn build(&self) -> MessageWebsiteConnected { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn domain_name<T: AsRef<str>>(&mut self, domain_name: T) -> &mut Self {
     self.inner.domain_name = domain_name.as_ref().to_string();
@@ -4309,5 +5330,11 @@ impl AsRef<MessageWebsiteConnected> for RTDMessageWebsiteConnectedBuilder {
   fn as_ref(&self) -> &MessageWebsiteConnected { &self.inner }
 }
 
+impl PartialEq for MessageWebsiteConnected {
+  fn eq(&self, other: &Self) -> bool {
+    self.domain_name == other.domain_name
+  }
+}
+
 
 
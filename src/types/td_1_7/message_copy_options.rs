@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MessageCopyOptions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if content of the message needs to be copied without reference to the original sender. Always true if the message is forwarded to a secret chat or is local
   send_copy: bool,
   /// True, if media caption of the message copy needs to be replaced. Ignored if send_copy is false
@@ -25,22 +27,24 @@ pub struct MessageCopyOptions {
 }
 
 impl RObject for MessageCopyOptions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageCopyOptions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageCopyOptions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageCopyOptions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageCopyOptionsBuilder {
-    let mut inner = MessageCopyOptions::default();
-    inner.td_name = "messageCopyOptions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageCopyOptions { td_name: "messageCopyOptions".to_string(), ..Default::default() };
     RTDMessageCopyOptionsBuilder { inner }
   }
 
+  /// `MessageCopyOptions` with the fields tdlib requires, the others are set by `MessageCopyOptions::builder()`
+  pub fn new(send_copy: bool, replace_caption: bool) -> Self { Self::builder().send_copy(send_copy).replace_caption(replace_caption).build() }
+
   pub fn send_copy(&self) -> bool { self.send_copy }
 
   pub fn replace_caption(&self) -> bool { self.replace_caption }
@@ -57,6 +61,12 @@ pub struct RTDMessageCopyOptionsBuilder {
 impl RTDMessageCopyOptionsBuilder {
   pub fn build(&self) -> MessageCopyOptions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn send_copy(&mut self, send_copy: bool) -> &mut Self {
     self.inner.send_copy = send_copy;
@@ -85,5 +95,13 @@ impl AsRef<MessageCopyOptions> for RTDMessageCopyOptionsBuilder {
   fn as_ref(&self) -> &MessageCopyOptions { &self.inner }
 }
 
+impl PartialEq for MessageCopyOptions {
+  fn eq(&self, other: &Self) -> bool {
+    self.send_copy == other.send_copy
+      && self.replace_caption == other.replace_caption
+      && self.new_caption == other.new_caption
+  }
+}
+
 
 
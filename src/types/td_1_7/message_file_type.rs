@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDMessageFileType: Debug + RObject {}
 
 /// Contains information about a file with messages exported from another app
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MessageFileType {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum MessageFileType {
   Private(MessageFileTypePrivate),
   /// The messages was exported from a chat of unknown type
   Unknown(MessageFileTypeUnknown),
+  /// An object of a type this crate doesn't know, kept as received
+  UnknownType(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for MessageFileType {
   fn deserialize<D>(deserializer: D) -> Result<MessageFileType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      MessageFileType,
+      MessageFileType, UnknownType;
       (getMessageFileType, GetMessageFileType);
       (messageFileTypeGroup, Group);
       (messageFileTypePrivate, Private);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for MessageFileType {
 }
 
 impl RObject for MessageFileType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       MessageFileType::GetMessageFileType(t) => t.td_name(),
       MessageFileType::Group(t) => t.td_name(),
       MessageFileType::Private(t) => t.td_name(),
       MessageFileType::Unknown(t) => t.td_name(),
+      MessageFileType::UnknownType(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       MessageFileType::GetMessageFileType(t) => t.extra(),
       MessageFileType::Group(t) => t.extra(),
       MessageFileType::Private(t) => t.extra(),
       MessageFileType::Unknown(t) => t.extra(),
+      MessageFileType::UnknownType(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      MessageFileType::GetMessageFileType(t) => t.client_id(),
+      MessageFileType::Group(t) => t.client_id(),
+      MessageFileType::Private(t) => t.client_id(),
+      MessageFileType::Unknown(t) => t.client_id(),
+      MessageFileType::UnknownType(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for MessageFileType {
 }
 
 impl MessageFileType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageFileType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, MessageFileType::_Default(_)) }
 
-  pub fn is_get_message_file_type(&self) -> bool { if let MessageFileType::GetMessageFileType(_) = self { true } else { false } }
-  pub fn is_group(&self) -> bool { if let MessageFileType::Group(_) = self { true } else { false } }
-  pub fn is_private(&self) -> bool { if let MessageFileType::Private(_) = self { true } else { false } }
-  pub fn is_unknown(&self) -> bool { if let MessageFileType::Unknown(_) = self { true } else { false } }
+  pub fn is_get_message_file_type(&self) -> bool { matches!(self, MessageFileType::GetMessageFileType(_)) }
+  pub fn is_group(&self) -> bool { matches!(self, MessageFileType::Group(_)) }
+  pub fn is_private(&self) -> bool { matches!(self, MessageFileType::Private(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, MessageFileType::Unknown(_)) }
+  pub fn is_unknown_type(&self) -> bool { matches!(self, MessageFileType::UnknownType(_)) }
 
   pub fn on_get_message_file_type<F: FnOnce(&GetMessageFileType)>(&self, fnc: F) -> &Self { if let MessageFileType::GetMessageFileType(t) = self { fnc(t) }; self }
   pub fn on_group<F: FnOnce(&MessageFileTypeGroup)>(&self, fnc: F) -> &Self { if let MessageFileType::Group(t) = self { fnc(t) }; self }
   pub fn on_private<F: FnOnce(&MessageFileTypePrivate)>(&self, fnc: F) -> &Self { if let MessageFileType::Private(t) = self { fnc(t) }; self }
   pub fn on_unknown<F: FnOnce(&MessageFileTypeUnknown)>(&self, fnc: F) -> &Self { if let MessageFileType::Unknown(t) = self { fnc(t) }; self }
+  pub fn on_unknown_type<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let MessageFileType::UnknownType(t) = self { fnc(t) }; self }
 
   pub fn as_get_message_file_type(&self) -> Option<&GetMessageFileType> { if let MessageFileType::GetMessageFileType(t) = self { return Some(t) } None }
   pub fn as_group(&self) -> Option<&MessageFileTypeGroup> { if let MessageFileType::Group(t) = self { return Some(t) } None }
   pub fn as_private(&self) -> Option<&MessageFileTypePrivate> { if let MessageFileType::Private(t) = self { return Some(t) } None }
   pub fn as_unknown(&self) -> Option<&MessageFileTypeUnknown> { if let MessageFileType::Unknown(t) = self { return Some(t) } None }
+  pub fn as_unknown_type(&self) -> Option<&UnknownObject> { if let MessageFileType::UnknownType(t) = self { return Some(t) } None }
 
 
 
@@ -121,15 +138,19 @@ pub struct MessageFileTypeGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Title of the group chat; may be empty if unrecognized
   title: String,
   
 }
 
 impl RObject for MessageFileTypeGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageFileTypeGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageFileTypeGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,14 +160,15 @@ impl TDMessageFileType for MessageFileTypeGroup {}
 
 
 impl MessageFileTypeGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageFileTypeGroupBuilder {
-    let mut inner = MessageFileTypeGroup::default();
-    inner.td_name = "messageFileTypeGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageFileTypeGroup { td_name: "messageFileTypeGroup".to_string(), ..Default::default() };
     RTDMessageFileTypeGroupBuilder { inner }
   }
 
+  /// `MessageFileTypeGroup` with the fields tdlib requires, the others are set by `MessageFileTypeGroup::builder()`
+  pub fn new(title: impl AsRef<str>) -> Self { Self::builder().title(title).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
 }
@@ -159,6 +181,12 @@ pub struct RTDMessageFileTypeGroupBuilder {
 impl RTDMessageFileTypeGroupBuilder {
   pub fn build(&self) -> MessageFileTypeGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -175,6 +203,12 @@ impl AsRef<MessageFileTypeGroup> for RTDMessageFileTypeGroupBuilder {
   fn as_ref(&self) -> &MessageFileTypeGroup { &self.inner }
 }
 
+impl PartialEq for MessageFileTypeGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+  }
+}
+
 
 
 
@@ -189,15 +223,19 @@ pub struct MessageFileTypePrivate {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the other party; may be empty if unrecognized
   name: String,
   
 }
 
 impl RObject for MessageFileTypePrivate {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageFileTypePrivate" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageFileTypePrivate" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,14 +245,15 @@ impl TDMessageFileType for MessageFileTypePrivate {}
 
 
 impl MessageFileTypePrivate {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageFileTypePrivateBuilder {
-    let mut inner = MessageFileTypePrivate::default();
-    inner.td_name = "messageFileTypePrivate".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageFileTypePrivate { td_name: "messageFileTypePrivate".to_string(), ..Default::default() };
     RTDMessageFileTypePrivateBuilder { inner }
   }
 
+  /// `MessageFileTypePrivate` with the fields tdlib requires, the others are set by `MessageFileTypePrivate::builder()`
+  pub fn new(name: impl AsRef<str>) -> Self { Self::builder().name(name).build() }
+
   pub fn name(&self) -> &String { &self.name }
 
 }
@@ -227,6 +266,12 @@ pub struct RTDMessageFileTypePrivateBuilder {
 impl RTDMessageFileTypePrivateBuilder {
   pub fn build(&self) -> MessageFileTypePrivate { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -243,6 +288,12 @@ impl AsRef<MessageFileTypePrivate> for RTDMessageFileTypePrivateBuilder {
   fn as_ref(&self) -> &MessageFileTypePrivate { &self.inner }
 }
 
+impl PartialEq for MessageFileTypePrivate {
+  fn eq(&self, other: &Self) -> bool {
+    self.name == other.name
+  }
+}
+
 
 
 
@@ -257,13 +308,17 @@ pub struct MessageFileTypeUnknown {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageFileTypeUnknown {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageFileTypeUnknown" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageFileTypeUnknown" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,14 +328,15 @@ impl TDMessageFileType for MessageFileTypeUnknown {}
 
 
 impl MessageFileTypeUnknown {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageFileTypeUnknownBuilder {
-    let mut inner = MessageFileTypeUnknown::default();
-    inner.td_name = "messageFileTypeUnknown".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageFileTypeUnknown { td_name: "messageFileTypeUnknown".to_string(), ..Default::default() };
     RTDMessageFileTypeUnknownBuilder { inner }
   }
 
+  /// `MessageFileTypeUnknown` with the fields tdlib requires, the others are set by `MessageFileTypeUnknown::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +347,12 @@ pub struct RTDMessageFileTypeUnknownBuilder {
 impl RTDMessageFileTypeUnknownBuilder {
   pub fn build(&self) -> MessageFileTypeUnknown { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageFileTypeUnknown> for MessageFileTypeUnknown {
@@ -301,5 +363,9 @@ impl AsRef<MessageFileTypeUnknown> for RTDMessageFileTypeUnknownBuilder {
   fn as_ref(&self) -> &MessageFileTypeUnknown { &self.inner }
 }
 
+impl PartialEq for MessageFileTypeUnknown {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
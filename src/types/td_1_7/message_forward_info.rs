@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MessageForwardInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Origin of a forwarded message
   origin: MessageForwardOrigin,
   /// Point in time (Unix timestamp) when the message was originally sent
@@ -22,38 +24,54 @@ pub struct MessageForwardInfo {
   /// The type of a public service announcement for the forwarded message
   public_service_announcement_type: String,
   /// For messages forwarded to the chat with the current user (Saved Messages), to the Replies bot chat, or to the channel's discussion group, the identifier of the chat from which the message was forwarded last time; 0 if unknown
-  from_chat_id: i64,
+  from_chat_id: ChatId,
   /// For messages forwarded to the chat with the current user (Saved Messages), to the Replies bot chat, or to the channel's discussion group, the identifier of the original message from which the new message was forwarded last time; 0 if unknown
-  from_message_id: i64,
+  from_message_id: MessageId,
   
 }
 
 impl RObject for MessageForwardInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageForwardInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageForwardInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageForwardInfoBuilder {
-    let mut inner = MessageForwardInfo::default();
-    inner.td_name = "messageForwardInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageForwardInfo { td_name: "messageForwardInfo".to_string(), ..Default::default() };
     RTDMessageForwardInfoBuilder { inner }
   }
 
+  /// `MessageForwardInfo` with the fields tdlib requires, the others are set by `MessageForwardInfo::builder()`
+  pub fn new(
+    origin: impl AsRef<MessageForwardOrigin>,
+    date: i64,
+    public_service_announcement_type: impl AsRef<str>,
+    from_chat_id: ChatId,
+    from_message_id: MessageId,
+  ) -> Self {
+    Self::builder()
+      .origin(origin)
+      .date(date)
+      .public_service_announcement_type(public_service_announcement_type)
+      .from_chat_id(from_chat_id)
+      .from_message_id(from_message_id)
+      .build()
+  }
+
   pub fn origin(&self) -> &MessageForwardOrigin { &self.origin }
 
   pub fn date(&self) -> i64 { self.date }
 
   pub fn public_service_announcement_type(&self) -> &String { &self.public_service_announcement_type }
 
-  pub fn from_chat_id(&self) -> i64 { self.from_chat_id }
+  pub fn from_chat_id(&self) -> ChatId { self.from_chat_id }
 
-  pub fn from_message_id(&self) -> i64 { self.from_message_id }
+  pub fn from_message_id(&self) -> MessageId { self.from_message_id }
 
 }
 
@@ -65,6 +83,12 @@ pub struct RTDMessageForwardInfoBuilder {
 impl RTDMessageForwardInfoBuilder {
   pub fn build(&self) -> MessageForwardInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn origin<T: AsRef<MessageForwardOrigin>>(&mut self, origin: T) -> &mut Self {
     self.inner.origin = origin.as_ref().clone();
@@ -84,13 +108,13 @@ impl RTDMessageForwardInfoBuilder {
   }
 
    
-  pub fn from_chat_id(&mut self, from_chat_id: i64) -> &mut Self {
+  pub fn from_chat_id(&mut self, from_chat_id: ChatId) -> &mut Self {
     self.inner.from_chat_id = from_chat_id;
     self
   }
 
    
-  pub fn from_message_id(&mut self, from_message_id: i64) -> &mut Self {
+  pub fn from_message_id(&mut self, from_message_id: MessageId) -> &mut Self {
     self.inner.from_message_id = from_message_id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<MessageForwardInfo> for RTDMessageForwardInfoBuilder {
   fn as_ref(&self) -> &MessageForwardInfo { &self.inner }
 }
 
+impl PartialEq for MessageForwardInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.origin == other.origin
+      && self.date == other.date
+      && self.public_service_announcement_type == other.public_service_announcement_type
+      && self.from_chat_id == other.from_chat_id
+      && self.from_message_id == other.from_message_id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDMessageForwardOrigin: Debug + RObject {}
 
 /// Contains information about the origin of a forwarded message
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MessageForwardOrigin {
   #[doc(hidden)] _Default(()),
@@ -29,6 +28,8 @@ pub enum MessageForwardOrigin {
   MessageImport(MessageForwardOriginMessageImport),
   /// The message was originally sent by a known user
   User(MessageForwardOriginUser),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -40,7 +41,7 @@ impl<'de> Deserialize<'de> for MessageForwardOrigin {
   fn deserialize<D>(deserializer: D) -> Result<MessageForwardOrigin, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      MessageForwardOrigin,
+      MessageForwardOrigin, Unknown;
       (messageForwardOriginChannel, Channel);
       (messageForwardOriginChat, Chat);
       (messageForwardOriginHiddenUser, HiddenUser);
@@ -52,24 +53,38 @@ impl<'de> Deserialize<'de> for MessageForwardOrigin {
 }
 
 impl RObject for MessageForwardOrigin {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       MessageForwardOrigin::Channel(t) => t.td_name(),
       MessageForwardOrigin::Chat(t) => t.td_name(),
       MessageForwardOrigin::HiddenUser(t) => t.td_name(),
       MessageForwardOrigin::MessageImport(t) => t.td_name(),
       MessageForwardOrigin::User(t) => t.td_name(),
+      MessageForwardOrigin::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       MessageForwardOrigin::Channel(t) => t.extra(),
       MessageForwardOrigin::Chat(t) => t.extra(),
       MessageForwardOrigin::HiddenUser(t) => t.extra(),
       MessageForwardOrigin::MessageImport(t) => t.extra(),
       MessageForwardOrigin::User(t) => t.extra(),
+      MessageForwardOrigin::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      MessageForwardOrigin::Channel(t) => t.client_id(),
+      MessageForwardOrigin::Chat(t) => t.client_id(),
+      MessageForwardOrigin::HiddenUser(t) => t.client_id(),
+      MessageForwardOrigin::MessageImport(t) => t.client_id(),
+      MessageForwardOrigin::User(t) => t.client_id(),
+      MessageForwardOrigin::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -78,26 +93,29 @@ impl RObject for MessageForwardOrigin {
 }
 
 impl MessageForwardOrigin {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageForwardOrigin::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, MessageForwardOrigin::_Default(_)) }
 
-  pub fn is_channel(&self) -> bool { if let MessageForwardOrigin::Channel(_) = self { true } else { false } }
-  pub fn is_chat(&self) -> bool { if let MessageForwardOrigin::Chat(_) = self { true } else { false } }
-  pub fn is_hidden_user(&self) -> bool { if let MessageForwardOrigin::HiddenUser(_) = self { true } else { false } }
-  pub fn is_message_import(&self) -> bool { if let MessageForwardOrigin::MessageImport(_) = self { true } else { false } }
-  pub fn is_user(&self) -> bool { if let MessageForwardOrigin::User(_) = self { true } else { false } }
+  pub fn is_channel(&self) -> bool { matches!(self, MessageForwardOrigin::Channel(_)) }
+  pub fn is_chat(&self) -> bool { matches!(self, MessageForwardOrigin::Chat(_)) }
+  pub fn is_hidden_user(&self) -> bool { matches!(self, MessageForwardOrigin::HiddenUser(_)) }
+  pub fn is_message_import(&self) -> bool { matches!(self, MessageForwardOrigin::MessageImport(_)) }
+  pub fn is_user(&self) -> bool { matches!(self, MessageForwardOrigin::User(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, MessageForwardOrigin::Unknown(_)) }
 
   pub fn on_channel<F: FnOnce(&MessageForwardOriginChannel)>(&self, fnc: F) -> &Self { if let MessageForwardOrigin::Channel(t) = self { fnc(t) }; self }
   pub fn on_chat<F: FnOnce(&MessageForwardOriginChat)>(&self, fnc: F) -> &Self { if let MessageForwardOrigin::Chat(t) = self { fnc(t) }; self }
   pub fn on_hidden_user<F: FnOnce(&MessageForwardOriginHiddenUser)>(&self, fnc: F) -> &Self { if let MessageForwardOrigin::HiddenUser(t) = self { fnc(t) }; self }
   pub fn on_message_import<F: FnOnce(&MessageForwardOriginMessageImport)>(&self, fnc: F) -> &Self { if let MessageForwardOrigin::MessageImport(t) = self { fnc(t) }; self }
   pub fn on_user<F: FnOnce(&MessageForwardOriginUser)>(&self, fnc: F) -> &Self { if let MessageForwardOrigin::User(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let MessageForwardOrigin::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_channel(&self) -> Option<&MessageForwardOriginChannel> { if let MessageForwardOrigin::Channel(t) = self { return Some(t) } None }
   pub fn as_chat(&self) -> Option<&MessageForwardOriginChat> { if let MessageForwardOrigin::Chat(t) = self { return Some(t) } None }
   pub fn as_hidden_user(&self) -> Option<&MessageForwardOriginHiddenUser> { if let MessageForwardOrigin::HiddenUser(t) = self { return Some(t) } None }
   pub fn as_message_import(&self) -> Option<&MessageForwardOriginMessageImport> { if let MessageForwardOrigin::MessageImport(t) = self { return Some(t) } None }
   pub fn as_user(&self) -> Option<&MessageForwardOriginUser> { if let MessageForwardOrigin::User(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let MessageForwardOrigin::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -131,19 +149,23 @@ pub struct MessageForwardOriginChannel {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat from which the message was originally forwarded
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message identifier of the original message
-  message_id: i64,
+  message_id: MessageId,
   /// Original post author signature
   author_signature: String,
   
 }
 
 impl RObject for MessageForwardOriginChannel {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginChannel" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageForwardOriginChannel" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -153,17 +175,18 @@ impl TDMessageForwardOrigin for MessageForwardOriginChannel {}
 
 
 impl MessageForwardOriginChannel {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageForwardOriginChannelBuilder {
-    let mut inner = MessageForwardOriginChannel::default();
-    inner.td_name = "messageForwardOriginChannel".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageForwardOriginChannel { td_name: "messageForwardOriginChannel".to_string(), ..Default::default() };
     RTDMessageForwardOriginChannelBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `MessageForwardOriginChannel` with the fields tdlib requires, the others are set by `MessageForwardOriginChannel::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId, author_signature: impl AsRef<str>) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).author_signature(author_signature).build() }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn author_signature(&self) -> &String { &self.author_signature }
 
@@ -177,14 +200,20 @@ pub struct RTDMessageForwardOriginChannelBuilder {
 impl RTDMessageForwardOriginChannelBuilder {
   pub fn build(&self) -> MessageForwardOriginChannel { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -205,6 +234,14 @@ impl AsRef<MessageForwardOriginChannel> for RTDMessageForwardOriginChannelBuilde
   fn as_ref(&self) -> &MessageForwardOriginChannel { &self.inner }
 }
 
+impl PartialEq for MessageForwardOriginChannel {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.author_signature == other.author_signature
+  }
+}
+
 
 
 
@@ -219,17 +256,21 @@ pub struct MessageForwardOriginChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat that originally sent the message
-  sender_chat_id: i64,
+  sender_chat_id: ChatId,
   /// Original message author signature
   author_signature: String,
   
 }
 
 impl RObject for MessageForwardOriginChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageForwardOriginChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -239,15 +280,16 @@ impl TDMessageForwardOrigin for MessageForwardOriginChat {}
 
 
 impl MessageForwardOriginChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageForwardOriginChatBuilder {
-    let mut inner = MessageForwardOriginChat::default();
-    inner.td_name = "messageForwardOriginChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageForwardOriginChat { td_name: "messageForwardOriginChat".to_string(), ..Default::default() };
     RTDMessageForwardOriginChatBuilder { inner }
   }
 
-  pub fn sender_chat_id(&self) -> i64 { self.sender_chat_id }
+  /// `MessageForwardOriginChat` with the fields tdlib requires, the others are set by `MessageForwardOriginChat::builder()`
+  pub fn new(sender_chat_id: ChatId, author_signature: impl AsRef<str>) -> Self { Self::builder().sender_chat_id(sender_chat_id).author_signature(author_signature).build() }
+
+  pub fn sender_chat_id(&self) -> ChatId { self.sender_chat_id }
 
   pub fn author_signature(&self) -> &String { &self.author_signature }
 
@@ -261,8 +303,14 @@ pub struct RTDMessageForwardOriginChatBuilder {
 impl RTDMessageForwardOriginChatBuilder {
   pub fn build(&self) -> MessageForwardOriginChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn sender_chat_id(&mut self, sender_chat_id: i64) -> &mut Self {
+  pub fn sender_chat_id(&mut self, sender_chat_id: ChatId) -> &mut Self {
     self.inner.sender_chat_id = sender_chat_id;
     self
   }
@@ -283,6 +331,13 @@ impl AsRef<MessageForwardOriginChat> for RTDMessageForwardOriginChatBuilder {
   fn as_ref(&self) -> &MessageForwardOriginChat { &self.inner }
 }
 
+impl PartialEq for MessageForwardOriginChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.sender_chat_id == other.sender_chat_id
+      && self.author_signature == other.author_signature
+  }
+}
+
 
 
 
@@ -297,15 +352,19 @@ pub struct MessageForwardOriginHiddenUser {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the sender
   sender_name: String,
   
 }
 
 impl RObject for MessageForwardOriginHiddenUser {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginHiddenUser" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageForwardOriginHiddenUser" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -315,14 +374,15 @@ impl TDMessageForwardOrigin for MessageForwardOriginHiddenUser {}
 
 
 impl MessageForwardOriginHiddenUser {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageForwardOriginHiddenUserBuilder {
-    let mut inner = MessageForwardOriginHiddenUser::default();
-    inner.td_name = "messageForwardOriginHiddenUser".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageForwardOriginHiddenUser { td_name: "messageForwardOriginHiddenUser".to_string(), ..Default::default() };
     RTDMessageForwardOriginHiddenUserBuilder { inner }
   }
 
+  /// `MessageForwardOriginHiddenUser` with the fields tdlib requires, the others are set by `MessageForwardOriginHiddenUser::builder()`
+  pub fn new(sender_name: impl AsRef<str>) -> Self { Self::builder().sender_name(sender_name).build() }
+
   pub fn sender_name(&self) -> &String { &self.sender_name }
 
 }
@@ -335,6 +395,12 @@ pub struct RTDMessageForwardOriginHiddenUserBuilder {
 impl RTDMessageForwardOriginHiddenUserBuilder {
   pub fn build(&self) -> MessageForwardOriginHiddenUser { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> &mut Self {
     self.inner.sender_name = sender_name.as_ref().to_string();
@@ -351,6 +417,12 @@ impl AsRef<MessageForwardOriginHiddenUser> for RTDMessageForwardOriginHiddenUser
   fn as_ref(&self) -> &MessageForwardOriginHiddenUser { &self.inner }
 }
 
+impl PartialEq for MessageForwardOriginHiddenUser {
+  fn eq(&self, other: &Self) -> bool {
+    self.sender_name == other.sender_name
+  }
+}
+
 
 
 
@@ -365,15 +437,19 @@ pub struct MessageForwardOriginMessageImport {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the sender
   sender_name: String,
   
 }
 
 impl RObject for MessageForwardOriginMessageImport {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginMessageImport" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageForwardOriginMessageImport" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -383,14 +459,15 @@ impl TDMessageForwardOrigin for MessageForwardOriginMessageImport {}
 
 
 impl MessageForwardOriginMessageImport {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageForwardOriginMessageImportBuilder {
-    let mut inner = MessageForwardOriginMessageImport::default();
-    inner.td_name = "messageForwardOriginMessageImport".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageForwardOriginMessageImport { td_name: "messageForwardOriginMessageImport".to_string(), ..Default::default() };
     RTDMessageForwardOriginMessageImportBuilder { inner }
   }
 
+  /// `MessageForwardOriginMessageImport` with the fields tdlib requires, the others are set by `MessageForwardOriginMessageImport::builder()`
+  pub fn new(sender_name: impl AsRef<str>) -> Self { Self::builder().sender_name(sender_name).build() }
+
   pub fn sender_name(&self) -> &String { &self.sender_name }
 
 }
@@ -403,6 +480,12 @@ pub struct RTDMessageForwardOriginMessageImportBuilder {
 impl RTDMessageForwardOriginMessageImportBuilder {
   pub fn build(&self) -> MessageForwardOriginMessageImport { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> &mut Self {
     self.inner.sender_name = sender_name.as_ref().to_string();
@@ -419,6 +502,12 @@ impl AsRef<MessageForwardOriginMessageImport> for RTDMessageForwardOriginMessage
   fn as_ref(&self) -> &MessageForwardOriginMessageImport { &self.inner }
 }
 
+impl PartialEq for MessageForwardOriginMessageImport {
+  fn eq(&self, other: &Self) -> bool {
+    self.sender_name == other.sender_name
+  }
+}
+
 
 
 
@@ -433,15 +522,19 @@ pub struct MessageForwardOriginUser {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the user that originally sent the message
-  sender_user_id: i64,
+  sender_user_id: UserId,
   
 }
 
 impl RObject for MessageForwardOriginUser {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageForwardOriginUser" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageForwardOriginUser" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -451,15 +544,16 @@ impl TDMessageForwardOrigin for MessageForwardOriginUser {}
 
 
 impl MessageForwardOriginUser {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageForwardOriginUserBuilder {
-    let mut inner = MessageForwardOriginUser::default();
-    inner.td_name = "messageForwardOriginUser".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageForwardOriginUser { td_name: "messageForwardOriginUser".to_string(), ..Default::default() };
     RTDMessageForwardOriginUserBuilder { inner }
   }
 
-  pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
+  /// `MessageForwardOriginUser` with the fields tdlib requires, the others are set by `MessageForwardOriginUser::builder()`
+  pub fn new(sender_user_id: UserId) -> Self { Self::builder().sender_user_id(sender_user_id).build() }
+
+  pub fn sender_user_id(&self) -> UserId { self.sender_user_id }
 
 }
 
@@ -471,8 +565,14 @@ pub struct RTDMessageForwardOriginUserBuilder {
 impl RTDMessageForwardOriginUserBuilder {
   pub fn build(&self) -> MessageForwardOriginUser { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+  pub fn sender_user_id(&mut self, sender_user_id: UserId) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
     self
   }
@@ -487,5 +587,11 @@ impl AsRef<MessageForwardOriginUser> for RTDMessageForwardOriginUserBuilder {
   fn as_ref(&self) -> &MessageForwardOriginUser { &self.inner }
 }
 
+impl PartialEq for MessageForwardOriginUser {
+  fn eq(&self, other: &Self) -> bool {
+    self.sender_user_id == other.sender_user_id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MessageInteractionInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Number of times the message was viewed
   view_count: i64,
   /// Number of times the message was forwarded
@@ -25,22 +27,24 @@ pub struct MessageInteractionInfo {
 }
 
 impl RObject for MessageInteractionInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageInteractionInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageInteractionInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageInteractionInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageInteractionInfoBuilder {
-    let mut inner = MessageInteractionInfo::default();
-    inner.td_name = "messageInteractionInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageInteractionInfo { td_name: "messageInteractionInfo".to_string(), ..Default::default() };
     RTDMessageInteractionInfoBuilder { inner }
   }
 
+  /// `MessageInteractionInfo` with the fields tdlib requires, the others are set by `MessageInteractionInfo::builder()`
+  pub fn new(view_count: i64, forward_count: i64) -> Self { Self::builder().view_count(view_count).forward_count(forward_count).build() }
+
   pub fn view_count(&self) -> i64 { self.view_count }
 
   pub fn forward_count(&self) -> i64 { self.forward_count }
@@ -57,6 +61,12 @@ pub struct RTDMessageInteractionInfoBuilder {
 impl RTDMessageInteractionInfoBuilder {
   pub fn build(&self) -> MessageInteractionInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn view_count(&mut self, view_count: i64) -> &mut Self {
     self.inner.view_count = view_count;
@@ -85,5 +95,13 @@ impl AsRef<MessageInteractionInfo> for RTDMessageInteractionInfoBuilder {
   fn as_ref(&self) -> &MessageInteractionInfo { &self.inner }
 }
 
+impl PartialEq for MessageInteractionInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.view_count == other.view_count
+      && self.forward_count == other.forward_count
+      && self.reply_info == other.reply_info
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MessageLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message link
   link: String,
   /// True, if the link will work for non-members of the chat
@@ -23,22 +25,24 @@ pub struct MessageLink {
 }
 
 impl RObject for MessageLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageLinkBuilder {
-    let mut inner = MessageLink::default();
-    inner.td_name = "messageLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageLink { td_name: "messageLink".to_string(), ..Default::default() };
     RTDMessageLinkBuilder { inner }
   }
 
+  /// `MessageLink` with the fields tdlib requires, the others are set by `MessageLink::builder()`
+  pub fn new(link: impl AsRef<str>, is_public: bool) -> Self { Self::builder().link(link).is_public(is_public).build() }
+
   pub fn link(&self) -> &String { &self.link }
 
   pub fn is_public(&self) -> bool { self.is_public }
@@ -53,6 +57,12 @@ pub struct RTDMessageLinkBuilder {
 impl RTDMessageLinkBuilder {
   pub fn build(&self) -> MessageLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<MessageLink> for RTDMessageLinkBuilder {
   fn as_ref(&self) -> &MessageLink { &self.inner }
 }
 
+impl PartialEq for MessageLink {
+  fn eq(&self, other: &Self) -> bool {
+    self.link == other.link
+      && self.is_public == other.is_public
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,11 +13,14 @@ pub struct MessageLinkInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the link is a public link for a message in a chat
   is_public: bool,
   /// If found, identifier of the chat to which the message belongs, 0 otherwise
-  chat_id: i64,
+  chat_id: ChatId,
   /// If found, the linked message; may be null
   message: Option<Message>,
   /// Timestamp from which the video/audio/video note/voice note playing must start, in seconds; 0 if not specified. The media can be in the message content or in its web page preview
@@ -31,25 +33,41 @@ pub struct MessageLinkInfo {
 }
 
 impl RObject for MessageLinkInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageLinkInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageLinkInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageLinkInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageLinkInfoBuilder {
-    let mut inner = MessageLinkInfo::default();
-    inner.td_name = "messageLinkInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageLinkInfo { td_name: "messageLinkInfo".to_string(), ..Default::default() };
     RTDMessageLinkInfoBuilder { inner }
   }
 
+  /// `MessageLinkInfo` with the fields tdlib requires, the others are set by `MessageLinkInfo::builder()`
+  pub fn new(
+    is_public: bool,
+    chat_id: ChatId,
+    media_timestamp: i64,
+    for_album: bool,
+    for_comment: bool,
+  ) -> Self {
+    Self::builder()
+      .is_public(is_public)
+      .chat_id(chat_id)
+      .media_timestamp(media_timestamp)
+      .for_album(for_album)
+      .for_comment(for_comment)
+      .build()
+  }
+
   pub fn is_public(&self) -> bool { self.is_public }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn message(&self) -> &Option<Message> { &self.message }
 
@@ -69,6 +87,12 @@ pub struct RTDMessageLinkInfoBuilder {
 impl RTDMessageLinkInfoBuilder {
   pub fn build(&self) -> MessageLinkInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_public(&mut self, is_public: bool) -> &mut Self {
     self.inner.is_public = is_public;
@@ -76,7 +100,7 @@ impl RTDMessageLinkInfoBuilder {
   }
 
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -115,5 +139,16 @@ impl AsRef<MessageLinkInfo> for RTDMessageLinkInfoBuilder {
   fn as_ref(&self) -> &MessageLinkInfo { &self.inner }
 }
 
+impl PartialEq for MessageLinkInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_public == other.is_public
+      && self.chat_id == other.chat_id
+      && self.message == other.message
+      && self.media_timestamp == other.media_timestamp
+      && self.for_album == other.for_album
+      && self.for_comment == other.for_comment
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,36 +13,41 @@ pub struct MessagePosition {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// 0-based message position in the full list of suitable messages
   position: i64,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// Point in time (Unix timestamp) when the message was sent
   date: i64,
   
 }
 
 impl RObject for MessagePosition {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messagePosition" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messagePosition" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessagePosition {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessagePositionBuilder {
-    let mut inner = MessagePosition::default();
-    inner.td_name = "messagePosition".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessagePosition { td_name: "messagePosition".to_string(), ..Default::default() };
     RTDMessagePositionBuilder { inner }
   }
 
+  /// `MessagePosition` with the fields tdlib requires, the others are set by `MessagePosition::builder()`
+  pub fn new(position: i64, message_id: MessageId, date: i64) -> Self { Self::builder().position(position).message_id(message_id).date(date).build() }
+
   pub fn position(&self) -> i64 { self.position }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn date(&self) -> i64 { self.date }
 
@@ -57,6 +61,12 @@ pub struct RTDMessagePositionBuilder {
 impl RTDMessagePositionBuilder {
   pub fn build(&self) -> MessagePosition { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn position(&mut self, position: i64) -> &mut Self {
     self.inner.position = position;
@@ -64,7 +74,7 @@ impl RTDMessagePositionBuilder {
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -85,5 +95,13 @@ impl AsRef<MessagePosition> for RTDMessagePositionBuilder {
   fn as_ref(&self) -> &MessagePosition { &self.inner }
 }
 
+impl PartialEq for MessagePosition {
+  fn eq(&self, other: &Self) -> bool {
+    self.position == other.position
+      && self.message_id == other.message_id
+      && self.date == other.date
+  }
+}
+
 
 
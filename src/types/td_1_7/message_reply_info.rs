@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,46 +13,65 @@ pub struct MessageReplyInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Number of times the message was directly or indirectly replied
   reply_count: i64,
   /// Recent repliers to the message; available in channels with a discussion supergroup
   recent_repliers: Vec<MessageSender>,
   /// Identifier of the last read incoming reply to the message
-  last_read_inbox_message_id: i64,
+  last_read_inbox_message_id: MessageId,
   /// Identifier of the last read outgoing reply to the message
-  last_read_outbox_message_id: i64,
+  last_read_outbox_message_id: MessageId,
   /// Identifier of the last reply to the message
-  last_message_id: i64,
+  last_message_id: MessageId,
   
 }
 
 impl RObject for MessageReplyInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageReplyInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageReplyInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageReplyInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageReplyInfoBuilder {
-    let mut inner = MessageReplyInfo::default();
-    inner.td_name = "messageReplyInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageReplyInfo { td_name: "messageReplyInfo".to_string(), ..Default::default() };
     RTDMessageReplyInfoBuilder { inner }
   }
 
+  /// `MessageReplyInfo` with the fields tdlib requires, the others are set by `MessageReplyInfo::builder()`
+  pub fn new(
+    reply_count: i64,
+    recent_repliers: Vec<MessageSender>,
+    last_read_inbox_message_id: MessageId,
+    last_read_outbox_message_id: MessageId,
+    last_message_id: MessageId,
+  ) -> Self {
+    Self::builder()
+      .reply_count(reply_count)
+      .recent_repliers(recent_repliers)
+      .last_read_inbox_message_id(last_read_inbox_message_id)
+      .last_read_outbox_message_id(last_read_outbox_message_id)
+      .last_message_id(last_message_id)
+      .build()
+  }
+
   pub fn reply_count(&self) -> i64 { self.reply_count }
 
   pub fn recent_repliers(&self) -> &Vec<MessageSender> { &self.recent_repliers }
 
-  pub fn last_read_inbox_message_id(&self) -> i64 { self.last_read_inbox_message_id }
+  pub fn last_read_inbox_message_id(&self) -> MessageId { self.last_read_inbox_message_id }
 
-  pub fn last_read_outbox_message_id(&self) -> i64 { self.last_read_outbox_message_id }
+  pub fn last_read_outbox_message_id(&self) -> MessageId { self.last_read_outbox_message_id }
 
-  pub fn last_message_id(&self) -> i64 { self.last_message_id }
+  pub fn last_message_id(&self) -> MessageId { self.last_message_id }
 
 }
 
@@ -65,6 +83,12 @@ pub struct RTDMessageReplyInfoBuilder {
 impl RTDMessageReplyInfoBuilder {
   pub fn build(&self) -> MessageReplyInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn reply_count(&mut self, reply_count: i64) -> &mut Self {
     self.inner.reply_count = reply_count;
@@ -78,19 +102,19 @@ impl RTDMessageReplyInfoBuilder {
   }
 
    
-  pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
+  pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: MessageId) -> &mut Self {
     self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
     self
   }
 
    
-  pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
+  pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: MessageId) -> &mut Self {
     self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
     self
   }
 
    
-  pub fn last_message_id(&mut self, last_message_id: i64) -> &mut Self {
+  pub fn last_message_id(&mut self, last_message_id: MessageId) -> &mut Self {
     self.inner.last_message_id = last_message_id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<MessageReplyInfo> for RTDMessageReplyInfoBuilder {
   fn as_ref(&self) -> &MessageReplyInfo { &self.inner }
 }
 
+impl PartialEq for MessageReplyInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.reply_count == other.reply_count
+      && self.recent_repliers == other.recent_repliers
+      && self.last_read_inbox_message_id == other.last_read_inbox_message_id
+      && self.last_read_outbox_message_id == other.last_read_outbox_message_id
+      && self.last_message_id == other.last_message_id
+  }
+}
+
 
 
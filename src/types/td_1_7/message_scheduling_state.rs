@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDMessageSchedulingState: Debug + RObject {}
 
 /// Contains information about the time when a scheduled message will be sent
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MessageSchedulingState {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum MessageSchedulingState {
   SendAtDate(MessageSchedulingStateSendAtDate),
   /// The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known
   SendWhenOnline(MessageSchedulingStateSendWhenOnline),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for MessageSchedulingState {
   fn deserialize<D>(deserializer: D) -> Result<MessageSchedulingState, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      MessageSchedulingState,
+      MessageSchedulingState, Unknown;
       (messageSchedulingStateSendAtDate, SendAtDate);
       (messageSchedulingStateSendWhenOnline, SendWhenOnline);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for MessageSchedulingState {
 }
 
 impl RObject for MessageSchedulingState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       MessageSchedulingState::SendAtDate(t) => t.td_name(),
       MessageSchedulingState::SendWhenOnline(t) => t.td_name(),
+      MessageSchedulingState::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       MessageSchedulingState::SendAtDate(t) => t.extra(),
       MessageSchedulingState::SendWhenOnline(t) => t.extra(),
+      MessageSchedulingState::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      MessageSchedulingState::SendAtDate(t) => t.client_id(),
+      MessageSchedulingState::SendWhenOnline(t) => t.client_id(),
+      MessageSchedulingState::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for MessageSchedulingState {
 }
 
 impl MessageSchedulingState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSchedulingState::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, MessageSchedulingState::_Default(_)) }
 
-  pub fn is_send_at_date(&self) -> bool { if let MessageSchedulingState::SendAtDate(_) = self { true } else { false } }
-  pub fn is_send_when_online(&self) -> bool { if let MessageSchedulingState::SendWhenOnline(_) = self { true } else { false } }
+  pub fn is_send_at_date(&self) -> bool { matches!(self, MessageSchedulingState::SendAtDate(_)) }
+  pub fn is_send_when_online(&self) -> bool { matches!(self, MessageSchedulingState::SendWhenOnline(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, MessageSchedulingState::Unknown(_)) }
 
   pub fn on_send_at_date<F: FnOnce(&MessageSchedulingStateSendAtDate)>(&self, fnc: F) -> &Self { if let MessageSchedulingState::SendAtDate(t) = self { fnc(t) }; self }
   pub fn on_send_when_online<F: FnOnce(&MessageSchedulingStateSendWhenOnline)>(&self, fnc: F) -> &Self { if let MessageSchedulingState::SendWhenOnline(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let MessageSchedulingState::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_send_at_date(&self) -> Option<&MessageSchedulingStateSendAtDate> { if let MessageSchedulingState::SendAtDate(t) = self { return Some(t) } None }
   pub fn as_send_when_online(&self) -> Option<&MessageSchedulingStateSendWhenOnline> { if let MessageSchedulingState::SendWhenOnline(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let MessageSchedulingState::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,15 +116,19 @@ pub struct MessageSchedulingStateSendAtDate {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Date the message will be sent. The date must be within 367 days in the future
   send_date: i64,
   
 }
 
 impl RObject for MessageSchedulingStateSendAtDate {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSchedulingStateSendAtDate" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSchedulingStateSendAtDate" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -119,14 +138,15 @@ impl TDMessageSchedulingState for MessageSchedulingStateSendAtDate {}
 
 
 impl MessageSchedulingStateSendAtDate {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSchedulingStateSendAtDateBuilder {
-    let mut inner = MessageSchedulingStateSendAtDate::default();
-    inner.td_name = "messageSchedulingStateSendAtDate".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSchedulingStateSendAtDate { td_name: "messageSchedulingStateSendAtDate".to_string(), ..Default::default() };
     RTDMessageSchedulingStateSendAtDateBuilder { inner }
   }
 
+  /// `MessageSchedulingStateSendAtDate` with the fields tdlib requires, the others are set by `MessageSchedulingStateSendAtDate::builder()`
+  pub fn new(send_date: i64) -> Self { Self::builder().send_date(send_date).build() }
+
   pub fn send_date(&self) -> i64 { self.send_date }
 
 }
@@ -139,6 +159,12 @@ pub struct RTDMessageSchedulingStateSendAtDateBuilder {
 impl RTDMessageSchedulingStateSendAtDateBuilder {
   pub fn build(&self) -> MessageSchedulingStateSendAtDate { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn send_date(&mut self, send_date: i64) -> &mut Self {
     self.inner.send_date = send_date;
@@ -155,6 +181,12 @@ impl AsRef<MessageSchedulingStateSendAtDate> for RTDMessageSchedulingStateSendAt
   fn as_ref(&self) -> &MessageSchedulingStateSendAtDate { &self.inner }
 }
 
+impl PartialEq for MessageSchedulingStateSendAtDate {
+  fn eq(&self, other: &Self) -> bool {
+    self.send_date == other.send_date
+  }
+}
+
 
 
 
@@ -169,13 +201,17 @@ pub struct MessageSchedulingStateSendWhenOnline {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageSchedulingStateSendWhenOnline {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSchedulingStateSendWhenOnline" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSchedulingStateSendWhenOnline" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -185,14 +221,15 @@ impl TDMessageSchedulingState for MessageSchedulingStateSendWhenOnline {}
 
 
 impl MessageSchedulingStateSendWhenOnline {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSchedulingStateSendWhenOnlineBuilder {
-    let mut inner = MessageSchedulingStateSendWhenOnline::default();
-    inner.td_name = "messageSchedulingStateSendWhenOnline".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSchedulingStateSendWhenOnline { td_name: "messageSchedulingStateSendWhenOnline".to_string(), ..Default::default() };
     RTDMessageSchedulingStateSendWhenOnlineBuilder { inner }
   }
 
+  /// `MessageSchedulingStateSendWhenOnline` with the fields tdlib requires, the others are set by `MessageSchedulingStateSendWhenOnline::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +240,12 @@ pub struct RTDMessageSchedulingStateSendWhenOnlineBuilder {
 impl RTDMessageSchedulingStateSendWhenOnlineBuilder {
   pub fn build(&self) -> MessageSchedulingStateSendWhenOnline { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageSchedulingStateSendWhenOnline> for MessageSchedulingStateSendWhenOnline {
@@ -213,5 +256,9 @@ impl AsRef<MessageSchedulingStateSendWhenOnline> for RTDMessageSchedulingStateSe
   fn as_ref(&self) -> &MessageSchedulingStateSendWhenOnline { &self.inner }
 }
 
+impl PartialEq for MessageSchedulingStateSendWhenOnline {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
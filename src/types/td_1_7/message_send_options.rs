@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MessageSendOptions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Pass true to disable notification for the message
   disable_notification: bool,
   /// Pass true if the message is sent from the background
@@ -25,22 +27,24 @@ pub struct MessageSendOptions {
 }
 
 impl RObject for MessageSendOptions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSendOptions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSendOptions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageSendOptions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSendOptionsBuilder {
-    let mut inner = MessageSendOptions::default();
-    inner.td_name = "messageSendOptions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSendOptions { td_name: "messageSendOptions".to_string(), ..Default::default() };
     RTDMessageSendOptionsBuilder { inner }
   }
 
+  /// `MessageSendOptions` with the fields tdlib requires, the others are set by `MessageSendOptions::builder()`
+  pub fn new(disable_notification: bool, from_background: bool) -> Self { Self::builder().disable_notification(disable_notification).from_background(from_background).build() }
+
   pub fn disable_notification(&self) -> bool { self.disable_notification }
 
   pub fn from_background(&self) -> bool { self.from_background }
@@ -57,6 +61,12 @@ pub struct RTDMessageSendOptionsBuilder {
 impl RTDMessageSendOptionsBuilder {
   pub fn build(&self) -> MessageSendOptions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn disable_notification(&mut self, disable_notification: bool) -> &mut Self {
     self.inner.disable_notification = disable_notification;
@@ -85,5 +95,13 @@ impl AsRef<MessageSendOptions> for RTDMessageSendOptionsBuilder {
   fn as_ref(&self) -> &MessageSendOptions { &self.inner }
 }
 
+impl PartialEq for MessageSendOptions {
+  fn eq(&self, other: &Self) -> bool {
+    self.disable_notification == other.disable_notification
+      && self.from_background == other.from_background
+      && self.scheduling_state == other.scheduling_state
+  }
+}
+
 
 
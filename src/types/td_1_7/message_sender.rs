@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDMessageSender: Debug + RObject {}
 
 /// Contains information about the sender of a message
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MessageSender {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum MessageSender {
   Chat(MessageSenderChat),
   /// The message was sent by a known user
   User(MessageSenderUser),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for MessageSender {
   fn deserialize<D>(deserializer: D) -> Result<MessageSender, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      MessageSender,
+      MessageSender, Unknown;
       (messageSenderChat, Chat);
       (messageSenderUser, User);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for MessageSender {
 }
 
 impl RObject for MessageSender {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       MessageSender::Chat(t) => t.td_name(),
       MessageSender::User(t) => t.td_name(),
+      MessageSender::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       MessageSender::Chat(t) => t.extra(),
       MessageSender::User(t) => t.extra(),
+      MessageSender::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      MessageSender::Chat(t) => t.client_id(),
+      MessageSender::User(t) => t.client_id(),
+      MessageSender::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for MessageSender {
 }
 
 impl MessageSender {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSender::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, MessageSender::_Default(_)) }
 
-  pub fn is_chat(&self) -> bool { if let MessageSender::Chat(_) = self { true } else { false } }
-  pub fn is_user(&self) -> bool { if let MessageSender::User(_) = self { true } else { false } }
+  pub fn is_chat(&self) -> bool { matches!(self, MessageSender::Chat(_)) }
+  pub fn is_user(&self) -> bool { matches!(self, MessageSender::User(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, MessageSender::Unknown(_)) }
 
   pub fn on_chat<F: FnOnce(&MessageSenderChat)>(&self, fnc: F) -> &Self { if let MessageSender::Chat(t) = self { fnc(t) }; self }
   pub fn on_user<F: FnOnce(&MessageSenderUser)>(&self, fnc: F) -> &Self { if let MessageSender::User(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let MessageSender::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_chat(&self) -> Option<&MessageSenderChat> { if let MessageSender::Chat(t) = self { return Some(t) } None }
   pub fn as_user(&self) -> Option<&MessageSenderUser> { if let MessageSender::User(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let MessageSender::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,15 +116,19 @@ pub struct MessageSenderChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat that sent the message
-  chat_id: i64,
+  chat_id: ChatId,
   
 }
 
 impl RObject for MessageSenderChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSenderChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSenderChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -119,15 +138,16 @@ impl TDMessageSender for MessageSenderChat {}
 
 
 impl MessageSenderChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSenderChatBuilder {
-    let mut inner = MessageSenderChat::default();
-    inner.td_name = "messageSenderChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSenderChat { td_name: "messageSenderChat".to_string(), ..Default::default() };
     RTDMessageSenderChatBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `MessageSenderChat` with the fields tdlib requires, the others are set by `MessageSenderChat::builder()`
+  pub fn new(chat_id: ChatId) -> Self { Self::builder().chat_id(chat_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
 }
 
@@ -139,8 +159,14 @@ pub struct RTDMessageSenderChatBuilder {
 impl RTDMessageSenderChatBuilder {
   pub fn build(&self) -> MessageSenderChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -155,6 +181,12 @@ impl AsRef<MessageSenderChat> for RTDMessageSenderChatBuilder {
   fn as_ref(&self) -> &MessageSenderChat { &self.inner }
 }
 
+impl PartialEq for MessageSenderChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+  }
+}
+
 
 
 
@@ -169,15 +201,19 @@ pub struct MessageSenderUser {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the user that sent the message
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for MessageSenderUser {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSenderUser" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSenderUser" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -187,15 +223,16 @@ impl TDMessageSender for MessageSenderUser {}
 
 
 impl MessageSenderUser {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSenderUserBuilder {
-    let mut inner = MessageSenderUser::default();
-    inner.td_name = "messageSenderUser".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSenderUser { td_name: "messageSenderUser".to_string(), ..Default::default() };
     RTDMessageSenderUserBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `MessageSenderUser` with the fields tdlib requires, the others are set by `MessageSenderUser::builder()`
+  pub fn new(user_id: UserId) -> Self { Self::builder().user_id(user_id).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -207,8 +244,14 @@ pub struct RTDMessageSenderUserBuilder {
 impl RTDMessageSenderUserBuilder {
   pub fn build(&self) -> MessageSenderUser { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -223,5 +266,11 @@ impl AsRef<MessageSenderUser> for RTDMessageSenderUserBuilder {
   fn as_ref(&self) -> &MessageSenderUser { &self.inner }
 }
 
+impl PartialEq for MessageSenderUser {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+  }
+}
+
 
 
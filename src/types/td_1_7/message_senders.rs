@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct MessageSenders {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Approximate total count of messages senders found
   total_count: i64,
   /// List of message senders
@@ -23,22 +25,24 @@ pub struct MessageSenders {
 }
 
 impl RObject for MessageSenders {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSenders" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSenders" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageSenders {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSendersBuilder {
-    let mut inner = MessageSenders::default();
-    inner.td_name = "messageSenders".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSenders { td_name: "messageSenders".to_string(), ..Default::default() };
     RTDMessageSendersBuilder { inner }
   }
 
+  /// `MessageSenders` with the fields tdlib requires, the others are set by `MessageSenders::builder()`
+  pub fn new(total_count: i64, senders: Vec<MessageSender>) -> Self { Self::builder().total_count(total_count).senders(senders).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
   pub fn senders(&self) -> &Vec<MessageSender> { &self.senders }
@@ -53,6 +57,12 @@ pub struct RTDMessageSendersBuilder {
 impl RTDMessageSendersBuilder {
   pub fn build(&self) -> MessageSenders { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -75,5 +85,12 @@ impl AsRef<MessageSenders> for RTDMessageSendersBuilder {
   fn as_ref(&self) -> &MessageSenders { &self.inner }
 }
 
+impl PartialEq for MessageSenders {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.senders == other.senders
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDMessageSendingState: Debug + RObject {}
 
 /// Contains information about the sending state of the message
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum MessageSendingState {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum MessageSendingState {
   Failed(MessageSendingStateFailed),
   /// The message is being sent now, but has not yet been delivered to the server
   Pending(MessageSendingStatePending),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for MessageSendingState {
   fn deserialize<D>(deserializer: D) -> Result<MessageSendingState, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      MessageSendingState,
+      MessageSendingState, Unknown;
       (messageSendingStateFailed, Failed);
       (messageSendingStatePending, Pending);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for MessageSendingState {
 }
 
 impl RObject for MessageSendingState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       MessageSendingState::Failed(t) => t.td_name(),
       MessageSendingState::Pending(t) => t.td_name(),
+      MessageSendingState::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       MessageSendingState::Failed(t) => t.extra(),
       MessageSendingState::Pending(t) => t.extra(),
+      MessageSendingState::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      MessageSendingState::Failed(t) => t.client_id(),
+      MessageSendingState::Pending(t) => t.client_id(),
+      MessageSendingState::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for MessageSendingState {
 }
 
 impl MessageSendingState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSendingState::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, MessageSendingState::_Default(_)) }
 
-  pub fn is_failed(&self) -> bool { if let MessageSendingState::Failed(_) = self { true } else { false } }
-  pub fn is_pending(&self) -> bool { if let MessageSendingState::Pending(_) = self { true } else { false } }
+  pub fn is_failed(&self) -> bool { matches!(self, MessageSendingState::Failed(_)) }
+  pub fn is_pending(&self) -> bool { matches!(self, MessageSendingState::Pending(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, MessageSendingState::Unknown(_)) }
 
   pub fn on_failed<F: FnOnce(&MessageSendingStateFailed)>(&self, fnc: F) -> &Self { if let MessageSendingState::Failed(t) = self { fnc(t) }; self }
   pub fn on_pending<F: FnOnce(&MessageSendingStatePending)>(&self, fnc: F) -> &Self { if let MessageSendingState::Pending(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let MessageSendingState::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_failed(&self) -> Option<&MessageSendingStateFailed> { if let MessageSendingState::Failed(t) = self { return Some(t) } None }
   pub fn as_pending(&self) -> Option<&MessageSendingStatePending> { if let MessageSendingState::Pending(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let MessageSendingState::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,7 +116,10 @@ pub struct MessageSendingStateFailed {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// An error code; 0 if unknown
   error_code: i64,
   /// Error message
@@ -114,8 +132,9 @@ pub struct MessageSendingStateFailed {
 }
 
 impl RObject for MessageSendingStateFailed {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSendingStateFailed" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSendingStateFailed" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -125,14 +144,27 @@ impl TDMessageSendingState for MessageSendingStateFailed {}
 
 
 impl MessageSendingStateFailed {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSendingStateFailedBuilder {
-    let mut inner = MessageSendingStateFailed::default();
-    inner.td_name = "messageSendingStateFailed".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSendingStateFailed { td_name: "messageSendingStateFailed".to_string(), ..Default::default() };
     RTDMessageSendingStateFailedBuilder { inner }
   }
 
+  /// `MessageSendingStateFailed` with the fields tdlib requires, the others are set by `MessageSendingStateFailed::builder()`
+  pub fn new(
+    error_code: i64,
+    error_message: impl AsRef<str>,
+    can_retry: bool,
+    retry_after: f32,
+  ) -> Self {
+    Self::builder()
+      .error_code(error_code)
+      .error_message(error_message)
+      .can_retry(can_retry)
+      .retry_after(retry_after)
+      .build()
+  }
+
   pub fn error_code(&self) -> i64 { self.error_code }
 
   pub fn error_message(&self) -> &String { &self.error_message }
@@ -151,6 +183,12 @@ pub struct RTDMessageSendingStateFailedBuilder {
 impl RTDMessageSendingStateFailedBuilder {
   pub fn build(&self) -> MessageSendingStateFailed { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn error_code(&mut self, error_code: i64) -> &mut Self {
     self.inner.error_code = error_code;
@@ -185,6 +223,15 @@ impl AsRef<MessageSendingStateFailed> for RTDMessageSendingStateFailedBuilder {
   fn as_ref(&self) -> &MessageSendingStateFailed { &self.inner }
 }
 
+impl PartialEq for MessageSendingStateFailed {
+  fn eq(&self, other: &Self) -> bool {
+    self.error_code == other.error_code
+      && self.error_message == other.error_message
+      && self.can_retry == other.can_retry
+      && self.retry_after == other.retry_after
+  }
+}
+
 
 
 
@@ -199,13 +246,17 @@ pub struct MessageSendingStatePending {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for MessageSendingStatePending {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageSendingStatePending" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageSendingStatePending" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -215,14 +266,15 @@ impl TDMessageSendingState for MessageSendingStatePending {}
 
 
 impl MessageSendingStatePending {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageSendingStatePendingBuilder {
-    let mut inner = MessageSendingStatePending::default();
-    inner.td_name = "messageSendingStatePending".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageSendingStatePending { td_name: "messageSendingStatePending".to_string(), ..Default::default() };
     RTDMessageSendingStatePendingBuilder { inner }
   }
 
+  /// `MessageSendingStatePending` with the fields tdlib requires, the others are set by `MessageSendingStatePending::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +285,12 @@ pub struct RTDMessageSendingStatePendingBuilder {
 impl RTDMessageSendingStatePendingBuilder {
   pub fn build(&self) -> MessageSendingStatePending { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<MessageSendingStatePending> for MessageSendingStatePending {
@@ -243,5 +301,9 @@ impl AsRef<MessageSendingStatePending> for RTDMessageSendingStatePendingBuilder
   fn as_ref(&self) -> &MessageSendingStatePending { &self.inner }
 }
 
+impl PartialEq for MessageSendingStatePending {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
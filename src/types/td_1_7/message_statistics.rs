@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct MessageStatistics {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A graph containing number of message views and shares
   message_interaction_graph: StatisticalGraph,
   
 }
 
 impl RObject for MessageStatistics {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageStatistics" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageStatistics" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageStatistics {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageStatisticsBuilder {
-    let mut inner = MessageStatistics::default();
-    inner.td_name = "messageStatistics".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageStatistics { td_name: "messageStatistics".to_string(), ..Default::default() };
     RTDMessageStatisticsBuilder { inner }
   }
 
+  /// `MessageStatistics` with the fields tdlib requires, the others are set by `MessageStatistics::builder()`
+  pub fn new(message_interaction_graph: impl AsRef<StatisticalGraph>) -> Self { Self::builder().message_interaction_graph(message_interaction_graph).build() }
+
   pub fn message_interaction_graph(&self) -> &StatisticalGraph { &self.message_interaction_graph }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDMessageStatisticsBuilder {
 impl RTDMessageStatisticsBuilder {
   pub fn build(&self) -> MessageStatistics { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message_interaction_graph<T: AsRef<StatisticalGraph>>(&mut self, message_interaction_graph: T) -> &mut Self {
     self.inner.message_interaction_graph = message_interaction_graph.as_ref().clone();
@@ -65,5 +75,11 @@ impl AsRef<MessageStatistics> for RTDMessageStatisticsBuilder {
   fn as_ref(&self) -> &MessageStatistics { &self.inner }
 }
 
+impl PartialEq for MessageStatistics {
+  fn eq(&self, other: &Self) -> bool {
+    self.message_interaction_graph == other.message_interaction_graph
+  }
+}
+
 
 
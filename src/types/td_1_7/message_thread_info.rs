@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct MessageThreadInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat to which the message thread belongs
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message thread identifier, unique within the chat
   message_thread_id: i64,
   /// Information about the message thread
@@ -31,23 +33,39 @@ pub struct MessageThreadInfo {
 }
 
 impl RObject for MessageThreadInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "messageThreadInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "messageThreadInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl MessageThreadInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMessageThreadInfoBuilder {
-    let mut inner = MessageThreadInfo::default();
-    inner.td_name = "messageThreadInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = MessageThreadInfo { td_name: "messageThreadInfo".to_string(), ..Default::default() };
     RTDMessageThreadInfoBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `MessageThreadInfo` with the fields tdlib requires, the others are set by `MessageThreadInfo::builder()`
+  pub fn new(
+    chat_id: ChatId,
+    message_thread_id: i64,
+    reply_info: impl AsRef<MessageReplyInfo>,
+    unread_message_count: i64,
+    messages: Vec<Message>,
+  ) -> Self {
+    Self::builder()
+      .chat_id(chat_id)
+      .message_thread_id(message_thread_id)
+      .reply_info(reply_info)
+      .unread_message_count(unread_message_count)
+      .messages(messages)
+      .build()
+  }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn message_thread_id(&self) -> i64 { self.message_thread_id }
 
@@ -69,8 +87,14 @@ pub struct RTDMessageThreadInfoBuilder {
 impl RTDMessageThreadInfoBuilder {
   pub fn build(&self) -> MessageThreadInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -115,5 +139,16 @@ impl AsRef<MessageThreadInfo> for RTDMessageThreadInfoBuilder {
   fn as_ref(&self) -> &MessageThreadInfo { &self.inner }
 }
 
+impl PartialEq for MessageThreadInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_thread_id == other.message_thread_id
+      && self.reply_info == other.reply_info
+      && self.unread_message_count == other.unread_message_count
+      && self.messages == other.messages
+      && self.draft_message == other.draft_message
+  }
+}
+
 
 
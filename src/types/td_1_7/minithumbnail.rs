@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,38 +13,43 @@ pub struct Minithumbnail {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Thumbnail width, usually doesn't exceed 40
   width: i64,
   /// Thumbnail height, usually doesn't exceed 40
   height: i64,
   /// The thumbnail in JPEG format
-  data: String,
+  data: Bytes,
   
 }
 
 impl RObject for Minithumbnail {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "minithumbnail" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "minithumbnail" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Minithumbnail {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDMinithumbnailBuilder {
-    let mut inner = Minithumbnail::default();
-    inner.td_name = "minithumbnail".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Minithumbnail { td_name: "minithumbnail".to_string(), ..Default::default() };
     RTDMinithumbnailBuilder { inner }
   }
 
+  /// `Minithumbnail` with the fields tdlib requires, the others are set by `Minithumbnail::builder()`
+  pub fn new(width: i64, height: i64, data: impl AsRef<Bytes>) -> Self { Self::builder().width(width).height(height).data(data).build() }
+
   pub fn width(&self) -> i64 { self.width }
 
   pub fn height(&self) -> i64 { self.height }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &Bytes { &self.data }
 
 }
 
@@ -57,6 +61,12 @@ pub struct RTDMinithumbnailBuilder {
 impl RTDMinithumbnailBuilder {
   pub fn build(&self) -> Minithumbnail { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
@@ -70,8 +80,8 @@ impl RTDMinithumbnailBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
     self
   }
 
@@ -85,5 +95,13 @@ impl AsRef<Minithumbnail> for RTDMinithumbnailBuilder {
   fn as_ref(&self) -> &Minithumbnail { &self.inner }
 }
 
+impl PartialEq for Minithumbnail {
+  fn eq(&self, other: &Self) -> bool {
+    self.width == other.width
+      && self.height == other.height
+      && self.data == other.data
+  }
+}
+
 
 
@@ -0,0 +1,2454 @@
+#![allow(clippy::large_enum_variant)]
+
+use serde::de::{Deserialize, Deserializer};
+
+use crate::errors::*;
+use crate::types::*;
+
+pub use self::account_ttl::*;
+pub use self::address::*;
+pub use self::animated_chat_photo::*;
+pub use self::animated_emoji::*;
+pub use self::animation::*;
+pub use self::animations::*;
+pub use self::audio::*;
+pub use self::authentication_code_info::*;
+pub use self::authentication_code_type::*;
+pub use self::authorization_state::*;
+pub use self::auto_download_settings::*;
+pub use self::auto_download_settings_presets::*;
+pub use self::background::*;
+pub use self::background_fill::*;
+pub use self::background_type::*;
+pub use self::backgrounds::*;
+#[cfg(feature = "payments")]
+pub use self::bank_card_action_open_url::*;
+#[cfg(feature = "payments")]
+pub use self::bank_card_info::*;
+pub use self::basic_group::*;
+pub use self::basic_group_full_info::*;
+pub use self::bot_command::*;
+pub use self::bot_command_scope::*;
+pub use self::bot_commands::*;
+#[cfg(feature = "calls")]
+pub use self::call::*;
+#[cfg(feature = "calls")]
+pub use self::call_discard_reason::*;
+#[cfg(feature = "calls")]
+pub use self::call_id::*;
+#[cfg(feature = "calls")]
+pub use self::call_problem::*;
+#[cfg(feature = "calls")]
+pub use self::call_protocol::*;
+#[cfg(feature = "calls")]
+pub use self::call_server::*;
+#[cfg(feature = "calls")]
+pub use self::call_server_type::*;
+#[cfg(feature = "calls")]
+pub use self::call_state::*;
+pub use self::callback_query_answer::*;
+pub use self::callback_query_payload::*;
+pub use self::can_transfer_ownership_result::*;
+pub use self::chat::*;
+pub use self::chat_action::*;
+pub use self::chat_action_bar::*;
+pub use self::chat_administrator::*;
+pub use self::chat_administrators::*;
+pub use self::chat_event::*;
+pub use self::chat_event_action::*;
+pub use self::chat_event_log_filters::*;
+pub use self::chat_events::*;
+pub use self::chat_filter::*;
+pub use self::chat_filter_info::*;
+pub use self::chat_invite_link::*;
+pub use self::chat_invite_link_count::*;
+pub use self::chat_invite_link_counts::*;
+pub use self::chat_invite_link_info::*;
+pub use self::chat_invite_link_member::*;
+pub use self::chat_invite_link_members::*;
+pub use self::chat_invite_links::*;
+pub use self::chat_join_request::*;
+pub use self::chat_join_requests::*;
+pub use self::chat_join_requests_info::*;
+pub use self::chat_list::*;
+pub use self::chat_lists::*;
+pub use self::chat_location::*;
+pub use self::chat_member::*;
+pub use self::chat_member_status::*;
+pub use self::chat_members::*;
+pub use self::chat_members_filter::*;
+pub use self::chat_nearby::*;
+pub use self::chat_notification_settings::*;
+pub use self::chat_permissions::*;
+pub use self::chat_photo::*;
+pub use self::chat_photo_info::*;
+pub use self::chat_photos::*;
+pub use self::chat_position::*;
+pub use self::chat_report_reason::*;
+pub use self::chat_source::*;
+#[cfg(feature = "statistics")]
+pub use self::chat_statistics::*;
+#[cfg(feature = "statistics")]
+pub use self::chat_statistics_administrator_actions_info::*;
+#[cfg(feature = "statistics")]
+pub use self::chat_statistics_inviter_info::*;
+#[cfg(feature = "statistics")]
+pub use self::chat_statistics_message_interaction_info::*;
+#[cfg(feature = "statistics")]
+pub use self::chat_statistics_message_sender_info::*;
+pub use self::chat_theme::*;
+pub use self::chat_type::*;
+pub use self::chats::*;
+pub use self::chats_nearby::*;
+pub use self::check_chat_username_result::*;
+#[cfg(feature = "stickers")]
+pub use self::check_sticker_set_name_result::*;
+pub use self::closed_vector_path::*;
+pub use self::color_replacement::*;
+pub use self::connected_website::*;
+pub use self::connected_websites::*;
+pub use self::connection_state::*;
+pub use self::contact::*;
+pub use self::count::*;
+pub use self::countries::*;
+pub use self::country_info::*;
+pub use self::custom_request_result::*;
+#[cfg(feature = "statistics")]
+pub use self::database_statistics::*;
+pub use self::date::*;
+pub use self::date_range::*;
+#[cfg(feature = "passport")]
+pub use self::dated_file::*;
+pub use self::deep_link_info::*;
+pub use self::device_token::*;
+pub use self::dice_stickers::*;
+pub use self::document::*;
+pub use self::draft_message::*;
+pub use self::email_address_authentication_code_info::*;
+pub use self::emojis::*;
+#[cfg(feature = "passport")]
+pub use self::encrypted_credentials::*;
+#[cfg(feature = "passport")]
+pub use self::encrypted_passport_element::*;
+pub use self::error::*;
+pub use self::file::*;
+pub use self::file_part::*;
+pub use self::file_type::*;
+pub use self::formatted_text::*;
+pub use self::found_messages::*;
+pub use self::functions::*;
+pub use self::game::*;
+pub use self::game_high_score::*;
+pub use self::game_high_scores::*;
+#[cfg(feature = "calls")]
+pub use self::group_call::*;
+#[cfg(feature = "calls")]
+pub use self::group_call_id::*;
+#[cfg(feature = "calls")]
+pub use self::group_call_participant::*;
+#[cfg(feature = "calls")]
+pub use self::group_call_participant_video_info::*;
+#[cfg(feature = "calls")]
+pub use self::group_call_recent_speaker::*;
+#[cfg(feature = "calls")]
+pub use self::group_call_video_quality::*;
+#[cfg(feature = "calls")]
+pub use self::group_call_video_source_group::*;
+pub use self::hashtags::*;
+pub use self::http_url::*;
+#[cfg(feature = "passport")]
+pub use self::identity_document::*;
+pub use self::imported_contacts::*;
+pub use self::inline_keyboard_button::*;
+pub use self::inline_keyboard_button_type::*;
+pub use self::inline_query_result::*;
+pub use self::inline_query_results::*;
+pub use self::input_background::*;
+pub use self::input_chat_photo::*;
+#[cfg(feature = "payments")]
+pub use self::input_credentials::*;
+pub use self::input_file::*;
+#[cfg(feature = "passport")]
+pub use self::input_identity_document::*;
+pub use self::input_inline_query_result::*;
+pub use self::input_message_content::*;
+#[cfg(feature = "passport")]
+pub use self::input_passport_element::*;
+#[cfg(feature = "passport")]
+pub use self::input_passport_element_error::*;
+#[cfg(feature = "passport")]
+pub use self::input_passport_element_error_source::*;
+#[cfg(feature = "passport")]
+pub use self::input_personal_document::*;
+#[cfg(feature = "stickers")]
+pub use self::input_sticker::*;
+pub use self::input_thumbnail::*;
+pub use self::internal_link_type::*;
+#[cfg(feature = "payments")]
+pub use self::invoice::*;
+pub use self::json_object_member::*;
+pub use self::json_value::*;
+pub use self::keyboard_button::*;
+pub use self::keyboard_button_type::*;
+#[cfg(feature = "payments")]
+pub use self::labeled_price_part::*;
+pub use self::language_pack_info::*;
+pub use self::language_pack_string::*;
+pub use self::language_pack_string_value::*;
+pub use self::language_pack_strings::*;
+pub use self::local_file::*;
+pub use self::localization_target_info::*;
+pub use self::location::*;
+pub use self::log_stream::*;
+pub use self::log_tags::*;
+pub use self::log_verbosity_level::*;
+pub use self::login_url_info::*;
+pub use self::mask_point::*;
+pub use self::mask_position::*;
+pub use self::message::*;
+pub use self::message_calendar::*;
+pub use self::message_calendar_day::*;
+pub use self::message_content::*;
+pub use self::message_copy_options::*;
+pub use self::message_file_type::*;
+pub use self::message_forward_info::*;
+pub use self::message_forward_origin::*;
+pub use self::message_interaction_info::*;
+pub use self::message_link::*;
+pub use self::message_link_info::*;
+pub use self::message_position::*;
+pub use self::message_positions::*;
+pub use self::message_reply_info::*;
+pub use self::message_scheduling_state::*;
+pub use self::message_send_options::*;
+pub use self::message_sender::*;
+pub use self::message_senders::*;
+pub use self::message_sending_state::*;
+#[cfg(feature = "statistics")]
+pub use self::message_statistics::*;
+pub use self::message_thread_info::*;
+pub use self::messages::*;
+pub use self::minithumbnail::*;
+#[cfg(feature = "statistics")]
+pub use self::network_statistics::*;
+#[cfg(feature = "statistics")]
+pub use self::network_statistics_entry::*;
+pub use self::network_type::*;
+pub use self::notification::*;
+pub use self::notification_group::*;
+pub use self::notification_group_type::*;
+pub use self::notification_settings_scope::*;
+pub use self::notification_type::*;
+pub use self::ok::*;
+pub use self::option_value::*;
+pub use self::order_info::*;
+#[cfg(feature = "instant-view")]
+pub use self::page_block::*;
+#[cfg(feature = "instant-view")]
+pub use self::page_block_caption::*;
+#[cfg(feature = "instant-view")]
+pub use self::page_block_horizontal_alignment::*;
+#[cfg(feature = "instant-view")]
+pub use self::page_block_list_item::*;
+#[cfg(feature = "instant-view")]
+pub use self::page_block_related_article::*;
+#[cfg(feature = "instant-view")]
+pub use self::page_block_table_cell::*;
+#[cfg(feature = "instant-view")]
+pub use self::page_block_vertical_alignment::*;
+#[cfg(feature = "passport")]
+pub use self::passport_authorization_form::*;
+#[cfg(feature = "passport")]
+pub use self::passport_element::*;
+#[cfg(feature = "passport")]
+pub use self::passport_element_error::*;
+#[cfg(feature = "passport")]
+pub use self::passport_element_error_source::*;
+#[cfg(feature = "passport")]
+pub use self::passport_element_type::*;
+#[cfg(feature = "passport")]
+pub use self::passport_elements::*;
+#[cfg(feature = "passport")]
+pub use self::passport_elements_with_errors::*;
+#[cfg(feature = "passport")]
+pub use self::passport_required_element::*;
+#[cfg(feature = "passport")]
+pub use self::passport_suitable_element::*;
+pub use self::password_state::*;
+#[cfg(feature = "payments")]
+pub use self::payment_form::*;
+#[cfg(feature = "payments")]
+pub use self::payment_form_theme::*;
+#[cfg(feature = "payments")]
+pub use self::payment_receipt::*;
+#[cfg(feature = "payments")]
+pub use self::payment_result::*;
+#[cfg(feature = "payments")]
+pub use self::payments_provider_stripe::*;
+#[cfg(feature = "passport")]
+pub use self::personal_details::*;
+#[cfg(feature = "passport")]
+pub use self::personal_document::*;
+pub use self::phone_number_authentication_settings::*;
+pub use self::phone_number_info::*;
+pub use self::photo::*;
+pub use self::photo_size::*;
+pub use self::point::*;
+pub use self::poll::*;
+pub use self::poll_option::*;
+pub use self::poll_type::*;
+pub use self::profile_photo::*;
+pub use self::proxies::*;
+pub use self::proxy::*;
+pub use self::proxy_type::*;
+pub use self::public_chat_type::*;
+pub use self::push_message_content::*;
+pub use self::push_receiver_id::*;
+pub use self::recommended_chat_filter::*;
+pub use self::recommended_chat_filters::*;
+pub use self::recovery_email_address::*;
+pub use self::remote_file::*;
+pub use self::reply_markup::*;
+pub use self::reset_password_result::*;
+#[cfg(feature = "instant-view")]
+pub use self::rich_text::*;
+#[cfg(feature = "payments")]
+pub use self::saved_credentials::*;
+pub use self::scope_notification_settings::*;
+pub use self::search_messages_filter::*;
+pub use self::seconds::*;
+pub use self::secret_chat::*;
+pub use self::secret_chat_state::*;
+pub use self::session::*;
+pub use self::sessions::*;
+#[cfg(feature = "payments")]
+pub use self::shipping_option::*;
+pub use self::sponsored_message::*;
+pub use self::sponsored_messages::*;
+#[cfg(feature = "statistics")]
+pub use self::statistical_graph::*;
+#[cfg(feature = "statistics")]
+pub use self::statistical_value::*;
+pub use self::sticker::*;
+#[cfg(feature = "stickers")]
+pub use self::sticker_set::*;
+#[cfg(feature = "stickers")]
+pub use self::sticker_set_info::*;
+#[cfg(feature = "stickers")]
+pub use self::sticker_sets::*;
+#[cfg(feature = "stickers")]
+pub use self::stickers::*;
+pub use self::storage_statistics::*;
+pub use self::storage_statistics_by_chat::*;
+pub use self::storage_statistics_by_file_type::*;
+pub use self::storage_statistics_fast::*;
+pub use self::suggested_action::*;
+pub use self::supergroup::*;
+pub use self::supergroup_full_info::*;
+pub use self::supergroup_members_filter::*;
+pub use self::t_me_url::*;
+pub use self::t_me_url_type::*;
+pub use self::t_me_urls::*;
+pub use self::tdlib_parameters::*;
+#[cfg(feature = "payments")]
+pub use self::temporary_password_state::*;
+pub use self::terms_of_service::*;
+pub use self::test_bytes::*;
+pub use self::test_int::*;
+pub use self::test_string::*;
+pub use self::test_vector_int::*;
+pub use self::test_vector_int_object::*;
+pub use self::test_vector_string::*;
+pub use self::test_vector_string_object::*;
+pub use self::text::*;
+pub use self::text_entities::*;
+pub use self::text_entity::*;
+pub use self::text_entity_type::*;
+pub use self::text_parse_mode::*;
+pub use self::theme_settings::*;
+pub use self::thumbnail::*;
+pub use self::thumbnail_format::*;
+pub use self::top_chat_category::*;
+pub use self::update::*;
+pub use self::updates::*;
+pub use self::user::*;
+pub use self::user_full_info::*;
+pub use self::user_privacy_setting::*;
+pub use self::user_privacy_setting_rule::*;
+pub use self::user_privacy_setting_rules::*;
+pub use self::user_status::*;
+pub use self::user_type::*;
+pub use self::users::*;
+#[cfg(feature = "payments")]
+pub use self::validated_order_info::*;
+pub use self::vector_path_command::*;
+pub use self::venue::*;
+pub use self::video::*;
+pub use self::video_chat::*;
+pub use self::video_note::*;
+pub use self::voice_note::*;
+pub use self::web_page::*;
+#[cfg(feature = "instant-view")]
+pub use self::web_page_instant_view::*;
+
+mod account_ttl;
+mod address;
+mod animated_chat_photo;
+mod animated_emoji;
+mod animation;
+mod animations;
+mod audio;
+mod authentication_code_info;
+mod authentication_code_type;
+mod authorization_state;
+mod auto_download_settings;
+mod auto_download_settings_presets;
+mod background;
+mod background_fill;
+mod background_type;
+mod backgrounds;
+#[cfg(feature = "payments")]
+mod bank_card_action_open_url;
+#[cfg(feature = "payments")]
+mod bank_card_info;
+mod basic_group;
+mod basic_group_full_info;
+mod bot_command;
+mod bot_command_scope;
+mod bot_commands;
+#[cfg(feature = "calls")]
+mod call;
+#[cfg(feature = "calls")]
+mod call_discard_reason;
+#[cfg(feature = "calls")]
+mod call_id;
+#[cfg(feature = "calls")]
+mod call_problem;
+#[cfg(feature = "calls")]
+mod call_protocol;
+#[cfg(feature = "calls")]
+mod call_server;
+#[cfg(feature = "calls")]
+mod call_server_type;
+#[cfg(feature = "calls")]
+mod call_state;
+mod callback_query_answer;
+mod callback_query_payload;
+mod can_transfer_ownership_result;
+mod chat;
+mod chat_action;
+mod chat_action_bar;
+mod chat_administrator;
+mod chat_administrators;
+mod chat_event;
+mod chat_event_action;
+mod chat_event_log_filters;
+mod chat_events;
+mod chat_filter;
+mod chat_filter_info;
+mod chat_invite_link;
+mod chat_invite_link_count;
+mod chat_invite_link_counts;
+mod chat_invite_link_info;
+mod chat_invite_link_member;
+mod chat_invite_link_members;
+mod chat_invite_links;
+mod chat_join_request;
+mod chat_join_requests;
+mod chat_join_requests_info;
+mod chat_list;
+mod chat_lists;
+mod chat_location;
+mod chat_member;
+mod chat_member_status;
+mod chat_members;
+mod chat_members_filter;
+mod chat_nearby;
+mod chat_notification_settings;
+mod chat_permissions;
+mod chat_photo;
+mod chat_photo_info;
+mod chat_photos;
+mod chat_position;
+mod chat_report_reason;
+mod chat_source;
+#[cfg(feature = "statistics")]
+mod chat_statistics;
+#[cfg(feature = "statistics")]
+mod chat_statistics_administrator_actions_info;
+#[cfg(feature = "statistics")]
+mod chat_statistics_inviter_info;
+#[cfg(feature = "statistics")]
+mod chat_statistics_message_interaction_info;
+#[cfg(feature = "statistics")]
+mod chat_statistics_message_sender_info;
+mod chat_theme;
+mod chat_type;
+mod chats;
+mod chats_nearby;
+mod check_chat_username_result;
+#[cfg(feature = "stickers")]
+mod check_sticker_set_name_result;
+mod closed_vector_path;
+mod color_replacement;
+mod connected_website;
+mod connected_websites;
+mod connection_state;
+mod contact;
+mod count;
+mod countries;
+mod country_info;
+mod custom_request_result;
+#[cfg(feature = "statistics")]
+mod database_statistics;
+mod date;
+mod date_range;
+#[cfg(feature = "passport")]
+mod dated_file;
+mod deep_link_info;
+mod device_token;
+mod dice_stickers;
+mod document;
+mod draft_message;
+mod email_address_authentication_code_info;
+mod emojis;
+#[cfg(feature = "passport")]
+mod encrypted_credentials;
+#[cfg(feature = "passport")]
+mod encrypted_passport_element;
+mod error;
+mod file;
+mod file_part;
+mod file_type;
+mod formatted_text;
+mod found_messages;
+mod functions;
+mod game;
+mod game_high_score;
+mod game_high_scores;
+#[cfg(feature = "calls")]
+mod group_call;
+#[cfg(feature = "calls")]
+mod group_call_id;
+#[cfg(feature = "calls")]
+mod group_call_participant;
+#[cfg(feature = "calls")]
+mod group_call_participant_video_info;
+#[cfg(feature = "calls")]
+mod group_call_recent_speaker;
+#[cfg(feature = "calls")]
+mod group_call_video_quality;
+#[cfg(feature = "calls")]
+mod group_call_video_source_group;
+mod hashtags;
+mod http_url;
+#[cfg(feature = "passport")]
+mod identity_document;
+mod imported_contacts;
+mod inline_keyboard_button;
+mod inline_keyboard_button_type;
+mod inline_query_result;
+mod inline_query_results;
+mod input_background;
+mod input_chat_photo;
+#[cfg(feature = "payments")]
+mod input_credentials;
+mod input_file;
+#[cfg(feature = "passport")]
+mod input_identity_document;
+mod input_inline_query_result;
+mod input_message_content;
+#[cfg(feature = "passport")]
+mod input_passport_element;
+#[cfg(feature = "passport")]
+mod input_passport_element_error;
+#[cfg(feature = "passport")]
+mod input_passport_element_error_source;
+#[cfg(feature = "passport")]
+mod input_personal_document;
+#[cfg(feature = "stickers")]
+mod input_sticker;
+mod input_thumbnail;
+mod internal_link_type;
+#[cfg(feature = "payments")]
+mod invoice;
+mod json_object_member;
+mod json_value;
+mod keyboard_button;
+mod keyboard_button_type;
+#[cfg(feature = "payments")]
+mod labeled_price_part;
+mod language_pack_info;
+mod language_pack_string;
+mod language_pack_string_value;
+mod language_pack_strings;
+mod local_file;
+mod localization_target_info;
+mod location;
+mod log_stream;
+mod log_tags;
+mod log_verbosity_level;
+mod login_url_info;
+mod mask_point;
+mod mask_position;
+mod message;
+mod message_calendar;
+mod message_calendar_day;
+mod message_content;
+mod message_copy_options;
+mod message_file_type;
+mod message_forward_info;
+mod message_forward_origin;
+mod message_interaction_info;
+mod message_link;
+mod message_link_info;
+mod message_position;
+mod message_positions;
+mod message_reply_info;
+mod message_scheduling_state;
+mod message_send_options;
+mod message_sender;
+mod message_senders;
+mod message_sending_state;
+#[cfg(feature = "statistics")]
+mod message_statistics;
+mod message_thread_info;
+mod messages;
+mod minithumbnail;
+#[cfg(feature = "statistics")]
+mod network_statistics;
+#[cfg(feature = "statistics")]
+mod network_statistics_entry;
+mod network_type;
+mod notification;
+mod notification_group;
+mod notification_group_type;
+mod notification_settings_scope;
+mod notification_type;
+mod ok;
+mod option_value;
+mod order_info;
+#[cfg(feature = "instant-view")]
+mod page_block;
+#[cfg(feature = "instant-view")]
+mod page_block_caption;
+#[cfg(feature = "instant-view")]
+mod page_block_horizontal_alignment;
+#[cfg(feature = "instant-view")]
+mod page_block_list_item;
+#[cfg(feature = "instant-view")]
+mod page_block_related_article;
+#[cfg(feature = "instant-view")]
+mod page_block_table_cell;
+#[cfg(feature = "instant-view")]
+mod page_block_vertical_alignment;
+#[cfg(feature = "passport")]
+mod passport_authorization_form;
+#[cfg(feature = "passport")]
+mod passport_element;
+#[cfg(feature = "passport")]
+mod passport_element_error;
+#[cfg(feature = "passport")]
+mod passport_element_error_source;
+#[cfg(feature = "passport")]
+mod passport_element_type;
+#[cfg(feature = "passport")]
+mod passport_elements;
+#[cfg(feature = "passport")]
+mod passport_elements_with_errors;
+#[cfg(feature = "passport")]
+mod passport_required_element;
+#[cfg(feature = "passport")]
+mod passport_suitable_element;
+mod password_state;
+#[cfg(feature = "payments")]
+mod payment_form;
+#[cfg(feature = "payments")]
+mod payment_form_theme;
+#[cfg(feature = "payments")]
+mod payment_receipt;
+#[cfg(feature = "payments")]
+mod payment_result;
+#[cfg(feature = "payments")]
+mod payments_provider_stripe;
+#[cfg(feature = "passport")]
+mod personal_details;
+#[cfg(feature = "passport")]
+mod personal_document;
+mod phone_number_authentication_settings;
+mod phone_number_info;
+mod photo;
+mod photo_size;
+mod point;
+mod poll;
+mod poll_option;
+mod poll_type;
+mod profile_photo;
+mod proxies;
+mod proxy;
+mod proxy_type;
+mod public_chat_type;
+mod push_message_content;
+mod push_receiver_id;
+mod recommended_chat_filter;
+mod recommended_chat_filters;
+mod recovery_email_address;
+mod remote_file;
+mod reply_markup;
+mod reset_password_result;
+#[cfg(feature = "instant-view")]
+mod rich_text;
+#[cfg(feature = "payments")]
+mod saved_credentials;
+mod scope_notification_settings;
+mod search_messages_filter;
+mod seconds;
+mod secret_chat;
+mod secret_chat_state;
+mod session;
+mod sessions;
+#[cfg(feature = "payments")]
+mod shipping_option;
+mod sponsored_message;
+mod sponsored_messages;
+#[cfg(feature = "statistics")]
+mod statistical_graph;
+#[cfg(feature = "statistics")]
+mod statistical_value;
+mod sticker;
+#[cfg(feature = "stickers")]
+mod sticker_set;
+#[cfg(feature = "stickers")]
+mod sticker_set_info;
+#[cfg(feature = "stickers")]
+mod sticker_sets;
+#[cfg(feature = "stickers")]
+mod stickers;
+mod storage_statistics;
+mod storage_statistics_by_chat;
+mod storage_statistics_by_file_type;
+mod storage_statistics_fast;
+mod suggested_action;
+mod supergroup;
+mod supergroup_full_info;
+mod supergroup_members_filter;
+mod t_me_url;
+mod t_me_url_type;
+mod t_me_urls;
+mod tdlib_parameters;
+#[cfg(feature = "payments")]
+mod temporary_password_state;
+mod terms_of_service;
+mod test_bytes;
+mod test_int;
+mod test_string;
+mod test_vector_int;
+mod test_vector_int_object;
+mod test_vector_string;
+mod test_vector_string_object;
+mod text;
+mod text_entities;
+mod text_entity;
+mod text_entity_type;
+mod text_parse_mode;
+mod theme_settings;
+mod thumbnail;
+mod thumbnail_format;
+mod top_chat_category;
+mod update;
+mod updates;
+mod user;
+mod user_full_info;
+mod user_privacy_setting;
+mod user_privacy_setting_rule;
+mod user_privacy_setting_rules;
+mod user_status;
+mod user_type;
+mod users;
+#[cfg(feature = "payments")]
+mod validated_order_info;
+mod vector_path_command;
+mod venue;
+mod video;
+mod video_chat;
+mod video_note;
+mod voice_note;
+mod web_page;
+#[cfg(feature = "instant-view")]
+mod web_page_instant_view;
+
+impl<AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType for &AUTHENTICATIONCODETYPE {}
+impl<AUTHENTICATIONCODETYPE: TDAuthenticationCodeType> TDAuthenticationCodeType for &mut AUTHENTICATIONCODETYPE {}
+
+impl<AUTHORIZATIONSTATE: TDAuthorizationState> TDAuthorizationState for &AUTHORIZATIONSTATE {}
+impl<AUTHORIZATIONSTATE: TDAuthorizationState> TDAuthorizationState for &mut AUTHORIZATIONSTATE {}
+
+impl<BACKGROUNDFILL: TDBackgroundFill> TDBackgroundFill for &BACKGROUNDFILL {}
+impl<BACKGROUNDFILL: TDBackgroundFill> TDBackgroundFill for &mut BACKGROUNDFILL {}
+
+impl<BACKGROUNDTYPE: TDBackgroundType> TDBackgroundType for &BACKGROUNDTYPE {}
+impl<BACKGROUNDTYPE: TDBackgroundType> TDBackgroundType for &mut BACKGROUNDTYPE {}
+
+impl<BOTCOMMANDSCOPE: TDBotCommandScope> TDBotCommandScope for &BOTCOMMANDSCOPE {}
+impl<BOTCOMMANDSCOPE: TDBotCommandScope> TDBotCommandScope for &mut BOTCOMMANDSCOPE {}
+
+#[cfg(feature = "calls")]
+impl<CALLDISCARDREASON: TDCallDiscardReason> TDCallDiscardReason for &CALLDISCARDREASON {}
+#[cfg(feature = "calls")]
+impl<CALLDISCARDREASON: TDCallDiscardReason> TDCallDiscardReason for &mut CALLDISCARDREASON {}
+
+#[cfg(feature = "calls")]
+impl<CALLPROBLEM: TDCallProblem> TDCallProblem for &CALLPROBLEM {}
+#[cfg(feature = "calls")]
+impl<CALLPROBLEM: TDCallProblem> TDCallProblem for &mut CALLPROBLEM {}
+
+#[cfg(feature = "calls")]
+impl<CALLSERVERTYPE: TDCallServerType> TDCallServerType for &CALLSERVERTYPE {}
+#[cfg(feature = "calls")]
+impl<CALLSERVERTYPE: TDCallServerType> TDCallServerType for &mut CALLSERVERTYPE {}
+
+#[cfg(feature = "calls")]
+impl<CALLSTATE: TDCallState> TDCallState for &CALLSTATE {}
+#[cfg(feature = "calls")]
+impl<CALLSTATE: TDCallState> TDCallState for &mut CALLSTATE {}
+
+impl<CALLBACKQUERYPAYLOAD: TDCallbackQueryPayload> TDCallbackQueryPayload for &CALLBACKQUERYPAYLOAD {}
+impl<CALLBACKQUERYPAYLOAD: TDCallbackQueryPayload> TDCallbackQueryPayload for &mut CALLBACKQUERYPAYLOAD {}
+
+impl<CANTRANSFEROWNERSHIPRESULT: TDCanTransferOwnershipResult> TDCanTransferOwnershipResult for &CANTRANSFEROWNERSHIPRESULT {}
+impl<CANTRANSFEROWNERSHIPRESULT: TDCanTransferOwnershipResult> TDCanTransferOwnershipResult for &mut CANTRANSFEROWNERSHIPRESULT {}
+
+impl<CHATACTION: TDChatAction> TDChatAction for &CHATACTION {}
+impl<CHATACTION: TDChatAction> TDChatAction for &mut CHATACTION {}
+
+impl<CHATACTIONBAR: TDChatActionBar> TDChatActionBar for &CHATACTIONBAR {}
+impl<CHATACTIONBAR: TDChatActionBar> TDChatActionBar for &mut CHATACTIONBAR {}
+
+impl<CHATEVENTACTION: TDChatEventAction> TDChatEventAction for &CHATEVENTACTION {}
+impl<CHATEVENTACTION: TDChatEventAction> TDChatEventAction for &mut CHATEVENTACTION {}
+
+impl<CHATLIST: TDChatList> TDChatList for &CHATLIST {}
+impl<CHATLIST: TDChatList> TDChatList for &mut CHATLIST {}
+
+impl<CHATMEMBERSTATUS: TDChatMemberStatus> TDChatMemberStatus for &CHATMEMBERSTATUS {}
+impl<CHATMEMBERSTATUS: TDChatMemberStatus> TDChatMemberStatus for &mut CHATMEMBERSTATUS {}
+
+impl<CHATMEMBERSFILTER: TDChatMembersFilter> TDChatMembersFilter for &CHATMEMBERSFILTER {}
+impl<CHATMEMBERSFILTER: TDChatMembersFilter> TDChatMembersFilter for &mut CHATMEMBERSFILTER {}
+
+impl<CHATREPORTREASON: TDChatReportReason> TDChatReportReason for &CHATREPORTREASON {}
+impl<CHATREPORTREASON: TDChatReportReason> TDChatReportReason for &mut CHATREPORTREASON {}
+
+impl<CHATSOURCE: TDChatSource> TDChatSource for &CHATSOURCE {}
+impl<CHATSOURCE: TDChatSource> TDChatSource for &mut CHATSOURCE {}
+
+#[cfg(feature = "statistics")]
+impl<CHATSTATISTICS: TDChatStatistics> TDChatStatistics for &CHATSTATISTICS {}
+#[cfg(feature = "statistics")]
+impl<CHATSTATISTICS: TDChatStatistics> TDChatStatistics for &mut CHATSTATISTICS {}
+
+impl<CHATTYPE: TDChatType> TDChatType for &CHATTYPE {}
+impl<CHATTYPE: TDChatType> TDChatType for &mut CHATTYPE {}
+
+impl<CHECKCHATUSERNAMERESULT: TDCheckChatUsernameResult> TDCheckChatUsernameResult for &CHECKCHATUSERNAMERESULT {}
+impl<CHECKCHATUSERNAMERESULT: TDCheckChatUsernameResult> TDCheckChatUsernameResult for &mut CHECKCHATUSERNAMERESULT {}
+
+#[cfg(feature = "stickers")]
+impl<CHECKSTICKERSETNAMERESULT: TDCheckStickerSetNameResult> TDCheckStickerSetNameResult for &CHECKSTICKERSETNAMERESULT {}
+#[cfg(feature = "stickers")]
+impl<CHECKSTICKERSETNAMERESULT: TDCheckStickerSetNameResult> TDCheckStickerSetNameResult for &mut CHECKSTICKERSETNAMERESULT {}
+
+impl<CONNECTIONSTATE: TDConnectionState> TDConnectionState for &CONNECTIONSTATE {}
+impl<CONNECTIONSTATE: TDConnectionState> TDConnectionState for &mut CONNECTIONSTATE {}
+
+impl<DEVICETOKEN: TDDeviceToken> TDDeviceToken for &DEVICETOKEN {}
+impl<DEVICETOKEN: TDDeviceToken> TDDeviceToken for &mut DEVICETOKEN {}
+
+impl<DICESTICKERS: TDDiceStickers> TDDiceStickers for &DICESTICKERS {}
+impl<DICESTICKERS: TDDiceStickers> TDDiceStickers for &mut DICESTICKERS {}
+
+impl<FILETYPE: TDFileType> TDFileType for &FILETYPE {}
+impl<FILETYPE: TDFileType> TDFileType for &mut FILETYPE {}
+
+#[cfg(feature = "calls")]
+impl<GROUPCALLVIDEOQUALITY: TDGroupCallVideoQuality> TDGroupCallVideoQuality for &GROUPCALLVIDEOQUALITY {}
+#[cfg(feature = "calls")]
+impl<GROUPCALLVIDEOQUALITY: TDGroupCallVideoQuality> TDGroupCallVideoQuality for &mut GROUPCALLVIDEOQUALITY {}
+
+impl<INLINEKEYBOARDBUTTONTYPE: TDInlineKeyboardButtonType> TDInlineKeyboardButtonType for &INLINEKEYBOARDBUTTONTYPE {}
+impl<INLINEKEYBOARDBUTTONTYPE: TDInlineKeyboardButtonType> TDInlineKeyboardButtonType for &mut INLINEKEYBOARDBUTTONTYPE {}
+
+impl<INLINEQUERYRESULT: TDInlineQueryResult> TDInlineQueryResult for &INLINEQUERYRESULT {}
+impl<INLINEQUERYRESULT: TDInlineQueryResult> TDInlineQueryResult for &mut INLINEQUERYRESULT {}
+
+impl<INPUTBACKGROUND: TDInputBackground> TDInputBackground for &INPUTBACKGROUND {}
+impl<INPUTBACKGROUND: TDInputBackground> TDInputBackground for &mut INPUTBACKGROUND {}
+
+impl<INPUTCHATPHOTO: TDInputChatPhoto> TDInputChatPhoto for &INPUTCHATPHOTO {}
+impl<INPUTCHATPHOTO: TDInputChatPhoto> TDInputChatPhoto for &mut INPUTCHATPHOTO {}
+
+#[cfg(feature = "payments")]
+impl<INPUTCREDENTIALS: TDInputCredentials> TDInputCredentials for &INPUTCREDENTIALS {}
+#[cfg(feature = "payments")]
+impl<INPUTCREDENTIALS: TDInputCredentials> TDInputCredentials for &mut INPUTCREDENTIALS {}
+
+impl<INPUTFILE: TDInputFile> TDInputFile for &INPUTFILE {}
+impl<INPUTFILE: TDInputFile> TDInputFile for &mut INPUTFILE {}
+
+impl<INPUTINLINEQUERYRESULT: TDInputInlineQueryResult> TDInputInlineQueryResult for &INPUTINLINEQUERYRESULT {}
+impl<INPUTINLINEQUERYRESULT: TDInputInlineQueryResult> TDInputInlineQueryResult for &mut INPUTINLINEQUERYRESULT {}
+
+impl<INPUTMESSAGECONTENT: TDInputMessageContent> TDInputMessageContent for &INPUTMESSAGECONTENT {}
+impl<INPUTMESSAGECONTENT: TDInputMessageContent> TDInputMessageContent for &mut INPUTMESSAGECONTENT {}
+
+#[cfg(feature = "passport")]
+impl<INPUTPASSPORTELEMENT: TDInputPassportElement> TDInputPassportElement for &INPUTPASSPORTELEMENT {}
+#[cfg(feature = "passport")]
+impl<INPUTPASSPORTELEMENT: TDInputPassportElement> TDInputPassportElement for &mut INPUTPASSPORTELEMENT {}
+
+#[cfg(feature = "passport")]
+impl<INPUTPASSPORTELEMENTERRORSOURCE: TDInputPassportElementErrorSource> TDInputPassportElementErrorSource for &INPUTPASSPORTELEMENTERRORSOURCE {}
+#[cfg(feature = "passport")]
+impl<INPUTPASSPORTELEMENTERRORSOURCE: TDInputPassportElementErrorSource> TDInputPassportElementErrorSource for &mut INPUTPASSPORTELEMENTERRORSOURCE {}
+
+#[cfg(feature = "stickers")]
+impl<INPUTSTICKER: TDInputSticker> TDInputSticker for &INPUTSTICKER {}
+#[cfg(feature = "stickers")]
+impl<INPUTSTICKER: TDInputSticker> TDInputSticker for &mut INPUTSTICKER {}
+
+impl<INTERNALLINKTYPE: TDInternalLinkType> TDInternalLinkType for &INTERNALLINKTYPE {}
+impl<INTERNALLINKTYPE: TDInternalLinkType> TDInternalLinkType for &mut INTERNALLINKTYPE {}
+
+impl<JSONVALUE: TDJsonValue> TDJsonValue for &JSONVALUE {}
+impl<JSONVALUE: TDJsonValue> TDJsonValue for &mut JSONVALUE {}
+
+impl<KEYBOARDBUTTONTYPE: TDKeyboardButtonType> TDKeyboardButtonType for &KEYBOARDBUTTONTYPE {}
+impl<KEYBOARDBUTTONTYPE: TDKeyboardButtonType> TDKeyboardButtonType for &mut KEYBOARDBUTTONTYPE {}
+
+impl<LANGUAGEPACKSTRINGVALUE: TDLanguagePackStringValue> TDLanguagePackStringValue for &LANGUAGEPACKSTRINGVALUE {}
+impl<LANGUAGEPACKSTRINGVALUE: TDLanguagePackStringValue> TDLanguagePackStringValue for &mut LANGUAGEPACKSTRINGVALUE {}
+
+impl<LOGSTREAM: TDLogStream> TDLogStream for &LOGSTREAM {}
+impl<LOGSTREAM: TDLogStream> TDLogStream for &mut LOGSTREAM {}
+
+impl<LOGINURLINFO: TDLoginUrlInfo> TDLoginUrlInfo for &LOGINURLINFO {}
+impl<LOGINURLINFO: TDLoginUrlInfo> TDLoginUrlInfo for &mut LOGINURLINFO {}
+
+impl<MASKPOINT: TDMaskPoint> TDMaskPoint for &MASKPOINT {}
+impl<MASKPOINT: TDMaskPoint> TDMaskPoint for &mut MASKPOINT {}
+
+impl<MESSAGECONTENT: TDMessageContent> TDMessageContent for &MESSAGECONTENT {}
+impl<MESSAGECONTENT: TDMessageContent> TDMessageContent for &mut MESSAGECONTENT {}
+
+impl<MESSAGEFILETYPE: TDMessageFileType> TDMessageFileType for &MESSAGEFILETYPE {}
+impl<MESSAGEFILETYPE: TDMessageFileType> TDMessageFileType for &mut MESSAGEFILETYPE {}
+
+impl<MESSAGEFORWARDORIGIN: TDMessageForwardOrigin> TDMessageForwardOrigin for &MESSAGEFORWARDORIGIN {}
+impl<MESSAGEFORWARDORIGIN: TDMessageForwardOrigin> TDMessageForwardOrigin for &mut MESSAGEFORWARDORIGIN {}
+
+impl<MESSAGESCHEDULINGSTATE: TDMessageSchedulingState> TDMessageSchedulingState for &MESSAGESCHEDULINGSTATE {}
+impl<MESSAGESCHEDULINGSTATE: TDMessageSchedulingState> TDMessageSchedulingState for &mut MESSAGESCHEDULINGSTATE {}
+
+impl<MESSAGESENDER: TDMessageSender> TDMessageSender for &MESSAGESENDER {}
+impl<MESSAGESENDER: TDMessageSender> TDMessageSender for &mut MESSAGESENDER {}
+
+impl<MESSAGESENDINGSTATE: TDMessageSendingState> TDMessageSendingState for &MESSAGESENDINGSTATE {}
+impl<MESSAGESENDINGSTATE: TDMessageSendingState> TDMessageSendingState for &mut MESSAGESENDINGSTATE {}
+
+#[cfg(feature = "statistics")]
+impl<NETWORKSTATISTICSENTRY: TDNetworkStatisticsEntry> TDNetworkStatisticsEntry for &NETWORKSTATISTICSENTRY {}
+#[cfg(feature = "statistics")]
+impl<NETWORKSTATISTICSENTRY: TDNetworkStatisticsEntry> TDNetworkStatisticsEntry for &mut NETWORKSTATISTICSENTRY {}
+
+impl<NETWORKTYPE: TDNetworkType> TDNetworkType for &NETWORKTYPE {}
+impl<NETWORKTYPE: TDNetworkType> TDNetworkType for &mut NETWORKTYPE {}
+
+impl<NOTIFICATIONGROUPTYPE: TDNotificationGroupType> TDNotificationGroupType for &NOTIFICATIONGROUPTYPE {}
+impl<NOTIFICATIONGROUPTYPE: TDNotificationGroupType> TDNotificationGroupType for &mut NOTIFICATIONGROUPTYPE {}
+
+impl<NOTIFICATIONSETTINGSSCOPE: TDNotificationSettingsScope> TDNotificationSettingsScope for &NOTIFICATIONSETTINGSSCOPE {}
+impl<NOTIFICATIONSETTINGSSCOPE: TDNotificationSettingsScope> TDNotificationSettingsScope for &mut NOTIFICATIONSETTINGSSCOPE {}
+
+impl<NOTIFICATIONTYPE: TDNotificationType> TDNotificationType for &NOTIFICATIONTYPE {}
+impl<NOTIFICATIONTYPE: TDNotificationType> TDNotificationType for &mut NOTIFICATIONTYPE {}
+
+impl<OPTIONVALUE: TDOptionValue> TDOptionValue for &OPTIONVALUE {}
+impl<OPTIONVALUE: TDOptionValue> TDOptionValue for &mut OPTIONVALUE {}
+
+#[cfg(feature = "instant-view")]
+impl<PAGEBLOCK: TDPageBlock> TDPageBlock for &PAGEBLOCK {}
+#[cfg(feature = "instant-view")]
+impl<PAGEBLOCK: TDPageBlock> TDPageBlock for &mut PAGEBLOCK {}
+
+#[cfg(feature = "instant-view")]
+impl<PAGEBLOCKHORIZONTALALIGNMENT: TDPageBlockHorizontalAlignment> TDPageBlockHorizontalAlignment for &PAGEBLOCKHORIZONTALALIGNMENT {}
+#[cfg(feature = "instant-view")]
+impl<PAGEBLOCKHORIZONTALALIGNMENT: TDPageBlockHorizontalAlignment> TDPageBlockHorizontalAlignment for &mut PAGEBLOCKHORIZONTALALIGNMENT {}
+
+#[cfg(feature = "instant-view")]
+impl<PAGEBLOCKVERTICALALIGNMENT: TDPageBlockVerticalAlignment> TDPageBlockVerticalAlignment for &PAGEBLOCKVERTICALALIGNMENT {}
+#[cfg(feature = "instant-view")]
+impl<PAGEBLOCKVERTICALALIGNMENT: TDPageBlockVerticalAlignment> TDPageBlockVerticalAlignment for &mut PAGEBLOCKVERTICALALIGNMENT {}
+
+#[cfg(feature = "passport")]
+impl<PASSPORTELEMENT: TDPassportElement> TDPassportElement for &PASSPORTELEMENT {}
+#[cfg(feature = "passport")]
+impl<PASSPORTELEMENT: TDPassportElement> TDPassportElement for &mut PASSPORTELEMENT {}
+
+#[cfg(feature = "passport")]
+impl<PASSPORTELEMENTERRORSOURCE: TDPassportElementErrorSource> TDPassportElementErrorSource for &PASSPORTELEMENTERRORSOURCE {}
+#[cfg(feature = "passport")]
+impl<PASSPORTELEMENTERRORSOURCE: TDPassportElementErrorSource> TDPassportElementErrorSource for &mut PASSPORTELEMENTERRORSOURCE {}
+
+#[cfg(feature = "passport")]
+impl<PASSPORTELEMENTTYPE: TDPassportElementType> TDPassportElementType for &PASSPORTELEMENTTYPE {}
+#[cfg(feature = "passport")]
+impl<PASSPORTELEMENTTYPE: TDPassportElementType> TDPassportElementType for &mut PASSPORTELEMENTTYPE {}
+
+impl<POLLTYPE: TDPollType> TDPollType for &POLLTYPE {}
+impl<POLLTYPE: TDPollType> TDPollType for &mut POLLTYPE {}
+
+impl<PROXYTYPE: TDProxyType> TDProxyType for &PROXYTYPE {}
+impl<PROXYTYPE: TDProxyType> TDProxyType for &mut PROXYTYPE {}
+
+impl<PUBLICCHATTYPE: TDPublicChatType> TDPublicChatType for &PUBLICCHATTYPE {}
+impl<PUBLICCHATTYPE: TDPublicChatType> TDPublicChatType for &mut PUBLICCHATTYPE {}
+
+impl<PUSHMESSAGECONTENT: TDPushMessageContent> TDPushMessageContent for &PUSHMESSAGECONTENT {}
+impl<PUSHMESSAGECONTENT: TDPushMessageContent> TDPushMessageContent for &mut PUSHMESSAGECONTENT {}
+
+impl<REPLYMARKUP: TDReplyMarkup> TDReplyMarkup for &REPLYMARKUP {}
+impl<REPLYMARKUP: TDReplyMarkup> TDReplyMarkup for &mut REPLYMARKUP {}
+
+impl<RESETPASSWORDRESULT: TDResetPasswordResult> TDResetPasswordResult for &RESETPASSWORDRESULT {}
+impl<RESETPASSWORDRESULT: TDResetPasswordResult> TDResetPasswordResult for &mut RESETPASSWORDRESULT {}
+
+#[cfg(feature = "instant-view")]
+impl<RICHTEXT: TDRichText> TDRichText for &RICHTEXT {}
+#[cfg(feature = "instant-view")]
+impl<RICHTEXT: TDRichText> TDRichText for &mut RICHTEXT {}
+
+impl<SEARCHMESSAGESFILTER: TDSearchMessagesFilter> TDSearchMessagesFilter for &SEARCHMESSAGESFILTER {}
+impl<SEARCHMESSAGESFILTER: TDSearchMessagesFilter> TDSearchMessagesFilter for &mut SEARCHMESSAGESFILTER {}
+
+impl<SECRETCHATSTATE: TDSecretChatState> TDSecretChatState for &SECRETCHATSTATE {}
+impl<SECRETCHATSTATE: TDSecretChatState> TDSecretChatState for &mut SECRETCHATSTATE {}
+
+#[cfg(feature = "statistics")]
+impl<STATISTICALGRAPH: TDStatisticalGraph> TDStatisticalGraph for &STATISTICALGRAPH {}
+#[cfg(feature = "statistics")]
+impl<STATISTICALGRAPH: TDStatisticalGraph> TDStatisticalGraph for &mut STATISTICALGRAPH {}
+
+impl<SUGGESTEDACTION: TDSuggestedAction> TDSuggestedAction for &SUGGESTEDACTION {}
+impl<SUGGESTEDACTION: TDSuggestedAction> TDSuggestedAction for &mut SUGGESTEDACTION {}
+
+impl<SUPERGROUPMEMBERSFILTER: TDSupergroupMembersFilter> TDSupergroupMembersFilter for &SUPERGROUPMEMBERSFILTER {}
+impl<SUPERGROUPMEMBERSFILTER: TDSupergroupMembersFilter> TDSupergroupMembersFilter for &mut SUPERGROUPMEMBERSFILTER {}
+
+impl<TMEURLTYPE: TDTMeUrlType> TDTMeUrlType for &TMEURLTYPE {}
+impl<TMEURLTYPE: TDTMeUrlType> TDTMeUrlType for &mut TMEURLTYPE {}
+
+impl<TEXTENTITYTYPE: TDTextEntityType> TDTextEntityType for &TEXTENTITYTYPE {}
+impl<TEXTENTITYTYPE: TDTextEntityType> TDTextEntityType for &mut TEXTENTITYTYPE {}
+
+impl<TEXTPARSEMODE: TDTextParseMode> TDTextParseMode for &TEXTPARSEMODE {}
+impl<TEXTPARSEMODE: TDTextParseMode> TDTextParseMode for &mut TEXTPARSEMODE {}
+
+impl<THUMBNAILFORMAT: TDThumbnailFormat> TDThumbnailFormat for &THUMBNAILFORMAT {}
+impl<THUMBNAILFORMAT: TDThumbnailFormat> TDThumbnailFormat for &mut THUMBNAILFORMAT {}
+
+impl<TOPCHATCATEGORY: TDTopChatCategory> TDTopChatCategory for &TOPCHATCATEGORY {}
+impl<TOPCHATCATEGORY: TDTopChatCategory> TDTopChatCategory for &mut TOPCHATCATEGORY {}
+
+impl<UPDATE: TDUpdate> TDUpdate for &UPDATE {}
+impl<UPDATE: TDUpdate> TDUpdate for &mut UPDATE {}
+
+impl<USERPRIVACYSETTING: TDUserPrivacySetting> TDUserPrivacySetting for &USERPRIVACYSETTING {}
+impl<USERPRIVACYSETTING: TDUserPrivacySetting> TDUserPrivacySetting for &mut USERPRIVACYSETTING {}
+
+impl<USERPRIVACYSETTINGRULE: TDUserPrivacySettingRule> TDUserPrivacySettingRule for &USERPRIVACYSETTINGRULE {}
+impl<USERPRIVACYSETTINGRULE: TDUserPrivacySettingRule> TDUserPrivacySettingRule for &mut USERPRIVACYSETTINGRULE {}
+
+impl<USERSTATUS: TDUserStatus> TDUserStatus for &USERSTATUS {}
+impl<USERSTATUS: TDUserStatus> TDUserStatus for &mut USERSTATUS {}
+
+impl<USERTYPE: TDUserType> TDUserType for &USERTYPE {}
+impl<USERTYPE: TDUserType> TDUserType for &mut USERTYPE {}
+
+impl<VECTORPATHCOMMAND: TDVectorPathCommand> TDVectorPathCommand for &VECTORPATHCOMMAND {}
+impl<VECTORPATHCOMMAND: TDVectorPathCommand> TDVectorPathCommand for &mut VECTORPATHCOMMAND {}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TdType {
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
+  TestUseUpdate(TestUseUpdate),
+  UpdateActiveNotifications(UpdateActiveNotifications),
+  UpdateAnimatedEmojiMessageClicked(UpdateAnimatedEmojiMessageClicked),
+  UpdateAnimationSearchParameters(UpdateAnimationSearchParameters),
+  UpdateAuthorizationState(UpdateAuthorizationState),
+  UpdateBasicGroup(UpdateBasicGroup),
+  UpdateBasicGroupFullInfo(UpdateBasicGroupFullInfo),
+  #[cfg(feature = "calls")] UpdateCall(UpdateCall),
+  UpdateChatActionBar(UpdateChatActionBar),
+  UpdateChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
+  UpdateChatDraftMessage(UpdateChatDraftMessage),
+  UpdateChatFilters(UpdateChatFilters),
+  UpdateChatHasScheduledMessages(UpdateChatHasScheduledMessages),
+  UpdateChatIsBlocked(UpdateChatIsBlocked),
+  UpdateChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
+  UpdateChatLastMessage(UpdateChatLastMessage),
+  UpdateChatMember(UpdateChatMember),
+  UpdateChatMessageTtlSetting(UpdateChatMessageTtlSetting),
+  UpdateChatNotificationSettings(UpdateChatNotificationSettings),
+  UpdateChatOnlineMemberCount(UpdateChatOnlineMemberCount),
+  UpdateChatPendingJoinRequests(UpdateChatPendingJoinRequests),
+  UpdateChatPermissions(UpdateChatPermissions),
+  UpdateChatPhoto(UpdateChatPhoto),
+  UpdateChatPosition(UpdateChatPosition),
+  UpdateChatReadInbox(UpdateChatReadInbox),
+  UpdateChatReadOutbox(UpdateChatReadOutbox),
+  UpdateChatReplyMarkup(UpdateChatReplyMarkup),
+  UpdateChatTheme(UpdateChatTheme),
+  UpdateChatThemes(UpdateChatThemes),
+  UpdateChatTitle(UpdateChatTitle),
+  UpdateChatUnreadMentionCount(UpdateChatUnreadMentionCount),
+  UpdateChatVideoChat(UpdateChatVideoChat),
+  UpdateConnectionState(UpdateConnectionState),
+  UpdateDeleteMessages(UpdateDeleteMessages),
+  UpdateDiceEmojis(UpdateDiceEmojis),
+  #[cfg(feature = "stickers")] UpdateFavoriteStickers(UpdateFavoriteStickers),
+  UpdateFile(UpdateFile),
+  UpdateFileGenerationStart(UpdateFileGenerationStart),
+  UpdateFileGenerationStop(UpdateFileGenerationStop),
+  #[cfg(feature = "calls")] UpdateGroupCall(UpdateGroupCall),
+  #[cfg(feature = "calls")] UpdateGroupCallParticipant(UpdateGroupCallParticipant),
+  UpdateHavePendingNotifications(UpdateHavePendingNotifications),
+  #[cfg(feature = "stickers")] UpdateInstalledStickerSets(UpdateInstalledStickerSets),
+  UpdateLanguagePackStrings(UpdateLanguagePackStrings),
+  UpdateMessageContent(UpdateMessageContent),
+  UpdateMessageContentOpened(UpdateMessageContentOpened),
+  UpdateMessageEdited(UpdateMessageEdited),
+  UpdateMessageInteractionInfo(UpdateMessageInteractionInfo),
+  UpdateMessageIsPinned(UpdateMessageIsPinned),
+  UpdateMessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
+  UpdateMessageMentionRead(UpdateMessageMentionRead),
+  UpdateMessageSendAcknowledged(UpdateMessageSendAcknowledged),
+  UpdateMessageSendFailed(UpdateMessageSendFailed),
+  UpdateMessageSendSucceeded(UpdateMessageSendSucceeded),
+  #[cfg(feature = "calls")] UpdateNewCallSignalingData(UpdateNewCallSignalingData),
+  UpdateNewCallbackQuery(UpdateNewCallbackQuery),
+  UpdateNewChat(UpdateNewChat),
+  UpdateNewChatJoinRequest(UpdateNewChatJoinRequest),
+  UpdateNewChosenInlineResult(UpdateNewChosenInlineResult),
+  UpdateNewCustomEvent(UpdateNewCustomEvent),
+  UpdateNewCustomQuery(UpdateNewCustomQuery),
+  UpdateNewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
+  UpdateNewInlineQuery(UpdateNewInlineQuery),
+  UpdateNewMessage(UpdateNewMessage),
+  UpdateNewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
+  #[cfg(feature = "payments")] UpdateNewShippingQuery(UpdateNewShippingQuery),
+  UpdateNotification(UpdateNotification),
+  UpdateNotificationGroup(UpdateNotificationGroup),
+  UpdateOption(UpdateOption),
+  UpdatePoll(UpdatePoll),
+  UpdatePollAnswer(UpdatePollAnswer),
+  #[cfg(feature = "stickers")] UpdateRecentStickers(UpdateRecentStickers),
+  UpdateSavedAnimations(UpdateSavedAnimations),
+  UpdateScopeNotificationSettings(UpdateScopeNotificationSettings),
+  UpdateSecretChat(UpdateSecretChat),
+  UpdateSelectedBackground(UpdateSelectedBackground),
+  UpdateServiceNotification(UpdateServiceNotification),
+  #[cfg(feature = "stickers")] UpdateStickerSet(UpdateStickerSet),
+  UpdateSuggestedActions(UpdateSuggestedActions),
+  UpdateSupergroup(UpdateSupergroup),
+  UpdateSupergroupFullInfo(UpdateSupergroupFullInfo),
+  UpdateTermsOfService(UpdateTermsOfService),
+  #[cfg(feature = "stickers")] UpdateTrendingStickerSets(UpdateTrendingStickerSets),
+  UpdateUnreadChatCount(UpdateUnreadChatCount),
+  UpdateUnreadMessageCount(UpdateUnreadMessageCount),
+  UpdateUser(UpdateUser),
+  UpdateUserChatAction(UpdateUserChatAction),
+  UpdateUserFullInfo(UpdateUserFullInfo),
+  UpdateUserPrivacySettingRules(UpdateUserPrivacySettingRules),
+  UpdateUserStatus(UpdateUserStatus),
+  UpdateUsersNearby(UpdateUsersNearby),
+
+  AuthorizationState(AuthorizationState),
+  CanTransferOwnershipResult(CanTransferOwnershipResult),
+  #[cfg(feature = "statistics")] ChatStatistics(ChatStatistics),
+  CheckChatUsernameResult(CheckChatUsernameResult),
+  #[cfg(feature = "stickers")] CheckStickerSetNameResult(CheckStickerSetNameResult),
+  InternalLinkType(InternalLinkType),
+  JsonValue(JsonValue),
+  LanguagePackStringValue(LanguagePackStringValue),
+  LogStream(LogStream),
+  LoginUrlInfo(LoginUrlInfo),
+  MessageFileType(MessageFileType),
+  OptionValue(OptionValue),
+  #[cfg(feature = "passport")] PassportElement(PassportElement),
+  ResetPasswordResult(ResetPasswordResult),
+  #[cfg(feature = "statistics")] StatisticalGraph(StatisticalGraph),
+  Update(Update),
+  AccountTtl(AccountTtl),
+  AnimatedEmoji(AnimatedEmoji),
+  Animations(Animations),
+  AuthenticationCodeInfo(AuthenticationCodeInfo),
+  AutoDownloadSettingsPresets(AutoDownloadSettingsPresets),
+  Background(Background),
+  Backgrounds(Backgrounds),
+  #[cfg(feature = "payments")] BankCardInfo(BankCardInfo),
+  BasicGroup(BasicGroup),
+  BasicGroupFullInfo(BasicGroupFullInfo),
+  BotCommands(BotCommands),
+  #[cfg(feature = "calls")] CallId(CallId),
+  CallbackQueryAnswer(CallbackQueryAnswer),
+  Chat(Chat),
+  ChatAdministrators(ChatAdministrators),
+  ChatEvents(ChatEvents),
+  ChatFilter(ChatFilter),
+  ChatFilterInfo(ChatFilterInfo),
+  ChatInviteLink(ChatInviteLink),
+  ChatInviteLinkCounts(ChatInviteLinkCounts),
+  ChatInviteLinkInfo(ChatInviteLinkInfo),
+  ChatInviteLinkMembers(ChatInviteLinkMembers),
+  ChatInviteLinks(ChatInviteLinks),
+  ChatJoinRequests(ChatJoinRequests),
+  ChatLists(ChatLists),
+  ChatMember(ChatMember),
+  ChatMembers(ChatMembers),
+  ChatPhotos(ChatPhotos),
+  Chats(Chats),
+  ChatsNearby(ChatsNearby),
+  ConnectedWebsites(ConnectedWebsites),
+  Count(Count),
+  Countries(Countries),
+  CustomRequestResult(CustomRequestResult),
+  #[cfg(feature = "statistics")] DatabaseStatistics(DatabaseStatistics),
+  DeepLinkInfo(DeepLinkInfo),
+  EmailAddressAuthenticationCodeInfo(EmailAddressAuthenticationCodeInfo),
+  Emojis(Emojis),
+  Error(Error),
+  File(File),
+  FilePart(FilePart),
+  FormattedText(FormattedText),
+  FoundMessages(FoundMessages),
+  GameHighScores(GameHighScores),
+  #[cfg(feature = "calls")] GroupCall(GroupCall),
+  #[cfg(feature = "calls")] GroupCallId(GroupCallId),
+  Hashtags(Hashtags),
+  HttpUrl(HttpUrl),
+  ImportedContacts(ImportedContacts),
+  InlineQueryResults(InlineQueryResults),
+  LanguagePackInfo(LanguagePackInfo),
+  LanguagePackStrings(LanguagePackStrings),
+  LocalizationTargetInfo(LocalizationTargetInfo),
+  LogTags(LogTags),
+  LogVerbosityLevel(LogVerbosityLevel),
+  Message(Message),
+  MessageCalendar(MessageCalendar),
+  MessageLink(MessageLink),
+  MessageLinkInfo(MessageLinkInfo),
+  MessagePositions(MessagePositions),
+  MessageSenders(MessageSenders),
+  #[cfg(feature = "statistics")] MessageStatistics(MessageStatistics),
+  MessageThreadInfo(MessageThreadInfo),
+  Messages(Messages),
+  #[cfg(feature = "statistics")] NetworkStatistics(NetworkStatistics),
+  Ok(Ok),
+  OrderInfo(OrderInfo),
+  #[cfg(feature = "passport")] PassportAuthorizationForm(PassportAuthorizationForm),
+  #[cfg(feature = "passport")] PassportElements(PassportElements),
+  #[cfg(feature = "passport")] PassportElementsWithErrors(PassportElementsWithErrors),
+  PasswordState(PasswordState),
+  #[cfg(feature = "payments")] PaymentForm(PaymentForm),
+  #[cfg(feature = "payments")] PaymentReceipt(PaymentReceipt),
+  #[cfg(feature = "payments")] PaymentResult(PaymentResult),
+  PhoneNumberInfo(PhoneNumberInfo),
+  Proxies(Proxies),
+  Proxy(Proxy),
+  PushReceiverId(PushReceiverId),
+  RecommendedChatFilters(RecommendedChatFilters),
+  RecoveryEmailAddress(RecoveryEmailAddress),
+  ScopeNotificationSettings(ScopeNotificationSettings),
+  Seconds(Seconds),
+  SecretChat(SecretChat),
+  Session(Session),
+  Sessions(Sessions),
+  SponsoredMessages(SponsoredMessages),
+  Sticker(Sticker),
+  #[cfg(feature = "stickers")] StickerSet(StickerSet),
+  #[cfg(feature = "stickers")] StickerSets(StickerSets),
+  #[cfg(feature = "stickers")] Stickers(Stickers),
+  StorageStatistics(StorageStatistics),
+  StorageStatisticsFast(StorageStatisticsFast),
+  Supergroup(Supergroup),
+  SupergroupFullInfo(SupergroupFullInfo),
+  TMeUrls(TMeUrls),
+  #[cfg(feature = "payments")] TemporaryPasswordState(TemporaryPasswordState),
+  TestBytes(TestBytes),
+  TestInt(TestInt),
+  TestString(TestString),
+  TestVectorInt(TestVectorInt),
+  TestVectorIntObject(TestVectorIntObject),
+  TestVectorString(TestVectorString),
+  TestVectorStringObject(TestVectorStringObject),
+  Text(Text),
+  TextEntities(TextEntities),
+  Updates(Updates),
+  User(User),
+  UserFullInfo(UserFullInfo),
+  UserPrivacySettingRules(UserPrivacySettingRules),
+  Users(Users),
+  #[cfg(feature = "payments")] ValidatedOrderInfo(ValidatedOrderInfo),
+  WebPage(WebPage),
+  #[cfg(feature = "instant-view")] WebPageInstantView(WebPageInstantView),
+
+}
+impl<'de> Deserialize<'de> for TdType {
+fn deserialize<D>(deserializer: D) -> Result<TdType, D::Error> where D: Deserializer<'de> {
+    use serde::de::Error;
+    rtd_enum_deserialize!(
+      TdType, Unknown, no_default;
+  (testUseUpdate, TestUseUpdate);
+  (updateActiveNotifications, UpdateActiveNotifications);
+  (updateAnimatedEmojiMessageClicked, UpdateAnimatedEmojiMessageClicked);
+  (updateAnimationSearchParameters, UpdateAnimationSearchParameters);
+  (updateAuthorizationState, UpdateAuthorizationState);
+  (updateBasicGroup, UpdateBasicGroup);
+  (updateBasicGroupFullInfo, UpdateBasicGroupFullInfo);
+  #[cfg(feature = "calls")] (updateCall, UpdateCall);
+  (updateChatActionBar, UpdateChatActionBar);
+  (updateChatDefaultDisableNotification, UpdateChatDefaultDisableNotification);
+  (updateChatDraftMessage, UpdateChatDraftMessage);
+  (updateChatFilters, UpdateChatFilters);
+  (updateChatHasScheduledMessages, UpdateChatHasScheduledMessages);
+  (updateChatIsBlocked, UpdateChatIsBlocked);
+  (updateChatIsMarkedAsUnread, UpdateChatIsMarkedAsUnread);
+  (updateChatLastMessage, UpdateChatLastMessage);
+  (updateChatMember, UpdateChatMember);
+  (updateChatMessageTtlSetting, UpdateChatMessageTtlSetting);
+  (updateChatNotificationSettings, UpdateChatNotificationSettings);
+  (updateChatOnlineMemberCount, UpdateChatOnlineMemberCount);
+  (updateChatPendingJoinRequests, UpdateChatPendingJoinRequests);
+  (updateChatPermissions, UpdateChatPermissions);
+  (updateChatPhoto, UpdateChatPhoto);
+  (updateChatPosition, UpdateChatPosition);
+  (updateChatReadInbox, UpdateChatReadInbox);
+  (updateChatReadOutbox, UpdateChatReadOutbox);
+  (updateChatReplyMarkup, UpdateChatReplyMarkup);
+  (updateChatTheme, UpdateChatTheme);
+  (updateChatThemes, UpdateChatThemes);
+  (updateChatTitle, UpdateChatTitle);
+  (updateChatUnreadMentionCount, UpdateChatUnreadMentionCount);
+  (updateChatVideoChat, UpdateChatVideoChat);
+  (updateConnectionState, UpdateConnectionState);
+  (updateDeleteMessages, UpdateDeleteMessages);
+  (updateDiceEmojis, UpdateDiceEmojis);
+  #[cfg(feature = "stickers")] (updateFavoriteStickers, UpdateFavoriteStickers);
+  (updateFile, UpdateFile);
+  (updateFileGenerationStart, UpdateFileGenerationStart);
+  (updateFileGenerationStop, UpdateFileGenerationStop);
+  #[cfg(feature = "calls")] (updateGroupCall, UpdateGroupCall);
+  #[cfg(feature = "calls")] (updateGroupCallParticipant, UpdateGroupCallParticipant);
+  (updateHavePendingNotifications, UpdateHavePendingNotifications);
+  #[cfg(feature = "stickers")] (updateInstalledStickerSets, UpdateInstalledStickerSets);
+  (updateLanguagePackStrings, UpdateLanguagePackStrings);
+  (updateMessageContent, UpdateMessageContent);
+  (updateMessageContentOpened, UpdateMessageContentOpened);
+  (updateMessageEdited, UpdateMessageEdited);
+  (updateMessageInteractionInfo, UpdateMessageInteractionInfo);
+  (updateMessageIsPinned, UpdateMessageIsPinned);
+  (updateMessageLiveLocationViewed, UpdateMessageLiveLocationViewed);
+  (updateMessageMentionRead, UpdateMessageMentionRead);
+  (updateMessageSendAcknowledged, UpdateMessageSendAcknowledged);
+  (updateMessageSendFailed, UpdateMessageSendFailed);
+  (updateMessageSendSucceeded, UpdateMessageSendSucceeded);
+  #[cfg(feature = "calls")] (updateNewCallSignalingData, UpdateNewCallSignalingData);
+  (updateNewCallbackQuery, UpdateNewCallbackQuery);
+  (updateNewChat, UpdateNewChat);
+  (updateNewChatJoinRequest, UpdateNewChatJoinRequest);
+  (updateNewChosenInlineResult, UpdateNewChosenInlineResult);
+  (updateNewCustomEvent, UpdateNewCustomEvent);
+  (updateNewCustomQuery, UpdateNewCustomQuery);
+  (updateNewInlineCallbackQuery, UpdateNewInlineCallbackQuery);
+  (updateNewInlineQuery, UpdateNewInlineQuery);
+  (updateNewMessage, UpdateNewMessage);
+  (updateNewPreCheckoutQuery, UpdateNewPreCheckoutQuery);
+  #[cfg(feature = "payments")] (updateNewShippingQuery, UpdateNewShippingQuery);
+  (updateNotification, UpdateNotification);
+  (updateNotificationGroup, UpdateNotificationGroup);
+  (updateOption, UpdateOption);
+  (updatePoll, UpdatePoll);
+  (updatePollAnswer, UpdatePollAnswer);
+  #[cfg(feature = "stickers")] (updateRecentStickers, UpdateRecentStickers);
+  (updateSavedAnimations, UpdateSavedAnimations);
+  (updateScopeNotificationSettings, UpdateScopeNotificationSettings);
+  (updateSecretChat, UpdateSecretChat);
+  (updateSelectedBackground, UpdateSelectedBackground);
+  (updateServiceNotification, UpdateServiceNotification);
+  #[cfg(feature = "stickers")] (updateStickerSet, UpdateStickerSet);
+  (updateSuggestedActions, UpdateSuggestedActions);
+  (updateSupergroup, UpdateSupergroup);
+  (updateSupergroupFullInfo, UpdateSupergroupFullInfo);
+  (updateTermsOfService, UpdateTermsOfService);
+  #[cfg(feature = "stickers")] (updateTrendingStickerSets, UpdateTrendingStickerSets);
+  (updateUnreadChatCount, UpdateUnreadChatCount);
+  (updateUnreadMessageCount, UpdateUnreadMessageCount);
+  (updateUser, UpdateUser);
+  (updateUserChatAction, UpdateUserChatAction);
+  (updateUserFullInfo, UpdateUserFullInfo);
+  (updateUserPrivacySettingRules, UpdateUserPrivacySettingRules);
+  (updateUserStatus, UpdateUserStatus);
+  (updateUsersNearby, UpdateUsersNearby);
+
+  (AuthorizationState, AuthorizationState);
+  (CanTransferOwnershipResult, CanTransferOwnershipResult);
+  #[cfg(feature = "statistics")] (ChatStatistics, ChatStatistics);
+  (CheckChatUsernameResult, CheckChatUsernameResult);
+  #[cfg(feature = "stickers")] (CheckStickerSetNameResult, CheckStickerSetNameResult);
+  (InternalLinkType, InternalLinkType);
+  (JsonValue, JsonValue);
+  (LanguagePackStringValue, LanguagePackStringValue);
+  (LogStream, LogStream);
+  (LoginUrlInfo, LoginUrlInfo);
+  (MessageFileType, MessageFileType);
+  (OptionValue, OptionValue);
+  #[cfg(feature = "passport")] (PassportElement, PassportElement);
+  (ResetPasswordResult, ResetPasswordResult);
+  #[cfg(feature = "statistics")] (StatisticalGraph, StatisticalGraph);
+  (Update, Update);
+  (accountTtl, AccountTtl);
+  (animatedEmoji, AnimatedEmoji);
+  (animations, Animations);
+  (authenticationCodeInfo, AuthenticationCodeInfo);
+  (autoDownloadSettingsPresets, AutoDownloadSettingsPresets);
+  (background, Background);
+  (backgrounds, Backgrounds);
+  #[cfg(feature = "payments")] (bankCardInfo, BankCardInfo);
+  (basicGroup, BasicGroup);
+  (basicGroupFullInfo, BasicGroupFullInfo);
+  (botCommands, BotCommands);
+  #[cfg(feature = "calls")] (callId, CallId);
+  (callbackQueryAnswer, CallbackQueryAnswer);
+  (chat, Chat);
+  (chatAdministrators, ChatAdministrators);
+  (chatEvents, ChatEvents);
+  (chatFilter, ChatFilter);
+  (chatFilterInfo, ChatFilterInfo);
+  (chatInviteLink, ChatInviteLink);
+  (chatInviteLinkCounts, ChatInviteLinkCounts);
+  (chatInviteLinkInfo, ChatInviteLinkInfo);
+  (chatInviteLinkMembers, ChatInviteLinkMembers);
+  (chatInviteLinks, ChatInviteLinks);
+  (chatJoinRequests, ChatJoinRequests);
+  (chatLists, ChatLists);
+  (chatMember, ChatMember);
+  (chatMembers, ChatMembers);
+  (chatPhotos, ChatPhotos);
+  (chats, Chats);
+  (chatsNearby, ChatsNearby);
+  (connectedWebsites, ConnectedWebsites);
+  (count, Count);
+  (countries, Countries);
+  (customRequestResult, CustomRequestResult);
+  #[cfg(feature = "statistics")] (databaseStatistics, DatabaseStatistics);
+  (deepLinkInfo, DeepLinkInfo);
+  (emailAddressAuthenticationCodeInfo, EmailAddressAuthenticationCodeInfo);
+  (emojis, Emojis);
+  (error, Error);
+  (file, File);
+  (filePart, FilePart);
+  (formattedText, FormattedText);
+  (foundMessages, FoundMessages);
+  (gameHighScores, GameHighScores);
+  #[cfg(feature = "calls")] (groupCall, GroupCall);
+  #[cfg(feature = "calls")] (groupCallId, GroupCallId);
+  (hashtags, Hashtags);
+  (httpUrl, HttpUrl);
+  (importedContacts, ImportedContacts);
+  (inlineQueryResults, InlineQueryResults);
+  (languagePackInfo, LanguagePackInfo);
+  (languagePackStrings, LanguagePackStrings);
+  (localizationTargetInfo, LocalizationTargetInfo);
+  (logTags, LogTags);
+  (logVerbosityLevel, LogVerbosityLevel);
+  (message, Message);
+  (messageCalendar, MessageCalendar);
+  (messageLink, MessageLink);
+  (messageLinkInfo, MessageLinkInfo);
+  (messagePositions, MessagePositions);
+  (messageSenders, MessageSenders);
+  #[cfg(feature = "statistics")] (messageStatistics, MessageStatistics);
+  (messageThreadInfo, MessageThreadInfo);
+  (messages, Messages);
+  #[cfg(feature = "statistics")] (networkStatistics, NetworkStatistics);
+  (ok, Ok);
+  (orderInfo, OrderInfo);
+  #[cfg(feature = "passport")] (passportAuthorizationForm, PassportAuthorizationForm);
+  #[cfg(feature = "passport")] (passportElements, PassportElements);
+  #[cfg(feature = "passport")] (passportElementsWithErrors, PassportElementsWithErrors);
+  (passwordState, PasswordState);
+  #[cfg(feature = "payments")] (paymentForm, PaymentForm);
+  #[cfg(feature = "payments")] (paymentReceipt, PaymentReceipt);
+  #[cfg(feature = "payments")] (paymentResult, PaymentResult);
+  (phoneNumberInfo, PhoneNumberInfo);
+  (proxies, Proxies);
+  (proxy, Proxy);
+  (pushReceiverId, PushReceiverId);
+  (recommendedChatFilters, RecommendedChatFilters);
+  (recoveryEmailAddress, RecoveryEmailAddress);
+  (scopeNotificationSettings, ScopeNotificationSettings);
+  (seconds, Seconds);
+  (secretChat, SecretChat);
+  (session, Session);
+  (sessions, Sessions);
+  (sponsoredMessages, SponsoredMessages);
+  (sticker, Sticker);
+  #[cfg(feature = "stickers")] (stickerSet, StickerSet);
+  #[cfg(feature = "stickers")] (stickerSets, StickerSets);
+  #[cfg(feature = "stickers")] (stickers, Stickers);
+  (storageStatistics, StorageStatistics);
+  (storageStatisticsFast, StorageStatisticsFast);
+  (supergroup, Supergroup);
+  (supergroupFullInfo, SupergroupFullInfo);
+  (tMeUrls, TMeUrls);
+  #[cfg(feature = "payments")] (temporaryPasswordState, TemporaryPasswordState);
+  (testBytes, TestBytes);
+  (testInt, TestInt);
+  (testString, TestString);
+  (testVectorInt, TestVectorInt);
+  (testVectorIntObject, TestVectorIntObject);
+  (testVectorString, TestVectorString);
+  (testVectorStringObject, TestVectorStringObject);
+  (text, Text);
+  (textEntities, TextEntities);
+  (updates, Updates);
+  (user, User);
+  (userFullInfo, UserFullInfo);
+  (userPrivacySettingRules, UserPrivacySettingRules);
+  (users, Users);
+  #[cfg(feature = "payments")] (validatedOrderInfo, ValidatedOrderInfo);
+  (webPage, WebPage);
+  #[cfg(feature = "instant-view")] (webPageInstantView, WebPageInstantView);
+
+ )(deserializer)
+
+ }
+}
+
+impl RObject for TdType {
+  #[doc(hidden)]
+  fn td_name(&self) -> &str {
+    match self {
+      Self::Unknown(value) => value.td_name(),
+      Self::TestUseUpdate(value) => value.td_name(),
+      Self::UpdateActiveNotifications(value) => value.td_name(),
+      Self::UpdateAnimatedEmojiMessageClicked(value) => value.td_name(),
+      Self::UpdateAnimationSearchParameters(value) => value.td_name(),
+      Self::UpdateAuthorizationState(value) => value.td_name(),
+      Self::UpdateBasicGroup(value) => value.td_name(),
+      Self::UpdateBasicGroupFullInfo(value) => value.td_name(),
+      #[cfg(feature = "calls")] Self::UpdateCall(value) => value.td_name(),
+      Self::UpdateChatActionBar(value) => value.td_name(),
+      Self::UpdateChatDefaultDisableNotification(value) => value.td_name(),
+      Self::UpdateChatDraftMessage(value) => value.td_name(),
+      Self::UpdateChatFilters(value) => value.td_name(),
+      Self::UpdateChatHasScheduledMessages(value) => value.td_name(),
+      Self::UpdateChatIsBlocked(value) => value.td_name(),
+      Self::UpdateChatIsMarkedAsUnread(value) => value.td_name(),
+      Self::UpdateChatLastMessage(value) => value.td_name(),
+      Self::UpdateChatMember(value) => value.td_name(),
+      Self::UpdateChatMessageTtlSetting(value) => value.td_name(),
+      Self::UpdateChatNotificationSettings(value) => value.td_name(),
+      Self::UpdateChatOnlineMemberCount(value) => value.td_name(),
+      Self::UpdateChatPendingJoinRequests(value) => value.td_name(),
+      Self::UpdateChatPermissions(value) => value.td_name(),
+      Self::UpdateChatPhoto(value) => value.td_name(),
+      Self::UpdateChatPosition(value) => value.td_name(),
+      Self::UpdateChatReadInbox(value) => value.td_name(),
+      Self::UpdateChatReadOutbox(value) => value.td_name(),
+      Self::UpdateChatReplyMarkup(value) => value.td_name(),
+      Self::UpdateChatTheme(value) => value.td_name(),
+      Self::UpdateChatThemes(value) => value.td_name(),
+      Self::UpdateChatTitle(value) => value.td_name(),
+      Self::UpdateChatUnreadMentionCount(value) => value.td_name(),
+      Self::UpdateChatVideoChat(value) => value.td_name(),
+      Self::UpdateConnectionState(value) => value.td_name(),
+      Self::UpdateDeleteMessages(value) => value.td_name(),
+      Self::UpdateDiceEmojis(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::UpdateFavoriteStickers(value) => value.td_name(),
+      Self::UpdateFile(value) => value.td_name(),
+      Self::UpdateFileGenerationStart(value) => value.td_name(),
+      Self::UpdateFileGenerationStop(value) => value.td_name(),
+      #[cfg(feature = "calls")] Self::UpdateGroupCall(value) => value.td_name(),
+      #[cfg(feature = "calls")] Self::UpdateGroupCallParticipant(value) => value.td_name(),
+      Self::UpdateHavePendingNotifications(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::UpdateInstalledStickerSets(value) => value.td_name(),
+      Self::UpdateLanguagePackStrings(value) => value.td_name(),
+      Self::UpdateMessageContent(value) => value.td_name(),
+      Self::UpdateMessageContentOpened(value) => value.td_name(),
+      Self::UpdateMessageEdited(value) => value.td_name(),
+      Self::UpdateMessageInteractionInfo(value) => value.td_name(),
+      Self::UpdateMessageIsPinned(value) => value.td_name(),
+      Self::UpdateMessageLiveLocationViewed(value) => value.td_name(),
+      Self::UpdateMessageMentionRead(value) => value.td_name(),
+      Self::UpdateMessageSendAcknowledged(value) => value.td_name(),
+      Self::UpdateMessageSendFailed(value) => value.td_name(),
+      Self::UpdateMessageSendSucceeded(value) => value.td_name(),
+      #[cfg(feature = "calls")] Self::UpdateNewCallSignalingData(value) => value.td_name(),
+      Self::UpdateNewCallbackQuery(value) => value.td_name(),
+      Self::UpdateNewChat(value) => value.td_name(),
+      Self::UpdateNewChatJoinRequest(value) => value.td_name(),
+      Self::UpdateNewChosenInlineResult(value) => value.td_name(),
+      Self::UpdateNewCustomEvent(value) => value.td_name(),
+      Self::UpdateNewCustomQuery(value) => value.td_name(),
+      Self::UpdateNewInlineCallbackQuery(value) => value.td_name(),
+      Self::UpdateNewInlineQuery(value) => value.td_name(),
+      Self::UpdateNewMessage(value) => value.td_name(),
+      Self::UpdateNewPreCheckoutQuery(value) => value.td_name(),
+      #[cfg(feature = "payments")] Self::UpdateNewShippingQuery(value) => value.td_name(),
+      Self::UpdateNotification(value) => value.td_name(),
+      Self::UpdateNotificationGroup(value) => value.td_name(),
+      Self::UpdateOption(value) => value.td_name(),
+      Self::UpdatePoll(value) => value.td_name(),
+      Self::UpdatePollAnswer(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::UpdateRecentStickers(value) => value.td_name(),
+      Self::UpdateSavedAnimations(value) => value.td_name(),
+      Self::UpdateScopeNotificationSettings(value) => value.td_name(),
+      Self::UpdateSecretChat(value) => value.td_name(),
+      Self::UpdateSelectedBackground(value) => value.td_name(),
+      Self::UpdateServiceNotification(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::UpdateStickerSet(value) => value.td_name(),
+      Self::UpdateSuggestedActions(value) => value.td_name(),
+      Self::UpdateSupergroup(value) => value.td_name(),
+      Self::UpdateSupergroupFullInfo(value) => value.td_name(),
+      Self::UpdateTermsOfService(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::UpdateTrendingStickerSets(value) => value.td_name(),
+      Self::UpdateUnreadChatCount(value) => value.td_name(),
+      Self::UpdateUnreadMessageCount(value) => value.td_name(),
+      Self::UpdateUser(value) => value.td_name(),
+      Self::UpdateUserChatAction(value) => value.td_name(),
+      Self::UpdateUserFullInfo(value) => value.td_name(),
+      Self::UpdateUserPrivacySettingRules(value) => value.td_name(),
+      Self::UpdateUserStatus(value) => value.td_name(),
+      Self::UpdateUsersNearby(value) => value.td_name(),
+    
+      Self::AuthorizationState(value) => value.td_name(),
+      Self::CanTransferOwnershipResult(value) => value.td_name(),
+      #[cfg(feature = "statistics")] Self::ChatStatistics(value) => value.td_name(),
+      Self::CheckChatUsernameResult(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::CheckStickerSetNameResult(value) => value.td_name(),
+      Self::InternalLinkType(value) => value.td_name(),
+      Self::JsonValue(value) => value.td_name(),
+      Self::LanguagePackStringValue(value) => value.td_name(),
+      Self::LogStream(value) => value.td_name(),
+      Self::LoginUrlInfo(value) => value.td_name(),
+      Self::MessageFileType(value) => value.td_name(),
+      Self::OptionValue(value) => value.td_name(),
+      #[cfg(feature = "passport")] Self::PassportElement(value) => value.td_name(),
+      Self::ResetPasswordResult(value) => value.td_name(),
+      #[cfg(feature = "statistics")] Self::StatisticalGraph(value) => value.td_name(),
+      Self::Update(value) => value.td_name(),
+      Self::AccountTtl(value) => value.td_name(),
+      Self::AnimatedEmoji(value) => value.td_name(),
+      Self::Animations(value) => value.td_name(),
+      Self::AuthenticationCodeInfo(value) => value.td_name(),
+      Self::AutoDownloadSettingsPresets(value) => value.td_name(),
+      Self::Background(value) => value.td_name(),
+      Self::Backgrounds(value) => value.td_name(),
+      #[cfg(feature = "payments")] Self::BankCardInfo(value) => value.td_name(),
+      Self::BasicGroup(value) => value.td_name(),
+      Self::BasicGroupFullInfo(value) => value.td_name(),
+      Self::BotCommands(value) => value.td_name(),
+      #[cfg(feature = "calls")] Self::CallId(value) => value.td_name(),
+      Self::CallbackQueryAnswer(value) => value.td_name(),
+      Self::Chat(value) => value.td_name(),
+      Self::ChatAdministrators(value) => value.td_name(),
+      Self::ChatEvents(value) => value.td_name(),
+      Self::ChatFilter(value) => value.td_name(),
+      Self::ChatFilterInfo(value) => value.td_name(),
+      Self::ChatInviteLink(value) => value.td_name(),
+      Self::ChatInviteLinkCounts(value) => value.td_name(),
+      Self::ChatInviteLinkInfo(value) => value.td_name(),
+      Self::ChatInviteLinkMembers(value) => value.td_name(),
+      Self::ChatInviteLinks(value) => value.td_name(),
+      Self::ChatJoinRequests(value) => value.td_name(),
+      Self::ChatLists(value) => value.td_name(),
+      Self::ChatMember(value) => value.td_name(),
+      Self::ChatMembers(value) => value.td_name(),
+      Self::ChatPhotos(value) => value.td_name(),
+      Self::Chats(value) => value.td_name(),
+      Self::ChatsNearby(value) => value.td_name(),
+      Self::ConnectedWebsites(value) => value.td_name(),
+      Self::Count(value) => value.td_name(),
+      Self::Countries(value) => value.td_name(),
+      Self::CustomRequestResult(value) => value.td_name(),
+      #[cfg(feature = "statistics")] Self::DatabaseStatistics(value) => value.td_name(),
+      Self::DeepLinkInfo(value) => value.td_name(),
+      Self::EmailAddressAuthenticationCodeInfo(value) => value.td_name(),
+      Self::Emojis(value) => value.td_name(),
+      Self::Error(value) => value.td_name(),
+      Self::File(value) => value.td_name(),
+      Self::FilePart(value) => value.td_name(),
+      Self::FormattedText(value) => value.td_name(),
+      Self::FoundMessages(value) => value.td_name(),
+      Self::GameHighScores(value) => value.td_name(),
+      #[cfg(feature = "calls")] Self::GroupCall(value) => value.td_name(),
+      #[cfg(feature = "calls")] Self::GroupCallId(value) => value.td_name(),
+      Self::Hashtags(value) => value.td_name(),
+      Self::HttpUrl(value) => value.td_name(),
+      Self::ImportedContacts(value) => value.td_name(),
+      Self::InlineQueryResults(value) => value.td_name(),
+      Self::LanguagePackInfo(value) => value.td_name(),
+      Self::LanguagePackStrings(value) => value.td_name(),
+      Self::LocalizationTargetInfo(value) => value.td_name(),
+      Self::LogTags(value) => value.td_name(),
+      Self::LogVerbosityLevel(value) => value.td_name(),
+      Self::Message(value) => value.td_name(),
+      Self::MessageCalendar(value) => value.td_name(),
+      Self::MessageLink(value) => value.td_name(),
+      Self::MessageLinkInfo(value) => value.td_name(),
+      Self::MessagePositions(value) => value.td_name(),
+      Self::MessageSenders(value) => value.td_name(),
+      #[cfg(feature = "statistics")] Self::MessageStatistics(value) => value.td_name(),
+      Self::MessageThreadInfo(value) => value.td_name(),
+      Self::Messages(value) => value.td_name(),
+      #[cfg(feature = "statistics")] Self::NetworkStatistics(value) => value.td_name(),
+      Self::Ok(value) => value.td_name(),
+      Self::OrderInfo(value) => value.td_name(),
+      #[cfg(feature = "passport")] Self::PassportAuthorizationForm(value) => value.td_name(),
+      #[cfg(feature = "passport")] Self::PassportElements(value) => value.td_name(),
+      #[cfg(feature = "passport")] Self::PassportElementsWithErrors(value) => value.td_name(),
+      Self::PasswordState(value) => value.td_name(),
+      #[cfg(feature = "payments")] Self::PaymentForm(value) => value.td_name(),
+      #[cfg(feature = "payments")] Self::PaymentReceipt(value) => value.td_name(),
+      #[cfg(feature = "payments")] Self::PaymentResult(value) => value.td_name(),
+      Self::PhoneNumberInfo(value) => value.td_name(),
+      Self::Proxies(value) => value.td_name(),
+      Self::Proxy(value) => value.td_name(),
+      Self::PushReceiverId(value) => value.td_name(),
+      Self::RecommendedChatFilters(value) => value.td_name(),
+      Self::RecoveryEmailAddress(value) => value.td_name(),
+      Self::ScopeNotificationSettings(value) => value.td_name(),
+      Self::Seconds(value) => value.td_name(),
+      Self::SecretChat(value) => value.td_name(),
+      Self::Session(value) => value.td_name(),
+      Self::Sessions(value) => value.td_name(),
+      Self::SponsoredMessages(value) => value.td_name(),
+      Self::Sticker(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::StickerSet(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::StickerSets(value) => value.td_name(),
+      #[cfg(feature = "stickers")] Self::Stickers(value) => value.td_name(),
+      Self::StorageStatistics(value) => value.td_name(),
+      Self::StorageStatisticsFast(value) => value.td_name(),
+      Self::Supergroup(value) => value.td_name(),
+      Self::SupergroupFullInfo(value) => value.td_name(),
+      Self::TMeUrls(value) => value.td_name(),
+      #[cfg(feature = "payments")] Self::TemporaryPasswordState(value) => value.td_name(),
+      Self::TestBytes(value) => value.td_name(),
+      Self::TestInt(value) => value.td_name(),
+      Self::TestString(value) => value.td_name(),
+      Self::TestVectorInt(value) => value.td_name(),
+      Self::TestVectorIntObject(value) => value.td_name(),
+      Self::TestVectorString(value) => value.td_name(),
+      Self::TestVectorStringObject(value) => value.td_name(),
+      Self::Text(value) => value.td_name(),
+      Self::TextEntities(value) => value.td_name(),
+      Self::Updates(value) => value.td_name(),
+      Self::User(value) => value.td_name(),
+      Self::UserFullInfo(value) => value.td_name(),
+      Self::UserPrivacySettingRules(value) => value.td_name(),
+      Self::Users(value) => value.td_name(),
+      #[cfg(feature = "payments")] Self::ValidatedOrderInfo(value) => value.td_name(),
+      Self::WebPage(value) => value.td_name(),
+      #[cfg(feature = "instant-view")] Self::WebPageInstantView(value) => value.td_name(),
+    
+    }
+  }
+  #[doc(hidden)]
+  fn extra(&self) -> Option<Extra> {
+    match self {
+        Self::Unknown(value) => value.extra(),
+        Self::TestUseUpdate(value) => value.extra(),
+        Self::UpdateActiveNotifications(value) => value.extra(),
+        Self::UpdateAnimatedEmojiMessageClicked(value) => value.extra(),
+        Self::UpdateAnimationSearchParameters(value) => value.extra(),
+        Self::UpdateAuthorizationState(value) => value.extra(),
+        Self::UpdateBasicGroup(value) => value.extra(),
+        Self::UpdateBasicGroupFullInfo(value) => value.extra(),
+        #[cfg(feature = "calls")] Self::UpdateCall(value) => value.extra(),
+        Self::UpdateChatActionBar(value) => value.extra(),
+        Self::UpdateChatDefaultDisableNotification(value) => value.extra(),
+        Self::UpdateChatDraftMessage(value) => value.extra(),
+        Self::UpdateChatFilters(value) => value.extra(),
+        Self::UpdateChatHasScheduledMessages(value) => value.extra(),
+        Self::UpdateChatIsBlocked(value) => value.extra(),
+        Self::UpdateChatIsMarkedAsUnread(value) => value.extra(),
+        Self::UpdateChatLastMessage(value) => value.extra(),
+        Self::UpdateChatMember(value) => value.extra(),
+        Self::UpdateChatMessageTtlSetting(value) => value.extra(),
+        Self::UpdateChatNotificationSettings(value) => value.extra(),
+        Self::UpdateChatOnlineMemberCount(value) => value.extra(),
+        Self::UpdateChatPendingJoinRequests(value) => value.extra(),
+        Self::UpdateChatPermissions(value) => value.extra(),
+        Self::UpdateChatPhoto(value) => value.extra(),
+        Self::UpdateChatPosition(value) => value.extra(),
+        Self::UpdateChatReadInbox(value) => value.extra(),
+        Self::UpdateChatReadOutbox(value) => value.extra(),
+        Self::UpdateChatReplyMarkup(value) => value.extra(),
+        Self::UpdateChatTheme(value) => value.extra(),
+        Self::UpdateChatThemes(value) => value.extra(),
+        Self::UpdateChatTitle(value) => value.extra(),
+        Self::UpdateChatUnreadMentionCount(value) => value.extra(),
+        Self::UpdateChatVideoChat(value) => value.extra(),
+        Self::UpdateConnectionState(value) => value.extra(),
+        Self::UpdateDeleteMessages(value) => value.extra(),
+        Self::UpdateDiceEmojis(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::UpdateFavoriteStickers(value) => value.extra(),
+        Self::UpdateFile(value) => value.extra(),
+        Self::UpdateFileGenerationStart(value) => value.extra(),
+        Self::UpdateFileGenerationStop(value) => value.extra(),
+        #[cfg(feature = "calls")] Self::UpdateGroupCall(value) => value.extra(),
+        #[cfg(feature = "calls")] Self::UpdateGroupCallParticipant(value) => value.extra(),
+        Self::UpdateHavePendingNotifications(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::UpdateInstalledStickerSets(value) => value.extra(),
+        Self::UpdateLanguagePackStrings(value) => value.extra(),
+        Self::UpdateMessageContent(value) => value.extra(),
+        Self::UpdateMessageContentOpened(value) => value.extra(),
+        Self::UpdateMessageEdited(value) => value.extra(),
+        Self::UpdateMessageInteractionInfo(value) => value.extra(),
+        Self::UpdateMessageIsPinned(value) => value.extra(),
+        Self::UpdateMessageLiveLocationViewed(value) => value.extra(),
+        Self::UpdateMessageMentionRead(value) => value.extra(),
+        Self::UpdateMessageSendAcknowledged(value) => value.extra(),
+        Self::UpdateMessageSendFailed(value) => value.extra(),
+        Self::UpdateMessageSendSucceeded(value) => value.extra(),
+        #[cfg(feature = "calls")] Self::UpdateNewCallSignalingData(value) => value.extra(),
+        Self::UpdateNewCallbackQuery(value) => value.extra(),
+        Self::UpdateNewChat(value) => value.extra(),
+        Self::UpdateNewChatJoinRequest(value) => value.extra(),
+        Self::UpdateNewChosenInlineResult(value) => value.extra(),
+        Self::UpdateNewCustomEvent(value) => value.extra(),
+        Self::UpdateNewCustomQuery(value) => value.extra(),
+        Self::UpdateNewInlineCallbackQuery(value) => value.extra(),
+        Self::UpdateNewInlineQuery(value) => value.extra(),
+        Self::UpdateNewMessage(value) => value.extra(),
+        Self::UpdateNewPreCheckoutQuery(value) => value.extra(),
+        #[cfg(feature = "payments")] Self::UpdateNewShippingQuery(value) => value.extra(),
+        Self::UpdateNotification(value) => value.extra(),
+        Self::UpdateNotificationGroup(value) => value.extra(),
+        Self::UpdateOption(value) => value.extra(),
+        Self::UpdatePoll(value) => value.extra(),
+        Self::UpdatePollAnswer(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::UpdateRecentStickers(value) => value.extra(),
+        Self::UpdateSavedAnimations(value) => value.extra(),
+        Self::UpdateScopeNotificationSettings(value) => value.extra(),
+        Self::UpdateSecretChat(value) => value.extra(),
+        Self::UpdateSelectedBackground(value) => value.extra(),
+        Self::UpdateServiceNotification(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::UpdateStickerSet(value) => value.extra(),
+        Self::UpdateSuggestedActions(value) => value.extra(),
+        Self::UpdateSupergroup(value) => value.extra(),
+        Self::UpdateSupergroupFullInfo(value) => value.extra(),
+        Self::UpdateTermsOfService(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::UpdateTrendingStickerSets(value) => value.extra(),
+        Self::UpdateUnreadChatCount(value) => value.extra(),
+        Self::UpdateUnreadMessageCount(value) => value.extra(),
+        Self::UpdateUser(value) => value.extra(),
+        Self::UpdateUserChatAction(value) => value.extra(),
+        Self::UpdateUserFullInfo(value) => value.extra(),
+        Self::UpdateUserPrivacySettingRules(value) => value.extra(),
+        Self::UpdateUserStatus(value) => value.extra(),
+        Self::UpdateUsersNearby(value) => value.extra(),
+      
+        Self::AuthorizationState(value) => value.extra(),
+        Self::CanTransferOwnershipResult(value) => value.extra(),
+        #[cfg(feature = "statistics")] Self::ChatStatistics(value) => value.extra(),
+        Self::CheckChatUsernameResult(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::CheckStickerSetNameResult(value) => value.extra(),
+        Self::InternalLinkType(value) => value.extra(),
+        Self::JsonValue(value) => value.extra(),
+        Self::LanguagePackStringValue(value) => value.extra(),
+        Self::LogStream(value) => value.extra(),
+        Self::LoginUrlInfo(value) => value.extra(),
+        Self::MessageFileType(value) => value.extra(),
+        Self::OptionValue(value) => value.extra(),
+        #[cfg(feature = "passport")] Self::PassportElement(value) => value.extra(),
+        Self::ResetPasswordResult(value) => value.extra(),
+        #[cfg(feature = "statistics")] Self::StatisticalGraph(value) => value.extra(),
+        Self::Update(value) => value.extra(),
+        Self::AccountTtl(value) => value.extra(),
+        Self::AnimatedEmoji(value) => value.extra(),
+        Self::Animations(value) => value.extra(),
+        Self::AuthenticationCodeInfo(value) => value.extra(),
+        Self::AutoDownloadSettingsPresets(value) => value.extra(),
+        Self::Background(value) => value.extra(),
+        Self::Backgrounds(value) => value.extra(),
+        #[cfg(feature = "payments")] Self::BankCardInfo(value) => value.extra(),
+        Self::BasicGroup(value) => value.extra(),
+        Self::BasicGroupFullInfo(value) => value.extra(),
+        Self::BotCommands(value) => value.extra(),
+        #[cfg(feature = "calls")] Self::CallId(value) => value.extra(),
+        Self::CallbackQueryAnswer(value) => value.extra(),
+        Self::Chat(value) => value.extra(),
+        Self::ChatAdministrators(value) => value.extra(),
+        Self::ChatEvents(value) => value.extra(),
+        Self::ChatFilter(value) => value.extra(),
+        Self::ChatFilterInfo(value) => value.extra(),
+        Self::ChatInviteLink(value) => value.extra(),
+        Self::ChatInviteLinkCounts(value) => value.extra(),
+        Self::ChatInviteLinkInfo(value) => value.extra(),
+        Self::ChatInviteLinkMembers(value) => value.extra(),
+        Self::ChatInviteLinks(value) => value.extra(),
+        Self::ChatJoinRequests(value) => value.extra(),
+        Self::ChatLists(value) => value.extra(),
+        Self::ChatMember(value) => value.extra(),
+        Self::ChatMembers(value) => value.extra(),
+        Self::ChatPhotos(value) => value.extra(),
+        Self::Chats(value) => value.extra(),
+        Self::ChatsNearby(value) => value.extra(),
+        Self::ConnectedWebsites(value) => value.extra(),
+        Self::Count(value) => value.extra(),
+        Self::Countries(value) => value.extra(),
+        Self::CustomRequestResult(value) => value.extra(),
+        #[cfg(feature = "statistics")] Self::DatabaseStatistics(value) => value.extra(),
+        Self::DeepLinkInfo(value) => value.extra(),
+        Self::EmailAddressAuthenticationCodeInfo(value) => value.extra(),
+        Self::Emojis(value) => value.extra(),
+        Self::Error(value) => value.extra(),
+        Self::File(value) => value.extra(),
+        Self::FilePart(value) => value.extra(),
+        Self::FormattedText(value) => value.extra(),
+        Self::FoundMessages(value) => value.extra(),
+        Self::GameHighScores(value) => value.extra(),
+        #[cfg(feature = "calls")] Self::GroupCall(value) => value.extra(),
+        #[cfg(feature = "calls")] Self::GroupCallId(value) => value.extra(),
+        Self::Hashtags(value) => value.extra(),
+        Self::HttpUrl(value) => value.extra(),
+        Self::ImportedContacts(value) => value.extra(),
+        Self::InlineQueryResults(value) => value.extra(),
+        Self::LanguagePackInfo(value) => value.extra(),
+        Self::LanguagePackStrings(value) => value.extra(),
+        Self::LocalizationTargetInfo(value) => value.extra(),
+        Self::LogTags(value) => value.extra(),
+        Self::LogVerbosityLevel(value) => value.extra(),
+        Self::Message(value) => value.extra(),
+        Self::MessageCalendar(value) => value.extra(),
+        Self::MessageLink(value) => value.extra(),
+        Self::MessageLinkInfo(value) => value.extra(),
+        Self::MessagePositions(value) => value.extra(),
+        Self::MessageSenders(value) => value.extra(),
+        #[cfg(feature = "statistics")] Self::MessageStatistics(value) => value.extra(),
+        Self::MessageThreadInfo(value) => value.extra(),
+        Self::Messages(value) => value.extra(),
+        #[cfg(feature = "statistics")] Self::NetworkStatistics(value) => value.extra(),
+        Self::Ok(value) => value.extra(),
+        Self::OrderInfo(value) => value.extra(),
+        #[cfg(feature = "passport")] Self::PassportAuthorizationForm(value) => value.extra(),
+        #[cfg(feature = "passport")] Self::PassportElements(value) => value.extra(),
+        #[cfg(feature = "passport")] Self::PassportElementsWithErrors(value) => value.extra(),
+        Self::PasswordState(value) => value.extra(),
+        #[cfg(feature = "payments")] Self::PaymentForm(value) => value.extra(),
+        #[cfg(feature = "payments")] Self::PaymentReceipt(value) => value.extra(),
+        #[cfg(feature = "payments")] Self::PaymentResult(value) => value.extra(),
+        Self::PhoneNumberInfo(value) => value.extra(),
+        Self::Proxies(value) => value.extra(),
+        Self::Proxy(value) => value.extra(),
+        Self::PushReceiverId(value) => value.extra(),
+        Self::RecommendedChatFilters(value) => value.extra(),
+        Self::RecoveryEmailAddress(value) => value.extra(),
+        Self::ScopeNotificationSettings(value) => value.extra(),
+        Self::Seconds(value) => value.extra(),
+        Self::SecretChat(value) => value.extra(),
+        Self::Session(value) => value.extra(),
+        Self::Sessions(value) => value.extra(),
+        Self::SponsoredMessages(value) => value.extra(),
+        Self::Sticker(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::StickerSet(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::StickerSets(value) => value.extra(),
+        #[cfg(feature = "stickers")] Self::Stickers(value) => value.extra(),
+        Self::StorageStatistics(value) => value.extra(),
+        Self::StorageStatisticsFast(value) => value.extra(),
+        Self::Supergroup(value) => value.extra(),
+        Self::SupergroupFullInfo(value) => value.extra(),
+        Self::TMeUrls(value) => value.extra(),
+        #[cfg(feature = "payments")] Self::TemporaryPasswordState(value) => value.extra(),
+        Self::TestBytes(value) => value.extra(),
+        Self::TestInt(value) => value.extra(),
+        Self::TestString(value) => value.extra(),
+        Self::TestVectorInt(value) => value.extra(),
+        Self::TestVectorIntObject(value) => value.extra(),
+        Self::TestVectorString(value) => value.extra(),
+        Self::TestVectorStringObject(value) => value.extra(),
+        Self::Text(value) => value.extra(),
+        Self::TextEntities(value) => value.extra(),
+        Self::Updates(value) => value.extra(),
+        Self::User(value) => value.extra(),
+        Self::UserFullInfo(value) => value.extra(),
+        Self::UserPrivacySettingRules(value) => value.extra(),
+        Self::Users(value) => value.extra(),
+        #[cfg(feature = "payments")] Self::ValidatedOrderInfo(value) => value.extra(),
+        Self::WebPage(value) => value.extra(),
+        #[cfg(feature = "instant-view")] Self::WebPageInstantView(value) => value.extra(),
+      
+    }
+  }
+  /// The `@client_id` of the client that received it, see `RObject::client_id`
+  fn client_id(&self) -> Option<i32> {
+    match self {
+        Self::Unknown(value) => value.client_id(),
+        Self::TestUseUpdate(value) => value.client_id(),
+        Self::UpdateActiveNotifications(value) => value.client_id(),
+        Self::UpdateAnimatedEmojiMessageClicked(value) => value.client_id(),
+        Self::UpdateAnimationSearchParameters(value) => value.client_id(),
+        Self::UpdateAuthorizationState(value) => value.client_id(),
+        Self::UpdateBasicGroup(value) => value.client_id(),
+        Self::UpdateBasicGroupFullInfo(value) => value.client_id(),
+        #[cfg(feature = "calls")] Self::UpdateCall(value) => value.client_id(),
+        Self::UpdateChatActionBar(value) => value.client_id(),
+        Self::UpdateChatDefaultDisableNotification(value) => value.client_id(),
+        Self::UpdateChatDraftMessage(value) => value.client_id(),
+        Self::UpdateChatFilters(value) => value.client_id(),
+        Self::UpdateChatHasScheduledMessages(value) => value.client_id(),
+        Self::UpdateChatIsBlocked(value) => value.client_id(),
+        Self::UpdateChatIsMarkedAsUnread(value) => value.client_id(),
+        Self::UpdateChatLastMessage(value) => value.client_id(),
+        Self::UpdateChatMember(value) => value.client_id(),
+        Self::UpdateChatMessageTtlSetting(value) => value.client_id(),
+        Self::UpdateChatNotificationSettings(value) => value.client_id(),
+        Self::UpdateChatOnlineMemberCount(value) => value.client_id(),
+        Self::UpdateChatPendingJoinRequests(value) => value.client_id(),
+        Self::UpdateChatPermissions(value) => value.client_id(),
+        Self::UpdateChatPhoto(value) => value.client_id(),
+        Self::UpdateChatPosition(value) => value.client_id(),
+        Self::UpdateChatReadInbox(value) => value.client_id(),
+        Self::UpdateChatReadOutbox(value) => value.client_id(),
+        Self::UpdateChatReplyMarkup(value) => value.client_id(),
+        Self::UpdateChatTheme(value) => value.client_id(),
+        Self::UpdateChatThemes(value) => value.client_id(),
+        Self::UpdateChatTitle(value) => value.client_id(),
+        Self::UpdateChatUnreadMentionCount(value) => value.client_id(),
+        Self::UpdateChatVideoChat(value) => value.client_id(),
+        Self::UpdateConnectionState(value) => value.client_id(),
+        Self::UpdateDeleteMessages(value) => value.client_id(),
+        Self::UpdateDiceEmojis(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::UpdateFavoriteStickers(value) => value.client_id(),
+        Self::UpdateFile(value) => value.client_id(),
+        Self::UpdateFileGenerationStart(value) => value.client_id(),
+        Self::UpdateFileGenerationStop(value) => value.client_id(),
+        #[cfg(feature = "calls")] Self::UpdateGroupCall(value) => value.client_id(),
+        #[cfg(feature = "calls")] Self::UpdateGroupCallParticipant(value) => value.client_id(),
+        Self::UpdateHavePendingNotifications(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::UpdateInstalledStickerSets(value) => value.client_id(),
+        Self::UpdateLanguagePackStrings(value) => value.client_id(),
+        Self::UpdateMessageContent(value) => value.client_id(),
+        Self::UpdateMessageContentOpened(value) => value.client_id(),
+        Self::UpdateMessageEdited(value) => value.client_id(),
+        Self::UpdateMessageInteractionInfo(value) => value.client_id(),
+        Self::UpdateMessageIsPinned(value) => value.client_id(),
+        Self::UpdateMessageLiveLocationViewed(value) => value.client_id(),
+        Self::UpdateMessageMentionRead(value) => value.client_id(),
+        Self::UpdateMessageSendAcknowledged(value) => value.client_id(),
+        Self::UpdateMessageSendFailed(value) => value.client_id(),
+        Self::UpdateMessageSendSucceeded(value) => value.client_id(),
+        #[cfg(feature = "calls")] Self::UpdateNewCallSignalingData(value) => value.client_id(),
+        Self::UpdateNewCallbackQuery(value) => value.client_id(),
+        Self::UpdateNewChat(value) => value.client_id(),
+        Self::UpdateNewChatJoinRequest(value) => value.client_id(),
+        Self::UpdateNewChosenInlineResult(value) => value.client_id(),
+        Self::UpdateNewCustomEvent(value) => value.client_id(),
+        Self::UpdateNewCustomQuery(value) => value.client_id(),
+        Self::UpdateNewInlineCallbackQuery(value) => value.client_id(),
+        Self::UpdateNewInlineQuery(value) => value.client_id(),
+        Self::UpdateNewMessage(value) => value.client_id(),
+        Self::UpdateNewPreCheckoutQuery(value) => value.client_id(),
+        #[cfg(feature = "payments")] Self::UpdateNewShippingQuery(value) => value.client_id(),
+        Self::UpdateNotification(value) => value.client_id(),
+        Self::UpdateNotificationGroup(value) => value.client_id(),
+        Self::UpdateOption(value) => value.client_id(),
+        Self::UpdatePoll(value) => value.client_id(),
+        Self::UpdatePollAnswer(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::UpdateRecentStickers(value) => value.client_id(),
+        Self::UpdateSavedAnimations(value) => value.client_id(),
+        Self::UpdateScopeNotificationSettings(value) => value.client_id(),
+        Self::UpdateSecretChat(value) => value.client_id(),
+        Self::UpdateSelectedBackground(value) => value.client_id(),
+        Self::UpdateServiceNotification(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::UpdateStickerSet(value) => value.client_id(),
+        Self::UpdateSuggestedActions(value) => value.client_id(),
+        Self::UpdateSupergroup(value) => value.client_id(),
+        Self::UpdateSupergroupFullInfo(value) => value.client_id(),
+        Self::UpdateTermsOfService(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::UpdateTrendingStickerSets(value) => value.client_id(),
+        Self::UpdateUnreadChatCount(value) => value.client_id(),
+        Self::UpdateUnreadMessageCount(value) => value.client_id(),
+        Self::UpdateUser(value) => value.client_id(),
+        Self::UpdateUserChatAction(value) => value.client_id(),
+        Self::UpdateUserFullInfo(value) => value.client_id(),
+        Self::UpdateUserPrivacySettingRules(value) => value.client_id(),
+        Self::UpdateUserStatus(value) => value.client_id(),
+        Self::UpdateUsersNearby(value) => value.client_id(),
+      
+        Self::AuthorizationState(value) => value.client_id(),
+        Self::CanTransferOwnershipResult(value) => value.client_id(),
+        #[cfg(feature = "statistics")] Self::ChatStatistics(value) => value.client_id(),
+        Self::CheckChatUsernameResult(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::CheckStickerSetNameResult(value) => value.client_id(),
+        Self::InternalLinkType(value) => value.client_id(),
+        Self::JsonValue(value) => value.client_id(),
+        Self::LanguagePackStringValue(value) => value.client_id(),
+        Self::LogStream(value) => value.client_id(),
+        Self::LoginUrlInfo(value) => value.client_id(),
+        Self::MessageFileType(value) => value.client_id(),
+        Self::OptionValue(value) => value.client_id(),
+        #[cfg(feature = "passport")] Self::PassportElement(value) => value.client_id(),
+        Self::ResetPasswordResult(value) => value.client_id(),
+        #[cfg(feature = "statistics")] Self::StatisticalGraph(value) => value.client_id(),
+        Self::Update(value) => value.client_id(),
+        Self::AccountTtl(value) => value.client_id(),
+        Self::AnimatedEmoji(value) => value.client_id(),
+        Self::Animations(value) => value.client_id(),
+        Self::AuthenticationCodeInfo(value) => value.client_id(),
+        Self::AutoDownloadSettingsPresets(value) => value.client_id(),
+        Self::Background(value) => value.client_id(),
+        Self::Backgrounds(value) => value.client_id(),
+        #[cfg(feature = "payments")] Self::BankCardInfo(value) => value.client_id(),
+        Self::BasicGroup(value) => value.client_id(),
+        Self::BasicGroupFullInfo(value) => value.client_id(),
+        Self::BotCommands(value) => value.client_id(),
+        #[cfg(feature = "calls")] Self::CallId(value) => value.client_id(),
+        Self::CallbackQueryAnswer(value) => value.client_id(),
+        Self::Chat(value) => value.client_id(),
+        Self::ChatAdministrators(value) => value.client_id(),
+        Self::ChatEvents(value) => value.client_id(),
+        Self::ChatFilter(value) => value.client_id(),
+        Self::ChatFilterInfo(value) => value.client_id(),
+        Self::ChatInviteLink(value) => value.client_id(),
+        Self::ChatInviteLinkCounts(value) => value.client_id(),
+        Self::ChatInviteLinkInfo(value) => value.client_id(),
+        Self::ChatInviteLinkMembers(value) => value.client_id(),
+        Self::ChatInviteLinks(value) => value.client_id(),
+        Self::ChatJoinRequests(value) => value.client_id(),
+        Self::ChatLists(value) => value.client_id(),
+        Self::ChatMember(value) => value.client_id(),
+        Self::ChatMembers(value) => value.client_id(),
+        Self::ChatPhotos(value) => value.client_id(),
+        Self::Chats(value) => value.client_id(),
+        Self::ChatsNearby(value) => value.client_id(),
+        Self::ConnectedWebsites(value) => value.client_id(),
+        Self::Count(value) => value.client_id(),
+        Self::Countries(value) => value.client_id(),
+        Self::CustomRequestResult(value) => value.client_id(),
+        #[cfg(feature = "statistics")] Self::DatabaseStatistics(value) => value.client_id(),
+        Self::DeepLinkInfo(value) => value.client_id(),
+        Self::EmailAddressAuthenticationCodeInfo(value) => value.client_id(),
+        Self::Emojis(value) => value.client_id(),
+        Self::Error(value) => value.client_id(),
+        Self::File(value) => value.client_id(),
+        Self::FilePart(value) => value.client_id(),
+        Self::FormattedText(value) => value.client_id(),
+        Self::FoundMessages(value) => value.client_id(),
+        Self::GameHighScores(value) => value.client_id(),
+        #[cfg(feature = "calls")] Self::GroupCall(value) => value.client_id(),
+        #[cfg(feature = "calls")] Self::GroupCallId(value) => value.client_id(),
+        Self::Hashtags(value) => value.client_id(),
+        Self::HttpUrl(value) => value.client_id(),
+        Self::ImportedContacts(value) => value.client_id(),
+        Self::InlineQueryResults(value) => value.client_id(),
+        Self::LanguagePackInfo(value) => value.client_id(),
+        Self::LanguagePackStrings(value) => value.client_id(),
+        Self::LocalizationTargetInfo(value) => value.client_id(),
+        Self::LogTags(value) => value.client_id(),
+        Self::LogVerbosityLevel(value) => value.client_id(),
+        Self::Message(value) => value.client_id(),
+        Self::MessageCalendar(value) => value.client_id(),
+        Self::MessageLink(value) => value.client_id(),
+        Self::MessageLinkInfo(value) => value.client_id(),
+        Self::MessagePositions(value) => value.client_id(),
+        Self::MessageSenders(value) => value.client_id(),
+        #[cfg(feature = "statistics")] Self::MessageStatistics(value) => value.client_id(),
+        Self::MessageThreadInfo(value) => value.client_id(),
+        Self::Messages(value) => value.client_id(),
+        #[cfg(feature = "statistics")] Self::NetworkStatistics(value) => value.client_id(),
+        Self::Ok(value) => value.client_id(),
+        Self::OrderInfo(value) => value.client_id(),
+        #[cfg(feature = "passport")] Self::PassportAuthorizationForm(value) => value.client_id(),
+        #[cfg(feature = "passport")] Self::PassportElements(value) => value.client_id(),
+        #[cfg(feature = "passport")] Self::PassportElementsWithErrors(value) => value.client_id(),
+        Self::PasswordState(value) => value.client_id(),
+        #[cfg(feature = "payments")] Self::PaymentForm(value) => value.client_id(),
+        #[cfg(feature = "payments")] Self::PaymentReceipt(value) => value.client_id(),
+        #[cfg(feature = "payments")] Self::PaymentResult(value) => value.client_id(),
+        Self::PhoneNumberInfo(value) => value.client_id(),
+        Self::Proxies(value) => value.client_id(),
+        Self::Proxy(value) => value.client_id(),
+        Self::PushReceiverId(value) => value.client_id(),
+        Self::RecommendedChatFilters(value) => value.client_id(),
+        Self::RecoveryEmailAddress(value) => value.client_id(),
+        Self::ScopeNotificationSettings(value) => value.client_id(),
+        Self::Seconds(value) => value.client_id(),
+        Self::SecretChat(value) => value.client_id(),
+        Self::Session(value) => value.client_id(),
+        Self::Sessions(value) => value.client_id(),
+        Self::SponsoredMessages(value) => value.client_id(),
+        Self::Sticker(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::StickerSet(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::StickerSets(value) => value.client_id(),
+        #[cfg(feature = "stickers")] Self::Stickers(value) => value.client_id(),
+        Self::StorageStatistics(value) => value.client_id(),
+        Self::StorageStatisticsFast(value) => value.client_id(),
+        Self::Supergroup(value) => value.client_id(),
+        Self::SupergroupFullInfo(value) => value.client_id(),
+        Self::TMeUrls(value) => value.client_id(),
+        #[cfg(feature = "payments")] Self::TemporaryPasswordState(value) => value.client_id(),
+        Self::TestBytes(value) => value.client_id(),
+        Self::TestInt(value) => value.client_id(),
+        Self::TestString(value) => value.client_id(),
+        Self::TestVectorInt(value) => value.client_id(),
+        Self::TestVectorIntObject(value) => value.client_id(),
+        Self::TestVectorString(value) => value.client_id(),
+        Self::TestVectorStringObject(value) => value.client_id(),
+        Self::Text(value) => value.client_id(),
+        Self::TextEntities(value) => value.client_id(),
+        Self::Updates(value) => value.client_id(),
+        Self::User(value) => value.client_id(),
+        Self::UserFullInfo(value) => value.client_id(),
+        Self::UserPrivacySettingRules(value) => value.client_id(),
+        Self::Users(value) => value.client_id(),
+        #[cfg(feature = "payments")] Self::ValidatedOrderInfo(value) => value.client_id(),
+        Self::WebPage(value) => value.client_id(),
+        #[cfg(feature = "instant-view")] Self::WebPageInstantView(value) => value.client_id(),
+      
+    }
+  }
+  /// Return td type to json string
+  fn to_json(&self) -> RTDResult<String> {
+    match self {
+        Self::Unknown(value) => value.to_json(),
+        Self::TestUseUpdate(value) => value.to_json(),
+        Self::UpdateActiveNotifications(value) => value.to_json(),
+        Self::UpdateAnimatedEmojiMessageClicked(value) => value.to_json(),
+        Self::UpdateAnimationSearchParameters(value) => value.to_json(),
+        Self::UpdateAuthorizationState(value) => value.to_json(),
+        Self::UpdateBasicGroup(value) => value.to_json(),
+        Self::UpdateBasicGroupFullInfo(value) => value.to_json(),
+        #[cfg(feature = "calls")] Self::UpdateCall(value) => value.to_json(),
+        Self::UpdateChatActionBar(value) => value.to_json(),
+        Self::UpdateChatDefaultDisableNotification(value) => value.to_json(),
+        Self::UpdateChatDraftMessage(value) => value.to_json(),
+        Self::UpdateChatFilters(value) => value.to_json(),
+        Self::UpdateChatHasScheduledMessages(value) => value.to_json(),
+        Self::UpdateChatIsBlocked(value) => value.to_json(),
+        Self::UpdateChatIsMarkedAsUnread(value) => value.to_json(),
+        Self::UpdateChatLastMessage(value) => value.to_json(),
+        Self::UpdateChatMember(value) => value.to_json(),
+        Self::UpdateChatMessageTtlSetting(value) => value.to_json(),
+        Self::UpdateChatNotificationSettings(value) => value.to_json(),
+        Self::UpdateChatOnlineMemberCount(value) => value.to_json(),
+        Self::UpdateChatPendingJoinRequests(value) => value.to_json(),
+        Self::UpdateChatPermissions(value) => value.to_json(),
+        Self::UpdateChatPhoto(value) => value.to_json(),
+        Self::UpdateChatPosition(value) => value.to_json(),
+        Self::UpdateChatReadInbox(value) => value.to_json(),
+        Self::UpdateChatReadOutbox(value) => value.to_json(),
+        Self::UpdateChatReplyMarkup(value) => value.to_json(),
+        Self::UpdateChatTheme(value) => value.to_json(),
+        Self::UpdateChatThemes(value) => value.to_json(),
+        Self::UpdateChatTitle(value) => value.to_json(),
+        Self::UpdateChatUnreadMentionCount(value) => value.to_json(),
+        Self::UpdateChatVideoChat(value) => value.to_json(),
+        Self::UpdateConnectionState(value) => value.to_json(),
+        Self::UpdateDeleteMessages(value) => value.to_json(),
+        Self::UpdateDiceEmojis(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::UpdateFavoriteStickers(value) => value.to_json(),
+        Self::UpdateFile(value) => value.to_json(),
+        Self::UpdateFileGenerationStart(value) => value.to_json(),
+        Self::UpdateFileGenerationStop(value) => value.to_json(),
+        #[cfg(feature = "calls")] Self::UpdateGroupCall(value) => value.to_json(),
+        #[cfg(feature = "calls")] Self::UpdateGroupCallParticipant(value) => value.to_json(),
+        Self::UpdateHavePendingNotifications(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::UpdateInstalledStickerSets(value) => value.to_json(),
+        Self::UpdateLanguagePackStrings(value) => value.to_json(),
+        Self::UpdateMessageContent(value) => value.to_json(),
+        Self::UpdateMessageContentOpened(value) => value.to_json(),
+        Self::UpdateMessageEdited(value) => value.to_json(),
+        Self::UpdateMessageInteractionInfo(value) => value.to_json(),
+        Self::UpdateMessageIsPinned(value) => value.to_json(),
+        Self::UpdateMessageLiveLocationViewed(value) => value.to_json(),
+        Self::UpdateMessageMentionRead(value) => value.to_json(),
+        Self::UpdateMessageSendAcknowledged(value) => value.to_json(),
+        Self::UpdateMessageSendFailed(value) => value.to_json(),
+        Self::UpdateMessageSendSucceeded(value) => value.to_json(),
+        #[cfg(feature = "calls")] Self::UpdateNewCallSignalingData(value) => value.to_json(),
+        Self::UpdateNewCallbackQuery(value) => value.to_json(),
+        Self::UpdateNewChat(value) => value.to_json(),
+        Self::UpdateNewChatJoinRequest(value) => value.to_json(),
+        Self::UpdateNewChosenInlineResult(value) => value.to_json(),
+        Self::UpdateNewCustomEvent(value) => value.to_json(),
+        Self::UpdateNewCustomQuery(value) => value.to_json(),
+        Self::UpdateNewInlineCallbackQuery(value) => value.to_json(),
+        Self::UpdateNewInlineQuery(value) => value.to_json(),
+        Self::UpdateNewMessage(value) => value.to_json(),
+        Self::UpdateNewPreCheckoutQuery(value) => value.to_json(),
+        #[cfg(feature = "payments")] Self::UpdateNewShippingQuery(value) => value.to_json(),
+        Self::UpdateNotification(value) => value.to_json(),
+        Self::UpdateNotificationGroup(value) => value.to_json(),
+        Self::UpdateOption(value) => value.to_json(),
+        Self::UpdatePoll(value) => value.to_json(),
+        Self::UpdatePollAnswer(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::UpdateRecentStickers(value) => value.to_json(),
+        Self::UpdateSavedAnimations(value) => value.to_json(),
+        Self::UpdateScopeNotificationSettings(value) => value.to_json(),
+        Self::UpdateSecretChat(value) => value.to_json(),
+        Self::UpdateSelectedBackground(value) => value.to_json(),
+        Self::UpdateServiceNotification(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::UpdateStickerSet(value) => value.to_json(),
+        Self::UpdateSuggestedActions(value) => value.to_json(),
+        Self::UpdateSupergroup(value) => value.to_json(),
+        Self::UpdateSupergroupFullInfo(value) => value.to_json(),
+        Self::UpdateTermsOfService(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::UpdateTrendingStickerSets(value) => value.to_json(),
+        Self::UpdateUnreadChatCount(value) => value.to_json(),
+        Self::UpdateUnreadMessageCount(value) => value.to_json(),
+        Self::UpdateUser(value) => value.to_json(),
+        Self::UpdateUserChatAction(value) => value.to_json(),
+        Self::UpdateUserFullInfo(value) => value.to_json(),
+        Self::UpdateUserPrivacySettingRules(value) => value.to_json(),
+        Self::UpdateUserStatus(value) => value.to_json(),
+        Self::UpdateUsersNearby(value) => value.to_json(),
+      
+        Self::AuthorizationState(value) => value.to_json(),
+        Self::CanTransferOwnershipResult(value) => value.to_json(),
+        #[cfg(feature = "statistics")] Self::ChatStatistics(value) => value.to_json(),
+        Self::CheckChatUsernameResult(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::CheckStickerSetNameResult(value) => value.to_json(),
+        Self::InternalLinkType(value) => value.to_json(),
+        Self::JsonValue(value) => value.to_json(),
+        Self::LanguagePackStringValue(value) => value.to_json(),
+        Self::LogStream(value) => value.to_json(),
+        Self::LoginUrlInfo(value) => value.to_json(),
+        Self::MessageFileType(value) => value.to_json(),
+        Self::OptionValue(value) => value.to_json(),
+        #[cfg(feature = "passport")] Self::PassportElement(value) => value.to_json(),
+        Self::ResetPasswordResult(value) => value.to_json(),
+        #[cfg(feature = "statistics")] Self::StatisticalGraph(value) => value.to_json(),
+        Self::Update(value) => value.to_json(),
+        Self::AccountTtl(value) => value.to_json(),
+        Self::AnimatedEmoji(value) => value.to_json(),
+        Self::Animations(value) => value.to_json(),
+        Self::AuthenticationCodeInfo(value) => value.to_json(),
+        Self::AutoDownloadSettingsPresets(value) => value.to_json(),
+        Self::Background(value) => value.to_json(),
+        Self::Backgrounds(value) => value.to_json(),
+        #[cfg(feature = "payments")] Self::BankCardInfo(value) => value.to_json(),
+        Self::BasicGroup(value) => value.to_json(),
+        Self::BasicGroupFullInfo(value) => value.to_json(),
+        Self::BotCommands(value) => value.to_json(),
+        #[cfg(feature = "calls")] Self::CallId(value) => value.to_json(),
+        Self::CallbackQueryAnswer(value) => value.to_json(),
+        Self::Chat(value) => value.to_json(),
+        Self::ChatAdministrators(value) => value.to_json(),
+        Self::ChatEvents(value) => value.to_json(),
+        Self::ChatFilter(value) => value.to_json(),
+        Self::ChatFilterInfo(value) => value.to_json(),
+        Self::ChatInviteLink(value) => value.to_json(),
+        Self::ChatInviteLinkCounts(value) => value.to_json(),
+        Self::ChatInviteLinkInfo(value) => value.to_json(),
+        Self::ChatInviteLinkMembers(value) => value.to_json(),
+        Self::ChatInviteLinks(value) => value.to_json(),
+        Self::ChatJoinRequests(value) => value.to_json(),
+        Self::ChatLists(value) => value.to_json(),
+        Self::ChatMember(value) => value.to_json(),
+        Self::ChatMembers(value) => value.to_json(),
+        Self::ChatPhotos(value) => value.to_json(),
+        Self::Chats(value) => value.to_json(),
+        Self::ChatsNearby(value) => value.to_json(),
+        Self::ConnectedWebsites(value) => value.to_json(),
+        Self::Count(value) => value.to_json(),
+        Self::Countries(value) => value.to_json(),
+        Self::CustomRequestResult(value) => value.to_json(),
+        #[cfg(feature = "statistics")] Self::DatabaseStatistics(value) => value.to_json(),
+        Self::DeepLinkInfo(value) => value.to_json(),
+        Self::EmailAddressAuthenticationCodeInfo(value) => value.to_json(),
+        Self::Emojis(value) => value.to_json(),
+        Self::Error(value) => value.to_json(),
+        Self::File(value) => value.to_json(),
+        Self::FilePart(value) => value.to_json(),
+        Self::FormattedText(value) => value.to_json(),
+        Self::FoundMessages(value) => value.to_json(),
+        Self::GameHighScores(value) => value.to_json(),
+        #[cfg(feature = "calls")] Self::GroupCall(value) => value.to_json(),
+        #[cfg(feature = "calls")] Self::GroupCallId(value) => value.to_json(),
+        Self::Hashtags(value) => value.to_json(),
+        Self::HttpUrl(value) => value.to_json(),
+        Self::ImportedContacts(value) => value.to_json(),
+        Self::InlineQueryResults(value) => value.to_json(),
+        Self::LanguagePackInfo(value) => value.to_json(),
+        Self::LanguagePackStrings(value) => value.to_json(),
+        Self::LocalizationTargetInfo(value) => value.to_json(),
+        Self::LogTags(value) => value.to_json(),
+        Self::LogVerbosityLevel(value) => value.to_json(),
+        Self::Message(value) => value.to_json(),
+        Self::MessageCalendar(value) => value.to_json(),
+        Self::MessageLink(value) => value.to_json(),
+        Self::MessageLinkInfo(value) => value.to_json(),
+        Self::MessagePositions(value) => value.to_json(),
+        Self::MessageSenders(value) => value.to_json(),
+        #[cfg(feature = "statistics")] Self::MessageStatistics(value) => value.to_json(),
+        Self::MessageThreadInfo(value) => value.to_json(),
+        Self::Messages(value) => value.to_json(),
+        #[cfg(feature = "statistics")] Self::NetworkStatistics(value) => value.to_json(),
+        Self::Ok(value) => value.to_json(),
+        Self::OrderInfo(value) => value.to_json(),
+        #[cfg(feature = "passport")] Self::PassportAuthorizationForm(value) => value.to_json(),
+        #[cfg(feature = "passport")] Self::PassportElements(value) => value.to_json(),
+        #[cfg(feature = "passport")] Self::PassportElementsWithErrors(value) => value.to_json(),
+        Self::PasswordState(value) => value.to_json(),
+        #[cfg(feature = "payments")] Self::PaymentForm(value) => value.to_json(),
+        #[cfg(feature = "payments")] Self::PaymentReceipt(value) => value.to_json(),
+        #[cfg(feature = "payments")] Self::PaymentResult(value) => value.to_json(),
+        Self::PhoneNumberInfo(value) => value.to_json(),
+        Self::Proxies(value) => value.to_json(),
+        Self::Proxy(value) => value.to_json(),
+        Self::PushReceiverId(value) => value.to_json(),
+        Self::RecommendedChatFilters(value) => value.to_json(),
+        Self::RecoveryEmailAddress(value) => value.to_json(),
+        Self::ScopeNotificationSettings(value) => value.to_json(),
+        Self::Seconds(value) => value.to_json(),
+        Self::SecretChat(value) => value.to_json(),
+        Self::Session(value) => value.to_json(),
+        Self::Sessions(value) => value.to_json(),
+        Self::SponsoredMessages(value) => value.to_json(),
+        Self::Sticker(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::StickerSet(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::StickerSets(value) => value.to_json(),
+        #[cfg(feature = "stickers")] Self::Stickers(value) => value.to_json(),
+        Self::StorageStatistics(value) => value.to_json(),
+        Self::StorageStatisticsFast(value) => value.to_json(),
+        Self::Supergroup(value) => value.to_json(),
+        Self::SupergroupFullInfo(value) => value.to_json(),
+        Self::TMeUrls(value) => value.to_json(),
+        #[cfg(feature = "payments")] Self::TemporaryPasswordState(value) => value.to_json(),
+        Self::TestBytes(value) => value.to_json(),
+        Self::TestInt(value) => value.to_json(),
+        Self::TestString(value) => value.to_json(),
+        Self::TestVectorInt(value) => value.to_json(),
+        Self::TestVectorIntObject(value) => value.to_json(),
+        Self::TestVectorString(value) => value.to_json(),
+        Self::TestVectorStringObject(value) => value.to_json(),
+        Self::Text(value) => value.to_json(),
+        Self::TextEntities(value) => value.to_json(),
+        Self::Updates(value) => value.to_json(),
+        Self::User(value) => value.to_json(),
+        Self::UserFullInfo(value) => value.to_json(),
+        Self::UserPrivacySettingRules(value) => value.to_json(),
+        Self::Users(value) => value.to_json(),
+        #[cfg(feature = "payments")] Self::ValidatedOrderInfo(value) => value.to_json(),
+        Self::WebPage(value) => value.to_json(),
+        #[cfg(feature = "instant-view")] Self::WebPageInstantView(value) => value.to_json(),
+      
+    }
+  }
+}
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct NetworkStatistics {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Point in time (Unix timestamp) from which the statistics are collected
   since_date: i64,
   /// Network statistics entries
@@ -23,22 +25,24 @@ pub struct NetworkStatistics {
 }
 
 impl RObject for NetworkStatistics {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkStatistics" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkStatistics" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl NetworkStatistics {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkStatisticsBuilder {
-    let mut inner = NetworkStatistics::default();
-    inner.td_name = "networkStatistics".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkStatistics { td_name: "networkStatistics".to_string(), ..Default::default() };
     RTDNetworkStatisticsBuilder { inner }
   }
 
+  /// `NetworkStatistics` with the fields tdlib requires, the others are set by `NetworkStatistics::builder()`
+  pub fn new(since_date: i64, entries: Vec<NetworkStatisticsEntry>) -> Self { Self::builder().since_date(since_date).entries(entries).build() }
+
   pub fn since_date(&self) -> i64 { self.since_date }
 
   pub fn entries(&self) -> &Vec<NetworkStatisticsEntry> { &self.entries }
@@ -53,6 +57,12 @@ pub struct RTDNetworkStatisticsBuilder {
 impl RTDNetworkStatisticsBuilder {
   pub fn build(&self) -> NetworkStatistics { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn since_date(&mut self, since_date: i64) -> &mut Self {
     self.inner.since_date = since_date;
@@ -75,5 +85,12 @@ impl AsRef<NetworkStatistics> for RTDNetworkStatisticsBuilder {
   fn as_ref(&self) -> &NetworkStatistics { &self.inner }
 }
 
+impl PartialEq for NetworkStatistics {
+  fn eq(&self, other: &Self) -> bool {
+    self.since_date == other.since_date
+      && self.entries == other.entries
+  }
+}
+
 
 
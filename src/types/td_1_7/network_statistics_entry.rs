@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDNetworkStatisticsEntry: Debug + RObject {}
 
 /// Contains statistics about network usage
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum NetworkStatisticsEntry {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum NetworkStatisticsEntry {
   Call(NetworkStatisticsEntryCall),
   /// Contains information about the total amount of data that was used to send and receive files
   File(NetworkStatisticsEntryFile),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for NetworkStatisticsEntry {
   fn deserialize<D>(deserializer: D) -> Result<NetworkStatisticsEntry, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      NetworkStatisticsEntry,
+      NetworkStatisticsEntry, Unknown;
       (networkStatisticsEntryCall, Call);
       (networkStatisticsEntryFile, File);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for NetworkStatisticsEntry {
 }
 
 impl RObject for NetworkStatisticsEntry {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       NetworkStatisticsEntry::Call(t) => t.td_name(),
       NetworkStatisticsEntry::File(t) => t.td_name(),
+      NetworkStatisticsEntry::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       NetworkStatisticsEntry::Call(t) => t.extra(),
       NetworkStatisticsEntry::File(t) => t.extra(),
+      NetworkStatisticsEntry::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      NetworkStatisticsEntry::Call(t) => t.client_id(),
+      NetworkStatisticsEntry::File(t) => t.client_id(),
+      NetworkStatisticsEntry::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for NetworkStatisticsEntry {
 }
 
 impl NetworkStatisticsEntry {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NetworkStatisticsEntry::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, NetworkStatisticsEntry::_Default(_)) }
 
-  pub fn is_call(&self) -> bool { if let NetworkStatisticsEntry::Call(_) = self { true } else { false } }
-  pub fn is_file(&self) -> bool { if let NetworkStatisticsEntry::File(_) = self { true } else { false } }
+  pub fn is_call(&self) -> bool { matches!(self, NetworkStatisticsEntry::Call(_)) }
+  pub fn is_file(&self) -> bool { matches!(self, NetworkStatisticsEntry::File(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, NetworkStatisticsEntry::Unknown(_)) }
 
   pub fn on_call<F: FnOnce(&NetworkStatisticsEntryCall)>(&self, fnc: F) -> &Self { if let NetworkStatisticsEntry::Call(t) = self { fnc(t) }; self }
   pub fn on_file<F: FnOnce(&NetworkStatisticsEntryFile)>(&self, fnc: F) -> &Self { if let NetworkStatisticsEntry::File(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let NetworkStatisticsEntry::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_call(&self) -> Option<&NetworkStatisticsEntryCall> { if let NetworkStatisticsEntry::Call(t) = self { return Some(t) } None }
   pub fn as_file(&self) -> Option<&NetworkStatisticsEntryFile> { if let NetworkStatisticsEntry::File(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let NetworkStatisticsEntry::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,7 +116,10 @@ pub struct NetworkStatisticsEntryCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Type of the network the data was sent through. Call setNetworkType to maintain the actual network type
   network_type: NetworkType,
   /// Total number of bytes sent
@@ -114,8 +132,9 @@ pub struct NetworkStatisticsEntryCall {
 }
 
 impl RObject for NetworkStatisticsEntryCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkStatisticsEntryCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkStatisticsEntryCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -125,14 +144,27 @@ impl TDNetworkStatisticsEntry for NetworkStatisticsEntryCall {}
 
 
 impl NetworkStatisticsEntryCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkStatisticsEntryCallBuilder {
-    let mut inner = NetworkStatisticsEntryCall::default();
-    inner.td_name = "networkStatisticsEntryCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkStatisticsEntryCall { td_name: "networkStatisticsEntryCall".to_string(), ..Default::default() };
     RTDNetworkStatisticsEntryCallBuilder { inner }
   }
 
+  /// `NetworkStatisticsEntryCall` with the fields tdlib requires, the others are set by `NetworkStatisticsEntryCall::builder()`
+  pub fn new(
+    network_type: impl AsRef<NetworkType>,
+    sent_bytes: i64,
+    received_bytes: i64,
+    duration: f32,
+  ) -> Self {
+    Self::builder()
+      .network_type(network_type)
+      .sent_bytes(sent_bytes)
+      .received_bytes(received_bytes)
+      .duration(duration)
+      .build()
+  }
+
   pub fn network_type(&self) -> &NetworkType { &self.network_type }
 
   pub fn sent_bytes(&self) -> i64 { self.sent_bytes }
@@ -151,6 +183,12 @@ pub struct RTDNetworkStatisticsEntryCallBuilder {
 impl RTDNetworkStatisticsEntryCallBuilder {
   pub fn build(&self) -> NetworkStatisticsEntryCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn network_type<T: AsRef<NetworkType>>(&mut self, network_type: T) -> &mut Self {
     self.inner.network_type = network_type.as_ref().clone();
@@ -185,6 +223,15 @@ impl AsRef<NetworkStatisticsEntryCall> for RTDNetworkStatisticsEntryCallBuilder
   fn as_ref(&self) -> &NetworkStatisticsEntryCall { &self.inner }
 }
 
+impl PartialEq for NetworkStatisticsEntryCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.network_type == other.network_type
+      && self.sent_bytes == other.sent_bytes
+      && self.received_bytes == other.received_bytes
+      && self.duration == other.duration
+  }
+}
+
 
 
 
@@ -199,7 +246,10 @@ pub struct NetworkStatisticsEntryFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Type of the file the data is part of; pass null if the data isn't related to files
   file_type: FileType,
   /// Type of the network the data was sent through. Call setNetworkType to maintain the actual network type
@@ -212,8 +262,9 @@ pub struct NetworkStatisticsEntryFile {
 }
 
 impl RObject for NetworkStatisticsEntryFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkStatisticsEntryFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkStatisticsEntryFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -223,14 +274,15 @@ impl TDNetworkStatisticsEntry for NetworkStatisticsEntryFile {}
 
 
 impl NetworkStatisticsEntryFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkStatisticsEntryFileBuilder {
-    let mut inner = NetworkStatisticsEntryFile::default();
-    inner.td_name = "networkStatisticsEntryFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkStatisticsEntryFile { td_name: "networkStatisticsEntryFile".to_string(), ..Default::default() };
     RTDNetworkStatisticsEntryFileBuilder { inner }
   }
 
+  /// `NetworkStatisticsEntryFile` with the fields tdlib requires, the others are set by `NetworkStatisticsEntryFile::builder()`
+  pub fn new(network_type: impl AsRef<NetworkType>, sent_bytes: i64, received_bytes: i64) -> Self { Self::builder().network_type(network_type).sent_bytes(sent_bytes).received_bytes(received_bytes).build() }
+
   pub fn file_type(&self) -> &FileType { &self.file_type }
 
   pub fn network_type(&self) -> &NetworkType { &self.network_type }
@@ -249,6 +301,12 @@ pub struct RTDNetworkStatisticsEntryFileBuilder {
 impl RTDNetworkStatisticsEntryFileBuilder {
   pub fn build(&self) -> NetworkStatisticsEntryFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
     self.inner.file_type = file_type.as_ref().clone();
@@ -283,5 +341,14 @@ impl AsRef<NetworkStatisticsEntryFile> for RTDNetworkStatisticsEntryFileBuilder
   fn as_ref(&self) -> &NetworkStatisticsEntryFile { &self.inner }
 }
 
+impl PartialEq for NetworkStatisticsEntryFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_type == other.file_type
+      && self.network_type == other.network_type
+      && self.sent_bytes == other.sent_bytes
+      && self.received_bytes == other.received_bytes
+  }
+}
+
 
 
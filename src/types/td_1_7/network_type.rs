@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDNetworkType: Debug + RObject {}
 
 /// Represents the type of a network
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum NetworkType {
   #[doc(hidden)] _Default(()),
@@ -29,6 +28,8 @@ pub enum NetworkType {
   Other(NetworkTypeOther),
   /// A Wi-Fi network
   WiFi(NetworkTypeWiFi),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -40,7 +41,7 @@ impl<'de> Deserialize<'de> for NetworkType {
   fn deserialize<D>(deserializer: D) -> Result<NetworkType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      NetworkType,
+      NetworkType, Unknown;
       (networkTypeMobile, Mobile);
       (networkTypeMobileRoaming, MobileRoaming);
       (networkTypeNone, None);
@@ -52,24 +53,38 @@ impl<'de> Deserialize<'de> for NetworkType {
 }
 
 impl RObject for NetworkType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       NetworkType::Mobile(t) => t.td_name(),
       NetworkType::MobileRoaming(t) => t.td_name(),
       NetworkType::None(t) => t.td_name(),
       NetworkType::Other(t) => t.td_name(),
       NetworkType::WiFi(t) => t.td_name(),
+      NetworkType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       NetworkType::Mobile(t) => t.extra(),
       NetworkType::MobileRoaming(t) => t.extra(),
       NetworkType::None(t) => t.extra(),
       NetworkType::Other(t) => t.extra(),
       NetworkType::WiFi(t) => t.extra(),
+      NetworkType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      NetworkType::Mobile(t) => t.client_id(),
+      NetworkType::MobileRoaming(t) => t.client_id(),
+      NetworkType::None(t) => t.client_id(),
+      NetworkType::Other(t) => t.client_id(),
+      NetworkType::WiFi(t) => t.client_id(),
+      NetworkType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -78,26 +93,29 @@ impl RObject for NetworkType {
 }
 
 impl NetworkType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NetworkType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, NetworkType::_Default(_)) }
 
-  pub fn is_mobile(&self) -> bool { if let NetworkType::Mobile(_) = self { true } else { false } }
-  pub fn is_mobile_roaming(&self) -> bool { if let NetworkType::MobileRoaming(_) = self { true } else { false } }
-  pub fn is_none(&self) -> bool { if let NetworkType::None(_) = self { true } else { false } }
-  pub fn is_other(&self) -> bool { if let NetworkType::Other(_) = self { true } else { false } }
-  pub fn is_wi_fi(&self) -> bool { if let NetworkType::WiFi(_) = self { true } else { false } }
+  pub fn is_mobile(&self) -> bool { matches!(self, NetworkType::Mobile(_)) }
+  pub fn is_mobile_roaming(&self) -> bool { matches!(self, NetworkType::MobileRoaming(_)) }
+  pub fn is_none(&self) -> bool { matches!(self, NetworkType::None(_)) }
+  pub fn is_other(&self) -> bool { matches!(self, NetworkType::Other(_)) }
+  pub fn is_wi_fi(&self) -> bool { matches!(self, NetworkType::WiFi(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, NetworkType::Unknown(_)) }
 
   pub fn on_mobile<F: FnOnce(&NetworkTypeMobile)>(&self, fnc: F) -> &Self { if let NetworkType::Mobile(t) = self { fnc(t) }; self }
   pub fn on_mobile_roaming<F: FnOnce(&NetworkTypeMobileRoaming)>(&self, fnc: F) -> &Self { if let NetworkType::MobileRoaming(t) = self { fnc(t) }; self }
   pub fn on_none<F: FnOnce(&NetworkTypeNone)>(&self, fnc: F) -> &Self { if let NetworkType::None(t) = self { fnc(t) }; self }
   pub fn on_other<F: FnOnce(&NetworkTypeOther)>(&self, fnc: F) -> &Self { if let NetworkType::Other(t) = self { fnc(t) }; self }
   pub fn on_wi_fi<F: FnOnce(&NetworkTypeWiFi)>(&self, fnc: F) -> &Self { if let NetworkType::WiFi(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let NetworkType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_mobile(&self) -> Option<&NetworkTypeMobile> { if let NetworkType::Mobile(t) = self { return Some(t) } None }
   pub fn as_mobile_roaming(&self) -> Option<&NetworkTypeMobileRoaming> { if let NetworkType::MobileRoaming(t) = self { return Some(t) } None }
   pub fn as_none(&self) -> Option<&NetworkTypeNone> { if let NetworkType::None(t) = self { return Some(t) } None }
   pub fn as_other(&self) -> Option<&NetworkTypeOther> { if let NetworkType::Other(t) = self { return Some(t) } None }
   pub fn as_wi_fi(&self) -> Option<&NetworkTypeWiFi> { if let NetworkType::WiFi(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let NetworkType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -131,13 +149,17 @@ pub struct NetworkTypeMobile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NetworkTypeMobile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeMobile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkTypeMobile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -147,14 +169,15 @@ impl TDNetworkType for NetworkTypeMobile {}
 
 
 impl NetworkTypeMobile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkTypeMobileBuilder {
-    let mut inner = NetworkTypeMobile::default();
-    inner.td_name = "networkTypeMobile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkTypeMobile { td_name: "networkTypeMobile".to_string(), ..Default::default() };
     RTDNetworkTypeMobileBuilder { inner }
   }
 
+  /// `NetworkTypeMobile` with the fields tdlib requires, the others are set by `NetworkTypeMobile::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +188,12 @@ pub struct RTDNetworkTypeMobileBuilder {
 impl RTDNetworkTypeMobileBuilder {
   pub fn build(&self) -> NetworkTypeMobile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeMobile> for NetworkTypeMobile {
@@ -175,6 +204,10 @@ impl AsRef<NetworkTypeMobile> for RTDNetworkTypeMobileBuilder {
   fn as_ref(&self) -> &NetworkTypeMobile { &self.inner }
 }
 
+impl PartialEq for NetworkTypeMobile {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -189,13 +222,17 @@ pub struct NetworkTypeMobileRoaming {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NetworkTypeMobileRoaming {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeMobileRoaming" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkTypeMobileRoaming" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -205,14 +242,15 @@ impl TDNetworkType for NetworkTypeMobileRoaming {}
 
 
 impl NetworkTypeMobileRoaming {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkTypeMobileRoamingBuilder {
-    let mut inner = NetworkTypeMobileRoaming::default();
-    inner.td_name = "networkTypeMobileRoaming".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkTypeMobileRoaming { td_name: "networkTypeMobileRoaming".to_string(), ..Default::default() };
     RTDNetworkTypeMobileRoamingBuilder { inner }
   }
 
+  /// `NetworkTypeMobileRoaming` with the fields tdlib requires, the others are set by `NetworkTypeMobileRoaming::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +261,12 @@ pub struct RTDNetworkTypeMobileRoamingBuilder {
 impl RTDNetworkTypeMobileRoamingBuilder {
   pub fn build(&self) -> NetworkTypeMobileRoaming { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeMobileRoaming> for NetworkTypeMobileRoaming {
@@ -233,6 +277,10 @@ impl AsRef<NetworkTypeMobileRoaming> for RTDNetworkTypeMobileRoamingBuilder {
   fn as_ref(&self) -> &NetworkTypeMobileRoaming { &self.inner }
 }
 
+impl PartialEq for NetworkTypeMobileRoaming {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -247,13 +295,17 @@ pub struct NetworkTypeNone {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NetworkTypeNone {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeNone" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkTypeNone" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -263,14 +315,15 @@ impl TDNetworkType for NetworkTypeNone {}
 
 
 impl NetworkTypeNone {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkTypeNoneBuilder {
-    let mut inner = NetworkTypeNone::default();
-    inner.td_name = "networkTypeNone".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkTypeNone { td_name: "networkTypeNone".to_string(), ..Default::default() };
     RTDNetworkTypeNoneBuilder { inner }
   }
 
+  /// `NetworkTypeNone` with the fields tdlib requires, the others are set by `NetworkTypeNone::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -281,6 +334,12 @@ pub struct RTDNetworkTypeNoneBuilder {
 impl RTDNetworkTypeNoneBuilder {
   pub fn build(&self) -> NetworkTypeNone { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeNone> for NetworkTypeNone {
@@ -291,6 +350,10 @@ impl AsRef<NetworkTypeNone> for RTDNetworkTypeNoneBuilder {
   fn as_ref(&self) -> &NetworkTypeNone { &self.inner }
 }
 
+impl PartialEq for NetworkTypeNone {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -305,13 +368,17 @@ pub struct NetworkTypeOther {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NetworkTypeOther {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeOther" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkTypeOther" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -321,14 +388,15 @@ impl TDNetworkType for NetworkTypeOther {}
 
 
 impl NetworkTypeOther {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkTypeOtherBuilder {
-    let mut inner = NetworkTypeOther::default();
-    inner.td_name = "networkTypeOther".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkTypeOther { td_name: "networkTypeOther".to_string(), ..Default::default() };
     RTDNetworkTypeOtherBuilder { inner }
   }
 
+  /// `NetworkTypeOther` with the fields tdlib requires, the others are set by `NetworkTypeOther::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -339,6 +407,12 @@ pub struct RTDNetworkTypeOtherBuilder {
 impl RTDNetworkTypeOtherBuilder {
   pub fn build(&self) -> NetworkTypeOther { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeOther> for NetworkTypeOther {
@@ -349,6 +423,10 @@ impl AsRef<NetworkTypeOther> for RTDNetworkTypeOtherBuilder {
   fn as_ref(&self) -> &NetworkTypeOther { &self.inner }
 }
 
+impl PartialEq for NetworkTypeOther {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -363,13 +441,17 @@ pub struct NetworkTypeWiFi {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NetworkTypeWiFi {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "networkTypeWiFi" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "networkTypeWiFi" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -379,14 +461,15 @@ impl TDNetworkType for NetworkTypeWiFi {}
 
 
 impl NetworkTypeWiFi {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNetworkTypeWiFiBuilder {
-    let mut inner = NetworkTypeWiFi::default();
-    inner.td_name = "networkTypeWiFi".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NetworkTypeWiFi { td_name: "networkTypeWiFi".to_string(), ..Default::default() };
     RTDNetworkTypeWiFiBuilder { inner }
   }
 
+  /// `NetworkTypeWiFi` with the fields tdlib requires, the others are set by `NetworkTypeWiFi::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -397,6 +480,12 @@ pub struct RTDNetworkTypeWiFiBuilder {
 impl RTDNetworkTypeWiFiBuilder {
   pub fn build(&self) -> NetworkTypeWiFi { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NetworkTypeWiFi> for NetworkTypeWiFi {
@@ -407,5 +496,9 @@ impl AsRef<NetworkTypeWiFi> for RTDNetworkTypeWiFiBuilder {
   fn as_ref(&self) -> &NetworkTypeWiFi { &self.inner }
 }
 
+impl PartialEq for NetworkTypeWiFi {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
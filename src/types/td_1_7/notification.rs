@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Notification {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique persistent identifier of this notification
   id: i64,
   /// Notification date
@@ -27,22 +29,36 @@ pub struct Notification {
 }
 
 impl RObject for Notification {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notification" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notification" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Notification {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationBuilder {
-    let mut inner = Notification::default();
-    inner.td_name = "notification".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Notification { td_name: "notification".to_string(), ..Default::default() };
     RTDNotificationBuilder { inner }
   }
 
+  /// `Notification` with the fields tdlib requires, the others are set by `Notification::builder()`
+  pub fn new(
+    id: i64,
+    date: i64,
+    is_silent: bool,
+    type_: impl AsRef<NotificationType>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .date(date)
+      .is_silent(is_silent)
+      .type_(type_)
+      .build()
+  }
+
   pub fn id(&self) -> i64 { self.id }
 
   pub fn date(&self) -> i64 { self.date }
@@ -61,6 +77,12 @@ pub struct RTDNotificationBuilder {
 impl RTDNotificationBuilder {
   pub fn build(&self) -> Notification { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -95,5 +117,14 @@ impl AsRef<Notification> for RTDNotificationBuilder {
   fn as_ref(&self) -> &Notification { &self.inner }
 }
 
+impl PartialEq for Notification {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.date == other.date
+      && self.is_silent == other.is_silent
+      && self.type_ == other.type_
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,13 +13,16 @@ pub struct NotificationGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique persistent auto-incremented from 1 identifier of the notification group
   id: i64,
   /// Type of the group
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: NotificationGroupType,
   /// Identifier of a chat to which all notifications in the group belong
-  chat_id: i64,
+  chat_id: ChatId,
   /// Total number of active notifications in the group
   total_count: i64,
   /// The list of active notifications
@@ -29,27 +31,43 @@ pub struct NotificationGroup {
 }
 
 impl RObject for NotificationGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl NotificationGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationGroupBuilder {
-    let mut inner = NotificationGroup::default();
-    inner.td_name = "notificationGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationGroup { td_name: "notificationGroup".to_string(), ..Default::default() };
     RTDNotificationGroupBuilder { inner }
   }
 
+  /// `NotificationGroup` with the fields tdlib requires, the others are set by `NotificationGroup::builder()`
+  pub fn new(
+    id: i64,
+    type_: impl AsRef<NotificationGroupType>,
+    chat_id: ChatId,
+    total_count: i64,
+    notifications: Vec<Notification>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .type_(type_)
+      .chat_id(chat_id)
+      .total_count(total_count)
+      .notifications(notifications)
+      .build()
+  }
+
   pub fn id(&self) -> i64 { self.id }
 
   pub fn type_(&self) -> &NotificationGroupType { &self.type_ }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn total_count(&self) -> i64 { self.total_count }
 
@@ -65,6 +83,12 @@ pub struct RTDNotificationGroupBuilder {
 impl RTDNotificationGroupBuilder {
   pub fn build(&self) -> NotificationGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -78,7 +102,7 @@ impl RTDNotificationGroupBuilder {
   }
 
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -105,5 +129,15 @@ impl AsRef<NotificationGroup> for RTDNotificationGroupBuilder {
   fn as_ref(&self) -> &NotificationGroup { &self.inner }
 }
 
+impl PartialEq for NotificationGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.type_ == other.type_
+      && self.chat_id == other.chat_id
+      && self.total_count == other.total_count
+      && self.notifications == other.notifications
+  }
+}
+
 
 
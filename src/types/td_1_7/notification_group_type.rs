@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,11 +14,12 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDNotificationGroupType: Debug + RObject {}
 
 /// Describes the type of notifications in a notification group
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum NotificationGroupType {
   #[doc(hidden)] _Default(()),
   /// A group containing notifications of type notificationTypeNewCall
+  #[cfg(feature = "calls")]
   Calls(NotificationGroupTypeCalls),
   /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message
   Mentions(NotificationGroupTypeMentions),
@@ -27,6 +27,8 @@ pub enum NotificationGroupType {
   Messages(NotificationGroupTypeMessages),
   /// A group containing a notification of type notificationTypeNewSecretChat
   SecretChat(NotificationGroupTypeSecretChat),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,8 +40,8 @@ impl<'de> Deserialize<'de> for NotificationGroupType {
   fn deserialize<D>(deserializer: D) -> Result<NotificationGroupType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      NotificationGroupType,
-      (notificationGroupTypeCalls, Calls);
+      NotificationGroupType, Unknown;
+      #[cfg(feature = "calls")] (notificationGroupTypeCalls, Calls);
       (notificationGroupTypeMentions, Mentions);
       (notificationGroupTypeMessages, Messages);
       (notificationGroupTypeSecretChat, SecretChat);
@@ -49,22 +51,35 @@ impl<'de> Deserialize<'de> for NotificationGroupType {
 }
 
 impl RObject for NotificationGroupType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
-      NotificationGroupType::Calls(t) => t.td_name(),
+      #[cfg(feature = "calls")] NotificationGroupType::Calls(t) => t.td_name(),
       NotificationGroupType::Mentions(t) => t.td_name(),
       NotificationGroupType::Messages(t) => t.td_name(),
       NotificationGroupType::SecretChat(t) => t.td_name(),
+      NotificationGroupType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
-      NotificationGroupType::Calls(t) => t.extra(),
+      #[cfg(feature = "calls")] NotificationGroupType::Calls(t) => t.extra(),
       NotificationGroupType::Mentions(t) => t.extra(),
       NotificationGroupType::Messages(t) => t.extra(),
       NotificationGroupType::SecretChat(t) => t.extra(),
+      NotificationGroupType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      #[cfg(feature = "calls")] NotificationGroupType::Calls(t) => t.client_id(),
+      NotificationGroupType::Mentions(t) => t.client_id(),
+      NotificationGroupType::Messages(t) => t.client_id(),
+      NotificationGroupType::SecretChat(t) => t.client_id(),
+      NotificationGroupType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,27 +88,30 @@ impl RObject for NotificationGroupType {
 }
 
 impl NotificationGroupType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationGroupType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, NotificationGroupType::_Default(_)) }
 
-  pub fn is_calls(&self) -> bool { if let NotificationGroupType::Calls(_) = self { true } else { false } }
-  pub fn is_mentions(&self) -> bool { if let NotificationGroupType::Mentions(_) = self { true } else { false } }
-  pub fn is_messages(&self) -> bool { if let NotificationGroupType::Messages(_) = self { true } else { false } }
-  pub fn is_secret_chat(&self) -> bool { if let NotificationGroupType::SecretChat(_) = self { true } else { false } }
+  #[cfg(feature = "calls")] pub fn is_calls(&self) -> bool { matches!(self, NotificationGroupType::Calls(_)) }
+  pub fn is_mentions(&self) -> bool { matches!(self, NotificationGroupType::Mentions(_)) }
+  pub fn is_messages(&self) -> bool { matches!(self, NotificationGroupType::Messages(_)) }
+  pub fn is_secret_chat(&self) -> bool { matches!(self, NotificationGroupType::SecretChat(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, NotificationGroupType::Unknown(_)) }
 
-  pub fn on_calls<F: FnOnce(&NotificationGroupTypeCalls)>(&self, fnc: F) -> &Self { if let NotificationGroupType::Calls(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_calls<F: FnOnce(&NotificationGroupTypeCalls)>(&self, fnc: F) -> &Self { if let NotificationGroupType::Calls(t) = self { fnc(t) }; self }
   pub fn on_mentions<F: FnOnce(&NotificationGroupTypeMentions)>(&self, fnc: F) -> &Self { if let NotificationGroupType::Mentions(t) = self { fnc(t) }; self }
   pub fn on_messages<F: FnOnce(&NotificationGroupTypeMessages)>(&self, fnc: F) -> &Self { if let NotificationGroupType::Messages(t) = self { fnc(t) }; self }
   pub fn on_secret_chat<F: FnOnce(&NotificationGroupTypeSecretChat)>(&self, fnc: F) -> &Self { if let NotificationGroupType::SecretChat(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let NotificationGroupType::Unknown(t) = self { fnc(t) }; self }
 
-  pub fn as_calls(&self) -> Option<&NotificationGroupTypeCalls> { if let NotificationGroupType::Calls(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_calls(&self) -> Option<&NotificationGroupTypeCalls> { if let NotificationGroupType::Calls(t) = self { return Some(t) } None }
   pub fn as_mentions(&self) -> Option<&NotificationGroupTypeMentions> { if let NotificationGroupType::Mentions(t) = self { return Some(t) } None }
   pub fn as_messages(&self) -> Option<&NotificationGroupTypeMessages> { if let NotificationGroupType::Messages(t) = self { return Some(t) } None }
   pub fn as_secret_chat(&self) -> Option<&NotificationGroupTypeSecretChat> { if let NotificationGroupType::SecretChat(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let NotificationGroupType::Unknown(t) = self { return Some(t) } None }
 
 
 
-  pub fn calls<T: AsRef<NotificationGroupTypeCalls>>(t: T) -> Self { NotificationGroupType::Calls(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn calls<T: AsRef<NotificationGroupTypeCalls>>(t: T) -> Self { NotificationGroupType::Calls(t.as_ref().clone()) }
 
   pub fn mentions<T: AsRef<NotificationGroupTypeMentions>>(t: T) -> Self { NotificationGroupType::Mentions(t.as_ref().clone()) }
 
@@ -113,6 +131,8 @@ impl AsRef<NotificationGroupType> for NotificationGroupType {
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
+
 /// A group containing notifications of type notificationTypeNewCall
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NotificationGroupTypeCalls {
@@ -121,13 +141,17 @@ pub struct NotificationGroupTypeCalls {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationGroupTypeCalls {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeCalls" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationGroupTypeCalls" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -137,14 +161,15 @@ impl TDNotificationGroupType for NotificationGroupTypeCalls {}
 
 
 impl NotificationGroupTypeCalls {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationGroupTypeCallsBuilder {
-    let mut inner = NotificationGroupTypeCalls::default();
-    inner.td_name = "notificationGroupTypeCalls".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationGroupTypeCalls { td_name: "notificationGroupTypeCalls".to_string(), ..Default::default() };
     RTDNotificationGroupTypeCallsBuilder { inner }
   }
 
+  /// `NotificationGroupTypeCalls` with the fields tdlib requires, the others are set by `NotificationGroupTypeCalls::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +180,12 @@ pub struct RTDNotificationGroupTypeCallsBuilder {
 impl RTDNotificationGroupTypeCallsBuilder {
   pub fn build(&self) -> NotificationGroupTypeCalls { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeCalls> for NotificationGroupTypeCalls {
@@ -165,6 +196,12 @@ impl AsRef<NotificationGroupTypeCalls> for RTDNotificationGroupTypeCallsBuilder
   fn as_ref(&self) -> &NotificationGroupTypeCalls { &self.inner }
 }
 
+impl PartialEq for NotificationGroupTypeCalls {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
+}
+
 
 
 
@@ -179,13 +216,17 @@ pub struct NotificationGroupTypeMentions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationGroupTypeMentions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeMentions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationGroupTypeMentions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -195,14 +236,15 @@ impl TDNotificationGroupType for NotificationGroupTypeMentions {}
 
 
 impl NotificationGroupTypeMentions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationGroupTypeMentionsBuilder {
-    let mut inner = NotificationGroupTypeMentions::default();
-    inner.td_name = "notificationGroupTypeMentions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationGroupTypeMentions { td_name: "notificationGroupTypeMentions".to_string(), ..Default::default() };
     RTDNotificationGroupTypeMentionsBuilder { inner }
   }
 
+  /// `NotificationGroupTypeMentions` with the fields tdlib requires, the others are set by `NotificationGroupTypeMentions::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +255,12 @@ pub struct RTDNotificationGroupTypeMentionsBuilder {
 impl RTDNotificationGroupTypeMentionsBuilder {
   pub fn build(&self) -> NotificationGroupTypeMentions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeMentions> for NotificationGroupTypeMentions {
@@ -223,6 +271,10 @@ impl AsRef<NotificationGroupTypeMentions> for RTDNotificationGroupTypeMentionsBu
   fn as_ref(&self) -> &NotificationGroupTypeMentions { &self.inner }
 }
 
+impl PartialEq for NotificationGroupTypeMentions {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -237,13 +289,17 @@ pub struct NotificationGroupTypeMessages {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationGroupTypeMessages {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeMessages" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationGroupTypeMessages" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -253,14 +309,15 @@ impl TDNotificationGroupType for NotificationGroupTypeMessages {}
 
 
 impl NotificationGroupTypeMessages {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationGroupTypeMessagesBuilder {
-    let mut inner = NotificationGroupTypeMessages::default();
-    inner.td_name = "notificationGroupTypeMessages".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationGroupTypeMessages { td_name: "notificationGroupTypeMessages".to_string(), ..Default::default() };
     RTDNotificationGroupTypeMessagesBuilder { inner }
   }
 
+  /// `NotificationGroupTypeMessages` with the fields tdlib requires, the others are set by `NotificationGroupTypeMessages::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -271,6 +328,12 @@ pub struct RTDNotificationGroupTypeMessagesBuilder {
 impl RTDNotificationGroupTypeMessagesBuilder {
   pub fn build(&self) -> NotificationGroupTypeMessages { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeMessages> for NotificationGroupTypeMessages {
@@ -281,6 +344,10 @@ impl AsRef<NotificationGroupTypeMessages> for RTDNotificationGroupTypeMessagesBu
   fn as_ref(&self) -> &NotificationGroupTypeMessages { &self.inner }
 }
 
+impl PartialEq for NotificationGroupTypeMessages {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -295,13 +362,17 @@ pub struct NotificationGroupTypeSecretChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationGroupTypeSecretChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationGroupTypeSecretChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationGroupTypeSecretChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -311,14 +382,15 @@ impl TDNotificationGroupType for NotificationGroupTypeSecretChat {}
 
 
 impl NotificationGroupTypeSecretChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationGroupTypeSecretChatBuilder {
-    let mut inner = NotificationGroupTypeSecretChat::default();
-    inner.td_name = "notificationGroupTypeSecretChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationGroupTypeSecretChat { td_name: "notificationGroupTypeSecretChat".to_string(), ..Default::default() };
     RTDNotificationGroupTypeSecretChatBuilder { inner }
   }
 
+  /// `NotificationGroupTypeSecretChat` with the fields tdlib requires, the others are set by `NotificationGroupTypeSecretChat::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -329,6 +401,12 @@ pub struct RTDNotificationGroupTypeSecretChatBuilder {
 impl RTDNotificationGroupTypeSecretChatBuilder {
   pub fn build(&self) -> NotificationGroupTypeSecretChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationGroupTypeSecretChat> for NotificationGroupTypeSecretChat {
@@ -339,5 +417,9 @@ impl AsRef<NotificationGroupTypeSecretChat> for RTDNotificationGroupTypeSecretCh
   fn as_ref(&self) -> &NotificationGroupTypeSecretChat { &self.inner }
 }
 
+impl PartialEq for NotificationGroupTypeSecretChat {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDNotificationSettingsScope: Debug + RObject {}
 
 /// Describes the types of chats to which notification settings are relevant
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum NotificationSettingsScope {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum NotificationSettingsScope {
   GroupChats(NotificationSettingsScopeGroupChats),
   /// Notification settings applied to all private and secret chats when the corresponding chat setting has a default value
   PrivateChats(NotificationSettingsScopePrivateChats),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for NotificationSettingsScope {
   fn deserialize<D>(deserializer: D) -> Result<NotificationSettingsScope, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      NotificationSettingsScope,
+      NotificationSettingsScope, Unknown;
       (notificationSettingsScopeChannelChats, ChannelChats);
       (notificationSettingsScopeGroupChats, GroupChats);
       (notificationSettingsScopePrivateChats, PrivateChats);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for NotificationSettingsScope {
 }
 
 impl RObject for NotificationSettingsScope {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       NotificationSettingsScope::ChannelChats(t) => t.td_name(),
       NotificationSettingsScope::GroupChats(t) => t.td_name(),
       NotificationSettingsScope::PrivateChats(t) => t.td_name(),
+      NotificationSettingsScope::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       NotificationSettingsScope::ChannelChats(t) => t.extra(),
       NotificationSettingsScope::GroupChats(t) => t.extra(),
       NotificationSettingsScope::PrivateChats(t) => t.extra(),
+      NotificationSettingsScope::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      NotificationSettingsScope::ChannelChats(t) => t.client_id(),
+      NotificationSettingsScope::GroupChats(t) => t.client_id(),
+      NotificationSettingsScope::PrivateChats(t) => t.client_id(),
+      NotificationSettingsScope::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for NotificationSettingsScope {
 }
 
 impl NotificationSettingsScope {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationSettingsScope::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, NotificationSettingsScope::_Default(_)) }
 
-  pub fn is_channel_chats(&self) -> bool { if let NotificationSettingsScope::ChannelChats(_) = self { true } else { false } }
-  pub fn is_group_chats(&self) -> bool { if let NotificationSettingsScope::GroupChats(_) = self { true } else { false } }
-  pub fn is_private_chats(&self) -> bool { if let NotificationSettingsScope::PrivateChats(_) = self { true } else { false } }
+  pub fn is_channel_chats(&self) -> bool { matches!(self, NotificationSettingsScope::ChannelChats(_)) }
+  pub fn is_group_chats(&self) -> bool { matches!(self, NotificationSettingsScope::GroupChats(_)) }
+  pub fn is_private_chats(&self) -> bool { matches!(self, NotificationSettingsScope::PrivateChats(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, NotificationSettingsScope::Unknown(_)) }
 
   pub fn on_channel_chats<F: FnOnce(&NotificationSettingsScopeChannelChats)>(&self, fnc: F) -> &Self { if let NotificationSettingsScope::ChannelChats(t) = self { fnc(t) }; self }
   pub fn on_group_chats<F: FnOnce(&NotificationSettingsScopeGroupChats)>(&self, fnc: F) -> &Self { if let NotificationSettingsScope::GroupChats(t) = self { fnc(t) }; self }
   pub fn on_private_chats<F: FnOnce(&NotificationSettingsScopePrivateChats)>(&self, fnc: F) -> &Self { if let NotificationSettingsScope::PrivateChats(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let NotificationSettingsScope::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_channel_chats(&self) -> Option<&NotificationSettingsScopeChannelChats> { if let NotificationSettingsScope::ChannelChats(t) = self { return Some(t) } None }
   pub fn as_group_chats(&self) -> Option<&NotificationSettingsScopeGroupChats> { if let NotificationSettingsScope::GroupChats(t) = self { return Some(t) } None }
   pub fn as_private_chats(&self) -> Option<&NotificationSettingsScopePrivateChats> { if let NotificationSettingsScope::PrivateChats(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let NotificationSettingsScope::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,13 +127,17 @@ pub struct NotificationSettingsScopeChannelChats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationSettingsScopeChannelChats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationSettingsScopeChannelChats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationSettingsScopeChannelChats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -127,14 +147,15 @@ impl TDNotificationSettingsScope for NotificationSettingsScopeChannelChats {}
 
 
 impl NotificationSettingsScopeChannelChats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationSettingsScopeChannelChatsBuilder {
-    let mut inner = NotificationSettingsScopeChannelChats::default();
-    inner.td_name = "notificationSettingsScopeChannelChats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationSettingsScopeChannelChats { td_name: "notificationSettingsScopeChannelChats".to_string(), ..Default::default() };
     RTDNotificationSettingsScopeChannelChatsBuilder { inner }
   }
 
+  /// `NotificationSettingsScopeChannelChats` with the fields tdlib requires, the others are set by `NotificationSettingsScopeChannelChats::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +166,12 @@ pub struct RTDNotificationSettingsScopeChannelChatsBuilder {
 impl RTDNotificationSettingsScopeChannelChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopeChannelChats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationSettingsScopeChannelChats> for NotificationSettingsScopeChannelChats {
@@ -155,6 +182,10 @@ impl AsRef<NotificationSettingsScopeChannelChats> for RTDNotificationSettingsSco
   fn as_ref(&self) -> &NotificationSettingsScopeChannelChats { &self.inner }
 }
 
+impl PartialEq for NotificationSettingsScopeChannelChats {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -169,13 +200,17 @@ pub struct NotificationSettingsScopeGroupChats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationSettingsScopeGroupChats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationSettingsScopeGroupChats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationSettingsScopeGroupChats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -185,14 +220,15 @@ impl TDNotificationSettingsScope for NotificationSettingsScopeGroupChats {}
 
 
 impl NotificationSettingsScopeGroupChats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationSettingsScopeGroupChatsBuilder {
-    let mut inner = NotificationSettingsScopeGroupChats::default();
-    inner.td_name = "notificationSettingsScopeGroupChats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationSettingsScopeGroupChats { td_name: "notificationSettingsScopeGroupChats".to_string(), ..Default::default() };
     RTDNotificationSettingsScopeGroupChatsBuilder { inner }
   }
 
+  /// `NotificationSettingsScopeGroupChats` with the fields tdlib requires, the others are set by `NotificationSettingsScopeGroupChats::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,12 @@ pub struct RTDNotificationSettingsScopeGroupChatsBuilder {
 impl RTDNotificationSettingsScopeGroupChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopeGroupChats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationSettingsScopeGroupChats> for NotificationSettingsScopeGroupChats {
@@ -213,6 +255,10 @@ impl AsRef<NotificationSettingsScopeGroupChats> for RTDNotificationSettingsScope
   fn as_ref(&self) -> &NotificationSettingsScopeGroupChats { &self.inner }
 }
 
+impl PartialEq for NotificationSettingsScopeGroupChats {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -227,13 +273,17 @@ pub struct NotificationSettingsScopePrivateChats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationSettingsScopePrivateChats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationSettingsScopePrivateChats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationSettingsScopePrivateChats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -243,14 +293,15 @@ impl TDNotificationSettingsScope for NotificationSettingsScopePrivateChats {}
 
 
 impl NotificationSettingsScopePrivateChats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationSettingsScopePrivateChatsBuilder {
-    let mut inner = NotificationSettingsScopePrivateChats::default();
-    inner.td_name = "notificationSettingsScopePrivateChats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationSettingsScopePrivateChats { td_name: "notificationSettingsScopePrivateChats".to_string(), ..Default::default() };
     RTDNotificationSettingsScopePrivateChatsBuilder { inner }
   }
 
+  /// `NotificationSettingsScopePrivateChats` with the fields tdlib requires, the others are set by `NotificationSettingsScopePrivateChats::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +312,12 @@ pub struct RTDNotificationSettingsScopePrivateChatsBuilder {
 impl RTDNotificationSettingsScopePrivateChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopePrivateChats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationSettingsScopePrivateChats> for NotificationSettingsScopePrivateChats {
@@ -271,5 +328,9 @@ impl AsRef<NotificationSettingsScopePrivateChats> for RTDNotificationSettingsSco
   fn as_ref(&self) -> &NotificationSettingsScopePrivateChats { &self.inner }
 }
 
+impl PartialEq for NotificationSettingsScopePrivateChats {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,11 +14,12 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDNotificationType: Debug + RObject {}
 
 /// Contains detailed information about a notification
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum NotificationType {
   #[doc(hidden)] _Default(()),
   /// New call was received
+  #[cfg(feature = "calls")]
   NewCall(NotificationTypeNewCall),
   /// New message was received
   NewMessage(NotificationTypeNewMessage),
@@ -27,6 +27,8 @@ pub enum NotificationType {
   NewPushMessage(NotificationTypeNewPushMessage),
   /// New secret chat was created
   NewSecretChat(NotificationTypeNewSecretChat),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,8 +40,8 @@ impl<'de> Deserialize<'de> for NotificationType {
   fn deserialize<D>(deserializer: D) -> Result<NotificationType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      NotificationType,
-      (notificationTypeNewCall, NewCall);
+      NotificationType, Unknown;
+      #[cfg(feature = "calls")] (notificationTypeNewCall, NewCall);
       (notificationTypeNewMessage, NewMessage);
       (notificationTypeNewPushMessage, NewPushMessage);
       (notificationTypeNewSecretChat, NewSecretChat);
@@ -49,22 +51,35 @@ impl<'de> Deserialize<'de> for NotificationType {
 }
 
 impl RObject for NotificationType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
-      NotificationType::NewCall(t) => t.td_name(),
+      #[cfg(feature = "calls")] NotificationType::NewCall(t) => t.td_name(),
       NotificationType::NewMessage(t) => t.td_name(),
       NotificationType::NewPushMessage(t) => t.td_name(),
       NotificationType::NewSecretChat(t) => t.td_name(),
+      NotificationType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
-      NotificationType::NewCall(t) => t.extra(),
+      #[cfg(feature = "calls")] NotificationType::NewCall(t) => t.extra(),
       NotificationType::NewMessage(t) => t.extra(),
       NotificationType::NewPushMessage(t) => t.extra(),
       NotificationType::NewSecretChat(t) => t.extra(),
+      NotificationType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      #[cfg(feature = "calls")] NotificationType::NewCall(t) => t.client_id(),
+      NotificationType::NewMessage(t) => t.client_id(),
+      NotificationType::NewPushMessage(t) => t.client_id(),
+      NotificationType::NewSecretChat(t) => t.client_id(),
+      NotificationType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,27 +88,30 @@ impl RObject for NotificationType {
 }
 
 impl NotificationType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, NotificationType::_Default(_)) }
 
-  pub fn is_new_call(&self) -> bool { if let NotificationType::NewCall(_) = self { true } else { false } }
-  pub fn is_new_message(&self) -> bool { if let NotificationType::NewMessage(_) = self { true } else { false } }
-  pub fn is_new_push_message(&self) -> bool { if let NotificationType::NewPushMessage(_) = self { true } else { false } }
-  pub fn is_new_secret_chat(&self) -> bool { if let NotificationType::NewSecretChat(_) = self { true } else { false } }
+  #[cfg(feature = "calls")] pub fn is_new_call(&self) -> bool { matches!(self, NotificationType::NewCall(_)) }
+  pub fn is_new_message(&self) -> bool { matches!(self, NotificationType::NewMessage(_)) }
+  pub fn is_new_push_message(&self) -> bool { matches!(self, NotificationType::NewPushMessage(_)) }
+  pub fn is_new_secret_chat(&self) -> bool { matches!(self, NotificationType::NewSecretChat(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, NotificationType::Unknown(_)) }
 
-  pub fn on_new_call<F: FnOnce(&NotificationTypeNewCall)>(&self, fnc: F) -> &Self { if let NotificationType::NewCall(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_new_call<F: FnOnce(&NotificationTypeNewCall)>(&self, fnc: F) -> &Self { if let NotificationType::NewCall(t) = self { fnc(t) }; self }
   pub fn on_new_message<F: FnOnce(&NotificationTypeNewMessage)>(&self, fnc: F) -> &Self { if let NotificationType::NewMessage(t) = self { fnc(t) }; self }
   pub fn on_new_push_message<F: FnOnce(&NotificationTypeNewPushMessage)>(&self, fnc: F) -> &Self { if let NotificationType::NewPushMessage(t) = self { fnc(t) }; self }
   pub fn on_new_secret_chat<F: FnOnce(&NotificationTypeNewSecretChat)>(&self, fnc: F) -> &Self { if let NotificationType::NewSecretChat(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let NotificationType::Unknown(t) = self { fnc(t) }; self }
 
-  pub fn as_new_call(&self) -> Option<&NotificationTypeNewCall> { if let NotificationType::NewCall(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_new_call(&self) -> Option<&NotificationTypeNewCall> { if let NotificationType::NewCall(t) = self { return Some(t) } None }
   pub fn as_new_message(&self) -> Option<&NotificationTypeNewMessage> { if let NotificationType::NewMessage(t) = self { return Some(t) } None }
   pub fn as_new_push_message(&self) -> Option<&NotificationTypeNewPushMessage> { if let NotificationType::NewPushMessage(t) = self { return Some(t) } None }
   pub fn as_new_secret_chat(&self) -> Option<&NotificationTypeNewSecretChat> { if let NotificationType::NewSecretChat(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let NotificationType::Unknown(t) = self { return Some(t) } None }
 
 
 
-  pub fn new_call<T: AsRef<NotificationTypeNewCall>>(t: T) -> Self { NotificationType::NewCall(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn new_call<T: AsRef<NotificationTypeNewCall>>(t: T) -> Self { NotificationType::NewCall(t.as_ref().clone()) }
 
   pub fn new_message<T: AsRef<NotificationTypeNewMessage>>(t: T) -> Self { NotificationType::NewMessage(t.as_ref().clone()) }
 
@@ -113,6 +131,8 @@ impl AsRef<NotificationType> for NotificationType {
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
+
 /// New call was received
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NotificationTypeNewCall {
@@ -121,15 +141,19 @@ pub struct NotificationTypeNewCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Call identifier
   call_id: i64,
   
 }
 
 impl RObject for NotificationTypeNewCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationTypeNewCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,14 +163,15 @@ impl TDNotificationType for NotificationTypeNewCall {}
 
 
 impl NotificationTypeNewCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationTypeNewCallBuilder {
-    let mut inner = NotificationTypeNewCall::default();
-    inner.td_name = "notificationTypeNewCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationTypeNewCall { td_name: "notificationTypeNewCall".to_string(), ..Default::default() };
     RTDNotificationTypeNewCallBuilder { inner }
   }
 
+  /// `NotificationTypeNewCall` with the fields tdlib requires, the others are set by `NotificationTypeNewCall::builder()`
+  pub fn new(call_id: i64) -> Self { Self::builder().call_id(call_id).build() }
+
   pub fn call_id(&self) -> i64 { self.call_id }
 
 }
@@ -159,6 +184,12 @@ pub struct RTDNotificationTypeNewCallBuilder {
 impl RTDNotificationTypeNewCallBuilder {
   pub fn build(&self) -> NotificationTypeNewCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -175,6 +206,14 @@ impl AsRef<NotificationTypeNewCall> for RTDNotificationTypeNewCallBuilder {
   fn as_ref(&self) -> &NotificationTypeNewCall { &self.inner }
 }
 
+impl PartialEq for NotificationTypeNewCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.call_id == other.call_id
+  }
+}
+
+}
+
 
 
 
@@ -189,15 +228,19 @@ pub struct NotificationTypeNewMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The message
   message: Message,
   
 }
 
 impl RObject for NotificationTypeNewMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationTypeNewMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,14 +250,15 @@ impl TDNotificationType for NotificationTypeNewMessage {}
 
 
 impl NotificationTypeNewMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationTypeNewMessageBuilder {
-    let mut inner = NotificationTypeNewMessage::default();
-    inner.td_name = "notificationTypeNewMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationTypeNewMessage { td_name: "notificationTypeNewMessage".to_string(), ..Default::default() };
     RTDNotificationTypeNewMessageBuilder { inner }
   }
 
+  /// `NotificationTypeNewMessage` with the fields tdlib requires, the others are set by `NotificationTypeNewMessage::builder()`
+  pub fn new(message: impl AsRef<Message>) -> Self { Self::builder().message(message).build() }
+
   pub fn message(&self) -> &Message { &self.message }
 
 }
@@ -227,6 +271,12 @@ pub struct RTDNotificationTypeNewMessageBuilder {
 impl RTDNotificationTypeNewMessageBuilder {
   pub fn build(&self) -> NotificationTypeNewMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -243,6 +293,12 @@ impl AsRef<NotificationTypeNewMessage> for RTDNotificationTypeNewMessageBuilder
   fn as_ref(&self) -> &NotificationTypeNewMessage { &self.inner }
 }
 
+impl PartialEq for NotificationTypeNewMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+  }
+}
+
 
 
 
@@ -257,9 +313,12 @@ pub struct NotificationTypeNewPushMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The message identifier. The message will not be available in the chat history, but the ID can be used in viewMessages, or as reply_to_message_id
-  message_id: i64,
+  message_id: MessageId,
   /// The sender of the message. Corresponding user or chat may be inaccessible
   sender: MessageSender,
   /// Name of the sender
@@ -272,8 +331,9 @@ pub struct NotificationTypeNewPushMessage {
 }
 
 impl RObject for NotificationTypeNewPushMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewPushMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationTypeNewPushMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -283,15 +343,30 @@ impl TDNotificationType for NotificationTypeNewPushMessage {}
 
 
 impl NotificationTypeNewPushMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationTypeNewPushMessageBuilder {
-    let mut inner = NotificationTypeNewPushMessage::default();
-    inner.td_name = "notificationTypeNewPushMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationTypeNewPushMessage { td_name: "notificationTypeNewPushMessage".to_string(), ..Default::default() };
     RTDNotificationTypeNewPushMessageBuilder { inner }
   }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  /// `NotificationTypeNewPushMessage` with the fields tdlib requires, the others are set by `NotificationTypeNewPushMessage::builder()`
+  pub fn new(
+    message_id: MessageId,
+    sender: impl AsRef<MessageSender>,
+    sender_name: impl AsRef<str>,
+    is_outgoing: bool,
+    content: impl AsRef<PushMessageContent>,
+  ) -> Self {
+    Self::builder()
+      .message_id(message_id)
+      .sender(sender)
+      .sender_name(sender_name)
+      .is_outgoing(is_outgoing)
+      .content(content)
+      .build()
+  }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn sender(&self) -> &MessageSender { &self.sender }
 
@@ -311,8 +386,14 @@ pub struct RTDNotificationTypeNewPushMessageBuilder {
 impl RTDNotificationTypeNewPushMessageBuilder {
   pub fn build(&self) -> NotificationTypeNewPushMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -351,6 +432,16 @@ impl AsRef<NotificationTypeNewPushMessage> for RTDNotificationTypeNewPushMessage
   fn as_ref(&self) -> &NotificationTypeNewPushMessage { &self.inner }
 }
 
+impl PartialEq for NotificationTypeNewPushMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.message_id == other.message_id
+      && self.sender == other.sender
+      && self.sender_name == other.sender_name
+      && self.is_outgoing == other.is_outgoing
+      && self.content == other.content
+  }
+}
+
 
 
 
@@ -365,13 +456,17 @@ pub struct NotificationTypeNewSecretChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for NotificationTypeNewSecretChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "notificationTypeNewSecretChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "notificationTypeNewSecretChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -381,14 +476,15 @@ impl TDNotificationType for NotificationTypeNewSecretChat {}
 
 
 impl NotificationTypeNewSecretChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDNotificationTypeNewSecretChatBuilder {
-    let mut inner = NotificationTypeNewSecretChat::default();
-    inner.td_name = "notificationTypeNewSecretChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = NotificationTypeNewSecretChat { td_name: "notificationTypeNewSecretChat".to_string(), ..Default::default() };
     RTDNotificationTypeNewSecretChatBuilder { inner }
   }
 
+  /// `NotificationTypeNewSecretChat` with the fields tdlib requires, the others are set by `NotificationTypeNewSecretChat::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -399,6 +495,12 @@ pub struct RTDNotificationTypeNewSecretChatBuilder {
 impl RTDNotificationTypeNewSecretChatBuilder {
   pub fn build(&self) -> NotificationTypeNewSecretChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<NotificationTypeNewSecretChat> for NotificationTypeNewSecretChat {
@@ -409,5 +511,9 @@ impl AsRef<NotificationTypeNewSecretChat> for RTDNotificationTypeNewSecretChatBu
   fn as_ref(&self) -> &NotificationTypeNewSecretChat { &self.inner }
 }
 
+impl PartialEq for NotificationTypeNewSecretChat {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,27 +13,32 @@ pub struct Ok {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for Ok {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "ok" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "ok" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Ok {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDOkBuilder {
-    let mut inner = Ok::default();
-    inner.td_name = "ok".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Ok { td_name: "ok".to_string(), ..Default::default() };
     RTDOkBuilder { inner }
   }
 
+  /// `Ok` with the fields tdlib requires, the others are set by `Ok::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -45,6 +49,12 @@ pub struct RTDOkBuilder {
 impl RTDOkBuilder {
   pub fn build(&self) -> Ok { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<Ok> for Ok {
@@ -55,5 +65,9 @@ impl AsRef<Ok> for RTDOkBuilder {
   fn as_ref(&self) -> &Ok { &self.inner }
 }
 
+impl PartialEq for Ok {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDOptionValue: Debug + RObject {}
 
 /// Represents the value of an option
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum OptionValue {
   #[doc(hidden)] _Default(()),
@@ -29,6 +28,8 @@ pub enum OptionValue {
   Integer(OptionValueInteger),
   /// Represents a string option
   String(OptionValueString),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -40,7 +41,7 @@ impl<'de> Deserialize<'de> for OptionValue {
   fn deserialize<D>(deserializer: D) -> Result<OptionValue, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      OptionValue,
+      OptionValue, Unknown;
       (getOption, GetOption);
       (optionValueBoolean, Boolean);
       (optionValueEmpty, Empty);
@@ -52,24 +53,38 @@ impl<'de> Deserialize<'de> for OptionValue {
 }
 
 impl RObject for OptionValue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       OptionValue::GetOption(t) => t.td_name(),
       OptionValue::Boolean(t) => t.td_name(),
       OptionValue::Empty(t) => t.td_name(),
       OptionValue::Integer(t) => t.td_name(),
       OptionValue::String(t) => t.td_name(),
+      OptionValue::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       OptionValue::GetOption(t) => t.extra(),
       OptionValue::Boolean(t) => t.extra(),
       OptionValue::Empty(t) => t.extra(),
       OptionValue::Integer(t) => t.extra(),
       OptionValue::String(t) => t.extra(),
+      OptionValue::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      OptionValue::GetOption(t) => t.client_id(),
+      OptionValue::Boolean(t) => t.client_id(),
+      OptionValue::Empty(t) => t.client_id(),
+      OptionValue::Integer(t) => t.client_id(),
+      OptionValue::String(t) => t.client_id(),
+      OptionValue::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -78,26 +93,29 @@ impl RObject for OptionValue {
 }
 
 impl OptionValue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let OptionValue::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, OptionValue::_Default(_)) }
 
-  pub fn is_get_option(&self) -> bool { if let OptionValue::GetOption(_) = self { true } else { false } }
-  pub fn is_boolean(&self) -> bool { if let OptionValue::Boolean(_) = self { true } else { false } }
-  pub fn is_empty(&self) -> bool { if let OptionValue::Empty(_) = self { true } else { false } }
-  pub fn is_integer(&self) -> bool { if let OptionValue::Integer(_) = self { true } else { false } }
-  pub fn is_string(&self) -> bool { if let OptionValue::String(_) = self { true } else { false } }
+  pub fn is_get_option(&self) -> bool { matches!(self, OptionValue::GetOption(_)) }
+  pub fn is_boolean(&self) -> bool { matches!(self, OptionValue::Boolean(_)) }
+  pub fn is_empty(&self) -> bool { matches!(self, OptionValue::Empty(_)) }
+  pub fn is_integer(&self) -> bool { matches!(self, OptionValue::Integer(_)) }
+  pub fn is_string(&self) -> bool { matches!(self, OptionValue::String(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, OptionValue::Unknown(_)) }
 
   pub fn on_get_option<F: FnOnce(&GetOption)>(&self, fnc: F) -> &Self { if let OptionValue::GetOption(t) = self { fnc(t) }; self }
   pub fn on_boolean<F: FnOnce(&OptionValueBoolean)>(&self, fnc: F) -> &Self { if let OptionValue::Boolean(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&OptionValueEmpty)>(&self, fnc: F) -> &Self { if let OptionValue::Empty(t) = self { fnc(t) }; self }
   pub fn on_integer<F: FnOnce(&OptionValueInteger)>(&self, fnc: F) -> &Self { if let OptionValue::Integer(t) = self { fnc(t) }; self }
   pub fn on_string<F: FnOnce(&OptionValueString)>(&self, fnc: F) -> &Self { if let OptionValue::String(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let OptionValue::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_option(&self) -> Option<&GetOption> { if let OptionValue::GetOption(t) = self { return Some(t) } None }
   pub fn as_boolean(&self) -> Option<&OptionValueBoolean> { if let OptionValue::Boolean(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&OptionValueEmpty> { if let OptionValue::Empty(t) = self { return Some(t) } None }
   pub fn as_integer(&self) -> Option<&OptionValueInteger> { if let OptionValue::Integer(t) = self { return Some(t) } None }
   pub fn as_string(&self) -> Option<&OptionValueString> { if let OptionValue::String(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let OptionValue::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -131,15 +149,19 @@ pub struct OptionValueBoolean {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The value of the option
   value: bool,
   
 }
 
 impl RObject for OptionValueBoolean {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueBoolean" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "optionValueBoolean" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -149,14 +171,15 @@ impl TDOptionValue for OptionValueBoolean {}
 
 
 impl OptionValueBoolean {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDOptionValueBooleanBuilder {
-    let mut inner = OptionValueBoolean::default();
-    inner.td_name = "optionValueBoolean".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = OptionValueBoolean { td_name: "optionValueBoolean".to_string(), ..Default::default() };
     RTDOptionValueBooleanBuilder { inner }
   }
 
+  /// `OptionValueBoolean` with the fields tdlib requires, the others are set by `OptionValueBoolean::builder()`
+  pub fn new(value: bool) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> bool { self.value }
 
 }
@@ -169,6 +192,12 @@ pub struct RTDOptionValueBooleanBuilder {
 impl RTDOptionValueBooleanBuilder {
   pub fn build(&self) -> OptionValueBoolean { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: bool) -> &mut Self {
     self.inner.value = value;
@@ -185,6 +214,12 @@ impl AsRef<OptionValueBoolean> for RTDOptionValueBooleanBuilder {
   fn as_ref(&self) -> &OptionValueBoolean { &self.inner }
 }
 
+impl PartialEq for OptionValueBoolean {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
 
@@ -199,13 +234,17 @@ pub struct OptionValueEmpty {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for OptionValueEmpty {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueEmpty" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "optionValueEmpty" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -215,14 +254,15 @@ impl TDOptionValue for OptionValueEmpty {}
 
 
 impl OptionValueEmpty {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDOptionValueEmptyBuilder {
-    let mut inner = OptionValueEmpty::default();
-    inner.td_name = "optionValueEmpty".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = OptionValueEmpty { td_name: "optionValueEmpty".to_string(), ..Default::default() };
     RTDOptionValueEmptyBuilder { inner }
   }
 
+  /// `OptionValueEmpty` with the fields tdlib requires, the others are set by `OptionValueEmpty::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +273,12 @@ pub struct RTDOptionValueEmptyBuilder {
 impl RTDOptionValueEmptyBuilder {
   pub fn build(&self) -> OptionValueEmpty { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<OptionValueEmpty> for OptionValueEmpty {
@@ -243,6 +289,10 @@ impl AsRef<OptionValueEmpty> for RTDOptionValueEmptyBuilder {
   fn as_ref(&self) -> &OptionValueEmpty { &self.inner }
 }
 
+impl PartialEq for OptionValueEmpty {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -257,15 +307,19 @@ pub struct OptionValueInteger {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The value of the option
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] value: isize,
+  #[serde(with = "crate::types::_common::int64")] value: i64,
   
 }
 
 impl RObject for OptionValueInteger {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueInteger" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "optionValueInteger" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -275,15 +329,16 @@ impl TDOptionValue for OptionValueInteger {}
 
 
 impl OptionValueInteger {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDOptionValueIntegerBuilder {
-    let mut inner = OptionValueInteger::default();
-    inner.td_name = "optionValueInteger".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = OptionValueInteger { td_name: "optionValueInteger".to_string(), ..Default::default() };
     RTDOptionValueIntegerBuilder { inner }
   }
 
-  pub fn value(&self) -> isize { self.value }
+  /// `OptionValueInteger` with the fields tdlib requires, the others are set by `OptionValueInteger::builder()`
+  pub fn new(value: i64) -> Self { Self::builder().value(value).build() }
+
+  pub fn value(&self) -> i64 { self.value }
 
 }
 
@@ -295,8 +350,14 @@ pub struct RTDOptionValueIntegerBuilder {
 impl RTDOptionValueIntegerBuilder {
   pub fn build(&self) -> OptionValueInteger { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn value(&mut self, value: isize) -> &mut Self {
+  pub fn value(&mut self, value: i64) -> &mut Self {
     self.inner.value = value;
     self
   }
@@ -311,6 +372,12 @@ impl AsRef<OptionValueInteger> for RTDOptionValueIntegerBuilder {
   fn as_ref(&self) -> &OptionValueInteger { &self.inner }
 }
 
+impl PartialEq for OptionValueInteger {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
 
@@ -325,15 +392,19 @@ pub struct OptionValueString {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The value of the option
   value: String,
   
 }
 
 impl RObject for OptionValueString {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "optionValueString" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "optionValueString" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -343,14 +414,15 @@ impl TDOptionValue for OptionValueString {}
 
 
 impl OptionValueString {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDOptionValueStringBuilder {
-    let mut inner = OptionValueString::default();
-    inner.td_name = "optionValueString".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = OptionValueString { td_name: "optionValueString".to_string(), ..Default::default() };
     RTDOptionValueStringBuilder { inner }
   }
 
+  /// `OptionValueString` with the fields tdlib requires, the others are set by `OptionValueString::builder()`
+  pub fn new(value: impl AsRef<str>) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> &String { &self.value }
 
 }
@@ -363,6 +435,12 @@ pub struct RTDOptionValueStringBuilder {
 impl RTDOptionValueStringBuilder {
   pub fn build(&self) -> OptionValueString { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
@@ -379,5 +457,11 @@ impl AsRef<OptionValueString> for RTDOptionValueStringBuilder {
   fn as_ref(&self) -> &OptionValueString { &self.inner }
 }
 
+impl PartialEq for OptionValueString {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
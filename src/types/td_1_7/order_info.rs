@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct OrderInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the user
   name: String,
   /// Phone number of the user
@@ -27,22 +29,24 @@ pub struct OrderInfo {
 }
 
 impl RObject for OrderInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "orderInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "orderInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl OrderInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDOrderInfoBuilder {
-    let mut inner = OrderInfo::default();
-    inner.td_name = "orderInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = OrderInfo { td_name: "orderInfo".to_string(), ..Default::default() };
     RTDOrderInfoBuilder { inner }
   }
 
+  /// `OrderInfo` with the fields tdlib requires, the others are set by `OrderInfo::builder()`
+  pub fn new(name: impl AsRef<str>, phone_number: impl AsRef<str>, email_address: impl AsRef<str>) -> Self { Self::builder().name(name).phone_number(phone_number).email_address(email_address).build() }
+
   pub fn name(&self) -> &String { &self.name }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -61,6 +65,12 @@ pub struct RTDOrderInfoBuilder {
 impl RTDOrderInfoBuilder {
   pub fn build(&self) -> OrderInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -95,5 +105,14 @@ impl AsRef<OrderInfo> for RTDOrderInfoBuilder {
   fn as_ref(&self) -> &OrderInfo { &self.inner }
 }
 
+impl PartialEq for OrderInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.name == other.name
+      && self.phone_number == other.phone_number
+      && self.email_address == other.email_address
+      && self.shipping_address == other.shipping_address
+  }
+}
+
 
 
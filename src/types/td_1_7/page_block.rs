@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPageBlock: Debug + RObject {}
 
 /// Describes a block of an instant view web page
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PageBlock {
   #[doc(hidden)] _Default(()),
@@ -77,6 +76,8 @@ pub enum PageBlock {
   Video(PageBlockVideo),
   /// A voice note
   VoiceNote(PageBlockVoiceNote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -88,7 +89,7 @@ impl<'de> Deserialize<'de> for PageBlock {
   fn deserialize<D>(deserializer: D) -> Result<PageBlock, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PageBlock,
+      PageBlock, Unknown;
       (pageBlockAnchor, Anchor);
       (pageBlockAnimation, Animation);
       (pageBlockAudio, Audio);
@@ -124,7 +125,7 @@ impl<'de> Deserialize<'de> for PageBlock {
 }
 
 impl RObject for PageBlock {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PageBlock::Anchor(t) => t.td_name(),
       PageBlock::Animation(t) => t.td_name(),
@@ -155,11 +156,12 @@ impl RObject for PageBlock {
       PageBlock::Title(t) => t.td_name(),
       PageBlock::Video(t) => t.td_name(),
       PageBlock::VoiceNote(t) => t.td_name(),
+      PageBlock::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PageBlock::Anchor(t) => t.extra(),
       PageBlock::Animation(t) => t.extra(),
@@ -190,6 +192,43 @@ impl RObject for PageBlock {
       PageBlock::Title(t) => t.extra(),
       PageBlock::Video(t) => t.extra(),
       PageBlock::VoiceNote(t) => t.extra(),
+      PageBlock::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PageBlock::Anchor(t) => t.client_id(),
+      PageBlock::Animation(t) => t.client_id(),
+      PageBlock::Audio(t) => t.client_id(),
+      PageBlock::AuthorDate(t) => t.client_id(),
+      PageBlock::BlockQuote(t) => t.client_id(),
+      PageBlock::ChatLink(t) => t.client_id(),
+      PageBlock::Collage(t) => t.client_id(),
+      PageBlock::Cover(t) => t.client_id(),
+      PageBlock::Details(t) => t.client_id(),
+      PageBlock::Divider(t) => t.client_id(),
+      PageBlock::Embedded(t) => t.client_id(),
+      PageBlock::EmbeddedPost(t) => t.client_id(),
+      PageBlock::Footer(t) => t.client_id(),
+      PageBlock::Header(t) => t.client_id(),
+      PageBlock::Kicker(t) => t.client_id(),
+      PageBlock::List(t) => t.client_id(),
+      PageBlock::Map(t) => t.client_id(),
+      PageBlock::Paragraph(t) => t.client_id(),
+      PageBlock::Photo(t) => t.client_id(),
+      PageBlock::Preformatted(t) => t.client_id(),
+      PageBlock::PullQuote(t) => t.client_id(),
+      PageBlock::RelatedArticles(t) => t.client_id(),
+      PageBlock::Slideshow(t) => t.client_id(),
+      PageBlock::Subheader(t) => t.client_id(),
+      PageBlock::Subtitle(t) => t.client_id(),
+      PageBlock::Table(t) => t.client_id(),
+      PageBlock::Title(t) => t.client_id(),
+      PageBlock::Video(t) => t.client_id(),
+      PageBlock::VoiceNote(t) => t.client_id(),
+      PageBlock::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -198,38 +237,39 @@ impl RObject for PageBlock {
 }
 
 impl PageBlock {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlock::_Default(_) = self { true } else { false } }
-
-  pub fn is_anchor(&self) -> bool { if let PageBlock::Anchor(_) = self { true } else { false } }
-  pub fn is_animation(&self) -> bool { if let PageBlock::Animation(_) = self { true } else { false } }
-  pub fn is_audio(&self) -> bool { if let PageBlock::Audio(_) = self { true } else { false } }
-  pub fn is_author_date(&self) -> bool { if let PageBlock::AuthorDate(_) = self { true } else { false } }
-  pub fn is_block_quote(&self) -> bool { if let PageBlock::BlockQuote(_) = self { true } else { false } }
-  pub fn is_chat_link(&self) -> bool { if let PageBlock::ChatLink(_) = self { true } else { false } }
-  pub fn is_collage(&self) -> bool { if let PageBlock::Collage(_) = self { true } else { false } }
-  pub fn is_cover(&self) -> bool { if let PageBlock::Cover(_) = self { true } else { false } }
-  pub fn is_details(&self) -> bool { if let PageBlock::Details(_) = self { true } else { false } }
-  pub fn is_divider(&self) -> bool { if let PageBlock::Divider(_) = self { true } else { false } }
-  pub fn is_embedded(&self) -> bool { if let PageBlock::Embedded(_) = self { true } else { false } }
-  pub fn is_embedded_post(&self) -> bool { if let PageBlock::EmbeddedPost(_) = self { true } else { false } }
-  pub fn is_footer(&self) -> bool { if let PageBlock::Footer(_) = self { true } else { false } }
-  pub fn is_header(&self) -> bool { if let PageBlock::Header(_) = self { true } else { false } }
-  pub fn is_kicker(&self) -> bool { if let PageBlock::Kicker(_) = self { true } else { false } }
-  pub fn is_list(&self) -> bool { if let PageBlock::List(_) = self { true } else { false } }
-  pub fn is_map(&self) -> bool { if let PageBlock::Map(_) = self { true } else { false } }
-  pub fn is_paragraph(&self) -> bool { if let PageBlock::Paragraph(_) = self { true } else { false } }
-  pub fn is_photo(&self) -> bool { if let PageBlock::Photo(_) = self { true } else { false } }
-  pub fn is_preformatted(&self) -> bool { if let PageBlock::Preformatted(_) = self { true } else { false } }
-  pub fn is_pull_quote(&self) -> bool { if let PageBlock::PullQuote(_) = self { true } else { false } }
-  pub fn is_related_articles(&self) -> bool { if let PageBlock::RelatedArticles(_) = self { true } else { false } }
-  pub fn is_slideshow(&self) -> bool { if let PageBlock::Slideshow(_) = self { true } else { false } }
-  pub fn is_subheader(&self) -> bool { if let PageBlock::Subheader(_) = self { true } else { false } }
-  pub fn is_subtitle(&self) -> bool { if let PageBlock::Subtitle(_) = self { true } else { false } }
-  pub fn is_table(&self) -> bool { if let PageBlock::Table(_) = self { true } else { false } }
-  pub fn is_title(&self) -> bool { if let PageBlock::Title(_) = self { true } else { false } }
-  pub fn is_video(&self) -> bool { if let PageBlock::Video(_) = self { true } else { false } }
-  pub fn is_voice_note(&self) -> bool { if let PageBlock::VoiceNote(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PageBlock::_Default(_)) }
+
+  pub fn is_anchor(&self) -> bool { matches!(self, PageBlock::Anchor(_)) }
+  pub fn is_animation(&self) -> bool { matches!(self, PageBlock::Animation(_)) }
+  pub fn is_audio(&self) -> bool { matches!(self, PageBlock::Audio(_)) }
+  pub fn is_author_date(&self) -> bool { matches!(self, PageBlock::AuthorDate(_)) }
+  pub fn is_block_quote(&self) -> bool { matches!(self, PageBlock::BlockQuote(_)) }
+  pub fn is_chat_link(&self) -> bool { matches!(self, PageBlock::ChatLink(_)) }
+  pub fn is_collage(&self) -> bool { matches!(self, PageBlock::Collage(_)) }
+  pub fn is_cover(&self) -> bool { matches!(self, PageBlock::Cover(_)) }
+  pub fn is_details(&self) -> bool { matches!(self, PageBlock::Details(_)) }
+  pub fn is_divider(&self) -> bool { matches!(self, PageBlock::Divider(_)) }
+  pub fn is_embedded(&self) -> bool { matches!(self, PageBlock::Embedded(_)) }
+  pub fn is_embedded_post(&self) -> bool { matches!(self, PageBlock::EmbeddedPost(_)) }
+  pub fn is_footer(&self) -> bool { matches!(self, PageBlock::Footer(_)) }
+  pub fn is_header(&self) -> bool { matches!(self, PageBlock::Header(_)) }
+  pub fn is_kicker(&self) -> bool { matches!(self, PageBlock::Kicker(_)) }
+  pub fn is_list(&self) -> bool { matches!(self, PageBlock::List(_)) }
+  pub fn is_map(&self) -> bool { matches!(self, PageBlock::Map(_)) }
+  pub fn is_paragraph(&self) -> bool { matches!(self, PageBlock::Paragraph(_)) }
+  pub fn is_photo(&self) -> bool { matches!(self, PageBlock::Photo(_)) }
+  pub fn is_preformatted(&self) -> bool { matches!(self, PageBlock::Preformatted(_)) }
+  pub fn is_pull_quote(&self) -> bool { matches!(self, PageBlock::PullQuote(_)) }
+  pub fn is_related_articles(&self) -> bool { matches!(self, PageBlock::RelatedArticles(_)) }
+  pub fn is_slideshow(&self) -> bool { matches!(self, PageBlock::Slideshow(_)) }
+  pub fn is_subheader(&self) -> bool { matches!(self, PageBlock::Subheader(_)) }
+  pub fn is_subtitle(&self) -> bool { matches!(self, PageBlock::Subtitle(_)) }
+  pub fn is_table(&self) -> bool { matches!(self, PageBlock::Table(_)) }
+  pub fn is_title(&self) -> bool { matches!(self, PageBlock::Title(_)) }
+  pub fn is_video(&self) -> bool { matches!(self, PageBlock::Video(_)) }
+  pub fn is_voice_note(&self) -> bool { matches!(self, PageBlock::VoiceNote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PageBlock::Unknown(_)) }
 
   pub fn on_anchor<F: FnOnce(&PageBlockAnchor)>(&self, fnc: F) -> &Self { if let PageBlock::Anchor(t) = self { fnc(t) }; self }
   pub fn on_animation<F: FnOnce(&PageBlockAnimation)>(&self, fnc: F) -> &Self { if let PageBlock::Animation(t) = self { fnc(t) }; self }
@@ -260,6 +300,7 @@ impl PageBlock {
   pub fn on_title<F: FnOnce(&PageBlockTitle)>(&self, fnc: F) -> &Self { if let PageBlock::Title(t) = self { fnc(t) }; self }
   pub fn on_video<F: FnOnce(&PageBlockVideo)>(&self, fnc: F) -> &Self { if let PageBlock::Video(t) = self { fnc(t) }; self }
   pub fn on_voice_note<F: FnOnce(&PageBlockVoiceNote)>(&self, fnc: F) -> &Self { if let PageBlock::VoiceNote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PageBlock::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_anchor(&self) -> Option<&PageBlockAnchor> { if let PageBlock::Anchor(t) = self { return Some(t) } None }
   pub fn as_animation(&self) -> Option<&PageBlockAnimation> { if let PageBlock::Animation(t) = self { return Some(t) } None }
@@ -290,6 +331,7 @@ impl PageBlock {
   pub fn as_title(&self) -> Option<&PageBlockTitle> { if let PageBlock::Title(t) = self { return Some(t) } None }
   pub fn as_video(&self) -> Option<&PageBlockVideo> { if let PageBlock::Video(t) = self { return Some(t) } None }
   pub fn as_voice_note(&self) -> Option<&PageBlockVoiceNote> { if let PageBlock::VoiceNote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PageBlock::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -371,15 +413,19 @@ pub struct PageBlockAnchor {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the anchor
   name: String,
   
 }
 
 impl RObject for PageBlockAnchor {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAnchor" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockAnchor" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -389,14 +435,15 @@ impl TDPageBlock for PageBlockAnchor {}
 
 
 impl PageBlockAnchor {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockAnchorBuilder {
-    let mut inner = PageBlockAnchor::default();
-    inner.td_name = "pageBlockAnchor".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockAnchor { td_name: "pageBlockAnchor".to_string(), ..Default::default() };
     RTDPageBlockAnchorBuilder { inner }
   }
 
+  /// `PageBlockAnchor` with the fields tdlib requires, the others are set by `PageBlockAnchor::builder()`
+  pub fn new(name: impl AsRef<str>) -> Self { Self::builder().name(name).build() }
+
   pub fn name(&self) -> &String { &self.name }
 
 }
@@ -409,6 +456,12 @@ pub struct RTDPageBlockAnchorBuilder {
 impl RTDPageBlockAnchorBuilder {
   pub fn build(&self) -> PageBlockAnchor { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -425,6 +478,12 @@ impl AsRef<PageBlockAnchor> for RTDPageBlockAnchorBuilder {
   fn as_ref(&self) -> &PageBlockAnchor { &self.inner }
 }
 
+impl PartialEq for PageBlockAnchor {
+  fn eq(&self, other: &Self) -> bool {
+    self.name == other.name
+  }
+}
+
 
 
 
@@ -439,7 +498,10 @@ pub struct PageBlockAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Animation file; may be null
   animation: Option<Animation>,
   /// Animation caption
@@ -450,8 +512,9 @@ pub struct PageBlockAnimation {
 }
 
 impl RObject for PageBlockAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -461,14 +524,15 @@ impl TDPageBlock for PageBlockAnimation {}
 
 
 impl PageBlockAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockAnimationBuilder {
-    let mut inner = PageBlockAnimation::default();
-    inner.td_name = "pageBlockAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockAnimation { td_name: "pageBlockAnimation".to_string(), ..Default::default() };
     RTDPageBlockAnimationBuilder { inner }
   }
 
+  /// `PageBlockAnimation` with the fields tdlib requires, the others are set by `PageBlockAnimation::builder()`
+  pub fn new(caption: impl AsRef<PageBlockCaption>, need_autoplay: bool) -> Self { Self::builder().caption(caption).need_autoplay(need_autoplay).build() }
+
   pub fn animation(&self) -> &Option<Animation> { &self.animation }
 
   pub fn caption(&self) -> &PageBlockCaption { &self.caption }
@@ -485,6 +549,12 @@ pub struct RTDPageBlockAnimationBuilder {
 impl RTDPageBlockAnimationBuilder {
   pub fn build(&self) -> PageBlockAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = Some(animation.as_ref().clone());
@@ -513,6 +583,14 @@ impl AsRef<PageBlockAnimation> for RTDPageBlockAnimationBuilder {
   fn as_ref(&self) -> &PageBlockAnimation { &self.inner }
 }
 
+impl PartialEq for PageBlockAnimation {
+  fn eq(&self, other: &Self) -> bool {
+    self.animation == other.animation
+      && self.caption == other.caption
+      && self.need_autoplay == other.need_autoplay
+  }
+}
+
 
 
 
@@ -527,7 +605,10 @@ pub struct PageBlockAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Audio file; may be null
   audio: Option<Audio>,
   /// Audio file caption
@@ -536,8 +617,9 @@ pub struct PageBlockAudio {
 }
 
 impl RObject for PageBlockAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -547,14 +629,15 @@ impl TDPageBlock for PageBlockAudio {}
 
 
 impl PageBlockAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockAudioBuilder {
-    let mut inner = PageBlockAudio::default();
-    inner.td_name = "pageBlockAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockAudio { td_name: "pageBlockAudio".to_string(), ..Default::default() };
     RTDPageBlockAudioBuilder { inner }
   }
 
+  /// `PageBlockAudio` with the fields tdlib requires, the others are set by `PageBlockAudio::builder()`
+  pub fn new(caption: impl AsRef<PageBlockCaption>) -> Self { Self::builder().caption(caption).build() }
+
   pub fn audio(&self) -> &Option<Audio> { &self.audio }
 
   pub fn caption(&self) -> &PageBlockCaption { &self.caption }
@@ -569,6 +652,12 @@ pub struct RTDPageBlockAudioBuilder {
 impl RTDPageBlockAudioBuilder {
   pub fn build(&self) -> PageBlockAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = Some(audio.as_ref().clone());
@@ -591,6 +680,13 @@ impl AsRef<PageBlockAudio> for RTDPageBlockAudioBuilder {
   fn as_ref(&self) -> &PageBlockAudio { &self.inner }
 }
 
+impl PartialEq for PageBlockAudio {
+  fn eq(&self, other: &Self) -> bool {
+    self.audio == other.audio
+      && self.caption == other.caption
+  }
+}
+
 
 
 
@@ -605,7 +701,10 @@ pub struct PageBlockAuthorDate {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Author
   author: RichText,
   /// Point in time (Unix timestamp) when the article was published; 0 if unknown
@@ -614,8 +713,9 @@ pub struct PageBlockAuthorDate {
 }
 
 impl RObject for PageBlockAuthorDate {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockAuthorDate" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockAuthorDate" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -625,14 +725,15 @@ impl TDPageBlock for PageBlockAuthorDate {}
 
 
 impl PageBlockAuthorDate {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockAuthorDateBuilder {
-    let mut inner = PageBlockAuthorDate::default();
-    inner.td_name = "pageBlockAuthorDate".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockAuthorDate { td_name: "pageBlockAuthorDate".to_string(), ..Default::default() };
     RTDPageBlockAuthorDateBuilder { inner }
   }
 
+  /// `PageBlockAuthorDate` with the fields tdlib requires, the others are set by `PageBlockAuthorDate::builder()`
+  pub fn new(author: impl AsRef<RichText>, publish_date: i64) -> Self { Self::builder().author(author).publish_date(publish_date).build() }
+
   pub fn author(&self) -> &RichText { &self.author }
 
   pub fn publish_date(&self) -> i64 { self.publish_date }
@@ -647,6 +748,12 @@ pub struct RTDPageBlockAuthorDateBuilder {
 impl RTDPageBlockAuthorDateBuilder {
   pub fn build(&self) -> PageBlockAuthorDate { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn author<T: AsRef<RichText>>(&mut self, author: T) -> &mut Self {
     self.inner.author = author.as_ref().clone();
@@ -669,6 +776,13 @@ impl AsRef<PageBlockAuthorDate> for RTDPageBlockAuthorDateBuilder {
   fn as_ref(&self) -> &PageBlockAuthorDate { &self.inner }
 }
 
+impl PartialEq for PageBlockAuthorDate {
+  fn eq(&self, other: &Self) -> bool {
+    self.author == other.author
+      && self.publish_date == other.publish_date
+  }
+}
+
 
 
 
@@ -683,7 +797,10 @@ pub struct PageBlockBlockQuote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Quote text
   text: RichText,
   /// Quote credit
@@ -692,8 +809,9 @@ pub struct PageBlockBlockQuote {
 }
 
 impl RObject for PageBlockBlockQuote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockBlockQuote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockBlockQuote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -703,14 +821,15 @@ impl TDPageBlock for PageBlockBlockQuote {}
 
 
 impl PageBlockBlockQuote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockBlockQuoteBuilder {
-    let mut inner = PageBlockBlockQuote::default();
-    inner.td_name = "pageBlockBlockQuote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockBlockQuote { td_name: "pageBlockBlockQuote".to_string(), ..Default::default() };
     RTDPageBlockBlockQuoteBuilder { inner }
   }
 
+  /// `PageBlockBlockQuote` with the fields tdlib requires, the others are set by `PageBlockBlockQuote::builder()`
+  pub fn new(text: impl AsRef<RichText>, credit: impl AsRef<RichText>) -> Self { Self::builder().text(text).credit(credit).build() }
+
   pub fn text(&self) -> &RichText { &self.text }
 
   pub fn credit(&self) -> &RichText { &self.credit }
@@ -725,6 +844,12 @@ pub struct RTDPageBlockBlockQuoteBuilder {
 impl RTDPageBlockBlockQuoteBuilder {
   pub fn build(&self) -> PageBlockBlockQuote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -747,6 +872,13 @@ impl AsRef<PageBlockBlockQuote> for RTDPageBlockBlockQuoteBuilder {
   fn as_ref(&self) -> &PageBlockBlockQuote { &self.inner }
 }
 
+impl PartialEq for PageBlockBlockQuote {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.credit == other.credit
+  }
+}
+
 
 
 
@@ -761,7 +893,10 @@ pub struct PageBlockChatLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat title
   title: String,
   /// Chat photo; may be null
@@ -772,8 +907,9 @@ pub struct PageBlockChatLink {
 }
 
 impl RObject for PageBlockChatLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockChatLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockChatLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -783,14 +919,15 @@ impl TDPageBlock for PageBlockChatLink {}
 
 
 impl PageBlockChatLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockChatLinkBuilder {
-    let mut inner = PageBlockChatLink::default();
-    inner.td_name = "pageBlockChatLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockChatLink { td_name: "pageBlockChatLink".to_string(), ..Default::default() };
     RTDPageBlockChatLinkBuilder { inner }
   }
 
+  /// `PageBlockChatLink` with the fields tdlib requires, the others are set by `PageBlockChatLink::builder()`
+  pub fn new(title: impl AsRef<str>, username: impl AsRef<str>) -> Self { Self::builder().title(title).username(username).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
   pub fn photo(&self) -> &Option<ChatPhotoInfo> { &self.photo }
@@ -807,6 +944,12 @@ pub struct RTDPageBlockChatLinkBuilder {
 impl RTDPageBlockChatLinkBuilder {
   pub fn build(&self) -> PageBlockChatLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -835,6 +978,14 @@ impl AsRef<PageBlockChatLink> for RTDPageBlockChatLinkBuilder {
   fn as_ref(&self) -> &PageBlockChatLink { &self.inner }
 }
 
+impl PartialEq for PageBlockChatLink {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.photo == other.photo
+      && self.username == other.username
+  }
+}
+
 
 
 
@@ -849,7 +1000,10 @@ pub struct PageBlockCollage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Collage item contents
   page_blocks: Vec<PageBlock>,
   /// Block caption
@@ -858,8 +1012,9 @@ pub struct PageBlockCollage {
 }
 
 impl RObject for PageBlockCollage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockCollage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockCollage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -869,14 +1024,15 @@ impl TDPageBlock for PageBlockCollage {}
 
 
 impl PageBlockCollage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockCollageBuilder {
-    let mut inner = PageBlockCollage::default();
-    inner.td_name = "pageBlockCollage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockCollage { td_name: "pageBlockCollage".to_string(), ..Default::default() };
     RTDPageBlockCollageBuilder { inner }
   }
 
+  /// `PageBlockCollage` with the fields tdlib requires, the others are set by `PageBlockCollage::builder()`
+  pub fn new(page_blocks: Vec<PageBlock>, caption: impl AsRef<PageBlockCaption>) -> Self { Self::builder().page_blocks(page_blocks).caption(caption).build() }
+
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
 
   pub fn caption(&self) -> &PageBlockCaption { &self.caption }
@@ -891,6 +1047,12 @@ pub struct RTDPageBlockCollageBuilder {
 impl RTDPageBlockCollageBuilder {
   pub fn build(&self) -> PageBlockCollage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -913,6 +1075,13 @@ impl AsRef<PageBlockCollage> for RTDPageBlockCollageBuilder {
   fn as_ref(&self) -> &PageBlockCollage { &self.inner }
 }
 
+impl PartialEq for PageBlockCollage {
+  fn eq(&self, other: &Self) -> bool {
+    self.page_blocks == other.page_blocks
+      && self.caption == other.caption
+  }
+}
+
 
 
 
@@ -927,15 +1096,19 @@ pub struct PageBlockCover {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Cover
   cover: Box<PageBlock>,
   
 }
 
 impl RObject for PageBlockCover {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockCover" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockCover" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -945,14 +1118,16 @@ impl TDPageBlock for PageBlockCover {}
 
 
 impl PageBlockCover {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockCoverBuilder {
-    let mut inner = PageBlockCover::default();
-    inner.td_name = "pageBlockCover".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockCover { td_name: "pageBlockCover".to_string(), ..Default::default() };
     RTDPageBlockCoverBuilder { inner }
   }
 
+  /// `PageBlockCover` with the fields tdlib requires, the others are set by `PageBlockCover::builder()`
+  pub fn new(cover: impl AsRef<Box<PageBlock>>) -> Self { Self::builder().cover(cover).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn cover(&self) -> &Box<PageBlock> { &self.cover }
 
 }
@@ -965,6 +1140,12 @@ pub struct RTDPageBlockCoverBuilder {
 impl RTDPageBlockCoverBuilder {
   pub fn build(&self) -> PageBlockCover { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn cover<T: AsRef<Box<PageBlock>>>(&mut self, cover: T) -> &mut Self {
     self.inner.cover = cover.as_ref().clone();
@@ -981,6 +1162,12 @@ impl AsRef<PageBlockCover> for RTDPageBlockCoverBuilder {
   fn as_ref(&self) -> &PageBlockCover { &self.inner }
 }
 
+impl PartialEq for PageBlockCover {
+  fn eq(&self, other: &Self) -> bool {
+    self.cover == other.cover
+  }
+}
+
 
 
 
@@ -995,7 +1182,10 @@ pub struct PageBlockDetails {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Always visible heading for the block
   header: RichText,
   /// Block contents
@@ -1006,8 +1196,9 @@ pub struct PageBlockDetails {
 }
 
 impl RObject for PageBlockDetails {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockDetails" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockDetails" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1017,14 +1208,15 @@ impl TDPageBlock for PageBlockDetails {}
 
 
 impl PageBlockDetails {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockDetailsBuilder {
-    let mut inner = PageBlockDetails::default();
-    inner.td_name = "pageBlockDetails".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockDetails { td_name: "pageBlockDetails".to_string(), ..Default::default() };
     RTDPageBlockDetailsBuilder { inner }
   }
 
+  /// `PageBlockDetails` with the fields tdlib requires, the others are set by `PageBlockDetails::builder()`
+  pub fn new(header: impl AsRef<RichText>, page_blocks: Vec<PageBlock>, is_open: bool) -> Self { Self::builder().header(header).page_blocks(page_blocks).is_open(is_open).build() }
+
   pub fn header(&self) -> &RichText { &self.header }
 
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
@@ -1041,6 +1233,12 @@ pub struct RTDPageBlockDetailsBuilder {
 impl RTDPageBlockDetailsBuilder {
   pub fn build(&self) -> PageBlockDetails { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -1069,6 +1267,14 @@ impl AsRef<PageBlockDetails> for RTDPageBlockDetailsBuilder {
   fn as_ref(&self) -> &PageBlockDetails { &self.inner }
 }
 
+impl PartialEq for PageBlockDetails {
+  fn eq(&self, other: &Self) -> bool {
+    self.header == other.header
+      && self.page_blocks == other.page_blocks
+      && self.is_open == other.is_open
+  }
+}
+
 
 
 
@@ -1083,13 +1289,17 @@ pub struct PageBlockDivider {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PageBlockDivider {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockDivider" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockDivider" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1099,14 +1309,15 @@ impl TDPageBlock for PageBlockDivider {}
 
 
 impl PageBlockDivider {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockDividerBuilder {
-    let mut inner = PageBlockDivider::default();
-    inner.td_name = "pageBlockDivider".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockDivider { td_name: "pageBlockDivider".to_string(), ..Default::default() };
     RTDPageBlockDividerBuilder { inner }
   }
 
+  /// `PageBlockDivider` with the fields tdlib requires, the others are set by `PageBlockDivider::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1117,6 +1328,12 @@ pub struct RTDPageBlockDividerBuilder {
 impl RTDPageBlockDividerBuilder {
   pub fn build(&self) -> PageBlockDivider { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockDivider> for PageBlockDivider {
@@ -1127,6 +1344,10 @@ impl AsRef<PageBlockDivider> for RTDPageBlockDividerBuilder {
   fn as_ref(&self) -> &PageBlockDivider { &self.inner }
 }
 
+impl PartialEq for PageBlockDivider {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1141,7 +1362,10 @@ pub struct PageBlockEmbedded {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Web page URL, if available
   url: String,
   /// HTML-markup of the embedded page
@@ -1162,8 +1386,9 @@ pub struct PageBlockEmbedded {
 }
 
 impl RObject for PageBlockEmbedded {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockEmbedded" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockEmbedded" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1173,14 +1398,33 @@ impl TDPageBlock for PageBlockEmbedded {}
 
 
 impl PageBlockEmbedded {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockEmbeddedBuilder {
-    let mut inner = PageBlockEmbedded::default();
-    inner.td_name = "pageBlockEmbedded".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockEmbedded { td_name: "pageBlockEmbedded".to_string(), ..Default::default() };
     RTDPageBlockEmbeddedBuilder { inner }
   }
 
+  /// `PageBlockEmbedded` with the fields tdlib requires, the others are set by `PageBlockEmbedded::builder()`
+  pub fn new(
+    url: impl AsRef<str>,
+    html: impl AsRef<str>,
+    width: i64,
+    height: i64,
+    caption: impl AsRef<PageBlockCaption>,
+    is_full_width: bool,
+    allow_scrolling: bool,
+  ) -> Self {
+    Self::builder()
+      .url(url)
+      .html(html)
+      .width(width)
+      .height(height)
+      .caption(caption)
+      .is_full_width(is_full_width)
+      .allow_scrolling(allow_scrolling)
+      .build()
+  }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn html(&self) -> &String { &self.html }
@@ -1207,6 +1451,12 @@ pub struct RTDPageBlockEmbeddedBuilder {
 impl RTDPageBlockEmbeddedBuilder {
   pub fn build(&self) -> PageBlockEmbedded { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -1265,6 +1515,19 @@ impl AsRef<PageBlockEmbedded> for RTDPageBlockEmbeddedBuilder {
   fn as_ref(&self) -> &PageBlockEmbedded { &self.inner }
 }
 
+impl PartialEq for PageBlockEmbedded {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.html == other.html
+      && self.poster_photo == other.poster_photo
+      && self.width == other.width
+      && self.height == other.height
+      && self.caption == other.caption
+      && self.is_full_width == other.is_full_width
+      && self.allow_scrolling == other.allow_scrolling
+  }
+}
+
 
 
 
@@ -1279,7 +1542,10 @@ pub struct PageBlockEmbeddedPost {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Web page URL
   url: String,
   /// Post author
@@ -1296,8 +1562,9 @@ pub struct PageBlockEmbeddedPost {
 }
 
 impl RObject for PageBlockEmbeddedPost {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockEmbeddedPost" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockEmbeddedPost" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1307,14 +1574,29 @@ impl TDPageBlock for PageBlockEmbeddedPost {}
 
 
 impl PageBlockEmbeddedPost {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockEmbeddedPostBuilder {
-    let mut inner = PageBlockEmbeddedPost::default();
-    inner.td_name = "pageBlockEmbeddedPost".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockEmbeddedPost { td_name: "pageBlockEmbeddedPost".to_string(), ..Default::default() };
     RTDPageBlockEmbeddedPostBuilder { inner }
   }
 
+  /// `PageBlockEmbeddedPost` with the fields tdlib requires, the others are set by `PageBlockEmbeddedPost::builder()`
+  pub fn new(
+    url: impl AsRef<str>,
+    author: impl AsRef<str>,
+    date: i64,
+    page_blocks: Vec<PageBlock>,
+    caption: impl AsRef<PageBlockCaption>,
+  ) -> Self {
+    Self::builder()
+      .url(url)
+      .author(author)
+      .date(date)
+      .page_blocks(page_blocks)
+      .caption(caption)
+      .build()
+  }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn author(&self) -> &String { &self.author }
@@ -1337,6 +1619,12 @@ pub struct RTDPageBlockEmbeddedPostBuilder {
 impl RTDPageBlockEmbeddedPostBuilder {
   pub fn build(&self) -> PageBlockEmbeddedPost { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -1383,6 +1671,17 @@ impl AsRef<PageBlockEmbeddedPost> for RTDPageBlockEmbeddedPostBuilder {
   fn as_ref(&self) -> &PageBlockEmbeddedPost { &self.inner }
 }
 
+impl PartialEq for PageBlockEmbeddedPost {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.author == other.author
+      && self.author_photo == other.author_photo
+      && self.date == other.date
+      && self.page_blocks == other.page_blocks
+      && self.caption == other.caption
+  }
+}
+
 
 
 
@@ -1397,15 +1696,19 @@ pub struct PageBlockFooter {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Footer
   footer: RichText,
   
 }
 
 impl RObject for PageBlockFooter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockFooter" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockFooter" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1415,14 +1718,15 @@ impl TDPageBlock for PageBlockFooter {}
 
 
 impl PageBlockFooter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockFooterBuilder {
-    let mut inner = PageBlockFooter::default();
-    inner.td_name = "pageBlockFooter".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockFooter { td_name: "pageBlockFooter".to_string(), ..Default::default() };
     RTDPageBlockFooterBuilder { inner }
   }
 
+  /// `PageBlockFooter` with the fields tdlib requires, the others are set by `PageBlockFooter::builder()`
+  pub fn new(footer: impl AsRef<RichText>) -> Self { Self::builder().footer(footer).build() }
+
   pub fn footer(&self) -> &RichText { &self.footer }
 
 }
@@ -1435,6 +1739,12 @@ pub struct RTDPageBlockFooterBuilder {
 impl RTDPageBlockFooterBuilder {
   pub fn build(&self) -> PageBlockFooter { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn footer<T: AsRef<RichText>>(&mut self, footer: T) -> &mut Self {
     self.inner.footer = footer.as_ref().clone();
@@ -1451,6 +1761,12 @@ impl AsRef<PageBlockFooter> for RTDPageBlockFooterBuilder {
   fn as_ref(&self) -> &PageBlockFooter { &self.inner }
 }
 
+impl PartialEq for PageBlockFooter {
+  fn eq(&self, other: &Self) -> bool {
+    self.footer == other.footer
+  }
+}
+
 
 
 
@@ -1465,15 +1781,19 @@ pub struct PageBlockHeader {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Header
   header: RichText,
   
 }
 
 impl RObject for PageBlockHeader {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHeader" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockHeader" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1483,14 +1803,15 @@ impl TDPageBlock for PageBlockHeader {}
 
 
 impl PageBlockHeader {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockHeaderBuilder {
-    let mut inner = PageBlockHeader::default();
-    inner.td_name = "pageBlockHeader".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockHeader { td_name: "pageBlockHeader".to_string(), ..Default::default() };
     RTDPageBlockHeaderBuilder { inner }
   }
 
+  /// `PageBlockHeader` with the fields tdlib requires, the others are set by `PageBlockHeader::builder()`
+  pub fn new(header: impl AsRef<RichText>) -> Self { Self::builder().header(header).build() }
+
   pub fn header(&self) -> &RichText { &self.header }
 
 }
@@ -1503,6 +1824,12 @@ pub struct RTDPageBlockHeaderBuilder {
 impl RTDPageBlockHeaderBuilder {
   pub fn build(&self) -> PageBlockHeader { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -1519,6 +1846,12 @@ impl AsRef<PageBlockHeader> for RTDPageBlockHeaderBuilder {
   fn as_ref(&self) -> &PageBlockHeader { &self.inner }
 }
 
+impl PartialEq for PageBlockHeader {
+  fn eq(&self, other: &Self) -> bool {
+    self.header == other.header
+  }
+}
+
 
 
 
@@ -1533,15 +1866,19 @@ pub struct PageBlockKicker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Kicker
   kicker: RichText,
   
 }
 
 impl RObject for PageBlockKicker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockKicker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockKicker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1551,14 +1888,15 @@ impl TDPageBlock for PageBlockKicker {}
 
 
 impl PageBlockKicker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockKickerBuilder {
-    let mut inner = PageBlockKicker::default();
-    inner.td_name = "pageBlockKicker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockKicker { td_name: "pageBlockKicker".to_string(), ..Default::default() };
     RTDPageBlockKickerBuilder { inner }
   }
 
+  /// `PageBlockKicker` with the fields tdlib requires, the others are set by `PageBlockKicker::builder()`
+  pub fn new(kicker: impl AsRef<RichText>) -> Self { Self::builder().kicker(kicker).build() }
+
   pub fn kicker(&self) -> &RichText { &self.kicker }
 
 }
@@ -1571,6 +1909,12 @@ pub struct RTDPageBlockKickerBuilder {
 impl RTDPageBlockKickerBuilder {
   pub fn build(&self) -> PageBlockKicker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn kicker<T: AsRef<RichText>>(&mut self, kicker: T) -> &mut Self {
     self.inner.kicker = kicker.as_ref().clone();
@@ -1587,6 +1931,12 @@ impl AsRef<PageBlockKicker> for RTDPageBlockKickerBuilder {
   fn as_ref(&self) -> &PageBlockKicker { &self.inner }
 }
 
+impl PartialEq for PageBlockKicker {
+  fn eq(&self, other: &Self) -> bool {
+    self.kicker == other.kicker
+  }
+}
+
 
 
 
@@ -1601,15 +1951,19 @@ pub struct PageBlockList {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The items of the list
   items: Vec<PageBlockListItem>,
   
 }
 
 impl RObject for PageBlockList {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockList" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockList" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1619,14 +1973,15 @@ impl TDPageBlock for PageBlockList {}
 
 
 impl PageBlockList {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockListBuilder {
-    let mut inner = PageBlockList::default();
-    inner.td_name = "pageBlockList".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockList { td_name: "pageBlockList".to_string(), ..Default::default() };
     RTDPageBlockListBuilder { inner }
   }
 
+  /// `PageBlockList` with the fields tdlib requires, the others are set by `PageBlockList::builder()`
+  pub fn new(items: Vec<PageBlockListItem>) -> Self { Self::builder().items(items).build() }
+
   pub fn items(&self) -> &Vec<PageBlockListItem> { &self.items }
 
 }
@@ -1639,6 +1994,12 @@ pub struct RTDPageBlockListBuilder {
 impl RTDPageBlockListBuilder {
   pub fn build(&self) -> PageBlockList { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn items(&mut self, items: Vec<PageBlockListItem>) -> &mut Self {
     self.inner.items = items;
@@ -1655,6 +2016,12 @@ impl AsRef<PageBlockList> for RTDPageBlockListBuilder {
   fn as_ref(&self) -> &PageBlockList { &self.inner }
 }
 
+impl PartialEq for PageBlockList {
+  fn eq(&self, other: &Self) -> bool {
+    self.items == other.items
+  }
+}
+
 
 
 
@@ -1669,7 +2036,10 @@ pub struct PageBlockMap {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Location of the map center
   location: Location,
   /// Map zoom level
@@ -1684,8 +2054,9 @@ pub struct PageBlockMap {
 }
 
 impl RObject for PageBlockMap {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockMap" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockMap" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1695,14 +2066,29 @@ impl TDPageBlock for PageBlockMap {}
 
 
 impl PageBlockMap {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockMapBuilder {
-    let mut inner = PageBlockMap::default();
-    inner.td_name = "pageBlockMap".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockMap { td_name: "pageBlockMap".to_string(), ..Default::default() };
     RTDPageBlockMapBuilder { inner }
   }
 
+  /// `PageBlockMap` with the fields tdlib requires, the others are set by `PageBlockMap::builder()`
+  pub fn new(
+    location: impl AsRef<Location>,
+    zoom: i64,
+    width: i64,
+    height: i64,
+    caption: impl AsRef<PageBlockCaption>,
+  ) -> Self {
+    Self::builder()
+      .location(location)
+      .zoom(zoom)
+      .width(width)
+      .height(height)
+      .caption(caption)
+      .build()
+  }
+
   pub fn location(&self) -> &Location { &self.location }
 
   pub fn zoom(&self) -> i64 { self.zoom }
@@ -1723,6 +2109,12 @@ pub struct RTDPageBlockMapBuilder {
 impl RTDPageBlockMapBuilder {
   pub fn build(&self) -> PageBlockMap { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -1763,6 +2155,16 @@ impl AsRef<PageBlockMap> for RTDPageBlockMapBuilder {
   fn as_ref(&self) -> &PageBlockMap { &self.inner }
 }
 
+impl PartialEq for PageBlockMap {
+  fn eq(&self, other: &Self) -> bool {
+    self.location == other.location
+      && self.zoom == other.zoom
+      && self.width == other.width
+      && self.height == other.height
+      && self.caption == other.caption
+  }
+}
+
 
 
 
@@ -1777,15 +2179,19 @@ pub struct PageBlockParagraph {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Paragraph text
   text: RichText,
   
 }
 
 impl RObject for PageBlockParagraph {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockParagraph" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockParagraph" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1795,14 +2201,15 @@ impl TDPageBlock for PageBlockParagraph {}
 
 
 impl PageBlockParagraph {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockParagraphBuilder {
-    let mut inner = PageBlockParagraph::default();
-    inner.td_name = "pageBlockParagraph".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockParagraph { td_name: "pageBlockParagraph".to_string(), ..Default::default() };
     RTDPageBlockParagraphBuilder { inner }
   }
 
+  /// `PageBlockParagraph` with the fields tdlib requires, the others are set by `PageBlockParagraph::builder()`
+  pub fn new(text: impl AsRef<RichText>) -> Self { Self::builder().text(text).build() }
+
   pub fn text(&self) -> &RichText { &self.text }
 
 }
@@ -1815,6 +2222,12 @@ pub struct RTDPageBlockParagraphBuilder {
 impl RTDPageBlockParagraphBuilder {
   pub fn build(&self) -> PageBlockParagraph { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1831,6 +2244,12 @@ impl AsRef<PageBlockParagraph> for RTDPageBlockParagraphBuilder {
   fn as_ref(&self) -> &PageBlockParagraph { &self.inner }
 }
 
+impl PartialEq for PageBlockParagraph {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -1845,7 +2264,10 @@ pub struct PageBlockPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Photo file; may be null
   photo: Option<Photo>,
   /// Photo caption
@@ -1856,8 +2278,9 @@ pub struct PageBlockPhoto {
 }
 
 impl RObject for PageBlockPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1867,14 +2290,15 @@ impl TDPageBlock for PageBlockPhoto {}
 
 
 impl PageBlockPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockPhotoBuilder {
-    let mut inner = PageBlockPhoto::default();
-    inner.td_name = "pageBlockPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockPhoto { td_name: "pageBlockPhoto".to_string(), ..Default::default() };
     RTDPageBlockPhotoBuilder { inner }
   }
 
+  /// `PageBlockPhoto` with the fields tdlib requires, the others are set by `PageBlockPhoto::builder()`
+  pub fn new(caption: impl AsRef<PageBlockCaption>, url: impl AsRef<str>) -> Self { Self::builder().caption(caption).url(url).build() }
+
   pub fn photo(&self) -> &Option<Photo> { &self.photo }
 
   pub fn caption(&self) -> &PageBlockCaption { &self.caption }
@@ -1891,6 +2315,12 @@ pub struct RTDPageBlockPhotoBuilder {
 impl RTDPageBlockPhotoBuilder {
   pub fn build(&self) -> PageBlockPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -1919,6 +2349,14 @@ impl AsRef<PageBlockPhoto> for RTDPageBlockPhotoBuilder {
   fn as_ref(&self) -> &PageBlockPhoto { &self.inner }
 }
 
+impl PartialEq for PageBlockPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+      && self.caption == other.caption
+      && self.url == other.url
+  }
+}
+
 
 
 
@@ -1933,7 +2371,10 @@ pub struct PageBlockPreformatted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Paragraph text
   text: RichText,
   /// Programming language for which the text needs to be formatted
@@ -1942,8 +2383,9 @@ pub struct PageBlockPreformatted {
 }
 
 impl RObject for PageBlockPreformatted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockPreformatted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockPreformatted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1953,14 +2395,15 @@ impl TDPageBlock for PageBlockPreformatted {}
 
 
 impl PageBlockPreformatted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockPreformattedBuilder {
-    let mut inner = PageBlockPreformatted::default();
-    inner.td_name = "pageBlockPreformatted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockPreformatted { td_name: "pageBlockPreformatted".to_string(), ..Default::default() };
     RTDPageBlockPreformattedBuilder { inner }
   }
 
+  /// `PageBlockPreformatted` with the fields tdlib requires, the others are set by `PageBlockPreformatted::builder()`
+  pub fn new(text: impl AsRef<RichText>, language: impl AsRef<str>) -> Self { Self::builder().text(text).language(language).build() }
+
   pub fn text(&self) -> &RichText { &self.text }
 
   pub fn language(&self) -> &String { &self.language }
@@ -1975,6 +2418,12 @@ pub struct RTDPageBlockPreformattedBuilder {
 impl RTDPageBlockPreformattedBuilder {
   pub fn build(&self) -> PageBlockPreformatted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1997,6 +2446,13 @@ impl AsRef<PageBlockPreformatted> for RTDPageBlockPreformattedBuilder {
   fn as_ref(&self) -> &PageBlockPreformatted { &self.inner }
 }
 
+impl PartialEq for PageBlockPreformatted {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.language == other.language
+  }
+}
+
 
 
 
@@ -2011,7 +2467,10 @@ pub struct PageBlockPullQuote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Quote text
   text: RichText,
   /// Quote credit
@@ -2020,8 +2479,9 @@ pub struct PageBlockPullQuote {
 }
 
 impl RObject for PageBlockPullQuote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockPullQuote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockPullQuote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2031,14 +2491,15 @@ impl TDPageBlock for PageBlockPullQuote {}
 
 
 impl PageBlockPullQuote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockPullQuoteBuilder {
-    let mut inner = PageBlockPullQuote::default();
-    inner.td_name = "pageBlockPullQuote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockPullQuote { td_name: "pageBlockPullQuote".to_string(), ..Default::default() };
     RTDPageBlockPullQuoteBuilder { inner }
   }
 
+  /// `PageBlockPullQuote` with the fields tdlib requires, the others are set by `PageBlockPullQuote::builder()`
+  pub fn new(text: impl AsRef<RichText>, credit: impl AsRef<RichText>) -> Self { Self::builder().text(text).credit(credit).build() }
+
   pub fn text(&self) -> &RichText { &self.text }
 
   pub fn credit(&self) -> &RichText { &self.credit }
@@ -2053,6 +2514,12 @@ pub struct RTDPageBlockPullQuoteBuilder {
 impl RTDPageBlockPullQuoteBuilder {
   pub fn build(&self) -> PageBlockPullQuote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -2075,6 +2542,13 @@ impl AsRef<PageBlockPullQuote> for RTDPageBlockPullQuoteBuilder {
   fn as_ref(&self) -> &PageBlockPullQuote { &self.inner }
 }
 
+impl PartialEq for PageBlockPullQuote {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.credit == other.credit
+  }
+}
+
 
 
 
@@ -2089,7 +2563,10 @@ pub struct PageBlockRelatedArticles {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Block header
   header: RichText,
   /// List of related articles
@@ -2098,8 +2575,9 @@ pub struct PageBlockRelatedArticles {
 }
 
 impl RObject for PageBlockRelatedArticles {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockRelatedArticles" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockRelatedArticles" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2109,14 +2587,15 @@ impl TDPageBlock for PageBlockRelatedArticles {}
 
 
 impl PageBlockRelatedArticles {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockRelatedArticlesBuilder {
-    let mut inner = PageBlockRelatedArticles::default();
-    inner.td_name = "pageBlockRelatedArticles".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockRelatedArticles { td_name: "pageBlockRelatedArticles".to_string(), ..Default::default() };
     RTDPageBlockRelatedArticlesBuilder { inner }
   }
 
+  /// `PageBlockRelatedArticles` with the fields tdlib requires, the others are set by `PageBlockRelatedArticles::builder()`
+  pub fn new(header: impl AsRef<RichText>, articles: Vec<PageBlockRelatedArticle>) -> Self { Self::builder().header(header).articles(articles).build() }
+
   pub fn header(&self) -> &RichText { &self.header }
 
   pub fn articles(&self) -> &Vec<PageBlockRelatedArticle> { &self.articles }
@@ -2131,6 +2610,12 @@ pub struct RTDPageBlockRelatedArticlesBuilder {
 impl RTDPageBlockRelatedArticlesBuilder {
   pub fn build(&self) -> PageBlockRelatedArticles { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -2153,6 +2638,13 @@ impl AsRef<PageBlockRelatedArticles> for RTDPageBlockRelatedArticlesBuilder {
   fn as_ref(&self) -> &PageBlockRelatedArticles { &self.inner }
 }
 
+impl PartialEq for PageBlockRelatedArticles {
+  fn eq(&self, other: &Self) -> bool {
+    self.header == other.header
+      && self.articles == other.articles
+  }
+}
+
 
 
 
@@ -2167,7 +2659,10 @@ pub struct PageBlockSlideshow {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Slideshow item contents
   page_blocks: Vec<PageBlock>,
   /// Block caption
@@ -2176,8 +2671,9 @@ pub struct PageBlockSlideshow {
 }
 
 impl RObject for PageBlockSlideshow {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockSlideshow" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockSlideshow" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2187,14 +2683,15 @@ impl TDPageBlock for PageBlockSlideshow {}
 
 
 impl PageBlockSlideshow {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockSlideshowBuilder {
-    let mut inner = PageBlockSlideshow::default();
-    inner.td_name = "pageBlockSlideshow".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockSlideshow { td_name: "pageBlockSlideshow".to_string(), ..Default::default() };
     RTDPageBlockSlideshowBuilder { inner }
   }
 
+  /// `PageBlockSlideshow` with the fields tdlib requires, the others are set by `PageBlockSlideshow::builder()`
+  pub fn new(page_blocks: Vec<PageBlock>, caption: impl AsRef<PageBlockCaption>) -> Self { Self::builder().page_blocks(page_blocks).caption(caption).build() }
+
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
 
   pub fn caption(&self) -> &PageBlockCaption { &self.caption }
@@ -2209,6 +2706,12 @@ pub struct RTDPageBlockSlideshowBuilder {
 impl RTDPageBlockSlideshowBuilder {
   pub fn build(&self) -> PageBlockSlideshow { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -2231,6 +2734,13 @@ impl AsRef<PageBlockSlideshow> for RTDPageBlockSlideshowBuilder {
   fn as_ref(&self) -> &PageBlockSlideshow { &self.inner }
 }
 
+impl PartialEq for PageBlockSlideshow {
+  fn eq(&self, other: &Self) -> bool {
+    self.page_blocks == other.page_blocks
+      && self.caption == other.caption
+  }
+}
+
 
 
 
@@ -2245,15 +2755,19 @@ pub struct PageBlockSubheader {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Subheader
   subheader: RichText,
   
 }
 
 impl RObject for PageBlockSubheader {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockSubheader" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockSubheader" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2263,14 +2777,15 @@ impl TDPageBlock for PageBlockSubheader {}
 
 
 impl PageBlockSubheader {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockSubheaderBuilder {
-    let mut inner = PageBlockSubheader::default();
-    inner.td_name = "pageBlockSubheader".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockSubheader { td_name: "pageBlockSubheader".to_string(), ..Default::default() };
     RTDPageBlockSubheaderBuilder { inner }
   }
 
+  /// `PageBlockSubheader` with the fields tdlib requires, the others are set by `PageBlockSubheader::builder()`
+  pub fn new(subheader: impl AsRef<RichText>) -> Self { Self::builder().subheader(subheader).build() }
+
   pub fn subheader(&self) -> &RichText { &self.subheader }
 
 }
@@ -2283,6 +2798,12 @@ pub struct RTDPageBlockSubheaderBuilder {
 impl RTDPageBlockSubheaderBuilder {
   pub fn build(&self) -> PageBlockSubheader { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn subheader<T: AsRef<RichText>>(&mut self, subheader: T) -> &mut Self {
     self.inner.subheader = subheader.as_ref().clone();
@@ -2299,6 +2820,12 @@ impl AsRef<PageBlockSubheader> for RTDPageBlockSubheaderBuilder {
   fn as_ref(&self) -> &PageBlockSubheader { &self.inner }
 }
 
+impl PartialEq for PageBlockSubheader {
+  fn eq(&self, other: &Self) -> bool {
+    self.subheader == other.subheader
+  }
+}
+
 
 
 
@@ -2313,15 +2840,19 @@ pub struct PageBlockSubtitle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Subtitle
   subtitle: RichText,
   
 }
 
 impl RObject for PageBlockSubtitle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockSubtitle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockSubtitle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2331,14 +2862,15 @@ impl TDPageBlock for PageBlockSubtitle {}
 
 
 impl PageBlockSubtitle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockSubtitleBuilder {
-    let mut inner = PageBlockSubtitle::default();
-    inner.td_name = "pageBlockSubtitle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockSubtitle { td_name: "pageBlockSubtitle".to_string(), ..Default::default() };
     RTDPageBlockSubtitleBuilder { inner }
   }
 
+  /// `PageBlockSubtitle` with the fields tdlib requires, the others are set by `PageBlockSubtitle::builder()`
+  pub fn new(subtitle: impl AsRef<RichText>) -> Self { Self::builder().subtitle(subtitle).build() }
+
   pub fn subtitle(&self) -> &RichText { &self.subtitle }
 
 }
@@ -2351,6 +2883,12 @@ pub struct RTDPageBlockSubtitleBuilder {
 impl RTDPageBlockSubtitleBuilder {
   pub fn build(&self) -> PageBlockSubtitle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn subtitle<T: AsRef<RichText>>(&mut self, subtitle: T) -> &mut Self {
     self.inner.subtitle = subtitle.as_ref().clone();
@@ -2367,6 +2905,12 @@ impl AsRef<PageBlockSubtitle> for RTDPageBlockSubtitleBuilder {
   fn as_ref(&self) -> &PageBlockSubtitle { &self.inner }
 }
 
+impl PartialEq for PageBlockSubtitle {
+  fn eq(&self, other: &Self) -> bool {
+    self.subtitle == other.subtitle
+  }
+}
+
 
 
 
@@ -2381,7 +2925,10 @@ pub struct PageBlockTable {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Table caption
   caption: RichText,
   /// Table cells
@@ -2394,8 +2941,9 @@ pub struct PageBlockTable {
 }
 
 impl RObject for PageBlockTable {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockTable" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockTable" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2405,14 +2953,27 @@ impl TDPageBlock for PageBlockTable {}
 
 
 impl PageBlockTable {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockTableBuilder {
-    let mut inner = PageBlockTable::default();
-    inner.td_name = "pageBlockTable".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockTable { td_name: "pageBlockTable".to_string(), ..Default::default() };
     RTDPageBlockTableBuilder { inner }
   }
 
+  /// `PageBlockTable` with the fields tdlib requires, the others are set by `PageBlockTable::builder()`
+  pub fn new(
+    caption: impl AsRef<RichText>,
+    cells: Vec<Vec<PageBlockTableCell>>,
+    is_bordered: bool,
+    is_striped: bool,
+  ) -> Self {
+    Self::builder()
+      .caption(caption)
+      .cells(cells)
+      .is_bordered(is_bordered)
+      .is_striped(is_striped)
+      .build()
+  }
+
   pub fn caption(&self) -> &RichText { &self.caption }
 
   pub fn cells(&self) -> &Vec<Vec<PageBlockTableCell>> { &self.cells }
@@ -2431,6 +2992,12 @@ pub struct RTDPageBlockTableBuilder {
 impl RTDPageBlockTableBuilder {
   pub fn build(&self) -> PageBlockTable { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn caption<T: AsRef<RichText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
@@ -2465,6 +3032,15 @@ impl AsRef<PageBlockTable> for RTDPageBlockTableBuilder {
   fn as_ref(&self) -> &PageBlockTable { &self.inner }
 }
 
+impl PartialEq for PageBlockTable {
+  fn eq(&self, other: &Self) -> bool {
+    self.caption == other.caption
+      && self.cells == other.cells
+      && self.is_bordered == other.is_bordered
+      && self.is_striped == other.is_striped
+  }
+}
+
 
 
 
@@ -2479,15 +3055,19 @@ pub struct PageBlockTitle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Title
   title: RichText,
   
 }
 
 impl RObject for PageBlockTitle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockTitle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockTitle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2497,14 +3077,15 @@ impl TDPageBlock for PageBlockTitle {}
 
 
 impl PageBlockTitle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockTitleBuilder {
-    let mut inner = PageBlockTitle::default();
-    inner.td_name = "pageBlockTitle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockTitle { td_name: "pageBlockTitle".to_string(), ..Default::default() };
     RTDPageBlockTitleBuilder { inner }
   }
 
+  /// `PageBlockTitle` with the fields tdlib requires, the others are set by `PageBlockTitle::builder()`
+  pub fn new(title: impl AsRef<RichText>) -> Self { Self::builder().title(title).build() }
+
   pub fn title(&self) -> &RichText { &self.title }
 
 }
@@ -2517,6 +3098,12 @@ pub struct RTDPageBlockTitleBuilder {
 impl RTDPageBlockTitleBuilder {
   pub fn build(&self) -> PageBlockTitle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<RichText>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().clone();
@@ -2533,6 +3120,12 @@ impl AsRef<PageBlockTitle> for RTDPageBlockTitleBuilder {
   fn as_ref(&self) -> &PageBlockTitle { &self.inner }
 }
 
+impl PartialEq for PageBlockTitle {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+  }
+}
+
 
 
 
@@ -2547,7 +3140,10 @@ pub struct PageBlockVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Video file; may be null
   video: Option<Video>,
   /// Video caption
@@ -2560,8 +3156,9 @@ pub struct PageBlockVideo {
 }
 
 impl RObject for PageBlockVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2571,14 +3168,15 @@ impl TDPageBlock for PageBlockVideo {}
 
 
 impl PageBlockVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockVideoBuilder {
-    let mut inner = PageBlockVideo::default();
-    inner.td_name = "pageBlockVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockVideo { td_name: "pageBlockVideo".to_string(), ..Default::default() };
     RTDPageBlockVideoBuilder { inner }
   }
 
+  /// `PageBlockVideo` with the fields tdlib requires, the others are set by `PageBlockVideo::builder()`
+  pub fn new(caption: impl AsRef<PageBlockCaption>, need_autoplay: bool, is_looped: bool) -> Self { Self::builder().caption(caption).need_autoplay(need_autoplay).is_looped(is_looped).build() }
+
   pub fn video(&self) -> &Option<Video> { &self.video }
 
   pub fn caption(&self) -> &PageBlockCaption { &self.caption }
@@ -2597,6 +3195,12 @@ pub struct RTDPageBlockVideoBuilder {
 impl RTDPageBlockVideoBuilder {
   pub fn build(&self) -> PageBlockVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = Some(video.as_ref().clone());
@@ -2631,6 +3235,15 @@ impl AsRef<PageBlockVideo> for RTDPageBlockVideoBuilder {
   fn as_ref(&self) -> &PageBlockVideo { &self.inner }
 }
 
+impl PartialEq for PageBlockVideo {
+  fn eq(&self, other: &Self) -> bool {
+    self.video == other.video
+      && self.caption == other.caption
+      && self.need_autoplay == other.need_autoplay
+      && self.is_looped == other.is_looped
+  }
+}
+
 
 
 
@@ -2645,7 +3258,10 @@ pub struct PageBlockVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Voice note; may be null
   voice_note: Option<VoiceNote>,
   /// Voice note caption
@@ -2654,8 +3270,9 @@ pub struct PageBlockVoiceNote {
 }
 
 impl RObject for PageBlockVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2665,14 +3282,15 @@ impl TDPageBlock for PageBlockVoiceNote {}
 
 
 impl PageBlockVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockVoiceNoteBuilder {
-    let mut inner = PageBlockVoiceNote::default();
-    inner.td_name = "pageBlockVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockVoiceNote { td_name: "pageBlockVoiceNote".to_string(), ..Default::default() };
     RTDPageBlockVoiceNoteBuilder { inner }
   }
 
+  /// `PageBlockVoiceNote` with the fields tdlib requires, the others are set by `PageBlockVoiceNote::builder()`
+  pub fn new(caption: impl AsRef<PageBlockCaption>) -> Self { Self::builder().caption(caption).build() }
+
   pub fn voice_note(&self) -> &Option<VoiceNote> { &self.voice_note }
 
   pub fn caption(&self) -> &PageBlockCaption { &self.caption }
@@ -2687,6 +3305,12 @@ pub struct RTDPageBlockVoiceNoteBuilder {
 impl RTDPageBlockVoiceNoteBuilder {
   pub fn build(&self) -> PageBlockVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -2709,5 +3333,12 @@ impl AsRef<PageBlockVoiceNote> for RTDPageBlockVoiceNoteBuilder {
   fn as_ref(&self) -> &PageBlockVoiceNote { &self.inner }
 }
 
+impl PartialEq for PageBlockVoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.voice_note == other.voice_note
+      && self.caption == other.caption
+  }
+}
+
 
 
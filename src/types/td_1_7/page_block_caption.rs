@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PageBlockCaption {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Content of the caption
   text: RichText,
   /// Block credit (like HTML tag <cite>)
@@ -23,22 +25,24 @@ pub struct PageBlockCaption {
 }
 
 impl RObject for PageBlockCaption {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockCaption" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockCaption" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PageBlockCaption {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockCaptionBuilder {
-    let mut inner = PageBlockCaption::default();
-    inner.td_name = "pageBlockCaption".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockCaption { td_name: "pageBlockCaption".to_string(), ..Default::default() };
     RTDPageBlockCaptionBuilder { inner }
   }
 
+  /// `PageBlockCaption` with the fields tdlib requires, the others are set by `PageBlockCaption::builder()`
+  pub fn new(text: impl AsRef<RichText>, credit: impl AsRef<RichText>) -> Self { Self::builder().text(text).credit(credit).build() }
+
   pub fn text(&self) -> &RichText { &self.text }
 
   pub fn credit(&self) -> &RichText { &self.credit }
@@ -53,6 +57,12 @@ pub struct RTDPageBlockCaptionBuilder {
 impl RTDPageBlockCaptionBuilder {
   pub fn build(&self) -> PageBlockCaption { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -75,5 +85,12 @@ impl AsRef<PageBlockCaption> for RTDPageBlockCaptionBuilder {
   fn as_ref(&self) -> &PageBlockCaption { &self.inner }
 }
 
+impl PartialEq for PageBlockCaption {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.credit == other.credit
+  }
+}
+
 
 
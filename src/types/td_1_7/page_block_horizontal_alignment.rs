@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPageBlockHorizontalAlignment: Debug + RObject {}
 
 /// Describes a horizontal alignment of a table cell content
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PageBlockHorizontalAlignment {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum PageBlockHorizontalAlignment {
   Left(PageBlockHorizontalAlignmentLeft),
   /// The content must be right-aligned
   Right(PageBlockHorizontalAlignmentRight),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for PageBlockHorizontalAlignment {
   fn deserialize<D>(deserializer: D) -> Result<PageBlockHorizontalAlignment, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PageBlockHorizontalAlignment,
+      PageBlockHorizontalAlignment, Unknown;
       (pageBlockHorizontalAlignmentCenter, Center);
       (pageBlockHorizontalAlignmentLeft, Left);
       (pageBlockHorizontalAlignmentRight, Right);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for PageBlockHorizontalAlignment {
 }
 
 impl RObject for PageBlockHorizontalAlignment {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PageBlockHorizontalAlignment::Center(t) => t.td_name(),
       PageBlockHorizontalAlignment::Left(t) => t.td_name(),
       PageBlockHorizontalAlignment::Right(t) => t.td_name(),
+      PageBlockHorizontalAlignment::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PageBlockHorizontalAlignment::Center(t) => t.extra(),
       PageBlockHorizontalAlignment::Left(t) => t.extra(),
       PageBlockHorizontalAlignment::Right(t) => t.extra(),
+      PageBlockHorizontalAlignment::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PageBlockHorizontalAlignment::Center(t) => t.client_id(),
+      PageBlockHorizontalAlignment::Left(t) => t.client_id(),
+      PageBlockHorizontalAlignment::Right(t) => t.client_id(),
+      PageBlockHorizontalAlignment::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for PageBlockHorizontalAlignment {
 }
 
 impl PageBlockHorizontalAlignment {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlockHorizontalAlignment::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PageBlockHorizontalAlignment::_Default(_)) }
 
-  pub fn is_center(&self) -> bool { if let PageBlockHorizontalAlignment::Center(_) = self { true } else { false } }
-  pub fn is_left(&self) -> bool { if let PageBlockHorizontalAlignment::Left(_) = self { true } else { false } }
-  pub fn is_right(&self) -> bool { if let PageBlockHorizontalAlignment::Right(_) = self { true } else { false } }
+  pub fn is_center(&self) -> bool { matches!(self, PageBlockHorizontalAlignment::Center(_)) }
+  pub fn is_left(&self) -> bool { matches!(self, PageBlockHorizontalAlignment::Left(_)) }
+  pub fn is_right(&self) -> bool { matches!(self, PageBlockHorizontalAlignment::Right(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PageBlockHorizontalAlignment::Unknown(_)) }
 
   pub fn on_center<F: FnOnce(&PageBlockHorizontalAlignmentCenter)>(&self, fnc: F) -> &Self { if let PageBlockHorizontalAlignment::Center(t) = self { fnc(t) }; self }
   pub fn on_left<F: FnOnce(&PageBlockHorizontalAlignmentLeft)>(&self, fnc: F) -> &Self { if let PageBlockHorizontalAlignment::Left(t) = self { fnc(t) }; self }
   pub fn on_right<F: FnOnce(&PageBlockHorizontalAlignmentRight)>(&self, fnc: F) -> &Self { if let PageBlockHorizontalAlignment::Right(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PageBlockHorizontalAlignment::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_center(&self) -> Option<&PageBlockHorizontalAlignmentCenter> { if let PageBlockHorizontalAlignment::Center(t) = self { return Some(t) } None }
   pub fn as_left(&self) -> Option<&PageBlockHorizontalAlignmentLeft> { if let PageBlockHorizontalAlignment::Left(t) = self { return Some(t) } None }
   pub fn as_right(&self) -> Option<&PageBlockHorizontalAlignmentRight> { if let PageBlockHorizontalAlignment::Right(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PageBlockHorizontalAlignment::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,13 +127,17 @@ pub struct PageBlockHorizontalAlignmentCenter {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PageBlockHorizontalAlignmentCenter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHorizontalAlignmentCenter" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockHorizontalAlignmentCenter" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -127,14 +147,15 @@ impl TDPageBlockHorizontalAlignment for PageBlockHorizontalAlignmentCenter {}
 
 
 impl PageBlockHorizontalAlignmentCenter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockHorizontalAlignmentCenterBuilder {
-    let mut inner = PageBlockHorizontalAlignmentCenter::default();
-    inner.td_name = "pageBlockHorizontalAlignmentCenter".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockHorizontalAlignmentCenter { td_name: "pageBlockHorizontalAlignmentCenter".to_string(), ..Default::default() };
     RTDPageBlockHorizontalAlignmentCenterBuilder { inner }
   }
 
+  /// `PageBlockHorizontalAlignmentCenter` with the fields tdlib requires, the others are set by `PageBlockHorizontalAlignmentCenter::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +166,12 @@ pub struct RTDPageBlockHorizontalAlignmentCenterBuilder {
 impl RTDPageBlockHorizontalAlignmentCenterBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentCenter { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentCenter> for PageBlockHorizontalAlignmentCenter {
@@ -155,6 +182,10 @@ impl AsRef<PageBlockHorizontalAlignmentCenter> for RTDPageBlockHorizontalAlignme
   fn as_ref(&self) -> &PageBlockHorizontalAlignmentCenter { &self.inner }
 }
 
+impl PartialEq for PageBlockHorizontalAlignmentCenter {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -169,13 +200,17 @@ pub struct PageBlockHorizontalAlignmentLeft {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PageBlockHorizontalAlignmentLeft {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHorizontalAlignmentLeft" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockHorizontalAlignmentLeft" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -185,14 +220,15 @@ impl TDPageBlockHorizontalAlignment for PageBlockHorizontalAlignmentLeft {}
 
 
 impl PageBlockHorizontalAlignmentLeft {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockHorizontalAlignmentLeftBuilder {
-    let mut inner = PageBlockHorizontalAlignmentLeft::default();
-    inner.td_name = "pageBlockHorizontalAlignmentLeft".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockHorizontalAlignmentLeft { td_name: "pageBlockHorizontalAlignmentLeft".to_string(), ..Default::default() };
     RTDPageBlockHorizontalAlignmentLeftBuilder { inner }
   }
 
+  /// `PageBlockHorizontalAlignmentLeft` with the fields tdlib requires, the others are set by `PageBlockHorizontalAlignmentLeft::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,12 @@ pub struct RTDPageBlockHorizontalAlignmentLeftBuilder {
 impl RTDPageBlockHorizontalAlignmentLeftBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentLeft { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentLeft> for PageBlockHorizontalAlignmentLeft {
@@ -213,6 +255,10 @@ impl AsRef<PageBlockHorizontalAlignmentLeft> for RTDPageBlockHorizontalAlignment
   fn as_ref(&self) -> &PageBlockHorizontalAlignmentLeft { &self.inner }
 }
 
+impl PartialEq for PageBlockHorizontalAlignmentLeft {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -227,13 +273,17 @@ pub struct PageBlockHorizontalAlignmentRight {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PageBlockHorizontalAlignmentRight {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockHorizontalAlignmentRight" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockHorizontalAlignmentRight" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -243,14 +293,15 @@ impl TDPageBlockHorizontalAlignment for PageBlockHorizontalAlignmentRight {}
 
 
 impl PageBlockHorizontalAlignmentRight {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockHorizontalAlignmentRightBuilder {
-    let mut inner = PageBlockHorizontalAlignmentRight::default();
-    inner.td_name = "pageBlockHorizontalAlignmentRight".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockHorizontalAlignmentRight { td_name: "pageBlockHorizontalAlignmentRight".to_string(), ..Default::default() };
     RTDPageBlockHorizontalAlignmentRightBuilder { inner }
   }
 
+  /// `PageBlockHorizontalAlignmentRight` with the fields tdlib requires, the others are set by `PageBlockHorizontalAlignmentRight::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +312,12 @@ pub struct RTDPageBlockHorizontalAlignmentRightBuilder {
 impl RTDPageBlockHorizontalAlignmentRightBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentRight { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentRight> for PageBlockHorizontalAlignmentRight {
@@ -271,5 +328,9 @@ impl AsRef<PageBlockHorizontalAlignmentRight> for RTDPageBlockHorizontalAlignmen
   fn as_ref(&self) -> &PageBlockHorizontalAlignmentRight { &self.inner }
 }
 
+impl PartialEq for PageBlockHorizontalAlignmentRight {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PageBlockListItem {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Item label
   label: String,
   /// Item blocks
@@ -23,22 +25,24 @@ pub struct PageBlockListItem {
 }
 
 impl RObject for PageBlockListItem {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockListItem" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockListItem" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PageBlockListItem {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockListItemBuilder {
-    let mut inner = PageBlockListItem::default();
-    inner.td_name = "pageBlockListItem".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockListItem { td_name: "pageBlockListItem".to_string(), ..Default::default() };
     RTDPageBlockListItemBuilder { inner }
   }
 
+  /// `PageBlockListItem` with the fields tdlib requires, the others are set by `PageBlockListItem::builder()`
+  pub fn new(label: impl AsRef<str>, page_blocks: Vec<PageBlock>) -> Self { Self::builder().label(label).page_blocks(page_blocks).build() }
+
   pub fn label(&self) -> &String { &self.label }
 
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
@@ -53,6 +57,12 @@ pub struct RTDPageBlockListItemBuilder {
 impl RTDPageBlockListItemBuilder {
   pub fn build(&self) -> PageBlockListItem { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn label<T: AsRef<str>>(&mut self, label: T) -> &mut Self {
     self.inner.label = label.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<PageBlockListItem> for RTDPageBlockListItemBuilder {
   fn as_ref(&self) -> &PageBlockListItem { &self.inner }
 }
 
+impl PartialEq for PageBlockListItem {
+  fn eq(&self, other: &Self) -> bool {
+    self.label == other.label
+      && self.page_blocks == other.page_blocks
+  }
+}
+
 
 
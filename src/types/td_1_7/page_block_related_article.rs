@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,12 +13,15 @@ pub struct PageBlockRelatedArticle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Related article URL
   url: String,
   /// Article title; may be empty
   title: String,
-  /// Contains information about a related article
+  /// Article description; may be empty
   description: String,
   /// Article photo; may be null
   photo: Option<Photo>,
@@ -31,22 +33,38 @@ pub struct PageBlockRelatedArticle {
 }
 
 impl RObject for PageBlockRelatedArticle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockRelatedArticle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockRelatedArticle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PageBlockRelatedArticle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockRelatedArticleBuilder {
-    let mut inner = PageBlockRelatedArticle::default();
-    inner.td_name = "pageBlockRelatedArticle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockRelatedArticle { td_name: "pageBlockRelatedArticle".to_string(), ..Default::default() };
     RTDPageBlockRelatedArticleBuilder { inner }
   }
 
+  /// `PageBlockRelatedArticle` with the fields tdlib requires, the others are set by `PageBlockRelatedArticle::builder()`
+  pub fn new(
+    url: impl AsRef<str>,
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    author: impl AsRef<str>,
+    publish_date: i64,
+  ) -> Self {
+    Self::builder()
+      .url(url)
+      .title(title)
+      .description(description)
+      .author(author)
+      .publish_date(publish_date)
+      .build()
+  }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn title(&self) -> &String { &self.title }
@@ -69,6 +87,12 @@ pub struct RTDPageBlockRelatedArticleBuilder {
 impl RTDPageBlockRelatedArticleBuilder {
   pub fn build(&self) -> PageBlockRelatedArticle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -115,5 +139,16 @@ impl AsRef<PageBlockRelatedArticle> for RTDPageBlockRelatedArticleBuilder {
   fn as_ref(&self) -> &PageBlockRelatedArticle { &self.inner }
 }
 
+impl PartialEq for PageBlockRelatedArticle {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.title == other.title
+      && self.description == other.description
+      && self.photo == other.photo
+      && self.author == other.author
+      && self.publish_date == other.publish_date
+  }
+}
+
 
 
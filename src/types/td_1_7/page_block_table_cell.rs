@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PageBlockTableCell {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Cell text; may be null. If the text is null, then the cell must be invisible
   text: Option<RichText>,
   /// True, if it is a header cell
@@ -31,22 +33,38 @@ pub struct PageBlockTableCell {
 }
 
 impl RObject for PageBlockTableCell {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockTableCell" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockTableCell" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PageBlockTableCell {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockTableCellBuilder {
-    let mut inner = PageBlockTableCell::default();
-    inner.td_name = "pageBlockTableCell".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockTableCell { td_name: "pageBlockTableCell".to_string(), ..Default::default() };
     RTDPageBlockTableCellBuilder { inner }
   }
 
+  /// `PageBlockTableCell` with the fields tdlib requires, the others are set by `PageBlockTableCell::builder()`
+  pub fn new(
+    is_header: bool,
+    colspan: i64,
+    rowspan: i64,
+    align: impl AsRef<PageBlockHorizontalAlignment>,
+    valign: impl AsRef<PageBlockVerticalAlignment>,
+  ) -> Self {
+    Self::builder()
+      .is_header(is_header)
+      .colspan(colspan)
+      .rowspan(rowspan)
+      .align(align)
+      .valign(valign)
+      .build()
+  }
+
   pub fn text(&self) -> &Option<RichText> { &self.text }
 
   pub fn is_header(&self) -> bool { self.is_header }
@@ -69,6 +87,12 @@ pub struct RTDPageBlockTableCellBuilder {
 impl RTDPageBlockTableCellBuilder {
   pub fn build(&self) -> PageBlockTableCell { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = Some(text.as_ref().clone());
@@ -115,5 +139,16 @@ impl AsRef<PageBlockTableCell> for RTDPageBlockTableCellBuilder {
   fn as_ref(&self) -> &PageBlockTableCell { &self.inner }
 }
 
+impl PartialEq for PageBlockTableCell {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.is_header == other.is_header
+      && self.colspan == other.colspan
+      && self.rowspan == other.rowspan
+      && self.align == other.align
+      && self.valign == other.valign
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPageBlockVerticalAlignment: Debug + RObject {}
 
 /// Describes a Vertical alignment of a table cell content
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PageBlockVerticalAlignment {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum PageBlockVerticalAlignment {
   Middle(PageBlockVerticalAlignmentMiddle),
   /// The content must be top-aligned
   Top(PageBlockVerticalAlignmentTop),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for PageBlockVerticalAlignment {
   fn deserialize<D>(deserializer: D) -> Result<PageBlockVerticalAlignment, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PageBlockVerticalAlignment,
+      PageBlockVerticalAlignment, Unknown;
       (pageBlockVerticalAlignmentBottom, Bottom);
       (pageBlockVerticalAlignmentMiddle, Middle);
       (pageBlockVerticalAlignmentTop, Top);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for PageBlockVerticalAlignment {
 }
 
 impl RObject for PageBlockVerticalAlignment {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PageBlockVerticalAlignment::Bottom(t) => t.td_name(),
       PageBlockVerticalAlignment::Middle(t) => t.td_name(),
       PageBlockVerticalAlignment::Top(t) => t.td_name(),
+      PageBlockVerticalAlignment::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PageBlockVerticalAlignment::Bottom(t) => t.extra(),
       PageBlockVerticalAlignment::Middle(t) => t.extra(),
       PageBlockVerticalAlignment::Top(t) => t.extra(),
+      PageBlockVerticalAlignment::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PageBlockVerticalAlignment::Bottom(t) => t.client_id(),
+      PageBlockVerticalAlignment::Middle(t) => t.client_id(),
+      PageBlockVerticalAlignment::Top(t) => t.client_id(),
+      PageBlockVerticalAlignment::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for PageBlockVerticalAlignment {
 }
 
 impl PageBlockVerticalAlignment {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlockVerticalAlignment::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PageBlockVerticalAlignment::_Default(_)) }
 
-  pub fn is_bottom(&self) -> bool { if let PageBlockVerticalAlignment::Bottom(_) = self { true } else { false } }
-  pub fn is_middle(&self) -> bool { if let PageBlockVerticalAlignment::Middle(_) = self { true } else { false } }
-  pub fn is_top(&self) -> bool { if let PageBlockVerticalAlignment::Top(_) = self { true } else { false } }
+  pub fn is_bottom(&self) -> bool { matches!(self, PageBlockVerticalAlignment::Bottom(_)) }
+  pub fn is_middle(&self) -> bool { matches!(self, PageBlockVerticalAlignment::Middle(_)) }
+  pub fn is_top(&self) -> bool { matches!(self, PageBlockVerticalAlignment::Top(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PageBlockVerticalAlignment::Unknown(_)) }
 
   pub fn on_bottom<F: FnOnce(&PageBlockVerticalAlignmentBottom)>(&self, fnc: F) -> &Self { if let PageBlockVerticalAlignment::Bottom(t) = self { fnc(t) }; self }
   pub fn on_middle<F: FnOnce(&PageBlockVerticalAlignmentMiddle)>(&self, fnc: F) -> &Self { if let PageBlockVerticalAlignment::Middle(t) = self { fnc(t) }; self }
   pub fn on_top<F: FnOnce(&PageBlockVerticalAlignmentTop)>(&self, fnc: F) -> &Self { if let PageBlockVerticalAlignment::Top(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PageBlockVerticalAlignment::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_bottom(&self) -> Option<&PageBlockVerticalAlignmentBottom> { if let PageBlockVerticalAlignment::Bottom(t) = self { return Some(t) } None }
   pub fn as_middle(&self) -> Option<&PageBlockVerticalAlignmentMiddle> { if let PageBlockVerticalAlignment::Middle(t) = self { return Some(t) } None }
   pub fn as_top(&self) -> Option<&PageBlockVerticalAlignmentTop> { if let PageBlockVerticalAlignment::Top(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PageBlockVerticalAlignment::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,13 +127,17 @@ pub struct PageBlockVerticalAlignmentBottom {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PageBlockVerticalAlignmentBottom {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVerticalAlignmentBottom" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockVerticalAlignmentBottom" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -127,14 +147,15 @@ impl TDPageBlockVerticalAlignment for PageBlockVerticalAlignmentBottom {}
 
 
 impl PageBlockVerticalAlignmentBottom {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockVerticalAlignmentBottomBuilder {
-    let mut inner = PageBlockVerticalAlignmentBottom::default();
-    inner.td_name = "pageBlockVerticalAlignmentBottom".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockVerticalAlignmentBottom { td_name: "pageBlockVerticalAlignmentBottom".to_string(), ..Default::default() };
     RTDPageBlockVerticalAlignmentBottomBuilder { inner }
   }
 
+  /// `PageBlockVerticalAlignmentBottom` with the fields tdlib requires, the others are set by `PageBlockVerticalAlignmentBottom::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +166,12 @@ pub struct RTDPageBlockVerticalAlignmentBottomBuilder {
 impl RTDPageBlockVerticalAlignmentBottomBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentBottom { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentBottom> for PageBlockVerticalAlignmentBottom {
@@ -155,6 +182,10 @@ impl AsRef<PageBlockVerticalAlignmentBottom> for RTDPageBlockVerticalAlignmentBo
   fn as_ref(&self) -> &PageBlockVerticalAlignmentBottom { &self.inner }
 }
 
+impl PartialEq for PageBlockVerticalAlignmentBottom {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -169,13 +200,17 @@ pub struct PageBlockVerticalAlignmentMiddle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PageBlockVerticalAlignmentMiddle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVerticalAlignmentMiddle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockVerticalAlignmentMiddle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -185,14 +220,15 @@ impl TDPageBlockVerticalAlignment for PageBlockVerticalAlignmentMiddle {}
 
 
 impl PageBlockVerticalAlignmentMiddle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockVerticalAlignmentMiddleBuilder {
-    let mut inner = PageBlockVerticalAlignmentMiddle::default();
-    inner.td_name = "pageBlockVerticalAlignmentMiddle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockVerticalAlignmentMiddle { td_name: "pageBlockVerticalAlignmentMiddle".to_string(), ..Default::default() };
     RTDPageBlockVerticalAlignmentMiddleBuilder { inner }
   }
 
+  /// `PageBlockVerticalAlignmentMiddle` with the fields tdlib requires, the others are set by `PageBlockVerticalAlignmentMiddle::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,12 @@ pub struct RTDPageBlockVerticalAlignmentMiddleBuilder {
 impl RTDPageBlockVerticalAlignmentMiddleBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentMiddle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentMiddle> for PageBlockVerticalAlignmentMiddle {
@@ -213,6 +255,10 @@ impl AsRef<PageBlockVerticalAlignmentMiddle> for RTDPageBlockVerticalAlignmentMi
   fn as_ref(&self) -> &PageBlockVerticalAlignmentMiddle { &self.inner }
 }
 
+impl PartialEq for PageBlockVerticalAlignmentMiddle {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -227,13 +273,17 @@ pub struct PageBlockVerticalAlignmentTop {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PageBlockVerticalAlignmentTop {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pageBlockVerticalAlignmentTop" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pageBlockVerticalAlignmentTop" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -243,14 +293,15 @@ impl TDPageBlockVerticalAlignment for PageBlockVerticalAlignmentTop {}
 
 
 impl PageBlockVerticalAlignmentTop {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPageBlockVerticalAlignmentTopBuilder {
-    let mut inner = PageBlockVerticalAlignmentTop::default();
-    inner.td_name = "pageBlockVerticalAlignmentTop".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PageBlockVerticalAlignmentTop { td_name: "pageBlockVerticalAlignmentTop".to_string(), ..Default::default() };
     RTDPageBlockVerticalAlignmentTopBuilder { inner }
   }
 
+  /// `PageBlockVerticalAlignmentTop` with the fields tdlib requires, the others are set by `PageBlockVerticalAlignmentTop::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +312,12 @@ pub struct RTDPageBlockVerticalAlignmentTopBuilder {
 impl RTDPageBlockVerticalAlignmentTopBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentTop { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentTop> for PageBlockVerticalAlignmentTop {
@@ -271,5 +328,9 @@ impl AsRef<PageBlockVerticalAlignmentTop> for RTDPageBlockVerticalAlignmentTopBu
   fn as_ref(&self) -> &PageBlockVerticalAlignmentTop { &self.inner }
 }
 
+impl PartialEq for PageBlockVerticalAlignmentTop {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PassportAuthorizationForm {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the authorization form
   id: i64,
   /// Telegram Passport elements that must be provided to complete the form
@@ -25,22 +27,24 @@ pub struct PassportAuthorizationForm {
 }
 
 impl RObject for PassportAuthorizationForm {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportAuthorizationForm" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportAuthorizationForm" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PassportAuthorizationForm {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportAuthorizationFormBuilder {
-    let mut inner = PassportAuthorizationForm::default();
-    inner.td_name = "passportAuthorizationForm".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportAuthorizationForm { td_name: "passportAuthorizationForm".to_string(), ..Default::default() };
     RTDPassportAuthorizationFormBuilder { inner }
   }
 
+  /// `PassportAuthorizationForm` with the fields tdlib requires, the others are set by `PassportAuthorizationForm::builder()`
+  pub fn new(id: i64, required_elements: Vec<PassportRequiredElement>, privacy_policy_url: impl AsRef<str>) -> Self { Self::builder().id(id).required_elements(required_elements).privacy_policy_url(privacy_policy_url).build() }
+
   pub fn id(&self) -> i64 { self.id }
 
   pub fn required_elements(&self) -> &Vec<PassportRequiredElement> { &self.required_elements }
@@ -57,6 +61,12 @@ pub struct RTDPassportAuthorizationFormBuilder {
 impl RTDPassportAuthorizationFormBuilder {
   pub fn build(&self) -> PassportAuthorizationForm { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -85,5 +95,13 @@ impl AsRef<PassportAuthorizationForm> for RTDPassportAuthorizationFormBuilder {
   fn as_ref(&self) -> &PassportAuthorizationForm { &self.inner }
 }
 
+impl PartialEq for PassportAuthorizationForm {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.required_elements == other.required_elements
+      && self.privacy_policy_url == other.privacy_policy_url
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPassportElement: Debug + RObject {}
 
 /// Contains information about a Telegram Passport element
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PassportElement {
   #[doc(hidden)] _Default(()),
@@ -49,6 +48,8 @@ pub enum PassportElement {
   UtilityBill(PassportElementUtilityBill),
   /// Adds an element to the user's Telegram Passport. May return an error with a message "PHONE_VERIFICATION_NEEDED" or "EMAIL_VERIFICATION_NEEDED" if the chosen phone number or the chosen email address must be verified first
   SetPassportElement(SetPassportElement),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -60,7 +61,7 @@ impl<'de> Deserialize<'de> for PassportElement {
   fn deserialize<D>(deserializer: D) -> Result<PassportElement, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PassportElement,
+      PassportElement, Unknown;
       (getPassportElement, GetPassportElement);
       (passportElementAddress, Address);
       (passportElementBankStatement, BankStatement);
@@ -82,7 +83,7 @@ impl<'de> Deserialize<'de> for PassportElement {
 }
 
 impl RObject for PassportElement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PassportElement::GetPassportElement(t) => t.td_name(),
       PassportElement::Address(t) => t.td_name(),
@@ -99,11 +100,12 @@ impl RObject for PassportElement {
       PassportElement::TemporaryRegistration(t) => t.td_name(),
       PassportElement::UtilityBill(t) => t.td_name(),
       PassportElement::SetPassportElement(t) => t.td_name(),
+      PassportElement::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PassportElement::GetPassportElement(t) => t.extra(),
       PassportElement::Address(t) => t.extra(),
@@ -120,6 +122,29 @@ impl RObject for PassportElement {
       PassportElement::TemporaryRegistration(t) => t.extra(),
       PassportElement::UtilityBill(t) => t.extra(),
       PassportElement::SetPassportElement(t) => t.extra(),
+      PassportElement::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PassportElement::GetPassportElement(t) => t.client_id(),
+      PassportElement::Address(t) => t.client_id(),
+      PassportElement::BankStatement(t) => t.client_id(),
+      PassportElement::DriverLicense(t) => t.client_id(),
+      PassportElement::EmailAddress(t) => t.client_id(),
+      PassportElement::IdentityCard(t) => t.client_id(),
+      PassportElement::InternalPassport(t) => t.client_id(),
+      PassportElement::Passport(t) => t.client_id(),
+      PassportElement::PassportRegistration(t) => t.client_id(),
+      PassportElement::PersonalDetails(t) => t.client_id(),
+      PassportElement::PhoneNumber(t) => t.client_id(),
+      PassportElement::RentalAgreement(t) => t.client_id(),
+      PassportElement::TemporaryRegistration(t) => t.client_id(),
+      PassportElement::UtilityBill(t) => t.client_id(),
+      PassportElement::SetPassportElement(t) => t.client_id(),
+      PassportElement::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -128,24 +153,25 @@ impl RObject for PassportElement {
 }
 
 impl PassportElement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElement::_Default(_) = self { true } else { false } }
-
-  pub fn is_get_passport_element(&self) -> bool { if let PassportElement::GetPassportElement(_) = self { true } else { false } }
-  pub fn is_address(&self) -> bool { if let PassportElement::Address(_) = self { true } else { false } }
-  pub fn is_bank_statement(&self) -> bool { if let PassportElement::BankStatement(_) = self { true } else { false } }
-  pub fn is_driver_license(&self) -> bool { if let PassportElement::DriverLicense(_) = self { true } else { false } }
-  pub fn is_email_address(&self) -> bool { if let PassportElement::EmailAddress(_) = self { true } else { false } }
-  pub fn is_identity_card(&self) -> bool { if let PassportElement::IdentityCard(_) = self { true } else { false } }
-  pub fn is_internal_passport(&self) -> bool { if let PassportElement::InternalPassport(_) = self { true } else { false } }
-  pub fn is_passport(&self) -> bool { if let PassportElement::Passport(_) = self { true } else { false } }
-  pub fn is_passport_registration(&self) -> bool { if let PassportElement::PassportRegistration(_) = self { true } else { false } }
-  pub fn is_personal_details(&self) -> bool { if let PassportElement::PersonalDetails(_) = self { true } else { false } }
-  pub fn is_phone_number(&self) -> bool { if let PassportElement::PhoneNumber(_) = self { true } else { false } }
-  pub fn is_rental_agreement(&self) -> bool { if let PassportElement::RentalAgreement(_) = self { true } else { false } }
-  pub fn is_temporary_registration(&self) -> bool { if let PassportElement::TemporaryRegistration(_) = self { true } else { false } }
-  pub fn is_utility_bill(&self) -> bool { if let PassportElement::UtilityBill(_) = self { true } else { false } }
-  pub fn is_set_passport_element(&self) -> bool { if let PassportElement::SetPassportElement(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PassportElement::_Default(_)) }
+
+  pub fn is_get_passport_element(&self) -> bool { matches!(self, PassportElement::GetPassportElement(_)) }
+  pub fn is_address(&self) -> bool { matches!(self, PassportElement::Address(_)) }
+  pub fn is_bank_statement(&self) -> bool { matches!(self, PassportElement::BankStatement(_)) }
+  pub fn is_driver_license(&self) -> bool { matches!(self, PassportElement::DriverLicense(_)) }
+  pub fn is_email_address(&self) -> bool { matches!(self, PassportElement::EmailAddress(_)) }
+  pub fn is_identity_card(&self) -> bool { matches!(self, PassportElement::IdentityCard(_)) }
+  pub fn is_internal_passport(&self) -> bool { matches!(self, PassportElement::InternalPassport(_)) }
+  pub fn is_passport(&self) -> bool { matches!(self, PassportElement::Passport(_)) }
+  pub fn is_passport_registration(&self) -> bool { matches!(self, PassportElement::PassportRegistration(_)) }
+  pub fn is_personal_details(&self) -> bool { matches!(self, PassportElement::PersonalDetails(_)) }
+  pub fn is_phone_number(&self) -> bool { matches!(self, PassportElement::PhoneNumber(_)) }
+  pub fn is_rental_agreement(&self) -> bool { matches!(self, PassportElement::RentalAgreement(_)) }
+  pub fn is_temporary_registration(&self) -> bool { matches!(self, PassportElement::TemporaryRegistration(_)) }
+  pub fn is_utility_bill(&self) -> bool { matches!(self, PassportElement::UtilityBill(_)) }
+  pub fn is_set_passport_element(&self) -> bool { matches!(self, PassportElement::SetPassportElement(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PassportElement::Unknown(_)) }
 
   pub fn on_get_passport_element<F: FnOnce(&GetPassportElement)>(&self, fnc: F) -> &Self { if let PassportElement::GetPassportElement(t) = self { fnc(t) }; self }
   pub fn on_address<F: FnOnce(&PassportElementAddress)>(&self, fnc: F) -> &Self { if let PassportElement::Address(t) = self { fnc(t) }; self }
@@ -162,6 +188,7 @@ impl PassportElement {
   pub fn on_temporary_registration<F: FnOnce(&PassportElementTemporaryRegistration)>(&self, fnc: F) -> &Self { if let PassportElement::TemporaryRegistration(t) = self { fnc(t) }; self }
   pub fn on_utility_bill<F: FnOnce(&PassportElementUtilityBill)>(&self, fnc: F) -> &Self { if let PassportElement::UtilityBill(t) = self { fnc(t) }; self }
   pub fn on_set_passport_element<F: FnOnce(&SetPassportElement)>(&self, fnc: F) -> &Self { if let PassportElement::SetPassportElement(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PassportElement::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_passport_element(&self) -> Option<&GetPassportElement> { if let PassportElement::GetPassportElement(t) = self { return Some(t) } None }
   pub fn as_address(&self) -> Option<&PassportElementAddress> { if let PassportElement::Address(t) = self { return Some(t) } None }
@@ -178,6 +205,7 @@ impl PassportElement {
   pub fn as_temporary_registration(&self) -> Option<&PassportElementTemporaryRegistration> { if let PassportElement::TemporaryRegistration(t) = self { return Some(t) } None }
   pub fn as_utility_bill(&self) -> Option<&PassportElementUtilityBill> { if let PassportElement::UtilityBill(t) = self { return Some(t) } None }
   pub fn as_set_passport_element(&self) -> Option<&SetPassportElement> { if let PassportElement::SetPassportElement(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PassportElement::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -231,15 +259,19 @@ pub struct PassportElementAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Address
   address: Address,
   
 }
 
 impl RObject for PassportElementAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -249,14 +281,15 @@ impl TDPassportElement for PassportElementAddress {}
 
 
 impl PassportElementAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementAddressBuilder {
-    let mut inner = PassportElementAddress::default();
-    inner.td_name = "passportElementAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementAddress { td_name: "passportElementAddress".to_string(), ..Default::default() };
     RTDPassportElementAddressBuilder { inner }
   }
 
+  /// `PassportElementAddress` with the fields tdlib requires, the others are set by `PassportElementAddress::builder()`
+  pub fn new(address: impl AsRef<Address>) -> Self { Self::builder().address(address).build() }
+
   pub fn address(&self) -> &Address { &self.address }
 
 }
@@ -269,6 +302,12 @@ pub struct RTDPassportElementAddressBuilder {
 impl RTDPassportElementAddressBuilder {
   pub fn build(&self) -> PassportElementAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().clone();
@@ -285,6 +324,12 @@ impl AsRef<PassportElementAddress> for RTDPassportElementAddressBuilder {
   fn as_ref(&self) -> &PassportElementAddress { &self.inner }
 }
 
+impl PartialEq for PassportElementAddress {
+  fn eq(&self, other: &Self) -> bool {
+    self.address == other.address
+  }
+}
+
 
 
 
@@ -299,15 +344,19 @@ pub struct PassportElementBankStatement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Bank statement
   bank_statement: PersonalDocument,
   
 }
 
 impl RObject for PassportElementBankStatement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementBankStatement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementBankStatement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -317,14 +366,15 @@ impl TDPassportElement for PassportElementBankStatement {}
 
 
 impl PassportElementBankStatement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementBankStatementBuilder {
-    let mut inner = PassportElementBankStatement::default();
-    inner.td_name = "passportElementBankStatement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementBankStatement { td_name: "passportElementBankStatement".to_string(), ..Default::default() };
     RTDPassportElementBankStatementBuilder { inner }
   }
 
+  /// `PassportElementBankStatement` with the fields tdlib requires, the others are set by `PassportElementBankStatement::builder()`
+  pub fn new(bank_statement: impl AsRef<PersonalDocument>) -> Self { Self::builder().bank_statement(bank_statement).build() }
+
   pub fn bank_statement(&self) -> &PersonalDocument { &self.bank_statement }
 
 }
@@ -337,6 +387,12 @@ pub struct RTDPassportElementBankStatementBuilder {
 impl RTDPassportElementBankStatementBuilder {
   pub fn build(&self) -> PassportElementBankStatement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn bank_statement<T: AsRef<PersonalDocument>>(&mut self, bank_statement: T) -> &mut Self {
     self.inner.bank_statement = bank_statement.as_ref().clone();
@@ -353,6 +409,12 @@ impl AsRef<PassportElementBankStatement> for RTDPassportElementBankStatementBuil
   fn as_ref(&self) -> &PassportElementBankStatement { &self.inner }
 }
 
+impl PartialEq for PassportElementBankStatement {
+  fn eq(&self, other: &Self) -> bool {
+    self.bank_statement == other.bank_statement
+  }
+}
+
 
 
 
@@ -367,15 +429,19 @@ pub struct PassportElementDriverLicense {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Driver license
   driver_license: IdentityDocument,
   
 }
 
 impl RObject for PassportElementDriverLicense {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementDriverLicense" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementDriverLicense" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -385,14 +451,15 @@ impl TDPassportElement for PassportElementDriverLicense {}
 
 
 impl PassportElementDriverLicense {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementDriverLicenseBuilder {
-    let mut inner = PassportElementDriverLicense::default();
-    inner.td_name = "passportElementDriverLicense".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementDriverLicense { td_name: "passportElementDriverLicense".to_string(), ..Default::default() };
     RTDPassportElementDriverLicenseBuilder { inner }
   }
 
+  /// `PassportElementDriverLicense` with the fields tdlib requires, the others are set by `PassportElementDriverLicense::builder()`
+  pub fn new(driver_license: impl AsRef<IdentityDocument>) -> Self { Self::builder().driver_license(driver_license).build() }
+
   pub fn driver_license(&self) -> &IdentityDocument { &self.driver_license }
 
 }
@@ -405,6 +472,12 @@ pub struct RTDPassportElementDriverLicenseBuilder {
 impl RTDPassportElementDriverLicenseBuilder {
   pub fn build(&self) -> PassportElementDriverLicense { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn driver_license<T: AsRef<IdentityDocument>>(&mut self, driver_license: T) -> &mut Self {
     self.inner.driver_license = driver_license.as_ref().clone();
@@ -421,6 +494,12 @@ impl AsRef<PassportElementDriverLicense> for RTDPassportElementDriverLicenseBuil
   fn as_ref(&self) -> &PassportElementDriverLicense { &self.inner }
 }
 
+impl PartialEq for PassportElementDriverLicense {
+  fn eq(&self, other: &Self) -> bool {
+    self.driver_license == other.driver_license
+  }
+}
+
 
 
 
@@ -435,15 +514,19 @@ pub struct PassportElementEmailAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Email address
   email_address: String,
   
 }
 
 impl RObject for PassportElementEmailAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementEmailAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementEmailAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -453,14 +536,15 @@ impl TDPassportElement for PassportElementEmailAddress {}
 
 
 impl PassportElementEmailAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementEmailAddressBuilder {
-    let mut inner = PassportElementEmailAddress::default();
-    inner.td_name = "passportElementEmailAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementEmailAddress { td_name: "passportElementEmailAddress".to_string(), ..Default::default() };
     RTDPassportElementEmailAddressBuilder { inner }
   }
 
+  /// `PassportElementEmailAddress` with the fields tdlib requires, the others are set by `PassportElementEmailAddress::builder()`
+  pub fn new(email_address: impl AsRef<str>) -> Self { Self::builder().email_address(email_address).build() }
+
   pub fn email_address(&self) -> &String { &self.email_address }
 
 }
@@ -473,6 +557,12 @@ pub struct RTDPassportElementEmailAddressBuilder {
 impl RTDPassportElementEmailAddressBuilder {
   pub fn build(&self) -> PassportElementEmailAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
@@ -489,6 +579,12 @@ impl AsRef<PassportElementEmailAddress> for RTDPassportElementEmailAddressBuilde
   fn as_ref(&self) -> &PassportElementEmailAddress { &self.inner }
 }
 
+impl PartialEq for PassportElementEmailAddress {
+  fn eq(&self, other: &Self) -> bool {
+    self.email_address == other.email_address
+  }
+}
+
 
 
 
@@ -503,15 +599,19 @@ pub struct PassportElementIdentityCard {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identity card
   identity_card: IdentityDocument,
   
 }
 
 impl RObject for PassportElementIdentityCard {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementIdentityCard" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementIdentityCard" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -521,14 +621,15 @@ impl TDPassportElement for PassportElementIdentityCard {}
 
 
 impl PassportElementIdentityCard {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementIdentityCardBuilder {
-    let mut inner = PassportElementIdentityCard::default();
-    inner.td_name = "passportElementIdentityCard".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementIdentityCard { td_name: "passportElementIdentityCard".to_string(), ..Default::default() };
     RTDPassportElementIdentityCardBuilder { inner }
   }
 
+  /// `PassportElementIdentityCard` with the fields tdlib requires, the others are set by `PassportElementIdentityCard::builder()`
+  pub fn new(identity_card: impl AsRef<IdentityDocument>) -> Self { Self::builder().identity_card(identity_card).build() }
+
   pub fn identity_card(&self) -> &IdentityDocument { &self.identity_card }
 
 }
@@ -541,6 +642,12 @@ pub struct RTDPassportElementIdentityCardBuilder {
 impl RTDPassportElementIdentityCardBuilder {
   pub fn build(&self) -> PassportElementIdentityCard { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn identity_card<T: AsRef<IdentityDocument>>(&mut self, identity_card: T) -> &mut Self {
     self.inner.identity_card = identity_card.as_ref().clone();
@@ -557,6 +664,12 @@ impl AsRef<PassportElementIdentityCard> for RTDPassportElementIdentityCardBuilde
   fn as_ref(&self) -> &PassportElementIdentityCard { &self.inner }
 }
 
+impl PartialEq for PassportElementIdentityCard {
+  fn eq(&self, other: &Self) -> bool {
+    self.identity_card == other.identity_card
+  }
+}
+
 
 
 
@@ -571,15 +684,19 @@ pub struct PassportElementInternalPassport {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Internal passport
   internal_passport: IdentityDocument,
   
 }
 
 impl RObject for PassportElementInternalPassport {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementInternalPassport" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementInternalPassport" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -589,14 +706,15 @@ impl TDPassportElement for PassportElementInternalPassport {}
 
 
 impl PassportElementInternalPassport {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementInternalPassportBuilder {
-    let mut inner = PassportElementInternalPassport::default();
-    inner.td_name = "passportElementInternalPassport".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementInternalPassport { td_name: "passportElementInternalPassport".to_string(), ..Default::default() };
     RTDPassportElementInternalPassportBuilder { inner }
   }
 
+  /// `PassportElementInternalPassport` with the fields tdlib requires, the others are set by `PassportElementInternalPassport::builder()`
+  pub fn new(internal_passport: impl AsRef<IdentityDocument>) -> Self { Self::builder().internal_passport(internal_passport).build() }
+
   pub fn internal_passport(&self) -> &IdentityDocument { &self.internal_passport }
 
 }
@@ -609,6 +727,12 @@ pub struct RTDPassportElementInternalPassportBuilder {
 impl RTDPassportElementInternalPassportBuilder {
   pub fn build(&self) -> PassportElementInternalPassport { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn internal_passport<T: AsRef<IdentityDocument>>(&mut self, internal_passport: T) -> &mut Self {
     self.inner.internal_passport = internal_passport.as_ref().clone();
@@ -625,6 +749,12 @@ impl AsRef<PassportElementInternalPassport> for RTDPassportElementInternalPasspo
   fn as_ref(&self) -> &PassportElementInternalPassport { &self.inner }
 }
 
+impl PartialEq for PassportElementInternalPassport {
+  fn eq(&self, other: &Self) -> bool {
+    self.internal_passport == other.internal_passport
+  }
+}
+
 
 
 
@@ -639,15 +769,19 @@ pub struct PassportElementPassport {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Passport
   passport: IdentityDocument,
   
 }
 
 impl RObject for PassportElementPassport {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPassport" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementPassport" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -657,14 +791,15 @@ impl TDPassportElement for PassportElementPassport {}
 
 
 impl PassportElementPassport {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementPassportBuilder {
-    let mut inner = PassportElementPassport::default();
-    inner.td_name = "passportElementPassport".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementPassport { td_name: "passportElementPassport".to_string(), ..Default::default() };
     RTDPassportElementPassportBuilder { inner }
   }
 
+  /// `PassportElementPassport` with the fields tdlib requires, the others are set by `PassportElementPassport::builder()`
+  pub fn new(passport: impl AsRef<IdentityDocument>) -> Self { Self::builder().passport(passport).build() }
+
   pub fn passport(&self) -> &IdentityDocument { &self.passport }
 
 }
@@ -677,6 +812,12 @@ pub struct RTDPassportElementPassportBuilder {
 impl RTDPassportElementPassportBuilder {
   pub fn build(&self) -> PassportElementPassport { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport<T: AsRef<IdentityDocument>>(&mut self, passport: T) -> &mut Self {
     self.inner.passport = passport.as_ref().clone();
@@ -693,6 +834,12 @@ impl AsRef<PassportElementPassport> for RTDPassportElementPassportBuilder {
   fn as_ref(&self) -> &PassportElementPassport { &self.inner }
 }
 
+impl PartialEq for PassportElementPassport {
+  fn eq(&self, other: &Self) -> bool {
+    self.passport == other.passport
+  }
+}
+
 
 
 
@@ -707,15 +854,19 @@ pub struct PassportElementPassportRegistration {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Passport registration pages
   passport_registration: PersonalDocument,
   
 }
 
 impl RObject for PassportElementPassportRegistration {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPassportRegistration" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementPassportRegistration" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -725,14 +876,15 @@ impl TDPassportElement for PassportElementPassportRegistration {}
 
 
 impl PassportElementPassportRegistration {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementPassportRegistrationBuilder {
-    let mut inner = PassportElementPassportRegistration::default();
-    inner.td_name = "passportElementPassportRegistration".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementPassportRegistration { td_name: "passportElementPassportRegistration".to_string(), ..Default::default() };
     RTDPassportElementPassportRegistrationBuilder { inner }
   }
 
+  /// `PassportElementPassportRegistration` with the fields tdlib requires, the others are set by `PassportElementPassportRegistration::builder()`
+  pub fn new(passport_registration: impl AsRef<PersonalDocument>) -> Self { Self::builder().passport_registration(passport_registration).build() }
+
   pub fn passport_registration(&self) -> &PersonalDocument { &self.passport_registration }
 
 }
@@ -745,6 +897,12 @@ pub struct RTDPassportElementPassportRegistrationBuilder {
 impl RTDPassportElementPassportRegistrationBuilder {
   pub fn build(&self) -> PassportElementPassportRegistration { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn passport_registration<T: AsRef<PersonalDocument>>(&mut self, passport_registration: T) -> &mut Self {
     self.inner.passport_registration = passport_registration.as_ref().clone();
@@ -761,6 +919,12 @@ impl AsRef<PassportElementPassportRegistration> for RTDPassportElementPassportRe
   fn as_ref(&self) -> &PassportElementPassportRegistration { &self.inner }
 }
 
+impl PartialEq for PassportElementPassportRegistration {
+  fn eq(&self, other: &Self) -> bool {
+    self.passport_registration == other.passport_registration
+  }
+}
+
 
 
 
@@ -775,15 +939,19 @@ pub struct PassportElementPersonalDetails {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Personal details of the user
   personal_details: PersonalDetails,
   
 }
 
 impl RObject for PassportElementPersonalDetails {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPersonalDetails" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementPersonalDetails" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -793,14 +961,15 @@ impl TDPassportElement for PassportElementPersonalDetails {}
 
 
 impl PassportElementPersonalDetails {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementPersonalDetailsBuilder {
-    let mut inner = PassportElementPersonalDetails::default();
-    inner.td_name = "passportElementPersonalDetails".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementPersonalDetails { td_name: "passportElementPersonalDetails".to_string(), ..Default::default() };
     RTDPassportElementPersonalDetailsBuilder { inner }
   }
 
+  /// `PassportElementPersonalDetails` with the fields tdlib requires, the others are set by `PassportElementPersonalDetails::builder()`
+  pub fn new(personal_details: impl AsRef<PersonalDetails>) -> Self { Self::builder().personal_details(personal_details).build() }
+
   pub fn personal_details(&self) -> &PersonalDetails { &self.personal_details }
 
 }
@@ -813,6 +982,12 @@ pub struct RTDPassportElementPersonalDetailsBuilder {
 impl RTDPassportElementPersonalDetailsBuilder {
   pub fn build(&self) -> PassportElementPersonalDetails { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn personal_details<T: AsRef<PersonalDetails>>(&mut self, personal_details: T) -> &mut Self {
     self.inner.personal_details = personal_details.as_ref().clone();
@@ -829,6 +1004,12 @@ impl AsRef<PassportElementPersonalDetails> for RTDPassportElementPersonalDetails
   fn as_ref(&self) -> &PassportElementPersonalDetails { &self.inner }
 }
 
+impl PartialEq for PassportElementPersonalDetails {
+  fn eq(&self, other: &Self) -> bool {
+    self.personal_details == other.personal_details
+  }
+}
+
 
 
 
@@ -843,15 +1024,19 @@ pub struct PassportElementPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Phone number
   phone_number: String,
   
 }
 
 impl RObject for PassportElementPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -861,14 +1046,15 @@ impl TDPassportElement for PassportElementPhoneNumber {}
 
 
 impl PassportElementPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementPhoneNumberBuilder {
-    let mut inner = PassportElementPhoneNumber::default();
-    inner.td_name = "passportElementPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementPhoneNumber { td_name: "passportElementPhoneNumber".to_string(), ..Default::default() };
     RTDPassportElementPhoneNumberBuilder { inner }
   }
 
+  /// `PassportElementPhoneNumber` with the fields tdlib requires, the others are set by `PassportElementPhoneNumber::builder()`
+  pub fn new(phone_number: impl AsRef<str>) -> Self { Self::builder().phone_number(phone_number).build() }
+
   pub fn phone_number(&self) -> &String { &self.phone_number }
 
 }
@@ -881,6 +1067,12 @@ pub struct RTDPassportElementPhoneNumberBuilder {
 impl RTDPassportElementPhoneNumberBuilder {
   pub fn build(&self) -> PassportElementPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
@@ -897,6 +1089,12 @@ impl AsRef<PassportElementPhoneNumber> for RTDPassportElementPhoneNumberBuilder
   fn as_ref(&self) -> &PassportElementPhoneNumber { &self.inner }
 }
 
+impl PartialEq for PassportElementPhoneNumber {
+  fn eq(&self, other: &Self) -> bool {
+    self.phone_number == other.phone_number
+  }
+}
+
 
 
 
@@ -911,15 +1109,19 @@ pub struct PassportElementRentalAgreement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Rental agreement
   rental_agreement: PersonalDocument,
   
 }
 
 impl RObject for PassportElementRentalAgreement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementRentalAgreement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementRentalAgreement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -929,14 +1131,15 @@ impl TDPassportElement for PassportElementRentalAgreement {}
 
 
 impl PassportElementRentalAgreement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementRentalAgreementBuilder {
-    let mut inner = PassportElementRentalAgreement::default();
-    inner.td_name = "passportElementRentalAgreement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementRentalAgreement { td_name: "passportElementRentalAgreement".to_string(), ..Default::default() };
     RTDPassportElementRentalAgreementBuilder { inner }
   }
 
+  /// `PassportElementRentalAgreement` with the fields tdlib requires, the others are set by `PassportElementRentalAgreement::builder()`
+  pub fn new(rental_agreement: impl AsRef<PersonalDocument>) -> Self { Self::builder().rental_agreement(rental_agreement).build() }
+
   pub fn rental_agreement(&self) -> &PersonalDocument { &self.rental_agreement }
 
 }
@@ -949,6 +1152,12 @@ pub struct RTDPassportElementRentalAgreementBuilder {
 impl RTDPassportElementRentalAgreementBuilder {
   pub fn build(&self) -> PassportElementRentalAgreement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rental_agreement<T: AsRef<PersonalDocument>>(&mut self, rental_agreement: T) -> &mut Self {
     self.inner.rental_agreement = rental_agreement.as_ref().clone();
@@ -965,6 +1174,12 @@ impl AsRef<PassportElementRentalAgreement> for RTDPassportElementRentalAgreement
   fn as_ref(&self) -> &PassportElementRentalAgreement { &self.inner }
 }
 
+impl PartialEq for PassportElementRentalAgreement {
+  fn eq(&self, other: &Self) -> bool {
+    self.rental_agreement == other.rental_agreement
+  }
+}
+
 
 
 
@@ -979,15 +1194,19 @@ pub struct PassportElementTemporaryRegistration {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Temporary registration
   temporary_registration: PersonalDocument,
   
 }
 
 impl RObject for PassportElementTemporaryRegistration {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTemporaryRegistration" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTemporaryRegistration" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -997,14 +1216,15 @@ impl TDPassportElement for PassportElementTemporaryRegistration {}
 
 
 impl PassportElementTemporaryRegistration {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTemporaryRegistrationBuilder {
-    let mut inner = PassportElementTemporaryRegistration::default();
-    inner.td_name = "passportElementTemporaryRegistration".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTemporaryRegistration { td_name: "passportElementTemporaryRegistration".to_string(), ..Default::default() };
     RTDPassportElementTemporaryRegistrationBuilder { inner }
   }
 
+  /// `PassportElementTemporaryRegistration` with the fields tdlib requires, the others are set by `PassportElementTemporaryRegistration::builder()`
+  pub fn new(temporary_registration: impl AsRef<PersonalDocument>) -> Self { Self::builder().temporary_registration(temporary_registration).build() }
+
   pub fn temporary_registration(&self) -> &PersonalDocument { &self.temporary_registration }
 
 }
@@ -1017,6 +1237,12 @@ pub struct RTDPassportElementTemporaryRegistrationBuilder {
 impl RTDPassportElementTemporaryRegistrationBuilder {
   pub fn build(&self) -> PassportElementTemporaryRegistration { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn temporary_registration<T: AsRef<PersonalDocument>>(&mut self, temporary_registration: T) -> &mut Self {
     self.inner.temporary_registration = temporary_registration.as_ref().clone();
@@ -1033,6 +1259,12 @@ impl AsRef<PassportElementTemporaryRegistration> for RTDPassportElementTemporary
   fn as_ref(&self) -> &PassportElementTemporaryRegistration { &self.inner }
 }
 
+impl PartialEq for PassportElementTemporaryRegistration {
+  fn eq(&self, other: &Self) -> bool {
+    self.temporary_registration == other.temporary_registration
+  }
+}
+
 
 
 
@@ -1047,15 +1279,19 @@ pub struct PassportElementUtilityBill {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Utility bill
   utility_bill: PersonalDocument,
   
 }
 
 impl RObject for PassportElementUtilityBill {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementUtilityBill" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementUtilityBill" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1065,14 +1301,15 @@ impl TDPassportElement for PassportElementUtilityBill {}
 
 
 impl PassportElementUtilityBill {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementUtilityBillBuilder {
-    let mut inner = PassportElementUtilityBill::default();
-    inner.td_name = "passportElementUtilityBill".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementUtilityBill { td_name: "passportElementUtilityBill".to_string(), ..Default::default() };
     RTDPassportElementUtilityBillBuilder { inner }
   }
 
+  /// `PassportElementUtilityBill` with the fields tdlib requires, the others are set by `PassportElementUtilityBill::builder()`
+  pub fn new(utility_bill: impl AsRef<PersonalDocument>) -> Self { Self::builder().utility_bill(utility_bill).build() }
+
   pub fn utility_bill(&self) -> &PersonalDocument { &self.utility_bill }
 
 }
@@ -1085,6 +1322,12 @@ pub struct RTDPassportElementUtilityBillBuilder {
 impl RTDPassportElementUtilityBillBuilder {
   pub fn build(&self) -> PassportElementUtilityBill { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn utility_bill<T: AsRef<PersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
     self.inner.utility_bill = utility_bill.as_ref().clone();
@@ -1101,5 +1344,11 @@ impl AsRef<PassportElementUtilityBill> for RTDPassportElementUtilityBillBuilder
   fn as_ref(&self) -> &PassportElementUtilityBill { &self.inner }
 }
 
+impl PartialEq for PassportElementUtilityBill {
+  fn eq(&self, other: &Self) -> bool {
+    self.utility_bill == other.utility_bill
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PassportElementError {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Type of the Telegram Passport element which has the error
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// Error message
@@ -25,22 +27,24 @@ pub struct PassportElementError {
 }
 
 impl RObject for PassportElementError {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementError" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementError" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PassportElementError {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorBuilder {
-    let mut inner = PassportElementError::default();
-    inner.td_name = "passportElementError".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementError { td_name: "passportElementError".to_string(), ..Default::default() };
     RTDPassportElementErrorBuilder { inner }
   }
 
+  /// `PassportElementError` with the fields tdlib requires, the others are set by `PassportElementError::builder()`
+  pub fn new(type_: impl AsRef<PassportElementType>, message: impl AsRef<str>, source: impl AsRef<PassportElementErrorSource>) -> Self { Self::builder().type_(type_).message(message).source(source).build() }
+
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
 
   pub fn message(&self) -> &String { &self.message }
@@ -57,6 +61,12 @@ pub struct RTDPassportElementErrorBuilder {
 impl RTDPassportElementErrorBuilder {
   pub fn build(&self) -> PassportElementError { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -85,5 +95,13 @@ impl AsRef<PassportElementError> for RTDPassportElementErrorBuilder {
   fn as_ref(&self) -> &PassportElementError { &self.inner }
 }
 
+impl PartialEq for PassportElementError {
+  fn eq(&self, other: &Self) -> bool {
+    self.type_ == other.type_
+      && self.message == other.message
+      && self.source == other.source
+  }
+}
+
 
 
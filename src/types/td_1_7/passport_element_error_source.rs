@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPassportElementErrorSource: Debug + RObject {}
 
 /// Contains the description of an error in a Telegram Passport element
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PassportElementErrorSource {
   #[doc(hidden)] _Default(()),
@@ -37,6 +36,8 @@ pub enum PassportElementErrorSource {
   TranslationFiles(PassportElementErrorSourceTranslationFiles),
   /// The element contains an error in an unspecified place. The error will be considered resolved when new data is added
   Unspecified(PassportElementErrorSourceUnspecified),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -48,7 +49,7 @@ impl<'de> Deserialize<'de> for PassportElementErrorSource {
   fn deserialize<D>(deserializer: D) -> Result<PassportElementErrorSource, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PassportElementErrorSource,
+      PassportElementErrorSource, Unknown;
       (passportElementErrorSourceDataField, DataField);
       (passportElementErrorSourceFile, File);
       (passportElementErrorSourceFiles, Files);
@@ -64,7 +65,7 @@ impl<'de> Deserialize<'de> for PassportElementErrorSource {
 }
 
 impl RObject for PassportElementErrorSource {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PassportElementErrorSource::DataField(t) => t.td_name(),
       PassportElementErrorSource::File(t) => t.td_name(),
@@ -75,11 +76,12 @@ impl RObject for PassportElementErrorSource {
       PassportElementErrorSource::TranslationFile(t) => t.td_name(),
       PassportElementErrorSource::TranslationFiles(t) => t.td_name(),
       PassportElementErrorSource::Unspecified(t) => t.td_name(),
+      PassportElementErrorSource::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PassportElementErrorSource::DataField(t) => t.extra(),
       PassportElementErrorSource::File(t) => t.extra(),
@@ -90,6 +92,23 @@ impl RObject for PassportElementErrorSource {
       PassportElementErrorSource::TranslationFile(t) => t.extra(),
       PassportElementErrorSource::TranslationFiles(t) => t.extra(),
       PassportElementErrorSource::Unspecified(t) => t.extra(),
+      PassportElementErrorSource::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PassportElementErrorSource::DataField(t) => t.client_id(),
+      PassportElementErrorSource::File(t) => t.client_id(),
+      PassportElementErrorSource::Files(t) => t.client_id(),
+      PassportElementErrorSource::FrontSide(t) => t.client_id(),
+      PassportElementErrorSource::ReverseSide(t) => t.client_id(),
+      PassportElementErrorSource::Selfie(t) => t.client_id(),
+      PassportElementErrorSource::TranslationFile(t) => t.client_id(),
+      PassportElementErrorSource::TranslationFiles(t) => t.client_id(),
+      PassportElementErrorSource::Unspecified(t) => t.client_id(),
+      PassportElementErrorSource::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -98,18 +117,19 @@ impl RObject for PassportElementErrorSource {
 }
 
 impl PassportElementErrorSource {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElementErrorSource::_Default(_) = self { true } else { false } }
-
-  pub fn is_data_field(&self) -> bool { if let PassportElementErrorSource::DataField(_) = self { true } else { false } }
-  pub fn is_file(&self) -> bool { if let PassportElementErrorSource::File(_) = self { true } else { false } }
-  pub fn is_files(&self) -> bool { if let PassportElementErrorSource::Files(_) = self { true } else { false } }
-  pub fn is_front_side(&self) -> bool { if let PassportElementErrorSource::FrontSide(_) = self { true } else { false } }
-  pub fn is_reverse_side(&self) -> bool { if let PassportElementErrorSource::ReverseSide(_) = self { true } else { false } }
-  pub fn is_selfie(&self) -> bool { if let PassportElementErrorSource::Selfie(_) = self { true } else { false } }
-  pub fn is_translation_file(&self) -> bool { if let PassportElementErrorSource::TranslationFile(_) = self { true } else { false } }
-  pub fn is_translation_files(&self) -> bool { if let PassportElementErrorSource::TranslationFiles(_) = self { true } else { false } }
-  pub fn is_unspecified(&self) -> bool { if let PassportElementErrorSource::Unspecified(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PassportElementErrorSource::_Default(_)) }
+
+  pub fn is_data_field(&self) -> bool { matches!(self, PassportElementErrorSource::DataField(_)) }
+  pub fn is_file(&self) -> bool { matches!(self, PassportElementErrorSource::File(_)) }
+  pub fn is_files(&self) -> bool { matches!(self, PassportElementErrorSource::Files(_)) }
+  pub fn is_front_side(&self) -> bool { matches!(self, PassportElementErrorSource::FrontSide(_)) }
+  pub fn is_reverse_side(&self) -> bool { matches!(self, PassportElementErrorSource::ReverseSide(_)) }
+  pub fn is_selfie(&self) -> bool { matches!(self, PassportElementErrorSource::Selfie(_)) }
+  pub fn is_translation_file(&self) -> bool { matches!(self, PassportElementErrorSource::TranslationFile(_)) }
+  pub fn is_translation_files(&self) -> bool { matches!(self, PassportElementErrorSource::TranslationFiles(_)) }
+  pub fn is_unspecified(&self) -> bool { matches!(self, PassportElementErrorSource::Unspecified(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PassportElementErrorSource::Unknown(_)) }
 
   pub fn on_data_field<F: FnOnce(&PassportElementErrorSourceDataField)>(&self, fnc: F) -> &Self { if let PassportElementErrorSource::DataField(t) = self { fnc(t) }; self }
   pub fn on_file<F: FnOnce(&PassportElementErrorSourceFile)>(&self, fnc: F) -> &Self { if let PassportElementErrorSource::File(t) = self { fnc(t) }; self }
@@ -120,6 +140,7 @@ impl PassportElementErrorSource {
   pub fn on_translation_file<F: FnOnce(&PassportElementErrorSourceTranslationFile)>(&self, fnc: F) -> &Self { if let PassportElementErrorSource::TranslationFile(t) = self { fnc(t) }; self }
   pub fn on_translation_files<F: FnOnce(&PassportElementErrorSourceTranslationFiles)>(&self, fnc: F) -> &Self { if let PassportElementErrorSource::TranslationFiles(t) = self { fnc(t) }; self }
   pub fn on_unspecified<F: FnOnce(&PassportElementErrorSourceUnspecified)>(&self, fnc: F) -> &Self { if let PassportElementErrorSource::Unspecified(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PassportElementErrorSource::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_data_field(&self) -> Option<&PassportElementErrorSourceDataField> { if let PassportElementErrorSource::DataField(t) = self { return Some(t) } None }
   pub fn as_file(&self) -> Option<&PassportElementErrorSourceFile> { if let PassportElementErrorSource::File(t) = self { return Some(t) } None }
@@ -130,6 +151,7 @@ impl PassportElementErrorSource {
   pub fn as_translation_file(&self) -> Option<&PassportElementErrorSourceTranslationFile> { if let PassportElementErrorSource::TranslationFile(t) = self { return Some(t) } None }
   pub fn as_translation_files(&self) -> Option<&PassportElementErrorSourceTranslationFiles> { if let PassportElementErrorSource::TranslationFiles(t) = self { return Some(t) } None }
   pub fn as_unspecified(&self) -> Option<&PassportElementErrorSourceUnspecified> { if let PassportElementErrorSource::Unspecified(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PassportElementErrorSource::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -171,15 +193,19 @@ pub struct PassportElementErrorSourceDataField {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Field name
   field_name: String,
   
 }
 
 impl RObject for PassportElementErrorSourceDataField {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceDataField" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceDataField" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -189,14 +215,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceDataField {}
 
 
 impl PassportElementErrorSourceDataField {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceDataFieldBuilder {
-    let mut inner = PassportElementErrorSourceDataField::default();
-    inner.td_name = "passportElementErrorSourceDataField".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceDataField { td_name: "passportElementErrorSourceDataField".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceDataFieldBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceDataField` with the fields tdlib requires, the others are set by `PassportElementErrorSourceDataField::builder()`
+  pub fn new(field_name: impl AsRef<str>) -> Self { Self::builder().field_name(field_name).build() }
+
   pub fn field_name(&self) -> &String { &self.field_name }
 
 }
@@ -209,6 +236,12 @@ pub struct RTDPassportElementErrorSourceDataFieldBuilder {
 impl RTDPassportElementErrorSourceDataFieldBuilder {
   pub fn build(&self) -> PassportElementErrorSourceDataField { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
     self.inner.field_name = field_name.as_ref().to_string();
@@ -225,6 +258,12 @@ impl AsRef<PassportElementErrorSourceDataField> for RTDPassportElementErrorSourc
   fn as_ref(&self) -> &PassportElementErrorSourceDataField { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceDataField {
+  fn eq(&self, other: &Self) -> bool {
+    self.field_name == other.field_name
+  }
+}
+
 
 
 
@@ -239,15 +278,19 @@ pub struct PassportElementErrorSourceFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Index of a file with the error
   file_index: i64,
   
 }
 
 impl RObject for PassportElementErrorSourceFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -257,14 +300,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceFile {}
 
 
 impl PassportElementErrorSourceFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceFileBuilder {
-    let mut inner = PassportElementErrorSourceFile::default();
-    inner.td_name = "passportElementErrorSourceFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceFile { td_name: "passportElementErrorSourceFile".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceFileBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceFile` with the fields tdlib requires, the others are set by `PassportElementErrorSourceFile::builder()`
+  pub fn new(file_index: i64) -> Self { Self::builder().file_index(file_index).build() }
+
   pub fn file_index(&self) -> i64 { self.file_index }
 
 }
@@ -277,6 +321,12 @@ pub struct RTDPassportElementErrorSourceFileBuilder {
 impl RTDPassportElementErrorSourceFileBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_index(&mut self, file_index: i64) -> &mut Self {
     self.inner.file_index = file_index;
@@ -293,6 +343,12 @@ impl AsRef<PassportElementErrorSourceFile> for RTDPassportElementErrorSourceFile
   fn as_ref(&self) -> &PassportElementErrorSourceFile { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_index == other.file_index
+  }
+}
+
 
 
 
@@ -307,13 +363,17 @@ pub struct PassportElementErrorSourceFiles {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementErrorSourceFiles {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceFiles" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceFiles" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -323,14 +383,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceFiles {}
 
 
 impl PassportElementErrorSourceFiles {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceFilesBuilder {
-    let mut inner = PassportElementErrorSourceFiles::default();
-    inner.td_name = "passportElementErrorSourceFiles".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceFiles { td_name: "passportElementErrorSourceFiles".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceFilesBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceFiles` with the fields tdlib requires, the others are set by `PassportElementErrorSourceFiles::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -341,6 +402,12 @@ pub struct RTDPassportElementErrorSourceFilesBuilder {
 impl RTDPassportElementErrorSourceFilesBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFiles { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceFiles> for PassportElementErrorSourceFiles {
@@ -351,6 +418,10 @@ impl AsRef<PassportElementErrorSourceFiles> for RTDPassportElementErrorSourceFil
   fn as_ref(&self) -> &PassportElementErrorSourceFiles { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceFiles {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -365,13 +436,17 @@ pub struct PassportElementErrorSourceFrontSide {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementErrorSourceFrontSide {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceFrontSide" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceFrontSide" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -381,14 +456,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceFrontSide {}
 
 
 impl PassportElementErrorSourceFrontSide {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceFrontSideBuilder {
-    let mut inner = PassportElementErrorSourceFrontSide::default();
-    inner.td_name = "passportElementErrorSourceFrontSide".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceFrontSide { td_name: "passportElementErrorSourceFrontSide".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceFrontSideBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceFrontSide` with the fields tdlib requires, the others are set by `PassportElementErrorSourceFrontSide::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -399,6 +475,12 @@ pub struct RTDPassportElementErrorSourceFrontSideBuilder {
 impl RTDPassportElementErrorSourceFrontSideBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFrontSide { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceFrontSide> for PassportElementErrorSourceFrontSide {
@@ -409,6 +491,10 @@ impl AsRef<PassportElementErrorSourceFrontSide> for RTDPassportElementErrorSourc
   fn as_ref(&self) -> &PassportElementErrorSourceFrontSide { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceFrontSide {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -423,13 +509,17 @@ pub struct PassportElementErrorSourceReverseSide {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementErrorSourceReverseSide {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceReverseSide" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceReverseSide" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -439,14 +529,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceReverseSide {}
 
 
 impl PassportElementErrorSourceReverseSide {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceReverseSideBuilder {
-    let mut inner = PassportElementErrorSourceReverseSide::default();
-    inner.td_name = "passportElementErrorSourceReverseSide".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceReverseSide { td_name: "passportElementErrorSourceReverseSide".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceReverseSideBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceReverseSide` with the fields tdlib requires, the others are set by `PassportElementErrorSourceReverseSide::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -457,6 +548,12 @@ pub struct RTDPassportElementErrorSourceReverseSideBuilder {
 impl RTDPassportElementErrorSourceReverseSideBuilder {
   pub fn build(&self) -> PassportElementErrorSourceReverseSide { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceReverseSide> for PassportElementErrorSourceReverseSide {
@@ -467,6 +564,10 @@ impl AsRef<PassportElementErrorSourceReverseSide> for RTDPassportElementErrorSou
   fn as_ref(&self) -> &PassportElementErrorSourceReverseSide { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceReverseSide {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -481,13 +582,17 @@ pub struct PassportElementErrorSourceSelfie {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementErrorSourceSelfie {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceSelfie" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceSelfie" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -497,14 +602,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceSelfie {}
 
 
 impl PassportElementErrorSourceSelfie {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceSelfieBuilder {
-    let mut inner = PassportElementErrorSourceSelfie::default();
-    inner.td_name = "passportElementErrorSourceSelfie".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceSelfie { td_name: "passportElementErrorSourceSelfie".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceSelfieBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceSelfie` with the fields tdlib requires, the others are set by `PassportElementErrorSourceSelfie::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -515,6 +621,12 @@ pub struct RTDPassportElementErrorSourceSelfieBuilder {
 impl RTDPassportElementErrorSourceSelfieBuilder {
   pub fn build(&self) -> PassportElementErrorSourceSelfie { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceSelfie> for PassportElementErrorSourceSelfie {
@@ -525,6 +637,10 @@ impl AsRef<PassportElementErrorSourceSelfie> for RTDPassportElementErrorSourceSe
   fn as_ref(&self) -> &PassportElementErrorSourceSelfie { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceSelfie {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -539,15 +655,19 @@ pub struct PassportElementErrorSourceTranslationFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Index of a file with the error
   file_index: i64,
   
 }
 
 impl RObject for PassportElementErrorSourceTranslationFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceTranslationFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceTranslationFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -557,14 +677,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceTranslationFile
 
 
 impl PassportElementErrorSourceTranslationFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceTranslationFileBuilder {
-    let mut inner = PassportElementErrorSourceTranslationFile::default();
-    inner.td_name = "passportElementErrorSourceTranslationFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceTranslationFile { td_name: "passportElementErrorSourceTranslationFile".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceTranslationFileBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceTranslationFile` with the fields tdlib requires, the others are set by `PassportElementErrorSourceTranslationFile::builder()`
+  pub fn new(file_index: i64) -> Self { Self::builder().file_index(file_index).build() }
+
   pub fn file_index(&self) -> i64 { self.file_index }
 
 }
@@ -577,6 +698,12 @@ pub struct RTDPassportElementErrorSourceTranslationFileBuilder {
 impl RTDPassportElementErrorSourceTranslationFileBuilder {
   pub fn build(&self) -> PassportElementErrorSourceTranslationFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_index(&mut self, file_index: i64) -> &mut Self {
     self.inner.file_index = file_index;
@@ -593,6 +720,12 @@ impl AsRef<PassportElementErrorSourceTranslationFile> for RTDPassportElementErro
   fn as_ref(&self) -> &PassportElementErrorSourceTranslationFile { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceTranslationFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_index == other.file_index
+  }
+}
+
 
 
 
@@ -607,13 +740,17 @@ pub struct PassportElementErrorSourceTranslationFiles {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementErrorSourceTranslationFiles {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceTranslationFiles" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceTranslationFiles" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -623,14 +760,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceTranslationFiles
 
 
 impl PassportElementErrorSourceTranslationFiles {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceTranslationFilesBuilder {
-    let mut inner = PassportElementErrorSourceTranslationFiles::default();
-    inner.td_name = "passportElementErrorSourceTranslationFiles".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceTranslationFiles { td_name: "passportElementErrorSourceTranslationFiles".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceTranslationFilesBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceTranslationFiles` with the fields tdlib requires, the others are set by `PassportElementErrorSourceTranslationFiles::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -641,6 +779,12 @@ pub struct RTDPassportElementErrorSourceTranslationFilesBuilder {
 impl RTDPassportElementErrorSourceTranslationFilesBuilder {
   pub fn build(&self) -> PassportElementErrorSourceTranslationFiles { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceTranslationFiles> for PassportElementErrorSourceTranslationFiles {
@@ -651,6 +795,10 @@ impl AsRef<PassportElementErrorSourceTranslationFiles> for RTDPassportElementErr
   fn as_ref(&self) -> &PassportElementErrorSourceTranslationFiles { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceTranslationFiles {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -665,13 +813,17 @@ pub struct PassportElementErrorSourceUnspecified {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementErrorSourceUnspecified {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementErrorSourceUnspecified" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementErrorSourceUnspecified" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -681,14 +833,15 @@ impl TDPassportElementErrorSource for PassportElementErrorSourceUnspecified {}
 
 
 impl PassportElementErrorSourceUnspecified {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementErrorSourceUnspecifiedBuilder {
-    let mut inner = PassportElementErrorSourceUnspecified::default();
-    inner.td_name = "passportElementErrorSourceUnspecified".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementErrorSourceUnspecified { td_name: "passportElementErrorSourceUnspecified".to_string(), ..Default::default() };
     RTDPassportElementErrorSourceUnspecifiedBuilder { inner }
   }
 
+  /// `PassportElementErrorSourceUnspecified` with the fields tdlib requires, the others are set by `PassportElementErrorSourceUnspecified::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -699,6 +852,12 @@ pub struct RTDPassportElementErrorSourceUnspecifiedBuilder {
 impl RTDPassportElementErrorSourceUnspecifiedBuilder {
   pub fn build(&self) -> PassportElementErrorSourceUnspecified { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementErrorSourceUnspecified> for PassportElementErrorSourceUnspecified {
@@ -709,5 +868,9 @@ impl AsRef<PassportElementErrorSourceUnspecified> for RTDPassportElementErrorSou
   fn as_ref(&self) -> &PassportElementErrorSourceUnspecified { &self.inner }
 }
 
+impl PartialEq for PassportElementErrorSourceUnspecified {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
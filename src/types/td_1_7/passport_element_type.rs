@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPassportElementType: Debug + RObject {}
 
 /// Contains the type of a Telegram Passport element
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PassportElementType {
   #[doc(hidden)] _Default(()),
@@ -45,6 +44,8 @@ pub enum PassportElementType {
   TemporaryRegistration(PassportElementTypeTemporaryRegistration),
   /// A Telegram Passport element containing the user's utility bill
   UtilityBill(PassportElementTypeUtilityBill),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -56,7 +57,7 @@ impl<'de> Deserialize<'de> for PassportElementType {
   fn deserialize<D>(deserializer: D) -> Result<PassportElementType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PassportElementType,
+      PassportElementType, Unknown;
       (passportElementTypeAddress, Address);
       (passportElementTypeBankStatement, BankStatement);
       (passportElementTypeDriverLicense, DriverLicense);
@@ -76,7 +77,7 @@ impl<'de> Deserialize<'de> for PassportElementType {
 }
 
 impl RObject for PassportElementType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PassportElementType::Address(t) => t.td_name(),
       PassportElementType::BankStatement(t) => t.td_name(),
@@ -91,11 +92,12 @@ impl RObject for PassportElementType {
       PassportElementType::RentalAgreement(t) => t.td_name(),
       PassportElementType::TemporaryRegistration(t) => t.td_name(),
       PassportElementType::UtilityBill(t) => t.td_name(),
+      PassportElementType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PassportElementType::Address(t) => t.extra(),
       PassportElementType::BankStatement(t) => t.extra(),
@@ -110,6 +112,27 @@ impl RObject for PassportElementType {
       PassportElementType::RentalAgreement(t) => t.extra(),
       PassportElementType::TemporaryRegistration(t) => t.extra(),
       PassportElementType::UtilityBill(t) => t.extra(),
+      PassportElementType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PassportElementType::Address(t) => t.client_id(),
+      PassportElementType::BankStatement(t) => t.client_id(),
+      PassportElementType::DriverLicense(t) => t.client_id(),
+      PassportElementType::EmailAddress(t) => t.client_id(),
+      PassportElementType::IdentityCard(t) => t.client_id(),
+      PassportElementType::InternalPassport(t) => t.client_id(),
+      PassportElementType::Passport(t) => t.client_id(),
+      PassportElementType::PassportRegistration(t) => t.client_id(),
+      PassportElementType::PersonalDetails(t) => t.client_id(),
+      PassportElementType::PhoneNumber(t) => t.client_id(),
+      PassportElementType::RentalAgreement(t) => t.client_id(),
+      PassportElementType::TemporaryRegistration(t) => t.client_id(),
+      PassportElementType::UtilityBill(t) => t.client_id(),
+      PassportElementType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -118,22 +141,23 @@ impl RObject for PassportElementType {
 }
 
 impl PassportElementType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElementType::_Default(_) = self { true } else { false } }
-
-  pub fn is_address(&self) -> bool { if let PassportElementType::Address(_) = self { true } else { false } }
-  pub fn is_bank_statement(&self) -> bool { if let PassportElementType::BankStatement(_) = self { true } else { false } }
-  pub fn is_driver_license(&self) -> bool { if let PassportElementType::DriverLicense(_) = self { true } else { false } }
-  pub fn is_email_address(&self) -> bool { if let PassportElementType::EmailAddress(_) = self { true } else { false } }
-  pub fn is_identity_card(&self) -> bool { if let PassportElementType::IdentityCard(_) = self { true } else { false } }
-  pub fn is_internal_passport(&self) -> bool { if let PassportElementType::InternalPassport(_) = self { true } else { false } }
-  pub fn is_passport(&self) -> bool { if let PassportElementType::Passport(_) = self { true } else { false } }
-  pub fn is_passport_registration(&self) -> bool { if let PassportElementType::PassportRegistration(_) = self { true } else { false } }
-  pub fn is_personal_details(&self) -> bool { if let PassportElementType::PersonalDetails(_) = self { true } else { false } }
-  pub fn is_phone_number(&self) -> bool { if let PassportElementType::PhoneNumber(_) = self { true } else { false } }
-  pub fn is_rental_agreement(&self) -> bool { if let PassportElementType::RentalAgreement(_) = self { true } else { false } }
-  pub fn is_temporary_registration(&self) -> bool { if let PassportElementType::TemporaryRegistration(_) = self { true } else { false } }
-  pub fn is_utility_bill(&self) -> bool { if let PassportElementType::UtilityBill(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PassportElementType::_Default(_)) }
+
+  pub fn is_address(&self) -> bool { matches!(self, PassportElementType::Address(_)) }
+  pub fn is_bank_statement(&self) -> bool { matches!(self, PassportElementType::BankStatement(_)) }
+  pub fn is_driver_license(&self) -> bool { matches!(self, PassportElementType::DriverLicense(_)) }
+  pub fn is_email_address(&self) -> bool { matches!(self, PassportElementType::EmailAddress(_)) }
+  pub fn is_identity_card(&self) -> bool { matches!(self, PassportElementType::IdentityCard(_)) }
+  pub fn is_internal_passport(&self) -> bool { matches!(self, PassportElementType::InternalPassport(_)) }
+  pub fn is_passport(&self) -> bool { matches!(self, PassportElementType::Passport(_)) }
+  pub fn is_passport_registration(&self) -> bool { matches!(self, PassportElementType::PassportRegistration(_)) }
+  pub fn is_personal_details(&self) -> bool { matches!(self, PassportElementType::PersonalDetails(_)) }
+  pub fn is_phone_number(&self) -> bool { matches!(self, PassportElementType::PhoneNumber(_)) }
+  pub fn is_rental_agreement(&self) -> bool { matches!(self, PassportElementType::RentalAgreement(_)) }
+  pub fn is_temporary_registration(&self) -> bool { matches!(self, PassportElementType::TemporaryRegistration(_)) }
+  pub fn is_utility_bill(&self) -> bool { matches!(self, PassportElementType::UtilityBill(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PassportElementType::Unknown(_)) }
 
   pub fn on_address<F: FnOnce(&PassportElementTypeAddress)>(&self, fnc: F) -> &Self { if let PassportElementType::Address(t) = self { fnc(t) }; self }
   pub fn on_bank_statement<F: FnOnce(&PassportElementTypeBankStatement)>(&self, fnc: F) -> &Self { if let PassportElementType::BankStatement(t) = self { fnc(t) }; self }
@@ -148,6 +172,7 @@ impl PassportElementType {
   pub fn on_rental_agreement<F: FnOnce(&PassportElementTypeRentalAgreement)>(&self, fnc: F) -> &Self { if let PassportElementType::RentalAgreement(t) = self { fnc(t) }; self }
   pub fn on_temporary_registration<F: FnOnce(&PassportElementTypeTemporaryRegistration)>(&self, fnc: F) -> &Self { if let PassportElementType::TemporaryRegistration(t) = self { fnc(t) }; self }
   pub fn on_utility_bill<F: FnOnce(&PassportElementTypeUtilityBill)>(&self, fnc: F) -> &Self { if let PassportElementType::UtilityBill(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PassportElementType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_address(&self) -> Option<&PassportElementTypeAddress> { if let PassportElementType::Address(t) = self { return Some(t) } None }
   pub fn as_bank_statement(&self) -> Option<&PassportElementTypeBankStatement> { if let PassportElementType::BankStatement(t) = self { return Some(t) } None }
@@ -162,6 +187,7 @@ impl PassportElementType {
   pub fn as_rental_agreement(&self) -> Option<&PassportElementTypeRentalAgreement> { if let PassportElementType::RentalAgreement(t) = self { return Some(t) } None }
   pub fn as_temporary_registration(&self) -> Option<&PassportElementTypeTemporaryRegistration> { if let PassportElementType::TemporaryRegistration(t) = self { return Some(t) } None }
   pub fn as_utility_bill(&self) -> Option<&PassportElementTypeUtilityBill> { if let PassportElementType::UtilityBill(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PassportElementType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -211,13 +237,17 @@ pub struct PassportElementTypeAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -227,14 +257,15 @@ impl TDPassportElementType for PassportElementTypeAddress {}
 
 
 impl PassportElementTypeAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeAddressBuilder {
-    let mut inner = PassportElementTypeAddress::default();
-    inner.td_name = "passportElementTypeAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeAddress { td_name: "passportElementTypeAddress".to_string(), ..Default::default() };
     RTDPassportElementTypeAddressBuilder { inner }
   }
 
+  /// `PassportElementTypeAddress` with the fields tdlib requires, the others are set by `PassportElementTypeAddress::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -245,6 +276,12 @@ pub struct RTDPassportElementTypeAddressBuilder {
 impl RTDPassportElementTypeAddressBuilder {
   pub fn build(&self) -> PassportElementTypeAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeAddress> for PassportElementTypeAddress {
@@ -255,6 +292,10 @@ impl AsRef<PassportElementTypeAddress> for RTDPassportElementTypeAddressBuilder
   fn as_ref(&self) -> &PassportElementTypeAddress { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeAddress {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -269,13 +310,17 @@ pub struct PassportElementTypeBankStatement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeBankStatement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeBankStatement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeBankStatement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -285,14 +330,15 @@ impl TDPassportElementType for PassportElementTypeBankStatement {}
 
 
 impl PassportElementTypeBankStatement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeBankStatementBuilder {
-    let mut inner = PassportElementTypeBankStatement::default();
-    inner.td_name = "passportElementTypeBankStatement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeBankStatement { td_name: "passportElementTypeBankStatement".to_string(), ..Default::default() };
     RTDPassportElementTypeBankStatementBuilder { inner }
   }
 
+  /// `PassportElementTypeBankStatement` with the fields tdlib requires, the others are set by `PassportElementTypeBankStatement::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -303,6 +349,12 @@ pub struct RTDPassportElementTypeBankStatementBuilder {
 impl RTDPassportElementTypeBankStatementBuilder {
   pub fn build(&self) -> PassportElementTypeBankStatement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeBankStatement> for PassportElementTypeBankStatement {
@@ -313,6 +365,10 @@ impl AsRef<PassportElementTypeBankStatement> for RTDPassportElementTypeBankState
   fn as_ref(&self) -> &PassportElementTypeBankStatement { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeBankStatement {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -327,13 +383,17 @@ pub struct PassportElementTypeDriverLicense {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeDriverLicense {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeDriverLicense" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeDriverLicense" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -343,14 +403,15 @@ impl TDPassportElementType for PassportElementTypeDriverLicense {}
 
 
 impl PassportElementTypeDriverLicense {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeDriverLicenseBuilder {
-    let mut inner = PassportElementTypeDriverLicense::default();
-    inner.td_name = "passportElementTypeDriverLicense".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeDriverLicense { td_name: "passportElementTypeDriverLicense".to_string(), ..Default::default() };
     RTDPassportElementTypeDriverLicenseBuilder { inner }
   }
 
+  /// `PassportElementTypeDriverLicense` with the fields tdlib requires, the others are set by `PassportElementTypeDriverLicense::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -361,6 +422,12 @@ pub struct RTDPassportElementTypeDriverLicenseBuilder {
 impl RTDPassportElementTypeDriverLicenseBuilder {
   pub fn build(&self) -> PassportElementTypeDriverLicense { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeDriverLicense> for PassportElementTypeDriverLicense {
@@ -371,6 +438,10 @@ impl AsRef<PassportElementTypeDriverLicense> for RTDPassportElementTypeDriverLic
   fn as_ref(&self) -> &PassportElementTypeDriverLicense { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeDriverLicense {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -385,13 +456,17 @@ pub struct PassportElementTypeEmailAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeEmailAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeEmailAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeEmailAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -401,14 +476,15 @@ impl TDPassportElementType for PassportElementTypeEmailAddress {}
 
 
 impl PassportElementTypeEmailAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeEmailAddressBuilder {
-    let mut inner = PassportElementTypeEmailAddress::default();
-    inner.td_name = "passportElementTypeEmailAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeEmailAddress { td_name: "passportElementTypeEmailAddress".to_string(), ..Default::default() };
     RTDPassportElementTypeEmailAddressBuilder { inner }
   }
 
+  /// `PassportElementTypeEmailAddress` with the fields tdlib requires, the others are set by `PassportElementTypeEmailAddress::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -419,6 +495,12 @@ pub struct RTDPassportElementTypeEmailAddressBuilder {
 impl RTDPassportElementTypeEmailAddressBuilder {
   pub fn build(&self) -> PassportElementTypeEmailAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeEmailAddress> for PassportElementTypeEmailAddress {
@@ -429,6 +511,10 @@ impl AsRef<PassportElementTypeEmailAddress> for RTDPassportElementTypeEmailAddre
   fn as_ref(&self) -> &PassportElementTypeEmailAddress { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeEmailAddress {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -443,13 +529,17 @@ pub struct PassportElementTypeIdentityCard {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeIdentityCard {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeIdentityCard" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeIdentityCard" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -459,14 +549,15 @@ impl TDPassportElementType for PassportElementTypeIdentityCard {}
 
 
 impl PassportElementTypeIdentityCard {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeIdentityCardBuilder {
-    let mut inner = PassportElementTypeIdentityCard::default();
-    inner.td_name = "passportElementTypeIdentityCard".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeIdentityCard { td_name: "passportElementTypeIdentityCard".to_string(), ..Default::default() };
     RTDPassportElementTypeIdentityCardBuilder { inner }
   }
 
+  /// `PassportElementTypeIdentityCard` with the fields tdlib requires, the others are set by `PassportElementTypeIdentityCard::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -477,6 +568,12 @@ pub struct RTDPassportElementTypeIdentityCardBuilder {
 impl RTDPassportElementTypeIdentityCardBuilder {
   pub fn build(&self) -> PassportElementTypeIdentityCard { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeIdentityCard> for PassportElementTypeIdentityCard {
@@ -487,6 +584,10 @@ impl AsRef<PassportElementTypeIdentityCard> for RTDPassportElementTypeIdentityCa
   fn as_ref(&self) -> &PassportElementTypeIdentityCard { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeIdentityCard {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -501,13 +602,17 @@ pub struct PassportElementTypeInternalPassport {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeInternalPassport {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeInternalPassport" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeInternalPassport" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -517,14 +622,15 @@ impl TDPassportElementType for PassportElementTypeInternalPassport {}
 
 
 impl PassportElementTypeInternalPassport {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeInternalPassportBuilder {
-    let mut inner = PassportElementTypeInternalPassport::default();
-    inner.td_name = "passportElementTypeInternalPassport".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeInternalPassport { td_name: "passportElementTypeInternalPassport".to_string(), ..Default::default() };
     RTDPassportElementTypeInternalPassportBuilder { inner }
   }
 
+  /// `PassportElementTypeInternalPassport` with the fields tdlib requires, the others are set by `PassportElementTypeInternalPassport::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -535,6 +641,12 @@ pub struct RTDPassportElementTypeInternalPassportBuilder {
 impl RTDPassportElementTypeInternalPassportBuilder {
   pub fn build(&self) -> PassportElementTypeInternalPassport { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeInternalPassport> for PassportElementTypeInternalPassport {
@@ -545,6 +657,10 @@ impl AsRef<PassportElementTypeInternalPassport> for RTDPassportElementTypeIntern
   fn as_ref(&self) -> &PassportElementTypeInternalPassport { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeInternalPassport {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -559,13 +675,17 @@ pub struct PassportElementTypePassport {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypePassport {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePassport" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypePassport" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -575,14 +695,15 @@ impl TDPassportElementType for PassportElementTypePassport {}
 
 
 impl PassportElementTypePassport {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypePassportBuilder {
-    let mut inner = PassportElementTypePassport::default();
-    inner.td_name = "passportElementTypePassport".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypePassport { td_name: "passportElementTypePassport".to_string(), ..Default::default() };
     RTDPassportElementTypePassportBuilder { inner }
   }
 
+  /// `PassportElementTypePassport` with the fields tdlib requires, the others are set by `PassportElementTypePassport::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -593,6 +714,12 @@ pub struct RTDPassportElementTypePassportBuilder {
 impl RTDPassportElementTypePassportBuilder {
   pub fn build(&self) -> PassportElementTypePassport { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePassport> for PassportElementTypePassport {
@@ -603,6 +730,10 @@ impl AsRef<PassportElementTypePassport> for RTDPassportElementTypePassportBuilde
   fn as_ref(&self) -> &PassportElementTypePassport { &self.inner }
 }
 
+impl PartialEq for PassportElementTypePassport {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -617,13 +748,17 @@ pub struct PassportElementTypePassportRegistration {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypePassportRegistration {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePassportRegistration" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypePassportRegistration" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -633,14 +768,15 @@ impl TDPassportElementType for PassportElementTypePassportRegistration {}
 
 
 impl PassportElementTypePassportRegistration {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypePassportRegistrationBuilder {
-    let mut inner = PassportElementTypePassportRegistration::default();
-    inner.td_name = "passportElementTypePassportRegistration".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypePassportRegistration { td_name: "passportElementTypePassportRegistration".to_string(), ..Default::default() };
     RTDPassportElementTypePassportRegistrationBuilder { inner }
   }
 
+  /// `PassportElementTypePassportRegistration` with the fields tdlib requires, the others are set by `PassportElementTypePassportRegistration::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -651,6 +787,12 @@ pub struct RTDPassportElementTypePassportRegistrationBuilder {
 impl RTDPassportElementTypePassportRegistrationBuilder {
   pub fn build(&self) -> PassportElementTypePassportRegistration { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePassportRegistration> for PassportElementTypePassportRegistration {
@@ -661,6 +803,10 @@ impl AsRef<PassportElementTypePassportRegistration> for RTDPassportElementTypePa
   fn as_ref(&self) -> &PassportElementTypePassportRegistration { &self.inner }
 }
 
+impl PartialEq for PassportElementTypePassportRegistration {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -675,13 +821,17 @@ pub struct PassportElementTypePersonalDetails {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypePersonalDetails {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePersonalDetails" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypePersonalDetails" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -691,14 +841,15 @@ impl TDPassportElementType for PassportElementTypePersonalDetails {}
 
 
 impl PassportElementTypePersonalDetails {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypePersonalDetailsBuilder {
-    let mut inner = PassportElementTypePersonalDetails::default();
-    inner.td_name = "passportElementTypePersonalDetails".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypePersonalDetails { td_name: "passportElementTypePersonalDetails".to_string(), ..Default::default() };
     RTDPassportElementTypePersonalDetailsBuilder { inner }
   }
 
+  /// `PassportElementTypePersonalDetails` with the fields tdlib requires, the others are set by `PassportElementTypePersonalDetails::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -709,6 +860,12 @@ pub struct RTDPassportElementTypePersonalDetailsBuilder {
 impl RTDPassportElementTypePersonalDetailsBuilder {
   pub fn build(&self) -> PassportElementTypePersonalDetails { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePersonalDetails> for PassportElementTypePersonalDetails {
@@ -719,6 +876,10 @@ impl AsRef<PassportElementTypePersonalDetails> for RTDPassportElementTypePersona
   fn as_ref(&self) -> &PassportElementTypePersonalDetails { &self.inner }
 }
 
+impl PartialEq for PassportElementTypePersonalDetails {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -733,13 +894,17 @@ pub struct PassportElementTypePhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypePhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypePhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypePhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -749,14 +914,15 @@ impl TDPassportElementType for PassportElementTypePhoneNumber {}
 
 
 impl PassportElementTypePhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypePhoneNumberBuilder {
-    let mut inner = PassportElementTypePhoneNumber::default();
-    inner.td_name = "passportElementTypePhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypePhoneNumber { td_name: "passportElementTypePhoneNumber".to_string(), ..Default::default() };
     RTDPassportElementTypePhoneNumberBuilder { inner }
   }
 
+  /// `PassportElementTypePhoneNumber` with the fields tdlib requires, the others are set by `PassportElementTypePhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -767,6 +933,12 @@ pub struct RTDPassportElementTypePhoneNumberBuilder {
 impl RTDPassportElementTypePhoneNumberBuilder {
   pub fn build(&self) -> PassportElementTypePhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypePhoneNumber> for PassportElementTypePhoneNumber {
@@ -777,6 +949,10 @@ impl AsRef<PassportElementTypePhoneNumber> for RTDPassportElementTypePhoneNumber
   fn as_ref(&self) -> &PassportElementTypePhoneNumber { &self.inner }
 }
 
+impl PartialEq for PassportElementTypePhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -791,13 +967,17 @@ pub struct PassportElementTypeRentalAgreement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeRentalAgreement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeRentalAgreement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeRentalAgreement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -807,14 +987,15 @@ impl TDPassportElementType for PassportElementTypeRentalAgreement {}
 
 
 impl PassportElementTypeRentalAgreement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeRentalAgreementBuilder {
-    let mut inner = PassportElementTypeRentalAgreement::default();
-    inner.td_name = "passportElementTypeRentalAgreement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeRentalAgreement { td_name: "passportElementTypeRentalAgreement".to_string(), ..Default::default() };
     RTDPassportElementTypeRentalAgreementBuilder { inner }
   }
 
+  /// `PassportElementTypeRentalAgreement` with the fields tdlib requires, the others are set by `PassportElementTypeRentalAgreement::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -825,6 +1006,12 @@ pub struct RTDPassportElementTypeRentalAgreementBuilder {
 impl RTDPassportElementTypeRentalAgreementBuilder {
   pub fn build(&self) -> PassportElementTypeRentalAgreement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeRentalAgreement> for PassportElementTypeRentalAgreement {
@@ -835,6 +1022,10 @@ impl AsRef<PassportElementTypeRentalAgreement> for RTDPassportElementTypeRentalA
   fn as_ref(&self) -> &PassportElementTypeRentalAgreement { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeRentalAgreement {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -849,13 +1040,17 @@ pub struct PassportElementTypeTemporaryRegistration {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeTemporaryRegistration {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeTemporaryRegistration" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeTemporaryRegistration" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -865,14 +1060,15 @@ impl TDPassportElementType for PassportElementTypeTemporaryRegistration {}
 
 
 impl PassportElementTypeTemporaryRegistration {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeTemporaryRegistrationBuilder {
-    let mut inner = PassportElementTypeTemporaryRegistration::default();
-    inner.td_name = "passportElementTypeTemporaryRegistration".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeTemporaryRegistration { td_name: "passportElementTypeTemporaryRegistration".to_string(), ..Default::default() };
     RTDPassportElementTypeTemporaryRegistrationBuilder { inner }
   }
 
+  /// `PassportElementTypeTemporaryRegistration` with the fields tdlib requires, the others are set by `PassportElementTypeTemporaryRegistration::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -883,6 +1079,12 @@ pub struct RTDPassportElementTypeTemporaryRegistrationBuilder {
 impl RTDPassportElementTypeTemporaryRegistrationBuilder {
   pub fn build(&self) -> PassportElementTypeTemporaryRegistration { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeTemporaryRegistration> for PassportElementTypeTemporaryRegistration {
@@ -893,6 +1095,10 @@ impl AsRef<PassportElementTypeTemporaryRegistration> for RTDPassportElementTypeT
   fn as_ref(&self) -> &PassportElementTypeTemporaryRegistration { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeTemporaryRegistration {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -907,13 +1113,17 @@ pub struct PassportElementTypeUtilityBill {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PassportElementTypeUtilityBill {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementTypeUtilityBill" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementTypeUtilityBill" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -923,14 +1133,15 @@ impl TDPassportElementType for PassportElementTypeUtilityBill {}
 
 
 impl PassportElementTypeUtilityBill {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementTypeUtilityBillBuilder {
-    let mut inner = PassportElementTypeUtilityBill::default();
-    inner.td_name = "passportElementTypeUtilityBill".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementTypeUtilityBill { td_name: "passportElementTypeUtilityBill".to_string(), ..Default::default() };
     RTDPassportElementTypeUtilityBillBuilder { inner }
   }
 
+  /// `PassportElementTypeUtilityBill` with the fields tdlib requires, the others are set by `PassportElementTypeUtilityBill::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -941,6 +1152,12 @@ pub struct RTDPassportElementTypeUtilityBillBuilder {
 impl RTDPassportElementTypeUtilityBillBuilder {
   pub fn build(&self) -> PassportElementTypeUtilityBill { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PassportElementTypeUtilityBill> for PassportElementTypeUtilityBill {
@@ -951,5 +1168,9 @@ impl AsRef<PassportElementTypeUtilityBill> for RTDPassportElementTypeUtilityBill
   fn as_ref(&self) -> &PassportElementTypeUtilityBill { &self.inner }
 }
 
+impl PartialEq for PassportElementTypeUtilityBill {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
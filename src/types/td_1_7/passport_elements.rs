@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct PassportElements {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Telegram Passport elements
   elements: Vec<PassportElement>,
   
 }
 
 impl RObject for PassportElements {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElements" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElements" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PassportElements {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementsBuilder {
-    let mut inner = PassportElements::default();
-    inner.td_name = "passportElements".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElements { td_name: "passportElements".to_string(), ..Default::default() };
     RTDPassportElementsBuilder { inner }
   }
 
+  /// `PassportElements` with the fields tdlib requires, the others are set by `PassportElements::builder()`
+  pub fn new(elements: Vec<PassportElement>) -> Self { Self::builder().elements(elements).build() }
+
   pub fn elements(&self) -> &Vec<PassportElement> { &self.elements }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDPassportElementsBuilder {
 impl RTDPassportElementsBuilder {
   pub fn build(&self) -> PassportElements { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
     self.inner.elements = elements;
@@ -65,5 +75,11 @@ impl AsRef<PassportElements> for RTDPassportElementsBuilder {
   fn as_ref(&self) -> &PassportElements { &self.inner }
 }
 
+impl PartialEq for PassportElements {
+  fn eq(&self, other: &Self) -> bool {
+    self.elements == other.elements
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PassportElementsWithErrors {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Telegram Passport elements
   elements: Vec<PassportElement>,
   /// Errors in the elements that are already available
@@ -23,22 +25,24 @@ pub struct PassportElementsWithErrors {
 }
 
 impl RObject for PassportElementsWithErrors {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportElementsWithErrors" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportElementsWithErrors" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PassportElementsWithErrors {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportElementsWithErrorsBuilder {
-    let mut inner = PassportElementsWithErrors::default();
-    inner.td_name = "passportElementsWithErrors".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportElementsWithErrors { td_name: "passportElementsWithErrors".to_string(), ..Default::default() };
     RTDPassportElementsWithErrorsBuilder { inner }
   }
 
+  /// `PassportElementsWithErrors` with the fields tdlib requires, the others are set by `PassportElementsWithErrors::builder()`
+  pub fn new(elements: Vec<PassportElement>, errors: Vec<PassportElementError>) -> Self { Self::builder().elements(elements).errors(errors).build() }
+
   pub fn elements(&self) -> &Vec<PassportElement> { &self.elements }
 
   pub fn errors(&self) -> &Vec<PassportElementError> { &self.errors }
@@ -53,6 +57,12 @@ pub struct RTDPassportElementsWithErrorsBuilder {
 impl RTDPassportElementsWithErrorsBuilder {
   pub fn build(&self) -> PassportElementsWithErrors { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
     self.inner.elements = elements;
@@ -75,5 +85,12 @@ impl AsRef<PassportElementsWithErrors> for RTDPassportElementsWithErrorsBuilder
   fn as_ref(&self) -> &PassportElementsWithErrors { &self.inner }
 }
 
+impl PartialEq for PassportElementsWithErrors {
+  fn eq(&self, other: &Self) -> bool {
+    self.elements == other.elements
+      && self.errors == other.errors
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct PassportRequiredElement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of Telegram Passport elements any of which is enough to provide
   suitable_elements: Vec<PassportSuitableElement>,
   
 }
 
 impl RObject for PassportRequiredElement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportRequiredElement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportRequiredElement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PassportRequiredElement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportRequiredElementBuilder {
-    let mut inner = PassportRequiredElement::default();
-    inner.td_name = "passportRequiredElement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportRequiredElement { td_name: "passportRequiredElement".to_string(), ..Default::default() };
     RTDPassportRequiredElementBuilder { inner }
   }
 
+  /// `PassportRequiredElement` with the fields tdlib requires, the others are set by `PassportRequiredElement::builder()`
+  pub fn new(suitable_elements: Vec<PassportSuitableElement>) -> Self { Self::builder().suitable_elements(suitable_elements).build() }
+
   pub fn suitable_elements(&self) -> &Vec<PassportSuitableElement> { &self.suitable_elements }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDPassportRequiredElementBuilder {
 impl RTDPassportRequiredElementBuilder {
   pub fn build(&self) -> PassportRequiredElement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn suitable_elements(&mut self, suitable_elements: Vec<PassportSuitableElement>) -> &mut Self {
     self.inner.suitable_elements = suitable_elements;
@@ -65,5 +75,11 @@ impl AsRef<PassportRequiredElement> for RTDPassportRequiredElementBuilder {
   fn as_ref(&self) -> &PassportRequiredElement { &self.inner }
 }
 
+impl PartialEq for PassportRequiredElement {
+  fn eq(&self, other: &Self) -> bool {
+    self.suitable_elements == other.suitable_elements
+  }
+}
+
 
 
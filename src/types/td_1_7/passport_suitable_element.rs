@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PassportSuitableElement {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Type of the element
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: PassportElementType,
   /// True, if a selfie is required with the identity document
@@ -27,22 +29,36 @@ pub struct PassportSuitableElement {
 }
 
 impl RObject for PassportSuitableElement {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passportSuitableElement" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passportSuitableElement" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PassportSuitableElement {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPassportSuitableElementBuilder {
-    let mut inner = PassportSuitableElement::default();
-    inner.td_name = "passportSuitableElement".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PassportSuitableElement { td_name: "passportSuitableElement".to_string(), ..Default::default() };
     RTDPassportSuitableElementBuilder { inner }
   }
 
+  /// `PassportSuitableElement` with the fields tdlib requires, the others are set by `PassportSuitableElement::builder()`
+  pub fn new(
+    type_: impl AsRef<PassportElementType>,
+    is_selfie_required: bool,
+    is_translation_required: bool,
+    is_native_name_required: bool,
+  ) -> Self {
+    Self::builder()
+      .type_(type_)
+      .is_selfie_required(is_selfie_required)
+      .is_translation_required(is_translation_required)
+      .is_native_name_required(is_native_name_required)
+      .build()
+  }
+
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
 
   pub fn is_selfie_required(&self) -> bool { self.is_selfie_required }
@@ -61,6 +77,12 @@ pub struct RTDPassportSuitableElementBuilder {
 impl RTDPassportSuitableElementBuilder {
   pub fn build(&self) -> PassportSuitableElement { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -95,5 +117,14 @@ impl AsRef<PassportSuitableElement> for RTDPassportSuitableElementBuilder {
   fn as_ref(&self) -> &PassportSuitableElement { &self.inner }
 }
 
+impl PartialEq for PassportSuitableElement {
+  fn eq(&self, other: &Self) -> bool {
+    self.type_ == other.type_
+      && self.is_selfie_required == other.is_selfie_required
+      && self.is_translation_required == other.is_translation_required
+      && self.is_native_name_required == other.is_native_name_required
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PasswordState {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if a 2-step verification password is set
   has_password: bool,
   /// Hint for the password; may be empty
@@ -31,22 +33,38 @@ pub struct PasswordState {
 }
 
 impl RObject for PasswordState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "passwordState" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "passwordState" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PasswordState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPasswordStateBuilder {
-    let mut inner = PasswordState::default();
-    inner.td_name = "passwordState".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PasswordState { td_name: "passwordState".to_string(), ..Default::default() };
     RTDPasswordStateBuilder { inner }
   }
 
+  /// `PasswordState` with the fields tdlib requires, the others are set by `PasswordState::builder()`
+  pub fn new(
+    has_password: bool,
+    password_hint: impl AsRef<str>,
+    has_recovery_email_address: bool,
+    has_passport_data: bool,
+    pending_reset_date: i64,
+  ) -> Self {
+    Self::builder()
+      .has_password(has_password)
+      .password_hint(password_hint)
+      .has_recovery_email_address(has_recovery_email_address)
+      .has_passport_data(has_passport_data)
+      .pending_reset_date(pending_reset_date)
+      .build()
+  }
+
   pub fn has_password(&self) -> bool { self.has_password }
 
   pub fn password_hint(&self) -> &String { &self.password_hint }
@@ -69,6 +87,12 @@ pub struct RTDPasswordStateBuilder {
 impl RTDPasswordStateBuilder {
   pub fn build(&self) -> PasswordState { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn has_password(&mut self, has_password: bool) -> &mut Self {
     self.inner.has_password = has_password;
@@ -115,5 +139,16 @@ impl AsRef<PasswordState> for RTDPasswordStateBuilder {
   fn as_ref(&self) -> &PasswordState { &self.inner }
 }
 
+impl PartialEq for PasswordState {
+  fn eq(&self, other: &Self) -> bool {
+    self.has_password == other.has_password
+      && self.password_hint == other.password_hint
+      && self.has_recovery_email_address == other.has_recovery_email_address
+      && self.has_passport_data == other.has_passport_data
+      && self.recovery_email_address_code_info == other.recovery_email_address_code_info
+      && self.pending_reset_date == other.pending_reset_date
+  }
+}
+
 
 
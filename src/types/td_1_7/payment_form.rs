@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,17 +13,20 @@ pub struct PaymentForm {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The payment form identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Full information of the invoice
   invoice: Invoice,
   /// Payment form URL
   url: String,
   /// User identifier of the seller bot
-  seller_bot_user_id: i64,
+  seller_bot_user_id: UserId,
   /// User identifier of the payment provider bot
-  payments_provider_user_id: i64,
+  payments_provider_user_id: UserId,
   /// Information about the payment provider, if available, to support it natively without the need for opening the URL; may be null
   payments_provider: Option<PaymentsProviderStripe>,
   /// Saved server-side order information; may be null
@@ -39,31 +41,51 @@ pub struct PaymentForm {
 }
 
 impl RObject for PaymentForm {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentForm" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "paymentForm" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PaymentForm {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPaymentFormBuilder {
-    let mut inner = PaymentForm::default();
-    inner.td_name = "paymentForm".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PaymentForm { td_name: "paymentForm".to_string(), ..Default::default() };
     RTDPaymentFormBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `PaymentForm` with the fields tdlib requires, the others are set by `PaymentForm::builder()`
+  pub fn new(
+    id: i64,
+    invoice: impl AsRef<Invoice>,
+    url: impl AsRef<str>,
+    seller_bot_user_id: UserId,
+    payments_provider_user_id: UserId,
+    can_save_credentials: bool,
+    need_password: bool,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .invoice(invoice)
+      .url(url)
+      .seller_bot_user_id(seller_bot_user_id)
+      .payments_provider_user_id(payments_provider_user_id)
+      .can_save_credentials(can_save_credentials)
+      .need_password(need_password)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn invoice(&self) -> &Invoice { &self.invoice }
 
   pub fn url(&self) -> &String { &self.url }
 
-  pub fn seller_bot_user_id(&self) -> i64 { self.seller_bot_user_id }
+  pub fn seller_bot_user_id(&self) -> UserId { self.seller_bot_user_id }
 
-  pub fn payments_provider_user_id(&self) -> i64 { self.payments_provider_user_id }
+  pub fn payments_provider_user_id(&self) -> UserId { self.payments_provider_user_id }
 
   pub fn payments_provider(&self) -> &Option<PaymentsProviderStripe> { &self.payments_provider }
 
@@ -85,8 +107,14 @@ pub struct RTDPaymentFormBuilder {
 impl RTDPaymentFormBuilder {
   pub fn build(&self) -> PaymentForm { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -104,13 +132,13 @@ impl RTDPaymentFormBuilder {
   }
 
    
-  pub fn seller_bot_user_id(&mut self, seller_bot_user_id: i64) -> &mut Self {
+  pub fn seller_bot_user_id(&mut self, seller_bot_user_id: UserId) -> &mut Self {
     self.inner.seller_bot_user_id = seller_bot_user_id;
     self
   }
 
    
-  pub fn payments_provider_user_id(&mut self, payments_provider_user_id: i64) -> &mut Self {
+  pub fn payments_provider_user_id(&mut self, payments_provider_user_id: UserId) -> &mut Self {
     self.inner.payments_provider_user_id = payments_provider_user_id;
     self
   }
@@ -155,5 +183,20 @@ impl AsRef<PaymentForm> for RTDPaymentFormBuilder {
   fn as_ref(&self) -> &PaymentForm { &self.inner }
 }
 
+impl PartialEq for PaymentForm {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.invoice == other.invoice
+      && self.url == other.url
+      && self.seller_bot_user_id == other.seller_bot_user_id
+      && self.payments_provider_user_id == other.payments_provider_user_id
+      && self.payments_provider == other.payments_provider
+      && self.saved_order_info == other.saved_order_info
+      && self.saved_credentials == other.saved_credentials
+      && self.can_save_credentials == other.can_save_credentials
+      && self.need_password == other.need_password
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PaymentFormTheme {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A color of the payment form background in the RGB24 format
   background_color: i64,
   /// A color of text in the RGB24 format
@@ -31,22 +33,40 @@ pub struct PaymentFormTheme {
 }
 
 impl RObject for PaymentFormTheme {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentFormTheme" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "paymentFormTheme" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PaymentFormTheme {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPaymentFormThemeBuilder {
-    let mut inner = PaymentFormTheme::default();
-    inner.td_name = "paymentFormTheme".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PaymentFormTheme { td_name: "paymentFormTheme".to_string(), ..Default::default() };
     RTDPaymentFormThemeBuilder { inner }
   }
 
+  /// `PaymentFormTheme` with the fields tdlib requires, the others are set by `PaymentFormTheme::builder()`
+  pub fn new(
+    background_color: i64,
+    text_color: i64,
+    hint_color: i64,
+    link_color: i64,
+    button_color: i64,
+    button_text_color: i64,
+  ) -> Self {
+    Self::builder()
+      .background_color(background_color)
+      .text_color(text_color)
+      .hint_color(hint_color)
+      .link_color(link_color)
+      .button_color(button_color)
+      .button_text_color(button_text_color)
+      .build()
+  }
+
   pub fn background_color(&self) -> i64 { self.background_color }
 
   pub fn text_color(&self) -> i64 { self.text_color }
@@ -69,6 +89,12 @@ pub struct RTDPaymentFormThemeBuilder {
 impl RTDPaymentFormThemeBuilder {
   pub fn build(&self) -> PaymentFormTheme { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn background_color(&mut self, background_color: i64) -> &mut Self {
     self.inner.background_color = background_color;
@@ -115,5 +141,16 @@ impl AsRef<PaymentFormTheme> for RTDPaymentFormThemeBuilder {
   fn as_ref(&self) -> &PaymentFormTheme { &self.inner }
 }
 
+impl PartialEq for PaymentFormTheme {
+  fn eq(&self, other: &Self) -> bool {
+    self.background_color == other.background_color
+      && self.text_color == other.text_color
+      && self.hint_color == other.hint_color
+      && self.link_color == other.link_color
+      && self.button_color == other.button_color
+      && self.button_text_color == other.button_text_color
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,19 +13,22 @@ pub struct PaymentReceipt {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Product title
   title: String,
-  /// Contains information about a successful payment
+  /// Product description
   description: String,
   /// Product photo; may be null
   photo: Option<Photo>,
   /// Point in time (Unix timestamp) when the payment was made
   date: i64,
   /// User identifier of the seller bot
-  seller_bot_user_id: i64,
+  seller_bot_user_id: UserId,
   /// User identifier of the payment provider bot
-  payments_provider_user_id: i64,
+  payments_provider_user_id: UserId,
   /// Information about the invoice
   invoice: Invoice,
   /// Order information; may be null
@@ -41,22 +43,45 @@ pub struct PaymentReceipt {
 }
 
 impl RObject for PaymentReceipt {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentReceipt" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "paymentReceipt" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PaymentReceipt {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPaymentReceiptBuilder {
-    let mut inner = PaymentReceipt::default();
-    inner.td_name = "paymentReceipt".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PaymentReceipt { td_name: "paymentReceipt".to_string(), ..Default::default() };
     RTDPaymentReceiptBuilder { inner }
   }
 
+  /// `PaymentReceipt` with the fields tdlib requires, the others are set by `PaymentReceipt::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    title: impl AsRef<str>,
+    description: impl AsRef<str>,
+    date: i64,
+    seller_bot_user_id: UserId,
+    payments_provider_user_id: UserId,
+    invoice: impl AsRef<Invoice>,
+    credentials_title: impl AsRef<str>,
+    tip_amount: i64,
+  ) -> Self {
+    Self::builder()
+      .title(title)
+      .description(description)
+      .date(date)
+      .seller_bot_user_id(seller_bot_user_id)
+      .payments_provider_user_id(payments_provider_user_id)
+      .invoice(invoice)
+      .credentials_title(credentials_title)
+      .tip_amount(tip_amount)
+      .build()
+  }
+
   pub fn title(&self) -> &String { &self.title }
 
   pub fn description(&self) -> &String { &self.description }
@@ -65,9 +90,9 @@ impl PaymentReceipt {
 
   pub fn date(&self) -> i64 { self.date }
 
-  pub fn seller_bot_user_id(&self) -> i64 { self.seller_bot_user_id }
+  pub fn seller_bot_user_id(&self) -> UserId { self.seller_bot_user_id }
 
-  pub fn payments_provider_user_id(&self) -> i64 { self.payments_provider_user_id }
+  pub fn payments_provider_user_id(&self) -> UserId { self.payments_provider_user_id }
 
   pub fn invoice(&self) -> &Invoice { &self.invoice }
 
@@ -89,6 +114,12 @@ pub struct RTDPaymentReceiptBuilder {
 impl RTDPaymentReceiptBuilder {
   pub fn build(&self) -> PaymentReceipt { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -114,13 +145,13 @@ impl RTDPaymentReceiptBuilder {
   }
 
    
-  pub fn seller_bot_user_id(&mut self, seller_bot_user_id: i64) -> &mut Self {
+  pub fn seller_bot_user_id(&mut self, seller_bot_user_id: UserId) -> &mut Self {
     self.inner.seller_bot_user_id = seller_bot_user_id;
     self
   }
 
    
-  pub fn payments_provider_user_id(&mut self, payments_provider_user_id: i64) -> &mut Self {
+  pub fn payments_provider_user_id(&mut self, payments_provider_user_id: UserId) -> &mut Self {
     self.inner.payments_provider_user_id = payments_provider_user_id;
     self
   }
@@ -165,5 +196,21 @@ impl AsRef<PaymentReceipt> for RTDPaymentReceiptBuilder {
   fn as_ref(&self) -> &PaymentReceipt { &self.inner }
 }
 
+impl PartialEq for PaymentReceipt {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.description == other.description
+      && self.photo == other.photo
+      && self.date == other.date
+      && self.seller_bot_user_id == other.seller_bot_user_id
+      && self.payments_provider_user_id == other.payments_provider_user_id
+      && self.invoice == other.invoice
+      && self.order_info == other.order_info
+      && self.shipping_option == other.shipping_option
+      && self.credentials_title == other.credentials_title
+      && self.tip_amount == other.tip_amount
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PaymentResult {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the payment request was successful; otherwise the verification_url will be non-empty
   success: bool,
   /// URL for additional payment credentials verification
@@ -23,22 +25,24 @@ pub struct PaymentResult {
 }
 
 impl RObject for PaymentResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentResult" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "paymentResult" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PaymentResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPaymentResultBuilder {
-    let mut inner = PaymentResult::default();
-    inner.td_name = "paymentResult".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PaymentResult { td_name: "paymentResult".to_string(), ..Default::default() };
     RTDPaymentResultBuilder { inner }
   }
 
+  /// `PaymentResult` with the fields tdlib requires, the others are set by `PaymentResult::builder()`
+  pub fn new(success: bool, verification_url: impl AsRef<str>) -> Self { Self::builder().success(success).verification_url(verification_url).build() }
+
   pub fn success(&self) -> bool { self.success }
 
   pub fn verification_url(&self) -> &String { &self.verification_url }
@@ -53,6 +57,12 @@ pub struct RTDPaymentResultBuilder {
 impl RTDPaymentResultBuilder {
   pub fn build(&self) -> PaymentResult { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn success(&mut self, success: bool) -> &mut Self {
     self.inner.success = success;
@@ -75,5 +85,12 @@ impl AsRef<PaymentResult> for RTDPaymentResultBuilder {
   fn as_ref(&self) -> &PaymentResult { &self.inner }
 }
 
+impl PartialEq for PaymentResult {
+  fn eq(&self, other: &Self) -> bool {
+    self.success == other.success
+      && self.verification_url == other.verification_url
+  }
+}
+
 
 
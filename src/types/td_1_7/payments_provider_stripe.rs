@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PaymentsProviderStripe {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Stripe API publishable key
   publishable_key: String,
   /// True, if the user country must be provided
@@ -27,22 +29,36 @@ pub struct PaymentsProviderStripe {
 }
 
 impl RObject for PaymentsProviderStripe {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "paymentsProviderStripe" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "paymentsProviderStripe" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PaymentsProviderStripe {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPaymentsProviderStripeBuilder {
-    let mut inner = PaymentsProviderStripe::default();
-    inner.td_name = "paymentsProviderStripe".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PaymentsProviderStripe { td_name: "paymentsProviderStripe".to_string(), ..Default::default() };
     RTDPaymentsProviderStripeBuilder { inner }
   }
 
+  /// `PaymentsProviderStripe` with the fields tdlib requires, the others are set by `PaymentsProviderStripe::builder()`
+  pub fn new(
+    publishable_key: impl AsRef<str>,
+    need_country: bool,
+    need_postal_code: bool,
+    need_cardholder_name: bool,
+  ) -> Self {
+    Self::builder()
+      .publishable_key(publishable_key)
+      .need_country(need_country)
+      .need_postal_code(need_postal_code)
+      .need_cardholder_name(need_cardholder_name)
+      .build()
+  }
+
   pub fn publishable_key(&self) -> &String { &self.publishable_key }
 
   pub fn need_country(&self) -> bool { self.need_country }
@@ -61,6 +77,12 @@ pub struct RTDPaymentsProviderStripeBuilder {
 impl RTDPaymentsProviderStripeBuilder {
   pub fn build(&self) -> PaymentsProviderStripe { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn publishable_key<T: AsRef<str>>(&mut self, publishable_key: T) -> &mut Self {
     self.inner.publishable_key = publishable_key.as_ref().to_string();
@@ -95,5 +117,14 @@ impl AsRef<PaymentsProviderStripe> for RTDPaymentsProviderStripeBuilder {
   fn as_ref(&self) -> &PaymentsProviderStripe { &self.inner }
 }
 
+impl PartialEq for PaymentsProviderStripe {
+  fn eq(&self, other: &Self) -> bool {
+    self.publishable_key == other.publishable_key
+      && self.need_country == other.need_country
+      && self.need_postal_code == other.need_postal_code
+      && self.need_cardholder_name == other.need_cardholder_name
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PersonalDetails {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// First name of the user written in English; 1-255 characters
   first_name: String,
   /// Middle name of the user written in English; 0-255 characters
@@ -39,22 +41,49 @@ pub struct PersonalDetails {
 }
 
 impl RObject for PersonalDetails {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "personalDetails" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "personalDetails" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PersonalDetails {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPersonalDetailsBuilder {
-    let mut inner = PersonalDetails::default();
-    inner.td_name = "personalDetails".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PersonalDetails { td_name: "personalDetails".to_string(), ..Default::default() };
     RTDPersonalDetailsBuilder { inner }
   }
 
+  /// `PersonalDetails` with the fields tdlib requires, the others are set by `PersonalDetails::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    first_name: impl AsRef<str>,
+    middle_name: impl AsRef<str>,
+    last_name: impl AsRef<str>,
+    native_first_name: impl AsRef<str>,
+    native_middle_name: impl AsRef<str>,
+    native_last_name: impl AsRef<str>,
+    birthdate: impl AsRef<Date>,
+    gender: impl AsRef<str>,
+    country_code: impl AsRef<str>,
+    residence_country_code: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .first_name(first_name)
+      .middle_name(middle_name)
+      .last_name(last_name)
+      .native_first_name(native_first_name)
+      .native_middle_name(native_middle_name)
+      .native_last_name(native_last_name)
+      .birthdate(birthdate)
+      .gender(gender)
+      .country_code(country_code)
+      .residence_country_code(residence_country_code)
+      .build()
+  }
+
   pub fn first_name(&self) -> &String { &self.first_name }
 
   pub fn middle_name(&self) -> &String { &self.middle_name }
@@ -85,6 +114,12 @@ pub struct RTDPersonalDetailsBuilder {
 impl RTDPersonalDetailsBuilder {
   pub fn build(&self) -> PersonalDetails { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
@@ -155,5 +190,31 @@ impl AsRef<PersonalDetails> for RTDPersonalDetailsBuilder {
   fn as_ref(&self) -> &PersonalDetails { &self.inner }
 }
 
+impl PartialEq for PersonalDetails {
+  fn eq(&self, other: &Self) -> bool {
+    self.first_name == other.first_name
+      && self.middle_name == other.middle_name
+      && self.last_name == other.last_name
+      && self.native_first_name == other.native_first_name
+      && self.native_middle_name == other.native_middle_name
+      && self.native_last_name == other.native_last_name
+      && self.birthdate == other.birthdate
+      && self.gender == other.gender
+      && self.country_code == other.country_code
+      && self.residence_country_code == other.residence_country_code
+  }
+}
+
+impl Validate for PersonalDetails {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("first_name", &self.first_name, 1, 255);
+    checker.length("middle_name", &self.middle_name, 0, 255);
+    checker.length("last_name", &self.last_name, 1, 255);
+    checker.length("native_first_name", &self.native_first_name, 1, 255);
+    checker.length("native_middle_name", &self.native_middle_name, 0, 255);
+    checker.length("native_last_name", &self.native_last_name, 1, 255);
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PersonalDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of files containing the pages of the document
   files: Vec<DatedFile>,
   /// List of files containing a certified English translation of the document
@@ -23,22 +25,24 @@ pub struct PersonalDocument {
 }
 
 impl RObject for PersonalDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "personalDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "personalDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PersonalDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPersonalDocumentBuilder {
-    let mut inner = PersonalDocument::default();
-    inner.td_name = "personalDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PersonalDocument { td_name: "personalDocument".to_string(), ..Default::default() };
     RTDPersonalDocumentBuilder { inner }
   }
 
+  /// `PersonalDocument` with the fields tdlib requires, the others are set by `PersonalDocument::builder()`
+  pub fn new(files: Vec<DatedFile>, translation: Vec<DatedFile>) -> Self { Self::builder().files(files).translation(translation).build() }
+
   pub fn files(&self) -> &Vec<DatedFile> { &self.files }
 
   pub fn translation(&self) -> &Vec<DatedFile> { &self.translation }
@@ -53,6 +57,12 @@ pub struct RTDPersonalDocumentBuilder {
 impl RTDPersonalDocumentBuilder {
   pub fn build(&self) -> PersonalDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn files(&mut self, files: Vec<DatedFile>) -> &mut Self {
     self.inner.files = files;
@@ -75,5 +85,12 @@ impl AsRef<PersonalDocument> for RTDPersonalDocumentBuilder {
   fn as_ref(&self) -> &PersonalDocument { &self.inner }
 }
 
+impl PartialEq for PersonalDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.files == other.files
+      && self.translation == other.translation
+  }
+}
+
 
 
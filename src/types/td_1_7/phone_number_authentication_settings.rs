@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PhoneNumberAuthenticationSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Pass true if the authentication code may be sent via flash call to the specified phone number
   allow_flash_call: bool,
   /// Pass true if the authenticated phone number is used on the current device
@@ -25,22 +27,24 @@ pub struct PhoneNumberAuthenticationSettings {
 }
 
 impl RObject for PhoneNumberAuthenticationSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "phoneNumberAuthenticationSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "phoneNumberAuthenticationSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PhoneNumberAuthenticationSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPhoneNumberAuthenticationSettingsBuilder {
-    let mut inner = PhoneNumberAuthenticationSettings::default();
-    inner.td_name = "phoneNumberAuthenticationSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PhoneNumberAuthenticationSettings { td_name: "phoneNumberAuthenticationSettings".to_string(), ..Default::default() };
     RTDPhoneNumberAuthenticationSettingsBuilder { inner }
   }
 
+  /// `PhoneNumberAuthenticationSettings` with the fields tdlib requires, the others are set by `PhoneNumberAuthenticationSettings::builder()`
+  pub fn new(allow_flash_call: bool, is_current_phone_number: bool, allow_sms_retriever_api: bool) -> Self { Self::builder().allow_flash_call(allow_flash_call).is_current_phone_number(is_current_phone_number).allow_sms_retriever_api(allow_sms_retriever_api).build() }
+
   pub fn allow_flash_call(&self) -> bool { self.allow_flash_call }
 
   pub fn is_current_phone_number(&self) -> bool { self.is_current_phone_number }
@@ -57,6 +61,12 @@ pub struct RTDPhoneNumberAuthenticationSettingsBuilder {
 impl RTDPhoneNumberAuthenticationSettingsBuilder {
   pub fn build(&self) -> PhoneNumberAuthenticationSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn allow_flash_call(&mut self, allow_flash_call: bool) -> &mut Self {
     self.inner.allow_flash_call = allow_flash_call;
@@ -85,5 +95,13 @@ impl AsRef<PhoneNumberAuthenticationSettings> for RTDPhoneNumberAuthenticationSe
   fn as_ref(&self) -> &PhoneNumberAuthenticationSettings { &self.inner }
 }
 
+impl PartialEq for PhoneNumberAuthenticationSettings {
+  fn eq(&self, other: &Self) -> bool {
+    self.allow_flash_call == other.allow_flash_call
+      && self.is_current_phone_number == other.is_current_phone_number
+      && self.allow_sms_retriever_api == other.allow_sms_retriever_api
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PhoneNumberInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Information about the country to which the phone number belongs; may be null
   country: Option<CountryInfo>,
   /// The part of the phone number denoting country calling code or its part
@@ -25,22 +27,24 @@ pub struct PhoneNumberInfo {
 }
 
 impl RObject for PhoneNumberInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "phoneNumberInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "phoneNumberInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PhoneNumberInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPhoneNumberInfoBuilder {
-    let mut inner = PhoneNumberInfo::default();
-    inner.td_name = "phoneNumberInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PhoneNumberInfo { td_name: "phoneNumberInfo".to_string(), ..Default::default() };
     RTDPhoneNumberInfoBuilder { inner }
   }
 
+  /// `PhoneNumberInfo` with the fields tdlib requires, the others are set by `PhoneNumberInfo::builder()`
+  pub fn new(country_calling_code: impl AsRef<str>, formatted_phone_number: impl AsRef<str>) -> Self { Self::builder().country_calling_code(country_calling_code).formatted_phone_number(formatted_phone_number).build() }
+
   pub fn country(&self) -> &Option<CountryInfo> { &self.country }
 
   pub fn country_calling_code(&self) -> &String { &self.country_calling_code }
@@ -57,6 +61,12 @@ pub struct RTDPhoneNumberInfoBuilder {
 impl RTDPhoneNumberInfoBuilder {
   pub fn build(&self) -> PhoneNumberInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn country<T: AsRef<CountryInfo>>(&mut self, country: T) -> &mut Self {
     self.inner.country = Some(country.as_ref().clone());
@@ -85,5 +95,13 @@ impl AsRef<PhoneNumberInfo> for RTDPhoneNumberInfoBuilder {
   fn as_ref(&self) -> &PhoneNumberInfo { &self.inner }
 }
 
+impl PartialEq for PhoneNumberInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.country == other.country
+      && self.country_calling_code == other.country_calling_code
+      && self.formatted_phone_number == other.formatted_phone_number
+  }
+}
+
 
 
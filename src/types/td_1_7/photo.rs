@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Photo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if stickers were added to the photo. The list of corresponding sticker sets can be received using getAttachedStickerSets
   has_stickers: bool,
   /// Photo minithumbnail; may be null
@@ -25,22 +27,24 @@ pub struct Photo {
 }
 
 impl RObject for Photo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "photo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "photo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Photo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPhotoBuilder {
-    let mut inner = Photo::default();
-    inner.td_name = "photo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Photo { td_name: "photo".to_string(), ..Default::default() };
     RTDPhotoBuilder { inner }
   }
 
+  /// `Photo` with the fields tdlib requires, the others are set by `Photo::builder()`
+  pub fn new(has_stickers: bool, sizes: Vec<PhotoSize>) -> Self { Self::builder().has_stickers(has_stickers).sizes(sizes).build() }
+
   pub fn has_stickers(&self) -> bool { self.has_stickers }
 
   pub fn minithumbnail(&self) -> &Option<Minithumbnail> { &self.minithumbnail }
@@ -57,6 +61,12 @@ pub struct RTDPhotoBuilder {
 impl RTDPhotoBuilder {
   pub fn build(&self) -> Photo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn has_stickers(&mut self, has_stickers: bool) -> &mut Self {
     self.inner.has_stickers = has_stickers;
@@ -85,5 +95,13 @@ impl AsRef<Photo> for RTDPhotoBuilder {
   fn as_ref(&self) -> &Photo { &self.inner }
 }
 
+impl PartialEq for Photo {
+  fn eq(&self, other: &Self) -> bool {
+    self.has_stickers == other.has_stickers
+      && self.minithumbnail == other.minithumbnail
+      && self.sizes == other.sizes
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PhotoSize {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Image type (see https://core.telegram.org/constructor/photoSize)
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: String,
   /// Information about the image file
@@ -29,22 +31,38 @@ pub struct PhotoSize {
 }
 
 impl RObject for PhotoSize {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "photoSize" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "photoSize" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PhotoSize {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPhotoSizeBuilder {
-    let mut inner = PhotoSize::default();
-    inner.td_name = "photoSize".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PhotoSize { td_name: "photoSize".to_string(), ..Default::default() };
     RTDPhotoSizeBuilder { inner }
   }
 
+  /// `PhotoSize` with the fields tdlib requires, the others are set by `PhotoSize::builder()`
+  pub fn new(
+    type_: impl AsRef<str>,
+    photo: impl AsRef<File>,
+    width: i64,
+    height: i64,
+    progressive_sizes: Vec<i64>,
+  ) -> Self {
+    Self::builder()
+      .type_(type_)
+      .photo(photo)
+      .width(width)
+      .height(height)
+      .progressive_sizes(progressive_sizes)
+      .build()
+  }
+
   pub fn type_(&self) -> &String { &self.type_ }
 
   pub fn photo(&self) -> &File { &self.photo }
@@ -65,6 +83,12 @@ pub struct RTDPhotoSizeBuilder {
 impl RTDPhotoSizeBuilder {
   pub fn build(&self) -> PhotoSize { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
@@ -105,5 +129,15 @@ impl AsRef<PhotoSize> for RTDPhotoSizeBuilder {
   fn as_ref(&self) -> &PhotoSize { &self.inner }
 }
 
+impl PartialEq for PhotoSize {
+  fn eq(&self, other: &Self) -> bool {
+    self.type_ == other.type_
+      && self.photo == other.photo
+      && self.width == other.width
+      && self.height == other.height
+      && self.progressive_sizes == other.progressive_sizes
+  }
+}
+
 
 
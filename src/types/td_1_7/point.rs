@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Point {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The point's first coordinate
   x: f32,
   /// The point's second coordinate
@@ -23,22 +25,24 @@ pub struct Point {
 }
 
 impl RObject for Point {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "point" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "point" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Point {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPointBuilder {
-    let mut inner = Point::default();
-    inner.td_name = "point".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Point { td_name: "point".to_string(), ..Default::default() };
     RTDPointBuilder { inner }
   }
 
+  /// `Point` with the fields tdlib requires, the others are set by `Point::builder()`
+  pub fn new(x: f32, y: f32) -> Self { Self::builder().x(x).y(y).build() }
+
   pub fn x(&self) -> f32 { self.x }
 
   pub fn y(&self) -> f32 { self.y }
@@ -53,6 +57,12 @@ pub struct RTDPointBuilder {
 impl RTDPointBuilder {
   pub fn build(&self) -> Point { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn x(&mut self, x: f32) -> &mut Self {
     self.inner.x = x;
@@ -75,5 +85,12 @@ impl AsRef<Point> for RTDPointBuilder {
   fn as_ref(&self) -> &Point { &self.inner }
 }
 
+impl PartialEq for Point {
+  fn eq(&self, other: &Self) -> bool {
+    self.x == other.x
+      && self.y == other.y
+  }
+}
+
 
 
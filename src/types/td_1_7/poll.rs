@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct Poll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique poll identifier
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Poll question; 1-300 characters
   question: String,
   /// List of poll answer options
@@ -24,7 +26,7 @@ pub struct Poll {
   /// Total number of voters, participating in the poll
   total_voter_count: i64,
   /// User identifiers of recent voters, if the poll is non-anonymous
-  recent_voter_user_ids: Vec<i64>,
+  recent_voter_user_ids: Vec<UserId>,
   /// True, if the poll is anonymous
   is_anonymous: bool,
   /// Type of the poll
@@ -39,23 +41,50 @@ pub struct Poll {
 }
 
 impl RObject for Poll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "poll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "poll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Poll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPollBuilder {
-    let mut inner = Poll::default();
-    inner.td_name = "poll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Poll { td_name: "poll".to_string(), ..Default::default() };
     RTDPollBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `Poll` with the fields tdlib requires, the others are set by `Poll::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: i64,
+    question: impl AsRef<str>,
+    options: Vec<PollOption>,
+    total_voter_count: i64,
+    recent_voter_user_ids: Vec<UserId>,
+    is_anonymous: bool,
+    type_: impl AsRef<PollType>,
+    open_period: i64,
+    close_date: i64,
+    is_closed: bool,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .question(question)
+      .options(options)
+      .total_voter_count(total_voter_count)
+      .recent_voter_user_ids(recent_voter_user_ids)
+      .is_anonymous(is_anonymous)
+      .type_(type_)
+      .open_period(open_period)
+      .close_date(close_date)
+      .is_closed(is_closed)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn question(&self) -> &String { &self.question }
 
@@ -63,7 +92,7 @@ impl Poll {
 
   pub fn total_voter_count(&self) -> i64 { self.total_voter_count }
 
-  pub fn recent_voter_user_ids(&self) -> &Vec<i64> { &self.recent_voter_user_ids }
+  pub fn recent_voter_user_ids(&self) -> &Vec<UserId> { &self.recent_voter_user_ids }
 
   pub fn is_anonymous(&self) -> bool { self.is_anonymous }
 
@@ -85,8 +114,14 @@ pub struct RTDPollBuilder {
 impl RTDPollBuilder {
   pub fn build(&self) -> Poll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -110,7 +145,7 @@ impl RTDPollBuilder {
   }
 
    
-  pub fn recent_voter_user_ids(&mut self, recent_voter_user_ids: Vec<i64>) -> &mut Self {
+  pub fn recent_voter_user_ids(&mut self, recent_voter_user_ids: Vec<UserId>) -> &mut Self {
     self.inner.recent_voter_user_ids = recent_voter_user_ids;
     self
   }
@@ -155,5 +190,20 @@ impl AsRef<Poll> for RTDPollBuilder {
   fn as_ref(&self) -> &Poll { &self.inner }
 }
 
+impl PartialEq for Poll {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.question == other.question
+      && self.options == other.options
+      && self.total_voter_count == other.total_voter_count
+      && self.recent_voter_user_ids == other.recent_voter_user_ids
+      && self.is_anonymous == other.is_anonymous
+      && self.type_ == other.type_
+      && self.open_period == other.open_period
+      && self.close_date == other.close_date
+      && self.is_closed == other.is_closed
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct PollOption {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Option text; 1-100 characters
   text: String,
   /// Number of voters for this option, available only for closed or voted polls
@@ -29,22 +31,38 @@ pub struct PollOption {
 }
 
 impl RObject for PollOption {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pollOption" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pollOption" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PollOption {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPollOptionBuilder {
-    let mut inner = PollOption::default();
-    inner.td_name = "pollOption".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PollOption { td_name: "pollOption".to_string(), ..Default::default() };
     RTDPollOptionBuilder { inner }
   }
 
+  /// `PollOption` with the fields tdlib requires, the others are set by `PollOption::builder()`
+  pub fn new(
+    text: impl AsRef<str>,
+    voter_count: i64,
+    vote_percentage: i64,
+    is_chosen: bool,
+    is_being_chosen: bool,
+  ) -> Self {
+    Self::builder()
+      .text(text)
+      .voter_count(voter_count)
+      .vote_percentage(vote_percentage)
+      .is_chosen(is_chosen)
+      .is_being_chosen(is_being_chosen)
+      .build()
+  }
+
   pub fn text(&self) -> &String { &self.text }
 
   pub fn voter_count(&self) -> i64 { self.voter_count }
@@ -65,6 +83,12 @@ pub struct RTDPollOptionBuilder {
 impl RTDPollOptionBuilder {
   pub fn build(&self) -> PollOption { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -105,5 +129,15 @@ impl AsRef<PollOption> for RTDPollOptionBuilder {
   fn as_ref(&self) -> &PollOption { &self.inner }
 }
 
+impl PartialEq for PollOption {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.voter_count == other.voter_count
+      && self.vote_percentage == other.vote_percentage
+      && self.is_chosen == other.is_chosen
+      && self.is_being_chosen == other.is_being_chosen
+  }
+}
+
 
 
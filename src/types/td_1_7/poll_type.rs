@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPollType: Debug + RObject {}
 
 /// Describes the type of a poll
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PollType {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum PollType {
   Quiz(PollTypeQuiz),
   /// A regular poll
   Regular(PollTypeRegular),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for PollType {
   fn deserialize<D>(deserializer: D) -> Result<PollType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PollType,
+      PollType, Unknown;
       (pollTypeQuiz, Quiz);
       (pollTypeRegular, Regular);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for PollType {
 }
 
 impl RObject for PollType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PollType::Quiz(t) => t.td_name(),
       PollType::Regular(t) => t.td_name(),
+      PollType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PollType::Quiz(t) => t.extra(),
       PollType::Regular(t) => t.extra(),
+      PollType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PollType::Quiz(t) => t.client_id(),
+      PollType::Regular(t) => t.client_id(),
+      PollType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for PollType {
 }
 
 impl PollType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PollType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PollType::_Default(_)) }
 
-  pub fn is_quiz(&self) -> bool { if let PollType::Quiz(_) = self { true } else { false } }
-  pub fn is_regular(&self) -> bool { if let PollType::Regular(_) = self { true } else { false } }
+  pub fn is_quiz(&self) -> bool { matches!(self, PollType::Quiz(_)) }
+  pub fn is_regular(&self) -> bool { matches!(self, PollType::Regular(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PollType::Unknown(_)) }
 
   pub fn on_quiz<F: FnOnce(&PollTypeQuiz)>(&self, fnc: F) -> &Self { if let PollType::Quiz(t) = self { fnc(t) }; self }
   pub fn on_regular<F: FnOnce(&PollTypeRegular)>(&self, fnc: F) -> &Self { if let PollType::Regular(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PollType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_quiz(&self) -> Option<&PollTypeQuiz> { if let PollType::Quiz(t) = self { return Some(t) } None }
   pub fn as_regular(&self) -> Option<&PollTypeRegular> { if let PollType::Regular(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PollType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -87,6 +102,14 @@ impl AsRef<PollType> for PollType {
   fn as_ref(&self) -> &PollType { self }
 }
 
+impl Validate for PollType {
+  fn check(&self, checker: &mut Checker) {
+    if let PollType::Quiz(value) = self {
+      value.check(checker);
+    }
+  }
+}
+
 
 
 
@@ -101,7 +124,10 @@ pub struct PollTypeQuiz {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// 0-based identifier of the correct answer option; 1 for a yet unanswered poll
   correct_option_id: i64,
   /// Text that is shown when the user chooses an incorrect answer or taps on the lamp icon; 0-200 characters with at most 2 line feeds; empty for a yet unanswered poll
@@ -110,8 +136,9 @@ pub struct PollTypeQuiz {
 }
 
 impl RObject for PollTypeQuiz {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pollTypeQuiz" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pollTypeQuiz" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -121,14 +148,15 @@ impl TDPollType for PollTypeQuiz {}
 
 
 impl PollTypeQuiz {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPollTypeQuizBuilder {
-    let mut inner = PollTypeQuiz::default();
-    inner.td_name = "pollTypeQuiz".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PollTypeQuiz { td_name: "pollTypeQuiz".to_string(), ..Default::default() };
     RTDPollTypeQuizBuilder { inner }
   }
 
+  /// `PollTypeQuiz` with the fields tdlib requires, the others are set by `PollTypeQuiz::builder()`
+  pub fn new(correct_option_id: i64, explanation: impl AsRef<FormattedText>) -> Self { Self::builder().correct_option_id(correct_option_id).explanation(explanation).build() }
+
   pub fn correct_option_id(&self) -> i64 { self.correct_option_id }
 
   pub fn explanation(&self) -> &FormattedText { &self.explanation }
@@ -143,6 +171,12 @@ pub struct RTDPollTypeQuizBuilder {
 impl RTDPollTypeQuizBuilder {
   pub fn build(&self) -> PollTypeQuiz { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn correct_option_id(&mut self, correct_option_id: i64) -> &mut Self {
     self.inner.correct_option_id = correct_option_id;
@@ -165,6 +199,19 @@ impl AsRef<PollTypeQuiz> for RTDPollTypeQuizBuilder {
   fn as_ref(&self) -> &PollTypeQuiz { &self.inner }
 }
 
+impl PartialEq for PollTypeQuiz {
+  fn eq(&self, other: &Self) -> bool {
+    self.correct_option_id == other.correct_option_id
+      && self.explanation == other.explanation
+  }
+}
+
+impl Validate for PollTypeQuiz {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("explanation", self.explanation.text(), 0, 200);
+  }
+}
+
 
 
 
@@ -179,15 +226,19 @@ pub struct PollTypeRegular {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if multiple answer options can be chosen simultaneously
   allow_multiple_answers: bool,
   
 }
 
 impl RObject for PollTypeRegular {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pollTypeRegular" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pollTypeRegular" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -197,14 +248,15 @@ impl TDPollType for PollTypeRegular {}
 
 
 impl PollTypeRegular {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPollTypeRegularBuilder {
-    let mut inner = PollTypeRegular::default();
-    inner.td_name = "pollTypeRegular".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PollTypeRegular { td_name: "pollTypeRegular".to_string(), ..Default::default() };
     RTDPollTypeRegularBuilder { inner }
   }
 
+  /// `PollTypeRegular` with the fields tdlib requires, the others are set by `PollTypeRegular::builder()`
+  pub fn new(allow_multiple_answers: bool) -> Self { Self::builder().allow_multiple_answers(allow_multiple_answers).build() }
+
   pub fn allow_multiple_answers(&self) -> bool { self.allow_multiple_answers }
 
 }
@@ -217,6 +269,12 @@ pub struct RTDPollTypeRegularBuilder {
 impl RTDPollTypeRegularBuilder {
   pub fn build(&self) -> PollTypeRegular { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn allow_multiple_answers(&mut self, allow_multiple_answers: bool) -> &mut Self {
     self.inner.allow_multiple_answers = allow_multiple_answers;
@@ -233,5 +291,11 @@ impl AsRef<PollTypeRegular> for RTDPollTypeRegularBuilder {
   fn as_ref(&self) -> &PollTypeRegular { &self.inner }
 }
 
+impl PartialEq for PollTypeRegular {
+  fn eq(&self, other: &Self) -> bool {
+    self.allow_multiple_answers == other.allow_multiple_answers
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct ProfilePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Photo identifier; 0 for an empty photo. Can be used to find a photo in a list of user profile photos
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// A small (160x160) user profile photo. The file can be downloaded only before the photo is changed
   small: File,
   /// A big (640x640) user profile photo. The file can be downloaded only before the photo is changed
@@ -29,23 +31,25 @@ pub struct ProfilePhoto {
 }
 
 impl RObject for ProfilePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "profilePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "profilePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ProfilePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDProfilePhotoBuilder {
-    let mut inner = ProfilePhoto::default();
-    inner.td_name = "profilePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ProfilePhoto { td_name: "profilePhoto".to_string(), ..Default::default() };
     RTDProfilePhotoBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `ProfilePhoto` with the fields tdlib requires, the others are set by `ProfilePhoto::builder()`
+  pub fn new(id: i64, small: impl AsRef<File>, big: impl AsRef<File>) -> Self { Self::builder().id(id).small(small).big(big).build() }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn small(&self) -> &File { &self.small }
 
@@ -65,8 +69,14 @@ pub struct RTDProfilePhotoBuilder {
 impl RTDProfilePhotoBuilder {
   pub fn build(&self) -> ProfilePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -105,5 +115,15 @@ impl AsRef<ProfilePhoto> for RTDProfilePhotoBuilder {
   fn as_ref(&self) -> &ProfilePhoto { &self.inner }
 }
 
+impl PartialEq for ProfilePhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.small == other.small
+      && self.big == other.big
+      && self.minithumbnail == other.minithumbnail
+      && self.has_animation == other.has_animation
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Proxies {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of proxy servers
   proxies: Vec<Proxy>,
   
 }
 
 impl RObject for Proxies {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "proxies" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "proxies" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Proxies {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDProxiesBuilder {
-    let mut inner = Proxies::default();
-    inner.td_name = "proxies".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Proxies { td_name: "proxies".to_string(), ..Default::default() };
     RTDProxiesBuilder { inner }
   }
 
+  /// `Proxies` with the fields tdlib requires, the others are set by `Proxies::builder()`
+  pub fn new(proxies: Vec<Proxy>) -> Self { Self::builder().proxies(proxies).build() }
+
   pub fn proxies(&self) -> &Vec<Proxy> { &self.proxies }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDProxiesBuilder {
 impl RTDProxiesBuilder {
   pub fn build(&self) -> Proxies { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn proxies(&mut self, proxies: Vec<Proxy>) -> &mut Self {
     self.inner.proxies = proxies;
@@ -65,5 +75,11 @@ impl AsRef<Proxies> for RTDProxiesBuilder {
   fn as_ref(&self) -> &Proxies { &self.inner }
 }
 
+impl PartialEq for Proxies {
+  fn eq(&self, other: &Self) -> bool {
+    self.proxies == other.proxies
+  }
+}
+
 
 
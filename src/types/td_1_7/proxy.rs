@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Proxy {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the proxy
   id: i64,
   /// Proxy server IP address
@@ -31,22 +33,40 @@ pub struct Proxy {
 }
 
 impl RObject for Proxy {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "proxy" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "proxy" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Proxy {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDProxyBuilder {
-    let mut inner = Proxy::default();
-    inner.td_name = "proxy".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Proxy { td_name: "proxy".to_string(), ..Default::default() };
     RTDProxyBuilder { inner }
   }
 
+  /// `Proxy` with the fields tdlib requires, the others are set by `Proxy::builder()`
+  pub fn new(
+    id: i64,
+    server: impl AsRef<str>,
+    port: i64,
+    last_used_date: i64,
+    is_enabled: bool,
+    type_: impl AsRef<ProxyType>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .server(server)
+      .port(port)
+      .last_used_date(last_used_date)
+      .is_enabled(is_enabled)
+      .type_(type_)
+      .build()
+  }
+
   pub fn id(&self) -> i64 { self.id }
 
   pub fn server(&self) -> &String { &self.server }
@@ -69,6 +89,12 @@ pub struct RTDProxyBuilder {
 impl RTDProxyBuilder {
   pub fn build(&self) -> Proxy { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -115,5 +141,16 @@ impl AsRef<Proxy> for RTDProxyBuilder {
   fn as_ref(&self) -> &Proxy { &self.inner }
 }
 
+impl PartialEq for Proxy {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.server == other.server
+      && self.port == other.port
+      && self.last_used_date == other.last_used_date
+      && self.is_enabled == other.is_enabled
+      && self.type_ == other.type_
+  }
+}
+
 
 
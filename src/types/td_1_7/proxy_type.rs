@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDProxyType: Debug + RObject {}
 
 /// Describes the type of a proxy server
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ProxyType {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum ProxyType {
   Mtproto(ProxyTypeMtproto),
   /// A SOCKS5 proxy server
   Socks5(ProxyTypeSocks5),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for ProxyType {
   fn deserialize<D>(deserializer: D) -> Result<ProxyType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ProxyType,
+      ProxyType, Unknown;
       (proxyTypeHttp, Http);
       (proxyTypeMtproto, Mtproto);
       (proxyTypeSocks5, Socks5);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for ProxyType {
 }
 
 impl RObject for ProxyType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ProxyType::Http(t) => t.td_name(),
       ProxyType::Mtproto(t) => t.td_name(),
       ProxyType::Socks5(t) => t.td_name(),
+      ProxyType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ProxyType::Http(t) => t.extra(),
       ProxyType::Mtproto(t) => t.extra(),
       ProxyType::Socks5(t) => t.extra(),
+      ProxyType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ProxyType::Http(t) => t.client_id(),
+      ProxyType::Mtproto(t) => t.client_id(),
+      ProxyType::Socks5(t) => t.client_id(),
+      ProxyType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for ProxyType {
 }
 
 impl ProxyType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ProxyType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ProxyType::_Default(_)) }
 
-  pub fn is_http(&self) -> bool { if let ProxyType::Http(_) = self { true } else { false } }
-  pub fn is_mtproto(&self) -> bool { if let ProxyType::Mtproto(_) = self { true } else { false } }
-  pub fn is_socks5(&self) -> bool { if let ProxyType::Socks5(_) = self { true } else { false } }
+  pub fn is_http(&self) -> bool { matches!(self, ProxyType::Http(_)) }
+  pub fn is_mtproto(&self) -> bool { matches!(self, ProxyType::Mtproto(_)) }
+  pub fn is_socks5(&self) -> bool { matches!(self, ProxyType::Socks5(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ProxyType::Unknown(_)) }
 
   pub fn on_http<F: FnOnce(&ProxyTypeHttp)>(&self, fnc: F) -> &Self { if let ProxyType::Http(t) = self { fnc(t) }; self }
   pub fn on_mtproto<F: FnOnce(&ProxyTypeMtproto)>(&self, fnc: F) -> &Self { if let ProxyType::Mtproto(t) = self { fnc(t) }; self }
   pub fn on_socks5<F: FnOnce(&ProxyTypeSocks5)>(&self, fnc: F) -> &Self { if let ProxyType::Socks5(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ProxyType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_http(&self) -> Option<&ProxyTypeHttp> { if let ProxyType::Http(t) = self { return Some(t) } None }
   pub fn as_mtproto(&self) -> Option<&ProxyTypeMtproto> { if let ProxyType::Mtproto(t) = self { return Some(t) } None }
   pub fn as_socks5(&self) -> Option<&ProxyTypeSocks5> { if let ProxyType::Socks5(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ProxyType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,7 +127,10 @@ pub struct ProxyTypeHttp {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username for logging in; may be empty
   username: String,
   /// Password for logging in; may be empty
@@ -122,8 +141,9 @@ pub struct ProxyTypeHttp {
 }
 
 impl RObject for ProxyTypeHttp {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "proxyTypeHttp" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "proxyTypeHttp" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -133,14 +153,15 @@ impl TDProxyType for ProxyTypeHttp {}
 
 
 impl ProxyTypeHttp {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDProxyTypeHttpBuilder {
-    let mut inner = ProxyTypeHttp::default();
-    inner.td_name = "proxyTypeHttp".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ProxyTypeHttp { td_name: "proxyTypeHttp".to_string(), ..Default::default() };
     RTDProxyTypeHttpBuilder { inner }
   }
 
+  /// `ProxyTypeHttp` with the fields tdlib requires, the others are set by `ProxyTypeHttp::builder()`
+  pub fn new(username: impl AsRef<str>, password: impl AsRef<str>, http_only: bool) -> Self { Self::builder().username(username).password(password).http_only(http_only).build() }
+
   pub fn username(&self) -> &String { &self.username }
 
   pub fn password(&self) -> &String { &self.password }
@@ -157,6 +178,12 @@ pub struct RTDProxyTypeHttpBuilder {
 impl RTDProxyTypeHttpBuilder {
   pub fn build(&self) -> ProxyTypeHttp { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -185,6 +212,14 @@ impl AsRef<ProxyTypeHttp> for RTDProxyTypeHttpBuilder {
   fn as_ref(&self) -> &ProxyTypeHttp { &self.inner }
 }
 
+impl PartialEq for ProxyTypeHttp {
+  fn eq(&self, other: &Self) -> bool {
+    self.username == other.username
+      && self.password == other.password
+      && self.http_only == other.http_only
+  }
+}
+
 
 
 
@@ -199,15 +234,19 @@ pub struct ProxyTypeMtproto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The proxy's secret in hexadecimal encoding
   secret: String,
   
 }
 
 impl RObject for ProxyTypeMtproto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "proxyTypeMtproto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "proxyTypeMtproto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -217,14 +256,15 @@ impl TDProxyType for ProxyTypeMtproto {}
 
 
 impl ProxyTypeMtproto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDProxyTypeMtprotoBuilder {
-    let mut inner = ProxyTypeMtproto::default();
-    inner.td_name = "proxyTypeMtproto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ProxyTypeMtproto { td_name: "proxyTypeMtproto".to_string(), ..Default::default() };
     RTDProxyTypeMtprotoBuilder { inner }
   }
 
+  /// `ProxyTypeMtproto` with the fields tdlib requires, the others are set by `ProxyTypeMtproto::builder()`
+  pub fn new(secret: impl AsRef<str>) -> Self { Self::builder().secret(secret).build() }
+
   pub fn secret(&self) -> &String { &self.secret }
 
 }
@@ -237,6 +277,12 @@ pub struct RTDProxyTypeMtprotoBuilder {
 impl RTDProxyTypeMtprotoBuilder {
   pub fn build(&self) -> ProxyTypeMtproto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret<T: AsRef<str>>(&mut self, secret: T) -> &mut Self {
     self.inner.secret = secret.as_ref().to_string();
@@ -253,6 +299,12 @@ impl AsRef<ProxyTypeMtproto> for RTDProxyTypeMtprotoBuilder {
   fn as_ref(&self) -> &ProxyTypeMtproto { &self.inner }
 }
 
+impl PartialEq for ProxyTypeMtproto {
+  fn eq(&self, other: &Self) -> bool {
+    self.secret == other.secret
+  }
+}
+
 
 
 
@@ -267,7 +319,10 @@ pub struct ProxyTypeSocks5 {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Username for logging in; may be empty
   username: String,
   /// Password for logging in; may be empty
@@ -276,8 +331,9 @@ pub struct ProxyTypeSocks5 {
 }
 
 impl RObject for ProxyTypeSocks5 {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "proxyTypeSocks5" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "proxyTypeSocks5" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -287,14 +343,15 @@ impl TDProxyType for ProxyTypeSocks5 {}
 
 
 impl ProxyTypeSocks5 {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDProxyTypeSocks5Builder {
-    let mut inner = ProxyTypeSocks5::default();
-    inner.td_name = "proxyTypeSocks5".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ProxyTypeSocks5 { td_name: "proxyTypeSocks5".to_string(), ..Default::default() };
     RTDProxyTypeSocks5Builder { inner }
   }
 
+  /// `ProxyTypeSocks5` with the fields tdlib requires, the others are set by `ProxyTypeSocks5::builder()`
+  pub fn new(username: impl AsRef<str>, password: impl AsRef<str>) -> Self { Self::builder().username(username).password(password).build() }
+
   pub fn username(&self) -> &String { &self.username }
 
   pub fn password(&self) -> &String { &self.password }
@@ -309,6 +366,12 @@ pub struct RTDProxyTypeSocks5Builder {
 impl RTDProxyTypeSocks5Builder {
   pub fn build(&self) -> ProxyTypeSocks5 { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -331,5 +394,12 @@ impl AsRef<ProxyTypeSocks5> for RTDProxyTypeSocks5Builder {
   fn as_ref(&self) -> &ProxyTypeSocks5 { &self.inner }
 }
 
+impl PartialEq for ProxyTypeSocks5 {
+  fn eq(&self, other: &Self) -> bool {
+    self.username == other.username
+      && self.password == other.password
+  }
+}
+
 
 
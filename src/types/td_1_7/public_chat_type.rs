@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPublicChatType: Debug + RObject {}
 
 /// Describes a type of public chats
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PublicChatType {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum PublicChatType {
   HasUsername(PublicChatTypeHasUsername),
   /// The chat is public, because it is a location-based supergroup
   IsLocationBased(PublicChatTypeIsLocationBased),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for PublicChatType {
   fn deserialize<D>(deserializer: D) -> Result<PublicChatType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PublicChatType,
+      PublicChatType, Unknown;
       (publicChatTypeHasUsername, HasUsername);
       (publicChatTypeIsLocationBased, IsLocationBased);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for PublicChatType {
 }
 
 impl RObject for PublicChatType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PublicChatType::HasUsername(t) => t.td_name(),
       PublicChatType::IsLocationBased(t) => t.td_name(),
+      PublicChatType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PublicChatType::HasUsername(t) => t.extra(),
       PublicChatType::IsLocationBased(t) => t.extra(),
+      PublicChatType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PublicChatType::HasUsername(t) => t.client_id(),
+      PublicChatType::IsLocationBased(t) => t.client_id(),
+      PublicChatType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for PublicChatType {
 }
 
 impl PublicChatType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PublicChatType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PublicChatType::_Default(_)) }
 
-  pub fn is_has_username(&self) -> bool { if let PublicChatType::HasUsername(_) = self { true } else { false } }
-  pub fn is_is_location_based(&self) -> bool { if let PublicChatType::IsLocationBased(_) = self { true } else { false } }
+  pub fn is_has_username(&self) -> bool { matches!(self, PublicChatType::HasUsername(_)) }
+  pub fn is_is_location_based(&self) -> bool { matches!(self, PublicChatType::IsLocationBased(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PublicChatType::Unknown(_)) }
 
   pub fn on_has_username<F: FnOnce(&PublicChatTypeHasUsername)>(&self, fnc: F) -> &Self { if let PublicChatType::HasUsername(t) = self { fnc(t) }; self }
   pub fn on_is_location_based<F: FnOnce(&PublicChatTypeIsLocationBased)>(&self, fnc: F) -> &Self { if let PublicChatType::IsLocationBased(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PublicChatType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_has_username(&self) -> Option<&PublicChatTypeHasUsername> { if let PublicChatType::HasUsername(t) = self { return Some(t) } None }
   pub fn as_is_location_based(&self) -> Option<&PublicChatTypeIsLocationBased> { if let PublicChatType::IsLocationBased(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PublicChatType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,13 +116,17 @@ pub struct PublicChatTypeHasUsername {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PublicChatTypeHasUsername {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "publicChatTypeHasUsername" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "publicChatTypeHasUsername" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -117,14 +136,15 @@ impl TDPublicChatType for PublicChatTypeHasUsername {}
 
 
 impl PublicChatTypeHasUsername {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPublicChatTypeHasUsernameBuilder {
-    let mut inner = PublicChatTypeHasUsername::default();
-    inner.td_name = "publicChatTypeHasUsername".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PublicChatTypeHasUsername { td_name: "publicChatTypeHasUsername".to_string(), ..Default::default() };
     RTDPublicChatTypeHasUsernameBuilder { inner }
   }
 
+  /// `PublicChatTypeHasUsername` with the fields tdlib requires, the others are set by `PublicChatTypeHasUsername::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -135,6 +155,12 @@ pub struct RTDPublicChatTypeHasUsernameBuilder {
 impl RTDPublicChatTypeHasUsernameBuilder {
   pub fn build(&self) -> PublicChatTypeHasUsername { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PublicChatTypeHasUsername> for PublicChatTypeHasUsername {
@@ -145,6 +171,10 @@ impl AsRef<PublicChatTypeHasUsername> for RTDPublicChatTypeHasUsernameBuilder {
   fn as_ref(&self) -> &PublicChatTypeHasUsername { &self.inner }
 }
 
+impl PartialEq for PublicChatTypeHasUsername {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -159,13 +189,17 @@ pub struct PublicChatTypeIsLocationBased {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PublicChatTypeIsLocationBased {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "publicChatTypeIsLocationBased" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "publicChatTypeIsLocationBased" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -175,14 +209,15 @@ impl TDPublicChatType for PublicChatTypeIsLocationBased {}
 
 
 impl PublicChatTypeIsLocationBased {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPublicChatTypeIsLocationBasedBuilder {
-    let mut inner = PublicChatTypeIsLocationBased::default();
-    inner.td_name = "publicChatTypeIsLocationBased".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PublicChatTypeIsLocationBased { td_name: "publicChatTypeIsLocationBased".to_string(), ..Default::default() };
     RTDPublicChatTypeIsLocationBasedBuilder { inner }
   }
 
+  /// `PublicChatTypeIsLocationBased` with the fields tdlib requires, the others are set by `PublicChatTypeIsLocationBased::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -193,6 +228,12 @@ pub struct RTDPublicChatTypeIsLocationBasedBuilder {
 impl RTDPublicChatTypeIsLocationBasedBuilder {
   pub fn build(&self) -> PublicChatTypeIsLocationBased { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PublicChatTypeIsLocationBased> for PublicChatTypeIsLocationBased {
@@ -203,5 +244,9 @@ impl AsRef<PublicChatTypeIsLocationBased> for RTDPublicChatTypeIsLocationBasedBu
   fn as_ref(&self) -> &PublicChatTypeIsLocationBased { &self.inner }
 }
 
+impl PartialEq for PublicChatTypeIsLocationBased {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDPushMessageContent: Debug + RObject {}
 
 /// Contains content of a push message notification
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PushMessageContent {
   #[doc(hidden)] _Default(()),
@@ -52,6 +51,7 @@ pub enum PushMessageContent {
   /// A general message with hidden content
   Hidden(PushMessageContentHidden),
   /// A message with an invoice from a bot
+  #[cfg(feature = "payments")]
   Invoice(PushMessageContentInvoice),
   /// A message with a location
   Location(PushMessageContentLocation),
@@ -66,6 +66,7 @@ pub enum PushMessageContent {
   /// A screenshot of a message in the chat has been taken
   ScreenshotTaken(PushMessageContentScreenshotTaken),
   /// A message with a sticker
+  #[cfg(feature = "stickers")]
   Sticker(PushMessageContentSticker),
   /// A text message
   Text(PushMessageContentText),
@@ -75,6 +76,8 @@ pub enum PushMessageContent {
   VideoNote(PushMessageContentVideoNote),
   /// A voice note message
   VoiceNote(PushMessageContentVoiceNote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -86,7 +89,7 @@ impl<'de> Deserialize<'de> for PushMessageContent {
   fn deserialize<D>(deserializer: D) -> Result<PushMessageContent, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      PushMessageContent,
+      PushMessageContent, Unknown;
       (pushMessageContentAnimation, Animation);
       (pushMessageContentAudio, Audio);
       (pushMessageContentBasicGroupChatCreate, BasicGroupChatCreate);
@@ -103,14 +106,14 @@ impl<'de> Deserialize<'de> for PushMessageContent {
       (pushMessageContentGame, Game);
       (pushMessageContentGameScore, GameScore);
       (pushMessageContentHidden, Hidden);
-      (pushMessageContentInvoice, Invoice);
+      #[cfg(feature = "payments")] (pushMessageContentInvoice, Invoice);
       (pushMessageContentLocation, Location);
       (pushMessageContentMediaAlbum, MediaAlbum);
       (pushMessageContentMessageForwards, MessageForwards);
       (pushMessageContentPhoto, Photo);
       (pushMessageContentPoll, Poll);
       (pushMessageContentScreenshotTaken, ScreenshotTaken);
-      (pushMessageContentSticker, Sticker);
+      #[cfg(feature = "stickers")] (pushMessageContentSticker, Sticker);
       (pushMessageContentText, Text);
       (pushMessageContentVideo, Video);
       (pushMessageContentVideoNote, VideoNote);
@@ -121,7 +124,7 @@ impl<'de> Deserialize<'de> for PushMessageContent {
 }
 
 impl RObject for PushMessageContent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       PushMessageContent::Animation(t) => t.td_name(),
       PushMessageContent::Audio(t) => t.td_name(),
@@ -139,23 +142,24 @@ impl RObject for PushMessageContent {
       PushMessageContent::Game(t) => t.td_name(),
       PushMessageContent::GameScore(t) => t.td_name(),
       PushMessageContent::Hidden(t) => t.td_name(),
-      PushMessageContent::Invoice(t) => t.td_name(),
+      #[cfg(feature = "payments")] PushMessageContent::Invoice(t) => t.td_name(),
       PushMessageContent::Location(t) => t.td_name(),
       PushMessageContent::MediaAlbum(t) => t.td_name(),
       PushMessageContent::MessageForwards(t) => t.td_name(),
       PushMessageContent::Photo(t) => t.td_name(),
       PushMessageContent::Poll(t) => t.td_name(),
       PushMessageContent::ScreenshotTaken(t) => t.td_name(),
-      PushMessageContent::Sticker(t) => t.td_name(),
+      #[cfg(feature = "stickers")] PushMessageContent::Sticker(t) => t.td_name(),
       PushMessageContent::Text(t) => t.td_name(),
       PushMessageContent::Video(t) => t.td_name(),
       PushMessageContent::VideoNote(t) => t.td_name(),
       PushMessageContent::VoiceNote(t) => t.td_name(),
+      PushMessageContent::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       PushMessageContent::Animation(t) => t.extra(),
       PushMessageContent::Audio(t) => t.extra(),
@@ -173,18 +177,54 @@ impl RObject for PushMessageContent {
       PushMessageContent::Game(t) => t.extra(),
       PushMessageContent::GameScore(t) => t.extra(),
       PushMessageContent::Hidden(t) => t.extra(),
-      PushMessageContent::Invoice(t) => t.extra(),
+      #[cfg(feature = "payments")] PushMessageContent::Invoice(t) => t.extra(),
       PushMessageContent::Location(t) => t.extra(),
       PushMessageContent::MediaAlbum(t) => t.extra(),
       PushMessageContent::MessageForwards(t) => t.extra(),
       PushMessageContent::Photo(t) => t.extra(),
       PushMessageContent::Poll(t) => t.extra(),
       PushMessageContent::ScreenshotTaken(t) => t.extra(),
-      PushMessageContent::Sticker(t) => t.extra(),
+      #[cfg(feature = "stickers")] PushMessageContent::Sticker(t) => t.extra(),
       PushMessageContent::Text(t) => t.extra(),
       PushMessageContent::Video(t) => t.extra(),
       PushMessageContent::VideoNote(t) => t.extra(),
       PushMessageContent::VoiceNote(t) => t.extra(),
+      PushMessageContent::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      PushMessageContent::Animation(t) => t.client_id(),
+      PushMessageContent::Audio(t) => t.client_id(),
+      PushMessageContent::BasicGroupChatCreate(t) => t.client_id(),
+      PushMessageContent::ChatAddMembers(t) => t.client_id(),
+      PushMessageContent::ChatChangePhoto(t) => t.client_id(),
+      PushMessageContent::ChatChangeTitle(t) => t.client_id(),
+      PushMessageContent::ChatDeleteMember(t) => t.client_id(),
+      PushMessageContent::ChatJoinByLink(t) => t.client_id(),
+      PushMessageContent::ChatJoinByRequest(t) => t.client_id(),
+      PushMessageContent::ChatSetTheme(t) => t.client_id(),
+      PushMessageContent::Contact(t) => t.client_id(),
+      PushMessageContent::ContactRegistered(t) => t.client_id(),
+      PushMessageContent::Document(t) => t.client_id(),
+      PushMessageContent::Game(t) => t.client_id(),
+      PushMessageContent::GameScore(t) => t.client_id(),
+      PushMessageContent::Hidden(t) => t.client_id(),
+      #[cfg(feature = "payments")] PushMessageContent::Invoice(t) => t.client_id(),
+      PushMessageContent::Location(t) => t.client_id(),
+      PushMessageContent::MediaAlbum(t) => t.client_id(),
+      PushMessageContent::MessageForwards(t) => t.client_id(),
+      PushMessageContent::Photo(t) => t.client_id(),
+      PushMessageContent::Poll(t) => t.client_id(),
+      PushMessageContent::ScreenshotTaken(t) => t.client_id(),
+      #[cfg(feature = "stickers")] PushMessageContent::Sticker(t) => t.client_id(),
+      PushMessageContent::Text(t) => t.client_id(),
+      PushMessageContent::Video(t) => t.client_id(),
+      PushMessageContent::VideoNote(t) => t.client_id(),
+      PushMessageContent::VoiceNote(t) => t.client_id(),
+      PushMessageContent::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -193,37 +233,38 @@ impl RObject for PushMessageContent {
 }
 
 impl PushMessageContent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PushMessageContent::_Default(_) = self { true } else { false } }
-
-  pub fn is_animation(&self) -> bool { if let PushMessageContent::Animation(_) = self { true } else { false } }
-  pub fn is_audio(&self) -> bool { if let PushMessageContent::Audio(_) = self { true } else { false } }
-  pub fn is_basic_group_chat_create(&self) -> bool { if let PushMessageContent::BasicGroupChatCreate(_) = self { true } else { false } }
-  pub fn is_chat_add_members(&self) -> bool { if let PushMessageContent::ChatAddMembers(_) = self { true } else { false } }
-  pub fn is_chat_change_photo(&self) -> bool { if let PushMessageContent::ChatChangePhoto(_) = self { true } else { false } }
-  pub fn is_chat_change_title(&self) -> bool { if let PushMessageContent::ChatChangeTitle(_) = self { true } else { false } }
-  pub fn is_chat_delete_member(&self) -> bool { if let PushMessageContent::ChatDeleteMember(_) = self { true } else { false } }
-  pub fn is_chat_join_by_link(&self) -> bool { if let PushMessageContent::ChatJoinByLink(_) = self { true } else { false } }
-  pub fn is_chat_join_by_request(&self) -> bool { if let PushMessageContent::ChatJoinByRequest(_) = self { true } else { false } }
-  pub fn is_chat_set_theme(&self) -> bool { if let PushMessageContent::ChatSetTheme(_) = self { true } else { false } }
-  pub fn is_contact(&self) -> bool { if let PushMessageContent::Contact(_) = self { true } else { false } }
-  pub fn is_contact_registered(&self) -> bool { if let PushMessageContent::ContactRegistered(_) = self { true } else { false } }
-  pub fn is_document(&self) -> bool { if let PushMessageContent::Document(_) = self { true } else { false } }
-  pub fn is_game(&self) -> bool { if let PushMessageContent::Game(_) = self { true } else { false } }
-  pub fn is_game_score(&self) -> bool { if let PushMessageContent::GameScore(_) = self { true } else { false } }
-  pub fn is_hidden(&self) -> bool { if let PushMessageContent::Hidden(_) = self { true } else { false } }
-  pub fn is_invoice(&self) -> bool { if let PushMessageContent::Invoice(_) = self { true } else { false } }
-  pub fn is_location(&self) -> bool { if let PushMessageContent::Location(_) = self { true } else { false } }
-  pub fn is_media_album(&self) -> bool { if let PushMessageContent::MediaAlbum(_) = self { true } else { false } }
-  pub fn is_message_forwards(&self) -> bool { if let PushMessageContent::MessageForwards(_) = self { true } else { false } }
-  pub fn is_photo(&self) -> bool { if let PushMessageContent::Photo(_) = self { true } else { false } }
-  pub fn is_poll(&self) -> bool { if let PushMessageContent::Poll(_) = self { true } else { false } }
-  pub fn is_screenshot_taken(&self) -> bool { if let PushMessageContent::ScreenshotTaken(_) = self { true } else { false } }
-  pub fn is_sticker(&self) -> bool { if let PushMessageContent::Sticker(_) = self { true } else { false } }
-  pub fn is_text(&self) -> bool { if let PushMessageContent::Text(_) = self { true } else { false } }
-  pub fn is_video(&self) -> bool { if let PushMessageContent::Video(_) = self { true } else { false } }
-  pub fn is_video_note(&self) -> bool { if let PushMessageContent::VideoNote(_) = self { true } else { false } }
-  pub fn is_voice_note(&self) -> bool { if let PushMessageContent::VoiceNote(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, PushMessageContent::_Default(_)) }
+
+  pub fn is_animation(&self) -> bool { matches!(self, PushMessageContent::Animation(_)) }
+  pub fn is_audio(&self) -> bool { matches!(self, PushMessageContent::Audio(_)) }
+  pub fn is_basic_group_chat_create(&self) -> bool { matches!(self, PushMessageContent::BasicGroupChatCreate(_)) }
+  pub fn is_chat_add_members(&self) -> bool { matches!(self, PushMessageContent::ChatAddMembers(_)) }
+  pub fn is_chat_change_photo(&self) -> bool { matches!(self, PushMessageContent::ChatChangePhoto(_)) }
+  pub fn is_chat_change_title(&self) -> bool { matches!(self, PushMessageContent::ChatChangeTitle(_)) }
+  pub fn is_chat_delete_member(&self) -> bool { matches!(self, PushMessageContent::ChatDeleteMember(_)) }
+  pub fn is_chat_join_by_link(&self) -> bool { matches!(self, PushMessageContent::ChatJoinByLink(_)) }
+  pub fn is_chat_join_by_request(&self) -> bool { matches!(self, PushMessageContent::ChatJoinByRequest(_)) }
+  pub fn is_chat_set_theme(&self) -> bool { matches!(self, PushMessageContent::ChatSetTheme(_)) }
+  pub fn is_contact(&self) -> bool { matches!(self, PushMessageContent::Contact(_)) }
+  pub fn is_contact_registered(&self) -> bool { matches!(self, PushMessageContent::ContactRegistered(_)) }
+  pub fn is_document(&self) -> bool { matches!(self, PushMessageContent::Document(_)) }
+  pub fn is_game(&self) -> bool { matches!(self, PushMessageContent::Game(_)) }
+  pub fn is_game_score(&self) -> bool { matches!(self, PushMessageContent::GameScore(_)) }
+  pub fn is_hidden(&self) -> bool { matches!(self, PushMessageContent::Hidden(_)) }
+  #[cfg(feature = "payments")] pub fn is_invoice(&self) -> bool { matches!(self, PushMessageContent::Invoice(_)) }
+  pub fn is_location(&self) -> bool { matches!(self, PushMessageContent::Location(_)) }
+  pub fn is_media_album(&self) -> bool { matches!(self, PushMessageContent::MediaAlbum(_)) }
+  pub fn is_message_forwards(&self) -> bool { matches!(self, PushMessageContent::MessageForwards(_)) }
+  pub fn is_photo(&self) -> bool { matches!(self, PushMessageContent::Photo(_)) }
+  pub fn is_poll(&self) -> bool { matches!(self, PushMessageContent::Poll(_)) }
+  pub fn is_screenshot_taken(&self) -> bool { matches!(self, PushMessageContent::ScreenshotTaken(_)) }
+  #[cfg(feature = "stickers")] pub fn is_sticker(&self) -> bool { matches!(self, PushMessageContent::Sticker(_)) }
+  pub fn is_text(&self) -> bool { matches!(self, PushMessageContent::Text(_)) }
+  pub fn is_video(&self) -> bool { matches!(self, PushMessageContent::Video(_)) }
+  pub fn is_video_note(&self) -> bool { matches!(self, PushMessageContent::VideoNote(_)) }
+  pub fn is_voice_note(&self) -> bool { matches!(self, PushMessageContent::VoiceNote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, PushMessageContent::Unknown(_)) }
 
   pub fn on_animation<F: FnOnce(&PushMessageContentAnimation)>(&self, fnc: F) -> &Self { if let PushMessageContent::Animation(t) = self { fnc(t) }; self }
   pub fn on_audio<F: FnOnce(&PushMessageContentAudio)>(&self, fnc: F) -> &Self { if let PushMessageContent::Audio(t) = self { fnc(t) }; self }
@@ -241,18 +282,19 @@ impl PushMessageContent {
   pub fn on_game<F: FnOnce(&PushMessageContentGame)>(&self, fnc: F) -> &Self { if let PushMessageContent::Game(t) = self { fnc(t) }; self }
   pub fn on_game_score<F: FnOnce(&PushMessageContentGameScore)>(&self, fnc: F) -> &Self { if let PushMessageContent::GameScore(t) = self { fnc(t) }; self }
   pub fn on_hidden<F: FnOnce(&PushMessageContentHidden)>(&self, fnc: F) -> &Self { if let PushMessageContent::Hidden(t) = self { fnc(t) }; self }
-  pub fn on_invoice<F: FnOnce(&PushMessageContentInvoice)>(&self, fnc: F) -> &Self { if let PushMessageContent::Invoice(t) = self { fnc(t) }; self }
+  #[cfg(feature = "payments")] pub fn on_invoice<F: FnOnce(&PushMessageContentInvoice)>(&self, fnc: F) -> &Self { if let PushMessageContent::Invoice(t) = self { fnc(t) }; self }
   pub fn on_location<F: FnOnce(&PushMessageContentLocation)>(&self, fnc: F) -> &Self { if let PushMessageContent::Location(t) = self { fnc(t) }; self }
   pub fn on_media_album<F: FnOnce(&PushMessageContentMediaAlbum)>(&self, fnc: F) -> &Self { if let PushMessageContent::MediaAlbum(t) = self { fnc(t) }; self }
   pub fn on_message_forwards<F: FnOnce(&PushMessageContentMessageForwards)>(&self, fnc: F) -> &Self { if let PushMessageContent::MessageForwards(t) = self { fnc(t) }; self }
   pub fn on_photo<F: FnOnce(&PushMessageContentPhoto)>(&self, fnc: F) -> &Self { if let PushMessageContent::Photo(t) = self { fnc(t) }; self }
   pub fn on_poll<F: FnOnce(&PushMessageContentPoll)>(&self, fnc: F) -> &Self { if let PushMessageContent::Poll(t) = self { fnc(t) }; self }
   pub fn on_screenshot_taken<F: FnOnce(&PushMessageContentScreenshotTaken)>(&self, fnc: F) -> &Self { if let PushMessageContent::ScreenshotTaken(t) = self { fnc(t) }; self }
-  pub fn on_sticker<F: FnOnce(&PushMessageContentSticker)>(&self, fnc: F) -> &Self { if let PushMessageContent::Sticker(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_sticker<F: FnOnce(&PushMessageContentSticker)>(&self, fnc: F) -> &Self { if let PushMessageContent::Sticker(t) = self { fnc(t) }; self }
   pub fn on_text<F: FnOnce(&PushMessageContentText)>(&self, fnc: F) -> &Self { if let PushMessageContent::Text(t) = self { fnc(t) }; self }
   pub fn on_video<F: FnOnce(&PushMessageContentVideo)>(&self, fnc: F) -> &Self { if let PushMessageContent::Video(t) = self { fnc(t) }; self }
   pub fn on_video_note<F: FnOnce(&PushMessageContentVideoNote)>(&self, fnc: F) -> &Self { if let PushMessageContent::VideoNote(t) = self { fnc(t) }; self }
   pub fn on_voice_note<F: FnOnce(&PushMessageContentVoiceNote)>(&self, fnc: F) -> &Self { if let PushMessageContent::VoiceNote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let PushMessageContent::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_animation(&self) -> Option<&PushMessageContentAnimation> { if let PushMessageContent::Animation(t) = self { return Some(t) } None }
   pub fn as_audio(&self) -> Option<&PushMessageContentAudio> { if let PushMessageContent::Audio(t) = self { return Some(t) } None }
@@ -270,18 +312,19 @@ impl PushMessageContent {
   pub fn as_game(&self) -> Option<&PushMessageContentGame> { if let PushMessageContent::Game(t) = self { return Some(t) } None }
   pub fn as_game_score(&self) -> Option<&PushMessageContentGameScore> { if let PushMessageContent::GameScore(t) = self { return Some(t) } None }
   pub fn as_hidden(&self) -> Option<&PushMessageContentHidden> { if let PushMessageContent::Hidden(t) = self { return Some(t) } None }
-  pub fn as_invoice(&self) -> Option<&PushMessageContentInvoice> { if let PushMessageContent::Invoice(t) = self { return Some(t) } None }
+  #[cfg(feature = "payments")] pub fn as_invoice(&self) -> Option<&PushMessageContentInvoice> { if let PushMessageContent::Invoice(t) = self { return Some(t) } None }
   pub fn as_location(&self) -> Option<&PushMessageContentLocation> { if let PushMessageContent::Location(t) = self { return Some(t) } None }
   pub fn as_media_album(&self) -> Option<&PushMessageContentMediaAlbum> { if let PushMessageContent::MediaAlbum(t) = self { return Some(t) } None }
   pub fn as_message_forwards(&self) -> Option<&PushMessageContentMessageForwards> { if let PushMessageContent::MessageForwards(t) = self { return Some(t) } None }
   pub fn as_photo(&self) -> Option<&PushMessageContentPhoto> { if let PushMessageContent::Photo(t) = self { return Some(t) } None }
   pub fn as_poll(&self) -> Option<&PushMessageContentPoll> { if let PushMessageContent::Poll(t) = self { return Some(t) } None }
   pub fn as_screenshot_taken(&self) -> Option<&PushMessageContentScreenshotTaken> { if let PushMessageContent::ScreenshotTaken(t) = self { return Some(t) } None }
-  pub fn as_sticker(&self) -> Option<&PushMessageContentSticker> { if let PushMessageContent::Sticker(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_sticker(&self) -> Option<&PushMessageContentSticker> { if let PushMessageContent::Sticker(t) = self { return Some(t) } None }
   pub fn as_text(&self) -> Option<&PushMessageContentText> { if let PushMessageContent::Text(t) = self { return Some(t) } None }
   pub fn as_video(&self) -> Option<&PushMessageContentVideo> { if let PushMessageContent::Video(t) = self { return Some(t) } None }
   pub fn as_video_note(&self) -> Option<&PushMessageContentVideoNote> { if let PushMessageContent::VideoNote(t) = self { return Some(t) } None }
   pub fn as_voice_note(&self) -> Option<&PushMessageContentVoiceNote> { if let PushMessageContent::VoiceNote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let PushMessageContent::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -317,7 +360,7 @@ impl PushMessageContent {
 
   pub fn hidden<T: AsRef<PushMessageContentHidden>>(t: T) -> Self { PushMessageContent::Hidden(t.as_ref().clone()) }
 
-  pub fn invoice<T: AsRef<PushMessageContentInvoice>>(t: T) -> Self { PushMessageContent::Invoice(t.as_ref().clone()) }
+  #[cfg(feature = "payments")] pub fn invoice<T: AsRef<PushMessageContentInvoice>>(t: T) -> Self { PushMessageContent::Invoice(t.as_ref().clone()) }
 
   pub fn location<T: AsRef<PushMessageContentLocation>>(t: T) -> Self { PushMessageContent::Location(t.as_ref().clone()) }
 
@@ -331,7 +374,7 @@ impl PushMessageContent {
 
   pub fn screenshot_taken<T: AsRef<PushMessageContentScreenshotTaken>>(t: T) -> Self { PushMessageContent::ScreenshotTaken(t.as_ref().clone()) }
 
-  pub fn sticker<T: AsRef<PushMessageContentSticker>>(t: T) -> Self { PushMessageContent::Sticker(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn sticker<T: AsRef<PushMessageContentSticker>>(t: T) -> Self { PushMessageContent::Sticker(t.as_ref().clone()) }
 
   pub fn text<T: AsRef<PushMessageContentText>>(t: T) -> Self { PushMessageContent::Text(t.as_ref().clone()) }
 
@@ -361,7 +404,10 @@ pub struct PushMessageContentAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   animation: Option<Animation>,
   /// Animation caption
@@ -372,8 +418,9 @@ pub struct PushMessageContentAnimation {
 }
 
 impl RObject for PushMessageContentAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -383,14 +430,15 @@ impl TDPushMessageContent for PushMessageContentAnimation {}
 
 
 impl PushMessageContentAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentAnimationBuilder {
-    let mut inner = PushMessageContentAnimation::default();
-    inner.td_name = "pushMessageContentAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentAnimation { td_name: "pushMessageContentAnimation".to_string(), ..Default::default() };
     RTDPushMessageContentAnimationBuilder { inner }
   }
 
+  /// `PushMessageContentAnimation` with the fields tdlib requires, the others are set by `PushMessageContentAnimation::builder()`
+  pub fn new(caption: impl AsRef<str>, is_pinned: bool) -> Self { Self::builder().caption(caption).is_pinned(is_pinned).build() }
+
   pub fn animation(&self) -> &Option<Animation> { &self.animation }
 
   pub fn caption(&self) -> &String { &self.caption }
@@ -407,6 +455,12 @@ pub struct RTDPushMessageContentAnimationBuilder {
 impl RTDPushMessageContentAnimationBuilder {
   pub fn build(&self) -> PushMessageContentAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = Some(animation.as_ref().clone());
@@ -435,6 +489,14 @@ impl AsRef<PushMessageContentAnimation> for RTDPushMessageContentAnimationBuilde
   fn as_ref(&self) -> &PushMessageContentAnimation { &self.inner }
 }
 
+impl PartialEq for PushMessageContentAnimation {
+  fn eq(&self, other: &Self) -> bool {
+    self.animation == other.animation
+      && self.caption == other.caption
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -449,7 +511,10 @@ pub struct PushMessageContentAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   audio: Option<Audio>,
   /// True, if the message is a pinned message with the specified content
@@ -458,8 +523,9 @@ pub struct PushMessageContentAudio {
 }
 
 impl RObject for PushMessageContentAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -469,14 +535,15 @@ impl TDPushMessageContent for PushMessageContentAudio {}
 
 
 impl PushMessageContentAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentAudioBuilder {
-    let mut inner = PushMessageContentAudio::default();
-    inner.td_name = "pushMessageContentAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentAudio { td_name: "pushMessageContentAudio".to_string(), ..Default::default() };
     RTDPushMessageContentAudioBuilder { inner }
   }
 
+  /// `PushMessageContentAudio` with the fields tdlib requires, the others are set by `PushMessageContentAudio::builder()`
+  pub fn new(is_pinned: bool) -> Self { Self::builder().is_pinned(is_pinned).build() }
+
   pub fn audio(&self) -> &Option<Audio> { &self.audio }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -491,6 +558,12 @@ pub struct RTDPushMessageContentAudioBuilder {
 impl RTDPushMessageContentAudioBuilder {
   pub fn build(&self) -> PushMessageContentAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = Some(audio.as_ref().clone());
@@ -513,6 +586,13 @@ impl AsRef<PushMessageContentAudio> for RTDPushMessageContentAudioBuilder {
   fn as_ref(&self) -> &PushMessageContentAudio { &self.inner }
 }
 
+impl PartialEq for PushMessageContentAudio {
+  fn eq(&self, other: &Self) -> bool {
+    self.audio == other.audio
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -527,13 +607,17 @@ pub struct PushMessageContentBasicGroupChatCreate {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PushMessageContentBasicGroupChatCreate {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentBasicGroupChatCreate" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentBasicGroupChatCreate" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -543,14 +627,15 @@ impl TDPushMessageContent for PushMessageContentBasicGroupChatCreate {}
 
 
 impl PushMessageContentBasicGroupChatCreate {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentBasicGroupChatCreateBuilder {
-    let mut inner = PushMessageContentBasicGroupChatCreate::default();
-    inner.td_name = "pushMessageContentBasicGroupChatCreate".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentBasicGroupChatCreate { td_name: "pushMessageContentBasicGroupChatCreate".to_string(), ..Default::default() };
     RTDPushMessageContentBasicGroupChatCreateBuilder { inner }
   }
 
+  /// `PushMessageContentBasicGroupChatCreate` with the fields tdlib requires, the others are set by `PushMessageContentBasicGroupChatCreate::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -561,6 +646,12 @@ pub struct RTDPushMessageContentBasicGroupChatCreateBuilder {
 impl RTDPushMessageContentBasicGroupChatCreateBuilder {
   pub fn build(&self) -> PushMessageContentBasicGroupChatCreate { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentBasicGroupChatCreate> for PushMessageContentBasicGroupChatCreate {
@@ -571,6 +662,10 @@ impl AsRef<PushMessageContentBasicGroupChatCreate> for RTDPushMessageContentBasi
   fn as_ref(&self) -> &PushMessageContentBasicGroupChatCreate { &self.inner }
 }
 
+impl PartialEq for PushMessageContentBasicGroupChatCreate {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -585,7 +680,10 @@ pub struct PushMessageContentChatAddMembers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the added member
   member_name: String,
   /// True, if the current user was added to the group
@@ -596,8 +694,9 @@ pub struct PushMessageContentChatAddMembers {
 }
 
 impl RObject for PushMessageContentChatAddMembers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatAddMembers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentChatAddMembers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -607,14 +706,15 @@ impl TDPushMessageContent for PushMessageContentChatAddMembers {}
 
 
 impl PushMessageContentChatAddMembers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentChatAddMembersBuilder {
-    let mut inner = PushMessageContentChatAddMembers::default();
-    inner.td_name = "pushMessageContentChatAddMembers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentChatAddMembers { td_name: "pushMessageContentChatAddMembers".to_string(), ..Default::default() };
     RTDPushMessageContentChatAddMembersBuilder { inner }
   }
 
+  /// `PushMessageContentChatAddMembers` with the fields tdlib requires, the others are set by `PushMessageContentChatAddMembers::builder()`
+  pub fn new(member_name: impl AsRef<str>, is_current_user: bool, is_returned: bool) -> Self { Self::builder().member_name(member_name).is_current_user(is_current_user).is_returned(is_returned).build() }
+
   pub fn member_name(&self) -> &String { &self.member_name }
 
   pub fn is_current_user(&self) -> bool { self.is_current_user }
@@ -631,6 +731,12 @@ pub struct RTDPushMessageContentChatAddMembersBuilder {
 impl RTDPushMessageContentChatAddMembersBuilder {
   pub fn build(&self) -> PushMessageContentChatAddMembers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
     self.inner.member_name = member_name.as_ref().to_string();
@@ -659,6 +765,14 @@ impl AsRef<PushMessageContentChatAddMembers> for RTDPushMessageContentChatAddMem
   fn as_ref(&self) -> &PushMessageContentChatAddMembers { &self.inner }
 }
 
+impl PartialEq for PushMessageContentChatAddMembers {
+  fn eq(&self, other: &Self) -> bool {
+    self.member_name == other.member_name
+      && self.is_current_user == other.is_current_user
+      && self.is_returned == other.is_returned
+  }
+}
+
 
 
 
@@ -673,13 +787,17 @@ pub struct PushMessageContentChatChangePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PushMessageContentChatChangePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatChangePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentChatChangePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -689,14 +807,15 @@ impl TDPushMessageContent for PushMessageContentChatChangePhoto {}
 
 
 impl PushMessageContentChatChangePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentChatChangePhotoBuilder {
-    let mut inner = PushMessageContentChatChangePhoto::default();
-    inner.td_name = "pushMessageContentChatChangePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentChatChangePhoto { td_name: "pushMessageContentChatChangePhoto".to_string(), ..Default::default() };
     RTDPushMessageContentChatChangePhotoBuilder { inner }
   }
 
+  /// `PushMessageContentChatChangePhoto` with the fields tdlib requires, the others are set by `PushMessageContentChatChangePhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -707,6 +826,12 @@ pub struct RTDPushMessageContentChatChangePhotoBuilder {
 impl RTDPushMessageContentChatChangePhotoBuilder {
   pub fn build(&self) -> PushMessageContentChatChangePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentChatChangePhoto> for PushMessageContentChatChangePhoto {
@@ -717,6 +842,10 @@ impl AsRef<PushMessageContentChatChangePhoto> for RTDPushMessageContentChatChang
   fn as_ref(&self) -> &PushMessageContentChatChangePhoto { &self.inner }
 }
 
+impl PartialEq for PushMessageContentChatChangePhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -731,15 +860,19 @@ pub struct PushMessageContentChatChangeTitle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New chat title
   title: String,
   
 }
 
 impl RObject for PushMessageContentChatChangeTitle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatChangeTitle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentChatChangeTitle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -749,14 +882,15 @@ impl TDPushMessageContent for PushMessageContentChatChangeTitle {}
 
 
 impl PushMessageContentChatChangeTitle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentChatChangeTitleBuilder {
-    let mut inner = PushMessageContentChatChangeTitle::default();
-    inner.td_name = "pushMessageContentChatChangeTitle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentChatChangeTitle { td_name: "pushMessageContentChatChangeTitle".to_string(), ..Default::default() };
     RTDPushMessageContentChatChangeTitleBuilder { inner }
   }
 
+  /// `PushMessageContentChatChangeTitle` with the fields tdlib requires, the others are set by `PushMessageContentChatChangeTitle::builder()`
+  pub fn new(title: impl AsRef<str>) -> Self { Self::builder().title(title).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
 }
@@ -769,6 +903,12 @@ pub struct RTDPushMessageContentChatChangeTitleBuilder {
 impl RTDPushMessageContentChatChangeTitleBuilder {
   pub fn build(&self) -> PushMessageContentChatChangeTitle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -785,6 +925,12 @@ impl AsRef<PushMessageContentChatChangeTitle> for RTDPushMessageContentChatChang
   fn as_ref(&self) -> &PushMessageContentChatChangeTitle { &self.inner }
 }
 
+impl PartialEq for PushMessageContentChatChangeTitle {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+  }
+}
+
 
 
 
@@ -799,7 +945,10 @@ pub struct PushMessageContentChatDeleteMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the deleted member
   member_name: String,
   /// True, if the current user was deleted from the group
@@ -810,8 +959,9 @@ pub struct PushMessageContentChatDeleteMember {
 }
 
 impl RObject for PushMessageContentChatDeleteMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatDeleteMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentChatDeleteMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -821,14 +971,15 @@ impl TDPushMessageContent for PushMessageContentChatDeleteMember {}
 
 
 impl PushMessageContentChatDeleteMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentChatDeleteMemberBuilder {
-    let mut inner = PushMessageContentChatDeleteMember::default();
-    inner.td_name = "pushMessageContentChatDeleteMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentChatDeleteMember { td_name: "pushMessageContentChatDeleteMember".to_string(), ..Default::default() };
     RTDPushMessageContentChatDeleteMemberBuilder { inner }
   }
 
+  /// `PushMessageContentChatDeleteMember` with the fields tdlib requires, the others are set by `PushMessageContentChatDeleteMember::builder()`
+  pub fn new(member_name: impl AsRef<str>, is_current_user: bool, is_left: bool) -> Self { Self::builder().member_name(member_name).is_current_user(is_current_user).is_left(is_left).build() }
+
   pub fn member_name(&self) -> &String { &self.member_name }
 
   pub fn is_current_user(&self) -> bool { self.is_current_user }
@@ -845,6 +996,12 @@ pub struct RTDPushMessageContentChatDeleteMemberBuilder {
 impl RTDPushMessageContentChatDeleteMemberBuilder {
   pub fn build(&self) -> PushMessageContentChatDeleteMember { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
     self.inner.member_name = member_name.as_ref().to_string();
@@ -873,6 +1030,14 @@ impl AsRef<PushMessageContentChatDeleteMember> for RTDPushMessageContentChatDele
   fn as_ref(&self) -> &PushMessageContentChatDeleteMember { &self.inner }
 }
 
+impl PartialEq for PushMessageContentChatDeleteMember {
+  fn eq(&self, other: &Self) -> bool {
+    self.member_name == other.member_name
+      && self.is_current_user == other.is_current_user
+      && self.is_left == other.is_left
+  }
+}
+
 
 
 
@@ -887,13 +1052,17 @@ pub struct PushMessageContentChatJoinByLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PushMessageContentChatJoinByLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatJoinByLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentChatJoinByLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -903,14 +1072,15 @@ impl TDPushMessageContent for PushMessageContentChatJoinByLink {}
 
 
 impl PushMessageContentChatJoinByLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentChatJoinByLinkBuilder {
-    let mut inner = PushMessageContentChatJoinByLink::default();
-    inner.td_name = "pushMessageContentChatJoinByLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentChatJoinByLink { td_name: "pushMessageContentChatJoinByLink".to_string(), ..Default::default() };
     RTDPushMessageContentChatJoinByLinkBuilder { inner }
   }
 
+  /// `PushMessageContentChatJoinByLink` with the fields tdlib requires, the others are set by `PushMessageContentChatJoinByLink::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -921,6 +1091,12 @@ pub struct RTDPushMessageContentChatJoinByLinkBuilder {
 impl RTDPushMessageContentChatJoinByLinkBuilder {
   pub fn build(&self) -> PushMessageContentChatJoinByLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentChatJoinByLink> for PushMessageContentChatJoinByLink {
@@ -931,6 +1107,10 @@ impl AsRef<PushMessageContentChatJoinByLink> for RTDPushMessageContentChatJoinBy
   fn as_ref(&self) -> &PushMessageContentChatJoinByLink { &self.inner }
 }
 
+impl PartialEq for PushMessageContentChatJoinByLink {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -945,13 +1125,17 @@ pub struct PushMessageContentChatJoinByRequest {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PushMessageContentChatJoinByRequest {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatJoinByRequest" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentChatJoinByRequest" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -961,14 +1145,15 @@ impl TDPushMessageContent for PushMessageContentChatJoinByRequest {}
 
 
 impl PushMessageContentChatJoinByRequest {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentChatJoinByRequestBuilder {
-    let mut inner = PushMessageContentChatJoinByRequest::default();
-    inner.td_name = "pushMessageContentChatJoinByRequest".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentChatJoinByRequest { td_name: "pushMessageContentChatJoinByRequest".to_string(), ..Default::default() };
     RTDPushMessageContentChatJoinByRequestBuilder { inner }
   }
 
+  /// `PushMessageContentChatJoinByRequest` with the fields tdlib requires, the others are set by `PushMessageContentChatJoinByRequest::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -979,6 +1164,12 @@ pub struct RTDPushMessageContentChatJoinByRequestBuilder {
 impl RTDPushMessageContentChatJoinByRequestBuilder {
   pub fn build(&self) -> PushMessageContentChatJoinByRequest { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentChatJoinByRequest> for PushMessageContentChatJoinByRequest {
@@ -989,6 +1180,10 @@ impl AsRef<PushMessageContentChatJoinByRequest> for RTDPushMessageContentChatJoi
   fn as_ref(&self) -> &PushMessageContentChatJoinByRequest { &self.inner }
 }
 
+impl PartialEq for PushMessageContentChatJoinByRequest {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1003,15 +1198,19 @@ pub struct PushMessageContentChatSetTheme {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If non-empty, name of a new theme, set for the chat. Otherwise chat theme was reset to the default one
   theme_name: String,
   
 }
 
 impl RObject for PushMessageContentChatSetTheme {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentChatSetTheme" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentChatSetTheme" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1021,14 +1220,15 @@ impl TDPushMessageContent for PushMessageContentChatSetTheme {}
 
 
 impl PushMessageContentChatSetTheme {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentChatSetThemeBuilder {
-    let mut inner = PushMessageContentChatSetTheme::default();
-    inner.td_name = "pushMessageContentChatSetTheme".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentChatSetTheme { td_name: "pushMessageContentChatSetTheme".to_string(), ..Default::default() };
     RTDPushMessageContentChatSetThemeBuilder { inner }
   }
 
+  /// `PushMessageContentChatSetTheme` with the fields tdlib requires, the others are set by `PushMessageContentChatSetTheme::builder()`
+  pub fn new(theme_name: impl AsRef<str>) -> Self { Self::builder().theme_name(theme_name).build() }
+
   pub fn theme_name(&self) -> &String { &self.theme_name }
 
 }
@@ -1041,6 +1241,12 @@ pub struct RTDPushMessageContentChatSetThemeBuilder {
 impl RTDPushMessageContentChatSetThemeBuilder {
   pub fn build(&self) -> PushMessageContentChatSetTheme { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn theme_name<T: AsRef<str>>(&mut self, theme_name: T) -> &mut Self {
     self.inner.theme_name = theme_name.as_ref().to_string();
@@ -1057,6 +1263,12 @@ impl AsRef<PushMessageContentChatSetTheme> for RTDPushMessageContentChatSetTheme
   fn as_ref(&self) -> &PushMessageContentChatSetTheme { &self.inner }
 }
 
+impl PartialEq for PushMessageContentChatSetTheme {
+  fn eq(&self, other: &Self) -> bool {
+    self.theme_name == other.theme_name
+  }
+}
+
 
 
 
@@ -1071,7 +1283,10 @@ pub struct PushMessageContentContact {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Contact's name
   name: String,
   /// True, if the message is a pinned message with the specified content
@@ -1080,8 +1295,9 @@ pub struct PushMessageContentContact {
 }
 
 impl RObject for PushMessageContentContact {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentContact" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentContact" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1091,14 +1307,15 @@ impl TDPushMessageContent for PushMessageContentContact {}
 
 
 impl PushMessageContentContact {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentContactBuilder {
-    let mut inner = PushMessageContentContact::default();
-    inner.td_name = "pushMessageContentContact".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentContact { td_name: "pushMessageContentContact".to_string(), ..Default::default() };
     RTDPushMessageContentContactBuilder { inner }
   }
 
+  /// `PushMessageContentContact` with the fields tdlib requires, the others are set by `PushMessageContentContact::builder()`
+  pub fn new(name: impl AsRef<str>, is_pinned: bool) -> Self { Self::builder().name(name).is_pinned(is_pinned).build() }
+
   pub fn name(&self) -> &String { &self.name }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -1113,6 +1330,12 @@ pub struct RTDPushMessageContentContactBuilder {
 impl RTDPushMessageContentContactBuilder {
   pub fn build(&self) -> PushMessageContentContact { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -1135,6 +1358,13 @@ impl AsRef<PushMessageContentContact> for RTDPushMessageContentContactBuilder {
   fn as_ref(&self) -> &PushMessageContentContact { &self.inner }
 }
 
+impl PartialEq for PushMessageContentContact {
+  fn eq(&self, other: &Self) -> bool {
+    self.name == other.name
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -1149,13 +1379,17 @@ pub struct PushMessageContentContactRegistered {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PushMessageContentContactRegistered {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentContactRegistered" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentContactRegistered" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1165,14 +1399,15 @@ impl TDPushMessageContent for PushMessageContentContactRegistered {}
 
 
 impl PushMessageContentContactRegistered {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentContactRegisteredBuilder {
-    let mut inner = PushMessageContentContactRegistered::default();
-    inner.td_name = "pushMessageContentContactRegistered".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentContactRegistered { td_name: "pushMessageContentContactRegistered".to_string(), ..Default::default() };
     RTDPushMessageContentContactRegisteredBuilder { inner }
   }
 
+  /// `PushMessageContentContactRegistered` with the fields tdlib requires, the others are set by `PushMessageContentContactRegistered::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1183,6 +1418,12 @@ pub struct RTDPushMessageContentContactRegisteredBuilder {
 impl RTDPushMessageContentContactRegisteredBuilder {
   pub fn build(&self) -> PushMessageContentContactRegistered { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentContactRegistered> for PushMessageContentContactRegistered {
@@ -1193,6 +1434,10 @@ impl AsRef<PushMessageContentContactRegistered> for RTDPushMessageContentContact
   fn as_ref(&self) -> &PushMessageContentContactRegistered { &self.inner }
 }
 
+impl PartialEq for PushMessageContentContactRegistered {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1207,7 +1452,10 @@ pub struct PushMessageContentDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   document: Option<Document>,
   /// True, if the message is a pinned message with the specified content
@@ -1216,8 +1464,9 @@ pub struct PushMessageContentDocument {
 }
 
 impl RObject for PushMessageContentDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1227,14 +1476,15 @@ impl TDPushMessageContent for PushMessageContentDocument {}
 
 
 impl PushMessageContentDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentDocumentBuilder {
-    let mut inner = PushMessageContentDocument::default();
-    inner.td_name = "pushMessageContentDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentDocument { td_name: "pushMessageContentDocument".to_string(), ..Default::default() };
     RTDPushMessageContentDocumentBuilder { inner }
   }
 
+  /// `PushMessageContentDocument` with the fields tdlib requires, the others are set by `PushMessageContentDocument::builder()`
+  pub fn new(is_pinned: bool) -> Self { Self::builder().is_pinned(is_pinned).build() }
+
   pub fn document(&self) -> &Option<Document> { &self.document }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -1249,6 +1499,12 @@ pub struct RTDPushMessageContentDocumentBuilder {
 impl RTDPushMessageContentDocumentBuilder {
   pub fn build(&self) -> PushMessageContentDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = Some(document.as_ref().clone());
@@ -1271,6 +1527,13 @@ impl AsRef<PushMessageContentDocument> for RTDPushMessageContentDocumentBuilder
   fn as_ref(&self) -> &PushMessageContentDocument { &self.inner }
 }
 
+impl PartialEq for PushMessageContentDocument {
+  fn eq(&self, other: &Self) -> bool {
+    self.document == other.document
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -1285,7 +1548,10 @@ pub struct PushMessageContentGame {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Game title, empty for pinned game message
   title: String,
   /// True, if the message is a pinned message with the specified content
@@ -1294,8 +1560,9 @@ pub struct PushMessageContentGame {
 }
 
 impl RObject for PushMessageContentGame {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentGame" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentGame" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1305,14 +1572,15 @@ impl TDPushMessageContent for PushMessageContentGame {}
 
 
 impl PushMessageContentGame {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentGameBuilder {
-    let mut inner = PushMessageContentGame::default();
-    inner.td_name = "pushMessageContentGame".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentGame { td_name: "pushMessageContentGame".to_string(), ..Default::default() };
     RTDPushMessageContentGameBuilder { inner }
   }
 
+  /// `PushMessageContentGame` with the fields tdlib requires, the others are set by `PushMessageContentGame::builder()`
+  pub fn new(title: impl AsRef<str>, is_pinned: bool) -> Self { Self::builder().title(title).is_pinned(is_pinned).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -1327,6 +1595,12 @@ pub struct RTDPushMessageContentGameBuilder {
 impl RTDPushMessageContentGameBuilder {
   pub fn build(&self) -> PushMessageContentGame { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -1349,6 +1623,13 @@ impl AsRef<PushMessageContentGame> for RTDPushMessageContentGameBuilder {
   fn as_ref(&self) -> &PushMessageContentGame { &self.inner }
 }
 
+impl PartialEq for PushMessageContentGame {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -1363,7 +1644,10 @@ pub struct PushMessageContentGameScore {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Game title, empty for pinned message
   title: String,
   /// New score, 0 for pinned message
@@ -1374,8 +1658,9 @@ pub struct PushMessageContentGameScore {
 }
 
 impl RObject for PushMessageContentGameScore {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentGameScore" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentGameScore" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1385,14 +1670,15 @@ impl TDPushMessageContent for PushMessageContentGameScore {}
 
 
 impl PushMessageContentGameScore {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentGameScoreBuilder {
-    let mut inner = PushMessageContentGameScore::default();
-    inner.td_name = "pushMessageContentGameScore".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentGameScore { td_name: "pushMessageContentGameScore".to_string(), ..Default::default() };
     RTDPushMessageContentGameScoreBuilder { inner }
   }
 
+  /// `PushMessageContentGameScore` with the fields tdlib requires, the others are set by `PushMessageContentGameScore::builder()`
+  pub fn new(title: impl AsRef<str>, score: i64, is_pinned: bool) -> Self { Self::builder().title(title).score(score).is_pinned(is_pinned).build() }
+
   pub fn title(&self) -> &String { &self.title }
 
   pub fn score(&self) -> i64 { self.score }
@@ -1409,6 +1695,12 @@ pub struct RTDPushMessageContentGameScoreBuilder {
 impl RTDPushMessageContentGameScoreBuilder {
   pub fn build(&self) -> PushMessageContentGameScore { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -1437,6 +1729,14 @@ impl AsRef<PushMessageContentGameScore> for RTDPushMessageContentGameScoreBuilde
   fn as_ref(&self) -> &PushMessageContentGameScore { &self.inner }
 }
 
+impl PartialEq for PushMessageContentGameScore {
+  fn eq(&self, other: &Self) -> bool {
+    self.title == other.title
+      && self.score == other.score
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -1451,15 +1751,19 @@ pub struct PushMessageContentHidden {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the message is a pinned message with the specified content
   is_pinned: bool,
   
 }
 
 impl RObject for PushMessageContentHidden {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentHidden" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentHidden" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1469,14 +1773,15 @@ impl TDPushMessageContent for PushMessageContentHidden {}
 
 
 impl PushMessageContentHidden {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentHiddenBuilder {
-    let mut inner = PushMessageContentHidden::default();
-    inner.td_name = "pushMessageContentHidden".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentHidden { td_name: "pushMessageContentHidden".to_string(), ..Default::default() };
     RTDPushMessageContentHiddenBuilder { inner }
   }
 
+  /// `PushMessageContentHidden` with the fields tdlib requires, the others are set by `PushMessageContentHidden::builder()`
+  pub fn new(is_pinned: bool) -> Self { Self::builder().is_pinned(is_pinned).build() }
+
   pub fn is_pinned(&self) -> bool { self.is_pinned }
 
 }
@@ -1489,6 +1794,12 @@ pub struct RTDPushMessageContentHiddenBuilder {
 impl RTDPushMessageContentHiddenBuilder {
   pub fn build(&self) -> PushMessageContentHidden { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_pinned(&mut self, is_pinned: bool) -> &mut Self {
     self.inner.is_pinned = is_pinned;
@@ -1505,12 +1816,20 @@ impl AsRef<PushMessageContentHidden> for RTDPushMessageContentHiddenBuilder {
   fn as_ref(&self) -> &PushMessageContentHidden { &self.inner }
 }
 
+impl PartialEq for PushMessageContentHidden {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_pinned == other.is_pinned
+  }
+}
 
 
 
 
 
 
+
+rtd_cfg! { #[cfg(feature = "payments")]
+
 /// A message with an invoice from a bot
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PushMessageContentInvoice {
@@ -1519,7 +1838,10 @@ pub struct PushMessageContentInvoice {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Product price
   price: String,
   /// True, if the message is a pinned message with the specified content
@@ -1528,8 +1850,9 @@ pub struct PushMessageContentInvoice {
 }
 
 impl RObject for PushMessageContentInvoice {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentInvoice" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentInvoice" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1539,14 +1862,15 @@ impl TDPushMessageContent for PushMessageContentInvoice {}
 
 
 impl PushMessageContentInvoice {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentInvoiceBuilder {
-    let mut inner = PushMessageContentInvoice::default();
-    inner.td_name = "pushMessageContentInvoice".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentInvoice { td_name: "pushMessageContentInvoice".to_string(), ..Default::default() };
     RTDPushMessageContentInvoiceBuilder { inner }
   }
 
+  /// `PushMessageContentInvoice` with the fields tdlib requires, the others are set by `PushMessageContentInvoice::builder()`
+  pub fn new(price: impl AsRef<str>, is_pinned: bool) -> Self { Self::builder().price(price).is_pinned(is_pinned).build() }
+
   pub fn price(&self) -> &String { &self.price }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -1561,6 +1885,12 @@ pub struct RTDPushMessageContentInvoiceBuilder {
 impl RTDPushMessageContentInvoiceBuilder {
   pub fn build(&self) -> PushMessageContentInvoice { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn price<T: AsRef<str>>(&mut self, price: T) -> &mut Self {
     self.inner.price = price.as_ref().to_string();
@@ -1583,6 +1913,15 @@ impl AsRef<PushMessageContentInvoice> for RTDPushMessageContentInvoiceBuilder {
   fn as_ref(&self) -> &PushMessageContentInvoice { &self.inner }
 }
 
+impl PartialEq for PushMessageContentInvoice {
+  fn eq(&self, other: &Self) -> bool {
+    self.price == other.price
+      && self.is_pinned == other.is_pinned
+  }
+}
+
+}
+
 
 
 
@@ -1597,7 +1936,10 @@ pub struct PushMessageContentLocation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the location is live
   is_live: bool,
   /// True, if the message is a pinned message with the specified content
@@ -1606,8 +1948,9 @@ pub struct PushMessageContentLocation {
 }
 
 impl RObject for PushMessageContentLocation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentLocation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentLocation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1617,14 +1960,15 @@ impl TDPushMessageContent for PushMessageContentLocation {}
 
 
 impl PushMessageContentLocation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentLocationBuilder {
-    let mut inner = PushMessageContentLocation::default();
-    inner.td_name = "pushMessageContentLocation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentLocation { td_name: "pushMessageContentLocation".to_string(), ..Default::default() };
     RTDPushMessageContentLocationBuilder { inner }
   }
 
+  /// `PushMessageContentLocation` with the fields tdlib requires, the others are set by `PushMessageContentLocation::builder()`
+  pub fn new(is_live: bool, is_pinned: bool) -> Self { Self::builder().is_live(is_live).is_pinned(is_pinned).build() }
+
   pub fn is_live(&self) -> bool { self.is_live }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -1639,6 +1983,12 @@ pub struct RTDPushMessageContentLocationBuilder {
 impl RTDPushMessageContentLocationBuilder {
   pub fn build(&self) -> PushMessageContentLocation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_live(&mut self, is_live: bool) -> &mut Self {
     self.inner.is_live = is_live;
@@ -1661,6 +2011,13 @@ impl AsRef<PushMessageContentLocation> for RTDPushMessageContentLocationBuilder
   fn as_ref(&self) -> &PushMessageContentLocation { &self.inner }
 }
 
+impl PartialEq for PushMessageContentLocation {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_live == other.is_live
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -1675,7 +2032,10 @@ pub struct PushMessageContentMediaAlbum {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Number of messages in the album
   total_count: i64,
   /// True, if the album has at least one photo
@@ -1690,8 +2050,9 @@ pub struct PushMessageContentMediaAlbum {
 }
 
 impl RObject for PushMessageContentMediaAlbum {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentMediaAlbum" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentMediaAlbum" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1701,14 +2062,29 @@ impl TDPushMessageContent for PushMessageContentMediaAlbum {}
 
 
 impl PushMessageContentMediaAlbum {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentMediaAlbumBuilder {
-    let mut inner = PushMessageContentMediaAlbum::default();
-    inner.td_name = "pushMessageContentMediaAlbum".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentMediaAlbum { td_name: "pushMessageContentMediaAlbum".to_string(), ..Default::default() };
     RTDPushMessageContentMediaAlbumBuilder { inner }
   }
 
+  /// `PushMessageContentMediaAlbum` with the fields tdlib requires, the others are set by `PushMessageContentMediaAlbum::builder()`
+  pub fn new(
+    total_count: i64,
+    has_photos: bool,
+    has_videos: bool,
+    has_audios: bool,
+    has_documents: bool,
+  ) -> Self {
+    Self::builder()
+      .total_count(total_count)
+      .has_photos(has_photos)
+      .has_videos(has_videos)
+      .has_audios(has_audios)
+      .has_documents(has_documents)
+      .build()
+  }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
   pub fn has_photos(&self) -> bool { self.has_photos }
@@ -1729,6 +2105,12 @@ pub struct RTDPushMessageContentMediaAlbumBuilder {
 impl RTDPushMessageContentMediaAlbumBuilder {
   pub fn build(&self) -> PushMessageContentMediaAlbum { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -1769,6 +2151,16 @@ impl AsRef<PushMessageContentMediaAlbum> for RTDPushMessageContentMediaAlbumBuil
   fn as_ref(&self) -> &PushMessageContentMediaAlbum { &self.inner }
 }
 
+impl PartialEq for PushMessageContentMediaAlbum {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+      && self.has_photos == other.has_photos
+      && self.has_videos == other.has_videos
+      && self.has_audios == other.has_audios
+      && self.has_documents == other.has_documents
+  }
+}
+
 
 
 
@@ -1783,15 +2175,19 @@ pub struct PushMessageContentMessageForwards {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Number of forwarded messages
   total_count: i64,
   
 }
 
 impl RObject for PushMessageContentMessageForwards {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentMessageForwards" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentMessageForwards" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1801,14 +2197,15 @@ impl TDPushMessageContent for PushMessageContentMessageForwards {}
 
 
 impl PushMessageContentMessageForwards {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentMessageForwardsBuilder {
-    let mut inner = PushMessageContentMessageForwards::default();
-    inner.td_name = "pushMessageContentMessageForwards".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentMessageForwards { td_name: "pushMessageContentMessageForwards".to_string(), ..Default::default() };
     RTDPushMessageContentMessageForwardsBuilder { inner }
   }
 
+  /// `PushMessageContentMessageForwards` with the fields tdlib requires, the others are set by `PushMessageContentMessageForwards::builder()`
+  pub fn new(total_count: i64) -> Self { Self::builder().total_count(total_count).build() }
+
   pub fn total_count(&self) -> i64 { self.total_count }
 
 }
@@ -1821,6 +2218,12 @@ pub struct RTDPushMessageContentMessageForwardsBuilder {
 impl RTDPushMessageContentMessageForwardsBuilder {
   pub fn build(&self) -> PushMessageContentMessageForwards { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
@@ -1837,6 +2240,12 @@ impl AsRef<PushMessageContentMessageForwards> for RTDPushMessageContentMessageFo
   fn as_ref(&self) -> &PushMessageContentMessageForwards { &self.inner }
 }
 
+impl PartialEq for PushMessageContentMessageForwards {
+  fn eq(&self, other: &Self) -> bool {
+    self.total_count == other.total_count
+  }
+}
+
 
 
 
@@ -1851,7 +2260,10 @@ pub struct PushMessageContentPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   photo: Option<Photo>,
   /// Photo caption
@@ -1864,8 +2276,9 @@ pub struct PushMessageContentPhoto {
 }
 
 impl RObject for PushMessageContentPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1875,14 +2288,15 @@ impl TDPushMessageContent for PushMessageContentPhoto {}
 
 
 impl PushMessageContentPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentPhotoBuilder {
-    let mut inner = PushMessageContentPhoto::default();
-    inner.td_name = "pushMessageContentPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentPhoto { td_name: "pushMessageContentPhoto".to_string(), ..Default::default() };
     RTDPushMessageContentPhotoBuilder { inner }
   }
 
+  /// `PushMessageContentPhoto` with the fields tdlib requires, the others are set by `PushMessageContentPhoto::builder()`
+  pub fn new(caption: impl AsRef<str>, is_secret: bool, is_pinned: bool) -> Self { Self::builder().caption(caption).is_secret(is_secret).is_pinned(is_pinned).build() }
+
   pub fn photo(&self) -> &Option<Photo> { &self.photo }
 
   pub fn caption(&self) -> &String { &self.caption }
@@ -1901,6 +2315,12 @@ pub struct RTDPushMessageContentPhotoBuilder {
 impl RTDPushMessageContentPhotoBuilder {
   pub fn build(&self) -> PushMessageContentPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -1935,6 +2355,15 @@ impl AsRef<PushMessageContentPhoto> for RTDPushMessageContentPhotoBuilder {
   fn as_ref(&self) -> &PushMessageContentPhoto { &self.inner }
 }
 
+impl PartialEq for PushMessageContentPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+      && self.caption == other.caption
+      && self.is_secret == other.is_secret
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -1949,7 +2378,10 @@ pub struct PushMessageContentPoll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Poll question
   question: String,
   /// True, if the poll is regular and not in quiz mode
@@ -1960,8 +2392,9 @@ pub struct PushMessageContentPoll {
 }
 
 impl RObject for PushMessageContentPoll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentPoll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentPoll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1971,14 +2404,15 @@ impl TDPushMessageContent for PushMessageContentPoll {}
 
 
 impl PushMessageContentPoll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentPollBuilder {
-    let mut inner = PushMessageContentPoll::default();
-    inner.td_name = "pushMessageContentPoll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentPoll { td_name: "pushMessageContentPoll".to_string(), ..Default::default() };
     RTDPushMessageContentPollBuilder { inner }
   }
 
+  /// `PushMessageContentPoll` with the fields tdlib requires, the others are set by `PushMessageContentPoll::builder()`
+  pub fn new(question: impl AsRef<str>, is_regular: bool, is_pinned: bool) -> Self { Self::builder().question(question).is_regular(is_regular).is_pinned(is_pinned).build() }
+
   pub fn question(&self) -> &String { &self.question }
 
   pub fn is_regular(&self) -> bool { self.is_regular }
@@ -1995,6 +2429,12 @@ pub struct RTDPushMessageContentPollBuilder {
 impl RTDPushMessageContentPollBuilder {
   pub fn build(&self) -> PushMessageContentPoll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
     self.inner.question = question.as_ref().to_string();
@@ -2023,6 +2463,14 @@ impl AsRef<PushMessageContentPoll> for RTDPushMessageContentPollBuilder {
   fn as_ref(&self) -> &PushMessageContentPoll { &self.inner }
 }
 
+impl PartialEq for PushMessageContentPoll {
+  fn eq(&self, other: &Self) -> bool {
+    self.question == other.question
+      && self.is_regular == other.is_regular
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -2037,13 +2485,17 @@ pub struct PushMessageContentScreenshotTaken {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for PushMessageContentScreenshotTaken {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentScreenshotTaken" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentScreenshotTaken" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2053,14 +2505,15 @@ impl TDPushMessageContent for PushMessageContentScreenshotTaken {}
 
 
 impl PushMessageContentScreenshotTaken {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentScreenshotTakenBuilder {
-    let mut inner = PushMessageContentScreenshotTaken::default();
-    inner.td_name = "pushMessageContentScreenshotTaken".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentScreenshotTaken { td_name: "pushMessageContentScreenshotTaken".to_string(), ..Default::default() };
     RTDPushMessageContentScreenshotTakenBuilder { inner }
   }
 
+  /// `PushMessageContentScreenshotTaken` with the fields tdlib requires, the others are set by `PushMessageContentScreenshotTaken::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -2071,6 +2524,12 @@ pub struct RTDPushMessageContentScreenshotTakenBuilder {
 impl RTDPushMessageContentScreenshotTakenBuilder {
   pub fn build(&self) -> PushMessageContentScreenshotTaken { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<PushMessageContentScreenshotTaken> for PushMessageContentScreenshotTaken {
@@ -2081,11 +2540,17 @@ impl AsRef<PushMessageContentScreenshotTaken> for RTDPushMessageContentScreensho
   fn as_ref(&self) -> &PushMessageContentScreenshotTaken { &self.inner }
 }
 
+impl PartialEq for PushMessageContentScreenshotTaken {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
 
 /// A message with a sticker
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -2095,7 +2560,10 @@ pub struct PushMessageContentSticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   sticker: Option<Sticker>,
   /// Emoji corresponding to the sticker; may be empty
@@ -2106,8 +2574,9 @@ pub struct PushMessageContentSticker {
 }
 
 impl RObject for PushMessageContentSticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentSticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentSticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2117,14 +2586,15 @@ impl TDPushMessageContent for PushMessageContentSticker {}
 
 
 impl PushMessageContentSticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentStickerBuilder {
-    let mut inner = PushMessageContentSticker::default();
-    inner.td_name = "pushMessageContentSticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentSticker { td_name: "pushMessageContentSticker".to_string(), ..Default::default() };
     RTDPushMessageContentStickerBuilder { inner }
   }
 
+  /// `PushMessageContentSticker` with the fields tdlib requires, the others are set by `PushMessageContentSticker::builder()`
+  pub fn new(emoji: impl AsRef<str>, is_pinned: bool) -> Self { Self::builder().emoji(emoji).is_pinned(is_pinned).build() }
+
   pub fn sticker(&self) -> &Option<Sticker> { &self.sticker }
 
   pub fn emoji(&self) -> &String { &self.emoji }
@@ -2141,6 +2611,12 @@ pub struct RTDPushMessageContentStickerBuilder {
 impl RTDPushMessageContentStickerBuilder {
   pub fn build(&self) -> PushMessageContentSticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = Some(sticker.as_ref().clone());
@@ -2169,6 +2645,16 @@ impl AsRef<PushMessageContentSticker> for RTDPushMessageContentStickerBuilder {
   fn as_ref(&self) -> &PushMessageContentSticker { &self.inner }
 }
 
+impl PartialEq for PushMessageContentSticker {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker == other.sticker
+      && self.emoji == other.emoji
+      && self.is_pinned == other.is_pinned
+  }
+}
+
+}
+
 
 
 
@@ -2183,7 +2669,10 @@ pub struct PushMessageContentText {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message text
   text: String,
   /// True, if the message is a pinned message with the specified content
@@ -2192,8 +2681,9 @@ pub struct PushMessageContentText {
 }
 
 impl RObject for PushMessageContentText {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentText" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentText" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2203,14 +2693,15 @@ impl TDPushMessageContent for PushMessageContentText {}
 
 
 impl PushMessageContentText {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentTextBuilder {
-    let mut inner = PushMessageContentText::default();
-    inner.td_name = "pushMessageContentText".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentText { td_name: "pushMessageContentText".to_string(), ..Default::default() };
     RTDPushMessageContentTextBuilder { inner }
   }
 
+  /// `PushMessageContentText` with the fields tdlib requires, the others are set by `PushMessageContentText::builder()`
+  pub fn new(text: impl AsRef<str>, is_pinned: bool) -> Self { Self::builder().text(text).is_pinned(is_pinned).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -2225,6 +2716,12 @@ pub struct RTDPushMessageContentTextBuilder {
 impl RTDPushMessageContentTextBuilder {
   pub fn build(&self) -> PushMessageContentText { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -2247,6 +2744,13 @@ impl AsRef<PushMessageContentText> for RTDPushMessageContentTextBuilder {
   fn as_ref(&self) -> &PushMessageContentText { &self.inner }
 }
 
+impl PartialEq for PushMessageContentText {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -2261,7 +2765,10 @@ pub struct PushMessageContentVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   video: Option<Video>,
   /// Video caption
@@ -2274,8 +2781,9 @@ pub struct PushMessageContentVideo {
 }
 
 impl RObject for PushMessageContentVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2285,14 +2793,15 @@ impl TDPushMessageContent for PushMessageContentVideo {}
 
 
 impl PushMessageContentVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentVideoBuilder {
-    let mut inner = PushMessageContentVideo::default();
-    inner.td_name = "pushMessageContentVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentVideo { td_name: "pushMessageContentVideo".to_string(), ..Default::default() };
     RTDPushMessageContentVideoBuilder { inner }
   }
 
+  /// `PushMessageContentVideo` with the fields tdlib requires, the others are set by `PushMessageContentVideo::builder()`
+  pub fn new(caption: impl AsRef<str>, is_secret: bool, is_pinned: bool) -> Self { Self::builder().caption(caption).is_secret(is_secret).is_pinned(is_pinned).build() }
+
   pub fn video(&self) -> &Option<Video> { &self.video }
 
   pub fn caption(&self) -> &String { &self.caption }
@@ -2311,6 +2820,12 @@ pub struct RTDPushMessageContentVideoBuilder {
 impl RTDPushMessageContentVideoBuilder {
   pub fn build(&self) -> PushMessageContentVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = Some(video.as_ref().clone());
@@ -2345,6 +2860,15 @@ impl AsRef<PushMessageContentVideo> for RTDPushMessageContentVideoBuilder {
   fn as_ref(&self) -> &PushMessageContentVideo { &self.inner }
 }
 
+impl PartialEq for PushMessageContentVideo {
+  fn eq(&self, other: &Self) -> bool {
+    self.video == other.video
+      && self.caption == other.caption
+      && self.is_secret == other.is_secret
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -2359,7 +2883,10 @@ pub struct PushMessageContentVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   video_note: Option<VideoNote>,
   /// True, if the message is a pinned message with the specified content
@@ -2368,8 +2895,9 @@ pub struct PushMessageContentVideoNote {
 }
 
 impl RObject for PushMessageContentVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2379,14 +2907,15 @@ impl TDPushMessageContent for PushMessageContentVideoNote {}
 
 
 impl PushMessageContentVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentVideoNoteBuilder {
-    let mut inner = PushMessageContentVideoNote::default();
-    inner.td_name = "pushMessageContentVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentVideoNote { td_name: "pushMessageContentVideoNote".to_string(), ..Default::default() };
     RTDPushMessageContentVideoNoteBuilder { inner }
   }
 
+  /// `PushMessageContentVideoNote` with the fields tdlib requires, the others are set by `PushMessageContentVideoNote::builder()`
+  pub fn new(is_pinned: bool) -> Self { Self::builder().is_pinned(is_pinned).build() }
+
   pub fn video_note(&self) -> &Option<VideoNote> { &self.video_note }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -2401,6 +2930,12 @@ pub struct RTDPushMessageContentVideoNoteBuilder {
 impl RTDPushMessageContentVideoNoteBuilder {
   pub fn build(&self) -> PushMessageContentVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = Some(video_note.as_ref().clone());
@@ -2423,6 +2958,13 @@ impl AsRef<PushMessageContentVideoNote> for RTDPushMessageContentVideoNoteBuilde
   fn as_ref(&self) -> &PushMessageContentVideoNote { &self.inner }
 }
 
+impl PartialEq for PushMessageContentVideoNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.video_note == other.video_note
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -2437,7 +2979,10 @@ pub struct PushMessageContentVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Message content; may be null
   voice_note: Option<VoiceNote>,
   /// True, if the message is a pinned message with the specified content
@@ -2446,8 +2991,9 @@ pub struct PushMessageContentVoiceNote {
 }
 
 impl RObject for PushMessageContentVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushMessageContentVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushMessageContentVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2457,14 +3003,15 @@ impl TDPushMessageContent for PushMessageContentVoiceNote {}
 
 
 impl PushMessageContentVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushMessageContentVoiceNoteBuilder {
-    let mut inner = PushMessageContentVoiceNote::default();
-    inner.td_name = "pushMessageContentVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushMessageContentVoiceNote { td_name: "pushMessageContentVoiceNote".to_string(), ..Default::default() };
     RTDPushMessageContentVoiceNoteBuilder { inner }
   }
 
+  /// `PushMessageContentVoiceNote` with the fields tdlib requires, the others are set by `PushMessageContentVoiceNote::builder()`
+  pub fn new(is_pinned: bool) -> Self { Self::builder().is_pinned(is_pinned).build() }
+
   pub fn voice_note(&self) -> &Option<VoiceNote> { &self.voice_note }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -2479,6 +3026,12 @@ pub struct RTDPushMessageContentVoiceNoteBuilder {
 impl RTDPushMessageContentVoiceNoteBuilder {
   pub fn build(&self) -> PushMessageContentVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -2501,5 +3054,12 @@ impl AsRef<PushMessageContentVoiceNote> for RTDPushMessageContentVoiceNoteBuilde
   fn as_ref(&self) -> &PushMessageContentVoiceNote { &self.inner }
 }
 
+impl PartialEq for PushMessageContentVoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.voice_note == other.voice_note
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,30 +13,35 @@ pub struct PushReceiverId {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The globally unique identifier of push notification subscription
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   
 }
 
 impl RObject for PushReceiverId {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "pushReceiverId" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "pushReceiverId" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl PushReceiverId {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDPushReceiverIdBuilder {
-    let mut inner = PushReceiverId::default();
-    inner.td_name = "pushReceiverId".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = PushReceiverId { td_name: "pushReceiverId".to_string(), ..Default::default() };
     RTDPushReceiverIdBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `PushReceiverId` with the fields tdlib requires, the others are set by `PushReceiverId::builder()`
+  pub fn new(id: i64) -> Self { Self::builder().id(id).build() }
+
+  pub fn id(&self) -> i64 { self.id }
 
 }
 
@@ -49,8 +53,14 @@ pub struct RTDPushReceiverIdBuilder {
 impl RTDPushReceiverIdBuilder {
   pub fn build(&self) -> PushReceiverId { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -65,5 +75,11 @@ impl AsRef<PushReceiverId> for RTDPushReceiverIdBuilder {
   fn as_ref(&self) -> &PushReceiverId { &self.inner }
 }
 
+impl PartialEq for PushReceiverId {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,31 +13,36 @@ pub struct RecommendedChatFilter {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat filter
   filter: ChatFilter,
-  /// Describes a recommended chat filter
+  /// Chat filter description
   description: String,
   
 }
 
 impl RObject for RecommendedChatFilter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "recommendedChatFilter" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "recommendedChatFilter" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl RecommendedChatFilter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRecommendedChatFilterBuilder {
-    let mut inner = RecommendedChatFilter::default();
-    inner.td_name = "recommendedChatFilter".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RecommendedChatFilter { td_name: "recommendedChatFilter".to_string(), ..Default::default() };
     RTDRecommendedChatFilterBuilder { inner }
   }
 
+  /// `RecommendedChatFilter` with the fields tdlib requires, the others are set by `RecommendedChatFilter::builder()`
+  pub fn new(filter: impl AsRef<ChatFilter>, description: impl AsRef<str>) -> Self { Self::builder().filter(filter).description(description).build() }
+
   pub fn filter(&self) -> &ChatFilter { &self.filter }
 
   pub fn description(&self) -> &String { &self.description }
@@ -53,6 +57,12 @@ pub struct RTDRecommendedChatFilterBuilder {
 impl RTDRecommendedChatFilterBuilder {
   pub fn build(&self) -> RecommendedChatFilter { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
@@ -75,5 +85,12 @@ impl AsRef<RecommendedChatFilter> for RTDRecommendedChatFilterBuilder {
   fn as_ref(&self) -> &RecommendedChatFilter { &self.inner }
 }
 
+impl PartialEq for RecommendedChatFilter {
+  fn eq(&self, other: &Self) -> bool {
+    self.filter == other.filter
+      && self.description == other.description
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct RecommendedChatFilters {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of recommended chat filters
   chat_filters: Vec<RecommendedChatFilter>,
   
 }
 
 impl RObject for RecommendedChatFilters {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "recommendedChatFilters" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "recommendedChatFilters" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl RecommendedChatFilters {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRecommendedChatFiltersBuilder {
-    let mut inner = RecommendedChatFilters::default();
-    inner.td_name = "recommendedChatFilters".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RecommendedChatFilters { td_name: "recommendedChatFilters".to_string(), ..Default::default() };
     RTDRecommendedChatFiltersBuilder { inner }
   }
 
+  /// `RecommendedChatFilters` with the fields tdlib requires, the others are set by `RecommendedChatFilters::builder()`
+  pub fn new(chat_filters: Vec<RecommendedChatFilter>) -> Self { Self::builder().chat_filters(chat_filters).build() }
+
   pub fn chat_filters(&self) -> &Vec<RecommendedChatFilter> { &self.chat_filters }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDRecommendedChatFiltersBuilder {
 impl RTDRecommendedChatFiltersBuilder {
   pub fn build(&self) -> RecommendedChatFilters { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filters(&mut self, chat_filters: Vec<RecommendedChatFilter>) -> &mut Self {
     self.inner.chat_filters = chat_filters;
@@ -65,5 +75,11 @@ impl AsRef<RecommendedChatFilters> for RTDRecommendedChatFiltersBuilder {
   fn as_ref(&self) -> &RecommendedChatFilters { &self.inner }
 }
 
+impl PartialEq for RecommendedChatFilters {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_filters == other.chat_filters
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct RecoveryEmailAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Recovery email address
   recovery_email_address: String,
   
 }
 
 impl RObject for RecoveryEmailAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "recoveryEmailAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "recoveryEmailAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl RecoveryEmailAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRecoveryEmailAddressBuilder {
-    let mut inner = RecoveryEmailAddress::default();
-    inner.td_name = "recoveryEmailAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RecoveryEmailAddress { td_name: "recoveryEmailAddress".to_string(), ..Default::default() };
     RTDRecoveryEmailAddressBuilder { inner }
   }
 
+  /// `RecoveryEmailAddress` with the fields tdlib requires, the others are set by `RecoveryEmailAddress::builder()`
+  pub fn new(recovery_email_address: impl AsRef<str>) -> Self { Self::builder().recovery_email_address(recovery_email_address).build() }
+
   pub fn recovery_email_address(&self) -> &String { &self.recovery_email_address }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDRecoveryEmailAddressBuilder {
 impl RTDRecoveryEmailAddressBuilder {
   pub fn build(&self) -> RecoveryEmailAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn recovery_email_address<T: AsRef<str>>(&mut self, recovery_email_address: T) -> &mut Self {
     self.inner.recovery_email_address = recovery_email_address.as_ref().to_string();
@@ -65,5 +75,11 @@ impl AsRef<RecoveryEmailAddress> for RTDRecoveryEmailAddressBuilder {
   fn as_ref(&self) -> &RecoveryEmailAddress { &self.inner }
 }
 
+impl PartialEq for RecoveryEmailAddress {
+  fn eq(&self, other: &Self) -> bool {
+    self.recovery_email_address == other.recovery_email_address
+  }
+}
+
 
 
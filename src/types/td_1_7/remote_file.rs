@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct RemoteFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Remote file identifier; may be empty. Can be used by the current user across application restarts or even from other devices. Uniquely identifies a file, but a file can have a lot of different valid identifiers. If the ID starts with "http://" or "https://", it represents the HTTP URL of the file. TDLib is currently unable to download files if only their URL is known. If downloadFile is called on such a file or if it is sent to a secret chat, TDLib starts a file generation process by sending updateFileGenerationStart to the application with the HTTP URL in the original_path and "#url#" as the conversion string. Application must generate the file by downloading it to the specified location
   id: String,
   /// Unique file identifier; may be empty if unknown. The unique file identifier which is the same for the same file even for different users and is persistent over time
@@ -29,22 +31,38 @@ pub struct RemoteFile {
 }
 
 impl RObject for RemoteFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "remoteFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "remoteFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl RemoteFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRemoteFileBuilder {
-    let mut inner = RemoteFile::default();
-    inner.td_name = "remoteFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RemoteFile { td_name: "remoteFile".to_string(), ..Default::default() };
     RTDRemoteFileBuilder { inner }
   }
 
+  /// `RemoteFile` with the fields tdlib requires, the others are set by `RemoteFile::builder()`
+  pub fn new(
+    id: impl AsRef<str>,
+    unique_id: impl AsRef<str>,
+    is_uploading_active: bool,
+    is_uploading_completed: bool,
+    uploaded_size: i64,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .unique_id(unique_id)
+      .is_uploading_active(is_uploading_active)
+      .is_uploading_completed(is_uploading_completed)
+      .uploaded_size(uploaded_size)
+      .build()
+  }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn unique_id(&self) -> &String { &self.unique_id }
@@ -65,6 +83,12 @@ pub struct RTDRemoteFileBuilder {
 impl RTDRemoteFileBuilder {
   pub fn build(&self) -> RemoteFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -105,5 +129,15 @@ impl AsRef<RemoteFile> for RTDRemoteFileBuilder {
   fn as_ref(&self) -> &RemoteFile { &self.inner }
 }
 
+impl PartialEq for RemoteFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.unique_id == other.unique_id
+      && self.is_uploading_active == other.is_uploading_active
+      && self.is_uploading_completed == other.is_uploading_completed
+      && self.uploaded_size == other.uploaded_size
+  }
+}
+
 
 
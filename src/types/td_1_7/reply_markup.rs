@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDReplyMarkup: Debug + RObject {}
 
 /// Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ReplyMarkup {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum ReplyMarkup {
   RemoveKeyboard(ReplyMarkupRemoveKeyboard),
   /// Contains a custom keyboard layout to quickly reply to bots
   ShowKeyboard(ReplyMarkupShowKeyboard),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for ReplyMarkup {
   fn deserialize<D>(deserializer: D) -> Result<ReplyMarkup, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ReplyMarkup,
+      ReplyMarkup, Unknown;
       (replyMarkupForceReply, ForceReply);
       (replyMarkupInlineKeyboard, InlineKeyboard);
       (replyMarkupRemoveKeyboard, RemoveKeyboard);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for ReplyMarkup {
 }
 
 impl RObject for ReplyMarkup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ReplyMarkup::ForceReply(t) => t.td_name(),
       ReplyMarkup::InlineKeyboard(t) => t.td_name(),
       ReplyMarkup::RemoveKeyboard(t) => t.td_name(),
       ReplyMarkup::ShowKeyboard(t) => t.td_name(),
+      ReplyMarkup::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ReplyMarkup::ForceReply(t) => t.extra(),
       ReplyMarkup::InlineKeyboard(t) => t.extra(),
       ReplyMarkup::RemoveKeyboard(t) => t.extra(),
       ReplyMarkup::ShowKeyboard(t) => t.extra(),
+      ReplyMarkup::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ReplyMarkup::ForceReply(t) => t.client_id(),
+      ReplyMarkup::InlineKeyboard(t) => t.client_id(),
+      ReplyMarkup::RemoveKeyboard(t) => t.client_id(),
+      ReplyMarkup::ShowKeyboard(t) => t.client_id(),
+      ReplyMarkup::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for ReplyMarkup {
 }
 
 impl ReplyMarkup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ReplyMarkup::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ReplyMarkup::_Default(_)) }
 
-  pub fn is_force_reply(&self) -> bool { if let ReplyMarkup::ForceReply(_) = self { true } else { false } }
-  pub fn is_inline_keyboard(&self) -> bool { if let ReplyMarkup::InlineKeyboard(_) = self { true } else { false } }
-  pub fn is_remove_keyboard(&self) -> bool { if let ReplyMarkup::RemoveKeyboard(_) = self { true } else { false } }
-  pub fn is_show_keyboard(&self) -> bool { if let ReplyMarkup::ShowKeyboard(_) = self { true } else { false } }
+  pub fn is_force_reply(&self) -> bool { matches!(self, ReplyMarkup::ForceReply(_)) }
+  pub fn is_inline_keyboard(&self) -> bool { matches!(self, ReplyMarkup::InlineKeyboard(_)) }
+  pub fn is_remove_keyboard(&self) -> bool { matches!(self, ReplyMarkup::RemoveKeyboard(_)) }
+  pub fn is_show_keyboard(&self) -> bool { matches!(self, ReplyMarkup::ShowKeyboard(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ReplyMarkup::Unknown(_)) }
 
   pub fn on_force_reply<F: FnOnce(&ReplyMarkupForceReply)>(&self, fnc: F) -> &Self { if let ReplyMarkup::ForceReply(t) = self { fnc(t) }; self }
   pub fn on_inline_keyboard<F: FnOnce(&ReplyMarkupInlineKeyboard)>(&self, fnc: F) -> &Self { if let ReplyMarkup::InlineKeyboard(t) = self { fnc(t) }; self }
   pub fn on_remove_keyboard<F: FnOnce(&ReplyMarkupRemoveKeyboard)>(&self, fnc: F) -> &Self { if let ReplyMarkup::RemoveKeyboard(t) = self { fnc(t) }; self }
   pub fn on_show_keyboard<F: FnOnce(&ReplyMarkupShowKeyboard)>(&self, fnc: F) -> &Self { if let ReplyMarkup::ShowKeyboard(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ReplyMarkup::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_force_reply(&self) -> Option<&ReplyMarkupForceReply> { if let ReplyMarkup::ForceReply(t) = self { return Some(t) } None }
   pub fn as_inline_keyboard(&self) -> Option<&ReplyMarkupInlineKeyboard> { if let ReplyMarkup::InlineKeyboard(t) = self { return Some(t) } None }
   pub fn as_remove_keyboard(&self) -> Option<&ReplyMarkupRemoveKeyboard> { if let ReplyMarkup::RemoveKeyboard(t) = self { return Some(t) } None }
   pub fn as_show_keyboard(&self) -> Option<&ReplyMarkupShowKeyboard> { if let ReplyMarkup::ShowKeyboard(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ReplyMarkup::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -107,6 +124,16 @@ impl AsRef<ReplyMarkup> for ReplyMarkup {
   fn as_ref(&self) -> &ReplyMarkup { self }
 }
 
+impl Validate for ReplyMarkup {
+  fn check(&self, checker: &mut Checker) {
+    match self {
+      ReplyMarkup::ForceReply(value) => value.check(checker),
+      ReplyMarkup::ShowKeyboard(value) => value.check(checker),
+      _ => {}
+    }
+  }
+}
+
 
 
 
@@ -121,7 +148,10 @@ pub struct ReplyMarkupForceReply {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if a forced reply must automatically be shown to the current user. For outgoing messages, specify true to show the forced reply only for the mentioned users and for the target user of a reply
   is_personal: bool,
   /// If non-empty, the placeholder to be shown in the input field when the reply is active; 0-64 characters
@@ -130,8 +160,9 @@ pub struct ReplyMarkupForceReply {
 }
 
 impl RObject for ReplyMarkupForceReply {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupForceReply" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "replyMarkupForceReply" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -141,14 +172,15 @@ impl TDReplyMarkup for ReplyMarkupForceReply {}
 
 
 impl ReplyMarkupForceReply {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDReplyMarkupForceReplyBuilder {
-    let mut inner = ReplyMarkupForceReply::default();
-    inner.td_name = "replyMarkupForceReply".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ReplyMarkupForceReply { td_name: "replyMarkupForceReply".to_string(), ..Default::default() };
     RTDReplyMarkupForceReplyBuilder { inner }
   }
 
+  /// `ReplyMarkupForceReply` with the fields tdlib requires, the others are set by `ReplyMarkupForceReply::builder()`
+  pub fn new(is_personal: bool, input_field_placeholder: impl AsRef<str>) -> Self { Self::builder().is_personal(is_personal).input_field_placeholder(input_field_placeholder).build() }
+
   pub fn is_personal(&self) -> bool { self.is_personal }
 
   pub fn input_field_placeholder(&self) -> &String { &self.input_field_placeholder }
@@ -163,6 +195,12 @@ pub struct RTDReplyMarkupForceReplyBuilder {
 impl RTDReplyMarkupForceReplyBuilder {
   pub fn build(&self) -> ReplyMarkupForceReply { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
     self.inner.is_personal = is_personal;
@@ -185,6 +223,19 @@ impl AsRef<ReplyMarkupForceReply> for RTDReplyMarkupForceReplyBuilder {
   fn as_ref(&self) -> &ReplyMarkupForceReply { &self.inner }
 }
 
+impl PartialEq for ReplyMarkupForceReply {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_personal == other.is_personal
+      && self.input_field_placeholder == other.input_field_placeholder
+  }
+}
+
+impl Validate for ReplyMarkupForceReply {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("input_field_placeholder", &self.input_field_placeholder, 0, 64);
+  }
+}
+
 
 
 
@@ -199,15 +250,19 @@ pub struct ReplyMarkupInlineKeyboard {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of rows of inline keyboard buttons
   rows: Vec<Vec<InlineKeyboardButton>>,
   
 }
 
 impl RObject for ReplyMarkupInlineKeyboard {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupInlineKeyboard" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "replyMarkupInlineKeyboard" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -217,14 +272,15 @@ impl TDReplyMarkup for ReplyMarkupInlineKeyboard {}
 
 
 impl ReplyMarkupInlineKeyboard {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDReplyMarkupInlineKeyboardBuilder {
-    let mut inner = ReplyMarkupInlineKeyboard::default();
-    inner.td_name = "replyMarkupInlineKeyboard".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ReplyMarkupInlineKeyboard { td_name: "replyMarkupInlineKeyboard".to_string(), ..Default::default() };
     RTDReplyMarkupInlineKeyboardBuilder { inner }
   }
 
+  /// `ReplyMarkupInlineKeyboard` with the fields tdlib requires, the others are set by `ReplyMarkupInlineKeyboard::builder()`
+  pub fn new(rows: Vec<Vec<InlineKeyboardButton>>) -> Self { Self::builder().rows(rows).build() }
+
   pub fn rows(&self) -> &Vec<Vec<InlineKeyboardButton>> { &self.rows }
 
 }
@@ -237,6 +293,12 @@ pub struct RTDReplyMarkupInlineKeyboardBuilder {
 impl RTDReplyMarkupInlineKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupInlineKeyboard { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rows(&mut self, rows: Vec<Vec<InlineKeyboardButton>>) -> &mut Self {
     self.inner.rows = rows;
@@ -253,6 +315,12 @@ impl AsRef<ReplyMarkupInlineKeyboard> for RTDReplyMarkupInlineKeyboardBuilder {
   fn as_ref(&self) -> &ReplyMarkupInlineKeyboard { &self.inner }
 }
 
+impl PartialEq for ReplyMarkupInlineKeyboard {
+  fn eq(&self, other: &Self) -> bool {
+    self.rows == other.rows
+  }
+}
+
 
 
 
@@ -267,15 +335,19 @@ pub struct ReplyMarkupRemoveKeyboard {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the keyboard is removed only for the mentioned users or the target user of a reply
   is_personal: bool,
   
 }
 
 impl RObject for ReplyMarkupRemoveKeyboard {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupRemoveKeyboard" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "replyMarkupRemoveKeyboard" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -285,14 +357,15 @@ impl TDReplyMarkup for ReplyMarkupRemoveKeyboard {}
 
 
 impl ReplyMarkupRemoveKeyboard {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDReplyMarkupRemoveKeyboardBuilder {
-    let mut inner = ReplyMarkupRemoveKeyboard::default();
-    inner.td_name = "replyMarkupRemoveKeyboard".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ReplyMarkupRemoveKeyboard { td_name: "replyMarkupRemoveKeyboard".to_string(), ..Default::default() };
     RTDReplyMarkupRemoveKeyboardBuilder { inner }
   }
 
+  /// `ReplyMarkupRemoveKeyboard` with the fields tdlib requires, the others are set by `ReplyMarkupRemoveKeyboard::builder()`
+  pub fn new(is_personal: bool) -> Self { Self::builder().is_personal(is_personal).build() }
+
   pub fn is_personal(&self) -> bool { self.is_personal }
 
 }
@@ -305,6 +378,12 @@ pub struct RTDReplyMarkupRemoveKeyboardBuilder {
 impl RTDReplyMarkupRemoveKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupRemoveKeyboard { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
     self.inner.is_personal = is_personal;
@@ -321,6 +400,12 @@ impl AsRef<ReplyMarkupRemoveKeyboard> for RTDReplyMarkupRemoveKeyboardBuilder {
   fn as_ref(&self) -> &ReplyMarkupRemoveKeyboard { &self.inner }
 }
 
+impl PartialEq for ReplyMarkupRemoveKeyboard {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_personal == other.is_personal
+  }
+}
+
 
 
 
@@ -335,7 +420,10 @@ pub struct ReplyMarkupShowKeyboard {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of rows of bot keyboard buttons
   rows: Vec<Vec<KeyboardButton>>,
   /// True, if the application needs to resize the keyboard vertically
@@ -350,8 +438,9 @@ pub struct ReplyMarkupShowKeyboard {
 }
 
 impl RObject for ReplyMarkupShowKeyboard {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "replyMarkupShowKeyboard" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "replyMarkupShowKeyboard" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -361,14 +450,29 @@ impl TDReplyMarkup for ReplyMarkupShowKeyboard {}
 
 
 impl ReplyMarkupShowKeyboard {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDReplyMarkupShowKeyboardBuilder {
-    let mut inner = ReplyMarkupShowKeyboard::default();
-    inner.td_name = "replyMarkupShowKeyboard".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ReplyMarkupShowKeyboard { td_name: "replyMarkupShowKeyboard".to_string(), ..Default::default() };
     RTDReplyMarkupShowKeyboardBuilder { inner }
   }
 
+  /// `ReplyMarkupShowKeyboard` with the fields tdlib requires, the others are set by `ReplyMarkupShowKeyboard::builder()`
+  pub fn new(
+    rows: Vec<Vec<KeyboardButton>>,
+    resize_keyboard: bool,
+    one_time: bool,
+    is_personal: bool,
+    input_field_placeholder: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .rows(rows)
+      .resize_keyboard(resize_keyboard)
+      .one_time(one_time)
+      .is_personal(is_personal)
+      .input_field_placeholder(input_field_placeholder)
+      .build()
+  }
+
   pub fn rows(&self) -> &Vec<Vec<KeyboardButton>> { &self.rows }
 
   pub fn resize_keyboard(&self) -> bool { self.resize_keyboard }
@@ -389,6 +493,12 @@ pub struct RTDReplyMarkupShowKeyboardBuilder {
 impl RTDReplyMarkupShowKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupShowKeyboard { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rows(&mut self, rows: Vec<Vec<KeyboardButton>>) -> &mut Self {
     self.inner.rows = rows;
@@ -429,5 +539,21 @@ impl AsRef<ReplyMarkupShowKeyboard> for RTDReplyMarkupShowKeyboardBuilder {
   fn as_ref(&self) -> &ReplyMarkupShowKeyboard { &self.inner }
 }
 
+impl PartialEq for ReplyMarkupShowKeyboard {
+  fn eq(&self, other: &Self) -> bool {
+    self.rows == other.rows
+      && self.resize_keyboard == other.resize_keyboard
+      && self.one_time == other.one_time
+      && self.is_personal == other.is_personal
+      && self.input_field_placeholder == other.input_field_placeholder
+  }
+}
+
+impl Validate for ReplyMarkupShowKeyboard {
+  fn check(&self, checker: &mut Checker) {
+    checker.length("input_field_placeholder", &self.input_field_placeholder, 0, 64);
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDResetPasswordResult: Debug + RObject {}
 
 /// Represents result of 2-step verification password reset
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ResetPasswordResult {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum ResetPasswordResult {
   Ok(ResetPasswordResultOk),
   /// The password reset request is pending
   Pending(ResetPasswordResultPending),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for ResetPasswordResult {
   fn deserialize<D>(deserializer: D) -> Result<ResetPasswordResult, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ResetPasswordResult,
+      ResetPasswordResult, Unknown;
       (resetPassword, ResetPassword);
       (resetPasswordResultDeclined, Declined);
       (resetPasswordResultOk, Ok);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for ResetPasswordResult {
 }
 
 impl RObject for ResetPasswordResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ResetPasswordResult::ResetPassword(t) => t.td_name(),
       ResetPasswordResult::Declined(t) => t.td_name(),
       ResetPasswordResult::Ok(t) => t.td_name(),
       ResetPasswordResult::Pending(t) => t.td_name(),
+      ResetPasswordResult::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ResetPasswordResult::ResetPassword(t) => t.extra(),
       ResetPasswordResult::Declined(t) => t.extra(),
       ResetPasswordResult::Ok(t) => t.extra(),
       ResetPasswordResult::Pending(t) => t.extra(),
+      ResetPasswordResult::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ResetPasswordResult::ResetPassword(t) => t.client_id(),
+      ResetPasswordResult::Declined(t) => t.client_id(),
+      ResetPasswordResult::Ok(t) => t.client_id(),
+      ResetPasswordResult::Pending(t) => t.client_id(),
+      ResetPasswordResult::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for ResetPasswordResult {
 }
 
 impl ResetPasswordResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ResetPasswordResult::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ResetPasswordResult::_Default(_)) }
 
-  pub fn is_reset_password(&self) -> bool { if let ResetPasswordResult::ResetPassword(_) = self { true } else { false } }
-  pub fn is_declined(&self) -> bool { if let ResetPasswordResult::Declined(_) = self { true } else { false } }
-  pub fn is_ok(&self) -> bool { if let ResetPasswordResult::Ok(_) = self { true } else { false } }
-  pub fn is_pending(&self) -> bool { if let ResetPasswordResult::Pending(_) = self { true } else { false } }
+  pub fn is_reset_password(&self) -> bool { matches!(self, ResetPasswordResult::ResetPassword(_)) }
+  pub fn is_declined(&self) -> bool { matches!(self, ResetPasswordResult::Declined(_)) }
+  pub fn is_ok(&self) -> bool { matches!(self, ResetPasswordResult::Ok(_)) }
+  pub fn is_pending(&self) -> bool { matches!(self, ResetPasswordResult::Pending(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ResetPasswordResult::Unknown(_)) }
 
   pub fn on_reset_password<F: FnOnce(&ResetPassword)>(&self, fnc: F) -> &Self { if let ResetPasswordResult::ResetPassword(t) = self { fnc(t) }; self }
   pub fn on_declined<F: FnOnce(&ResetPasswordResultDeclined)>(&self, fnc: F) -> &Self { if let ResetPasswordResult::Declined(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&ResetPasswordResultOk)>(&self, fnc: F) -> &Self { if let ResetPasswordResult::Ok(t) = self { fnc(t) }; self }
   pub fn on_pending<F: FnOnce(&ResetPasswordResultPending)>(&self, fnc: F) -> &Self { if let ResetPasswordResult::Pending(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ResetPasswordResult::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_reset_password(&self) -> Option<&ResetPassword> { if let ResetPasswordResult::ResetPassword(t) = self { return Some(t) } None }
   pub fn as_declined(&self) -> Option<&ResetPasswordResultDeclined> { if let ResetPasswordResult::Declined(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&ResetPasswordResultOk> { if let ResetPasswordResult::Ok(t) = self { return Some(t) } None }
   pub fn as_pending(&self) -> Option<&ResetPasswordResultPending> { if let ResetPasswordResult::Pending(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ResetPasswordResult::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,15 +138,19 @@ pub struct ResetPasswordResultDeclined {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Point in time (Unix timestamp) when the password reset can be retried
   retry_date: i64,
   
 }
 
 impl RObject for ResetPasswordResultDeclined {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "resetPasswordResultDeclined" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "resetPasswordResultDeclined" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,14 +160,15 @@ impl TDResetPasswordResult for ResetPasswordResultDeclined {}
 
 
 impl ResetPasswordResultDeclined {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDResetPasswordResultDeclinedBuilder {
-    let mut inner = ResetPasswordResultDeclined::default();
-    inner.td_name = "resetPasswordResultDeclined".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ResetPasswordResultDeclined { td_name: "resetPasswordResultDeclined".to_string(), ..Default::default() };
     RTDResetPasswordResultDeclinedBuilder { inner }
   }
 
+  /// `ResetPasswordResultDeclined` with the fields tdlib requires, the others are set by `ResetPasswordResultDeclined::builder()`
+  pub fn new(retry_date: i64) -> Self { Self::builder().retry_date(retry_date).build() }
+
   pub fn retry_date(&self) -> i64 { self.retry_date }
 
 }
@@ -159,6 +181,12 @@ pub struct RTDResetPasswordResultDeclinedBuilder {
 impl RTDResetPasswordResultDeclinedBuilder {
   pub fn build(&self) -> ResetPasswordResultDeclined { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn retry_date(&mut self, retry_date: i64) -> &mut Self {
     self.inner.retry_date = retry_date;
@@ -175,6 +203,12 @@ impl AsRef<ResetPasswordResultDeclined> for RTDResetPasswordResultDeclinedBuilde
   fn as_ref(&self) -> &ResetPasswordResultDeclined { &self.inner }
 }
 
+impl PartialEq for ResetPasswordResultDeclined {
+  fn eq(&self, other: &Self) -> bool {
+    self.retry_date == other.retry_date
+  }
+}
+
 
 
 
@@ -189,13 +223,17 @@ pub struct ResetPasswordResultOk {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ResetPasswordResultOk {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "resetPasswordResultOk" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "resetPasswordResultOk" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -205,14 +243,15 @@ impl TDResetPasswordResult for ResetPasswordResultOk {}
 
 
 impl ResetPasswordResultOk {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDResetPasswordResultOkBuilder {
-    let mut inner = ResetPasswordResultOk::default();
-    inner.td_name = "resetPasswordResultOk".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ResetPasswordResultOk { td_name: "resetPasswordResultOk".to_string(), ..Default::default() };
     RTDResetPasswordResultOkBuilder { inner }
   }
 
+  /// `ResetPasswordResultOk` with the fields tdlib requires, the others are set by `ResetPasswordResultOk::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +262,12 @@ pub struct RTDResetPasswordResultOkBuilder {
 impl RTDResetPasswordResultOkBuilder {
   pub fn build(&self) -> ResetPasswordResultOk { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ResetPasswordResultOk> for ResetPasswordResultOk {
@@ -233,6 +278,10 @@ impl AsRef<ResetPasswordResultOk> for RTDResetPasswordResultOkBuilder {
   fn as_ref(&self) -> &ResetPasswordResultOk { &self.inner }
 }
 
+impl PartialEq for ResetPasswordResultOk {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -247,15 +296,19 @@ pub struct ResetPasswordResultPending {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Point in time (Unix timestamp) after which the password can be reset immediately using resetPassword
   pending_reset_date: i64,
   
 }
 
 impl RObject for ResetPasswordResultPending {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "resetPasswordResultPending" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "resetPasswordResultPending" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -265,14 +318,15 @@ impl TDResetPasswordResult for ResetPasswordResultPending {}
 
 
 impl ResetPasswordResultPending {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDResetPasswordResultPendingBuilder {
-    let mut inner = ResetPasswordResultPending::default();
-    inner.td_name = "resetPasswordResultPending".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ResetPasswordResultPending { td_name: "resetPasswordResultPending".to_string(), ..Default::default() };
     RTDResetPasswordResultPendingBuilder { inner }
   }
 
+  /// `ResetPasswordResultPending` with the fields tdlib requires, the others are set by `ResetPasswordResultPending::builder()`
+  pub fn new(pending_reset_date: i64) -> Self { Self::builder().pending_reset_date(pending_reset_date).build() }
+
   pub fn pending_reset_date(&self) -> i64 { self.pending_reset_date }
 
 }
@@ -285,6 +339,12 @@ pub struct RTDResetPasswordResultPendingBuilder {
 impl RTDResetPasswordResultPendingBuilder {
   pub fn build(&self) -> ResetPasswordResultPending { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn pending_reset_date(&mut self, pending_reset_date: i64) -> &mut Self {
     self.inner.pending_reset_date = pending_reset_date;
@@ -301,5 +361,11 @@ impl AsRef<ResetPasswordResultPending> for RTDResetPasswordResultPendingBuilder
   fn as_ref(&self) -> &ResetPasswordResultPending { &self.inner }
 }
 
+impl PartialEq for ResetPasswordResultPending {
+  fn eq(&self, other: &Self) -> bool {
+    self.pending_reset_date == other.pending_reset_date
+  }
+}
+
 
 
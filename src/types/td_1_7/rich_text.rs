@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDRichText: Debug + RObject {}
 
 /// Describes a text object inside an instant-view web page
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum RichText {
   #[doc(hidden)] _Default(()),
@@ -53,6 +52,8 @@ pub enum RichText {
   Url(RichTextUrl),
   /// A concatenation of rich texts
   RichTexts(RichTexts),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -64,7 +65,7 @@ impl<'de> Deserialize<'de> for RichText {
   fn deserialize<D>(deserializer: D) -> Result<RichText, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      RichText,
+      RichText, Unknown;
       (richTextAnchor, Anchor);
       (richTextAnchorLink, AnchorLink);
       (richTextBold, Bold);
@@ -88,7 +89,7 @@ impl<'de> Deserialize<'de> for RichText {
 }
 
 impl RObject for RichText {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       RichText::Anchor(t) => t.td_name(),
       RichText::AnchorLink(t) => t.td_name(),
@@ -107,11 +108,12 @@ impl RObject for RichText {
       RichText::Underline(t) => t.td_name(),
       RichText::Url(t) => t.td_name(),
       RichText::RichTexts(t) => t.td_name(),
+      RichText::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       RichText::Anchor(t) => t.extra(),
       RichText::AnchorLink(t) => t.extra(),
@@ -130,6 +132,31 @@ impl RObject for RichText {
       RichText::Underline(t) => t.extra(),
       RichText::Url(t) => t.extra(),
       RichText::RichTexts(t) => t.extra(),
+      RichText::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      RichText::Anchor(t) => t.client_id(),
+      RichText::AnchorLink(t) => t.client_id(),
+      RichText::Bold(t) => t.client_id(),
+      RichText::EmailAddress(t) => t.client_id(),
+      RichText::Fixed(t) => t.client_id(),
+      RichText::Icon(t) => t.client_id(),
+      RichText::Italic(t) => t.client_id(),
+      RichText::Marked(t) => t.client_id(),
+      RichText::PhoneNumber(t) => t.client_id(),
+      RichText::Plain(t) => t.client_id(),
+      RichText::Reference(t) => t.client_id(),
+      RichText::Strikethrough(t) => t.client_id(),
+      RichText::Subscript(t) => t.client_id(),
+      RichText::Superscript(t) => t.client_id(),
+      RichText::Underline(t) => t.client_id(),
+      RichText::Url(t) => t.client_id(),
+      RichText::RichTexts(t) => t.client_id(),
+      RichText::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -138,26 +165,27 @@ impl RObject for RichText {
 }
 
 impl RichText {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let RichText::_Default(_) = self { true } else { false } }
-
-  pub fn is_anchor(&self) -> bool { if let RichText::Anchor(_) = self { true } else { false } }
-  pub fn is_anchor_link(&self) -> bool { if let RichText::AnchorLink(_) = self { true } else { false } }
-  pub fn is_bold(&self) -> bool { if let RichText::Bold(_) = self { true } else { false } }
-  pub fn is_email_address(&self) -> bool { if let RichText::EmailAddress(_) = self { true } else { false } }
-  pub fn is_fixed(&self) -> bool { if let RichText::Fixed(_) = self { true } else { false } }
-  pub fn is_icon(&self) -> bool { if let RichText::Icon(_) = self { true } else { false } }
-  pub fn is_italic(&self) -> bool { if let RichText::Italic(_) = self { true } else { false } }
-  pub fn is_marked(&self) -> bool { if let RichText::Marked(_) = self { true } else { false } }
-  pub fn is_phone_number(&self) -> bool { if let RichText::PhoneNumber(_) = self { true } else { false } }
-  pub fn is_plain(&self) -> bool { if let RichText::Plain(_) = self { true } else { false } }
-  pub fn is_reference(&self) -> bool { if let RichText::Reference(_) = self { true } else { false } }
-  pub fn is_strikethrough(&self) -> bool { if let RichText::Strikethrough(_) = self { true } else { false } }
-  pub fn is_subscript(&self) -> bool { if let RichText::Subscript(_) = self { true } else { false } }
-  pub fn is_superscript(&self) -> bool { if let RichText::Superscript(_) = self { true } else { false } }
-  pub fn is_underline(&self) -> bool { if let RichText::Underline(_) = self { true } else { false } }
-  pub fn is_url(&self) -> bool { if let RichText::Url(_) = self { true } else { false } }
-  pub fn is_rich_texts(&self) -> bool { if let RichText::RichTexts(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, RichText::_Default(_)) }
+
+  pub fn is_anchor(&self) -> bool { matches!(self, RichText::Anchor(_)) }
+  pub fn is_anchor_link(&self) -> bool { matches!(self, RichText::AnchorLink(_)) }
+  pub fn is_bold(&self) -> bool { matches!(self, RichText::Bold(_)) }
+  pub fn is_email_address(&self) -> bool { matches!(self, RichText::EmailAddress(_)) }
+  pub fn is_fixed(&self) -> bool { matches!(self, RichText::Fixed(_)) }
+  pub fn is_icon(&self) -> bool { matches!(self, RichText::Icon(_)) }
+  pub fn is_italic(&self) -> bool { matches!(self, RichText::Italic(_)) }
+  pub fn is_marked(&self) -> bool { matches!(self, RichText::Marked(_)) }
+  pub fn is_phone_number(&self) -> bool { matches!(self, RichText::PhoneNumber(_)) }
+  pub fn is_plain(&self) -> bool { matches!(self, RichText::Plain(_)) }
+  pub fn is_reference(&self) -> bool { matches!(self, RichText::Reference(_)) }
+  pub fn is_strikethrough(&self) -> bool { matches!(self, RichText::Strikethrough(_)) }
+  pub fn is_subscript(&self) -> bool { matches!(self, RichText::Subscript(_)) }
+  pub fn is_superscript(&self) -> bool { matches!(self, RichText::Superscript(_)) }
+  pub fn is_underline(&self) -> bool { matches!(self, RichText::Underline(_)) }
+  pub fn is_url(&self) -> bool { matches!(self, RichText::Url(_)) }
+  pub fn is_rich_texts(&self) -> bool { matches!(self, RichText::RichTexts(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, RichText::Unknown(_)) }
 
   pub fn on_anchor<F: FnOnce(&RichTextAnchor)>(&self, fnc: F) -> &Self { if let RichText::Anchor(t) = self { fnc(t) }; self }
   pub fn on_anchor_link<F: FnOnce(&RichTextAnchorLink)>(&self, fnc: F) -> &Self { if let RichText::AnchorLink(t) = self { fnc(t) }; self }
@@ -176,6 +204,7 @@ impl RichText {
   pub fn on_underline<F: FnOnce(&RichTextUnderline)>(&self, fnc: F) -> &Self { if let RichText::Underline(t) = self { fnc(t) }; self }
   pub fn on_url<F: FnOnce(&RichTextUrl)>(&self, fnc: F) -> &Self { if let RichText::Url(t) = self { fnc(t) }; self }
   pub fn on_rich_texts<F: FnOnce(&RichTexts)>(&self, fnc: F) -> &Self { if let RichText::RichTexts(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let RichText::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_anchor(&self) -> Option<&RichTextAnchor> { if let RichText::Anchor(t) = self { return Some(t) } None }
   pub fn as_anchor_link(&self) -> Option<&RichTextAnchorLink> { if let RichText::AnchorLink(t) = self { return Some(t) } None }
@@ -194,6 +223,7 @@ impl RichText {
   pub fn as_underline(&self) -> Option<&RichTextUnderline> { if let RichText::Underline(t) = self { return Some(t) } None }
   pub fn as_url(&self) -> Option<&RichTextUrl> { if let RichText::Url(t) = self { return Some(t) } None }
   pub fn as_rich_texts(&self) -> Option<&RichTexts> { if let RichText::RichTexts(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let RichText::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -251,15 +281,19 @@ pub struct RichTextAnchor {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Anchor name
   name: String,
   
 }
 
 impl RObject for RichTextAnchor {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextAnchor" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextAnchor" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -269,14 +303,15 @@ impl TDRichText for RichTextAnchor {}
 
 
 impl RichTextAnchor {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextAnchorBuilder {
-    let mut inner = RichTextAnchor::default();
-    inner.td_name = "richTextAnchor".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextAnchor { td_name: "richTextAnchor".to_string(), ..Default::default() };
     RTDRichTextAnchorBuilder { inner }
   }
 
+  /// `RichTextAnchor` with the fields tdlib requires, the others are set by `RichTextAnchor::builder()`
+  pub fn new(name: impl AsRef<str>) -> Self { Self::builder().name(name).build() }
+
   pub fn name(&self) -> &String { &self.name }
 
 }
@@ -289,6 +324,12 @@ pub struct RTDRichTextAnchorBuilder {
 impl RTDRichTextAnchorBuilder {
   pub fn build(&self) -> RichTextAnchor { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -305,6 +346,12 @@ impl AsRef<RichTextAnchor> for RTDRichTextAnchorBuilder {
   fn as_ref(&self) -> &RichTextAnchor { &self.inner }
 }
 
+impl PartialEq for RichTextAnchor {
+  fn eq(&self, other: &Self) -> bool {
+    self.name == other.name
+  }
+}
+
 
 
 
@@ -319,7 +366,10 @@ pub struct RichTextAnchorLink {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The link text
   text: Box<RichText>,
   /// The anchor name. If the name is empty, the link must bring back to top
@@ -330,8 +380,9 @@ pub struct RichTextAnchorLink {
 }
 
 impl RObject for RichTextAnchorLink {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextAnchorLink" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextAnchorLink" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -341,14 +392,16 @@ impl TDRichText for RichTextAnchorLink {}
 
 
 impl RichTextAnchorLink {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextAnchorLinkBuilder {
-    let mut inner = RichTextAnchorLink::default();
-    inner.td_name = "richTextAnchorLink".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextAnchorLink { td_name: "richTextAnchorLink".to_string(), ..Default::default() };
     RTDRichTextAnchorLinkBuilder { inner }
   }
 
+  /// `RichTextAnchorLink` with the fields tdlib requires, the others are set by `RichTextAnchorLink::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>, anchor_name: impl AsRef<str>, url: impl AsRef<str>) -> Self { Self::builder().text(text).anchor_name(anchor_name).url(url).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
   pub fn anchor_name(&self) -> &String { &self.anchor_name }
@@ -365,6 +418,12 @@ pub struct RTDRichTextAnchorLinkBuilder {
 impl RTDRichTextAnchorLinkBuilder {
   pub fn build(&self) -> RichTextAnchorLink { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -393,6 +452,14 @@ impl AsRef<RichTextAnchorLink> for RTDRichTextAnchorLinkBuilder {
   fn as_ref(&self) -> &RichTextAnchorLink { &self.inner }
 }
 
+impl PartialEq for RichTextAnchorLink {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.anchor_name == other.anchor_name
+      && self.url == other.url
+  }
+}
+
 
 
 
@@ -407,15 +474,19 @@ pub struct RichTextBold {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextBold {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextBold" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextBold" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -425,14 +496,16 @@ impl TDRichText for RichTextBold {}
 
 
 impl RichTextBold {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextBoldBuilder {
-    let mut inner = RichTextBold::default();
-    inner.td_name = "richTextBold".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextBold { td_name: "richTextBold".to_string(), ..Default::default() };
     RTDRichTextBoldBuilder { inner }
   }
 
+  /// `RichTextBold` with the fields tdlib requires, the others are set by `RichTextBold::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -445,6 +518,12 @@ pub struct RTDRichTextBoldBuilder {
 impl RTDRichTextBoldBuilder {
   pub fn build(&self) -> RichTextBold { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -461,6 +540,12 @@ impl AsRef<RichTextBold> for RTDRichTextBoldBuilder {
   fn as_ref(&self) -> &RichTextBold { &self.inner }
 }
 
+impl PartialEq for RichTextBold {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -475,7 +560,10 @@ pub struct RichTextEmailAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   /// Email address
@@ -484,8 +572,9 @@ pub struct RichTextEmailAddress {
 }
 
 impl RObject for RichTextEmailAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextEmailAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextEmailAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -495,14 +584,16 @@ impl TDRichText for RichTextEmailAddress {}
 
 
 impl RichTextEmailAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextEmailAddressBuilder {
-    let mut inner = RichTextEmailAddress::default();
-    inner.td_name = "richTextEmailAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextEmailAddress { td_name: "richTextEmailAddress".to_string(), ..Default::default() };
     RTDRichTextEmailAddressBuilder { inner }
   }
 
+  /// `RichTextEmailAddress` with the fields tdlib requires, the others are set by `RichTextEmailAddress::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>, email_address: impl AsRef<str>) -> Self { Self::builder().text(text).email_address(email_address).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
   pub fn email_address(&self) -> &String { &self.email_address }
@@ -517,6 +608,12 @@ pub struct RTDRichTextEmailAddressBuilder {
 impl RTDRichTextEmailAddressBuilder {
   pub fn build(&self) -> RichTextEmailAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -539,6 +636,13 @@ impl AsRef<RichTextEmailAddress> for RTDRichTextEmailAddressBuilder {
   fn as_ref(&self) -> &RichTextEmailAddress { &self.inner }
 }
 
+impl PartialEq for RichTextEmailAddress {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.email_address == other.email_address
+  }
+}
+
 
 
 
@@ -553,15 +657,19 @@ pub struct RichTextFixed {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextFixed {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextFixed" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextFixed" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -571,14 +679,16 @@ impl TDRichText for RichTextFixed {}
 
 
 impl RichTextFixed {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextFixedBuilder {
-    let mut inner = RichTextFixed::default();
-    inner.td_name = "richTextFixed".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextFixed { td_name: "richTextFixed".to_string(), ..Default::default() };
     RTDRichTextFixedBuilder { inner }
   }
 
+  /// `RichTextFixed` with the fields tdlib requires, the others are set by `RichTextFixed::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -591,6 +701,12 @@ pub struct RTDRichTextFixedBuilder {
 impl RTDRichTextFixedBuilder {
   pub fn build(&self) -> RichTextFixed { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -607,6 +723,12 @@ impl AsRef<RichTextFixed> for RTDRichTextFixedBuilder {
   fn as_ref(&self) -> &RichTextFixed { &self.inner }
 }
 
+impl PartialEq for RichTextFixed {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -621,7 +743,10 @@ pub struct RichTextIcon {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The image represented as a document. The image can be in GIF, JPEG or PNG format
   document: Document,
   /// Width of a bounding box in which the image must be shown; 0 if unknown
@@ -632,8 +757,9 @@ pub struct RichTextIcon {
 }
 
 impl RObject for RichTextIcon {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextIcon" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextIcon" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -643,14 +769,15 @@ impl TDRichText for RichTextIcon {}
 
 
 impl RichTextIcon {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextIconBuilder {
-    let mut inner = RichTextIcon::default();
-    inner.td_name = "richTextIcon".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextIcon { td_name: "richTextIcon".to_string(), ..Default::default() };
     RTDRichTextIconBuilder { inner }
   }
 
+  /// `RichTextIcon` with the fields tdlib requires, the others are set by `RichTextIcon::builder()`
+  pub fn new(document: impl AsRef<Document>, width: i64, height: i64) -> Self { Self::builder().document(document).width(width).height(height).build() }
+
   pub fn document(&self) -> &Document { &self.document }
 
   pub fn width(&self) -> i64 { self.width }
@@ -667,6 +794,12 @@ pub struct RTDRichTextIconBuilder {
 impl RTDRichTextIconBuilder {
   pub fn build(&self) -> RichTextIcon { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
@@ -695,6 +828,14 @@ impl AsRef<RichTextIcon> for RTDRichTextIconBuilder {
   fn as_ref(&self) -> &RichTextIcon { &self.inner }
 }
 
+impl PartialEq for RichTextIcon {
+  fn eq(&self, other: &Self) -> bool {
+    self.document == other.document
+      && self.width == other.width
+      && self.height == other.height
+  }
+}
+
 
 
 
@@ -709,15 +850,19 @@ pub struct RichTextItalic {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextItalic {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextItalic" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextItalic" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -727,14 +872,16 @@ impl TDRichText for RichTextItalic {}
 
 
 impl RichTextItalic {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextItalicBuilder {
-    let mut inner = RichTextItalic::default();
-    inner.td_name = "richTextItalic".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextItalic { td_name: "richTextItalic".to_string(), ..Default::default() };
     RTDRichTextItalicBuilder { inner }
   }
 
+  /// `RichTextItalic` with the fields tdlib requires, the others are set by `RichTextItalic::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -747,6 +894,12 @@ pub struct RTDRichTextItalicBuilder {
 impl RTDRichTextItalicBuilder {
   pub fn build(&self) -> RichTextItalic { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -763,6 +916,12 @@ impl AsRef<RichTextItalic> for RTDRichTextItalicBuilder {
   fn as_ref(&self) -> &RichTextItalic { &self.inner }
 }
 
+impl PartialEq for RichTextItalic {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -777,15 +936,19 @@ pub struct RichTextMarked {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextMarked {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextMarked" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextMarked" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -795,14 +958,16 @@ impl TDRichText for RichTextMarked {}
 
 
 impl RichTextMarked {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextMarkedBuilder {
-    let mut inner = RichTextMarked::default();
-    inner.td_name = "richTextMarked".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextMarked { td_name: "richTextMarked".to_string(), ..Default::default() };
     RTDRichTextMarkedBuilder { inner }
   }
 
+  /// `RichTextMarked` with the fields tdlib requires, the others are set by `RichTextMarked::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -815,6 +980,12 @@ pub struct RTDRichTextMarkedBuilder {
 impl RTDRichTextMarkedBuilder {
   pub fn build(&self) -> RichTextMarked { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -831,6 +1002,12 @@ impl AsRef<RichTextMarked> for RTDRichTextMarkedBuilder {
   fn as_ref(&self) -> &RichTextMarked { &self.inner }
 }
 
+impl PartialEq for RichTextMarked {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -845,7 +1022,10 @@ pub struct RichTextPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   /// Phone number
@@ -854,8 +1034,9 @@ pub struct RichTextPhoneNumber {
 }
 
 impl RObject for RichTextPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -865,14 +1046,16 @@ impl TDRichText for RichTextPhoneNumber {}
 
 
 impl RichTextPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextPhoneNumberBuilder {
-    let mut inner = RichTextPhoneNumber::default();
-    inner.td_name = "richTextPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextPhoneNumber { td_name: "richTextPhoneNumber".to_string(), ..Default::default() };
     RTDRichTextPhoneNumberBuilder { inner }
   }
 
+  /// `RichTextPhoneNumber` with the fields tdlib requires, the others are set by `RichTextPhoneNumber::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>, phone_number: impl AsRef<str>) -> Self { Self::builder().text(text).phone_number(phone_number).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -887,6 +1070,12 @@ pub struct RTDRichTextPhoneNumberBuilder {
 impl RTDRichTextPhoneNumberBuilder {
   pub fn build(&self) -> RichTextPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -909,6 +1098,13 @@ impl AsRef<RichTextPhoneNumber> for RTDRichTextPhoneNumberBuilder {
   fn as_ref(&self) -> &RichTextPhoneNumber { &self.inner }
 }
 
+impl PartialEq for RichTextPhoneNumber {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.phone_number == other.phone_number
+  }
+}
+
 
 
 
@@ -923,15 +1119,19 @@ pub struct RichTextPlain {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextPlain {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextPlain" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextPlain" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -941,14 +1141,16 @@ impl TDRichText for RichTextPlain {}
 
 
 impl RichTextPlain {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextPlainBuilder {
-    let mut inner = RichTextPlain::default();
-    inner.td_name = "richTextPlain".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextPlain { td_name: "richTextPlain".to_string(), ..Default::default() };
     RTDRichTextPlainBuilder { inner }
   }
 
+  /// `RichTextPlain` with the fields tdlib requires, the others are set by `RichTextPlain::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -961,6 +1163,12 @@ pub struct RTDRichTextPlainBuilder {
 impl RTDRichTextPlainBuilder {
   pub fn build(&self) -> RichTextPlain { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -977,6 +1185,12 @@ impl AsRef<RichTextPlain> for RTDRichTextPlainBuilder {
   fn as_ref(&self) -> &RichTextPlain { &self.inner }
 }
 
+impl PartialEq for RichTextPlain {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -991,7 +1205,10 @@ pub struct RichTextReference {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The text
   text: Box<RichText>,
   /// The name of a richTextAnchor object, which is the first element of the target richTexts object
@@ -1002,8 +1219,9 @@ pub struct RichTextReference {
 }
 
 impl RObject for RichTextReference {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextReference" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextReference" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1013,14 +1231,16 @@ impl TDRichText for RichTextReference {}
 
 
 impl RichTextReference {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextReferenceBuilder {
-    let mut inner = RichTextReference::default();
-    inner.td_name = "richTextReference".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextReference { td_name: "richTextReference".to_string(), ..Default::default() };
     RTDRichTextReferenceBuilder { inner }
   }
 
+  /// `RichTextReference` with the fields tdlib requires, the others are set by `RichTextReference::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>, anchor_name: impl AsRef<str>, url: impl AsRef<str>) -> Self { Self::builder().text(text).anchor_name(anchor_name).url(url).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
   pub fn anchor_name(&self) -> &String { &self.anchor_name }
@@ -1037,6 +1257,12 @@ pub struct RTDRichTextReferenceBuilder {
 impl RTDRichTextReferenceBuilder {
   pub fn build(&self) -> RichTextReference { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1065,6 +1291,14 @@ impl AsRef<RichTextReference> for RTDRichTextReferenceBuilder {
   fn as_ref(&self) -> &RichTextReference { &self.inner }
 }
 
+impl PartialEq for RichTextReference {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.anchor_name == other.anchor_name
+      && self.url == other.url
+  }
+}
+
 
 
 
@@ -1079,15 +1313,19 @@ pub struct RichTextStrikethrough {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextStrikethrough {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextStrikethrough" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextStrikethrough" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1097,14 +1335,16 @@ impl TDRichText for RichTextStrikethrough {}
 
 
 impl RichTextStrikethrough {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextStrikethroughBuilder {
-    let mut inner = RichTextStrikethrough::default();
-    inner.td_name = "richTextStrikethrough".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextStrikethrough { td_name: "richTextStrikethrough".to_string(), ..Default::default() };
     RTDRichTextStrikethroughBuilder { inner }
   }
 
+  /// `RichTextStrikethrough` with the fields tdlib requires, the others are set by `RichTextStrikethrough::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1117,6 +1357,12 @@ pub struct RTDRichTextStrikethroughBuilder {
 impl RTDRichTextStrikethroughBuilder {
   pub fn build(&self) -> RichTextStrikethrough { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1133,6 +1379,12 @@ impl AsRef<RichTextStrikethrough> for RTDRichTextStrikethroughBuilder {
   fn as_ref(&self) -> &RichTextStrikethrough { &self.inner }
 }
 
+impl PartialEq for RichTextStrikethrough {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -1147,15 +1399,19 @@ pub struct RichTextSubscript {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextSubscript {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextSubscript" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextSubscript" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1165,14 +1421,16 @@ impl TDRichText for RichTextSubscript {}
 
 
 impl RichTextSubscript {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextSubscriptBuilder {
-    let mut inner = RichTextSubscript::default();
-    inner.td_name = "richTextSubscript".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextSubscript { td_name: "richTextSubscript".to_string(), ..Default::default() };
     RTDRichTextSubscriptBuilder { inner }
   }
 
+  /// `RichTextSubscript` with the fields tdlib requires, the others are set by `RichTextSubscript::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1185,6 +1443,12 @@ pub struct RTDRichTextSubscriptBuilder {
 impl RTDRichTextSubscriptBuilder {
   pub fn build(&self) -> RichTextSubscript { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1201,6 +1465,12 @@ impl AsRef<RichTextSubscript> for RTDRichTextSubscriptBuilder {
   fn as_ref(&self) -> &RichTextSubscript { &self.inner }
 }
 
+impl PartialEq for RichTextSubscript {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -1215,15 +1485,19 @@ pub struct RichTextSuperscript {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextSuperscript {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextSuperscript" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextSuperscript" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1233,14 +1507,16 @@ impl TDRichText for RichTextSuperscript {}
 
 
 impl RichTextSuperscript {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextSuperscriptBuilder {
-    let mut inner = RichTextSuperscript::default();
-    inner.td_name = "richTextSuperscript".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextSuperscript { td_name: "richTextSuperscript".to_string(), ..Default::default() };
     RTDRichTextSuperscriptBuilder { inner }
   }
 
+  /// `RichTextSuperscript` with the fields tdlib requires, the others are set by `RichTextSuperscript::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1253,6 +1529,12 @@ pub struct RTDRichTextSuperscriptBuilder {
 impl RTDRichTextSuperscriptBuilder {
   pub fn build(&self) -> RichTextSuperscript { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1269,6 +1551,12 @@ impl AsRef<RichTextSuperscript> for RTDRichTextSuperscriptBuilder {
   fn as_ref(&self) -> &RichTextSuperscript { &self.inner }
 }
 
+impl PartialEq for RichTextSuperscript {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -1283,15 +1571,19 @@ pub struct RichTextUnderline {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   
 }
 
 impl RObject for RichTextUnderline {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextUnderline" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextUnderline" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1301,14 +1593,16 @@ impl TDRichText for RichTextUnderline {}
 
 
 impl RichTextUnderline {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextUnderlineBuilder {
-    let mut inner = RichTextUnderline::default();
-    inner.td_name = "richTextUnderline".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextUnderline { td_name: "richTextUnderline".to_string(), ..Default::default() };
     RTDRichTextUnderlineBuilder { inner }
   }
 
+  /// `RichTextUnderline` with the fields tdlib requires, the others are set by `RichTextUnderline::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>) -> Self { Self::builder().text(text).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1321,6 +1615,12 @@ pub struct RTDRichTextUnderlineBuilder {
 impl RTDRichTextUnderlineBuilder {
   pub fn build(&self) -> RichTextUnderline { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1337,6 +1637,12 @@ impl AsRef<RichTextUnderline> for RTDRichTextUnderlineBuilder {
   fn as_ref(&self) -> &RichTextUnderline { &self.inner }
 }
 
+impl PartialEq for RichTextUnderline {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
 
@@ -1351,7 +1657,10 @@ pub struct RichTextUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: Box<RichText>,
   /// URL
@@ -1362,8 +1671,9 @@ pub struct RichTextUrl {
 }
 
 impl RObject for RichTextUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTextUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTextUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1373,14 +1683,16 @@ impl TDRichText for RichTextUrl {}
 
 
 impl RichTextUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextUrlBuilder {
-    let mut inner = RichTextUrl::default();
-    inner.td_name = "richTextUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTextUrl { td_name: "richTextUrl".to_string(), ..Default::default() };
     RTDRichTextUrlBuilder { inner }
   }
 
+  /// `RichTextUrl` with the fields tdlib requires, the others are set by `RichTextUrl::builder()`
+  pub fn new(text: impl AsRef<Box<RichText>>, url: impl AsRef<str>, is_cached: bool) -> Self { Self::builder().text(text).url(url).is_cached(is_cached).build() }
+
+  #[allow(clippy::borrowed_box)]
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
   pub fn url(&self) -> &String { &self.url }
@@ -1397,6 +1709,12 @@ pub struct RTDRichTextUrlBuilder {
 impl RTDRichTextUrlBuilder {
   pub fn build(&self) -> RichTextUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1425,6 +1743,14 @@ impl AsRef<RichTextUrl> for RTDRichTextUrlBuilder {
   fn as_ref(&self) -> &RichTextUrl { &self.inner }
 }
 
+impl PartialEq for RichTextUrl {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.url == other.url
+      && self.is_cached == other.is_cached
+  }
+}
+
 
 
 
@@ -1439,15 +1765,19 @@ pub struct RichTexts {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Texts
   texts: Vec<RichText>,
   
 }
 
 impl RObject for RichTexts {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "richTexts" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "richTexts" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1457,14 +1787,15 @@ impl TDRichText for RichTexts {}
 
 
 impl RichTexts {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDRichTextsBuilder {
-    let mut inner = RichTexts::default();
-    inner.td_name = "richTexts".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = RichTexts { td_name: "richTexts".to_string(), ..Default::default() };
     RTDRichTextsBuilder { inner }
   }
 
+  /// `RichTexts` with the fields tdlib requires, the others are set by `RichTexts::builder()`
+  pub fn new(texts: Vec<RichText>) -> Self { Self::builder().texts(texts).build() }
+
   pub fn texts(&self) -> &Vec<RichText> { &self.texts }
 
 }
@@ -1477,6 +1808,12 @@ pub struct RTDRichTextsBuilder {
 impl RTDRichTextsBuilder {
   pub fn build(&self) -> RichTexts { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn texts(&mut self, texts: Vec<RichText>) -> &mut Self {
     self.inner.texts = texts;
@@ -1493,5 +1830,11 @@ impl AsRef<RichTexts> for RTDRichTextsBuilder {
   fn as_ref(&self) -> &RichTexts { &self.inner }
 }
 
+impl PartialEq for RichTexts {
+  fn eq(&self, other: &Self) -> bool {
+    self.texts == other.texts
+  }
+}
+
 
 
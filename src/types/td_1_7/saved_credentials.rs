@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct SavedCredentials {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier of the saved credentials
   id: String,
   /// Title of the saved credentials
@@ -23,22 +25,24 @@ pub struct SavedCredentials {
 }
 
 impl RObject for SavedCredentials {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "savedCredentials" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "savedCredentials" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl SavedCredentials {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSavedCredentialsBuilder {
-    let mut inner = SavedCredentials::default();
-    inner.td_name = "savedCredentials".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SavedCredentials { td_name: "savedCredentials".to_string(), ..Default::default() };
     RTDSavedCredentialsBuilder { inner }
   }
 
+  /// `SavedCredentials` with the fields tdlib requires, the others are set by `SavedCredentials::builder()`
+  pub fn new(id: impl AsRef<str>, title: impl AsRef<str>) -> Self { Self::builder().id(id).title(title).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -53,6 +57,12 @@ pub struct RTDSavedCredentialsBuilder {
 impl RTDSavedCredentialsBuilder {
   pub fn build(&self) -> SavedCredentials { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<SavedCredentials> for RTDSavedCredentialsBuilder {
   fn as_ref(&self) -> &SavedCredentials { &self.inner }
 }
 
+impl PartialEq for SavedCredentials {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ScopeNotificationSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Time left before notifications will be unmuted, in seconds
   mute_for: i64,
   /// The name of an audio file to be used for notification sounds; only applies to iOS applications
@@ -29,22 +31,38 @@ pub struct ScopeNotificationSettings {
 }
 
 impl RObject for ScopeNotificationSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "scopeNotificationSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "scopeNotificationSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ScopeNotificationSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDScopeNotificationSettingsBuilder {
-    let mut inner = ScopeNotificationSettings::default();
-    inner.td_name = "scopeNotificationSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ScopeNotificationSettings { td_name: "scopeNotificationSettings".to_string(), ..Default::default() };
     RTDScopeNotificationSettingsBuilder { inner }
   }
 
+  /// `ScopeNotificationSettings` with the fields tdlib requires, the others are set by `ScopeNotificationSettings::builder()`
+  pub fn new(
+    mute_for: i64,
+    sound: impl AsRef<str>,
+    show_preview: bool,
+    disable_pinned_message_notifications: bool,
+    disable_mention_notifications: bool,
+  ) -> Self {
+    Self::builder()
+      .mute_for(mute_for)
+      .sound(sound)
+      .show_preview(show_preview)
+      .disable_pinned_message_notifications(disable_pinned_message_notifications)
+      .disable_mention_notifications(disable_mention_notifications)
+      .build()
+  }
+
   pub fn mute_for(&self) -> i64 { self.mute_for }
 
   pub fn sound(&self) -> &String { &self.sound }
@@ -65,6 +83,12 @@ pub struct RTDScopeNotificationSettingsBuilder {
 impl RTDScopeNotificationSettingsBuilder {
   pub fn build(&self) -> ScopeNotificationSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn mute_for(&mut self, mute_for: i64) -> &mut Self {
     self.inner.mute_for = mute_for;
@@ -105,5 +129,15 @@ impl AsRef<ScopeNotificationSettings> for RTDScopeNotificationSettingsBuilder {
   fn as_ref(&self) -> &ScopeNotificationSettings { &self.inner }
 }
 
+impl PartialEq for ScopeNotificationSettings {
+  fn eq(&self, other: &Self) -> bool {
+    self.mute_for == other.mute_for
+      && self.sound == other.sound
+      && self.show_preview == other.show_preview
+      && self.disable_pinned_message_notifications == other.disable_pinned_message_notifications
+      && self.disable_mention_notifications == other.disable_mention_notifications
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDSearchMessagesFilter: Debug + RObject {}
 
 /// Represents a filter for message search results
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum SearchMessagesFilter {
   #[doc(hidden)] _Default(()),
@@ -24,6 +23,7 @@ pub enum SearchMessagesFilter {
   /// Returns only audio messages
   Audio(SearchMessagesFilterAudio),
   /// Returns only call messages
+  #[cfg(feature = "calls")]
   Call(SearchMessagesFilterCall),
   /// Returns only messages containing chat photos
   ChatPhoto(SearchMessagesFilterChatPhoto),
@@ -36,6 +36,7 @@ pub enum SearchMessagesFilter {
   /// Returns only messages with mentions of the current user, or messages that are replies to their messages
   Mention(SearchMessagesFilterMention),
   /// Returns only incoming call messages with missed/declined discard reasons
+  #[cfg(feature = "calls")]
   MissedCall(SearchMessagesFilterMissedCall),
   /// Returns only photo messages
   Photo(SearchMessagesFilterPhoto),
@@ -55,6 +56,8 @@ pub enum SearchMessagesFilter {
   VoiceAndVideoNote(SearchMessagesFilterVoiceAndVideoNote),
   /// Returns only voice note messages
   VoiceNote(SearchMessagesFilterVoiceNote),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -66,16 +69,16 @@ impl<'de> Deserialize<'de> for SearchMessagesFilter {
   fn deserialize<D>(deserializer: D) -> Result<SearchMessagesFilter, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      SearchMessagesFilter,
+      SearchMessagesFilter, Unknown;
       (searchMessagesFilterAnimation, Animation);
       (searchMessagesFilterAudio, Audio);
-      (searchMessagesFilterCall, Call);
+      #[cfg(feature = "calls")] (searchMessagesFilterCall, Call);
       (searchMessagesFilterChatPhoto, ChatPhoto);
       (searchMessagesFilterDocument, Document);
       (searchMessagesFilterEmpty, Empty);
       (searchMessagesFilterFailedToSend, FailedToSend);
       (searchMessagesFilterMention, Mention);
-      (searchMessagesFilterMissedCall, MissedCall);
+      #[cfg(feature = "calls")] (searchMessagesFilterMissedCall, MissedCall);
       (searchMessagesFilterPhoto, Photo);
       (searchMessagesFilterPhotoAndVideo, PhotoAndVideo);
       (searchMessagesFilterPinned, Pinned);
@@ -91,17 +94,17 @@ impl<'de> Deserialize<'de> for SearchMessagesFilter {
 }
 
 impl RObject for SearchMessagesFilter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       SearchMessagesFilter::Animation(t) => t.td_name(),
       SearchMessagesFilter::Audio(t) => t.td_name(),
-      SearchMessagesFilter::Call(t) => t.td_name(),
+      #[cfg(feature = "calls")] SearchMessagesFilter::Call(t) => t.td_name(),
       SearchMessagesFilter::ChatPhoto(t) => t.td_name(),
       SearchMessagesFilter::Document(t) => t.td_name(),
       SearchMessagesFilter::Empty(t) => t.td_name(),
       SearchMessagesFilter::FailedToSend(t) => t.td_name(),
       SearchMessagesFilter::Mention(t) => t.td_name(),
-      SearchMessagesFilter::MissedCall(t) => t.td_name(),
+      #[cfg(feature = "calls")] SearchMessagesFilter::MissedCall(t) => t.td_name(),
       SearchMessagesFilter::Photo(t) => t.td_name(),
       SearchMessagesFilter::PhotoAndVideo(t) => t.td_name(),
       SearchMessagesFilter::Pinned(t) => t.td_name(),
@@ -111,21 +114,22 @@ impl RObject for SearchMessagesFilter {
       SearchMessagesFilter::VideoNote(t) => t.td_name(),
       SearchMessagesFilter::VoiceAndVideoNote(t) => t.td_name(),
       SearchMessagesFilter::VoiceNote(t) => t.td_name(),
+      SearchMessagesFilter::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       SearchMessagesFilter::Animation(t) => t.extra(),
       SearchMessagesFilter::Audio(t) => t.extra(),
-      SearchMessagesFilter::Call(t) => t.extra(),
+      #[cfg(feature = "calls")] SearchMessagesFilter::Call(t) => t.extra(),
       SearchMessagesFilter::ChatPhoto(t) => t.extra(),
       SearchMessagesFilter::Document(t) => t.extra(),
       SearchMessagesFilter::Empty(t) => t.extra(),
       SearchMessagesFilter::FailedToSend(t) => t.extra(),
       SearchMessagesFilter::Mention(t) => t.extra(),
-      SearchMessagesFilter::MissedCall(t) => t.extra(),
+      #[cfg(feature = "calls")] SearchMessagesFilter::MissedCall(t) => t.extra(),
       SearchMessagesFilter::Photo(t) => t.extra(),
       SearchMessagesFilter::PhotoAndVideo(t) => t.extra(),
       SearchMessagesFilter::Pinned(t) => t.extra(),
@@ -135,6 +139,32 @@ impl RObject for SearchMessagesFilter {
       SearchMessagesFilter::VideoNote(t) => t.extra(),
       SearchMessagesFilter::VoiceAndVideoNote(t) => t.extra(),
       SearchMessagesFilter::VoiceNote(t) => t.extra(),
+      SearchMessagesFilter::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      SearchMessagesFilter::Animation(t) => t.client_id(),
+      SearchMessagesFilter::Audio(t) => t.client_id(),
+      #[cfg(feature = "calls")] SearchMessagesFilter::Call(t) => t.client_id(),
+      SearchMessagesFilter::ChatPhoto(t) => t.client_id(),
+      SearchMessagesFilter::Document(t) => t.client_id(),
+      SearchMessagesFilter::Empty(t) => t.client_id(),
+      SearchMessagesFilter::FailedToSend(t) => t.client_id(),
+      SearchMessagesFilter::Mention(t) => t.client_id(),
+      #[cfg(feature = "calls")] SearchMessagesFilter::MissedCall(t) => t.client_id(),
+      SearchMessagesFilter::Photo(t) => t.client_id(),
+      SearchMessagesFilter::PhotoAndVideo(t) => t.client_id(),
+      SearchMessagesFilter::Pinned(t) => t.client_id(),
+      SearchMessagesFilter::UnreadMention(t) => t.client_id(),
+      SearchMessagesFilter::Url(t) => t.client_id(),
+      SearchMessagesFilter::Video(t) => t.client_id(),
+      SearchMessagesFilter::VideoNote(t) => t.client_id(),
+      SearchMessagesFilter::VoiceAndVideoNote(t) => t.client_id(),
+      SearchMessagesFilter::VoiceNote(t) => t.client_id(),
+      SearchMessagesFilter::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -143,37 +173,38 @@ impl RObject for SearchMessagesFilter {
 }
 
 impl SearchMessagesFilter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SearchMessagesFilter::_Default(_) = self { true } else { false } }
-
-  pub fn is_animation(&self) -> bool { if let SearchMessagesFilter::Animation(_) = self { true } else { false } }
-  pub fn is_audio(&self) -> bool { if let SearchMessagesFilter::Audio(_) = self { true } else { false } }
-  pub fn is_call(&self) -> bool { if let SearchMessagesFilter::Call(_) = self { true } else { false } }
-  pub fn is_chat_photo(&self) -> bool { if let SearchMessagesFilter::ChatPhoto(_) = self { true } else { false } }
-  pub fn is_document(&self) -> bool { if let SearchMessagesFilter::Document(_) = self { true } else { false } }
-  pub fn is_empty(&self) -> bool { if let SearchMessagesFilter::Empty(_) = self { true } else { false } }
-  pub fn is_failed_to_send(&self) -> bool { if let SearchMessagesFilter::FailedToSend(_) = self { true } else { false } }
-  pub fn is_mention(&self) -> bool { if let SearchMessagesFilter::Mention(_) = self { true } else { false } }
-  pub fn is_missed_call(&self) -> bool { if let SearchMessagesFilter::MissedCall(_) = self { true } else { false } }
-  pub fn is_photo(&self) -> bool { if let SearchMessagesFilter::Photo(_) = self { true } else { false } }
-  pub fn is_photo_and_video(&self) -> bool { if let SearchMessagesFilter::PhotoAndVideo(_) = self { true } else { false } }
-  pub fn is_pinned(&self) -> bool { if let SearchMessagesFilter::Pinned(_) = self { true } else { false } }
-  pub fn is_unread_mention(&self) -> bool { if let SearchMessagesFilter::UnreadMention(_) = self { true } else { false } }
-  pub fn is_url(&self) -> bool { if let SearchMessagesFilter::Url(_) = self { true } else { false } }
-  pub fn is_video(&self) -> bool { if let SearchMessagesFilter::Video(_) = self { true } else { false } }
-  pub fn is_video_note(&self) -> bool { if let SearchMessagesFilter::VideoNote(_) = self { true } else { false } }
-  pub fn is_voice_and_video_note(&self) -> bool { if let SearchMessagesFilter::VoiceAndVideoNote(_) = self { true } else { false } }
-  pub fn is_voice_note(&self) -> bool { if let SearchMessagesFilter::VoiceNote(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, SearchMessagesFilter::_Default(_)) }
+
+  pub fn is_animation(&self) -> bool { matches!(self, SearchMessagesFilter::Animation(_)) }
+  pub fn is_audio(&self) -> bool { matches!(self, SearchMessagesFilter::Audio(_)) }
+  #[cfg(feature = "calls")] pub fn is_call(&self) -> bool { matches!(self, SearchMessagesFilter::Call(_)) }
+  pub fn is_chat_photo(&self) -> bool { matches!(self, SearchMessagesFilter::ChatPhoto(_)) }
+  pub fn is_document(&self) -> bool { matches!(self, SearchMessagesFilter::Document(_)) }
+  pub fn is_empty(&self) -> bool { matches!(self, SearchMessagesFilter::Empty(_)) }
+  pub fn is_failed_to_send(&self) -> bool { matches!(self, SearchMessagesFilter::FailedToSend(_)) }
+  pub fn is_mention(&self) -> bool { matches!(self, SearchMessagesFilter::Mention(_)) }
+  #[cfg(feature = "calls")] pub fn is_missed_call(&self) -> bool { matches!(self, SearchMessagesFilter::MissedCall(_)) }
+  pub fn is_photo(&self) -> bool { matches!(self, SearchMessagesFilter::Photo(_)) }
+  pub fn is_photo_and_video(&self) -> bool { matches!(self, SearchMessagesFilter::PhotoAndVideo(_)) }
+  pub fn is_pinned(&self) -> bool { matches!(self, SearchMessagesFilter::Pinned(_)) }
+  pub fn is_unread_mention(&self) -> bool { matches!(self, SearchMessagesFilter::UnreadMention(_)) }
+  pub fn is_url(&self) -> bool { matches!(self, SearchMessagesFilter::Url(_)) }
+  pub fn is_video(&self) -> bool { matches!(self, SearchMessagesFilter::Video(_)) }
+  pub fn is_video_note(&self) -> bool { matches!(self, SearchMessagesFilter::VideoNote(_)) }
+  pub fn is_voice_and_video_note(&self) -> bool { matches!(self, SearchMessagesFilter::VoiceAndVideoNote(_)) }
+  pub fn is_voice_note(&self) -> bool { matches!(self, SearchMessagesFilter::VoiceNote(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, SearchMessagesFilter::Unknown(_)) }
 
   pub fn on_animation<F: FnOnce(&SearchMessagesFilterAnimation)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Animation(t) = self { fnc(t) }; self }
   pub fn on_audio<F: FnOnce(&SearchMessagesFilterAudio)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Audio(t) = self { fnc(t) }; self }
-  pub fn on_call<F: FnOnce(&SearchMessagesFilterCall)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Call(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_call<F: FnOnce(&SearchMessagesFilterCall)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Call(t) = self { fnc(t) }; self }
   pub fn on_chat_photo<F: FnOnce(&SearchMessagesFilterChatPhoto)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::ChatPhoto(t) = self { fnc(t) }; self }
   pub fn on_document<F: FnOnce(&SearchMessagesFilterDocument)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Document(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&SearchMessagesFilterEmpty)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Empty(t) = self { fnc(t) }; self }
   pub fn on_failed_to_send<F: FnOnce(&SearchMessagesFilterFailedToSend)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::FailedToSend(t) = self { fnc(t) }; self }
   pub fn on_mention<F: FnOnce(&SearchMessagesFilterMention)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Mention(t) = self { fnc(t) }; self }
-  pub fn on_missed_call<F: FnOnce(&SearchMessagesFilterMissedCall)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::MissedCall(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_missed_call<F: FnOnce(&SearchMessagesFilterMissedCall)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::MissedCall(t) = self { fnc(t) }; self }
   pub fn on_photo<F: FnOnce(&SearchMessagesFilterPhoto)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Photo(t) = self { fnc(t) }; self }
   pub fn on_photo_and_video<F: FnOnce(&SearchMessagesFilterPhotoAndVideo)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::PhotoAndVideo(t) = self { fnc(t) }; self }
   pub fn on_pinned<F: FnOnce(&SearchMessagesFilterPinned)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Pinned(t) = self { fnc(t) }; self }
@@ -183,16 +214,17 @@ impl SearchMessagesFilter {
   pub fn on_video_note<F: FnOnce(&SearchMessagesFilterVideoNote)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::VideoNote(t) = self { fnc(t) }; self }
   pub fn on_voice_and_video_note<F: FnOnce(&SearchMessagesFilterVoiceAndVideoNote)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::VoiceAndVideoNote(t) = self { fnc(t) }; self }
   pub fn on_voice_note<F: FnOnce(&SearchMessagesFilterVoiceNote)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::VoiceNote(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let SearchMessagesFilter::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_animation(&self) -> Option<&SearchMessagesFilterAnimation> { if let SearchMessagesFilter::Animation(t) = self { return Some(t) } None }
   pub fn as_audio(&self) -> Option<&SearchMessagesFilterAudio> { if let SearchMessagesFilter::Audio(t) = self { return Some(t) } None }
-  pub fn as_call(&self) -> Option<&SearchMessagesFilterCall> { if let SearchMessagesFilter::Call(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_call(&self) -> Option<&SearchMessagesFilterCall> { if let SearchMessagesFilter::Call(t) = self { return Some(t) } None }
   pub fn as_chat_photo(&self) -> Option<&SearchMessagesFilterChatPhoto> { if let SearchMessagesFilter::ChatPhoto(t) = self { return Some(t) } None }
   pub fn as_document(&self) -> Option<&SearchMessagesFilterDocument> { if let SearchMessagesFilter::Document(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&SearchMessagesFilterEmpty> { if let SearchMessagesFilter::Empty(t) = self { return Some(t) } None }
   pub fn as_failed_to_send(&self) -> Option<&SearchMessagesFilterFailedToSend> { if let SearchMessagesFilter::FailedToSend(t) = self { return Some(t) } None }
   pub fn as_mention(&self) -> Option<&SearchMessagesFilterMention> { if let SearchMessagesFilter::Mention(t) = self { return Some(t) } None }
-  pub fn as_missed_call(&self) -> Option<&SearchMessagesFilterMissedCall> { if let SearchMessagesFilter::MissedCall(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_missed_call(&self) -> Option<&SearchMessagesFilterMissedCall> { if let SearchMessagesFilter::MissedCall(t) = self { return Some(t) } None }
   pub fn as_photo(&self) -> Option<&SearchMessagesFilterPhoto> { if let SearchMessagesFilter::Photo(t) = self { return Some(t) } None }
   pub fn as_photo_and_video(&self) -> Option<&SearchMessagesFilterPhotoAndVideo> { if let SearchMessagesFilter::PhotoAndVideo(t) = self { return Some(t) } None }
   pub fn as_pinned(&self) -> Option<&SearchMessagesFilterPinned> { if let SearchMessagesFilter::Pinned(t) = self { return Some(t) } None }
@@ -202,6 +234,7 @@ impl SearchMessagesFilter {
   pub fn as_video_note(&self) -> Option<&SearchMessagesFilterVideoNote> { if let SearchMessagesFilter::VideoNote(t) = self { return Some(t) } None }
   pub fn as_voice_and_video_note(&self) -> Option<&SearchMessagesFilterVoiceAndVideoNote> { if let SearchMessagesFilter::VoiceAndVideoNote(t) = self { return Some(t) } None }
   pub fn as_voice_note(&self) -> Option<&SearchMessagesFilterVoiceNote> { if let SearchMessagesFilter::VoiceNote(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let SearchMessagesFilter::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -209,7 +242,7 @@ impl SearchMessagesFilter {
 
   pub fn audio<T: AsRef<SearchMessagesFilterAudio>>(t: T) -> Self { SearchMessagesFilter::Audio(t.as_ref().clone()) }
 
-  pub fn call<T: AsRef<SearchMessagesFilterCall>>(t: T) -> Self { SearchMessagesFilter::Call(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn call<T: AsRef<SearchMessagesFilterCall>>(t: T) -> Self { SearchMessagesFilter::Call(t.as_ref().clone()) }
 
   pub fn chat_photo<T: AsRef<SearchMessagesFilterChatPhoto>>(t: T) -> Self { SearchMessagesFilter::ChatPhoto(t.as_ref().clone()) }
 
@@ -221,7 +254,7 @@ impl SearchMessagesFilter {
 
   pub fn mention<T: AsRef<SearchMessagesFilterMention>>(t: T) -> Self { SearchMessagesFilter::Mention(t.as_ref().clone()) }
 
-  pub fn missed_call<T: AsRef<SearchMessagesFilterMissedCall>>(t: T) -> Self { SearchMessagesFilter::MissedCall(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn missed_call<T: AsRef<SearchMessagesFilterMissedCall>>(t: T) -> Self { SearchMessagesFilter::MissedCall(t.as_ref().clone()) }
 
   pub fn photo<T: AsRef<SearchMessagesFilterPhoto>>(t: T) -> Self { SearchMessagesFilter::Photo(t.as_ref().clone()) }
 
@@ -261,13 +294,17 @@ pub struct SearchMessagesFilterAnimation {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterAnimation {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterAnimation" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterAnimation" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -277,14 +314,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterAnimation {}
 
 
 impl SearchMessagesFilterAnimation {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterAnimationBuilder {
-    let mut inner = SearchMessagesFilterAnimation::default();
-    inner.td_name = "searchMessagesFilterAnimation".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterAnimation { td_name: "searchMessagesFilterAnimation".to_string(), ..Default::default() };
     RTDSearchMessagesFilterAnimationBuilder { inner }
   }
 
+  /// `SearchMessagesFilterAnimation` with the fields tdlib requires, the others are set by `SearchMessagesFilterAnimation::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -295,6 +333,12 @@ pub struct RTDSearchMessagesFilterAnimationBuilder {
 impl RTDSearchMessagesFilterAnimationBuilder {
   pub fn build(&self) -> SearchMessagesFilterAnimation { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterAnimation> for SearchMessagesFilterAnimation {
@@ -305,6 +349,10 @@ impl AsRef<SearchMessagesFilterAnimation> for RTDSearchMessagesFilterAnimationBu
   fn as_ref(&self) -> &SearchMessagesFilterAnimation { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterAnimation {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -319,13 +367,17 @@ pub struct SearchMessagesFilterAudio {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterAudio {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterAudio" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterAudio" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -335,14 +387,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterAudio {}
 
 
 impl SearchMessagesFilterAudio {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterAudioBuilder {
-    let mut inner = SearchMessagesFilterAudio::default();
-    inner.td_name = "searchMessagesFilterAudio".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterAudio { td_name: "searchMessagesFilterAudio".to_string(), ..Default::default() };
     RTDSearchMessagesFilterAudioBuilder { inner }
   }
 
+  /// `SearchMessagesFilterAudio` with the fields tdlib requires, the others are set by `SearchMessagesFilterAudio::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -353,6 +406,12 @@ pub struct RTDSearchMessagesFilterAudioBuilder {
 impl RTDSearchMessagesFilterAudioBuilder {
   pub fn build(&self) -> SearchMessagesFilterAudio { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterAudio> for SearchMessagesFilterAudio {
@@ -363,12 +422,18 @@ impl AsRef<SearchMessagesFilterAudio> for RTDSearchMessagesFilterAudioBuilder {
   fn as_ref(&self) -> &SearchMessagesFilterAudio { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterAudio {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
+
 /// Returns only call messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SearchMessagesFilterCall {
@@ -377,13 +442,17 @@ pub struct SearchMessagesFilterCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -393,14 +462,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterCall {}
 
 
 impl SearchMessagesFilterCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterCallBuilder {
-    let mut inner = SearchMessagesFilterCall::default();
-    inner.td_name = "searchMessagesFilterCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterCall { td_name: "searchMessagesFilterCall".to_string(), ..Default::default() };
     RTDSearchMessagesFilterCallBuilder { inner }
   }
 
+  /// `SearchMessagesFilterCall` with the fields tdlib requires, the others are set by `SearchMessagesFilterCall::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -411,6 +481,12 @@ pub struct RTDSearchMessagesFilterCallBuilder {
 impl RTDSearchMessagesFilterCallBuilder {
   pub fn build(&self) -> SearchMessagesFilterCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterCall> for SearchMessagesFilterCall {
@@ -421,6 +497,12 @@ impl AsRef<SearchMessagesFilterCall> for RTDSearchMessagesFilterCallBuilder {
   fn as_ref(&self) -> &SearchMessagesFilterCall { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterCall {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
+}
+
 
 
 
@@ -435,13 +517,17 @@ pub struct SearchMessagesFilterChatPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterChatPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterChatPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterChatPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -451,14 +537,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterChatPhoto {}
 
 
 impl SearchMessagesFilterChatPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterChatPhotoBuilder {
-    let mut inner = SearchMessagesFilterChatPhoto::default();
-    inner.td_name = "searchMessagesFilterChatPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterChatPhoto { td_name: "searchMessagesFilterChatPhoto".to_string(), ..Default::default() };
     RTDSearchMessagesFilterChatPhotoBuilder { inner }
   }
 
+  /// `SearchMessagesFilterChatPhoto` with the fields tdlib requires, the others are set by `SearchMessagesFilterChatPhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -469,6 +556,12 @@ pub struct RTDSearchMessagesFilterChatPhotoBuilder {
 impl RTDSearchMessagesFilterChatPhotoBuilder {
   pub fn build(&self) -> SearchMessagesFilterChatPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterChatPhoto> for SearchMessagesFilterChatPhoto {
@@ -479,6 +572,10 @@ impl AsRef<SearchMessagesFilterChatPhoto> for RTDSearchMessagesFilterChatPhotoBu
   fn as_ref(&self) -> &SearchMessagesFilterChatPhoto { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterChatPhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -493,13 +590,17 @@ pub struct SearchMessagesFilterDocument {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterDocument {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterDocument" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterDocument" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -509,14 +610,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterDocument {}
 
 
 impl SearchMessagesFilterDocument {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterDocumentBuilder {
-    let mut inner = SearchMessagesFilterDocument::default();
-    inner.td_name = "searchMessagesFilterDocument".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterDocument { td_name: "searchMessagesFilterDocument".to_string(), ..Default::default() };
     RTDSearchMessagesFilterDocumentBuilder { inner }
   }
 
+  /// `SearchMessagesFilterDocument` with the fields tdlib requires, the others are set by `SearchMessagesFilterDocument::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -527,6 +629,12 @@ pub struct RTDSearchMessagesFilterDocumentBuilder {
 impl RTDSearchMessagesFilterDocumentBuilder {
   pub fn build(&self) -> SearchMessagesFilterDocument { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterDocument> for SearchMessagesFilterDocument {
@@ -537,6 +645,10 @@ impl AsRef<SearchMessagesFilterDocument> for RTDSearchMessagesFilterDocumentBuil
   fn as_ref(&self) -> &SearchMessagesFilterDocument { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterDocument {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -551,13 +663,17 @@ pub struct SearchMessagesFilterEmpty {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterEmpty {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterEmpty" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterEmpty" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -567,14 +683,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterEmpty {}
 
 
 impl SearchMessagesFilterEmpty {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterEmptyBuilder {
-    let mut inner = SearchMessagesFilterEmpty::default();
-    inner.td_name = "searchMessagesFilterEmpty".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterEmpty { td_name: "searchMessagesFilterEmpty".to_string(), ..Default::default() };
     RTDSearchMessagesFilterEmptyBuilder { inner }
   }
 
+  /// `SearchMessagesFilterEmpty` with the fields tdlib requires, the others are set by `SearchMessagesFilterEmpty::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -585,6 +702,12 @@ pub struct RTDSearchMessagesFilterEmptyBuilder {
 impl RTDSearchMessagesFilterEmptyBuilder {
   pub fn build(&self) -> SearchMessagesFilterEmpty { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterEmpty> for SearchMessagesFilterEmpty {
@@ -595,6 +718,10 @@ impl AsRef<SearchMessagesFilterEmpty> for RTDSearchMessagesFilterEmptyBuilder {
   fn as_ref(&self) -> &SearchMessagesFilterEmpty { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterEmpty {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -609,13 +736,17 @@ pub struct SearchMessagesFilterFailedToSend {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterFailedToSend {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterFailedToSend" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterFailedToSend" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -625,14 +756,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterFailedToSend {}
 
 
 impl SearchMessagesFilterFailedToSend {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterFailedToSendBuilder {
-    let mut inner = SearchMessagesFilterFailedToSend::default();
-    inner.td_name = "searchMessagesFilterFailedToSend".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterFailedToSend { td_name: "searchMessagesFilterFailedToSend".to_string(), ..Default::default() };
     RTDSearchMessagesFilterFailedToSendBuilder { inner }
   }
 
+  /// `SearchMessagesFilterFailedToSend` with the fields tdlib requires, the others are set by `SearchMessagesFilterFailedToSend::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -643,6 +775,12 @@ pub struct RTDSearchMessagesFilterFailedToSendBuilder {
 impl RTDSearchMessagesFilterFailedToSendBuilder {
   pub fn build(&self) -> SearchMessagesFilterFailedToSend { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterFailedToSend> for SearchMessagesFilterFailedToSend {
@@ -653,6 +791,10 @@ impl AsRef<SearchMessagesFilterFailedToSend> for RTDSearchMessagesFilterFailedTo
   fn as_ref(&self) -> &SearchMessagesFilterFailedToSend { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterFailedToSend {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -667,13 +809,17 @@ pub struct SearchMessagesFilterMention {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterMention {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterMention" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterMention" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -683,14 +829,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterMention {}
 
 
 impl SearchMessagesFilterMention {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterMentionBuilder {
-    let mut inner = SearchMessagesFilterMention::default();
-    inner.td_name = "searchMessagesFilterMention".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterMention { td_name: "searchMessagesFilterMention".to_string(), ..Default::default() };
     RTDSearchMessagesFilterMentionBuilder { inner }
   }
 
+  /// `SearchMessagesFilterMention` with the fields tdlib requires, the others are set by `SearchMessagesFilterMention::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -701,6 +848,12 @@ pub struct RTDSearchMessagesFilterMentionBuilder {
 impl RTDSearchMessagesFilterMentionBuilder {
   pub fn build(&self) -> SearchMessagesFilterMention { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterMention> for SearchMessagesFilterMention {
@@ -711,11 +864,17 @@ impl AsRef<SearchMessagesFilterMention> for RTDSearchMessagesFilterMentionBuilde
   fn as_ref(&self) -> &SearchMessagesFilterMention { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterMention {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
 
 /// Returns only incoming call messages with missed/declined discard reasons
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -725,13 +884,17 @@ pub struct SearchMessagesFilterMissedCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterMissedCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterMissedCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterMissedCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -741,14 +904,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterMissedCall {}
 
 
 impl SearchMessagesFilterMissedCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterMissedCallBuilder {
-    let mut inner = SearchMessagesFilterMissedCall::default();
-    inner.td_name = "searchMessagesFilterMissedCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterMissedCall { td_name: "searchMessagesFilterMissedCall".to_string(), ..Default::default() };
     RTDSearchMessagesFilterMissedCallBuilder { inner }
   }
 
+  /// `SearchMessagesFilterMissedCall` with the fields tdlib requires, the others are set by `SearchMessagesFilterMissedCall::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -759,6 +923,12 @@ pub struct RTDSearchMessagesFilterMissedCallBuilder {
 impl RTDSearchMessagesFilterMissedCallBuilder {
   pub fn build(&self) -> SearchMessagesFilterMissedCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterMissedCall> for SearchMessagesFilterMissedCall {
@@ -769,6 +939,12 @@ impl AsRef<SearchMessagesFilterMissedCall> for RTDSearchMessagesFilterMissedCall
   fn as_ref(&self) -> &SearchMessagesFilterMissedCall { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterMissedCall {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
+}
+
 
 
 
@@ -783,13 +959,17 @@ pub struct SearchMessagesFilterPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -799,14 +979,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterPhoto {}
 
 
 impl SearchMessagesFilterPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterPhotoBuilder {
-    let mut inner = SearchMessagesFilterPhoto::default();
-    inner.td_name = "searchMessagesFilterPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterPhoto { td_name: "searchMessagesFilterPhoto".to_string(), ..Default::default() };
     RTDSearchMessagesFilterPhotoBuilder { inner }
   }
 
+  /// `SearchMessagesFilterPhoto` with the fields tdlib requires, the others are set by `SearchMessagesFilterPhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -817,6 +998,12 @@ pub struct RTDSearchMessagesFilterPhotoBuilder {
 impl RTDSearchMessagesFilterPhotoBuilder {
   pub fn build(&self) -> SearchMessagesFilterPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterPhoto> for SearchMessagesFilterPhoto {
@@ -827,6 +1014,10 @@ impl AsRef<SearchMessagesFilterPhoto> for RTDSearchMessagesFilterPhotoBuilder {
   fn as_ref(&self) -> &SearchMessagesFilterPhoto { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterPhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -841,13 +1032,17 @@ pub struct SearchMessagesFilterPhotoAndVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterPhotoAndVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterPhotoAndVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterPhotoAndVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -857,14 +1052,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterPhotoAndVideo {}
 
 
 impl SearchMessagesFilterPhotoAndVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterPhotoAndVideoBuilder {
-    let mut inner = SearchMessagesFilterPhotoAndVideo::default();
-    inner.td_name = "searchMessagesFilterPhotoAndVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterPhotoAndVideo { td_name: "searchMessagesFilterPhotoAndVideo".to_string(), ..Default::default() };
     RTDSearchMessagesFilterPhotoAndVideoBuilder { inner }
   }
 
+  /// `SearchMessagesFilterPhotoAndVideo` with the fields tdlib requires, the others are set by `SearchMessagesFilterPhotoAndVideo::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -875,6 +1071,12 @@ pub struct RTDSearchMessagesFilterPhotoAndVideoBuilder {
 impl RTDSearchMessagesFilterPhotoAndVideoBuilder {
   pub fn build(&self) -> SearchMessagesFilterPhotoAndVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterPhotoAndVideo> for SearchMessagesFilterPhotoAndVideo {
@@ -885,6 +1087,10 @@ impl AsRef<SearchMessagesFilterPhotoAndVideo> for RTDSearchMessagesFilterPhotoAn
   fn as_ref(&self) -> &SearchMessagesFilterPhotoAndVideo { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterPhotoAndVideo {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -899,13 +1105,17 @@ pub struct SearchMessagesFilterPinned {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterPinned {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterPinned" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterPinned" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -915,14 +1125,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterPinned {}
 
 
 impl SearchMessagesFilterPinned {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterPinnedBuilder {
-    let mut inner = SearchMessagesFilterPinned::default();
-    inner.td_name = "searchMessagesFilterPinned".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterPinned { td_name: "searchMessagesFilterPinned".to_string(), ..Default::default() };
     RTDSearchMessagesFilterPinnedBuilder { inner }
   }
 
+  /// `SearchMessagesFilterPinned` with the fields tdlib requires, the others are set by `SearchMessagesFilterPinned::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -933,6 +1144,12 @@ pub struct RTDSearchMessagesFilterPinnedBuilder {
 impl RTDSearchMessagesFilterPinnedBuilder {
   pub fn build(&self) -> SearchMessagesFilterPinned { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterPinned> for SearchMessagesFilterPinned {
@@ -943,6 +1160,10 @@ impl AsRef<SearchMessagesFilterPinned> for RTDSearchMessagesFilterPinnedBuilder
   fn as_ref(&self) -> &SearchMessagesFilterPinned { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterPinned {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -957,13 +1178,17 @@ pub struct SearchMessagesFilterUnreadMention {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterUnreadMention {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterUnreadMention" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterUnreadMention" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -973,14 +1198,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterUnreadMention {}
 
 
 impl SearchMessagesFilterUnreadMention {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterUnreadMentionBuilder {
-    let mut inner = SearchMessagesFilterUnreadMention::default();
-    inner.td_name = "searchMessagesFilterUnreadMention".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterUnreadMention { td_name: "searchMessagesFilterUnreadMention".to_string(), ..Default::default() };
     RTDSearchMessagesFilterUnreadMentionBuilder { inner }
   }
 
+  /// `SearchMessagesFilterUnreadMention` with the fields tdlib requires, the others are set by `SearchMessagesFilterUnreadMention::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -991,6 +1217,12 @@ pub struct RTDSearchMessagesFilterUnreadMentionBuilder {
 impl RTDSearchMessagesFilterUnreadMentionBuilder {
   pub fn build(&self) -> SearchMessagesFilterUnreadMention { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterUnreadMention> for SearchMessagesFilterUnreadMention {
@@ -1001,6 +1233,10 @@ impl AsRef<SearchMessagesFilterUnreadMention> for RTDSearchMessagesFilterUnreadM
   fn as_ref(&self) -> &SearchMessagesFilterUnreadMention { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterUnreadMention {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1015,13 +1251,17 @@ pub struct SearchMessagesFilterUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1031,14 +1271,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterUrl {}
 
 
 impl SearchMessagesFilterUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterUrlBuilder {
-    let mut inner = SearchMessagesFilterUrl::default();
-    inner.td_name = "searchMessagesFilterUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterUrl { td_name: "searchMessagesFilterUrl".to_string(), ..Default::default() };
     RTDSearchMessagesFilterUrlBuilder { inner }
   }
 
+  /// `SearchMessagesFilterUrl` with the fields tdlib requires, the others are set by `SearchMessagesFilterUrl::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1049,6 +1290,12 @@ pub struct RTDSearchMessagesFilterUrlBuilder {
 impl RTDSearchMessagesFilterUrlBuilder {
   pub fn build(&self) -> SearchMessagesFilterUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterUrl> for SearchMessagesFilterUrl {
@@ -1059,6 +1306,10 @@ impl AsRef<SearchMessagesFilterUrl> for RTDSearchMessagesFilterUrlBuilder {
   fn as_ref(&self) -> &SearchMessagesFilterUrl { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterUrl {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1073,13 +1324,17 @@ pub struct SearchMessagesFilterVideo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterVideo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVideo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterVideo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1089,14 +1344,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterVideo {}
 
 
 impl SearchMessagesFilterVideo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterVideoBuilder {
-    let mut inner = SearchMessagesFilterVideo::default();
-    inner.td_name = "searchMessagesFilterVideo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterVideo { td_name: "searchMessagesFilterVideo".to_string(), ..Default::default() };
     RTDSearchMessagesFilterVideoBuilder { inner }
   }
 
+  /// `SearchMessagesFilterVideo` with the fields tdlib requires, the others are set by `SearchMessagesFilterVideo::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1107,6 +1363,12 @@ pub struct RTDSearchMessagesFilterVideoBuilder {
 impl RTDSearchMessagesFilterVideoBuilder {
   pub fn build(&self) -> SearchMessagesFilterVideo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVideo> for SearchMessagesFilterVideo {
@@ -1117,6 +1379,10 @@ impl AsRef<SearchMessagesFilterVideo> for RTDSearchMessagesFilterVideoBuilder {
   fn as_ref(&self) -> &SearchMessagesFilterVideo { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterVideo {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1131,13 +1397,17 @@ pub struct SearchMessagesFilterVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1147,14 +1417,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterVideoNote {}
 
 
 impl SearchMessagesFilterVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterVideoNoteBuilder {
-    let mut inner = SearchMessagesFilterVideoNote::default();
-    inner.td_name = "searchMessagesFilterVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterVideoNote { td_name: "searchMessagesFilterVideoNote".to_string(), ..Default::default() };
     RTDSearchMessagesFilterVideoNoteBuilder { inner }
   }
 
+  /// `SearchMessagesFilterVideoNote` with the fields tdlib requires, the others are set by `SearchMessagesFilterVideoNote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1165,6 +1436,12 @@ pub struct RTDSearchMessagesFilterVideoNoteBuilder {
 impl RTDSearchMessagesFilterVideoNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVideoNote> for SearchMessagesFilterVideoNote {
@@ -1175,6 +1452,10 @@ impl AsRef<SearchMessagesFilterVideoNote> for RTDSearchMessagesFilterVideoNoteBu
   fn as_ref(&self) -> &SearchMessagesFilterVideoNote { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterVideoNote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1189,13 +1470,17 @@ pub struct SearchMessagesFilterVoiceAndVideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterVoiceAndVideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVoiceAndVideoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterVoiceAndVideoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1205,14 +1490,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterVoiceAndVideoNote {}
 
 
 impl SearchMessagesFilterVoiceAndVideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
-    let mut inner = SearchMessagesFilterVoiceAndVideoNote::default();
-    inner.td_name = "searchMessagesFilterVoiceAndVideoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterVoiceAndVideoNote { td_name: "searchMessagesFilterVoiceAndVideoNote".to_string(), ..Default::default() };
     RTDSearchMessagesFilterVoiceAndVideoNoteBuilder { inner }
   }
 
+  /// `SearchMessagesFilterVoiceAndVideoNote` with the fields tdlib requires, the others are set by `SearchMessagesFilterVoiceAndVideoNote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1223,6 +1509,12 @@ pub struct RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
 impl RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVoiceAndVideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVoiceAndVideoNote> for SearchMessagesFilterVoiceAndVideoNote {
@@ -1233,6 +1525,10 @@ impl AsRef<SearchMessagesFilterVoiceAndVideoNote> for RTDSearchMessagesFilterVoi
   fn as_ref(&self) -> &SearchMessagesFilterVoiceAndVideoNote { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterVoiceAndVideoNote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1247,13 +1543,17 @@ pub struct SearchMessagesFilterVoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SearchMessagesFilterVoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "searchMessagesFilterVoiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "searchMessagesFilterVoiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1263,14 +1563,15 @@ impl TDSearchMessagesFilter for SearchMessagesFilterVoiceNote {}
 
 
 impl SearchMessagesFilterVoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSearchMessagesFilterVoiceNoteBuilder {
-    let mut inner = SearchMessagesFilterVoiceNote::default();
-    inner.td_name = "searchMessagesFilterVoiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SearchMessagesFilterVoiceNote { td_name: "searchMessagesFilterVoiceNote".to_string(), ..Default::default() };
     RTDSearchMessagesFilterVoiceNoteBuilder { inner }
   }
 
+  /// `SearchMessagesFilterVoiceNote` with the fields tdlib requires, the others are set by `SearchMessagesFilterVoiceNote::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1281,6 +1582,12 @@ pub struct RTDSearchMessagesFilterVoiceNoteBuilder {
 impl RTDSearchMessagesFilterVoiceNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SearchMessagesFilterVoiceNote> for SearchMessagesFilterVoiceNote {
@@ -1291,5 +1598,9 @@ impl AsRef<SearchMessagesFilterVoiceNote> for RTDSearchMessagesFilterVoiceNoteBu
   fn as_ref(&self) -> &SearchMessagesFilterVoiceNote { &self.inner }
 }
 
+impl PartialEq for SearchMessagesFilterVoiceNote {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Seconds {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Number of seconds
   seconds: f32,
   
 }
 
 impl RObject for Seconds {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "seconds" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "seconds" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Seconds {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSecondsBuilder {
-    let mut inner = Seconds::default();
-    inner.td_name = "seconds".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Seconds { td_name: "seconds".to_string(), ..Default::default() };
     RTDSecondsBuilder { inner }
   }
 
+  /// `Seconds` with the fields tdlib requires, the others are set by `Seconds::builder()`
+  pub fn new(seconds: f32) -> Self { Self::builder().seconds(seconds).build() }
+
   pub fn seconds(&self) -> f32 { self.seconds }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDSecondsBuilder {
 impl RTDSecondsBuilder {
   pub fn build(&self) -> Seconds { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn seconds(&mut self, seconds: f32) -> &mut Self {
     self.inner.seconds = seconds;
@@ -65,5 +75,11 @@ impl AsRef<Seconds> for RTDSecondsBuilder {
   fn as_ref(&self) -> &Seconds { &self.inner }
 }
 
+impl PartialEq for Seconds {
+  fn eq(&self, other: &Self) -> bool {
+    self.seconds == other.seconds
+  }
+}
+
 
 
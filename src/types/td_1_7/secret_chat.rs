@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,48 +13,69 @@ pub struct SecretChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Secret chat identifier
-  id: i64,
+  id: SecretChatId,
   /// Identifier of the chat partner
-  user_id: i64,
+  user_id: UserId,
   /// State of the secret chat
   state: SecretChatState,
   /// True, if the chat was created by the current user; otherwise false
   is_outbound: bool,
   /// Hash of the currently used key for comparison with the hash of the chat partner's key. This is a string of 36 little-endian bytes, which must be split into groups of 2 bits, each denoting a pixel of one of 4 colors FFFFFF, D5E6F3, 2D5775, and 2F99C9. The pixels must be used to make a 12x12 square image filled from left to right, top to bottom. Alternatively, the first 32 bytes of the hash can be converted to the hexadecimal format and printed as 32 2-digit hex numbers
-  key_hash: String,
+  key_hash: Bytes,
   /// Secret chat layer; determines features supported by the chat partner's application. Nested text entities and underline and strikethrough entities are supported if the layer >= 101
   layer: i64,
   
 }
 
 impl RObject for SecretChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "secretChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl SecretChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSecretChatBuilder {
-    let mut inner = SecretChat::default();
-    inner.td_name = "secretChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SecretChat { td_name: "secretChat".to_string(), ..Default::default() };
     RTDSecretChatBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `SecretChat` with the fields tdlib requires, the others are set by `SecretChat::builder()`
+  pub fn new(
+    id: SecretChatId,
+    user_id: UserId,
+    state: impl AsRef<SecretChatState>,
+    is_outbound: bool,
+    key_hash: impl AsRef<Bytes>,
+    layer: i64,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .user_id(user_id)
+      .state(state)
+      .is_outbound(is_outbound)
+      .key_hash(key_hash)
+      .layer(layer)
+      .build()
+  }
+
+  pub fn id(&self) -> SecretChatId { self.id }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn state(&self) -> &SecretChatState { &self.state }
 
   pub fn is_outbound(&self) -> bool { self.is_outbound }
 
-  pub fn key_hash(&self) -> &String { &self.key_hash }
+  pub fn key_hash(&self) -> &Bytes { &self.key_hash }
 
   pub fn layer(&self) -> i64 { self.layer }
 
@@ -69,14 +89,20 @@ pub struct RTDSecretChatBuilder {
 impl RTDSecretChatBuilder {
   pub fn build(&self) -> SecretChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: SecretChatId) -> &mut Self {
     self.inner.id = id;
     self
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -94,8 +120,8 @@ impl RTDSecretChatBuilder {
   }
 
    
-  pub fn key_hash<T: AsRef<str>>(&mut self, key_hash: T) -> &mut Self {
-    self.inner.key_hash = key_hash.as_ref().to_string();
+  pub fn key_hash<T: AsRef<Bytes>>(&mut self, key_hash: T) -> &mut Self {
+    self.inner.key_hash = key_hash.as_ref().clone();
     self
   }
 
@@ -115,5 +141,16 @@ impl AsRef<SecretChat> for RTDSecretChatBuilder {
   fn as_ref(&self) -> &SecretChat { &self.inner }
 }
 
+impl PartialEq for SecretChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.user_id == other.user_id
+      && self.state == other.state
+      && self.is_outbound == other.is_outbound
+      && self.key_hash == other.key_hash
+      && self.layer == other.layer
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDSecretChatState: Debug + RObject {}
 
 /// Describes the current secret chat state
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum SecretChatState {
   #[doc(hidden)] _Default(()),
@@ -25,6 +24,8 @@ pub enum SecretChatState {
   Pending(SecretChatStatePending),
   /// The secret chat is ready to use
   Ready(SecretChatStateReady),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for SecretChatState {
   fn deserialize<D>(deserializer: D) -> Result<SecretChatState, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      SecretChatState,
+      SecretChatState, Unknown;
       (secretChatStateClosed, Closed);
       (secretChatStatePending, Pending);
       (secretChatStateReady, Ready);
@@ -46,20 +47,32 @@ impl<'de> Deserialize<'de> for SecretChatState {
 }
 
 impl RObject for SecretChatState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       SecretChatState::Closed(t) => t.td_name(),
       SecretChatState::Pending(t) => t.td_name(),
       SecretChatState::Ready(t) => t.td_name(),
+      SecretChatState::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       SecretChatState::Closed(t) => t.extra(),
       SecretChatState::Pending(t) => t.extra(),
       SecretChatState::Ready(t) => t.extra(),
+      SecretChatState::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      SecretChatState::Closed(t) => t.client_id(),
+      SecretChatState::Pending(t) => t.client_id(),
+      SecretChatState::Ready(t) => t.client_id(),
+      SecretChatState::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -68,20 +81,23 @@ impl RObject for SecretChatState {
 }
 
 impl SecretChatState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SecretChatState::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, SecretChatState::_Default(_)) }
 
-  pub fn is_closed(&self) -> bool { if let SecretChatState::Closed(_) = self { true } else { false } }
-  pub fn is_pending(&self) -> bool { if let SecretChatState::Pending(_) = self { true } else { false } }
-  pub fn is_ready(&self) -> bool { if let SecretChatState::Ready(_) = self { true } else { false } }
+  pub fn is_closed(&self) -> bool { matches!(self, SecretChatState::Closed(_)) }
+  pub fn is_pending(&self) -> bool { matches!(self, SecretChatState::Pending(_)) }
+  pub fn is_ready(&self) -> bool { matches!(self, SecretChatState::Ready(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, SecretChatState::Unknown(_)) }
 
   pub fn on_closed<F: FnOnce(&SecretChatStateClosed)>(&self, fnc: F) -> &Self { if let SecretChatState::Closed(t) = self { fnc(t) }; self }
   pub fn on_pending<F: FnOnce(&SecretChatStatePending)>(&self, fnc: F) -> &Self { if let SecretChatState::Pending(t) = self { fnc(t) }; self }
   pub fn on_ready<F: FnOnce(&SecretChatStateReady)>(&self, fnc: F) -> &Self { if let SecretChatState::Ready(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let SecretChatState::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_closed(&self) -> Option<&SecretChatStateClosed> { if let SecretChatState::Closed(t) = self { return Some(t) } None }
   pub fn as_pending(&self) -> Option<&SecretChatStatePending> { if let SecretChatState::Pending(t) = self { return Some(t) } None }
   pub fn as_ready(&self) -> Option<&SecretChatStateReady> { if let SecretChatState::Ready(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let SecretChatState::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -111,13 +127,17 @@ pub struct SecretChatStateClosed {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SecretChatStateClosed {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChatStateClosed" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "secretChatStateClosed" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -127,14 +147,15 @@ impl TDSecretChatState for SecretChatStateClosed {}
 
 
 impl SecretChatStateClosed {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSecretChatStateClosedBuilder {
-    let mut inner = SecretChatStateClosed::default();
-    inner.td_name = "secretChatStateClosed".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SecretChatStateClosed { td_name: "secretChatStateClosed".to_string(), ..Default::default() };
     RTDSecretChatStateClosedBuilder { inner }
   }
 
+  /// `SecretChatStateClosed` with the fields tdlib requires, the others are set by `SecretChatStateClosed::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +166,12 @@ pub struct RTDSecretChatStateClosedBuilder {
 impl RTDSecretChatStateClosedBuilder {
   pub fn build(&self) -> SecretChatStateClosed { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SecretChatStateClosed> for SecretChatStateClosed {
@@ -155,6 +182,10 @@ impl AsRef<SecretChatStateClosed> for RTDSecretChatStateClosedBuilder {
   fn as_ref(&self) -> &SecretChatStateClosed { &self.inner }
 }
 
+impl PartialEq for SecretChatStateClosed {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -169,13 +200,17 @@ pub struct SecretChatStatePending {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SecretChatStatePending {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChatStatePending" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "secretChatStatePending" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -185,14 +220,15 @@ impl TDSecretChatState for SecretChatStatePending {}
 
 
 impl SecretChatStatePending {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSecretChatStatePendingBuilder {
-    let mut inner = SecretChatStatePending::default();
-    inner.td_name = "secretChatStatePending".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SecretChatStatePending { td_name: "secretChatStatePending".to_string(), ..Default::default() };
     RTDSecretChatStatePendingBuilder { inner }
   }
 
+  /// `SecretChatStatePending` with the fields tdlib requires, the others are set by `SecretChatStatePending::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,12 @@ pub struct RTDSecretChatStatePendingBuilder {
 impl RTDSecretChatStatePendingBuilder {
   pub fn build(&self) -> SecretChatStatePending { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SecretChatStatePending> for SecretChatStatePending {
@@ -213,6 +255,10 @@ impl AsRef<SecretChatStatePending> for RTDSecretChatStatePendingBuilder {
   fn as_ref(&self) -> &SecretChatStatePending { &self.inner }
 }
 
+impl PartialEq for SecretChatStatePending {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -227,13 +273,17 @@ pub struct SecretChatStateReady {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SecretChatStateReady {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "secretChatStateReady" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "secretChatStateReady" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -243,14 +293,15 @@ impl TDSecretChatState for SecretChatStateReady {}
 
 
 impl SecretChatStateReady {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSecretChatStateReadyBuilder {
-    let mut inner = SecretChatStateReady::default();
-    inner.td_name = "secretChatStateReady".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SecretChatStateReady { td_name: "secretChatStateReady".to_string(), ..Default::default() };
     RTDSecretChatStateReadyBuilder { inner }
   }
 
+  /// `SecretChatStateReady` with the fields tdlib requires, the others are set by `SecretChatStateReady::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +312,12 @@ pub struct RTDSecretChatStateReadyBuilder {
 impl RTDSecretChatStateReadyBuilder {
   pub fn build(&self) -> SecretChatStateReady { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SecretChatStateReady> for SecretChatStateReady {
@@ -271,5 +328,9 @@ impl AsRef<SecretChatStateReady> for RTDSecretChatStateReadyBuilder {
   fn as_ref(&self) -> &SecretChatStateReady { &self.inner }
 }
 
+impl PartialEq for SecretChatStateReady {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct Session {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Session identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// True, if this session is the current session
   is_current: bool,
   /// True, if a password is needed to complete authorization of the session
@@ -49,23 +51,60 @@ pub struct Session {
 }
 
 impl RObject for Session {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "session" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "session" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Session {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSessionBuilder {
-    let mut inner = Session::default();
-    inner.td_name = "session".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Session { td_name: "session".to_string(), ..Default::default() };
     RTDSessionBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `Session` with the fields tdlib requires, the others are set by `Session::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: i64,
+    is_current: bool,
+    is_password_pending: bool,
+    api_id: i64,
+    application_name: impl AsRef<str>,
+    application_version: impl AsRef<str>,
+    is_official_application: bool,
+    device_model: impl AsRef<str>,
+    platform: impl AsRef<str>,
+    system_version: impl AsRef<str>,
+    log_in_date: i64,
+    last_active_date: i64,
+    ip: impl AsRef<str>,
+    country: impl AsRef<str>,
+    region: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .is_current(is_current)
+      .is_password_pending(is_password_pending)
+      .api_id(api_id)
+      .application_name(application_name)
+      .application_version(application_version)
+      .is_official_application(is_official_application)
+      .device_model(device_model)
+      .platform(platform)
+      .system_version(system_version)
+      .log_in_date(log_in_date)
+      .last_active_date(last_active_date)
+      .ip(ip)
+      .country(country)
+      .region(region)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn is_current(&self) -> bool { self.is_current }
 
@@ -105,8 +144,14 @@ pub struct RTDSessionBuilder {
 impl RTDSessionBuilder {
   pub fn build(&self) -> Session { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -205,5 +250,25 @@ impl AsRef<Session> for RTDSessionBuilder {
   fn as_ref(&self) -> &Session { &self.inner }
 }
 
+impl PartialEq for Session {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.is_current == other.is_current
+      && self.is_password_pending == other.is_password_pending
+      && self.api_id == other.api_id
+      && self.application_name == other.application_name
+      && self.application_version == other.application_version
+      && self.is_official_application == other.is_official_application
+      && self.device_model == other.device_model
+      && self.platform == other.platform
+      && self.system_version == other.system_version
+      && self.log_in_date == other.log_in_date
+      && self.last_active_date == other.last_active_date
+      && self.ip == other.ip
+      && self.country == other.country
+      && self.region == other.region
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Sessions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of sessions
   sessions: Vec<Session>,
   
 }
 
 impl RObject for Sessions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "sessions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "sessions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Sessions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSessionsBuilder {
-    let mut inner = Sessions::default();
-    inner.td_name = "sessions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Sessions { td_name: "sessions".to_string(), ..Default::default() };
     RTDSessionsBuilder { inner }
   }
 
+  /// `Sessions` with the fields tdlib requires, the others are set by `Sessions::builder()`
+  pub fn new(sessions: Vec<Session>) -> Self { Self::builder().sessions(sessions).build() }
+
   pub fn sessions(&self) -> &Vec<Session> { &self.sessions }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDSessionsBuilder {
 impl RTDSessionsBuilder {
   pub fn build(&self) -> Sessions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sessions(&mut self, sessions: Vec<Session>) -> &mut Self {
     self.inner.sessions = sessions;
@@ -65,5 +75,11 @@ impl AsRef<Sessions> for RTDSessionsBuilder {
   fn as_ref(&self) -> &Sessions { &self.inner }
 }
 
+impl PartialEq for Sessions {
+  fn eq(&self, other: &Self) -> bool {
+    self.sessions == other.sessions
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ShippingOption {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Shipping option identifier
   id: String,
   /// Option title
@@ -25,22 +27,24 @@ pub struct ShippingOption {
 }
 
 impl RObject for ShippingOption {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "shippingOption" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "shippingOption" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ShippingOption {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDShippingOptionBuilder {
-    let mut inner = ShippingOption::default();
-    inner.td_name = "shippingOption".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ShippingOption { td_name: "shippingOption".to_string(), ..Default::default() };
     RTDShippingOptionBuilder { inner }
   }
 
+  /// `ShippingOption` with the fields tdlib requires, the others are set by `ShippingOption::builder()`
+  pub fn new(id: impl AsRef<str>, title: impl AsRef<str>, price_parts: Vec<LabeledPricePart>) -> Self { Self::builder().id(id).title(title).price_parts(price_parts).build() }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn title(&self) -> &String { &self.title }
@@ -57,6 +61,12 @@ pub struct RTDShippingOptionBuilder {
 impl RTDShippingOptionBuilder {
   pub fn build(&self) -> ShippingOption { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
@@ -85,5 +95,13 @@ impl AsRef<ShippingOption> for RTDShippingOptionBuilder {
   fn as_ref(&self) -> &ShippingOption { &self.inner }
 }
 
+impl PartialEq for ShippingOption {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.price_parts == other.price_parts
+  }
+}
+
 
 
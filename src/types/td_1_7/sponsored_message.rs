@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,11 +13,14 @@ pub struct SponsoredMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique sponsored message identifier
   id: i64,
   /// Chat identifier
-  sponsor_chat_id: i64,
+  sponsor_chat_id: ChatId,
   /// An internal link to be opened when the sponsored message is clicked; may be null. If null, the sponsor chat needs to be opened instead
   link: Option<InternalLinkType>,
   /// Content of the message
@@ -27,25 +29,27 @@ pub struct SponsoredMessage {
 }
 
 impl RObject for SponsoredMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "sponsoredMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "sponsoredMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl SponsoredMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSponsoredMessageBuilder {
-    let mut inner = SponsoredMessage::default();
-    inner.td_name = "sponsoredMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SponsoredMessage { td_name: "sponsoredMessage".to_string(), ..Default::default() };
     RTDSponsoredMessageBuilder { inner }
   }
 
+  /// `SponsoredMessage` with the fields tdlib requires, the others are set by `SponsoredMessage::builder()`
+  pub fn new(id: i64, sponsor_chat_id: ChatId, content: impl AsRef<MessageContent>) -> Self { Self::builder().id(id).sponsor_chat_id(sponsor_chat_id).content(content).build() }
+
   pub fn id(&self) -> i64 { self.id }
 
-  pub fn sponsor_chat_id(&self) -> i64 { self.sponsor_chat_id }
+  pub fn sponsor_chat_id(&self) -> ChatId { self.sponsor_chat_id }
 
   pub fn link(&self) -> &Option<InternalLinkType> { &self.link }
 
@@ -61,6 +65,12 @@ pub struct RTDSponsoredMessageBuilder {
 impl RTDSponsoredMessageBuilder {
   pub fn build(&self) -> SponsoredMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
@@ -68,7 +78,7 @@ impl RTDSponsoredMessageBuilder {
   }
 
    
-  pub fn sponsor_chat_id(&mut self, sponsor_chat_id: i64) -> &mut Self {
+  pub fn sponsor_chat_id(&mut self, sponsor_chat_id: ChatId) -> &mut Self {
     self.inner.sponsor_chat_id = sponsor_chat_id;
     self
   }
@@ -95,5 +105,14 @@ impl AsRef<SponsoredMessage> for RTDSponsoredMessageBuilder {
   fn as_ref(&self) -> &SponsoredMessage { &self.inner }
 }
 
+impl PartialEq for SponsoredMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sponsor_chat_id == other.sponsor_chat_id
+      && self.link == other.link
+      && self.content == other.content
+  }
+}
+
 
 
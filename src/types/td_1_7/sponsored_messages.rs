@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct SponsoredMessages {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of sponsored messages
   messages: Vec<SponsoredMessage>,
   
 }
 
 impl RObject for SponsoredMessages {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "sponsoredMessages" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "sponsoredMessages" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl SponsoredMessages {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSponsoredMessagesBuilder {
-    let mut inner = SponsoredMessages::default();
-    inner.td_name = "sponsoredMessages".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SponsoredMessages { td_name: "sponsoredMessages".to_string(), ..Default::default() };
     RTDSponsoredMessagesBuilder { inner }
   }
 
+  /// `SponsoredMessages` with the fields tdlib requires, the others are set by `SponsoredMessages::builder()`
+  pub fn new(messages: Vec<SponsoredMessage>) -> Self { Self::builder().messages(messages).build() }
+
   pub fn messages(&self) -> &Vec<SponsoredMessage> { &self.messages }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDSponsoredMessagesBuilder {
 impl RTDSponsoredMessagesBuilder {
   pub fn build(&self) -> SponsoredMessages { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn messages(&mut self, messages: Vec<SponsoredMessage>) -> &mut Self {
     self.inner.messages = messages;
@@ -65,5 +75,11 @@ impl AsRef<SponsoredMessages> for RTDSponsoredMessagesBuilder {
   fn as_ref(&self) -> &SponsoredMessages { &self.inner }
 }
 
+impl PartialEq for SponsoredMessages {
+  fn eq(&self, other: &Self) -> bool {
+    self.messages == other.messages
+  }
+}
+
 
 
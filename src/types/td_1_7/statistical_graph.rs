@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDStatisticalGraph: Debug + RObject {}
 
 /// Describes a statistical graph
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum StatisticalGraph {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum StatisticalGraph {
   Data(StatisticalGraphData),
   /// An error message to be shown to the user instead of the graph
   Error(StatisticalGraphError),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for StatisticalGraph {
   fn deserialize<D>(deserializer: D) -> Result<StatisticalGraph, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      StatisticalGraph,
+      StatisticalGraph, Unknown;
       (getStatisticalGraph, GetStatisticalGraph);
       (statisticalGraphAsync, Async);
       (statisticalGraphData, Data);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for StatisticalGraph {
 }
 
 impl RObject for StatisticalGraph {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       StatisticalGraph::GetStatisticalGraph(t) => t.td_name(),
       StatisticalGraph::Async(t) => t.td_name(),
       StatisticalGraph::Data(t) => t.td_name(),
       StatisticalGraph::Error(t) => t.td_name(),
+      StatisticalGraph::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       StatisticalGraph::GetStatisticalGraph(t) => t.extra(),
       StatisticalGraph::Async(t) => t.extra(),
       StatisticalGraph::Data(t) => t.extra(),
       StatisticalGraph::Error(t) => t.extra(),
+      StatisticalGraph::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      StatisticalGraph::GetStatisticalGraph(t) => t.client_id(),
+      StatisticalGraph::Async(t) => t.client_id(),
+      StatisticalGraph::Data(t) => t.client_id(),
+      StatisticalGraph::Error(t) => t.client_id(),
+      StatisticalGraph::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for StatisticalGraph {
 }
 
 impl StatisticalGraph {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let StatisticalGraph::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, StatisticalGraph::_Default(_)) }
 
-  pub fn is_get_statistical_graph(&self) -> bool { if let StatisticalGraph::GetStatisticalGraph(_) = self { true } else { false } }
-  pub fn is_async(&self) -> bool { if let StatisticalGraph::Async(_) = self { true } else { false } }
-  pub fn is_data(&self) -> bool { if let StatisticalGraph::Data(_) = self { true } else { false } }
-  pub fn is_error(&self) -> bool { if let StatisticalGraph::Error(_) = self { true } else { false } }
+  pub fn is_get_statistical_graph(&self) -> bool { matches!(self, StatisticalGraph::GetStatisticalGraph(_)) }
+  pub fn is_async(&self) -> bool { matches!(self, StatisticalGraph::Async(_)) }
+  pub fn is_data(&self) -> bool { matches!(self, StatisticalGraph::Data(_)) }
+  pub fn is_error(&self) -> bool { matches!(self, StatisticalGraph::Error(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, StatisticalGraph::Unknown(_)) }
 
   pub fn on_get_statistical_graph<F: FnOnce(&GetStatisticalGraph)>(&self, fnc: F) -> &Self { if let StatisticalGraph::GetStatisticalGraph(t) = self { fnc(t) }; self }
   pub fn on_async<F: FnOnce(&StatisticalGraphAsync)>(&self, fnc: F) -> &Self { if let StatisticalGraph::Async(t) = self { fnc(t) }; self }
   pub fn on_data<F: FnOnce(&StatisticalGraphData)>(&self, fnc: F) -> &Self { if let StatisticalGraph::Data(t) = self { fnc(t) }; self }
   pub fn on_error<F: FnOnce(&StatisticalGraphError)>(&self, fnc: F) -> &Self { if let StatisticalGraph::Error(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let StatisticalGraph::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_get_statistical_graph(&self) -> Option<&GetStatisticalGraph> { if let StatisticalGraph::GetStatisticalGraph(t) = self { return Some(t) } None }
   pub fn as_async(&self) -> Option<&StatisticalGraphAsync> { if let StatisticalGraph::Async(t) = self { return Some(t) } None }
   pub fn as_data(&self) -> Option<&StatisticalGraphData> { if let StatisticalGraph::Data(t) = self { return Some(t) } None }
   pub fn as_error(&self) -> Option<&StatisticalGraphError> { if let StatisticalGraph::Error(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let StatisticalGraph::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -121,15 +138,19 @@ pub struct StatisticalGraphAsync {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The token to use for data loading
   token: String,
   
 }
 
 impl RObject for StatisticalGraphAsync {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticalGraphAsync" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "statisticalGraphAsync" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,14 +160,15 @@ impl TDStatisticalGraph for StatisticalGraphAsync {}
 
 
 impl StatisticalGraphAsync {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStatisticalGraphAsyncBuilder {
-    let mut inner = StatisticalGraphAsync::default();
-    inner.td_name = "statisticalGraphAsync".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StatisticalGraphAsync { td_name: "statisticalGraphAsync".to_string(), ..Default::default() };
     RTDStatisticalGraphAsyncBuilder { inner }
   }
 
+  /// `StatisticalGraphAsync` with the fields tdlib requires, the others are set by `StatisticalGraphAsync::builder()`
+  pub fn new(token: impl AsRef<str>) -> Self { Self::builder().token(token).build() }
+
   pub fn token(&self) -> &String { &self.token }
 
 }
@@ -159,6 +181,12 @@ pub struct RTDStatisticalGraphAsyncBuilder {
 impl RTDStatisticalGraphAsyncBuilder {
   pub fn build(&self) -> StatisticalGraphAsync { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -175,6 +203,12 @@ impl AsRef<StatisticalGraphAsync> for RTDStatisticalGraphAsyncBuilder {
   fn as_ref(&self) -> &StatisticalGraphAsync { &self.inner }
 }
 
+impl PartialEq for StatisticalGraphAsync {
+  fn eq(&self, other: &Self) -> bool {
+    self.token == other.token
+  }
+}
+
 
 
 
@@ -189,7 +223,10 @@ pub struct StatisticalGraphData {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Graph data in JSON format
   json_data: String,
   /// If non-empty, a token which can be used to receive a zoomed in graph
@@ -198,8 +235,9 @@ pub struct StatisticalGraphData {
 }
 
 impl RObject for StatisticalGraphData {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticalGraphData" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "statisticalGraphData" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -209,14 +247,15 @@ impl TDStatisticalGraph for StatisticalGraphData {}
 
 
 impl StatisticalGraphData {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStatisticalGraphDataBuilder {
-    let mut inner = StatisticalGraphData::default();
-    inner.td_name = "statisticalGraphData".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StatisticalGraphData { td_name: "statisticalGraphData".to_string(), ..Default::default() };
     RTDStatisticalGraphDataBuilder { inner }
   }
 
+  /// `StatisticalGraphData` with the fields tdlib requires, the others are set by `StatisticalGraphData::builder()`
+  pub fn new(json_data: impl AsRef<str>, zoom_token: impl AsRef<str>) -> Self { Self::builder().json_data(json_data).zoom_token(zoom_token).build() }
+
   pub fn json_data(&self) -> &String { &self.json_data }
 
   pub fn zoom_token(&self) -> &String { &self.zoom_token }
@@ -231,6 +270,12 @@ pub struct RTDStatisticalGraphDataBuilder {
 impl RTDStatisticalGraphDataBuilder {
   pub fn build(&self) -> StatisticalGraphData { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn json_data<T: AsRef<str>>(&mut self, json_data: T) -> &mut Self {
     self.inner.json_data = json_data.as_ref().to_string();
@@ -253,6 +298,13 @@ impl AsRef<StatisticalGraphData> for RTDStatisticalGraphDataBuilder {
   fn as_ref(&self) -> &StatisticalGraphData { &self.inner }
 }
 
+impl PartialEq for StatisticalGraphData {
+  fn eq(&self, other: &Self) -> bool {
+    self.json_data == other.json_data
+      && self.zoom_token == other.zoom_token
+  }
+}
+
 
 
 
@@ -267,15 +319,19 @@ pub struct StatisticalGraphError {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The error message
   error_message: String,
   
 }
 
 impl RObject for StatisticalGraphError {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticalGraphError" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "statisticalGraphError" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -285,14 +341,15 @@ impl TDStatisticalGraph for StatisticalGraphError {}
 
 
 impl StatisticalGraphError {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStatisticalGraphErrorBuilder {
-    let mut inner = StatisticalGraphError::default();
-    inner.td_name = "statisticalGraphError".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StatisticalGraphError { td_name: "statisticalGraphError".to_string(), ..Default::default() };
     RTDStatisticalGraphErrorBuilder { inner }
   }
 
+  /// `StatisticalGraphError` with the fields tdlib requires, the others are set by `StatisticalGraphError::builder()`
+  pub fn new(error_message: impl AsRef<str>) -> Self { Self::builder().error_message(error_message).build() }
+
   pub fn error_message(&self) -> &String { &self.error_message }
 
 }
@@ -305,6 +362,12 @@ pub struct RTDStatisticalGraphErrorBuilder {
 impl RTDStatisticalGraphErrorBuilder {
   pub fn build(&self) -> StatisticalGraphError { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
@@ -321,5 +384,11 @@ impl AsRef<StatisticalGraphError> for RTDStatisticalGraphErrorBuilder {
   fn as_ref(&self) -> &StatisticalGraphError { &self.inner }
 }
 
+impl PartialEq for StatisticalGraphError {
+  fn eq(&self, other: &Self) -> bool {
+    self.error_message == other.error_message
+  }
+}
+
 
 
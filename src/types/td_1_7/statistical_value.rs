@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct StatisticalValue {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The current value
   value: f32,
   /// The value for the previous day
@@ -25,22 +27,24 @@ pub struct StatisticalValue {
 }
 
 impl RObject for StatisticalValue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "statisticalValue" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "statisticalValue" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl StatisticalValue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStatisticalValueBuilder {
-    let mut inner = StatisticalValue::default();
-    inner.td_name = "statisticalValue".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StatisticalValue { td_name: "statisticalValue".to_string(), ..Default::default() };
     RTDStatisticalValueBuilder { inner }
   }
 
+  /// `StatisticalValue` with the fields tdlib requires, the others are set by `StatisticalValue::builder()`
+  pub fn new(value: f32, previous_value: f32, growth_rate_percentage: f32) -> Self { Self::builder().value(value).previous_value(previous_value).growth_rate_percentage(growth_rate_percentage).build() }
+
   pub fn value(&self) -> f32 { self.value }
 
   pub fn previous_value(&self) -> f32 { self.previous_value }
@@ -57,6 +61,12 @@ pub struct RTDStatisticalValueBuilder {
 impl RTDStatisticalValueBuilder {
   pub fn build(&self) -> StatisticalValue { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: f32) -> &mut Self {
     self.inner.value = value;
@@ -85,5 +95,13 @@ impl AsRef<StatisticalValue> for RTDStatisticalValueBuilder {
   fn as_ref(&self) -> &StatisticalValue { &self.inner }
 }
 
+impl PartialEq for StatisticalValue {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+      && self.previous_value == other.previous_value
+      && self.growth_rate_percentage == other.growth_rate_percentage
+  }
+}
+
 
 
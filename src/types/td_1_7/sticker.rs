@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct Sticker {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The identifier of the sticker set to which the sticker belongs; 0 if none
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] set_id: i64,
   /// Sticker width; as defined by the sender
   width: i64,
   /// Sticker height; as defined by the sender
@@ -39,23 +41,46 @@ pub struct Sticker {
 }
 
 impl RObject for Sticker {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "sticker" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "sticker" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Sticker {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStickerBuilder {
-    let mut inner = Sticker::default();
-    inner.td_name = "sticker".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Sticker { td_name: "sticker".to_string(), ..Default::default() };
     RTDStickerBuilder { inner }
   }
 
-  pub fn set_id(&self) -> isize { self.set_id }
+  /// `Sticker` with the fields tdlib requires, the others are set by `Sticker::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    set_id: i64,
+    width: i64,
+    height: i64,
+    emoji: impl AsRef<str>,
+    is_animated: bool,
+    is_mask: bool,
+    outline: Vec<ClosedVectorPath>,
+    sticker: impl AsRef<File>,
+  ) -> Self {
+    Self::builder()
+      .set_id(set_id)
+      .width(width)
+      .height(height)
+      .emoji(emoji)
+      .is_animated(is_animated)
+      .is_mask(is_mask)
+      .outline(outline)
+      .sticker(sticker)
+      .build()
+  }
+
+  pub fn set_id(&self) -> i64 { self.set_id }
 
   pub fn width(&self) -> i64 { self.width }
 
@@ -85,8 +110,14 @@ pub struct RTDStickerBuilder {
 impl RTDStickerBuilder {
   pub fn build(&self) -> Sticker { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+  pub fn set_id(&mut self, set_id: i64) -> &mut Self {
     self.inner.set_id = set_id;
     self
   }
@@ -155,5 +186,20 @@ impl AsRef<Sticker> for RTDStickerBuilder {
   fn as_ref(&self) -> &Sticker { &self.inner }
 }
 
+impl PartialEq for Sticker {
+  fn eq(&self, other: &Self) -> bool {
+    self.set_id == other.set_id
+      && self.width == other.width
+      && self.height == other.height
+      && self.emoji == other.emoji
+      && self.is_animated == other.is_animated
+      && self.is_mask == other.is_mask
+      && self.mask_position == other.mask_position
+      && self.outline == other.outline
+      && self.thumbnail == other.thumbnail
+      && self.sticker == other.sticker
+  }
+}
+
 
 
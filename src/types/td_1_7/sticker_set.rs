@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct StickerSet {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the sticker set
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Title of the sticker set
   title: String,
   /// Name of the sticker set
@@ -45,23 +47,54 @@ pub struct StickerSet {
 }
 
 impl RObject for StickerSet {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "stickerSet" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "stickerSet" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl StickerSet {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStickerSetBuilder {
-    let mut inner = StickerSet::default();
-    inner.td_name = "stickerSet".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StickerSet { td_name: "stickerSet".to_string(), ..Default::default() };
     RTDStickerSetBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `StickerSet` with the fields tdlib requires, the others are set by `StickerSet::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: i64,
+    title: impl AsRef<str>,
+    name: impl AsRef<str>,
+    thumbnail_outline: Vec<ClosedVectorPath>,
+    is_installed: bool,
+    is_archived: bool,
+    is_official: bool,
+    is_animated: bool,
+    is_masks: bool,
+    is_viewed: bool,
+    stickers: Vec<Sticker>,
+    emojis: Vec<Emojis>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .name(name)
+      .thumbnail_outline(thumbnail_outline)
+      .is_installed(is_installed)
+      .is_archived(is_archived)
+      .is_official(is_official)
+      .is_animated(is_animated)
+      .is_masks(is_masks)
+      .is_viewed(is_viewed)
+      .stickers(stickers)
+      .emojis(emojis)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn title(&self) -> &String { &self.title }
 
@@ -97,8 +130,14 @@ pub struct RTDStickerSetBuilder {
 impl RTDStickerSetBuilder {
   pub fn build(&self) -> StickerSet { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -185,5 +224,23 @@ impl AsRef<StickerSet> for RTDStickerSetBuilder {
   fn as_ref(&self) -> &StickerSet { &self.inner }
 }
 
+impl PartialEq for StickerSet {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.name == other.name
+      && self.thumbnail == other.thumbnail
+      && self.thumbnail_outline == other.thumbnail_outline
+      && self.is_installed == other.is_installed
+      && self.is_archived == other.is_archived
+      && self.is_official == other.is_official
+      && self.is_animated == other.is_animated
+      && self.is_masks == other.is_masks
+      && self.is_viewed == other.is_viewed
+      && self.stickers == other.stickers
+      && self.emojis == other.emojis
+  }
+}
+
 
 
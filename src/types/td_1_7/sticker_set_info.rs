@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct StickerSetInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the sticker set
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Title of the sticker set
   title: String,
   /// Name of the sticker set
@@ -45,23 +47,54 @@ pub struct StickerSetInfo {
 }
 
 impl RObject for StickerSetInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "stickerSetInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "stickerSetInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl StickerSetInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStickerSetInfoBuilder {
-    let mut inner = StickerSetInfo::default();
-    inner.td_name = "stickerSetInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StickerSetInfo { td_name: "stickerSetInfo".to_string(), ..Default::default() };
     RTDStickerSetInfoBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `StickerSetInfo` with the fields tdlib requires, the others are set by `StickerSetInfo::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: i64,
+    title: impl AsRef<str>,
+    name: impl AsRef<str>,
+    thumbnail_outline: Vec<ClosedVectorPath>,
+    is_installed: bool,
+    is_archived: bool,
+    is_official: bool,
+    is_animated: bool,
+    is_masks: bool,
+    is_viewed: bool,
+    size: i64,
+    covers: Vec<Sticker>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .title(title)
+      .name(name)
+      .thumbnail_outline(thumbnail_outline)
+      .is_installed(is_installed)
+      .is_archived(is_archived)
+      .is_official(is_official)
+      .is_animated(is_animated)
+      .is_masks(is_masks)
+      .is_viewed(is_viewed)
+      .size(size)
+      .covers(covers)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn title(&self) -> &String { &self.title }
 
@@ -97,8 +130,14 @@ pub struct RTDStickerSetInfoBuilder {
 impl RTDStickerSetInfoBuilder {
   pub fn build(&self) -> StickerSetInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -185,5 +224,23 @@ impl AsRef<StickerSetInfo> for RTDStickerSetInfoBuilder {
   fn as_ref(&self) -> &StickerSetInfo { &self.inner }
 }
 
+impl PartialEq for StickerSetInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.title == other.title
+      && self.name == other.name
+      && self.thumbnail == other.thumbnail
+      && self.thumbnail_outline == other.thumbnail_outline
+      && self.is_installed == other.is_installed
+      && self.is_archived == other.is_archived
+      && self.is_official == other.is_official
+      && self.is_animated == other.is_animated
+      && self.is_masks == other.is_masks
+      && self.is_viewed == other.is_viewed
+      && self.size == other.size
+      && self.covers == other.covers
+  }
+}
+
 
 
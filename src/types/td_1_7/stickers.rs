@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Stickers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of stickers
   stickers: Vec<Sticker>,
   
 }
 
 impl RObject for Stickers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "stickers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "stickers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Stickers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStickersBuilder {
-    let mut inner = Stickers::default();
-    inner.td_name = "stickers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Stickers { td_name: "stickers".to_string(), ..Default::default() };
     RTDStickersBuilder { inner }
   }
 
+  /// `Stickers` with the fields tdlib requires, the others are set by `Stickers::builder()`
+  pub fn new(stickers: Vec<Sticker>) -> Self { Self::builder().stickers(stickers).build() }
+
   pub fn stickers(&self) -> &Vec<Sticker> { &self.stickers }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDStickersBuilder {
 impl RTDStickersBuilder {
   pub fn build(&self) -> Stickers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn stickers(&mut self, stickers: Vec<Sticker>) -> &mut Self {
     self.inner.stickers = stickers;
@@ -65,5 +75,11 @@ impl AsRef<Stickers> for RTDStickersBuilder {
   fn as_ref(&self) -> &Stickers { &self.inner }
 }
 
+impl PartialEq for Stickers {
+  fn eq(&self, other: &Self) -> bool {
+    self.stickers == other.stickers
+  }
+}
+
 
 
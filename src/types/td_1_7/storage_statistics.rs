@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct StorageStatistics {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Total size of files, in bytes
   size: i64,
   /// Total number of files
@@ -25,22 +27,24 @@ pub struct StorageStatistics {
 }
 
 impl RObject for StorageStatistics {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatistics" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "storageStatistics" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl StorageStatistics {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStorageStatisticsBuilder {
-    let mut inner = StorageStatistics::default();
-    inner.td_name = "storageStatistics".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StorageStatistics { td_name: "storageStatistics".to_string(), ..Default::default() };
     RTDStorageStatisticsBuilder { inner }
   }
 
+  /// `StorageStatistics` with the fields tdlib requires, the others are set by `StorageStatistics::builder()`
+  pub fn new(size: i64, count: i64, by_chat: Vec<StorageStatisticsByChat>) -> Self { Self::builder().size(size).count(count).by_chat(by_chat).build() }
+
   pub fn size(&self) -> i64 { self.size }
 
   pub fn count(&self) -> i64 { self.count }
@@ -57,6 +61,12 @@ pub struct RTDStorageStatisticsBuilder {
 impl RTDStorageStatisticsBuilder {
   pub fn build(&self) -> StorageStatistics { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn size(&mut self, size: i64) -> &mut Self {
     self.inner.size = size;
@@ -85,5 +95,13 @@ impl AsRef<StorageStatistics> for RTDStorageStatisticsBuilder {
   fn as_ref(&self) -> &StorageStatistics { &self.inner }
 }
 
+impl PartialEq for StorageStatistics {
+  fn eq(&self, other: &Self) -> bool {
+    self.size == other.size
+      && self.count == other.count
+      && self.by_chat == other.by_chat
+  }
+}
+
 
 
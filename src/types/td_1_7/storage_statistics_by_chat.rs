@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct StorageStatisticsByChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier; 0 if none
-  chat_id: i64,
+  chat_id: ChatId,
   /// Total size of the files in the chat, in bytes
   size: i64,
   /// Total number of files in the chat
@@ -27,23 +29,37 @@ pub struct StorageStatisticsByChat {
 }
 
 impl RObject for StorageStatisticsByChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatisticsByChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "storageStatisticsByChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl StorageStatisticsByChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStorageStatisticsByChatBuilder {
-    let mut inner = StorageStatisticsByChat::default();
-    inner.td_name = "storageStatisticsByChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StorageStatisticsByChat { td_name: "storageStatisticsByChat".to_string(), ..Default::default() };
     RTDStorageStatisticsByChatBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `StorageStatisticsByChat` with the fields tdlib requires, the others are set by `StorageStatisticsByChat::builder()`
+  pub fn new(
+    chat_id: ChatId,
+    size: i64,
+    count: i64,
+    by_file_type: Vec<StorageStatisticsByFileType>,
+  ) -> Self {
+    Self::builder()
+      .chat_id(chat_id)
+      .size(size)
+      .count(count)
+      .by_file_type(by_file_type)
+      .build()
+  }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn size(&self) -> i64 { self.size }
 
@@ -61,8 +77,14 @@ pub struct RTDStorageStatisticsByChatBuilder {
 impl RTDStorageStatisticsByChatBuilder {
   pub fn build(&self) -> StorageStatisticsByChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -95,5 +117,14 @@ impl AsRef<StorageStatisticsByChat> for RTDStorageStatisticsByChatBuilder {
   fn as_ref(&self) -> &StorageStatisticsByChat { &self.inner }
 }
 
+impl PartialEq for StorageStatisticsByChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.size == other.size
+      && self.count == other.count
+      && self.by_file_type == other.by_file_type
+  }
+}
+
 
 
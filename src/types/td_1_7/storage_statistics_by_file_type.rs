@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct StorageStatisticsByFileType {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// File type
   file_type: FileType,
   /// Total size of the files, in bytes
@@ -25,22 +27,24 @@ pub struct StorageStatisticsByFileType {
 }
 
 impl RObject for StorageStatisticsByFileType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatisticsByFileType" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "storageStatisticsByFileType" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl StorageStatisticsByFileType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStorageStatisticsByFileTypeBuilder {
-    let mut inner = StorageStatisticsByFileType::default();
-    inner.td_name = "storageStatisticsByFileType".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StorageStatisticsByFileType { td_name: "storageStatisticsByFileType".to_string(), ..Default::default() };
     RTDStorageStatisticsByFileTypeBuilder { inner }
   }
 
+  /// `StorageStatisticsByFileType` with the fields tdlib requires, the others are set by `StorageStatisticsByFileType::builder()`
+  pub fn new(file_type: impl AsRef<FileType>, size: i64, count: i64) -> Self { Self::builder().file_type(file_type).size(size).count(count).build() }
+
   pub fn file_type(&self) -> &FileType { &self.file_type }
 
   pub fn size(&self) -> i64 { self.size }
@@ -57,6 +61,12 @@ pub struct RTDStorageStatisticsByFileTypeBuilder {
 impl RTDStorageStatisticsByFileTypeBuilder {
   pub fn build(&self) -> StorageStatisticsByFileType { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
     self.inner.file_type = file_type.as_ref().clone();
@@ -85,5 +95,13 @@ impl AsRef<StorageStatisticsByFileType> for RTDStorageStatisticsByFileTypeBuilde
   fn as_ref(&self) -> &StorageStatisticsByFileType { &self.inner }
 }
 
+impl PartialEq for StorageStatisticsByFileType {
+  fn eq(&self, other: &Self) -> bool {
+    self.file_type == other.file_type
+      && self.size == other.size
+      && self.count == other.count
+  }
+}
+
 
 
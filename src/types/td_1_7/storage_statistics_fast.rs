@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct StorageStatisticsFast {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Approximate total size of files, in bytes
   files_size: i64,
   /// Approximate number of files
@@ -29,22 +31,38 @@ pub struct StorageStatisticsFast {
 }
 
 impl RObject for StorageStatisticsFast {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "storageStatisticsFast" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "storageStatisticsFast" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl StorageStatisticsFast {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDStorageStatisticsFastBuilder {
-    let mut inner = StorageStatisticsFast::default();
-    inner.td_name = "storageStatisticsFast".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = StorageStatisticsFast { td_name: "storageStatisticsFast".to_string(), ..Default::default() };
     RTDStorageStatisticsFastBuilder { inner }
   }
 
+  /// `StorageStatisticsFast` with the fields tdlib requires, the others are set by `StorageStatisticsFast::builder()`
+  pub fn new(
+    files_size: i64,
+    file_count: i64,
+    database_size: i64,
+    language_pack_database_size: i64,
+    log_size: i64,
+  ) -> Self {
+    Self::builder()
+      .files_size(files_size)
+      .file_count(file_count)
+      .database_size(database_size)
+      .language_pack_database_size(language_pack_database_size)
+      .log_size(log_size)
+      .build()
+  }
+
   pub fn files_size(&self) -> i64 { self.files_size }
 
   pub fn file_count(&self) -> i64 { self.file_count }
@@ -65,6 +83,12 @@ pub struct RTDStorageStatisticsFastBuilder {
 impl RTDStorageStatisticsFastBuilder {
   pub fn build(&self) -> StorageStatisticsFast { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn files_size(&mut self, files_size: i64) -> &mut Self {
     self.inner.files_size = files_size;
@@ -105,5 +129,15 @@ impl AsRef<StorageStatisticsFast> for RTDStorageStatisticsFastBuilder {
   fn as_ref(&self) -> &StorageStatisticsFast { &self.inner }
 }
 
+impl PartialEq for StorageStatisticsFast {
+  fn eq(&self, other: &Self) -> bool {
+    self.files_size == other.files_size
+      && self.file_count == other.file_count
+      && self.database_size == other.database_size
+      && self.language_pack_database_size == other.language_pack_database_size
+      && self.log_size == other.log_size
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDSuggestedAction: Debug + RObject {}
 
 /// Describes an action suggested to the current user
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum SuggestedAction {
   #[doc(hidden)] _Default(()),
@@ -29,6 +28,8 @@ pub enum SuggestedAction {
   EnableArchiveAndMuteNewChats(SuggestedActionEnableArchiveAndMuteNewChats),
   /// Suggests the user to see a hint about meaning of one and two ticks on sent message
   SeeTicksHint(SuggestedActionSeeTicksHint),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -40,7 +41,7 @@ impl<'de> Deserialize<'de> for SuggestedAction {
   fn deserialize<D>(deserializer: D) -> Result<SuggestedAction, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      SuggestedAction,
+      SuggestedAction, Unknown;
       (suggestedActionCheckPassword, CheckPassword);
       (suggestedActionCheckPhoneNumber, CheckPhoneNumber);
       (suggestedActionConvertToBroadcastGroup, ConvertToBroadcastGroup);
@@ -52,24 +53,38 @@ impl<'de> Deserialize<'de> for SuggestedAction {
 }
 
 impl RObject for SuggestedAction {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       SuggestedAction::CheckPassword(t) => t.td_name(),
       SuggestedAction::CheckPhoneNumber(t) => t.td_name(),
       SuggestedAction::ConvertToBroadcastGroup(t) => t.td_name(),
       SuggestedAction::EnableArchiveAndMuteNewChats(t) => t.td_name(),
       SuggestedAction::SeeTicksHint(t) => t.td_name(),
+      SuggestedAction::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       SuggestedAction::CheckPassword(t) => t.extra(),
       SuggestedAction::CheckPhoneNumber(t) => t.extra(),
       SuggestedAction::ConvertToBroadcastGroup(t) => t.extra(),
       SuggestedAction::EnableArchiveAndMuteNewChats(t) => t.extra(),
       SuggestedAction::SeeTicksHint(t) => t.extra(),
+      SuggestedAction::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      SuggestedAction::CheckPassword(t) => t.client_id(),
+      SuggestedAction::CheckPhoneNumber(t) => t.client_id(),
+      SuggestedAction::ConvertToBroadcastGroup(t) => t.client_id(),
+      SuggestedAction::EnableArchiveAndMuteNewChats(t) => t.client_id(),
+      SuggestedAction::SeeTicksHint(t) => t.client_id(),
+      SuggestedAction::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -78,26 +93,29 @@ impl RObject for SuggestedAction {
 }
 
 impl SuggestedAction {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SuggestedAction::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, SuggestedAction::_Default(_)) }
 
-  pub fn is_check_password(&self) -> bool { if let SuggestedAction::CheckPassword(_) = self { true } else { false } }
-  pub fn is_check_phone_number(&self) -> bool { if let SuggestedAction::CheckPhoneNumber(_) = self { true } else { false } }
-  pub fn is_convert_to_broadcast_group(&self) -> bool { if let SuggestedAction::ConvertToBroadcastGroup(_) = self { true } else { false } }
-  pub fn is_enable_archive_and_mute_new_chats(&self) -> bool { if let SuggestedAction::EnableArchiveAndMuteNewChats(_) = self { true } else { false } }
-  pub fn is_see_ticks_hint(&self) -> bool { if let SuggestedAction::SeeTicksHint(_) = self { true } else { false } }
+  pub fn is_check_password(&self) -> bool { matches!(self, SuggestedAction::CheckPassword(_)) }
+  pub fn is_check_phone_number(&self) -> bool { matches!(self, SuggestedAction::CheckPhoneNumber(_)) }
+  pub fn is_convert_to_broadcast_group(&self) -> bool { matches!(self, SuggestedAction::ConvertToBroadcastGroup(_)) }
+  pub fn is_enable_archive_and_mute_new_chats(&self) -> bool { matches!(self, SuggestedAction::EnableArchiveAndMuteNewChats(_)) }
+  pub fn is_see_ticks_hint(&self) -> bool { matches!(self, SuggestedAction::SeeTicksHint(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, SuggestedAction::Unknown(_)) }
 
   pub fn on_check_password<F: FnOnce(&SuggestedActionCheckPassword)>(&self, fnc: F) -> &Self { if let SuggestedAction::CheckPassword(t) = self { fnc(t) }; self }
   pub fn on_check_phone_number<F: FnOnce(&SuggestedActionCheckPhoneNumber)>(&self, fnc: F) -> &Self { if let SuggestedAction::CheckPhoneNumber(t) = self { fnc(t) }; self }
   pub fn on_convert_to_broadcast_group<F: FnOnce(&SuggestedActionConvertToBroadcastGroup)>(&self, fnc: F) -> &Self { if let SuggestedAction::ConvertToBroadcastGroup(t) = self { fnc(t) }; self }
   pub fn on_enable_archive_and_mute_new_chats<F: FnOnce(&SuggestedActionEnableArchiveAndMuteNewChats)>(&self, fnc: F) -> &Self { if let SuggestedAction::EnableArchiveAndMuteNewChats(t) = self { fnc(t) }; self }
   pub fn on_see_ticks_hint<F: FnOnce(&SuggestedActionSeeTicksHint)>(&self, fnc: F) -> &Self { if let SuggestedAction::SeeTicksHint(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let SuggestedAction::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_check_password(&self) -> Option<&SuggestedActionCheckPassword> { if let SuggestedAction::CheckPassword(t) = self { return Some(t) } None }
   pub fn as_check_phone_number(&self) -> Option<&SuggestedActionCheckPhoneNumber> { if let SuggestedAction::CheckPhoneNumber(t) = self { return Some(t) } None }
   pub fn as_convert_to_broadcast_group(&self) -> Option<&SuggestedActionConvertToBroadcastGroup> { if let SuggestedAction::ConvertToBroadcastGroup(t) = self { return Some(t) } None }
   pub fn as_enable_archive_and_mute_new_chats(&self) -> Option<&SuggestedActionEnableArchiveAndMuteNewChats> { if let SuggestedAction::EnableArchiveAndMuteNewChats(t) = self { return Some(t) } None }
   pub fn as_see_ticks_hint(&self) -> Option<&SuggestedActionSeeTicksHint> { if let SuggestedAction::SeeTicksHint(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let SuggestedAction::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -131,13 +149,17 @@ pub struct SuggestedActionCheckPassword {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SuggestedActionCheckPassword {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "suggestedActionCheckPassword" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "suggestedActionCheckPassword" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -147,14 +169,15 @@ impl TDSuggestedAction for SuggestedActionCheckPassword {}
 
 
 impl SuggestedActionCheckPassword {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSuggestedActionCheckPasswordBuilder {
-    let mut inner = SuggestedActionCheckPassword::default();
-    inner.td_name = "suggestedActionCheckPassword".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SuggestedActionCheckPassword { td_name: "suggestedActionCheckPassword".to_string(), ..Default::default() };
     RTDSuggestedActionCheckPasswordBuilder { inner }
   }
 
+  /// `SuggestedActionCheckPassword` with the fields tdlib requires, the others are set by `SuggestedActionCheckPassword::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +188,12 @@ pub struct RTDSuggestedActionCheckPasswordBuilder {
 impl RTDSuggestedActionCheckPasswordBuilder {
   pub fn build(&self) -> SuggestedActionCheckPassword { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SuggestedActionCheckPassword> for SuggestedActionCheckPassword {
@@ -175,6 +204,10 @@ impl AsRef<SuggestedActionCheckPassword> for RTDSuggestedActionCheckPasswordBuil
   fn as_ref(&self) -> &SuggestedActionCheckPassword { &self.inner }
 }
 
+impl PartialEq for SuggestedActionCheckPassword {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -189,13 +222,17 @@ pub struct SuggestedActionCheckPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SuggestedActionCheckPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "suggestedActionCheckPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "suggestedActionCheckPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -205,14 +242,15 @@ impl TDSuggestedAction for SuggestedActionCheckPhoneNumber {}
 
 
 impl SuggestedActionCheckPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSuggestedActionCheckPhoneNumberBuilder {
-    let mut inner = SuggestedActionCheckPhoneNumber::default();
-    inner.td_name = "suggestedActionCheckPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SuggestedActionCheckPhoneNumber { td_name: "suggestedActionCheckPhoneNumber".to_string(), ..Default::default() };
     RTDSuggestedActionCheckPhoneNumberBuilder { inner }
   }
 
+  /// `SuggestedActionCheckPhoneNumber` with the fields tdlib requires, the others are set by `SuggestedActionCheckPhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +261,12 @@ pub struct RTDSuggestedActionCheckPhoneNumberBuilder {
 impl RTDSuggestedActionCheckPhoneNumberBuilder {
   pub fn build(&self) -> SuggestedActionCheckPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SuggestedActionCheckPhoneNumber> for SuggestedActionCheckPhoneNumber {
@@ -233,6 +277,10 @@ impl AsRef<SuggestedActionCheckPhoneNumber> for RTDSuggestedActionCheckPhoneNumb
   fn as_ref(&self) -> &SuggestedActionCheckPhoneNumber { &self.inner }
 }
 
+impl PartialEq for SuggestedActionCheckPhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -247,15 +295,19 @@ pub struct SuggestedActionConvertToBroadcastGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Supergroup identifier
-  supergroup_id: i64,
+  supergroup_id: SupergroupId,
   
 }
 
 impl RObject for SuggestedActionConvertToBroadcastGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "suggestedActionConvertToBroadcastGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "suggestedActionConvertToBroadcastGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -265,15 +317,16 @@ impl TDSuggestedAction for SuggestedActionConvertToBroadcastGroup {}
 
 
 impl SuggestedActionConvertToBroadcastGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSuggestedActionConvertToBroadcastGroupBuilder {
-    let mut inner = SuggestedActionConvertToBroadcastGroup::default();
-    inner.td_name = "suggestedActionConvertToBroadcastGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SuggestedActionConvertToBroadcastGroup { td_name: "suggestedActionConvertToBroadcastGroup".to_string(), ..Default::default() };
     RTDSuggestedActionConvertToBroadcastGroupBuilder { inner }
   }
 
-  pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
+  /// `SuggestedActionConvertToBroadcastGroup` with the fields tdlib requires, the others are set by `SuggestedActionConvertToBroadcastGroup::builder()`
+  pub fn new(supergroup_id: SupergroupId) -> Self { Self::builder().supergroup_id(supergroup_id).build() }
+
+  pub fn supergroup_id(&self) -> SupergroupId { self.supergroup_id }
 
 }
 
@@ -285,8 +338,14 @@ pub struct RTDSuggestedActionConvertToBroadcastGroupBuilder {
 impl RTDSuggestedActionConvertToBroadcastGroupBuilder {
   pub fn build(&self) -> SuggestedActionConvertToBroadcastGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
+  pub fn supergroup_id(&mut self, supergroup_id: SupergroupId) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
     self
   }
@@ -301,6 +360,12 @@ impl AsRef<SuggestedActionConvertToBroadcastGroup> for RTDSuggestedActionConvert
   fn as_ref(&self) -> &SuggestedActionConvertToBroadcastGroup { &self.inner }
 }
 
+impl PartialEq for SuggestedActionConvertToBroadcastGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.supergroup_id == other.supergroup_id
+  }
+}
+
 
 
 
@@ -315,13 +380,17 @@ pub struct SuggestedActionEnableArchiveAndMuteNewChats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SuggestedActionEnableArchiveAndMuteNewChats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "suggestedActionEnableArchiveAndMuteNewChats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "suggestedActionEnableArchiveAndMuteNewChats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -331,14 +400,15 @@ impl TDSuggestedAction for SuggestedActionEnableArchiveAndMuteNewChats {}
 
 
 impl SuggestedActionEnableArchiveAndMuteNewChats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder {
-    let mut inner = SuggestedActionEnableArchiveAndMuteNewChats::default();
-    inner.td_name = "suggestedActionEnableArchiveAndMuteNewChats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SuggestedActionEnableArchiveAndMuteNewChats { td_name: "suggestedActionEnableArchiveAndMuteNewChats".to_string(), ..Default::default() };
     RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder { inner }
   }
 
+  /// `SuggestedActionEnableArchiveAndMuteNewChats` with the fields tdlib requires, the others are set by `SuggestedActionEnableArchiveAndMuteNewChats::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -349,6 +419,12 @@ pub struct RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder {
 impl RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder {
   pub fn build(&self) -> SuggestedActionEnableArchiveAndMuteNewChats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SuggestedActionEnableArchiveAndMuteNewChats> for SuggestedActionEnableArchiveAndMuteNewChats {
@@ -359,6 +435,10 @@ impl AsRef<SuggestedActionEnableArchiveAndMuteNewChats> for RTDSuggestedActionEn
   fn as_ref(&self) -> &SuggestedActionEnableArchiveAndMuteNewChats { &self.inner }
 }
 
+impl PartialEq for SuggestedActionEnableArchiveAndMuteNewChats {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -373,13 +453,17 @@ pub struct SuggestedActionSeeTicksHint {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SuggestedActionSeeTicksHint {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "suggestedActionSeeTicksHint" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "suggestedActionSeeTicksHint" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -389,14 +473,15 @@ impl TDSuggestedAction for SuggestedActionSeeTicksHint {}
 
 
 impl SuggestedActionSeeTicksHint {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSuggestedActionSeeTicksHintBuilder {
-    let mut inner = SuggestedActionSeeTicksHint::default();
-    inner.td_name = "suggestedActionSeeTicksHint".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SuggestedActionSeeTicksHint { td_name: "suggestedActionSeeTicksHint".to_string(), ..Default::default() };
     RTDSuggestedActionSeeTicksHintBuilder { inner }
   }
 
+  /// `SuggestedActionSeeTicksHint` with the fields tdlib requires, the others are set by `SuggestedActionSeeTicksHint::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -407,6 +492,12 @@ pub struct RTDSuggestedActionSeeTicksHintBuilder {
 impl RTDSuggestedActionSeeTicksHintBuilder {
   pub fn build(&self) -> SuggestedActionSeeTicksHint { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SuggestedActionSeeTicksHint> for SuggestedActionSeeTicksHint {
@@ -417,5 +508,9 @@ impl AsRef<SuggestedActionSeeTicksHint> for RTDSuggestedActionSeeTicksHintBuilde
   fn as_ref(&self) -> &SuggestedActionSeeTicksHint { &self.inner }
 }
 
+impl PartialEq for SuggestedActionSeeTicksHint {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
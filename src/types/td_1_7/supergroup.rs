@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct Supergroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Supergroup or channel identifier
-  id: i64,
+  id: SupergroupId,
   /// Username of the supergroup or channel; empty for private supergroups or channels
   username: String,
   /// Point in time (Unix timestamp) when the current user joined, or the point in time when the supergroup or channel was created, in case the user is not a member
@@ -49,23 +51,60 @@ pub struct Supergroup {
 }
 
 impl RObject for Supergroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Supergroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupBuilder {
-    let mut inner = Supergroup::default();
-    inner.td_name = "supergroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Supergroup { td_name: "supergroup".to_string(), ..Default::default() };
     RTDSupergroupBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `Supergroup` with the fields tdlib requires, the others are set by `Supergroup::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: SupergroupId,
+    username: impl AsRef<str>,
+    date: i64,
+    status: impl AsRef<ChatMemberStatus>,
+    member_count: i64,
+    has_linked_chat: bool,
+    has_location: bool,
+    sign_messages: bool,
+    is_slow_mode_enabled: bool,
+    is_channel: bool,
+    is_broadcast_group: bool,
+    is_verified: bool,
+    restriction_reason: impl AsRef<str>,
+    is_scam: bool,
+    is_fake: bool,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .username(username)
+      .date(date)
+      .status(status)
+      .member_count(member_count)
+      .has_linked_chat(has_linked_chat)
+      .has_location(has_location)
+      .sign_messages(sign_messages)
+      .is_slow_mode_enabled(is_slow_mode_enabled)
+      .is_channel(is_channel)
+      .is_broadcast_group(is_broadcast_group)
+      .is_verified(is_verified)
+      .restriction_reason(restriction_reason)
+      .is_scam(is_scam)
+      .is_fake(is_fake)
+      .build()
+  }
+
+  pub fn id(&self) -> SupergroupId { self.id }
 
   pub fn username(&self) -> &String { &self.username }
 
@@ -105,8 +144,14 @@ pub struct RTDSupergroupBuilder {
 impl RTDSupergroupBuilder {
   pub fn build(&self) -> Supergroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: SupergroupId) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -205,5 +250,25 @@ impl AsRef<Supergroup> for RTDSupergroupBuilder {
   fn as_ref(&self) -> &Supergroup { &self.inner }
 }
 
+impl PartialEq for Supergroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.username == other.username
+      && self.date == other.date
+      && self.status == other.status
+      && self.member_count == other.member_count
+      && self.has_linked_chat == other.has_linked_chat
+      && self.has_location == other.has_location
+      && self.sign_messages == other.sign_messages
+      && self.is_slow_mode_enabled == other.is_slow_mode_enabled
+      && self.is_channel == other.is_channel
+      && self.is_broadcast_group == other.is_broadcast_group
+      && self.is_verified == other.is_verified
+      && self.restriction_reason == other.restriction_reason
+      && self.is_scam == other.is_scam
+      && self.is_fake == other.is_fake
+  }
+}
+
 
 
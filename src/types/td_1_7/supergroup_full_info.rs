@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,10 +13,13 @@ pub struct SupergroupFullInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat photo; may be null
   photo: Option<ChatPhoto>,
-  /// Contains full information about a supergroup or channel
+  /// Supergroup or channel description
   description: String,
   /// Number of members in the supergroup or channel; 0 if unknown
   member_count: i64,
@@ -28,7 +30,7 @@ pub struct SupergroupFullInfo {
   /// Number of users banned from chat; 0 if unknown
   banned_count: i64,
   /// Chat identifier of a discussion group for the channel, or a channel, for which the supergroup is the designated discussion group; 0 if none or unknown
-  linked_chat_id: i64,
+  linked_chat_id: ChatId,
   /// Delay between consecutive sent messages for non-administrator supergroup members, in seconds
   slow_mode_delay: i64,
   /// Time left before next message can be sent in the supergroup, in seconds. An updateSupergroupFullInfo update is not triggered when value of this field changes, but both new and old values are non-zero
@@ -46,7 +48,7 @@ pub struct SupergroupFullInfo {
   /// True, if new chat members will have access to old messages. In public or discussion groups and both public and private channels, old messages are always available, so this option affects only private supergroups without a linked chat. The value of this field is only available for chat administrators
   is_all_history_available: bool,
   /// Identifier of the supergroup sticker set; 0 if none
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] sticker_set_id: i64,
   /// Location to which the supergroup is connected; may be null
   location: Option<ChatLocation>,
   /// Primary invite link for this chat; may be null. For chat administrators with can_invite_users right only
@@ -54,29 +56,72 @@ pub struct SupergroupFullInfo {
   /// List of commands of bots in the group
   bot_commands: Vec<BotCommands>,
   /// Identifier of the basic group from which supergroup was upgraded; 0 if none
-  upgraded_from_basic_group_id: i64,
+  upgraded_from_basic_group_id: BasicGroupId,
   /// Identifier of the last message in the basic group from which supergroup was upgraded; 0 if none
-  upgraded_from_max_message_id: i64,
+  upgraded_from_max_message_id: MessageId,
   
 }
 
 impl RObject for SupergroupFullInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupFullInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupFullInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl SupergroupFullInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupFullInfoBuilder {
-    let mut inner = SupergroupFullInfo::default();
-    inner.td_name = "supergroupFullInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupFullInfo { td_name: "supergroupFullInfo".to_string(), ..Default::default() };
     RTDSupergroupFullInfoBuilder { inner }
   }
 
+  /// `SupergroupFullInfo` with the fields tdlib requires, the others are set by `SupergroupFullInfo::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    description: impl AsRef<str>,
+    member_count: i64,
+    administrator_count: i64,
+    restricted_count: i64,
+    banned_count: i64,
+    linked_chat_id: ChatId,
+    slow_mode_delay: i64,
+    slow_mode_delay_expires_in: f32,
+    can_get_members: bool,
+    can_set_username: bool,
+    can_set_sticker_set: bool,
+    can_set_location: bool,
+    can_get_statistics: bool,
+    is_all_history_available: bool,
+    sticker_set_id: i64,
+    bot_commands: Vec<BotCommands>,
+    upgraded_from_basic_group_id: BasicGroupId,
+    upgraded_from_max_message_id: MessageId,
+  ) -> Self {
+    Self::builder()
+      .description(description)
+      .member_count(member_count)
+      .administrator_count(administrator_count)
+      .restricted_count(restricted_count)
+      .banned_count(banned_count)
+      .linked_chat_id(linked_chat_id)
+      .slow_mode_delay(slow_mode_delay)
+      .slow_mode_delay_expires_in(slow_mode_delay_expires_in)
+      .can_get_members(can_get_members)
+      .can_set_username(can_set_username)
+      .can_set_sticker_set(can_set_sticker_set)
+      .can_set_location(can_set_location)
+      .can_get_statistics(can_get_statistics)
+      .is_all_history_available(is_all_history_available)
+      .sticker_set_id(sticker_set_id)
+      .bot_commands(bot_commands)
+      .upgraded_from_basic_group_id(upgraded_from_basic_group_id)
+      .upgraded_from_max_message_id(upgraded_from_max_message_id)
+      .build()
+  }
+
   pub fn photo(&self) -> &Option<ChatPhoto> { &self.photo }
 
   pub fn description(&self) -> &String { &self.description }
@@ -89,7 +134,7 @@ impl SupergroupFullInfo {
 
   pub fn banned_count(&self) -> i64 { self.banned_count }
 
-  pub fn linked_chat_id(&self) -> i64 { self.linked_chat_id }
+  pub fn linked_chat_id(&self) -> ChatId { self.linked_chat_id }
 
   pub fn slow_mode_delay(&self) -> i64 { self.slow_mode_delay }
 
@@ -107,7 +152,7 @@ impl SupergroupFullInfo {
 
   pub fn is_all_history_available(&self) -> bool { self.is_all_history_available }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
   pub fn location(&self) -> &Option<ChatLocation> { &self.location }
 
@@ -115,9 +160,9 @@ impl SupergroupFullInfo {
 
   pub fn bot_commands(&self) -> &Vec<BotCommands> { &self.bot_commands }
 
-  pub fn upgraded_from_basic_group_id(&self) -> i64 { self.upgraded_from_basic_group_id }
+  pub fn upgraded_from_basic_group_id(&self) -> BasicGroupId { self.upgraded_from_basic_group_id }
 
-  pub fn upgraded_from_max_message_id(&self) -> i64 { self.upgraded_from_max_message_id }
+  pub fn upgraded_from_max_message_id(&self) -> MessageId { self.upgraded_from_max_message_id }
 
 }
 
@@ -129,6 +174,12 @@ pub struct RTDSupergroupFullInfoBuilder {
 impl RTDSupergroupFullInfoBuilder {
   pub fn build(&self) -> SupergroupFullInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -166,7 +217,7 @@ impl RTDSupergroupFullInfoBuilder {
   }
 
    
-  pub fn linked_chat_id(&mut self, linked_chat_id: i64) -> &mut Self {
+  pub fn linked_chat_id(&mut self, linked_chat_id: ChatId) -> &mut Self {
     self.inner.linked_chat_id = linked_chat_id;
     self
   }
@@ -220,7 +271,7 @@ impl RTDSupergroupFullInfoBuilder {
   }
 
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
     self
   }
@@ -244,13 +295,13 @@ impl RTDSupergroupFullInfoBuilder {
   }
 
    
-  pub fn upgraded_from_basic_group_id(&mut self, upgraded_from_basic_group_id: i64) -> &mut Self {
+  pub fn upgraded_from_basic_group_id(&mut self, upgraded_from_basic_group_id: BasicGroupId) -> &mut Self {
     self.inner.upgraded_from_basic_group_id = upgraded_from_basic_group_id;
     self
   }
 
    
-  pub fn upgraded_from_max_message_id(&mut self, upgraded_from_max_message_id: i64) -> &mut Self {
+  pub fn upgraded_from_max_message_id(&mut self, upgraded_from_max_message_id: MessageId) -> &mut Self {
     self.inner.upgraded_from_max_message_id = upgraded_from_max_message_id;
     self
   }
@@ -265,5 +316,31 @@ impl AsRef<SupergroupFullInfo> for RTDSupergroupFullInfoBuilder {
   fn as_ref(&self) -> &SupergroupFullInfo { &self.inner }
 }
 
+impl PartialEq for SupergroupFullInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+      && self.description == other.description
+      && self.member_count == other.member_count
+      && self.administrator_count == other.administrator_count
+      && self.restricted_count == other.restricted_count
+      && self.banned_count == other.banned_count
+      && self.linked_chat_id == other.linked_chat_id
+      && self.slow_mode_delay == other.slow_mode_delay
+      && self.slow_mode_delay_expires_in == other.slow_mode_delay_expires_in
+      && self.can_get_members == other.can_get_members
+      && self.can_set_username == other.can_set_username
+      && self.can_set_sticker_set == other.can_set_sticker_set
+      && self.can_set_location == other.can_set_location
+      && self.can_get_statistics == other.can_get_statistics
+      && self.is_all_history_available == other.is_all_history_available
+      && self.sticker_set_id == other.sticker_set_id
+      && self.location == other.location
+      && self.invite_link == other.invite_link
+      && self.bot_commands == other.bot_commands
+      && self.upgraded_from_basic_group_id == other.upgraded_from_basic_group_id
+      && self.upgraded_from_max_message_id == other.upgraded_from_max_message_id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDSupergroupMembersFilter: Debug + RObject {}
 
 /// Specifies the kind of chat members to return in getSupergroupMembers
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum SupergroupMembersFilter {
   #[doc(hidden)] _Default(()),
@@ -35,6 +34,8 @@ pub enum SupergroupMembersFilter {
   Restricted(SupergroupMembersFilterRestricted),
   /// Used to search for supergroup or channel members via a (string) query
   Search(SupergroupMembersFilterSearch),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -46,7 +47,7 @@ impl<'de> Deserialize<'de> for SupergroupMembersFilter {
   fn deserialize<D>(deserializer: D) -> Result<SupergroupMembersFilter, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      SupergroupMembersFilter,
+      SupergroupMembersFilter, Unknown;
       (supergroupMembersFilterAdministrators, Administrators);
       (supergroupMembersFilterBanned, Banned);
       (supergroupMembersFilterBots, Bots);
@@ -61,7 +62,7 @@ impl<'de> Deserialize<'de> for SupergroupMembersFilter {
 }
 
 impl RObject for SupergroupMembersFilter {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       SupergroupMembersFilter::Administrators(t) => t.td_name(),
       SupergroupMembersFilter::Banned(t) => t.td_name(),
@@ -71,11 +72,12 @@ impl RObject for SupergroupMembersFilter {
       SupergroupMembersFilter::Recent(t) => t.td_name(),
       SupergroupMembersFilter::Restricted(t) => t.td_name(),
       SupergroupMembersFilter::Search(t) => t.td_name(),
+      SupergroupMembersFilter::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       SupergroupMembersFilter::Administrators(t) => t.extra(),
       SupergroupMembersFilter::Banned(t) => t.extra(),
@@ -85,6 +87,22 @@ impl RObject for SupergroupMembersFilter {
       SupergroupMembersFilter::Recent(t) => t.extra(),
       SupergroupMembersFilter::Restricted(t) => t.extra(),
       SupergroupMembersFilter::Search(t) => t.extra(),
+      SupergroupMembersFilter::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      SupergroupMembersFilter::Administrators(t) => t.client_id(),
+      SupergroupMembersFilter::Banned(t) => t.client_id(),
+      SupergroupMembersFilter::Bots(t) => t.client_id(),
+      SupergroupMembersFilter::Contacts(t) => t.client_id(),
+      SupergroupMembersFilter::Mention(t) => t.client_id(),
+      SupergroupMembersFilter::Recent(t) => t.client_id(),
+      SupergroupMembersFilter::Restricted(t) => t.client_id(),
+      SupergroupMembersFilter::Search(t) => t.client_id(),
+      SupergroupMembersFilter::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -93,17 +111,18 @@ impl RObject for SupergroupMembersFilter {
 }
 
 impl SupergroupMembersFilter {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SupergroupMembersFilter::_Default(_) = self { true } else { false } }
-
-  pub fn is_administrators(&self) -> bool { if let SupergroupMembersFilter::Administrators(_) = self { true } else { false } }
-  pub fn is_banned(&self) -> bool { if let SupergroupMembersFilter::Banned(_) = self { true } else { false } }
-  pub fn is_bots(&self) -> bool { if let SupergroupMembersFilter::Bots(_) = self { true } else { false } }
-  pub fn is_contacts(&self) -> bool { if let SupergroupMembersFilter::Contacts(_) = self { true } else { false } }
-  pub fn is_mention(&self) -> bool { if let SupergroupMembersFilter::Mention(_) = self { true } else { false } }
-  pub fn is_recent(&self) -> bool { if let SupergroupMembersFilter::Recent(_) = self { true } else { false } }
-  pub fn is_restricted(&self) -> bool { if let SupergroupMembersFilter::Restricted(_) = self { true } else { false } }
-  pub fn is_search(&self) -> bool { if let SupergroupMembersFilter::Search(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, SupergroupMembersFilter::_Default(_)) }
+
+  pub fn is_administrators(&self) -> bool { matches!(self, SupergroupMembersFilter::Administrators(_)) }
+  pub fn is_banned(&self) -> bool { matches!(self, SupergroupMembersFilter::Banned(_)) }
+  pub fn is_bots(&self) -> bool { matches!(self, SupergroupMembersFilter::Bots(_)) }
+  pub fn is_contacts(&self) -> bool { matches!(self, SupergroupMembersFilter::Contacts(_)) }
+  pub fn is_mention(&self) -> bool { matches!(self, SupergroupMembersFilter::Mention(_)) }
+  pub fn is_recent(&self) -> bool { matches!(self, SupergroupMembersFilter::Recent(_)) }
+  pub fn is_restricted(&self) -> bool { matches!(self, SupergroupMembersFilter::Restricted(_)) }
+  pub fn is_search(&self) -> bool { matches!(self, SupergroupMembersFilter::Search(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, SupergroupMembersFilter::Unknown(_)) }
 
   pub fn on_administrators<F: FnOnce(&SupergroupMembersFilterAdministrators)>(&self, fnc: F) -> &Self { if let SupergroupMembersFilter::Administrators(t) = self { fnc(t) }; self }
   pub fn on_banned<F: FnOnce(&SupergroupMembersFilterBanned)>(&self, fnc: F) -> &Self { if let SupergroupMembersFilter::Banned(t) = self { fnc(t) }; self }
@@ -113,6 +132,7 @@ impl SupergroupMembersFilter {
   pub fn on_recent<F: FnOnce(&SupergroupMembersFilterRecent)>(&self, fnc: F) -> &Self { if let SupergroupMembersFilter::Recent(t) = self { fnc(t) }; self }
   pub fn on_restricted<F: FnOnce(&SupergroupMembersFilterRestricted)>(&self, fnc: F) -> &Self { if let SupergroupMembersFilter::Restricted(t) = self { fnc(t) }; self }
   pub fn on_search<F: FnOnce(&SupergroupMembersFilterSearch)>(&self, fnc: F) -> &Self { if let SupergroupMembersFilter::Search(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let SupergroupMembersFilter::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_administrators(&self) -> Option<&SupergroupMembersFilterAdministrators> { if let SupergroupMembersFilter::Administrators(t) = self { return Some(t) } None }
   pub fn as_banned(&self) -> Option<&SupergroupMembersFilterBanned> { if let SupergroupMembersFilter::Banned(t) = self { return Some(t) } None }
@@ -122,6 +142,7 @@ impl SupergroupMembersFilter {
   pub fn as_recent(&self) -> Option<&SupergroupMembersFilterRecent> { if let SupergroupMembersFilter::Recent(t) = self { return Some(t) } None }
   pub fn as_restricted(&self) -> Option<&SupergroupMembersFilterRestricted> { if let SupergroupMembersFilter::Restricted(t) = self { return Some(t) } None }
   pub fn as_search(&self) -> Option<&SupergroupMembersFilterSearch> { if let SupergroupMembersFilter::Search(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let SupergroupMembersFilter::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -161,13 +182,17 @@ pub struct SupergroupMembersFilterAdministrators {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SupergroupMembersFilterAdministrators {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterAdministrators" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterAdministrators" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -177,14 +202,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterAdministrators {}
 
 
 impl SupergroupMembersFilterAdministrators {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterAdministratorsBuilder {
-    let mut inner = SupergroupMembersFilterAdministrators::default();
-    inner.td_name = "supergroupMembersFilterAdministrators".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterAdministrators { td_name: "supergroupMembersFilterAdministrators".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterAdministratorsBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterAdministrators` with the fields tdlib requires, the others are set by `SupergroupMembersFilterAdministrators::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +221,12 @@ pub struct RTDSupergroupMembersFilterAdministratorsBuilder {
 impl RTDSupergroupMembersFilterAdministratorsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterAdministrators { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SupergroupMembersFilterAdministrators> for SupergroupMembersFilterAdministrators {
@@ -205,6 +237,10 @@ impl AsRef<SupergroupMembersFilterAdministrators> for RTDSupergroupMembersFilter
   fn as_ref(&self) -> &SupergroupMembersFilterAdministrators { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterAdministrators {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -219,15 +255,19 @@ pub struct SupergroupMembersFilterBanned {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Query to search for
   query: String,
   
 }
 
 impl RObject for SupergroupMembersFilterBanned {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterBanned" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterBanned" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -237,14 +277,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterBanned {}
 
 
 impl SupergroupMembersFilterBanned {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterBannedBuilder {
-    let mut inner = SupergroupMembersFilterBanned::default();
-    inner.td_name = "supergroupMembersFilterBanned".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterBanned { td_name: "supergroupMembersFilterBanned".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterBannedBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterBanned` with the fields tdlib requires, the others are set by `SupergroupMembersFilterBanned::builder()`
+  pub fn new(query: impl AsRef<str>) -> Self { Self::builder().query(query).build() }
+
   pub fn query(&self) -> &String { &self.query }
 
 }
@@ -257,6 +298,12 @@ pub struct RTDSupergroupMembersFilterBannedBuilder {
 impl RTDSupergroupMembersFilterBannedBuilder {
   pub fn build(&self) -> SupergroupMembersFilterBanned { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -273,6 +320,12 @@ impl AsRef<SupergroupMembersFilterBanned> for RTDSupergroupMembersFilterBannedBu
   fn as_ref(&self) -> &SupergroupMembersFilterBanned { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterBanned {
+  fn eq(&self, other: &Self) -> bool {
+    self.query == other.query
+  }
+}
+
 
 
 
@@ -287,13 +340,17 @@ pub struct SupergroupMembersFilterBots {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SupergroupMembersFilterBots {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterBots" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterBots" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -303,14 +360,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterBots {}
 
 
 impl SupergroupMembersFilterBots {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterBotsBuilder {
-    let mut inner = SupergroupMembersFilterBots::default();
-    inner.td_name = "supergroupMembersFilterBots".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterBots { td_name: "supergroupMembersFilterBots".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterBotsBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterBots` with the fields tdlib requires, the others are set by `SupergroupMembersFilterBots::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +379,12 @@ pub struct RTDSupergroupMembersFilterBotsBuilder {
 impl RTDSupergroupMembersFilterBotsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterBots { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SupergroupMembersFilterBots> for SupergroupMembersFilterBots {
@@ -331,6 +395,10 @@ impl AsRef<SupergroupMembersFilterBots> for RTDSupergroupMembersFilterBotsBuilde
   fn as_ref(&self) -> &SupergroupMembersFilterBots { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterBots {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -345,15 +413,19 @@ pub struct SupergroupMembersFilterContacts {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Query to search for
   query: String,
   
 }
 
 impl RObject for SupergroupMembersFilterContacts {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterContacts" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterContacts" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -363,14 +435,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterContacts {}
 
 
 impl SupergroupMembersFilterContacts {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterContactsBuilder {
-    let mut inner = SupergroupMembersFilterContacts::default();
-    inner.td_name = "supergroupMembersFilterContacts".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterContacts { td_name: "supergroupMembersFilterContacts".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterContactsBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterContacts` with the fields tdlib requires, the others are set by `SupergroupMembersFilterContacts::builder()`
+  pub fn new(query: impl AsRef<str>) -> Self { Self::builder().query(query).build() }
+
   pub fn query(&self) -> &String { &self.query }
 
 }
@@ -383,6 +456,12 @@ pub struct RTDSupergroupMembersFilterContactsBuilder {
 impl RTDSupergroupMembersFilterContactsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterContacts { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -399,6 +478,12 @@ impl AsRef<SupergroupMembersFilterContacts> for RTDSupergroupMembersFilterContac
   fn as_ref(&self) -> &SupergroupMembersFilterContacts { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterContacts {
+  fn eq(&self, other: &Self) -> bool {
+    self.query == other.query
+  }
+}
+
 
 
 
@@ -413,7 +498,10 @@ pub struct SupergroupMembersFilterMention {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Query to search for
   query: String,
   /// If non-zero, the identifier of the current message thread
@@ -422,8 +510,9 @@ pub struct SupergroupMembersFilterMention {
 }
 
 impl RObject for SupergroupMembersFilterMention {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterMention" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterMention" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -433,14 +522,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterMention {}
 
 
 impl SupergroupMembersFilterMention {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterMentionBuilder {
-    let mut inner = SupergroupMembersFilterMention::default();
-    inner.td_name = "supergroupMembersFilterMention".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterMention { td_name: "supergroupMembersFilterMention".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterMentionBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterMention` with the fields tdlib requires, the others are set by `SupergroupMembersFilterMention::builder()`
+  pub fn new(query: impl AsRef<str>, message_thread_id: i64) -> Self { Self::builder().query(query).message_thread_id(message_thread_id).build() }
+
   pub fn query(&self) -> &String { &self.query }
 
   pub fn message_thread_id(&self) -> i64 { self.message_thread_id }
@@ -455,6 +545,12 @@ pub struct RTDSupergroupMembersFilterMentionBuilder {
 impl RTDSupergroupMembersFilterMentionBuilder {
   pub fn build(&self) -> SupergroupMembersFilterMention { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -477,6 +573,13 @@ impl AsRef<SupergroupMembersFilterMention> for RTDSupergroupMembersFilterMention
   fn as_ref(&self) -> &SupergroupMembersFilterMention { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterMention {
+  fn eq(&self, other: &Self) -> bool {
+    self.query == other.query
+      && self.message_thread_id == other.message_thread_id
+  }
+}
+
 
 
 
@@ -491,13 +594,17 @@ pub struct SupergroupMembersFilterRecent {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for SupergroupMembersFilterRecent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterRecent" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterRecent" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -507,14 +614,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterRecent {}
 
 
 impl SupergroupMembersFilterRecent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterRecentBuilder {
-    let mut inner = SupergroupMembersFilterRecent::default();
-    inner.td_name = "supergroupMembersFilterRecent".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterRecent { td_name: "supergroupMembersFilterRecent".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterRecentBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterRecent` with the fields tdlib requires, the others are set by `SupergroupMembersFilterRecent::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -525,6 +633,12 @@ pub struct RTDSupergroupMembersFilterRecentBuilder {
 impl RTDSupergroupMembersFilterRecentBuilder {
   pub fn build(&self) -> SupergroupMembersFilterRecent { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<SupergroupMembersFilterRecent> for SupergroupMembersFilterRecent {
@@ -535,6 +649,10 @@ impl AsRef<SupergroupMembersFilterRecent> for RTDSupergroupMembersFilterRecentBu
   fn as_ref(&self) -> &SupergroupMembersFilterRecent { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterRecent {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -549,15 +667,19 @@ pub struct SupergroupMembersFilterRestricted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Query to search for
   query: String,
   
 }
 
 impl RObject for SupergroupMembersFilterRestricted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterRestricted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterRestricted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -567,14 +689,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterRestricted {}
 
 
 impl SupergroupMembersFilterRestricted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterRestrictedBuilder {
-    let mut inner = SupergroupMembersFilterRestricted::default();
-    inner.td_name = "supergroupMembersFilterRestricted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterRestricted { td_name: "supergroupMembersFilterRestricted".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterRestrictedBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterRestricted` with the fields tdlib requires, the others are set by `SupergroupMembersFilterRestricted::builder()`
+  pub fn new(query: impl AsRef<str>) -> Self { Self::builder().query(query).build() }
+
   pub fn query(&self) -> &String { &self.query }
 
 }
@@ -587,6 +710,12 @@ pub struct RTDSupergroupMembersFilterRestrictedBuilder {
 impl RTDSupergroupMembersFilterRestrictedBuilder {
   pub fn build(&self) -> SupergroupMembersFilterRestricted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -603,6 +732,12 @@ impl AsRef<SupergroupMembersFilterRestricted> for RTDSupergroupMembersFilterRest
   fn as_ref(&self) -> &SupergroupMembersFilterRestricted { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterRestricted {
+  fn eq(&self, other: &Self) -> bool {
+    self.query == other.query
+  }
+}
+
 
 
 
@@ -617,15 +752,19 @@ pub struct SupergroupMembersFilterSearch {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Query to search for
   query: String,
   
 }
 
 impl RObject for SupergroupMembersFilterSearch {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "supergroupMembersFilterSearch" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "supergroupMembersFilterSearch" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -635,14 +774,15 @@ impl TDSupergroupMembersFilter for SupergroupMembersFilterSearch {}
 
 
 impl SupergroupMembersFilterSearch {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDSupergroupMembersFilterSearchBuilder {
-    let mut inner = SupergroupMembersFilterSearch::default();
-    inner.td_name = "supergroupMembersFilterSearch".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = SupergroupMembersFilterSearch { td_name: "supergroupMembersFilterSearch".to_string(), ..Default::default() };
     RTDSupergroupMembersFilterSearchBuilder { inner }
   }
 
+  /// `SupergroupMembersFilterSearch` with the fields tdlib requires, the others are set by `SupergroupMembersFilterSearch::builder()`
+  pub fn new(query: impl AsRef<str>) -> Self { Self::builder().query(query).build() }
+
   pub fn query(&self) -> &String { &self.query }
 
 }
@@ -655,6 +795,12 @@ pub struct RTDSupergroupMembersFilterSearchBuilder {
 impl RTDSupergroupMembersFilterSearchBuilder {
   pub fn build(&self) -> SupergroupMembersFilterSearch { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -671,5 +817,11 @@ impl AsRef<SupergroupMembersFilterSearch> for RTDSupergroupMembersFilterSearchBu
   fn as_ref(&self) -> &SupergroupMembersFilterSearch { &self.inner }
 }
 
+impl PartialEq for SupergroupMembersFilterSearch {
+  fn eq(&self, other: &Self) -> bool {
+    self.query == other.query
+  }
+}
+
 
 
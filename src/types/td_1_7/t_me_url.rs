@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct TMeUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// URL
   url: String,
   /// Type of the URL
@@ -23,22 +25,24 @@ pub struct TMeUrl {
 }
 
 impl RObject for TMeUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "tMeUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TMeUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTMeUrlBuilder {
-    let mut inner = TMeUrl::default();
-    inner.td_name = "tMeUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TMeUrl { td_name: "tMeUrl".to_string(), ..Default::default() };
     RTDTMeUrlBuilder { inner }
   }
 
+  /// `TMeUrl` with the fields tdlib requires, the others are set by `TMeUrl::builder()`
+  pub fn new(url: impl AsRef<str>, type_: impl AsRef<TMeUrlType>) -> Self { Self::builder().url(url).type_(type_).build() }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn type_(&self) -> &TMeUrlType { &self.type_ }
@@ -53,6 +57,12 @@ pub struct RTDTMeUrlBuilder {
 impl RTDTMeUrlBuilder {
   pub fn build(&self) -> TMeUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<TMeUrl> for RTDTMeUrlBuilder {
   fn as_ref(&self) -> &TMeUrl { &self.inner }
 }
 
+impl PartialEq for TMeUrl {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.type_ == other.type_
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,18 +14,21 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDTMeUrlType: Debug + RObject {}
 
 /// Describes the type of a URL linking to an internal Telegram entity
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum TMeUrlType {
   #[doc(hidden)] _Default(()),
   /// A chat invite link
   ChatInvite(TMeUrlTypeChatInvite),
   /// A URL linking to a sticker set
+  #[cfg(feature = "stickers")]
   StickerSet(TMeUrlTypeStickerSet),
   /// A URL linking to a public supergroup or channel
   Supergroup(TMeUrlTypeSupergroup),
   /// A URL linking to a user
   User(TMeUrlTypeUser),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -38,9 +40,9 @@ impl<'de> Deserialize<'de> for TMeUrlType {
   fn deserialize<D>(deserializer: D) -> Result<TMeUrlType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      TMeUrlType,
+      TMeUrlType, Unknown;
       (tMeUrlTypeChatInvite, ChatInvite);
-      (tMeUrlTypeStickerSet, StickerSet);
+      #[cfg(feature = "stickers")] (tMeUrlTypeStickerSet, StickerSet);
       (tMeUrlTypeSupergroup, Supergroup);
       (tMeUrlTypeUser, User);
 
@@ -49,22 +51,35 @@ impl<'de> Deserialize<'de> for TMeUrlType {
 }
 
 impl RObject for TMeUrlType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       TMeUrlType::ChatInvite(t) => t.td_name(),
-      TMeUrlType::StickerSet(t) => t.td_name(),
+      #[cfg(feature = "stickers")] TMeUrlType::StickerSet(t) => t.td_name(),
       TMeUrlType::Supergroup(t) => t.td_name(),
       TMeUrlType::User(t) => t.td_name(),
+      TMeUrlType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       TMeUrlType::ChatInvite(t) => t.extra(),
-      TMeUrlType::StickerSet(t) => t.extra(),
+      #[cfg(feature = "stickers")] TMeUrlType::StickerSet(t) => t.extra(),
       TMeUrlType::Supergroup(t) => t.extra(),
       TMeUrlType::User(t) => t.extra(),
+      TMeUrlType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      TMeUrlType::ChatInvite(t) => t.client_id(),
+      #[cfg(feature = "stickers")] TMeUrlType::StickerSet(t) => t.client_id(),
+      TMeUrlType::Supergroup(t) => t.client_id(),
+      TMeUrlType::User(t) => t.client_id(),
+      TMeUrlType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,29 +88,32 @@ impl RObject for TMeUrlType {
 }
 
 impl TMeUrlType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TMeUrlType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, TMeUrlType::_Default(_)) }
 
-  pub fn is_chat_invite(&self) -> bool { if let TMeUrlType::ChatInvite(_) = self { true } else { false } }
-  pub fn is_sticker_set(&self) -> bool { if let TMeUrlType::StickerSet(_) = self { true } else { false } }
-  pub fn is_supergroup(&self) -> bool { if let TMeUrlType::Supergroup(_) = self { true } else { false } }
-  pub fn is_user(&self) -> bool { if let TMeUrlType::User(_) = self { true } else { false } }
+  pub fn is_chat_invite(&self) -> bool { matches!(self, TMeUrlType::ChatInvite(_)) }
+  #[cfg(feature = "stickers")] pub fn is_sticker_set(&self) -> bool { matches!(self, TMeUrlType::StickerSet(_)) }
+  pub fn is_supergroup(&self) -> bool { matches!(self, TMeUrlType::Supergroup(_)) }
+  pub fn is_user(&self) -> bool { matches!(self, TMeUrlType::User(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, TMeUrlType::Unknown(_)) }
 
   pub fn on_chat_invite<F: FnOnce(&TMeUrlTypeChatInvite)>(&self, fnc: F) -> &Self { if let TMeUrlType::ChatInvite(t) = self { fnc(t) }; self }
-  pub fn on_sticker_set<F: FnOnce(&TMeUrlTypeStickerSet)>(&self, fnc: F) -> &Self { if let TMeUrlType::StickerSet(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_sticker_set<F: FnOnce(&TMeUrlTypeStickerSet)>(&self, fnc: F) -> &Self { if let TMeUrlType::StickerSet(t) = self { fnc(t) }; self }
   pub fn on_supergroup<F: FnOnce(&TMeUrlTypeSupergroup)>(&self, fnc: F) -> &Self { if let TMeUrlType::Supergroup(t) = self { fnc(t) }; self }
   pub fn on_user<F: FnOnce(&TMeUrlTypeUser)>(&self, fnc: F) -> &Self { if let TMeUrlType::User(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let TMeUrlType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_chat_invite(&self) -> Option<&TMeUrlTypeChatInvite> { if let TMeUrlType::ChatInvite(t) = self { return Some(t) } None }
-  pub fn as_sticker_set(&self) -> Option<&TMeUrlTypeStickerSet> { if let TMeUrlType::StickerSet(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_sticker_set(&self) -> Option<&TMeUrlTypeStickerSet> { if let TMeUrlType::StickerSet(t) = self { return Some(t) } None }
   pub fn as_supergroup(&self) -> Option<&TMeUrlTypeSupergroup> { if let TMeUrlType::Supergroup(t) = self { return Some(t) } None }
   pub fn as_user(&self) -> Option<&TMeUrlTypeUser> { if let TMeUrlType::User(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let TMeUrlType::Unknown(t) = self { return Some(t) } None }
 
 
 
   pub fn chat_invite<T: AsRef<TMeUrlTypeChatInvite>>(t: T) -> Self { TMeUrlType::ChatInvite(t.as_ref().clone()) }
 
-  pub fn sticker_set<T: AsRef<TMeUrlTypeStickerSet>>(t: T) -> Self { TMeUrlType::StickerSet(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn sticker_set<T: AsRef<TMeUrlTypeStickerSet>>(t: T) -> Self { TMeUrlType::StickerSet(t.as_ref().clone()) }
 
   pub fn supergroup<T: AsRef<TMeUrlTypeSupergroup>>(t: T) -> Self { TMeUrlType::Supergroup(t.as_ref().clone()) }
 
@@ -121,15 +139,19 @@ pub struct TMeUrlTypeChatInvite {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat invite link info
   info: ChatInviteLinkInfo,
   
 }
 
 impl RObject for TMeUrlTypeChatInvite {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeChatInvite" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "tMeUrlTypeChatInvite" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -139,14 +161,15 @@ impl TDTMeUrlType for TMeUrlTypeChatInvite {}
 
 
 impl TMeUrlTypeChatInvite {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTMeUrlTypeChatInviteBuilder {
-    let mut inner = TMeUrlTypeChatInvite::default();
-    inner.td_name = "tMeUrlTypeChatInvite".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TMeUrlTypeChatInvite { td_name: "tMeUrlTypeChatInvite".to_string(), ..Default::default() };
     RTDTMeUrlTypeChatInviteBuilder { inner }
   }
 
+  /// `TMeUrlTypeChatInvite` with the fields tdlib requires, the others are set by `TMeUrlTypeChatInvite::builder()`
+  pub fn new(info: impl AsRef<ChatInviteLinkInfo>) -> Self { Self::builder().info(info).build() }
+
   pub fn info(&self) -> &ChatInviteLinkInfo { &self.info }
 
 }
@@ -159,6 +182,12 @@ pub struct RTDTMeUrlTypeChatInviteBuilder {
 impl RTDTMeUrlTypeChatInviteBuilder {
   pub fn build(&self) -> TMeUrlTypeChatInvite { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn info<T: AsRef<ChatInviteLinkInfo>>(&mut self, info: T) -> &mut Self {
     self.inner.info = info.as_ref().clone();
@@ -175,12 +204,20 @@ impl AsRef<TMeUrlTypeChatInvite> for RTDTMeUrlTypeChatInviteBuilder {
   fn as_ref(&self) -> &TMeUrlTypeChatInvite { &self.inner }
 }
 
+impl PartialEq for TMeUrlTypeChatInvite {
+  fn eq(&self, other: &Self) -> bool {
+    self.info == other.info
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// A URL linking to a sticker set
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TMeUrlTypeStickerSet {
@@ -189,15 +226,19 @@ pub struct TMeUrlTypeStickerSet {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the sticker set
-  sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] sticker_set_id: i64,
   
 }
 
 impl RObject for TMeUrlTypeStickerSet {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeStickerSet" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "tMeUrlTypeStickerSet" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,15 +248,16 @@ impl TDTMeUrlType for TMeUrlTypeStickerSet {}
 
 
 impl TMeUrlTypeStickerSet {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTMeUrlTypeStickerSetBuilder {
-    let mut inner = TMeUrlTypeStickerSet::default();
-    inner.td_name = "tMeUrlTypeStickerSet".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TMeUrlTypeStickerSet { td_name: "tMeUrlTypeStickerSet".to_string(), ..Default::default() };
     RTDTMeUrlTypeStickerSetBuilder { inner }
   }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  /// `TMeUrlTypeStickerSet` with the fields tdlib requires, the others are set by `TMeUrlTypeStickerSet::builder()`
+  pub fn new(sticker_set_id: i64) -> Self { Self::builder().sticker_set_id(sticker_set_id).build() }
+
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
 }
 
@@ -227,8 +269,14 @@ pub struct RTDTMeUrlTypeStickerSetBuilder {
 impl RTDTMeUrlTypeStickerSetBuilder {
   pub fn build(&self) -> TMeUrlTypeStickerSet { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
     self
   }
@@ -243,6 +291,14 @@ impl AsRef<TMeUrlTypeStickerSet> for RTDTMeUrlTypeStickerSetBuilder {
   fn as_ref(&self) -> &TMeUrlTypeStickerSet { &self.inner }
 }
 
+impl PartialEq for TMeUrlTypeStickerSet {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker_set_id == other.sticker_set_id
+  }
+}
+
+}
+
 
 
 
@@ -257,15 +313,19 @@ pub struct TMeUrlTypeSupergroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the supergroup or channel
-  supergroup_id: i64,
+  supergroup_id: SupergroupId,
   
 }
 
 impl RObject for TMeUrlTypeSupergroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeSupergroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "tMeUrlTypeSupergroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -275,15 +335,16 @@ impl TDTMeUrlType for TMeUrlTypeSupergroup {}
 
 
 impl TMeUrlTypeSupergroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTMeUrlTypeSupergroupBuilder {
-    let mut inner = TMeUrlTypeSupergroup::default();
-    inner.td_name = "tMeUrlTypeSupergroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TMeUrlTypeSupergroup { td_name: "tMeUrlTypeSupergroup".to_string(), ..Default::default() };
     RTDTMeUrlTypeSupergroupBuilder { inner }
   }
 
-  pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
+  /// `TMeUrlTypeSupergroup` with the fields tdlib requires, the others are set by `TMeUrlTypeSupergroup::builder()`
+  pub fn new(supergroup_id: SupergroupId) -> Self { Self::builder().supergroup_id(supergroup_id).build() }
+
+  pub fn supergroup_id(&self) -> SupergroupId { self.supergroup_id }
 
 }
 
@@ -295,8 +356,14 @@ pub struct RTDTMeUrlTypeSupergroupBuilder {
 impl RTDTMeUrlTypeSupergroupBuilder {
   pub fn build(&self) -> TMeUrlTypeSupergroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
+  pub fn supergroup_id(&mut self, supergroup_id: SupergroupId) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
     self
   }
@@ -311,6 +378,12 @@ impl AsRef<TMeUrlTypeSupergroup> for RTDTMeUrlTypeSupergroupBuilder {
   fn as_ref(&self) -> &TMeUrlTypeSupergroup { &self.inner }
 }
 
+impl PartialEq for TMeUrlTypeSupergroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.supergroup_id == other.supergroup_id
+  }
+}
+
 
 
 
@@ -325,15 +398,19 @@ pub struct TMeUrlTypeUser {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the user
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for TMeUrlTypeUser {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrlTypeUser" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "tMeUrlTypeUser" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -343,15 +420,16 @@ impl TDTMeUrlType for TMeUrlTypeUser {}
 
 
 impl TMeUrlTypeUser {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTMeUrlTypeUserBuilder {
-    let mut inner = TMeUrlTypeUser::default();
-    inner.td_name = "tMeUrlTypeUser".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TMeUrlTypeUser { td_name: "tMeUrlTypeUser".to_string(), ..Default::default() };
     RTDTMeUrlTypeUserBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `TMeUrlTypeUser` with the fields tdlib requires, the others are set by `TMeUrlTypeUser::builder()`
+  pub fn new(user_id: UserId) -> Self { Self::builder().user_id(user_id).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -363,8 +441,14 @@ pub struct RTDTMeUrlTypeUserBuilder {
 impl RTDTMeUrlTypeUserBuilder {
   pub fn build(&self) -> TMeUrlTypeUser { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -379,5 +463,11 @@ impl AsRef<TMeUrlTypeUser> for RTDTMeUrlTypeUserBuilder {
   fn as_ref(&self) -> &TMeUrlTypeUser { &self.inner }
 }
 
+impl PartialEq for TMeUrlTypeUser {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+  }
+}
+
 
 
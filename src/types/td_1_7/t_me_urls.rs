@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct TMeUrls {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of URLs
   urls: Vec<TMeUrl>,
   
 }
 
 impl RObject for TMeUrls {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "tMeUrls" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "tMeUrls" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TMeUrls {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTMeUrlsBuilder {
-    let mut inner = TMeUrls::default();
-    inner.td_name = "tMeUrls".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TMeUrls { td_name: "tMeUrls".to_string(), ..Default::default() };
     RTDTMeUrlsBuilder { inner }
   }
 
+  /// `TMeUrls` with the fields tdlib requires, the others are set by `TMeUrls::builder()`
+  pub fn new(urls: Vec<TMeUrl>) -> Self { Self::builder().urls(urls).build() }
+
   pub fn urls(&self) -> &Vec<TMeUrl> { &self.urls }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDTMeUrlsBuilder {
 impl RTDTMeUrlsBuilder {
   pub fn build(&self) -> TMeUrls { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn urls(&mut self, urls: Vec<TMeUrl>) -> &mut Self {
     self.inner.urls = urls;
@@ -65,5 +75,11 @@ impl AsRef<TMeUrls> for RTDTMeUrlsBuilder {
   fn as_ref(&self) -> &TMeUrls { &self.inner }
 }
 
+impl PartialEq for TMeUrls {
+  fn eq(&self, other: &Self) -> bool {
+    self.urls == other.urls
+  }
+}
+
 
 
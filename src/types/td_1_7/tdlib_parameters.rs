@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct TdlibParameters {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// If set to true, the Telegram test environment will be used instead of the production environment
   use_test_dc: bool,
   /// The path to the directory for the persistent database; if empty, the current working directory will be used
@@ -49,22 +51,59 @@ pub struct TdlibParameters {
 }
 
 impl RObject for TdlibParameters {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "tdlibParameters" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "tdlibParameters" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TdlibParameters {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTdlibParametersBuilder {
-    let mut inner = TdlibParameters::default();
-    inner.td_name = "tdlibParameters".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TdlibParameters { td_name: "tdlibParameters".to_string(), ..Default::default() };
     RTDTdlibParametersBuilder { inner }
   }
 
+  /// `TdlibParameters` with the fields tdlib requires, the others are set by `TdlibParameters::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    use_test_dc: bool,
+    database_directory: impl AsRef<str>,
+    files_directory: impl AsRef<str>,
+    use_file_database: bool,
+    use_chat_info_database: bool,
+    use_message_database: bool,
+    use_secret_chats: bool,
+    api_id: i64,
+    api_hash: impl AsRef<str>,
+    system_language_code: impl AsRef<str>,
+    device_model: impl AsRef<str>,
+    system_version: impl AsRef<str>,
+    application_version: impl AsRef<str>,
+    enable_storage_optimizer: bool,
+    ignore_file_names: bool,
+  ) -> Self {
+    Self::builder()
+      .use_test_dc(use_test_dc)
+      .database_directory(database_directory)
+      .files_directory(files_directory)
+      .use_file_database(use_file_database)
+      .use_chat_info_database(use_chat_info_database)
+      .use_message_database(use_message_database)
+      .use_secret_chats(use_secret_chats)
+      .api_id(api_id)
+      .api_hash(api_hash)
+      .system_language_code(system_language_code)
+      .device_model(device_model)
+      .system_version(system_version)
+      .application_version(application_version)
+      .enable_storage_optimizer(enable_storage_optimizer)
+      .ignore_file_names(ignore_file_names)
+      .build()
+  }
+
   pub fn use_test_dc(&self) -> bool { self.use_test_dc }
 
   pub fn database_directory(&self) -> &String { &self.database_directory }
@@ -105,6 +144,12 @@ pub struct RTDTdlibParametersBuilder {
 impl RTDTdlibParametersBuilder {
   pub fn build(&self) -> TdlibParameters { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn use_test_dc(&mut self, use_test_dc: bool) -> &mut Self {
     self.inner.use_test_dc = use_test_dc;
@@ -205,5 +250,25 @@ impl AsRef<TdlibParameters> for RTDTdlibParametersBuilder {
   fn as_ref(&self) -> &TdlibParameters { &self.inner }
 }
 
+impl PartialEq for TdlibParameters {
+  fn eq(&self, other: &Self) -> bool {
+    self.use_test_dc == other.use_test_dc
+      && self.database_directory == other.database_directory
+      && self.files_directory == other.files_directory
+      && self.use_file_database == other.use_file_database
+      && self.use_chat_info_database == other.use_chat_info_database
+      && self.use_message_database == other.use_message_database
+      && self.use_secret_chats == other.use_secret_chats
+      && self.api_id == other.api_id
+      && self.api_hash == other.api_hash
+      && self.system_language_code == other.system_language_code
+      && self.device_model == other.device_model
+      && self.system_version == other.system_version
+      && self.application_version == other.application_version
+      && self.enable_storage_optimizer == other.enable_storage_optimizer
+      && self.ignore_file_names == other.ignore_file_names
+  }
+}
+
 
 
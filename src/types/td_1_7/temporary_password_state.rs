@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct TemporaryPasswordState {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if a temporary password is available
   has_password: bool,
   /// Time left before the temporary password expires, in seconds
@@ -23,22 +25,24 @@ pub struct TemporaryPasswordState {
 }
 
 impl RObject for TemporaryPasswordState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "temporaryPasswordState" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "temporaryPasswordState" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TemporaryPasswordState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTemporaryPasswordStateBuilder {
-    let mut inner = TemporaryPasswordState::default();
-    inner.td_name = "temporaryPasswordState".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TemporaryPasswordState { td_name: "temporaryPasswordState".to_string(), ..Default::default() };
     RTDTemporaryPasswordStateBuilder { inner }
   }
 
+  /// `TemporaryPasswordState` with the fields tdlib requires, the others are set by `TemporaryPasswordState::builder()`
+  pub fn new(has_password: bool, valid_for: i64) -> Self { Self::builder().has_password(has_password).valid_for(valid_for).build() }
+
   pub fn has_password(&self) -> bool { self.has_password }
 
   pub fn valid_for(&self) -> i64 { self.valid_for }
@@ -53,6 +57,12 @@ pub struct RTDTemporaryPasswordStateBuilder {
 impl RTDTemporaryPasswordStateBuilder {
   pub fn build(&self) -> TemporaryPasswordState { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn has_password(&mut self, has_password: bool) -> &mut Self {
     self.inner.has_password = has_password;
@@ -75,5 +85,12 @@ impl AsRef<TemporaryPasswordState> for RTDTemporaryPasswordStateBuilder {
   fn as_ref(&self) -> &TemporaryPasswordState { &self.inner }
 }
 
+impl PartialEq for TemporaryPasswordState {
+  fn eq(&self, other: &Self) -> bool {
+    self.has_password == other.has_password
+      && self.valid_for == other.valid_for
+  }
+}
+
 
 
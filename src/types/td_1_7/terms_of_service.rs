@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct TermsOfService {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text of the terms of service
   text: FormattedText,
   /// The minimum age of a user to be able to accept the terms; 0 if any
@@ -25,22 +27,24 @@ pub struct TermsOfService {
 }
 
 impl RObject for TermsOfService {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "termsOfService" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "termsOfService" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TermsOfService {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTermsOfServiceBuilder {
-    let mut inner = TermsOfService::default();
-    inner.td_name = "termsOfService".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TermsOfService { td_name: "termsOfService".to_string(), ..Default::default() };
     RTDTermsOfServiceBuilder { inner }
   }
 
+  /// `TermsOfService` with the fields tdlib requires, the others are set by `TermsOfService::builder()`
+  pub fn new(text: impl AsRef<FormattedText>, min_user_age: i64, show_popup: bool) -> Self { Self::builder().text(text).min_user_age(min_user_age).show_popup(show_popup).build() }
+
   pub fn text(&self) -> &FormattedText { &self.text }
 
   pub fn min_user_age(&self) -> i64 { self.min_user_age }
@@ -57,6 +61,12 @@ pub struct RTDTermsOfServiceBuilder {
 impl RTDTermsOfServiceBuilder {
   pub fn build(&self) -> TermsOfService { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -85,5 +95,13 @@ impl AsRef<TermsOfService> for RTDTermsOfServiceBuilder {
   fn as_ref(&self) -> &TermsOfService { &self.inner }
 }
 
+impl PartialEq for TermsOfService {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+      && self.min_user_age == other.min_user_age
+      && self.show_popup == other.show_popup
+  }
+}
+
 
 
@@ -0,0 +1,85 @@
+
+use crate::types::*;
+use crate::errors::*;
+
+
+
+
+/// A simple object containing a sequence of bytes; for testing only
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TestBytes {
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@type", deserialize = "@type"))]
+  td_name: String,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
+  /// Bytes
+  value: Bytes,
+  
+}
+
+impl RObject for TestBytes {
+  #[doc(hidden)] fn td_name(&self) -> &str { "testBytes" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
+  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
+}
+
+
+
+impl TestBytes {
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  pub fn builder() -> RTDTestBytesBuilder {
+    let inner = TestBytes { td_name: "testBytes".to_string(), ..Default::default() };
+    RTDTestBytesBuilder { inner }
+  }
+
+  /// `TestBytes` with the fields tdlib requires, the others are set by `TestBytes::builder()`
+  pub fn new(value: impl AsRef<Bytes>) -> Self { Self::builder().value(value).build() }
+
+  pub fn value(&self) -> &Bytes { &self.value }
+
+}
+
+#[doc(hidden)]
+pub struct RTDTestBytesBuilder {
+  inner: TestBytes
+}
+
+impl RTDTestBytesBuilder {
+  pub fn build(&self) -> TestBytes { self.inner.clone() }
+
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
+   
+  pub fn value<T: AsRef<Bytes>>(&mut self, value: T) -> &mut Self {
+    self.inner.value = value.as_ref().clone();
+    self
+  }
+
+}
+
+impl AsRef<TestBytes> for TestBytes {
+  fn as_ref(&self) -> &TestBytes { self }
+}
+
+impl AsRef<TestBytes> for RTDTestBytesBuilder {
+  fn as_ref(&self) -> &TestBytes { &self.inner }
+}
+
+impl PartialEq for TestBytes {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
+
+
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct TestInt {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Number
   value: i64,
   
 }
 
 impl RObject for TestInt {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "testInt" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "testInt" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TestInt {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTestIntBuilder {
-    let mut inner = TestInt::default();
-    inner.td_name = "testInt".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TestInt { td_name: "testInt".to_string(), ..Default::default() };
     RTDTestIntBuilder { inner }
   }
 
+  /// `TestInt` with the fields tdlib requires, the others are set by `TestInt::builder()`
+  pub fn new(value: i64) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> i64 { self.value }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDTestIntBuilder {
 impl RTDTestIntBuilder {
   pub fn build(&self) -> TestInt { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: i64) -> &mut Self {
     self.inner.value = value;
@@ -65,5 +75,11 @@ impl AsRef<TestInt> for RTDTestIntBuilder {
   fn as_ref(&self) -> &TestInt { &self.inner }
 }
 
+impl PartialEq for TestInt {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct TestString {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// String
   value: String,
   
 }
 
 impl RObject for TestString {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "testString" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "testString" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TestString {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTestStringBuilder {
-    let mut inner = TestString::default();
-    inner.td_name = "testString".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TestString { td_name: "testString".to_string(), ..Default::default() };
     RTDTestStringBuilder { inner }
   }
 
+  /// `TestString` with the fields tdlib requires, the others are set by `TestString::builder()`
+  pub fn new(value: impl AsRef<str>) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> &String { &self.value }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDTestStringBuilder {
 impl RTDTestStringBuilder {
   pub fn build(&self) -> TestString { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
@@ -65,5 +75,11 @@ impl AsRef<TestString> for RTDTestStringBuilder {
   fn as_ref(&self) -> &TestString { &self.inner }
 }
 
+impl PartialEq for TestString {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct TestVectorString {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Vector of strings
   value: Vec<String>,
   
 }
 
 impl RObject for TestVectorString {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "testVectorString" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "testVectorString" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TestVectorString {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTestVectorStringBuilder {
-    let mut inner = TestVectorString::default();
-    inner.td_name = "testVectorString".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TestVectorString { td_name: "testVectorString".to_string(), ..Default::default() };
     RTDTestVectorStringBuilder { inner }
   }
 
+  /// `TestVectorString` with the fields tdlib requires, the others are set by `TestVectorString::builder()`
+  pub fn new(value: Vec<String>) -> Self { Self::builder().value(value).build() }
+
   pub fn value(&self) -> &Vec<String> { &self.value }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDTestVectorStringBuilder {
 impl RTDTestVectorStringBuilder {
   pub fn build(&self) -> TestVectorString { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn value(&mut self, value: Vec<String>) -> &mut Self {
     self.inner.value = value;
@@ -65,5 +75,11 @@ impl AsRef<TestVectorString> for RTDTestVectorStringBuilder {
   fn as_ref(&self) -> &TestVectorString { &self.inner }
 }
 
+impl PartialEq for TestVectorString {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
 
 
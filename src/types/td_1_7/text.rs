@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Text {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Text
   text: String,
   
 }
 
 impl RObject for Text {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "text" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "text" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Text {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextBuilder {
-    let mut inner = Text::default();
-    inner.td_name = "text".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Text { td_name: "text".to_string(), ..Default::default() };
     RTDTextBuilder { inner }
   }
 
+  /// `Text` with the fields tdlib requires, the others are set by `Text::builder()`
+  pub fn new(text: impl AsRef<str>) -> Self { Self::builder().text(text).build() }
+
   pub fn text(&self) -> &String { &self.text }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDTextBuilder {
 impl RTDTextBuilder {
   pub fn build(&self) -> Text { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
@@ -65,5 +75,11 @@ impl AsRef<Text> for RTDTextBuilder {
   fn as_ref(&self) -> &Text { &self.inner }
 }
 
+impl PartialEq for Text {
+  fn eq(&self, other: &Self) -> bool {
+    self.text == other.text
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct TextEntities {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of text entities
   entities: Vec<TextEntity>,
   
 }
 
 impl RObject for TextEntities {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntities" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntities" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TextEntities {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntitiesBuilder {
-    let mut inner = TextEntities::default();
-    inner.td_name = "textEntities".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntities { td_name: "textEntities".to_string(), ..Default::default() };
     RTDTextEntitiesBuilder { inner }
   }
 
+  /// `TextEntities` with the fields tdlib requires, the others are set by `TextEntities::builder()`
+  pub fn new(entities: Vec<TextEntity>) -> Self { Self::builder().entities(entities).build() }
+
   pub fn entities(&self) -> &Vec<TextEntity> { &self.entities }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDTextEntitiesBuilder {
 impl RTDTextEntitiesBuilder {
   pub fn build(&self) -> TextEntities { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn entities(&mut self, entities: Vec<TextEntity>) -> &mut Self {
     self.inner.entities = entities;
@@ -65,5 +75,11 @@ impl AsRef<TextEntities> for RTDTextEntitiesBuilder {
   fn as_ref(&self) -> &TextEntities { &self.inner }
 }
 
+impl PartialEq for TextEntities {
+  fn eq(&self, other: &Self) -> bool {
+    self.entities == other.entities
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct TextEntity {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Offset of the entity, in UTF-16 code units
   offset: i64,
   /// Length of the entity, in UTF-16 code units
@@ -25,22 +27,24 @@ pub struct TextEntity {
 }
 
 impl RObject for TextEntity {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntity" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntity" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl TextEntity {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityBuilder {
-    let mut inner = TextEntity::default();
-    inner.td_name = "textEntity".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntity { td_name: "textEntity".to_string(), ..Default::default() };
     RTDTextEntityBuilder { inner }
   }
 
+  /// `TextEntity` with the fields tdlib requires, the others are set by `TextEntity::builder()`
+  pub fn new(offset: i64, length: i64, type_: impl AsRef<TextEntityType>) -> Self { Self::builder().offset(offset).length(length).type_(type_).build() }
+
   pub fn offset(&self) -> i64 { self.offset }
 
   pub fn length(&self) -> i64 { self.length }
@@ -57,6 +61,12 @@ pub struct RTDTextEntityBuilder {
 impl RTDTextEntityBuilder {
   pub fn build(&self) -> TextEntity { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
@@ -85,5 +95,13 @@ impl AsRef<TextEntity> for RTDTextEntityBuilder {
   fn as_ref(&self) -> &TextEntity { &self.inner }
 }
 
+impl PartialEq for TextEntity {
+  fn eq(&self, other: &Self) -> bool {
+    self.offset == other.offset
+      && self.length == other.length
+      && self.type_ == other.type_
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDTextEntityType: Debug + RObject {}
 
 /// Represents a part of the text which must be formatted differently
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum TextEntityType {
   #[doc(hidden)] _Default(()),
@@ -55,6 +54,8 @@ pub enum TextEntityType {
   Underline(TextEntityTypeUnderline),
   /// An HTTP URL
   Url(TextEntityTypeUrl),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -66,7 +67,7 @@ impl<'de> Deserialize<'de> for TextEntityType {
   fn deserialize<D>(deserializer: D) -> Result<TextEntityType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      TextEntityType,
+      TextEntityType, Unknown;
       (textEntityTypeBankCardNumber, BankCardNumber);
       (textEntityTypeBold, Bold);
       (textEntityTypeBotCommand, BotCommand);
@@ -91,7 +92,7 @@ impl<'de> Deserialize<'de> for TextEntityType {
 }
 
 impl RObject for TextEntityType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       TextEntityType::BankCardNumber(t) => t.td_name(),
       TextEntityType::Bold(t) => t.td_name(),
@@ -111,11 +112,12 @@ impl RObject for TextEntityType {
       TextEntityType::TextUrl(t) => t.td_name(),
       TextEntityType::Underline(t) => t.td_name(),
       TextEntityType::Url(t) => t.td_name(),
+      TextEntityType::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       TextEntityType::BankCardNumber(t) => t.extra(),
       TextEntityType::Bold(t) => t.extra(),
@@ -135,6 +137,32 @@ impl RObject for TextEntityType {
       TextEntityType::TextUrl(t) => t.extra(),
       TextEntityType::Underline(t) => t.extra(),
       TextEntityType::Url(t) => t.extra(),
+      TextEntityType::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      TextEntityType::BankCardNumber(t) => t.client_id(),
+      TextEntityType::Bold(t) => t.client_id(),
+      TextEntityType::BotCommand(t) => t.client_id(),
+      TextEntityType::Cashtag(t) => t.client_id(),
+      TextEntityType::Code(t) => t.client_id(),
+      TextEntityType::EmailAddress(t) => t.client_id(),
+      TextEntityType::Hashtag(t) => t.client_id(),
+      TextEntityType::Italic(t) => t.client_id(),
+      TextEntityType::MediaTimestamp(t) => t.client_id(),
+      TextEntityType::Mention(t) => t.client_id(),
+      TextEntityType::MentionName(t) => t.client_id(),
+      TextEntityType::PhoneNumber(t) => t.client_id(),
+      TextEntityType::Pre(t) => t.client_id(),
+      TextEntityType::PreCode(t) => t.client_id(),
+      TextEntityType::Strikethrough(t) => t.client_id(),
+      TextEntityType::TextUrl(t) => t.client_id(),
+      TextEntityType::Underline(t) => t.client_id(),
+      TextEntityType::Url(t) => t.client_id(),
+      TextEntityType::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -143,27 +171,28 @@ impl RObject for TextEntityType {
 }
 
 impl TextEntityType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TextEntityType::_Default(_) = self { true } else { false } }
-
-  pub fn is_bank_card_number(&self) -> bool { if let TextEntityType::BankCardNumber(_) = self { true } else { false } }
-  pub fn is_bold(&self) -> bool { if let TextEntityType::Bold(_) = self { true } else { false } }
-  pub fn is_bot_command(&self) -> bool { if let TextEntityType::BotCommand(_) = self { true } else { false } }
-  pub fn is_cashtag(&self) -> bool { if let TextEntityType::Cashtag(_) = self { true } else { false } }
-  pub fn is_code(&self) -> bool { if let TextEntityType::Code(_) = self { true } else { false } }
-  pub fn is_email_address(&self) -> bool { if let TextEntityType::EmailAddress(_) = self { true } else { false } }
-  pub fn is_hashtag(&self) -> bool { if let TextEntityType::Hashtag(_) = self { true } else { false } }
-  pub fn is_italic(&self) -> bool { if let TextEntityType::Italic(_) = self { true } else { false } }
-  pub fn is_media_timestamp(&self) -> bool { if let TextEntityType::MediaTimestamp(_) = self { true } else { false } }
-  pub fn is_mention(&self) -> bool { if let TextEntityType::Mention(_) = self { true } else { false } }
-  pub fn is_mention_name(&self) -> bool { if let TextEntityType::MentionName(_) = self { true } else { false } }
-  pub fn is_phone_number(&self) -> bool { if let TextEntityType::PhoneNumber(_) = self { true } else { false } }
-  pub fn is_pre(&self) -> bool { if let TextEntityType::Pre(_) = self { true } else { false } }
-  pub fn is_pre_code(&self) -> bool { if let TextEntityType::PreCode(_) = self { true } else { false } }
-  pub fn is_strikethrough(&self) -> bool { if let TextEntityType::Strikethrough(_) = self { true } else { false } }
-  pub fn is_text_url(&self) -> bool { if let TextEntityType::TextUrl(_) = self { true } else { false } }
-  pub fn is_underline(&self) -> bool { if let TextEntityType::Underline(_) = self { true } else { false } }
-  pub fn is_url(&self) -> bool { if let TextEntityType::Url(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, TextEntityType::_Default(_)) }
+
+  pub fn is_bank_card_number(&self) -> bool { matches!(self, TextEntityType::BankCardNumber(_)) }
+  pub fn is_bold(&self) -> bool { matches!(self, TextEntityType::Bold(_)) }
+  pub fn is_bot_command(&self) -> bool { matches!(self, TextEntityType::BotCommand(_)) }
+  pub fn is_cashtag(&self) -> bool { matches!(self, TextEntityType::Cashtag(_)) }
+  pub fn is_code(&self) -> bool { matches!(self, TextEntityType::Code(_)) }
+  pub fn is_email_address(&self) -> bool { matches!(self, TextEntityType::EmailAddress(_)) }
+  pub fn is_hashtag(&self) -> bool { matches!(self, TextEntityType::Hashtag(_)) }
+  pub fn is_italic(&self) -> bool { matches!(self, TextEntityType::Italic(_)) }
+  pub fn is_media_timestamp(&self) -> bool { matches!(self, TextEntityType::MediaTimestamp(_)) }
+  pub fn is_mention(&self) -> bool { matches!(self, TextEntityType::Mention(_)) }
+  pub fn is_mention_name(&self) -> bool { matches!(self, TextEntityType::MentionName(_)) }
+  pub fn is_phone_number(&self) -> bool { matches!(self, TextEntityType::PhoneNumber(_)) }
+  pub fn is_pre(&self) -> bool { matches!(self, TextEntityType::Pre(_)) }
+  pub fn is_pre_code(&self) -> bool { matches!(self, TextEntityType::PreCode(_)) }
+  pub fn is_strikethrough(&self) -> bool { matches!(self, TextEntityType::Strikethrough(_)) }
+  pub fn is_text_url(&self) -> bool { matches!(self, TextEntityType::TextUrl(_)) }
+  pub fn is_underline(&self) -> bool { matches!(self, TextEntityType::Underline(_)) }
+  pub fn is_url(&self) -> bool { matches!(self, TextEntityType::Url(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, TextEntityType::Unknown(_)) }
 
   pub fn on_bank_card_number<F: FnOnce(&TextEntityTypeBankCardNumber)>(&self, fnc: F) -> &Self { if let TextEntityType::BankCardNumber(t) = self { fnc(t) }; self }
   pub fn on_bold<F: FnOnce(&TextEntityTypeBold)>(&self, fnc: F) -> &Self { if let TextEntityType::Bold(t) = self { fnc(t) }; self }
@@ -183,6 +212,7 @@ impl TextEntityType {
   pub fn on_text_url<F: FnOnce(&TextEntityTypeTextUrl)>(&self, fnc: F) -> &Self { if let TextEntityType::TextUrl(t) = self { fnc(t) }; self }
   pub fn on_underline<F: FnOnce(&TextEntityTypeUnderline)>(&self, fnc: F) -> &Self { if let TextEntityType::Underline(t) = self { fnc(t) }; self }
   pub fn on_url<F: FnOnce(&TextEntityTypeUrl)>(&self, fnc: F) -> &Self { if let TextEntityType::Url(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let TextEntityType::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_bank_card_number(&self) -> Option<&TextEntityTypeBankCardNumber> { if let TextEntityType::BankCardNumber(t) = self { return Some(t) } None }
   pub fn as_bold(&self) -> Option<&TextEntityTypeBold> { if let TextEntityType::Bold(t) = self { return Some(t) } None }
@@ -202,6 +232,7 @@ impl TextEntityType {
   pub fn as_text_url(&self) -> Option<&TextEntityTypeTextUrl> { if let TextEntityType::TextUrl(t) = self { return Some(t) } None }
   pub fn as_underline(&self) -> Option<&TextEntityTypeUnderline> { if let TextEntityType::Underline(t) = self { return Some(t) } None }
   pub fn as_url(&self) -> Option<&TextEntityTypeUrl> { if let TextEntityType::Url(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let TextEntityType::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -261,13 +292,17 @@ pub struct TextEntityTypeBankCardNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeBankCardNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBankCardNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeBankCardNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -277,14 +312,15 @@ impl TDTextEntityType for TextEntityTypeBankCardNumber {}
 
 
 impl TextEntityTypeBankCardNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeBankCardNumberBuilder {
-    let mut inner = TextEntityTypeBankCardNumber::default();
-    inner.td_name = "textEntityTypeBankCardNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeBankCardNumber { td_name: "textEntityTypeBankCardNumber".to_string(), ..Default::default() };
     RTDTextEntityTypeBankCardNumberBuilder { inner }
   }
 
+  /// `TextEntityTypeBankCardNumber` with the fields tdlib requires, the others are set by `TextEntityTypeBankCardNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -295,6 +331,12 @@ pub struct RTDTextEntityTypeBankCardNumberBuilder {
 impl RTDTextEntityTypeBankCardNumberBuilder {
   pub fn build(&self) -> TextEntityTypeBankCardNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeBankCardNumber> for TextEntityTypeBankCardNumber {
@@ -305,6 +347,10 @@ impl AsRef<TextEntityTypeBankCardNumber> for RTDTextEntityTypeBankCardNumberBuil
   fn as_ref(&self) -> &TextEntityTypeBankCardNumber { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeBankCardNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -319,13 +365,17 @@ pub struct TextEntityTypeBold {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeBold {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBold" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeBold" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -335,14 +385,15 @@ impl TDTextEntityType for TextEntityTypeBold {}
 
 
 impl TextEntityTypeBold {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeBoldBuilder {
-    let mut inner = TextEntityTypeBold::default();
-    inner.td_name = "textEntityTypeBold".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeBold { td_name: "textEntityTypeBold".to_string(), ..Default::default() };
     RTDTextEntityTypeBoldBuilder { inner }
   }
 
+  /// `TextEntityTypeBold` with the fields tdlib requires, the others are set by `TextEntityTypeBold::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -353,6 +404,12 @@ pub struct RTDTextEntityTypeBoldBuilder {
 impl RTDTextEntityTypeBoldBuilder {
   pub fn build(&self) -> TextEntityTypeBold { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeBold> for TextEntityTypeBold {
@@ -363,6 +420,10 @@ impl AsRef<TextEntityTypeBold> for RTDTextEntityTypeBoldBuilder {
   fn as_ref(&self) -> &TextEntityTypeBold { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeBold {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -377,13 +438,17 @@ pub struct TextEntityTypeBotCommand {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeBotCommand {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeBotCommand" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeBotCommand" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -393,14 +458,15 @@ impl TDTextEntityType for TextEntityTypeBotCommand {}
 
 
 impl TextEntityTypeBotCommand {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeBotCommandBuilder {
-    let mut inner = TextEntityTypeBotCommand::default();
-    inner.td_name = "textEntityTypeBotCommand".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeBotCommand { td_name: "textEntityTypeBotCommand".to_string(), ..Default::default() };
     RTDTextEntityTypeBotCommandBuilder { inner }
   }
 
+  /// `TextEntityTypeBotCommand` with the fields tdlib requires, the others are set by `TextEntityTypeBotCommand::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -411,6 +477,12 @@ pub struct RTDTextEntityTypeBotCommandBuilder {
 impl RTDTextEntityTypeBotCommandBuilder {
   pub fn build(&self) -> TextEntityTypeBotCommand { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeBotCommand> for TextEntityTypeBotCommand {
@@ -421,6 +493,10 @@ impl AsRef<TextEntityTypeBotCommand> for RTDTextEntityTypeBotCommandBuilder {
   fn as_ref(&self) -> &TextEntityTypeBotCommand { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeBotCommand {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -435,13 +511,17 @@ pub struct TextEntityTypeCashtag {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeCashtag {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeCashtag" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeCashtag" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -451,14 +531,15 @@ impl TDTextEntityType for TextEntityTypeCashtag {}
 
 
 impl TextEntityTypeCashtag {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeCashtagBuilder {
-    let mut inner = TextEntityTypeCashtag::default();
-    inner.td_name = "textEntityTypeCashtag".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeCashtag { td_name: "textEntityTypeCashtag".to_string(), ..Default::default() };
     RTDTextEntityTypeCashtagBuilder { inner }
   }
 
+  /// `TextEntityTypeCashtag` with the fields tdlib requires, the others are set by `TextEntityTypeCashtag::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -469,6 +550,12 @@ pub struct RTDTextEntityTypeCashtagBuilder {
 impl RTDTextEntityTypeCashtagBuilder {
   pub fn build(&self) -> TextEntityTypeCashtag { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeCashtag> for TextEntityTypeCashtag {
@@ -479,6 +566,10 @@ impl AsRef<TextEntityTypeCashtag> for RTDTextEntityTypeCashtagBuilder {
   fn as_ref(&self) -> &TextEntityTypeCashtag { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeCashtag {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -493,13 +584,17 @@ pub struct TextEntityTypeCode {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeCode {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeCode" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeCode" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -509,14 +604,15 @@ impl TDTextEntityType for TextEntityTypeCode {}
 
 
 impl TextEntityTypeCode {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeCodeBuilder {
-    let mut inner = TextEntityTypeCode::default();
-    inner.td_name = "textEntityTypeCode".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeCode { td_name: "textEntityTypeCode".to_string(), ..Default::default() };
     RTDTextEntityTypeCodeBuilder { inner }
   }
 
+  /// `TextEntityTypeCode` with the fields tdlib requires, the others are set by `TextEntityTypeCode::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -527,6 +623,12 @@ pub struct RTDTextEntityTypeCodeBuilder {
 impl RTDTextEntityTypeCodeBuilder {
   pub fn build(&self) -> TextEntityTypeCode { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeCode> for TextEntityTypeCode {
@@ -537,6 +639,10 @@ impl AsRef<TextEntityTypeCode> for RTDTextEntityTypeCodeBuilder {
   fn as_ref(&self) -> &TextEntityTypeCode { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeCode {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -551,13 +657,17 @@ pub struct TextEntityTypeEmailAddress {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeEmailAddress {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeEmailAddress" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeEmailAddress" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -567,14 +677,15 @@ impl TDTextEntityType for TextEntityTypeEmailAddress {}
 
 
 impl TextEntityTypeEmailAddress {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeEmailAddressBuilder {
-    let mut inner = TextEntityTypeEmailAddress::default();
-    inner.td_name = "textEntityTypeEmailAddress".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeEmailAddress { td_name: "textEntityTypeEmailAddress".to_string(), ..Default::default() };
     RTDTextEntityTypeEmailAddressBuilder { inner }
   }
 
+  /// `TextEntityTypeEmailAddress` with the fields tdlib requires, the others are set by `TextEntityTypeEmailAddress::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -585,6 +696,12 @@ pub struct RTDTextEntityTypeEmailAddressBuilder {
 impl RTDTextEntityTypeEmailAddressBuilder {
   pub fn build(&self) -> TextEntityTypeEmailAddress { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeEmailAddress> for TextEntityTypeEmailAddress {
@@ -595,6 +712,10 @@ impl AsRef<TextEntityTypeEmailAddress> for RTDTextEntityTypeEmailAddressBuilder
   fn as_ref(&self) -> &TextEntityTypeEmailAddress { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeEmailAddress {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -609,13 +730,17 @@ pub struct TextEntityTypeHashtag {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeHashtag {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeHashtag" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeHashtag" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -625,14 +750,15 @@ impl TDTextEntityType for TextEntityTypeHashtag {}
 
 
 impl TextEntityTypeHashtag {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeHashtagBuilder {
-    let mut inner = TextEntityTypeHashtag::default();
-    inner.td_name = "textEntityTypeHashtag".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeHashtag { td_name: "textEntityTypeHashtag".to_string(), ..Default::default() };
     RTDTextEntityTypeHashtagBuilder { inner }
   }
 
+  /// `TextEntityTypeHashtag` with the fields tdlib requires, the others are set by `TextEntityTypeHashtag::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -643,6 +769,12 @@ pub struct RTDTextEntityTypeHashtagBuilder {
 impl RTDTextEntityTypeHashtagBuilder {
   pub fn build(&self) -> TextEntityTypeHashtag { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeHashtag> for TextEntityTypeHashtag {
@@ -653,6 +785,10 @@ impl AsRef<TextEntityTypeHashtag> for RTDTextEntityTypeHashtagBuilder {
   fn as_ref(&self) -> &TextEntityTypeHashtag { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeHashtag {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -667,13 +803,17 @@ pub struct TextEntityTypeItalic {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeItalic {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeItalic" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeItalic" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -683,14 +823,15 @@ impl TDTextEntityType for TextEntityTypeItalic {}
 
 
 impl TextEntityTypeItalic {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeItalicBuilder {
-    let mut inner = TextEntityTypeItalic::default();
-    inner.td_name = "textEntityTypeItalic".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeItalic { td_name: "textEntityTypeItalic".to_string(), ..Default::default() };
     RTDTextEntityTypeItalicBuilder { inner }
   }
 
+  /// `TextEntityTypeItalic` with the fields tdlib requires, the others are set by `TextEntityTypeItalic::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -701,6 +842,12 @@ pub struct RTDTextEntityTypeItalicBuilder {
 impl RTDTextEntityTypeItalicBuilder {
   pub fn build(&self) -> TextEntityTypeItalic { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeItalic> for TextEntityTypeItalic {
@@ -711,6 +858,10 @@ impl AsRef<TextEntityTypeItalic> for RTDTextEntityTypeItalicBuilder {
   fn as_ref(&self) -> &TextEntityTypeItalic { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeItalic {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -725,15 +876,19 @@ pub struct TextEntityTypeMediaTimestamp {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Timestamp from which a video/audio/video note/voice note playing must start, in seconds. The media can be in the content or the web page preview of the current message, or in the same places in the replied message
   media_timestamp: i64,
   
 }
 
 impl RObject for TextEntityTypeMediaTimestamp {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeMediaTimestamp" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeMediaTimestamp" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -743,14 +898,15 @@ impl TDTextEntityType for TextEntityTypeMediaTimestamp {}
 
 
 impl TextEntityTypeMediaTimestamp {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeMediaTimestampBuilder {
-    let mut inner = TextEntityTypeMediaTimestamp::default();
-    inner.td_name = "textEntityTypeMediaTimestamp".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeMediaTimestamp { td_name: "textEntityTypeMediaTimestamp".to_string(), ..Default::default() };
     RTDTextEntityTypeMediaTimestampBuilder { inner }
   }
 
+  /// `TextEntityTypeMediaTimestamp` with the fields tdlib requires, the others are set by `TextEntityTypeMediaTimestamp::builder()`
+  pub fn new(media_timestamp: i64) -> Self { Self::builder().media_timestamp(media_timestamp).build() }
+
   pub fn media_timestamp(&self) -> i64 { self.media_timestamp }
 
 }
@@ -763,6 +919,12 @@ pub struct RTDTextEntityTypeMediaTimestampBuilder {
 impl RTDTextEntityTypeMediaTimestampBuilder {
   pub fn build(&self) -> TextEntityTypeMediaTimestamp { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn media_timestamp(&mut self, media_timestamp: i64) -> &mut Self {
     self.inner.media_timestamp = media_timestamp;
@@ -779,6 +941,12 @@ impl AsRef<TextEntityTypeMediaTimestamp> for RTDTextEntityTypeMediaTimestampBuil
   fn as_ref(&self) -> &TextEntityTypeMediaTimestamp { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeMediaTimestamp {
+  fn eq(&self, other: &Self) -> bool {
+    self.media_timestamp == other.media_timestamp
+  }
+}
+
 
 
 
@@ -793,13 +961,17 @@ pub struct TextEntityTypeMention {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeMention {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeMention" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeMention" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -809,14 +981,15 @@ impl TDTextEntityType for TextEntityTypeMention {}
 
 
 impl TextEntityTypeMention {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeMentionBuilder {
-    let mut inner = TextEntityTypeMention::default();
-    inner.td_name = "textEntityTypeMention".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeMention { td_name: "textEntityTypeMention".to_string(), ..Default::default() };
     RTDTextEntityTypeMentionBuilder { inner }
   }
 
+  /// `TextEntityTypeMention` with the fields tdlib requires, the others are set by `TextEntityTypeMention::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -827,6 +1000,12 @@ pub struct RTDTextEntityTypeMentionBuilder {
 impl RTDTextEntityTypeMentionBuilder {
   pub fn build(&self) -> TextEntityTypeMention { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeMention> for TextEntityTypeMention {
@@ -837,6 +1016,10 @@ impl AsRef<TextEntityTypeMention> for RTDTextEntityTypeMentionBuilder {
   fn as_ref(&self) -> &TextEntityTypeMention { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeMention {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -851,15 +1034,19 @@ pub struct TextEntityTypeMentionName {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the mentioned user
-  user_id: i64,
+  user_id: UserId,
   
 }
 
 impl RObject for TextEntityTypeMentionName {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeMentionName" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeMentionName" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -869,15 +1056,16 @@ impl TDTextEntityType for TextEntityTypeMentionName {}
 
 
 impl TextEntityTypeMentionName {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeMentionNameBuilder {
-    let mut inner = TextEntityTypeMentionName::default();
-    inner.td_name = "textEntityTypeMentionName".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeMentionName { td_name: "textEntityTypeMentionName".to_string(), ..Default::default() };
     RTDTextEntityTypeMentionNameBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `TextEntityTypeMentionName` with the fields tdlib requires, the others are set by `TextEntityTypeMentionName::builder()`
+  pub fn new(user_id: UserId) -> Self { Self::builder().user_id(user_id).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
 }
 
@@ -889,8 +1077,14 @@ pub struct RTDTextEntityTypeMentionNameBuilder {
 impl RTDTextEntityTypeMentionNameBuilder {
   pub fn build(&self) -> TextEntityTypeMentionName { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -905,6 +1099,12 @@ impl AsRef<TextEntityTypeMentionName> for RTDTextEntityTypeMentionNameBuilder {
   fn as_ref(&self) -> &TextEntityTypeMentionName { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeMentionName {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+  }
+}
+
 
 
 
@@ -919,13 +1119,17 @@ pub struct TextEntityTypePhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypePhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypePhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypePhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -935,14 +1139,15 @@ impl TDTextEntityType for TextEntityTypePhoneNumber {}
 
 
 impl TextEntityTypePhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypePhoneNumberBuilder {
-    let mut inner = TextEntityTypePhoneNumber::default();
-    inner.td_name = "textEntityTypePhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypePhoneNumber { td_name: "textEntityTypePhoneNumber".to_string(), ..Default::default() };
     RTDTextEntityTypePhoneNumberBuilder { inner }
   }
 
+  /// `TextEntityTypePhoneNumber` with the fields tdlib requires, the others are set by `TextEntityTypePhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -953,6 +1158,12 @@ pub struct RTDTextEntityTypePhoneNumberBuilder {
 impl RTDTextEntityTypePhoneNumberBuilder {
   pub fn build(&self) -> TextEntityTypePhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypePhoneNumber> for TextEntityTypePhoneNumber {
@@ -963,6 +1174,10 @@ impl AsRef<TextEntityTypePhoneNumber> for RTDTextEntityTypePhoneNumberBuilder {
   fn as_ref(&self) -> &TextEntityTypePhoneNumber { &self.inner }
 }
 
+impl PartialEq for TextEntityTypePhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -977,13 +1192,17 @@ pub struct TextEntityTypePre {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypePre {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypePre" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypePre" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -993,14 +1212,15 @@ impl TDTextEntityType for TextEntityTypePre {}
 
 
 impl TextEntityTypePre {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypePreBuilder {
-    let mut inner = TextEntityTypePre::default();
-    inner.td_name = "textEntityTypePre".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypePre { td_name: "textEntityTypePre".to_string(), ..Default::default() };
     RTDTextEntityTypePreBuilder { inner }
   }
 
+  /// `TextEntityTypePre` with the fields tdlib requires, the others are set by `TextEntityTypePre::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1011,6 +1231,12 @@ pub struct RTDTextEntityTypePreBuilder {
 impl RTDTextEntityTypePreBuilder {
   pub fn build(&self) -> TextEntityTypePre { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypePre> for TextEntityTypePre {
@@ -1021,6 +1247,10 @@ impl AsRef<TextEntityTypePre> for RTDTextEntityTypePreBuilder {
   fn as_ref(&self) -> &TextEntityTypePre { &self.inner }
 }
 
+impl PartialEq for TextEntityTypePre {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1035,15 +1265,19 @@ pub struct TextEntityTypePreCode {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Programming language of the code; as defined by the sender
   language: String,
   
 }
 
 impl RObject for TextEntityTypePreCode {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypePreCode" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypePreCode" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1053,14 +1287,15 @@ impl TDTextEntityType for TextEntityTypePreCode {}
 
 
 impl TextEntityTypePreCode {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypePreCodeBuilder {
-    let mut inner = TextEntityTypePreCode::default();
-    inner.td_name = "textEntityTypePreCode".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypePreCode { td_name: "textEntityTypePreCode".to_string(), ..Default::default() };
     RTDTextEntityTypePreCodeBuilder { inner }
   }
 
+  /// `TextEntityTypePreCode` with the fields tdlib requires, the others are set by `TextEntityTypePreCode::builder()`
+  pub fn new(language: impl AsRef<str>) -> Self { Self::builder().language(language).build() }
+
   pub fn language(&self) -> &String { &self.language }
 
 }
@@ -1073,6 +1308,12 @@ pub struct RTDTextEntityTypePreCodeBuilder {
 impl RTDTextEntityTypePreCodeBuilder {
   pub fn build(&self) -> TextEntityTypePreCode { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn language<T: AsRef<str>>(&mut self, language: T) -> &mut Self {
     self.inner.language = language.as_ref().to_string();
@@ -1089,6 +1330,12 @@ impl AsRef<TextEntityTypePreCode> for RTDTextEntityTypePreCodeBuilder {
   fn as_ref(&self) -> &TextEntityTypePreCode { &self.inner }
 }
 
+impl PartialEq for TextEntityTypePreCode {
+  fn eq(&self, other: &Self) -> bool {
+    self.language == other.language
+  }
+}
+
 
 
 
@@ -1103,13 +1350,17 @@ pub struct TextEntityTypeStrikethrough {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeStrikethrough {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeStrikethrough" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeStrikethrough" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1119,14 +1370,15 @@ impl TDTextEntityType for TextEntityTypeStrikethrough {}
 
 
 impl TextEntityTypeStrikethrough {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeStrikethroughBuilder {
-    let mut inner = TextEntityTypeStrikethrough::default();
-    inner.td_name = "textEntityTypeStrikethrough".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeStrikethrough { td_name: "textEntityTypeStrikethrough".to_string(), ..Default::default() };
     RTDTextEntityTypeStrikethroughBuilder { inner }
   }
 
+  /// `TextEntityTypeStrikethrough` with the fields tdlib requires, the others are set by `TextEntityTypeStrikethrough::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1137,6 +1389,12 @@ pub struct RTDTextEntityTypeStrikethroughBuilder {
 impl RTDTextEntityTypeStrikethroughBuilder {
   pub fn build(&self) -> TextEntityTypeStrikethrough { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeStrikethrough> for TextEntityTypeStrikethrough {
@@ -1147,6 +1405,10 @@ impl AsRef<TextEntityTypeStrikethrough> for RTDTextEntityTypeStrikethroughBuilde
   fn as_ref(&self) -> &TextEntityTypeStrikethrough { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeStrikethrough {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1161,15 +1423,19 @@ pub struct TextEntityTypeTextUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// HTTP or tg:// URL to be opened when the link is clicked
   url: String,
   
 }
 
 impl RObject for TextEntityTypeTextUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeTextUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeTextUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1179,14 +1445,15 @@ impl TDTextEntityType for TextEntityTypeTextUrl {}
 
 
 impl TextEntityTypeTextUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeTextUrlBuilder {
-    let mut inner = TextEntityTypeTextUrl::default();
-    inner.td_name = "textEntityTypeTextUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeTextUrl { td_name: "textEntityTypeTextUrl".to_string(), ..Default::default() };
     RTDTextEntityTypeTextUrlBuilder { inner }
   }
 
+  /// `TextEntityTypeTextUrl` with the fields tdlib requires, the others are set by `TextEntityTypeTextUrl::builder()`
+  pub fn new(url: impl AsRef<str>) -> Self { Self::builder().url(url).build() }
+
   pub fn url(&self) -> &String { &self.url }
 
 }
@@ -1199,6 +1466,12 @@ pub struct RTDTextEntityTypeTextUrlBuilder {
 impl RTDTextEntityTypeTextUrlBuilder {
   pub fn build(&self) -> TextEntityTypeTextUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -1215,6 +1488,12 @@ impl AsRef<TextEntityTypeTextUrl> for RTDTextEntityTypeTextUrlBuilder {
   fn as_ref(&self) -> &TextEntityTypeTextUrl { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeTextUrl {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+  }
+}
+
 
 
 
@@ -1229,13 +1508,17 @@ pub struct TextEntityTypeUnderline {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeUnderline {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeUnderline" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeUnderline" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1245,14 +1528,15 @@ impl TDTextEntityType for TextEntityTypeUnderline {}
 
 
 impl TextEntityTypeUnderline {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeUnderlineBuilder {
-    let mut inner = TextEntityTypeUnderline::default();
-    inner.td_name = "textEntityTypeUnderline".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeUnderline { td_name: "textEntityTypeUnderline".to_string(), ..Default::default() };
     RTDTextEntityTypeUnderlineBuilder { inner }
   }
 
+  /// `TextEntityTypeUnderline` with the fields tdlib requires, the others are set by `TextEntityTypeUnderline::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1263,6 +1547,12 @@ pub struct RTDTextEntityTypeUnderlineBuilder {
 impl RTDTextEntityTypeUnderlineBuilder {
   pub fn build(&self) -> TextEntityTypeUnderline { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeUnderline> for TextEntityTypeUnderline {
@@ -1273,6 +1563,10 @@ impl AsRef<TextEntityTypeUnderline> for RTDTextEntityTypeUnderlineBuilder {
   fn as_ref(&self) -> &TextEntityTypeUnderline { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeUnderline {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -1287,13 +1581,17 @@ pub struct TextEntityTypeUrl {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextEntityTypeUrl {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textEntityTypeUrl" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textEntityTypeUrl" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1303,14 +1601,15 @@ impl TDTextEntityType for TextEntityTypeUrl {}
 
 
 impl TextEntityTypeUrl {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextEntityTypeUrlBuilder {
-    let mut inner = TextEntityTypeUrl::default();
-    inner.td_name = "textEntityTypeUrl".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextEntityTypeUrl { td_name: "textEntityTypeUrl".to_string(), ..Default::default() };
     RTDTextEntityTypeUrlBuilder { inner }
   }
 
+  /// `TextEntityTypeUrl` with the fields tdlib requires, the others are set by `TextEntityTypeUrl::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -1321,6 +1620,12 @@ pub struct RTDTextEntityTypeUrlBuilder {
 impl RTDTextEntityTypeUrlBuilder {
   pub fn build(&self) -> TextEntityTypeUrl { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextEntityTypeUrl> for TextEntityTypeUrl {
@@ -1331,5 +1636,9 @@ impl AsRef<TextEntityTypeUrl> for RTDTextEntityTypeUrlBuilder {
   fn as_ref(&self) -> &TextEntityTypeUrl { &self.inner }
 }
 
+impl PartialEq for TextEntityTypeUrl {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDTextParseMode: Debug + RObject {}
 
 /// Describes the way the text needs to be parsed for TextEntities
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum TextParseMode {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum TextParseMode {
   HTML(TextParseModeHTML),
   /// The text uses Markdown-style formatting
   Markdown(TextParseModeMarkdown),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for TextParseMode {
   fn deserialize<D>(deserializer: D) -> Result<TextParseMode, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      TextParseMode,
+      TextParseMode, Unknown;
       (textParseModeHTML, HTML);
       (textParseModeMarkdown, Markdown);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for TextParseMode {
 }
 
 impl RObject for TextParseMode {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       TextParseMode::HTML(t) => t.td_name(),
       TextParseMode::Markdown(t) => t.td_name(),
+      TextParseMode::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       TextParseMode::HTML(t) => t.extra(),
       TextParseMode::Markdown(t) => t.extra(),
+      TextParseMode::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      TextParseMode::HTML(t) => t.client_id(),
+      TextParseMode::Markdown(t) => t.client_id(),
+      TextParseMode::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for TextParseMode {
 }
 
 impl TextParseMode {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TextParseMode::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, TextParseMode::_Default(_)) }
 
-  pub fn is_h_t_m_l(&self) -> bool { if let TextParseMode::HTML(_) = self { true } else { false } }
-  pub fn is_markdown(&self) -> bool { if let TextParseMode::Markdown(_) = self { true } else { false } }
+  pub fn is_h_t_m_l(&self) -> bool { matches!(self, TextParseMode::HTML(_)) }
+  pub fn is_markdown(&self) -> bool { matches!(self, TextParseMode::Markdown(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, TextParseMode::Unknown(_)) }
 
   pub fn on_h_t_m_l<F: FnOnce(&TextParseModeHTML)>(&self, fnc: F) -> &Self { if let TextParseMode::HTML(t) = self { fnc(t) }; self }
   pub fn on_markdown<F: FnOnce(&TextParseModeMarkdown)>(&self, fnc: F) -> &Self { if let TextParseMode::Markdown(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let TextParseMode::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_h_t_m_l(&self) -> Option<&TextParseModeHTML> { if let TextParseMode::HTML(t) = self { return Some(t) } None }
   pub fn as_markdown(&self) -> Option<&TextParseModeMarkdown> { if let TextParseMode::Markdown(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let TextParseMode::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,13 +116,17 @@ pub struct TextParseModeHTML {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TextParseModeHTML {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textParseModeHTML" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textParseModeHTML" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -117,14 +136,15 @@ impl TDTextParseMode for TextParseModeHTML {}
 
 
 impl TextParseModeHTML {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextParseModeHTMLBuilder {
-    let mut inner = TextParseModeHTML::default();
-    inner.td_name = "textParseModeHTML".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextParseModeHTML { td_name: "textParseModeHTML".to_string(), ..Default::default() };
     RTDTextParseModeHTMLBuilder { inner }
   }
 
+  /// `TextParseModeHTML` with the fields tdlib requires, the others are set by `TextParseModeHTML::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -135,6 +155,12 @@ pub struct RTDTextParseModeHTMLBuilder {
 impl RTDTextParseModeHTMLBuilder {
   pub fn build(&self) -> TextParseModeHTML { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TextParseModeHTML> for TextParseModeHTML {
@@ -145,6 +171,10 @@ impl AsRef<TextParseModeHTML> for RTDTextParseModeHTMLBuilder {
   fn as_ref(&self) -> &TextParseModeHTML { &self.inner }
 }
 
+impl PartialEq for TextParseModeHTML {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -159,15 +189,19 @@ pub struct TextParseModeMarkdown {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Version of the parser: 0 or 1  Telegram Bot API "Markdown" parse mode, 2  Telegram Bot API "MarkdownV2" parse mode
   version: i64,
   
 }
 
 impl RObject for TextParseModeMarkdown {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "textParseModeMarkdown" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "textParseModeMarkdown" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -177,14 +211,15 @@ impl TDTextParseMode for TextParseModeMarkdown {}
 
 
 impl TextParseModeMarkdown {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTextParseModeMarkdownBuilder {
-    let mut inner = TextParseModeMarkdown::default();
-    inner.td_name = "textParseModeMarkdown".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TextParseModeMarkdown { td_name: "textParseModeMarkdown".to_string(), ..Default::default() };
     RTDTextParseModeMarkdownBuilder { inner }
   }
 
+  /// `TextParseModeMarkdown` with the fields tdlib requires, the others are set by `TextParseModeMarkdown::builder()`
+  pub fn new(version: i64) -> Self { Self::builder().version(version).build() }
+
   pub fn version(&self) -> i64 { self.version }
 
 }
@@ -197,6 +232,12 @@ pub struct RTDTextParseModeMarkdownBuilder {
 impl RTDTextParseModeMarkdownBuilder {
   pub fn build(&self) -> TextParseModeMarkdown { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn version(&mut self, version: i64) -> &mut Self {
     self.inner.version = version;
@@ -213,5 +254,11 @@ impl AsRef<TextParseModeMarkdown> for RTDTextParseModeMarkdownBuilder {
   fn as_ref(&self) -> &TextParseModeMarkdown { &self.inner }
 }
 
+impl PartialEq for TextParseModeMarkdown {
+  fn eq(&self, other: &Self) -> bool {
+    self.version == other.version
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ThemeSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Theme accent color in ARGB format
   accent_color: i64,
   /// The background to be used in chats; may be null
@@ -29,22 +31,36 @@ pub struct ThemeSettings {
 }
 
 impl RObject for ThemeSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "themeSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "themeSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ThemeSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThemeSettingsBuilder {
-    let mut inner = ThemeSettings::default();
-    inner.td_name = "themeSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ThemeSettings { td_name: "themeSettings".to_string(), ..Default::default() };
     RTDThemeSettingsBuilder { inner }
   }
 
+  /// `ThemeSettings` with the fields tdlib requires, the others are set by `ThemeSettings::builder()`
+  pub fn new(
+    accent_color: i64,
+    outgoing_message_fill: impl AsRef<BackgroundFill>,
+    animate_outgoing_message_fill: bool,
+    outgoing_message_accent_color: i64,
+  ) -> Self {
+    Self::builder()
+      .accent_color(accent_color)
+      .outgoing_message_fill(outgoing_message_fill)
+      .animate_outgoing_message_fill(animate_outgoing_message_fill)
+      .outgoing_message_accent_color(outgoing_message_accent_color)
+      .build()
+  }
+
   pub fn accent_color(&self) -> i64 { self.accent_color }
 
   pub fn background(&self) -> &Option<Background> { &self.background }
@@ -65,6 +81,12 @@ pub struct RTDThemeSettingsBuilder {
 impl RTDThemeSettingsBuilder {
   pub fn build(&self) -> ThemeSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn accent_color(&mut self, accent_color: i64) -> &mut Self {
     self.inner.accent_color = accent_color;
@@ -105,5 +127,15 @@ impl AsRef<ThemeSettings> for RTDThemeSettingsBuilder {
   fn as_ref(&self) -> &ThemeSettings { &self.inner }
 }
 
+impl PartialEq for ThemeSettings {
+  fn eq(&self, other: &Self) -> bool {
+    self.accent_color == other.accent_color
+      && self.background == other.background
+      && self.outgoing_message_fill == other.outgoing_message_fill
+      && self.animate_outgoing_message_fill == other.animate_outgoing_message_fill
+      && self.outgoing_message_accent_color == other.outgoing_message_accent_color
+  }
+}
+
 
 
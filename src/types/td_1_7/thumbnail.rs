@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Thumbnail {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Thumbnail format
   format: ThumbnailFormat,
   /// Thumbnail width
@@ -27,22 +29,36 @@ pub struct Thumbnail {
 }
 
 impl RObject for Thumbnail {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnail" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "thumbnail" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Thumbnail {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThumbnailBuilder {
-    let mut inner = Thumbnail::default();
-    inner.td_name = "thumbnail".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Thumbnail { td_name: "thumbnail".to_string(), ..Default::default() };
     RTDThumbnailBuilder { inner }
   }
 
+  /// `Thumbnail` with the fields tdlib requires, the others are set by `Thumbnail::builder()`
+  pub fn new(
+    format: impl AsRef<ThumbnailFormat>,
+    width: i64,
+    height: i64,
+    file: impl AsRef<File>,
+  ) -> Self {
+    Self::builder()
+      .format(format)
+      .width(width)
+      .height(height)
+      .file(file)
+      .build()
+  }
+
   pub fn format(&self) -> &ThumbnailFormat { &self.format }
 
   pub fn width(&self) -> i64 { self.width }
@@ -61,6 +77,12 @@ pub struct RTDThumbnailBuilder {
 impl RTDThumbnailBuilder {
   pub fn build(&self) -> Thumbnail { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn format<T: AsRef<ThumbnailFormat>>(&mut self, format: T) -> &mut Self {
     self.inner.format = format.as_ref().clone();
@@ -95,5 +117,14 @@ impl AsRef<Thumbnail> for RTDThumbnailBuilder {
   fn as_ref(&self) -> &Thumbnail { &self.inner }
 }
 
+impl PartialEq for Thumbnail {
+  fn eq(&self, other: &Self) -> bool {
+    self.format == other.format
+      && self.width == other.width
+      && self.height == other.height
+      && self.file == other.file
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDThumbnailFormat: Debug + RObject {}
 
 /// Describes format of the thumbnail
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ThumbnailFormat {
   #[doc(hidden)] _Default(()),
@@ -31,6 +30,8 @@ pub enum ThumbnailFormat {
   Tgs(ThumbnailFormatTgs),
   /// The thumbnail is in WEBP format. It will be used only for some stickers
   Webp(ThumbnailFormatWebp),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -42,7 +43,7 @@ impl<'de> Deserialize<'de> for ThumbnailFormat {
   fn deserialize<D>(deserializer: D) -> Result<ThumbnailFormat, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      ThumbnailFormat,
+      ThumbnailFormat, Unknown;
       (thumbnailFormatGif, Gif);
       (thumbnailFormatJpeg, Jpeg);
       (thumbnailFormatMpeg4, Mpeg4);
@@ -55,7 +56,7 @@ impl<'de> Deserialize<'de> for ThumbnailFormat {
 }
 
 impl RObject for ThumbnailFormat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       ThumbnailFormat::Gif(t) => t.td_name(),
       ThumbnailFormat::Jpeg(t) => t.td_name(),
@@ -63,11 +64,12 @@ impl RObject for ThumbnailFormat {
       ThumbnailFormat::Png(t) => t.td_name(),
       ThumbnailFormat::Tgs(t) => t.td_name(),
       ThumbnailFormat::Webp(t) => t.td_name(),
+      ThumbnailFormat::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       ThumbnailFormat::Gif(t) => t.extra(),
       ThumbnailFormat::Jpeg(t) => t.extra(),
@@ -75,6 +77,20 @@ impl RObject for ThumbnailFormat {
       ThumbnailFormat::Png(t) => t.extra(),
       ThumbnailFormat::Tgs(t) => t.extra(),
       ThumbnailFormat::Webp(t) => t.extra(),
+      ThumbnailFormat::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      ThumbnailFormat::Gif(t) => t.client_id(),
+      ThumbnailFormat::Jpeg(t) => t.client_id(),
+      ThumbnailFormat::Mpeg4(t) => t.client_id(),
+      ThumbnailFormat::Png(t) => t.client_id(),
+      ThumbnailFormat::Tgs(t) => t.client_id(),
+      ThumbnailFormat::Webp(t) => t.client_id(),
+      ThumbnailFormat::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -83,15 +99,16 @@ impl RObject for ThumbnailFormat {
 }
 
 impl ThumbnailFormat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ThumbnailFormat::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, ThumbnailFormat::_Default(_)) }
 
-  pub fn is_gif(&self) -> bool { if let ThumbnailFormat::Gif(_) = self { true } else { false } }
-  pub fn is_jpeg(&self) -> bool { if let ThumbnailFormat::Jpeg(_) = self { true } else { false } }
-  pub fn is_mpeg4(&self) -> bool { if let ThumbnailFormat::Mpeg4(_) = self { true } else { false } }
-  pub fn is_png(&self) -> bool { if let ThumbnailFormat::Png(_) = self { true } else { false } }
-  pub fn is_tgs(&self) -> bool { if let ThumbnailFormat::Tgs(_) = self { true } else { false } }
-  pub fn is_webp(&self) -> bool { if let ThumbnailFormat::Webp(_) = self { true } else { false } }
+  pub fn is_gif(&self) -> bool { matches!(self, ThumbnailFormat::Gif(_)) }
+  pub fn is_jpeg(&self) -> bool { matches!(self, ThumbnailFormat::Jpeg(_)) }
+  pub fn is_mpeg4(&self) -> bool { matches!(self, ThumbnailFormat::Mpeg4(_)) }
+  pub fn is_png(&self) -> bool { matches!(self, ThumbnailFormat::Png(_)) }
+  pub fn is_tgs(&self) -> bool { matches!(self, ThumbnailFormat::Tgs(_)) }
+  pub fn is_webp(&self) -> bool { matches!(self, ThumbnailFormat::Webp(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, ThumbnailFormat::Unknown(_)) }
 
   pub fn on_gif<F: FnOnce(&ThumbnailFormatGif)>(&self, fnc: F) -> &Self { if let ThumbnailFormat::Gif(t) = self { fnc(t) }; self }
   pub fn on_jpeg<F: FnOnce(&ThumbnailFormatJpeg)>(&self, fnc: F) -> &Self { if let ThumbnailFormat::Jpeg(t) = self { fnc(t) }; self }
@@ -99,6 +116,7 @@ impl ThumbnailFormat {
   pub fn on_png<F: FnOnce(&ThumbnailFormatPng)>(&self, fnc: F) -> &Self { if let ThumbnailFormat::Png(t) = self { fnc(t) }; self }
   pub fn on_tgs<F: FnOnce(&ThumbnailFormatTgs)>(&self, fnc: F) -> &Self { if let ThumbnailFormat::Tgs(t) = self { fnc(t) }; self }
   pub fn on_webp<F: FnOnce(&ThumbnailFormatWebp)>(&self, fnc: F) -> &Self { if let ThumbnailFormat::Webp(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let ThumbnailFormat::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_gif(&self) -> Option<&ThumbnailFormatGif> { if let ThumbnailFormat::Gif(t) = self { return Some(t) } None }
   pub fn as_jpeg(&self) -> Option<&ThumbnailFormatJpeg> { if let ThumbnailFormat::Jpeg(t) = self { return Some(t) } None }
@@ -106,6 +124,7 @@ impl ThumbnailFormat {
   pub fn as_png(&self) -> Option<&ThumbnailFormatPng> { if let ThumbnailFormat::Png(t) = self { return Some(t) } None }
   pub fn as_tgs(&self) -> Option<&ThumbnailFormatTgs> { if let ThumbnailFormat::Tgs(t) = self { return Some(t) } None }
   pub fn as_webp(&self) -> Option<&ThumbnailFormatWebp> { if let ThumbnailFormat::Webp(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let ThumbnailFormat::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -141,13 +160,17 @@ pub struct ThumbnailFormatGif {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ThumbnailFormatGif {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatGif" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "thumbnailFormatGif" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,14 +180,15 @@ impl TDThumbnailFormat for ThumbnailFormatGif {}
 
 
 impl ThumbnailFormatGif {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThumbnailFormatGifBuilder {
-    let mut inner = ThumbnailFormatGif::default();
-    inner.td_name = "thumbnailFormatGif".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ThumbnailFormatGif { td_name: "thumbnailFormatGif".to_string(), ..Default::default() };
     RTDThumbnailFormatGifBuilder { inner }
   }
 
+  /// `ThumbnailFormatGif` with the fields tdlib requires, the others are set by `ThumbnailFormatGif::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +199,12 @@ pub struct RTDThumbnailFormatGifBuilder {
 impl RTDThumbnailFormatGifBuilder {
   pub fn build(&self) -> ThumbnailFormatGif { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatGif> for ThumbnailFormatGif {
@@ -185,6 +215,10 @@ impl AsRef<ThumbnailFormatGif> for RTDThumbnailFormatGifBuilder {
   fn as_ref(&self) -> &ThumbnailFormatGif { &self.inner }
 }
 
+impl PartialEq for ThumbnailFormatGif {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -199,13 +233,17 @@ pub struct ThumbnailFormatJpeg {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ThumbnailFormatJpeg {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatJpeg" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "thumbnailFormatJpeg" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -215,14 +253,15 @@ impl TDThumbnailFormat for ThumbnailFormatJpeg {}
 
 
 impl ThumbnailFormatJpeg {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThumbnailFormatJpegBuilder {
-    let mut inner = ThumbnailFormatJpeg::default();
-    inner.td_name = "thumbnailFormatJpeg".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ThumbnailFormatJpeg { td_name: "thumbnailFormatJpeg".to_string(), ..Default::default() };
     RTDThumbnailFormatJpegBuilder { inner }
   }
 
+  /// `ThumbnailFormatJpeg` with the fields tdlib requires, the others are set by `ThumbnailFormatJpeg::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +272,12 @@ pub struct RTDThumbnailFormatJpegBuilder {
 impl RTDThumbnailFormatJpegBuilder {
   pub fn build(&self) -> ThumbnailFormatJpeg { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatJpeg> for ThumbnailFormatJpeg {
@@ -243,6 +288,10 @@ impl AsRef<ThumbnailFormatJpeg> for RTDThumbnailFormatJpegBuilder {
   fn as_ref(&self) -> &ThumbnailFormatJpeg { &self.inner }
 }
 
+impl PartialEq for ThumbnailFormatJpeg {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -257,13 +306,17 @@ pub struct ThumbnailFormatMpeg4 {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ThumbnailFormatMpeg4 {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatMpeg4" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "thumbnailFormatMpeg4" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,14 +326,15 @@ impl TDThumbnailFormat for ThumbnailFormatMpeg4 {}
 
 
 impl ThumbnailFormatMpeg4 {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThumbnailFormatMpeg4Builder {
-    let mut inner = ThumbnailFormatMpeg4::default();
-    inner.td_name = "thumbnailFormatMpeg4".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ThumbnailFormatMpeg4 { td_name: "thumbnailFormatMpeg4".to_string(), ..Default::default() };
     RTDThumbnailFormatMpeg4Builder { inner }
   }
 
+  /// `ThumbnailFormatMpeg4` with the fields tdlib requires, the others are set by `ThumbnailFormatMpeg4::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +345,12 @@ pub struct RTDThumbnailFormatMpeg4Builder {
 impl RTDThumbnailFormatMpeg4Builder {
   pub fn build(&self) -> ThumbnailFormatMpeg4 { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatMpeg4> for ThumbnailFormatMpeg4 {
@@ -301,6 +361,10 @@ impl AsRef<ThumbnailFormatMpeg4> for RTDThumbnailFormatMpeg4Builder {
   fn as_ref(&self) -> &ThumbnailFormatMpeg4 { &self.inner }
 }
 
+impl PartialEq for ThumbnailFormatMpeg4 {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -315,13 +379,17 @@ pub struct ThumbnailFormatPng {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ThumbnailFormatPng {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatPng" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "thumbnailFormatPng" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -331,14 +399,15 @@ impl TDThumbnailFormat for ThumbnailFormatPng {}
 
 
 impl ThumbnailFormatPng {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThumbnailFormatPngBuilder {
-    let mut inner = ThumbnailFormatPng::default();
-    inner.td_name = "thumbnailFormatPng".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ThumbnailFormatPng { td_name: "thumbnailFormatPng".to_string(), ..Default::default() };
     RTDThumbnailFormatPngBuilder { inner }
   }
 
+  /// `ThumbnailFormatPng` with the fields tdlib requires, the others are set by `ThumbnailFormatPng::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -349,6 +418,12 @@ pub struct RTDThumbnailFormatPngBuilder {
 impl RTDThumbnailFormatPngBuilder {
   pub fn build(&self) -> ThumbnailFormatPng { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatPng> for ThumbnailFormatPng {
@@ -359,6 +434,10 @@ impl AsRef<ThumbnailFormatPng> for RTDThumbnailFormatPngBuilder {
   fn as_ref(&self) -> &ThumbnailFormatPng { &self.inner }
 }
 
+impl PartialEq for ThumbnailFormatPng {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -373,13 +452,17 @@ pub struct ThumbnailFormatTgs {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ThumbnailFormatTgs {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatTgs" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "thumbnailFormatTgs" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -389,14 +472,15 @@ impl TDThumbnailFormat for ThumbnailFormatTgs {}
 
 
 impl ThumbnailFormatTgs {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThumbnailFormatTgsBuilder {
-    let mut inner = ThumbnailFormatTgs::default();
-    inner.td_name = "thumbnailFormatTgs".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ThumbnailFormatTgs { td_name: "thumbnailFormatTgs".to_string(), ..Default::default() };
     RTDThumbnailFormatTgsBuilder { inner }
   }
 
+  /// `ThumbnailFormatTgs` with the fields tdlib requires, the others are set by `ThumbnailFormatTgs::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -407,6 +491,12 @@ pub struct RTDThumbnailFormatTgsBuilder {
 impl RTDThumbnailFormatTgsBuilder {
   pub fn build(&self) -> ThumbnailFormatTgs { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatTgs> for ThumbnailFormatTgs {
@@ -417,6 +507,10 @@ impl AsRef<ThumbnailFormatTgs> for RTDThumbnailFormatTgsBuilder {
   fn as_ref(&self) -> &ThumbnailFormatTgs { &self.inner }
 }
 
+impl PartialEq for ThumbnailFormatTgs {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -431,13 +525,17 @@ pub struct ThumbnailFormatWebp {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for ThumbnailFormatWebp {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "thumbnailFormatWebp" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "thumbnailFormatWebp" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -447,14 +545,15 @@ impl TDThumbnailFormat for ThumbnailFormatWebp {}
 
 
 impl ThumbnailFormatWebp {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDThumbnailFormatWebpBuilder {
-    let mut inner = ThumbnailFormatWebp::default();
-    inner.td_name = "thumbnailFormatWebp".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ThumbnailFormatWebp { td_name: "thumbnailFormatWebp".to_string(), ..Default::default() };
     RTDThumbnailFormatWebpBuilder { inner }
   }
 
+  /// `ThumbnailFormatWebp` with the fields tdlib requires, the others are set by `ThumbnailFormatWebp::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -465,6 +564,12 @@ pub struct RTDThumbnailFormatWebpBuilder {
 impl RTDThumbnailFormatWebpBuilder {
   pub fn build(&self) -> ThumbnailFormatWebp { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<ThumbnailFormatWebp> for ThumbnailFormatWebp {
@@ -475,5 +580,9 @@ impl AsRef<ThumbnailFormatWebp> for RTDThumbnailFormatWebpBuilder {
   fn as_ref(&self) -> &ThumbnailFormatWebp { &self.inner }
 }
 
+impl PartialEq for ThumbnailFormatWebp {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
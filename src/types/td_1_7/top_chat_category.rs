@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,13 +14,14 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDTopChatCategory: Debug + RObject {}
 
 /// Represents the categories of chats for which a list of frequently used chats can be retrieved
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum TopChatCategory {
   #[doc(hidden)] _Default(()),
   /// A category containing frequently used private chats with bot users
   Bots(TopChatCategoryBots),
   /// A category containing frequently used chats used for calls
+  #[cfg(feature = "calls")]
   Calls(TopChatCategoryCalls),
   /// A category containing frequently used channels
   Channels(TopChatCategoryChannels),
@@ -33,6 +33,8 @@ pub enum TopChatCategory {
   InlineBots(TopChatCategoryInlineBots),
   /// A category containing frequently used private chats with non-bot users
   Users(TopChatCategoryUsers),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -44,9 +46,9 @@ impl<'de> Deserialize<'de> for TopChatCategory {
   fn deserialize<D>(deserializer: D) -> Result<TopChatCategory, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      TopChatCategory,
+      TopChatCategory, Unknown;
       (topChatCategoryBots, Bots);
-      (topChatCategoryCalls, Calls);
+      #[cfg(feature = "calls")] (topChatCategoryCalls, Calls);
       (topChatCategoryChannels, Channels);
       (topChatCategoryForwardChats, ForwardChats);
       (topChatCategoryGroups, Groups);
@@ -58,28 +60,44 @@ impl<'de> Deserialize<'de> for TopChatCategory {
 }
 
 impl RObject for TopChatCategory {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       TopChatCategory::Bots(t) => t.td_name(),
-      TopChatCategory::Calls(t) => t.td_name(),
+      #[cfg(feature = "calls")] TopChatCategory::Calls(t) => t.td_name(),
       TopChatCategory::Channels(t) => t.td_name(),
       TopChatCategory::ForwardChats(t) => t.td_name(),
       TopChatCategory::Groups(t) => t.td_name(),
       TopChatCategory::InlineBots(t) => t.td_name(),
       TopChatCategory::Users(t) => t.td_name(),
+      TopChatCategory::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       TopChatCategory::Bots(t) => t.extra(),
-      TopChatCategory::Calls(t) => t.extra(),
+      #[cfg(feature = "calls")] TopChatCategory::Calls(t) => t.extra(),
       TopChatCategory::Channels(t) => t.extra(),
       TopChatCategory::ForwardChats(t) => t.extra(),
       TopChatCategory::Groups(t) => t.extra(),
       TopChatCategory::InlineBots(t) => t.extra(),
       TopChatCategory::Users(t) => t.extra(),
+      TopChatCategory::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      TopChatCategory::Bots(t) => t.client_id(),
+      #[cfg(feature = "calls")] TopChatCategory::Calls(t) => t.client_id(),
+      TopChatCategory::Channels(t) => t.client_id(),
+      TopChatCategory::ForwardChats(t) => t.client_id(),
+      TopChatCategory::Groups(t) => t.client_id(),
+      TopChatCategory::InlineBots(t) => t.client_id(),
+      TopChatCategory::Users(t) => t.client_id(),
+      TopChatCategory::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -88,38 +106,41 @@ impl RObject for TopChatCategory {
 }
 
 impl TopChatCategory {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TopChatCategory::_Default(_) = self { true } else { false } }
-
-  pub fn is_bots(&self) -> bool { if let TopChatCategory::Bots(_) = self { true } else { false } }
-  pub fn is_calls(&self) -> bool { if let TopChatCategory::Calls(_) = self { true } else { false } }
-  pub fn is_channels(&self) -> bool { if let TopChatCategory::Channels(_) = self { true } else { false } }
-  pub fn is_forward_chats(&self) -> bool { if let TopChatCategory::ForwardChats(_) = self { true } else { false } }
-  pub fn is_groups(&self) -> bool { if let TopChatCategory::Groups(_) = self { true } else { false } }
-  pub fn is_inline_bots(&self) -> bool { if let TopChatCategory::InlineBots(_) = self { true } else { false } }
-  pub fn is_users(&self) -> bool { if let TopChatCategory::Users(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, TopChatCategory::_Default(_)) }
+
+  pub fn is_bots(&self) -> bool { matches!(self, TopChatCategory::Bots(_)) }
+  #[cfg(feature = "calls")] pub fn is_calls(&self) -> bool { matches!(self, TopChatCategory::Calls(_)) }
+  pub fn is_channels(&self) -> bool { matches!(self, TopChatCategory::Channels(_)) }
+  pub fn is_forward_chats(&self) -> bool { matches!(self, TopChatCategory::ForwardChats(_)) }
+  pub fn is_groups(&self) -> bool { matches!(self, TopChatCategory::Groups(_)) }
+  pub fn is_inline_bots(&self) -> bool { matches!(self, TopChatCategory::InlineBots(_)) }
+  pub fn is_users(&self) -> bool { matches!(self, TopChatCategory::Users(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, TopChatCategory::Unknown(_)) }
 
   pub fn on_bots<F: FnOnce(&TopChatCategoryBots)>(&self, fnc: F) -> &Self { if let TopChatCategory::Bots(t) = self { fnc(t) }; self }
-  pub fn on_calls<F: FnOnce(&TopChatCategoryCalls)>(&self, fnc: F) -> &Self { if let TopChatCategory::Calls(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_calls<F: FnOnce(&TopChatCategoryCalls)>(&self, fnc: F) -> &Self { if let TopChatCategory::Calls(t) = self { fnc(t) }; self }
   pub fn on_channels<F: FnOnce(&TopChatCategoryChannels)>(&self, fnc: F) -> &Self { if let TopChatCategory::Channels(t) = self { fnc(t) }; self }
   pub fn on_forward_chats<F: FnOnce(&TopChatCategoryForwardChats)>(&self, fnc: F) -> &Self { if let TopChatCategory::ForwardChats(t) = self { fnc(t) }; self }
   pub fn on_groups<F: FnOnce(&TopChatCategoryGroups)>(&self, fnc: F) -> &Self { if let TopChatCategory::Groups(t) = self { fnc(t) }; self }
   pub fn on_inline_bots<F: FnOnce(&TopChatCategoryInlineBots)>(&self, fnc: F) -> &Self { if let TopChatCategory::InlineBots(t) = self { fnc(t) }; self }
   pub fn on_users<F: FnOnce(&TopChatCategoryUsers)>(&self, fnc: F) -> &Self { if let TopChatCategory::Users(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let TopChatCategory::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_bots(&self) -> Option<&TopChatCategoryBots> { if let TopChatCategory::Bots(t) = self { return Some(t) } None }
-  pub fn as_calls(&self) -> Option<&TopChatCategoryCalls> { if let TopChatCategory::Calls(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_calls(&self) -> Option<&TopChatCategoryCalls> { if let TopChatCategory::Calls(t) = self { return Some(t) } None }
   pub fn as_channels(&self) -> Option<&TopChatCategoryChannels> { if let TopChatCategory::Channels(t) = self { return Some(t) } None }
   pub fn as_forward_chats(&self) -> Option<&TopChatCategoryForwardChats> { if let TopChatCategory::ForwardChats(t) = self { return Some(t) } None }
   pub fn as_groups(&self) -> Option<&TopChatCategoryGroups> { if let TopChatCategory::Groups(t) = self { return Some(t) } None }
   pub fn as_inline_bots(&self) -> Option<&TopChatCategoryInlineBots> { if let TopChatCategory::InlineBots(t) = self { return Some(t) } None }
   pub fn as_users(&self) -> Option<&TopChatCategoryUsers> { if let TopChatCategory::Users(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let TopChatCategory::Unknown(t) = self { return Some(t) } None }
 
 
 
   pub fn bots<T: AsRef<TopChatCategoryBots>>(t: T) -> Self { TopChatCategory::Bots(t.as_ref().clone()) }
 
-  pub fn calls<T: AsRef<TopChatCategoryCalls>>(t: T) -> Self { TopChatCategory::Calls(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn calls<T: AsRef<TopChatCategoryCalls>>(t: T) -> Self { TopChatCategory::Calls(t.as_ref().clone()) }
 
   pub fn channels<T: AsRef<TopChatCategoryChannels>>(t: T) -> Self { TopChatCategory::Channels(t.as_ref().clone()) }
 
@@ -151,13 +172,17 @@ pub struct TopChatCategoryBots {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TopChatCategoryBots {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryBots" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "topChatCategoryBots" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -167,14 +192,15 @@ impl TDTopChatCategory for TopChatCategoryBots {}
 
 
 impl TopChatCategoryBots {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTopChatCategoryBotsBuilder {
-    let mut inner = TopChatCategoryBots::default();
-    inner.td_name = "topChatCategoryBots".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TopChatCategoryBots { td_name: "topChatCategoryBots".to_string(), ..Default::default() };
     RTDTopChatCategoryBotsBuilder { inner }
   }
 
+  /// `TopChatCategoryBots` with the fields tdlib requires, the others are set by `TopChatCategoryBots::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -185,6 +211,12 @@ pub struct RTDTopChatCategoryBotsBuilder {
 impl RTDTopChatCategoryBotsBuilder {
   pub fn build(&self) -> TopChatCategoryBots { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryBots> for TopChatCategoryBots {
@@ -195,11 +227,17 @@ impl AsRef<TopChatCategoryBots> for RTDTopChatCategoryBotsBuilder {
   fn as_ref(&self) -> &TopChatCategoryBots { &self.inner }
 }
 
+impl PartialEq for TopChatCategoryBots {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
 
 /// A category containing frequently used chats used for calls
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -209,13 +247,17 @@ pub struct TopChatCategoryCalls {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TopChatCategoryCalls {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryCalls" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "topChatCategoryCalls" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -225,14 +267,15 @@ impl TDTopChatCategory for TopChatCategoryCalls {}
 
 
 impl TopChatCategoryCalls {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTopChatCategoryCallsBuilder {
-    let mut inner = TopChatCategoryCalls::default();
-    inner.td_name = "topChatCategoryCalls".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TopChatCategoryCalls { td_name: "topChatCategoryCalls".to_string(), ..Default::default() };
     RTDTopChatCategoryCallsBuilder { inner }
   }
 
+  /// `TopChatCategoryCalls` with the fields tdlib requires, the others are set by `TopChatCategoryCalls::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -243,6 +286,12 @@ pub struct RTDTopChatCategoryCallsBuilder {
 impl RTDTopChatCategoryCallsBuilder {
   pub fn build(&self) -> TopChatCategoryCalls { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryCalls> for TopChatCategoryCalls {
@@ -253,6 +302,12 @@ impl AsRef<TopChatCategoryCalls> for RTDTopChatCategoryCallsBuilder {
   fn as_ref(&self) -> &TopChatCategoryCalls { &self.inner }
 }
 
+impl PartialEq for TopChatCategoryCalls {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
+}
+
 
 
 
@@ -267,13 +322,17 @@ pub struct TopChatCategoryChannels {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TopChatCategoryChannels {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryChannels" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "topChatCategoryChannels" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -283,14 +342,15 @@ impl TDTopChatCategory for TopChatCategoryChannels {}
 
 
 impl TopChatCategoryChannels {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTopChatCategoryChannelsBuilder {
-    let mut inner = TopChatCategoryChannels::default();
-    inner.td_name = "topChatCategoryChannels".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TopChatCategoryChannels { td_name: "topChatCategoryChannels".to_string(), ..Default::default() };
     RTDTopChatCategoryChannelsBuilder { inner }
   }
 
+  /// `TopChatCategoryChannels` with the fields tdlib requires, the others are set by `TopChatCategoryChannels::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -301,6 +361,12 @@ pub struct RTDTopChatCategoryChannelsBuilder {
 impl RTDTopChatCategoryChannelsBuilder {
   pub fn build(&self) -> TopChatCategoryChannels { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryChannels> for TopChatCategoryChannels {
@@ -311,6 +377,10 @@ impl AsRef<TopChatCategoryChannels> for RTDTopChatCategoryChannelsBuilder {
   fn as_ref(&self) -> &TopChatCategoryChannels { &self.inner }
 }
 
+impl PartialEq for TopChatCategoryChannels {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -325,13 +395,17 @@ pub struct TopChatCategoryForwardChats {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TopChatCategoryForwardChats {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryForwardChats" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "topChatCategoryForwardChats" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -341,14 +415,15 @@ impl TDTopChatCategory for TopChatCategoryForwardChats {}
 
 
 impl TopChatCategoryForwardChats {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTopChatCategoryForwardChatsBuilder {
-    let mut inner = TopChatCategoryForwardChats::default();
-    inner.td_name = "topChatCategoryForwardChats".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TopChatCategoryForwardChats { td_name: "topChatCategoryForwardChats".to_string(), ..Default::default() };
     RTDTopChatCategoryForwardChatsBuilder { inner }
   }
 
+  /// `TopChatCategoryForwardChats` with the fields tdlib requires, the others are set by `TopChatCategoryForwardChats::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -359,6 +434,12 @@ pub struct RTDTopChatCategoryForwardChatsBuilder {
 impl RTDTopChatCategoryForwardChatsBuilder {
   pub fn build(&self) -> TopChatCategoryForwardChats { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryForwardChats> for TopChatCategoryForwardChats {
@@ -369,6 +450,10 @@ impl AsRef<TopChatCategoryForwardChats> for RTDTopChatCategoryForwardChatsBuilde
   fn as_ref(&self) -> &TopChatCategoryForwardChats { &self.inner }
 }
 
+impl PartialEq for TopChatCategoryForwardChats {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -383,13 +468,17 @@ pub struct TopChatCategoryGroups {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TopChatCategoryGroups {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryGroups" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "topChatCategoryGroups" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -399,14 +488,15 @@ impl TDTopChatCategory for TopChatCategoryGroups {}
 
 
 impl TopChatCategoryGroups {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTopChatCategoryGroupsBuilder {
-    let mut inner = TopChatCategoryGroups::default();
-    inner.td_name = "topChatCategoryGroups".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TopChatCategoryGroups { td_name: "topChatCategoryGroups".to_string(), ..Default::default() };
     RTDTopChatCategoryGroupsBuilder { inner }
   }
 
+  /// `TopChatCategoryGroups` with the fields tdlib requires, the others are set by `TopChatCategoryGroups::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -417,6 +507,12 @@ pub struct RTDTopChatCategoryGroupsBuilder {
 impl RTDTopChatCategoryGroupsBuilder {
   pub fn build(&self) -> TopChatCategoryGroups { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryGroups> for TopChatCategoryGroups {
@@ -427,6 +523,10 @@ impl AsRef<TopChatCategoryGroups> for RTDTopChatCategoryGroupsBuilder {
   fn as_ref(&self) -> &TopChatCategoryGroups { &self.inner }
 }
 
+impl PartialEq for TopChatCategoryGroups {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -441,13 +541,17 @@ pub struct TopChatCategoryInlineBots {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TopChatCategoryInlineBots {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryInlineBots" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "topChatCategoryInlineBots" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -457,14 +561,15 @@ impl TDTopChatCategory for TopChatCategoryInlineBots {}
 
 
 impl TopChatCategoryInlineBots {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTopChatCategoryInlineBotsBuilder {
-    let mut inner = TopChatCategoryInlineBots::default();
-    inner.td_name = "topChatCategoryInlineBots".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TopChatCategoryInlineBots { td_name: "topChatCategoryInlineBots".to_string(), ..Default::default() };
     RTDTopChatCategoryInlineBotsBuilder { inner }
   }
 
+  /// `TopChatCategoryInlineBots` with the fields tdlib requires, the others are set by `TopChatCategoryInlineBots::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -475,6 +580,12 @@ pub struct RTDTopChatCategoryInlineBotsBuilder {
 impl RTDTopChatCategoryInlineBotsBuilder {
   pub fn build(&self) -> TopChatCategoryInlineBots { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryInlineBots> for TopChatCategoryInlineBots {
@@ -485,6 +596,10 @@ impl AsRef<TopChatCategoryInlineBots> for RTDTopChatCategoryInlineBotsBuilder {
   fn as_ref(&self) -> &TopChatCategoryInlineBots { &self.inner }
 }
 
+impl PartialEq for TopChatCategoryInlineBots {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -499,13 +614,17 @@ pub struct TopChatCategoryUsers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for TopChatCategoryUsers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "topChatCategoryUsers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "topChatCategoryUsers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -515,14 +634,15 @@ impl TDTopChatCategory for TopChatCategoryUsers {}
 
 
 impl TopChatCategoryUsers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDTopChatCategoryUsersBuilder {
-    let mut inner = TopChatCategoryUsers::default();
-    inner.td_name = "topChatCategoryUsers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = TopChatCategoryUsers { td_name: "topChatCategoryUsers".to_string(), ..Default::default() };
     RTDTopChatCategoryUsersBuilder { inner }
   }
 
+  /// `TopChatCategoryUsers` with the fields tdlib requires, the others are set by `TopChatCategoryUsers::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -533,6 +653,12 @@ pub struct RTDTopChatCategoryUsersBuilder {
 impl RTDTopChatCategoryUsersBuilder {
   pub fn build(&self) -> TopChatCategoryUsers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<TopChatCategoryUsers> for TopChatCategoryUsers {
@@ -543,5 +669,9 @@ impl AsRef<TopChatCategoryUsers> for RTDTopChatCategoryUsersBuilder {
   fn as_ref(&self) -> &TopChatCategoryUsers { &self.inner }
 }
 
+impl PartialEq for TopChatCategoryUsers {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDUpdate: Debug + RObject {}
 
 /// Contains notifications about data changes
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum Update {
   #[doc(hidden)] _Default(()),
@@ -34,6 +33,7 @@ pub enum Update {
   /// Some data from basicGroupFullInfo has been changed
   BasicGroupFullInfo(UpdateBasicGroupFullInfo),
   /// New call was created or information about a call was updated
+  #[cfg(feature = "calls")]
   Call(UpdateCall),
   /// The chat action bar was changed
   ChatActionBar(UpdateChatActionBar),
@@ -90,6 +90,7 @@ pub enum Update {
   /// The list of supported dice emojis has changed
   DiceEmojis(UpdateDiceEmojis),
   /// The list of favorite stickers was updated
+  #[cfg(feature = "stickers")]
   FavoriteStickers(UpdateFavoriteStickers),
   /// Information about a file was updated
   File(UpdateFile),
@@ -98,12 +99,15 @@ pub enum Update {
   /// File generation is no longer needed
   FileGenerationStop(UpdateFileGenerationStop),
   /// Information about a group call was updated
+  #[cfg(feature = "calls")]
   GroupCall(UpdateGroupCall),
   /// Information about a group call participant was changed. The updates are sent only after the group call is received through getGroupCall and only if the call is joined or being joined
+  #[cfg(feature = "calls")]
   GroupCallParticipant(UpdateGroupCallParticipant),
   /// Describes whether there are some pending notification updates. Can be used to prevent application from killing, while there are some pending notifications
   HavePendingNotifications(UpdateHavePendingNotifications),
   /// The list of installed sticker sets was updated
+  #[cfg(feature = "stickers")]
   InstalledStickerSets(UpdateInstalledStickerSets),
   /// Some language pack strings have been updated
   LanguagePackStrings(UpdateLanguagePackStrings),
@@ -128,6 +132,7 @@ pub enum Update {
   /// A message has been successfully sent
   MessageSendSucceeded(UpdateMessageSendSucceeded),
   /// New call signaling data arrived
+  #[cfg(feature = "calls")]
   NewCallSignalingData(UpdateNewCallSignalingData),
   /// A new incoming callback query; for bots only
   NewCallbackQuery(UpdateNewCallbackQuery),
@@ -150,6 +155,7 @@ pub enum Update {
   /// A new incoming pre-checkout query; for bots only. Contains full information about a checkout
   NewPreCheckoutQuery(UpdateNewPreCheckoutQuery),
   /// A new incoming shipping query; for bots only. Only for invoices with flexible price
+  #[cfg(feature = "payments")]
   NewShippingQuery(UpdateNewShippingQuery),
   /// A notification was changed
   Notification(UpdateNotification),
@@ -162,6 +168,7 @@ pub enum Update {
   /// A user changed the answer to a poll; for bots only
   PollAnswer(UpdatePollAnswer),
   /// The list of recently used stickers was updated
+  #[cfg(feature = "stickers")]
   RecentStickers(UpdateRecentStickers),
   /// The list of saved animations was updated
   SavedAnimations(UpdateSavedAnimations),
@@ -174,6 +181,7 @@ pub enum Update {
   /// Service notification from the server. Upon receiving this the application must show a popup with the content of the notification
   ServiceNotification(UpdateServiceNotification),
   /// A sticker set has changed
+  #[cfg(feature = "stickers")]
   StickerSet(UpdateStickerSet),
   /// The list of suggested to the user actions has changed
   SuggestedActions(UpdateSuggestedActions),
@@ -184,6 +192,7 @@ pub enum Update {
   /// New terms of service must be accepted by the user. If the terms of service are declined, then the deleteAccount method must be called with the reason "Decline ToS update"
   TermsOfService(UpdateTermsOfService),
   /// The list of trending sticker sets was updated or some of them were viewed
+  #[cfg(feature = "stickers")]
   TrendingStickerSets(UpdateTrendingStickerSets),
   /// Number of unread chats, i.e. with unread messages or marked as unread, has changed. This update is sent only if the message database is used
   UnreadChatCount(UpdateUnreadChatCount),
@@ -201,6 +210,8 @@ pub enum Update {
   UserStatus(UpdateUserStatus),
   /// The list of users nearby has changed. The update is guaranteed to be sent only 60 seconds after a successful searchChatsNearby request
   UsersNearby(UpdateUsersNearby),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -212,7 +223,7 @@ impl<'de> Deserialize<'de> for Update {
   fn deserialize<D>(deserializer: D) -> Result<Update, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      Update,
+      Update, Unknown;
       (testUseUpdate, TestUseUpdate);
       (updateActiveNotifications, ActiveNotifications);
       (updateAnimatedEmojiMessageClicked, AnimatedEmojiMessageClicked);
@@ -220,7 +231,7 @@ impl<'de> Deserialize<'de> for Update {
       (updateAuthorizationState, AuthorizationState);
       (updateBasicGroup, BasicGroup);
       (updateBasicGroupFullInfo, BasicGroupFullInfo);
-      (updateCall, Call);
+      #[cfg(feature = "calls")] (updateCall, Call);
       (updateChatActionBar, ChatActionBar);
       (updateChatDefaultDisableNotification, ChatDefaultDisableNotification);
       (updateChatDraftMessage, ChatDraftMessage);
@@ -248,14 +259,14 @@ impl<'de> Deserialize<'de> for Update {
       (updateConnectionState, ConnectionState);
       (updateDeleteMessages, DeleteMessages);
       (updateDiceEmojis, DiceEmojis);
-      (updateFavoriteStickers, FavoriteStickers);
+      #[cfg(feature = "stickers")] (updateFavoriteStickers, FavoriteStickers);
       (updateFile, File);
       (updateFileGenerationStart, FileGenerationStart);
       (updateFileGenerationStop, FileGenerationStop);
-      (updateGroupCall, GroupCall);
-      (updateGroupCallParticipant, GroupCallParticipant);
+      #[cfg(feature = "calls")] (updateGroupCall, GroupCall);
+      #[cfg(feature = "calls")] (updateGroupCallParticipant, GroupCallParticipant);
       (updateHavePendingNotifications, HavePendingNotifications);
-      (updateInstalledStickerSets, InstalledStickerSets);
+      #[cfg(feature = "stickers")] (updateInstalledStickerSets, InstalledStickerSets);
       (updateLanguagePackStrings, LanguagePackStrings);
       (updateMessageContent, MessageContent);
       (updateMessageContentOpened, MessageContentOpened);
@@ -267,7 +278,7 @@ impl<'de> Deserialize<'de> for Update {
       (updateMessageSendAcknowledged, MessageSendAcknowledged);
       (updateMessageSendFailed, MessageSendFailed);
       (updateMessageSendSucceeded, MessageSendSucceeded);
-      (updateNewCallSignalingData, NewCallSignalingData);
+      #[cfg(feature = "calls")] (updateNewCallSignalingData, NewCallSignalingData);
       (updateNewCallbackQuery, NewCallbackQuery);
       (updateNewChat, NewChat);
       (updateNewChatJoinRequest, NewChatJoinRequest);
@@ -278,24 +289,24 @@ impl<'de> Deserialize<'de> for Update {
       (updateNewInlineQuery, NewInlineQuery);
       (updateNewMessage, NewMessage);
       (updateNewPreCheckoutQuery, NewPreCheckoutQuery);
-      (updateNewShippingQuery, NewShippingQuery);
+      #[cfg(feature = "payments")] (updateNewShippingQuery, NewShippingQuery);
       (updateNotification, Notification);
       (updateNotificationGroup, NotificationGroup);
       (updateOption, Option);
       (updatePoll, Poll);
       (updatePollAnswer, PollAnswer);
-      (updateRecentStickers, RecentStickers);
+      #[cfg(feature = "stickers")] (updateRecentStickers, RecentStickers);
       (updateSavedAnimations, SavedAnimations);
       (updateScopeNotificationSettings, ScopeNotificationSettings);
       (updateSecretChat, SecretChat);
       (updateSelectedBackground, SelectedBackground);
       (updateServiceNotification, ServiceNotification);
-      (updateStickerSet, StickerSet);
+      #[cfg(feature = "stickers")] (updateStickerSet, StickerSet);
       (updateSuggestedActions, SuggestedActions);
       (updateSupergroup, Supergroup);
       (updateSupergroupFullInfo, SupergroupFullInfo);
       (updateTermsOfService, TermsOfService);
-      (updateTrendingStickerSets, TrendingStickerSets);
+      #[cfg(feature = "stickers")] (updateTrendingStickerSets, TrendingStickerSets);
       (updateUnreadChatCount, UnreadChatCount);
       (updateUnreadMessageCount, UnreadMessageCount);
       (updateUser, User);
@@ -310,7 +321,7 @@ impl<'de> Deserialize<'de> for Update {
 }
 
 impl RObject for Update {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       Update::TestUseUpdate(t) => t.td_name(),
       Update::ActiveNotifications(t) => t.td_name(),
@@ -319,7 +330,7 @@ impl RObject for Update {
       Update::AuthorizationState(t) => t.td_name(),
       Update::BasicGroup(t) => t.td_name(),
       Update::BasicGroupFullInfo(t) => t.td_name(),
-      Update::Call(t) => t.td_name(),
+      #[cfg(feature = "calls")] Update::Call(t) => t.td_name(),
       Update::ChatActionBar(t) => t.td_name(),
       Update::ChatDefaultDisableNotification(t) => t.td_name(),
       Update::ChatDraftMessage(t) => t.td_name(),
@@ -347,14 +358,14 @@ impl RObject for Update {
       Update::ConnectionState(t) => t.td_name(),
       Update::DeleteMessages(t) => t.td_name(),
       Update::DiceEmojis(t) => t.td_name(),
-      Update::FavoriteStickers(t) => t.td_name(),
+      #[cfg(feature = "stickers")] Update::FavoriteStickers(t) => t.td_name(),
       Update::File(t) => t.td_name(),
       Update::FileGenerationStart(t) => t.td_name(),
       Update::FileGenerationStop(t) => t.td_name(),
-      Update::GroupCall(t) => t.td_name(),
-      Update::GroupCallParticipant(t) => t.td_name(),
+      #[cfg(feature = "calls")] Update::GroupCall(t) => t.td_name(),
+      #[cfg(feature = "calls")] Update::GroupCallParticipant(t) => t.td_name(),
       Update::HavePendingNotifications(t) => t.td_name(),
-      Update::InstalledStickerSets(t) => t.td_name(),
+      #[cfg(feature = "stickers")] Update::InstalledStickerSets(t) => t.td_name(),
       Update::LanguagePackStrings(t) => t.td_name(),
       Update::MessageContent(t) => t.td_name(),
       Update::MessageContentOpened(t) => t.td_name(),
@@ -366,7 +377,7 @@ impl RObject for Update {
       Update::MessageSendAcknowledged(t) => t.td_name(),
       Update::MessageSendFailed(t) => t.td_name(),
       Update::MessageSendSucceeded(t) => t.td_name(),
-      Update::NewCallSignalingData(t) => t.td_name(),
+      #[cfg(feature = "calls")] Update::NewCallSignalingData(t) => t.td_name(),
       Update::NewCallbackQuery(t) => t.td_name(),
       Update::NewChat(t) => t.td_name(),
       Update::NewChatJoinRequest(t) => t.td_name(),
@@ -377,24 +388,24 @@ impl RObject for Update {
       Update::NewInlineQuery(t) => t.td_name(),
       Update::NewMessage(t) => t.td_name(),
       Update::NewPreCheckoutQuery(t) => t.td_name(),
-      Update::NewShippingQuery(t) => t.td_name(),
+      #[cfg(feature = "payments")] Update::NewShippingQuery(t) => t.td_name(),
       Update::Notification(t) => t.td_name(),
       Update::NotificationGroup(t) => t.td_name(),
       Update::Option(t) => t.td_name(),
       Update::Poll(t) => t.td_name(),
       Update::PollAnswer(t) => t.td_name(),
-      Update::RecentStickers(t) => t.td_name(),
+      #[cfg(feature = "stickers")] Update::RecentStickers(t) => t.td_name(),
       Update::SavedAnimations(t) => t.td_name(),
       Update::ScopeNotificationSettings(t) => t.td_name(),
       Update::SecretChat(t) => t.td_name(),
       Update::SelectedBackground(t) => t.td_name(),
       Update::ServiceNotification(t) => t.td_name(),
-      Update::StickerSet(t) => t.td_name(),
+      #[cfg(feature = "stickers")] Update::StickerSet(t) => t.td_name(),
       Update::SuggestedActions(t) => t.td_name(),
       Update::Supergroup(t) => t.td_name(),
       Update::SupergroupFullInfo(t) => t.td_name(),
       Update::TermsOfService(t) => t.td_name(),
-      Update::TrendingStickerSets(t) => t.td_name(),
+      #[cfg(feature = "stickers")] Update::TrendingStickerSets(t) => t.td_name(),
       Update::UnreadChatCount(t) => t.td_name(),
       Update::UnreadMessageCount(t) => t.td_name(),
       Update::User(t) => t.td_name(),
@@ -403,11 +414,12 @@ impl RObject for Update {
       Update::UserPrivacySettingRules(t) => t.td_name(),
       Update::UserStatus(t) => t.td_name(),
       Update::UsersNearby(t) => t.td_name(),
+      Update::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       Update::TestUseUpdate(t) => t.extra(),
       Update::ActiveNotifications(t) => t.extra(),
@@ -416,7 +428,7 @@ impl RObject for Update {
       Update::AuthorizationState(t) => t.extra(),
       Update::BasicGroup(t) => t.extra(),
       Update::BasicGroupFullInfo(t) => t.extra(),
-      Update::Call(t) => t.extra(),
+      #[cfg(feature = "calls")] Update::Call(t) => t.extra(),
       Update::ChatActionBar(t) => t.extra(),
       Update::ChatDefaultDisableNotification(t) => t.extra(),
       Update::ChatDraftMessage(t) => t.extra(),
@@ -444,14 +456,14 @@ impl RObject for Update {
       Update::ConnectionState(t) => t.extra(),
       Update::DeleteMessages(t) => t.extra(),
       Update::DiceEmojis(t) => t.extra(),
-      Update::FavoriteStickers(t) => t.extra(),
+      #[cfg(feature = "stickers")] Update::FavoriteStickers(t) => t.extra(),
       Update::File(t) => t.extra(),
       Update::FileGenerationStart(t) => t.extra(),
       Update::FileGenerationStop(t) => t.extra(),
-      Update::GroupCall(t) => t.extra(),
-      Update::GroupCallParticipant(t) => t.extra(),
+      #[cfg(feature = "calls")] Update::GroupCall(t) => t.extra(),
+      #[cfg(feature = "calls")] Update::GroupCallParticipant(t) => t.extra(),
       Update::HavePendingNotifications(t) => t.extra(),
-      Update::InstalledStickerSets(t) => t.extra(),
+      #[cfg(feature = "stickers")] Update::InstalledStickerSets(t) => t.extra(),
       Update::LanguagePackStrings(t) => t.extra(),
       Update::MessageContent(t) => t.extra(),
       Update::MessageContentOpened(t) => t.extra(),
@@ -463,7 +475,7 @@ impl RObject for Update {
       Update::MessageSendAcknowledged(t) => t.extra(),
       Update::MessageSendFailed(t) => t.extra(),
       Update::MessageSendSucceeded(t) => t.extra(),
-      Update::NewCallSignalingData(t) => t.extra(),
+      #[cfg(feature = "calls")] Update::NewCallSignalingData(t) => t.extra(),
       Update::NewCallbackQuery(t) => t.extra(),
       Update::NewChat(t) => t.extra(),
       Update::NewChatJoinRequest(t) => t.extra(),
@@ -474,24 +486,24 @@ impl RObject for Update {
       Update::NewInlineQuery(t) => t.extra(),
       Update::NewMessage(t) => t.extra(),
       Update::NewPreCheckoutQuery(t) => t.extra(),
-      Update::NewShippingQuery(t) => t.extra(),
+      #[cfg(feature = "payments")] Update::NewShippingQuery(t) => t.extra(),
       Update::Notification(t) => t.extra(),
       Update::NotificationGroup(t) => t.extra(),
       Update::Option(t) => t.extra(),
       Update::Poll(t) => t.extra(),
       Update::PollAnswer(t) => t.extra(),
-      Update::RecentStickers(t) => t.extra(),
+      #[cfg(feature = "stickers")] Update::RecentStickers(t) => t.extra(),
       Update::SavedAnimations(t) => t.extra(),
       Update::ScopeNotificationSettings(t) => t.extra(),
       Update::SecretChat(t) => t.extra(),
       Update::SelectedBackground(t) => t.extra(),
       Update::ServiceNotification(t) => t.extra(),
-      Update::StickerSet(t) => t.extra(),
+      #[cfg(feature = "stickers")] Update::StickerSet(t) => t.extra(),
       Update::SuggestedActions(t) => t.extra(),
       Update::Supergroup(t) => t.extra(),
       Update::SupergroupFullInfo(t) => t.extra(),
       Update::TermsOfService(t) => t.extra(),
-      Update::TrendingStickerSets(t) => t.extra(),
+      #[cfg(feature = "stickers")] Update::TrendingStickerSets(t) => t.extra(),
       Update::UnreadChatCount(t) => t.extra(),
       Update::UnreadMessageCount(t) => t.extra(),
       Update::User(t) => t.extra(),
@@ -500,6 +512,105 @@ impl RObject for Update {
       Update::UserPrivacySettingRules(t) => t.extra(),
       Update::UserStatus(t) => t.extra(),
       Update::UsersNearby(t) => t.extra(),
+      Update::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      Update::TestUseUpdate(t) => t.client_id(),
+      Update::ActiveNotifications(t) => t.client_id(),
+      Update::AnimatedEmojiMessageClicked(t) => t.client_id(),
+      Update::AnimationSearchParameters(t) => t.client_id(),
+      Update::AuthorizationState(t) => t.client_id(),
+      Update::BasicGroup(t) => t.client_id(),
+      Update::BasicGroupFullInfo(t) => t.client_id(),
+      #[cfg(feature = "calls")] Update::Call(t) => t.client_id(),
+      Update::ChatActionBar(t) => t.client_id(),
+      Update::ChatDefaultDisableNotification(t) => t.client_id(),
+      Update::ChatDraftMessage(t) => t.client_id(),
+      Update::ChatFilters(t) => t.client_id(),
+      Update::ChatHasScheduledMessages(t) => t.client_id(),
+      Update::ChatIsBlocked(t) => t.client_id(),
+      Update::ChatIsMarkedAsUnread(t) => t.client_id(),
+      Update::ChatLastMessage(t) => t.client_id(),
+      Update::ChatMember(t) => t.client_id(),
+      Update::ChatMessageTtlSetting(t) => t.client_id(),
+      Update::ChatNotificationSettings(t) => t.client_id(),
+      Update::ChatOnlineMemberCount(t) => t.client_id(),
+      Update::ChatPendingJoinRequests(t) => t.client_id(),
+      Update::ChatPermissions(t) => t.client_id(),
+      Update::ChatPhoto(t) => t.client_id(),
+      Update::ChatPosition(t) => t.client_id(),
+      Update::ChatReadInbox(t) => t.client_id(),
+      Update::ChatReadOutbox(t) => t.client_id(),
+      Update::ChatReplyMarkup(t) => t.client_id(),
+      Update::ChatTheme(t) => t.client_id(),
+      Update::ChatThemes(t) => t.client_id(),
+      Update::ChatTitle(t) => t.client_id(),
+      Update::ChatUnreadMentionCount(t) => t.client_id(),
+      Update::ChatVideoChat(t) => t.client_id(),
+      Update::ConnectionState(t) => t.client_id(),
+      Update::DeleteMessages(t) => t.client_id(),
+      Update::DiceEmojis(t) => t.client_id(),
+      #[cfg(feature = "stickers")] Update::FavoriteStickers(t) => t.client_id(),
+      Update::File(t) => t.client_id(),
+      Update::FileGenerationStart(t) => t.client_id(),
+      Update::FileGenerationStop(t) => t.client_id(),
+      #[cfg(feature = "calls")] Update::GroupCall(t) => t.client_id(),
+      #[cfg(feature = "calls")] Update::GroupCallParticipant(t) => t.client_id(),
+      Update::HavePendingNotifications(t) => t.client_id(),
+      #[cfg(feature = "stickers")] Update::InstalledStickerSets(t) => t.client_id(),
+      Update::LanguagePackStrings(t) => t.client_id(),
+      Update::MessageContent(t) => t.client_id(),
+      Update::MessageContentOpened(t) => t.client_id(),
+      Update::MessageEdited(t) => t.client_id(),
+      Update::MessageInteractionInfo(t) => t.client_id(),
+      Update::MessageIsPinned(t) => t.client_id(),
+      Update::MessageLiveLocationViewed(t) => t.client_id(),
+      Update::MessageMentionRead(t) => t.client_id(),
+      Update::MessageSendAcknowledged(t) => t.client_id(),
+      Update::MessageSendFailed(t) => t.client_id(),
+      Update::MessageSendSucceeded(t) => t.client_id(),
+      #[cfg(feature = "calls")] Update::NewCallSignalingData(t) => t.client_id(),
+      Update::NewCallbackQuery(t) => t.client_id(),
+      Update::NewChat(t) => t.client_id(),
+      Update::NewChatJoinRequest(t) => t.client_id(),
+      Update::NewChosenInlineResult(t) => t.client_id(),
+      Update::NewCustomEvent(t) => t.client_id(),
+      Update::NewCustomQuery(t) => t.client_id(),
+      Update::NewInlineCallbackQuery(t) => t.client_id(),
+      Update::NewInlineQuery(t) => t.client_id(),
+      Update::NewMessage(t) => t.client_id(),
+      Update::NewPreCheckoutQuery(t) => t.client_id(),
+      #[cfg(feature = "payments")] Update::NewShippingQuery(t) => t.client_id(),
+      Update::Notification(t) => t.client_id(),
+      Update::NotificationGroup(t) => t.client_id(),
+      Update::Option(t) => t.client_id(),
+      Update::Poll(t) => t.client_id(),
+      Update::PollAnswer(t) => t.client_id(),
+      #[cfg(feature = "stickers")] Update::RecentStickers(t) => t.client_id(),
+      Update::SavedAnimations(t) => t.client_id(),
+      Update::ScopeNotificationSettings(t) => t.client_id(),
+      Update::SecretChat(t) => t.client_id(),
+      Update::SelectedBackground(t) => t.client_id(),
+      Update::ServiceNotification(t) => t.client_id(),
+      #[cfg(feature = "stickers")] Update::StickerSet(t) => t.client_id(),
+      Update::SuggestedActions(t) => t.client_id(),
+      Update::Supergroup(t) => t.client_id(),
+      Update::SupergroupFullInfo(t) => t.client_id(),
+      Update::TermsOfService(t) => t.client_id(),
+      #[cfg(feature = "stickers")] Update::TrendingStickerSets(t) => t.client_id(),
+      Update::UnreadChatCount(t) => t.client_id(),
+      Update::UnreadMessageCount(t) => t.client_id(),
+      Update::User(t) => t.client_id(),
+      Update::UserChatAction(t) => t.client_id(),
+      Update::UserFullInfo(t) => t.client_id(),
+      Update::UserPrivacySettingRules(t) => t.client_id(),
+      Update::UserStatus(t) => t.client_id(),
+      Update::UsersNearby(t) => t.client_id(),
+      Update::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -508,100 +619,101 @@ impl RObject for Update {
 }
 
 impl Update {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let Update::_Default(_) = self { true } else { false } }
-
-  pub fn is_test_use_update(&self) -> bool { if let Update::TestUseUpdate(_) = self { true } else { false } }
-  pub fn is_active_notifications(&self) -> bool { if let Update::ActiveNotifications(_) = self { true } else { false } }
-  pub fn is_animated_emoji_message_clicked(&self) -> bool { if let Update::AnimatedEmojiMessageClicked(_) = self { true } else { false } }
-  pub fn is_animation_search_parameters(&self) -> bool { if let Update::AnimationSearchParameters(_) = self { true } else { false } }
-  pub fn is_authorization_state(&self) -> bool { if let Update::AuthorizationState(_) = self { true } else { false } }
-  pub fn is_basic_group(&self) -> bool { if let Update::BasicGroup(_) = self { true } else { false } }
-  pub fn is_basic_group_full_info(&self) -> bool { if let Update::BasicGroupFullInfo(_) = self { true } else { false } }
-  pub fn is_call(&self) -> bool { if let Update::Call(_) = self { true } else { false } }
-  pub fn is_chat_action_bar(&self) -> bool { if let Update::ChatActionBar(_) = self { true } else { false } }
-  pub fn is_chat_default_disable_notification(&self) -> bool { if let Update::ChatDefaultDisableNotification(_) = self { true } else { false } }
-  pub fn is_chat_draft_message(&self) -> bool { if let Update::ChatDraftMessage(_) = self { true } else { false } }
-  pub fn is_chat_filters(&self) -> bool { if let Update::ChatFilters(_) = self { true } else { false } }
-  pub fn is_chat_has_scheduled_messages(&self) -> bool { if let Update::ChatHasScheduledMessages(_) = self { true } else { false } }
-  pub fn is_chat_is_blocked(&self) -> bool { if let Update::ChatIsBlocked(_) = self { true } else { false } }
-  pub fn is_chat_is_marked_as_unread(&self) -> bool { if let Update::ChatIsMarkedAsUnread(_) = self { true } else { false } }
-  pub fn is_chat_last_message(&self) -> bool { if let Update::ChatLastMessage(_) = self { true } else { false } }
-  pub fn is_chat_member(&self) -> bool { if let Update::ChatMember(_) = self { true } else { false } }
-  pub fn is_chat_message_ttl_setting(&self) -> bool { if let Update::ChatMessageTtlSetting(_) = self { true } else { false } }
-  pub fn is_chat_notification_settings(&self) -> bool { if let Update::ChatNotificationSettings(_) = self { true } else { false } }
-  pub fn is_chat_online_member_count(&self) -> bool { if let Update::ChatOnlineMemberCount(_) = self { true } else { false } }
-  pub fn is_chat_pending_join_requests(&self) -> bool { if let Update::ChatPendingJoinRequests(_) = self { true } else { false } }
-  pub fn is_chat_permissions(&self) -> bool { if let Update::ChatPermissions(_) = self { true } else { false } }
-  pub fn is_chat_photo(&self) -> bool { if let Update::ChatPhoto(_) = self { true } else { false } }
-  pub fn is_chat_position(&self) -> bool { if let Update::ChatPosition(_) = self { true } else { false } }
-  pub fn is_chat_read_inbox(&self) -> bool { if let Update::ChatReadInbox(_) = self { true } else { false } }
-  pub fn is_chat_read_outbox(&self) -> bool { if let Update::ChatReadOutbox(_) = self { true } else { false } }
-  pub fn is_chat_reply_markup(&self) -> bool { if let Update::ChatReplyMarkup(_) = self { true } else { false } }
-  pub fn is_chat_theme(&self) -> bool { if let Update::ChatTheme(_) = self { true } else { false } }
-  pub fn is_chat_themes(&self) -> bool { if let Update::ChatThemes(_) = self { true } else { false } }
-  pub fn is_chat_title(&self) -> bool { if let Update::ChatTitle(_) = self { true } else { false } }
-  pub fn is_chat_unread_mention_count(&self) -> bool { if let Update::ChatUnreadMentionCount(_) = self { true } else { false } }
-  pub fn is_chat_video_chat(&self) -> bool { if let Update::ChatVideoChat(_) = self { true } else { false } }
-  pub fn is_connection_state(&self) -> bool { if let Update::ConnectionState(_) = self { true } else { false } }
-  pub fn is_delete_messages(&self) -> bool { if let Update::DeleteMessages(_) = self { true } else { false } }
-  pub fn is_dice_emojis(&self) -> bool { if let Update::DiceEmojis(_) = self { true } else { false } }
-  pub fn is_favorite_stickers(&self) -> bool { if let Update::FavoriteStickers(_) = self { true } else { false } }
-  pub fn is_file(&self) -> bool { if let Update::File(_) = self { true } else { false } }
-  pub fn is_file_generation_start(&self) -> bool { if let Update::FileGenerationStart(_) = self { true } else { false } }
-  pub fn is_file_generation_stop(&self) -> bool { if let Update::FileGenerationStop(_) = self { true } else { false } }
-  pub fn is_group_call(&self) -> bool { if let Update::GroupCall(_) = self { true } else { false } }
-  pub fn is_group_call_participant(&self) -> bool { if let Update::GroupCallParticipant(_) = self { true } else { false } }
-  pub fn is_have_pending_notifications(&self) -> bool { if let Update::HavePendingNotifications(_) = self { true } else { false } }
-  pub fn is_installed_sticker_sets(&self) -> bool { if let Update::InstalledStickerSets(_) = self { true } else { false } }
-  pub fn is_language_pack_strings(&self) -> bool { if let Update::LanguagePackStrings(_) = self { true } else { false } }
-  pub fn is_message_content(&self) -> bool { if let Update::MessageContent(_) = self { true } else { false } }
-  pub fn is_message_content_opened(&self) -> bool { if let Update::MessageContentOpened(_) = self { true } else { false } }
-  pub fn is_message_edited(&self) -> bool { if let Update::MessageEdited(_) = self { true } else { false } }
-  pub fn is_message_interaction_info(&self) -> bool { if let Update::MessageInteractionInfo(_) = self { true } else { false } }
-  pub fn is_message_is_pinned(&self) -> bool { if let Update::MessageIsPinned(_) = self { true } else { false } }
-  pub fn is_message_live_location_viewed(&self) -> bool { if let Update::MessageLiveLocationViewed(_) = self { true } else { false } }
-  pub fn is_message_mention_read(&self) -> bool { if let Update::MessageMentionRead(_) = self { true } else { false } }
-  pub fn is_message_send_acknowledged(&self) -> bool { if let Update::MessageSendAcknowledged(_) = self { true } else { false } }
-  pub fn is_message_send_failed(&self) -> bool { if let Update::MessageSendFailed(_) = self { true } else { false } }
-  pub fn is_message_send_succeeded(&self) -> bool { if let Update::MessageSendSucceeded(_) = self { true } else { false } }
-  pub fn is_new_call_signaling_data(&self) -> bool { if let Update::NewCallSignalingData(_) = self { true } else { false } }
-  pub fn is_new_callback_query(&self) -> bool { if let Update::NewCallbackQuery(_) = self { true } else { false } }
-  pub fn is_new_chat(&self) -> bool { if let Update::NewChat(_) = self { true } else { false } }
-  pub fn is_new_chat_join_request(&self) -> bool { if let Update::NewChatJoinRequest(_) = self { true } else { false } }
-  pub fn is_new_chosen_inline_result(&self) -> bool { if let Update::NewChosenInlineResult(_) = self { true } else { false } }
-  pub fn is_new_custom_event(&self) -> bool { if let Update::NewCustomEvent(_) = self { true } else { false } }
-  pub fn is_new_custom_query(&self) -> bool { if let Update::NewCustomQuery(_) = self { true } else { false } }
-  pub fn is_new_inline_callback_query(&self) -> bool { if let Update::NewInlineCallbackQuery(_) = self { true } else { false } }
-  pub fn is_new_inline_query(&self) -> bool { if let Update::NewInlineQuery(_) = self { true } else { false } }
-  pub fn is_new_message(&self) -> bool { if let Update::NewMessage(_) = self { true } else { false } }
-  pub fn is_new_pre_checkout_query(&self) -> bool { if let Update::NewPreCheckoutQuery(_) = self { true } else { false } }
-  pub fn is_new_shipping_query(&self) -> bool { if let Update::NewShippingQuery(_) = self { true } else { false } }
-  pub fn is_notification(&self) -> bool { if let Update::Notification(_) = self { true } else { false } }
-  pub fn is_notification_group(&self) -> bool { if let Update::NotificationGroup(_) = self { true } else { false } }
-  pub fn is_option(&self) -> bool { if let Update::Option(_) = self { true } else { false } }
-  pub fn is_poll(&self) -> bool { if let Update::Poll(_) = self { true } else { false } }
-  pub fn is_poll_answer(&self) -> bool { if let Update::PollAnswer(_) = self { true } else { false } }
-  pub fn is_recent_stickers(&self) -> bool { if let Update::RecentStickers(_) = self { true } else { false } }
-  pub fn is_saved_animations(&self) -> bool { if let Update::SavedAnimations(_) = self { true } else { false } }
-  pub fn is_scope_notification_settings(&self) -> bool { if let Update::ScopeNotificationSettings(_) = self { true } else { false } }
-  pub fn is_secret_chat(&self) -> bool { if let Update::SecretChat(_) = self { true } else { false } }
-  pub fn is_selected_background(&self) -> bool { if let Update::SelectedBackground(_) = self { true } else { false } }
-  pub fn is_service_notification(&self) -> bool { if let Update::ServiceNotification(_) = self { true } else { false } }
-  pub fn is_sticker_set(&self) -> bool { if let Update::StickerSet(_) = self { true } else { false } }
-  pub fn is_suggested_actions(&self) -> bool { if let Update::SuggestedActions(_) = self { true } else { false } }
-  pub fn is_supergroup(&self) -> bool { if let Update::Supergroup(_) = self { true } else { false } }
-  pub fn is_supergroup_full_info(&self) -> bool { if let Update::SupergroupFullInfo(_) = self { true } else { false } }
-  pub fn is_terms_of_service(&self) -> bool { if let Update::TermsOfService(_) = self { true } else { false } }
-  pub fn is_trending_sticker_sets(&self) -> bool { if let Update::TrendingStickerSets(_) = self { true } else { false } }
-  pub fn is_unread_chat_count(&self) -> bool { if let Update::UnreadChatCount(_) = self { true } else { false } }
-  pub fn is_unread_message_count(&self) -> bool { if let Update::UnreadMessageCount(_) = self { true } else { false } }
-  pub fn is_user(&self) -> bool { if let Update::User(_) = self { true } else { false } }
-  pub fn is_user_chat_action(&self) -> bool { if let Update::UserChatAction(_) = self { true } else { false } }
-  pub fn is_user_full_info(&self) -> bool { if let Update::UserFullInfo(_) = self { true } else { false } }
-  pub fn is_user_privacy_setting_rules(&self) -> bool { if let Update::UserPrivacySettingRules(_) = self { true } else { false } }
-  pub fn is_user_status(&self) -> bool { if let Update::UserStatus(_) = self { true } else { false } }
-  pub fn is_users_nearby(&self) -> bool { if let Update::UsersNearby(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, Update::_Default(_)) }
+
+  pub fn is_test_use_update(&self) -> bool { matches!(self, Update::TestUseUpdate(_)) }
+  pub fn is_active_notifications(&self) -> bool { matches!(self, Update::ActiveNotifications(_)) }
+  pub fn is_animated_emoji_message_clicked(&self) -> bool { matches!(self, Update::AnimatedEmojiMessageClicked(_)) }
+  pub fn is_animation_search_parameters(&self) -> bool { matches!(self, Update::AnimationSearchParameters(_)) }
+  pub fn is_authorization_state(&self) -> bool { matches!(self, Update::AuthorizationState(_)) }
+  pub fn is_basic_group(&self) -> bool { matches!(self, Update::BasicGroup(_)) }
+  pub fn is_basic_group_full_info(&self) -> bool { matches!(self, Update::BasicGroupFullInfo(_)) }
+  #[cfg(feature = "calls")] pub fn is_call(&self) -> bool { matches!(self, Update::Call(_)) }
+  pub fn is_chat_action_bar(&self) -> bool { matches!(self, Update::ChatActionBar(_)) }
+  pub fn is_chat_default_disable_notification(&self) -> bool { matches!(self, Update::ChatDefaultDisableNotification(_)) }
+  pub fn is_chat_draft_message(&self) -> bool { matches!(self, Update::ChatDraftMessage(_)) }
+  pub fn is_chat_filters(&self) -> bool { matches!(self, Update::ChatFilters(_)) }
+  pub fn is_chat_has_scheduled_messages(&self) -> bool { matches!(self, Update::ChatHasScheduledMessages(_)) }
+  pub fn is_chat_is_blocked(&self) -> bool { matches!(self, Update::ChatIsBlocked(_)) }
+  pub fn is_chat_is_marked_as_unread(&self) -> bool { matches!(self, Update::ChatIsMarkedAsUnread(_)) }
+  pub fn is_chat_last_message(&self) -> bool { matches!(self, Update::ChatLastMessage(_)) }
+  pub fn is_chat_member(&self) -> bool { matches!(self, Update::ChatMember(_)) }
+  pub fn is_chat_message_ttl_setting(&self) -> bool { matches!(self, Update::ChatMessageTtlSetting(_)) }
+  pub fn is_chat_notification_settings(&self) -> bool { matches!(self, Update::ChatNotificationSettings(_)) }
+  pub fn is_chat_online_member_count(&self) -> bool { matches!(self, Update::ChatOnlineMemberCount(_)) }
+  pub fn is_chat_pending_join_requests(&self) -> bool { matches!(self, Update::ChatPendingJoinRequests(_)) }
+  pub fn is_chat_permissions(&self) -> bool { matches!(self, Update::ChatPermissions(_)) }
+  pub fn is_chat_photo(&self) -> bool { matches!(self, Update::ChatPhoto(_)) }
+  pub fn is_chat_position(&self) -> bool { matches!(self, Update::ChatPosition(_)) }
+  pub fn is_chat_read_inbox(&self) -> bool { matches!(self, Update::ChatReadInbox(_)) }
+  pub fn is_chat_read_outbox(&self) -> bool { matches!(self, Update::ChatReadOutbox(_)) }
+  pub fn is_chat_reply_markup(&self) -> bool { matches!(self, Update::ChatReplyMarkup(_)) }
+  pub fn is_chat_theme(&self) -> bool { matches!(self, Update::ChatTheme(_)) }
+  pub fn is_chat_themes(&self) -> bool { matches!(self, Update::ChatThemes(_)) }
+  pub fn is_chat_title(&self) -> bool { matches!(self, Update::ChatTitle(_)) }
+  pub fn is_chat_unread_mention_count(&self) -> bool { matches!(self, Update::ChatUnreadMentionCount(_)) }
+  pub fn is_chat_video_chat(&self) -> bool { matches!(self, Update::ChatVideoChat(_)) }
+  pub fn is_connection_state(&self) -> bool { matches!(self, Update::ConnectionState(_)) }
+  pub fn is_delete_messages(&self) -> bool { matches!(self, Update::DeleteMessages(_)) }
+  pub fn is_dice_emojis(&self) -> bool { matches!(self, Update::DiceEmojis(_)) }
+  #[cfg(feature = "stickers")] pub fn is_favorite_stickers(&self) -> bool { matches!(self, Update::FavoriteStickers(_)) }
+  pub fn is_file(&self) -> bool { matches!(self, Update::File(_)) }
+  pub fn is_file_generation_start(&self) -> bool { matches!(self, Update::FileGenerationStart(_)) }
+  pub fn is_file_generation_stop(&self) -> bool { matches!(self, Update::FileGenerationStop(_)) }
+  #[cfg(feature = "calls")] pub fn is_group_call(&self) -> bool { matches!(self, Update::GroupCall(_)) }
+  #[cfg(feature = "calls")] pub fn is_group_call_participant(&self) -> bool { matches!(self, Update::GroupCallParticipant(_)) }
+  pub fn is_have_pending_notifications(&self) -> bool { matches!(self, Update::HavePendingNotifications(_)) }
+  #[cfg(feature = "stickers")] pub fn is_installed_sticker_sets(&self) -> bool { matches!(self, Update::InstalledStickerSets(_)) }
+  pub fn is_language_pack_strings(&self) -> bool { matches!(self, Update::LanguagePackStrings(_)) }
+  pub fn is_message_content(&self) -> bool { matches!(self, Update::MessageContent(_)) }
+  pub fn is_message_content_opened(&self) -> bool { matches!(self, Update::MessageContentOpened(_)) }
+  pub fn is_message_edited(&self) -> bool { matches!(self, Update::MessageEdited(_)) }
+  pub fn is_message_interaction_info(&self) -> bool { matches!(self, Update::MessageInteractionInfo(_)) }
+  pub fn is_message_is_pinned(&self) -> bool { matches!(self, Update::MessageIsPinned(_)) }
+  pub fn is_message_live_location_viewed(&self) -> bool { matches!(self, Update::MessageLiveLocationViewed(_)) }
+  pub fn is_message_mention_read(&self) -> bool { matches!(self, Update::MessageMentionRead(_)) }
+  pub fn is_message_send_acknowledged(&self) -> bool { matches!(self, Update::MessageSendAcknowledged(_)) }
+  pub fn is_message_send_failed(&self) -> bool { matches!(self, Update::MessageSendFailed(_)) }
+  pub fn is_message_send_succeeded(&self) -> bool { matches!(self, Update::MessageSendSucceeded(_)) }
+  #[cfg(feature = "calls")] pub fn is_new_call_signaling_data(&self) -> bool { matches!(self, Update::NewCallSignalingData(_)) }
+  pub fn is_new_callback_query(&self) -> bool { matches!(self, Update::NewCallbackQuery(_)) }
+  pub fn is_new_chat(&self) -> bool { matches!(self, Update::NewChat(_)) }
+  pub fn is_new_chat_join_request(&self) -> bool { matches!(self, Update::NewChatJoinRequest(_)) }
+  pub fn is_new_chosen_inline_result(&self) -> bool { matches!(self, Update::NewChosenInlineResult(_)) }
+  pub fn is_new_custom_event(&self) -> bool { matches!(self, Update::NewCustomEvent(_)) }
+  pub fn is_new_custom_query(&self) -> bool { matches!(self, Update::NewCustomQuery(_)) }
+  pub fn is_new_inline_callback_query(&self) -> bool { matches!(self, Update::NewInlineCallbackQuery(_)) }
+  pub fn is_new_inline_query(&self) -> bool { matches!(self, Update::NewInlineQuery(_)) }
+  pub fn is_new_message(&self) -> bool { matches!(self, Update::NewMessage(_)) }
+  pub fn is_new_pre_checkout_query(&self) -> bool { matches!(self, Update::NewPreCheckoutQuery(_)) }
+  #[cfg(feature = "payments")] pub fn is_new_shipping_query(&self) -> bool { matches!(self, Update::NewShippingQuery(_)) }
+  pub fn is_notification(&self) -> bool { matches!(self, Update::Notification(_)) }
+  pub fn is_notification_group(&self) -> bool { matches!(self, Update::NotificationGroup(_)) }
+  pub fn is_option(&self) -> bool { matches!(self, Update::Option(_)) }
+  pub fn is_poll(&self) -> bool { matches!(self, Update::Poll(_)) }
+  pub fn is_poll_answer(&self) -> bool { matches!(self, Update::PollAnswer(_)) }
+  #[cfg(feature = "stickers")] pub fn is_recent_stickers(&self) -> bool { matches!(self, Update::RecentStickers(_)) }
+  pub fn is_saved_animations(&self) -> bool { matches!(self, Update::SavedAnimations(_)) }
+  pub fn is_scope_notification_settings(&self) -> bool { matches!(self, Update::ScopeNotificationSettings(_)) }
+  pub fn is_secret_chat(&self) -> bool { matches!(self, Update::SecretChat(_)) }
+  pub fn is_selected_background(&self) -> bool { matches!(self, Update::SelectedBackground(_)) }
+  pub fn is_service_notification(&self) -> bool { matches!(self, Update::ServiceNotification(_)) }
+  #[cfg(feature = "stickers")] pub fn is_sticker_set(&self) -> bool { matches!(self, Update::StickerSet(_)) }
+  pub fn is_suggested_actions(&self) -> bool { matches!(self, Update::SuggestedActions(_)) }
+  pub fn is_supergroup(&self) -> bool { matches!(self, Update::Supergroup(_)) }
+  pub fn is_supergroup_full_info(&self) -> bool { matches!(self, Update::SupergroupFullInfo(_)) }
+  pub fn is_terms_of_service(&self) -> bool { matches!(self, Update::TermsOfService(_)) }
+  #[cfg(feature = "stickers")] pub fn is_trending_sticker_sets(&self) -> bool { matches!(self, Update::TrendingStickerSets(_)) }
+  pub fn is_unread_chat_count(&self) -> bool { matches!(self, Update::UnreadChatCount(_)) }
+  pub fn is_unread_message_count(&self) -> bool { matches!(self, Update::UnreadMessageCount(_)) }
+  pub fn is_user(&self) -> bool { matches!(self, Update::User(_)) }
+  pub fn is_user_chat_action(&self) -> bool { matches!(self, Update::UserChatAction(_)) }
+  pub fn is_user_full_info(&self) -> bool { matches!(self, Update::UserFullInfo(_)) }
+  pub fn is_user_privacy_setting_rules(&self) -> bool { matches!(self, Update::UserPrivacySettingRules(_)) }
+  pub fn is_user_status(&self) -> bool { matches!(self, Update::UserStatus(_)) }
+  pub fn is_users_nearby(&self) -> bool { matches!(self, Update::UsersNearby(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, Update::Unknown(_)) }
 
   pub fn on_test_use_update<F: FnOnce(&TestUseUpdate)>(&self, fnc: F) -> &Self { if let Update::TestUseUpdate(t) = self { fnc(t) }; self }
   pub fn on_active_notifications<F: FnOnce(&UpdateActiveNotifications)>(&self, fnc: F) -> &Self { if let Update::ActiveNotifications(t) = self { fnc(t) }; self }
@@ -610,7 +722,7 @@ impl Update {
   pub fn on_authorization_state<F: FnOnce(&UpdateAuthorizationState)>(&self, fnc: F) -> &Self { if let Update::AuthorizationState(t) = self { fnc(t) }; self }
   pub fn on_basic_group<F: FnOnce(&UpdateBasicGroup)>(&self, fnc: F) -> &Self { if let Update::BasicGroup(t) = self { fnc(t) }; self }
   pub fn on_basic_group_full_info<F: FnOnce(&UpdateBasicGroupFullInfo)>(&self, fnc: F) -> &Self { if let Update::BasicGroupFullInfo(t) = self { fnc(t) }; self }
-  pub fn on_call<F: FnOnce(&UpdateCall)>(&self, fnc: F) -> &Self { if let Update::Call(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_call<F: FnOnce(&UpdateCall)>(&self, fnc: F) -> &Self { if let Update::Call(t) = self { fnc(t) }; self }
   pub fn on_chat_action_bar<F: FnOnce(&UpdateChatActionBar)>(&self, fnc: F) -> &Self { if let Update::ChatActionBar(t) = self { fnc(t) }; self }
   pub fn on_chat_default_disable_notification<F: FnOnce(&UpdateChatDefaultDisableNotification)>(&self, fnc: F) -> &Self { if let Update::ChatDefaultDisableNotification(t) = self { fnc(t) }; self }
   pub fn on_chat_draft_message<F: FnOnce(&UpdateChatDraftMessage)>(&self, fnc: F) -> &Self { if let Update::ChatDraftMessage(t) = self { fnc(t) }; self }
@@ -638,14 +750,14 @@ impl Update {
   pub fn on_connection_state<F: FnOnce(&UpdateConnectionState)>(&self, fnc: F) -> &Self { if let Update::ConnectionState(t) = self { fnc(t) }; self }
   pub fn on_delete_messages<F: FnOnce(&UpdateDeleteMessages)>(&self, fnc: F) -> &Self { if let Update::DeleteMessages(t) = self { fnc(t) }; self }
   pub fn on_dice_emojis<F: FnOnce(&UpdateDiceEmojis)>(&self, fnc: F) -> &Self { if let Update::DiceEmojis(t) = self { fnc(t) }; self }
-  pub fn on_favorite_stickers<F: FnOnce(&UpdateFavoriteStickers)>(&self, fnc: F) -> &Self { if let Update::FavoriteStickers(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_favorite_stickers<F: FnOnce(&UpdateFavoriteStickers)>(&self, fnc: F) -> &Self { if let Update::FavoriteStickers(t) = self { fnc(t) }; self }
   pub fn on_file<F: FnOnce(&UpdateFile)>(&self, fnc: F) -> &Self { if let Update::File(t) = self { fnc(t) }; self }
   pub fn on_file_generation_start<F: FnOnce(&UpdateFileGenerationStart)>(&self, fnc: F) -> &Self { if let Update::FileGenerationStart(t) = self { fnc(t) }; self }
   pub fn on_file_generation_stop<F: FnOnce(&UpdateFileGenerationStop)>(&self, fnc: F) -> &Self { if let Update::FileGenerationStop(t) = self { fnc(t) }; self }
-  pub fn on_group_call<F: FnOnce(&UpdateGroupCall)>(&self, fnc: F) -> &Self { if let Update::GroupCall(t) = self { fnc(t) }; self }
-  pub fn on_group_call_participant<F: FnOnce(&UpdateGroupCallParticipant)>(&self, fnc: F) -> &Self { if let Update::GroupCallParticipant(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_group_call<F: FnOnce(&UpdateGroupCall)>(&self, fnc: F) -> &Self { if let Update::GroupCall(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_group_call_participant<F: FnOnce(&UpdateGroupCallParticipant)>(&self, fnc: F) -> &Self { if let Update::GroupCallParticipant(t) = self { fnc(t) }; self }
   pub fn on_have_pending_notifications<F: FnOnce(&UpdateHavePendingNotifications)>(&self, fnc: F) -> &Self { if let Update::HavePendingNotifications(t) = self { fnc(t) }; self }
-  pub fn on_installed_sticker_sets<F: FnOnce(&UpdateInstalledStickerSets)>(&self, fnc: F) -> &Self { if let Update::InstalledStickerSets(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_installed_sticker_sets<F: FnOnce(&UpdateInstalledStickerSets)>(&self, fnc: F) -> &Self { if let Update::InstalledStickerSets(t) = self { fnc(t) }; self }
   pub fn on_language_pack_strings<F: FnOnce(&UpdateLanguagePackStrings)>(&self, fnc: F) -> &Self { if let Update::LanguagePackStrings(t) = self { fnc(t) }; self }
   pub fn on_message_content<F: FnOnce(&UpdateMessageContent)>(&self, fnc: F) -> &Self { if let Update::MessageContent(t) = self { fnc(t) }; self }
   pub fn on_message_content_opened<F: FnOnce(&UpdateMessageContentOpened)>(&self, fnc: F) -> &Self { if let Update::MessageContentOpened(t) = self { fnc(t) }; self }
@@ -657,7 +769,7 @@ impl Update {
   pub fn on_message_send_acknowledged<F: FnOnce(&UpdateMessageSendAcknowledged)>(&self, fnc: F) -> &Self { if let Update::MessageSendAcknowledged(t) = self { fnc(t) }; self }
   pub fn on_message_send_failed<F: FnOnce(&UpdateMessageSendFailed)>(&self, fnc: F) -> &Self { if let Update::MessageSendFailed(t) = self { fnc(t) }; self }
   pub fn on_message_send_succeeded<F: FnOnce(&UpdateMessageSendSucceeded)>(&self, fnc: F) -> &Self { if let Update::MessageSendSucceeded(t) = self { fnc(t) }; self }
-  pub fn on_new_call_signaling_data<F: FnOnce(&UpdateNewCallSignalingData)>(&self, fnc: F) -> &Self { if let Update::NewCallSignalingData(t) = self { fnc(t) }; self }
+  #[cfg(feature = "calls")] pub fn on_new_call_signaling_data<F: FnOnce(&UpdateNewCallSignalingData)>(&self, fnc: F) -> &Self { if let Update::NewCallSignalingData(t) = self { fnc(t) }; self }
   pub fn on_new_callback_query<F: FnOnce(&UpdateNewCallbackQuery)>(&self, fnc: F) -> &Self { if let Update::NewCallbackQuery(t) = self { fnc(t) }; self }
   pub fn on_new_chat<F: FnOnce(&UpdateNewChat)>(&self, fnc: F) -> &Self { if let Update::NewChat(t) = self { fnc(t) }; self }
   pub fn on_new_chat_join_request<F: FnOnce(&UpdateNewChatJoinRequest)>(&self, fnc: F) -> &Self { if let Update::NewChatJoinRequest(t) = self { fnc(t) }; self }
@@ -668,24 +780,24 @@ impl Update {
   pub fn on_new_inline_query<F: FnOnce(&UpdateNewInlineQuery)>(&self, fnc: F) -> &Self { if let Update::NewInlineQuery(t) = self { fnc(t) }; self }
   pub fn on_new_message<F: FnOnce(&UpdateNewMessage)>(&self, fnc: F) -> &Self { if let Update::NewMessage(t) = self { fnc(t) }; self }
   pub fn on_new_pre_checkout_query<F: FnOnce(&UpdateNewPreCheckoutQuery)>(&self, fnc: F) -> &Self { if let Update::NewPreCheckoutQuery(t) = self { fnc(t) }; self }
-  pub fn on_new_shipping_query<F: FnOnce(&UpdateNewShippingQuery)>(&self, fnc: F) -> &Self { if let Update::NewShippingQuery(t) = self { fnc(t) }; self }
+  #[cfg(feature = "payments")] pub fn on_new_shipping_query<F: FnOnce(&UpdateNewShippingQuery)>(&self, fnc: F) -> &Self { if let Update::NewShippingQuery(t) = self { fnc(t) }; self }
   pub fn on_notification<F: FnOnce(&UpdateNotification)>(&self, fnc: F) -> &Self { if let Update::Notification(t) = self { fnc(t) }; self }
   pub fn on_notification_group<F: FnOnce(&UpdateNotificationGroup)>(&self, fnc: F) -> &Self { if let Update::NotificationGroup(t) = self { fnc(t) }; self }
   pub fn on_option<F: FnOnce(&UpdateOption)>(&self, fnc: F) -> &Self { if let Update::Option(t) = self { fnc(t) }; self }
   pub fn on_poll<F: FnOnce(&UpdatePoll)>(&self, fnc: F) -> &Self { if let Update::Poll(t) = self { fnc(t) }; self }
   pub fn on_poll_answer<F: FnOnce(&UpdatePollAnswer)>(&self, fnc: F) -> &Self { if let Update::PollAnswer(t) = self { fnc(t) }; self }
-  pub fn on_recent_stickers<F: FnOnce(&UpdateRecentStickers)>(&self, fnc: F) -> &Self { if let Update::RecentStickers(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_recent_stickers<F: FnOnce(&UpdateRecentStickers)>(&self, fnc: F) -> &Self { if let Update::RecentStickers(t) = self { fnc(t) }; self }
   pub fn on_saved_animations<F: FnOnce(&UpdateSavedAnimations)>(&self, fnc: F) -> &Self { if let Update::SavedAnimations(t) = self { fnc(t) }; self }
   pub fn on_scope_notification_settings<F: FnOnce(&UpdateScopeNotificationSettings)>(&self, fnc: F) -> &Self { if let Update::ScopeNotificationSettings(t) = self { fnc(t) }; self }
   pub fn on_secret_chat<F: FnOnce(&UpdateSecretChat)>(&self, fnc: F) -> &Self { if let Update::SecretChat(t) = self { fnc(t) }; self }
   pub fn on_selected_background<F: FnOnce(&UpdateSelectedBackground)>(&self, fnc: F) -> &Self { if let Update::SelectedBackground(t) = self { fnc(t) }; self }
   pub fn on_service_notification<F: FnOnce(&UpdateServiceNotification)>(&self, fnc: F) -> &Self { if let Update::ServiceNotification(t) = self { fnc(t) }; self }
-  pub fn on_sticker_set<F: FnOnce(&UpdateStickerSet)>(&self, fnc: F) -> &Self { if let Update::StickerSet(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_sticker_set<F: FnOnce(&UpdateStickerSet)>(&self, fnc: F) -> &Self { if let Update::StickerSet(t) = self { fnc(t) }; self }
   pub fn on_suggested_actions<F: FnOnce(&UpdateSuggestedActions)>(&self, fnc: F) -> &Self { if let Update::SuggestedActions(t) = self { fnc(t) }; self }
   pub fn on_supergroup<F: FnOnce(&UpdateSupergroup)>(&self, fnc: F) -> &Self { if let Update::Supergroup(t) = self { fnc(t) }; self }
   pub fn on_supergroup_full_info<F: FnOnce(&UpdateSupergroupFullInfo)>(&self, fnc: F) -> &Self { if let Update::SupergroupFullInfo(t) = self { fnc(t) }; self }
   pub fn on_terms_of_service<F: FnOnce(&UpdateTermsOfService)>(&self, fnc: F) -> &Self { if let Update::TermsOfService(t) = self { fnc(t) }; self }
-  pub fn on_trending_sticker_sets<F: FnOnce(&UpdateTrendingStickerSets)>(&self, fnc: F) -> &Self { if let Update::TrendingStickerSets(t) = self { fnc(t) }; self }
+  #[cfg(feature = "stickers")] pub fn on_trending_sticker_sets<F: FnOnce(&UpdateTrendingStickerSets)>(&self, fnc: F) -> &Self { if let Update::TrendingStickerSets(t) = self { fnc(t) }; self }
   pub fn on_unread_chat_count<F: FnOnce(&UpdateUnreadChatCount)>(&self, fnc: F) -> &Self { if let Update::UnreadChatCount(t) = self { fnc(t) }; self }
   pub fn on_unread_message_count<F: FnOnce(&UpdateUnreadMessageCount)>(&self, fnc: F) -> &Self { if let Update::UnreadMessageCount(t) = self { fnc(t) }; self }
   pub fn on_user<F: FnOnce(&UpdateUser)>(&self, fnc: F) -> &Self { if let Update::User(t) = self { fnc(t) }; self }
@@ -694,6 +806,7 @@ impl Update {
   pub fn on_user_privacy_setting_rules<F: FnOnce(&UpdateUserPrivacySettingRules)>(&self, fnc: F) -> &Self { if let Update::UserPrivacySettingRules(t) = self { fnc(t) }; self }
   pub fn on_user_status<F: FnOnce(&UpdateUserStatus)>(&self, fnc: F) -> &Self { if let Update::UserStatus(t) = self { fnc(t) }; self }
   pub fn on_users_nearby<F: FnOnce(&UpdateUsersNearby)>(&self, fnc: F) -> &Self { if let Update::UsersNearby(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let Update::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_test_use_update(&self) -> Option<&TestUseUpdate> { if let Update::TestUseUpdate(t) = self { return Some(t) } None }
   pub fn as_active_notifications(&self) -> Option<&UpdateActiveNotifications> { if let Update::ActiveNotifications(t) = self { return Some(t) } None }
@@ -702,7 +815,7 @@ impl Update {
   pub fn as_authorization_state(&self) -> Option<&UpdateAuthorizationState> { if let Update::AuthorizationState(t) = self { return Some(t) } None }
   pub fn as_basic_group(&self) -> Option<&UpdateBasicGroup> { if let Update::BasicGroup(t) = self { return Some(t) } None }
   pub fn as_basic_group_full_info(&self) -> Option<&UpdateBasicGroupFullInfo> { if let Update::BasicGroupFullInfo(t) = self { return Some(t) } None }
-  pub fn as_call(&self) -> Option<&UpdateCall> { if let Update::Call(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_call(&self) -> Option<&UpdateCall> { if let Update::Call(t) = self { return Some(t) } None }
   pub fn as_chat_action_bar(&self) -> Option<&UpdateChatActionBar> { if let Update::ChatActionBar(t) = self { return Some(t) } None }
   pub fn as_chat_default_disable_notification(&self) -> Option<&UpdateChatDefaultDisableNotification> { if let Update::ChatDefaultDisableNotification(t) = self { return Some(t) } None }
   pub fn as_chat_draft_message(&self) -> Option<&UpdateChatDraftMessage> { if let Update::ChatDraftMessage(t) = self { return Some(t) } None }
@@ -730,14 +843,14 @@ impl Update {
   pub fn as_connection_state(&self) -> Option<&UpdateConnectionState> { if let Update::ConnectionState(t) = self { return Some(t) } None }
   pub fn as_delete_messages(&self) -> Option<&UpdateDeleteMessages> { if let Update::DeleteMessages(t) = self { return Some(t) } None }
   pub fn as_dice_emojis(&self) -> Option<&UpdateDiceEmojis> { if let Update::DiceEmojis(t) = self { return Some(t) } None }
-  pub fn as_favorite_stickers(&self) -> Option<&UpdateFavoriteStickers> { if let Update::FavoriteStickers(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_favorite_stickers(&self) -> Option<&UpdateFavoriteStickers> { if let Update::FavoriteStickers(t) = self { return Some(t) } None }
   pub fn as_file(&self) -> Option<&UpdateFile> { if let Update::File(t) = self { return Some(t) } None }
   pub fn as_file_generation_start(&self) -> Option<&UpdateFileGenerationStart> { if let Update::FileGenerationStart(t) = self { return Some(t) } None }
   pub fn as_file_generation_stop(&self) -> Option<&UpdateFileGenerationStop> { if let Update::FileGenerationStop(t) = self { return Some(t) } None }
-  pub fn as_group_call(&self) -> Option<&UpdateGroupCall> { if let Update::GroupCall(t) = self { return Some(t) } None }
-  pub fn as_group_call_participant(&self) -> Option<&UpdateGroupCallParticipant> { if let Update::GroupCallParticipant(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_group_call(&self) -> Option<&UpdateGroupCall> { if let Update::GroupCall(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_group_call_participant(&self) -> Option<&UpdateGroupCallParticipant> { if let Update::GroupCallParticipant(t) = self { return Some(t) } None }
   pub fn as_have_pending_notifications(&self) -> Option<&UpdateHavePendingNotifications> { if let Update::HavePendingNotifications(t) = self { return Some(t) } None }
-  pub fn as_installed_sticker_sets(&self) -> Option<&UpdateInstalledStickerSets> { if let Update::InstalledStickerSets(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_installed_sticker_sets(&self) -> Option<&UpdateInstalledStickerSets> { if let Update::InstalledStickerSets(t) = self { return Some(t) } None }
   pub fn as_language_pack_strings(&self) -> Option<&UpdateLanguagePackStrings> { if let Update::LanguagePackStrings(t) = self { return Some(t) } None }
   pub fn as_message_content(&self) -> Option<&UpdateMessageContent> { if let Update::MessageContent(t) = self { return Some(t) } None }
   pub fn as_message_content_opened(&self) -> Option<&UpdateMessageContentOpened> { if let Update::MessageContentOpened(t) = self { return Some(t) } None }
@@ -749,7 +862,7 @@ impl Update {
   pub fn as_message_send_acknowledged(&self) -> Option<&UpdateMessageSendAcknowledged> { if let Update::MessageSendAcknowledged(t) = self { return Some(t) } None }
   pub fn as_message_send_failed(&self) -> Option<&UpdateMessageSendFailed> { if let Update::MessageSendFailed(t) = self { return Some(t) } None }
   pub fn as_message_send_succeeded(&self) -> Option<&UpdateMessageSendSucceeded> { if let Update::MessageSendSucceeded(t) = self { return Some(t) } None }
-  pub fn as_new_call_signaling_data(&self) -> Option<&UpdateNewCallSignalingData> { if let Update::NewCallSignalingData(t) = self { return Some(t) } None }
+  #[cfg(feature = "calls")] pub fn as_new_call_signaling_data(&self) -> Option<&UpdateNewCallSignalingData> { if let Update::NewCallSignalingData(t) = self { return Some(t) } None }
   pub fn as_new_callback_query(&self) -> Option<&UpdateNewCallbackQuery> { if let Update::NewCallbackQuery(t) = self { return Some(t) } None }
   pub fn as_new_chat(&self) -> Option<&UpdateNewChat> { if let Update::NewChat(t) = self { return Some(t) } None }
   pub fn as_new_chat_join_request(&self) -> Option<&UpdateNewChatJoinRequest> { if let Update::NewChatJoinRequest(t) = self { return Some(t) } None }
@@ -760,24 +873,24 @@ impl Update {
   pub fn as_new_inline_query(&self) -> Option<&UpdateNewInlineQuery> { if let Update::NewInlineQuery(t) = self { return Some(t) } None }
   pub fn as_new_message(&self) -> Option<&UpdateNewMessage> { if let Update::NewMessage(t) = self { return Some(t) } None }
   pub fn as_new_pre_checkout_query(&self) -> Option<&UpdateNewPreCheckoutQuery> { if let Update::NewPreCheckoutQuery(t) = self { return Some(t) } None }
-  pub fn as_new_shipping_query(&self) -> Option<&UpdateNewShippingQuery> { if let Update::NewShippingQuery(t) = self { return Some(t) } None }
+  #[cfg(feature = "payments")] pub fn as_new_shipping_query(&self) -> Option<&UpdateNewShippingQuery> { if let Update::NewShippingQuery(t) = self { return Some(t) } None }
   pub fn as_notification(&self) -> Option<&UpdateNotification> { if let Update::Notification(t) = self { return Some(t) } None }
   pub fn as_notification_group(&self) -> Option<&UpdateNotificationGroup> { if let Update::NotificationGroup(t) = self { return Some(t) } None }
   pub fn as_option(&self) -> Option<&UpdateOption> { if let Update::Option(t) = self { return Some(t) } None }
   pub fn as_poll(&self) -> Option<&UpdatePoll> { if let Update::Poll(t) = self { return Some(t) } None }
   pub fn as_poll_answer(&self) -> Option<&UpdatePollAnswer> { if let Update::PollAnswer(t) = self { return Some(t) } None }
-  pub fn as_recent_stickers(&self) -> Option<&UpdateRecentStickers> { if let Update::RecentStickers(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_recent_stickers(&self) -> Option<&UpdateRecentStickers> { if let Update::RecentStickers(t) = self { return Some(t) } None }
   pub fn as_saved_animations(&self) -> Option<&UpdateSavedAnimations> { if let Update::SavedAnimations(t) = self { return Some(t) } None }
   pub fn as_scope_notification_settings(&self) -> Option<&UpdateScopeNotificationSettings> { if let Update::ScopeNotificationSettings(t) = self { return Some(t) } None }
   pub fn as_secret_chat(&self) -> Option<&UpdateSecretChat> { if let Update::SecretChat(t) = self { return Some(t) } None }
   pub fn as_selected_background(&self) -> Option<&UpdateSelectedBackground> { if let Update::SelectedBackground(t) = self { return Some(t) } None }
   pub fn as_service_notification(&self) -> Option<&UpdateServiceNotification> { if let Update::ServiceNotification(t) = self { return Some(t) } None }
-  pub fn as_sticker_set(&self) -> Option<&UpdateStickerSet> { if let Update::StickerSet(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_sticker_set(&self) -> Option<&UpdateStickerSet> { if let Update::StickerSet(t) = self { return Some(t) } None }
   pub fn as_suggested_actions(&self) -> Option<&UpdateSuggestedActions> { if let Update::SuggestedActions(t) = self { return Some(t) } None }
   pub fn as_supergroup(&self) -> Option<&UpdateSupergroup> { if let Update::Supergroup(t) = self { return Some(t) } None }
   pub fn as_supergroup_full_info(&self) -> Option<&UpdateSupergroupFullInfo> { if let Update::SupergroupFullInfo(t) = self { return Some(t) } None }
   pub fn as_terms_of_service(&self) -> Option<&UpdateTermsOfService> { if let Update::TermsOfService(t) = self { return Some(t) } None }
-  pub fn as_trending_sticker_sets(&self) -> Option<&UpdateTrendingStickerSets> { if let Update::TrendingStickerSets(t) = self { return Some(t) } None }
+  #[cfg(feature = "stickers")] pub fn as_trending_sticker_sets(&self) -> Option<&UpdateTrendingStickerSets> { if let Update::TrendingStickerSets(t) = self { return Some(t) } None }
   pub fn as_unread_chat_count(&self) -> Option<&UpdateUnreadChatCount> { if let Update::UnreadChatCount(t) = self { return Some(t) } None }
   pub fn as_unread_message_count(&self) -> Option<&UpdateUnreadMessageCount> { if let Update::UnreadMessageCount(t) = self { return Some(t) } None }
   pub fn as_user(&self) -> Option<&UpdateUser> { if let Update::User(t) = self { return Some(t) } None }
@@ -786,6 +899,7 @@ impl Update {
   pub fn as_user_privacy_setting_rules(&self) -> Option<&UpdateUserPrivacySettingRules> { if let Update::UserPrivacySettingRules(t) = self { return Some(t) } None }
   pub fn as_user_status(&self) -> Option<&UpdateUserStatus> { if let Update::UserStatus(t) = self { return Some(t) } None }
   pub fn as_users_nearby(&self) -> Option<&UpdateUsersNearby> { if let Update::UsersNearby(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let Update::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -803,7 +917,7 @@ impl Update {
 
   pub fn basic_group_full_info<T: AsRef<UpdateBasicGroupFullInfo>>(t: T) -> Self { Update::BasicGroupFullInfo(t.as_ref().clone()) }
 
-  pub fn call<T: AsRef<UpdateCall>>(t: T) -> Self { Update::Call(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn call<T: AsRef<UpdateCall>>(t: T) -> Self { Update::Call(t.as_ref().clone()) }
 
   pub fn chat_action_bar<T: AsRef<UpdateChatActionBar>>(t: T) -> Self { Update::ChatActionBar(t.as_ref().clone()) }
 
@@ -859,7 +973,7 @@ impl Update {
 
   pub fn dice_emojis<T: AsRef<UpdateDiceEmojis>>(t: T) -> Self { Update::DiceEmojis(t.as_ref().clone()) }
 
-  pub fn favorite_stickers<T: AsRef<UpdateFavoriteStickers>>(t: T) -> Self { Update::FavoriteStickers(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn favorite_stickers<T: AsRef<UpdateFavoriteStickers>>(t: T) -> Self { Update::FavoriteStickers(t.as_ref().clone()) }
 
   pub fn file<T: AsRef<UpdateFile>>(t: T) -> Self { Update::File(t.as_ref().clone()) }
 
@@ -867,13 +981,13 @@ impl Update {
 
   pub fn file_generation_stop<T: AsRef<UpdateFileGenerationStop>>(t: T) -> Self { Update::FileGenerationStop(t.as_ref().clone()) }
 
-  pub fn group_call<T: AsRef<UpdateGroupCall>>(t: T) -> Self { Update::GroupCall(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn group_call<T: AsRef<UpdateGroupCall>>(t: T) -> Self { Update::GroupCall(t.as_ref().clone()) }
 
-  pub fn group_call_participant<T: AsRef<UpdateGroupCallParticipant>>(t: T) -> Self { Update::GroupCallParticipant(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn group_call_participant<T: AsRef<UpdateGroupCallParticipant>>(t: T) -> Self { Update::GroupCallParticipant(t.as_ref().clone()) }
 
   pub fn have_pending_notifications<T: AsRef<UpdateHavePendingNotifications>>(t: T) -> Self { Update::HavePendingNotifications(t.as_ref().clone()) }
 
-  pub fn installed_sticker_sets<T: AsRef<UpdateInstalledStickerSets>>(t: T) -> Self { Update::InstalledStickerSets(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn installed_sticker_sets<T: AsRef<UpdateInstalledStickerSets>>(t: T) -> Self { Update::InstalledStickerSets(t.as_ref().clone()) }
 
   pub fn language_pack_strings<T: AsRef<UpdateLanguagePackStrings>>(t: T) -> Self { Update::LanguagePackStrings(t.as_ref().clone()) }
 
@@ -897,7 +1011,7 @@ impl Update {
 
   pub fn message_send_succeeded<T: AsRef<UpdateMessageSendSucceeded>>(t: T) -> Self { Update::MessageSendSucceeded(t.as_ref().clone()) }
 
-  pub fn new_call_signaling_data<T: AsRef<UpdateNewCallSignalingData>>(t: T) -> Self { Update::NewCallSignalingData(t.as_ref().clone()) }
+  #[cfg(feature = "calls")] pub fn new_call_signaling_data<T: AsRef<UpdateNewCallSignalingData>>(t: T) -> Self { Update::NewCallSignalingData(t.as_ref().clone()) }
 
   pub fn new_callback_query<T: AsRef<UpdateNewCallbackQuery>>(t: T) -> Self { Update::NewCallbackQuery(t.as_ref().clone()) }
 
@@ -919,7 +1033,7 @@ impl Update {
 
   pub fn new_pre_checkout_query<T: AsRef<UpdateNewPreCheckoutQuery>>(t: T) -> Self { Update::NewPreCheckoutQuery(t.as_ref().clone()) }
 
-  pub fn new_shipping_query<T: AsRef<UpdateNewShippingQuery>>(t: T) -> Self { Update::NewShippingQuery(t.as_ref().clone()) }
+  #[cfg(feature = "payments")] pub fn new_shipping_query<T: AsRef<UpdateNewShippingQuery>>(t: T) -> Self { Update::NewShippingQuery(t.as_ref().clone()) }
 
   pub fn notification<T: AsRef<UpdateNotification>>(t: T) -> Self { Update::Notification(t.as_ref().clone()) }
 
@@ -931,7 +1045,7 @@ impl Update {
 
   pub fn poll_answer<T: AsRef<UpdatePollAnswer>>(t: T) -> Self { Update::PollAnswer(t.as_ref().clone()) }
 
-  pub fn recent_stickers<T: AsRef<UpdateRecentStickers>>(t: T) -> Self { Update::RecentStickers(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn recent_stickers<T: AsRef<UpdateRecentStickers>>(t: T) -> Self { Update::RecentStickers(t.as_ref().clone()) }
 
   pub fn saved_animations<T: AsRef<UpdateSavedAnimations>>(t: T) -> Self { Update::SavedAnimations(t.as_ref().clone()) }
 
@@ -943,7 +1057,7 @@ impl Update {
 
   pub fn service_notification<T: AsRef<UpdateServiceNotification>>(t: T) -> Self { Update::ServiceNotification(t.as_ref().clone()) }
 
-  pub fn sticker_set<T: AsRef<UpdateStickerSet>>(t: T) -> Self { Update::StickerSet(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn sticker_set<T: AsRef<UpdateStickerSet>>(t: T) -> Self { Update::StickerSet(t.as_ref().clone()) }
 
   pub fn suggested_actions<T: AsRef<UpdateSuggestedActions>>(t: T) -> Self { Update::SuggestedActions(t.as_ref().clone()) }
 
@@ -953,7 +1067,7 @@ impl Update {
 
   pub fn terms_of_service<T: AsRef<UpdateTermsOfService>>(t: T) -> Self { Update::TermsOfService(t.as_ref().clone()) }
 
-  pub fn trending_sticker_sets<T: AsRef<UpdateTrendingStickerSets>>(t: T) -> Self { Update::TrendingStickerSets(t.as_ref().clone()) }
+  #[cfg(feature = "stickers")] pub fn trending_sticker_sets<T: AsRef<UpdateTrendingStickerSets>>(t: T) -> Self { Update::TrendingStickerSets(t.as_ref().clone()) }
 
   pub fn unread_chat_count<T: AsRef<UpdateUnreadChatCount>>(t: T) -> Self { Update::UnreadChatCount(t.as_ref().clone()) }
 
@@ -991,15 +1105,19 @@ pub struct UpdateActiveNotifications {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Lists of active notification groups
   groups: Vec<NotificationGroup>,
   
 }
 
 impl RObject for UpdateActiveNotifications {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateActiveNotifications" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateActiveNotifications" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1009,14 +1127,15 @@ impl TDUpdate for UpdateActiveNotifications {}
 
 
 impl UpdateActiveNotifications {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateActiveNotificationsBuilder {
-    let mut inner = UpdateActiveNotifications::default();
-    inner.td_name = "updateActiveNotifications".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateActiveNotifications { td_name: "updateActiveNotifications".to_string(), ..Default::default() };
     RTDUpdateActiveNotificationsBuilder { inner }
   }
 
+  /// `UpdateActiveNotifications` with the fields tdlib requires, the others are set by `UpdateActiveNotifications::builder()`
+  pub fn new(groups: Vec<NotificationGroup>) -> Self { Self::builder().groups(groups).build() }
+
   pub fn groups(&self) -> &Vec<NotificationGroup> { &self.groups }
 
 }
@@ -1029,6 +1148,12 @@ pub struct RTDUpdateActiveNotificationsBuilder {
 impl RTDUpdateActiveNotificationsBuilder {
   pub fn build(&self) -> UpdateActiveNotifications { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn groups(&mut self, groups: Vec<NotificationGroup>) -> &mut Self {
     self.inner.groups = groups;
@@ -1045,6 +1170,12 @@ impl AsRef<UpdateActiveNotifications> for RTDUpdateActiveNotificationsBuilder {
   fn as_ref(&self) -> &UpdateActiveNotifications { &self.inner }
 }
 
+impl PartialEq for UpdateActiveNotifications {
+  fn eq(&self, other: &Self) -> bool {
+    self.groups == other.groups
+  }
+}
+
 
 
 
@@ -1059,19 +1190,23 @@ pub struct UpdateAnimatedEmojiMessageClicked {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// The animated sticker to be played
   sticker: Sticker,
   
 }
 
 impl RObject for UpdateAnimatedEmojiMessageClicked {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateAnimatedEmojiMessageClicked" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateAnimatedEmojiMessageClicked" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1081,17 +1216,18 @@ impl TDUpdate for UpdateAnimatedEmojiMessageClicked {}
 
 
 impl UpdateAnimatedEmojiMessageClicked {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateAnimatedEmojiMessageClickedBuilder {
-    let mut inner = UpdateAnimatedEmojiMessageClicked::default();
-    inner.td_name = "updateAnimatedEmojiMessageClicked".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateAnimatedEmojiMessageClicked { td_name: "updateAnimatedEmojiMessageClicked".to_string(), ..Default::default() };
     RTDUpdateAnimatedEmojiMessageClickedBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateAnimatedEmojiMessageClicked` with the fields tdlib requires, the others are set by `UpdateAnimatedEmojiMessageClicked::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId, sticker: impl AsRef<Sticker>) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).sticker(sticker).build() }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn sticker(&self) -> &Sticker { &self.sticker }
 
@@ -1105,14 +1241,20 @@ pub struct RTDUpdateAnimatedEmojiMessageClickedBuilder {
 impl RTDUpdateAnimatedEmojiMessageClickedBuilder {
   pub fn build(&self) -> UpdateAnimatedEmojiMessageClicked { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -1133,6 +1275,14 @@ impl AsRef<UpdateAnimatedEmojiMessageClicked> for RTDUpdateAnimatedEmojiMessageC
   fn as_ref(&self) -> &UpdateAnimatedEmojiMessageClicked { &self.inner }
 }
 
+impl PartialEq for UpdateAnimatedEmojiMessageClicked {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.sticker == other.sticker
+  }
+}
+
 
 
 
@@ -1147,7 +1297,10 @@ pub struct UpdateAnimationSearchParameters {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Name of the animation search provider
   provider: String,
   /// The new list of emojis suggested for searching
@@ -1156,8 +1309,9 @@ pub struct UpdateAnimationSearchParameters {
 }
 
 impl RObject for UpdateAnimationSearchParameters {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateAnimationSearchParameters" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateAnimationSearchParameters" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1167,14 +1321,15 @@ impl TDUpdate for UpdateAnimationSearchParameters {}
 
 
 impl UpdateAnimationSearchParameters {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateAnimationSearchParametersBuilder {
-    let mut inner = UpdateAnimationSearchParameters::default();
-    inner.td_name = "updateAnimationSearchParameters".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateAnimationSearchParameters { td_name: "updateAnimationSearchParameters".to_string(), ..Default::default() };
     RTDUpdateAnimationSearchParametersBuilder { inner }
   }
 
+  /// `UpdateAnimationSearchParameters` with the fields tdlib requires, the others are set by `UpdateAnimationSearchParameters::builder()`
+  pub fn new(provider: impl AsRef<str>, emojis: Vec<String>) -> Self { Self::builder().provider(provider).emojis(emojis).build() }
+
   pub fn provider(&self) -> &String { &self.provider }
 
   pub fn emojis(&self) -> &Vec<String> { &self.emojis }
@@ -1189,6 +1344,12 @@ pub struct RTDUpdateAnimationSearchParametersBuilder {
 impl RTDUpdateAnimationSearchParametersBuilder {
   pub fn build(&self) -> UpdateAnimationSearchParameters { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn provider<T: AsRef<str>>(&mut self, provider: T) -> &mut Self {
     self.inner.provider = provider.as_ref().to_string();
@@ -1211,6 +1372,13 @@ impl AsRef<UpdateAnimationSearchParameters> for RTDUpdateAnimationSearchParamete
   fn as_ref(&self) -> &UpdateAnimationSearchParameters { &self.inner }
 }
 
+impl PartialEq for UpdateAnimationSearchParameters {
+  fn eq(&self, other: &Self) -> bool {
+    self.provider == other.provider
+      && self.emojis == other.emojis
+  }
+}
+
 
 
 
@@ -1225,15 +1393,19 @@ pub struct UpdateAuthorizationState {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New authorization state
   authorization_state: AuthorizationState,
   
 }
 
 impl RObject for UpdateAuthorizationState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateAuthorizationState" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateAuthorizationState" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1243,14 +1415,15 @@ impl TDUpdate for UpdateAuthorizationState {}
 
 
 impl UpdateAuthorizationState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateAuthorizationStateBuilder {
-    let mut inner = UpdateAuthorizationState::default();
-    inner.td_name = "updateAuthorizationState".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateAuthorizationState { td_name: "updateAuthorizationState".to_string(), ..Default::default() };
     RTDUpdateAuthorizationStateBuilder { inner }
   }
 
+  /// `UpdateAuthorizationState` with the fields tdlib requires, the others are set by `UpdateAuthorizationState::builder()`
+  pub fn new(authorization_state: impl AsRef<AuthorizationState>) -> Self { Self::builder().authorization_state(authorization_state).build() }
+
   pub fn authorization_state(&self) -> &AuthorizationState { &self.authorization_state }
 
 }
@@ -1263,6 +1436,12 @@ pub struct RTDUpdateAuthorizationStateBuilder {
 impl RTDUpdateAuthorizationStateBuilder {
   pub fn build(&self) -> UpdateAuthorizationState { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn authorization_state<T: AsRef<AuthorizationState>>(&mut self, authorization_state: T) -> &mut Self {
     self.inner.authorization_state = authorization_state.as_ref().clone();
@@ -1279,6 +1458,12 @@ impl AsRef<UpdateAuthorizationState> for RTDUpdateAuthorizationStateBuilder {
   fn as_ref(&self) -> &UpdateAuthorizationState { &self.inner }
 }
 
+impl PartialEq for UpdateAuthorizationState {
+  fn eq(&self, other: &Self) -> bool {
+    self.authorization_state == other.authorization_state
+  }
+}
+
 
 
 
@@ -1293,15 +1478,19 @@ pub struct UpdateBasicGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about the group
   basic_group: BasicGroup,
   
 }
 
 impl RObject for UpdateBasicGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateBasicGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateBasicGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1311,14 +1500,15 @@ impl TDUpdate for UpdateBasicGroup {}
 
 
 impl UpdateBasicGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateBasicGroupBuilder {
-    let mut inner = UpdateBasicGroup::default();
-    inner.td_name = "updateBasicGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateBasicGroup { td_name: "updateBasicGroup".to_string(), ..Default::default() };
     RTDUpdateBasicGroupBuilder { inner }
   }
 
+  /// `UpdateBasicGroup` with the fields tdlib requires, the others are set by `UpdateBasicGroup::builder()`
+  pub fn new(basic_group: impl AsRef<BasicGroup>) -> Self { Self::builder().basic_group(basic_group).build() }
+
   pub fn basic_group(&self) -> &BasicGroup { &self.basic_group }
 
 }
@@ -1331,6 +1521,12 @@ pub struct RTDUpdateBasicGroupBuilder {
 impl RTDUpdateBasicGroupBuilder {
   pub fn build(&self) -> UpdateBasicGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn basic_group<T: AsRef<BasicGroup>>(&mut self, basic_group: T) -> &mut Self {
     self.inner.basic_group = basic_group.as_ref().clone();
@@ -1347,6 +1543,12 @@ impl AsRef<UpdateBasicGroup> for RTDUpdateBasicGroupBuilder {
   fn as_ref(&self) -> &UpdateBasicGroup { &self.inner }
 }
 
+impl PartialEq for UpdateBasicGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.basic_group == other.basic_group
+  }
+}
+
 
 
 
@@ -1361,17 +1563,21 @@ pub struct UpdateBasicGroupFullInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of a basic group
-  basic_group_id: i64,
+  basic_group_id: BasicGroupId,
   /// New full information about the group
   basic_group_full_info: BasicGroupFullInfo,
   
 }
 
 impl RObject for UpdateBasicGroupFullInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateBasicGroupFullInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateBasicGroupFullInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1381,15 +1587,16 @@ impl TDUpdate for UpdateBasicGroupFullInfo {}
 
 
 impl UpdateBasicGroupFullInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateBasicGroupFullInfoBuilder {
-    let mut inner = UpdateBasicGroupFullInfo::default();
-    inner.td_name = "updateBasicGroupFullInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateBasicGroupFullInfo { td_name: "updateBasicGroupFullInfo".to_string(), ..Default::default() };
     RTDUpdateBasicGroupFullInfoBuilder { inner }
   }
 
-  pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
+  /// `UpdateBasicGroupFullInfo` with the fields tdlib requires, the others are set by `UpdateBasicGroupFullInfo::builder()`
+  pub fn new(basic_group_id: BasicGroupId, basic_group_full_info: impl AsRef<BasicGroupFullInfo>) -> Self { Self::builder().basic_group_id(basic_group_id).basic_group_full_info(basic_group_full_info).build() }
+
+  pub fn basic_group_id(&self) -> BasicGroupId { self.basic_group_id }
 
   pub fn basic_group_full_info(&self) -> &BasicGroupFullInfo { &self.basic_group_full_info }
 
@@ -1403,8 +1610,14 @@ pub struct RTDUpdateBasicGroupFullInfoBuilder {
 impl RTDUpdateBasicGroupFullInfoBuilder {
   pub fn build(&self) -> UpdateBasicGroupFullInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
+  pub fn basic_group_id(&mut self, basic_group_id: BasicGroupId) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
     self
   }
@@ -1425,11 +1638,20 @@ impl AsRef<UpdateBasicGroupFullInfo> for RTDUpdateBasicGroupFullInfoBuilder {
   fn as_ref(&self) -> &UpdateBasicGroupFullInfo { &self.inner }
 }
 
+impl PartialEq for UpdateBasicGroupFullInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.basic_group_id == other.basic_group_id
+      && self.basic_group_full_info == other.basic_group_full_info
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
 
 /// New call was created or information about a call was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -1439,15 +1661,19 @@ pub struct UpdateCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about a call
   call: Call,
   
 }
 
 impl RObject for UpdateCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1457,14 +1683,15 @@ impl TDUpdate for UpdateCall {}
 
 
 impl UpdateCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateCallBuilder {
-    let mut inner = UpdateCall::default();
-    inner.td_name = "updateCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateCall { td_name: "updateCall".to_string(), ..Default::default() };
     RTDUpdateCallBuilder { inner }
   }
 
+  /// `UpdateCall` with the fields tdlib requires, the others are set by `UpdateCall::builder()`
+  pub fn new(call: impl AsRef<Call>) -> Self { Self::builder().call(call).build() }
+
   pub fn call(&self) -> &Call { &self.call }
 
 }
@@ -1477,6 +1704,12 @@ pub struct RTDUpdateCallBuilder {
 impl RTDUpdateCallBuilder {
   pub fn build(&self) -> UpdateCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call<T: AsRef<Call>>(&mut self, call: T) -> &mut Self {
     self.inner.call = call.as_ref().clone();
@@ -1493,6 +1726,14 @@ impl AsRef<UpdateCall> for RTDUpdateCallBuilder {
   fn as_ref(&self) -> &UpdateCall { &self.inner }
 }
 
+impl PartialEq for UpdateCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.call == other.call
+  }
+}
+
+}
+
 
 
 
@@ -1507,17 +1748,21 @@ pub struct UpdateChatActionBar {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new value of the action bar; may be null
   action_bar: Option<ChatActionBar>,
   
 }
 
 impl RObject for UpdateChatActionBar {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatActionBar" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatActionBar" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1527,15 +1772,16 @@ impl TDUpdate for UpdateChatActionBar {}
 
 
 impl UpdateChatActionBar {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatActionBarBuilder {
-    let mut inner = UpdateChatActionBar::default();
-    inner.td_name = "updateChatActionBar".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatActionBar { td_name: "updateChatActionBar".to_string(), ..Default::default() };
     RTDUpdateChatActionBarBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatActionBar` with the fields tdlib requires, the others are set by `UpdateChatActionBar::builder()`
+  pub fn new(chat_id: ChatId) -> Self { Self::builder().chat_id(chat_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn action_bar(&self) -> &Option<ChatActionBar> { &self.action_bar }
 
@@ -1549,8 +1795,14 @@ pub struct RTDUpdateChatActionBarBuilder {
 impl RTDUpdateChatActionBarBuilder {
   pub fn build(&self) -> UpdateChatActionBar { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -1571,6 +1823,13 @@ impl AsRef<UpdateChatActionBar> for RTDUpdateChatActionBarBuilder {
   fn as_ref(&self) -> &UpdateChatActionBar { &self.inner }
 }
 
+impl PartialEq for UpdateChatActionBar {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.action_bar == other.action_bar
+  }
+}
+
 
 
 
@@ -1585,17 +1844,21 @@ pub struct UpdateChatDefaultDisableNotification {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new default_disable_notification value
   default_disable_notification: bool,
   
 }
 
 impl RObject for UpdateChatDefaultDisableNotification {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatDefaultDisableNotification" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatDefaultDisableNotification" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1605,15 +1868,16 @@ impl TDUpdate for UpdateChatDefaultDisableNotification {}
 
 
 impl UpdateChatDefaultDisableNotification {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatDefaultDisableNotificationBuilder {
-    let mut inner = UpdateChatDefaultDisableNotification::default();
-    inner.td_name = "updateChatDefaultDisableNotification".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatDefaultDisableNotification { td_name: "updateChatDefaultDisableNotification".to_string(), ..Default::default() };
     RTDUpdateChatDefaultDisableNotificationBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatDefaultDisableNotification` with the fields tdlib requires, the others are set by `UpdateChatDefaultDisableNotification::builder()`
+  pub fn new(chat_id: ChatId, default_disable_notification: bool) -> Self { Self::builder().chat_id(chat_id).default_disable_notification(default_disable_notification).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn default_disable_notification(&self) -> bool { self.default_disable_notification }
 
@@ -1627,8 +1891,14 @@ pub struct RTDUpdateChatDefaultDisableNotificationBuilder {
 impl RTDUpdateChatDefaultDisableNotificationBuilder {
   pub fn build(&self) -> UpdateChatDefaultDisableNotification { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -1649,6 +1919,13 @@ impl AsRef<UpdateChatDefaultDisableNotification> for RTDUpdateChatDefaultDisable
   fn as_ref(&self) -> &UpdateChatDefaultDisableNotification { &self.inner }
 }
 
+impl PartialEq for UpdateChatDefaultDisableNotification {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.default_disable_notification == other.default_disable_notification
+  }
+}
+
 
 
 
@@ -1663,9 +1940,12 @@ pub struct UpdateChatDraftMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new draft message; may be null
   draft_message: Option<DraftMessage>,
   /// The new chat positions in the chat lists
@@ -1674,8 +1954,9 @@ pub struct UpdateChatDraftMessage {
 }
 
 impl RObject for UpdateChatDraftMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatDraftMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatDraftMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1685,15 +1966,16 @@ impl TDUpdate for UpdateChatDraftMessage {}
 
 
 impl UpdateChatDraftMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatDraftMessageBuilder {
-    let mut inner = UpdateChatDraftMessage::default();
-    inner.td_name = "updateChatDraftMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatDraftMessage { td_name: "updateChatDraftMessage".to_string(), ..Default::default() };
     RTDUpdateChatDraftMessageBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatDraftMessage` with the fields tdlib requires, the others are set by `UpdateChatDraftMessage::builder()`
+  pub fn new(chat_id: ChatId, positions: Vec<ChatPosition>) -> Self { Self::builder().chat_id(chat_id).positions(positions).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn draft_message(&self) -> &Option<DraftMessage> { &self.draft_message }
 
@@ -1709,8 +1991,14 @@ pub struct RTDUpdateChatDraftMessageBuilder {
 impl RTDUpdateChatDraftMessageBuilder {
   pub fn build(&self) -> UpdateChatDraftMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -1737,6 +2025,14 @@ impl AsRef<UpdateChatDraftMessage> for RTDUpdateChatDraftMessageBuilder {
   fn as_ref(&self) -> &UpdateChatDraftMessage { &self.inner }
 }
 
+impl PartialEq for UpdateChatDraftMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.draft_message == other.draft_message
+      && self.positions == other.positions
+  }
+}
+
 
 
 
@@ -1751,15 +2047,19 @@ pub struct UpdateChatFilters {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new list of chat filters
   chat_filters: Vec<ChatFilterInfo>,
   
 }
 
 impl RObject for UpdateChatFilters {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatFilters" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatFilters" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1769,14 +2069,15 @@ impl TDUpdate for UpdateChatFilters {}
 
 
 impl UpdateChatFilters {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatFiltersBuilder {
-    let mut inner = UpdateChatFilters::default();
-    inner.td_name = "updateChatFilters".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatFilters { td_name: "updateChatFilters".to_string(), ..Default::default() };
     RTDUpdateChatFiltersBuilder { inner }
   }
 
+  /// `UpdateChatFilters` with the fields tdlib requires, the others are set by `UpdateChatFilters::builder()`
+  pub fn new(chat_filters: Vec<ChatFilterInfo>) -> Self { Self::builder().chat_filters(chat_filters).build() }
+
   pub fn chat_filters(&self) -> &Vec<ChatFilterInfo> { &self.chat_filters }
 
 }
@@ -1789,6 +2090,12 @@ pub struct RTDUpdateChatFiltersBuilder {
 impl RTDUpdateChatFiltersBuilder {
   pub fn build(&self) -> UpdateChatFilters { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_filters(&mut self, chat_filters: Vec<ChatFilterInfo>) -> &mut Self {
     self.inner.chat_filters = chat_filters;
@@ -1805,6 +2112,12 @@ impl AsRef<UpdateChatFilters> for RTDUpdateChatFiltersBuilder {
   fn as_ref(&self) -> &UpdateChatFilters { &self.inner }
 }
 
+impl PartialEq for UpdateChatFilters {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_filters == other.chat_filters
+  }
+}
+
 
 
 
@@ -1819,17 +2132,21 @@ pub struct UpdateChatHasScheduledMessages {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// New value of has_scheduled_messages
   has_scheduled_messages: bool,
   
 }
 
 impl RObject for UpdateChatHasScheduledMessages {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatHasScheduledMessages" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatHasScheduledMessages" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1839,15 +2156,16 @@ impl TDUpdate for UpdateChatHasScheduledMessages {}
 
 
 impl UpdateChatHasScheduledMessages {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatHasScheduledMessagesBuilder {
-    let mut inner = UpdateChatHasScheduledMessages::default();
-    inner.td_name = "updateChatHasScheduledMessages".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatHasScheduledMessages { td_name: "updateChatHasScheduledMessages".to_string(), ..Default::default() };
     RTDUpdateChatHasScheduledMessagesBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatHasScheduledMessages` with the fields tdlib requires, the others are set by `UpdateChatHasScheduledMessages::builder()`
+  pub fn new(chat_id: ChatId, has_scheduled_messages: bool) -> Self { Self::builder().chat_id(chat_id).has_scheduled_messages(has_scheduled_messages).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn has_scheduled_messages(&self) -> bool { self.has_scheduled_messages }
 
@@ -1861,8 +2179,14 @@ pub struct RTDUpdateChatHasScheduledMessagesBuilder {
 impl RTDUpdateChatHasScheduledMessagesBuilder {
   pub fn build(&self) -> UpdateChatHasScheduledMessages { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -1883,6 +2207,13 @@ impl AsRef<UpdateChatHasScheduledMessages> for RTDUpdateChatHasScheduledMessages
   fn as_ref(&self) -> &UpdateChatHasScheduledMessages { &self.inner }
 }
 
+impl PartialEq for UpdateChatHasScheduledMessages {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.has_scheduled_messages == other.has_scheduled_messages
+  }
+}
+
 
 
 
@@ -1897,17 +2228,21 @@ pub struct UpdateChatIsBlocked {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// New value of is_blocked
   is_blocked: bool,
   
 }
 
 impl RObject for UpdateChatIsBlocked {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatIsBlocked" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatIsBlocked" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1917,15 +2252,16 @@ impl TDUpdate for UpdateChatIsBlocked {}
 
 
 impl UpdateChatIsBlocked {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatIsBlockedBuilder {
-    let mut inner = UpdateChatIsBlocked::default();
-    inner.td_name = "updateChatIsBlocked".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatIsBlocked { td_name: "updateChatIsBlocked".to_string(), ..Default::default() };
     RTDUpdateChatIsBlockedBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatIsBlocked` with the fields tdlib requires, the others are set by `UpdateChatIsBlocked::builder()`
+  pub fn new(chat_id: ChatId, is_blocked: bool) -> Self { Self::builder().chat_id(chat_id).is_blocked(is_blocked).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn is_blocked(&self) -> bool { self.is_blocked }
 
@@ -1939,8 +2275,14 @@ pub struct RTDUpdateChatIsBlockedBuilder {
 impl RTDUpdateChatIsBlockedBuilder {
   pub fn build(&self) -> UpdateChatIsBlocked { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -1961,6 +2303,13 @@ impl AsRef<UpdateChatIsBlocked> for RTDUpdateChatIsBlockedBuilder {
   fn as_ref(&self) -> &UpdateChatIsBlocked { &self.inner }
 }
 
+impl PartialEq for UpdateChatIsBlocked {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.is_blocked == other.is_blocked
+  }
+}
+
 
 
 
@@ -1975,17 +2324,21 @@ pub struct UpdateChatIsMarkedAsUnread {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// New value of is_marked_as_unread
   is_marked_as_unread: bool,
   
 }
 
 impl RObject for UpdateChatIsMarkedAsUnread {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatIsMarkedAsUnread" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatIsMarkedAsUnread" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -1995,15 +2348,16 @@ impl TDUpdate for UpdateChatIsMarkedAsUnread {}
 
 
 impl UpdateChatIsMarkedAsUnread {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatIsMarkedAsUnreadBuilder {
-    let mut inner = UpdateChatIsMarkedAsUnread::default();
-    inner.td_name = "updateChatIsMarkedAsUnread".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatIsMarkedAsUnread { td_name: "updateChatIsMarkedAsUnread".to_string(), ..Default::default() };
     RTDUpdateChatIsMarkedAsUnreadBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatIsMarkedAsUnread` with the fields tdlib requires, the others are set by `UpdateChatIsMarkedAsUnread::builder()`
+  pub fn new(chat_id: ChatId, is_marked_as_unread: bool) -> Self { Self::builder().chat_id(chat_id).is_marked_as_unread(is_marked_as_unread).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn is_marked_as_unread(&self) -> bool { self.is_marked_as_unread }
 
@@ -2017,8 +2371,14 @@ pub struct RTDUpdateChatIsMarkedAsUnreadBuilder {
 impl RTDUpdateChatIsMarkedAsUnreadBuilder {
   pub fn build(&self) -> UpdateChatIsMarkedAsUnread { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2039,6 +2399,13 @@ impl AsRef<UpdateChatIsMarkedAsUnread> for RTDUpdateChatIsMarkedAsUnreadBuilder
   fn as_ref(&self) -> &UpdateChatIsMarkedAsUnread { &self.inner }
 }
 
+impl PartialEq for UpdateChatIsMarkedAsUnread {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.is_marked_as_unread == other.is_marked_as_unread
+  }
+}
+
 
 
 
@@ -2053,9 +2420,12 @@ pub struct UpdateChatLastMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new last message in the chat; may be null
   last_message: Option<Message>,
   /// The new chat positions in the chat lists
@@ -2064,8 +2434,9 @@ pub struct UpdateChatLastMessage {
 }
 
 impl RObject for UpdateChatLastMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatLastMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatLastMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2075,15 +2446,16 @@ impl TDUpdate for UpdateChatLastMessage {}
 
 
 impl UpdateChatLastMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatLastMessageBuilder {
-    let mut inner = UpdateChatLastMessage::default();
-    inner.td_name = "updateChatLastMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatLastMessage { td_name: "updateChatLastMessage".to_string(), ..Default::default() };
     RTDUpdateChatLastMessageBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatLastMessage` with the fields tdlib requires, the others are set by `UpdateChatLastMessage::builder()`
+  pub fn new(chat_id: ChatId) -> Self { Self::builder().chat_id(chat_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn last_message(&self) -> &Option<Message> { &self.last_message }
 
@@ -2099,8 +2471,14 @@ pub struct RTDUpdateChatLastMessageBuilder {
 impl RTDUpdateChatLastMessageBuilder {
   pub fn build(&self) -> UpdateChatLastMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2127,6 +2505,14 @@ impl AsRef<UpdateChatLastMessage> for RTDUpdateChatLastMessageBuilder {
   fn as_ref(&self) -> &UpdateChatLastMessage { &self.inner }
 }
 
+impl PartialEq for UpdateChatLastMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.last_message == other.last_message
+      && self.positions == other.positions
+  }
+}
+
 
 
 
@@ -2141,11 +2527,14 @@ pub struct UpdateChatMember {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Identifier of the user, changing the rights
-  actor_user_id: i64,
+  actor_user_id: UserId,
   /// Point in time (Unix timestamp) when the user rights was changed
   date: i64,
   /// If user has joined the chat using an invite link, the invite link; may be null
@@ -2158,8 +2547,9 @@ pub struct UpdateChatMember {
 }
 
 impl RObject for UpdateChatMember {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatMember" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatMember" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2169,17 +2559,32 @@ impl TDUpdate for UpdateChatMember {}
 
 
 impl UpdateChatMember {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatMemberBuilder {
-    let mut inner = UpdateChatMember::default();
-    inner.td_name = "updateChatMember".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatMember { td_name: "updateChatMember".to_string(), ..Default::default() };
     RTDUpdateChatMemberBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatMember` with the fields tdlib requires, the others are set by `UpdateChatMember::builder()`
+  pub fn new(
+    chat_id: ChatId,
+    actor_user_id: UserId,
+    date: i64,
+    old_chat_member: impl AsRef<ChatMember>,
+    new_chat_member: impl AsRef<ChatMember>,
+  ) -> Self {
+    Self::builder()
+      .chat_id(chat_id)
+      .actor_user_id(actor_user_id)
+      .date(date)
+      .old_chat_member(old_chat_member)
+      .new_chat_member(new_chat_member)
+      .build()
+  }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn actor_user_id(&self) -> i64 { self.actor_user_id }
+  pub fn actor_user_id(&self) -> UserId { self.actor_user_id }
 
   pub fn date(&self) -> i64 { self.date }
 
@@ -2199,14 +2604,20 @@ pub struct RTDUpdateChatMemberBuilder {
 impl RTDUpdateChatMemberBuilder {
   pub fn build(&self) -> UpdateChatMember { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn actor_user_id(&mut self, actor_user_id: i64) -> &mut Self {
+  pub fn actor_user_id(&mut self, actor_user_id: UserId) -> &mut Self {
     self.inner.actor_user_id = actor_user_id;
     self
   }
@@ -2245,6 +2656,17 @@ impl AsRef<UpdateChatMember> for RTDUpdateChatMemberBuilder {
   fn as_ref(&self) -> &UpdateChatMember { &self.inner }
 }
 
+impl PartialEq for UpdateChatMember {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.actor_user_id == other.actor_user_id
+      && self.date == other.date
+      && self.invite_link == other.invite_link
+      && self.old_chat_member == other.old_chat_member
+      && self.new_chat_member == other.new_chat_member
+  }
+}
+
 
 
 
@@ -2259,17 +2681,21 @@ pub struct UpdateChatMessageTtlSetting {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// New value of message_ttl_setting
   message_ttl_setting: i64,
   
 }
 
 impl RObject for UpdateChatMessageTtlSetting {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatMessageTtlSetting" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatMessageTtlSetting" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2279,15 +2705,16 @@ impl TDUpdate for UpdateChatMessageTtlSetting {}
 
 
 impl UpdateChatMessageTtlSetting {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatMessageTtlSettingBuilder {
-    let mut inner = UpdateChatMessageTtlSetting::default();
-    inner.td_name = "updateChatMessageTtlSetting".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatMessageTtlSetting { td_name: "updateChatMessageTtlSetting".to_string(), ..Default::default() };
     RTDUpdateChatMessageTtlSettingBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatMessageTtlSetting` with the fields tdlib requires, the others are set by `UpdateChatMessageTtlSetting::builder()`
+  pub fn new(chat_id: ChatId, message_ttl_setting: i64) -> Self { Self::builder().chat_id(chat_id).message_ttl_setting(message_ttl_setting).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn message_ttl_setting(&self) -> i64 { self.message_ttl_setting }
 
@@ -2301,8 +2728,14 @@ pub struct RTDUpdateChatMessageTtlSettingBuilder {
 impl RTDUpdateChatMessageTtlSettingBuilder {
   pub fn build(&self) -> UpdateChatMessageTtlSetting { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2323,6 +2756,13 @@ impl AsRef<UpdateChatMessageTtlSetting> for RTDUpdateChatMessageTtlSettingBuilde
   fn as_ref(&self) -> &UpdateChatMessageTtlSetting { &self.inner }
 }
 
+impl PartialEq for UpdateChatMessageTtlSetting {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_ttl_setting == other.message_ttl_setting
+  }
+}
+
 
 
 
@@ -2337,17 +2777,21 @@ pub struct UpdateChatNotificationSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new notification settings
   notification_settings: ChatNotificationSettings,
   
 }
 
 impl RObject for UpdateChatNotificationSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatNotificationSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatNotificationSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2357,15 +2801,16 @@ impl TDUpdate for UpdateChatNotificationSettings {}
 
 
 impl UpdateChatNotificationSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatNotificationSettingsBuilder {
-    let mut inner = UpdateChatNotificationSettings::default();
-    inner.td_name = "updateChatNotificationSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatNotificationSettings { td_name: "updateChatNotificationSettings".to_string(), ..Default::default() };
     RTDUpdateChatNotificationSettingsBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatNotificationSettings` with the fields tdlib requires, the others are set by `UpdateChatNotificationSettings::builder()`
+  pub fn new(chat_id: ChatId, notification_settings: impl AsRef<ChatNotificationSettings>) -> Self { Self::builder().chat_id(chat_id).notification_settings(notification_settings).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn notification_settings(&self) -> &ChatNotificationSettings { &self.notification_settings }
 
@@ -2379,8 +2824,14 @@ pub struct RTDUpdateChatNotificationSettingsBuilder {
 impl RTDUpdateChatNotificationSettingsBuilder {
   pub fn build(&self) -> UpdateChatNotificationSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2401,6 +2852,13 @@ impl AsRef<UpdateChatNotificationSettings> for RTDUpdateChatNotificationSettings
   fn as_ref(&self) -> &UpdateChatNotificationSettings { &self.inner }
 }
 
+impl PartialEq for UpdateChatNotificationSettings {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.notification_settings == other.notification_settings
+  }
+}
+
 
 
 
@@ -2415,17 +2873,21 @@ pub struct UpdateChatOnlineMemberCount {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat
-  chat_id: i64,
+  chat_id: ChatId,
   /// New number of online members in the chat, or 0 if unknown
   online_member_count: i64,
   
 }
 
 impl RObject for UpdateChatOnlineMemberCount {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatOnlineMemberCount" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatOnlineMemberCount" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2435,15 +2897,16 @@ impl TDUpdate for UpdateChatOnlineMemberCount {}
 
 
 impl UpdateChatOnlineMemberCount {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatOnlineMemberCountBuilder {
-    let mut inner = UpdateChatOnlineMemberCount::default();
-    inner.td_name = "updateChatOnlineMemberCount".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatOnlineMemberCount { td_name: "updateChatOnlineMemberCount".to_string(), ..Default::default() };
     RTDUpdateChatOnlineMemberCountBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatOnlineMemberCount` with the fields tdlib requires, the others are set by `UpdateChatOnlineMemberCount::builder()`
+  pub fn new(chat_id: ChatId, online_member_count: i64) -> Self { Self::builder().chat_id(chat_id).online_member_count(online_member_count).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn online_member_count(&self) -> i64 { self.online_member_count }
 
@@ -2457,8 +2920,14 @@ pub struct RTDUpdateChatOnlineMemberCountBuilder {
 impl RTDUpdateChatOnlineMemberCountBuilder {
   pub fn build(&self) -> UpdateChatOnlineMemberCount { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2479,6 +2948,13 @@ impl AsRef<UpdateChatOnlineMemberCount> for RTDUpdateChatOnlineMemberCountBuilde
   fn as_ref(&self) -> &UpdateChatOnlineMemberCount { &self.inner }
 }
 
+impl PartialEq for UpdateChatOnlineMemberCount {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.online_member_count == other.online_member_count
+  }
+}
+
 
 
 
@@ -2493,17 +2969,21 @@ pub struct UpdateChatPendingJoinRequests {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new data about pending join requests; may be null
   pending_join_requests: Option<ChatJoinRequestsInfo>,
   
 }
 
 impl RObject for UpdateChatPendingJoinRequests {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPendingJoinRequests" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatPendingJoinRequests" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2513,15 +2993,16 @@ impl TDUpdate for UpdateChatPendingJoinRequests {}
 
 
 impl UpdateChatPendingJoinRequests {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatPendingJoinRequestsBuilder {
-    let mut inner = UpdateChatPendingJoinRequests::default();
-    inner.td_name = "updateChatPendingJoinRequests".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatPendingJoinRequests { td_name: "updateChatPendingJoinRequests".to_string(), ..Default::default() };
     RTDUpdateChatPendingJoinRequestsBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatPendingJoinRequests` with the fields tdlib requires, the others are set by `UpdateChatPendingJoinRequests::builder()`
+  pub fn new(chat_id: ChatId) -> Self { Self::builder().chat_id(chat_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn pending_join_requests(&self) -> &Option<ChatJoinRequestsInfo> { &self.pending_join_requests }
 
@@ -2535,8 +3016,14 @@ pub struct RTDUpdateChatPendingJoinRequestsBuilder {
 impl RTDUpdateChatPendingJoinRequestsBuilder {
   pub fn build(&self) -> UpdateChatPendingJoinRequests { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2557,6 +3044,13 @@ impl AsRef<UpdateChatPendingJoinRequests> for RTDUpdateChatPendingJoinRequestsBu
   fn as_ref(&self) -> &UpdateChatPendingJoinRequests { &self.inner }
 }
 
+impl PartialEq for UpdateChatPendingJoinRequests {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.pending_join_requests == other.pending_join_requests
+  }
+}
+
 
 
 
@@ -2571,17 +3065,21 @@ pub struct UpdateChatPermissions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new chat permissions
   permissions: ChatPermissions,
   
 }
 
 impl RObject for UpdateChatPermissions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPermissions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatPermissions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2591,15 +3089,16 @@ impl TDUpdate for UpdateChatPermissions {}
 
 
 impl UpdateChatPermissions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatPermissionsBuilder {
-    let mut inner = UpdateChatPermissions::default();
-    inner.td_name = "updateChatPermissions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatPermissions { td_name: "updateChatPermissions".to_string(), ..Default::default() };
     RTDUpdateChatPermissionsBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatPermissions` with the fields tdlib requires, the others are set by `UpdateChatPermissions::builder()`
+  pub fn new(chat_id: ChatId, permissions: impl AsRef<ChatPermissions>) -> Self { Self::builder().chat_id(chat_id).permissions(permissions).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn permissions(&self) -> &ChatPermissions { &self.permissions }
 
@@ -2613,8 +3112,14 @@ pub struct RTDUpdateChatPermissionsBuilder {
 impl RTDUpdateChatPermissionsBuilder {
   pub fn build(&self) -> UpdateChatPermissions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2635,6 +3140,13 @@ impl AsRef<UpdateChatPermissions> for RTDUpdateChatPermissionsBuilder {
   fn as_ref(&self) -> &UpdateChatPermissions { &self.inner }
 }
 
+impl PartialEq for UpdateChatPermissions {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.permissions == other.permissions
+  }
+}
+
 
 
 
@@ -2649,17 +3161,21 @@ pub struct UpdateChatPhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new chat photo; may be null
   photo: Option<ChatPhotoInfo>,
   
 }
 
 impl RObject for UpdateChatPhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatPhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2669,15 +3185,16 @@ impl TDUpdate for UpdateChatPhoto {}
 
 
 impl UpdateChatPhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatPhotoBuilder {
-    let mut inner = UpdateChatPhoto::default();
-    inner.td_name = "updateChatPhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatPhoto { td_name: "updateChatPhoto".to_string(), ..Default::default() };
     RTDUpdateChatPhotoBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatPhoto` with the fields tdlib requires, the others are set by `UpdateChatPhoto::builder()`
+  pub fn new(chat_id: ChatId) -> Self { Self::builder().chat_id(chat_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn photo(&self) -> &Option<ChatPhotoInfo> { &self.photo }
 
@@ -2691,8 +3208,14 @@ pub struct RTDUpdateChatPhotoBuilder {
 impl RTDUpdateChatPhotoBuilder {
   pub fn build(&self) -> UpdateChatPhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2713,6 +3236,13 @@ impl AsRef<UpdateChatPhoto> for RTDUpdateChatPhotoBuilder {
   fn as_ref(&self) -> &UpdateChatPhoto { &self.inner }
 }
 
+impl PartialEq for UpdateChatPhoto {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.photo == other.photo
+  }
+}
+
 
 
 
@@ -2727,17 +3257,21 @@ pub struct UpdateChatPosition {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// New chat position. If new order is 0, then the chat needs to be removed from the list
   position: ChatPosition,
   
 }
 
 impl RObject for UpdateChatPosition {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatPosition" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatPosition" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2747,15 +3281,16 @@ impl TDUpdate for UpdateChatPosition {}
 
 
 impl UpdateChatPosition {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatPositionBuilder {
-    let mut inner = UpdateChatPosition::default();
-    inner.td_name = "updateChatPosition".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatPosition { td_name: "updateChatPosition".to_string(), ..Default::default() };
     RTDUpdateChatPositionBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatPosition` with the fields tdlib requires, the others are set by `UpdateChatPosition::builder()`
+  pub fn new(chat_id: ChatId, position: impl AsRef<ChatPosition>) -> Self { Self::builder().chat_id(chat_id).position(position).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn position(&self) -> &ChatPosition { &self.position }
 
@@ -2769,8 +3304,14 @@ pub struct RTDUpdateChatPositionBuilder {
 impl RTDUpdateChatPositionBuilder {
   pub fn build(&self) -> UpdateChatPosition { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -2791,6 +3332,13 @@ impl AsRef<UpdateChatPosition> for RTDUpdateChatPositionBuilder {
   fn as_ref(&self) -> &UpdateChatPosition { &self.inner }
 }
 
+impl PartialEq for UpdateChatPosition {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.position == other.position
+  }
+}
+
 
 
 
@@ -2805,19 +3353,23 @@ pub struct UpdateChatReadInbox {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Identifier of the last read incoming message
-  last_read_inbox_message_id: i64,
+  last_read_inbox_message_id: MessageId,
   /// The number of unread messages left in the chat
   unread_count: i64,
   
 }
 
 impl RObject for UpdateChatReadInbox {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatReadInbox" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatReadInbox" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2827,17 +3379,18 @@ impl TDUpdate for UpdateChatReadInbox {}
 
 
 impl UpdateChatReadInbox {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatReadInboxBuilder {
-    let mut inner = UpdateChatReadInbox::default();
-    inner.td_name = "updateChatReadInbox".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatReadInbox { td_name: "updateChatReadInbox".to_string(), ..Default::default() };
     RTDUpdateChatReadInboxBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatReadInbox` with the fields tdlib requires, the others are set by `UpdateChatReadInbox::builder()`
+  pub fn new(chat_id: ChatId, last_read_inbox_message_id: MessageId, unread_count: i64) -> Self { Self::builder().chat_id(chat_id).last_read_inbox_message_id(last_read_inbox_message_id).unread_count(unread_count).build() }
 
-  pub fn last_read_inbox_message_id(&self) -> i64 { self.last_read_inbox_message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn last_read_inbox_message_id(&self) -> MessageId { self.last_read_inbox_message_id }
 
   pub fn unread_count(&self) -> i64 { self.unread_count }
 
@@ -2851,14 +3404,20 @@ pub struct RTDUpdateChatReadInboxBuilder {
 impl RTDUpdateChatReadInboxBuilder {
   pub fn build(&self) -> UpdateChatReadInbox { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
+  pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: MessageId) -> &mut Self {
     self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
     self
   }
@@ -2879,6 +3438,14 @@ impl AsRef<UpdateChatReadInbox> for RTDUpdateChatReadInboxBuilder {
   fn as_ref(&self) -> &UpdateChatReadInbox { &self.inner }
 }
 
+impl PartialEq for UpdateChatReadInbox {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.last_read_inbox_message_id == other.last_read_inbox_message_id
+      && self.unread_count == other.unread_count
+  }
+}
+
 
 
 
@@ -2893,17 +3460,21 @@ pub struct UpdateChatReadOutbox {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Identifier of last read outgoing message
-  last_read_outbox_message_id: i64,
+  last_read_outbox_message_id: MessageId,
   
 }
 
 impl RObject for UpdateChatReadOutbox {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatReadOutbox" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatReadOutbox" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2913,17 +3484,18 @@ impl TDUpdate for UpdateChatReadOutbox {}
 
 
 impl UpdateChatReadOutbox {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatReadOutboxBuilder {
-    let mut inner = UpdateChatReadOutbox::default();
-    inner.td_name = "updateChatReadOutbox".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatReadOutbox { td_name: "updateChatReadOutbox".to_string(), ..Default::default() };
     RTDUpdateChatReadOutboxBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatReadOutbox` with the fields tdlib requires, the others are set by `UpdateChatReadOutbox::builder()`
+  pub fn new(chat_id: ChatId, last_read_outbox_message_id: MessageId) -> Self { Self::builder().chat_id(chat_id).last_read_outbox_message_id(last_read_outbox_message_id).build() }
 
-  pub fn last_read_outbox_message_id(&self) -> i64 { self.last_read_outbox_message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn last_read_outbox_message_id(&self) -> MessageId { self.last_read_outbox_message_id }
 
 }
 
@@ -2935,14 +3507,20 @@ pub struct RTDUpdateChatReadOutboxBuilder {
 impl RTDUpdateChatReadOutboxBuilder {
   pub fn build(&self) -> UpdateChatReadOutbox { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
+  pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: MessageId) -> &mut Self {
     self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
     self
   }
@@ -2957,6 +3535,13 @@ impl AsRef<UpdateChatReadOutbox> for RTDUpdateChatReadOutboxBuilder {
   fn as_ref(&self) -> &UpdateChatReadOutbox { &self.inner }
 }
 
+impl PartialEq for UpdateChatReadOutbox {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.last_read_outbox_message_id == other.last_read_outbox_message_id
+  }
+}
+
 
 
 
@@ -2971,17 +3556,21 @@ pub struct UpdateChatReplyMarkup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat
-  reply_markup_message_id: i64,
+  reply_markup_message_id: MessageId,
   
 }
 
 impl RObject for UpdateChatReplyMarkup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatReplyMarkup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatReplyMarkup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -2991,17 +3580,18 @@ impl TDUpdate for UpdateChatReplyMarkup {}
 
 
 impl UpdateChatReplyMarkup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatReplyMarkupBuilder {
-    let mut inner = UpdateChatReplyMarkup::default();
-    inner.td_name = "updateChatReplyMarkup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatReplyMarkup { td_name: "updateChatReplyMarkup".to_string(), ..Default::default() };
     RTDUpdateChatReplyMarkupBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatReplyMarkup` with the fields tdlib requires, the others are set by `UpdateChatReplyMarkup::builder()`
+  pub fn new(chat_id: ChatId, reply_markup_message_id: MessageId) -> Self { Self::builder().chat_id(chat_id).reply_markup_message_id(reply_markup_message_id).build() }
 
-  pub fn reply_markup_message_id(&self) -> i64 { self.reply_markup_message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn reply_markup_message_id(&self) -> MessageId { self.reply_markup_message_id }
 
 }
 
@@ -3013,14 +3603,20 @@ pub struct RTDUpdateChatReplyMarkupBuilder {
 impl RTDUpdateChatReplyMarkupBuilder {
   pub fn build(&self) -> UpdateChatReplyMarkup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn reply_markup_message_id(&mut self, reply_markup_message_id: i64) -> &mut Self {
+  pub fn reply_markup_message_id(&mut self, reply_markup_message_id: MessageId) -> &mut Self {
     self.inner.reply_markup_message_id = reply_markup_message_id;
     self
   }
@@ -3035,6 +3631,13 @@ impl AsRef<UpdateChatReplyMarkup> for RTDUpdateChatReplyMarkupBuilder {
   fn as_ref(&self) -> &UpdateChatReplyMarkup { &self.inner }
 }
 
+impl PartialEq for UpdateChatReplyMarkup {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.reply_markup_message_id == other.reply_markup_message_id
+  }
+}
+
 
 
 
@@ -3049,17 +3652,21 @@ pub struct UpdateChatTheme {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new name of the chat theme; may be empty if theme was reset to default
   theme_name: String,
   
 }
 
 impl RObject for UpdateChatTheme {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatTheme" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatTheme" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3069,15 +3676,16 @@ impl TDUpdate for UpdateChatTheme {}
 
 
 impl UpdateChatTheme {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatThemeBuilder {
-    let mut inner = UpdateChatTheme::default();
-    inner.td_name = "updateChatTheme".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatTheme { td_name: "updateChatTheme".to_string(), ..Default::default() };
     RTDUpdateChatThemeBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatTheme` with the fields tdlib requires, the others are set by `UpdateChatTheme::builder()`
+  pub fn new(chat_id: ChatId, theme_name: impl AsRef<str>) -> Self { Self::builder().chat_id(chat_id).theme_name(theme_name).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn theme_name(&self) -> &String { &self.theme_name }
 
@@ -3091,8 +3699,14 @@ pub struct RTDUpdateChatThemeBuilder {
 impl RTDUpdateChatThemeBuilder {
   pub fn build(&self) -> UpdateChatTheme { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -3113,6 +3727,13 @@ impl AsRef<UpdateChatTheme> for RTDUpdateChatThemeBuilder {
   fn as_ref(&self) -> &UpdateChatTheme { &self.inner }
 }
 
+impl PartialEq for UpdateChatTheme {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.theme_name == other.theme_name
+  }
+}
+
 
 
 
@@ -3127,15 +3748,19 @@ pub struct UpdateChatThemes {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new list of chat themes
   chat_themes: Vec<ChatTheme>,
   
 }
 
 impl RObject for UpdateChatThemes {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatThemes" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatThemes" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3145,14 +3770,15 @@ impl TDUpdate for UpdateChatThemes {}
 
 
 impl UpdateChatThemes {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatThemesBuilder {
-    let mut inner = UpdateChatThemes::default();
-    inner.td_name = "updateChatThemes".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatThemes { td_name: "updateChatThemes".to_string(), ..Default::default() };
     RTDUpdateChatThemesBuilder { inner }
   }
 
+  /// `UpdateChatThemes` with the fields tdlib requires, the others are set by `UpdateChatThemes::builder()`
+  pub fn new(chat_themes: Vec<ChatTheme>) -> Self { Self::builder().chat_themes(chat_themes).build() }
+
   pub fn chat_themes(&self) -> &Vec<ChatTheme> { &self.chat_themes }
 
 }
@@ -3165,6 +3791,12 @@ pub struct RTDUpdateChatThemesBuilder {
 impl RTDUpdateChatThemesBuilder {
   pub fn build(&self) -> UpdateChatThemes { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_themes(&mut self, chat_themes: Vec<ChatTheme>) -> &mut Self {
     self.inner.chat_themes = chat_themes;
@@ -3181,6 +3813,12 @@ impl AsRef<UpdateChatThemes> for RTDUpdateChatThemesBuilder {
   fn as_ref(&self) -> &UpdateChatThemes { &self.inner }
 }
 
+impl PartialEq for UpdateChatThemes {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_themes == other.chat_themes
+  }
+}
+
 
 
 
@@ -3195,17 +3833,21 @@ pub struct UpdateChatTitle {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The new chat title
   title: String,
   
 }
 
 impl RObject for UpdateChatTitle {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatTitle" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatTitle" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3215,15 +3857,16 @@ impl TDUpdate for UpdateChatTitle {}
 
 
 impl UpdateChatTitle {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatTitleBuilder {
-    let mut inner = UpdateChatTitle::default();
-    inner.td_name = "updateChatTitle".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatTitle { td_name: "updateChatTitle".to_string(), ..Default::default() };
     RTDUpdateChatTitleBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatTitle` with the fields tdlib requires, the others are set by `UpdateChatTitle::builder()`
+  pub fn new(chat_id: ChatId, title: impl AsRef<str>) -> Self { Self::builder().chat_id(chat_id).title(title).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn title(&self) -> &String { &self.title }
 
@@ -3237,8 +3880,14 @@ pub struct RTDUpdateChatTitleBuilder {
 impl RTDUpdateChatTitleBuilder {
   pub fn build(&self) -> UpdateChatTitle { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -3259,6 +3908,13 @@ impl AsRef<UpdateChatTitle> for RTDUpdateChatTitleBuilder {
   fn as_ref(&self) -> &UpdateChatTitle { &self.inner }
 }
 
+impl PartialEq for UpdateChatTitle {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.title == other.title
+  }
+}
+
 
 
 
@@ -3273,17 +3929,21 @@ pub struct UpdateChatUnreadMentionCount {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The number of unread mention messages left in the chat
   unread_mention_count: i64,
   
 }
 
 impl RObject for UpdateChatUnreadMentionCount {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatUnreadMentionCount" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatUnreadMentionCount" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3293,15 +3953,16 @@ impl TDUpdate for UpdateChatUnreadMentionCount {}
 
 
 impl UpdateChatUnreadMentionCount {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatUnreadMentionCountBuilder {
-    let mut inner = UpdateChatUnreadMentionCount::default();
-    inner.td_name = "updateChatUnreadMentionCount".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatUnreadMentionCount { td_name: "updateChatUnreadMentionCount".to_string(), ..Default::default() };
     RTDUpdateChatUnreadMentionCountBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatUnreadMentionCount` with the fields tdlib requires, the others are set by `UpdateChatUnreadMentionCount::builder()`
+  pub fn new(chat_id: ChatId, unread_mention_count: i64) -> Self { Self::builder().chat_id(chat_id).unread_mention_count(unread_mention_count).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn unread_mention_count(&self) -> i64 { self.unread_mention_count }
 
@@ -3315,8 +3976,14 @@ pub struct RTDUpdateChatUnreadMentionCountBuilder {
 impl RTDUpdateChatUnreadMentionCountBuilder {
   pub fn build(&self) -> UpdateChatUnreadMentionCount { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -3337,6 +4004,13 @@ impl AsRef<UpdateChatUnreadMentionCount> for RTDUpdateChatUnreadMentionCountBuil
   fn as_ref(&self) -> &UpdateChatUnreadMentionCount { &self.inner }
 }
 
+impl PartialEq for UpdateChatUnreadMentionCount {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.unread_mention_count == other.unread_mention_count
+  }
+}
+
 
 
 
@@ -3351,17 +4025,21 @@ pub struct UpdateChatVideoChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// New value of video_chat
   video_chat: VideoChat,
   
 }
 
 impl RObject for UpdateChatVideoChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateChatVideoChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateChatVideoChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3371,15 +4049,16 @@ impl TDUpdate for UpdateChatVideoChat {}
 
 
 impl UpdateChatVideoChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateChatVideoChatBuilder {
-    let mut inner = UpdateChatVideoChat::default();
-    inner.td_name = "updateChatVideoChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateChatVideoChat { td_name: "updateChatVideoChat".to_string(), ..Default::default() };
     RTDUpdateChatVideoChatBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateChatVideoChat` with the fields tdlib requires, the others are set by `UpdateChatVideoChat::builder()`
+  pub fn new(chat_id: ChatId, video_chat: impl AsRef<VideoChat>) -> Self { Self::builder().chat_id(chat_id).video_chat(video_chat).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn video_chat(&self) -> &VideoChat { &self.video_chat }
 
@@ -3393,8 +4072,14 @@ pub struct RTDUpdateChatVideoChatBuilder {
 impl RTDUpdateChatVideoChatBuilder {
   pub fn build(&self) -> UpdateChatVideoChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -3415,6 +4100,13 @@ impl AsRef<UpdateChatVideoChat> for RTDUpdateChatVideoChatBuilder {
   fn as_ref(&self) -> &UpdateChatVideoChat { &self.inner }
 }
 
+impl PartialEq for UpdateChatVideoChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.video_chat == other.video_chat
+  }
+}
+
 
 
 
@@ -3429,15 +4121,19 @@ pub struct UpdateConnectionState {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new connection state
   state: ConnectionState,
   
 }
 
 impl RObject for UpdateConnectionState {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateConnectionState" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateConnectionState" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3447,14 +4143,15 @@ impl TDUpdate for UpdateConnectionState {}
 
 
 impl UpdateConnectionState {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateConnectionStateBuilder {
-    let mut inner = UpdateConnectionState::default();
-    inner.td_name = "updateConnectionState".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateConnectionState { td_name: "updateConnectionState".to_string(), ..Default::default() };
     RTDUpdateConnectionStateBuilder { inner }
   }
 
+  /// `UpdateConnectionState` with the fields tdlib requires, the others are set by `UpdateConnectionState::builder()`
+  pub fn new(state: impl AsRef<ConnectionState>) -> Self { Self::builder().state(state).build() }
+
   pub fn state(&self) -> &ConnectionState { &self.state }
 
 }
@@ -3467,6 +4164,12 @@ pub struct RTDUpdateConnectionStateBuilder {
 impl RTDUpdateConnectionStateBuilder {
   pub fn build(&self) -> UpdateConnectionState { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn state<T: AsRef<ConnectionState>>(&mut self, state: T) -> &mut Self {
     self.inner.state = state.as_ref().clone();
@@ -3483,6 +4186,12 @@ impl AsRef<UpdateConnectionState> for RTDUpdateConnectionStateBuilder {
   fn as_ref(&self) -> &UpdateConnectionState { &self.inner }
 }
 
+impl PartialEq for UpdateConnectionState {
+  fn eq(&self, other: &Self) -> bool {
+    self.state == other.state
+  }
+}
+
 
 
 
@@ -3497,11 +4206,14 @@ pub struct UpdateDeleteMessages {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Identifiers of the deleted messages
-  message_ids: Vec<i64>,
+  message_ids: Vec<MessageId>,
   /// True, if the messages are permanently deleted by a user (as opposed to just becoming inaccessible)
   is_permanent: bool,
   /// True, if the messages are deleted only from the cache and can possibly be retrieved again in the future
@@ -3510,8 +4222,9 @@ pub struct UpdateDeleteMessages {
 }
 
 impl RObject for UpdateDeleteMessages {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateDeleteMessages" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateDeleteMessages" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3521,17 +4234,30 @@ impl TDUpdate for UpdateDeleteMessages {}
 
 
 impl UpdateDeleteMessages {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateDeleteMessagesBuilder {
-    let mut inner = UpdateDeleteMessages::default();
-    inner.td_name = "updateDeleteMessages".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateDeleteMessages { td_name: "updateDeleteMessages".to_string(), ..Default::default() };
     RTDUpdateDeleteMessagesBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateDeleteMessages` with the fields tdlib requires, the others are set by `UpdateDeleteMessages::builder()`
+  pub fn new(
+    chat_id: ChatId,
+    message_ids: Vec<MessageId>,
+    is_permanent: bool,
+    from_cache: bool,
+  ) -> Self {
+    Self::builder()
+      .chat_id(chat_id)
+      .message_ids(message_ids)
+      .is_permanent(is_permanent)
+      .from_cache(from_cache)
+      .build()
+  }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn message_ids(&self) -> &Vec<i64> { &self.message_ids }
+  pub fn message_ids(&self) -> &Vec<MessageId> { &self.message_ids }
 
   pub fn is_permanent(&self) -> bool { self.is_permanent }
 
@@ -3547,14 +4273,20 @@ pub struct RTDUpdateDeleteMessagesBuilder {
 impl RTDUpdateDeleteMessagesBuilder {
   pub fn build(&self) -> UpdateDeleteMessages { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_ids(&mut self, message_ids: Vec<i64>) -> &mut Self {
+  pub fn message_ids(&mut self, message_ids: Vec<MessageId>) -> &mut Self {
     self.inner.message_ids = message_ids;
     self
   }
@@ -3581,6 +4313,15 @@ impl AsRef<UpdateDeleteMessages> for RTDUpdateDeleteMessagesBuilder {
   fn as_ref(&self) -> &UpdateDeleteMessages { &self.inner }
 }
 
+impl PartialEq for UpdateDeleteMessages {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_ids == other.message_ids
+      && self.is_permanent == other.is_permanent
+      && self.from_cache == other.from_cache
+  }
+}
+
 
 
 
@@ -3595,15 +4336,19 @@ pub struct UpdateDiceEmojis {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new list of supported dice emojis
   emojis: Vec<String>,
   
 }
 
 impl RObject for UpdateDiceEmojis {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateDiceEmojis" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateDiceEmojis" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3613,14 +4358,15 @@ impl TDUpdate for UpdateDiceEmojis {}
 
 
 impl UpdateDiceEmojis {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateDiceEmojisBuilder {
-    let mut inner = UpdateDiceEmojis::default();
-    inner.td_name = "updateDiceEmojis".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateDiceEmojis { td_name: "updateDiceEmojis".to_string(), ..Default::default() };
     RTDUpdateDiceEmojisBuilder { inner }
   }
 
+  /// `UpdateDiceEmojis` with the fields tdlib requires, the others are set by `UpdateDiceEmojis::builder()`
+  pub fn new(emojis: Vec<String>) -> Self { Self::builder().emojis(emojis).build() }
+
   pub fn emojis(&self) -> &Vec<String> { &self.emojis }
 
 }
@@ -3633,6 +4379,12 @@ pub struct RTDUpdateDiceEmojisBuilder {
 impl RTDUpdateDiceEmojisBuilder {
   pub fn build(&self) -> UpdateDiceEmojis { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn emojis(&mut self, emojis: Vec<String>) -> &mut Self {
     self.inner.emojis = emojis;
@@ -3649,11 +4401,19 @@ impl AsRef<UpdateDiceEmojis> for RTDUpdateDiceEmojisBuilder {
   fn as_ref(&self) -> &UpdateDiceEmojis { &self.inner }
 }
 
+impl PartialEq for UpdateDiceEmojis {
+  fn eq(&self, other: &Self) -> bool {
+    self.emojis == other.emojis
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
 
 /// The list of favorite stickers was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -3663,15 +4423,19 @@ pub struct UpdateFavoriteStickers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new list of file identifiers of favorite stickers
   sticker_ids: Vec<i64>,
   
 }
 
 impl RObject for UpdateFavoriteStickers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFavoriteStickers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateFavoriteStickers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3681,14 +4445,15 @@ impl TDUpdate for UpdateFavoriteStickers {}
 
 
 impl UpdateFavoriteStickers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateFavoriteStickersBuilder {
-    let mut inner = UpdateFavoriteStickers::default();
-    inner.td_name = "updateFavoriteStickers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateFavoriteStickers { td_name: "updateFavoriteStickers".to_string(), ..Default::default() };
     RTDUpdateFavoriteStickersBuilder { inner }
   }
 
+  /// `UpdateFavoriteStickers` with the fields tdlib requires, the others are set by `UpdateFavoriteStickers::builder()`
+  pub fn new(sticker_ids: Vec<i64>) -> Self { Self::builder().sticker_ids(sticker_ids).build() }
+
   pub fn sticker_ids(&self) -> &Vec<i64> { &self.sticker_ids }
 
 }
@@ -3701,6 +4466,12 @@ pub struct RTDUpdateFavoriteStickersBuilder {
 impl RTDUpdateFavoriteStickersBuilder {
   pub fn build(&self) -> UpdateFavoriteStickers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker_ids(&mut self, sticker_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_ids = sticker_ids;
@@ -3717,6 +4488,14 @@ impl AsRef<UpdateFavoriteStickers> for RTDUpdateFavoriteStickersBuilder {
   fn as_ref(&self) -> &UpdateFavoriteStickers { &self.inner }
 }
 
+impl PartialEq for UpdateFavoriteStickers {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker_ids == other.sticker_ids
+  }
+}
+
+}
+
 
 
 
@@ -3731,15 +4510,19 @@ pub struct UpdateFile {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about the file
   file: File,
   
 }
 
 impl RObject for UpdateFile {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFile" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateFile" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3749,14 +4532,15 @@ impl TDUpdate for UpdateFile {}
 
 
 impl UpdateFile {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateFileBuilder {
-    let mut inner = UpdateFile::default();
-    inner.td_name = "updateFile".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateFile { td_name: "updateFile".to_string(), ..Default::default() };
     RTDUpdateFileBuilder { inner }
   }
 
+  /// `UpdateFile` with the fields tdlib requires, the others are set by `UpdateFile::builder()`
+  pub fn new(file: impl AsRef<File>) -> Self { Self::builder().file(file).build() }
+
   pub fn file(&self) -> &File { &self.file }
 
 }
@@ -3769,6 +4553,12 @@ pub struct RTDUpdateFileBuilder {
 impl RTDUpdateFileBuilder {
   pub fn build(&self) -> UpdateFile { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
@@ -3785,6 +4575,12 @@ impl AsRef<UpdateFile> for RTDUpdateFileBuilder {
   fn as_ref(&self) -> &UpdateFile { &self.inner }
 }
 
+impl PartialEq for UpdateFile {
+  fn eq(&self, other: &Self) -> bool {
+    self.file == other.file
+  }
+}
+
 
 
 
@@ -3799,9 +4595,12 @@ pub struct UpdateFileGenerationStart {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier for the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64")] generation_id: i64,
   /// The path to a file from which a new file is generated; may be empty
   original_path: String,
   /// The path to a file that must be created and where the new file is generated
@@ -3812,8 +4611,9 @@ pub struct UpdateFileGenerationStart {
 }
 
 impl RObject for UpdateFileGenerationStart {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFileGenerationStart" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateFileGenerationStart" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3823,15 +4623,28 @@ impl TDUpdate for UpdateFileGenerationStart {}
 
 
 impl UpdateFileGenerationStart {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateFileGenerationStartBuilder {
-    let mut inner = UpdateFileGenerationStart::default();
-    inner.td_name = "updateFileGenerationStart".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateFileGenerationStart { td_name: "updateFileGenerationStart".to_string(), ..Default::default() };
     RTDUpdateFileGenerationStartBuilder { inner }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  /// `UpdateFileGenerationStart` with the fields tdlib requires, the others are set by `UpdateFileGenerationStart::builder()`
+  pub fn new(
+    generation_id: i64,
+    original_path: impl AsRef<str>,
+    destination_path: impl AsRef<str>,
+    conversion: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .generation_id(generation_id)
+      .original_path(original_path)
+      .destination_path(destination_path)
+      .conversion(conversion)
+      .build()
+  }
+
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
   pub fn original_path(&self) -> &String { &self.original_path }
 
@@ -3849,8 +4662,14 @@ pub struct RTDUpdateFileGenerationStartBuilder {
 impl RTDUpdateFileGenerationStartBuilder {
   pub fn build(&self) -> UpdateFileGenerationStart { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
     self
   }
@@ -3883,6 +4702,15 @@ impl AsRef<UpdateFileGenerationStart> for RTDUpdateFileGenerationStartBuilder {
   fn as_ref(&self) -> &UpdateFileGenerationStart { &self.inner }
 }
 
+impl PartialEq for UpdateFileGenerationStart {
+  fn eq(&self, other: &Self) -> bool {
+    self.generation_id == other.generation_id
+      && self.original_path == other.original_path
+      && self.destination_path == other.destination_path
+      && self.conversion == other.conversion
+  }
+}
+
 
 
 
@@ -3897,15 +4725,19 @@ pub struct UpdateFileGenerationStop {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique identifier for the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64")] generation_id: i64,
   
 }
 
 impl RObject for UpdateFileGenerationStop {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateFileGenerationStop" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateFileGenerationStop" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3915,15 +4747,16 @@ impl TDUpdate for UpdateFileGenerationStop {}
 
 
 impl UpdateFileGenerationStop {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateFileGenerationStopBuilder {
-    let mut inner = UpdateFileGenerationStop::default();
-    inner.td_name = "updateFileGenerationStop".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateFileGenerationStop { td_name: "updateFileGenerationStop".to_string(), ..Default::default() };
     RTDUpdateFileGenerationStopBuilder { inner }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  /// `UpdateFileGenerationStop` with the fields tdlib requires, the others are set by `UpdateFileGenerationStop::builder()`
+  pub fn new(generation_id: i64) -> Self { Self::builder().generation_id(generation_id).build() }
+
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
 }
 
@@ -3935,8 +4768,14 @@ pub struct RTDUpdateFileGenerationStopBuilder {
 impl RTDUpdateFileGenerationStopBuilder {
   pub fn build(&self) -> UpdateFileGenerationStop { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
     self
   }
@@ -3951,12 +4790,20 @@ impl AsRef<UpdateFileGenerationStop> for RTDUpdateFileGenerationStopBuilder {
   fn as_ref(&self) -> &UpdateFileGenerationStop { &self.inner }
 }
 
+impl PartialEq for UpdateFileGenerationStop {
+  fn eq(&self, other: &Self) -> bool {
+    self.generation_id == other.generation_id
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
+
 /// Information about a group call was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateGroupCall {
@@ -3965,15 +4812,19 @@ pub struct UpdateGroupCall {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about a group call
   group_call: GroupCall,
   
 }
 
 impl RObject for UpdateGroupCall {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateGroupCall" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateGroupCall" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -3983,14 +4834,15 @@ impl TDUpdate for UpdateGroupCall {}
 
 
 impl UpdateGroupCall {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateGroupCallBuilder {
-    let mut inner = UpdateGroupCall::default();
-    inner.td_name = "updateGroupCall".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateGroupCall { td_name: "updateGroupCall".to_string(), ..Default::default() };
     RTDUpdateGroupCallBuilder { inner }
   }
 
+  /// `UpdateGroupCall` with the fields tdlib requires, the others are set by `UpdateGroupCall::builder()`
+  pub fn new(group_call: impl AsRef<GroupCall>) -> Self { Self::builder().group_call(group_call).build() }
+
   pub fn group_call(&self) -> &GroupCall { &self.group_call }
 
 }
@@ -4003,6 +4855,12 @@ pub struct RTDUpdateGroupCallBuilder {
 impl RTDUpdateGroupCallBuilder {
   pub fn build(&self) -> UpdateGroupCall { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call<T: AsRef<GroupCall>>(&mut self, group_call: T) -> &mut Self {
     self.inner.group_call = group_call.as_ref().clone();
@@ -4019,12 +4877,22 @@ impl AsRef<UpdateGroupCall> for RTDUpdateGroupCallBuilder {
   fn as_ref(&self) -> &UpdateGroupCall { &self.inner }
 }
 
+impl PartialEq for UpdateGroupCall {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call == other.group_call
+  }
+}
 
+}
 
 
 
 
 
+
+
+rtd_cfg! { #[cfg(feature = "calls")]
+
 /// Information about a group call participant was changed. The updates are sent only after the group call is received through getGroupCall and only if the call is joined or being joined
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateGroupCallParticipant {
@@ -4033,7 +4901,10 @@ pub struct UpdateGroupCallParticipant {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of group call
   group_call_id: i64,
   /// New data about a participant
@@ -4042,8 +4913,9 @@ pub struct UpdateGroupCallParticipant {
 }
 
 impl RObject for UpdateGroupCallParticipant {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateGroupCallParticipant" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateGroupCallParticipant" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4053,14 +4925,15 @@ impl TDUpdate for UpdateGroupCallParticipant {}
 
 
 impl UpdateGroupCallParticipant {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateGroupCallParticipantBuilder {
-    let mut inner = UpdateGroupCallParticipant::default();
-    inner.td_name = "updateGroupCallParticipant".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateGroupCallParticipant { td_name: "updateGroupCallParticipant".to_string(), ..Default::default() };
     RTDUpdateGroupCallParticipantBuilder { inner }
   }
 
+  /// `UpdateGroupCallParticipant` with the fields tdlib requires, the others are set by `UpdateGroupCallParticipant::builder()`
+  pub fn new(group_call_id: i64, participant: impl AsRef<GroupCallParticipant>) -> Self { Self::builder().group_call_id(group_call_id).participant(participant).build() }
+
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
 
   pub fn participant(&self) -> &GroupCallParticipant { &self.participant }
@@ -4075,6 +4948,12 @@ pub struct RTDUpdateGroupCallParticipantBuilder {
 impl RTDUpdateGroupCallParticipantBuilder {
   pub fn build(&self) -> UpdateGroupCallParticipant { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
@@ -4097,6 +4976,15 @@ impl AsRef<UpdateGroupCallParticipant> for RTDUpdateGroupCallParticipantBuilder
   fn as_ref(&self) -> &UpdateGroupCallParticipant { &self.inner }
 }
 
+impl PartialEq for UpdateGroupCallParticipant {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call_id == other.group_call_id
+      && self.participant == other.participant
+  }
+}
+
+}
+
 
 
 
@@ -4111,7 +4999,10 @@ pub struct UpdateHavePendingNotifications {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if there are some delayed notification updates, which will be sent soon
   have_delayed_notifications: bool,
   /// True, if there can be some yet unreceived notifications, which are being fetched from the server
@@ -4120,8 +5011,9 @@ pub struct UpdateHavePendingNotifications {
 }
 
 impl RObject for UpdateHavePendingNotifications {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateHavePendingNotifications" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateHavePendingNotifications" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4131,14 +5023,15 @@ impl TDUpdate for UpdateHavePendingNotifications {}
 
 
 impl UpdateHavePendingNotifications {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateHavePendingNotificationsBuilder {
-    let mut inner = UpdateHavePendingNotifications::default();
-    inner.td_name = "updateHavePendingNotifications".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateHavePendingNotifications { td_name: "updateHavePendingNotifications".to_string(), ..Default::default() };
     RTDUpdateHavePendingNotificationsBuilder { inner }
   }
 
+  /// `UpdateHavePendingNotifications` with the fields tdlib requires, the others are set by `UpdateHavePendingNotifications::builder()`
+  pub fn new(have_delayed_notifications: bool, have_unreceived_notifications: bool) -> Self { Self::builder().have_delayed_notifications(have_delayed_notifications).have_unreceived_notifications(have_unreceived_notifications).build() }
+
   pub fn have_delayed_notifications(&self) -> bool { self.have_delayed_notifications }
 
   pub fn have_unreceived_notifications(&self) -> bool { self.have_unreceived_notifications }
@@ -4153,6 +5046,12 @@ pub struct RTDUpdateHavePendingNotificationsBuilder {
 impl RTDUpdateHavePendingNotificationsBuilder {
   pub fn build(&self) -> UpdateHavePendingNotifications { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn have_delayed_notifications(&mut self, have_delayed_notifications: bool) -> &mut Self {
     self.inner.have_delayed_notifications = have_delayed_notifications;
@@ -4175,11 +5074,20 @@ impl AsRef<UpdateHavePendingNotifications> for RTDUpdateHavePendingNotifications
   fn as_ref(&self) -> &UpdateHavePendingNotifications { &self.inner }
 }
 
+impl PartialEq for UpdateHavePendingNotifications {
+  fn eq(&self, other: &Self) -> bool {
+    self.have_delayed_notifications == other.have_delayed_notifications
+      && self.have_unreceived_notifications == other.have_unreceived_notifications
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
 
 /// The list of installed sticker sets was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -4189,17 +5097,21 @@ pub struct UpdateInstalledStickerSets {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the list of installed mask sticker sets was updated
   is_masks: bool,
   /// The new list of installed ordinary sticker sets
-  sticker_set_ids: Vec<isize>,
+  #[serde(with = "crate::types::_common::vec_int64")] sticker_set_ids: Vec<i64>,
   
 }
 
 impl RObject for UpdateInstalledStickerSets {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateInstalledStickerSets" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateInstalledStickerSets" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4209,17 +5121,18 @@ impl TDUpdate for UpdateInstalledStickerSets {}
 
 
 impl UpdateInstalledStickerSets {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateInstalledStickerSetsBuilder {
-    let mut inner = UpdateInstalledStickerSets::default();
-    inner.td_name = "updateInstalledStickerSets".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateInstalledStickerSets { td_name: "updateInstalledStickerSets".to_string(), ..Default::default() };
     RTDUpdateInstalledStickerSetsBuilder { inner }
   }
 
+  /// `UpdateInstalledStickerSets` with the fields tdlib requires, the others are set by `UpdateInstalledStickerSets::builder()`
+  pub fn new(is_masks: bool, sticker_set_ids: Vec<i64>) -> Self { Self::builder().is_masks(is_masks).sticker_set_ids(sticker_set_ids).build() }
+
   pub fn is_masks(&self) -> bool { self.is_masks }
 
-  pub fn sticker_set_ids(&self) -> &Vec<isize> { &self.sticker_set_ids }
+  pub fn sticker_set_ids(&self) -> &Vec<i64> { &self.sticker_set_ids }
 
 }
 
@@ -4231,6 +5144,12 @@ pub struct RTDUpdateInstalledStickerSetsBuilder {
 impl RTDUpdateInstalledStickerSetsBuilder {
   pub fn build(&self) -> UpdateInstalledStickerSets { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -4238,7 +5157,7 @@ impl RTDUpdateInstalledStickerSetsBuilder {
   }
 
    
-  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_set_ids = sticker_set_ids;
     self
   }
@@ -4253,6 +5172,15 @@ impl AsRef<UpdateInstalledStickerSets> for RTDUpdateInstalledStickerSetsBuilder
   fn as_ref(&self) -> &UpdateInstalledStickerSets { &self.inner }
 }
 
+impl PartialEq for UpdateInstalledStickerSets {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_masks == other.is_masks
+      && self.sticker_set_ids == other.sticker_set_ids
+  }
+}
+
+}
+
 
 
 
@@ -4267,7 +5195,10 @@ pub struct UpdateLanguagePackStrings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Localization target to which the language pack belongs
   localization_target: String,
   /// Identifier of the updated language pack
@@ -4278,8 +5209,9 @@ pub struct UpdateLanguagePackStrings {
 }
 
 impl RObject for UpdateLanguagePackStrings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateLanguagePackStrings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateLanguagePackStrings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4289,14 +5221,15 @@ impl TDUpdate for UpdateLanguagePackStrings {}
 
 
 impl UpdateLanguagePackStrings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateLanguagePackStringsBuilder {
-    let mut inner = UpdateLanguagePackStrings::default();
-    inner.td_name = "updateLanguagePackStrings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateLanguagePackStrings { td_name: "updateLanguagePackStrings".to_string(), ..Default::default() };
     RTDUpdateLanguagePackStringsBuilder { inner }
   }
 
+  /// `UpdateLanguagePackStrings` with the fields tdlib requires, the others are set by `UpdateLanguagePackStrings::builder()`
+  pub fn new(localization_target: impl AsRef<str>, language_pack_id: impl AsRef<str>, strings: Vec<LanguagePackString>) -> Self { Self::builder().localization_target(localization_target).language_pack_id(language_pack_id).strings(strings).build() }
+
   pub fn localization_target(&self) -> &String { &self.localization_target }
 
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
@@ -4313,6 +5246,12 @@ pub struct RTDUpdateLanguagePackStringsBuilder {
 impl RTDUpdateLanguagePackStringsBuilder {
   pub fn build(&self) -> UpdateLanguagePackStrings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn localization_target<T: AsRef<str>>(&mut self, localization_target: T) -> &mut Self {
     self.inner.localization_target = localization_target.as_ref().to_string();
@@ -4341,6 +5280,14 @@ impl AsRef<UpdateLanguagePackStrings> for RTDUpdateLanguagePackStringsBuilder {
   fn as_ref(&self) -> &UpdateLanguagePackStrings { &self.inner }
 }
 
+impl PartialEq for UpdateLanguagePackStrings {
+  fn eq(&self, other: &Self) -> bool {
+    self.localization_target == other.localization_target
+      && self.language_pack_id == other.language_pack_id
+      && self.strings == other.strings
+  }
+}
+
 
 
 
@@ -4355,19 +5302,23 @@ pub struct UpdateMessageContent {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// New message content
   new_content: MessageContent,
   
 }
 
 impl RObject for UpdateMessageContent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageContent" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageContent" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4377,17 +5328,18 @@ impl TDUpdate for UpdateMessageContent {}
 
 
 impl UpdateMessageContent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageContentBuilder {
-    let mut inner = UpdateMessageContent::default();
-    inner.td_name = "updateMessageContent".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageContent { td_name: "updateMessageContent".to_string(), ..Default::default() };
     RTDUpdateMessageContentBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageContent` with the fields tdlib requires, the others are set by `UpdateMessageContent::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId, new_content: impl AsRef<MessageContent>) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).new_content(new_content).build() }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn new_content(&self) -> &MessageContent { &self.new_content }
 
@@ -4401,14 +5353,20 @@ pub struct RTDUpdateMessageContentBuilder {
 impl RTDUpdateMessageContentBuilder {
   pub fn build(&self) -> UpdateMessageContent { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -4429,6 +5387,14 @@ impl AsRef<UpdateMessageContent> for RTDUpdateMessageContentBuilder {
   fn as_ref(&self) -> &UpdateMessageContent { &self.inner }
 }
 
+impl PartialEq for UpdateMessageContent {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.new_content == other.new_content
+  }
+}
+
 
 
 
@@ -4443,17 +5409,21 @@ pub struct UpdateMessageContentOpened {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   
 }
 
 impl RObject for UpdateMessageContentOpened {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageContentOpened" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageContentOpened" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4463,17 +5433,18 @@ impl TDUpdate for UpdateMessageContentOpened {}
 
 
 impl UpdateMessageContentOpened {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageContentOpenedBuilder {
-    let mut inner = UpdateMessageContentOpened::default();
-    inner.td_name = "updateMessageContentOpened".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageContentOpened { td_name: "updateMessageContentOpened".to_string(), ..Default::default() };
     RTDUpdateMessageContentOpenedBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageContentOpened` with the fields tdlib requires, the others are set by `UpdateMessageContentOpened::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).build() }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
 }
 
@@ -4485,14 +5456,20 @@ pub struct RTDUpdateMessageContentOpenedBuilder {
 impl RTDUpdateMessageContentOpenedBuilder {
   pub fn build(&self) -> UpdateMessageContentOpened { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -4507,6 +5484,13 @@ impl AsRef<UpdateMessageContentOpened> for RTDUpdateMessageContentOpenedBuilder
   fn as_ref(&self) -> &UpdateMessageContentOpened { &self.inner }
 }
 
+impl PartialEq for UpdateMessageContentOpened {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+  }
+}
+
 
 
 
@@ -4521,11 +5505,14 @@ pub struct UpdateMessageEdited {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// Point in time (Unix timestamp) when the message was edited
   edit_date: i64,
   /// New message reply markup; may be null
@@ -4534,8 +5521,9 @@ pub struct UpdateMessageEdited {
 }
 
 impl RObject for UpdateMessageEdited {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageEdited" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageEdited" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4545,17 +5533,18 @@ impl TDUpdate for UpdateMessageEdited {}
 
 
 impl UpdateMessageEdited {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageEditedBuilder {
-    let mut inner = UpdateMessageEdited::default();
-    inner.td_name = "updateMessageEdited".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageEdited { td_name: "updateMessageEdited".to_string(), ..Default::default() };
     RTDUpdateMessageEditedBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageEdited` with the fields tdlib requires, the others are set by `UpdateMessageEdited::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId, edit_date: i64) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).edit_date(edit_date).build() }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn edit_date(&self) -> i64 { self.edit_date }
 
@@ -4571,14 +5560,20 @@ pub struct RTDUpdateMessageEditedBuilder {
 impl RTDUpdateMessageEditedBuilder {
   pub fn build(&self) -> UpdateMessageEdited { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -4605,6 +5600,15 @@ impl AsRef<UpdateMessageEdited> for RTDUpdateMessageEditedBuilder {
   fn as_ref(&self) -> &UpdateMessageEdited { &self.inner }
 }
 
+impl PartialEq for UpdateMessageEdited {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.edit_date == other.edit_date
+      && self.reply_markup == other.reply_markup
+  }
+}
+
 
 
 
@@ -4619,19 +5623,23 @@ pub struct UpdateMessageInteractionInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// New information about interactions with the message; may be null
   interaction_info: Option<MessageInteractionInfo>,
   
 }
 
 impl RObject for UpdateMessageInteractionInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageInteractionInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageInteractionInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4641,17 +5649,18 @@ impl TDUpdate for UpdateMessageInteractionInfo {}
 
 
 impl UpdateMessageInteractionInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageInteractionInfoBuilder {
-    let mut inner = UpdateMessageInteractionInfo::default();
-    inner.td_name = "updateMessageInteractionInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageInteractionInfo { td_name: "updateMessageInteractionInfo".to_string(), ..Default::default() };
     RTDUpdateMessageInteractionInfoBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageInteractionInfo` with the fields tdlib requires, the others are set by `UpdateMessageInteractionInfo::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn interaction_info(&self) -> &Option<MessageInteractionInfo> { &self.interaction_info }
 
@@ -4665,14 +5674,20 @@ pub struct RTDUpdateMessageInteractionInfoBuilder {
 impl RTDUpdateMessageInteractionInfoBuilder {
   pub fn build(&self) -> UpdateMessageInteractionInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -4693,6 +5708,14 @@ impl AsRef<UpdateMessageInteractionInfo> for RTDUpdateMessageInteractionInfoBuil
   fn as_ref(&self) -> &UpdateMessageInteractionInfo { &self.inner }
 }
 
+impl PartialEq for UpdateMessageInteractionInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.interaction_info == other.interaction_info
+  }
+}
+
 
 
 
@@ -4707,19 +5730,23 @@ pub struct UpdateMessageIsPinned {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// The message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// True, if the message is pinned
   is_pinned: bool,
   
 }
 
 impl RObject for UpdateMessageIsPinned {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageIsPinned" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageIsPinned" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4729,17 +5756,18 @@ impl TDUpdate for UpdateMessageIsPinned {}
 
 
 impl UpdateMessageIsPinned {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageIsPinnedBuilder {
-    let mut inner = UpdateMessageIsPinned::default();
-    inner.td_name = "updateMessageIsPinned".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageIsPinned { td_name: "updateMessageIsPinned".to_string(), ..Default::default() };
     RTDUpdateMessageIsPinnedBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageIsPinned` with the fields tdlib requires, the others are set by `UpdateMessageIsPinned::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId, is_pinned: bool) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).is_pinned(is_pinned).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
 
@@ -4753,14 +5781,20 @@ pub struct RTDUpdateMessageIsPinnedBuilder {
 impl RTDUpdateMessageIsPinnedBuilder {
   pub fn build(&self) -> UpdateMessageIsPinned { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -4781,6 +5815,14 @@ impl AsRef<UpdateMessageIsPinned> for RTDUpdateMessageIsPinnedBuilder {
   fn as_ref(&self) -> &UpdateMessageIsPinned { &self.inner }
 }
 
+impl PartialEq for UpdateMessageIsPinned {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.is_pinned == other.is_pinned
+  }
+}
+
 
 
 
@@ -4795,17 +5837,21 @@ pub struct UpdateMessageLiveLocationViewed {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the chat with the live location message
-  chat_id: i64,
+  chat_id: ChatId,
   /// Identifier of the message with live location
-  message_id: i64,
+  message_id: MessageId,
   
 }
 
 impl RObject for UpdateMessageLiveLocationViewed {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageLiveLocationViewed" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageLiveLocationViewed" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4815,17 +5861,18 @@ impl TDUpdate for UpdateMessageLiveLocationViewed {}
 
 
 impl UpdateMessageLiveLocationViewed {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageLiveLocationViewedBuilder {
-    let mut inner = UpdateMessageLiveLocationViewed::default();
-    inner.td_name = "updateMessageLiveLocationViewed".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageLiveLocationViewed { td_name: "updateMessageLiveLocationViewed".to_string(), ..Default::default() };
     RTDUpdateMessageLiveLocationViewedBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageLiveLocationViewed` with the fields tdlib requires, the others are set by `UpdateMessageLiveLocationViewed::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
 }
 
@@ -4837,14 +5884,20 @@ pub struct RTDUpdateMessageLiveLocationViewedBuilder {
 impl RTDUpdateMessageLiveLocationViewedBuilder {
   pub fn build(&self) -> UpdateMessageLiveLocationViewed { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -4859,6 +5912,13 @@ impl AsRef<UpdateMessageLiveLocationViewed> for RTDUpdateMessageLiveLocationView
   fn as_ref(&self) -> &UpdateMessageLiveLocationViewed { &self.inner }
 }
 
+impl PartialEq for UpdateMessageLiveLocationViewed {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+  }
+}
+
 
 
 
@@ -4873,19 +5933,23 @@ pub struct UpdateMessageMentionRead {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Message identifier
-  message_id: i64,
+  message_id: MessageId,
   /// The new number of unread mention messages left in the chat
   unread_mention_count: i64,
   
 }
 
 impl RObject for UpdateMessageMentionRead {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageMentionRead" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageMentionRead" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4895,17 +5959,18 @@ impl TDUpdate for UpdateMessageMentionRead {}
 
 
 impl UpdateMessageMentionRead {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageMentionReadBuilder {
-    let mut inner = UpdateMessageMentionRead::default();
-    inner.td_name = "updateMessageMentionRead".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageMentionRead { td_name: "updateMessageMentionRead".to_string(), ..Default::default() };
     RTDUpdateMessageMentionReadBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageMentionRead` with the fields tdlib requires, the others are set by `UpdateMessageMentionRead::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId, unread_mention_count: i64) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).unread_mention_count(unread_mention_count).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
   pub fn unread_mention_count(&self) -> i64 { self.unread_mention_count }
 
@@ -4919,14 +5984,20 @@ pub struct RTDUpdateMessageMentionReadBuilder {
 impl RTDUpdateMessageMentionReadBuilder {
   pub fn build(&self) -> UpdateMessageMentionRead { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -4947,6 +6018,14 @@ impl AsRef<UpdateMessageMentionRead> for RTDUpdateMessageMentionReadBuilder {
   fn as_ref(&self) -> &UpdateMessageMentionRead { &self.inner }
 }
 
+impl PartialEq for UpdateMessageMentionRead {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.unread_mention_count == other.unread_mention_count
+  }
+}
+
 
 
 
@@ -4961,17 +6040,21 @@ pub struct UpdateMessageSendAcknowledged {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat identifier of the sent message
-  chat_id: i64,
+  chat_id: ChatId,
   /// A temporary message identifier
-  message_id: i64,
+  message_id: MessageId,
   
 }
 
 impl RObject for UpdateMessageSendAcknowledged {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageSendAcknowledged" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageSendAcknowledged" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -4981,17 +6064,18 @@ impl TDUpdate for UpdateMessageSendAcknowledged {}
 
 
 impl UpdateMessageSendAcknowledged {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageSendAcknowledgedBuilder {
-    let mut inner = UpdateMessageSendAcknowledged::default();
-    inner.td_name = "updateMessageSendAcknowledged".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageSendAcknowledged { td_name: "updateMessageSendAcknowledged".to_string(), ..Default::default() };
     RTDUpdateMessageSendAcknowledgedBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateMessageSendAcknowledged` with the fields tdlib requires, the others are set by `UpdateMessageSendAcknowledged::builder()`
+  pub fn new(chat_id: ChatId, message_id: MessageId) -> Self { Self::builder().chat_id(chat_id).message_id(message_id).build() }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
+
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
 }
 
@@ -5003,14 +6087,20 @@ pub struct RTDUpdateMessageSendAcknowledgedBuilder {
 impl RTDUpdateMessageSendAcknowledgedBuilder {
   pub fn build(&self) -> UpdateMessageSendAcknowledged { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
@@ -5025,6 +6115,13 @@ impl AsRef<UpdateMessageSendAcknowledged> for RTDUpdateMessageSendAcknowledgedBu
   fn as_ref(&self) -> &UpdateMessageSendAcknowledged { &self.inner }
 }
 
+impl PartialEq for UpdateMessageSendAcknowledged {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+  }
+}
+
 
 
 
@@ -5039,11 +6136,14 @@ pub struct UpdateMessageSendFailed {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The failed to send message
   message: Message,
   /// The previous temporary message identifier
-  old_message_id: i64,
+  old_message_id: MessageId,
   /// An error code
   error_code: i64,
   /// Error message
@@ -5052,8 +6152,9 @@ pub struct UpdateMessageSendFailed {
 }
 
 impl RObject for UpdateMessageSendFailed {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageSendFailed" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageSendFailed" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5063,17 +6164,30 @@ impl TDUpdate for UpdateMessageSendFailed {}
 
 
 impl UpdateMessageSendFailed {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageSendFailedBuilder {
-    let mut inner = UpdateMessageSendFailed::default();
-    inner.td_name = "updateMessageSendFailed".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageSendFailed { td_name: "updateMessageSendFailed".to_string(), ..Default::default() };
     RTDUpdateMessageSendFailedBuilder { inner }
   }
 
+  /// `UpdateMessageSendFailed` with the fields tdlib requires, the others are set by `UpdateMessageSendFailed::builder()`
+  pub fn new(
+    message: impl AsRef<Message>,
+    old_message_id: MessageId,
+    error_code: i64,
+    error_message: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .message(message)
+      .old_message_id(old_message_id)
+      .error_code(error_code)
+      .error_message(error_message)
+      .build()
+  }
+
   pub fn message(&self) -> &Message { &self.message }
 
-  pub fn old_message_id(&self) -> i64 { self.old_message_id }
+  pub fn old_message_id(&self) -> MessageId { self.old_message_id }
 
   pub fn error_code(&self) -> i64 { self.error_code }
 
@@ -5089,6 +6203,12 @@ pub struct RTDUpdateMessageSendFailedBuilder {
 impl RTDUpdateMessageSendFailedBuilder {
   pub fn build(&self) -> UpdateMessageSendFailed { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -5096,7 +6216,7 @@ impl RTDUpdateMessageSendFailedBuilder {
   }
 
    
-  pub fn old_message_id(&mut self, old_message_id: i64) -> &mut Self {
+  pub fn old_message_id(&mut self, old_message_id: MessageId) -> &mut Self {
     self.inner.old_message_id = old_message_id;
     self
   }
@@ -5123,6 +6243,15 @@ impl AsRef<UpdateMessageSendFailed> for RTDUpdateMessageSendFailedBuilder {
   fn as_ref(&self) -> &UpdateMessageSendFailed { &self.inner }
 }
 
+impl PartialEq for UpdateMessageSendFailed {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+      && self.old_message_id == other.old_message_id
+      && self.error_code == other.error_code
+      && self.error_message == other.error_message
+  }
+}
+
 
 
 
@@ -5137,17 +6266,21 @@ pub struct UpdateMessageSendSucceeded {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The sent message. Usually only the message identifier, date, and content are changed, but almost all other fields can also change
   message: Message,
   /// The previous temporary message identifier
-  old_message_id: i64,
+  old_message_id: MessageId,
   
 }
 
 impl RObject for UpdateMessageSendSucceeded {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateMessageSendSucceeded" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateMessageSendSucceeded" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5157,17 +6290,18 @@ impl TDUpdate for UpdateMessageSendSucceeded {}
 
 
 impl UpdateMessageSendSucceeded {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateMessageSendSucceededBuilder {
-    let mut inner = UpdateMessageSendSucceeded::default();
-    inner.td_name = "updateMessageSendSucceeded".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateMessageSendSucceeded { td_name: "updateMessageSendSucceeded".to_string(), ..Default::default() };
     RTDUpdateMessageSendSucceededBuilder { inner }
   }
 
+  /// `UpdateMessageSendSucceeded` with the fields tdlib requires, the others are set by `UpdateMessageSendSucceeded::builder()`
+  pub fn new(message: impl AsRef<Message>, old_message_id: MessageId) -> Self { Self::builder().message(message).old_message_id(old_message_id).build() }
+
   pub fn message(&self) -> &Message { &self.message }
 
-  pub fn old_message_id(&self) -> i64 { self.old_message_id }
+  pub fn old_message_id(&self) -> MessageId { self.old_message_id }
 
 }
 
@@ -5179,6 +6313,12 @@ pub struct RTDUpdateMessageSendSucceededBuilder {
 impl RTDUpdateMessageSendSucceededBuilder {
   pub fn build(&self) -> UpdateMessageSendSucceeded { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -5186,7 +6326,7 @@ impl RTDUpdateMessageSendSucceededBuilder {
   }
 
    
-  pub fn old_message_id(&mut self, old_message_id: i64) -> &mut Self {
+  pub fn old_message_id(&mut self, old_message_id: MessageId) -> &mut Self {
     self.inner.old_message_id = old_message_id;
     self
   }
@@ -5201,12 +6341,21 @@ impl AsRef<UpdateMessageSendSucceeded> for RTDUpdateMessageSendSucceededBuilder
   fn as_ref(&self) -> &UpdateMessageSendSucceeded { &self.inner }
 }
 
+impl PartialEq for UpdateMessageSendSucceeded {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+      && self.old_message_id == other.old_message_id
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "calls")]
+
 /// New call signaling data arrived
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateNewCallSignalingData {
@@ -5215,17 +6364,21 @@ pub struct UpdateNewCallSignalingData {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The call identifier
   call_id: i64,
   /// The data
-  data: String,
+  data: Bytes,
   
 }
 
 impl RObject for UpdateNewCallSignalingData {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCallSignalingData" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewCallSignalingData" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5235,17 +6388,18 @@ impl TDUpdate for UpdateNewCallSignalingData {}
 
 
 impl UpdateNewCallSignalingData {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewCallSignalingDataBuilder {
-    let mut inner = UpdateNewCallSignalingData::default();
-    inner.td_name = "updateNewCallSignalingData".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewCallSignalingData { td_name: "updateNewCallSignalingData".to_string(), ..Default::default() };
     RTDUpdateNewCallSignalingDataBuilder { inner }
   }
 
+  /// `UpdateNewCallSignalingData` with the fields tdlib requires, the others are set by `UpdateNewCallSignalingData::builder()`
+  pub fn new(call_id: i64, data: impl AsRef<Bytes>) -> Self { Self::builder().call_id(call_id).data(data).build() }
+
   pub fn call_id(&self) -> i64 { self.call_id }
 
-  pub fn data(&self) -> &String { &self.data }
+  pub fn data(&self) -> &Bytes { &self.data }
 
 }
 
@@ -5257,6 +6411,12 @@ pub struct RTDUpdateNewCallSignalingDataBuilder {
 impl RTDUpdateNewCallSignalingDataBuilder {
   pub fn build(&self) -> UpdateNewCallSignalingData { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
@@ -5264,8 +6424,8 @@ impl RTDUpdateNewCallSignalingDataBuilder {
   }
 
    
-  pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
-    self.inner.data = data.as_ref().to_string();
+  pub fn data<T: AsRef<Bytes>>(&mut self, data: T) -> &mut Self {
+    self.inner.data = data.as_ref().clone();
     self
   }
 
@@ -5279,6 +6439,15 @@ impl AsRef<UpdateNewCallSignalingData> for RTDUpdateNewCallSignalingDataBuilder
   fn as_ref(&self) -> &UpdateNewCallSignalingData { &self.inner }
 }
 
+impl PartialEq for UpdateNewCallSignalingData {
+  fn eq(&self, other: &Self) -> bool {
+    self.call_id == other.call_id
+      && self.data == other.data
+  }
+}
+
+}
+
 
 
 
@@ -5293,25 +6462,29 @@ pub struct UpdateNewCallbackQuery {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
-  sender_user_id: i64,
+  sender_user_id: UserId,
   /// Identifier of the chat where the query was sent
-  chat_id: i64,
+  chat_id: ChatId,
   /// Identifier of the message, from which the query originated
-  message_id: i64,
+  message_id: MessageId,
   /// Identifier that uniquely corresponds to the chat to which the message was sent
-  chat_instance: isize,
+  #[serde(with = "crate::types::_common::int64")] chat_instance: i64,
   /// Query payload
   payload: CallbackQueryPayload,
   
 }
 
 impl RObject for UpdateNewCallbackQuery {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCallbackQuery" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewCallbackQuery" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5321,23 +6494,40 @@ impl TDUpdate for UpdateNewCallbackQuery {}
 
 
 impl UpdateNewCallbackQuery {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewCallbackQueryBuilder {
-    let mut inner = UpdateNewCallbackQuery::default();
-    inner.td_name = "updateNewCallbackQuery".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewCallbackQuery { td_name: "updateNewCallbackQuery".to_string(), ..Default::default() };
     RTDUpdateNewCallbackQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `UpdateNewCallbackQuery` with the fields tdlib requires, the others are set by `UpdateNewCallbackQuery::builder()`
+  pub fn new(
+    id: i64,
+    sender_user_id: UserId,
+    chat_id: ChatId,
+    message_id: MessageId,
+    chat_instance: i64,
+    payload: impl AsRef<CallbackQueryPayload>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .sender_user_id(sender_user_id)
+      .chat_id(chat_id)
+      .message_id(message_id)
+      .chat_instance(chat_instance)
+      .payload(payload)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
-  pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
+  pub fn sender_user_id(&self) -> UserId { self.sender_user_id }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn message_id(&self) -> i64 { self.message_id }
+  pub fn message_id(&self) -> MessageId { self.message_id }
 
-  pub fn chat_instance(&self) -> isize { self.chat_instance }
+  pub fn chat_instance(&self) -> i64 { self.chat_instance }
 
   pub fn payload(&self) -> &CallbackQueryPayload { &self.payload }
 
@@ -5351,32 +6541,38 @@ pub struct RTDUpdateNewCallbackQueryBuilder {
 impl RTDUpdateNewCallbackQueryBuilder {
   pub fn build(&self) -> UpdateNewCallbackQuery { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
 
    
-  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+  pub fn sender_user_id(&mut self, sender_user_id: UserId) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
     self
   }
 
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn message_id(&mut self, message_id: i64) -> &mut Self {
+  pub fn message_id(&mut self, message_id: MessageId) -> &mut Self {
     self.inner.message_id = message_id;
     self
   }
 
    
-  pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
+  pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
     self.inner.chat_instance = chat_instance;
     self
   }
@@ -5397,6 +6593,17 @@ impl AsRef<UpdateNewCallbackQuery> for RTDUpdateNewCallbackQueryBuilder {
   fn as_ref(&self) -> &UpdateNewCallbackQuery { &self.inner }
 }
 
+impl PartialEq for UpdateNewCallbackQuery {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sender_user_id == other.sender_user_id
+      && self.chat_id == other.chat_id
+      && self.message_id == other.message_id
+      && self.chat_instance == other.chat_instance
+      && self.payload == other.payload
+  }
+}
+
 
 
 
@@ -5411,15 +6618,19 @@ pub struct UpdateNewChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat
   chat: Chat,
   
 }
 
 impl RObject for UpdateNewChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5429,14 +6640,15 @@ impl TDUpdate for UpdateNewChat {}
 
 
 impl UpdateNewChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewChatBuilder {
-    let mut inner = UpdateNewChat::default();
-    inner.td_name = "updateNewChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewChat { td_name: "updateNewChat".to_string(), ..Default::default() };
     RTDUpdateNewChatBuilder { inner }
   }
 
+  /// `UpdateNewChat` with the fields tdlib requires, the others are set by `UpdateNewChat::builder()`
+  pub fn new(chat: impl AsRef<Chat>) -> Self { Self::builder().chat(chat).build() }
+
   pub fn chat(&self) -> &Chat { &self.chat }
 
 }
@@ -5449,6 +6661,12 @@ pub struct RTDUpdateNewChatBuilder {
 impl RTDUpdateNewChatBuilder {
   pub fn build(&self) -> UpdateNewChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat<T: AsRef<Chat>>(&mut self, chat: T) -> &mut Self {
     self.inner.chat = chat.as_ref().clone();
@@ -5465,6 +6683,12 @@ impl AsRef<UpdateNewChat> for RTDUpdateNewChatBuilder {
   fn as_ref(&self) -> &UpdateNewChat { &self.inner }
 }
 
+impl PartialEq for UpdateNewChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat == other.chat
+  }
+}
+
 
 
 
@@ -5479,9 +6703,12 @@ pub struct UpdateNewChatJoinRequest {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// Join request
   request: ChatJoinRequest,
   /// The invite link, which was used to send join request; may be null
@@ -5490,8 +6717,9 @@ pub struct UpdateNewChatJoinRequest {
 }
 
 impl RObject for UpdateNewChatJoinRequest {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewChatJoinRequest" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewChatJoinRequest" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5501,15 +6729,16 @@ impl TDUpdate for UpdateNewChatJoinRequest {}
 
 
 impl UpdateNewChatJoinRequest {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewChatJoinRequestBuilder {
-    let mut inner = UpdateNewChatJoinRequest::default();
-    inner.td_name = "updateNewChatJoinRequest".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewChatJoinRequest { td_name: "updateNewChatJoinRequest".to_string(), ..Default::default() };
     RTDUpdateNewChatJoinRequestBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateNewChatJoinRequest` with the fields tdlib requires, the others are set by `UpdateNewChatJoinRequest::builder()`
+  pub fn new(chat_id: ChatId, request: impl AsRef<ChatJoinRequest>) -> Self { Self::builder().chat_id(chat_id).request(request).build() }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn request(&self) -> &ChatJoinRequest { &self.request }
 
@@ -5525,8 +6754,14 @@ pub struct RTDUpdateNewChatJoinRequestBuilder {
 impl RTDUpdateNewChatJoinRequestBuilder {
   pub fn build(&self) -> UpdateNewChatJoinRequest { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -5553,6 +6788,14 @@ impl AsRef<UpdateNewChatJoinRequest> for RTDUpdateNewChatJoinRequestBuilder {
   fn as_ref(&self) -> &UpdateNewChatJoinRequest { &self.inner }
 }
 
+impl PartialEq for UpdateNewChatJoinRequest {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.request == other.request
+      && self.invite_link == other.invite_link
+  }
+}
+
 
 
 
@@ -5567,9 +6810,12 @@ pub struct UpdateNewChosenInlineResult {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the user who sent the query
-  sender_user_id: i64,
+  sender_user_id: UserId,
   /// User location; may be null
   user_location: Option<Location>,
   /// Text of the query
@@ -5582,8 +6828,9 @@ pub struct UpdateNewChosenInlineResult {
 }
 
 impl RObject for UpdateNewChosenInlineResult {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewChosenInlineResult" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewChosenInlineResult" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5593,15 +6840,28 @@ impl TDUpdate for UpdateNewChosenInlineResult {}
 
 
 impl UpdateNewChosenInlineResult {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewChosenInlineResultBuilder {
-    let mut inner = UpdateNewChosenInlineResult::default();
-    inner.td_name = "updateNewChosenInlineResult".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewChosenInlineResult { td_name: "updateNewChosenInlineResult".to_string(), ..Default::default() };
     RTDUpdateNewChosenInlineResultBuilder { inner }
   }
 
-  pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
+  /// `UpdateNewChosenInlineResult` with the fields tdlib requires, the others are set by `UpdateNewChosenInlineResult::builder()`
+  pub fn new(
+    sender_user_id: UserId,
+    query: impl AsRef<str>,
+    result_id: impl AsRef<str>,
+    inline_message_id: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .sender_user_id(sender_user_id)
+      .query(query)
+      .result_id(result_id)
+      .inline_message_id(inline_message_id)
+      .build()
+  }
+
+  pub fn sender_user_id(&self) -> UserId { self.sender_user_id }
 
   pub fn user_location(&self) -> &Option<Location> { &self.user_location }
 
@@ -5621,8 +6881,14 @@ pub struct RTDUpdateNewChosenInlineResultBuilder {
 impl RTDUpdateNewChosenInlineResultBuilder {
   pub fn build(&self) -> UpdateNewChosenInlineResult { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+  pub fn sender_user_id(&mut self, sender_user_id: UserId) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
     self
   }
@@ -5661,6 +6927,16 @@ impl AsRef<UpdateNewChosenInlineResult> for RTDUpdateNewChosenInlineResultBuilde
   fn as_ref(&self) -> &UpdateNewChosenInlineResult { &self.inner }
 }
 
+impl PartialEq for UpdateNewChosenInlineResult {
+  fn eq(&self, other: &Self) -> bool {
+    self.sender_user_id == other.sender_user_id
+      && self.user_location == other.user_location
+      && self.query == other.query
+      && self.result_id == other.result_id
+      && self.inline_message_id == other.inline_message_id
+  }
+}
+
 
 
 
@@ -5675,15 +6951,19 @@ pub struct UpdateNewCustomEvent {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A JSON-serialized event
   event: String,
   
 }
 
 impl RObject for UpdateNewCustomEvent {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCustomEvent" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewCustomEvent" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5693,14 +6973,15 @@ impl TDUpdate for UpdateNewCustomEvent {}
 
 
 impl UpdateNewCustomEvent {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewCustomEventBuilder {
-    let mut inner = UpdateNewCustomEvent::default();
-    inner.td_name = "updateNewCustomEvent".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewCustomEvent { td_name: "updateNewCustomEvent".to_string(), ..Default::default() };
     RTDUpdateNewCustomEventBuilder { inner }
   }
 
+  /// `UpdateNewCustomEvent` with the fields tdlib requires, the others are set by `UpdateNewCustomEvent::builder()`
+  pub fn new(event: impl AsRef<str>) -> Self { Self::builder().event(event).build() }
+
   pub fn event(&self) -> &String { &self.event }
 
 }
@@ -5713,6 +6994,12 @@ pub struct RTDUpdateNewCustomEventBuilder {
 impl RTDUpdateNewCustomEventBuilder {
   pub fn build(&self) -> UpdateNewCustomEvent { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn event<T: AsRef<str>>(&mut self, event: T) -> &mut Self {
     self.inner.event = event.as_ref().to_string();
@@ -5729,6 +7016,12 @@ impl AsRef<UpdateNewCustomEvent> for RTDUpdateNewCustomEventBuilder {
   fn as_ref(&self) -> &UpdateNewCustomEvent { &self.inner }
 }
 
+impl PartialEq for UpdateNewCustomEvent {
+  fn eq(&self, other: &Self) -> bool {
+    self.event == other.event
+  }
+}
+
 
 
 
@@ -5743,9 +7036,12 @@ pub struct UpdateNewCustomQuery {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// JSON-serialized query data
   data: String,
   /// Query timeout
@@ -5754,8 +7050,9 @@ pub struct UpdateNewCustomQuery {
 }
 
 impl RObject for UpdateNewCustomQuery {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewCustomQuery" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewCustomQuery" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5765,15 +7062,16 @@ impl TDUpdate for UpdateNewCustomQuery {}
 
 
 impl UpdateNewCustomQuery {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewCustomQueryBuilder {
-    let mut inner = UpdateNewCustomQuery::default();
-    inner.td_name = "updateNewCustomQuery".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewCustomQuery { td_name: "updateNewCustomQuery".to_string(), ..Default::default() };
     RTDUpdateNewCustomQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `UpdateNewCustomQuery` with the fields tdlib requires, the others are set by `UpdateNewCustomQuery::builder()`
+  pub fn new(id: i64, data: impl AsRef<str>, timeout: i64) -> Self { Self::builder().id(id).data(data).timeout(timeout).build() }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn data(&self) -> &String { &self.data }
 
@@ -5789,8 +7087,14 @@ pub struct RTDUpdateNewCustomQueryBuilder {
 impl RTDUpdateNewCustomQueryBuilder {
   pub fn build(&self) -> UpdateNewCustomQuery { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -5817,6 +7121,14 @@ impl AsRef<UpdateNewCustomQuery> for RTDUpdateNewCustomQueryBuilder {
   fn as_ref(&self) -> &UpdateNewCustomQuery { &self.inner }
 }
 
+impl PartialEq for UpdateNewCustomQuery {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.data == other.data
+      && self.timeout == other.timeout
+  }
+}
+
 
 
 
@@ -5831,23 +7143,27 @@ pub struct UpdateNewInlineCallbackQuery {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
-  sender_user_id: i64,
+  sender_user_id: UserId,
   /// Identifier of the inline message, from which the query originated
   inline_message_id: String,
   /// An identifier uniquely corresponding to the chat a message was sent to
-  chat_instance: isize,
+  #[serde(with = "crate::types::_common::int64")] chat_instance: i64,
   /// Query payload
   payload: CallbackQueryPayload,
   
 }
 
 impl RObject for UpdateNewInlineCallbackQuery {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewInlineCallbackQuery" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewInlineCallbackQuery" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5857,21 +7173,36 @@ impl TDUpdate for UpdateNewInlineCallbackQuery {}
 
 
 impl UpdateNewInlineCallbackQuery {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewInlineCallbackQueryBuilder {
-    let mut inner = UpdateNewInlineCallbackQuery::default();
-    inner.td_name = "updateNewInlineCallbackQuery".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewInlineCallbackQuery { td_name: "updateNewInlineCallbackQuery".to_string(), ..Default::default() };
     RTDUpdateNewInlineCallbackQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `UpdateNewInlineCallbackQuery` with the fields tdlib requires, the others are set by `UpdateNewInlineCallbackQuery::builder()`
+  pub fn new(
+    id: i64,
+    sender_user_id: UserId,
+    inline_message_id: impl AsRef<str>,
+    chat_instance: i64,
+    payload: impl AsRef<CallbackQueryPayload>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .sender_user_id(sender_user_id)
+      .inline_message_id(inline_message_id)
+      .chat_instance(chat_instance)
+      .payload(payload)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
-  pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
+  pub fn sender_user_id(&self) -> UserId { self.sender_user_id }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
 
-  pub fn chat_instance(&self) -> isize { self.chat_instance }
+  pub fn chat_instance(&self) -> i64 { self.chat_instance }
 
   pub fn payload(&self) -> &CallbackQueryPayload { &self.payload }
 
@@ -5885,14 +7216,20 @@ pub struct RTDUpdateNewInlineCallbackQueryBuilder {
 impl RTDUpdateNewInlineCallbackQueryBuilder {
   pub fn build(&self) -> UpdateNewInlineCallbackQuery { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
 
    
-  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+  pub fn sender_user_id(&mut self, sender_user_id: UserId) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
     self
   }
@@ -5904,7 +7241,7 @@ impl RTDUpdateNewInlineCallbackQueryBuilder {
   }
 
    
-  pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
+  pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
     self.inner.chat_instance = chat_instance;
     self
   }
@@ -5925,6 +7262,16 @@ impl AsRef<UpdateNewInlineCallbackQuery> for RTDUpdateNewInlineCallbackQueryBuil
   fn as_ref(&self) -> &UpdateNewInlineCallbackQuery { &self.inner }
 }
 
+impl PartialEq for UpdateNewInlineCallbackQuery {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sender_user_id == other.sender_user_id
+      && self.inline_message_id == other.inline_message_id
+      && self.chat_instance == other.chat_instance
+      && self.payload == other.payload
+  }
+}
+
 
 
 
@@ -5939,11 +7286,14 @@ pub struct UpdateNewInlineQuery {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
-  sender_user_id: i64,
+  sender_user_id: UserId,
   /// User location; may be null
   user_location: Option<Location>,
   /// The type of the chat, from which the query originated; may be null if unknown
@@ -5956,8 +7306,9 @@ pub struct UpdateNewInlineQuery {
 }
 
 impl RObject for UpdateNewInlineQuery {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewInlineQuery" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewInlineQuery" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -5967,17 +7318,30 @@ impl TDUpdate for UpdateNewInlineQuery {}
 
 
 impl UpdateNewInlineQuery {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewInlineQueryBuilder {
-    let mut inner = UpdateNewInlineQuery::default();
-    inner.td_name = "updateNewInlineQuery".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewInlineQuery { td_name: "updateNewInlineQuery".to_string(), ..Default::default() };
     RTDUpdateNewInlineQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `UpdateNewInlineQuery` with the fields tdlib requires, the others are set by `UpdateNewInlineQuery::builder()`
+  pub fn new(
+    id: i64,
+    sender_user_id: UserId,
+    query: impl AsRef<str>,
+    offset: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .sender_user_id(sender_user_id)
+      .query(query)
+      .offset(offset)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
-  pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
+  pub fn sender_user_id(&self) -> UserId { self.sender_user_id }
 
   pub fn user_location(&self) -> &Option<Location> { &self.user_location }
 
@@ -5997,14 +7361,20 @@ pub struct RTDUpdateNewInlineQueryBuilder {
 impl RTDUpdateNewInlineQueryBuilder {
   pub fn build(&self) -> UpdateNewInlineQuery { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
 
    
-  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+  pub fn sender_user_id(&mut self, sender_user_id: UserId) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
     self
   }
@@ -6043,6 +7413,17 @@ impl AsRef<UpdateNewInlineQuery> for RTDUpdateNewInlineQueryBuilder {
   fn as_ref(&self) -> &UpdateNewInlineQuery { &self.inner }
 }
 
+impl PartialEq for UpdateNewInlineQuery {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sender_user_id == other.sender_user_id
+      && self.user_location == other.user_location
+      && self.chat_type == other.chat_type
+      && self.query == other.query
+      && self.offset == other.offset
+  }
+}
+
 
 
 
@@ -6057,15 +7438,19 @@ pub struct UpdateNewMessage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new message
   message: Message,
   
 }
 
 impl RObject for UpdateNewMessage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewMessage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewMessage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6075,14 +7460,15 @@ impl TDUpdate for UpdateNewMessage {}
 
 
 impl UpdateNewMessage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewMessageBuilder {
-    let mut inner = UpdateNewMessage::default();
-    inner.td_name = "updateNewMessage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewMessage { td_name: "updateNewMessage".to_string(), ..Default::default() };
     RTDUpdateNewMessageBuilder { inner }
   }
 
+  /// `UpdateNewMessage` with the fields tdlib requires, the others are set by `UpdateNewMessage::builder()`
+  pub fn new(message: impl AsRef<Message>) -> Self { Self::builder().message(message).build() }
+
   pub fn message(&self) -> &Message { &self.message }
 
 }
@@ -6095,6 +7481,12 @@ pub struct RTDUpdateNewMessageBuilder {
 impl RTDUpdateNewMessageBuilder {
   pub fn build(&self) -> UpdateNewMessage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
@@ -6111,6 +7503,12 @@ impl AsRef<UpdateNewMessage> for RTDUpdateNewMessageBuilder {
   fn as_ref(&self) -> &UpdateNewMessage { &self.inner }
 }
 
+impl PartialEq for UpdateNewMessage {
+  fn eq(&self, other: &Self) -> bool {
+    self.message == other.message
+  }
+}
+
 
 
 
@@ -6125,17 +7523,20 @@ pub struct UpdateNewPreCheckoutQuery {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
-  sender_user_id: i64,
+  sender_user_id: UserId,
   /// Currency for the product price
   currency: String,
   /// Total price for the product, in the smallest units of the currency
   total_amount: i64,
   /// Invoice payload
-  invoice_payload: String,
+  invoice_payload: Bytes,
   /// Identifier of a shipping option chosen by the user; may be empty if not applicable
   shipping_option_id: String,
   /// Information about the order; may be null
@@ -6144,8 +7545,9 @@ pub struct UpdateNewPreCheckoutQuery {
 }
 
 impl RObject for UpdateNewPreCheckoutQuery {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewPreCheckoutQuery" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewPreCheckoutQuery" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6155,23 +7557,40 @@ impl TDUpdate for UpdateNewPreCheckoutQuery {}
 
 
 impl UpdateNewPreCheckoutQuery {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewPreCheckoutQueryBuilder {
-    let mut inner = UpdateNewPreCheckoutQuery::default();
-    inner.td_name = "updateNewPreCheckoutQuery".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewPreCheckoutQuery { td_name: "updateNewPreCheckoutQuery".to_string(), ..Default::default() };
     RTDUpdateNewPreCheckoutQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `UpdateNewPreCheckoutQuery` with the fields tdlib requires, the others are set by `UpdateNewPreCheckoutQuery::builder()`
+  pub fn new(
+    id: i64,
+    sender_user_id: UserId,
+    currency: impl AsRef<str>,
+    total_amount: i64,
+    invoice_payload: impl AsRef<Bytes>,
+    shipping_option_id: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .sender_user_id(sender_user_id)
+      .currency(currency)
+      .total_amount(total_amount)
+      .invoice_payload(invoice_payload)
+      .shipping_option_id(shipping_option_id)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
-  pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
+  pub fn sender_user_id(&self) -> UserId { self.sender_user_id }
 
   pub fn currency(&self) -> &String { &self.currency }
 
   pub fn total_amount(&self) -> i64 { self.total_amount }
 
-  pub fn invoice_payload(&self) -> &String { &self.invoice_payload }
+  pub fn invoice_payload(&self) -> &Bytes { &self.invoice_payload }
 
   pub fn shipping_option_id(&self) -> &String { &self.shipping_option_id }
 
@@ -6187,14 +7606,20 @@ pub struct RTDUpdateNewPreCheckoutQueryBuilder {
 impl RTDUpdateNewPreCheckoutQueryBuilder {
   pub fn build(&self) -> UpdateNewPreCheckoutQuery { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
 
    
-  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+  pub fn sender_user_id(&mut self, sender_user_id: UserId) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
     self
   }
@@ -6212,8 +7637,8 @@ impl RTDUpdateNewPreCheckoutQueryBuilder {
   }
 
    
-  pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
-    self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+  pub fn invoice_payload<T: AsRef<Bytes>>(&mut self, invoice_payload: T) -> &mut Self {
+    self.inner.invoice_payload = invoice_payload.as_ref().clone();
     self
   }
 
@@ -6239,12 +7664,26 @@ impl AsRef<UpdateNewPreCheckoutQuery> for RTDUpdateNewPreCheckoutQueryBuilder {
   fn as_ref(&self) -> &UpdateNewPreCheckoutQuery { &self.inner }
 }
 
+impl PartialEq for UpdateNewPreCheckoutQuery {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sender_user_id == other.sender_user_id
+      && self.currency == other.currency
+      && self.total_amount == other.total_amount
+      && self.invoice_payload == other.invoice_payload
+      && self.shipping_option_id == other.shipping_option_id
+      && self.order_info == other.order_info
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "payments")]
+
 /// A new incoming shipping query; for bots only. Only for invoices with flexible price
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateNewShippingQuery {
@@ -6253,11 +7692,14 @@ pub struct UpdateNewShippingQuery {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
-  sender_user_id: i64,
+  sender_user_id: UserId,
   /// Invoice payload
   invoice_payload: String,
   /// User shipping address
@@ -6266,8 +7708,9 @@ pub struct UpdateNewShippingQuery {
 }
 
 impl RObject for UpdateNewShippingQuery {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNewShippingQuery" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNewShippingQuery" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6277,17 +7720,30 @@ impl TDUpdate for UpdateNewShippingQuery {}
 
 
 impl UpdateNewShippingQuery {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNewShippingQueryBuilder {
-    let mut inner = UpdateNewShippingQuery::default();
-    inner.td_name = "updateNewShippingQuery".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNewShippingQuery { td_name: "updateNewShippingQuery".to_string(), ..Default::default() };
     RTDUpdateNewShippingQueryBuilder { inner }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// `UpdateNewShippingQuery` with the fields tdlib requires, the others are set by `UpdateNewShippingQuery::builder()`
+  pub fn new(
+    id: i64,
+    sender_user_id: UserId,
+    invoice_payload: impl AsRef<str>,
+    shipping_address: impl AsRef<Address>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .sender_user_id(sender_user_id)
+      .invoice_payload(invoice_payload)
+      .shipping_address(shipping_address)
+      .build()
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
-  pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
+  pub fn sender_user_id(&self) -> UserId { self.sender_user_id }
 
   pub fn invoice_payload(&self) -> &String { &self.invoice_payload }
 
@@ -6303,14 +7759,20 @@ pub struct RTDUpdateNewShippingQueryBuilder {
 impl RTDUpdateNewShippingQueryBuilder {
   pub fn build(&self) -> UpdateNewShippingQuery { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
     self
   }
 
    
-  pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
+  pub fn sender_user_id(&mut self, sender_user_id: UserId) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
     self
   }
@@ -6337,6 +7799,17 @@ impl AsRef<UpdateNewShippingQuery> for RTDUpdateNewShippingQueryBuilder {
   fn as_ref(&self) -> &UpdateNewShippingQuery { &self.inner }
 }
 
+impl PartialEq for UpdateNewShippingQuery {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.sender_user_id == other.sender_user_id
+      && self.invoice_payload == other.invoice_payload
+      && self.shipping_address == other.shipping_address
+  }
+}
+
+}
+
 
 
 
@@ -6351,7 +7824,10 @@ pub struct UpdateNotification {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique notification group identifier
   notification_group_id: i64,
   /// Changed notification
@@ -6360,8 +7836,9 @@ pub struct UpdateNotification {
 }
 
 impl RObject for UpdateNotification {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNotification" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNotification" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6371,14 +7848,15 @@ impl TDUpdate for UpdateNotification {}
 
 
 impl UpdateNotification {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNotificationBuilder {
-    let mut inner = UpdateNotification::default();
-    inner.td_name = "updateNotification".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNotification { td_name: "updateNotification".to_string(), ..Default::default() };
     RTDUpdateNotificationBuilder { inner }
   }
 
+  /// `UpdateNotification` with the fields tdlib requires, the others are set by `UpdateNotification::builder()`
+  pub fn new(notification_group_id: i64, notification: impl AsRef<Notification>) -> Self { Self::builder().notification_group_id(notification_group_id).notification(notification).build() }
+
   pub fn notification_group_id(&self) -> i64 { self.notification_group_id }
 
   pub fn notification(&self) -> &Notification { &self.notification }
@@ -6393,6 +7871,12 @@ pub struct RTDUpdateNotificationBuilder {
 impl RTDUpdateNotificationBuilder {
   pub fn build(&self) -> UpdateNotification { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
@@ -6415,6 +7899,13 @@ impl AsRef<UpdateNotification> for RTDUpdateNotificationBuilder {
   fn as_ref(&self) -> &UpdateNotification { &self.inner }
 }
 
+impl PartialEq for UpdateNotification {
+  fn eq(&self, other: &Self) -> bool {
+    self.notification_group_id == other.notification_group_id
+      && self.notification == other.notification
+  }
+}
+
 
 
 
@@ -6429,15 +7920,18 @@ pub struct UpdateNotificationGroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique notification group identifier
   notification_group_id: i64,
   /// New type of the notification group
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: NotificationGroupType,
   /// Identifier of a chat to which all notifications in the group belong
-  chat_id: i64,
+  chat_id: ChatId,
   /// Chat identifier, which notification settings must be applied to the added notifications
-  notification_settings_chat_id: i64,
+  notification_settings_chat_id: ChatId,
   /// True, if the notifications must be shown without sound
   is_silent: bool,
   /// Total number of unread notifications in the group, can be bigger than number of active notifications
@@ -6450,8 +7944,9 @@ pub struct UpdateNotificationGroup {
 }
 
 impl RObject for UpdateNotificationGroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateNotificationGroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateNotificationGroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6461,21 +7956,43 @@ impl TDUpdate for UpdateNotificationGroup {}
 
 
 impl UpdateNotificationGroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateNotificationGroupBuilder {
-    let mut inner = UpdateNotificationGroup::default();
-    inner.td_name = "updateNotificationGroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateNotificationGroup { td_name: "updateNotificationGroup".to_string(), ..Default::default() };
     RTDUpdateNotificationGroupBuilder { inner }
   }
 
+  /// `UpdateNotificationGroup` with the fields tdlib requires, the others are set by `UpdateNotificationGroup::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    notification_group_id: i64,
+    type_: impl AsRef<NotificationGroupType>,
+    chat_id: ChatId,
+    notification_settings_chat_id: ChatId,
+    is_silent: bool,
+    total_count: i64,
+    added_notifications: Vec<Notification>,
+    removed_notification_ids: Vec<i64>,
+  ) -> Self {
+    Self::builder()
+      .notification_group_id(notification_group_id)
+      .type_(type_)
+      .chat_id(chat_id)
+      .notification_settings_chat_id(notification_settings_chat_id)
+      .is_silent(is_silent)
+      .total_count(total_count)
+      .added_notifications(added_notifications)
+      .removed_notification_ids(removed_notification_ids)
+      .build()
+  }
+
   pub fn notification_group_id(&self) -> i64 { self.notification_group_id }
 
   pub fn type_(&self) -> &NotificationGroupType { &self.type_ }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
-  pub fn notification_settings_chat_id(&self) -> i64 { self.notification_settings_chat_id }
+  pub fn notification_settings_chat_id(&self) -> ChatId { self.notification_settings_chat_id }
 
   pub fn is_silent(&self) -> bool { self.is_silent }
 
@@ -6495,6 +8012,12 @@ pub struct RTDUpdateNotificationGroupBuilder {
 impl RTDUpdateNotificationGroupBuilder {
   pub fn build(&self) -> UpdateNotificationGroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
@@ -6508,13 +8031,13 @@ impl RTDUpdateNotificationGroupBuilder {
   }
 
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
 
    
-  pub fn notification_settings_chat_id(&mut self, notification_settings_chat_id: i64) -> &mut Self {
+  pub fn notification_settings_chat_id(&mut self, notification_settings_chat_id: ChatId) -> &mut Self {
     self.inner.notification_settings_chat_id = notification_settings_chat_id;
     self
   }
@@ -6553,6 +8076,19 @@ impl AsRef<UpdateNotificationGroup> for RTDUpdateNotificationGroupBuilder {
   fn as_ref(&self) -> &UpdateNotificationGroup { &self.inner }
 }
 
+impl PartialEq for UpdateNotificationGroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.notification_group_id == other.notification_group_id
+      && self.type_ == other.type_
+      && self.chat_id == other.chat_id
+      && self.notification_settings_chat_id == other.notification_settings_chat_id
+      && self.is_silent == other.is_silent
+      && self.total_count == other.total_count
+      && self.added_notifications == other.added_notifications
+      && self.removed_notification_ids == other.removed_notification_ids
+  }
+}
+
 
 
 
@@ -6567,7 +8103,10 @@ pub struct UpdateOption {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The option name
   name: String,
   /// The new option value
@@ -6576,8 +8115,9 @@ pub struct UpdateOption {
 }
 
 impl RObject for UpdateOption {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateOption" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateOption" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6587,14 +8127,15 @@ impl TDUpdate for UpdateOption {}
 
 
 impl UpdateOption {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateOptionBuilder {
-    let mut inner = UpdateOption::default();
-    inner.td_name = "updateOption".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateOption { td_name: "updateOption".to_string(), ..Default::default() };
     RTDUpdateOptionBuilder { inner }
   }
 
+  /// `UpdateOption` with the fields tdlib requires, the others are set by `UpdateOption::builder()`
+  pub fn new(name: impl AsRef<str>, value: impl AsRef<OptionValue>) -> Self { Self::builder().name(name).value(value).build() }
+
   pub fn name(&self) -> &String { &self.name }
 
   pub fn value(&self) -> &OptionValue { &self.value }
@@ -6609,6 +8150,12 @@ pub struct RTDUpdateOptionBuilder {
 impl RTDUpdateOptionBuilder {
   pub fn build(&self) -> UpdateOption { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -6631,6 +8178,13 @@ impl AsRef<UpdateOption> for RTDUpdateOptionBuilder {
   fn as_ref(&self) -> &UpdateOption { &self.inner }
 }
 
+impl PartialEq for UpdateOption {
+  fn eq(&self, other: &Self) -> bool {
+    self.name == other.name
+      && self.value == other.value
+  }
+}
+
 
 
 
@@ -6645,15 +8199,19 @@ pub struct UpdatePoll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about the poll
   poll: Poll,
   
 }
 
 impl RObject for UpdatePoll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updatePoll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updatePoll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6663,14 +8221,15 @@ impl TDUpdate for UpdatePoll {}
 
 
 impl UpdatePoll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdatePollBuilder {
-    let mut inner = UpdatePoll::default();
-    inner.td_name = "updatePoll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdatePoll { td_name: "updatePoll".to_string(), ..Default::default() };
     RTDUpdatePollBuilder { inner }
   }
 
+  /// `UpdatePoll` with the fields tdlib requires, the others are set by `UpdatePoll::builder()`
+  pub fn new(poll: impl AsRef<Poll>) -> Self { Self::builder().poll(poll).build() }
+
   pub fn poll(&self) -> &Poll { &self.poll }
 
 }
@@ -6683,6 +8242,12 @@ pub struct RTDUpdatePollBuilder {
 impl RTDUpdatePollBuilder {
   pub fn build(&self) -> UpdatePoll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
     self.inner.poll = poll.as_ref().clone();
@@ -6699,6 +8264,12 @@ impl AsRef<UpdatePoll> for RTDUpdatePollBuilder {
   fn as_ref(&self) -> &UpdatePoll { &self.inner }
 }
 
+impl PartialEq for UpdatePoll {
+  fn eq(&self, other: &Self) -> bool {
+    self.poll == other.poll
+  }
+}
+
 
 
 
@@ -6713,19 +8284,23 @@ pub struct UpdatePollAnswer {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Unique poll identifier
-  poll_id: isize,
+  #[serde(with = "crate::types::_common::int64")] poll_id: i64,
   /// The user, who changed the answer to the poll
-  user_id: i64,
+  user_id: UserId,
   /// 0-based identifiers of answer options, chosen by the user
   option_ids: Vec<i64>,
   
 }
 
 impl RObject for UpdatePollAnswer {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updatePollAnswer" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updatePollAnswer" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6735,17 +8310,18 @@ impl TDUpdate for UpdatePollAnswer {}
 
 
 impl UpdatePollAnswer {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdatePollAnswerBuilder {
-    let mut inner = UpdatePollAnswer::default();
-    inner.td_name = "updatePollAnswer".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdatePollAnswer { td_name: "updatePollAnswer".to_string(), ..Default::default() };
     RTDUpdatePollAnswerBuilder { inner }
   }
 
-  pub fn poll_id(&self) -> isize { self.poll_id }
+  /// `UpdatePollAnswer` with the fields tdlib requires, the others are set by `UpdatePollAnswer::builder()`
+  pub fn new(poll_id: i64, user_id: UserId, option_ids: Vec<i64>) -> Self { Self::builder().poll_id(poll_id).user_id(user_id).option_ids(option_ids).build() }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn poll_id(&self) -> i64 { self.poll_id }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn option_ids(&self) -> &Vec<i64> { &self.option_ids }
 
@@ -6759,14 +8335,20 @@ pub struct RTDUpdatePollAnswerBuilder {
 impl RTDUpdatePollAnswerBuilder {
   pub fn build(&self) -> UpdatePollAnswer { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn poll_id(&mut self, poll_id: isize) -> &mut Self {
+  pub fn poll_id(&mut self, poll_id: i64) -> &mut Self {
     self.inner.poll_id = poll_id;
     self
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -6787,11 +8369,21 @@ impl AsRef<UpdatePollAnswer> for RTDUpdatePollAnswerBuilder {
   fn as_ref(&self) -> &UpdatePollAnswer { &self.inner }
 }
 
+impl PartialEq for UpdatePollAnswer {
+  fn eq(&self, other: &Self) -> bool {
+    self.poll_id == other.poll_id
+      && self.user_id == other.user_id
+      && self.option_ids == other.option_ids
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
 
 /// The list of recently used stickers was updated
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -6801,7 +8393,10 @@ pub struct UpdateRecentStickers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the list of stickers attached to photo or video files was updated, otherwise the list of sent stickers is updated
   is_attached: bool,
   /// The new list of file identifiers of recently used stickers
@@ -6810,8 +8405,9 @@ pub struct UpdateRecentStickers {
 }
 
 impl RObject for UpdateRecentStickers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateRecentStickers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateRecentStickers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6821,14 +8417,15 @@ impl TDUpdate for UpdateRecentStickers {}
 
 
 impl UpdateRecentStickers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateRecentStickersBuilder {
-    let mut inner = UpdateRecentStickers::default();
-    inner.td_name = "updateRecentStickers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateRecentStickers { td_name: "updateRecentStickers".to_string(), ..Default::default() };
     RTDUpdateRecentStickersBuilder { inner }
   }
 
+  /// `UpdateRecentStickers` with the fields tdlib requires, the others are set by `UpdateRecentStickers::builder()`
+  pub fn new(is_attached: bool, sticker_ids: Vec<i64>) -> Self { Self::builder().is_attached(is_attached).sticker_ids(sticker_ids).build() }
+
   pub fn is_attached(&self) -> bool { self.is_attached }
 
   pub fn sticker_ids(&self) -> &Vec<i64> { &self.sticker_ids }
@@ -6843,6 +8440,12 @@ pub struct RTDUpdateRecentStickersBuilder {
 impl RTDUpdateRecentStickersBuilder {
   pub fn build(&self) -> UpdateRecentStickers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -6865,6 +8468,15 @@ impl AsRef<UpdateRecentStickers> for RTDUpdateRecentStickersBuilder {
   fn as_ref(&self) -> &UpdateRecentStickers { &self.inner }
 }
 
+impl PartialEq for UpdateRecentStickers {
+  fn eq(&self, other: &Self) -> bool {
+    self.is_attached == other.is_attached
+      && self.sticker_ids == other.sticker_ids
+  }
+}
+
+}
+
 
 
 
@@ -6879,15 +8491,19 @@ pub struct UpdateSavedAnimations {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new list of file identifiers of saved animations
   animation_ids: Vec<i64>,
   
 }
 
 impl RObject for UpdateSavedAnimations {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSavedAnimations" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateSavedAnimations" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6897,14 +8513,15 @@ impl TDUpdate for UpdateSavedAnimations {}
 
 
 impl UpdateSavedAnimations {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateSavedAnimationsBuilder {
-    let mut inner = UpdateSavedAnimations::default();
-    inner.td_name = "updateSavedAnimations".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateSavedAnimations { td_name: "updateSavedAnimations".to_string(), ..Default::default() };
     RTDUpdateSavedAnimationsBuilder { inner }
   }
 
+  /// `UpdateSavedAnimations` with the fields tdlib requires, the others are set by `UpdateSavedAnimations::builder()`
+  pub fn new(animation_ids: Vec<i64>) -> Self { Self::builder().animation_ids(animation_ids).build() }
+
   pub fn animation_ids(&self) -> &Vec<i64> { &self.animation_ids }
 
 }
@@ -6917,6 +8534,12 @@ pub struct RTDUpdateSavedAnimationsBuilder {
 impl RTDUpdateSavedAnimationsBuilder {
   pub fn build(&self) -> UpdateSavedAnimations { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn animation_ids(&mut self, animation_ids: Vec<i64>) -> &mut Self {
     self.inner.animation_ids = animation_ids;
@@ -6933,6 +8556,12 @@ impl AsRef<UpdateSavedAnimations> for RTDUpdateSavedAnimationsBuilder {
   fn as_ref(&self) -> &UpdateSavedAnimations { &self.inner }
 }
 
+impl PartialEq for UpdateSavedAnimations {
+  fn eq(&self, other: &Self) -> bool {
+    self.animation_ids == other.animation_ids
+  }
+}
+
 
 
 
@@ -6947,7 +8576,10 @@ pub struct UpdateScopeNotificationSettings {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Types of chats for which notification settings were updated
   scope: NotificationSettingsScope,
   /// The new notification settings
@@ -6956,8 +8588,9 @@ pub struct UpdateScopeNotificationSettings {
 }
 
 impl RObject for UpdateScopeNotificationSettings {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateScopeNotificationSettings" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateScopeNotificationSettings" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -6967,14 +8600,15 @@ impl TDUpdate for UpdateScopeNotificationSettings {}
 
 
 impl UpdateScopeNotificationSettings {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateScopeNotificationSettingsBuilder {
-    let mut inner = UpdateScopeNotificationSettings::default();
-    inner.td_name = "updateScopeNotificationSettings".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateScopeNotificationSettings { td_name: "updateScopeNotificationSettings".to_string(), ..Default::default() };
     RTDUpdateScopeNotificationSettingsBuilder { inner }
   }
 
+  /// `UpdateScopeNotificationSettings` with the fields tdlib requires, the others are set by `UpdateScopeNotificationSettings::builder()`
+  pub fn new(scope: impl AsRef<NotificationSettingsScope>, notification_settings: impl AsRef<ScopeNotificationSettings>) -> Self { Self::builder().scope(scope).notification_settings(notification_settings).build() }
+
   pub fn scope(&self) -> &NotificationSettingsScope { &self.scope }
 
   pub fn notification_settings(&self) -> &ScopeNotificationSettings { &self.notification_settings }
@@ -6989,6 +8623,12 @@ pub struct RTDUpdateScopeNotificationSettingsBuilder {
 impl RTDUpdateScopeNotificationSettingsBuilder {
   pub fn build(&self) -> UpdateScopeNotificationSettings { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -7011,6 +8651,13 @@ impl AsRef<UpdateScopeNotificationSettings> for RTDUpdateScopeNotificationSettin
   fn as_ref(&self) -> &UpdateScopeNotificationSettings { &self.inner }
 }
 
+impl PartialEq for UpdateScopeNotificationSettings {
+  fn eq(&self, other: &Self) -> bool {
+    self.scope == other.scope
+      && self.notification_settings == other.notification_settings
+  }
+}
+
 
 
 
@@ -7025,15 +8672,19 @@ pub struct UpdateSecretChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about the secret chat
   secret_chat: SecretChat,
   
 }
 
 impl RObject for UpdateSecretChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSecretChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateSecretChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7043,14 +8694,15 @@ impl TDUpdate for UpdateSecretChat {}
 
 
 impl UpdateSecretChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateSecretChatBuilder {
-    let mut inner = UpdateSecretChat::default();
-    inner.td_name = "updateSecretChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateSecretChat { td_name: "updateSecretChat".to_string(), ..Default::default() };
     RTDUpdateSecretChatBuilder { inner }
   }
 
+  /// `UpdateSecretChat` with the fields tdlib requires, the others are set by `UpdateSecretChat::builder()`
+  pub fn new(secret_chat: impl AsRef<SecretChat>) -> Self { Self::builder().secret_chat(secret_chat).build() }
+
   pub fn secret_chat(&self) -> &SecretChat { &self.secret_chat }
 
 }
@@ -7063,6 +8715,12 @@ pub struct RTDUpdateSecretChatBuilder {
 impl RTDUpdateSecretChatBuilder {
   pub fn build(&self) -> UpdateSecretChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn secret_chat<T: AsRef<SecretChat>>(&mut self, secret_chat: T) -> &mut Self {
     self.inner.secret_chat = secret_chat.as_ref().clone();
@@ -7079,6 +8737,12 @@ impl AsRef<UpdateSecretChat> for RTDUpdateSecretChatBuilder {
   fn as_ref(&self) -> &UpdateSecretChat { &self.inner }
 }
 
+impl PartialEq for UpdateSecretChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.secret_chat == other.secret_chat
+  }
+}
+
 
 
 
@@ -7093,7 +8757,10 @@ pub struct UpdateSelectedBackground {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if background for dark theme has changed
   for_dark_theme: bool,
   /// The new selected background; may be null
@@ -7102,8 +8769,9 @@ pub struct UpdateSelectedBackground {
 }
 
 impl RObject for UpdateSelectedBackground {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSelectedBackground" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateSelectedBackground" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7113,14 +8781,15 @@ impl TDUpdate for UpdateSelectedBackground {}
 
 
 impl UpdateSelectedBackground {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateSelectedBackgroundBuilder {
-    let mut inner = UpdateSelectedBackground::default();
-    inner.td_name = "updateSelectedBackground".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateSelectedBackground { td_name: "updateSelectedBackground".to_string(), ..Default::default() };
     RTDUpdateSelectedBackgroundBuilder { inner }
   }
 
+  /// `UpdateSelectedBackground` with the fields tdlib requires, the others are set by `UpdateSelectedBackground::builder()`
+  pub fn new(for_dark_theme: bool) -> Self { Self::builder().for_dark_theme(for_dark_theme).build() }
+
   pub fn for_dark_theme(&self) -> bool { self.for_dark_theme }
 
   pub fn background(&self) -> &Option<Background> { &self.background }
@@ -7135,6 +8804,12 @@ pub struct RTDUpdateSelectedBackgroundBuilder {
 impl RTDUpdateSelectedBackgroundBuilder {
   pub fn build(&self) -> UpdateSelectedBackground { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn for_dark_theme(&mut self, for_dark_theme: bool) -> &mut Self {
     self.inner.for_dark_theme = for_dark_theme;
@@ -7157,6 +8832,13 @@ impl AsRef<UpdateSelectedBackground> for RTDUpdateSelectedBackgroundBuilder {
   fn as_ref(&self) -> &UpdateSelectedBackground { &self.inner }
 }
 
+impl PartialEq for UpdateSelectedBackground {
+  fn eq(&self, other: &Self) -> bool {
+    self.for_dark_theme == other.for_dark_theme
+      && self.background == other.background
+  }
+}
+
 
 
 
@@ -7171,7 +8853,10 @@ pub struct UpdateServiceNotification {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Notification type. If type begins with "AUTH_KEY_DROP_", then two buttons "Cancel" and "Log out" must be shown under notification; if user presses the second, all local data must be destroyed using Destroy method
   #[serde(rename(serialize = "type", deserialize = "type"))] type_: String,
   /// Notification content
@@ -7180,8 +8865,9 @@ pub struct UpdateServiceNotification {
 }
 
 impl RObject for UpdateServiceNotification {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateServiceNotification" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateServiceNotification" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7191,14 +8877,15 @@ impl TDUpdate for UpdateServiceNotification {}
 
 
 impl UpdateServiceNotification {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateServiceNotificationBuilder {
-    let mut inner = UpdateServiceNotification::default();
-    inner.td_name = "updateServiceNotification".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateServiceNotification { td_name: "updateServiceNotification".to_string(), ..Default::default() };
     RTDUpdateServiceNotificationBuilder { inner }
   }
 
+  /// `UpdateServiceNotification` with the fields tdlib requires, the others are set by `UpdateServiceNotification::builder()`
+  pub fn new(type_: impl AsRef<str>, content: impl AsRef<MessageContent>) -> Self { Self::builder().type_(type_).content(content).build() }
+
   pub fn type_(&self) -> &String { &self.type_ }
 
   pub fn content(&self) -> &MessageContent { &self.content }
@@ -7213,6 +8900,12 @@ pub struct RTDUpdateServiceNotificationBuilder {
 impl RTDUpdateServiceNotificationBuilder {
   pub fn build(&self) -> UpdateServiceNotification { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
@@ -7235,12 +8928,21 @@ impl AsRef<UpdateServiceNotification> for RTDUpdateServiceNotificationBuilder {
   fn as_ref(&self) -> &UpdateServiceNotification { &self.inner }
 }
 
+impl PartialEq for UpdateServiceNotification {
+  fn eq(&self, other: &Self) -> bool {
+    self.type_ == other.type_
+      && self.content == other.content
+  }
+}
+
 
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
+
 /// A sticker set has changed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateStickerSet {
@@ -7249,15 +8951,19 @@ pub struct UpdateStickerSet {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The sticker set
   sticker_set: StickerSet,
   
 }
 
 impl RObject for UpdateStickerSet {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateStickerSet" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateStickerSet" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7267,14 +8973,15 @@ impl TDUpdate for UpdateStickerSet {}
 
 
 impl UpdateStickerSet {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateStickerSetBuilder {
-    let mut inner = UpdateStickerSet::default();
-    inner.td_name = "updateStickerSet".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateStickerSet { td_name: "updateStickerSet".to_string(), ..Default::default() };
     RTDUpdateStickerSetBuilder { inner }
   }
 
+  /// `UpdateStickerSet` with the fields tdlib requires, the others are set by `UpdateStickerSet::builder()`
+  pub fn new(sticker_set: impl AsRef<StickerSet>) -> Self { Self::builder().sticker_set(sticker_set).build() }
+
   pub fn sticker_set(&self) -> &StickerSet { &self.sticker_set }
 
 }
@@ -7287,6 +8994,12 @@ pub struct RTDUpdateStickerSetBuilder {
 impl RTDUpdateStickerSetBuilder {
   pub fn build(&self) -> UpdateStickerSet { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker_set<T: AsRef<StickerSet>>(&mut self, sticker_set: T) -> &mut Self {
     self.inner.sticker_set = sticker_set.as_ref().clone();
@@ -7303,6 +9016,14 @@ impl AsRef<UpdateStickerSet> for RTDUpdateStickerSetBuilder {
   fn as_ref(&self) -> &UpdateStickerSet { &self.inner }
 }
 
+impl PartialEq for UpdateStickerSet {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker_set == other.sticker_set
+  }
+}
+
+}
+
 
 
 
@@ -7317,7 +9038,10 @@ pub struct UpdateSuggestedActions {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Added suggested actions
   added_actions: Vec<SuggestedAction>,
   /// Removed suggested actions
@@ -7326,8 +9050,9 @@ pub struct UpdateSuggestedActions {
 }
 
 impl RObject for UpdateSuggestedActions {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSuggestedActions" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateSuggestedActions" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7337,14 +9062,15 @@ impl TDUpdate for UpdateSuggestedActions {}
 
 
 impl UpdateSuggestedActions {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateSuggestedActionsBuilder {
-    let mut inner = UpdateSuggestedActions::default();
-    inner.td_name = "updateSuggestedActions".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateSuggestedActions { td_name: "updateSuggestedActions".to_string(), ..Default::default() };
     RTDUpdateSuggestedActionsBuilder { inner }
   }
 
+  /// `UpdateSuggestedActions` with the fields tdlib requires, the others are set by `UpdateSuggestedActions::builder()`
+  pub fn new(added_actions: Vec<SuggestedAction>, removed_actions: Vec<SuggestedAction>) -> Self { Self::builder().added_actions(added_actions).removed_actions(removed_actions).build() }
+
   pub fn added_actions(&self) -> &Vec<SuggestedAction> { &self.added_actions }
 
   pub fn removed_actions(&self) -> &Vec<SuggestedAction> { &self.removed_actions }
@@ -7359,6 +9085,12 @@ pub struct RTDUpdateSuggestedActionsBuilder {
 impl RTDUpdateSuggestedActionsBuilder {
   pub fn build(&self) -> UpdateSuggestedActions { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn added_actions(&mut self, added_actions: Vec<SuggestedAction>) -> &mut Self {
     self.inner.added_actions = added_actions;
@@ -7381,6 +9113,13 @@ impl AsRef<UpdateSuggestedActions> for RTDUpdateSuggestedActionsBuilder {
   fn as_ref(&self) -> &UpdateSuggestedActions { &self.inner }
 }
 
+impl PartialEq for UpdateSuggestedActions {
+  fn eq(&self, other: &Self) -> bool {
+    self.added_actions == other.added_actions
+      && self.removed_actions == other.removed_actions
+  }
+}
+
 
 
 
@@ -7395,15 +9134,19 @@ pub struct UpdateSupergroup {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about the supergroup
   supergroup: Supergroup,
   
 }
 
 impl RObject for UpdateSupergroup {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSupergroup" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateSupergroup" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7413,14 +9156,15 @@ impl TDUpdate for UpdateSupergroup {}
 
 
 impl UpdateSupergroup {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateSupergroupBuilder {
-    let mut inner = UpdateSupergroup::default();
-    inner.td_name = "updateSupergroup".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateSupergroup { td_name: "updateSupergroup".to_string(), ..Default::default() };
     RTDUpdateSupergroupBuilder { inner }
   }
 
+  /// `UpdateSupergroup` with the fields tdlib requires, the others are set by `UpdateSupergroup::builder()`
+  pub fn new(supergroup: impl AsRef<Supergroup>) -> Self { Self::builder().supergroup(supergroup).build() }
+
   pub fn supergroup(&self) -> &Supergroup { &self.supergroup }
 
 }
@@ -7433,6 +9177,12 @@ pub struct RTDUpdateSupergroupBuilder {
 impl RTDUpdateSupergroupBuilder {
   pub fn build(&self) -> UpdateSupergroup { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn supergroup<T: AsRef<Supergroup>>(&mut self, supergroup: T) -> &mut Self {
     self.inner.supergroup = supergroup.as_ref().clone();
@@ -7449,6 +9199,12 @@ impl AsRef<UpdateSupergroup> for RTDUpdateSupergroupBuilder {
   fn as_ref(&self) -> &UpdateSupergroup { &self.inner }
 }
 
+impl PartialEq for UpdateSupergroup {
+  fn eq(&self, other: &Self) -> bool {
+    self.supergroup == other.supergroup
+  }
+}
+
 
 
 
@@ -7463,17 +9219,21 @@ pub struct UpdateSupergroupFullInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the supergroup or channel
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] supergroup_id: i64,
+  supergroup_id: SupergroupId,
   /// New full information about the supergroup
   supergroup_full_info: SupergroupFullInfo,
   
 }
 
 impl RObject for UpdateSupergroupFullInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateSupergroupFullInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateSupergroupFullInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7483,15 +9243,16 @@ impl TDUpdate for UpdateSupergroupFullInfo {}
 
 
 impl UpdateSupergroupFullInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateSupergroupFullInfoBuilder {
-    let mut inner = UpdateSupergroupFullInfo::default();
-    inner.td_name = "updateSupergroupFullInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateSupergroupFullInfo { td_name: "updateSupergroupFullInfo".to_string(), ..Default::default() };
     RTDUpdateSupergroupFullInfoBuilder { inner }
   }
 
-  pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
+  /// `UpdateSupergroupFullInfo` with the fields tdlib requires, the others are set by `UpdateSupergroupFullInfo::builder()`
+  pub fn new(supergroup_id: SupergroupId, supergroup_full_info: impl AsRef<SupergroupFullInfo>) -> Self { Self::builder().supergroup_id(supergroup_id).supergroup_full_info(supergroup_full_info).build() }
+
+  pub fn supergroup_id(&self) -> SupergroupId { self.supergroup_id }
 
   pub fn supergroup_full_info(&self) -> &SupergroupFullInfo { &self.supergroup_full_info }
 
@@ -7505,8 +9266,14 @@ pub struct RTDUpdateSupergroupFullInfoBuilder {
 impl RTDUpdateSupergroupFullInfoBuilder {
   pub fn build(&self) -> UpdateSupergroupFullInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
+  pub fn supergroup_id(&mut self, supergroup_id: SupergroupId) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
     self
   }
@@ -7527,6 +9294,13 @@ impl AsRef<UpdateSupergroupFullInfo> for RTDUpdateSupergroupFullInfoBuilder {
   fn as_ref(&self) -> &UpdateSupergroupFullInfo { &self.inner }
 }
 
+impl PartialEq for UpdateSupergroupFullInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.supergroup_id == other.supergroup_id
+      && self.supergroup_full_info == other.supergroup_full_info
+  }
+}
+
 
 
 
@@ -7541,7 +9315,10 @@ pub struct UpdateTermsOfService {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Identifier of the terms of service
   terms_of_service_id: String,
   /// The new terms of service
@@ -7550,8 +9327,9 @@ pub struct UpdateTermsOfService {
 }
 
 impl RObject for UpdateTermsOfService {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateTermsOfService" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateTermsOfService" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7561,14 +9339,15 @@ impl TDUpdate for UpdateTermsOfService {}
 
 
 impl UpdateTermsOfService {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateTermsOfServiceBuilder {
-    let mut inner = UpdateTermsOfService::default();
-    inner.td_name = "updateTermsOfService".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateTermsOfService { td_name: "updateTermsOfService".to_string(), ..Default::default() };
     RTDUpdateTermsOfServiceBuilder { inner }
   }
 
+  /// `UpdateTermsOfService` with the fields tdlib requires, the others are set by `UpdateTermsOfService::builder()`
+  pub fn new(terms_of_service_id: impl AsRef<str>, terms_of_service: impl AsRef<TermsOfService>) -> Self { Self::builder().terms_of_service_id(terms_of_service_id).terms_of_service(terms_of_service).build() }
+
   pub fn terms_of_service_id(&self) -> &String { &self.terms_of_service_id }
 
   pub fn terms_of_service(&self) -> &TermsOfService { &self.terms_of_service }
@@ -7583,6 +9362,12 @@ pub struct RTDUpdateTermsOfServiceBuilder {
 impl RTDUpdateTermsOfServiceBuilder {
   pub fn build(&self) -> UpdateTermsOfService { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn terms_of_service_id<T: AsRef<str>>(&mut self, terms_of_service_id: T) -> &mut Self {
     self.inner.terms_of_service_id = terms_of_service_id.as_ref().to_string();
@@ -7605,11 +9390,20 @@ impl AsRef<UpdateTermsOfService> for RTDUpdateTermsOfServiceBuilder {
   fn as_ref(&self) -> &UpdateTermsOfService { &self.inner }
 }
 
+impl PartialEq for UpdateTermsOfService {
+  fn eq(&self, other: &Self) -> bool {
+    self.terms_of_service_id == other.terms_of_service_id
+      && self.terms_of_service == other.terms_of_service
+  }
+}
+
+
 
 
 
 
 
+rtd_cfg! { #[cfg(feature = "stickers")]
 
 /// The list of trending sticker sets was updated or some of them were viewed
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -7619,15 +9413,19 @@ pub struct UpdateTrendingStickerSets {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The prefix of the list of trending sticker sets with the newest trending sticker sets
   sticker_sets: StickerSets,
   
 }
 
 impl RObject for UpdateTrendingStickerSets {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateTrendingStickerSets" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateTrendingStickerSets" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7637,14 +9435,15 @@ impl TDUpdate for UpdateTrendingStickerSets {}
 
 
 impl UpdateTrendingStickerSets {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateTrendingStickerSetsBuilder {
-    let mut inner = UpdateTrendingStickerSets::default();
-    inner.td_name = "updateTrendingStickerSets".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateTrendingStickerSets { td_name: "updateTrendingStickerSets".to_string(), ..Default::default() };
     RTDUpdateTrendingStickerSetsBuilder { inner }
   }
 
+  /// `UpdateTrendingStickerSets` with the fields tdlib requires, the others are set by `UpdateTrendingStickerSets::builder()`
+  pub fn new(sticker_sets: impl AsRef<StickerSets>) -> Self { Self::builder().sticker_sets(sticker_sets).build() }
+
   pub fn sticker_sets(&self) -> &StickerSets { &self.sticker_sets }
 
 }
@@ -7657,6 +9456,12 @@ pub struct RTDUpdateTrendingStickerSetsBuilder {
 impl RTDUpdateTrendingStickerSetsBuilder {
   pub fn build(&self) -> UpdateTrendingStickerSets { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn sticker_sets<T: AsRef<StickerSets>>(&mut self, sticker_sets: T) -> &mut Self {
     self.inner.sticker_sets = sticker_sets.as_ref().clone();
@@ -7673,6 +9478,14 @@ impl AsRef<UpdateTrendingStickerSets> for RTDUpdateTrendingStickerSetsBuilder {
   fn as_ref(&self) -> &UpdateTrendingStickerSets { &self.inner }
 }
 
+impl PartialEq for UpdateTrendingStickerSets {
+  fn eq(&self, other: &Self) -> bool {
+    self.sticker_sets == other.sticker_sets
+  }
+}
+
+}
+
 
 
 
@@ -7687,7 +9500,10 @@ pub struct UpdateUnreadChatCount {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat list with changed number of unread messages
   chat_list: ChatList,
   /// Approximate total number of chats in the chat list
@@ -7704,8 +9520,9 @@ pub struct UpdateUnreadChatCount {
 }
 
 impl RObject for UpdateUnreadChatCount {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUnreadChatCount" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUnreadChatCount" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7715,14 +9532,31 @@ impl TDUpdate for UpdateUnreadChatCount {}
 
 
 impl UpdateUnreadChatCount {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUnreadChatCountBuilder {
-    let mut inner = UpdateUnreadChatCount::default();
-    inner.td_name = "updateUnreadChatCount".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUnreadChatCount { td_name: "updateUnreadChatCount".to_string(), ..Default::default() };
     RTDUpdateUnreadChatCountBuilder { inner }
   }
 
+  /// `UpdateUnreadChatCount` with the fields tdlib requires, the others are set by `UpdateUnreadChatCount::builder()`
+  pub fn new(
+    chat_list: impl AsRef<ChatList>,
+    total_count: i64,
+    unread_count: i64,
+    unread_unmuted_count: i64,
+    marked_as_unread_count: i64,
+    marked_as_unread_unmuted_count: i64,
+  ) -> Self {
+    Self::builder()
+      .chat_list(chat_list)
+      .total_count(total_count)
+      .unread_count(unread_count)
+      .unread_unmuted_count(unread_unmuted_count)
+      .marked_as_unread_count(marked_as_unread_count)
+      .marked_as_unread_unmuted_count(marked_as_unread_unmuted_count)
+      .build()
+  }
+
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
 
   pub fn total_count(&self) -> i64 { self.total_count }
@@ -7745,6 +9579,12 @@ pub struct RTDUpdateUnreadChatCountBuilder {
 impl RTDUpdateUnreadChatCountBuilder {
   pub fn build(&self) -> UpdateUnreadChatCount { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -7791,6 +9631,17 @@ impl AsRef<UpdateUnreadChatCount> for RTDUpdateUnreadChatCountBuilder {
   fn as_ref(&self) -> &UpdateUnreadChatCount { &self.inner }
 }
 
+impl PartialEq for UpdateUnreadChatCount {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_list == other.chat_list
+      && self.total_count == other.total_count
+      && self.unread_count == other.unread_count
+      && self.unread_unmuted_count == other.unread_unmuted_count
+      && self.marked_as_unread_count == other.marked_as_unread_count
+      && self.marked_as_unread_unmuted_count == other.marked_as_unread_unmuted_count
+  }
+}
+
 
 
 
@@ -7805,7 +9656,10 @@ pub struct UpdateUnreadMessageCount {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat list with changed number of unread messages
   chat_list: ChatList,
   /// Total number of unread messages
@@ -7816,8 +9670,9 @@ pub struct UpdateUnreadMessageCount {
 }
 
 impl RObject for UpdateUnreadMessageCount {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUnreadMessageCount" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUnreadMessageCount" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7827,14 +9682,15 @@ impl TDUpdate for UpdateUnreadMessageCount {}
 
 
 impl UpdateUnreadMessageCount {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUnreadMessageCountBuilder {
-    let mut inner = UpdateUnreadMessageCount::default();
-    inner.td_name = "updateUnreadMessageCount".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUnreadMessageCount { td_name: "updateUnreadMessageCount".to_string(), ..Default::default() };
     RTDUpdateUnreadMessageCountBuilder { inner }
   }
 
+  /// `UpdateUnreadMessageCount` with the fields tdlib requires, the others are set by `UpdateUnreadMessageCount::builder()`
+  pub fn new(chat_list: impl AsRef<ChatList>, unread_count: i64, unread_unmuted_count: i64) -> Self { Self::builder().chat_list(chat_list).unread_count(unread_count).unread_unmuted_count(unread_unmuted_count).build() }
+
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
 
   pub fn unread_count(&self) -> i64 { self.unread_count }
@@ -7851,6 +9707,12 @@ pub struct RTDUpdateUnreadMessageCountBuilder {
 impl RTDUpdateUnreadMessageCountBuilder {
   pub fn build(&self) -> UpdateUnreadMessageCount { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -7879,6 +9741,14 @@ impl AsRef<UpdateUnreadMessageCount> for RTDUpdateUnreadMessageCountBuilder {
   fn as_ref(&self) -> &UpdateUnreadMessageCount { &self.inner }
 }
 
+impl PartialEq for UpdateUnreadMessageCount {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_list == other.chat_list
+      && self.unread_count == other.unread_count
+      && self.unread_unmuted_count == other.unread_unmuted_count
+  }
+}
+
 
 
 
@@ -7893,15 +9763,19 @@ pub struct UpdateUser {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// New data about the user
   user: User,
   
 }
 
 impl RObject for UpdateUser {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUser" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUser" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7911,14 +9785,15 @@ impl TDUpdate for UpdateUser {}
 
 
 impl UpdateUser {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUserBuilder {
-    let mut inner = UpdateUser::default();
-    inner.td_name = "updateUser".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUser { td_name: "updateUser".to_string(), ..Default::default() };
     RTDUpdateUserBuilder { inner }
   }
 
+  /// `UpdateUser` with the fields tdlib requires, the others are set by `UpdateUser::builder()`
+  pub fn new(user: impl AsRef<User>) -> Self { Self::builder().user(user).build() }
+
   pub fn user(&self) -> &User { &self.user }
 
 }
@@ -7931,6 +9806,12 @@ pub struct RTDUpdateUserBuilder {
 impl RTDUpdateUserBuilder {
   pub fn build(&self) -> UpdateUser { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn user<T: AsRef<User>>(&mut self, user: T) -> &mut Self {
     self.inner.user = user.as_ref().clone();
@@ -7947,6 +9828,12 @@ impl AsRef<UpdateUser> for RTDUpdateUserBuilder {
   fn as_ref(&self) -> &UpdateUser { &self.inner }
 }
 
+impl PartialEq for UpdateUser {
+  fn eq(&self, other: &Self) -> bool {
+    self.user == other.user
+  }
+}
+
 
 
 
@@ -7961,21 +9848,25 @@ pub struct UpdateUserChatAction {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Chat identifier
-  chat_id: i64,
+  chat_id: ChatId,
   /// If not 0, a message thread identifier in which the action was performed
   message_thread_id: i64,
   /// Identifier of a user performing an action
-  user_id: i64,
+  user_id: UserId,
   /// The action description
   action: ChatAction,
   
 }
 
 impl RObject for UpdateUserChatAction {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserChatAction" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUserChatAction" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -7985,19 +9876,32 @@ impl TDUpdate for UpdateUserChatAction {}
 
 
 impl UpdateUserChatAction {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUserChatActionBuilder {
-    let mut inner = UpdateUserChatAction::default();
-    inner.td_name = "updateUserChatAction".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUserChatAction { td_name: "updateUserChatAction".to_string(), ..Default::default() };
     RTDUpdateUserChatActionBuilder { inner }
   }
 
-  pub fn chat_id(&self) -> i64 { self.chat_id }
+  /// `UpdateUserChatAction` with the fields tdlib requires, the others are set by `UpdateUserChatAction::builder()`
+  pub fn new(
+    chat_id: ChatId,
+    message_thread_id: i64,
+    user_id: UserId,
+    action: impl AsRef<ChatAction>,
+  ) -> Self {
+    Self::builder()
+      .chat_id(chat_id)
+      .message_thread_id(message_thread_id)
+      .user_id(user_id)
+      .action(action)
+      .build()
+  }
+
+  pub fn chat_id(&self) -> ChatId { self.chat_id }
 
   pub fn message_thread_id(&self) -> i64 { self.message_thread_id }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn action(&self) -> &ChatAction { &self.action }
 
@@ -8011,8 +9915,14 @@ pub struct RTDUpdateUserChatActionBuilder {
 impl RTDUpdateUserChatActionBuilder {
   pub fn build(&self) -> UpdateUserChatAction { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
+  pub fn chat_id(&mut self, chat_id: ChatId) -> &mut Self {
     self.inner.chat_id = chat_id;
     self
   }
@@ -8024,7 +9934,7 @@ impl RTDUpdateUserChatActionBuilder {
   }
 
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -8045,6 +9955,15 @@ impl AsRef<UpdateUserChatAction> for RTDUpdateUserChatActionBuilder {
   fn as_ref(&self) -> &UpdateUserChatAction { &self.inner }
 }
 
+impl PartialEq for UpdateUserChatAction {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_id == other.chat_id
+      && self.message_thread_id == other.message_thread_id
+      && self.user_id == other.user_id
+      && self.action == other.action
+  }
+}
+
 
 
 
@@ -8059,17 +9978,21 @@ pub struct UpdateUserFullInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   /// New full information about the user
   user_full_info: UserFullInfo,
   
 }
 
 impl RObject for UpdateUserFullInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserFullInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUserFullInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -8079,15 +10002,16 @@ impl TDUpdate for UpdateUserFullInfo {}
 
 
 impl UpdateUserFullInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUserFullInfoBuilder {
-    let mut inner = UpdateUserFullInfo::default();
-    inner.td_name = "updateUserFullInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUserFullInfo { td_name: "updateUserFullInfo".to_string(), ..Default::default() };
     RTDUpdateUserFullInfoBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `UpdateUserFullInfo` with the fields tdlib requires, the others are set by `UpdateUserFullInfo::builder()`
+  pub fn new(user_id: UserId, user_full_info: impl AsRef<UserFullInfo>) -> Self { Self::builder().user_id(user_id).user_full_info(user_full_info).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn user_full_info(&self) -> &UserFullInfo { &self.user_full_info }
 
@@ -8101,8 +10025,14 @@ pub struct RTDUpdateUserFullInfoBuilder {
 impl RTDUpdateUserFullInfoBuilder {
   pub fn build(&self) -> UpdateUserFullInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -8123,6 +10053,13 @@ impl AsRef<UpdateUserFullInfo> for RTDUpdateUserFullInfoBuilder {
   fn as_ref(&self) -> &UpdateUserFullInfo { &self.inner }
 }
 
+impl PartialEq for UpdateUserFullInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.user_full_info == other.user_full_info
+  }
+}
+
 
 
 
@@ -8137,7 +10074,10 @@ pub struct UpdateUserPrivacySettingRules {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The privacy setting
   setting: UserPrivacySetting,
   /// New privacy rules
@@ -8146,8 +10086,9 @@ pub struct UpdateUserPrivacySettingRules {
 }
 
 impl RObject for UpdateUserPrivacySettingRules {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserPrivacySettingRules" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUserPrivacySettingRules" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -8157,14 +10098,15 @@ impl TDUpdate for UpdateUserPrivacySettingRules {}
 
 
 impl UpdateUserPrivacySettingRules {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUserPrivacySettingRulesBuilder {
-    let mut inner = UpdateUserPrivacySettingRules::default();
-    inner.td_name = "updateUserPrivacySettingRules".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUserPrivacySettingRules { td_name: "updateUserPrivacySettingRules".to_string(), ..Default::default() };
     RTDUpdateUserPrivacySettingRulesBuilder { inner }
   }
 
+  /// `UpdateUserPrivacySettingRules` with the fields tdlib requires, the others are set by `UpdateUserPrivacySettingRules::builder()`
+  pub fn new(setting: impl AsRef<UserPrivacySetting>, rules: impl AsRef<UserPrivacySettingRules>) -> Self { Self::builder().setting(setting).rules(rules).build() }
+
   pub fn setting(&self) -> &UserPrivacySetting { &self.setting }
 
   pub fn rules(&self) -> &UserPrivacySettingRules { &self.rules }
@@ -8179,6 +10121,12 @@ pub struct RTDUpdateUserPrivacySettingRulesBuilder {
 impl RTDUpdateUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> UpdateUserPrivacySettingRules { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
     self.inner.setting = setting.as_ref().clone();
@@ -8201,6 +10149,13 @@ impl AsRef<UpdateUserPrivacySettingRules> for RTDUpdateUserPrivacySettingRulesBu
   fn as_ref(&self) -> &UpdateUserPrivacySettingRules { &self.inner }
 }
 
+impl PartialEq for UpdateUserPrivacySettingRules {
+  fn eq(&self, other: &Self) -> bool {
+    self.setting == other.setting
+      && self.rules == other.rules
+  }
+}
+
 
 
 
@@ -8215,17 +10170,21 @@ pub struct UpdateUserStatus {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  user_id: i64,
+  user_id: UserId,
   /// New status of the user
   status: UserStatus,
   
 }
 
 impl RObject for UpdateUserStatus {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUserStatus" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUserStatus" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -8235,15 +10194,16 @@ impl TDUpdate for UpdateUserStatus {}
 
 
 impl UpdateUserStatus {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUserStatusBuilder {
-    let mut inner = UpdateUserStatus::default();
-    inner.td_name = "updateUserStatus".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUserStatus { td_name: "updateUserStatus".to_string(), ..Default::default() };
     RTDUpdateUserStatusBuilder { inner }
   }
 
-  pub fn user_id(&self) -> i64 { self.user_id }
+  /// `UpdateUserStatus` with the fields tdlib requires, the others are set by `UpdateUserStatus::builder()`
+  pub fn new(user_id: UserId, status: impl AsRef<UserStatus>) -> Self { Self::builder().user_id(user_id).status(status).build() }
+
+  pub fn user_id(&self) -> UserId { self.user_id }
 
   pub fn status(&self) -> &UserStatus { &self.status }
 
@@ -8257,8 +10217,14 @@ pub struct RTDUpdateUserStatusBuilder {
 impl RTDUpdateUserStatusBuilder {
   pub fn build(&self) -> UpdateUserStatus { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_id(&mut self, user_id: i64) -> &mut Self {
+  pub fn user_id(&mut self, user_id: UserId) -> &mut Self {
     self.inner.user_id = user_id;
     self
   }
@@ -8279,6 +10245,13 @@ impl AsRef<UpdateUserStatus> for RTDUpdateUserStatusBuilder {
   fn as_ref(&self) -> &UpdateUserStatus { &self.inner }
 }
 
+impl PartialEq for UpdateUserStatus {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_id == other.user_id
+      && self.status == other.status
+  }
+}
+
 
 
 
@@ -8293,15 +10266,19 @@ pub struct UpdateUsersNearby {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The new list of users nearby
   users_nearby: Vec<ChatNearby>,
   
 }
 
 impl RObject for UpdateUsersNearby {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updateUsersNearby" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updateUsersNearby" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -8311,14 +10288,15 @@ impl TDUpdate for UpdateUsersNearby {}
 
 
 impl UpdateUsersNearby {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdateUsersNearbyBuilder {
-    let mut inner = UpdateUsersNearby::default();
-    inner.td_name = "updateUsersNearby".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UpdateUsersNearby { td_name: "updateUsersNearby".to_string(), ..Default::default() };
     RTDUpdateUsersNearbyBuilder { inner }
   }
 
+  /// `UpdateUsersNearby` with the fields tdlib requires, the others are set by `UpdateUsersNearby::builder()`
+  pub fn new(users_nearby: Vec<ChatNearby>) -> Self { Self::builder().users_nearby(users_nearby).build() }
+
   pub fn users_nearby(&self) -> &Vec<ChatNearby> { &self.users_nearby }
 
 }
@@ -8331,6 +10309,12 @@ pub struct RTDUpdateUsersNearbyBuilder {
 impl RTDUpdateUsersNearbyBuilder {
   pub fn build(&self) -> UpdateUsersNearby { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
     self.inner.users_nearby = users_nearby;
@@ -8347,5 +10331,11 @@ impl AsRef<UpdateUsersNearby> for RTDUpdateUsersNearbyBuilder {
   fn as_ref(&self) -> &UpdateUsersNearby { &self.inner }
 }
 
+impl PartialEq for UpdateUsersNearby {
+  fn eq(&self, other: &Self) -> bool {
+    self.users_nearby == other.users_nearby
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct Updates {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// List of updates
   updates: Vec<Update>,
   
 }
 
 impl RObject for Updates {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "updates" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "updates" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Updates {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUpdatesBuilder {
-    let mut inner = Updates::default();
-    inner.td_name = "updates".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Updates { td_name: "updates".to_string(), ..Default::default() };
     RTDUpdatesBuilder { inner }
   }
 
+  /// `Updates` with the fields tdlib requires, the others are set by `Updates::builder()`
+  pub fn new(updates: Vec<Update>) -> Self { Self::builder().updates(updates).build() }
+
   pub fn updates(&self) -> &Vec<Update> { &self.updates }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDUpdatesBuilder {
 impl RTDUpdatesBuilder {
   pub fn build(&self) -> Updates { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn updates(&mut self, updates: Vec<Update>) -> &mut Self {
     self.inner.updates = updates;
@@ -65,5 +75,11 @@ impl AsRef<Updates> for RTDUpdatesBuilder {
   fn as_ref(&self) -> &Updates { &self.inner }
 }
 
+impl PartialEq for Updates {
+  fn eq(&self, other: &Self) -> bool {
+    self.updates == other.updates
+  }
+}
+
 
 
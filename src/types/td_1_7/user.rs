@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,9 +13,12 @@ pub struct User {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User identifier
-  id: i64,
+  id: UserId,
   /// First name of the user
   first_name: String,
   /// Last name of the user
@@ -53,23 +55,62 @@ pub struct User {
 }
 
 impl RObject for User {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "user" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "user" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl User {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserBuilder {
-    let mut inner = User::default();
-    inner.td_name = "user".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = User { td_name: "user".to_string(), ..Default::default() };
     RTDUserBuilder { inner }
   }
 
-  pub fn id(&self) -> i64 { self.id }
+  /// `User` with the fields tdlib requires, the others are set by `User::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: UserId,
+    first_name: impl AsRef<str>,
+    last_name: impl AsRef<str>,
+    username: impl AsRef<str>,
+    phone_number: impl AsRef<str>,
+    status: impl AsRef<UserStatus>,
+    is_contact: bool,
+    is_mutual_contact: bool,
+    is_verified: bool,
+    is_support: bool,
+    restriction_reason: impl AsRef<str>,
+    is_scam: bool,
+    is_fake: bool,
+    have_access: bool,
+    type_: impl AsRef<UserType>,
+    language_code: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .id(id)
+      .first_name(first_name)
+      .last_name(last_name)
+      .username(username)
+      .phone_number(phone_number)
+      .status(status)
+      .is_contact(is_contact)
+      .is_mutual_contact(is_mutual_contact)
+      .is_verified(is_verified)
+      .is_support(is_support)
+      .restriction_reason(restriction_reason)
+      .is_scam(is_scam)
+      .is_fake(is_fake)
+      .have_access(have_access)
+      .type_(type_)
+      .language_code(language_code)
+      .build()
+  }
+
+  pub fn id(&self) -> UserId { self.id }
 
   pub fn first_name(&self) -> &String { &self.first_name }
 
@@ -113,8 +154,14 @@ pub struct RTDUserBuilder {
 impl RTDUserBuilder {
   pub fn build(&self) -> User { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn id(&mut self, id: i64) -> &mut Self {
+  pub fn id(&mut self, id: UserId) -> &mut Self {
     self.inner.id = id;
     self
   }
@@ -225,5 +272,27 @@ impl AsRef<User> for RTDUserBuilder {
   fn as_ref(&self) -> &User { &self.inner }
 }
 
+impl PartialEq for User {
+  fn eq(&self, other: &Self) -> bool {
+    self.id == other.id
+      && self.first_name == other.first_name
+      && self.last_name == other.last_name
+      && self.username == other.username
+      && self.phone_number == other.phone_number
+      && self.status == other.status
+      && self.profile_photo == other.profile_photo
+      && self.is_contact == other.is_contact
+      && self.is_mutual_contact == other.is_mutual_contact
+      && self.is_verified == other.is_verified
+      && self.is_support == other.is_support
+      && self.restriction_reason == other.restriction_reason
+      && self.is_scam == other.is_scam
+      && self.is_fake == other.is_fake
+      && self.have_access == other.have_access
+      && self.type_ == other.type_
+      && self.language_code == other.language_code
+  }
+}
+
 
 
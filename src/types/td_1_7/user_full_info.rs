@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct UserFullInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// User profile photo; may be null
   photo: Option<ChatPhoto>,
   /// True, if the user is blocked by the current user
@@ -31,7 +33,7 @@ pub struct UserFullInfo {
   bio: String,
   /// For bots, the text that is shown on the bot's profile page and is sent together with the link when users share the bot
   share_text: String,
-  /// Contains full information about a user
+  /// For bots, the text that is shown on the bot's profile page and is sent together with the link when users share the bot
   description: String,
   /// Number of group chats where both the other user and the current user are a member; 0 for the current user
   group_in_common_count: i64,
@@ -41,22 +43,49 @@ pub struct UserFullInfo {
 }
 
 impl RObject for UserFullInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userFullInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userFullInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl UserFullInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserFullInfoBuilder {
-    let mut inner = UserFullInfo::default();
-    inner.td_name = "userFullInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserFullInfo { td_name: "userFullInfo".to_string(), ..Default::default() };
     RTDUserFullInfoBuilder { inner }
   }
 
+  /// `UserFullInfo` with the fields tdlib requires, the others are set by `UserFullInfo::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    is_blocked: bool,
+    can_be_called: bool,
+    supports_video_calls: bool,
+    has_private_calls: bool,
+    need_phone_number_privacy_exception: bool,
+    bio: impl AsRef<str>,
+    share_text: impl AsRef<str>,
+    description: impl AsRef<str>,
+    group_in_common_count: i64,
+    commands: Vec<BotCommand>,
+  ) -> Self {
+    Self::builder()
+      .is_blocked(is_blocked)
+      .can_be_called(can_be_called)
+      .supports_video_calls(supports_video_calls)
+      .has_private_calls(has_private_calls)
+      .need_phone_number_privacy_exception(need_phone_number_privacy_exception)
+      .bio(bio)
+      .share_text(share_text)
+      .description(description)
+      .group_in_common_count(group_in_common_count)
+      .commands(commands)
+      .build()
+  }
+
   pub fn photo(&self) -> &Option<ChatPhoto> { &self.photo }
 
   pub fn is_blocked(&self) -> bool { self.is_blocked }
@@ -89,6 +118,12 @@ pub struct RTDUserFullInfoBuilder {
 impl RTDUserFullInfoBuilder {
   pub fn build(&self) -> UserFullInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -165,5 +200,21 @@ impl AsRef<UserFullInfo> for RTDUserFullInfoBuilder {
   fn as_ref(&self) -> &UserFullInfo { &self.inner }
 }
 
+impl PartialEq for UserFullInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.photo == other.photo
+      && self.is_blocked == other.is_blocked
+      && self.can_be_called == other.can_be_called
+      && self.supports_video_calls == other.supports_video_calls
+      && self.has_private_calls == other.has_private_calls
+      && self.need_phone_number_privacy_exception == other.need_phone_number_privacy_exception
+      && self.bio == other.bio
+      && self.share_text == other.share_text
+      && self.description == other.description
+      && self.group_in_common_count == other.group_in_common_count
+      && self.commands == other.commands
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDUserPrivacySetting: Debug + RObject {}
 
 /// Describes available user privacy settings
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum UserPrivacySetting {
   #[doc(hidden)] _Default(()),
@@ -35,6 +34,8 @@ pub enum UserPrivacySetting {
   ShowProfilePhoto(UserPrivacySettingShowProfilePhoto),
   /// A privacy setting for managing whether the user's online status is visible
   ShowStatus(UserPrivacySettingShowStatus),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -46,7 +47,7 @@ impl<'de> Deserialize<'de> for UserPrivacySetting {
   fn deserialize<D>(deserializer: D) -> Result<UserPrivacySetting, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      UserPrivacySetting,
+      UserPrivacySetting, Unknown;
       (userPrivacySettingAllowCalls, AllowCalls);
       (userPrivacySettingAllowChatInvites, AllowChatInvites);
       (userPrivacySettingAllowFindingByPhoneNumber, AllowFindingByPhoneNumber);
@@ -61,7 +62,7 @@ impl<'de> Deserialize<'de> for UserPrivacySetting {
 }
 
 impl RObject for UserPrivacySetting {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       UserPrivacySetting::AllowCalls(t) => t.td_name(),
       UserPrivacySetting::AllowChatInvites(t) => t.td_name(),
@@ -71,11 +72,12 @@ impl RObject for UserPrivacySetting {
       UserPrivacySetting::ShowPhoneNumber(t) => t.td_name(),
       UserPrivacySetting::ShowProfilePhoto(t) => t.td_name(),
       UserPrivacySetting::ShowStatus(t) => t.td_name(),
+      UserPrivacySetting::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       UserPrivacySetting::AllowCalls(t) => t.extra(),
       UserPrivacySetting::AllowChatInvites(t) => t.extra(),
@@ -85,6 +87,22 @@ impl RObject for UserPrivacySetting {
       UserPrivacySetting::ShowPhoneNumber(t) => t.extra(),
       UserPrivacySetting::ShowProfilePhoto(t) => t.extra(),
       UserPrivacySetting::ShowStatus(t) => t.extra(),
+      UserPrivacySetting::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      UserPrivacySetting::AllowCalls(t) => t.client_id(),
+      UserPrivacySetting::AllowChatInvites(t) => t.client_id(),
+      UserPrivacySetting::AllowFindingByPhoneNumber(t) => t.client_id(),
+      UserPrivacySetting::AllowPeerToPeerCalls(t) => t.client_id(),
+      UserPrivacySetting::ShowLinkInForwardedMessages(t) => t.client_id(),
+      UserPrivacySetting::ShowPhoneNumber(t) => t.client_id(),
+      UserPrivacySetting::ShowProfilePhoto(t) => t.client_id(),
+      UserPrivacySetting::ShowStatus(t) => t.client_id(),
+      UserPrivacySetting::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -93,17 +111,18 @@ impl RObject for UserPrivacySetting {
 }
 
 impl UserPrivacySetting {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserPrivacySetting::_Default(_) = self { true } else { false } }
-
-  pub fn is_allow_calls(&self) -> bool { if let UserPrivacySetting::AllowCalls(_) = self { true } else { false } }
-  pub fn is_allow_chat_invites(&self) -> bool { if let UserPrivacySetting::AllowChatInvites(_) = self { true } else { false } }
-  pub fn is_allow_finding_by_phone_number(&self) -> bool { if let UserPrivacySetting::AllowFindingByPhoneNumber(_) = self { true } else { false } }
-  pub fn is_allow_peer_to_peer_calls(&self) -> bool { if let UserPrivacySetting::AllowPeerToPeerCalls(_) = self { true } else { false } }
-  pub fn is_show_link_in_forwarded_messages(&self) -> bool { if let UserPrivacySetting::ShowLinkInForwardedMessages(_) = self { true } else { false } }
-  pub fn is_show_phone_number(&self) -> bool { if let UserPrivacySetting::ShowPhoneNumber(_) = self { true } else { false } }
-  pub fn is_show_profile_photo(&self) -> bool { if let UserPrivacySetting::ShowProfilePhoto(_) = self { true } else { false } }
-  pub fn is_show_status(&self) -> bool { if let UserPrivacySetting::ShowStatus(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, UserPrivacySetting::_Default(_)) }
+
+  pub fn is_allow_calls(&self) -> bool { matches!(self, UserPrivacySetting::AllowCalls(_)) }
+  pub fn is_allow_chat_invites(&self) -> bool { matches!(self, UserPrivacySetting::AllowChatInvites(_)) }
+  pub fn is_allow_finding_by_phone_number(&self) -> bool { matches!(self, UserPrivacySetting::AllowFindingByPhoneNumber(_)) }
+  pub fn is_allow_peer_to_peer_calls(&self) -> bool { matches!(self, UserPrivacySetting::AllowPeerToPeerCalls(_)) }
+  pub fn is_show_link_in_forwarded_messages(&self) -> bool { matches!(self, UserPrivacySetting::ShowLinkInForwardedMessages(_)) }
+  pub fn is_show_phone_number(&self) -> bool { matches!(self, UserPrivacySetting::ShowPhoneNumber(_)) }
+  pub fn is_show_profile_photo(&self) -> bool { matches!(self, UserPrivacySetting::ShowProfilePhoto(_)) }
+  pub fn is_show_status(&self) -> bool { matches!(self, UserPrivacySetting::ShowStatus(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, UserPrivacySetting::Unknown(_)) }
 
   pub fn on_allow_calls<F: FnOnce(&UserPrivacySettingAllowCalls)>(&self, fnc: F) -> &Self { if let UserPrivacySetting::AllowCalls(t) = self { fnc(t) }; self }
   pub fn on_allow_chat_invites<F: FnOnce(&UserPrivacySettingAllowChatInvites)>(&self, fnc: F) -> &Self { if let UserPrivacySetting::AllowChatInvites(t) = self { fnc(t) }; self }
@@ -113,6 +132,7 @@ impl UserPrivacySetting {
   pub fn on_show_phone_number<F: FnOnce(&UserPrivacySettingShowPhoneNumber)>(&self, fnc: F) -> &Self { if let UserPrivacySetting::ShowPhoneNumber(t) = self { fnc(t) }; self }
   pub fn on_show_profile_photo<F: FnOnce(&UserPrivacySettingShowProfilePhoto)>(&self, fnc: F) -> &Self { if let UserPrivacySetting::ShowProfilePhoto(t) = self { fnc(t) }; self }
   pub fn on_show_status<F: FnOnce(&UserPrivacySettingShowStatus)>(&self, fnc: F) -> &Self { if let UserPrivacySetting::ShowStatus(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let UserPrivacySetting::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_allow_calls(&self) -> Option<&UserPrivacySettingAllowCalls> { if let UserPrivacySetting::AllowCalls(t) = self { return Some(t) } None }
   pub fn as_allow_chat_invites(&self) -> Option<&UserPrivacySettingAllowChatInvites> { if let UserPrivacySetting::AllowChatInvites(t) = self { return Some(t) } None }
@@ -122,6 +142,7 @@ impl UserPrivacySetting {
   pub fn as_show_phone_number(&self) -> Option<&UserPrivacySettingShowPhoneNumber> { if let UserPrivacySetting::ShowPhoneNumber(t) = self { return Some(t) } None }
   pub fn as_show_profile_photo(&self) -> Option<&UserPrivacySettingShowProfilePhoto> { if let UserPrivacySetting::ShowProfilePhoto(t) = self { return Some(t) } None }
   pub fn as_show_status(&self) -> Option<&UserPrivacySettingShowStatus> { if let UserPrivacySetting::ShowStatus(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let UserPrivacySetting::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -161,13 +182,17 @@ pub struct UserPrivacySettingAllowCalls {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingAllowCalls {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowCalls" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingAllowCalls" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -177,14 +202,15 @@ impl TDUserPrivacySetting for UserPrivacySettingAllowCalls {}
 
 
 impl UserPrivacySettingAllowCalls {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingAllowCallsBuilder {
-    let mut inner = UserPrivacySettingAllowCalls::default();
-    inner.td_name = "userPrivacySettingAllowCalls".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingAllowCalls { td_name: "userPrivacySettingAllowCalls".to_string(), ..Default::default() };
     RTDUserPrivacySettingAllowCallsBuilder { inner }
   }
 
+  /// `UserPrivacySettingAllowCalls` with the fields tdlib requires, the others are set by `UserPrivacySettingAllowCalls::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +221,12 @@ pub struct RTDUserPrivacySettingAllowCallsBuilder {
 impl RTDUserPrivacySettingAllowCallsBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowCalls { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowCalls> for UserPrivacySettingAllowCalls {
@@ -205,6 +237,10 @@ impl AsRef<UserPrivacySettingAllowCalls> for RTDUserPrivacySettingAllowCallsBuil
   fn as_ref(&self) -> &UserPrivacySettingAllowCalls { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingAllowCalls {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -219,13 +255,17 @@ pub struct UserPrivacySettingAllowChatInvites {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingAllowChatInvites {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowChatInvites" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingAllowChatInvites" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -235,14 +275,15 @@ impl TDUserPrivacySetting for UserPrivacySettingAllowChatInvites {}
 
 
 impl UserPrivacySettingAllowChatInvites {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingAllowChatInvitesBuilder {
-    let mut inner = UserPrivacySettingAllowChatInvites::default();
-    inner.td_name = "userPrivacySettingAllowChatInvites".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingAllowChatInvites { td_name: "userPrivacySettingAllowChatInvites".to_string(), ..Default::default() };
     RTDUserPrivacySettingAllowChatInvitesBuilder { inner }
   }
 
+  /// `UserPrivacySettingAllowChatInvites` with the fields tdlib requires, the others are set by `UserPrivacySettingAllowChatInvites::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -253,6 +294,12 @@ pub struct RTDUserPrivacySettingAllowChatInvitesBuilder {
 impl RTDUserPrivacySettingAllowChatInvitesBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowChatInvites { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowChatInvites> for UserPrivacySettingAllowChatInvites {
@@ -263,6 +310,10 @@ impl AsRef<UserPrivacySettingAllowChatInvites> for RTDUserPrivacySettingAllowCha
   fn as_ref(&self) -> &UserPrivacySettingAllowChatInvites { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingAllowChatInvites {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -277,13 +328,17 @@ pub struct UserPrivacySettingAllowFindingByPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingAllowFindingByPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowFindingByPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingAllowFindingByPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -293,14 +348,15 @@ impl TDUserPrivacySetting for UserPrivacySettingAllowFindingByPhoneNumber {}
 
 
 impl UserPrivacySettingAllowFindingByPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
-    let mut inner = UserPrivacySettingAllowFindingByPhoneNumber::default();
-    inner.td_name = "userPrivacySettingAllowFindingByPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingAllowFindingByPhoneNumber { td_name: "userPrivacySettingAllowFindingByPhoneNumber".to_string(), ..Default::default() };
     RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder { inner }
   }
 
+  /// `UserPrivacySettingAllowFindingByPhoneNumber` with the fields tdlib requires, the others are set by `UserPrivacySettingAllowFindingByPhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -311,6 +367,12 @@ pub struct RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
 impl RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowFindingByPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowFindingByPhoneNumber> for UserPrivacySettingAllowFindingByPhoneNumber {
@@ -321,6 +383,10 @@ impl AsRef<UserPrivacySettingAllowFindingByPhoneNumber> for RTDUserPrivacySettin
   fn as_ref(&self) -> &UserPrivacySettingAllowFindingByPhoneNumber { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingAllowFindingByPhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -335,13 +401,17 @@ pub struct UserPrivacySettingAllowPeerToPeerCalls {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingAllowPeerToPeerCalls {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingAllowPeerToPeerCalls" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingAllowPeerToPeerCalls" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -351,14 +421,15 @@ impl TDUserPrivacySetting for UserPrivacySettingAllowPeerToPeerCalls {}
 
 
 impl UserPrivacySettingAllowPeerToPeerCalls {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
-    let mut inner = UserPrivacySettingAllowPeerToPeerCalls::default();
-    inner.td_name = "userPrivacySettingAllowPeerToPeerCalls".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingAllowPeerToPeerCalls { td_name: "userPrivacySettingAllowPeerToPeerCalls".to_string(), ..Default::default() };
     RTDUserPrivacySettingAllowPeerToPeerCallsBuilder { inner }
   }
 
+  /// `UserPrivacySettingAllowPeerToPeerCalls` with the fields tdlib requires, the others are set by `UserPrivacySettingAllowPeerToPeerCalls::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -369,6 +440,12 @@ pub struct RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
 impl RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowPeerToPeerCalls { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingAllowPeerToPeerCalls> for UserPrivacySettingAllowPeerToPeerCalls {
@@ -379,6 +456,10 @@ impl AsRef<UserPrivacySettingAllowPeerToPeerCalls> for RTDUserPrivacySettingAllo
   fn as_ref(&self) -> &UserPrivacySettingAllowPeerToPeerCalls { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingAllowPeerToPeerCalls {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -393,13 +474,17 @@ pub struct UserPrivacySettingShowLinkInForwardedMessages {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingShowLinkInForwardedMessages {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowLinkInForwardedMessages" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingShowLinkInForwardedMessages" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -409,14 +494,15 @@ impl TDUserPrivacySetting for UserPrivacySettingShowLinkInForwardedMessages {}
 
 
 impl UserPrivacySettingShowLinkInForwardedMessages {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
-    let mut inner = UserPrivacySettingShowLinkInForwardedMessages::default();
-    inner.td_name = "userPrivacySettingShowLinkInForwardedMessages".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingShowLinkInForwardedMessages { td_name: "userPrivacySettingShowLinkInForwardedMessages".to_string(), ..Default::default() };
     RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder { inner }
   }
 
+  /// `UserPrivacySettingShowLinkInForwardedMessages` with the fields tdlib requires, the others are set by `UserPrivacySettingShowLinkInForwardedMessages::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -427,6 +513,12 @@ pub struct RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
 impl RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
   pub fn build(&self) -> UserPrivacySettingShowLinkInForwardedMessages { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowLinkInForwardedMessages> for UserPrivacySettingShowLinkInForwardedMessages {
@@ -437,6 +529,10 @@ impl AsRef<UserPrivacySettingShowLinkInForwardedMessages> for RTDUserPrivacySett
   fn as_ref(&self) -> &UserPrivacySettingShowLinkInForwardedMessages { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingShowLinkInForwardedMessages {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -451,13 +547,17 @@ pub struct UserPrivacySettingShowPhoneNumber {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingShowPhoneNumber {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowPhoneNumber" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingShowPhoneNumber" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -467,14 +567,15 @@ impl TDUserPrivacySetting for UserPrivacySettingShowPhoneNumber {}
 
 
 impl UserPrivacySettingShowPhoneNumber {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingShowPhoneNumberBuilder {
-    let mut inner = UserPrivacySettingShowPhoneNumber::default();
-    inner.td_name = "userPrivacySettingShowPhoneNumber".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingShowPhoneNumber { td_name: "userPrivacySettingShowPhoneNumber".to_string(), ..Default::default() };
     RTDUserPrivacySettingShowPhoneNumberBuilder { inner }
   }
 
+  /// `UserPrivacySettingShowPhoneNumber` with the fields tdlib requires, the others are set by `UserPrivacySettingShowPhoneNumber::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -485,6 +586,12 @@ pub struct RTDUserPrivacySettingShowPhoneNumberBuilder {
 impl RTDUserPrivacySettingShowPhoneNumberBuilder {
   pub fn build(&self) -> UserPrivacySettingShowPhoneNumber { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowPhoneNumber> for UserPrivacySettingShowPhoneNumber {
@@ -495,6 +602,10 @@ impl AsRef<UserPrivacySettingShowPhoneNumber> for RTDUserPrivacySettingShowPhone
   fn as_ref(&self) -> &UserPrivacySettingShowPhoneNumber { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingShowPhoneNumber {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -509,13 +620,17 @@ pub struct UserPrivacySettingShowProfilePhoto {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingShowProfilePhoto {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowProfilePhoto" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingShowProfilePhoto" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -525,14 +640,15 @@ impl TDUserPrivacySetting for UserPrivacySettingShowProfilePhoto {}
 
 
 impl UserPrivacySettingShowProfilePhoto {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingShowProfilePhotoBuilder {
-    let mut inner = UserPrivacySettingShowProfilePhoto::default();
-    inner.td_name = "userPrivacySettingShowProfilePhoto".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingShowProfilePhoto { td_name: "userPrivacySettingShowProfilePhoto".to_string(), ..Default::default() };
     RTDUserPrivacySettingShowProfilePhotoBuilder { inner }
   }
 
+  /// `UserPrivacySettingShowProfilePhoto` with the fields tdlib requires, the others are set by `UserPrivacySettingShowProfilePhoto::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -543,6 +659,12 @@ pub struct RTDUserPrivacySettingShowProfilePhotoBuilder {
 impl RTDUserPrivacySettingShowProfilePhotoBuilder {
   pub fn build(&self) -> UserPrivacySettingShowProfilePhoto { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowProfilePhoto> for UserPrivacySettingShowProfilePhoto {
@@ -553,6 +675,10 @@ impl AsRef<UserPrivacySettingShowProfilePhoto> for RTDUserPrivacySettingShowProf
   fn as_ref(&self) -> &UserPrivacySettingShowProfilePhoto { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingShowProfilePhoto {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -567,13 +693,17 @@ pub struct UserPrivacySettingShowStatus {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingShowStatus {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingShowStatus" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingShowStatus" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -583,14 +713,15 @@ impl TDUserPrivacySetting for UserPrivacySettingShowStatus {}
 
 
 impl UserPrivacySettingShowStatus {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingShowStatusBuilder {
-    let mut inner = UserPrivacySettingShowStatus::default();
-    inner.td_name = "userPrivacySettingShowStatus".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingShowStatus { td_name: "userPrivacySettingShowStatus".to_string(), ..Default::default() };
     RTDUserPrivacySettingShowStatusBuilder { inner }
   }
 
+  /// `UserPrivacySettingShowStatus` with the fields tdlib requires, the others are set by `UserPrivacySettingShowStatus::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -601,6 +732,12 @@ pub struct RTDUserPrivacySettingShowStatusBuilder {
 impl RTDUserPrivacySettingShowStatusBuilder {
   pub fn build(&self) -> UserPrivacySettingShowStatus { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingShowStatus> for UserPrivacySettingShowStatus {
@@ -611,5 +748,9 @@ impl AsRef<UserPrivacySettingShowStatus> for RTDUserPrivacySettingShowStatusBuil
   fn as_ref(&self) -> &UserPrivacySettingShowStatus { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingShowStatus {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDUserPrivacySettingRule: Debug + RObject {}
 
 /// Represents a single rule for managing privacy settings
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum UserPrivacySettingRule {
   #[doc(hidden)] _Default(()),
@@ -35,6 +34,8 @@ pub enum UserPrivacySettingRule {
   RestrictContacts(UserPrivacySettingRuleRestrictContacts),
   /// A rule to restrict all specified users from doing something
   RestrictUsers(UserPrivacySettingRuleRestrictUsers),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -46,7 +47,7 @@ impl<'de> Deserialize<'de> for UserPrivacySettingRule {
   fn deserialize<D>(deserializer: D) -> Result<UserPrivacySettingRule, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      UserPrivacySettingRule,
+      UserPrivacySettingRule, Unknown;
       (userPrivacySettingRuleAllowAll, AllowAll);
       (userPrivacySettingRuleAllowChatMembers, AllowChatMembers);
       (userPrivacySettingRuleAllowContacts, AllowContacts);
@@ -61,7 +62,7 @@ impl<'de> Deserialize<'de> for UserPrivacySettingRule {
 }
 
 impl RObject for UserPrivacySettingRule {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       UserPrivacySettingRule::AllowAll(t) => t.td_name(),
       UserPrivacySettingRule::AllowChatMembers(t) => t.td_name(),
@@ -71,11 +72,12 @@ impl RObject for UserPrivacySettingRule {
       UserPrivacySettingRule::RestrictChatMembers(t) => t.td_name(),
       UserPrivacySettingRule::RestrictContacts(t) => t.td_name(),
       UserPrivacySettingRule::RestrictUsers(t) => t.td_name(),
+      UserPrivacySettingRule::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       UserPrivacySettingRule::AllowAll(t) => t.extra(),
       UserPrivacySettingRule::AllowChatMembers(t) => t.extra(),
@@ -85,6 +87,22 @@ impl RObject for UserPrivacySettingRule {
       UserPrivacySettingRule::RestrictChatMembers(t) => t.extra(),
       UserPrivacySettingRule::RestrictContacts(t) => t.extra(),
       UserPrivacySettingRule::RestrictUsers(t) => t.extra(),
+      UserPrivacySettingRule::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      UserPrivacySettingRule::AllowAll(t) => t.client_id(),
+      UserPrivacySettingRule::AllowChatMembers(t) => t.client_id(),
+      UserPrivacySettingRule::AllowContacts(t) => t.client_id(),
+      UserPrivacySettingRule::AllowUsers(t) => t.client_id(),
+      UserPrivacySettingRule::RestrictAll(t) => t.client_id(),
+      UserPrivacySettingRule::RestrictChatMembers(t) => t.client_id(),
+      UserPrivacySettingRule::RestrictContacts(t) => t.client_id(),
+      UserPrivacySettingRule::RestrictUsers(t) => t.client_id(),
+      UserPrivacySettingRule::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -93,17 +111,18 @@ impl RObject for UserPrivacySettingRule {
 }
 
 impl UserPrivacySettingRule {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserPrivacySettingRule::_Default(_) = self { true } else { false } }
-
-  pub fn is_allow_all(&self) -> bool { if let UserPrivacySettingRule::AllowAll(_) = self { true } else { false } }
-  pub fn is_allow_chat_members(&self) -> bool { if let UserPrivacySettingRule::AllowChatMembers(_) = self { true } else { false } }
-  pub fn is_allow_contacts(&self) -> bool { if let UserPrivacySettingRule::AllowContacts(_) = self { true } else { false } }
-  pub fn is_allow_users(&self) -> bool { if let UserPrivacySettingRule::AllowUsers(_) = self { true } else { false } }
-  pub fn is_restrict_all(&self) -> bool { if let UserPrivacySettingRule::RestrictAll(_) = self { true } else { false } }
-  pub fn is_restrict_chat_members(&self) -> bool { if let UserPrivacySettingRule::RestrictChatMembers(_) = self { true } else { false } }
-  pub fn is_restrict_contacts(&self) -> bool { if let UserPrivacySettingRule::RestrictContacts(_) = self { true } else { false } }
-  pub fn is_restrict_users(&self) -> bool { if let UserPrivacySettingRule::RestrictUsers(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, UserPrivacySettingRule::_Default(_)) }
+
+  pub fn is_allow_all(&self) -> bool { matches!(self, UserPrivacySettingRule::AllowAll(_)) }
+  pub fn is_allow_chat_members(&self) -> bool { matches!(self, UserPrivacySettingRule::AllowChatMembers(_)) }
+  pub fn is_allow_contacts(&self) -> bool { matches!(self, UserPrivacySettingRule::AllowContacts(_)) }
+  pub fn is_allow_users(&self) -> bool { matches!(self, UserPrivacySettingRule::AllowUsers(_)) }
+  pub fn is_restrict_all(&self) -> bool { matches!(self, UserPrivacySettingRule::RestrictAll(_)) }
+  pub fn is_restrict_chat_members(&self) -> bool { matches!(self, UserPrivacySettingRule::RestrictChatMembers(_)) }
+  pub fn is_restrict_contacts(&self) -> bool { matches!(self, UserPrivacySettingRule::RestrictContacts(_)) }
+  pub fn is_restrict_users(&self) -> bool { matches!(self, UserPrivacySettingRule::RestrictUsers(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, UserPrivacySettingRule::Unknown(_)) }
 
   pub fn on_allow_all<F: FnOnce(&UserPrivacySettingRuleAllowAll)>(&self, fnc: F) -> &Self { if let UserPrivacySettingRule::AllowAll(t) = self { fnc(t) }; self }
   pub fn on_allow_chat_members<F: FnOnce(&UserPrivacySettingRuleAllowChatMembers)>(&self, fnc: F) -> &Self { if let UserPrivacySettingRule::AllowChatMembers(t) = self { fnc(t) }; self }
@@ -113,6 +132,7 @@ impl UserPrivacySettingRule {
   pub fn on_restrict_chat_members<F: FnOnce(&UserPrivacySettingRuleRestrictChatMembers)>(&self, fnc: F) -> &Self { if let UserPrivacySettingRule::RestrictChatMembers(t) = self { fnc(t) }; self }
   pub fn on_restrict_contacts<F: FnOnce(&UserPrivacySettingRuleRestrictContacts)>(&self, fnc: F) -> &Self { if let UserPrivacySettingRule::RestrictContacts(t) = self { fnc(t) }; self }
   pub fn on_restrict_users<F: FnOnce(&UserPrivacySettingRuleRestrictUsers)>(&self, fnc: F) -> &Self { if let UserPrivacySettingRule::RestrictUsers(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let UserPrivacySettingRule::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_allow_all(&self) -> Option<&UserPrivacySettingRuleAllowAll> { if let UserPrivacySettingRule::AllowAll(t) = self { return Some(t) } None }
   pub fn as_allow_chat_members(&self) -> Option<&UserPrivacySettingRuleAllowChatMembers> { if let UserPrivacySettingRule::AllowChatMembers(t) = self { return Some(t) } None }
@@ -122,6 +142,7 @@ impl UserPrivacySettingRule {
   pub fn as_restrict_chat_members(&self) -> Option<&UserPrivacySettingRuleRestrictChatMembers> { if let UserPrivacySettingRule::RestrictChatMembers(t) = self { return Some(t) } None }
   pub fn as_restrict_contacts(&self) -> Option<&UserPrivacySettingRuleRestrictContacts> { if let UserPrivacySettingRule::RestrictContacts(t) = self { return Some(t) } None }
   pub fn as_restrict_users(&self) -> Option<&UserPrivacySettingRuleRestrictUsers> { if let UserPrivacySettingRule::RestrictUsers(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let UserPrivacySettingRule::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -161,13 +182,17 @@ pub struct UserPrivacySettingRuleAllowAll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingRuleAllowAll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowAll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleAllowAll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -177,14 +202,15 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowAll {}
 
 
 impl UserPrivacySettingRuleAllowAll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleAllowAllBuilder {
-    let mut inner = UserPrivacySettingRuleAllowAll::default();
-    inner.td_name = "userPrivacySettingRuleAllowAll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleAllowAll { td_name: "userPrivacySettingRuleAllowAll".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleAllowAllBuilder { inner }
   }
 
+  /// `UserPrivacySettingRuleAllowAll` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleAllowAll::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +221,12 @@ pub struct RTDUserPrivacySettingRuleAllowAllBuilder {
 impl RTDUserPrivacySettingRuleAllowAllBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowAll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleAllowAll> for UserPrivacySettingRuleAllowAll {
@@ -205,6 +237,10 @@ impl AsRef<UserPrivacySettingRuleAllowAll> for RTDUserPrivacySettingRuleAllowAll
   fn as_ref(&self) -> &UserPrivacySettingRuleAllowAll { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowAll {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -219,15 +255,19 @@ pub struct UserPrivacySettingRuleAllowChatMembers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat identifiers, total number of chats in all rules must not exceed 20
-  chat_ids: Vec<i64>,
+  chat_ids: Vec<ChatId>,
   
 }
 
 impl RObject for UserPrivacySettingRuleAllowChatMembers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowChatMembers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleAllowChatMembers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -237,15 +277,16 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowChatMembers {}
 
 
 impl UserPrivacySettingRuleAllowChatMembers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleAllowChatMembersBuilder {
-    let mut inner = UserPrivacySettingRuleAllowChatMembers::default();
-    inner.td_name = "userPrivacySettingRuleAllowChatMembers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleAllowChatMembers { td_name: "userPrivacySettingRuleAllowChatMembers".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleAllowChatMembersBuilder { inner }
   }
 
-  pub fn chat_ids(&self) -> &Vec<i64> { &self.chat_ids }
+  /// `UserPrivacySettingRuleAllowChatMembers` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleAllowChatMembers::builder()`
+  pub fn new(chat_ids: Vec<ChatId>) -> Self { Self::builder().chat_ids(chat_ids).build() }
+
+  pub fn chat_ids(&self) -> &Vec<ChatId> { &self.chat_ids }
 
 }
 
@@ -257,8 +298,14 @@ pub struct RTDUserPrivacySettingRuleAllowChatMembersBuilder {
 impl RTDUserPrivacySettingRuleAllowChatMembersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowChatMembers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
+  pub fn chat_ids(&mut self, chat_ids: Vec<ChatId>) -> &mut Self {
     self.inner.chat_ids = chat_ids;
     self
   }
@@ -273,6 +320,12 @@ impl AsRef<UserPrivacySettingRuleAllowChatMembers> for RTDUserPrivacySettingRule
   fn as_ref(&self) -> &UserPrivacySettingRuleAllowChatMembers { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowChatMembers {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_ids == other.chat_ids
+  }
+}
+
 
 
 
@@ -287,13 +340,17 @@ pub struct UserPrivacySettingRuleAllowContacts {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingRuleAllowContacts {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowContacts" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleAllowContacts" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -303,14 +360,15 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowContacts {}
 
 
 impl UserPrivacySettingRuleAllowContacts {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleAllowContactsBuilder {
-    let mut inner = UserPrivacySettingRuleAllowContacts::default();
-    inner.td_name = "userPrivacySettingRuleAllowContacts".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleAllowContacts { td_name: "userPrivacySettingRuleAllowContacts".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleAllowContactsBuilder { inner }
   }
 
+  /// `UserPrivacySettingRuleAllowContacts` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleAllowContacts::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +379,12 @@ pub struct RTDUserPrivacySettingRuleAllowContactsBuilder {
 impl RTDUserPrivacySettingRuleAllowContactsBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowContacts { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleAllowContacts> for UserPrivacySettingRuleAllowContacts {
@@ -331,6 +395,10 @@ impl AsRef<UserPrivacySettingRuleAllowContacts> for RTDUserPrivacySettingRuleAll
   fn as_ref(&self) -> &UserPrivacySettingRuleAllowContacts { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowContacts {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -345,15 +413,19 @@ pub struct UserPrivacySettingRuleAllowUsers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The user identifiers, total number of users in all rules must not exceed 1000
-  user_ids: Vec<i64>,
+  user_ids: Vec<UserId>,
   
 }
 
 impl RObject for UserPrivacySettingRuleAllowUsers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleAllowUsers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleAllowUsers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -363,15 +435,16 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleAllowUsers {}
 
 
 impl UserPrivacySettingRuleAllowUsers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleAllowUsersBuilder {
-    let mut inner = UserPrivacySettingRuleAllowUsers::default();
-    inner.td_name = "userPrivacySettingRuleAllowUsers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleAllowUsers { td_name: "userPrivacySettingRuleAllowUsers".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleAllowUsersBuilder { inner }
   }
 
-  pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
+  /// `UserPrivacySettingRuleAllowUsers` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleAllowUsers::builder()`
+  pub fn new(user_ids: Vec<UserId>) -> Self { Self::builder().user_ids(user_ids).build() }
+
+  pub fn user_ids(&self) -> &Vec<UserId> { &self.user_ids }
 
 }
 
@@ -383,8 +456,14 @@ pub struct RTDUserPrivacySettingRuleAllowUsersBuilder {
 impl RTDUserPrivacySettingRuleAllowUsersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowUsers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+  pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
     self.inner.user_ids = user_ids;
     self
   }
@@ -399,6 +478,12 @@ impl AsRef<UserPrivacySettingRuleAllowUsers> for RTDUserPrivacySettingRuleAllowU
   fn as_ref(&self) -> &UserPrivacySettingRuleAllowUsers { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleAllowUsers {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_ids == other.user_ids
+  }
+}
+
 
 
 
@@ -413,13 +498,17 @@ pub struct UserPrivacySettingRuleRestrictAll {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingRuleRestrictAll {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictAll" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleRestrictAll" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -429,14 +518,15 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictAll {}
 
 
 impl UserPrivacySettingRuleRestrictAll {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleRestrictAllBuilder {
-    let mut inner = UserPrivacySettingRuleRestrictAll::default();
-    inner.td_name = "userPrivacySettingRuleRestrictAll".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleRestrictAll { td_name: "userPrivacySettingRuleRestrictAll".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleRestrictAllBuilder { inner }
   }
 
+  /// `UserPrivacySettingRuleRestrictAll` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleRestrictAll::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -447,6 +537,12 @@ pub struct RTDUserPrivacySettingRuleRestrictAllBuilder {
 impl RTDUserPrivacySettingRuleRestrictAllBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictAll { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictAll> for UserPrivacySettingRuleRestrictAll {
@@ -457,6 +553,10 @@ impl AsRef<UserPrivacySettingRuleRestrictAll> for RTDUserPrivacySettingRuleRestr
   fn as_ref(&self) -> &UserPrivacySettingRuleRestrictAll { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictAll {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -471,15 +571,19 @@ pub struct UserPrivacySettingRuleRestrictChatMembers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The chat identifiers, total number of chats in all rules must not exceed 20
-  chat_ids: Vec<i64>,
+  chat_ids: Vec<ChatId>,
   
 }
 
 impl RObject for UserPrivacySettingRuleRestrictChatMembers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictChatMembers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleRestrictChatMembers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -489,15 +593,16 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictChatMembers {}
 
 
 impl UserPrivacySettingRuleRestrictChatMembers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
-    let mut inner = UserPrivacySettingRuleRestrictChatMembers::default();
-    inner.td_name = "userPrivacySettingRuleRestrictChatMembers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleRestrictChatMembers { td_name: "userPrivacySettingRuleRestrictChatMembers".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleRestrictChatMembersBuilder { inner }
   }
 
-  pub fn chat_ids(&self) -> &Vec<i64> { &self.chat_ids }
+  /// `UserPrivacySettingRuleRestrictChatMembers` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleRestrictChatMembers::builder()`
+  pub fn new(chat_ids: Vec<ChatId>) -> Self { Self::builder().chat_ids(chat_ids).build() }
+
+  pub fn chat_ids(&self) -> &Vec<ChatId> { &self.chat_ids }
 
 }
 
@@ -509,8 +614,14 @@ pub struct RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
 impl RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictChatMembers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
+  pub fn chat_ids(&mut self, chat_ids: Vec<ChatId>) -> &mut Self {
     self.inner.chat_ids = chat_ids;
     self
   }
@@ -525,6 +636,12 @@ impl AsRef<UserPrivacySettingRuleRestrictChatMembers> for RTDUserPrivacySettingR
   fn as_ref(&self) -> &UserPrivacySettingRuleRestrictChatMembers { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictChatMembers {
+  fn eq(&self, other: &Self) -> bool {
+    self.chat_ids == other.chat_ids
+  }
+}
+
 
 
 
@@ -539,13 +656,17 @@ pub struct UserPrivacySettingRuleRestrictContacts {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserPrivacySettingRuleRestrictContacts {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictContacts" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleRestrictContacts" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -555,14 +676,15 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictContacts {}
 
 
 impl UserPrivacySettingRuleRestrictContacts {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleRestrictContactsBuilder {
-    let mut inner = UserPrivacySettingRuleRestrictContacts::default();
-    inner.td_name = "userPrivacySettingRuleRestrictContacts".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleRestrictContacts { td_name: "userPrivacySettingRuleRestrictContacts".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleRestrictContactsBuilder { inner }
   }
 
+  /// `UserPrivacySettingRuleRestrictContacts` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleRestrictContacts::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -573,6 +695,12 @@ pub struct RTDUserPrivacySettingRuleRestrictContactsBuilder {
 impl RTDUserPrivacySettingRuleRestrictContactsBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictContacts { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictContacts> for UserPrivacySettingRuleRestrictContacts {
@@ -583,6 +711,10 @@ impl AsRef<UserPrivacySettingRuleRestrictContacts> for RTDUserPrivacySettingRule
   fn as_ref(&self) -> &UserPrivacySettingRuleRestrictContacts { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictContacts {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -597,15 +729,19 @@ pub struct UserPrivacySettingRuleRestrictUsers {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The user identifiers, total number of users in all rules must not exceed 1000
-  user_ids: Vec<i64>,
+  user_ids: Vec<UserId>,
   
 }
 
 impl RObject for UserPrivacySettingRuleRestrictUsers {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRuleRestrictUsers" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRuleRestrictUsers" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -615,15 +751,16 @@ impl TDUserPrivacySettingRule for UserPrivacySettingRuleRestrictUsers {}
 
 
 impl UserPrivacySettingRuleRestrictUsers {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRuleRestrictUsersBuilder {
-    let mut inner = UserPrivacySettingRuleRestrictUsers::default();
-    inner.td_name = "userPrivacySettingRuleRestrictUsers".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRuleRestrictUsers { td_name: "userPrivacySettingRuleRestrictUsers".to_string(), ..Default::default() };
     RTDUserPrivacySettingRuleRestrictUsersBuilder { inner }
   }
 
-  pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
+  /// `UserPrivacySettingRuleRestrictUsers` with the fields tdlib requires, the others are set by `UserPrivacySettingRuleRestrictUsers::builder()`
+  pub fn new(user_ids: Vec<UserId>) -> Self { Self::builder().user_ids(user_ids).build() }
+
+  pub fn user_ids(&self) -> &Vec<UserId> { &self.user_ids }
 
 }
 
@@ -635,8 +772,14 @@ pub struct RTDUserPrivacySettingRuleRestrictUsersBuilder {
 impl RTDUserPrivacySettingRuleRestrictUsersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictUsers { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
-  pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
+  pub fn user_ids(&mut self, user_ids: Vec<UserId>) -> &mut Self {
     self.inner.user_ids = user_ids;
     self
   }
@@ -651,5 +794,11 @@ impl AsRef<UserPrivacySettingRuleRestrictUsers> for RTDUserPrivacySettingRuleRes
   fn as_ref(&self) -> &UserPrivacySettingRuleRestrictUsers { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRuleRestrictUsers {
+  fn eq(&self, other: &Self) -> bool {
+    self.user_ids == other.user_ids
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,29 +13,34 @@ pub struct UserPrivacySettingRules {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// A list of rules
   rules: Vec<UserPrivacySettingRule>,
   
 }
 
 impl RObject for UserPrivacySettingRules {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userPrivacySettingRules" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userPrivacySettingRules" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl UserPrivacySettingRules {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserPrivacySettingRulesBuilder {
-    let mut inner = UserPrivacySettingRules::default();
-    inner.td_name = "userPrivacySettingRules".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserPrivacySettingRules { td_name: "userPrivacySettingRules".to_string(), ..Default::default() };
     RTDUserPrivacySettingRulesBuilder { inner }
   }
 
+  /// `UserPrivacySettingRules` with the fields tdlib requires, the others are set by `UserPrivacySettingRules::builder()`
+  pub fn new(rules: Vec<UserPrivacySettingRule>) -> Self { Self::builder().rules(rules).build() }
+
   pub fn rules(&self) -> &Vec<UserPrivacySettingRule> { &self.rules }
 
 }
@@ -49,6 +53,12 @@ pub struct RTDUserPrivacySettingRulesBuilder {
 impl RTDUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> UserPrivacySettingRules { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn rules(&mut self, rules: Vec<UserPrivacySettingRule>) -> &mut Self {
     self.inner.rules = rules;
@@ -65,5 +75,11 @@ impl AsRef<UserPrivacySettingRules> for RTDUserPrivacySettingRulesBuilder {
   fn as_ref(&self) -> &UserPrivacySettingRules { &self.inner }
 }
 
+impl PartialEq for UserPrivacySettingRules {
+  fn eq(&self, other: &Self) -> bool {
+    self.rules == other.rules
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDUserStatus: Debug + RObject {}
 
 /// Describes the last time the user was online
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum UserStatus {
   #[doc(hidden)] _Default(()),
@@ -31,6 +30,8 @@ pub enum UserStatus {
   Online(UserStatusOnline),
   /// The user was online recently
   Recently(UserStatusRecently),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -42,7 +43,7 @@ impl<'de> Deserialize<'de> for UserStatus {
   fn deserialize<D>(deserializer: D) -> Result<UserStatus, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      UserStatus,
+      UserStatus, Unknown;
       (userStatusEmpty, Empty);
       (userStatusLastMonth, LastMonth);
       (userStatusLastWeek, LastWeek);
@@ -55,7 +56,7 @@ impl<'de> Deserialize<'de> for UserStatus {
 }
 
 impl RObject for UserStatus {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       UserStatus::Empty(t) => t.td_name(),
       UserStatus::LastMonth(t) => t.td_name(),
@@ -63,11 +64,12 @@ impl RObject for UserStatus {
       UserStatus::Offline(t) => t.td_name(),
       UserStatus::Online(t) => t.td_name(),
       UserStatus::Recently(t) => t.td_name(),
+      UserStatus::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       UserStatus::Empty(t) => t.extra(),
       UserStatus::LastMonth(t) => t.extra(),
@@ -75,6 +77,20 @@ impl RObject for UserStatus {
       UserStatus::Offline(t) => t.extra(),
       UserStatus::Online(t) => t.extra(),
       UserStatus::Recently(t) => t.extra(),
+      UserStatus::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      UserStatus::Empty(t) => t.client_id(),
+      UserStatus::LastMonth(t) => t.client_id(),
+      UserStatus::LastWeek(t) => t.client_id(),
+      UserStatus::Offline(t) => t.client_id(),
+      UserStatus::Online(t) => t.client_id(),
+      UserStatus::Recently(t) => t.client_id(),
+      UserStatus::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -83,15 +99,16 @@ impl RObject for UserStatus {
 }
 
 impl UserStatus {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserStatus::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, UserStatus::_Default(_)) }
 
-  pub fn is_empty(&self) -> bool { if let UserStatus::Empty(_) = self { true } else { false } }
-  pub fn is_last_month(&self) -> bool { if let UserStatus::LastMonth(_) = self { true } else { false } }
-  pub fn is_last_week(&self) -> bool { if let UserStatus::LastWeek(_) = self { true } else { false } }
-  pub fn is_offline(&self) -> bool { if let UserStatus::Offline(_) = self { true } else { false } }
-  pub fn is_online(&self) -> bool { if let UserStatus::Online(_) = self { true } else { false } }
-  pub fn is_recently(&self) -> bool { if let UserStatus::Recently(_) = self { true } else { false } }
+  pub fn is_empty(&self) -> bool { matches!(self, UserStatus::Empty(_)) }
+  pub fn is_last_month(&self) -> bool { matches!(self, UserStatus::LastMonth(_)) }
+  pub fn is_last_week(&self) -> bool { matches!(self, UserStatus::LastWeek(_)) }
+  pub fn is_offline(&self) -> bool { matches!(self, UserStatus::Offline(_)) }
+  pub fn is_online(&self) -> bool { matches!(self, UserStatus::Online(_)) }
+  pub fn is_recently(&self) -> bool { matches!(self, UserStatus::Recently(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, UserStatus::Unknown(_)) }
 
   pub fn on_empty<F: FnOnce(&UserStatusEmpty)>(&self, fnc: F) -> &Self { if let UserStatus::Empty(t) = self { fnc(t) }; self }
   pub fn on_last_month<F: FnOnce(&UserStatusLastMonth)>(&self, fnc: F) -> &Self { if let UserStatus::LastMonth(t) = self { fnc(t) }; self }
@@ -99,6 +116,7 @@ impl UserStatus {
   pub fn on_offline<F: FnOnce(&UserStatusOffline)>(&self, fnc: F) -> &Self { if let UserStatus::Offline(t) = self { fnc(t) }; self }
   pub fn on_online<F: FnOnce(&UserStatusOnline)>(&self, fnc: F) -> &Self { if let UserStatus::Online(t) = self { fnc(t) }; self }
   pub fn on_recently<F: FnOnce(&UserStatusRecently)>(&self, fnc: F) -> &Self { if let UserStatus::Recently(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let UserStatus::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_empty(&self) -> Option<&UserStatusEmpty> { if let UserStatus::Empty(t) = self { return Some(t) } None }
   pub fn as_last_month(&self) -> Option<&UserStatusLastMonth> { if let UserStatus::LastMonth(t) = self { return Some(t) } None }
@@ -106,6 +124,7 @@ impl UserStatus {
   pub fn as_offline(&self) -> Option<&UserStatusOffline> { if let UserStatus::Offline(t) = self { return Some(t) } None }
   pub fn as_online(&self) -> Option<&UserStatusOnline> { if let UserStatus::Online(t) = self { return Some(t) } None }
   pub fn as_recently(&self) -> Option<&UserStatusRecently> { if let UserStatus::Recently(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let UserStatus::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -141,13 +160,17 @@ pub struct UserStatusEmpty {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserStatusEmpty {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusEmpty" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userStatusEmpty" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -157,14 +180,15 @@ impl TDUserStatus for UserStatusEmpty {}
 
 
 impl UserStatusEmpty {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserStatusEmptyBuilder {
-    let mut inner = UserStatusEmpty::default();
-    inner.td_name = "userStatusEmpty".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserStatusEmpty { td_name: "userStatusEmpty".to_string(), ..Default::default() };
     RTDUserStatusEmptyBuilder { inner }
   }
 
+  /// `UserStatusEmpty` with the fields tdlib requires, the others are set by `UserStatusEmpty::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +199,12 @@ pub struct RTDUserStatusEmptyBuilder {
 impl RTDUserStatusEmptyBuilder {
   pub fn build(&self) -> UserStatusEmpty { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusEmpty> for UserStatusEmpty {
@@ -185,6 +215,10 @@ impl AsRef<UserStatusEmpty> for RTDUserStatusEmptyBuilder {
   fn as_ref(&self) -> &UserStatusEmpty { &self.inner }
 }
 
+impl PartialEq for UserStatusEmpty {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -199,13 +233,17 @@ pub struct UserStatusLastMonth {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserStatusLastMonth {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusLastMonth" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userStatusLastMonth" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -215,14 +253,15 @@ impl TDUserStatus for UserStatusLastMonth {}
 
 
 impl UserStatusLastMonth {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserStatusLastMonthBuilder {
-    let mut inner = UserStatusLastMonth::default();
-    inner.td_name = "userStatusLastMonth".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserStatusLastMonth { td_name: "userStatusLastMonth".to_string(), ..Default::default() };
     RTDUserStatusLastMonthBuilder { inner }
   }
 
+  /// `UserStatusLastMonth` with the fields tdlib requires, the others are set by `UserStatusLastMonth::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +272,12 @@ pub struct RTDUserStatusLastMonthBuilder {
 impl RTDUserStatusLastMonthBuilder {
   pub fn build(&self) -> UserStatusLastMonth { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusLastMonth> for UserStatusLastMonth {
@@ -243,6 +288,10 @@ impl AsRef<UserStatusLastMonth> for RTDUserStatusLastMonthBuilder {
   fn as_ref(&self) -> &UserStatusLastMonth { &self.inner }
 }
 
+impl PartialEq for UserStatusLastMonth {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -257,13 +306,17 @@ pub struct UserStatusLastWeek {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserStatusLastWeek {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusLastWeek" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userStatusLastWeek" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -273,14 +326,15 @@ impl TDUserStatus for UserStatusLastWeek {}
 
 
 impl UserStatusLastWeek {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserStatusLastWeekBuilder {
-    let mut inner = UserStatusLastWeek::default();
-    inner.td_name = "userStatusLastWeek".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserStatusLastWeek { td_name: "userStatusLastWeek".to_string(), ..Default::default() };
     RTDUserStatusLastWeekBuilder { inner }
   }
 
+  /// `UserStatusLastWeek` with the fields tdlib requires, the others are set by `UserStatusLastWeek::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +345,12 @@ pub struct RTDUserStatusLastWeekBuilder {
 impl RTDUserStatusLastWeekBuilder {
   pub fn build(&self) -> UserStatusLastWeek { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusLastWeek> for UserStatusLastWeek {
@@ -301,6 +361,10 @@ impl AsRef<UserStatusLastWeek> for RTDUserStatusLastWeekBuilder {
   fn as_ref(&self) -> &UserStatusLastWeek { &self.inner }
 }
 
+impl PartialEq for UserStatusLastWeek {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -315,15 +379,19 @@ pub struct UserStatusOffline {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Point in time (Unix timestamp) when the user was last online
   was_online: i64,
   
 }
 
 impl RObject for UserStatusOffline {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusOffline" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userStatusOffline" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -333,14 +401,15 @@ impl TDUserStatus for UserStatusOffline {}
 
 
 impl UserStatusOffline {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserStatusOfflineBuilder {
-    let mut inner = UserStatusOffline::default();
-    inner.td_name = "userStatusOffline".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserStatusOffline { td_name: "userStatusOffline".to_string(), ..Default::default() };
     RTDUserStatusOfflineBuilder { inner }
   }
 
+  /// `UserStatusOffline` with the fields tdlib requires, the others are set by `UserStatusOffline::builder()`
+  pub fn new(was_online: i64) -> Self { Self::builder().was_online(was_online).build() }
+
   pub fn was_online(&self) -> i64 { self.was_online }
 
 }
@@ -353,6 +422,12 @@ pub struct RTDUserStatusOfflineBuilder {
 impl RTDUserStatusOfflineBuilder {
   pub fn build(&self) -> UserStatusOffline { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn was_online(&mut self, was_online: i64) -> &mut Self {
     self.inner.was_online = was_online;
@@ -369,6 +444,12 @@ impl AsRef<UserStatusOffline> for RTDUserStatusOfflineBuilder {
   fn as_ref(&self) -> &UserStatusOffline { &self.inner }
 }
 
+impl PartialEq for UserStatusOffline {
+  fn eq(&self, other: &Self) -> bool {
+    self.was_online == other.was_online
+  }
+}
+
 
 
 
@@ -383,15 +464,19 @@ pub struct UserStatusOnline {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Point in time (Unix timestamp) when the user's online status will expire
   expires: i64,
   
 }
 
 impl RObject for UserStatusOnline {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusOnline" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userStatusOnline" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -401,14 +486,15 @@ impl TDUserStatus for UserStatusOnline {}
 
 
 impl UserStatusOnline {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserStatusOnlineBuilder {
-    let mut inner = UserStatusOnline::default();
-    inner.td_name = "userStatusOnline".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserStatusOnline { td_name: "userStatusOnline".to_string(), ..Default::default() };
     RTDUserStatusOnlineBuilder { inner }
   }
 
+  /// `UserStatusOnline` with the fields tdlib requires, the others are set by `UserStatusOnline::builder()`
+  pub fn new(expires: i64) -> Self { Self::builder().expires(expires).build() }
+
   pub fn expires(&self) -> i64 { self.expires }
 
 }
@@ -421,6 +507,12 @@ pub struct RTDUserStatusOnlineBuilder {
 impl RTDUserStatusOnlineBuilder {
   pub fn build(&self) -> UserStatusOnline { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn expires(&mut self, expires: i64) -> &mut Self {
     self.inner.expires = expires;
@@ -437,6 +529,12 @@ impl AsRef<UserStatusOnline> for RTDUserStatusOnlineBuilder {
   fn as_ref(&self) -> &UserStatusOnline { &self.inner }
 }
 
+impl PartialEq for UserStatusOnline {
+  fn eq(&self, other: &Self) -> bool {
+    self.expires == other.expires
+  }
+}
+
 
 
 
@@ -451,13 +549,17 @@ pub struct UserStatusRecently {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserStatusRecently {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userStatusRecently" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userStatusRecently" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -467,14 +569,15 @@ impl TDUserStatus for UserStatusRecently {}
 
 
 impl UserStatusRecently {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserStatusRecentlyBuilder {
-    let mut inner = UserStatusRecently::default();
-    inner.td_name = "userStatusRecently".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserStatusRecently { td_name: "userStatusRecently".to_string(), ..Default::default() };
     RTDUserStatusRecentlyBuilder { inner }
   }
 
+  /// `UserStatusRecently` with the fields tdlib requires, the others are set by `UserStatusRecently::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -485,6 +588,12 @@ pub struct RTDUserStatusRecentlyBuilder {
 impl RTDUserStatusRecentlyBuilder {
   pub fn build(&self) -> UserStatusRecently { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserStatusRecently> for UserStatusRecently {
@@ -495,5 +604,9 @@ impl AsRef<UserStatusRecently> for RTDUserStatusRecentlyBuilder {
   fn as_ref(&self) -> &UserStatusRecently { &self.inner }
 }
 
+impl PartialEq for UserStatusRecently {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDUserType: Debug + RObject {}
 
 /// Represents the type of a user. The following types are possible: regular users, deleted users and bots
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum UserType {
   #[doc(hidden)] _Default(()),
@@ -27,6 +26,8 @@ pub enum UserType {
   Regular(UserTypeRegular),
   /// No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type
   Unknown(UserTypeUnknown),
+  /// An object of a type this crate doesn't know, kept as received
+  UnknownType(UnknownObject),
 
 }
 
@@ -38,7 +39,7 @@ impl<'de> Deserialize<'de> for UserType {
   fn deserialize<D>(deserializer: D) -> Result<UserType, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      UserType,
+      UserType, UnknownType;
       (userTypeBot, Bot);
       (userTypeDeleted, Deleted);
       (userTypeRegular, Regular);
@@ -49,22 +50,35 @@ impl<'de> Deserialize<'de> for UserType {
 }
 
 impl RObject for UserType {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       UserType::Bot(t) => t.td_name(),
       UserType::Deleted(t) => t.td_name(),
       UserType::Regular(t) => t.td_name(),
       UserType::Unknown(t) => t.td_name(),
+      UserType::UnknownType(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       UserType::Bot(t) => t.extra(),
       UserType::Deleted(t) => t.extra(),
       UserType::Regular(t) => t.extra(),
       UserType::Unknown(t) => t.extra(),
+      UserType::UnknownType(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      UserType::Bot(t) => t.client_id(),
+      UserType::Deleted(t) => t.client_id(),
+      UserType::Regular(t) => t.client_id(),
+      UserType::Unknown(t) => t.client_id(),
+      UserType::UnknownType(t) => t.client_id(),
 
       _ => None,
     }
@@ -73,23 +87,26 @@ impl RObject for UserType {
 }
 
 impl UserType {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserType::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, UserType::_Default(_)) }
 
-  pub fn is_bot(&self) -> bool { if let UserType::Bot(_) = self { true } else { false } }
-  pub fn is_deleted(&self) -> bool { if let UserType::Deleted(_) = self { true } else { false } }
-  pub fn is_regular(&self) -> bool { if let UserType::Regular(_) = self { true } else { false } }
-  pub fn is_unknown(&self) -> bool { if let UserType::Unknown(_) = self { true } else { false } }
+  pub fn is_bot(&self) -> bool { matches!(self, UserType::Bot(_)) }
+  pub fn is_deleted(&self) -> bool { matches!(self, UserType::Deleted(_)) }
+  pub fn is_regular(&self) -> bool { matches!(self, UserType::Regular(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, UserType::Unknown(_)) }
+  pub fn is_unknown_type(&self) -> bool { matches!(self, UserType::UnknownType(_)) }
 
   pub fn on_bot<F: FnOnce(&UserTypeBot)>(&self, fnc: F) -> &Self { if let UserType::Bot(t) = self { fnc(t) }; self }
   pub fn on_deleted<F: FnOnce(&UserTypeDeleted)>(&self, fnc: F) -> &Self { if let UserType::Deleted(t) = self { fnc(t) }; self }
   pub fn on_regular<F: FnOnce(&UserTypeRegular)>(&self, fnc: F) -> &Self { if let UserType::Regular(t) = self { fnc(t) }; self }
   pub fn on_unknown<F: FnOnce(&UserTypeUnknown)>(&self, fnc: F) -> &Self { if let UserType::Unknown(t) = self { fnc(t) }; self }
+  pub fn on_unknown_type<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let UserType::UnknownType(t) = self { fnc(t) }; self }
 
   pub fn as_bot(&self) -> Option<&UserTypeBot> { if let UserType::Bot(t) = self { return Some(t) } None }
   pub fn as_deleted(&self) -> Option<&UserTypeDeleted> { if let UserType::Deleted(t) = self { return Some(t) } None }
   pub fn as_regular(&self) -> Option<&UserTypeRegular> { if let UserType::Regular(t) = self { return Some(t) } None }
   pub fn as_unknown(&self) -> Option<&UserTypeUnknown> { if let UserType::Unknown(t) = self { return Some(t) } None }
+  pub fn as_unknown_type(&self) -> Option<&UnknownObject> { if let UserType::UnknownType(t) = self { return Some(t) } None }
 
 
 
@@ -121,7 +138,10 @@ pub struct UserTypeBot {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// True, if the bot can be invited to basic group and supergroup chats
   can_join_groups: bool,
   /// True, if the bot can read all messages in basic group or supergroup chats and not just those addressed to the bot. In private and channel chats a bot can always read all messages
@@ -136,8 +156,9 @@ pub struct UserTypeBot {
 }
 
 impl RObject for UserTypeBot {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeBot" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userTypeBot" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -147,14 +168,29 @@ impl TDUserType for UserTypeBot {}
 
 
 impl UserTypeBot {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserTypeBotBuilder {
-    let mut inner = UserTypeBot::default();
-    inner.td_name = "userTypeBot".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserTypeBot { td_name: "userTypeBot".to_string(), ..Default::default() };
     RTDUserTypeBotBuilder { inner }
   }
 
+  /// `UserTypeBot` with the fields tdlib requires, the others are set by `UserTypeBot::builder()`
+  pub fn new(
+    can_join_groups: bool,
+    can_read_all_group_messages: bool,
+    is_inline: bool,
+    inline_query_placeholder: impl AsRef<str>,
+    need_location: bool,
+  ) -> Self {
+    Self::builder()
+      .can_join_groups(can_join_groups)
+      .can_read_all_group_messages(can_read_all_group_messages)
+      .is_inline(is_inline)
+      .inline_query_placeholder(inline_query_placeholder)
+      .need_location(need_location)
+      .build()
+  }
+
   pub fn can_join_groups(&self) -> bool { self.can_join_groups }
 
   pub fn can_read_all_group_messages(&self) -> bool { self.can_read_all_group_messages }
@@ -175,6 +211,12 @@ pub struct RTDUserTypeBotBuilder {
 impl RTDUserTypeBotBuilder {
   pub fn build(&self) -> UserTypeBot { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn can_join_groups(&mut self, can_join_groups: bool) -> &mut Self {
     self.inner.can_join_groups = can_join_groups;
@@ -215,6 +257,16 @@ impl AsRef<UserTypeBot> for RTDUserTypeBotBuilder {
   fn as_ref(&self) -> &UserTypeBot { &self.inner }
 }
 
+impl PartialEq for UserTypeBot {
+  fn eq(&self, other: &Self) -> bool {
+    self.can_join_groups == other.can_join_groups
+      && self.can_read_all_group_messages == other.can_read_all_group_messages
+      && self.is_inline == other.is_inline
+      && self.inline_query_placeholder == other.inline_query_placeholder
+      && self.need_location == other.need_location
+  }
+}
+
 
 
 
@@ -229,13 +281,17 @@ pub struct UserTypeDeleted {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserTypeDeleted {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeDeleted" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userTypeDeleted" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -245,14 +301,15 @@ impl TDUserType for UserTypeDeleted {}
 
 
 impl UserTypeDeleted {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserTypeDeletedBuilder {
-    let mut inner = UserTypeDeleted::default();
-    inner.td_name = "userTypeDeleted".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserTypeDeleted { td_name: "userTypeDeleted".to_string(), ..Default::default() };
     RTDUserTypeDeletedBuilder { inner }
   }
 
+  /// `UserTypeDeleted` with the fields tdlib requires, the others are set by `UserTypeDeleted::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -263,6 +320,12 @@ pub struct RTDUserTypeDeletedBuilder {
 impl RTDUserTypeDeletedBuilder {
   pub fn build(&self) -> UserTypeDeleted { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserTypeDeleted> for UserTypeDeleted {
@@ -273,6 +336,10 @@ impl AsRef<UserTypeDeleted> for RTDUserTypeDeletedBuilder {
   fn as_ref(&self) -> &UserTypeDeleted { &self.inner }
 }
 
+impl PartialEq for UserTypeDeleted {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -287,13 +354,17 @@ pub struct UserTypeRegular {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserTypeRegular {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeRegular" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userTypeRegular" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -303,14 +374,15 @@ impl TDUserType for UserTypeRegular {}
 
 
 impl UserTypeRegular {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserTypeRegularBuilder {
-    let mut inner = UserTypeRegular::default();
-    inner.td_name = "userTypeRegular".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserTypeRegular { td_name: "userTypeRegular".to_string(), ..Default::default() };
     RTDUserTypeRegularBuilder { inner }
   }
 
+  /// `UserTypeRegular` with the fields tdlib requires, the others are set by `UserTypeRegular::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +393,12 @@ pub struct RTDUserTypeRegularBuilder {
 impl RTDUserTypeRegularBuilder {
   pub fn build(&self) -> UserTypeRegular { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserTypeRegular> for UserTypeRegular {
@@ -331,6 +409,10 @@ impl AsRef<UserTypeRegular> for RTDUserTypeRegularBuilder {
   fn as_ref(&self) -> &UserTypeRegular { &self.inner }
 }
 
+impl PartialEq for UserTypeRegular {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
 
@@ -345,13 +427,17 @@ pub struct UserTypeUnknown {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   
 }
 
 impl RObject for UserTypeUnknown {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "userTypeUnknown" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "userTypeUnknown" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -361,14 +447,15 @@ impl TDUserType for UserTypeUnknown {}
 
 
 impl UserTypeUnknown {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDUserTypeUnknownBuilder {
-    let mut inner = UserTypeUnknown::default();
-    inner.td_name = "userTypeUnknown".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = UserTypeUnknown { td_name: "userTypeUnknown".to_string(), ..Default::default() };
     RTDUserTypeUnknownBuilder { inner }
   }
 
+  /// `UserTypeUnknown` with the fields tdlib requires, the others are set by `UserTypeUnknown::builder()`
+  pub fn new() -> Self { Self::builder().build() }
+
 }
 
 #[doc(hidden)]
@@ -379,6 +466,12 @@ pub struct RTDUserTypeUnknownBuilder {
 impl RTDUserTypeUnknownBuilder {
   pub fn build(&self) -> UserTypeUnknown { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
 }
 
 impl AsRef<UserTypeUnknown> for UserTypeUnknown {
@@ -389,5 +482,9 @@ impl AsRef<UserTypeUnknown> for RTDUserTypeUnknownBuilder {
   fn as_ref(&self) -> &UserTypeUnknown { &self.inner }
 }
 
+impl PartialEq for UserTypeUnknown {
+  fn eq(&self, _other: &Self) -> bool { true }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct ValidatedOrderInfo {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Temporary identifier of the order information
   order_info_id: String,
   /// Available shipping options
@@ -23,22 +25,24 @@ pub struct ValidatedOrderInfo {
 }
 
 impl RObject for ValidatedOrderInfo {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "validatedOrderInfo" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "validatedOrderInfo" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl ValidatedOrderInfo {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDValidatedOrderInfoBuilder {
-    let mut inner = ValidatedOrderInfo::default();
-    inner.td_name = "validatedOrderInfo".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = ValidatedOrderInfo { td_name: "validatedOrderInfo".to_string(), ..Default::default() };
     RTDValidatedOrderInfoBuilder { inner }
   }
 
+  /// `ValidatedOrderInfo` with the fields tdlib requires, the others are set by `ValidatedOrderInfo::builder()`
+  pub fn new(order_info_id: impl AsRef<str>, shipping_options: Vec<ShippingOption>) -> Self { Self::builder().order_info_id(order_info_id).shipping_options(shipping_options).build() }
+
   pub fn order_info_id(&self) -> &String { &self.order_info_id }
 
   pub fn shipping_options(&self) -> &Vec<ShippingOption> { &self.shipping_options }
@@ -53,6 +57,12 @@ pub struct RTDValidatedOrderInfoBuilder {
 impl RTDValidatedOrderInfoBuilder {
   pub fn build(&self) -> ValidatedOrderInfo { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn order_info_id<T: AsRef<str>>(&mut self, order_info_id: T) -> &mut Self {
     self.inner.order_info_id = order_info_id.as_ref().to_string();
@@ -75,5 +85,12 @@ impl AsRef<ValidatedOrderInfo> for RTDValidatedOrderInfoBuilder {
   fn as_ref(&self) -> &ValidatedOrderInfo { &self.inner }
 }
 
+impl PartialEq for ValidatedOrderInfo {
+  fn eq(&self, other: &Self) -> bool {
+    self.order_info_id == other.order_info_id
+      && self.shipping_options == other.shipping_options
+  }
+}
+
 
 
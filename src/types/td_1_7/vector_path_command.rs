@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -15,7 +14,7 @@ use serde::de::{Deserialize, Deserializer};
 pub trait TDVectorPathCommand: Debug + RObject {}
 
 /// Represents a vector path command
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum VectorPathCommand {
   #[doc(hidden)] _Default(()),
@@ -23,6 +22,8 @@ pub enum VectorPathCommand {
   CubicBezierCurve(VectorPathCommandCubicBezierCurve),
   /// A straight line to a given point
   Line(VectorPathCommandLine),
+  /// An object of a type this crate doesn't know, kept as received
+  Unknown(UnknownObject),
 
 }
 
@@ -34,7 +35,7 @@ impl<'de> Deserialize<'de> for VectorPathCommand {
   fn deserialize<D>(deserializer: D) -> Result<VectorPathCommand, D::Error> where D: Deserializer<'de> {
     use serde::de::Error;
     rtd_enum_deserialize!(
-      VectorPathCommand,
+      VectorPathCommand, Unknown;
       (vectorPathCommandCubicBezierCurve, CubicBezierCurve);
       (vectorPathCommandLine, Line);
 
@@ -43,18 +44,29 @@ impl<'de> Deserialize<'de> for VectorPathCommand {
 }
 
 impl RObject for VectorPathCommand {
-  #[doc(hidden)] fn td_name(&self) -> &'static str {
+  #[doc(hidden)] fn td_name(&self) -> &str {
     match self {
       VectorPathCommand::CubicBezierCurve(t) => t.td_name(),
       VectorPathCommand::Line(t) => t.td_name(),
+      VectorPathCommand::Unknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
-  #[doc(hidden)] fn extra(&self) -> Option<String> {
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> {
     match self {
       VectorPathCommand::CubicBezierCurve(t) => t.extra(),
       VectorPathCommand::Line(t) => t.extra(),
+      VectorPathCommand::Unknown(t) => t.extra(),
+
+      _ => None,
+    }
+  }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> {
+    match self {
+      VectorPathCommand::CubicBezierCurve(t) => t.client_id(),
+      VectorPathCommand::Line(t) => t.client_id(),
+      VectorPathCommand::Unknown(t) => t.client_id(),
 
       _ => None,
     }
@@ -63,17 +75,20 @@ impl RObject for VectorPathCommand {
 }
 
 impl VectorPathCommand {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
-  #[doc(hidden)] pub fn _is_default(&self) -> bool { if let VectorPathCommand::_Default(_) = self { true } else { false } }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
+  #[doc(hidden)] pub fn _is_default(&self) -> bool { matches!(self, VectorPathCommand::_Default(_)) }
 
-  pub fn is_cubic_bezier_curve(&self) -> bool { if let VectorPathCommand::CubicBezierCurve(_) = self { true } else { false } }
-  pub fn is_line(&self) -> bool { if let VectorPathCommand::Line(_) = self { true } else { false } }
+  pub fn is_cubic_bezier_curve(&self) -> bool { matches!(self, VectorPathCommand::CubicBezierCurve(_)) }
+  pub fn is_line(&self) -> bool { matches!(self, VectorPathCommand::Line(_)) }
+  pub fn is_unknown(&self) -> bool { matches!(self, VectorPathCommand::Unknown(_)) }
 
   pub fn on_cubic_bezier_curve<F: FnOnce(&VectorPathCommandCubicBezierCurve)>(&self, fnc: F) -> &Self { if let VectorPathCommand::CubicBezierCurve(t) = self { fnc(t) }; self }
   pub fn on_line<F: FnOnce(&VectorPathCommandLine)>(&self, fnc: F) -> &Self { if let VectorPathCommand::Line(t) = self { fnc(t) }; self }
+  pub fn on_unknown<F: FnOnce(&UnknownObject)>(&self, fnc: F) -> &Self { if let VectorPathCommand::Unknown(t) = self { fnc(t) }; self }
 
   pub fn as_cubic_bezier_curve(&self) -> Option<&VectorPathCommandCubicBezierCurve> { if let VectorPathCommand::CubicBezierCurve(t) = self { return Some(t) } None }
   pub fn as_line(&self) -> Option<&VectorPathCommandLine> { if let VectorPathCommand::Line(t) = self { return Some(t) } None }
+  pub fn as_unknown(&self) -> Option<&UnknownObject> { if let VectorPathCommand::Unknown(t) = self { return Some(t) } None }
 
 
 
@@ -101,7 +116,10 @@ pub struct VectorPathCommandCubicBezierCurve {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The start control point of the curve
   start_control_point: Point,
   /// The end control point of the curve
@@ -112,8 +130,9 @@ pub struct VectorPathCommandCubicBezierCurve {
 }
 
 impl RObject for VectorPathCommandCubicBezierCurve {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "vectorPathCommandCubicBezierCurve" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "vectorPathCommandCubicBezierCurve" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -123,14 +142,15 @@ impl TDVectorPathCommand for VectorPathCommandCubicBezierCurve {}
 
 
 impl VectorPathCommandCubicBezierCurve {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDVectorPathCommandCubicBezierCurveBuilder {
-    let mut inner = VectorPathCommandCubicBezierCurve::default();
-    inner.td_name = "vectorPathCommandCubicBezierCurve".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = VectorPathCommandCubicBezierCurve { td_name: "vectorPathCommandCubicBezierCurve".to_string(), ..Default::default() };
     RTDVectorPathCommandCubicBezierCurveBuilder { inner }
   }
 
+  /// `VectorPathCommandCubicBezierCurve` with the fields tdlib requires, the others are set by `VectorPathCommandCubicBezierCurve::builder()`
+  pub fn new(start_control_point: impl AsRef<Point>, end_control_point: impl AsRef<Point>, end_point: impl AsRef<Point>) -> Self { Self::builder().start_control_point(start_control_point).end_control_point(end_control_point).end_point(end_point).build() }
+
   pub fn start_control_point(&self) -> &Point { &self.start_control_point }
 
   pub fn end_control_point(&self) -> &Point { &self.end_control_point }
@@ -147,6 +167,12 @@ pub struct RTDVectorPathCommandCubicBezierCurveBuilder {
 impl RTDVectorPathCommandCubicBezierCurveBuilder {
   pub fn build(&self) -> VectorPathCommandCubicBezierCurve { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn start_control_point<T: AsRef<Point>>(&mut self, start_control_point: T) -> &mut Self {
     self.inner.start_control_point = start_control_point.as_ref().clone();
@@ -175,6 +201,14 @@ impl AsRef<VectorPathCommandCubicBezierCurve> for RTDVectorPathCommandCubicBezie
   fn as_ref(&self) -> &VectorPathCommandCubicBezierCurve { &self.inner }
 }
 
+impl PartialEq for VectorPathCommandCubicBezierCurve {
+  fn eq(&self, other: &Self) -> bool {
+    self.start_control_point == other.start_control_point
+      && self.end_control_point == other.end_control_point
+      && self.end_point == other.end_point
+  }
+}
+
 
 
 
@@ -189,15 +223,19 @@ pub struct VectorPathCommandLine {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// The end point of the straight line
   end_point: Point,
   
 }
 
 impl RObject for VectorPathCommandLine {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "vectorPathCommandLine" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "vectorPathCommandLine" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
@@ -207,14 +245,15 @@ impl TDVectorPathCommand for VectorPathCommandLine {}
 
 
 impl VectorPathCommandLine {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDVectorPathCommandLineBuilder {
-    let mut inner = VectorPathCommandLine::default();
-    inner.td_name = "vectorPathCommandLine".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = VectorPathCommandLine { td_name: "vectorPathCommandLine".to_string(), ..Default::default() };
     RTDVectorPathCommandLineBuilder { inner }
   }
 
+  /// `VectorPathCommandLine` with the fields tdlib requires, the others are set by `VectorPathCommandLine::builder()`
+  pub fn new(end_point: impl AsRef<Point>) -> Self { Self::builder().end_point(end_point).build() }
+
   pub fn end_point(&self) -> &Point { &self.end_point }
 
 }
@@ -227,6 +266,12 @@ pub struct RTDVectorPathCommandLineBuilder {
 impl RTDVectorPathCommandLineBuilder {
   pub fn build(&self) -> VectorPathCommandLine { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn end_point<T: AsRef<Point>>(&mut self, end_point: T) -> &mut Self {
     self.inner.end_point = end_point.as_ref().clone();
@@ -243,5 +288,11 @@ impl AsRef<VectorPathCommandLine> for RTDVectorPathCommandLineBuilder {
   fn as_ref(&self) -> &VectorPathCommandLine { &self.inner }
 }
 
+impl PartialEq for VectorPathCommandLine {
+  fn eq(&self, other: &Self) -> bool {
+    self.end_point == other.end_point
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct Venue {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Venue location; as defined by the sender
   location: Location,
   /// Venue name; as defined by the sender
@@ -31,22 +33,40 @@ pub struct Venue {
 }
 
 impl RObject for Venue {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "venue" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "venue" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl Venue {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDVenueBuilder {
-    let mut inner = Venue::default();
-    inner.td_name = "venue".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = Venue { td_name: "venue".to_string(), ..Default::default() };
     RTDVenueBuilder { inner }
   }
 
+  /// `Venue` with the fields tdlib requires, the others are set by `Venue::builder()`
+  pub fn new(
+    location: impl AsRef<Location>,
+    title: impl AsRef<str>,
+    address: impl AsRef<str>,
+    provider: impl AsRef<str>,
+    id: impl AsRef<str>,
+    type_: impl AsRef<str>,
+  ) -> Self {
+    Self::builder()
+      .location(location)
+      .title(title)
+      .address(address)
+      .provider(provider)
+      .id(id)
+      .type_(type_)
+      .build()
+  }
+
   pub fn location(&self) -> &Location { &self.location }
 
   pub fn title(&self) -> &String { &self.title }
@@ -69,6 +89,12 @@ pub struct RTDVenueBuilder {
 impl RTDVenueBuilder {
   pub fn build(&self) -> Venue { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
@@ -115,5 +141,16 @@ impl AsRef<Venue> for RTDVenueBuilder {
   fn as_ref(&self) -> &Venue { &self.inner }
 }
 
+impl PartialEq for Venue {
+  fn eq(&self, other: &Self) -> bool {
+    self.location == other.location
+      && self.title == other.title
+      && self.address == other.address
+      && self.provider == other.provider
+      && self.id == other.id
+      && self.type_ == other.type_
+  }
+}
+
 
 
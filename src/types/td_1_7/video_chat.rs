@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct VideoChat {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Group call identifier of an active video chat; 0 if none. Full information about the video chat can be received through the method getGroupCall
   group_call_id: i64,
   /// True, if the video chat has participants
@@ -25,22 +27,24 @@ pub struct VideoChat {
 }
 
 impl RObject for VideoChat {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "videoChat" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "videoChat" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl VideoChat {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDVideoChatBuilder {
-    let mut inner = VideoChat::default();
-    inner.td_name = "videoChat".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = VideoChat { td_name: "videoChat".to_string(), ..Default::default() };
     RTDVideoChatBuilder { inner }
   }
 
+  /// `VideoChat` with the fields tdlib requires, the others are set by `VideoChat::builder()`
+  pub fn new(group_call_id: i64, has_participants: bool) -> Self { Self::builder().group_call_id(group_call_id).has_participants(has_participants).build() }
+
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
 
   pub fn has_participants(&self) -> bool { self.has_participants }
@@ -57,6 +61,12 @@ pub struct RTDVideoChatBuilder {
 impl RTDVideoChatBuilder {
   pub fn build(&self) -> VideoChat { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
@@ -85,5 +95,13 @@ impl AsRef<VideoChat> for RTDVideoChatBuilder {
   fn as_ref(&self) -> &VideoChat { &self.inner }
 }
 
+impl PartialEq for VideoChat {
+  fn eq(&self, other: &Self) -> bool {
+    self.group_call_id == other.group_call_id
+      && self.has_participants == other.has_participants
+      && self.default_participant_id == other.default_participant_id
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct VideoNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Duration of the video, in seconds; as defined by the sender
   duration: i64,
   /// Video width and height; as defined by the sender
@@ -29,22 +31,24 @@ pub struct VideoNote {
 }
 
 impl RObject for VideoNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "videoNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "videoNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl VideoNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDVideoNoteBuilder {
-    let mut inner = VideoNote::default();
-    inner.td_name = "videoNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = VideoNote { td_name: "videoNote".to_string(), ..Default::default() };
     RTDVideoNoteBuilder { inner }
   }
 
+  /// `VideoNote` with the fields tdlib requires, the others are set by `VideoNote::builder()`
+  pub fn new(duration: i64, length: i64, video: impl AsRef<File>) -> Self { Self::builder().duration(duration).length(length).video(video).build() }
+
   pub fn duration(&self) -> i64 { self.duration }
 
   pub fn length(&self) -> i64 { self.length }
@@ -65,6 +69,12 @@ pub struct RTDVideoNoteBuilder {
 impl RTDVideoNoteBuilder {
   pub fn build(&self) -> VideoNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -105,5 +115,15 @@ impl AsRef<VideoNote> for RTDVideoNoteBuilder {
   fn as_ref(&self) -> &VideoNote { &self.inner }
 }
 
+impl PartialEq for VideoNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.duration == other.duration
+      && self.length == other.length
+      && self.minithumbnail == other.minithumbnail
+      && self.thumbnail == other.thumbnail
+      && self.video == other.video
+  }
+}
+
 
 
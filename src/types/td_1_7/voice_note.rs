@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,11 +13,14 @@ pub struct VoiceNote {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Duration of the voice note, in seconds; as defined by the sender
   duration: i64,
   /// A waveform representation of the voice note in 5-bit format
-  waveform: String,
+  waveform: Bytes,
   /// MIME type of the file; as defined by the sender
   mime_type: String,
   /// File containing the voice note
@@ -27,25 +29,39 @@ pub struct VoiceNote {
 }
 
 impl RObject for VoiceNote {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "voiceNote" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "voiceNote" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl VoiceNote {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDVoiceNoteBuilder {
-    let mut inner = VoiceNote::default();
-    inner.td_name = "voiceNote".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = VoiceNote { td_name: "voiceNote".to_string(), ..Default::default() };
     RTDVoiceNoteBuilder { inner }
   }
 
+  /// `VoiceNote` with the fields tdlib requires, the others are set by `VoiceNote::builder()`
+  pub fn new(
+    duration: i64,
+    waveform: impl AsRef<Bytes>,
+    mime_type: impl AsRef<str>,
+    voice: impl AsRef<File>,
+  ) -> Self {
+    Self::builder()
+      .duration(duration)
+      .waveform(waveform)
+      .mime_type(mime_type)
+      .voice(voice)
+      .build()
+  }
+
   pub fn duration(&self) -> i64 { self.duration }
 
-  pub fn waveform(&self) -> &String { &self.waveform }
+  pub fn waveform(&self) -> &Bytes { &self.waveform }
 
   pub fn mime_type(&self) -> &String { &self.mime_type }
 
@@ -61,6 +77,12 @@ pub struct RTDVoiceNoteBuilder {
 impl RTDVoiceNoteBuilder {
   pub fn build(&self) -> VoiceNote { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
@@ -68,8 +90,8 @@ impl RTDVoiceNoteBuilder {
   }
 
    
-  pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
-    self.inner.waveform = waveform.as_ref().to_string();
+  pub fn waveform<T: AsRef<Bytes>>(&mut self, waveform: T) -> &mut Self {
+    self.inner.waveform = waveform.as_ref().clone();
     self
   }
 
@@ -95,5 +117,14 @@ impl AsRef<VoiceNote> for RTDVoiceNoteBuilder {
   fn as_ref(&self) -> &VoiceNote { &self.inner }
 }
 
+impl PartialEq for VoiceNote {
+  fn eq(&self, other: &Self) -> bool {
+    self.duration == other.duration
+      && self.waveform == other.waveform
+      && self.mime_type == other.mime_type
+      && self.voice == other.voice
+  }
+}
+
 
 
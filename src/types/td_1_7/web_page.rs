@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct WebPage {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Original URL of the link
   url: String,
   /// URL to display
@@ -25,7 +27,7 @@ pub struct WebPage {
   site_name: String,
   /// Title of the content
   title: String,
-  /// Describes a web page preview
+  /// Description of the content
   description: FormattedText,
   /// Image representing the content; may be null
   photo: Option<Photo>,
@@ -61,22 +63,55 @@ pub struct WebPage {
 }
 
 impl RObject for WebPage {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "webPage" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "webPage" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl WebPage {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDWebPageBuilder {
-    let mut inner = WebPage::default();
-    inner.td_name = "webPage".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = WebPage { td_name: "webPage".to_string(), ..Default::default() };
     RTDWebPageBuilder { inner }
   }
 
+  /// `WebPage` with the fields tdlib requires, the others are set by `WebPage::builder()`
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    url: impl AsRef<str>,
+    display_url: impl AsRef<str>,
+    type_: impl AsRef<str>,
+    site_name: impl AsRef<str>,
+    title: impl AsRef<str>,
+    description: impl AsRef<FormattedText>,
+    embed_url: impl AsRef<str>,
+    embed_type: impl AsRef<str>,
+    embed_width: i64,
+    embed_height: i64,
+    duration: i64,
+    author: impl AsRef<str>,
+    instant_view_version: i64,
+  ) -> Self {
+    Self::builder()
+      .url(url)
+      .display_url(display_url)
+      .type_(type_)
+      .site_name(site_name)
+      .title(title)
+      .description(description)
+      .embed_url(embed_url)
+      .embed_type(embed_type)
+      .embed_width(embed_width)
+      .embed_height(embed_height)
+      .duration(duration)
+      .author(author)
+      .instant_view_version(instant_view_version)
+      .build()
+  }
+
   pub fn url(&self) -> &String { &self.url }
 
   pub fn display_url(&self) -> &String { &self.display_url }
@@ -129,6 +164,12 @@ pub struct RTDWebPageBuilder {
 impl RTDWebPageBuilder {
   pub fn build(&self) -> WebPage { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
@@ -265,5 +306,31 @@ impl AsRef<WebPage> for RTDWebPageBuilder {
   fn as_ref(&self) -> &WebPage { &self.inner }
 }
 
+impl PartialEq for WebPage {
+  fn eq(&self, other: &Self) -> bool {
+    self.url == other.url
+      && self.display_url == other.display_url
+      && self.type_ == other.type_
+      && self.site_name == other.site_name
+      && self.title == other.title
+      && self.description == other.description
+      && self.photo == other.photo
+      && self.embed_url == other.embed_url
+      && self.embed_type == other.embed_type
+      && self.embed_width == other.embed_width
+      && self.embed_height == other.embed_height
+      && self.duration == other.duration
+      && self.author == other.author
+      && self.animation == other.animation
+      && self.audio == other.audio
+      && self.document == other.document
+      && self.sticker == other.sticker
+      && self.video == other.video
+      && self.video_note == other.video_note
+      && self.voice_note == other.voice_note
+      && self.instant_view_version == other.instant_view_version
+  }
+}
+
 
 
@@ -1,7 +1,6 @@
 
 use crate::types::*;
 use crate::errors::*;
-use uuid::Uuid;
 
 
 
@@ -14,7 +13,10 @@ pub struct WebPageInstantView {
   td_name: String,
   #[doc(hidden)]
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
-  extra: Option<String>,
+  extra: Option<Extra>,
+  #[doc(hidden)]
+  #[serde(rename(serialize = "@client_id", deserialize = "@client_id"), skip_serializing_if = "Option::is_none")]
+  client_id: Option<i32>,
   /// Content of the web page
   page_blocks: Vec<PageBlock>,
   /// Number of the instant view views; 0 if unknown
@@ -31,22 +33,40 @@ pub struct WebPageInstantView {
 }
 
 impl RObject for WebPageInstantView {
-  #[doc(hidden)] fn td_name(&self) -> &'static str { "webPageInstantView" }
-  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }
+  #[doc(hidden)] fn td_name(&self) -> &str { "webPageInstantView" }
+  #[doc(hidden)] fn extra(&self) -> Option<Extra> { self.extra.clone() }
+  #[doc(hidden)] fn client_id(&self) -> Option<i32> { self.client_id }
   fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }
 }
 
 
 
 impl WebPageInstantView {
-  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
+  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { from_json(json.as_ref()) }
   pub fn builder() -> RTDWebPageInstantViewBuilder {
-    let mut inner = WebPageInstantView::default();
-    inner.td_name = "webPageInstantView".to_string();
-    inner.extra = Some(Uuid::new_v4().to_string());
+    let inner = WebPageInstantView { td_name: "webPageInstantView".to_string(), ..Default::default() };
     RTDWebPageInstantViewBuilder { inner }
   }
 
+  /// `WebPageInstantView` with the fields tdlib requires, the others are set by `WebPageInstantView::builder()`
+  pub fn new(
+    page_blocks: Vec<PageBlock>,
+    view_count: i64,
+    version: i64,
+    is_rtl: bool,
+    is_full: bool,
+    feedback_link: impl AsRef<InternalLinkType>,
+  ) -> Self {
+    Self::builder()
+      .page_blocks(page_blocks)
+      .view_count(view_count)
+      .version(version)
+      .is_rtl(is_rtl)
+      .is_full(is_full)
+      .feedback_link(feedback_link)
+      .build()
+  }
+
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
 
   pub fn view_count(&self) -> i64 { self.view_count }
@@ -69,6 +89,12 @@ pub struct RTDWebPageInstantViewBuilder {
 impl RTDWebPageInstantViewBuilder {
   pub fn build(&self) -> WebPageInstantView { self.inner.clone() }
 
+  /// Instead of the one the client sending it gets from its `ExtraGenerator`
+  pub fn extra<T: Into<Extra>>(&mut self, extra: T) -> &mut Self {
+    self.inner.extra = Some(extra.into());
+    self
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -115,5 +141,16 @@ impl AsRef<WebPageInstantView> for RTDWebPageInstantViewBuilder {
   fn as_ref(&self) -> &WebPageInstantView { &self.inner }
 }
 
+impl PartialEq for WebPageInstantView {
+  fn eq(&self, other: &Self) -> bool {
+    self.page_blocks == other.page_blocks
+      && self.view_count == other.view_count
+      && self.version == other.version
+      && self.is_rtl == other.is_rtl
+      && self.is_full == other.is_full
+      && self.feedback_link == other.feedback_link
+  }
+}
+
 
 
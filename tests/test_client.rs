@@ -0,0 +1,132 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+use futures_core::Stream;
+use rtdlib::client::{AsyncClient, BlockingClient};
+use rtdlib::errors::RTDError;
+use rtdlib::transport::{ChannelPeer, ChannelTransport};
+use rtdlib::types::*;
+
+const USER: &str = r#"{"@type":"user","id":1,"first_name":"Alice","last_name":"","username":"alice","phone_number":"","status":{"@type":"userStatusEmpty"},"is_contact":false,"is_mutual_contact":false,"is_verified":false,"is_support":false,"restriction_reason":"","is_scam":false,"is_fake":false,"have_access":true,"type":{"@type":"userTypeRegular"},"language_code":""}"#;
+const UPDATE: &str = r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateReady"}}"#;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+  fn wake(self: Arc<Self>) { self.0.unpark() }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+  let waker = Arc::new(ThreadWaker(thread::current())).into();
+  let mut cx = Context::from_waker(&waker);
+  let mut future = Box::pin(future);
+  loop {
+    match future.as_mut().poll(&mut cx) {
+      Poll::Ready(output) => return output,
+      Poll::Pending => thread::park(),
+    }
+  }
+}
+
+/// Answer the next request with an update first, then `reply` carrying the request `@extra`
+fn answer(peer: &ChannelPeer, reply: &str) -> String {
+  let request = peer.receive(Duration::from_secs(5)).expect("no request");
+  let (_, extra) = detect_td_type_and_extra(&request);
+  let mut reply: serde_json::Value = serde_json::from_str(reply).unwrap();
+  reply["@extra"] = extra.unwrap().into_value();
+  peer.send(UPDATE).unwrap();
+  peer.send(reply.to_string()).unwrap();
+  request
+}
+
+#[test]
+fn test_blocking_client() {
+  let (transport, peer) = ChannelTransport::pair();
+  let mut client = BlockingClient::new(transport);
+  let tdlib = thread::spawn(move || {
+    answer(&peer, USER);
+    answer(&peer, r#"{"@type":"error","code":400,"message":"Chat not found"}"#);
+    peer
+  });
+
+  let me = client.call(GetMe::builder().build(), Duration::from_secs(5)).expect("call fail");
+  assert_eq!(me.username(), "alice");
+  match client.call(GetChat::builder().chat_id(ChatId::from(1)).build(), Duration::from_secs(5)) {
+    Err(RTDError::Tdlib(error)) => assert_eq!(error.code(), 400),
+    other => panic!("expected tdlib error, got {:?}", other),
+  }
+  let updates = client.poll_updates(Duration::from_millis(10));
+  assert_eq!(updates.len(), 2);
+  assert!(updates.iter().all(|update| matches!(update, Ok(TdType::UpdateAuthorizationState(_)))));
+
+  let _peer = tdlib.join().unwrap();
+  match client.call(GetMe::builder().build(), Duration::from_millis(50)) {
+    Err(RTDError::Timeout(timeout)) => assert_eq!(timeout, Duration::from_millis(50)),
+    other => panic!("expected timeout, got {:?}", other),
+  }
+}
+
+#[test]
+fn test_async_client() {
+  let (transport, peer) = ChannelTransport::pair();
+  let client = AsyncClient::new(transport);
+  let tdlib = thread::spawn(move || {
+    answer(&peer, USER);
+    peer
+  });
+
+  let me = block_on(client.send(GetMe::builder().build())).expect("send fail");
+  assert_eq!(me.first_name(), "Alice");
+
+  let mut updates = client.updates();
+  let update = block_on(next_item(&mut updates));
+  assert!(matches!(update, Some(Ok(TdType::UpdateAuthorizationState(_)))));
+  tdlib.join().unwrap();
+}
+
+/// Remembers whether it was woken
+#[derive(Default)]
+struct FlagWaker(AtomicBool);
+
+impl Wake for FlagWaker {
+  fn wake(self: Arc<Self>) { self.0.store(true, Ordering::SeqCst) }
+}
+
+#[test]
+fn test_async_client_wakes_every_stream() {
+  let (transport, peer) = ChannelTransport::pair();
+  let client = AsyncClient::new(transport);
+  let mut streams = [client.updates(), client.updates()];
+  let flags = [Arc::new(FlagWaker::default()), Arc::new(FlagWaker::default())];
+  for (stream, flag) in streams.iter_mut().zip(flags.iter()) {
+    let waker = flag.clone().into();
+    assert!(Pin::new(stream).poll_next(&mut Context::from_waker(&waker)).is_pending());
+  }
+
+  peer.send(UPDATE).unwrap();
+  for _ in 0..100 {
+    if flags.iter().all(|flag| flag.0.load(Ordering::SeqCst)) { break }
+    thread::sleep(Duration::from_millis(10));
+  }
+  assert!(flags.iter().all(|flag| flag.0.load(Ordering::SeqCst)), "a waiting stream was not woken");
+}
+
+/// The next item of a stream, `std::future::poll_fn` is newer than the crate's rust-version
+struct NextItem<'a, S>(&'a mut S);
+
+impl<S: Stream + Unpin> Future for NextItem<'_, S> {
+  type Output = Option<S::Item>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    Pin::new(&mut *self.0).poll_next(cx)
+  }
+}
+
+fn next_item<S: Stream + Unpin>(stream: &mut S) -> NextItem<'_, S> {
+  NextItem(stream)
+}
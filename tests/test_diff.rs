@@ -0,0 +1,65 @@
+use rtdlib::types::*;
+
+fn permissions(can_send_polls: bool) -> ChatPermissions {
+  ChatPermissions::builder().can_send_messages(true).can_send_polls(can_send_polls).build()
+}
+
+fn chat(title: &str, can_send_polls: bool) -> Chat {
+  Chat::builder()
+    .title(title)
+    .type_(ChatType::BasicGroup(ChatTypeBasicGroup::builder().build()))
+    .permissions(permissions(can_send_polls))
+    .build()
+}
+
+#[test]
+fn test_equality_ignores_extra() {
+  let with_extra = Chat::builder()
+    .title("Bob")
+    .type_(ChatType::BasicGroup(ChatTypeBasicGroup::builder().build()))
+    .permissions(permissions(true))
+    .extra("1")
+    .build();
+  assert_ne!(with_extra.extra(), chat("Bob", true).extra());
+  assert_eq!(with_extra, chat("Bob", true));
+  assert_ne!(chat("Bob", true), chat("Bob", false));
+
+  let json = r#"{"@type":"chatTypePrivate","@extra":"1","user_id":7}"#;
+  let other = r#"{"@type":"chatTypePrivate","@extra":"2","user_id":7}"#;
+  assert_eq!(ChatType::from_json(json).unwrap(), ChatType::from_json(other).unwrap());
+  assert_ne!(ChatType::from_json(json).unwrap(), ChatType::default());
+
+  let unknown = r#"{"@type":"chatTypeForum","@extra":"1","forum_id":7}"#;
+  let other = r#"{"@type":"chatTypeForum","@extra":"2","forum_id":7}"#;
+  assert_eq!(ChatType::from_json(unknown).unwrap(), ChatType::from_json(other).unwrap());
+}
+
+#[test]
+fn test_diff() {
+  let old = chat("Bob", true);
+  assert_eq!(diff(&old, &old.clone()), vec![]);
+
+  let new = chat("Bobby", false);
+  let changes: Vec<String> = diff(&old, &new).iter().map(|change| change.to_string()).collect();
+  assert_eq!(changes, vec![r#"permissions.can_send_polls: true -> false"#, r#"title: "Bob" -> "Bobby""#]);
+}
+
+#[test]
+fn test_diff_vectors_and_types() {
+  let position = |order: i64| ChatPosition::builder().list(ChatList::Main(ChatListMain::builder().build())).order(order).build();
+  let old = Chat::builder().positions(vec![position(1)]).build();
+  let new = Chat::builder().positions(vec![position(2), position(3)]).build();
+  let changes = diff(&old, &new);
+  let paths: Vec<&str> = changes.iter().map(|change| change.path.as_str()).collect();
+  assert_eq!(paths, vec!["positions[0].order", "positions[1]"]);
+  // int64, a string as in tdlib's json
+  assert_eq!((&changes[0].old, &changes[0].new), (&serde_json::json!("1"), &serde_json::json!("2")));
+  assert_eq!(changes[1].old, serde_json::Value::Null);
+
+  let old = Chat::builder().type_(ChatType::BasicGroup(ChatTypeBasicGroup::builder().build())).build();
+  let new = Chat::builder().type_(ChatType::Private(ChatTypePrivate::builder().build())).build();
+  let changes = diff(&old, &new);
+  assert_eq!(changes.len(), 1);
+  assert_eq!(changes[0].path, "type");
+  assert_eq!(changes[0].new["@type"], "chatTypePrivate");
+}
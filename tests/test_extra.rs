@@ -0,0 +1,148 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rtdlib::client::{AsyncClient, BlockingClient, ClientManager};
+use rtdlib::errors::RTDError;
+use rtdlib::transport::{ChannelPeer, ChannelTransport, Multiplexer};
+use rtdlib::types::*;
+
+/// The `@extra` of the next request `peer` received
+fn sent_extra(peer: &ChannelPeer) -> Option<Extra> {
+  detect_td_type_and_extra(peer.receive(Duration::from_secs(1)).unwrap()).1
+}
+
+// `set_extra_generator` is global to the test binary, only this test uses it
+#[test]
+fn test_extra_generator() {
+  // given by the client sending it, not by `builder()`
+  assert_eq!(GetMe::builder().build().extra(), None);
+  let (transport, peer) = ChannelTransport::pair();
+  let client = AsyncClient::new(transport);
+  // UUID v4 by default
+  let _reply = client.send(GetMe::builder().build());
+  assert_eq!(sent_extra(&peer).unwrap().to_string().len(), 36);
+
+  set_extra_generator(CounterExtra::starting_at(7));
+  let _reply = client.send(GetMe::builder().build());
+  assert_eq!(sent_extra(&peer), Some(Extra::from("7")));
+  let _reply = client.send(Close::builder().build());
+  assert_eq!(sent_extra(&peer), Some(Extra::from("8")));
+  assert_eq!(next_extra("getMe"), "9");
+
+  let prefixed = PrefixedExtra::new("billing", CounterExtra::new());
+  assert_eq!(prefixed.prefix(), "billing");
+  set_extra_generator(prefixed);
+  let _reply = client.send(GetMe::builder().build());
+  assert_eq!(sent_extra(&peer), Some(Extra::from("billing:1")));
+  let _reply = client.send(GetChat::builder().chat_id(ChatId::from(1)).build());
+  assert_eq!(sent_extra(&peer), Some(Extra::from("billing:2")));
+
+  set_extra_generator(|td_name: &str| format!("{}-request", td_name));
+  let _reply = client.send(GetMe::builder().build());
+  assert_eq!(sent_extra(&peer), Some(Extra::from("getMe-request")));
+
+  // set explicitly, whatever the generator
+  let get_me = GetMe::builder().extra("mine").build();
+  assert_eq!(get_me.extra(), Some(Extra::from("mine")));
+  assert!(get_me.to_json().unwrap().contains(r#""@extra":"mine""#));
+  let _reply = client.send(get_me);
+  assert_eq!(sent_extra(&peer), Some(Extra::from("mine")));
+
+  reset_extra_generator();
+  let _reply = client.send(GetMe::builder().build());
+  let _other = client.send(GetMe::builder().build());
+  let (first, second) = (sent_extra(&peer).unwrap(), sent_extra(&peer).unwrap());
+  assert_ne!(first, second);
+  assert_eq!(first.to_string().len(), 36);
+}
+
+/// Each client with a generator of its own, whatever the global one
+#[test]
+fn test_client_extra_generators() {
+  let (transport, billing_peer) = ChannelTransport::pair();
+  let billing = AsyncClient::new(transport).extra_generator(PrefixedExtra::new("billing", CounterExtra::new()));
+  let (transport, chats_peer) = ChannelTransport::pair();
+  let mut chats = BlockingClient::new(transport).extra_generator(PrefixedExtra::new("chats", CounterExtra::new()));
+
+  let _reply = billing.send(GetMe::builder().build());
+  assert!(matches!(chats.call(GetMe::builder().build(), Duration::from_millis(0)), Err(RTDError::Timeout(_))));
+  let _reply = billing.send(GetMe::builder().build());
+  assert_eq!(sent_extra(&billing_peer), Some(Extra::from("billing:1")));
+  assert_eq!(sent_extra(&billing_peer), Some(Extra::from("billing:2")));
+  assert_eq!(sent_extra(&chats_peer), Some(Extra::from("chats:1")));
+  // set explicitly, whatever the generator
+  let _reply = billing.send(GetMe::builder().extra("mine").build());
+  assert_eq!(sent_extra(&billing_peer), Some(Extra::from("mine")));
+  let mut get_me = GetMe::builder().build();
+  get_me.set_extra("traced");
+  assert_eq!(get_me.to_json().unwrap(), r#"{"@type":"getMe","@extra":"traced"}"#);
+  let _reply = billing.send(get_me);
+  assert_eq!(sent_extra(&billing_peer), Some(Extra::from("traced")));
+  // a borrowed function is sent with a new one, left without
+  let get_me = GetMe::builder().build();
+  let _reply = billing.send(&get_me);
+  assert_eq!(sent_extra(&billing_peer), Some(Extra::from("billing:3")));
+  assert_eq!(get_me.extra(), None);
+
+  // the requests the manager sends itself, like the first `getAuthorizationState`
+  let peers = Arc::new(Mutex::new(vec![]));
+  let tdlib = {
+    let peers = peers.clone();
+    Multiplexer::new(move |_client_id| {
+      let (transport, peer) = ChannelTransport::pair();
+      peers.lock().unwrap().push(peer);
+      Ok(transport)
+    })
+  };
+  let manager = ClientManager::new(tdlib).extra_generator(PrefixedExtra::new("manager", CounterExtra::new()));
+  let _account = manager.add_account(TdlibParameters::builder().build()).unwrap();
+  assert_eq!(sent_extra(&peers.lock().unwrap()[0]), Some(Extra::from("manager:1")));
+}
+
+#[test]
+fn test_extra_json_and_client_id() {
+  // any json as @extra, kept as it is
+  let json = r#"{"@type":"ok","@extra":{"request":7,"tags":["a","b"]},"@client_id":3}"#;
+  let ok = Ok::from_json(json).unwrap();
+  let extra = ok.extra().unwrap();
+  assert_eq!(extra.value()["request"], 7);
+  assert_eq!(extra.as_str(), None);
+  assert_eq!(extra.to_string(), r#"{"request":7,"tags":["a","b"]}"#);
+  assert_eq!(ok.client_id(), Some(3));
+  assert_eq!(Extra::from("abc").to_string(), "abc");
+  assert_eq!(Extra::from(7u64), Extra::from(serde_json::json!(7)));
+  let mut own = std::collections::HashSet::new();
+  own.insert(Extra::from(serde_json::from_str::<serde_json::Value>(r#"{"request":7,"tags":["a","b"]}"#).unwrap()));
+  assert!(own.contains(&Extra::from(serde_json::from_str::<serde_json::Value>(r#"{"tags":["a","b"],"request":7}"#).unwrap())));
+  assert!(!own.contains(&Extra::from(serde_json::json!({"request": 7.0, "tags": ["a", "b"]}))));
+
+  let header = detect_td_header(json);
+  assert_eq!(header.td_type.as_deref(), Some("ok"));
+  assert_eq!(header.extra, ok.extra());
+  assert_eq!(header.client_id, Some(3));
+  assert_eq!(detect_td_header("[]"), TdHeader::default());
+  let header = detect_td_header(r#"{"v\u0061lue":[1,{"a":null}],"@type":"ok","@client_id":4}"#);
+  assert_eq!((header.td_type.as_deref(), header.client_id), (Some("ok"), Some(4)));
+  assert_eq!(detect_td_header(r#"{"@type":{"@type":"ok"},"@client_id":"4"}"#), TdHeader::default());
+  // out of range, not truncated to another client's id
+  assert_eq!(detect_td_header(r#"{"@type":"ok","@client_id":4294967297}"#).client_id, None);
+  assert_eq!(detect_td_header(r#"{"@type":"ok","@client_id":-2147483649}"#).client_id, None);
+
+  // updates carry @client_id but no @extra
+  let json = r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.7.0"},"@client_id":2}"#;
+  let update = from_json::<TdType>(json).unwrap();
+  assert_eq!(update.client_id(), Some(2));
+  assert_eq!(update.extra(), None);
+  let unknown = from_json::<TdType>(r#"{"@type":"updateStory","@extra":[1,2],"@client_id":4}"#).unwrap();
+  assert_eq!(unknown.client_id(), Some(4));
+  assert_eq!(unknown.extra(), Some(Extra::from(serde_json::json!([1, 2]))));
+  let unknown = from_json::<TdType>(r#"{"@type":"updateStory","@client_id":4294967297}"#).unwrap();
+  assert_eq!(unknown.client_id(), None);
+
+  // compared by their fields and sent without @client_id
+  let other = Ok::from_json(r#"{"@type":"ok","@extra":"1","@client_id":5}"#).unwrap();
+  assert_eq!(ok, other);
+  let get_me = GetMe::builder().extra(serde_json::json!({"request": 1})).build();
+  assert_eq!(get_me.client_id(), None);
+  assert_eq!(get_me.to_json().unwrap(), r#"{"@type":"getMe","@extra":{"request":1}}"#);
+}
@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use rtdlib::client::BlockingClient;
+use rtdlib::errors::RTDError;
+use rtdlib::fake::FakeTdlib;
+use rtdlib::types::*;
+
+const TIMEOUT: Duration = Duration::from_secs(1);
+const USER_ID: i64 = 2;
+
+fn user(id: i64, first_name: &str) -> User {
+  User::builder()
+    .id(UserId::from(id))
+    .first_name(first_name)
+    .status(UserStatus::Empty(UserStatusEmpty::builder().build()))
+    .type_(UserType::Regular(UserTypeRegular::builder().build()))
+    .build()
+}
+
+/// The private chat with the user, its id is theirs
+fn chat_id() -> ChatId { ChatId::from(USER_ID) }
+
+fn text_message(id: i64, sender_id: i64, text: &str) -> Message {
+  Message::builder()
+    .id(MessageId::from(id))
+    .chat_id(chat_id())
+    .sender(MessageSender::User(MessageSenderUser::builder().user_id(UserId::from(sender_id)).build()))
+    .content(MessageContent::MessageText(MessageText::builder().text(FormattedText::builder().text(text).build()).build()))
+    .build()
+}
+
+fn fake() -> FakeTdlib {
+  let fake = FakeTdlib::new(user(1, "Alice"));
+  fake
+    .add_chat(Chat::builder().id(chat_id()).title("Bob").type_(ChatType::Private(ChatTypePrivate::builder().user_id(UserId::from(USER_ID)).build())).build())
+    .add_message(text_message(1 << 20, USER_ID, "hi"))
+    .add_message(text_message(2 << 20, 1, "hello"));
+  fake
+}
+
+fn authorization_states(client: &mut BlockingClient<FakeTdlib>) -> Vec<AuthorizationState> {
+  client.poll_updates(Duration::from_millis(10)).into_iter()
+    .filter_map(|update| match update {
+      Ok(TdType::UpdateAuthorizationState(update)) => Some(update.authorization_state().clone()),
+      _ => None,
+    })
+    .collect()
+}
+
+#[test]
+fn test_fake_authorization() {
+  let mut client = BlockingClient::new(fake());
+  assert!(authorization_states(&mut client)[0].is_wait_tdlib_parameters());
+
+  match client.call(GetMe::builder().build(), TIMEOUT) {
+    Err(RTDError::Tdlib(error)) => assert_eq!(error.code(), 401),
+    other => panic!("expected unauthorized, got {:?}", other),
+  }
+
+  client.call(SetTdlibParameters::builder().build(), TIMEOUT).expect("setTdlibParameters");
+  client.call(CheckDatabaseEncryptionKey::builder().build(), TIMEOUT).expect("checkDatabaseEncryptionKey");
+  client.call(SetAuthenticationPhoneNumber::builder().phone_number("+100").build(), TIMEOUT).expect("setAuthenticationPhoneNumber");
+  let state = client.call(GetAuthorizationState::builder().build(), TIMEOUT).expect("getAuthorizationState");
+  assert_eq!(state.as_wait_code().map(|wait| wait.code_info().phone_number().clone()), Some("+100".to_string()));
+  client.call(CheckAuthenticationCode::builder().code("12345").build(), TIMEOUT).expect("checkAuthenticationCode");
+
+  let states = authorization_states(&mut client);
+  assert_eq!(states.len(), 4);
+  assert!(states[0].is_wait_encryption_key());
+  assert!(states[1].is_wait_phone_number());
+  assert!(states[2].is_wait_code());
+  assert!(states[3].is_ready());
+}
+
+#[test]
+fn test_fake_chat() {
+  let fake = fake();
+  fake.add_message(text_message(3 << 20, USER_ID, "how are you?"));
+  let mut client = BlockingClient::new(fake);
+  client.call(SetTdlibParameters::builder().build(), TIMEOUT).unwrap();
+  client.call(CheckDatabaseEncryptionKey::builder().build(), TIMEOUT).unwrap();
+  client.call(CheckAuthenticationBotToken::builder().token("token").build(), TIMEOUT).unwrap();
+
+  assert_eq!(client.call(GetMe::builder().build(), TIMEOUT).unwrap().first_name(), "Alice");
+  assert_eq!(client.call(GetChat::builder().chat_id(chat_id()).build(), TIMEOUT).unwrap().title(), "Bob");
+  match client.call(GetChat::builder().chat_id(ChatId::from(404)).build(), TIMEOUT) {
+    Err(RTDError::Tdlib(error)) => assert_eq!(error.code(), 400),
+    other => panic!("expected chat not found, got {:?}", other),
+  }
+
+  let history = client.call(GetChatHistory::builder().chat_id(chat_id()).from_message_id(MessageId::from(2 << 20)).limit(10).build(), TIMEOUT).unwrap();
+  let ids: Vec<MessageId> = history.messages().iter().flatten().map(|message| message.id()).collect();
+  assert_eq!(ids, vec![MessageId::from(2 << 20), MessageId::from(1 << 20)]);
+  // of the whole history, not the page
+  assert_eq!(history.total_count(), 3);
+  let page = client.call(GetChatHistory::builder().chat_id(chat_id()).limit(1).build(), TIMEOUT).unwrap();
+  assert_eq!((page.messages().len(), page.total_count()), (1, 3));
+}
+
+#[test]
+fn test_fake_send_message() {
+  let mut client = BlockingClient::new(fake());
+  client.call(SetTdlibParameters::builder().build(), TIMEOUT).unwrap();
+  client.call(CheckDatabaseEncryptionKey::builder().build(), TIMEOUT).unwrap();
+  client.call(CheckAuthenticationBotToken::builder().token("token").build(), TIMEOUT).unwrap();
+  client.poll_updates(Duration::from_millis(10));
+
+  let content = InputMessageContent::InputMessageText(InputMessageText::builder().text(FormattedText::builder().text("ping").build()).build());
+  let pending = client.call(SendMessage::builder().chat_id(chat_id()).input_message_content(content).build(), TIMEOUT).unwrap();
+  assert!(matches!(pending.sending_state(), Some(state) if state.is_pending()));
+
+  let updates: Vec<TdType> = client.poll_updates(Duration::from_millis(10)).into_iter().map(|update| update.unwrap()).collect();
+  assert_eq!(updates.len(), 2);
+  match &updates[0] {
+    TdType::UpdateNewMessage(update) => assert_eq!(update.message().id(), pending.id()),
+    other => panic!("expected updateNewMessage, got {:?}", other),
+  }
+  match &updates[1] {
+    TdType::UpdateMessageSendSucceeded(update) => {
+      assert_eq!(update.old_message_id(), pending.id());
+      assert_eq!(update.message().id(), MessageId::from(3 << 20));
+      assert!(update.message().sending_state().is_none());
+    }
+    other => panic!("expected updateMessageSendSucceeded, got {:?}", other),
+  }
+
+  let history = client.call(GetChatHistory::builder().chat_id(chat_id()).limit(1).build(), TIMEOUT).unwrap();
+  let text = history.messages()[0].as_ref().and_then(|message| message.content().as_message_text().map(|text| text.text().text().clone()));
+  assert_eq!(text, Some("ping".to_string()));
+}
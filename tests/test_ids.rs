@@ -0,0 +1,105 @@
+use rtdlib::types::*;
+
+/// `ChatId::from(-1001234567890)` would take an `i32` without the `typed-ids` feature
+fn chat_id(id: i64) -> ChatId { ChatId::from(id) }
+
+/// A chat kind of its id
+type Kind = fn(i64) -> ChatKind;
+
+#[test]
+fn test_chat_kinds() {
+  let kinds: [(Kind, i64, i64); 8] = [
+    (|id| ChatKind::Private(UserId::from(id)), 7, 7),
+    (|id| ChatKind::Private(UserId::from(id)), (1 << 40) - 1, (1 << 40) - 1),
+    (|id| ChatKind::BasicGroup(BasicGroupId::from(id)), 7, -7),
+    (|id| ChatKind::BasicGroup(BasicGroupId::from(id)), 999_999_999_999, -999_999_999_999),
+    (|id| ChatKind::Supergroup(SupergroupId::from(id)), 1234567890, -1001234567890),
+    (|id| ChatKind::Supergroup(SupergroupId::from(id)), 997_852_516_352, -1_997_852_516_352),
+    (|id| ChatKind::Secret(SecretChatId::from(id)), i64::from(i32::MAX), -1_997_852_516_353),
+    (|id| ChatKind::Secret(SecretChatId::from(id)), -7, -2_000_000_000_007),
+  ];
+  for (kind, id, expected) in kinds.iter() {
+    let kind = kind(*id);
+    assert_eq!(kind.chat_id(), Some(chat_id(*expected)));
+    assert_eq!(ChatKind::of(chat_id(*expected)), Some(kind));
+  }
+  // no chat has them, whether or not computing its id would overflow
+  let kinds: [Kind; 4] = [
+    |id| ChatKind::Private(UserId::from(id)),
+    |id| ChatKind::BasicGroup(BasicGroupId::from(id)),
+    |id| ChatKind::Supergroup(SupergroupId::from(id)),
+    |id| ChatKind::Secret(SecretChatId::from(id)),
+  ];
+  for kind in kinds.iter() {
+    for id in [0, i64::MIN, i64::MAX].iter() {
+      assert_eq!(kind(*id).chat_id(), None);
+    }
+  }
+  assert_eq!(ChatKind::Private(UserId::from(-7)).chat_id(), None);
+  // would be the id of a basic group chat
+  assert_eq!(ChatKind::Supergroup(SupergroupId::from(-1)).chat_id(), None);
+  for id in [0, -1_000_000_000_000, -2_000_000_000_000, 1 << 40, i64::MIN, i64::MAX].iter() {
+    assert_eq!(ChatKind::of(chat_id(*id)), None);
+  }
+}
+
+#[test]
+fn test_chat_ids_of_objects() {
+  let chat_type = ChatType::Supergroup(ChatTypeSupergroup::new(SupergroupId::from(1234567890), true));
+  assert_eq!(chat_type.chat_id(), Some(chat_id(-1001234567890)));
+  assert_eq!(chat_type.kind(), Some(ChatKind::Supergroup(SupergroupId::from(1234567890))));
+  assert_eq!(ChatType::default().chat_id(), None);
+
+  let supergroup = Supergroup::builder().id(SupergroupId::from(1234567890)).build();
+  assert_eq!(supergroup.chat_id(), Some(chat_id(-1001234567890)));
+  assert_eq!(BasicGroup::builder().id(BasicGroupId::from(7)).build().chat_id(), Some(chat_id(-7)));
+  assert_eq!(SecretChat::builder().id(SecretChatId::from(7)).build().chat_id(), Some(chat_id(-1_999_999_999_993)));
+}
+
+#[test]
+fn test_server_message_ids() {
+  for server_id in [1, 42, (1 << 31) - 1].iter() {
+    let message_id = message_id_from_server(*server_id);
+    assert_eq!(server_message_id(message_id), Some(*server_id));
+  }
+  assert_eq!(message_id_from_server(42), MessageId::from(42 << 20));
+  // being sent, and scheduled
+  assert_eq!(server_message_id(MessageId::from((42 << 20) + 1)), None);
+  assert_eq!(server_message_id(MessageId::from(4)), None);
+  assert_eq!(server_message_id(MessageId::from(0)), None);
+
+  let message = Message::builder().id(MessageId::from(3 << 20)).build();
+  assert_eq!(message.server_id(), Some(3));
+}
+
+#[cfg(feature = "typed-ids")]
+#[test]
+fn test_ids_serialize_as_numbers() {
+  let json = r#"{"@type":"messageSenderUser","@extra":null,"user_id":7}"#;
+  let sender: MessageSenderUser = from_json(json).expect("Json fail");
+  assert_eq!(sender.user_id(), UserId(7));
+  assert_eq!(sender.to_json().unwrap(), json);
+
+  let forward = ForwardMessages::builder().chat_id(ChatId(-100)).message_ids(vec![MessageId(1 << 20), MessageId(2 << 20)]).build();
+  let value: serde_json::Value = serde_json::from_str(&forward.to_json().unwrap()).unwrap();
+  assert_eq!(value["chat_id"], -100);
+  assert_eq!(value["message_ids"], serde_json::json!([1 << 20, 2 << 20]));
+}
+
+#[cfg(feature = "typed-ids")]
+#[test]
+fn test_typed_chat_ids() {
+  assert_eq!(UserId(7).chat_id(), Some(ChatId(7)));
+  assert_eq!(BasicGroupId(7).chat_id(), Some(ChatId(-7)));
+  assert_eq!(SupergroupId(7).chat_id(), Some(ChatId(-1_000_000_000_007)));
+  assert_eq!(SecretChatId(7).chat_id(), Some(ChatId(-1_999_999_999_993)));
+
+  assert_eq!(ChatId(7).user_id(), Some(UserId(7)));
+  assert_eq!(ChatId(7).supergroup_id(), None);
+  assert_eq!(ChatId(-1_000_000_000_007).supergroup_id(), Some(SupergroupId(7)));
+  assert_eq!(ChatId(-1_999_999_999_993).secret_chat_id(), Some(SecretChatId(7)));
+  assert_eq!(ChatId(-7).basic_group_id(), Some(BasicGroupId(7)));
+  assert_eq!(i64::from(ChatId(-7)), -7);
+  assert_eq!(UserId(i64::MIN).chat_id(), None);
+  assert_eq!(SupergroupId(i64::MIN).chat_id(), None);
+}
@@ -3,7 +3,7 @@ use rtdlib::types::*;
 #[test]
 fn test_authorization_state() {
   let json = r#"{"@type":"updateAuthorizationState","@extra":null,"authorization_state":{"@type":"authorizationStateWaitTdlibParameters","@extra":null}}"#;
-  let state: UpdateAuthorizationState = serde_json::from_str(&json[..]).expect("Json fail");
+  let state: UpdateAuthorizationState = serde_json::from_str(json).expect("Json fail");
   assert_eq!("updateAuthorizationState", state.td_name());
   let rjson = state.to_json();
   assert!(rjson.is_ok());
@@ -50,8 +50,8 @@ fn test_json_td_type() {
   "#;
   let (td_type, extra) = crate::detect_td_type_and_extra(json_error);
   assert_eq!(td_type, Some("error".to_string()));
-  assert_eq!(extra, Some("b51afb43-ea2a-45be-afa5-3957482206b3".to_string()));
-  let error: Error = serde_json::from_str(&json_error[..]).expect("Json fail");
+  assert_eq!(extra, Some(Extra::from("b51afb43-ea2a-45be-afa5-3957482206b3")));
+  let error: Error = serde_json::from_str(json_error).expect("Json fail");
   let td_type = TdType::Error(error);
   println!("{:?}", td_type);
 
@@ -59,3 +59,197 @@ fn test_json_td_type() {
 
 }
 
+
+#[test]
+fn test_parse_response() {
+  let json = r#"{"@type":"user","id":1,"first_name":"Alice","last_name":"","username":"alice","phone_number":"","status":{"@type":"userStatusEmpty"},"is_contact":false,"is_mutual_contact":false,"is_verified":false,"is_support":false,"restriction_reason":"","is_scam":false,"is_fake":false,"have_access":true,"type":{"@type":"userTypeRegular"},"language_code":"","@extra":"abc"}"#;
+  let user: User = parse_response::<GetMe>(json).expect("Json fail");
+  assert_eq!(user.username(), "alice");
+
+  let json_error = r#"{"@type":"error","code":400,"message":"Chat not found","@extra":"abc"}"#;
+  match parse_response::<GetChat>(json_error) {
+    Err(rtdlib::errors::RTDError::Tdlib(error)) => assert_eq!(error.code(), 400),
+    other => panic!("expected tdlib error, got {:?}", other),
+  }
+}
+
+#[test]
+fn test_type_after_fields() {
+  // tdlib puts `@type` first, but it may come anywhere
+  let json = r#"{"authorization_state":{"is_encrypted":true,"@type":"authorizationStateWaitEncryptionKey"},"@extra":"abc","@type":"updateAuthorizationState"}"#;
+  match from_json::<TdType>(json).expect("Json fail") {
+    TdType::UpdateAuthorizationState(update) => {
+      assert_eq!(update.extra(), Some(Extra::from("abc")));
+      let state = update.authorization_state().as_wait_encryption_key().expect("waitEncryptionKey");
+      assert!(state.is_encrypted());
+    }
+    other => panic!("expected updateAuthorizationState, got {:?}", other),
+  }
+  assert_eq!(detect_td_type_and_extra(json), (Some("updateAuthorizationState".to_string()), Some(Extra::from("abc"))));
+
+  let error = from_json::<TdType>(r#"{"authorization_state":{}}"#).unwrap_err();
+  assert!(error.to_string().contains("unknown field TdType -> @type"), "{}", error);
+  let error = from_json::<AuthorizationState>(r#"{"@type":"authorizationStateWaitEncryptionKey","is_encrypted":1}"#).unwrap_err();
+  assert!(error.to_string().contains("authorizationStateWaitEncryptionKey can't deserialize to AuthorizationState::WaitEncryptionKey"), "{}", error);
+  assert!(from_json::<AuthorizationState>("[]").is_err());
+  assert_eq!(detect_td_type("[\"error\"]"), None);
+
+  let json_error = r#"{"code":400,"@type":"error","message":"Chat not found"}"#;
+  match parse_response::<GetChat>(json_error) {
+    Err(rtdlib::errors::RTDError::Tdlib(error)) => assert_eq!(error.message(), "Chat not found"),
+    other => panic!("expected tdlib error, got {:?}", other),
+  }
+}
+
+#[test]
+fn test_unknown_type() {
+  let json = r#"{"@type":"updateNewMessage","message":{"@type":"message","id":1,"sender":{"@type":"messageSenderUser","user_id":1},"chat_id":2,"is_outgoing":false,"is_pinned":false,"can_be_edited":false,"can_be_forwarded":false,"can_be_saved":false,"can_be_deleted_only_for_self":false,"can_be_deleted_for_all_users":false,"can_get_statistics":false,"can_get_message_thread":false,"can_get_viewers":false,"can_get_media_timestamp_links":false,"has_timestamped_media":false,"is_channel_post":false,"contains_unread_mention":false,"date":0,"edit_date":0,"reply_in_chat_id":0,"reply_to_message_id":0,"message_thread_id":0,"ttl":0,"ttl_expires_in":0.0,"via_bot_user_id":0,"author_signature":"","media_album_id":"0","restriction_reason":"","content":{"@type":"messageStory","story_id":7}}}"#;
+  let update: UpdateNewMessage = from_json(json).expect("Json fail");
+  let content = update.message().content().as_unknown().expect("unknown content");
+  assert_eq!(content.td_name(), "messageStory");
+  assert_eq!(content.value()["story_id"], 7);
+  assert_eq!(content.to_json().unwrap(), r#"{"@type":"messageStory","story_id":7}"#);
+
+  let json = r#"{"@type":"updateStory","story":{"id":7},"@extra":"abc"}"#;
+  match from_json::<TdType>(json).expect("Json fail") {
+    TdType::Unknown(update) => {
+      assert_eq!(update.td_name(), "updateStory");
+      assert_eq!(update.extra(), Some(Extra::from("abc")));
+      let value: serde_json::Value = serde_json::from_str(&update.to_json().unwrap()).unwrap();
+      assert_eq!(&value, update.value());
+      assert_eq!(value, serde_json::from_str::<serde_json::Value>(json).unwrap());
+    }
+    other => panic!("expected unknown update, got {:?}", other),
+  }
+
+  let user_type: UserType = from_json(r#"{"@type":"userTypeFuture"}"#).expect("Json fail");
+  assert!(user_type.is_unknown_type() && !user_type.is_unknown());
+}
+
+#[test]
+fn test_lenient_decoding() {
+  let json = r#"{"@type":"updateNewMessage","message":{"@type":"message","id":"5","chat_id":2,"is_outgoing":"yes","date":0,"content":{"@type":"messageText"},"sender":null}}"#;
+  assert!(from_json::<UpdateNewMessage>(json).is_err());
+
+  let (update, diagnostics) = from_json_lenient::<UpdateNewMessage>(json).expect("Json fail");
+  let message = update.message();
+  assert_eq!(message.id(), MessageId::from(5));
+  assert_eq!(message.chat_id(), ChatId::from(2));
+  assert!(!message.is_outgoing());
+  assert!(message.sender()._is_default());
+  assert_eq!(message.content().as_message_text().map(|text| text.text().text().as_str()), Some(""));
+
+  let paths: Vec<String> = diagnostics.iter().map(|diagnostic| diagnostic.path.clone()).collect();
+  assert!(paths.contains(&"message.is_outgoing".to_string()));
+  assert!(paths.contains(&"message.content.text".to_string()));
+  assert!(paths.contains(&"message.can_be_edited".to_string()));
+  assert!(!paths.iter().any(|path| path == "message.sender" || path.starts_with("message.content.text.")));
+  let diagnostic = diagnostics.iter().find(|diagnostic| diagnostic.path == "message.is_outgoing").unwrap();
+  assert_eq!(diagnostic.to_string(), "message.is_outgoing: expected boolean, found string");
+  assert!(from_json_strict::<UpdateNewMessage>(json).is_err());
+}
+
+#[test]
+fn test_int64() {
+  #[cfg(feature = "stickers")]
+  {
+    let json = r#"{"@type":"updateInstalledStickerSets","is_masks":false,"sticker_set_ids":["9223372036854775807",-2]}"#;
+    let update: UpdateInstalledStickerSets = from_json(json).expect("Json fail");
+    assert_eq!(update.sticker_set_ids(), &vec![i64::MAX, -2]);
+    let value: serde_json::Value = serde_json::from_str(&update.to_json().unwrap()).unwrap();
+    assert_eq!(value["sticker_set_ids"], serde_json::json!(["9223372036854775807", "-2"]));
+  }
+
+  let session = Session::builder().id(-42).build();
+  let value: serde_json::Value = serde_json::from_str(&session.to_json().unwrap()).unwrap();
+  assert_eq!(value["id"], "-42");
+  assert_eq!(Session::from_json(session.to_json().unwrap()).unwrap().id(), -42);
+
+  let mut value = value;
+  value["id"] = serde_json::json!(42);
+  assert_eq!(Session::from_json(value.to_string()).unwrap().id(), 42);
+  value["id"] = serde_json::json!("4x2");
+  assert!(Session::from_json(value.to_string()).is_err());
+  value["id"] = serde_json::json!(u64::MAX);
+  assert!(Session::from_json(value.to_string()).is_err());
+  // the whole int64 range, on 32 bit targets too
+  for id in [i64::MIN, i64::MAX] {
+    value["id"] = serde_json::json!(id.to_string());
+    assert_eq!(Session::from_json(value.to_string()).unwrap().id(), id);
+  }
+}
+
+#[test]
+fn test_bytes() {
+  let test_bytes: TestBytes = from_json(r#"{"@type":"testBytes","value":"AAEC/w=="}"#).expect("Json fail");
+  assert_eq!(test_bytes.value().as_slice(), &[0, 1, 2, 255]);
+  assert_eq!(test_bytes.to_json().unwrap(), r#"{"@type":"testBytes","@extra":null,"value":"AAEC/w=="}"#);
+  assert!(from_json::<TestBytes>(r#"{"@type":"testBytes","value":"not base64!"}"#).is_err());
+
+  let call = TestCallBytes::builder().x(Bytes::new(b"rtdlib".to_vec())).build();
+  let value: serde_json::Value = serde_json::from_str(&call.to_json().unwrap()).unwrap();
+  assert_eq!(value["x"], "cnRkbGli");
+}
+
+#[test]
+fn test_new_with_required_fields() {
+  let text = FormattedText::new("hello", vec![]);
+  let content = InputMessageContent::InputMessageText(InputMessageText::new(text, false, true));
+  let send_message = SendMessage::new(ChatId::from(42), 0, MessageId::from(0), content);
+  assert_eq!(send_message.td_name(), "sendMessage");
+  // given by the client sending it
+  assert!(send_message.extra().is_none());
+  assert_eq!(send_message.chat_id(), ChatId::from(42));
+  assert!(send_message.input_message_content().is_input_message_text());
+  // left to the builder, tdlib takes null for them
+  assert!(send_message.reply_markup()._is_default());
+}
+
+#[cfg(feature = "simd-json")]
+#[test]
+fn test_simd_json() {
+  use rtdlib::json::{JsonBackend, SerdeJson, SimdJson};
+
+  let jsons = [
+    r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitTdlibParameters"}}"#,
+    r#"{"authorization_state":{"is_encrypted":true,"@type":"authorizationStateWaitEncryptionKey"},"@extra":"abc","@type":"updateAuthorizationState"}"#,
+    r#"{"@type":"updateInstalledStickerSets","is_masks":false,"sticker_set_ids":["9223372036854775807",-2],"@extra":"abc"}"#,
+    r#"{"@type":"testBytes","value":"AAEC/w=="}"#,
+    r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.7.0 é\n"}}"#,
+    r#"{"@type":"updateStory","story":{"id":7,"caption":[1.5,null,true]},"@extra":"abc"}"#,
+    r#"{"@type":"error","code":400,"message":"Chat not found"}"#,
+  ];
+  for json in jsons.iter() {
+    let serde: TdType = SerdeJson::from_str(json).expect("Json fail");
+    let simd: TdType = SimdJson::from_str(json).expect("Json fail");
+    assert_eq!(serde, simd, "{}", json);
+    assert_eq!(serde.extra(), simd.extra());
+    assert_eq!(serde.to_json().unwrap(), simd.to_json().unwrap());
+  }
+
+  assert!(SimdJson::from_str::<TdType>(r#"{"@type":"updateOption","#).is_err());
+  assert!(SimdJson::from_str::<TestBytes>(r#"{"@type":"testBytes","value":"not base64!"}"#).is_err());
+}
+
+#[test]
+fn test_feature_types() {
+  // generated with the `calls` feature, kept as json without it
+  let content: MessageContent = from_json(r#"{"@type":"messageCall","is_video":false,"discard_reason":{"@type":"callDiscardReasonMissed"},"duration":3}"#).expect("Json fail");
+  let update: TdType = from_json(r#"{"@type":"updateCall","call":{"@type":"call","id":1,"user_id":2,"is_outgoing":false,"is_video":false,"state":{"@type":"callStatePending","is_created":true,"is_received":false}}}"#).expect("Json fail");
+  #[cfg(feature = "calls")]
+  {
+    assert_eq!(content.as_message_call().expect("messageCall").duration(), 3);
+    match update {
+      TdType::UpdateCall(update) => assert!(update.call().state().is_pending()),
+      other => panic!("expected updateCall, got {:?}", other),
+    }
+  }
+  #[cfg(not(feature = "calls"))]
+  {
+    assert_eq!(content.as_unknown().expect("unknown").value()["duration"], 3);
+    match update {
+      TdType::Unknown(update) => assert_eq!(update.td_name(), "updateCall"),
+      other => panic!("expected unknown, got {:?}", other),
+    }
+  }
+}
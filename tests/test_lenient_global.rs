@@ -0,0 +1,19 @@
+//! `set_lenient_decoding` is process wide, so it is tested alone in its own binary
+
+use rtdlib::types::*;
+
+#[test]
+fn test_global_lenient_decoding() {
+  let json = r#"{"@type":"updateNewMessage","message":{"@type":"message","id":"5","chat_id":2,"is_outgoing":"yes","date":0,"content":{"@type":"messageText"},"sender":null}}"#;
+  let (_, diagnostics) = from_json_lenient::<UpdateNewMessage>(json).expect("Json fail");
+  assert!(from_json::<UpdateNewMessage>(json).is_err());
+
+  set_lenient_decoding(true);
+  let lenient = from_json::<UpdateNewMessage>(json);
+  let strict = from_json_strict::<UpdateNewMessage>(json);
+  set_lenient_decoding(false);
+  assert!(lenient.is_ok());
+  assert!(strict.is_err());
+  assert_eq!(take_diagnostics().len(), diagnostics.len());
+  assert!(from_json::<UpdateNewMessage>(json).is_err());
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use rtdlib::client::{Account, BlockingClient, ClientManager};
+use rtdlib::fake::FakeTdlib;
+use rtdlib::transport::{Multiplexer, TdTransport};
+use rtdlib::types::*;
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+type Tdlib = Arc<Multiplexer<FakeTdlib>>;
+
+fn user(id: i64, first_name: &str) -> User {
+  User::builder()
+    .id(UserId::from(id))
+    .first_name(first_name)
+    .status(UserStatus::Empty(UserStatusEmpty::builder().build()))
+    .type_(UserType::Regular(UserTypeRegular::builder().build()))
+    .build()
+}
+
+/// A fake tdlib per client, logged in as Alice, Bob, ... in the order the clients are created
+fn tdlib() -> Tdlib {
+  Arc::new(Multiplexer::new(|client_id| {
+    let names = ["Alice", "Bob", "Carol"];
+    Ok(FakeTdlib::new(user(client_id as i64, names[(client_id - 1) as usize])))
+  }))
+}
+
+fn parameters(name: &str) -> TdlibParameters {
+  TdlibParameters::builder()
+    .database_directory(format!("accounts/{}/db", name))
+    .files_directory(format!("accounts/{}/files", name))
+    .build()
+}
+
+fn log_in(account: &Account<Tdlib>) -> BlockingClient<&Account<Tdlib>> {
+  assert!(account.wait_authorization(TIMEOUT).unwrap().is_wait_phone_number());
+  let mut client = BlockingClient::new(account);
+  client.call(CheckAuthenticationBotToken::builder().token("token").build(), TIMEOUT).unwrap();
+  client
+}
+
+fn updates<T: TdTransport>(client: &mut BlockingClient<T>) -> Vec<TdType> {
+  client.poll_updates(Duration::from_millis(100)).into_iter().map(|update| update.unwrap()).collect()
+}
+
+fn authorization_states(updates: Vec<TdType>) -> Vec<AuthorizationState> {
+  updates.into_iter()
+    .filter_map(|update| match update {
+      TdType::UpdateAuthorizationState(update) => Some(update.authorization_state().clone()),
+      _ => None,
+    })
+    .collect()
+}
+
+#[test]
+fn test_manager_accounts() {
+  let tdlib = tdlib();
+  let manager = ClientManager::new(tdlib.clone());
+  let alice = manager.add_account(parameters("alice")).unwrap();
+  let bob = manager.add_account(parameters("bob")).unwrap();
+  assert_eq!(manager.client_ids(), vec![alice.client_id(), bob.client_id()]);
+
+  let mut alice_client = log_in(&alice);
+  let mut bob_client = log_in(&bob);
+  assert!(alice.authorization_state().is_ready());
+
+  // each client got the parameters of its account
+  let fake_parameters = |account: &Account<Tdlib>| tdlib.client(account.client_id()).unwrap().parameters().unwrap();
+  assert_eq!(fake_parameters(&alice).database_directory(), "accounts/alice/db");
+  assert_eq!(fake_parameters(&bob).files_directory(), "accounts/bob/files");
+  assert_eq!(bob.parameters().database_directory(), "accounts/bob/db");
+
+  // replies and updates reach the account of their @client_id
+  assert_eq!(alice_client.call(GetMe::builder().build(), TIMEOUT).unwrap().first_name(), "Alice");
+  assert_eq!(bob_client.call(GetMe::builder().build(), TIMEOUT).unwrap().first_name(), "Bob");
+  let states = authorization_states(updates(&mut bob_client));
+  assert!(states.first().unwrap().is_wait_tdlib_parameters());
+  assert!(states.last().unwrap().is_ready());
+  updates(&mut alice_client);
+
+  let message = Message::builder().id(MessageId::from(1 << 20)).chat_id(ChatId::from(7))
+    .sender(MessageSender::User(MessageSenderUser::builder().user_id(UserId::from(7)).build()))
+    .content(MessageContent::MessageText(MessageText::builder().text(FormattedText::builder().text("hi").build()).build()))
+    .build();
+  tdlib.client(bob.client_id()).unwrap().receive_message(&message);
+  let received = updates(&mut bob_client);
+  assert_eq!(received.len(), 1);
+  assert_eq!(received[0].client_id(), Some(bob.client_id()));
+  assert!(matches!(received[0], TdType::UpdateNewMessage(_)));
+  assert!(updates(&mut alice_client).is_empty());
+}
+
+#[test]
+fn test_manager_close() {
+  let manager = ClientManager::new(tdlib());
+  let alice = manager.add_account(parameters("alice")).unwrap();
+  let bob = manager.add_account(parameters("bob")).unwrap();
+  let mut alice_client = log_in(&alice);
+  log_in(&bob);
+
+  alice.close().unwrap();
+  let mut states: Vec<AuthorizationState> = vec![];
+  while !matches!(states.last(), Some(state) if state.is_closed()) {
+    let received = authorization_states(updates(&mut alice_client));
+    assert!(!received.is_empty(), "no authorizationStateClosed after {:?}", states);
+    states.extend(received);
+  }
+  assert!(states[states.len() - 2].is_closing());
+  assert!(alice.is_closed());
+  assert_eq!(manager.client_ids(), vec![bob.client_id()]);
+  assert!(alice_client.call(GetMe::builder().build(), TIMEOUT).is_err());
+
+  // dropping an account closes its client
+  let bob_id = bob.client_id();
+  drop(bob);
+  std::thread::sleep(Duration::from_millis(200));
+  assert!(!manager.client_ids().contains(&bob_id));
+}
@@ -0,0 +1,84 @@
+use std::fs;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rtdlib::client::BlockingClient;
+use rtdlib::transport::*;
+use rtdlib::types::*;
+
+const UPDATE: &str = r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateReady"}}"#;
+const OK: &str = r#"{"@type":"ok","@extra":"close"}"#;
+const GET_TEXT_ENTITIES: &str = r#"{"@type":"getTextEntities","text":"@rtdlib"}"#;
+const TEXT_ENTITIES: &str = r#"{"@type":"textEntities","entities":[]}"#;
+
+/// A `ChannelTransport` answering every `execute` with `TEXT_ENTITIES`
+struct Executing(ChannelTransport);
+
+impl TdTransport for Executing {
+  fn send(&self, request: &str) -> rtdlib::errors::RTDResult<()> { self.0.send(request) }
+  fn receive(&self, timeout: Duration) -> Option<String> { self.0.receive(timeout) }
+  fn execute(&self, _request: &str) -> Option<String> { Some(TEXT_ENTITIES.to_string()) }
+}
+
+fn record(path: &std::path::Path) {
+  let _ = fs::remove_file(path);
+  let (transport, peer) = ChannelTransport::pair();
+  let recorder = Recorder::create(Executing(transport), path).unwrap();
+  assert_eq!(recorder.execute(GET_TEXT_ENTITIES).as_deref(), Some(TEXT_ENTITIES));
+  recorder.send(r#"{"@type":"close","@extra":"close"}"#).unwrap();
+  peer.send(UPDATE).unwrap();
+  assert_eq!(recorder.receive(Duration::from_secs(1)).as_deref(), Some(UPDATE));
+  thread::sleep(Duration::from_millis(200));
+  peer.send(OK).unwrap();
+  assert_eq!(recorder.receive(Duration::from_secs(1)).as_deref(), Some(OK));
+}
+
+#[test]
+fn test_record_and_replay() {
+  let path = std::env::temp_dir().join(format!("rtdlib-record-{}.jsonl", std::process::id()));
+  record(&path);
+
+  let records = read_records(&path).unwrap();
+  let summary: Vec<(Direction, Option<&str>, Option<&str>)> = records.iter()
+    .map(|record| (record.direction, record.td_type.as_deref(), record.extra.as_ref().and_then(Extra::as_str)))
+    .collect();
+  assert_eq!(summary, vec![
+    (Direction::Execute, Some("getTextEntities"), None),
+    (Direction::Executed, Some("textEntities"), None),
+    (Direction::Send, Some("close"), Some("close")),
+    (Direction::Receive, Some("updateAuthorizationState"), None),
+    (Direction::Receive, Some("ok"), Some("close")),
+  ]);
+  assert!(records[4].timestamp >= records[3].timestamp + 150);
+
+  // the reply of `execute` is no update, `execute` answers with it
+  let replayer = Replayer::open(&path, None).unwrap();
+  assert_eq!(replayer.execute(r#"{"@type":"getMe"}"#), None);
+  assert_eq!(replayer.execute(GET_TEXT_ENTITIES).as_deref(), Some(TEXT_ENTITIES));
+  assert_eq!(replayer.execute(GET_TEXT_ENTITIES), None);
+  let mut client = BlockingClient::new(replayer);
+  let updates = client.poll_updates(Duration::from_millis(10));
+  assert_eq!(updates.len(), 2);
+  assert!(matches!(updates[0], Ok(TdType::UpdateAuthorizationState(_))));
+  assert!(matches!(updates[1], Ok(TdType::Ok(_))));
+
+  let replayer = Arc::new(Replayer::open(&path, Some(2.0)).unwrap());
+  // the replay starts with the first receive, not when it is opened
+  thread::sleep(Duration::from_millis(150));
+  let started = Instant::now();
+  assert_eq!(replayer.receive(Duration::from_secs(1)).as_deref(), Some(UPDATE));
+  assert_eq!(replayer.receive(Duration::from_millis(10)), None);
+  // waiting for the next record doesn't hold the records
+  let waiting = {
+    let replayer = replayer.clone();
+    thread::spawn(move || replayer.receive(Duration::from_secs(1)))
+  };
+  thread::sleep(Duration::from_millis(10));
+  // holding them, `is_finished` would only get them once the record is taken
+  assert!(!replayer.is_finished());
+  assert_eq!(waiting.join().unwrap().as_deref(), Some(OK));
+  assert!(started.elapsed() >= Duration::from_millis(75));
+  assert!(replayer.is_finished());
+  fs::remove_file(&path).unwrap();
+}
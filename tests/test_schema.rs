@@ -0,0 +1,44 @@
+use rtdlib::schema;
+use rtdlib::types::*;
+
+#[test]
+fn test_lookup_by_td_name() {
+  let update: TdType = from_json(r#"{"@type": "updateUserStatus", "user_id": 1, "status": {"@type": "userStatusEmpty"}}"#).unwrap();
+  let constructor = schema::constructor(update.td_name()).unwrap();
+  assert_eq!(constructor.class, "Update");
+  assert_eq!(constructor.field("status").unwrap().type_, "UserStatus");
+  assert!(constructor.field("unknown").is_none());
+
+  let function = schema::function(GetChat::builder().build().td_name()).unwrap();
+  assert_eq!(function.result, "Chat");
+  assert_eq!(function.parameters.len(), 1);
+  assert!(schema::function("getChatt").is_none());
+  assert!(schema::constructor("getChat").is_none());
+}
+
+#[test]
+fn test_class_hierarchy() {
+  let status = schema::class("UserStatus").unwrap();
+  assert_eq!(status.description, "Describes the last time the user was online");
+  for name in status.constructors {
+    assert_eq!(schema::constructor(name).unwrap().class, "UserStatus");
+  }
+  assert!(status.constructors.contains(&UserStatusEmpty::builder().build().td_name()));
+  // a class with a single constructor named after it is a struct, not a class
+  assert!(schema::class("User").is_none());
+}
+
+#[test]
+fn test_optional_fields() {
+  let chat = schema::constructor("chat").unwrap();
+  assert!(chat.field("photo").unwrap().may_be_null());
+  assert!(!chat.field("title").unwrap().may_be_null());
+}
+
+#[test]
+fn test_tables_are_sorted() {
+  assert!(schema::classes().windows(2).all(|pair| pair[0].name < pair[1].name));
+  assert!(schema::constructors().windows(2).all(|pair| pair[0].name < pair[1].name));
+  assert!(schema::functions().windows(2).all(|pair| pair[0].name < pair[1].name));
+  assert!(schema::constructors().iter().all(|constructor| schema::constructor(constructor.name) == Some(constructor)));
+}
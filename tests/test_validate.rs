@@ -0,0 +1,60 @@
+use rtdlib::types::*;
+
+fn send_message(content: InputMessageContent) -> SendMessage {
+  SendMessage::new(ChatId::from(1), 0, MessageId::from(0), content)
+}
+
+fn text(text: &str) -> InputMessageContent {
+  InputMessageContent::InputMessageText(InputMessageText::new(FormattedText::new(text, vec![]), false, false))
+}
+
+fn photo(caption: &str) -> InputMessageContent {
+  let photo = InputMessagePhoto::builder().caption(FormattedText::new(caption, vec![])).build();
+  InputMessageContent::InputMessagePhoto(photo)
+}
+
+#[test]
+fn test_valid_requests() {
+  assert_eq!(send_message(text("hello")).validate(), Ok(()));
+  assert_eq!(GetMe::builder().build().validate(), Ok(()));
+  let command = BotCommand::new("start", "Starts the bot");
+  assert!(SetCommands::builder().commands(vec![command]).build().violations().is_empty());
+}
+
+#[test]
+fn test_message_content() {
+  let violations = send_message(text("")).violations();
+  assert_eq!(violations, vec![Violation {
+    path: "input_message_content.text".to_string(),
+    kind: ViolationKind::Length { min: 1, max: 4096, actual: 0 },
+  }]);
+  assert_eq!(violations[0].to_string(), "input_message_content.text: 0 characters, expected 1-4096");
+
+  let album = SendMessageAlbum::new(ChatId::from(1), 0, MessageId::from(0), vec![photo("ok"), photo(&"x".repeat(1025))]);
+  let violations = album.violations();
+  assert_eq!(violations.len(), 1);
+  assert_eq!(violations[0].path, "input_message_contents[1].caption");
+
+  let album = SendMessageAlbum::builder().input_message_contents(vec![photo("alone")]).build();
+  assert_eq!(album.violations()[0].kind, ViolationKind::Count { min: 2, max: 10, actual: 1 });
+}
+
+#[test]
+fn test_function_limits() {
+  let answer = AnswerCallbackQuery::builder().text("x".repeat(201)).build();
+  assert_eq!(answer.violations()[0].kind, ViolationKind::Length { min: 0, max: 200, actual: 201 });
+
+  let forward = ForwardMessages::builder().message_ids((1..=101).map(MessageId::from).collect()).build();
+  assert_eq!(forward.violations()[0].path, "message_ids");
+
+  let commands = vec![BotCommand::new("start", "Starts the bot"), BotCommand::new("do-it", "")];
+  let violations = SetCommands::builder().commands(commands).build().violations();
+  let paths: Vec<&str> = violations.iter().map(|violation| violation.path.as_str()).collect();
+  assert_eq!(paths, vec!["commands[1].command", "commands[1].description"]);
+  assert_eq!(violations[0].kind, ViolationKind::BotCommand);
+  // lowercase only
+  let commands = vec![BotCommand::new("Start", "Starts the bot"), BotCommand::new("get_2", "Gets two")];
+  let violations = SetCommands::builder().commands(commands).build().violations();
+  let paths: Vec<&str> = violations.iter().map(|violation| violation.path.as_str()).collect();
+  assert_eq!(paths, vec!["commands[0].command"]);
+}